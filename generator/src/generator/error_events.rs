@@ -5,6 +5,7 @@ pub(super) fn generate(out: &mut Output, module: &xcbgen::defs::Module) {
     generate_errors(out, module);
     outln!(out, "");
     generate_events(out, module);
+    generate_extension_events(out, module);
     outln!(out, "");
     outln!(
         out,
@@ -21,6 +22,50 @@ pub(super) fn generate(out: &mut Output, module: &xcbgen::defs::Module) {
     });
     outln!(out, "}}");
     outln!(out, "");
+    outln!(
+        out,
+        "/// Set the response type of a serialized X11 event, keeping the `SendEvent` bit."
+    );
+    outln!(
+        out,
+        "fn set_response_type(raw_bytes: &mut [u8], response_type: u8) {{"
+    );
+    outln!(
+        out.indent(),
+        "raw_bytes[0] = (raw_bytes[0] & 0x80) | response_type;"
+    );
+    outln!(out, "}}");
+    outln!(out, "");
+    outln!(
+        out,
+        "/// Set the header fields of a serialized generic X11 event."
+    );
+    outln!(out, "#[allow(dead_code)]");
+    outln!(
+        out,
+        "fn set_generic_event_header(raw_bytes: &mut [u8], extension: u8, event_type: u16) {{"
+    );
+    out.indented(|out| {
+        outln!(
+            out,
+            "let length = u32::try_from((raw_bytes.len() - 32) / 4).unwrap();"
+        );
+        outln!(
+            out,
+            "set_response_type(raw_bytes, xproto::GE_GENERIC_EVENT);"
+        );
+        outln!(out, "raw_bytes[1] = extension;");
+        outln!(
+            out,
+            "raw_bytes[4..8].copy_from_slice(&length.to_ne_bytes());"
+        );
+        outln!(
+            out,
+            "raw_bytes[8..10].copy_from_slice(&event_type.to_ne_bytes());"
+        );
+    });
+    outln!(out, "}}");
+    outln!(out, "");
     outln!(out, "/// Get the sequence number out of an X11 packet.");
     outln!(
         out,
@@ -172,6 +217,33 @@ fn generate_events(out: &mut Output, module: &xcbgen::defs::Module) {
     });
     outln!(out, "}}");
     outln!(out, "");
+    outln!(out, "/// Enumeration of all possible X11 event kinds.");
+    outln!(out, "///");
+    outln!(
+        out,
+        "/// There is one kind for each variant of [`Event`], see [`Event::kind`]."
+    );
+    outln!(
+        out,
+        "#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]"
+    );
+    outln!(out, "#[non_exhaustive]");
+    outln!(out, "pub enum EventKind {{");
+    out.indented(|out| {
+        outln!(out, "Unknown,");
+        outln!(out, "Error,");
+        for ns in namespaces.iter() {
+            let has_feature = super::ext_has_feature(&ns.header);
+            for event_name in sorted_events(ns).iter().map(|def| def.name()) {
+                if has_feature {
+                    outln!(out, "#[cfg(feature = \"{}\")]", ns.header);
+                }
+                outln!(out, "{}{},", get_ns_name_prefix(ns), event_name);
+            }
+        }
+    });
+    outln!(out, "}}");
+    outln!(out, "");
     outln!(out, "impl Event {{");
     out.indented(|out| {
         outln!(
@@ -385,6 +457,300 @@ fn generate_events(out: &mut Output, module: &xcbgen::defs::Module) {
         outln!(out, "}}");
         outln!(out, "");
 
+        outln!(out, "/// Get the name of this X11 event");
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// Events from extensions are prefixed with the name of the extension, e.g. `RandR::Notify`.",
+        );
+        outln!(out, "pub fn name(&self) -> &'static str {{");
+        out.indented(|out| {
+            outln!(out, "match self {{");
+            outln!(out.indent(), "Event::Unknown(_) => \"Unknown\",");
+            outln!(out.indent(), "Event::Error(_) => \"Error\",");
+            for ns in namespaces.iter() {
+                let event_defs = sorted_events(ns);
+                let has_feature = super::ext_has_feature(&ns.header);
+                for event_def in event_defs.iter() {
+                    if has_feature {
+                        outln!(out.indent(), "#[cfg(feature = \"{}\")]", ns.header);
+                    }
+                    let name = match &ns.ext_info {
+                        Some(ext_info) => format!("{}::{}", ext_info.name, event_def.name()),
+                        None => event_def.name().to_string(),
+                    };
+                    outln!(
+                        out.indent(),
+                        "Event::{}{}(_) => \"{}\",",
+                        get_ns_name_prefix(ns),
+                        event_def.name(),
+                        name,
+                    );
+                }
+            }
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+        outln!(out, "");
+
+        outln!(out, "/// Get the kind of this X11 event");
+        outln!(out, "pub fn kind(&self) -> EventKind {{");
+        out.indented(|out| {
+            outln!(out, "match self {{");
+            outln!(out.indent(), "Event::Unknown(_) => EventKind::Unknown,");
+            outln!(out.indent(), "Event::Error(_) => EventKind::Error,");
+            for ns in namespaces.iter() {
+                let has_feature = super::ext_has_feature(&ns.header);
+                for event_def in sorted_events(ns).iter() {
+                    if has_feature {
+                        outln!(out.indent(), "#[cfg(feature = \"{}\")]", ns.header);
+                    }
+                    outln!(
+                        out.indent(),
+                        "Event::{prefix}{name}(_) => EventKind::{prefix}{name},",
+                        prefix = get_ns_name_prefix(ns),
+                        name = event_def.name(),
+                    );
+                }
+            }
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+        outln!(out, "");
+
+        outln!(
+            out,
+            "/// Get the number of this X11 event in the definition of its extension"
+        );
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// For core events, this is the response type, e.g. [`xproto::EXPOSE_EVENT`]. For"
+        );
+        outln!(
+            out,
+            "/// extension events, this is the number relative to the extension's first event or,"
+        );
+        outln!(
+            out,
+            "/// for generic events, the event type. Errors and unknown events return `None`."
+        );
+        outln!(out, "pub fn event_code(&self) -> Option<u16> {{");
+        out.indented(|out| {
+            outln!(out, "match self {{");
+            outln!(out.indent(), "Event::Unknown(_) | Event::Error(_) => None,");
+            for ns in namespaces.iter() {
+                let has_feature = super::ext_has_feature(&ns.header);
+                for event_def in sorted_events(ns).iter() {
+                    if has_feature {
+                        outln!(out.indent(), "#[cfg(feature = \"{}\")]", ns.header);
+                    }
+                    let number = format!(
+                        "{}::{}_EVENT",
+                        ns.header,
+                        super::camel_case_to_upper_snake(event_def.name()),
+                    );
+                    // Generic events from extensions use 16 bit event types
+                    let number = if event_def.is_xge() && ns.ext_info.is_some() {
+                        number
+                    } else {
+                        format!("u16::from({})", number)
+                    };
+                    outln!(
+                        out.indent(),
+                        "Event::{}{}(_) => Some({}),",
+                        get_ns_name_prefix(ns),
+                        event_def.name(),
+                        number,
+                    );
+                }
+            }
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+        outln!(out, "");
+
+        outln!(out, "/// Get the window that this X11 event is reported for");
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// Like in Xlib, this is the window on which the event was selected, if possible."
+        );
+        outln!(
+            out,
+            "/// This is the `event` field of events that have one, e.g. `ConfigureNotify`, the"
+        );
+        outln!(
+            out,
+            "/// `parent` field of e.g. `MapRequest`, and otherwise the `window` field. For"
+        );
+        outln!(
+            out,
+            "/// selection events, the `owner` or `requestor` is returned. Events without such"
+        );
+        outln!(out, "/// a field return `None`.");
+        outln!(out, "pub fn window(&self) -> Option<xproto::Window> {{");
+        out.indented(|out| {
+            outln!(out, "match self {{");
+            for ns in namespaces.iter() {
+                let has_feature = super::ext_has_feature(&ns.header);
+                for event_def in sorted_events(ns).iter() {
+                    if let Some(field) = event_window_field(&event_def.get_original_full_def()) {
+                        if has_feature {
+                            outln!(out.indent(), "#[cfg(feature = \"{}\")]", ns.header);
+                        }
+                        outln!(
+                            out.indent(),
+                            "Event::{}{}(value) => Some(value.{}),",
+                            get_ns_name_prefix(ns),
+                            event_def.name(),
+                            field,
+                        );
+                    }
+                }
+            }
+            outln!(out.indent(), "_ => None,");
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+        outln!(out, "");
+
+        outln!(
+            out,
+            "/// Get the name of the extension that this X11 event belongs to"
+        );
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// Returns `None` for core events, errors, and unknown events."
+        );
+        outln!(
+            out,
+            "pub fn extension(&self) -> Option<&'static str> {{"
+        );
+        out.indented(|out| {
+            outln!(out, "match self {{");
+            for ns in namespaces.iter() {
+                if ns.ext_info.is_none() {
+                    continue;
+                }
+                let has_feature = super::ext_has_feature(&ns.header);
+                for event_def in sorted_events(ns).iter() {
+                    if has_feature {
+                        outln!(out.indent(), "#[cfg(feature = \"{}\")]", ns.header);
+                    }
+                    outln!(
+                        out.indent(),
+                        "Event::{}{}(_) => Some({}::X11_EXTENSION_NAME),",
+                        get_ns_name_prefix(ns),
+                        event_def.name(),
+                        ns.header,
+                    );
+                }
+            }
+            outln!(out.indent(), "_ => None,");
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+        outln!(out, "");
+
+        outln!(out, "/// Serialize this X11 event into its wire format");
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// The response type is set according to the kind of this event, keeping the bit"
+        );
+        outln!(
+            out,
+            "/// that marks events sent via `SendEvent`. Generic events also get their extension,"
+        );
+        outln!(
+            out,
+            "/// event type and length fields set. For events from extensions, `ext_info` must"
+        );
+        outln!(
+            out,
+            "/// describe the extension returned by [`Event::extension`]. `None` is returned"
+        );
+        outln!(out, "/// if `ext_info` is needed, but missing.");
+        outln!(out, "pub fn serialize(");
+        out.indented(|out| {
+            outln!(out, "&self,");
+            outln!(
+                out,
+                "// Might not be used if none of the extensions with events is enabled"
+            );
+            outln!(out, "#[allow(unused_variables)]");
+            outln!(out, "ext_info: Option<&ExtensionInformation>,");
+        });
+        outln!(out, ") -> Option<Vec<u8>> {{");
+        out.indented(|out| {
+            outln!(out, "let mut bytes = Vec::new();");
+            outln!(out, "match self {{");
+            out.indented(|out| {
+                outln!(
+                    out,
+                    "Event::Unknown(value) => bytes.extend_from_slice(value),"
+                );
+                outln!(out, "Event::Error(value) => value.serialize_into(&mut bytes),");
+                for ns in namespaces.iter() {
+                    let has_feature = super::ext_has_feature(&ns.header);
+                    for event_def in sorted_events(ns).iter() {
+                        if has_feature {
+                            outln!(out, "#[cfg(feature = \"{}\")]", ns.header);
+                        }
+                        outln!(
+                            out,
+                            "Event::{}{}(value) => {{",
+                            get_ns_name_prefix(ns),
+                            event_def.name(),
+                        );
+                        let number = format!(
+                            "{}::{}_EVENT",
+                            ns.header,
+                            super::camel_case_to_upper_snake(event_def.name()),
+                        );
+                        out.indented(|out| {
+                            if event_def.is_xge() {
+                                outln!(out, "value.serialize_into(&mut bytes);");
+                            } else {
+                                outln!(
+                                    out,
+                                    "bytes.extend_from_slice(&<[u8; 32]>::from(value));"
+                                );
+                            }
+                            if ns.ext_info.is_none() {
+                                outln!(out, "set_response_type(&mut bytes, {});", number);
+                            } else if event_def.is_xge() {
+                                outln!(
+                                    out,
+                                    "set_generic_event_header(&mut bytes, ext_info?.major_opcode, {});",
+                                    number,
+                                );
+                            } else if ns.header == "xkb" {
+                                outln!(
+                                    out,
+                                    "set_response_type(&mut bytes, ext_info?.first_event);"
+                                );
+                                outln!(out, "bytes[1] = {};", number);
+                            } else {
+                                outln!(
+                                    out,
+                                    "set_response_type(&mut bytes, ext_info?.first_event.wrapping_add({}));",
+                                    number,
+                                );
+                            }
+                        });
+                        outln!(out, "}}");
+                    }
+                }
+            });
+            outln!(out, "}}");
+            outln!(out, "Some(bytes)");
+        });
+        outln!(out, "}}");
+        outln!(out, "");
+
         outln!(out, "/// Get the raw response type of this X11 event");
         outln!(out, "///");
         outln!(
@@ -454,6 +820,188 @@ pub fn sent_event(&self) -> bool {{
         );
     });
     outln!(out, "}}");
+
+    for ns in namespaces.iter() {
+        let has_feature = super::ext_has_feature(&ns.header);
+        // Event copies share the struct of the original event, so the conversion of that struct
+        // has to accept all variants that use it.
+        let mut structs: Vec<(String, Vec<String>)> = Vec::new();
+        for event_def in sorted_events(ns).iter() {
+            let original = event_def.get_original_full_def().name.clone();
+            let variant = format!("Event::{}{}", get_ns_name_prefix(ns), event_def.name());
+            match structs.iter_mut().find(|(name, _)| *name == original) {
+                Some((_, variants)) => variants.push(variant),
+                None => structs.push((original, vec![variant])),
+            }
+        }
+        for (name, variants) in structs.iter() {
+            let pattern = variants
+                .iter()
+                .map(|variant| format!("{}(value)", variant))
+                .collect::<Vec<_>>()
+                .join(" | ");
+            // Make it obvious that e.g. ButtonPressEvent also matches ButtonRelease
+            let doc = if variants.len() > 1 {
+                let copies = variants[1..]
+                    .iter()
+                    .map(|variant| format!("`{}`", variant))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                Some(format!(
+                    "/// Besides `{}`, this also accepts {}. They use the same struct; match on \
+                     [`Event`] to tell them apart.",
+                    variants[0], copies
+                ))
+            } else {
+                None
+            };
+            outln!(out, "");
+            if let Some(doc) = &doc {
+                outln!(out, "{}", doc);
+            }
+            if has_feature {
+                outln!(out, "#[cfg(feature = \"{}\")]", ns.header);
+            }
+            outln!(
+                out,
+                "impl TryFrom<Event> for {}::{}Event {{",
+                ns.header,
+                name
+            );
+            out.indented(|out| {
+                outln!(out, "type Error = Event;");
+                outln!(out, "fn try_from(event: Event) -> Result<Self, Event> {{");
+                out.indented(|out| {
+                    outln!(out, "match event {{");
+                    outln!(out.indent(), "{} => Ok(value),", pattern);
+                    outln!(out.indent(), "event => Err(event),");
+                    outln!(out, "}}");
+                });
+                outln!(out, "}}");
+            });
+            outln!(out, "}}");
+            if let Some(doc) = &doc {
+                outln!(out, "{}", doc);
+            }
+            if has_feature {
+                outln!(out, "#[cfg(feature = \"{}\")]", ns.header);
+            }
+            outln!(
+                out,
+                "impl<'a> TryFrom<&'a Event> for &'a {}::{}Event {{",
+                ns.header,
+                name
+            );
+            out.indented(|out| {
+                outln!(out, "type Error = &'a Event;");
+                outln!(
+                    out,
+                    "fn try_from(event: &'a Event) -> Result<Self, &'a Event> {{"
+                );
+                out.indented(|out| {
+                    outln!(out, "match event {{");
+                    outln!(out.indent(), "{} => Ok(value),", pattern);
+                    outln!(out.indent(), "event => Err(event),");
+                    outln!(out, "}}");
+                });
+                outln!(out, "}}");
+            });
+            outln!(out, "}}");
+        }
+    }
+}
+
+/// Generate an enum with the events of each extension and conversions from and to `Event`.
+fn generate_extension_events(out: &mut Output, module: &xcbgen::defs::Module) {
+    for ns in module.sorted_namespaces().iter() {
+        let event_defs = sorted_events(ns);
+        if ns.ext_info.is_none() || event_defs.is_empty() {
+            continue;
+        }
+        let prefix = get_ns_name_prefix(ns);
+        let cfg = if super::ext_has_feature(&ns.header) {
+            format!("#[cfg(feature = \"{}\")]", ns.header)
+        } else {
+            String::new()
+        };
+        let cfg = |out: &mut Output| {
+            if !cfg.is_empty() {
+                outln!(out, "{}", cfg);
+            }
+        };
+
+        outln!(out, "");
+        outln!(
+            out,
+            "/// Enumeration of the events of the `{}` extension.",
+            ns.header
+        );
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// An [`Event`] can be converted into this enum with `TryFrom`, which allows to"
+        );
+        outln!(
+            out,
+            "/// match on the events of one extension without listing the events of all others."
+        );
+        cfg(out);
+        outln!(out, "#[derive(Debug, Clone)]");
+        outln!(out, "#[non_exhaustive]");
+        outln!(out, "pub enum {}Event {{", prefix);
+        for event_def in event_defs.iter() {
+            outln!(
+                out.indent(),
+                "{}({}::{}Event),",
+                event_def.name(),
+                ns.header,
+                event_def.name(),
+            );
+        }
+        outln!(out, "}}");
+
+        cfg(out);
+        outln!(out, "impl From<{}Event> for Event {{", prefix);
+        out.indented(|out| {
+            outln!(out, "fn from(event: {}Event) -> Self {{", prefix);
+            out.indented(|out| {
+                outln!(out, "match event {{");
+                for event_def in event_defs.iter() {
+                    outln!(
+                        out.indent(),
+                        "{prefix}Event::{name}(value) => Event::{prefix}{name}(value),",
+                        prefix = prefix,
+                        name = event_def.name(),
+                    );
+                }
+                outln!(out, "}}");
+            });
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+
+        cfg(out);
+        outln!(out, "impl TryFrom<Event> for {}Event {{", prefix);
+        out.indented(|out| {
+            outln!(out, "type Error = Event;");
+            outln!(out, "fn try_from(event: Event) -> Result<Self, Event> {{");
+            out.indented(|out| {
+                outln!(out, "match event {{");
+                for event_def in event_defs.iter() {
+                    outln!(
+                        out.indent(),
+                        "Event::{prefix}{name}(value) => Ok({prefix}Event::{name}(value)),",
+                        prefix = prefix,
+                        name = event_def.name(),
+                    );
+                }
+                outln!(out.indent(), "event => Err(event),");
+                outln!(out, "}}");
+            });
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+    }
 }
 
 fn sorted_errors(ns: &xcbgen::defs::Namespace) -> Vec<xcbgen::defs::ErrorDef> {
@@ -481,6 +1029,28 @@ fn sorted_errors(ns: &xcbgen::defs::Namespace) -> Vec<xcbgen::defs::ErrorDef> {
     errors
 }
 
+/// Find the field that contains the window that an event is reported for.
+fn event_window_field(event_def: &xcbgen::defs::EventFullDef) -> Option<&'static str> {
+    let fields = event_def.fields.borrow();
+    let is_window = |name: &str| {
+        fields.iter().any(|field| match field {
+            xcbgen::defs::FieldDef::Normal(field) if field.name == name => {
+                match field.type_.type_.get_resolved() {
+                    xcbgen::defs::TypeRef::Xid(xid) => {
+                        let xid = xid.upgrade().unwrap();
+                        xid.name == "WINDOW" && xid.namespace.upgrade().unwrap().header == "xproto"
+                    }
+                    _ => false,
+                }
+            }
+            _ => false,
+        })
+    };
+    ["event", "parent", "window", "owner", "requestor"]
+        .into_iter()
+        .find(|name| is_window(name))
+}
+
 fn sorted_events(ns: &xcbgen::defs::Namespace) -> Vec<xcbgen::defs::EventDef> {
     let mut events: Vec<_> = ns.event_defs.borrow().values().cloned().collect();
     events.sort_by(|a, b| a.name().cmp(b.name()));
@@ -67,11 +67,11 @@ pub(crate) fn generate(module: &xcbgen::defs::Module) -> Vec<Generated> {
     outln!(main_proto_out, "use crate::RawFdContainer;");
     outln!(
         main_proto_out,
-        "use crate::x11_utils::{{TryParse, TryParseFd, X11Error, ReplyRequest, ReplyFDsRequest}};"
+        "use crate::x11_utils::{{Serialize, TryParse, TryParseFd, X11Error, ReplyRequest, ReplyFDsRequest}};"
     );
     outln!(
         main_proto_out,
-        "use crate::x11_utils::{{ExtInfoProvider, ReplyParsingFunction, RequestHeader}};"
+        "use crate::x11_utils::{{ExtInfoProvider, ExtensionInformation, ReplyParsingFunction, RequestDescription, RequestHeader}};"
     );
     outln!(main_proto_out, "");
 
@@ -141,6 +141,7 @@ pub(crate) fn generate(module: &xcbgen::defs::Module) -> Vec<Generated> {
         outln!(out, "pub use x11rb_protocol::protocol::Reply;");
         outln!(out, "pub use x11rb_protocol::protocol::ErrorKind;");
         outln!(out, "pub use x11rb_protocol::protocol::Event;");
+        outln!(out, "pub use x11rb_protocol::protocol::EventKind;");
     }
 
     out_map.push(Generated {
@@ -76,6 +76,9 @@ pub(super) fn write_header(
         out,
         "use crate::x11_utils::{{Request, RequestHeader, Serialize, TryParse, TryParseFd}};"
     );
+    if mode == Mode::Protocol {
+        outln!(out, "use crate::x11_utils::RequestDescription;");
+    }
     if mode == Mode::X11rb {
         outln!(out, "use std::io::IoSlice;");
         outln!(out, "use crate::connection::RequestConnection;");
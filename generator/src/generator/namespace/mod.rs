@@ -172,6 +172,18 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             }
         }
 
+        let request_descriptions = enum_cases
+            .get(&self.ns.header)
+            .map(|cases| cases.request_descriptions.as_slice())
+            .unwrap_or_default();
+        outln!(proto_out, "/// All requests of this module.");
+        outln!(proto_out, "pub const REQUESTS: &[RequestDescription] = &[");
+        for description in request_descriptions {
+            outln!(proto_out.indent(), "{}", description);
+        }
+        outln!(proto_out, "];");
+        outln!(proto_out, "");
+
         let trait_out = trait_out.into_data();
 
         outln!(
@@ -428,6 +440,10 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             out,
             r#"#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]"#
         );
+        outln!(
+            out,
+            r#"#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]"#
+        );
         outln!(out, "pub struct {}([u8; {}]);", rust_name, union_size);
 
         let fields = union_def.fields.as_slice();
@@ -614,6 +630,10 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             out,
             r#"#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]"#
         );
+        outln!(
+            out,
+            r#"#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]"#
+        );
         outln!(out, "pub struct {}([u8; 32]);", rust_name);
 
         outln!(out, "impl {} {{", rust_name);
@@ -752,6 +772,10 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             out,
             r#"#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]"#
         );
+        outln!(
+            out,
+            r#"#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]"#
+        );
         outln!(out, "pub struct {}({});", rust_name, raw_type);
 
         outln!(out, "impl {} {{", rust_name);
@@ -910,6 +934,60 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         });
         outln!(out, "}}");
 
+        outln!(out, "impl {} {{", rust_name);
+        out.indented(|out| {
+            outln!(
+                out,
+                "/// Get the name of this value if it is exactly one of the known values."
+            );
+            outln!(
+                out,
+                "pub fn name(&self) -> {}<&'static str> {{",
+                self.option_name
+            );
+            out.indented(|out| {
+                outln!(out, "match self.0 {{");
+                let mut seen_values = HashSet::new();
+                for enum_item in enum_def.items.iter() {
+                    let value = match enum_item.value {
+                        xcbdefs::EnumValue::Value(value) => value,
+                        xcbdefs::EnumValue::Bit(bit) => 1 << bit,
+                    };
+                    if !seen_values.insert(value) {
+                        continue;
+                    }
+                    let pattern = if global_enum_size == 1 {
+                        (if value == 0 { "false" } else { "true" }).to_string()
+                    } else {
+                        expr_to_str::format_literal_integer(value)
+                    };
+                    outln!(
+                        out.indent(),
+                        "{} => Some(\"{}\"),",
+                        pattern,
+                        ename_to_camel_case(&enum_item.name),
+                    );
+                }
+                if global_enum_size != 1 || seen_values.len() < 2 {
+                    outln!(out.indent(), "_ => None,");
+                }
+                outln!(out, "}}");
+            });
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+
+        outln!(out, "impl core::fmt::Display for {} {{", rust_name);
+        out.indented(|out| {
+            outln!(
+                out,
+                "fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {{"
+            );
+            outln!(out.indent(), "write!(fmt, \"{{:#?}}\", self)");
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+
         if ok_for_bitmask {
             outln!(out, "bitmask_binop!({}, {});", rust_name, raw_type);
         }
@@ -1076,6 +1154,31 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         }
     }
 
+    /// Emit the documentation of a single field of a struct, if it is documented.
+    ///
+    /// `rust_field_name` is the name of the field in the generated code.
+    fn emit_field_doc(&self, doc: Option<&xcbdefs::Doc>, rust_field_name: &str, out: &mut Output) {
+        let field_doc = doc
+            .and_then(|doc| {
+                doc.fields
+                    .iter()
+                    .find(|field| to_rust_variable_name(&field.name) == rust_field_name)
+            })
+            .and_then(|field| field.doc.as_deref())
+            .map(str::trim)
+            .filter(|field_doc| !field_doc.is_empty());
+        if let Some(field_doc) = field_doc {
+            for line in field_doc.split('\n') {
+                let line = line.trim();
+                if line.is_empty() {
+                    outln!(out, "///");
+                } else {
+                    outln!(out, "/// {}", line);
+                }
+            }
+        }
+    }
+
     fn emit_doc(
         &self,
         doc: &xcbdefs::Doc,
@@ -1584,6 +1687,56 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         }
     }
 
+    /// Returns an expression that checks whether `value`, the value of `field`, can be
+    /// serialized, or `None` if this is always the case.
+    ///
+    /// Only `<switch>`es with `<case>`s can contain values that cannot be serialized, because
+    /// parsing produces `InvalidValue` for discriminants that are not known.
+    fn field_encodable_check(&self, field: &xcbdefs::FieldDef, value: &str) -> Option<String> {
+        match field {
+            xcbdefs::FieldDef::Normal(normal_field) => self
+                .type_can_be_unencodable(normal_field.type_.type_.get_resolved())
+                .then(|| format!("{}.is_encodable()", value)),
+            xcbdefs::FieldDef::List(list_field) => self
+                .type_can_be_unencodable(list_field.element_type.type_.get_resolved())
+                .then(|| format!("{}.iter().all(|item| item.is_encodable())", value)),
+            xcbdefs::FieldDef::Switch(switch_field) => self
+                .switch_can_be_unencodable(switch_field)
+                .then(|| format!("{}.is_encodable()", value)),
+            _ => None,
+        }
+    }
+
+    /// Whether any of `fields` can contain a value that cannot be serialized.
+    fn fields_can_be_unencodable(&self, fields: &[xcbdefs::FieldDef]) -> bool {
+        fields
+            .iter()
+            .any(|field| self.field_encodable_check(field, "").is_some())
+    }
+
+    fn switch_can_be_unencodable(&self, switch: &xcbdefs::SwitchField) -> bool {
+        switch.kind == xcbdefs::SwitchKind::Case
+            || switch
+                .cases
+                .iter()
+                .any(|case| self.fields_can_be_unencodable(&case.fields.borrow()))
+    }
+
+    fn type_can_be_unencodable(&self, type_: &xcbdefs::TypeRef) -> bool {
+        match type_ {
+            xcbdefs::TypeRef::Struct(struct_def) => {
+                let struct_def = struct_def.upgrade().unwrap();
+                let fields = struct_def.fields.borrow();
+                self.fields_can_be_unencodable(&fields)
+            }
+            xcbdefs::TypeRef::Alias(type_alias_def) => {
+                let type_alias_def = type_alias_def.upgrade().unwrap();
+                self.type_can_be_unencodable(type_alias_def.old_name.get_resolved())
+            }
+            _ => false,
+        }
+    }
+
     /// Whether the field is visible (i.e., appears in parsed rust structs)
     fn field_is_visible(
         &self,
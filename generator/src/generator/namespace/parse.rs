@@ -161,13 +161,22 @@ pub(super) fn emit_field_parse(
                     );
                     outln!(
                         out,
-                        "let mut {} = Vec::with_capacity(list_length);",
+                        "let mut {} = Vec::with_capacity(list_length.min(remaining.len()));",
                         rust_field_name
                     );
                     outln!(out, "for _ in 0..list_length {{");
                 } else {
                     outln!(out, "// Length is 'everything left in the input'");
-                    outln!(out, "let mut {} = Vec::new();", rust_field_name);
+                    match list_field.element_type.size() {
+                        // Elements of a fixed size: the number of elements is known upfront
+                        Some(size) if size > 0 => outln!(
+                            out,
+                            "let mut {} = Vec::with_capacity(remaining.len() / {});",
+                            rust_field_name,
+                            size,
+                        ),
+                        _ => outln!(out, "let mut {} = Vec::new();", rust_field_name),
+                    }
                     outln!(out, "while !remaining.is_empty() {{");
                 }
                 out.indented(|out| {
@@ -149,11 +149,12 @@ pub(super) fn generate_request(
 
     let deducible_fields = gather_deducible_fields(&request_fields);
 
+    let mut aux_setters = Vec::new();
     if switch_fields.len() == 1 {
         if let Some(aux_start_align) = switch_fields[0].required_start_align {
             assert_eq!(aux_start_align.offset(), 0);
         }
-        generate_aux(
+        aux_setters = generate_aux(
             generator,
             request_def,
             switch_fields[0],
@@ -170,6 +171,13 @@ pub(super) fn generate_request(
         request_def.opcode,
     );
 
+    enum_cases.request_descriptions.push(format!(
+        "RequestDescription {{ opcode: {}_REQUEST, name: \"{}\", has_reply: {} }},",
+        super::super::camel_case_to_upper_snake(&name),
+        request_def.name,
+        request_def.reply.is_some(),
+    ));
+
     let gathered = gather_request_fields(generator, request_def, &deducible_fields);
 
     let num_slices = emit_request_struct(
@@ -180,6 +188,9 @@ pub(super) fn generate_request(
         &gathered,
         proto_out,
     );
+    if !aux_setters.is_empty() {
+        emit_request_builder(&name, switch_fields[0], &aux_setters, &gathered, proto_out);
+    }
     let ns_prefix = get_ns_name_prefix(generator.ns);
     let lifetime_block = if gathered.needs_lifetime {
         "<'input>"
@@ -278,6 +289,18 @@ pub(super) fn generate_request(
             },
             lifetime = if gathered.needs_lifetime { "<'_>" } else { "" }
         ));
+        enum_cases.reply_parser_by_opcode_cases.push(format!(
+            "{header}::{opcode_name}_REQUEST => Some({func}::<{header}::{name}Request{lifetime}>),",
+            header = generator.ns.header,
+            opcode_name = super::super::camel_case_to_upper_snake(&name),
+            name = name,
+            func = if gathered.reply_has_fds {
+                "parse_reply_fds"
+            } else {
+                "parse_reply"
+            },
+            lifetime = if gathered.needs_lifetime { "<'_>" } else { "" }
+        ));
         enum_cases.reply_from_cases.push(format!(
             r#"impl From<{header}::{name}Reply> for Reply {{
   fn from(reply: {header}::{name}Reply) -> Reply {{
@@ -337,8 +360,9 @@ fn generate_aux(
     switch_field: &xcbdefs::SwitchField,
     function_name: &str,
     out: &mut Output,
-) {
+) -> Vec<(String, String)> {
     let aux_name = format!("{}Aux", request_def.name);
+    let mut setters = Vec::new();
 
     if switch_field.kind == xcbdefs::SwitchKind::Case {
         switch::emit_switch_type(generator, switch_field, &aux_name, true, true, None, out);
@@ -397,6 +421,11 @@ fn generate_aux(
                 outln!(out.indent(), "self.{} = value.into();", rust_case_name);
                 outln!(out.indent(), "self");
                 outln!(out, "}}");
+                setters.push((rust_case_name, rust_case_type));
+            }
+
+            if let Some(fields) = comparable_aux_fields(switch_field, &cases_infos) {
+                emit_aux_changes_from(&fields, out);
             }
         });
         outln!(out, "}}");
@@ -405,6 +434,212 @@ fn generate_aux(
     special_cases::handle_request_switch(request_def, switch_field, &aux_name, out);
 
     outln!(out, "");
+    setters
+}
+
+/// Get the fields of an aux struct if they can all be compared without the `extra-traits`
+/// feature.
+///
+/// Returns the name of each field and whether it is a list that needs to be cloned.
+fn comparable_aux_fields(
+    switch_field: &xcbdefs::SwitchField,
+    cases_infos: &[CaseInfo],
+) -> Option<Vec<(String, bool)>> {
+    fn type_is_comparable(type_: &xcbdefs::TypeRef) -> bool {
+        match type_ {
+            xcbdefs::TypeRef::BuiltIn(_)
+            | xcbdefs::TypeRef::Xid(_)
+            | xcbdefs::TypeRef::XidUnion(_)
+            | xcbdefs::TypeRef::Enum(_) => true,
+            xcbdefs::TypeRef::Alias(type_alias_def) => {
+                let type_alias_def = type_alias_def.upgrade().unwrap();
+                type_is_comparable(type_alias_def.old_name.get_resolved())
+            }
+            xcbdefs::TypeRef::Struct(_)
+            | xcbdefs::TypeRef::Union(_)
+            | xcbdefs::TypeRef::EventStruct(_) => false,
+        }
+    }
+
+    switch_field
+        .cases
+        .iter()
+        .zip(cases_infos.iter())
+        .map(|(case, case_info)| {
+            let index = match case_info {
+                CaseInfo::SingleField(index) => *index,
+                CaseInfo::MultiField(_, _) => return None,
+            };
+            let fields = case.fields.borrow();
+            let (type_, is_list) = match &fields[index] {
+                xcbdefs::FieldDef::Normal(normal_field) => {
+                    (normal_field.type_.type_.get_resolved(), false)
+                }
+                xcbdefs::FieldDef::List(list_field) => {
+                    (list_field.element_type.type_.get_resolved(), true)
+                }
+                _ => return None,
+            };
+            if type_is_comparable(type_) {
+                Some((
+                    to_rust_variable_name(fields[index].name().unwrap()),
+                    is_list,
+                ))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Emit a function that computes the difference between two aux structs.
+fn emit_aux_changes_from(fields: &[(String, bool)], out: &mut Output) {
+    outln!(
+        out,
+        "/// Get the changes that are needed to go from `old` to `self`."
+    );
+    outln!(out, "///");
+    outln!(
+        out,
+        "/// The result contains all fields that are set in `self` and that are either unset in"
+    );
+    outln!(
+        out,
+        "/// `old` or set to a different value. This allows to only send values that changed."
+    );
+    outln!(out, "#[must_use]");
+    outln!(out, "pub fn changes_from(&self, old: &Self) -> Self {{");
+    out.indented(|out| {
+        outln!(out, "let mut result = Self::new();");
+        for (name, is_list) in fields {
+            outln!(
+                out,
+                "if self.{name}.is_some() && self.{name} != old.{name} {{",
+                name = name,
+            );
+            if *is_list {
+                outln!(
+                    out.indent(),
+                    "result.{name} = self.{name}.clone();",
+                    name = name
+                );
+            } else {
+                outln!(out.indent(), "result.{name} = self.{name};", name = name);
+            }
+            outln!(out, "}}");
+        }
+        outln!(out, "result");
+    });
+    outln!(out, "}}");
+}
+
+/// Emit a constructor and builder methods for a request with an aux struct.
+///
+/// The builder methods set the fields of the aux struct, so that requests with many optional
+/// fields can be constructed without naming the aux struct. Requests with other borrowed
+/// arguments or with file descriptors are skipped.
+fn emit_request_builder(
+    name: &str,
+    switch_field: &xcbdefs::SwitchField,
+    setters: &[(String, String)],
+    gathered: &GatheredRequestFields,
+    out: &mut Output,
+) {
+    let aux_field_name = to_rust_variable_name(&switch_field.name);
+    let other_args_are_simple = gathered
+        .args
+        .iter()
+        .all(|(arg_name, arg_type)| *arg_name == aux_field_name || !arg_type.needs_any_cow());
+    if !other_args_are_simple || gathered.has_fds() {
+        return;
+    }
+
+    let generics = gathered
+        .generics
+        .iter()
+        .map(|(param, _)| param.as_str())
+        .collect::<Vec<_>>();
+    let args = gathered
+        .args
+        .iter()
+        .filter(|(arg_name, _)| *arg_name != aux_field_name)
+        .map(|(arg_name, arg_type)| format!("{}: {}", arg_name, arg_type.as_argument()))
+        .collect::<Vec<_>>();
+
+    outln!(out, "impl {}Request<'static> {{", name);
+    out.indented(|out| {
+        outln!(
+            out,
+            "/// Create a new request with all fields of `{}` unset.",
+            aux_field_name,
+        );
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// The optional fields can then be set with the builder methods of this request."
+        );
+        if generics.is_empty() {
+            outln!(out, "pub fn new({}) -> Self {{", args.join(", "));
+        } else {
+            outln!(
+                out,
+                "pub fn new<{}>({}) -> Self",
+                generics.join(", "),
+                args.join(", "),
+            );
+            outln!(out, "where");
+            for (param, where_) in gathered.generics.iter() {
+                outln!(out.indent(), "{}: {},", param, where_);
+            }
+            outln!(out, "{{");
+        }
+        out.indented(|out| {
+            for preamble in gathered.preamble.iter() {
+                outln!(out, "{}", preamble);
+            }
+            outln!(out, "{}Request {{", name);
+            out.indented(|out| {
+                for (arg_name, _) in gathered.args.iter() {
+                    if *arg_name == aux_field_name {
+                        outln!(out, "{}: Cow::Owned({}Aux::new()),", arg_name, name);
+                    } else {
+                        outln!(out, "{},", arg_name);
+                    }
+                }
+            });
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+    });
+    outln!(out, "}}");
+
+    outln!(out, "impl<'input> {}Request<'input> {{", name);
+    out.indented(|out| {
+        for (setter_name, setter_type) in setters {
+            outln!(
+                out,
+                "/// Set the `{}` field of `{}`.",
+                setter_name,
+                aux_field_name,
+            );
+            outln!(out, "#[must_use]");
+            outln!(
+                out,
+                "pub fn {}<I>(mut self, value: I) -> Self where I: Into<Option<{}>> {{",
+                setter_name,
+                setter_type,
+            );
+            outln!(
+                out.indent(),
+                "self.{}.to_mut().{} = value.into();",
+                aux_field_name,
+                setter_name,
+            );
+            outln!(out.indent(), "self");
+            outln!(out, "}}");
+        }
+    });
+    outln!(out, "}}");
 }
 
 fn emit_request_struct(
@@ -442,6 +677,10 @@ fn emit_request_struct(
             out,
             r#"#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]"#
         );
+        outln!(
+            out,
+            r#"#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]"#
+        );
     }
 
     let (struct_lifetime_block, serialize_lifetime_return, parse_lifetime_block) =
@@ -466,6 +705,7 @@ fn emit_request_struct(
     );
     out.indented(|out| {
         for (member_name, member_type) in gathered.request_args.iter() {
+            generator.emit_field_doc(request_def.doc.as_ref(), member_name, out);
             outln!(out, "pub {name}: {type},",
                    name=member_name,
                    type=member_type.as_field());
@@ -866,39 +1106,7 @@ fn emit_request_struct(
                 "request0[2..4].copy_from_slice(&length.to_ne_bytes());",
             );
 
-            let fds_arg = if gathered.fd_lists.is_empty() {
-                let mut fds_arg = String::from("vec![");
-                let mut sep = "";
-                for single_fd in &gathered.single_fds {
-                    fds_arg.push_str(sep);
-                    fds_arg.push_str("self.");
-                    fds_arg.push_str(single_fd);
-
-                    sep = ", ";
-                }
-                fds_arg.push(']');
-                fds_arg
-            } else if gathered.fd_lists.len() == 1 && gathered.single_fds.is_empty() {
-                format!("self.{}", gathered.fd_lists[0])
-            } else {
-                outln!(out, "let mut fds = Vec::new();");
-                for field in fields.iter() {
-                    match field {
-                        xcbdefs::FieldDef::Fd(fd_field) => {
-                            outln!(out, "fds.push({});", to_rust_variable_name(&fd_field.name));
-                        }
-                        xcbdefs::FieldDef::FdList(fd_list_field) => {
-                            outln!(
-                                out,
-                                "fds.extend({});",
-                                to_rust_variable_name(&fd_list_field.name)
-                            );
-                        }
-                        _ => {}
-                    }
-                }
-                String::from("fds")
-            };
+            let fds_arg = emit_request_fds(&fields, gathered, out);
 
             num_slices = request_slices.len();
             num_slices_opt = Some(num_slices);
@@ -1140,6 +1348,33 @@ fn emit_request_struct(
             outln!(out, "(buf, fds)");
         });
         outln!(out, "}}");
+        emit_request_serialize_into(
+            generator,
+            request_def,
+            name,
+            deducible_fields,
+            gathered,
+            arg_name,
+            out,
+        );
+        outln!(
+            out,
+            "fn serialize_into_slices<'s>(self, {opcode}: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>",
+            opcode = arg_name
+        );
+        outln!(out, "where");
+        outln!(out.indent(), "Self: 's,");
+        outln!(out, "{{");
+        out.indented(|out| {
+            if is_xproto {
+                outln!(out, "let (bufs, fds) = self.serialize();");
+            } else {
+                outln!(out, "let (bufs, fds) = self.serialize(major_opcode);");
+            }
+            outln!(out, "slices.extend(bufs);");
+            outln!(out, "fds");
+        });
+        outln!(out, "}}");
     });
     outln!(out, "}}");
 
@@ -1164,6 +1399,371 @@ fn emit_request_struct(
     num_slices_opt.unwrap()
 }
 
+/// Emit the code that collects the file descriptors of a request and return the
+/// expression that evaluates to them.
+fn emit_request_fds(
+    fields: &[xcbdefs::FieldDef],
+    gathered: &GatheredRequestFields,
+    out: &mut Output,
+) -> String {
+    if gathered.fd_lists.is_empty() {
+        let mut fds_arg = String::from("vec![");
+        let mut sep = "";
+        for single_fd in &gathered.single_fds {
+            fds_arg.push_str(sep);
+            fds_arg.push_str("self.");
+            fds_arg.push_str(single_fd);
+
+            sep = ", ";
+        }
+        fds_arg.push(']');
+        fds_arg
+    } else if gathered.fd_lists.len() == 1 && gathered.single_fds.is_empty() {
+        format!("self.{}", gathered.fd_lists[0])
+    } else {
+        outln!(out, "let mut fds = Vec::new();");
+        for field in fields.iter() {
+            match field {
+                xcbdefs::FieldDef::Fd(fd_field) => {
+                    outln!(out, "fds.push({});", to_rust_variable_name(&fd_field.name));
+                }
+                xcbdefs::FieldDef::FdList(fd_list_field) => {
+                    outln!(
+                        out,
+                        "fds.extend({});",
+                        to_rust_variable_name(&fd_list_field.name)
+                    );
+                }
+                _ => {}
+            }
+        }
+        String::from("fds")
+    }
+}
+
+/// Emit `Request::serialize_into`, which writes the request directly into the
+/// caller's buffer instead of building the slices of `serialize` first.
+fn emit_request_serialize_into(
+    generator: &NamespaceGenerator<'_, '_>,
+    request_def: &xcbdefs::RequestDef,
+    name: &str,
+    deducible_fields: &HashMap<String, DeducibleField>,
+    gathered: &GatheredRequestFields,
+    opcode_arg_name: &str,
+    out: &mut Output,
+) {
+    let ns = request_def.namespace.upgrade().unwrap();
+    let fields = request_def.fields.borrow();
+
+    let wrap_field_ref = |field_name: &str| {
+        let rust_field_name = to_rust_variable_name(field_name);
+        if !deducible_fields.contains_key(field_name) {
+            format!("self.{}", rust_field_name)
+        } else {
+            rust_field_name
+        }
+    };
+    let flush_fixed_fields = |fixed_fields_bytes: &mut Vec<String>, out: &mut Output| {
+        if !fixed_fields_bytes.is_empty() {
+            outln!(out, "buffer.extend_from_slice(&[");
+            for byte in fixed_fields_bytes.iter() {
+                outln!(out.indent(), "{},", byte);
+            }
+            outln!(out, "]);");
+            fixed_fields_bytes.clear();
+        }
+    };
+
+    outln!(
+        out,
+        "fn serialize_into(self, {opcode}: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {{",
+        opcode = opcode_arg_name,
+    );
+    out.indented(|out| {
+        if fields
+            .iter()
+            .any(|field| matches!(field, xcbdefs::FieldDef::Expr(_)))
+        {
+            for field in fields.iter() {
+                if let xcbdefs::FieldDef::VirtualLen(virtual_len_field) = field {
+                    outln!(
+                        out,
+                        "let {} = u32::try_from(self.{}.len()).unwrap();",
+                        to_rust_variable_name(&virtual_len_field.name),
+                        to_rust_variable_name(&virtual_len_field.list_name),
+                    );
+                }
+            }
+        }
+
+        outln!(out, "let request_start = buffer.len();");
+
+        let mut request_size = fields
+            .iter()
+            .try_fold(0, |sum, field| Some(sum + field.size()?));
+        let mut fixed_fields_bytes = Vec::new();
+
+        for (field_i, field) in fields.iter().enumerate() {
+            // Code that appends a variable sized part of the request to the buffer
+            let mut var_part = None;
+            let mut asserts = Output::new();
+            serialize::emit_assert_for_field_serialize(
+                generator,
+                field,
+                deducible_fields,
+                wrap_field_ref,
+                &mut asserts,
+            );
+            match field {
+                xcbdefs::FieldDef::Pad(pad_field) => match pad_field.kind {
+                    xcbdefs::PadKind::Bytes(bytes) => {
+                        for _ in 0..bytes {
+                            fixed_fields_bytes.push(String::from("0"));
+                        }
+                    }
+                    xcbdefs::PadKind::Align(align) => {
+                        let mut part = Output::new();
+                        outln!(part, "let length_so_far = buffer.len() - request_start;");
+                        outln!(
+                            part,
+                            "buffer.extend_from_slice(&[0; {}][..({} - (length_so_far % {})) % {}]);",
+                            align - 1,
+                            align,
+                            align,
+                            align,
+                        );
+                        var_part = Some(part);
+                    }
+                },
+                xcbdefs::FieldDef::Normal(normal_field) => {
+                    if normal_field.name == "major_opcode" {
+                        if ns.ext_info.is_some() {
+                            fixed_fields_bytes.push(String::from("major_opcode"));
+                        } else {
+                            fixed_fields_bytes.push(format!(
+                                "{}_REQUEST",
+                                super::super::camel_case_to_upper_snake(name),
+                            ));
+                        }
+                    } else if normal_field.name == "minor_opcode" {
+                        fixed_fields_bytes.push(format!(
+                            "{}_REQUEST",
+                            super::super::camel_case_to_upper_snake(name),
+                        ));
+                    } else if normal_field.name == "length" {
+                        // the actual length will be calculated later
+                        fixed_fields_bytes.push(String::from("0"));
+                        fixed_fields_bytes.push(String::from("0"));
+                    } else {
+                        let rust_field_name = to_rust_variable_name(&normal_field.name);
+                        let was_deduced = if let Some(deducible_field) =
+                            deducible_fields.get(&normal_field.name)
+                        {
+                            generator.emit_calc_deducible_field(
+                                field,
+                                deducible_field,
+                                wrap_field_ref,
+                                &rust_field_name,
+                                out,
+                            );
+                            true
+                        } else {
+                            false
+                        };
+
+                        if let Some(field_size) = normal_field.type_.size() {
+                            let bytes_name = super::postfix_var_name(&rust_field_name, "bytes");
+                            let field_name = if was_deduced {
+                                rust_field_name
+                            } else {
+                                format!("self.{}", rust_field_name)
+                            };
+                            outln!(
+                                out,
+                                "let {} = {};",
+                                bytes_name,
+                                serialize::emit_value_serialize(
+                                    generator,
+                                    &normal_field.type_,
+                                    &field_name,
+                                    was_deduced,
+                                ),
+                            );
+                            for i in 0..field_size {
+                                fixed_fields_bytes.push(format!("{}[{}]", bytes_name, i));
+                            }
+                        } else {
+                            let mut part = Output::new();
+                            outln!(part, "self.{}.serialize_into(buffer);", rust_field_name);
+                            var_part = Some(part);
+                        }
+                    }
+                }
+                xcbdefs::FieldDef::List(list_field) => {
+                    let rust_field_name = to_rust_variable_name(&list_field.name);
+                    let mut part = Output::new();
+                    if generator.rust_value_type_is_u8(&list_field.element_type) {
+                        outln!(part, "buffer.extend_from_slice(&self.{}[..]);", rust_field_name);
+                    } else if parse::can_use_simple_list_parsing(
+                        generator,
+                        &list_field.element_type,
+                    ) {
+                        outln!(part, "self.{}.serialize_into(buffer);", rust_field_name);
+                    } else {
+                        outln!(part, "for element in self.{}.iter() {{", rust_field_name);
+                        part.indented(|part| {
+                            serialize::emit_value_serialize_into(
+                                generator,
+                                &list_field.element_type,
+                                "element",
+                                false,
+                                "buffer",
+                                part,
+                            );
+                        });
+                        outln!(part, "}}");
+                    }
+                    var_part = Some(part);
+                }
+                xcbdefs::FieldDef::Switch(switch_field) => {
+                    let rust_field_name = to_rust_variable_name(&switch_field.name);
+                    let ext_args = generator.ext_params_to_call_args(
+                        false,
+                        |name| {
+                            if deducible_fields.get(name).is_some() {
+                                to_rust_variable_name(name)
+                            } else {
+                                format!("self.{}", to_rust_variable_name(name))
+                            }
+                        },
+                        &switch_field.external_params.borrow(),
+                    );
+                    if let Some(field_size) = switch_field.size() {
+                        let bytes_name = super::postfix_var_name(&rust_field_name, "bytes");
+                        outln!(
+                            out,
+                            "let {} = self.{}.serialize({});",
+                            bytes_name,
+                            rust_field_name,
+                            ext_args,
+                        );
+                        for i in 0..field_size {
+                            fixed_fields_bytes.push(format!("{}[{}]", bytes_name, i));
+                        }
+                    } else {
+                        let ext_args = if ext_args.is_empty() {
+                            ext_args
+                        } else {
+                            format!(", {}", ext_args)
+                        };
+                        let mut part = Output::new();
+                        outln!(
+                            part,
+                            "self.{}.serialize_into(buffer{});",
+                            rust_field_name,
+                            ext_args,
+                        );
+                        var_part = Some(part);
+                    }
+                }
+                xcbdefs::FieldDef::Fd(_) => {}
+                xcbdefs::FieldDef::FdList(_) => {}
+                xcbdefs::FieldDef::Expr(expr_field) => {
+                    let rust_field_name = to_rust_variable_name(&expr_field.name);
+                    let bytes_name = super::postfix_var_name(&rust_field_name, "bytes");
+                    outln!(
+                        out,
+                        "let {} = {} != 0;",
+                        rust_field_name,
+                        expr_to_str(
+                            generator,
+                            &expr_field.expr,
+                            to_rust_variable_name,
+                            true,
+                            Some("u32"),
+                            true,
+                        ),
+                    );
+                    outln!(
+                        out,
+                        "let {} = {};",
+                        bytes_name,
+                        serialize::emit_value_serialize(
+                            generator,
+                            &expr_field.type_,
+                            &rust_field_name,
+                            false,
+                        ),
+                    );
+                    for i in 0..expr_field.type_.size().unwrap() {
+                        fixed_fields_bytes.push(format!("{}[{}]", bytes_name, i));
+                    }
+                }
+                xcbdefs::FieldDef::VirtualLen(_) => {}
+            }
+
+            // The XML does not describe trailing padding in requests. Requests
+            // are implicitly padded to a four byte boundary.
+            if var_part.is_none() && field_i == (fields.len() - 1) {
+                if let Some(ref mut request_size) = request_size {
+                    let req_size_rem = *request_size % 4;
+                    if req_size_rem != 0 {
+                        let pad_size = 4 - req_size_rem;
+                        for _ in 0..pad_size {
+                            fixed_fields_bytes.push(String::from("0"));
+                        }
+                        *request_size += pad_size;
+                    }
+                }
+            }
+
+            if var_part.is_some() || field_i == (fields.len() - 1) {
+                flush_fixed_fields(&mut fixed_fields_bytes, out);
+            }
+            out!(out, "{}", asserts.into_data());
+            if let Some(part) = var_part {
+                out!(out, "{}", part.into_data());
+            }
+        }
+        flush_fixed_fields(&mut fixed_fields_bytes, out);
+
+        // The XML does not describe trailing padding in requests. Requests
+        // are implicitly padded to a four byte boundary.
+        if let Some(request_size) = request_size {
+            let req_size_rem = request_size % 4;
+            if req_size_rem != 0 {
+                outln!(
+                    out,
+                    "buffer.extend_from_slice(&[0; {}]);",
+                    4 - req_size_rem
+                );
+            }
+        } else {
+            outln!(out, "let length_so_far = buffer.len() - request_start;");
+            outln!(
+                out,
+                "buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);",
+            );
+        }
+
+        outln!(out, "let length_so_far = buffer.len() - request_start;");
+        outln!(out, "assert_eq!(length_so_far % 4, 0);");
+        // Set the length in the request.
+        // If it does not fit into u16, compute_length_field will use BigRequests.
+        outln!(
+            out,
+            "let length = u16::try_from(length_so_far / 4).unwrap_or(0);",
+        );
+        outln!(
+            out,
+            "buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());",
+        );
+        let fds_arg = emit_request_fds(&fields, gathered, out);
+        outln!(out, "{}", fds_arg);
+    });
+    outln!(out, "}}");
+}
+
 fn emit_request_function(
     generator: &NamespaceGenerator<'_, '_>,
     request_def: &xcbdefs::RequestDef,
@@ -290,18 +290,8 @@ pub(super) fn emit_field_serialize_into(
         xcbdefs::FieldDef::Fd(..) | xcbdefs::FieldDef::FdList(..) => {
             // fds are handled elsewhere
         }
-        xcbdefs::FieldDef::VirtualLen(field) => {
-            // convert list length to bytes
-            let rfield_name = to_rust_variable_name(&field.name);
-            let rbytes_name = postfix_var_name(&rfield_name, "bytes");
-            outln!(
-                out,
-                "let {} = u32::try_from(self.{}.len()).unwrap();",
-                rbytes_name,
-                field.list_name
-            );
-            outln!(out, "let {0} = {0}.to_ne_bytes();", rbytes_name);
-            outln!(out, "{}.extend_from_slice(&{});", bytes_name, rbytes_name);
+        xcbdefs::FieldDef::VirtualLen(_) => {
+            // the length of the list is implied by the remaining data and is not sent
         }
         field => unreachable!("Unknown field: {:?}", field),
     }
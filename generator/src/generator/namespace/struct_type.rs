@@ -60,11 +60,20 @@ pub(super) fn emit_struct_type(
             extras.join(", ")
         );
     }
+    // Values that can be parsed get an `Arbitrary` implementation based on parsing, so that
+    // they satisfy the invariants of the wire format, e.g. length fields match their lists.
+    let arbitrary_from_wire = generate_try_parse && external_params.is_empty() && !has_fds;
     if !has_fds {
         outln!(
             out,
             r#"#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]"#
         );
+        if !arbitrary_from_wire {
+            outln!(
+                out,
+                r#"#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]"#
+            );
+        }
     }
     outln!(out, "pub struct {} {{", name);
     for field in fields.iter() {
@@ -72,12 +81,11 @@ pub(super) fn emit_struct_type(
             let field_name = field.name().unwrap();
             if !skip_length_field || field_name != "length" {
                 let field_type = generator.field_to_rust_type(field, switch_prefix);
-                outln!(
-                    out.indent(),
-                    "pub {}: {},",
-                    to_rust_variable_name(field_name),
-                    field_type
-                );
+                let rust_field_name = to_rust_variable_name(field_name);
+                out.indented(|out| {
+                    generator.emit_field_doc(doc, &rust_field_name, out);
+                    outln!(out, "pub {}: {},", rust_field_name, field_type);
+                });
             }
         }
     }
@@ -85,6 +93,34 @@ pub(super) fn emit_struct_type(
 
     super::helpers::default_debug_impl(name, out);
 
+    let can_be_unencodable =
+        generate_try_parse && !has_fds && generator.fields_can_be_unencodable(fields);
+    if arbitrary_from_wire {
+        emit_arbitrary_from_wire(name, &parse_size_constraint, can_be_unencodable, out);
+    }
+    if can_be_unencodable {
+        let checks = fields
+            .iter()
+            .filter(|field| generator.field_is_visible(field, &deducible_fields))
+            .filter_map(|field| {
+                let value = format!("self.{}", to_rust_variable_name(field.name().unwrap()));
+                generator.field_encodable_check(field, &value)
+            })
+            .collect::<Vec<_>>();
+        outln!(out, "#[cfg(feature = \"arbitrary\")]");
+        outln!(out, "impl {} {{", name);
+        out.indented(|out| {
+            outln!(
+                out,
+                "/// Whether this value can be serialized, i.e. does not contain an `InvalidValue`."
+            );
+            outln!(out, "pub(crate) fn is_encodable(&self) -> bool {{");
+            outln!(out.indent(), "{}", checks.join(" && "));
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+    }
+
     if generate_try_parse {
         let input_name = if !matches!(parse_size_constraint, StructSizeConstraint::None) {
             "initial_value"
@@ -233,6 +269,10 @@ pub(super) fn emit_struct_type(
                 external_params,
                 &deducible_fields,
                 skip_length_field,
+                matches!(
+                    parse_size_constraint,
+                    StructSizeConstraint::EmbeddedLength { .. }
+                ),
                 out,
             );
         }
@@ -344,6 +384,59 @@ fn generate_switches_for_fields(
     }
 }
 
+/// Emits an `Arbitrary` implementation that parses the unstructured data.
+///
+/// Values that cannot be serialized into the bytes they were parsed from are rejected.
+fn emit_arbitrary_from_wire(
+    name: &str,
+    parse_size_constraint: &StructSizeConstraint<'_>,
+    can_be_unencodable: bool,
+    out: &mut Output,
+) {
+    // Events and replies are padded to this size on the wire
+    let minimum_size = match parse_size_constraint {
+        StructSizeConstraint::Fixed(size) => *size,
+        StructSizeConstraint::EmbeddedLength { minimum } => *minimum,
+        StructSizeConstraint::None | StructSizeConstraint::LengthExpr(_) => 0,
+    };
+    outln!(out, "#[cfg(feature = \"arbitrary\")]");
+    outln!(out, "impl<'a> arbitrary::Arbitrary<'a> for {} {{", name);
+    out.indented(|out| {
+        outln!(
+            out,
+            "fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {{",
+        );
+        out.indented(|out| {
+            if can_be_unencodable {
+                outln!(
+                    out,
+                    "let (value, size): (Self, usize) = crate::x11_utils::arbitrary_parse(u)?;",
+                );
+                outln!(out, "if !value.is_encodable() {{");
+                outln!(
+                    out.indent(),
+                    "return Err(arbitrary::Error::IncorrectFormat);"
+                );
+                outln!(out, "}}");
+                outln!(
+                    out,
+                    "crate::x11_utils::arbitrary_check_size(&value, size, {})?;",
+                    minimum_size,
+                );
+                outln!(out, "Ok(value)");
+            } else {
+                outln!(
+                    out,
+                    "crate::x11_utils::arbitrary_from_wire(u, {})",
+                    minimum_size,
+                );
+            }
+        });
+        outln!(out, "}}");
+    });
+    outln!(out, "}}");
+}
+
 fn emit_fixed_size_struct_serialize(
     generator: &NamespaceGenerator<'_, '_>,
     name: &str,
@@ -443,6 +536,7 @@ fn emit_variable_size_struct_serialize(
     external_params: &[xcbdefs::ExternalParam],
     deducible_fields: &HashMap<String, DeducibleField>,
     skip_length_field: bool,
+    pad_to_length_unit: bool,
     out: &mut Output,
 ) {
     let ext_params_arg_defs = generator.ext_params_to_arg_defs(true, external_params);
@@ -522,6 +616,13 @@ fn emit_variable_size_struct_serialize(
                     out,
                 );
             }
+            if pad_to_length_unit {
+                // The length field counts units of four bytes
+                outln!(
+                    out,
+                    "bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);"
+                );
+            }
         });
         outln!(out, "}}");
     });
@@ -142,6 +142,10 @@ pub(super) fn emit_switch_type(
         out,
         r#"#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]"#
     );
+    outln!(
+        out,
+        r#"#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]"#
+    );
 
     if switch.kind == xcbdefs::SwitchKind::BitCase {
         outln!(out, "pub struct {} {{", name);
@@ -374,6 +378,88 @@ pub(super) fn emit_switch_type(
     case_infos
 }
 
+fn emit_switch_is_encodable(
+    generator: &NamespaceGenerator<'_, '_>,
+    switch: &xcbdefs::SwitchField,
+    name: &str,
+    case_infos: &[CaseInfo],
+    out: &mut Output,
+) {
+    let case_checks = switch
+        .cases
+        .iter()
+        .zip(case_infos.iter())
+        .map(|(case, case_info)| {
+            let case_fields = case.fields.borrow();
+            match case_info {
+                CaseInfo::SingleField(index) => {
+                    let field = &case_fields[*index];
+                    (
+                        field.name().unwrap().to_string(),
+                        generator.field_encodable_check(field, "value"),
+                    )
+                }
+                CaseInfo::MultiField(field_name, _) => (
+                    field_name.clone(),
+                    generator
+                        .fields_can_be_unencodable(&case_fields)
+                        .then(|| "value.is_encodable()".to_string()),
+                ),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    outln!(out, "#[cfg(feature = \"arbitrary\")]");
+    outln!(out, "impl {} {{", name);
+    out.indented(|out| {
+        outln!(
+            out,
+            "/// Whether this value can be serialized, i.e. does not contain an `InvalidValue`."
+        );
+        outln!(out, "pub(crate) fn is_encodable(&self) -> bool {{");
+        out.indented(|out| {
+            if switch.kind == xcbdefs::SwitchKind::BitCase {
+                let checks = case_checks
+                    .iter()
+                    .filter_map(|(field_name, check)| {
+                        check.as_ref().map(|check| {
+                            format!(
+                                "self.{}.as_ref().map_or(true, |value| {})",
+                                to_rust_variable_name(field_name),
+                                check,
+                            )
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                outln!(out, "{}", checks.join(" && "));
+            } else {
+                outln!(out, "match self {{");
+                for (field_name, check) in case_checks.iter() {
+                    match check {
+                        Some(check) => outln!(
+                            out.indent(),
+                            "{}::{}(value) => {},",
+                            name,
+                            to_rust_type_name(field_name),
+                            check,
+                        ),
+                        None => outln!(
+                            out.indent(),
+                            "{}::{}(_) => true,",
+                            name,
+                            to_rust_type_name(field_name),
+                        ),
+                    }
+                }
+                outln!(out.indent(), "{}::InvalidValue(_) => false,", name);
+                outln!(out, "}}");
+            }
+        });
+        outln!(out, "}}");
+    });
+    outln!(out, "}}");
+}
+
 fn emit_switch_try_parse(
     generator: &NamespaceGenerator<'_, '_>,
     switch: &xcbdefs::SwitchField,
@@ -1008,7 +1094,7 @@ pub(super) fn generate_switch(
     out: &mut Output,
 ) {
     let switch_name = format!("{}{}", name_prefix, to_rust_type_name(&switch.name));
-    emit_switch_type(
+    let case_infos = emit_switch_type(
         generator,
         switch,
         &switch_name,
@@ -1017,6 +1103,10 @@ pub(super) fn generate_switch(
         None,
         out,
     );
+    // Needed by the `Arbitrary` implementation of the containing struct
+    if generate_try_parse && generator.switch_can_be_unencodable(switch) {
+        emit_switch_is_encodable(generator, switch, &switch_name, &case_infos, out);
+    }
     outln!(out, "");
 }
 
@@ -18,6 +18,10 @@ pub(super) struct PerModuleEnumCases {
     pub(super) reply_variants: Vec<String>,
     /// Impls for From<ReplyType> for Reply enum.
     pub(super) reply_from_cases: Vec<String>,
+    /// Lines that belong in the definition of reply_parser_by_opcode.
+    pub(super) reply_parser_by_opcode_cases: Vec<String>,
+    /// Entries of the REQUESTS table of the module.
+    pub(super) request_descriptions: Vec<String>,
 }
 
 pub(super) type EnumCases = HashMap<String, PerModuleEnumCases>;
@@ -393,6 +397,9 @@ pub(super) fn generate(out: &mut Output, module: &xcbdefs::Module, mut enum_case
     }
     outln!(out, "");
 
+    generate_reply_parser_by_opcode(out, module, &mut enum_cases);
+    generate_request_descriptions(out, module);
+
     outln!(
         out,
         "/// Get the name of a request from its extension name and opcodes.",
@@ -431,4 +438,172 @@ pub(super) fn generate(out: &mut Output, module: &xcbdefs::Module, mut enum_case
     });
     outln!(out, "}}");
     outln!(out, "");
+
+    generate_request_name_by_opcode(out);
+}
+
+/// Generate functions that look up and run the reply parser for a request by its opcode.
+fn generate_reply_parser_by_opcode(
+    out: &mut Output,
+    module: &xcbdefs::Module,
+    enum_cases: &mut EnumCases,
+) {
+    outln!(
+        out,
+        "/// Get the reply parser for a request from the name of its extension and its opcode."
+    );
+    outln!(out, "///");
+    outln!(
+        out,
+        "/// `extension` and `opcode` are interpreted as in [`request_name_by_opcode`]."
+    );
+    outln!(
+        out,
+        "/// Returns `None` if the request is not known or does not have a reply."
+    );
+    outln!(out, "pub fn reply_parser_by_opcode(extension: Option<&str>, opcode: u8) -> Option<ReplyParsingFunction> {{");
+    out.indented(|out| {
+        outln!(out, "match extension {{");
+        out.indented(|out| {
+            for ns in module.sorted_namespaces() {
+                let cases = enum_cases
+                    .get_mut(&ns.header)
+                    .unwrap()
+                    .reply_parser_by_opcode_cases
+                    .drain(..)
+                    .collect::<Vec<_>>();
+                if cases.is_empty() {
+                    continue;
+                }
+                if ns.ext_info.is_some() {
+                    if super::ext_has_feature(&ns.header) {
+                        outln!(out, "#[cfg(feature = \"{}\")]", ns.header);
+                    }
+                    outln!(out, "Some({}::X11_EXTENSION_NAME) => {{", ns.header);
+                } else {
+                    outln!(out, "None => {{");
+                }
+                out.indented(|out| {
+                    outln!(out, "match opcode {{");
+                    for case in cases {
+                        outln!(out.indent(), "{}", case);
+                    }
+                    outln!(out.indent(), "_ => None,");
+                    outln!(out, "}}");
+                });
+                outln!(out, "}}");
+            }
+            outln!(out, "_ => None,");
+        });
+        outln!(out, "}}");
+    });
+    outln!(out, "}}");
+    outln!(out, "");
+
+    outln!(
+        out,
+        "/// Parse a reply to a request given by the name of its extension and its opcode."
+    );
+    outln!(out, "///");
+    outln!(
+        out,
+        "/// `extension` and `opcode` are interpreted as in [`request_name_by_opcode`]."
+    );
+    outln!(
+        out,
+        "/// Returns `Ok(None)` if the request is not known or does not have a reply."
+    );
+    outln!(out, "pub fn parse_reply_by_opcode(");
+    out.indented(|out| {
+        outln!(out, "extension: Option<&str>,");
+        outln!(out, "opcode: u8,");
+        outln!(out, "bytes: &[u8],");
+        outln!(out, "fds: &mut Vec<RawFdContainer>,");
+    });
+    outln!(out, ") -> Result<Option<Reply>, ParseError> {{");
+    out.indented(|out| {
+        outln!(out, "match reply_parser_by_opcode(extension, opcode) {{");
+        outln!(
+            out.indent(),
+            "Some(parser) => parser(bytes, fds).map(|(reply, _)| Some(reply)),"
+        );
+        outln!(out.indent(), "None => Ok(None),");
+        outln!(out, "}}");
+    });
+    outln!(out, "}}");
+    outln!(out, "");
+}
+
+/// Generate functions that look up the REQUESTS tables of the modules.
+fn generate_request_descriptions(out: &mut Output, module: &xcbdefs::Module) {
+    outln!(out, "/// Get the table of all requests of an extension.");
+    outln!(out, "///");
+    outln!(
+        out,
+        "/// `extension` is `None` for core requests and the name of the extension as used in"
+    );
+    outln!(
+        out,
+        "/// `QueryExtension` otherwise. Returns `None` if the extension is not known."
+    );
+    outln!(out, "pub fn requests_of_extension(extension: Option<&str>) -> Option<&'static [RequestDescription]> {{");
+    out.indented(|out| {
+        outln!(out, "match extension {{");
+        out.indented(|out| {
+            for ns in module.sorted_namespaces() {
+                if ns.ext_info.is_some() {
+                    if super::ext_has_feature(&ns.header) {
+                        outln!(out, "#[cfg(feature = \"{}\")]", ns.header);
+                    }
+                    outln!(
+                        out,
+                        "Some({header}::X11_EXTENSION_NAME) => Some({header}::REQUESTS),",
+                        header = ns.header,
+                    );
+                } else {
+                    outln!(out, "None => Some({}::REQUESTS),", ns.header);
+                }
+            }
+            outln!(out, "_ => None,");
+        });
+        outln!(out, "}}");
+    });
+    outln!(out, "}}");
+    outln!(out, "");
+
+    outln!(
+        out,
+        "/// Describe a request given by the name of its extension and its opcode."
+    );
+    outln!(out, "///");
+    outln!(
+        out,
+        "/// `extension` and `opcode` are interpreted as in [`request_name_by_opcode`]."
+    );
+    outln!(out, "/// Returns `None` if the request is not known.");
+    outln!(out, "pub fn request_description(extension: Option<&str>, opcode: u8) -> Option<&'static RequestDescription> {{");
+    outln!(
+        out.indent(),
+        "requests_of_extension(extension)?.iter().find(|request| request.opcode == opcode)"
+    );
+    outln!(out, "}}");
+    outln!(out, "");
+}
+
+/// Generate a function that looks up the name of a request by the name of its extension.
+fn generate_request_name_by_opcode(out: &mut Output) {
+    outln!(
+        out,
+        r#"/// Get the name of a request from the name of its extension and its opcode.
+///
+/// For core requests, `extension` is `None` and `opcode` is the major opcode of the request.
+/// Otherwise, `extension` is the name of the extension as used in `QueryExtension`, e.g.
+/// `"RENDER"`, and `opcode` is the minor opcode of the request.
+///
+/// Returns `None` if the request is not known.
+pub fn request_name_by_opcode(extension: Option<&str>, opcode: u8) -> Option<&'static str> {{
+    request_description(extension, opcode).map(|request| request.name)
+}}
+"#
+    );
 }
@@ -140,6 +140,18 @@ impl<C: BlConnection + Send + Sync + 'static> RequestConnection for BlockingConn
         Box::pin(self.with_conn(move |conn| conn.prefetch_extension_information(name)))
     }
 
+    fn prefetch_all_extensions<'this, 'names, 'future>(
+        &'this self,
+        names: &'names [&'static str],
+    ) -> Fut<'future, (), ConnectionError>
+    where
+        'this: 'future,
+        'names: 'future,
+    {
+        let names = names.to_vec();
+        Box::pin(self.with_conn(move |conn| conn.prefetch_all_extensions(&names)))
+    }
+
     fn maximum_request_bytes(&self) -> Pin<Box<dyn Future<Output = usize> + Send + '_>> {
         Box::pin(self.with_conn(|conn| conn.maximum_request_bytes()))
     }
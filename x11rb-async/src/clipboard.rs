@@ -0,0 +1,425 @@
+//! Owning and reading selections, e.g. the clipboard.
+//!
+//! This is the asynchronous counterpart of [`x11rb::clipboard::Clipboard`]. It shares the
+//! protocol logic with it, including `TARGETS`, `MULTIPLE` and `INCR` transfers.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use async_io::Timer;
+use futures_lite::future;
+
+use crate::connection::{Connection, RequestConnection};
+use crate::errors::{ConnectionError, ParseError, ReplyError, ReplyOrIdError};
+use crate::protocol::xproto::{
+    Atom, AtomEnum, ChangeWindowAttributesAux, ConnectionExt as _, CreateWindowAux, EventMask,
+    PropMode, Timestamp, Window, WindowClass,
+};
+use crate::protocol::Event;
+
+pub use x11rb::clipboard::{
+    chunk_size, Action, ClipboardAtoms, IncomingTransfer, SelectionContents, SelectionData,
+    SelectionOwner, TransferStep,
+};
+
+/// How long [`Clipboard`] waits for the owner of a selection by default.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Intern the atoms of the selection protocol.
+async fn intern_atoms<C: RequestConnection>(conn: &C) -> Result<ClipboardAtoms, ReplyError> {
+    let clipboard = conn.intern_atom(false, b"CLIPBOARD").await?;
+    let targets = conn.intern_atom(false, b"TARGETS").await?;
+    let multiple = conn.intern_atom(false, b"MULTIPLE").await?;
+    let timestamp = conn.intern_atom(false, b"TIMESTAMP").await?;
+    let incr = conn.intern_atom(false, b"INCR").await?;
+    let atom_pair = conn.intern_atom(false, b"ATOM_PAIR").await?;
+    let utf8_string = conn.intern_atom(false, b"UTF8_STRING").await?;
+    let clipboard_manager = conn.intern_atom(false, b"CLIPBOARD_MANAGER").await?;
+    let save_targets = conn.intern_atom(false, b"SAVE_TARGETS").await?;
+    let manager = conn.intern_atom(false, b"MANAGER").await?;
+    let selection = conn.intern_atom(false, b"X11RB_SELECTION").await?;
+    Ok(ClipboardAtoms {
+        CLIPBOARD: clipboard.reply().await?.atom,
+        TARGETS: targets.reply().await?.atom,
+        MULTIPLE: multiple.reply().await?.atom,
+        TIMESTAMP: timestamp.reply().await?.atom,
+        INCR: incr.reply().await?.atom,
+        ATOM_PAIR: atom_pair.reply().await?.atom,
+        UTF8_STRING: utf8_string.reply().await?.atom,
+        CLIPBOARD_MANAGER: clipboard_manager.reply().await?.atom,
+        SAVE_TARGETS: save_targets.reply().await?.atom,
+        MANAGER: manager.reply().await?.atom,
+        X11RB_SELECTION: selection.reply().await?.atom,
+    })
+}
+
+/// Send the request for an action of the selection protocol.
+async fn execute<C: RequestConnection>(conn: &C, action: Action) -> Result<(), ConnectionError> {
+    match action {
+        Action::ChangeProperty {
+            window,
+            property,
+            data,
+        } => {
+            let _ = conn
+                .change_property(
+                    PropMode::REPLACE,
+                    window,
+                    property,
+                    data.type_,
+                    data.format,
+                    data.value_len(),
+                    &data.data,
+                )
+                .await?;
+        }
+        Action::SelectPropertyChange { window, select } => {
+            let mask = if select {
+                EventMask::PROPERTY_CHANGE
+            } else {
+                EventMask::NO_EVENT
+            };
+            let aux = ChangeWindowAttributesAux::new().event_mask(mask);
+            let _ = conn.change_window_attributes(window, &aux).await?;
+        }
+        Action::SendSelectionNotify(event) => {
+            let requestor = event.requestor;
+            let _ = conn
+                .send_event(false, requestor, EventMask::NO_EVENT, event)
+                .await?;
+        }
+    }
+    Ok(())
+}
+
+/// Owns and reads selections through a window.
+///
+/// Events that arrive while [`Clipboard::get`] waits for the data of a selection and that do not
+/// belong to the selection protocol are set aside, see [`Clipboard::take_deferred_events`].
+/// Since the window cannot be destroyed asynchronously on drop, call [`Clipboard::destroy`] to
+/// release the owned selections.
+#[derive(Debug)]
+pub struct Clipboard<'c, C: Connection> {
+    conn: &'c C,
+    window: Window,
+    atoms: ClipboardAtoms,
+    owner: SelectionOwner,
+    timeout: Duration,
+    /// When [`SelectionOwner::expire_transfers`] has to be called next.
+    expire_transfers_at: Instant,
+    deferred: VecDeque<Event>,
+}
+
+impl<'c, C: Connection> Clipboard<'c, C> {
+    /// Create a window for the selections of a screen.
+    ///
+    /// This function fails with [`ParseError::InvalidValue`] if `screen` does not exist.
+    pub async fn new(conn: &'c C, screen: usize) -> Result<Self, ReplyOrIdError> {
+        let root = conn
+            .setup()
+            .roots
+            .get(screen)
+            .ok_or(ParseError::InvalidValue)?
+            .root;
+        let atoms = intern_atoms(conn).await?;
+        let window = conn.generate_id().await?;
+        let aux = CreateWindowAux::new().event_mask(EventMask::PROPERTY_CHANGE);
+        let _ = conn
+            .create_window(
+                x11rb::COPY_DEPTH_FROM_PARENT,
+                window,
+                root,
+                -1,
+                -1,
+                1,
+                1,
+                0,
+                WindowClass::INPUT_ONLY,
+                0,
+                &aux,
+            )
+            .await?;
+        let chunk_size = chunk_size(conn.maximum_request_bytes().await);
+        Ok(Self {
+            conn,
+            window,
+            atoms,
+            owner: SelectionOwner::new(window, atoms, chunk_size),
+            timeout: DEFAULT_TIMEOUT,
+            expire_transfers_at: Instant::now() + DEFAULT_TIMEOUT,
+            deferred: VecDeque::new(),
+        })
+    }
+
+    /// Destroy the window, which releases the owned selections.
+    pub async fn destroy(self) -> Result<(), ConnectionError> {
+        let _ = self.conn.destroy_window(self.window).await?;
+        Ok(())
+    }
+
+    /// Get the window that owns and reads selections.
+    pub fn window(&self) -> Window {
+        self.window
+    }
+
+    /// Get the atoms of the selection protocol.
+    pub fn atoms(&self) -> &ClipboardAtoms {
+        &self.atoms
+    }
+
+    /// Set how long [`Clipboard::get`] waits for the owner of a selection.
+    ///
+    /// The default is five seconds.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Take the events that were set aside while waiting for the data of a selection.
+    pub fn take_deferred_events(&mut self) -> VecDeque<Event> {
+        std::mem::take(&mut self.deferred)
+    }
+
+    /// Check whether this owns a selection.
+    pub fn is_owner(&self, selection: Atom) -> bool {
+        self.owner.contents(selection).is_some()
+    }
+
+    /// Take ownership of a selection, e.g. `CLIPBOARD` or `PRIMARY`.
+    ///
+    /// `time` must be the server time of the user action that caused this. Returns `false` if
+    /// the selection could not be acquired.
+    pub async fn set(
+        &mut self,
+        selection: impl Into<Atom>,
+        time: Timestamp,
+        contents: SelectionContents,
+    ) -> Result<bool, ReplyError> {
+        let selection = selection.into();
+        let _ = self
+            .conn
+            .set_selection_owner(self.window, selection, time)
+            .await?;
+        let owner = self
+            .conn
+            .get_selection_owner(selection)
+            .await?
+            .reply()
+            .await?
+            .owner;
+        if owner != self.window {
+            return Ok(false);
+        }
+        self.owner.set(selection, time, contents);
+        Ok(true)
+    }
+
+    /// Take ownership of a selection with a text.
+    pub async fn set_text(
+        &mut self,
+        selection: impl Into<Atom>,
+        time: Timestamp,
+        text: &str,
+    ) -> Result<bool, ReplyError> {
+        let contents = SelectionContents::text(&self.atoms, text);
+        self.set(selection, time, contents).await
+    }
+
+    /// Give up the ownership of a selection.
+    pub async fn clear(
+        &mut self,
+        selection: impl Into<Atom>,
+        time: Timestamp,
+    ) -> Result<(), ConnectionError> {
+        let selection = selection.into();
+        if self.owner.clear(selection).is_some() {
+            let _ = self
+                .conn
+                .set_selection_owner(x11rb::NONE, selection, time)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Handle an event of the selection protocol.
+    ///
+    /// Requests for owned selections are answered and `INCR` transfers are continued. Returns
+    /// `false` if the event does not belong to the selection protocol.
+    ///
+    /// `INCR` transfers that made no progress for the timeout are dropped, see
+    /// [`Clipboard::set_timeout`].
+    pub async fn handle_event(&mut self, event: &Event) -> Result<bool, ReplyError> {
+        let now = Instant::now();
+        if now >= self.expire_transfers_at {
+            self.expire_transfers_at = now + self.timeout;
+            for action in self.owner.expire_transfers() {
+                execute(self.conn, action).await?;
+            }
+        }
+        let actions = match event {
+            Event::SelectionRequest(event) if event.owner == self.window => {
+                let pairs = if event.target == self.atoms.MULTIPLE && event.property != x11rb::NONE
+                {
+                    let reply = self
+                        .conn
+                        .get_property(
+                            false,
+                            event.requestor,
+                            event.property,
+                            AtomEnum::ANY,
+                            0,
+                            u32::MAX,
+                        )
+                        .await?
+                        .reply()
+                        .await?;
+                    x11rb::clipboard::atom_pairs(&reply)
+                } else {
+                    None
+                };
+                self.owner.handle_request(event, pairs.as_deref())
+            }
+            // This includes our own window, which is the requestor when we read our own selection
+            Event::PropertyNotify(event) => self.owner.handle_property_notify(event),
+            Event::SelectionClear(event) => return Ok(self.owner.handle_selection_clear(event)),
+            _ => return Ok(false),
+        };
+        let handled = !actions.is_empty();
+        for action in actions {
+            execute(self.conn, action).await?;
+        }
+        Ok(handled)
+    }
+
+    /// Read a selection in the format of a target, e.g. `UTF8_STRING`.
+    ///
+    /// This waits until the owner of the selection sent the data. Returns `None` if the
+    /// selection has no owner, the owner refused to convert it to the target or did not answer
+    /// in time, see [`Clipboard::set_timeout`]. If this owns the selection, the data is returned
+    /// without asking the X11 server.
+    pub async fn get(
+        &mut self,
+        selection: impl Into<Atom>,
+        target: impl Into<Atom>,
+        time: Timestamp,
+    ) -> Result<Option<SelectionData>, ReplyError> {
+        let (selection, target) = (selection.into(), target.into());
+        if self.is_owner(selection) {
+            return Ok(self.owner.lookup(selection, target));
+        }
+        let property = self.atoms.X11RB_SELECTION;
+        let transfer = IncomingTransfer::new(self.window, selection, target, property);
+        let _ = self.conn.delete_property(self.window, property).await?;
+        self.receive(transfer, time).await
+    }
+
+    /// Ask the clipboard manager to save the contents of the `CLIPBOARD` selection.
+    ///
+    /// Applications should call this before they exit, so that copied data persists. This
+    /// waits until the clipboard manager has read the data, while answering its requests.
+    /// Returns `false` if the `CLIPBOARD` selection is not owned, there is no clipboard manager
+    /// or it failed to save the data.
+    pub async fn save(&mut self, time: Timestamp) -> Result<bool, ReplyError> {
+        let targets = match self.owner.save_targets(self.atoms.CLIPBOARD) {
+            Some(targets) => targets,
+            None => return Ok(false),
+        };
+        let manager = self
+            .conn
+            .get_selection_owner(self.atoms.CLIPBOARD_MANAGER)
+            .await?
+            .reply()
+            .await?
+            .owner;
+        if manager == x11rb::NONE {
+            return Ok(false);
+        }
+        let property = self.atoms.X11RB_SELECTION;
+        let transfer = IncomingTransfer::new(
+            self.window,
+            self.atoms.CLIPBOARD_MANAGER,
+            self.atoms.SAVE_TARGETS,
+            property,
+        );
+        let action = Action::ChangeProperty {
+            window: self.window,
+            property,
+            data: targets,
+        };
+        execute(self.conn, action).await?;
+        Ok(self.receive(transfer, time).await?.is_some())
+    }
+
+    /// Convert a selection and wait for the data.
+    async fn receive(
+        &mut self,
+        mut transfer: IncomingTransfer,
+        time: Timestamp,
+    ) -> Result<Option<SelectionData>, ReplyError> {
+        let property = transfer.property();
+        let _ = self
+            .conn
+            .convert_selection(
+                self.window,
+                transfer.selection(),
+                transfer.target(),
+                property,
+                time,
+            )
+            .await?;
+        self.conn.flush().await?;
+        let mut deadline = Instant::now() + self.timeout;
+        loop {
+            let event = future::or(async { Some(self.conn.wait_for_event().await) }, async {
+                let _ = Timer::at(deadline).await;
+                None
+            })
+            .await;
+            let event = match event {
+                Some(event) => event?,
+                None => return Ok(None),
+            };
+            match transfer.handle_event(&event) {
+                TransferStep::Refused => return Ok(None),
+                TransferStep::ReadProperty => {
+                    let reply = self
+                        .conn
+                        .get_property(true, self.window, property, AtomEnum::ANY, 0, u32::MAX)
+                        .await?
+                        .reply()
+                        .await?;
+                    if let Some(data) = transfer.handle_property(reply, &self.atoms) {
+                        return Ok(Some(data));
+                    }
+                    self.conn.flush().await?;
+                    // Each chunk of an INCR transfer may take as long as the whole transfer
+                    deadline = Instant::now() + self.timeout;
+                }
+                TransferStep::Ignore => {
+                    // The selection may be owned by ourselves
+                    if self.handle_event(&event).await? {
+                        self.conn.flush().await?;
+                    } else if !matches!(event, Event::PropertyNotify(ref e) if e.window == self.window)
+                    {
+                        self.deferred.push_back(event);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Read a selection as text.
+    ///
+    /// `UTF8_STRING` is tried first and Latin-1 `STRING` afterwards.
+    pub async fn get_text(
+        &mut self,
+        selection: impl Into<Atom>,
+        time: Timestamp,
+    ) -> Result<Option<String>, ReplyError> {
+        let selection = selection.into();
+        if let Some(data) = self.get(selection, self.atoms.UTF8_STRING, time).await? {
+            return Ok(Some(String::from_utf8_lossy(&data.data).into_owned()));
+        }
+        let data = self.get(selection, AtomEnum::STRING, time).await?;
+        Ok(data.map(|data| data.data.iter().map(|byte| char::from(*byte)).collect()))
+    }
+}
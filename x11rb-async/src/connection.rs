@@ -229,6 +229,28 @@ pub trait RequestConnection: Sync {
     /// ```
     fn prefetch_extension_information(&self, name: &'static str) -> Fut<'_, (), ConnectionError>;
 
+    /// Prefetch information about several extensions.
+    ///
+    /// This is the `async` analog of
+    /// [`x11rb::connection::RequestConnection::prefetch_all_extensions`]. All `QueryExtension`
+    /// requests are sent before waiting for any reply. The default implementation calls
+    /// [`RequestConnection::prefetch_extension_information`] for each extension.
+    fn prefetch_all_extensions<'this, 'names, 'future>(
+        &'this self,
+        names: &'names [&'static str],
+    ) -> Fut<'future, (), ConnectionError>
+    where
+        'this: 'future,
+        'names: 'future,
+    {
+        Box::pin(async move {
+            for name in names {
+                self.prefetch_extension_information(name).await?;
+            }
+            Ok(())
+        })
+    }
+
     /// Get information about an extension.
     ///
     /// This is the `async` analog of [`x11rb::connection::RequestConnection::extension_information`], and is
@@ -280,6 +302,30 @@ pub trait RequestConnection: Sync {
         sequence: SequenceNumber,
     ) -> Fut<'_, ReplyOrError<Self::Buf>, ConnectionError>;
 
+    /// Wait for the replies to several requests.
+    ///
+    /// The result contains the reply or error for each of the given sequence numbers, in the same
+    /// order. This is equivalent to calling
+    /// [`RequestConnection::wait_for_reply_or_raw_error`] for each sequence number, which is what
+    /// the default implementation does. Implementations can override this to wait for all the
+    /// replies at once.
+    fn wait_for_replies_or_raw_errors<'this, 'seq, 'future>(
+        &'this self,
+        sequences: &'seq [SequenceNumber],
+    ) -> Fut<'future, Vec<ReplyOrError<Self::Buf>>, ConnectionError>
+    where
+        'this: 'future,
+        'seq: 'future,
+    {
+        Box::pin(async move {
+            let mut result = Vec::with_capacity(sequences.len());
+            for &sequence in sequences {
+                result.push(self.wait_for_reply_or_raw_error(sequence).await?);
+            }
+            Ok(result)
+        })
+    }
+
     /// Wait for the reply to a request.
     ///
     /// This is the `async` analog of [`x11rb::connection::RequestConnection::wait_for_reply`], and is semantically
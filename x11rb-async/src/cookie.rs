@@ -6,9 +6,10 @@ use x11rb::connection::{BufWithFds, ReplyOrError, RequestKind};
 use x11rb_protocol::protocol::xproto::ListFontsWithInfoReply;
 use x11rb_protocol::{DiscardMode, SequenceNumber};
 
-use crate::connection::{Connection, RequestConnection};
+use crate::connection::RequestConnection;
 use crate::errors::{ConnectionError, ReplyError};
-use crate::x11_utils::{TryParse, TryParseFd};
+use crate::x11_utils::{BorrowedReply, TryParse, TryParseFd, X11Error};
+use crate::ReplyView;
 
 use futures_lite::{ready, stream::Stream};
 use std::future::Future;
@@ -27,7 +28,7 @@ pub struct VoidCookie<'conn, C: RequestConnection + ?Sized> {
     sequence: SequenceNumber,
 }
 
-impl<'conn, C: Connection + ?Sized> VoidCookie<'conn, C> {
+impl<'conn, C: RequestConnection + ?Sized> VoidCookie<'conn, C> {
     /// Create a new cookie from its raw parts.
     pub fn new(conn: &'conn C, sequence: SequenceNumber) -> Self {
         Self { conn, sequence }
@@ -111,7 +112,7 @@ pub struct Cookie<'conn, C: RequestConnection + ?Sized, R> {
     capture: PhantomData<R>,
 }
 
-impl<'conn, C: Connection + ?Sized, R: TryParse> Cookie<'conn, C, R> {
+impl<'conn, C: RequestConnection + ?Sized, R: TryParse> Cookie<'conn, C, R> {
     /// Create a new cookie from its raw parts.
     pub fn new(conn: &'conn C, sequence: SequenceNumber) -> Self {
         Self {
@@ -125,6 +126,11 @@ impl<'conn, C: Connection + ?Sized, R: TryParse> Cookie<'conn, C, R> {
         self.raw.sequence
     }
 
+    /// Consume this cookie without discarding the reply and get the sequence number out.
+    pub(crate) fn into_sequence_number(self) -> SequenceNumber {
+        self.raw.consume().1
+    }
+
     /// Get the raw reply that the server sent.
     pub async fn raw_reply(self) -> Result<C::Buf, ReplyError> {
         // Wait for the reply
@@ -175,6 +181,67 @@ impl<'conn, C: Connection + ?Sized, R: TryParse> Cookie<'conn, C, R> {
     }
 }
 
+impl<'conn, C, R> Cookie<'conn, C, R>
+where
+    C: RequestConnection + ?Sized,
+    R: TryParse + for<'a> BorrowedReply<'a>,
+{
+    /// Get the reply that the server sent without copying its data.
+    ///
+    /// See [`ReplyView`].
+    pub async fn reply_ref(self) -> Result<ReplyView<C::Buf, R>, ReplyError> {
+        Ok(ReplyView::new(self.raw_reply().await?)?)
+    }
+}
+
+/// Get the replies to several requests at once.
+///
+/// This is equivalent to calling [`Cookie::reply`] on each cookie, but the connection is only
+/// flushed once and all replies are collected in a single pass. This is much faster when many
+/// requests are in flight, e.g. when fetching a property of every window.
+///
+/// The result contains the reply or the X11 error for each cookie, in the same order as the
+/// cookies. All cookies must belong to the same connection.
+pub async fn join_cookies<'conn, C, R>(
+    cookies: Vec<Cookie<'conn, C, R>>,
+) -> Result<Vec<Result<R, X11Error>>, ConnectionError>
+where
+    C: RequestConnection + ?Sized,
+    R: TryParse,
+{
+    let conn = match cookies.first() {
+        Some(cookie) => cookie.raw.conn,
+        None => return Ok(Vec::new()),
+    };
+    assert!(
+        cookies
+            .iter()
+            .all(|cookie| std::ptr::eq(cookie.raw.conn, conn)),
+        "All cookies must belong to the same connection"
+    );
+
+    // Wait for the replies
+    let sequences = cookies
+        .iter()
+        .map(|cookie| cookie.raw.sequence)
+        .collect::<Vec<_>>();
+    let replies = conn.wait_for_replies_or_raw_errors(&sequences).await?;
+
+    // The replies were received, so the cookies can be consumed.
+    for cookie in cookies {
+        let _ = cookie.raw.consume();
+    }
+
+    // Parse the replies and errors
+    replies
+        .into_iter()
+        .map(|reply| match reply {
+            ReplyOrError::Reply(reply) => Ok(Ok(R::try_parse(reply.as_ref())?.0)),
+            ReplyOrError::Error(error) => Ok(Err(conn.parse_error(error.as_ref())?)),
+        })
+        .collect()
+}
+
 /// A cookie for a request that has a reply containing file descriptors.
 #[derive(Debug)]
 pub struct CookieWithFds<'conn, C: RequestConnection + ?Sized, R> {
@@ -182,7 +249,7 @@ pub struct CookieWithFds<'conn, C: RequestConnection + ?Sized, R> {
     capture: PhantomData<R>,
 }
 
-impl<'conn, C: Connection + ?Sized, R: TryParseFd> CookieWithFds<'conn, C, R> {
+impl<'conn, C: RequestConnection + ?Sized, R: TryParseFd> CookieWithFds<'conn, C, R> {
     /// Create a new cookie from its raw parts.
     pub fn new(conn: &'conn C, sequence: SequenceNumber) -> Self {
         Self {
@@ -70,6 +70,7 @@
 // -- Public Modules --
 
 pub mod blocking;
+pub mod clipboard;
 pub mod connection;
 #[allow(clippy::type_complexity, missing_docs)]
 #[rustfmt::skip]
@@ -77,7 +78,7 @@ pub mod protocol;
 pub mod rust_connection;
 
 #[doc(inline)]
-pub use x11rb::{errors, x11_utils};
+pub use x11rb::{errors, event_filter, reply_ref, x11_utils};
 
 #[doc(inline)]
 pub use x11rb_protocol::SequenceNumber;
@@ -86,7 +87,8 @@ pub use x11rb_protocol::SequenceNumber;
 
 mod cookie;
 
-pub use cookie::{Cookie, CookieWithFds, VoidCookie};
+pub use cookie::{join_cookies, Cookie, CookieWithFds, VoidCookie};
+pub use x11rb::cookie::ReplyView;
 
 pub mod utils {
     //! Utility functions that are not specific to X11.
@@ -0,0 +1,138 @@
+//! Sending requests from synchronous code, e.g. from `Drop` implementations.
+
+use event_listener::{Event, EventListener};
+use std::collections::VecDeque;
+use std::io;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex as StdMutex,
+};
+use x11rb::errors::ConnectionError;
+use x11rb_protocol::x11_utils::VoidRequest;
+use x11rb_protocol::RawFdContainer;
+
+/// A request that was serialized and waits to be sent to the X11 server.
+#[derive(Debug)]
+pub(super) struct DetachedRequest {
+    /// The bytes of the request, including a correct length field.
+    pub(super) data: Vec<u8>,
+
+    /// The file descriptors that are sent together with the request.
+    pub(super) fds: Vec<RawFdContainer>,
+}
+
+/// The queue of requests that were sent via a [`DetachedSender`].
+#[derive(Debug, Default)]
+pub(super) struct DetachedQueue {
+    /// The requests that still have to be sent.
+    requests: StdMutex<VecDeque<DetachedRequest>>,
+
+    /// Notified when a new request is added to the queue.
+    new_request: Event,
+
+    /// Flag that indicates that nobody will ever send the queued requests.
+    closed: AtomicBool,
+}
+
+impl DetachedQueue {
+    /// Take the oldest queued request.
+    pub(super) fn pop(&self) -> Option<DetachedRequest> {
+        self.requests.lock().unwrap().pop_front()
+    }
+
+    /// Put back a request that was taken, but could not be sent yet.
+    pub(super) fn push_front(&self, request: DetachedRequest) {
+        self.requests.lock().unwrap().push_front(request);
+    }
+
+    /// Get a listener that is notified when a new request is queued.
+    pub(super) fn listen(&self) -> EventListener {
+        self.new_request.listen()
+    }
+
+    /// Mark this queue as closed.
+    ///
+    /// Afterwards, all attempts to queue a request fail.
+    pub(super) fn close(&self) {
+        self.closed.store(true, Ordering::SeqCst);
+        self.requests.lock().unwrap().clear();
+    }
+}
+
+/// A handle for sending requests without an `async` context.
+///
+/// Sending a request on a [`super::RustConnection`] requires awaiting a future. This is not
+/// possible in synchronous code like `Drop` implementations. A `DetachedSender` allows such code
+/// to hand over requests that were already serialized. They are queued and written by the future
+/// that drives the connection whenever this is possible without waiting. Requests that are sent
+/// directly on the connection and calls to [`crate::connection::Connection::flush`] also send
+/// queued requests.
+///
+/// Requests sent this way are "fire-and-forget": No reply can be requested and any X11 errors
+/// that they cause are silently discarded. The relative order of requests sent via a
+/// `DetachedSender` is preserved, but there is no ordering guarantee relative to requests sent
+/// directly on the connection.
+///
+/// Since the maximum request length cannot be queried synchronously, requests sent this way
+/// cannot use the BIG-REQUESTS extension.
+#[derive(Debug, Clone)]
+pub struct DetachedSender(pub(super) Arc<DetachedQueue>);
+
+impl DetachedSender {
+    /// Queue a request that does not have a reply.
+    ///
+    /// For requests from an X11 extension, the major opcode of the extension has to be provided.
+    /// It can be obtained via
+    /// [`crate::connection::RequestConnection::extension_information`]. For requests from the
+    /// core protocol, the value of `extension_opcode` is ignored.
+    pub fn send_request<R: VoidRequest>(
+        &self,
+        request: R,
+        extension_opcode: u8,
+    ) -> Result<(), ConnectionError> {
+        let (data, fds) = request.serialize(extension_opcode);
+        self.send_raw_request(data, fds)
+    }
+
+    /// Queue a raw request that does not have a reply.
+    ///
+    /// `data` must be a complete request, including a correct length field. The BIG-REQUESTS
+    /// encoding, i.e. a length field of zero followed by a 32 bit length, is not supported.
+    /// Such requests and requests that are too large for the 16 bit length field fail with
+    /// [`ConnectionError::MaximumRequestLengthExceeded`].
+    pub fn send_raw_request(
+        &self,
+        data: Vec<u8>,
+        fds: Vec<RawFdContainer>,
+    ) -> Result<(), ConnectionError> {
+        assert!(
+            data.len() >= 4 && data.len() % 4 == 0,
+            "The length of X11 requests must be a non-zero multiple of 4, got {}",
+            data.len()
+        );
+        let length_field = u16::from_ne_bytes([data[2], data[3]]);
+        let length = u16::try_from(data.len() / 4)
+            .ok()
+            .filter(|_| length_field != 0)
+            .ok_or(ConnectionError::MaximumRequestLengthExceeded)?;
+        assert_eq!(
+            length, length_field,
+            "Length field contains incorrect value"
+        );
+
+        {
+            let mut requests = self.0.requests.lock().unwrap();
+            if self.0.closed.load(Ordering::SeqCst) {
+                return Err(ConnectionError::IoError(io::Error::new(
+                    io::ErrorKind::Other,
+                    "Driving future was dropped",
+                )));
+            }
+            requests.push_back(DetachedRequest { data, fds });
+        }
+        tracing::debug!("Queued a detached request");
+
+        let _num_notified = self.0.new_request.notify_additional(usize::MAX);
+        Ok(())
+    }
+}
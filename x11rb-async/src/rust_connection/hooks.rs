@@ -0,0 +1,119 @@
+//! Calling the [`ConnectionHooks`] that are installed on a [`super::RustConnection`].
+
+use std::io::IoSlice;
+use std::sync::{Arc, Mutex as StdMutex, RwLock as StdRwLock};
+
+use x11rb::rust_connection::ConnectionHooks;
+use x11rb_protocol::connection::Connection as ProtoConnection;
+use x11rb_protocol::x11_utils::{ExtInfoProvider, ExtensionInformation, X11Error};
+use x11rb_protocol::SequenceNumber;
+
+/// The hooks of a connection, together with what is needed to call them.
+///
+/// The hooks are called while the inner connection is locked, so the locks in here must never be
+/// held while waiting for anything else.
+#[derive(Debug, Default)]
+pub(super) struct Hooks {
+    /// The installed hooks.
+    hooks: StdMutex<Option<Arc<dyn ConnectionHooks>>>,
+
+    /// The extensions that are known to be present, for parsing errors.
+    ///
+    /// Packets are read by the driving future, which cannot wait for the extension cache of the
+    /// connection. This is a copy of the information that can always be locked.
+    known_extensions: StdRwLock<KnownExtensions>,
+}
+
+impl Hooks {
+    /// Install new hooks, replacing the previous ones.
+    pub(super) fn set(&self, hooks: Option<Arc<dyn ConnectionHooks>>) {
+        *self.hooks.lock().unwrap() = hooks;
+    }
+
+    /// Get the currently installed hooks.
+    fn current(&self) -> Option<Arc<dyn ConnectionHooks>> {
+        self.hooks.lock().unwrap().clone()
+    }
+
+    /// Record information about an extension.
+    pub(super) fn add_extension(&self, name: &'static str, info: ExtensionInformation) {
+        if !self.known_extensions.read().unwrap().contains(name) {
+            self.known_extensions.write().unwrap().insert(name, info);
+        }
+    }
+
+    /// Tell the hooks about a request that is sent.
+    pub(super) fn on_request(&self, sequence: SequenceNumber, bufs: &[IoSlice<'_>]) {
+        if let Some(hooks) = self.current() {
+            let request = bufs
+                .iter()
+                .flat_map(|buf| buf.iter().copied())
+                .collect::<Vec<_>>();
+            hooks.on_request(sequence, request[0], &request);
+        }
+    }
+
+    /// Tell the hooks about packets that were received.
+    ///
+    /// This must be called before the packets are enqueued in `inner`.
+    pub(super) fn on_packets(&self, inner: &ProtoConnection, packets: &[Vec<u8>]) {
+        let hooks = match self.current() {
+            Some(hooks) => hooks,
+            None => return,
+        };
+        for packet in packets {
+            match packet[0] {
+                0 => {
+                    let known_extensions = self.known_extensions.read().unwrap();
+                    match X11Error::try_parse(packet, &*known_extensions) {
+                        Ok(error) => hooks.on_error(&error),
+                        Err(e) => tracing::warn!("Failed to parse X11 error for hooks: {:?}", e),
+                    }
+                }
+                1 => hooks.on_reply(inner.sequence_number_of(packet), packet),
+                _ => hooks.on_event(packet),
+            }
+        }
+    }
+}
+
+/// The extensions that are known to be present.
+#[derive(Debug, Default)]
+struct KnownExtensions(Vec<(&'static str, ExtensionInformation)>);
+
+impl KnownExtensions {
+    fn contains(&self, name: &str) -> bool {
+        self.0.iter().any(|(known, _)| *known == name)
+    }
+
+    fn insert(&mut self, name: &'static str, info: ExtensionInformation) {
+        if !self.contains(name) {
+            self.0.push((name, info));
+        }
+    }
+}
+
+impl ExtInfoProvider for KnownExtensions {
+    fn get_from_major_opcode(&self, major_opcode: u8) -> Option<(&str, ExtensionInformation)> {
+        self.0
+            .iter()
+            .find(|(_, info)| info.major_opcode == major_opcode)
+            .map(|(name, info)| (*name, *info))
+    }
+
+    fn get_from_event_code(&self, event_code: u8) -> Option<(&str, ExtensionInformation)> {
+        self.0
+            .iter()
+            .filter(|(_, info)| info.first_event <= event_code)
+            .max_by_key(|(_, info)| info.first_event)
+            .map(|(name, info)| (*name, *info))
+    }
+
+    fn get_from_error_code(&self, error_code: u8) -> Option<(&str, ExtensionInformation)> {
+        self.0
+            .iter()
+            .filter(|(_, info)| info.first_error <= error_code)
+            .max_by_key(|(_, info)| info.first_error)
+            .map(|(name, info)| (*name, *info))
+    }
+}
@@ -1,37 +1,54 @@
 //! An implementation of a pure-Rust async connection to an X11 server.
 
 use async_lock::{Mutex, MutexGuard, RwLock};
-use futures_lite::future;
+use futures_lite::{future, stream as futures_stream};
 use tracing::Instrument as _;
 
 use std::convert::Infallible;
 use std::future::Future;
 use std::io;
 use std::pin::Pin;
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::connection::{Connection, Fut, RequestConnection};
 use crate::{Cookie, CookieWithFds, VoidCookie};
 
-use x11rb_protocol::connection::{Connection as ProtoConnection, PollReply, ReplyFdKind};
-use x11rb_protocol::id_allocator::IdAllocator;
+use x11rb_protocol::connection::{
+    Connection as ProtoConnection, EventQueueId, OutstandingRequests, PollReply, ReplyFdKind,
+};
+use x11rb_protocol::id_allocator::{AtomicIdRange, IdAllocator, IdPool};
 use x11rb_protocol::protocol::bigreq::EnableReply;
-use x11rb_protocol::protocol::xproto::{Setup, QUERY_EXTENSION_REQUEST};
-use x11rb_protocol::x11_utils::{ExtensionInformation, TryParse, TryParseFd, X11Error};
+use x11rb_protocol::protocol::xproto::{Setup, Window, QUERY_EXTENSION_REQUEST};
+use x11rb_protocol::x11_utils::{
+    ExtInfoProvider, ExtensionInformation, TryParse, TryParseFd, X11Error,
+};
 use x11rb_protocol::xauth::get_auth;
 use x11rb_protocol::{DiscardMode, RawFdContainer, SequenceNumber};
 
 use x11rb::connection::{BufWithFds, ReplyOrError};
-use x11rb::errors::{ConnectError, ConnectionError, ParseError, ReplyOrIdError};
+use x11rb::errors::{ConnectError, ConnectionError, ParseError, ReplyError, ReplyOrIdError};
+use x11rb::event_filter::EventFilter;
+use x11rb::protocol::Event;
 
+mod detached;
 mod extensions;
+mod hooks;
 mod nb_connect;
 mod shared_state;
+mod split;
 mod stream;
+mod window_events;
 mod write_buffer;
 
+pub use detached::DetachedSender;
+pub use nb_connect::{Resolver, SystemResolver};
+pub use split::{EventReceiver, RequestSender};
 pub use stream::{DefaultStream, Stream, StreamAdaptor, StreamBase};
-use write_buffer::{WriteBuffer, WriteBufferGuard};
+pub use window_events::Backpressure;
+use write_buffer::WriteBufferGuard;
+pub use x11rb::rust_connection::ConnectionHooks;
 
 /// A pure-Rust async connection to an X11 server.
 #[derive(Debug)]
@@ -39,11 +56,6 @@ pub struct RustConnection<S = DefaultStream> {
     /// Shared state between the conenction and the packet reader.
     shared: Arc<shared_state::SharedState<S>>,
 
-    /// The write buffer.
-    ///
-    /// Holding this lock implies the exclusive right to write to the stream.
-    write_buffer: WriteBuffer,
-
     /// The setup information.
     setup: Setup,
 
@@ -51,10 +63,19 @@ pub struct RustConnection<S = DefaultStream> {
     max_request_bytes: Mutex<MaxRequestBytes>,
 
     /// The allocator for resource IDs.
-    id_allocator: Mutex<IdAllocator>,
+    id_allocator: Mutex<IdState>,
+
+    /// IDs that can be allocated without locking `id_allocator`.
+    id_range: AtomicIdRange,
+
+    /// The number of XIDs to request via `GetXIDList`, or zero to use `GetXIDRange`.
+    xid_list_batch_size: AtomicU32,
 
     /// The extension information.
     extensions: RwLock<extensions::Extensions>,
+
+    /// The mailboxes for [`RustConnection::window_events`].
+    window_router: window_events::WindowRouter,
 }
 
 /// The maximum bytes we can send in a single request.
@@ -85,12 +106,31 @@ impl RustConnection {
             impl Future<Output = Result<Infallible, ConnectionError>> + Send,
         ),
         ConnectError,
+    > {
+        Self::connect_with_resolver(display_name, &SystemResolver).await
+    }
+
+    /// Connect to the X11 server, using the given resolver for TCP displays.
+    ///
+    /// This works like [`RustConnection::connect`], but hostnames are resolved via the given
+    /// [`Resolver`]. When a hostname resolves to multiple addresses, connection attempts are
+    /// raced against each other as described in RFC 8305 ("Happy Eyeballs").
+    pub async fn connect_with_resolver(
+        display_name: Option<&str>,
+        resolver: &dyn Resolver,
+    ) -> Result<
+        (
+            Self,
+            usize,
+            impl Future<Output = Result<Infallible, ConnectionError>> + Send,
+        ),
+        ConnectError,
     > {
         // Parse the display name.
         let addrs = x11rb_protocol::parse_display::parse_display(display_name)?;
 
         // Connect to the stream.
-        let (stream, screen, (family, address)) = nb_connect::connect(&addrs).await?;
+        let (stream, screen, (family, address)) = nb_connect::connect(&addrs, resolver).await?;
 
         // Wrap the stream in a connection.
         let stream = StreamAdaptor::new(stream)?;
@@ -109,6 +149,57 @@ impl RustConnection {
                 .await?;
         Ok((conn, screen, drive))
     }
+
+    /// Connect to the X11 server over an already connected socket.
+    ///
+    /// This is useful when a sandbox launcher hands out a socket that is connected to the X11
+    /// server. Only the setup handshake is performed on the given file descriptor.
+    ///
+    /// This function returns a future that drives the packet reader for the connection.
+    /// It should be spawned on a task executor to be polled while the connection is in
+    /// use.
+    #[cfg(unix)]
+    pub async fn connect_to_fd(
+        fd: std::os::unix::io::OwnedFd,
+        screen: usize,
+        auth_name: Vec<u8>,
+        auth_data: Vec<u8>,
+    ) -> Result<
+        (
+            Self,
+            impl Future<Output = Result<Infallible, ConnectionError>> + Send,
+        ),
+        ConnectError,
+    > {
+        use x11rb::rust_connection::DefaultStream as SyncDefaultStream;
+
+        let stream = StreamAdaptor::new(SyncDefaultStream::from_owned_fd(fd)?)?;
+        Self::connect_to_stream_with_auth_info(stream, screen, auth_name, auth_data).await
+    }
+
+    /// Connect to the X11 server, giving up after the given timeout.
+    ///
+    /// This works like [`RustConnection::connect`], but the time spent on resolving the hostname,
+    /// establishing the connection and performing the setup handshake with the X11 server is
+    /// bounded by `timeout`. If it expires, [`ConnectError::Timeout`] is returned.
+    pub async fn connect_with_timeout(
+        display_name: Option<&str>,
+        timeout: Duration,
+    ) -> Result<
+        (
+            Self,
+            usize,
+            impl Future<Output = Result<Infallible, ConnectionError>> + Send,
+        ),
+        ConnectError,
+    > {
+        future::or(Self::connect(display_name), async {
+            let _ = async_io::Timer::after(timeout).await;
+            tracing::debug!("Connecting to the X11 server timed out");
+            Err(ConnectError::Timeout)
+        })
+        .await
+    }
 }
 
 impl<S: Stream + Send + Sync> RustConnection<S> {
@@ -234,16 +325,308 @@ impl<S: Stream + Send + Sync> RustConnection<S> {
         Ok((
             RustConnection {
                 shared,
-                write_buffer: Default::default(),
                 setup,
                 max_request_bytes: Mutex::new(MaxRequestBytes::Unknown),
-                id_allocator: Mutex::new(id_allocator),
+                id_range: AtomicIdRange::new(&id_allocator),
+                id_allocator: Mutex::new(IdState {
+                    pool: IdPool::new(id_allocator),
+                    refill: None,
+                }),
+                xid_list_batch_size: AtomicU32::new(0),
                 extensions: Default::default(),
+                window_router: Default::default(),
             },
             drive,
         ))
     }
 
+    /// Get a future that completes when the connection to the X11 server is lost.
+    ///
+    /// The future completes as soon as the future that drives the connection stops. This happens
+    /// when reading from the X11 server fails, e.g. because the server closed the connection, or
+    /// when the driving future is dropped. Afterwards, all operations on this connection fail.
+    ///
+    /// This allows to react to a lost connection immediately instead of only when the next
+    /// request or wait fails.
+    pub fn on_disconnect(&self) -> impl Future<Output = ()> + Send + 'static {
+        let disconnect = Arc::clone(&self.shared.disconnect);
+        async move { disconnect.wait().await }
+    }
+
+    /// Check whether the connection to the X11 server was lost.
+    ///
+    /// See [`RustConnection::on_disconnect`] for details.
+    pub fn is_disconnected(&self) -> bool {
+        self.shared.disconnect.is_disconnected()
+    }
+
+    /// Returns a reference to the contained stream.
+    pub fn stream(&self) -> &S {
+        &self.shared.stream
+    }
+
+    /// Install hooks that observe the traffic on this connection.
+    ///
+    /// Any previously installed hooks are replaced. Pass `None` to remove the hooks. Received
+    /// packets are passed to the hooks by the future that drives the connection.
+    pub fn set_hooks(&self, hooks: Option<Arc<dyn ConnectionHooks>>) {
+        self.shared.hooks.set(hooks);
+    }
+
+    /// Set the size of the buffer that is used for reading from the X11 server.
+    ///
+    /// Incoming data is read into this buffer and then split into packets. A larger buffer allows
+    /// to read more packets with a single system call, which helps clients that receive lots of
+    /// events, e.g. XInput raw motion events. The default size is 4096 bytes. Sizes smaller than
+    /// 32 bytes (the size of an event) are increased to 32 bytes.
+    ///
+    /// Reads are always batched: The connection keeps reading until no more data is available and
+    /// only then hands all received packets over at once. The buffer size only limits how much
+    /// data a single system call returns.
+    ///
+    /// The new size is used for the next read.
+    pub fn set_read_buffer_size(&self, size: usize) {
+        self.shared.read_buffer_size.store(
+            size.max(shared_state::MIN_READ_BUFFER_SIZE),
+            Ordering::Relaxed,
+        );
+    }
+
+    /// Get the size of the buffer that is used for reading from the X11 server.
+    ///
+    /// See [`RustConnection::set_read_buffer_size`].
+    pub fn read_buffer_size(&self) -> usize {
+        self.shared.read_buffer_size.load(Ordering::Relaxed)
+    }
+
+    /// Get information about requests that were not fully handled yet.
+    ///
+    /// This allows to detect requests whose replies are never collected, for example because
+    /// their cookie was leaked, or requests that the X11 server takes long to answer.
+    pub fn outstanding_requests(&self) -> OutstandingRequests {
+        self.shared.lock_connection().outstanding_requests()
+    }
+
+    /// Get the discard mode of the request with the given sequence number.
+    ///
+    /// See [`ProtoConnection::discard_mode`] for details.
+    pub fn discard_mode(&self, sequence: SequenceNumber) -> Option<DiscardMode> {
+        self.shared.lock_connection().discard_mode(sequence)
+    }
+
+    /// Set how many XIDs are requested at once when the XIDs of this client are exhausted.
+    ///
+    /// With the default of zero, the XC-MISC request `GetXIDRange` is used to find free XIDs. On
+    /// busy servers, this may return very small ranges so that many round trips are needed to
+    /// create lots of resources. With a non-zero batch size, `GetXIDList` is used instead to ask
+    /// for up to this many free XIDs at once.
+    pub fn set_xid_list_batch_size(&self, size: u32) {
+        self.xid_list_batch_size.store(size, Ordering::Relaxed);
+    }
+
+    /// Get the number of XIDs that are requested at once via `GetXIDList`.
+    ///
+    /// See [`RustConnection::set_xid_list_batch_size`].
+    pub fn xid_list_batch_size(&self) -> u32 {
+        self.xid_list_batch_size.load(Ordering::Relaxed)
+    }
+
+    /// Take the first queued event for which `matches` returns `true` out of the event queue.
+    ///
+    /// `matches` gets the raw event, so that cheap checks do not have to parse every event.
+    async fn take_matching_event<F>(&self, matches: &mut F) -> Result<Option<Event>, ParseError>
+    where
+        F: FnMut(&[u8], &dyn ExtInfoProvider) -> bool,
+    {
+        let extensions = self.extensions.read().await;
+        let event = self
+            .shared
+            .lock_connection()
+            .poll_for_matching_event_with_sequence(|event| matches(event, &*extensions));
+        event
+            .map(|(event, _)| Event::parse(&event, &*extensions))
+            .transpose()
+    }
+
+    /// Wait for an event for which `matches` returns `true`.
+    async fn wait_for_event_where<F>(&self, mut matches: F) -> Result<Event, ConnectionError>
+    where
+        F: FnMut(&[u8], &dyn ExtInfoProvider) -> bool,
+    {
+        loop {
+            let listener = self.shared.listen_for_input();
+            if let Some(event) = self.take_matching_event(&mut matches).await? {
+                return Ok(event);
+            }
+            self.shared.check_driver()?;
+            listener.await;
+        }
+    }
+
+    /// Get the first queued event that matches `filter` without waiting.
+    ///
+    /// Events that do not match stay in the event queue and can still be fetched with e.g.
+    /// [`Connection::poll_for_event`].
+    pub async fn poll_for_matching_event(
+        &self,
+        filter: &EventFilter,
+    ) -> Result<Option<Event>, ConnectionError> {
+        Ok(self
+            .take_matching_event(&mut |event, ext_info| filter.matches_raw(event, ext_info))
+            .await?)
+    }
+
+    /// Wait for an event that matches `filter`.
+    ///
+    /// Events that do not match stay in the event queue and can still be fetched with e.g.
+    /// [`Connection::wait_for_event`].
+    pub async fn wait_for_matching_event(
+        &self,
+        filter: &EventFilter,
+    ) -> Result<Event, ConnectionError> {
+        self.wait_for_event_where(|event, ext_info| filter.matches_raw(event, ext_info))
+            .await
+    }
+
+    /// Wait for an event of type `E` for which `matches` returns `true`.
+    ///
+    /// Other events stay in the event queue and can still be fetched with e.g.
+    /// [`Connection::wait_for_event`]. This is useful for waiting for the answer to some action,
+    /// for example:
+    ///
+    /// ```no_run
+    /// # use x11rb_async::rust_connection::RustConnection;
+    /// # use x11rb_async::protocol::xproto::{Atom, PropertyNotifyEvent, Window};
+    /// # async fn example(conn: &RustConnection, window: Window, atom: Atom) {
+    /// let event = conn
+    ///     .await_event(|e: &PropertyNotifyEvent| e.window == window && e.atom == atom)
+    ///     .await;
+    /// # }
+    /// ```
+    ///
+    /// # Event copies
+    ///
+    /// Some events share their struct with other events. For example, `ButtonRelease` events
+    /// use [`ButtonPressEvent`], so `await_event::<ButtonPressEvent, _>` also returns button
+    /// releases. Check the event type in `matches` if the difference matters, e.g. with
+    /// `e.response_type & 0x7f == BUTTON_PRESS_EVENT`.
+    ///
+    /// [`ButtonPressEvent`]: crate::protocol::xproto::ButtonPressEvent
+    pub async fn await_event<E, F>(&self, mut matches: F) -> Result<E, ConnectionError>
+    where
+        E: TryFrom<Event>,
+        for<'e> &'e E: TryFrom<&'e Event>,
+        F: FnMut(&E) -> bool,
+    {
+        let event = self
+            .wait_for_event_where(|event, ext_info| {
+                Event::parse(event, ext_info).map_or(false, |event| {
+                    <&E>::try_from(&event).map_or(false, &mut matches)
+                })
+            })
+            .await?;
+        match E::try_from(event) {
+            Ok(event) => Ok(event),
+            Err(_) => unreachable!("Only events of the right type are matched"),
+        }
+    }
+
+    /// Get a stream of the events that match `filter`.
+    ///
+    /// The stream never ends. Other events stay in the event queue for the main event loop. This
+    /// is useful e.g. for modal dialogs.
+    pub fn filtered_events(
+        &self,
+        filter: EventFilter,
+    ) -> impl futures_stream::Stream<Item = Result<Event, ConnectionError>> + '_ {
+        futures_stream::unfold(filter, move |filter| async move {
+            let event = self.wait_for_matching_event(&filter).await;
+            Some((event, filter))
+        })
+    }
+
+    /// Get a stream of the events for `window`.
+    ///
+    /// From now on, events whose [`Event::window`] is `window` are taken out of the main event
+    /// queue of the connection and put into a mailbox that only this stream reads from. This
+    /// allows e.g. each widget of a toolkit to handle the events of its own window. Events are
+    /// moved into the mailbox whenever the stream or the main event queue is read. `policy`
+    /// decides what happens when the stream is read less often than events arrive. If several
+    /// streams exist for the same window, each of them gets a copy of the events.
+    ///
+    /// Events from extensions are only routed while the extension information is not being
+    /// updated concurrently. When the stream is dropped, the events in its mailbox are lost and
+    /// the events for `window` go to the main event queue again.
+    pub fn window_events(
+        &self,
+        window: Window,
+        policy: Backpressure,
+    ) -> impl futures_stream::Stream<Item = Result<Event, ConnectionError>> + '_ {
+        let registration = WindowRegistration {
+            conn: self,
+            id: self.window_router.register(window, policy),
+        };
+        futures_stream::unfold(registration, move |registration| async move {
+            let event = registration.wait_for_event().await;
+            Some((event, registration))
+        })
+    }
+
+    /// Move the events for windows with a mailbox out of the main event queue.
+    fn route_window_events(&self, inner: &mut ProtoConnection) {
+        match self.extensions.try_read() {
+            Some(extensions) => self.window_router.route(inner, &*extensions),
+            None => self
+                .window_router
+                .route(inner, &extensions::Extensions::default()),
+        }
+    }
+
+    /// Add a locally constructed event to the event queue.
+    ///
+    /// The event is returned like events from the X11 server, but it is marked as sent via the
+    /// `SendEvent` request (see [`Event::sent_event`]). This allows to simulate input, e.g. in
+    /// tests or input method layers, without a round trip to the X11 server. For events from
+    /// extensions, the extension must be supported by the server. X11 errors cannot be enqueued.
+    pub async fn enqueue_synthetic_event(&self, event: &Event) -> Result<(), ConnectionError> {
+        let ext_info = match event.extension() {
+            Some(name) => Some(
+                self.extension_information(name)
+                    .await?
+                    .ok_or(ConnectionError::UnsupportedExtension)?,
+            ),
+            None => None,
+        };
+        let bytes = event
+            .serialize(ext_info.as_ref())
+            .ok_or(ConnectionError::UnsupportedExtension)?;
+        if bytes.len() < 32 || bytes[0] == 0 {
+            return Err(ParseError::InvalidValue.into());
+        }
+        self.shared.enqueue_synthetic_event(bytes);
+        Ok(())
+    }
+
+    /// Get a handle to a new event queue.
+    ///
+    /// The handle gets a copy of every event that is received from now on, independent of the
+    /// main event queue of the connection and of other handles. This allows e.g. libraries that
+    /// are embedded in an application to observe events without taking them away from the
+    /// application. X11 errors are only delivered to the main event queue.
+    ///
+    /// Events pile up in the queue until they are fetched via the handle or the handle is dropped.
+    pub fn event_queue_handle(&self) -> EventQueueHandle<'_, S> {
+        let id = self.shared.lock_connection().add_event_queue();
+        EventQueueHandle { conn: self, id }
+    }
+
+    /// Get a handle for sending requests from synchronous code.
+    ///
+    /// See [`DetachedSender`] for details.
+    pub fn detached_sender(&self) -> DetachedSender {
+        DetachedSender(Arc::clone(&self.shared.detached))
+    }
+
     /// Send a request.
     async fn send_request(
         &self,
@@ -274,8 +657,9 @@ impl<S: Stream + Send + Sync> RustConnection<S> {
             let mut storage = Default::default();
             let bufs = compute_length_field(self, bufs, &mut storage).await?;
 
-            // Lock the buffer.
-            let mut buffer = self.write_buffer.lock().await?;
+            // Lock the buffer and send any queued detached requests first.
+            let buffer = self.shared.write_buffer.lock().await?;
+            let mut buffer = self.shared.send_detached_requests(buffer).await?;
 
             loop {
                 let seq = {
@@ -286,6 +670,8 @@ impl<S: Stream + Send + Sync> RustConnection<S> {
                 // Logically send the request.
                 match seq {
                     Some(seq) => {
+                        self.shared.hooks.on_request(seq, bufs);
+
                         // Write the request to the buffer.
                         buffer = self.write_all_vectored(buffer, bufs, &mut fds).await?;
                         buffer.unlock();
@@ -307,27 +693,7 @@ impl<S: Stream + Send + Sync> RustConnection<S> {
         &'a self,
         buffer: WriteBufferGuard<'a>,
     ) -> Result<WriteBufferGuard<'a>, ConnectionError> {
-        let length = 1u16.to_ne_bytes();
-        let request = [
-            x11rb_protocol::protocol::xproto::GET_INPUT_FOCUS_REQUEST,
-            0,
-            length[0],
-            length[1],
-        ];
-
-        // Send this request.
-        {
-            let mut inner = self.shared.lock_connection();
-            let seq = inner
-                .send_request(ReplyFdKind::ReplyWithoutFDs)
-                .expect("This request should not be blocked by syncs");
-            inner.discard_reply(seq, DiscardMode::DiscardReplyAndError);
-        };
-
-        // Write the entire packet.
-        let iov = &[io::IoSlice::new(&request)];
-        let mut fds = Vec::new();
-        self.write_all_vectored(buffer, iov, &mut fds).await
+        self.shared.send_sync(buffer).await
     }
 
     /// Write a set of buffers to the stream.
@@ -344,10 +710,13 @@ impl<S: Stream + Send + Sync> RustConnection<S> {
     }
 
     /// Flush the write buffer.
+    ///
+    /// This also sends requests that were queued via a [`DetachedSender`].
     async fn flush_impl<'a>(
         &'a self,
-        mut buffer: WriteBufferGuard<'a>,
+        buffer: WriteBufferGuard<'a>,
     ) -> Result<WriteBufferGuard<'a>, ConnectionError> {
+        let mut buffer = self.shared.send_detached_requests(buffer).await?;
         buffer.flush(&self.shared.stream).await?;
         Ok(buffer)
     }
@@ -383,7 +752,7 @@ impl<S: Stream + Send + Sync> RustConnection<S> {
         sequence: SequenceNumber,
     ) -> Result<ReplyOrError<BufWithFds<Vec<u8>>, Vec<u8>>, ConnectionError> {
         // Ensure that the request is sent.
-        self.flush_impl(self.write_buffer.lock().await?)
+        self.flush_impl(self.shared.write_buffer.lock().await?)
             .await?
             .unlock();
 
@@ -494,7 +863,11 @@ impl<S: Stream + Send + Sync> RequestConnection for RustConnection<S> {
     ) -> Fut<'_, Option<ExtensionInformation>, ConnectionError> {
         Box::pin(async move {
             let mut cache = self.extensions.write().await;
-            cache.information(self, name).await
+            let info = cache.information(self, name).await?;
+            if let Some(info) = info {
+                self.shared.hooks.add_extension(name, info);
+            }
+            Ok(info)
         })
     }
 
@@ -513,6 +886,54 @@ impl<S: Stream + Send + Sync> RequestConnection for RustConnection<S> {
         )
     }
 
+    fn wait_for_replies_or_raw_errors<'this, 'seq, 'future>(
+        &'this self,
+        sequences: &'seq [SequenceNumber],
+    ) -> Fut<'future, Vec<ReplyOrError<Self::Buf>>, ConnectionError>
+    where
+        'this: 'future,
+        'seq: 'future,
+    {
+        Box::pin(
+            async move {
+                // Ensure that all the requests are sent.
+                self.flush_impl(self.shared.write_buffer.lock().await?)
+                    .await?
+                    .unlock();
+
+                // The X11 server answers in order, so we look at the requests in order.
+                let mut order = (0..sequences.len()).collect::<Vec<_>>();
+                order.sort_by_key(|&index| sequences[index]);
+                let mut results = Vec::new();
+                results.resize_with(sequences.len(), || None);
+                let mut done = 0;
+
+                let get_replies = |inner: &mut ProtoConnection| {
+                    while let Some(&index) = order.get(done) {
+                        let (buffer, _fds) = inner.poll_for_reply_or_error(sequences[index])?;
+                        results[index] = Some(if buffer[0] == 0 {
+                            ReplyOrError::Error(buffer)
+                        } else {
+                            ReplyOrError::Reply(buffer)
+                        });
+                        done += 1;
+                    }
+                    Some(())
+                };
+                self.shared.wait_for_incoming(get_replies).await?;
+
+                Ok(results
+                    .into_iter()
+                    .map(|result| result.expect("All replies were received"))
+                    .collect())
+            }
+            .instrument(tracing::info_span!(
+                "wait_for_replies_or_raw_errors",
+                count = sequences.len()
+            )),
+        )
+    }
+
     fn wait_for_reply(
         &self,
         sequence: SequenceNumber,
@@ -520,7 +941,7 @@ impl<S: Stream + Send + Sync> RequestConnection for RustConnection<S> {
         Box::pin(
             async move {
                 // Flush the request.
-                self.flush_impl(self.write_buffer.lock().await?)
+                self.flush_impl(self.shared.write_buffer.lock().await?)
                     .await?
                     .unlock();
 
@@ -553,7 +974,7 @@ impl<S: Stream + Send + Sync> RequestConnection for RustConnection<S> {
     ) -> Fut<'_, Option<Self::Buf>, ConnectionError> {
         Box::pin(
             async move {
-                let mut write_buffer = self.write_buffer.lock().await?;
+                let mut write_buffer = self.shared.write_buffer.lock().await?;
                 if self
                     .shared
                     .lock_connection()
@@ -644,9 +1065,9 @@ impl<S: Stream + Send + Sync> RequestConnection for RustConnection<S> {
         X11Error::try_parse(error, &*extensions)
     }
 
-    fn parse_event(&self, event: &[u8]) -> Result<x11rb::protocol::Event, ParseError> {
+    fn parse_event(&self, event: &[u8]) -> Result<Event, ParseError> {
         let extensions = future::block_on(self.extensions.read());
-        x11rb::protocol::Event::parse(event, &*extensions)
+        Event::parse(event, &*extensions)
     }
 }
 
@@ -656,7 +1077,10 @@ impl<S: Stream + Send + Sync> Connection for RustConnection<S> {
     ) -> Fut<'_, x11rb_protocol::RawEventAndSeqNumber<Self::Buf>, ConnectionError> {
         Box::pin(
             async move {
-                let get_event = |inner: &mut ProtoConnection| inner.poll_for_event_with_sequence();
+                let get_event = |inner: &mut ProtoConnection| {
+                    self.route_window_events(inner);
+                    inner.poll_for_event_with_sequence()
+                };
 
                 Ok(self.shared.wait_for_incoming(get_event).await?)
             }
@@ -667,12 +1091,14 @@ impl<S: Stream + Send + Sync> Connection for RustConnection<S> {
     fn poll_for_raw_event_with_sequence(
         &self,
     ) -> Result<Option<x11rb_protocol::RawEventAndSeqNumber<Self::Buf>>, ConnectionError> {
-        Ok(self.shared.lock_connection().poll_for_event_with_sequence())
+        let mut inner = self.shared.lock_connection();
+        self.route_window_events(&mut inner);
+        Ok(inner.poll_for_event_with_sequence())
     }
 
     fn flush(&self) -> Fut<'_, (), ConnectionError> {
         Box::pin(async move {
-            self.flush_impl(self.write_buffer.lock().await?)
+            self.flush_impl(self.shared.write_buffer.lock().await?)
                 .await?
                 .unlock();
 
@@ -689,28 +1115,60 @@ impl<S: Stream + Send + Sync> Connection for RustConnection<S> {
             async move {
                 use crate::protocol::xc_misc;
 
-                let mut id_allocator = self.id_allocator.lock().await;
+                // Try to get an ID without locking.
+                if let Some(id) = self.id_range.generate_id() {
+                    if self.id_range.is_low() {
+                        // If the lock is held, its owner takes care of refilling.
+                        if let Some(mut ids) = self.id_allocator.try_lock() {
+                            self.refill_ids_ahead(&mut ids).await?;
+                        }
+                    }
+                    return Ok(id);
+                }
+
+                let mut ids = self.id_allocator.lock().await;
 
                 // Try to get an ID from the allocator.
-                if let Some(id) = id_allocator.generate_id() {
+                if let Some(id) = self.id_range.generate_id_from(&mut ids.pool) {
+                    self.refill_ids_ahead(&mut ids).await?;
                     return Ok(id);
                 }
 
+                // Try the IDs that were requested ahead of time.
+                if self.finish_id_refill(&mut ids).await? {
+                    if let Some(id) = self.id_range.generate_id_from(&mut ids.pool) {
+                        self.refill_ids_ahead(&mut ids).await?;
+                        return Ok(id);
+                    }
+                }
+
                 // We may need to allocate more IDs.
                 if self
                     .extension_information(xc_misc::X11_EXTENSION_NAME)
                     .await?
                     .is_some()
                 {
-                    tracing::info!("XIDs are exhausted; fetching free range via XC-MISC");
-
-                    // Update the ID range.
-                    id_allocator
-                        .update_xid_range(&xc_misc::get_xid_range(self).await?.reply().await?)?;
+                    // Update the available IDs.
+                    let batch_size = self.xid_list_batch_size();
+                    if batch_size == 0 {
+                        tracing::info!("XIDs are exhausted; fetching free range via XC-MISC");
+                        ids.pool.update_xid_range(
+                            &xc_misc::get_xid_range(self).await?.reply().await?,
+                        )?;
+                    } else {
+                        tracing::info!("XIDs are exhausted; fetching free list via XC-MISC");
+                        ids.pool.update_xid_list(
+                            &xc_misc::get_xid_list(self, batch_size)
+                                .await?
+                                .reply()
+                                .await?,
+                        )?;
+                    }
 
                     // Generate a new ID.
-                    return id_allocator
-                        .generate_id()
+                    return self
+                        .id_range
+                        .generate_id_from(&mut ids.pool)
                         .ok_or(ReplyOrIdError::IdsExhausted);
                 } else {
                     tracing::error!("XIDs are exhausted and XC-MISC extension is not available");
@@ -724,6 +1182,78 @@ impl<S: Stream + Send + Sync> Connection for RustConnection<S> {
     }
 }
 
+impl<S: Stream + Send + Sync> RustConnection<S> {
+    /// Send an XC-MISC request for more XIDs if only a few XIDs are left.
+    ///
+    /// The reply is not awaited. It is only needed once the XIDs are exhausted, see
+    /// `finish_id_refill`.
+    async fn refill_ids_ahead(&self, ids: &mut IdState) -> Result<(), ConnectionError> {
+        use crate::protocol::xc_misc;
+
+        if ids.refill.is_some() || !ids.pool.needs_refill(&self.id_range) {
+            return Ok(());
+        }
+        if self
+            .extension_information(xc_misc::X11_EXTENSION_NAME)
+            .await?
+            .is_none()
+        {
+            return Ok(());
+        }
+        // Reserve the remaining IDs before the server can see the request.
+        ids.pool.start_refill(&self.id_range);
+        let batch_size = self.xid_list_batch_size();
+        ids.refill = Some(if batch_size == 0 {
+            tracing::debug!("Few XIDs are left; fetching free range via XC-MISC");
+            XidRefill::Range(xc_misc::get_xid_range(self).await?.into_sequence_number())
+        } else {
+            tracing::debug!("Few XIDs are left; fetching free list via XC-MISC");
+            XidRefill::List(
+                xc_misc::get_xid_list(self, batch_size)
+                    .await?
+                    .into_sequence_number(),
+            )
+        });
+        Ok(())
+    }
+
+    /// Apply the reply to an XC-MISC request that was sent by `refill_ids_ahead`.
+    ///
+    /// Returns `false` if there is no such request or if its reply contains no usable IDs.
+    async fn finish_id_refill(&self, ids: &mut IdState) -> Result<bool, ReplyError> {
+        use crate::protocol::xc_misc::{GetXIDListReply, GetXIDRangeReply};
+
+        let result = match ids.refill.take() {
+            None => return Ok(false),
+            Some(XidRefill::Range(seq)) => {
+                let reply = Cookie::<_, GetXIDRangeReply>::new(self, seq)
+                    .reply()
+                    .await?;
+                ids.pool.update_xid_range(&reply)
+            }
+            Some(XidRefill::List(seq)) => {
+                let reply = Cookie::<_, GetXIDListReply>::new(self, seq).reply().await?;
+                ids.pool.update_xid_list(&reply)
+            }
+        };
+        Ok(result.is_ok())
+    }
+}
+
+/// An XC-MISC request that was sent before the XIDs ran out, see [`IdPool::needs_refill`].
+#[derive(Debug, Clone, Copy)]
+enum XidRefill {
+    Range(SequenceNumber),
+    List(SequenceNumber),
+}
+
+/// The state of the XID allocation that is protected by a lock.
+#[derive(Debug)]
+struct IdState {
+    pool: IdPool,
+    refill: Option<XidRefill>,
+}
+
 /// Copied from x11rb
 async fn compute_length_field<'b>(
     conn: &impl RequestConnection,
@@ -813,3 +1343,96 @@ where
         }
     }
 }
+
+/// A handle to an event queue of a [`RustConnection`] that is independent of the main queue.
+///
+/// This is returned by [`RustConnection::event_queue_handle`]. Cloning the handle produces a new
+/// queue that starts with the events that are currently in this queue.
+#[derive(Debug)]
+pub struct EventQueueHandle<'a, S: Stream + Send + Sync> {
+    conn: &'a RustConnection<S>,
+    id: EventQueueId,
+}
+
+impl<S: Stream + Send + Sync> EventQueueHandle<'_, S> {
+    /// Get the next event from this queue without waiting.
+    pub fn poll_for_event(&self) -> Result<Option<Event>, ConnectionError> {
+        let event = self
+            .conn
+            .shared
+            .lock_connection()
+            .poll_for_event_in_queue(self.id);
+        match event {
+            Some((event, _)) => Ok(Some(self.conn.parse_event(&event)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Wait for the next event in this queue.
+    pub async fn wait_for_event(&self) -> Result<Event, ConnectionError> {
+        let id = self.id;
+        let (event, _) = self
+            .conn
+            .shared
+            .wait_for_incoming(|inner: &mut ProtoConnection| inner.poll_for_event_in_queue(id))
+            .await?;
+        Ok(self.conn.parse_event(&event)?)
+    }
+}
+
+impl<S: Stream + Send + Sync> Clone for EventQueueHandle<'_, S> {
+    fn clone(&self) -> Self {
+        let id = self
+            .conn
+            .shared
+            .lock_connection()
+            .clone_event_queue(self.id)
+            .expect("The queue is only removed when the handle is dropped");
+        Self {
+            conn: self.conn,
+            id,
+        }
+    }
+}
+
+impl<S: Stream + Send + Sync> Drop for EventQueueHandle<'_, S> {
+    fn drop(&mut self) {
+        let _ = self
+            .conn
+            .shared
+            .lock_connection()
+            .remove_event_queue(self.id);
+    }
+}
+
+/// The registration of a mailbox for [`RustConnection::window_events`].
+#[derive(Debug)]
+struct WindowRegistration<'a, S: Stream + Send + Sync> {
+    conn: &'a RustConnection<S>,
+    id: usize,
+}
+
+impl<S: Stream + Send + Sync> WindowRegistration<'_, S> {
+    /// Wait for the next event in the mailbox.
+    async fn wait_for_event(&self) -> Result<Event, ConnectionError> {
+        loop {
+            let listener = self.conn.shared.listen_for_input();
+            {
+                let extensions = self.conn.extensions.read().await;
+                let mut inner = self.conn.shared.lock_connection();
+                self.conn.window_router.route(&mut inner, &*extensions);
+            }
+            if let Some(event) = self.conn.window_router.pop(self.id) {
+                return Ok(event);
+            }
+            self.conn.shared.check_driver()?;
+            listener.await;
+        }
+    }
+}
+
+impl<S: Stream + Send + Sync> Drop for WindowRegistration<'_, S> {
+    fn drop(&mut self) {
+        self.conn.window_router.unregister(self.id);
+    }
+}
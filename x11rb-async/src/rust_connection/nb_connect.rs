@@ -1,14 +1,18 @@
 //! Connect to the server using a non-blocking strategy.
 
+use std::future::Future;
 use std::io;
-use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, TcpStream};
-use std::time::Instant;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, TcpStream};
+use std::pin::Pin;
+use std::task::Poll;
+use std::time::{Duration, Instant};
 
 #[cfg(unix)]
 use std::os::unix::net::UnixStream;
 
-use async_io::Async;
+use async_io::{Async, Timer};
 
+use futures_lite::future;
 use futures_lite::pin;
 use futures_lite::prelude::*;
 use futures_lite::stream;
@@ -19,14 +23,57 @@ use x11rb_protocol::parse_display::{ConnectAddress, ParsedDisplay};
 
 type PeerAddr = (x11rb_protocol::xauth::Family, Vec<u8>);
 
+/// How long to wait for a connection attempt before starting the next one in parallel.
+///
+/// This is the "Connection Attempt Delay" recommended by RFC 8305.
+const CONNECTION_ATTEMPT_DELAY: Duration = Duration::from_millis(250);
+
+/// A resolver for the hostnames of TCP displays.
+///
+/// This allows to replace the name resolution that is used by
+/// [`super::RustConnection::connect_with_resolver`], e.g. with a fully asynchronous resolver.
+/// Hostnames that are literal IP addresses are never passed to the resolver.
+pub trait Resolver: Send + Sync {
+    /// Resolve the given hostname into a list of IP addresses.
+    ///
+    /// The addresses should be in order of preference.
+    fn resolve<'a>(
+        &'a self,
+        host: &'a str,
+    ) -> Pin<Box<dyn Future<Output = io::Result<Vec<IpAddr>>> + Send + 'a>>;
+}
+
+/// The default [`Resolver`].
+///
+/// This uses the system resolver (`getaddrinfo()`) in a thread pool.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemResolver;
+
+impl Resolver for SystemResolver {
+    fn resolve<'a>(
+        &'a self,
+        host: &'a str,
+    ) -> Pin<Box<dyn Future<Output = io::Result<Vec<IpAddr>>> + Send + 'a>> {
+        let host = format!("{}:0", host);
+        Box::pin(blocking::unblock(move || {
+            use std::net::ToSocketAddrs;
+
+            host.to_socket_addrs()
+                .map(|socket| socket.map(|socket| socket.ip()).collect())
+        }))
+    }
+}
+
 /// Connect to a `DefaultStream` asynchronously from a display string.
 pub(super) async fn connect(
     addrs: &ParsedDisplay,
+    resolver: &dyn Resolver,
 ) -> Result<(DefaultStream, usize, PeerAddr), ConnectError> {
     let screen: usize = addrs.screen.into();
     let mut err = None;
 
-    let connections = stream::iter(addrs.connect_instruction()).then(connect_to_addr);
+    let connections =
+        stream::iter(addrs.connect_instruction()).then(|addr| connect_to_addr(addr, resolver));
 
     // Pinning the stream lets us use `find_map` below without boxing.
     pin!(connections);
@@ -50,9 +97,12 @@ pub(super) async fn connect(
 }
 
 /// Connect to a `DefaultStream` asynchronously.
-async fn connect_to_addr(addr: ConnectAddress<'_>) -> io::Result<(DefaultStream, PeerAddr)> {
+async fn connect_to_addr(
+    addr: ConnectAddress<'_>,
+    resolver: &dyn Resolver,
+) -> io::Result<(DefaultStream, PeerAddr)> {
     let start = Instant::now();
-    match connect_to_addr_impl(&addr).await {
+    match connect_to_addr_impl(&addr, resolver).await {
         Ok(result) => {
             tracing::trace!(
                 "Connected to X11 server via {:?} in {:?}",
@@ -68,35 +118,20 @@ async fn connect_to_addr(addr: ConnectAddress<'_>) -> io::Result<(DefaultStream,
     }
 }
 
-async fn connect_to_addr_impl(addr: &ConnectAddress<'_>) -> io::Result<(DefaultStream, PeerAddr)> {
+async fn connect_to_addr_impl(
+    addr: &ConnectAddress<'_>,
+    resolver: &dyn Resolver,
+) -> io::Result<(DefaultStream, PeerAddr)> {
     match addr {
         ConnectAddress::Hostname(host, port) => {
-            let mut err = None;
-
             // Resolve the hostname.
-            let streams = resolve_host(host)
-                .await?
-                .then(|ip_addr| Async::<TcpStream>::connect((ip_addr, *port)))
-                .map(|result| {
-                    result.and_then(|stream| DefaultStream::from_tcp_stream(stream.into_inner()?))
-                });
-
-            pin!(streams);
-
-            streams
-                .find_map(|result| match result {
-                    Ok(stream) => Some(stream),
-                    Err(e) => {
-                        err = Some(e);
-                        None
-                    }
-                })
-                .await
-                .ok_or_else(|| {
-                    err.unwrap_or_else(|| {
-                        io::Error::new(io::ErrorKind::Other, "no address resolved")
-                    })
-                })
+            let addrs = interleave_families(resolve_host(host, resolver).await?)
+                .into_iter()
+                .map(|ip_addr| SocketAddr::new(ip_addr, *port))
+                .collect();
+
+            let stream = happy_eyeballs(addrs).await?;
+            DefaultStream::from_tcp_stream(stream.into_inner()?)
         }
 
         #[cfg(unix)]
@@ -119,47 +154,144 @@ async fn connect_to_addr_impl(addr: &ConnectAddress<'_>) -> io::Result<(DefaultS
 }
 
 /// Resolve the address asynchronously.
-async fn resolve_host(host: &str) -> io::Result<impl Stream<Item = IpAddr>> {
-    enum Either<A, B> {
-        Left(A),
-        Right(B),
+async fn resolve_host(host: &str, resolver: &dyn Resolver) -> io::Result<Vec<IpAddr>> {
+    // We can avoid using the resolver if we can resolve the host synchronously.
+    if let Ok(ipv4) = host.parse::<Ipv4Addr>() {
+        return Ok(vec![IpAddr::V4(ipv4)]);
     }
 
-    impl<A: Stream + Unpin, B: Stream<Item = A::Item> + Unpin> Stream for Either<A, B> {
-        type Item = A::Item;
+    if let Ok(ipv6) = host.parse::<Ipv6Addr>() {
+        return Ok(vec![IpAddr::V6(ipv6)]);
+    }
 
-        fn poll_next(
-            self: std::pin::Pin<&mut Self>,
-            cx: &mut std::task::Context<'_>,
-        ) -> std::task::Poll<Option<Self::Item>> {
-            match self.get_mut() {
-                Left(a) => a.poll_next(cx),
-                Right(b) => b.poll_next(cx),
-            }
+    resolver.resolve(host).await
+}
+
+/// Sort the addresses so that the address families alternate.
+///
+/// The family of the first address is kept first. Apart from that, the relative order of
+/// addresses of the same family is preserved. This is the ordering recommended by RFC 8305.
+fn interleave_families(addrs: Vec<IpAddr>) -> Vec<IpAddr> {
+    let first_is_v6 = matches!(addrs.first(), Some(IpAddr::V6(_)));
+    let (preferred, other): (Vec<_>, Vec<_>) = addrs
+        .into_iter()
+        .partition(|addr| addr.is_ipv6() == first_is_v6);
+
+    let mut result = Vec::with_capacity(preferred.len() + other.len());
+    let (mut preferred, mut other) = (preferred.into_iter(), other.into_iter());
+    loop {
+        match (preferred.next(), other.next()) {
+            (None, None) => return result,
+            (a, b) => result.extend(a.into_iter().chain(b)),
         }
     }
+}
 
-    use Either::{Left, Right};
+/// Connect to one of the given addresses via TCP.
+///
+/// The connection attempts are started one after another, but a new attempt is started before
+/// the previous one failed if it did not succeed within [`CONNECTION_ATTEMPT_DELAY`]. When an
+/// attempt fails, the next one is started immediately. The first connection that is established
+/// is returned. This is "Happy Eyeballs" as described in RFC 8305.
+async fn happy_eyeballs(addrs: Vec<SocketAddr>) -> io::Result<Async<TcpStream>> {
+    race_attempts(addrs, |addr| Box::pin(Async::<TcpStream>::connect(addr))).await
+}
 
-    // We can avoid using the threadpool if we can resolve the host synchronously.
-    if let Ok(ipv4) = host.parse::<Ipv4Addr>() {
-        return Ok(Left(stream::once(IpAddr::V4(ipv4))));
-    }
+type Attempt<T> = Pin<Box<dyn Future<Output = io::Result<T>> + Send>>;
 
-    if let Ok(ipv6) = host.parse::<Ipv6Addr>() {
-        return Ok(Left(stream::once(IpAddr::V6(ipv6))));
-    }
+/// The implementation of [`happy_eyeballs`], with `connect` starting a connection attempt.
+async fn race_attempts<T, F>(addrs: Vec<SocketAddr>, mut connect: F) -> io::Result<T>
+where
+    F: FnMut(SocketAddr) -> Attempt<T>,
+{
+    let mut remaining = addrs.into_iter();
+    let mut attempts: Vec<Attempt<T>> = Vec::new();
+    let mut timer = Timer::never();
+    let mut err = None;
 
-    // Resolve the host using the threadpool.
-    let host = format!("{}:0", host);
-    let iter = blocking::unblock(move || {
-        use std::net::ToSocketAddrs;
+    future::poll_fn(|cx| loop {
+        // Check the connection attempts that are in progress.
+        let mut failed = false;
+        let mut i = 0;
+        while i < attempts.len() {
+            match attempts[i].as_mut().poll(cx) {
+                Poll::Ready(Ok(stream)) => return Poll::Ready(Ok(stream)),
+                Poll::Ready(Err(e)) => {
+                    tracing::debug!("Connection attempt failed: {:?}", e);
+                    err = Some(e);
+                    failed = true;
+                    drop(attempts.swap_remove(i));
+                }
+                Poll::Pending => i += 1,
+            }
+        }
 
-        host.to_socket_addrs()
-            .map(|socket| socket.map(|socket| socket.ip()))
+        // Start another attempt if an attempt failed or if the delay expired.
+        if !failed && !attempts.is_empty() && timer.poll(cx).is_pending() {
+            return Poll::Pending;
+        }
+        match remaining.next() {
+            Some(addr) => {
+                tracing::trace!("Trying to connect to {}", addr);
+                attempts.push(connect(addr));
+                timer.set_after(CONNECTION_ATTEMPT_DELAY);
+            }
+            None if attempts.is_empty() => {
+                return Poll::Ready(Err(err.take().unwrap_or_else(|| {
+                    io::Error::new(io::ErrorKind::Other, "no address resolved")
+                })));
+            }
+            None => {
+                timer = Timer::never();
+                return Poll::Pending;
+            }
+        }
     })
-    .await?;
+    .await
+}
+
+#[cfg(test)]
+mod test {
+    use super::{interleave_families, race_attempts, Attempt, CONNECTION_ATTEMPT_DELAY};
+    use futures_lite::future;
+    use std::io;
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+    use std::time::Instant;
 
-    // Push the remaining addresses into a stream.
-    Ok(Right(blocking::Unblock::with_capacity(1, iter)))
+    #[test]
+    fn interleave() {
+        let v4 = |n| IpAddr::V4(Ipv4Addr::new(10, 0, 0, n));
+        let v6 = |n| IpAddr::V6(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, n));
+
+        assert!(interleave_families(Vec::new()).is_empty());
+        assert_eq!(
+            interleave_families(vec![v6(1), v6(2), v6(3), v4(1), v4(2)]),
+            vec![v6(1), v4(1), v6(2), v4(2), v6(3)]
+        );
+        assert_eq!(
+            interleave_families(vec![v4(1), v6(1), v6(2), v4(2)]),
+            vec![v4(1), v6(1), v4(2), v6(2)]
+        );
+    }
+
+    #[test]
+    fn next_attempt_after_failure() {
+        // The first attempt hangs, the second one fails, the third one succeeds
+        let addrs = (1..=3)
+            .map(|port| SocketAddr::new(Ipv4Addr::LOCALHOST.into(), port))
+            .collect();
+        let connect = |addr: SocketAddr| -> Attempt<u16> {
+            match addr.port() {
+                1 => Box::pin(future::pending()),
+                2 => Box::pin(future::ready(Err(io::ErrorKind::ConnectionRefused.into()))),
+                port => Box::pin(future::ready(Ok(port))),
+            }
+        };
+
+        let start = Instant::now();
+        let port = async_io::block_on(race_attempts(addrs, connect)).unwrap();
+        assert_eq!(port, 3);
+        // Only the first attempt had to wait for the delay
+        assert!(start.elapsed() < CONNECTION_ATTEMPT_DELAY * 2);
+    }
 }
@@ -1,21 +1,32 @@
 //! The state of the connection that is shared with the reading future
 
-use event_listener::Event;
+use event_listener::{Event, EventListener};
 use futures_lite::future;
 use std::convert::Infallible;
 use std::io;
 use std::mem;
 use std::sync::{
-    atomic::{AtomicBool, Ordering},
+    atomic::{AtomicBool, AtomicUsize, Ordering},
     Arc, Mutex as StdMutex, MutexGuard as StdMutexGuard,
 };
 use x11rb::errors::ConnectionError;
-use x11rb_protocol::connection::Connection as ProtoConnection;
+use x11rb_protocol::connection::{Connection as ProtoConnection, ReplyFdKind};
 use x11rb_protocol::packet_reader::PacketReader as ProtoPacketReader;
-use x11rb_protocol::RawFdContainer;
+use x11rb_protocol::{DiscardMode, RawFdContainer};
 
+use super::detached::DetachedQueue;
+use super::hooks::Hooks;
+use super::write_buffer::{WriteBuffer, WriteBufferGuard};
 use super::Stream;
 
+/// The default size of the read buffer.
+///
+/// This size was chosen by checking what libxcb does.
+const DEFAULT_READ_BUFFER_SIZE: usize = 4096;
+
+/// The minimum size of the read buffer, which is the size of the smallest X11 packet.
+pub(super) const MIN_READ_BUFFER_SIZE: usize = 32;
+
 /// State shared between the `RustConnection` and the future polling for new packets.
 #[derive(Debug)]
 pub(super) struct SharedState<S> {
@@ -27,11 +38,68 @@ pub(super) struct SharedState<S> {
     /// The stream for communicating with the X11 server.
     pub(super) stream: S,
 
+    /// The write buffer.
+    ///
+    /// Holding this lock implies the exclusive right to write to the stream.
+    pub(super) write_buffer: WriteBuffer,
+
+    /// Requests that were queued via a `DetachedSender`.
+    pub(super) detached: Arc<DetachedQueue>,
+
     /// Listener for when new data is available on the stream.
     new_input: Event,
 
     /// Flag that indicates that the future for drive() was dropped and we no longer read input.
     driver_dropped: AtomicBool,
+
+    /// Notification for when the connection to the X11 server was lost.
+    pub(super) disconnect: Arc<Disconnect>,
+
+    /// The size of the buffer that `drive()` reads into.
+    pub(super) read_buffer_size: AtomicUsize,
+
+    /// The hooks that observe the traffic on the connection.
+    pub(super) hooks: Hooks,
+}
+
+/// Notification for when the connection to the X11 server was lost.
+#[derive(Debug, Default)]
+pub(super) struct Disconnect {
+    /// Flag that indicates that the connection was lost.
+    disconnected: AtomicBool,
+
+    /// Notified when the connection is lost.
+    event: Event,
+}
+
+impl Disconnect {
+    /// Check whether the connection was lost.
+    pub(super) fn is_disconnected(&self) -> bool {
+        self.disconnected.load(Ordering::SeqCst)
+    }
+
+    /// Wait until the connection is lost.
+    pub(super) async fn wait(&self) {
+        loop {
+            if self.is_disconnected() {
+                return;
+            }
+
+            // Register a listener and check again to avoid missing a notification.
+            let listener = self.event.listen();
+            if self.is_disconnected() {
+                return;
+            }
+
+            listener.await;
+        }
+    }
+
+    /// Mark the connection as lost and wake up everyone waiting for this.
+    fn notify(&self) {
+        self.disconnected.store(true, Ordering::SeqCst);
+        let _num_notified = self.event.notify_additional(usize::MAX);
+    }
 }
 
 impl<S: Stream> SharedState<S> {
@@ -39,8 +107,13 @@ impl<S: Stream> SharedState<S> {
         Self {
             inner: Default::default(),
             stream,
+            write_buffer: Default::default(),
+            detached: Default::default(),
             new_input: Event::new(),
             driver_dropped: AtomicBool::new(false),
+            disconnect: Default::default(),
+            read_buffer_size: AtomicUsize::new(DEFAULT_READ_BUFFER_SIZE),
+            hooks: Default::default(),
         }
     }
 
@@ -86,21 +159,180 @@ impl<S: Stream> SharedState<S> {
         }
     }
 
+    /// Add a locally constructed event to the event queue and wake up everyone waiting for input.
+    pub(super) fn enqueue_synthetic_event(&self, event: Vec<u8>) {
+        self.lock_connection().enqueue_synthetic_event(event);
+        let _num_notified = self.new_input.notify_additional(usize::MAX);
+    }
+
+    /// Register a listener that is notified when new packets were received.
+    ///
+    /// This is a building block for waiting with a check that needs to `.await`, which
+    /// [`SharedState::wait_for_incoming`] does not support. The check must be done after
+    /// registering the listener and before calling [`SharedState::check_driver`].
+    pub(super) fn listen_for_input(&self) -> EventListener {
+        self.new_input.listen()
+    }
+
+    /// Check that the future from drive() still reads input.
+    pub(super) fn check_driver(&self) -> Result<(), io::Error> {
+        if self.driver_dropped.load(Ordering::SeqCst) {
+            Err(io::Error::new(
+                io::ErrorKind::Other,
+                "Driving future was dropped",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Send a request that catches us up to the current sequence number.
+    pub(super) async fn send_sync<'a>(
+        &'a self,
+        mut buffer: WriteBufferGuard<'a>,
+    ) -> Result<WriteBufferGuard<'a>, ConnectionError> {
+        let request = sync_request();
+        let iov = &[io::IoSlice::new(&request)];
+
+        // Send this request.
+        {
+            let mut inner = self.lock_connection();
+            let seq = inner
+                .send_request(ReplyFdKind::ReplyWithoutFDs)
+                .expect("This request should not be blocked by syncs");
+            inner.discard_reply(seq, DiscardMode::DiscardReplyAndError);
+            self.hooks.on_request(seq, iov);
+        };
+
+        // Write the entire packet.
+        let mut fds = Vec::new();
+        buffer
+            .write_all_vectored(&self.stream, iov, &mut fds)
+            .await?;
+        Ok(buffer)
+    }
+
+    /// Write all requests that were queued via a `DetachedSender` into the write buffer.
+    pub(super) async fn send_detached_requests<'a>(
+        &'a self,
+        mut buffer: WriteBufferGuard<'a>,
+    ) -> Result<WriteBufferGuard<'a>, ConnectionError> {
+        // Take the requests one at a time, so that a failure does not lose the others
+        while let Some(mut request) = self.detached.pop() {
+            loop {
+                let sent = {
+                    let mut inner = self.lock_connection();
+                    match inner.send_request(ReplyFdKind::NoReply) {
+                        Some(seq) => {
+                            inner.discard_reply(seq, DiscardMode::DiscardReplyAndError);
+                            self.hooks
+                                .on_request(seq, &[io::IoSlice::new(&request.data)]);
+                            true
+                        }
+                        None => false,
+                    }
+                };
+                if sent {
+                    break;
+                }
+                tracing::trace!("Syncing with the X11 server since there are too many outstanding void requests");
+                buffer = self.send_sync(buffer).await?;
+            }
+
+            tracing::debug!("Sending detached request");
+            let iov = &[io::IoSlice::new(&request.data)];
+            buffer
+                .write_all_vectored(&self.stream, iov, &mut request.fds)
+                .await?;
+        }
+        Ok(buffer)
+    }
+
+    /// Move queued detached requests into the write buffer without waiting for the stream.
+    ///
+    /// Requests that do not fit into the buffer stay queued. Returns whether a request was moved.
+    fn buffer_detached_requests(&self, buffer: &mut WriteBufferGuard<'_>) -> bool {
+        let mut moved = false;
+        while let Some(mut request) = self.detached.pop() {
+            // Leave room for a sync, so that the request fits either way. Requests that never fit
+            // are sent by the next flush.
+            let sync = sync_request();
+            if !buffer.has_room(sync.len() + request.data.len()) {
+                self.detached.push_front(request);
+                break;
+            }
+            {
+                let mut inner = self.lock_connection();
+                let seq = match inner.send_request(ReplyFdKind::NoReply) {
+                    Some(seq) => seq,
+                    None => {
+                        tracing::trace!("Syncing with the X11 server since there are too many outstanding void requests");
+                        let seq = inner
+                            .send_request(ReplyFdKind::ReplyWithoutFDs)
+                            .expect("This request should not be blocked by syncs");
+                        inner.discard_reply(seq, DiscardMode::DiscardReplyAndError);
+                        self.hooks.on_request(seq, &[io::IoSlice::new(&sync)]);
+                        buffer.append(&sync, &mut Vec::new());
+                        inner
+                            .send_request(ReplyFdKind::NoReply)
+                            .expect("A sync makes room for a new request")
+                    }
+                };
+                inner.discard_reply(seq, DiscardMode::DiscardReplyAndError);
+                self.hooks
+                    .on_request(seq, &[io::IoSlice::new(&request.data)]);
+            }
+            tracing::debug!("Sending detached request");
+            buffer.append(&request.data, &mut request.fds);
+            moved = true;
+        }
+        moved
+    }
+
+    /// Send queued detached requests if this is possible without waiting.
+    ///
+    /// This is used by the future that drives the connection. It must never wait for the write
+    /// buffer or for the stream to become writable, since no replies would be read in the
+    /// meantime. If the write buffer is locked, the requests are left for its holder, since every
+    /// request that is sent on the connection also sends queued detached requests.
+    ///
+    /// `pending` is the result of the previous call. Returns `true` if detached requests are still
+    /// waiting for the stream to become writable.
+    fn try_send_detached_requests(&self, pending: bool) -> Result<bool, ConnectionError> {
+        let mut buffer = match self.write_buffer.try_lock() {
+            Some(buffer) => buffer?,
+            None => return Ok(pending),
+        };
+        if !self.buffer_detached_requests(&mut buffer) && !pending {
+            buffer.unlock();
+            return Ok(false);
+        }
+        let result = buffer.try_flush(&self.stream);
+        let pending = buffer.has_data();
+        buffer.unlock();
+        result?;
+        Ok(pending)
+    }
+
     /// Read incoming packets from the stream and put them into the inner connection.
+    ///
+    /// This also sends requests that were queued via a `DetachedSender`.
     pub(super) async fn drive(
         &self,
         _break_on_drop: BreakOnDrop<S>,
     ) -> Result<Infallible, ConnectionError> {
         let mut packet_reader = PacketReader {
-            read_buffer: vec![0; 4096].into_boxed_slice(),
+            read_buffer: vec![0; DEFAULT_READ_BUFFER_SIZE].into_boxed_slice(),
             inner: ProtoPacketReader::new(),
         };
         let mut fds = vec![];
         let mut packets = vec![];
+        let mut pending = false;
 
         loop {
             for _ in 0..50 {
                 // Try to read packets from the stream.
+                packet_reader.set_buffer_size(self.read_buffer_size.load(Ordering::Relaxed));
                 packet_reader.try_read_packets(&self.stream, &mut packets, &mut fds)?;
                 let packet_count = packets.len();
 
@@ -108,6 +340,7 @@ impl<S: Stream> SharedState<S> {
                 {
                     let mut inner = self.inner.lock().unwrap();
                     inner.enqueue_fds(mem::take(&mut fds));
+                    self.hooks.on_packets(&inner, &packets);
                     packets
                         .drain(..)
                         .for_each(|packet| inner.enqueue_packet(packet));
@@ -116,10 +349,25 @@ impl<S: Stream> SharedState<S> {
                 if packet_count > 0 {
                     // Notify any listeners that there is new data.
                     let _num_notified = self.new_input.notify_additional(usize::MAX);
-                } else {
-                    // Wait for more data.
-                    self.stream.readable().await?;
+                    continue;
                 }
+
+                // Register a listener for detached requests before checking for them.
+                let listener = self.detached.listen();
+                pending = self.try_send_detached_requests(pending)?;
+
+                // Wait for more data, for a detached request or for the chance to write the
+                // pending ones. Reading must continue while waiting, or else the X11 server
+                // might stop reading from us since we do not read its output.
+                future::or(async { self.stream.readable().await }, async {
+                    if pending {
+                        self.stream.writable().await
+                    } else {
+                        listener.await;
+                        Ok(())
+                    }
+                })
+                .await?;
             }
 
             // In the case of a large influx of packets, don't starve other tasks.
@@ -128,6 +376,17 @@ impl<S: Stream> SharedState<S> {
     }
 }
 
+/// Get a `GetInputFocus` request, which is used to sync with the X11 server.
+fn sync_request() -> [u8; 4] {
+    let length = 1u16.to_ne_bytes();
+    [
+        x11rb_protocol::protocol::xproto::GET_INPUT_FOCUS_REQUEST,
+        0,
+        length[0],
+        length[1],
+    ]
+}
+
 #[derive(Debug)]
 struct PacketReader {
     /// The read buffer to store incoming bytes in.
@@ -138,6 +397,14 @@ struct PacketReader {
 }
 
 impl PacketReader {
+    /// Change the size of the read buffer.
+    fn set_buffer_size(&mut self, size: usize) {
+        if self.read_buffer.len() != size {
+            tracing::debug!("Changing the read buffer size to {} bytes", size);
+            self.read_buffer = vec![0; size].into_boxed_slice();
+        }
+    }
+
     /// Try to read packets from the stream.
     fn try_read_packets(
         &mut self,
@@ -222,6 +489,12 @@ impl<S> Drop for BreakOnDrop<S> {
         // Mark the connection as broken
         self.0.driver_dropped.store(true, Ordering::SeqCst);
 
+        // Nobody will send detached requests anymore
+        self.0.detached.close();
+
+        // Tell everyone interested that the connection is gone
+        self.0.disconnect.notify();
+
         // Wake up everyone that might be waiting
         let _num_notified = self.0.new_input.notify_additional(usize::MAX);
     }
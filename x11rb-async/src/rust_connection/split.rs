@@ -0,0 +1,292 @@
+//! Splitting a connection into a half for sending requests and a half for receiving events.
+
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::connection::{Connection, Fut, RequestConnection};
+use crate::{Cookie, CookieWithFds, VoidCookie};
+
+use x11rb::connection::{BufWithFds, EventAndSeqNumber, ReplyOrError, RequestKind};
+use x11rb::errors::{ConnectionError, ParseError, ReplyOrIdError};
+use x11rb_protocol::connection::ReplyFdKind;
+use x11rb_protocol::protocol::xproto::Setup;
+use x11rb_protocol::protocol::Event;
+use x11rb_protocol::x11_utils::{ExtensionInformation, TryParse, TryParseFd, X11Error};
+use x11rb_protocol::{DiscardMode, RawEventAndSeqNumber, RawFdContainer, SequenceNumber};
+
+use super::{RustConnection, Stream};
+
+impl<S: Stream + Send + Sync> RustConnection<S> {
+    /// Split this connection into a half for sending requests and a half for receiving events.
+    ///
+    /// The two halves can be moved to different tasks. For example, one task can draw to a
+    /// window while another task handles input events. The [`RequestSender`] can be cloned so
+    /// that multiple tasks can send requests.
+    ///
+    /// The future that drives the connection still has to be polled.
+    pub fn split(self) -> (RequestSender<S>, EventReceiver<S>) {
+        let conn = Arc::new(self);
+        (RequestSender(Arc::clone(&conn)), EventReceiver(conn))
+    }
+}
+
+/// The half of a split [`RustConnection`] that sends requests.
+///
+/// This implements [`RequestConnection`], so all the request functions from
+/// [`crate::protocol`] can be used with it.
+///
+/// See [`RustConnection::split`].
+#[derive(Debug)]
+pub struct RequestSender<S = super::DefaultStream>(Arc<RustConnection<S>>);
+
+/// The half of a split [`RustConnection`] that receives events.
+///
+/// See [`RustConnection::split`].
+#[derive(Debug)]
+pub struct EventReceiver<S = super::DefaultStream>(Arc<RustConnection<S>>);
+
+impl<S> Clone for RequestSender<S> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<S: Stream + Send + Sync> RequestSender<S> {
+    /// Put the two halves of a connection back together.
+    ///
+    /// This fails if the two halves belong to different connections or if there are other
+    /// clones of this `RequestSender`. In this case, the arguments are returned.
+    pub fn reunite(
+        self,
+        receiver: EventReceiver<S>,
+    ) -> Result<RustConnection<S>, (RequestSender<S>, EventReceiver<S>)> {
+        if !Arc::ptr_eq(&self.0, &receiver.0) {
+            return Err((self, receiver));
+        }
+        drop(receiver);
+        Arc::try_unwrap(self.0)
+            .map_err(|conn| (RequestSender(Arc::clone(&conn)), EventReceiver(conn)))
+    }
+
+    /// Get the setup information sent by the X11 server.
+    pub fn setup(&self) -> &Setup {
+        self.0.setup()
+    }
+
+    /// Send all pending requests to the server.
+    pub async fn flush(&self) -> Result<(), ConnectionError> {
+        self.0.flush().await
+    }
+
+    /// Generate a new X11 identifier.
+    ///
+    /// See [`Connection::generate_id`].
+    pub async fn generate_id(&self) -> Result<u32, ReplyOrIdError> {
+        self.0.generate_id().await
+    }
+}
+
+impl<S: Stream + Send + Sync> RequestConnection for RequestSender<S> {
+    type Buf = Vec<u8>;
+
+    fn send_request_with_reply<'this, 'bufs, 'sl, 're, 'future, R>(
+        &'this self,
+        bufs: &'bufs [io::IoSlice<'sl>],
+        fds: Vec<RawFdContainer>,
+    ) -> Fut<'future, Cookie<'this, Self, R>, ConnectionError>
+    where
+        'this: 'future,
+        'bufs: 'future,
+        'sl: 'future,
+        're: 'future,
+        R: TryParse + Send + 're,
+    {
+        Box::pin(async move {
+            let seq = self
+                .0
+                .send_request(bufs, fds, ReplyFdKind::ReplyWithoutFDs)
+                .await?;
+
+            Ok(Cookie::new(self, seq))
+        })
+    }
+
+    fn send_request_with_reply_with_fds<'this, 'bufs, 'sl, 're, 'future, R>(
+        &'this self,
+        bufs: &'bufs [io::IoSlice<'sl>],
+        fds: Vec<RawFdContainer>,
+    ) -> Fut<'future, CookieWithFds<'this, Self, R>, ConnectionError>
+    where
+        'this: 'future,
+        'bufs: 'future,
+        'sl: 'future,
+        're: 'future,
+        R: TryParseFd + Send + 're,
+    {
+        Box::pin(async move {
+            let seq = self
+                .0
+                .send_request(bufs, fds, ReplyFdKind::ReplyWithFDs)
+                .await?;
+
+            Ok(CookieWithFds::new(self, seq))
+        })
+    }
+
+    fn send_request_without_reply<'this, 'bufs, 'sl, 'future>(
+        &'this self,
+        bufs: &'bufs [io::IoSlice<'sl>],
+        fds: Vec<RawFdContainer>,
+    ) -> Fut<'future, VoidCookie<'this, Self>, ConnectionError>
+    where
+        'this: 'future,
+        'bufs: 'future,
+        'sl: 'future,
+    {
+        Box::pin(async move {
+            let seq = self.0.send_request(bufs, fds, ReplyFdKind::NoReply).await?;
+
+            Ok(VoidCookie::new(self, seq))
+        })
+    }
+
+    fn discard_reply(&self, sequence: SequenceNumber, kind: RequestKind, mode: DiscardMode) {
+        self.0.discard_reply(sequence, kind, mode)
+    }
+
+    fn prefetch_extension_information(&self, name: &'static str) -> Fut<'_, (), ConnectionError> {
+        self.0.prefetch_extension_information(name)
+    }
+
+    fn prefetch_all_extensions<'this, 'names, 'future>(
+        &'this self,
+        names: &'names [&'static str],
+    ) -> Fut<'future, (), ConnectionError>
+    where
+        'this: 'future,
+        'names: 'future,
+    {
+        self.0.prefetch_all_extensions(names)
+    }
+
+    fn extension_information(
+        &self,
+        name: &'static str,
+    ) -> Fut<'_, Option<ExtensionInformation>, ConnectionError> {
+        self.0.extension_information(name)
+    }
+
+    fn wait_for_reply_or_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Fut<'_, ReplyOrError<Self::Buf>, ConnectionError> {
+        self.0.wait_for_reply_or_raw_error(sequence)
+    }
+
+    fn wait_for_replies_or_raw_errors<'this, 'seq, 'future>(
+        &'this self,
+        sequences: &'seq [SequenceNumber],
+    ) -> Fut<'future, Vec<ReplyOrError<Self::Buf>>, ConnectionError>
+    where
+        'this: 'future,
+        'seq: 'future,
+    {
+        self.0.wait_for_replies_or_raw_errors(sequences)
+    }
+
+    fn wait_for_reply(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Fut<'_, Option<Self::Buf>, ConnectionError> {
+        self.0.wait_for_reply(sequence)
+    }
+
+    fn wait_for_reply_with_fds_raw(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Fut<'_, ReplyOrError<BufWithFds<Self::Buf>, Self::Buf>, ConnectionError> {
+        self.0.wait_for_reply_with_fds_raw(sequence)
+    }
+
+    fn check_for_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Fut<'_, Option<Self::Buf>, ConnectionError> {
+        self.0.check_for_raw_error(sequence)
+    }
+
+    fn prefetch_maximum_request_bytes(&self) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        self.0.prefetch_maximum_request_bytes()
+    }
+
+    fn maximum_request_bytes(&self) -> Pin<Box<dyn Future<Output = usize> + Send + '_>> {
+        self.0.maximum_request_bytes()
+    }
+
+    fn parse_error(&self, error: &[u8]) -> Result<X11Error, ParseError> {
+        self.0.parse_error(error)
+    }
+
+    fn parse_event(&self, event: &[u8]) -> Result<Event, ParseError> {
+        self.0.parse_event(event)
+    }
+}
+
+impl<S: Stream + Send + Sync> EventReceiver<S> {
+    /// Get a future that completes when the connection to the X11 server is lost.
+    ///
+    /// See [`RustConnection::on_disconnect`].
+    pub fn on_disconnect(&self) -> impl Future<Output = ()> + Send + 'static {
+        self.0.on_disconnect()
+    }
+
+    /// Wait for a new event from the X11 server.
+    ///
+    /// See [`Connection::wait_for_event`].
+    pub async fn wait_for_event(&self) -> Result<Event, ConnectionError> {
+        self.0.wait_for_event().await
+    }
+
+    /// Wait for a new event from the X11 server and return it together with its sequence number.
+    ///
+    /// See [`Connection::wait_for_event_with_sequence`].
+    pub async fn wait_for_event_with_sequence(&self) -> Result<EventAndSeqNumber, ConnectionError> {
+        self.0.wait_for_event_with_sequence().await
+    }
+
+    /// Wait for a new raw/unparsed event from the X11 server.
+    ///
+    /// See [`Connection::wait_for_raw_event_with_sequence`].
+    pub async fn wait_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<RawEventAndSeqNumber<Vec<u8>>, ConnectionError> {
+        self.0.wait_for_raw_event_with_sequence().await
+    }
+
+    /// Poll for a new event from the X11 server.
+    ///
+    /// See [`Connection::poll_for_event`].
+    pub fn poll_for_event(&self) -> Result<Option<Event>, ConnectionError> {
+        self.0.poll_for_event()
+    }
+
+    /// Poll for a new event from the X11 server and return it together with its sequence number.
+    ///
+    /// See [`Connection::poll_for_event_with_sequence`].
+    pub fn poll_for_event_with_sequence(
+        &self,
+    ) -> Result<Option<EventAndSeqNumber>, ConnectionError> {
+        self.0.poll_for_event_with_sequence()
+    }
+
+    /// Poll for a new raw/unparsed event from the X11 server.
+    ///
+    /// See [`Connection::poll_for_raw_event_with_sequence`].
+    pub fn poll_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<Option<RawEventAndSeqNumber<Vec<u8>>>, ConnectionError> {
+        self.0.poll_for_raw_event_with_sequence()
+    }
+}
@@ -52,6 +52,11 @@ impl<S: AsFd> StreamAdaptor<S> {
     pub fn new(stream: S) -> io::Result<Self> {
         Async::new(stream).map(|inner| Self { inner })
     }
+
+    /// Get a reference to the wrapped stream.
+    pub fn get_ref(&self) -> &S {
+        self.inner.get_ref()
+    }
 }
 
 /// A future for reading from a [`StreamAdaptor`].
@@ -0,0 +1,151 @@
+//! Routing the events of individual windows into dedicated mailboxes.
+
+use std::collections::VecDeque;
+use std::sync::Mutex as StdMutex;
+
+use x11rb::protocol::Event;
+use x11rb_protocol::connection::Connection as ProtoConnection;
+use x11rb_protocol::protocol::xproto::Window;
+use x11rb_protocol::x11_utils::ExtInfoProvider;
+
+/// What happens when the mailbox of [`RustConnection::window_events`] is full.
+///
+/// The mailbox is filled while the main event queue of the connection is read, so the reader
+/// cannot be blocked until the owner of the mailbox catches up. Instead, events are dropped.
+///
+/// [`RustConnection::window_events`]: super::RustConnection::window_events
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backpressure {
+    /// The mailbox grows without limit.
+    Unbounded,
+
+    /// The mailbox holds at most this many events. The oldest event is dropped for a new one.
+    DropOldest(usize),
+
+    /// The mailbox holds at most this many events. New events are dropped while it is full.
+    DropNewest(usize),
+}
+
+/// A mailbox for the events of one window.
+#[derive(Debug)]
+struct Mailbox {
+    /// The identifier of the registration.
+    id: usize,
+
+    /// The window whose events end up in this mailbox.
+    window: Window,
+
+    /// What to do when the mailbox is full.
+    policy: Backpressure,
+
+    /// The events that were routed to this mailbox.
+    events: VecDeque<Event>,
+}
+
+impl Mailbox {
+    fn push(&mut self, event: Event) {
+        match self.policy {
+            Backpressure::Unbounded => {}
+            Backpressure::DropOldest(capacity) => {
+                if capacity == 0 {
+                    return;
+                }
+                while self.events.len() >= capacity {
+                    let _ = self.events.pop_front();
+                }
+            }
+            Backpressure::DropNewest(capacity) => {
+                if self.events.len() >= capacity {
+                    return;
+                }
+            }
+        }
+        self.events.push_back(event);
+    }
+}
+
+/// The registered mailboxes of a connection.
+#[derive(Debug, Default)]
+pub(super) struct WindowRouter {
+    inner: StdMutex<RouterInner>,
+}
+
+#[derive(Debug, Default)]
+struct RouterInner {
+    /// The identifier for the next registration.
+    next_id: usize,
+
+    /// All registered mailboxes.
+    mailboxes: Vec<Mailbox>,
+}
+
+impl WindowRouter {
+    /// Register a new mailbox for the events of `window`.
+    pub(super) fn register(&self, window: Window, policy: Backpressure) -> usize {
+        let mut inner = self.inner.lock().unwrap();
+        let id = inner.next_id;
+        inner.next_id += 1;
+        inner.mailboxes.push(Mailbox {
+            id,
+            window,
+            policy,
+            events: VecDeque::new(),
+        });
+        id
+    }
+
+    /// Remove a mailbox together with the events in it.
+    pub(super) fn unregister(&self, id: usize) {
+        self.inner
+            .lock()
+            .unwrap()
+            .mailboxes
+            .retain(|mailbox| mailbox.id != id);
+    }
+
+    /// Take the next event out of a mailbox.
+    pub(super) fn pop(&self, id: usize) -> Option<Event> {
+        self.inner
+            .lock()
+            .unwrap()
+            .mailboxes
+            .iter_mut()
+            .find(|mailbox| mailbox.id == id)?
+            .events
+            .pop_front()
+    }
+
+    /// Move the events for registered windows from the main event queue into their mailboxes.
+    ///
+    /// If several mailboxes are registered for the same window, each of them gets a copy.
+    pub(super) fn route(&self, conn: &mut ProtoConnection, ext_info: &dyn ExtInfoProvider) {
+        let mut inner = self.inner.lock().unwrap();
+        if inner.mailboxes.is_empty() {
+            return;
+        }
+        let is_routed = |event: &Event| {
+            event.window().map_or(false, |window| {
+                inner
+                    .mailboxes
+                    .iter()
+                    .any(|mailbox| mailbox.window == window)
+            })
+        };
+        let mut routed = Vec::new();
+        while let Some((event, _)) = conn.poll_for_matching_event_with_sequence(|event| {
+            Event::parse(event, ext_info).map_or(false, |event| is_routed(&event))
+        }) {
+            if let Ok(event) = Event::parse(&event, ext_info) {
+                routed.push(event);
+            }
+        }
+        for event in routed {
+            let window = event.window();
+            for mailbox in inner.mailboxes.iter_mut() {
+                if Some(mailbox.window) == window {
+                    mailbox.push(event.clone());
+                }
+            }
+        }
+    }
+}
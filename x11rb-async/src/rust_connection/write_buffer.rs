@@ -54,6 +54,21 @@ impl WriteBuffer {
 
         Ok(WriteBufferGuard(lock))
     }
+
+    /// Lock the write buffer for writing if it is not locked already.
+    ///
+    /// The same rules as for [`WriteBuffer::lock`] apply to the returned guard.
+    pub(super) fn try_lock(&self) -> Option<Result<WriteBufferGuard<'_>, ConnectionError>> {
+        let mut lock = self.0.try_lock()?;
+        if std::mem::replace(&mut lock.corrupted, true) {
+            return Some(Err(ConnectionError::IoError(io::Error::new(
+                io::ErrorKind::Other,
+                "The write buffer was corrupted",
+            ))));
+        }
+
+        Some(Ok(WriteBufferGuard(lock)))
+    }
 }
 
 impl WriteBufferGuard<'_> {
@@ -78,6 +93,51 @@ impl std::ops::DerefMut for WriteBufferGuard<'_> {
 }
 
 impl WriteBufferInner {
+    /// Check whether the buffer contains data that still has to be written.
+    pub(super) fn has_data(&self) -> bool {
+        !self.buffer.is_empty() || !self.fds.is_empty()
+    }
+
+    /// Check whether `len` more bytes fit into the buffer without flushing.
+    pub(super) fn has_room(&self, len: usize) -> bool {
+        self.buffer.len() + len <= self.buffer.capacity()
+    }
+
+    /// Append data to the buffer without flushing.
+    ///
+    /// The caller has to check [`WriteBufferInner::has_room`] first.
+    pub(super) fn append(&mut self, data: &[u8], fds: &mut Vec<RawFdContainer>) {
+        debug_assert!(self.has_room(data.len()));
+        self.buffer.extend_from_slice(data);
+        self.fds.append(fds);
+    }
+
+    /// Write as much of the buffer as possible without waiting for the stream.
+    pub(super) fn try_flush<'b, S: StreamBase<'b>>(
+        &mut self,
+        stream: &'b S,
+    ) -> Result<(), ConnectionError> {
+        let mut position = 0;
+        while position < self.buffer.len() {
+            match stream.write(&self.buffer[position..], &mut self.fds) {
+                Ok(0) => {
+                    return Err(ConnectionError::IoError(io::Error::new(
+                        io::ErrorKind::WriteZero,
+                        "failed to write whole buffer",
+                    )))
+                }
+                Ok(n) => {
+                    tracing::trace!("Opportunistic flush wrote {} bytes of data", n);
+                    position += n;
+                }
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => break,
+                Err(e) => return Err(e.into()),
+            }
+        }
+        let _ = self.buffer.drain(..position);
+        Ok(())
+    }
+
     /// Flush the write buffer.
     pub(super) async fn flush<'b, S: StreamBase<'b>>(
         &mut self,
@@ -1,4 +1,4 @@
-use futures_lite::future::Ready;
+use futures_lite::future::{self, Ready};
 use std::io::IoSlice;
 use std::sync::{Arc, Mutex};
 
@@ -38,6 +38,46 @@ impl StreamBase<'_> for FakeStream {
     }
 }
 
+/// A stream that returns the given data on reads and discards everything that is written.
+#[derive(Debug)]
+struct ReplyStream(Mutex<Vec<u8>>);
+
+impl SyncStream for ReplyStream {
+    fn poll(&self, _: PollMode) -> Result<(), std::io::Error> {
+        unimplemented!()
+    }
+    fn read(&self, buf: &mut [u8], _: &mut Vec<RawFdContainer>) -> Result<usize, std::io::Error> {
+        let mut data = self.0.lock().unwrap();
+        if data.is_empty() {
+            return Err(std::io::ErrorKind::WouldBlock.into());
+        }
+        let amount = buf.len().min(data.len());
+        buf[..amount].copy_from_slice(&data[..amount]);
+        let _ = data.drain(..amount);
+        Ok(amount)
+    }
+    fn write(&self, buf: &[u8], _: &mut Vec<RawFdContainer>) -> Result<usize, std::io::Error> {
+        Ok(buf.len())
+    }
+}
+
+impl StreamBase<'_> for ReplyStream {
+    type Readable = future::Boxed<std::io::Result<()>>;
+    type Writable = Ready<std::io::Result<()>>;
+
+    fn readable(&self) -> Self::Readable {
+        if self.0.lock().unwrap().is_empty() {
+            Box::pin(future::pending())
+        } else {
+            Box::pin(future::ready(Ok(())))
+        }
+    }
+
+    fn writable(&self) -> Self::Writable {
+        future::ready(Ok(()))
+    }
+}
+
 fn make_setup() -> Setup {
     Setup {
         resource_id_mask: (1 << 8) - 1,
@@ -116,3 +156,458 @@ fn retry_for_left_over_fds() {
         e => panic!("Unexpected error: {:?}", e),
     }
 }
+
+#[test]
+fn detached_requests_are_sent_on_flush() {
+    let data = Arc::new(Mutex::new(Vec::new()));
+    let (conn, _driver) =
+        RustConnection::for_connected_stream(FakeStream(Arc::clone(&data)), make_setup()).unwrap();
+
+    // Queue a NoOperation request from synchronous code
+    let sender = conn.detached_sender();
+    let request = x11rb::protocol::xproto::NoOperationRequest;
+    sender.send_request(request, 0).unwrap();
+    assert!(data.lock().unwrap().is_empty());
+
+    // Flushing the connection sends the request
+    async_io::block_on(conn.flush()).unwrap();
+    let length = 1u16.to_ne_bytes();
+    assert_eq!(
+        *data.lock().unwrap(),
+        [
+            x11rb::protocol::xproto::NO_OPERATION_REQUEST,
+            0,
+            length[0],
+            length[1]
+        ]
+    );
+}
+
+#[test]
+fn detached_sender_fails_without_driver() {
+    let (conn, driver) =
+        RustConnection::for_connected_stream(FakeStream::default(), make_setup()).unwrap();
+    let sender = conn.detached_sender();
+    drop(driver);
+
+    let request = x11rb::protocol::xproto::NoOperationRequest;
+    match sender.send_request(request, 0) {
+        Err(ConnectionError::IoError(err)) => {
+            assert_eq!(err.to_string(), "Driving future was dropped")
+        }
+        res => panic!("Unexpected result: {:?}", res),
+    }
+}
+
+#[test]
+fn detached_sender_rejects_big_requests() {
+    let (conn, _driver) =
+        RustConnection::for_connected_stream(FakeStream::default(), make_setup()).unwrap();
+    let sender = conn.detached_sender();
+
+    // A NoOperation request in the BIG-REQUESTS encoding
+    let mut request = vec![127, 0, 0, 0];
+    request.extend(2u32.to_ne_bytes());
+    match sender.send_raw_request(request, Vec::new()) {
+        Err(ConnectionError::MaximumRequestLengthExceeded) => {}
+        res => panic!("Unexpected result: {:?}", res),
+    }
+}
+
+#[test]
+#[should_panic(expected = "must be a non-zero multiple of 4")]
+fn detached_sender_rejects_empty_requests() {
+    let (conn, _driver) =
+        RustConnection::for_connected_stream(FakeStream::default(), make_setup()).unwrap();
+    let _ = conn
+        .detached_sender()
+        .send_raw_request(Vec::new(), Vec::new());
+}
+
+/// Listen on a local TCP port that can be reached as an X11 display.
+///
+/// Returns the listener and the display number, i.e. the port minus 6000.
+fn listen_as_display() -> (std::net::TcpListener, u16) {
+    // Ephemeral ports are usually above 6000, but the OS may hand out lower ones
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    if let Some(display) = port.checked_sub(6000) {
+        return (listener, display);
+    }
+    (6100..7000)
+        .find_map(|port| {
+            let listener = std::net::TcpListener::bind(("127.0.0.1", port)).ok()?;
+            Some((listener, port - 6000))
+        })
+        .expect("No free port for a display")
+}
+
+#[test]
+fn connect_with_timeout() {
+    use std::time::Duration;
+    use x11rb::errors::ConnectError;
+
+    // A server that accepts the TCP connection, but never answers the setup request
+    let (_listener, display) = listen_as_display();
+    let display = format!("127.0.0.1:{}", display);
+
+    let timeout = Duration::from_millis(100);
+    match async_io::block_on(RustConnection::connect_with_timeout(
+        Some(&display),
+        timeout,
+    )) {
+        Err(ConnectError::Timeout) => {}
+        Err(e) => panic!("Unexpected error: {:?}", e),
+        Ok(_) => panic!("Unexpected success"),
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn connect_to_fd() {
+    use std::io::{Read, Write};
+    use x11rb::x11_utils::Serialize;
+
+    let (client, mut server) = std::os::unix::net::UnixStream::pair().unwrap();
+    let server = std::thread::spawn(move || {
+        // Receive a setup request without authentication and answer it
+        let mut request = [0; 12];
+        server.read_exact(&mut request).unwrap();
+        let mut setup = Setup {
+            status: 1,
+            roots: vec![Default::default()],
+            ..make_setup()
+        }
+        .serialize();
+        let length = u16::try_from((setup.len() - 8) / 4).unwrap();
+        setup[6..8].copy_from_slice(&length.to_ne_bytes());
+        server.write_all(&setup).unwrap();
+    });
+
+    let (conn, _driver) = async_io::block_on(RustConnection::connect_to_fd(
+        client.into(),
+        0,
+        Vec::new(),
+        Vec::new(),
+    ))
+    .unwrap();
+    server.join().unwrap();
+    assert_eq!(conn.setup().roots.len(), 1);
+}
+
+#[test]
+fn connect_with_custom_resolver() {
+    use std::future::Future;
+    use std::net::{IpAddr, Ipv4Addr};
+    use std::pin::Pin;
+    use std::time::Duration;
+    use x11rb_async::rust_connection::Resolver;
+
+    #[derive(Debug)]
+    struct LocalhostResolver;
+
+    impl Resolver for LocalhostResolver {
+        fn resolve<'a>(
+            &'a self,
+            host: &'a str,
+        ) -> Pin<Box<dyn Future<Output = std::io::Result<Vec<IpAddr>>> + Send + 'a>> {
+            assert_eq!(host, "x11rb.invalid");
+            Box::pin(async { Ok(vec![IpAddr::V4(Ipv4Addr::LOCALHOST)]) })
+        }
+    }
+
+    // A server that accepts the TCP connection, but never answers the setup request
+    let (listener, display) = listen_as_display();
+    let display = format!("x11rb.invalid:{}", display);
+
+    let connect = async {
+        RustConnection::connect_with_resolver(Some(&display), &LocalhostResolver)
+            .await
+            .map(|_| ())
+    };
+    let timeout = async {
+        let _ = async_io::Timer::after(Duration::from_millis(100)).await;
+        Ok(())
+    };
+    async_io::block_on(futures_lite::future::or(connect, timeout)).unwrap();
+
+    // The connection attempt went to the address provided by the resolver
+    listener.set_nonblocking(true).unwrap();
+    let _ = listener.accept().unwrap();
+}
+
+#[test]
+fn split_and_reunite() {
+    use x11rb_async::protocol::xproto::ConnectionExt as _;
+
+    let data = Arc::new(Mutex::new(Vec::new()));
+    let (conn, _driver) =
+        RustConnection::for_connected_stream(FakeStream(Arc::clone(&data)), make_setup()).unwrap();
+    let (sender, receiver) = conn.split();
+
+    // Requests can be sent via the sending half
+    async_io::block_on(async {
+        sender.no_operation().await.unwrap().ignore_error();
+        sender.flush().await.unwrap();
+    });
+    assert_eq!(data.lock().unwrap().len(), 4);
+    assert!(receiver.poll_for_event().unwrap().is_none());
+
+    // Reuniting fails while there is another clone of the sender
+    let clone = sender.clone();
+    let (clone, receiver) = clone.reunite(receiver).unwrap_err();
+    drop(clone);
+    let conn = sender.reunite(receiver).unwrap();
+    assert_eq!(conn.setup().resource_id_mask, (1 << 8) - 1);
+}
+
+#[test]
+fn split_sender_prefetches_all_extensions() {
+    let data = Arc::new(Mutex::new(Vec::new()));
+    let (conn, _driver) =
+        RustConnection::for_connected_stream(FakeStream(Arc::clone(&data)), make_setup()).unwrap();
+    let (sender, _receiver) = conn.split();
+
+    // Both QueryExtension requests are sent without waiting for a reply
+    async_io::block_on(async {
+        sender
+            .prefetch_all_extensions(&["FOO", "BAR"])
+            .await
+            .unwrap();
+        sender.flush().await.unwrap();
+    });
+    let data = data.lock().unwrap();
+    assert_eq!(data.len(), 24);
+    assert_eq!(data[0], 98);
+    assert_eq!(&data[8..11], b"FOO");
+    assert_eq!(data[12], 98);
+    assert_eq!(&data[20..23], b"BAR");
+}
+
+#[test]
+fn disconnect_on_driver_drop() {
+    let (conn, driver) =
+        RustConnection::for_connected_stream(FakeStream::default(), make_setup()).unwrap();
+    let disconnect = conn.on_disconnect();
+    assert!(!conn.is_disconnected());
+
+    drop(driver);
+    async_io::block_on(disconnect);
+    assert!(conn.is_disconnected());
+}
+
+#[test]
+fn disconnect_on_eof() {
+    #[derive(Debug)]
+    struct EofStream;
+
+    impl SyncStream for EofStream {
+        fn poll(&self, _: PollMode) -> Result<(), std::io::Error> {
+            unimplemented!()
+        }
+        fn read(&self, _: &mut [u8], _: &mut Vec<RawFdContainer>) -> Result<usize, std::io::Error> {
+            Ok(0)
+        }
+        fn write(&self, _: &[u8], _: &mut Vec<RawFdContainer>) -> Result<usize, std::io::Error> {
+            unimplemented!()
+        }
+    }
+
+    impl StreamBase<'_> for EofStream {
+        type Readable = Ready<std::io::Result<()>>;
+        type Writable = Ready<std::io::Result<()>>;
+
+        fn readable(&self) -> Self::Readable {
+            futures_lite::future::ready(Ok(()))
+        }
+
+        fn writable(&self) -> Self::Writable {
+            futures_lite::future::ready(Ok(()))
+        }
+    }
+
+    let (conn, driver) = RustConnection::for_connected_stream(EofStream, make_setup()).unwrap();
+    let disconnect = conn.on_disconnect();
+
+    // The driver notices the EOF and stops
+    let result = async_io::block_on(futures_lite::future::zip(driver, disconnect)).0;
+    match result {
+        Err(ConnectionError::IoError(e)) => assert_eq!(e.kind(), std::io::ErrorKind::UnexpectedEof),
+        res => panic!("Unexpected result: {:?}", res),
+    }
+    assert!(conn.is_disconnected());
+}
+
+#[test]
+fn join_cookies_in_order() {
+    use x11rb_async::protocol::xproto::ConnectionExt;
+
+    // Replies to three GetInputFocus requests, where the second one causes an error
+    let packet = |response_type: u8, detail: u8, sequence: u16, value: u32| {
+        let mut packet = vec![0; 32];
+        packet[0] = response_type;
+        packet[1] = detail;
+        packet[2..4].copy_from_slice(&sequence.to_ne_bytes());
+        packet[8..12].copy_from_slice(&value.to_ne_bytes());
+        packet
+    };
+    let mut data = packet(1, 0, 1, 10);
+    data.extend(packet(0, 3, 2, 0));
+    data.extend(packet(1, 0, 3, 30));
+
+    let (conn, driver) =
+        RustConnection::for_connected_stream(ReplyStream(Mutex::new(data)), make_setup()).unwrap();
+    let results = async_io::block_on(async {
+        let mut cookies = Vec::new();
+        for _ in 0..3 {
+            cookies.push(conn.get_input_focus().await?);
+        }
+        future::or(x11rb_async::join_cookies(cookies), async {
+            match driver.await? {}
+        })
+        .await
+    })
+    .unwrap();
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].as_ref().unwrap().focus, 10);
+    let error = results[1].as_ref().unwrap_err();
+    assert_eq!(error.error_kind, x11rb::protocol::ErrorKind::Window);
+    assert_eq!(error.sequence, 2);
+    assert_eq!(results[2].as_ref().unwrap().focus, 30);
+}
+
+#[test]
+fn read_buffer_size() {
+    let (conn, _driver) =
+        RustConnection::for_connected_stream(FakeStream::default(), make_setup()).unwrap();
+    assert_eq!(conn.read_buffer_size(), 4096);
+
+    conn.set_read_buffer_size(1 << 16);
+    assert_eq!(conn.read_buffer_size(), 1 << 16);
+
+    // The buffer must be able to hold at least an event
+    conn.set_read_buffer_size(1);
+    assert_eq!(conn.read_buffer_size(), 32);
+}
+
+#[test]
+fn window_events_are_routed() {
+    use futures_lite::StreamExt;
+    use x11rb::protocol::xproto::ExposeEvent;
+    use x11rb::protocol::Event;
+    use x11rb_async::rust_connection::Backpressure;
+
+    let (conn, _driver) =
+        RustConnection::for_connected_stream(FakeStream::default(), make_setup()).unwrap();
+    let expose = |window, count| {
+        Event::Expose(ExposeEvent {
+            window,
+            count,
+            ..Default::default()
+        })
+    };
+    let count = |event: &Event| match event {
+        Event::Expose(event) => (event.window, event.count),
+        event => panic!("Unexpected event {:?}", event),
+    };
+
+    async_io::block_on(async {
+        let mut events = Box::pin(conn.window_events(1, Backpressure::DropOldest(2)));
+        for (window, number) in [(1, 0), (2, 1), (1, 2), (1, 3)] {
+            conn.enqueue_synthetic_event(&expose(window, number))
+                .await
+                .unwrap();
+        }
+
+        // The main event queue routes the events for window 1 into the mailbox
+        let event = conn.poll_for_event().unwrap().unwrap();
+        assert_eq!(count(&event), (2, 1));
+        assert!(conn.poll_for_event().unwrap().is_none());
+
+        // The mailbox only kept the newest two events
+        let event = events.next().await.unwrap().unwrap();
+        assert_eq!(count(&event), (1, 2));
+        let event = events.next().await.unwrap().unwrap();
+        assert_eq!(count(&event), (1, 3));
+
+        // Without the stream, events go to the main event queue again
+        drop(events);
+        conn.enqueue_synthetic_event(&expose(1, 4)).await.unwrap();
+        let event = conn.poll_for_event().unwrap().unwrap();
+        assert_eq!(count(&event), (1, 4));
+    });
+}
+
+#[test]
+fn connection_hooks() {
+    use x11rb::rust_connection::ConnectionHooks;
+    use x11rb::x11_utils::X11Error;
+    use x11rb_async::protocol::xproto::ConnectionExt;
+    use x11rb_protocol::SequenceNumber;
+
+    #[derive(Debug, Default)]
+    struct RecordingHooks(Mutex<Vec<String>>);
+
+    impl ConnectionHooks for RecordingHooks {
+        fn on_request(&self, sequence: SequenceNumber, major_opcode: u8, request: &[u8]) {
+            let entry = format!("request {} {} {}", sequence, major_opcode, request.len());
+            self.0.lock().unwrap().push(entry);
+        }
+
+        fn on_reply(&self, sequence: SequenceNumber, reply: &[u8]) {
+            let entry = format!("reply {} {}", sequence, reply.len());
+            self.0.lock().unwrap().push(entry);
+        }
+
+        fn on_event(&self, event: &[u8]) {
+            self.0.lock().unwrap().push(format!("event {}", event[0]));
+        }
+
+        fn on_error(&self, error: &X11Error) {
+            let entry = format!("error {:?} {}", error.error_kind, error.sequence);
+            self.0.lock().unwrap().push(entry);
+        }
+    }
+
+    // A reply to GetInputFocus, an error for MapWindow, and a MapNotify event
+    let packet = |response_type: u8, detail: u8, sequence: u16| {
+        let mut packet = vec![0; 32];
+        packet[0] = response_type;
+        packet[1] = detail;
+        packet[2..4].copy_from_slice(&sequence.to_ne_bytes());
+        packet
+    };
+    let mut data = packet(1, 0, 1);
+    data.extend(packet(0, 3, 2));
+    data.extend(packet(19, 0, 2));
+
+    let (conn, driver) =
+        RustConnection::for_connected_stream(ReplyStream(Mutex::new(data)), make_setup()).unwrap();
+    let hooks = Arc::new(RecordingHooks::default());
+    conn.set_hooks(Some(hooks.clone()));
+
+    async_io::block_on(future::or(
+        async {
+            let cookie = conn.get_input_focus().await?;
+            conn.map_window(1).await?;
+            let _ = cookie.reply().await?;
+            let _ = conn.wait_for_event().await?;
+            let _ = conn.wait_for_event().await?;
+            Ok::<_, x11rb::errors::ReplyError>(())
+        },
+        async { match driver.await? {} },
+    ))
+    .unwrap();
+
+    assert_eq!(
+        *hooks.0.lock().unwrap(),
+        [
+            "request 1 43 4",
+            "request 2 8 8",
+            "reply 1 32",
+            "error Window 2",
+            "event 19",
+        ]
+    );
+}
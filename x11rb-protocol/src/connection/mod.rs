@@ -34,10 +34,72 @@ pub enum PollReply {
     Reply(Vec<u8>),
 }
 
+/// A packet that was received from the X11 server, as reported by the tap of a [`Connection`].
+///
+/// All variants contain the raw bytes of the packet. File descriptors that were sent together
+/// with a reply are not included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Incoming {
+    /// A reply to a request.
+    Reply(Vec<u8>),
+    /// An X11 error.
+    Error(Vec<u8>),
+    /// An event.
+    Event(Vec<u8>),
+}
+
+/// Reconstruct the full sequence number of a packet read from the X11 server.
+///
+/// `last_sequence_read` is the full sequence number of the previous packet. Returns `None` for
+/// KeymapNotify events, which do not have a sequence number.
+pub(crate) fn full_sequence_number(
+    last_sequence_read: SequenceNumber,
+    buffer: &[u8],
+) -> Option<SequenceNumber> {
+    use crate::protocol::xproto::KEYMAP_NOTIFY_EVENT;
+    if buffer[0] == KEYMAP_NOTIFY_EVENT {
+        return None;
+    }
+    // We get the u16 from the wire...
+    let number = u16::from_ne_bytes([buffer[2], buffer[3]]);
+
+    // ...and use our state to reconstruct the high bytes
+    let high_bytes = last_sequence_read & !SequenceNumber::from(u16::MAX);
+    let mut full_number = SequenceNumber::from(number) | high_bytes;
+    if full_number < last_sequence_read {
+        full_number += SequenceNumber::from(u16::MAX) + 1;
+    }
+    Some(full_number)
+}
+
+/// Information about requests that were sent, but not fully handled yet.
+///
+/// This is returned by [`Connection::outstanding_requests`] and can be used to detect requests
+/// whose replies are never collected, e.g. because their cookie was leaked.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OutstandingRequests {
+    /// The number of requests with a reply for which neither a reply nor an error was received.
+    pub awaiting_answer: usize,
+    /// The number of replies and errors that were received, but not yet collected.
+    pub unclaimed_replies: usize,
+    /// The sequence number of the oldest request with a reply that was not answered yet.
+    pub oldest_unanswered: Option<SequenceNumber>,
+}
+
+/// A queue of events together with their sequence numbers.
+type EventQueue = VecDeque<(SequenceNumber, Vec<u8>)>;
+
+/// The identifier of an additional event queue of a [`Connection`].
+///
+/// See [`Connection::add_event_queue`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EventQueueId(u64);
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 struct SentRequest {
     seqno: SequenceNumber,
     discard_mode: Option<DiscardMode>,
+    has_reply: bool,
     has_fds: bool,
 }
 
@@ -58,7 +120,15 @@ pub struct Connection {
     // The sequence number of the last reply/error/event that was read
     last_sequence_read: SequenceNumber,
     // Events that were read, but not yet returned to the API user
-    pending_events: VecDeque<(SequenceNumber, Vec<u8>)>,
+    pending_events: EventQueue,
+    // Additional event queues that get a copy of every event
+    event_queues: Vec<(EventQueueId, EventQueue)>,
+    // The identifier of the next additional event queue
+    next_event_queue_id: u64,
+    // Errors that no one waits for, if they are not put into the event queue
+    unchecked_errors: Option<EventQueue>,
+    // Copies of all received packets in the order in which they were received, if enabled
+    tap: Option<VecDeque<(Incoming, SequenceNumber)>>,
     // Replies that were read, but not yet returned to the API user
     pending_replies: VecDeque<(SequenceNumber, BufWithFds)>,
 
@@ -84,6 +154,10 @@ impl Connection {
             last_sequence_read: 0,
             sent_requests: VecDeque::new(),
             pending_events: VecDeque::new(),
+            unchecked_errors: None,
+            tap: None,
+            event_queues: Vec::new(),
+            next_event_queue_id: 0,
             pending_replies: VecDeque::new(),
             pending_fds: VecDeque::new(),
         }
@@ -118,6 +192,7 @@ impl Connection {
         let sent_request = SentRequest {
             seqno,
             discard_mode: None,
+            has_reply: has_response,
             has_fds: kind == ReplyFdKind::ReplyWithFDs,
         };
         self.sent_requests.push_back(sent_request);
@@ -143,7 +218,7 @@ impl Connection {
                         if let Some((_, packet)) = self.pending_replies.remove(index) {
                             if packet.0[0] == 0 {
                                 // This is an error
-                                self.pending_events.push_back((seqno, packet.0));
+                                self.push_unchecked_error(seqno, packet.0);
                             }
                         }
                     }
@@ -152,23 +227,63 @@ impl Connection {
         }
     }
 
+    /// Get information about requests that were not fully handled yet.
+    pub fn outstanding_requests(&self) -> OutstandingRequests {
+        let mut unanswered = self
+            .sent_requests
+            .iter()
+            .filter(|request| request.has_reply && request.seqno > self.last_sequence_read);
+        let oldest_unanswered = unanswered.next().map(|request| request.seqno);
+        OutstandingRequests {
+            awaiting_answer: usize::from(oldest_unanswered.is_some()) + unanswered.count(),
+            unclaimed_replies: self.pending_replies.len(),
+            oldest_unanswered,
+        }
+    }
+
+    /// Get the discard mode of a request that was sent.
+    ///
+    /// Returns `None` if the answer to the request is not discarded or if the request is no
+    /// longer tracked because a later answer was already received.
+    pub fn discard_mode(&self, seqno: SequenceNumber) -> Option<DiscardMode> {
+        self.sent_requests
+            .binary_search_by_key(&seqno, |r| r.seqno)
+            .ok()
+            .and_then(|index| self.sent_requests[index].discard_mode)
+    }
+
+    /// Get the sequence number of the last request that was sent.
+    pub fn last_sequence_written(&self) -> SequenceNumber {
+        self.last_sequence_written
+    }
+
+    /// Get the sequence number of the last packet that was received.
+    pub fn last_sequence_read(&self) -> SequenceNumber {
+        self.last_sequence_read
+    }
+
+    // Reconstruct the full sequence number of a packet read from the X11 server without updating
+    // our state. Returns `None` for KeymapNotify events, which do not have a sequence number.
+    fn full_sequence_number(&self, buffer: &[u8]) -> Option<SequenceNumber> {
+        full_sequence_number(self.last_sequence_read, buffer)
+    }
+
+    /// Get the full sequence number of a packet that is about to be enqueued.
+    ///
+    /// The X11 server only sends the lower 16 bits of sequence numbers. This function
+    /// reconstructs the full sequence number in the same way as
+    /// [`Connection::enqueue_packet`]. For packets without a sequence number (`KeymapNotify`
+    /// events), the sequence number of the previous packet is returned.
+    pub fn sequence_number_of(&self, packet: &[u8]) -> SequenceNumber {
+        self.full_sequence_number(packet)
+            .unwrap_or(self.last_sequence_read)
+    }
+
     // Extract the sequence number from a packet read from the X11 server. The packet must be a
     // reply, an event, or an error. All of these have a u16 sequence number in bytes 2 and 3...
     // except for KeymapNotify events.
     fn extract_sequence_number(&mut self, buffer: &[u8]) -> Option<SequenceNumber> {
-        use crate::protocol::xproto::KEYMAP_NOTIFY_EVENT;
-        if buffer[0] == KEYMAP_NOTIFY_EVENT {
-            return None;
-        }
-        // We get the u16 from the wire...
-        let number = u16::from_ne_bytes([buffer[2], buffer[3]]);
-
-        // ...and use our state to reconstruct the high bytes
-        let high_bytes = self.last_sequence_read & !SequenceNumber::from(u16::MAX);
-        let mut full_number = SequenceNumber::from(number) | high_bytes;
-        if full_number < self.last_sequence_read {
-            full_number += SequenceNumber::from(u16::MAX) + 1;
-        }
+        let full_number = self.full_sequence_number(buffer)?;
 
         // Update our state
         self.last_sequence_read = full_number;
@@ -208,22 +323,29 @@ impl Connection {
         }
         let request = self.sent_requests.front().filter(|r| r.seqno == seqno);
 
+        if let Some(tap) = self.tap.as_mut() {
+            let incoming = match kind {
+                0 => Incoming::Error(packet.clone()),
+                1 => Incoming::Reply(packet.clone()),
+                _ => Incoming::Event(packet.clone()),
+            };
+            tap.push_back((incoming, seqno));
+        }
+
         if kind == 0 {
             // It is an error. Let's see where we have to send it to.
             if let Some(request) = request {
                 match request.discard_mode {
                     Some(DiscardMode::DiscardReplyAndError) => { /* This error should be ignored */
                     }
-                    Some(DiscardMode::DiscardReply) => {
-                        self.pending_events.push_back((seqno, packet))
-                    }
+                    Some(DiscardMode::DiscardReply) => self.push_unchecked_error(seqno, packet),
                     None => self
                         .pending_replies
                         .push_back((seqno, (packet, Vec::new()))),
                 }
             } else {
                 // Unexpected error, send to main loop
-                self.pending_events.push_back((seqno, packet));
+                self.push_unchecked_error(seqno, packet);
             }
         } else if kind == 1 {
             let fds = if request.filter(|r| r.has_fds).is_some() {
@@ -250,8 +372,94 @@ impl Connection {
             }
         } else {
             // It is an event
-            self.pending_events.push_back((seqno, packet));
+            self.push_event(seqno, packet);
+        }
+    }
+
+    /// Add an error that no one waits for to the unchecked errors or to the main event queue.
+    fn push_unchecked_error(&mut self, seqno: SequenceNumber, packet: Vec<u8>) {
+        match self.unchecked_errors {
+            Some(ref mut errors) => errors.push_back((seqno, packet)),
+            None => self.pending_events.push_back((seqno, packet)),
+        }
+    }
+
+    /// Set whether errors that no one waits for are collected separately.
+    ///
+    /// These are errors for requests whose reply was discarded with [`DiscardMode::DiscardReply`]
+    /// and errors that do not belong to any known request. By default, they are put into the
+    /// event queue. When collected separately, they can be fetched with
+    /// [`Connection::poll_for_unchecked_error`] instead. Errors that were collected, but not
+    /// fetched yet, are moved to the event queue when the collection is disabled again.
+    pub fn set_collect_unchecked_errors(&mut self, collect: bool) {
+        match (collect, self.unchecked_errors.take()) {
+            (true, errors) => self.unchecked_errors = Some(errors.unwrap_or_default()),
+            (false, Some(errors)) => self.pending_events.extend(errors),
+            (false, None) => {}
+        }
+    }
+
+    /// Get an error that no one waits for.
+    ///
+    /// This only returns something if these errors are collected, see
+    /// [`Connection::set_collect_unchecked_errors`].
+    pub fn poll_for_unchecked_error(&mut self) -> Option<RawEventAndSeqNumber> {
+        self.unchecked_errors
+            .as_mut()?
+            .pop_front()
+            .map(|(seqno, error)| (error, seqno))
+    }
+
+    /// Enable or disable the tap.
+    ///
+    /// While the tap is enabled, a copy of every packet that is enqueued is kept, independent of
+    /// whether the packet is later returned to anyone. This includes replies to requests whose
+    /// reply is discarded and errors that are ignored. The copies can be fetched in the order in
+    /// which they were received with [`Connection::poll_for_tapped_packet`]. Disabling the tap
+    /// drops all copies that were not fetched yet.
+    pub fn set_tap_enabled(&mut self, enabled: bool) {
+        self.tap = if enabled {
+            Some(self.tap.take().unwrap_or_default())
+        } else {
+            None
+        };
+    }
+
+    /// Get the next packet that was recorded by the tap together with its sequence number.
+    ///
+    /// See [`Connection::set_tap_enabled`].
+    pub fn poll_for_tapped_packet(&mut self) -> Option<(Incoming, SequenceNumber)> {
+        self.tap.as_mut()?.pop_front()
+    }
+
+    /// Add an event to the main event queue and to all additional event queues.
+    fn push_event(&mut self, seqno: SequenceNumber, packet: Vec<u8>) {
+        for (_, queue) in self.event_queues.iter_mut() {
+            queue.push_back((seqno, packet.clone()));
+        }
+        self.pending_events.push_back((seqno, packet));
+    }
+
+    /// Add a locally constructed event to the event queues.
+    ///
+    /// The event is handled as if it was just received from the X11 server, except that it is
+    /// marked as being sent via the `SendEvent` request. It gets the sequence number of the last
+    /// packet that was received.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `event` is shorter than 32 bytes or is an X11 error.
+    pub fn enqueue_synthetic_event(&mut self, mut event: Vec<u8>) {
+        use crate::protocol::xproto::KEYMAP_NOTIFY_EVENT;
+
+        assert!(event.len() >= 32, "Events are at least 32 bytes long");
+        assert_ne!(event[0], 0, "Errors cannot be synthetic events");
+        event[0] |= 0x80;
+        if event[0] & 0x7f != KEYMAP_NOTIFY_EVENT {
+            let seqno = self.last_sequence_read as u16;
+            event[2..4].copy_from_slice(&seqno.to_ne_bytes());
         }
+        self.push_event(self.last_sequence_read, event);
     }
 
     /// Check if the server already sent an answer to the request with the given sequence number.
@@ -324,11 +532,85 @@ impl Connection {
             .pop_front()
             .map(|(seqno, event)| (event, seqno))
     }
+
+    /// Check whether events are waiting in the main event queue.
+    pub fn has_pending_events(&self) -> bool {
+        !self.pending_events.is_empty()
+    }
+
+    /// Add an additional event queue.
+    ///
+    /// The new queue gets a copy of every event that is received from now on, independent of the
+    /// main event queue and of other additional queues. This allows e.g. libraries to observe
+    /// events without taking them away from the application. X11 errors are only delivered to the
+    /// main event queue.
+    ///
+    /// Events pile up in the queue until they are fetched with
+    /// [`Connection::poll_for_event_in_queue`] or the queue is removed with
+    /// [`Connection::remove_event_queue`].
+    pub fn add_event_queue(&mut self) -> EventQueueId {
+        let id = EventQueueId(self.next_event_queue_id);
+        self.next_event_queue_id += 1;
+        self.event_queues.push((id, VecDeque::new()));
+        id
+    }
+
+    /// Add an additional event queue that starts with the same events as an existing one.
+    ///
+    /// Returns `None` if there is no queue with the given identifier.
+    pub fn clone_event_queue(&mut self, id: EventQueueId) -> Option<EventQueueId> {
+        let events = self.event_queue(id)?.clone();
+        let new_id = self.add_event_queue();
+        *self.event_queue(new_id).unwrap() = events;
+        Some(new_id)
+    }
+
+    /// Remove an additional event queue together with the events in it.
+    ///
+    /// Returns `false` if there is no queue with the given identifier.
+    pub fn remove_event_queue(&mut self, id: EventQueueId) -> bool {
+        let len = self.event_queues.len();
+        self.event_queues.retain(|(queue_id, _)| *queue_id != id);
+        self.event_queues.len() != len
+    }
+
+    /// Get a pending event from an additional event queue.
+    pub fn poll_for_event_in_queue(&mut self, id: EventQueueId) -> Option<RawEventAndSeqNumber> {
+        self.event_queue(id)?
+            .pop_front()
+            .map(|(seqno, event)| (event, seqno))
+    }
+
+    fn event_queue(&mut self, id: EventQueueId) -> Option<&mut EventQueue> {
+        self.event_queues
+            .iter_mut()
+            .find(|(queue_id, _)| *queue_id == id)
+            .map(|(_, queue)| queue)
+    }
+
+    /// Get the first pending event for which `matches` returns `true`.
+    ///
+    /// Events that do not match stay in the queue in their original order.
+    pub fn poll_for_matching_event_with_sequence(
+        &mut self,
+        mut matches: impl FnMut(&[u8]) -> bool,
+    ) -> Option<RawEventAndSeqNumber> {
+        let index = self
+            .pending_events
+            .iter()
+            .position(|(_, event)| matches(event))?;
+        self.pending_events
+            .remove(index)
+            .map(|(seqno, event)| (event, seqno))
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use super::{Connection, ReplyFdKind};
+    use super::{Connection, Incoming, OutstandingRequests, ReplyFdKind};
+    use crate::DiscardMode;
+    use alloc::vec;
+    use alloc::vec::Vec;
 
     #[test]
     fn insert_sync_no_reply() {
@@ -441,4 +723,222 @@ mod test {
         // Now check that the sequence number for the last packet was reconstructed correctly.
         assert!(connection.poll_for_reply_or_error(second_reply).is_some());
     }
+
+    #[test]
+    fn outstanding_requests() {
+        fn reply(seqno: u16) -> Vec<u8> {
+            let mut packet = vec![0; 32];
+            packet[0] = 1;
+            packet[2..4].copy_from_slice(&seqno.to_ne_bytes());
+            packet
+        }
+
+        let mut connection = Connection::new();
+        assert_eq!(
+            connection.outstanding_requests(),
+            OutstandingRequests::default()
+        );
+
+        let seqno1 = connection
+            .send_request(ReplyFdKind::ReplyWithoutFDs)
+            .unwrap();
+        let _ = connection.send_request(ReplyFdKind::NoReply).unwrap();
+        let seqno3 = connection
+            .send_request(ReplyFdKind::ReplyWithoutFDs)
+            .unwrap();
+        connection.discard_reply(seqno3, DiscardMode::DiscardReply);
+        assert_eq!(connection.last_sequence_written(), 3);
+        assert_eq!(
+            connection.outstanding_requests(),
+            OutstandingRequests {
+                awaiting_answer: 2,
+                unclaimed_replies: 0,
+                oldest_unanswered: Some(seqno1),
+            }
+        );
+        assert_eq!(connection.discard_mode(seqno1), None);
+        assert_eq!(
+            connection.discard_mode(seqno3),
+            Some(DiscardMode::DiscardReply)
+        );
+
+        connection.enqueue_packet(reply(1));
+        assert_eq!(connection.last_sequence_read(), 1);
+        assert_eq!(
+            connection.outstanding_requests(),
+            OutstandingRequests {
+                awaiting_answer: 1,
+                unclaimed_replies: 1,
+                oldest_unanswered: Some(seqno3),
+            }
+        );
+
+        connection.enqueue_packet(reply(3));
+        assert!(connection.poll_for_reply_or_error(seqno1).is_some());
+        assert_eq!(
+            connection.outstanding_requests(),
+            OutstandingRequests::default()
+        );
+    }
+
+    #[test]
+    fn event_queues() {
+        fn event(code: u8) -> Vec<u8> {
+            let mut event = vec![0; 32];
+            event[0] = code;
+            event
+        }
+        fn codes(mut poll: impl FnMut() -> Option<super::RawEventAndSeqNumber>) -> Vec<u8> {
+            core::iter::from_fn(|| poll().map(|(event, _)| event[0])).collect()
+        }
+
+        let mut connection = Connection::new();
+        connection.enqueue_packet(event(2));
+        let first = connection.add_event_queue();
+        connection.enqueue_packet(event(3));
+        let second = connection.clone_event_queue(first).unwrap();
+        connection.enqueue_packet(event(4));
+        // Errors only go to the main queue
+        connection.enqueue_packet(event(0));
+
+        assert_eq!(codes(|| connection.poll_for_event_in_queue(first)), [3, 4]);
+        assert!(connection.has_pending_events());
+        assert_eq!(
+            codes(|| connection.poll_for_event_with_sequence()),
+            [2, 3, 4, 0]
+        );
+        assert!(!connection.has_pending_events());
+        assert!(connection.remove_event_queue(second));
+        assert!(!connection.remove_event_queue(second));
+        assert_eq!(connection.poll_for_event_in_queue(second), None);
+        assert_eq!(connection.clone_event_queue(second), None);
+    }
+
+    #[test]
+    fn synthetic_event() {
+        let mut connection = Connection::new();
+        let seqno = connection
+            .send_request(ReplyFdKind::ReplyWithoutFDs)
+            .unwrap();
+        let mut reply = vec![0; 32];
+        reply[0] = 1;
+        reply[2..4].copy_from_slice(&(seqno as u16).to_ne_bytes());
+        connection.enqueue_packet(reply);
+
+        let mut event = vec![0; 32];
+        event[0] = 12;
+        connection.enqueue_synthetic_event(event);
+        let (event, event_seqno) = connection.poll_for_event_with_sequence().unwrap();
+        assert_eq!(event_seqno, seqno);
+        assert_eq!(event[0], 0x80 | 12);
+        assert_eq!(u16::from_ne_bytes([event[2], event[3]]), seqno as u16);
+    }
+
+    #[test]
+    fn matching_event() {
+        let mut connection = Connection::new();
+        for code in [2, 12, 22, 12] {
+            let mut event = vec![0; 32];
+            event[0] = code;
+            connection.enqueue_packet(event);
+        }
+        let event = connection.poll_for_matching_event_with_sequence(|event| event[0] == 12);
+        assert_eq!(event.map(|(event, _)| event[0]), Some(12));
+        assert_eq!(
+            connection.poll_for_matching_event_with_sequence(|event| event[0] == 3),
+            None
+        );
+        let remaining = core::iter::from_fn(|| connection.poll_for_event_with_sequence())
+            .map(|(event, _)| event[0])
+            .collect::<Vec<_>>();
+        assert_eq!(remaining, [2, 22, 12]);
+    }
+
+    #[test]
+    fn unchecked_errors() {
+        fn error(seqno: u16) -> Vec<u8> {
+            let mut error = vec![0; 32];
+            error[2..4].copy_from_slice(&seqno.to_ne_bytes());
+            error
+        }
+
+        let mut connection = Connection::new();
+        connection.set_collect_unchecked_errors(true);
+        for mode in [
+            Some(DiscardMode::DiscardReply),
+            Some(DiscardMode::DiscardReplyAndError),
+            None,
+        ] {
+            let seqno = connection
+                .send_request(ReplyFdKind::ReplyWithoutFDs)
+                .unwrap();
+            if let Some(mode) = mode {
+                connection.discard_reply(seqno, mode);
+            }
+            connection.enqueue_packet(error(seqno as u16));
+        }
+        // An error for a request that was never sent
+        connection.enqueue_packet(error(10));
+
+        let seqnos = core::iter::from_fn(|| connection.poll_for_unchecked_error())
+            .map(|(_, seqno)| seqno)
+            .collect::<Vec<_>>();
+        assert_eq!(seqnos, [1, 10]);
+        assert!(connection.poll_for_reply_or_error(3).is_some());
+        assert!(!connection.has_pending_events());
+
+        // Disabling the collection moves collected errors to the event queue
+        connection.enqueue_packet(error(11));
+        assert!(!connection.has_pending_events());
+        connection.set_collect_unchecked_errors(false);
+        assert!(connection.has_pending_events());
+        connection.enqueue_packet(error(12));
+        assert_eq!(connection.poll_for_unchecked_error(), None);
+        assert!(connection.poll_for_event_with_sequence().is_some());
+        assert!(connection.poll_for_event_with_sequence().is_some());
+    }
+
+    #[test]
+    fn tap() {
+        fn packet(kind: u8, seqno: u16) -> Vec<u8> {
+            let mut packet = vec![0; 32];
+            packet[0] = kind;
+            packet[2..4].copy_from_slice(&seqno.to_ne_bytes());
+            packet
+        }
+
+        let mut connection = Connection::new();
+        connection.set_tap_enabled(true);
+        let first = connection
+            .send_request(ReplyFdKind::ReplyWithoutFDs)
+            .unwrap();
+        let second = connection
+            .send_request(ReplyFdKind::ReplyWithoutFDs)
+            .unwrap();
+        connection.discard_reply(first, DiscardMode::DiscardReplyAndError);
+        connection.enqueue_packet(packet(1, 1));
+        connection.enqueue_packet(packet(12, 1));
+        connection.enqueue_packet(packet(0, 2));
+
+        assert_eq!(
+            connection.poll_for_tapped_packet(),
+            Some((Incoming::Reply(packet(1, 1)), first))
+        );
+        assert_eq!(
+            connection.poll_for_tapped_packet(),
+            Some((Incoming::Event(packet(12, 1)), first))
+        );
+        assert_eq!(
+            connection.poll_for_tapped_packet(),
+            Some((Incoming::Error(packet(0, 2)), second))
+        );
+        assert_eq!(connection.poll_for_tapped_packet(), None);
+        // The packets are still delivered normally
+        assert!(connection.poll_for_reply_or_error(second).is_some());
+        assert!(connection.poll_for_event_with_sequence().is_some());
+
+        connection.set_tap_enabled(false);
+        connection.enqueue_packet(packet(12, 2));
+        assert_eq!(connection.poll_for_tapped_packet(), None);
+    }
 }
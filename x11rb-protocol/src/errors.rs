@@ -76,6 +76,90 @@ impl fmt::Display for ParseError {
     }
 }
 
+/// A `SendEvent` request that the X11 server would reject.
+///
+/// See [`crate::send_event::SendEventBuilder`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidSendEvent {
+    /// The response type is not the code of a core or extension event.
+    ///
+    /// This e.g. happens when the `response_type` field of the event was not set.
+    ResponseType(u8),
+
+    /// Generic events cannot be sent since they do not fit into 32 bytes.
+    GenericEvent,
+
+    /// The response type has the "sent via `SendEvent`" flag set.
+    ///
+    /// The X11 server sets this flag itself.
+    SendEventFlag,
+
+    /// The format of a `ClientMessage` event is not 8, 16 or 32.
+    ClientMessageFormat(u8),
+
+    /// The event mask contains unknown bits.
+    EventMask(u32),
+}
+
+#[cfg(feature = "std")]
+impl Error for InvalidSendEvent {}
+
+impl fmt::Display for InvalidSendEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvalidSendEvent::ResponseType(response_type) => {
+                write!(f, "Response type {} is not an event", response_type)
+            }
+            InvalidSendEvent::GenericEvent => write!(f, "Generic events cannot be sent"),
+            InvalidSendEvent::SendEventFlag => {
+                write!(f, "The response type has the SendEvent flag set")
+            }
+            InvalidSendEvent::ClientMessageFormat(format) => {
+                write!(f, "Invalid ClientMessage format {}", format)
+            }
+            InvalidSendEvent::EventMask(mask) => write!(f, "Invalid event mask {:#x}", mask),
+        }
+    }
+}
+
+/// A value did not survive being serialized and parsed again.
+///
+/// See [`crate::testing`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RoundtripError {
+    /// Parsing the serialized value failed.
+    Parse(ParseError),
+
+    /// Parsing did not consume all serialized bytes. The number of remaining bytes is provided.
+    TrailingData(usize),
+
+    /// The parsed value differs from the original value.
+    Mismatch,
+}
+
+#[cfg(feature = "std")]
+impl Error for RoundtripError {}
+
+impl fmt::Display for RoundtripError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RoundtripError::Parse(err) => write!(f, "Parsing the serialized value failed: {}", err),
+            RoundtripError::TrailingData(len) => {
+                write!(f, "Parsing left {} bytes of the serialized value", len)
+            }
+            RoundtripError::Mismatch => write!(f, "The parsed value differs from the original"),
+        }
+    }
+}
+
+impl From<ParseError> for RoundtripError {
+    fn from(err: ParseError) -> Self {
+        RoundtripError::Parse(err)
+    }
+}
+
 /// An error that occurred while parsing the `$DISPLAY` environment variable
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
@@ -172,6 +256,9 @@ pub enum ConnectError {
         /// The number of bytes that were received.
         received: usize,
     },
+
+    /// Establishing the connection did not complete within the given timeout.
+    Timeout,
 }
 
 #[cfg(feature = "std")]
@@ -203,6 +290,7 @@ impl fmt::Display for ConnectError {
                 "Not enough data received to complete the handshake. Expected {}, received {}",
                 expected, received
             ),
+            ConnectError::Timeout => write!(f, "Timed out while connecting to the X11 server"),
         }
     }
 }
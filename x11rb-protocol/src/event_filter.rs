@@ -0,0 +1,135 @@
+//! Selecting events by their window and kind.
+//!
+//! Modal dialogs and widgets with their own event loop are only interested in some of the events
+//! that a client receives. An [`EventFilter`] describes these events. Connections can use it to
+//! take matching events out of their event queue while leaving all other events in the queue for
+//! the main event loop.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use x11rb_protocol::event_filter::EventFilter;
+//! # use x11rb_protocol::protocol::{Event, EventKind};
+//! # use x11rb_protocol::protocol::xproto::ExposeEvent;
+//! let filter = EventFilter::window(0x1234).types(&[EventKind::Expose, EventKind::ConfigureNotify]);
+//! let event = Event::Expose(ExposeEvent {
+//!     window: 0x1234,
+//!     ..Default::default()
+//! });
+//! assert!(filter.matches(&event));
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::protocol::xproto::Window;
+use crate::protocol::{Event, EventKind};
+use crate::x11_utils::ExtInfoProvider;
+
+/// A description of the events that someone is interested in.
+///
+/// The window of an event is determined by [`Event::window`]. By default, a filter matches all
+/// events.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventFilter {
+    window: Option<Window>,
+    kinds: Vec<EventKind>,
+}
+
+impl EventFilter {
+    /// Create a filter that matches all events.
+    pub fn any() -> Self {
+        Default::default()
+    }
+
+    /// Create a filter that matches all events for the given window.
+    pub fn window(window: Window) -> Self {
+        Self {
+            window: Some(window),
+            kinds: Vec::new(),
+        }
+    }
+
+    /// Only match events of the given kinds.
+    ///
+    /// Calling this more than once adds more kinds of events to the filter.
+    pub fn types(mut self, kinds: &[EventKind]) -> Self {
+        self.kinds.extend_from_slice(kinds);
+        self
+    }
+
+    /// Check whether an event matches this filter.
+    pub fn matches(&self, event: &Event) -> bool {
+        if self.window.is_some() && event.window() != self.window {
+            return false;
+        }
+        self.kinds.is_empty() || self.kinds.contains(&event.kind())
+    }
+
+    /// Check whether an event in its wire format matches this filter.
+    ///
+    /// Events that cannot be parsed do not match.
+    pub fn matches_raw(&self, event: &[u8], ext_info_provider: &dyn ExtInfoProvider) -> bool {
+        Event::parse(event, ext_info_provider).map_or(false, |event| self.matches(&event))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::EventFilter;
+    use crate::protocol::xproto::{ConfigureNotifyEvent, ExposeEvent, KeymapNotifyEvent};
+    use crate::protocol::{Event, EventKind};
+    use crate::x11_utils::{ExtInfoProvider, ExtensionInformation};
+
+    struct NoExtensions;
+
+    impl ExtInfoProvider for NoExtensions {
+        fn get_from_major_opcode(&self, _: u8) -> Option<(&str, ExtensionInformation)> {
+            None
+        }
+        fn get_from_event_code(&self, _: u8) -> Option<(&str, ExtensionInformation)> {
+            None
+        }
+        fn get_from_error_code(&self, _: u8) -> Option<(&str, ExtensionInformation)> {
+            None
+        }
+    }
+
+    fn expose(window: u32) -> ExposeEvent {
+        ExposeEvent {
+            response_type: crate::protocol::xproto::EXPOSE_EVENT,
+            window,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn window_and_types() {
+        let filter = EventFilter::window(1).types(&[EventKind::Expose]);
+        assert!(filter.matches(&Event::Expose(expose(1))));
+        assert!(!filter.matches(&Event::Expose(expose(2))));
+        let configure = Event::ConfigureNotify(ConfigureNotifyEvent {
+            event: 1,
+            ..Default::default()
+        });
+        assert!(!filter.matches(&configure));
+        assert!(filter
+            .types(&[EventKind::ConfigureNotify])
+            .matches(&configure));
+    }
+
+    #[test]
+    fn any() {
+        let filter = EventFilter::any();
+        assert!(filter.matches(&Event::Expose(expose(1))));
+        assert!(filter.matches(&Event::KeymapNotify(KeymapNotifyEvent::default())));
+        assert!(!EventFilter::window(1).matches(&Event::KeymapNotify(Default::default())));
+    }
+
+    #[test]
+    fn raw() {
+        let filter = EventFilter::window(1);
+        assert!(filter.matches_raw(&<[u8; 32]>::from(expose(1)), &NoExtensions));
+        assert!(!filter.matches_raw(&<[u8; 32]>::from(expose(2)), &NoExtensions));
+        assert!(!filter.matches_raw(&[], &NoExtensions));
+    }
+}
@@ -1,12 +1,19 @@
 //! A mechanism for allocating XIDs.
 
+use alloc::vec::Vec;
+
 use crate::errors::ConnectError;
-use crate::protocol::xc_misc::GetXIDRangeReply;
+use crate::protocol::xc_misc::{GetXIDListReply, GetXIDRangeReply};
 
 #[cfg(feature = "std")]
 use std::error::Error;
 
 use core::fmt;
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::{AtomicU64, Ordering};
+
+/// The number of IDs below which [`IdPool::needs_refill`] asks for a refill ahead of time.
+const LOW_WATER: u32 = 64;
 
 /// An allocator for X11 IDs.
 ///
@@ -55,6 +62,31 @@ impl IdAllocator {
         Ok(())
     }
 
+    /// Get the number of IDs that are left in the current range.
+    fn remaining(&self) -> u32 {
+        if self.next_id > self.max_id {
+            0
+        } else {
+            (self.max_id - self.next_id) / self.increment + 1
+        }
+    }
+
+    /// Take the IDs that are left in the current range out of this allocator.
+    ///
+    /// The result is the next ID and the number of IDs that are left. Afterwards, this allocator
+    /// is exhausted until its range is updated again.
+    #[cfg(target_has_atomic = "64")]
+    fn take_range(&mut self) -> Option<(u32, u32)> {
+        let count = self.remaining();
+        if count == 0 {
+            return None;
+        }
+        let next_id = self.next_id;
+        self.next_id = 1;
+        self.max_id = 0;
+        Some((next_id, count))
+    }
+
     /// Generate the next ID.
     pub fn generate_id(&mut self) -> Option<u32> {
         if self.next_id > self.max_id {
@@ -67,6 +99,250 @@ impl IdAllocator {
     }
 }
 
+/// An [`IdAllocator`] together with free IDs from XC-MISC `GetXIDList` replies.
+///
+/// When the range of an `IdAllocator` is exhausted, the XC-MISC extension can be used to find
+/// IDs that are free again. `GetXIDRange` returns a contiguous range, which can be very small on
+/// busy servers. `GetXIDList` returns a list of free IDs instead, see
+/// [`IdPool::update_xid_list`].
+///
+/// To avoid waiting for the server when the IDs run out, the XC-MISC request can be sent ahead
+/// of time, see [`IdPool::needs_refill`]. The server considers the IDs that were not handed out
+/// yet as free, so they may be part of the reply. [`IdPool::start_refill`] remembers them and
+/// they are skipped when the reply is applied.
+#[derive(Debug, Clone)]
+pub struct IdPool {
+    allocator: IdAllocator,
+    /// Free IDs from a `GetXIDList` reply, in reverse order.
+    free_ids: Vec<u32>,
+    /// Inclusive ranges of IDs that could still be handed out when a refill was started.
+    reserved: Option<Vec<(u32, u32)>>,
+}
+
+impl IdPool {
+    /// Create a new pool that hands out the IDs of the given allocator first.
+    pub fn new(allocator: IdAllocator) -> Self {
+        Self {
+            allocator,
+            free_ids: Vec::new(),
+            reserved: None,
+        }
+    }
+
+    /// Check whether a refill should be requested before the IDs run out.
+    ///
+    /// This is the case if fewer than a few dozen IDs are left in this pool and in `range`
+    /// together and no refill was started yet. Afterwards, the caller should send an XC-MISC
+    /// request, call [`IdPool::start_refill`] and apply the reply once the IDs are exhausted.
+    pub fn needs_refill(&self, range: &AtomicIdRange) -> bool {
+        let remaining = u64::from(self.allocator.remaining())
+            + u64::from(range.remaining())
+            + self.free_ids.len() as u64;
+        self.reserved.is_none() && remaining < u64::from(LOW_WATER)
+    }
+
+    /// Remember the IDs that can still be handed out, because a refill was requested.
+    ///
+    /// The IDs are skipped by the next call to [`IdPool::update_xid_range`] or
+    /// [`IdPool::update_xid_list`]. `range` must not be refilled concurrently.
+    pub fn start_refill(&mut self, range: &AtomicIdRange) {
+        let increment = self.allocator.increment;
+        let interval = |next_id: u32, count: u32| (next_id, next_id + (count - 1) * increment);
+        let mut reserved = Vec::with_capacity(self.free_ids.len() + 2);
+        if let Some((next_id, count)) = range.current().filter(|&(_, count)| count > 0) {
+            reserved.push(interval(next_id, count));
+        }
+        let count = self.allocator.remaining();
+        if count > 0 {
+            reserved.push(interval(self.allocator.next_id, count));
+        }
+        reserved.extend(self.free_ids.iter().map(|&id| (id, id)));
+        self.reserved = Some(reserved);
+    }
+
+    /// Update the available range of IDs based on a GetXIDRangeReply
+    ///
+    /// If a refill was started, the largest part of the range without reserved IDs is used.
+    pub fn update_xid_range(&mut self, xidrange: &GetXIDRangeReply) -> Result<(), IdsExhausted> {
+        let reserved = self.reserved.take().unwrap_or_default();
+        let (start, count) = (xidrange.start_id, xidrange.count);
+        if (start, count) == (0, 1) || count == 0 {
+            return Err(IdsExhausted);
+        }
+        let (start, count) = largest_free_part(start, count, self.allocator.increment, &reserved)
+            .ok_or(IdsExhausted)?;
+        self.allocator.update_xid_range(&GetXIDRangeReply {
+            start_id: start,
+            count,
+            ..*xidrange
+        })
+    }
+
+    /// Update the available IDs based on a GetXIDListReply
+    ///
+    /// The IDs from the list are handed out after the current range is exhausted. They replace
+    /// the IDs of a previous list. If a refill was started, reserved IDs are skipped.
+    pub fn update_xid_list(&mut self, xidlist: &GetXIDListReply) -> Result<(), IdsExhausted> {
+        let reserved = self.reserved.take().unwrap_or_default();
+        let is_free = |id: &u32| !reserved.iter().any(|&(lo, hi)| lo <= *id && *id <= hi);
+        if !xidlist.ids.iter().any(is_free) {
+            return Err(IdsExhausted);
+        }
+        self.free_ids.clear();
+        self.free_ids
+            .extend(xidlist.ids.iter().rev().copied().filter(is_free));
+        Ok(())
+    }
+
+    /// Generate the next ID.
+    ///
+    /// IDs from the range of the allocator are handed out first, then the IDs from the list.
+    pub fn generate_id(&mut self) -> Option<u32> {
+        self.allocator.generate_id().or_else(|| self.free_ids.pop())
+    }
+}
+
+/// A range of X11 IDs that can be shared between threads.
+///
+/// IDs are taken from this range without locking. Only when the range is exhausted, the
+/// [`IdPool`] needs to be consulted via [`AtomicIdRange::generate_id_from`]. Connections keep the
+/// `IdPool` behind a lock and try [`AtomicIdRange::generate_id`] first, so that threads creating
+/// lots of resources do not have to wait for each other.
+///
+/// On targets without 64 bit atomics, this range is always empty and all IDs come from the
+/// `IdPool`.
+#[derive(Debug)]
+pub struct AtomicIdRange {
+    /// The next ID in the upper 32 bits and the number of IDs that are left in the lower 32 bits.
+    #[cfg(target_has_atomic = "64")]
+    state: AtomicU64,
+    /// The difference between consecutive IDs.
+    #[cfg(target_has_atomic = "64")]
+    increment: u32,
+}
+
+/// Find the largest part of a range of IDs that does not contain any reserved ID.
+///
+/// The range consists of `count` IDs starting at `start` with a distance of `increment`.
+/// `reserved` contains inclusive ranges of IDs. Returns the start and count of the part.
+fn largest_free_part(
+    start: u32,
+    count: u32,
+    increment: u32,
+    reserved: &[(u32, u32)],
+) -> Option<(u32, u32)> {
+    // Convert the reserved IDs to inclusive ranges of indices into the range
+    let (start, count, increment) = (u64::from(start), u64::from(count), u64::from(increment));
+    let mut blocked = reserved
+        .iter()
+        .filter_map(|&(lo, hi)| {
+            let (lo, hi) = (u64::from(lo), u64::from(hi));
+            let first = (lo.saturating_sub(start) + increment - 1) / increment;
+            let last = hi.checked_sub(start)? / increment;
+            (first <= last && first < count).then_some((first, last.min(count - 1)))
+        })
+        .collect::<Vec<_>>();
+    blocked.sort_unstable();
+
+    // Find the largest gap between the blocked indices
+    let mut best = (0, 0);
+    let mut free_from = 0;
+    for (first, last) in blocked.into_iter().chain(core::iter::once((count, count))) {
+        if first > free_from && first - free_from > best.1 {
+            best = (free_from, first - free_from);
+        }
+        free_from = free_from.max(last + 1);
+    }
+    if best.1 == 0 {
+        return None;
+    }
+    // The values fit into u32, because the part is within the original range
+    Some(((start + best.0 * increment) as u32, best.1 as u32))
+}
+
+impl AtomicIdRange {
+    /// Create a new, empty range for IDs from the given allocator.
+    #[cfg_attr(not(target_has_atomic = "64"), allow(unused_variables))]
+    pub fn new(allocator: &IdAllocator) -> Self {
+        Self {
+            #[cfg(target_has_atomic = "64")]
+            state: AtomicU64::new(0),
+            #[cfg(target_has_atomic = "64")]
+            increment: allocator.increment,
+        }
+    }
+
+    /// Get the number of IDs that are left in this range.
+    pub fn remaining(&self) -> u32 {
+        self.current().map_or(0, |(_, count)| count)
+    }
+
+    /// Check whether only a few IDs are left in this range.
+    ///
+    /// This is a cheap check for whether [`IdPool::needs_refill`] should be consulted.
+    pub fn is_low(&self) -> bool {
+        self.remaining() < LOW_WATER
+    }
+
+    /// Get the next ID and the number of IDs that are left in this range.
+    fn current(&self) -> Option<(u32, u32)> {
+        #[cfg(target_has_atomic = "64")]
+        {
+            let state = self.state.load(Ordering::Relaxed);
+            Some(((state >> 32) as u32, state as u32))
+        }
+        #[cfg(not(target_has_atomic = "64"))]
+        None
+    }
+
+    /// Generate the next ID from this range without locking.
+    ///
+    /// Returns `None` if the range is exhausted.
+    pub fn generate_id(&self) -> Option<u32> {
+        #[cfg(target_has_atomic = "64")]
+        {
+            let increment = self.increment;
+            self.state
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |state| {
+                    let (next_id, count) = ((state >> 32) as u32, state as u32);
+                    if count == 0 {
+                        None
+                    } else {
+                        Some(Self::pack(next_id.wrapping_add(increment), count - 1))
+                    }
+                })
+                .ok()
+                .map(|state| (state >> 32) as u32)
+        }
+        #[cfg(not(target_has_atomic = "64"))]
+        None
+    }
+
+    /// Generate the next ID, refilling this range from `pool` if necessary.
+    ///
+    /// The caller must make sure that this function is not called concurrently for the same
+    /// range, e.g. by holding the lock that protects `pool`. Returns `None` if both this range
+    /// and `pool` are exhausted.
+    pub fn generate_id_from(&self, pool: &mut IdPool) -> Option<u32> {
+        if let Some(id) = self.generate_id() {
+            return Some(id);
+        }
+        #[cfg(target_has_atomic = "64")]
+        if let Some((next_id, count)) = pool.allocator.take_range() {
+            // The range is empty, so no other thread can modify it concurrently
+            self.state
+                .store(Self::pack(next_id, count), Ordering::Relaxed);
+            return self.generate_id();
+        }
+        pool.generate_id()
+    }
+
+    #[cfg(target_has_atomic = "64")]
+    fn pack(next_id: u32, count: u32) -> u64 {
+        (u64::from(next_id) << 32) | u64::from(count)
+    }
+}
+
 /// The XID range has been exhausted.
 #[derive(Debug, Copy, Clone)]
 pub struct IdsExhausted;
@@ -82,7 +358,11 @@ impl Error for IdsExhausted {}
 
 #[cfg(test)]
 mod test {
-    use super::{GetXIDRangeReply, IdAllocator, IdsExhausted};
+    use super::{
+        AtomicIdRange, GetXIDListReply, GetXIDRangeReply, IdAllocator, IdPool, IdsExhausted,
+    };
+    use alloc::vec;
+    use alloc::vec::Vec;
 
     #[test]
     fn exhaustive() {
@@ -124,6 +404,161 @@ mod test {
         assert_eq!(Some(0x13370), allocator.generate_id());
     }
 
+    #[test]
+    fn new_list() {
+        let mut pool = IdPool::new(IdAllocator::new(0x420, 2).unwrap());
+        assert_eq!(Some(0x420), pool.generate_id());
+        assert_eq!(Some(0x422), pool.generate_id());
+        assert_eq!(None, pool.generate_id());
+        let reply = GetXIDListReply {
+            sequence: 0,
+            length: 0,
+            ids: vec![0x13370, 0x42000, 0x13374],
+        };
+        pool.update_xid_list(&reply).unwrap();
+        assert_eq!(Some(0x13370), pool.generate_id());
+        assert_eq!(Some(0x42000), pool.generate_id());
+        assert_eq!(Some(0x13374), pool.generate_id());
+        assert_eq!(None, pool.generate_id());
+
+        let reply = GetXIDListReply {
+            ids: vec![],
+            ..reply
+        };
+        assert!(pool.update_xid_list(&reply).is_err());
+    }
+
+    #[test]
+    fn atomic_range() {
+        let allocator = IdAllocator::new(0x420, 6).unwrap();
+        let range = AtomicIdRange::new(&allocator);
+        let mut pool = IdPool::new(allocator);
+        assert_eq!(Some(0x420), range.generate_id_from(&mut pool));
+        assert_eq!(Some(0x422), range.generate_id_from(&mut pool));
+        assert_eq!(Some(0x424), range.generate_id_from(&mut pool));
+        assert_eq!(Some(0x426), range.generate_id_from(&mut pool));
+        assert_eq!(None, range.generate_id_from(&mut pool));
+        assert_eq!(None, range.generate_id());
+
+        pool.update_xid_range(&generate_get_xid_range_reply(0x13370, 2))
+            .unwrap();
+        let reply = GetXIDListReply {
+            sequence: 0,
+            length: 0,
+            ids: vec![0x42000],
+        };
+        pool.update_xid_list(&reply).unwrap();
+        assert_eq!(Some(0x13370), range.generate_id_from(&mut pool));
+        assert_eq!(Some(0x13372), range.generate_id_from(&mut pool));
+        assert_eq!(Some(0x42000), range.generate_id_from(&mut pool));
+        assert_eq!(None, range.generate_id_from(&mut pool));
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", target_has_atomic = "64"))]
+    fn atomic_range_threads() {
+        let allocator = IdAllocator::new(0, 0xfff).unwrap();
+        let range = AtomicIdRange::new(&allocator);
+        let mut pool = IdPool::new(allocator);
+        assert_eq!(Some(0), range.generate_id_from(&mut pool));
+        let mut ids = std::thread::scope(|scope| {
+            let threads = (0..4)
+                .map(|_| {
+                    scope
+                        .spawn(|| core::iter::from_fn(|| range.generate_id()).collect::<Vec<u32>>())
+                })
+                .collect::<Vec<_>>();
+            threads
+                .into_iter()
+                .flat_map(|thread| thread.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+        ids.sort_unstable();
+        assert_eq!(ids, (1..=0xfff).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn largest_free_part() {
+        use super::largest_free_part;
+
+        assert_eq!(largest_free_part(0x100, 16, 1, &[]), Some((0x100, 16)));
+        assert_eq!(
+            largest_free_part(0x100, 16, 1, &[(0x103, 0x104), (0x10a, 0x10a)]),
+            Some((0x105, 5))
+        );
+        // Reserved ranges that overlap each other or the ends of the range
+        assert_eq!(
+            largest_free_part(
+                0x100,
+                16,
+                1,
+                &[(0x80, 0x101), (0x108, 0x120), (0x100, 0x102)]
+            ),
+            Some((0x103, 5))
+        );
+        assert_eq!(largest_free_part(0x100, 16, 1, &[(0, u32::MAX)]), None);
+        // With an increment, only IDs of the range itself are relevant
+        assert_eq!(
+            largest_free_part(0x100, 8, 4, &[(0x105, 0x107), (0x111, 0x113)]),
+            Some((0x100, 8))
+        );
+        assert_eq!(
+            largest_free_part(0x100, 8, 4, &[(0x105, 0x108), (0x11b, 0x11c)]),
+            Some((0x10c, 4))
+        );
+    }
+
+    #[test]
+    #[cfg(target_has_atomic = "64")]
+    fn refill_ahead_of_time() {
+        let allocator = IdAllocator::new(0x1000, 0xff).unwrap();
+        let range = AtomicIdRange::new(&allocator);
+        let mut pool = IdPool::new(allocator);
+        assert!(!pool.needs_refill(&range));
+        for expected in 0x1000..0x10c1 {
+            assert_eq!(Some(expected), range.generate_id_from(&mut pool));
+        }
+        assert!(range.is_low());
+        assert!(pool.needs_refill(&range));
+        pool.start_refill(&range);
+        assert!(!pool.needs_refill(&range));
+
+        // The server considers the IDs that were not handed out yet as free
+        for expected in 0x10c1..=0x10ff {
+            assert_eq!(Some(expected), range.generate_id_from(&mut pool));
+        }
+        assert_eq!(None, range.generate_id_from(&mut pool));
+        pool.update_xid_range(&generate_get_xid_range_reply(0x1080, 0x80))
+            .unwrap();
+        for expected in 0x1080..=0x10c0 {
+            assert_eq!(Some(expected), range.generate_id_from(&mut pool));
+        }
+        assert_eq!(None, range.generate_id_from(&mut pool));
+        assert!(pool.needs_refill(&range));
+
+        // The same for GetXIDList
+        pool.start_refill(&range);
+        let reply = GetXIDListReply {
+            sequence: 0,
+            length: 0,
+            ids: vec![0x10c1, 0x10c2],
+        };
+        pool.update_xid_list(&reply).unwrap();
+        pool.start_refill(&range);
+        let reply = GetXIDListReply {
+            ids: vec![0x10c2, 0x10c3],
+            ..reply
+        };
+        pool.update_xid_list(&reply).unwrap();
+        assert_eq!(Some(0x10c3), range.generate_id_from(&mut pool));
+        assert_eq!(None, range.generate_id_from(&mut pool));
+
+        // A reply that only contains reserved IDs does not help
+        pool.update_xid_list(&reply).unwrap();
+        pool.start_refill(&range);
+        assert!(pool.update_xid_list(&reply).is_err());
+    }
+
     #[test]
     fn invalid_new_arg() {
         let err = IdAllocator::new(1234, 0).unwrap_err();
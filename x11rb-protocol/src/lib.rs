@@ -32,10 +32,16 @@
 //!
 //! Additionally, the following flags exist:
 //! * `std` (enabled by default): Enable functionality needing the std library, e.g. environment
-//!   variables or [`std::os::unix::io::OwnedFd`].
+//!   variables or [`std::os::unix::io::OwnedFd`]. Without this feature, the crate is `no_std` and
+//!   only needs `alloc`. Parsing and serializing all X11 packets still works, but the [xauth]
+//!   module and `$DISPLAY` handling are unavailable and [`RawFdContainer`] cannot hold file
+//!   descriptors.
 //! * `resource_manager`: Enable the code in [resource_manager] for loading and querying the
 //!   X11 resource database.
 //! * `serde`: Implement [`serde::Serialize`] and [`serde::Deserialize`] for all objects.
+//! * `arbitrary`: Implement `arbitrary::Arbitrary` for all objects. Objects that can be parsed are
+//!   generated by parsing the input, so that they are valid on the wire. Together with the helpers
+//!   in [testing], this allows to fuzz the parsers.
 //! * `request-parsing`: Add the ability to parse X11 requests. Not normally needed.
 //! * `extra-traits`: Implement extra traits for types. This improves the output of the `Debug`
 //!   impl and adds `PartialEq`, `Eq`, `PartialOrd`, `Ord`, and `Hash` where possible.
@@ -80,19 +86,26 @@ pub mod connection;
 #[macro_use]
 pub mod x11_utils;
 pub mod errors;
+pub mod event_filter;
 pub mod id_allocator;
 pub mod packet_reader;
 pub mod parse_display;
 #[rustfmt::skip]
 #[allow(missing_docs)]
 pub mod protocol;
+pub mod reply_ref;
 #[cfg(feature = "resource_manager")]
 pub mod resource_manager;
+pub mod send_event;
 #[cfg(test)]
 mod test;
+pub mod testing;
 mod utils;
+pub mod wire_parser;
 pub mod wrapper;
 pub mod xauth;
+#[cfg(feature = "xinput")]
+pub mod xinput_valuators;
 
 pub use utils::RawFdContainer;
 
@@ -6,7 +6,14 @@ use core::mem::replace;
 use alloc::{vec, vec::Vec};
 
 /// Minimal length of an X11 packet.
-const MINIMAL_PACKET_LENGTH: usize = 32;
+pub(crate) const MINIMAL_PACKET_LENGTH: usize = 32;
+
+/// The maximal number of buffers that are kept for reuse, see [`PacketReader::recycle`].
+pub const MAX_SPARE_BUFFERS: usize = 16;
+
+/// The capacity above which buffers are not kept for reuse, so that large replies do not stay
+/// around.
+const MAX_SPARE_BUFFER_CAPACITY: usize = 4096;
 
 /// A wrapper around a buffer used to read X11 packets.
 pub struct PacketReader {
@@ -15,6 +22,9 @@ pub struct PacketReader {
 
     /// The point at which the packet is already read.
     already_read: usize,
+
+    /// Buffers of packets that were handed back for reuse.
+    spare_buffers: Vec<Vec<u8>>,
 }
 
 impl fmt::Debug for PacketReader {
@@ -48,6 +58,19 @@ impl PacketReader {
         Self {
             pending_packet: vec![0; MINIMAL_PACKET_LENGTH],
             already_read: 0,
+            spare_buffers: Vec::new(),
+        }
+    }
+
+    /// Hand back the buffer of a packet that is no longer needed.
+    ///
+    /// The buffer is used for one of the following packets instead of allocating a new one. At
+    /// most [`MAX_SPARE_BUFFERS`] buffers are kept, and buffers of large packets are dropped.
+    pub fn recycle(&mut self, buffer: Vec<u8>) {
+        if self.spare_buffers.len() < MAX_SPARE_BUFFERS
+            && (MINIMAL_PACKET_LENGTH..=MAX_SPARE_BUFFER_CAPACITY).contains(&buffer.capacity())
+        {
+            self.spare_buffers.push(buffer);
         }
     }
 
@@ -100,15 +123,20 @@ impl PacketReader {
 
         // we've read in the full packet, return it
         self.already_read = 0;
-        Some(replace(
-            &mut self.pending_packet,
-            vec![0; MINIMAL_PACKET_LENGTH],
-        ))
+        let buffer = match self.spare_buffers.pop() {
+            Some(mut buffer) => {
+                buffer.clear();
+                buffer.resize(MINIMAL_PACKET_LENGTH, 0);
+                buffer
+            }
+            None => vec![0; MINIMAL_PACKET_LENGTH],
+        };
+        Some(replace(&mut self.pending_packet, buffer))
     }
 }
 
 /// Compute the length of the data we need to read, beyond the `MINIMAL_PACKET_LENGTH`.
-fn extra_length(buffer: &[u8]) -> usize {
+pub(crate) fn extra_length(buffer: &[u8]) -> usize {
     use crate::protocol::xproto::GE_GENERIC_EVENT;
     const REPLY: u8 = 1;
 
@@ -244,6 +272,21 @@ mod tests {
         test_packets(packets);
     }
 
+    #[test]
+    fn recycled_buffers_are_reused() {
+        let mut reader = PacketReader::new();
+        let first = reader.advance(32).unwrap();
+        let pointer = first.as_ptr();
+        reader.recycle(first);
+        reader.recycle(vec![0; 1 << 20]);
+
+        assert!(reader.advance(32).is_some());
+        // The buffer that was handed back is now used for the packet that is being read
+        assert_eq!(reader.buffer().as_ptr(), pointer);
+        assert_eq!(reader.buffer(), [0; 32]);
+        assert!(reader.spare_buffers.is_empty());
+    }
+
     #[test]
     fn test_debug_fixed_size_packet() {
         // The debug output includes the length of the packet of the packet and how much was
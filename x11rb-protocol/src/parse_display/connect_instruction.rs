@@ -1,7 +1,7 @@
 //! Provides the `ConnectInstruction` structure, which allows for a `ParsedDisplay`
 //! to be transformed into a server connection.
 
-use super::ParsedDisplay;
+use super::{ParsedDisplay, NAMED_PIPE_PREFIX};
 use alloc::format;
 use alloc::string::String;
 use alloc::vec::Vec;
@@ -17,6 +17,8 @@ pub enum ConnectAddress<'a> {
     /// First, the given path should be attempted in the abstract namespace. Only if that fails,
     /// then the named socket with the given name should be tried.
     Socket(String),
+    /// Connect to this named pipe on Windows, e.g. `\\.\pipe\X11\0`.
+    NamedPipe(String),
 }
 
 /// Get an iterator over all of the addresses we should target with a
@@ -32,10 +34,27 @@ pub(super) fn connect_addresses(p: &ParsedDisplay) -> impl Iterator<Item = Conne
 
     let mut targets = Vec::new();
 
+    // Named pipes on Windows are given by their full path
+    if protocol.as_deref() == Some("pipe") && host.starts_with(NAMED_PIPE_PREFIX) {
+        targets.push(ConnectAddress::NamedPipe(host.clone()));
+        return targets.into_iter();
+    }
+
+    // In "launchd mode", the host is the full path to a unix socket, e.g. on macOS with XQuartz
+    if protocol.as_deref() == Some("unix") && host.starts_with('/') {
+        targets.push(ConnectAddress::Socket(host.clone()));
+        return targets.into_iter();
+    }
+
     if (protocol.is_none() || protocol.as_deref() != Some("unix"))
         && !host.is_empty()
         && host != "unix"
     {
+        // IPv6 addresses may be written in brackets, e.g. "[::1]:0"
+        let host = host
+            .strip_prefix('[')
+            .and_then(|host| host.strip_suffix(']'))
+            .unwrap_or(host);
         targets.push(ConnectAddress::Hostname(host, TCP_PORT_BASE + display));
     } else {
         if protocol.is_none() || protocol.as_deref() == Some("unix") {
@@ -43,6 +62,7 @@ pub(super) fn connect_addresses(p: &ParsedDisplay) -> impl Iterator<Item = Conne
             targets.push(ConnectAddress::Socket(file_name));
         }
 
+        // Only the default protocol falls back to TCP on localhost
         if protocol.is_none() && host.is_empty() {
             targets.push(ConnectAddress::Hostname(
                 "localhost",
@@ -94,4 +114,55 @@ mod tests {
 
         assert_eq!(ci, vec![ConnectAddress::Socket("/tmp/.X11-unix/X0".into())]);
     }
+
+    #[test]
+    fn launchd_socket() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml");
+        for dpy_name in [path.into(), alloc::format!("{path}.1")] {
+            let pd = parse_display(Some(&dpy_name)).unwrap();
+            let ci = pd.connect_instruction().collect::<Vec<_>>();
+
+            assert_eq!(ci, vec![ConnectAddress::Socket(path.into())]);
+        }
+    }
+
+    #[test]
+    fn ipv6_literal() {
+        for (dpy_name, host) in [("[::1]:1", "::1"), ("::1:1", "::1"), ("tcp/[::1]:1", "::1")] {
+            let pd = parse_display(Some(dpy_name)).unwrap();
+            let ci = pd.connect_instruction().collect::<Vec<_>>();
+
+            assert_eq!(ci, vec![ConnectAddress::Hostname(host, 6001)]);
+        }
+    }
+
+    #[test]
+    fn named_pipe() {
+        for dpy_name in [r"\\.\pipe\X11\0", r"\\.\pipe\X11\0:0.1"] {
+            let pd = parse_display(Some(dpy_name)).unwrap();
+            let ci = pd.connect_instruction().collect::<Vec<_>>();
+
+            assert_eq!(
+                ci,
+                vec![ConnectAddress::NamedPipe(r"\\.\pipe\X11\0".into())]
+            );
+        }
+    }
+
+    #[test]
+    fn explicit_tcp() {
+        let pd = parse_display(Some("tcp/host:1")).unwrap();
+        let ci = pd.connect_instruction().collect::<Vec<_>>();
+
+        assert_eq!(ci, vec![ConnectAddress::Hostname("host", 6001)]);
+    }
+
+    #[test]
+    fn explicit_tcp_without_host() {
+        // An explicit protocol does not get the fallback to localhost
+        let pd = parse_display(Some("tcp/:0")).unwrap();
+        let ci = pd.connect_instruction().collect::<Vec<_>>();
+
+        assert_eq!(ci, vec![]);
+    }
 }
@@ -6,6 +6,9 @@ pub use connect_instruction::ConnectAddress;
 use crate::errors::DisplayParsingError;
 use alloc::string::{String, ToString};
 
+/// The prefix of the names of named pipes on Windows.
+const NAMED_PIPE_PREFIX: &str = r"\\.\pipe\";
+
 /// A parsed X11 display string.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ParsedDisplay {
@@ -69,6 +72,9 @@ pub fn parse_display_with_file_exists_callback(
     let malformed = || DisplayParsingError::MalformedValue(dpy_name.to_string().into());
     let map_malformed = |_| malformed();
 
+    if dpy_name.starts_with(NAMED_PIPE_PREFIX) {
+        return parse_display_named_pipe(dpy_name);
+    }
     if dpy_name.starts_with('/') {
         return parse_display_direct_path(dpy_name, file_exists);
     }
@@ -109,6 +115,28 @@ pub fn parse_display_with_file_exists_callback(
     })
 }
 
+// Named pipes on Windows are given by their full path, optionally followed by ":n" or ":n.m"
+fn parse_display_named_pipe(dpy_name: &str) -> Result<ParsedDisplay, DisplayParsingError> {
+    let malformed = |_| DisplayParsingError::MalformedValue(dpy_name.to_string().into());
+    let (path, display, screen) = match dpy_name.rsplit_once(':') {
+        Some((path, remaining)) => {
+            let (display, screen) = remaining.split_once('.').unwrap_or((remaining, "0"));
+            (
+                path,
+                display.parse().map_err(malformed)?,
+                screen.parse().map_err(malformed)?,
+            )
+        }
+        None => (dpy_name, 0, 0),
+    };
+    Ok(ParsedDisplay {
+        host: path.to_string(),
+        protocol: Some("pipe".to_string()),
+        display,
+        screen,
+    })
+}
+
 // Check for "launchd mode" where we get the full path to a unix socket
 fn parse_display_direct_path(
     dpy_name: &str,
@@ -136,6 +164,21 @@ fn parse_display_direct_path(
             });
         }
     }
+
+    // A display number may also be appended as ":n" or ":n.m", like in
+    // "unix:/run/user/1000/x11/socket:0".
+    if let Some((path, remaining)) = dpy_name.rsplit_once(':') {
+        if !path.is_empty() && file_exists(path) {
+            let malformed = |_| DisplayParsingError::MalformedValue(dpy_name.to_string().into());
+            let (display, screen) = remaining.split_once('.').unwrap_or((remaining, "0"));
+            return Ok(ParsedDisplay {
+                host: path.to_string(),
+                protocol: Some("unix".to_string()),
+                display: display.parse().map_err(malformed)?,
+                screen: screen.parse().map_err(malformed)?,
+            });
+        }
+    }
     Err(DisplayParsingError::MalformedValue(
         dpy_name.to_string().into(),
     ))
@@ -198,6 +241,24 @@ mod test {
                     screen: 2,
                 },
             ),
+            (
+                r"\\.\pipe\X11\0",
+                ParsedDisplay {
+                    host: r"\\.\pipe\X11\0".to_string(),
+                    protocol: Some("pipe".to_string()),
+                    display: 0,
+                    screen: 0,
+                },
+            ),
+            (
+                r"\\.\pipe\X11\0:1.2",
+                ParsedDisplay {
+                    host: r"\\.\pipe\X11\0".to_string(),
+                    protocol: Some("pipe".to_string()),
+                    display: 1,
+                    screen: 2,
+                },
+            ),
             (
                 "a:b/c/foo:bar:1.2",
                 ParsedDisplay {
@@ -628,6 +689,32 @@ mod test {
         run_test("unix:whatever.42", "whatever");
     }
 
+    #[test]
+    fn test_file_exists_callback_direct_path_with_display() {
+        let path = "/run/user/1000/x11/socket";
+        let callback = |candidate: &str| candidate == path;
+        for (display, expected) in [
+            ("unix:/run/user/1000/x11/socket:1", Ok((1, 0))),
+            ("/run/user/1000/x11/socket:2.3", Ok((2, 3))),
+            ("unix:/run/user/1000/x11/socket:a", Err(())),
+            ("unix:/run/user/1000/x11/other:1", Err(())),
+        ] {
+            let result = parse_display_with_file_exists_callback(display, callback);
+            let expected = expected
+                .map(|(display, screen)| ParsedDisplay {
+                    host: path.to_string(),
+                    protocol: Some("unix".to_string()),
+                    display,
+                    screen,
+                })
+                .map_err(|_| {
+                    let value = display.strip_prefix("unix:").unwrap_or(display);
+                    DisplayParsingError::MalformedValue(value.to_string().into())
+                });
+            assert_eq!(result, expected, "Unexpected result for {}", display);
+        }
+    }
+
     #[test]
     fn test_file_exists_callback_not_called_without_path() {
         let callback = |path: &str| unreachable!("Called with {path}");
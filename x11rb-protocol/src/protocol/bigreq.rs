@@ -22,6 +22,7 @@ use crate::BufWithFds;
 use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::x11_utils::RequestDescription;
 
 /// The X11 name of the extension for QueryExtension
 pub const X11_EXTENSION_NAME: &str = "BIG-REQUESTS";
@@ -45,6 +46,7 @@ pub const ENABLE_REQUEST: u8 = 0;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct EnableRequest;
 impl_debug_if_no_extra_traits!(EnableRequest, "EnableRequest");
 impl EnableRequest {
@@ -83,6 +85,28 @@ impl Request for EnableRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            ENABLE_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for EnableRequest {
     type Reply = EnableReply;
@@ -97,9 +121,16 @@ impl crate::x11_utils::ReplyRequest for EnableRequest {
 pub struct EnableReply {
     pub sequence: u16,
     pub length: u32,
+    /// The maximum length of requests supported by the server, in 4-byte units.
     pub maximum_request_length: u32,
 }
 impl_debug_if_no_extra_traits!(EnableReply, "EnableReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for EnableReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for EnableReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -151,3 +182,8 @@ impl Serialize for EnableReply {
     }
 }
 
+/// All requests of this module.
+pub const REQUESTS: &[RequestDescription] = &[
+    RequestDescription { opcode: ENABLE_REQUEST, name: "Enable", has_reply: true },
+];
+
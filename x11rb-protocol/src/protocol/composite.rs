@@ -22,6 +22,7 @@ use crate::BufWithFds;
 use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::x11_utils::RequestDescription;
 #[allow(unused_imports)]
 use super::xfixes;
 #[allow(unused_imports)]
@@ -40,6 +41,7 @@ pub const X11_XML_VERSION: (u32, u32) = (0, 4);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Redirect(u8);
 impl Redirect {
     pub const AUTOMATIC: Self = Self(0);
@@ -96,6 +98,21 @@ impl core::fmt::Debug for Redirect  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl Redirect {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Automatic"),
+            1 => Some("Manual"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Redirect {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the QueryVersion request
 pub const QUERY_VERSION_REQUEST: u8 = 0;
@@ -111,8 +128,11 @@ pub const QUERY_VERSION_REQUEST: u8 = 0;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryVersionRequest {
+    /// The major version supported by the client.
     pub client_major_version: u32,
+    /// The minor version supported by the client.
     pub client_minor_version: u32,
 }
 impl_debug_if_no_extra_traits!(QueryVersionRequest, "QueryVersionRequest");
@@ -166,6 +186,38 @@ impl Request for QueryVersionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let client_major_version_bytes = self.client_major_version.serialize();
+        let client_minor_version_bytes = self.client_minor_version.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+            client_major_version_bytes[0],
+            client_major_version_bytes[1],
+            client_major_version_bytes[2],
+            client_major_version_bytes[3],
+            client_minor_version_bytes[0],
+            client_minor_version_bytes[1],
+            client_minor_version_bytes[2],
+            client_minor_version_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryVersionRequest {
     type Reply = QueryVersionReply;
@@ -186,10 +238,18 @@ impl crate::x11_utils::ReplyRequest for QueryVersionRequest {
 pub struct QueryVersionReply {
     pub sequence: u16,
     pub length: u32,
+    /// The major version chosen by the server.
     pub major_version: u32,
+    /// The minor version chosen by the server.
     pub minor_version: u32,
 }
 impl_debug_if_no_extra_traits!(QueryVersionReply, "QueryVersionReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryVersionReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -286,8 +346,13 @@ pub const REDIRECT_WINDOW_REQUEST: u8 = 1;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct RedirectWindowRequest {
+    /// The root of the hierarchy to redirect to off-screen storage.
     pub window: xproto::Window,
+    /// Whether contents are automatically mirrored to the parent window.  If one client
+    /// already specifies an update type of Manual, any attempt by another to specify a
+    /// mode of Manual so will result in an Access error.
     pub update: Redirect,
 }
 impl_debug_if_no_extra_traits!(RedirectWindowRequest, "RedirectWindowRequest");
@@ -343,6 +408,38 @@ impl Request for RedirectWindowRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let update_bytes = u8::from(self.update).serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            REDIRECT_WINDOW_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            update_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for RedirectWindowRequest {
 }
@@ -365,8 +462,13 @@ pub const REDIRECT_SUBWINDOWS_REQUEST: u8 = 2;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct RedirectSubwindowsRequest {
+    /// The root of the hierarchy to redirect to off-screen storage.
     pub window: xproto::Window,
+    /// Whether contents are automatically mirrored to the parent window.  If one client
+    /// already specifies an update type of Manual, any attempt by another to specify a
+    /// mode of Manual so will result in an Access error.
     pub update: Redirect,
 }
 impl_debug_if_no_extra_traits!(RedirectSubwindowsRequest, "RedirectSubwindowsRequest");
@@ -422,6 +524,38 @@ impl Request for RedirectSubwindowsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let update_bytes = u8::from(self.update).serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            REDIRECT_SUBWINDOWS_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            update_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for RedirectSubwindowsRequest {
 }
@@ -442,8 +576,13 @@ pub const UNREDIRECT_WINDOW_REQUEST: u8 = 3;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct UnredirectWindowRequest {
+    /// The window to terminate redirection of.  Must be redirected by the
+    /// current client, or a Value error results.
     pub window: xproto::Window,
+    /// The update type passed to RedirectWindows.  If this does not match the
+    /// previously requested update type, a Value error results.
     pub update: Redirect,
 }
 impl_debug_if_no_extra_traits!(UnredirectWindowRequest, "UnredirectWindowRequest");
@@ -499,6 +638,38 @@ impl Request for UnredirectWindowRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let update_bytes = u8::from(self.update).serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            UNREDIRECT_WINDOW_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            update_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for UnredirectWindowRequest {
 }
@@ -519,8 +690,14 @@ pub const UNREDIRECT_SUBWINDOWS_REQUEST: u8 = 4;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct UnredirectSubwindowsRequest {
+    /// The window to terminate redirection of.  Must have previously been
+    /// selected for sub-redirection by the current client, or a Value error
+    /// results.
     pub window: xproto::Window,
+    /// The update type passed to RedirectSubWindows.  If this does not match
+    /// the previously requested update type, a Value error results.
     pub update: Redirect,
 }
 impl_debug_if_no_extra_traits!(UnredirectSubwindowsRequest, "UnredirectSubwindowsRequest");
@@ -576,6 +753,38 @@ impl Request for UnredirectSubwindowsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let update_bytes = u8::from(self.update).serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            UNREDIRECT_SUBWINDOWS_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            update_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for UnredirectSubwindowsRequest {
 }
@@ -585,6 +794,7 @@ pub const CREATE_REGION_FROM_BORDER_CLIP_REQUEST: u8 = 5;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateRegionFromBorderClipRequest {
     pub region: xfixes::Region,
     pub window: xproto::Window,
@@ -640,6 +850,38 @@ impl Request for CreateRegionFromBorderClipRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let region_bytes = self.region.serialize();
+        let window_bytes = self.window.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CREATE_REGION_FROM_BORDER_CLIP_REQUEST,
+            0,
+            0,
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for CreateRegionFromBorderClipRequest {
 }
@@ -649,6 +891,7 @@ pub const NAME_WINDOW_PIXMAP_REQUEST: u8 = 6;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct NameWindowPixmapRequest {
     pub window: xproto::Window,
     pub pixmap: xproto::Pixmap,
@@ -704,6 +947,38 @@ impl Request for NameWindowPixmapRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let pixmap_bytes = self.pixmap.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            NAME_WINDOW_PIXMAP_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            pixmap_bytes[0],
+            pixmap_bytes[1],
+            pixmap_bytes[2],
+            pixmap_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for NameWindowPixmapRequest {
 }
@@ -713,6 +988,7 @@ pub const GET_OVERLAY_WINDOW_REQUEST: u8 = 7;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetOverlayWindowRequest {
     pub window: xproto::Window,
 }
@@ -760,6 +1036,33 @@ impl Request for GetOverlayWindowRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_OVERLAY_WINDOW_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetOverlayWindowRequest {
     type Reply = GetOverlayWindowReply;
@@ -774,6 +1077,12 @@ pub struct GetOverlayWindowReply {
     pub overlay_win: xproto::Window,
 }
 impl_debug_if_no_extra_traits!(GetOverlayWindowReply, "GetOverlayWindowReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetOverlayWindowReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetOverlayWindowReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -852,6 +1161,7 @@ pub const RELEASE_OVERLAY_WINDOW_REQUEST: u8 = 8;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ReleaseOverlayWindowRequest {
     pub window: xproto::Window,
 }
@@ -899,7 +1209,47 @@ impl Request for ReleaseOverlayWindowRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            RELEASE_OVERLAY_WINDOW_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for ReleaseOverlayWindowRequest {
 }
 
+/// All requests of this module.
+pub const REQUESTS: &[RequestDescription] = &[
+    RequestDescription { opcode: QUERY_VERSION_REQUEST, name: "QueryVersion", has_reply: true },
+    RequestDescription { opcode: REDIRECT_WINDOW_REQUEST, name: "RedirectWindow", has_reply: false },
+    RequestDescription { opcode: REDIRECT_SUBWINDOWS_REQUEST, name: "RedirectSubwindows", has_reply: false },
+    RequestDescription { opcode: UNREDIRECT_WINDOW_REQUEST, name: "UnredirectWindow", has_reply: false },
+    RequestDescription { opcode: UNREDIRECT_SUBWINDOWS_REQUEST, name: "UnredirectSubwindows", has_reply: false },
+    RequestDescription { opcode: CREATE_REGION_FROM_BORDER_CLIP_REQUEST, name: "CreateRegionFromBorderClip", has_reply: false },
+    RequestDescription { opcode: NAME_WINDOW_PIXMAP_REQUEST, name: "NameWindowPixmap", has_reply: false },
+    RequestDescription { opcode: GET_OVERLAY_WINDOW_REQUEST, name: "GetOverlayWindow", has_reply: true },
+    RequestDescription { opcode: RELEASE_OVERLAY_WINDOW_REQUEST, name: "ReleaseOverlayWindow", has_reply: false },
+];
+
@@ -22,6 +22,7 @@ use crate::BufWithFds;
 use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::x11_utils::RequestDescription;
 #[allow(unused_imports)]
 use super::xfixes;
 #[allow(unused_imports)]
@@ -42,6 +43,7 @@ pub type Damage = u32;
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ReportLevel(u8);
 impl ReportLevel {
     pub const RAW_RECTANGLES: Self = Self(0);
@@ -102,6 +104,23 @@ impl core::fmt::Debug for ReportLevel  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl ReportLevel {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("RawRectangles"),
+            1 => Some("DeltaRectangles"),
+            2 => Some("BoundingBox"),
+            3 => Some("NonEmpty"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ReportLevel {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the BadDamage error
 pub const BAD_DAMAGE_ERROR: u8 = 0;
@@ -121,8 +140,11 @@ pub const QUERY_VERSION_REQUEST: u8 = 0;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryVersionRequest {
+    /// The major version supported by the client.
     pub client_major_version: u32,
+    /// The minor version supported by the client.
     pub client_minor_version: u32,
 }
 impl_debug_if_no_extra_traits!(QueryVersionRequest, "QueryVersionRequest");
@@ -176,6 +198,38 @@ impl Request for QueryVersionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let client_major_version_bytes = self.client_major_version.serialize();
+        let client_minor_version_bytes = self.client_minor_version.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+            client_major_version_bytes[0],
+            client_major_version_bytes[1],
+            client_major_version_bytes[2],
+            client_major_version_bytes[3],
+            client_minor_version_bytes[0],
+            client_minor_version_bytes[1],
+            client_minor_version_bytes[2],
+            client_minor_version_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryVersionRequest {
     type Reply = QueryVersionReply;
@@ -196,10 +250,18 @@ impl crate::x11_utils::ReplyRequest for QueryVersionRequest {
 pub struct QueryVersionReply {
     pub sequence: u16,
     pub length: u32,
+    /// The major version chosen by the server.
     pub major_version: u32,
+    /// The minor version chosen by the server.
     pub minor_version: u32,
 }
 impl_debug_if_no_extra_traits!(QueryVersionReply, "QueryVersionReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryVersionReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -314,9 +376,14 @@ pub const CREATE_REQUEST: u8 = 1;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateRequest {
+    /// The ID with which you will refer to the new Damage object, created by
+    /// `xcb_generate_id`.
     pub damage: Damage,
+    /// The ID of the drawable to be monitored.
     pub drawable: xproto::Drawable,
+    /// The level of detail to be provided in Damage events.
     pub level: ReportLevel,
 }
 impl_debug_if_no_extra_traits!(CreateRequest, "CreateRequest");
@@ -379,6 +446,43 @@ impl Request for CreateRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let damage_bytes = self.damage.serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let level_bytes = u8::from(self.level).serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CREATE_REQUEST,
+            0,
+            0,
+            damage_bytes[0],
+            damage_bytes[1],
+            damage_bytes[2],
+            damage_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            level_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for CreateRequest {
 }
@@ -396,7 +500,9 @@ pub const DESTROY_REQUEST: u8 = 2;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DestroyRequest {
+    /// The ID you provided to `xcb_create_damage`.
     pub damage: Damage,
 }
 impl_debug_if_no_extra_traits!(DestroyRequest, "DestroyRequest");
@@ -443,6 +549,33 @@ impl Request for DestroyRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let damage_bytes = self.damage.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            DESTROY_REQUEST,
+            0,
+            0,
+            damage_bytes[0],
+            damage_bytes[1],
+            damage_bytes[2],
+            damage_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for DestroyRequest {
 }
@@ -461,7 +594,9 @@ pub const SUBTRACT_REQUEST: u8 = 3;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SubtractRequest {
+    /// The ID you provided to `xcb_create_damage`.
     pub damage: Damage,
     pub repair: xfixes::Region,
     pub parts: xfixes::Region,
@@ -524,6 +659,43 @@ impl Request for SubtractRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let damage_bytes = self.damage.serialize();
+        let repair_bytes = self.repair.serialize();
+        let parts_bytes = self.parts.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SUBTRACT_REQUEST,
+            0,
+            0,
+            damage_bytes[0],
+            damage_bytes[1],
+            damage_bytes[2],
+            damage_bytes[3],
+            repair_bytes[0],
+            repair_bytes[1],
+            repair_bytes[2],
+            repair_bytes[3],
+            parts_bytes[0],
+            parts_bytes[1],
+            parts_bytes[2],
+            parts_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SubtractRequest {
 }
@@ -542,6 +714,7 @@ pub const ADD_REQUEST: u8 = 4;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct AddRequest {
     pub drawable: xproto::Drawable,
     pub region: xfixes::Region,
@@ -597,6 +770,38 @@ impl Request for AddRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        let region_bytes = self.region.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            ADD_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for AddRequest {
 }
@@ -624,15 +829,29 @@ pub const NOTIFY_EVENT: u8 = 0;
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NotifyEvent {
     pub response_type: u8,
+    /// The level of the damage being reported.
+    /// If the 0x80 bit is set, indicates there are subsequent Damage events
+    /// being delivered immediately as part of a larger Damage region.
     pub level: ReportLevel,
     pub sequence: u16,
+    /// The drawable for which damage is being reported.
     pub drawable: xproto::Drawable,
+    /// The Damage object being used to track the damage.
     pub damage: Damage,
+    /// Time when the event was generated (in milliseconds).
     pub timestamp: xproto::Timestamp,
+    /// Damaged area of the drawable.
     pub area: xproto::Rectangle,
+    /// Total area of the drawable.
     pub geometry: xproto::Rectangle,
 }
 impl_debug_if_no_extra_traits!(NotifyEvent, "NotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for NotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for NotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -762,3 +981,12 @@ impl From<NotifyEvent> for [u8; 32] {
     }
 }
 
+/// All requests of this module.
+pub const REQUESTS: &[RequestDescription] = &[
+    RequestDescription { opcode: QUERY_VERSION_REQUEST, name: "QueryVersion", has_reply: true },
+    RequestDescription { opcode: CREATE_REQUEST, name: "Create", has_reply: false },
+    RequestDescription { opcode: DESTROY_REQUEST, name: "Destroy", has_reply: false },
+    RequestDescription { opcode: SUBTRACT_REQUEST, name: "Subtract", has_reply: false },
+    RequestDescription { opcode: ADD_REQUEST, name: "Add", has_reply: false },
+];
+
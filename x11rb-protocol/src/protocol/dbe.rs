@@ -22,6 +22,7 @@ use crate::BufWithFds;
 use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::x11_utils::RequestDescription;
 #[allow(unused_imports)]
 use super::xproto;
 
@@ -48,6 +49,7 @@ pub type BackBuffer = xproto::Drawable;
 /// * `Copied` - Copy the newly displayed front buffer.
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SwapAction(u8);
 impl SwapAction {
     pub const UNDEFINED: Self = Self(0);
@@ -108,6 +110,23 @@ impl core::fmt::Debug for SwapAction  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl SwapAction {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Undefined"),
+            1 => Some("Background"),
+            2 => Some("Untouched"),
+            3 => Some("Copied"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for SwapAction {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
@@ -117,6 +136,12 @@ pub struct SwapInfo {
     pub swap_action: SwapAction,
 }
 impl_debug_if_no_extra_traits!(SwapInfo, "SwapInfo");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SwapInfo {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SwapInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (window, remaining) = xproto::Window::try_parse(remaining)?;
@@ -158,6 +183,12 @@ pub struct BufferAttributes {
     pub window: xproto::Window,
 }
 impl_debug_if_no_extra_traits!(BufferAttributes, "BufferAttributes");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for BufferAttributes {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for BufferAttributes {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (window, remaining) = xproto::Window::try_parse(remaining)?;
@@ -191,6 +222,12 @@ pub struct VisualInfo {
     pub perf_level: u8,
 }
 impl_debug_if_no_extra_traits!(VisualInfo, "VisualInfo");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for VisualInfo {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for VisualInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (visual_id, remaining) = xproto::Visualid::try_parse(remaining)?;
@@ -234,6 +271,12 @@ pub struct VisualInfos {
     pub infos: Vec<VisualInfo>,
 }
 impl_debug_if_no_extra_traits!(VisualInfos, "VisualInfos");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for VisualInfos {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for VisualInfos {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (n_infos, remaining) = u32::try_parse(remaining)?;
@@ -287,8 +330,11 @@ pub const QUERY_VERSION_REQUEST: u8 = 0;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryVersionRequest {
+    /// The major version of the extension. Check that it is compatible with the XCB_DBE_MAJOR_VERSION that your code is compiled with.
     pub major_version: u8,
+    /// The minor version of the extension. Check that it is compatible with the XCB_DBE_MINOR_VERSION that your code is compiled with.
     pub minor_version: u8,
 }
 impl_debug_if_no_extra_traits!(QueryVersionRequest, "QueryVersionRequest");
@@ -339,6 +385,34 @@ impl Request for QueryVersionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let major_version_bytes = self.major_version.serialize();
+        let minor_version_bytes = self.minor_version.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+            major_version_bytes[0],
+            minor_version_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryVersionRequest {
     type Reply = QueryVersionReply;
@@ -354,6 +428,12 @@ pub struct QueryVersionReply {
     pub minor_version: u8,
 }
 impl_debug_if_no_extra_traits!(QueryVersionReply, "QueryVersionReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryVersionReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -444,9 +524,13 @@ pub const ALLOCATE_BACK_BUFFER_REQUEST: u8 = 1;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct AllocateBackBufferRequest {
+    /// The window to which to add the back buffer.
     pub window: xproto::Window,
+    /// The buffer id to associate with the back buffer.
     pub buffer: BackBuffer,
+    /// The swap action most likely to be used to present this back buffer. This is only a hint, and does not preclude the use of other swap actions.
     pub swap_action: u8,
 }
 impl_debug_if_no_extra_traits!(AllocateBackBufferRequest, "AllocateBackBufferRequest");
@@ -508,6 +592,43 @@ impl Request for AllocateBackBufferRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let buffer_bytes = self.buffer.serialize();
+        let swap_action_bytes = self.swap_action.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            ALLOCATE_BACK_BUFFER_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            buffer_bytes[0],
+            buffer_bytes[1],
+            buffer_bytes[2],
+            buffer_bytes[3],
+            swap_action_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for AllocateBackBufferRequest {
 }
@@ -524,7 +645,9 @@ pub const DEALLOCATE_BACK_BUFFER_REQUEST: u8 = 2;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DeallocateBackBufferRequest {
+    /// The back buffer to deallocate.
     pub buffer: BackBuffer,
 }
 impl_debug_if_no_extra_traits!(DeallocateBackBufferRequest, "DeallocateBackBufferRequest");
@@ -571,6 +694,33 @@ impl Request for DeallocateBackBufferRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let buffer_bytes = self.buffer.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            DEALLOCATE_BACK_BUFFER_REQUEST,
+            0,
+            0,
+            buffer_bytes[0],
+            buffer_bytes[1],
+            buffer_bytes[2],
+            buffer_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for DeallocateBackBufferRequest {
 }
@@ -587,7 +737,9 @@ pub const SWAP_BUFFERS_REQUEST: u8 = 3;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SwapBuffersRequest<'input> {
+    /// List of windows on which to swap buffers.
     pub actions: Cow<'input, [SwapInfo]>,
 }
 impl_debug_if_no_extra_traits!(SwapBuffersRequest<'_>, "SwapBuffersRequest");
@@ -646,6 +798,37 @@ impl<'input> Request for SwapBuffersRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let n_actions = u32::try_from(self.actions.len()).expect("`actions` has too many elements");
+        let n_actions_bytes = n_actions.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SWAP_BUFFERS_REQUEST,
+            0,
+            0,
+            n_actions_bytes[0],
+            n_actions_bytes[1],
+            n_actions_bytes[2],
+            n_actions_bytes[3],
+        ]);
+        self.actions.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for SwapBuffersRequest<'input> {
 }
@@ -658,6 +841,7 @@ pub const BEGIN_IDIOM_REQUEST: u8 = 4;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct BeginIdiomRequest;
 impl_debug_if_no_extra_traits!(BeginIdiomRequest, "BeginIdiomRequest");
 impl BeginIdiomRequest {
@@ -696,6 +880,28 @@ impl Request for BeginIdiomRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            BEGIN_IDIOM_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for BeginIdiomRequest {
 }
@@ -706,6 +912,7 @@ pub const END_IDIOM_REQUEST: u8 = 5;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct EndIdiomRequest;
 impl_debug_if_no_extra_traits!(EndIdiomRequest, "EndIdiomRequest");
 impl EndIdiomRequest {
@@ -744,6 +951,28 @@ impl Request for EndIdiomRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            END_IDIOM_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for EndIdiomRequest {
 }
@@ -754,6 +983,7 @@ pub const GET_VISUAL_INFO_REQUEST: u8 = 6;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetVisualInfoRequest<'input> {
     pub drawables: Cow<'input, [xproto::Drawable]>,
 }
@@ -813,6 +1043,37 @@ impl<'input> Request for GetVisualInfoRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let n_drawables = u32::try_from(self.drawables.len()).expect("`drawables` has too many elements");
+        let n_drawables_bytes = n_drawables.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_VISUAL_INFO_REQUEST,
+            0,
+            0,
+            n_drawables_bytes[0],
+            n_drawables_bytes[1],
+            n_drawables_bytes[2],
+            n_drawables_bytes[3],
+        ]);
+        self.drawables.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::ReplyRequest for GetVisualInfoRequest<'input> {
     type Reply = GetVisualInfoReply;
@@ -827,6 +1088,12 @@ pub struct GetVisualInfoReply {
     pub supported_visuals: Vec<VisualInfos>,
 }
 impl_debug_if_no_extra_traits!(GetVisualInfoReply, "GetVisualInfoReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetVisualInfoReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetVisualInfoReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -865,6 +1132,7 @@ impl Serialize for GetVisualInfoReply {
         n_supported_visuals.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 20]);
         self.supported_visuals.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetVisualInfoReply {
@@ -896,7 +1164,9 @@ pub const GET_BACK_BUFFER_ATTRIBUTES_REQUEST: u8 = 7;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetBackBufferAttributesRequest {
+    /// The back buffer to query.
     pub buffer: BackBuffer,
 }
 impl_debug_if_no_extra_traits!(GetBackBufferAttributesRequest, "GetBackBufferAttributesRequest");
@@ -943,6 +1213,33 @@ impl Request for GetBackBufferAttributesRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let buffer_bytes = self.buffer.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_BACK_BUFFER_ATTRIBUTES_REQUEST,
+            0,
+            0,
+            buffer_bytes[0],
+            buffer_bytes[1],
+            buffer_bytes[2],
+            buffer_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetBackBufferAttributesRequest {
     type Reply = GetBackBufferAttributesReply;
@@ -957,6 +1254,12 @@ pub struct GetBackBufferAttributesReply {
     pub attributes: BufferAttributes,
 }
 impl_debug_if_no_extra_traits!(GetBackBufferAttributesReply, "GetBackBufferAttributesReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetBackBufferAttributesReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetBackBufferAttributesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1030,3 +1333,15 @@ impl Serialize for GetBackBufferAttributesReply {
     }
 }
 
+/// All requests of this module.
+pub const REQUESTS: &[RequestDescription] = &[
+    RequestDescription { opcode: QUERY_VERSION_REQUEST, name: "QueryVersion", has_reply: true },
+    RequestDescription { opcode: ALLOCATE_BACK_BUFFER_REQUEST, name: "AllocateBackBuffer", has_reply: false },
+    RequestDescription { opcode: DEALLOCATE_BACK_BUFFER_REQUEST, name: "DeallocateBackBuffer", has_reply: false },
+    RequestDescription { opcode: SWAP_BUFFERS_REQUEST, name: "SwapBuffers", has_reply: false },
+    RequestDescription { opcode: BEGIN_IDIOM_REQUEST, name: "BeginIdiom", has_reply: false },
+    RequestDescription { opcode: END_IDIOM_REQUEST, name: "EndIdiom", has_reply: false },
+    RequestDescription { opcode: GET_VISUAL_INFO_REQUEST, name: "GetVisualInfo", has_reply: true },
+    RequestDescription { opcode: GET_BACK_BUFFER_ATTRIBUTES_REQUEST, name: "GetBackBufferAttributes", has_reply: true },
+];
+
@@ -22,6 +22,7 @@ use crate::BufWithFds;
 use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::x11_utils::RequestDescription;
 #[allow(unused_imports)]
 use super::xproto;
 
@@ -41,6 +42,7 @@ pub const GET_VERSION_REQUEST: u8 = 0;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetVersionRequest {
     pub client_major_version: u16,
     pub client_minor_version: u16,
@@ -92,6 +94,34 @@ impl Request for GetVersionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let client_major_version_bytes = self.client_major_version.serialize();
+        let client_minor_version_bytes = self.client_minor_version.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_VERSION_REQUEST,
+            0,
+            0,
+            client_major_version_bytes[0],
+            client_major_version_bytes[1],
+            client_minor_version_bytes[0],
+            client_minor_version_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetVersionRequest {
     type Reply = GetVersionReply;
@@ -107,6 +137,12 @@ pub struct GetVersionReply {
     pub server_minor_version: u16,
 }
 impl_debug_if_no_extra_traits!(GetVersionReply, "GetVersionReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetVersionReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -166,6 +202,7 @@ pub const CAPABLE_REQUEST: u8 = 1;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CapableRequest;
 impl_debug_if_no_extra_traits!(CapableRequest, "CapableRequest");
 impl CapableRequest {
@@ -204,6 +241,28 @@ impl Request for CapableRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CAPABLE_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for CapableRequest {
     type Reply = CapableReply;
@@ -218,6 +277,12 @@ pub struct CapableReply {
     pub capable: bool,
 }
 impl_debug_if_no_extra_traits!(CapableReply, "CapableReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CapableReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for CapableReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -296,6 +361,7 @@ pub const GET_TIMEOUTS_REQUEST: u8 = 2;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetTimeoutsRequest;
 impl_debug_if_no_extra_traits!(GetTimeoutsRequest, "GetTimeoutsRequest");
 impl GetTimeoutsRequest {
@@ -334,6 +400,28 @@ impl Request for GetTimeoutsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_TIMEOUTS_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetTimeoutsRequest {
     type Reply = GetTimeoutsReply;
@@ -350,6 +438,12 @@ pub struct GetTimeoutsReply {
     pub off_timeout: u16,
 }
 impl_debug_if_no_extra_traits!(GetTimeoutsReply, "GetTimeoutsReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetTimeoutsReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetTimeoutsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -434,6 +528,7 @@ pub const SET_TIMEOUTS_REQUEST: u8 = 3;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetTimeoutsRequest {
     pub standby_timeout: u16,
     pub suspend_timeout: u16,
@@ -493,6 +588,39 @@ impl Request for SetTimeoutsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let standby_timeout_bytes = self.standby_timeout.serialize();
+        let suspend_timeout_bytes = self.suspend_timeout.serialize();
+        let off_timeout_bytes = self.off_timeout.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_TIMEOUTS_REQUEST,
+            0,
+            0,
+            standby_timeout_bytes[0],
+            standby_timeout_bytes[1],
+            suspend_timeout_bytes[0],
+            suspend_timeout_bytes[1],
+            off_timeout_bytes[0],
+            off_timeout_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SetTimeoutsRequest {
 }
@@ -502,6 +630,7 @@ pub const ENABLE_REQUEST: u8 = 4;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct EnableRequest;
 impl_debug_if_no_extra_traits!(EnableRequest, "EnableRequest");
 impl EnableRequest {
@@ -540,6 +669,28 @@ impl Request for EnableRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            ENABLE_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for EnableRequest {
 }
@@ -549,6 +700,7 @@ pub const DISABLE_REQUEST: u8 = 5;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DisableRequest;
 impl_debug_if_no_extra_traits!(DisableRequest, "DisableRequest");
 impl DisableRequest {
@@ -587,12 +739,35 @@ impl Request for DisableRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            DISABLE_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for DisableRequest {
 }
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DPMSMode(u16);
 impl DPMSMode {
     pub const ON: Self = Self(0);
@@ -647,12 +822,30 @@ impl core::fmt::Debug for DPMSMode  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl DPMSMode {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("On"),
+            1 => Some("Standby"),
+            2 => Some("Suspend"),
+            3 => Some("Off"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for DPMSMode {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the ForceLevel request
 pub const FORCE_LEVEL_REQUEST: u8 = 6;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ForceLevelRequest {
     pub power_level: DPMSMode,
 }
@@ -701,6 +894,33 @@ impl Request for ForceLevelRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let power_level_bytes = u16::from(self.power_level).serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            FORCE_LEVEL_REQUEST,
+            0,
+            0,
+            power_level_bytes[0],
+            power_level_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for ForceLevelRequest {
 }
@@ -710,6 +930,7 @@ pub const INFO_REQUEST: u8 = 7;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct InfoRequest;
 impl_debug_if_no_extra_traits!(InfoRequest, "InfoRequest");
 impl InfoRequest {
@@ -748,6 +969,28 @@ impl Request for InfoRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            INFO_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for InfoRequest {
     type Reply = InfoReply;
@@ -763,6 +1006,12 @@ pub struct InfoReply {
     pub state: bool,
 }
 impl_debug_if_no_extra_traits!(InfoReply, "InfoReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for InfoReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for InfoReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -842,6 +1091,7 @@ impl Serialize for InfoReply {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct EventMask(u32);
 impl EventMask {
     pub const INFO_NOTIFY: Self = Self(1 << 0);
@@ -884,6 +1134,20 @@ impl core::fmt::Debug for EventMask  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl EventMask {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("InfoNotify"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for EventMask {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(EventMask, u32);
 
 /// Opcode for the SelectInput request
@@ -891,6 +1155,7 @@ pub const SELECT_INPUT_REQUEST: u8 = 8;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SelectInputRequest {
     pub event_mask: EventMask,
 }
@@ -939,6 +1204,33 @@ impl Request for SelectInputRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let event_mask_bytes = u32::from(self.event_mask).serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SELECT_INPUT_REQUEST,
+            0,
+            0,
+            event_mask_bytes[0],
+            event_mask_bytes[1],
+            event_mask_bytes[2],
+            event_mask_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SelectInputRequest {
 }
@@ -959,6 +1251,12 @@ pub struct InfoNotifyEvent {
     pub state: bool,
 }
 impl_debug_if_no_extra_traits!(InfoNotifyEvent, "InfoNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for InfoNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for InfoNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1049,3 +1347,16 @@ impl Serialize for InfoNotifyEvent {
     }
 }
 
+/// All requests of this module.
+pub const REQUESTS: &[RequestDescription] = &[
+    RequestDescription { opcode: GET_VERSION_REQUEST, name: "GetVersion", has_reply: true },
+    RequestDescription { opcode: CAPABLE_REQUEST, name: "Capable", has_reply: true },
+    RequestDescription { opcode: GET_TIMEOUTS_REQUEST, name: "GetTimeouts", has_reply: true },
+    RequestDescription { opcode: SET_TIMEOUTS_REQUEST, name: "SetTimeouts", has_reply: false },
+    RequestDescription { opcode: ENABLE_REQUEST, name: "Enable", has_reply: false },
+    RequestDescription { opcode: DISABLE_REQUEST, name: "Disable", has_reply: false },
+    RequestDescription { opcode: FORCE_LEVEL_REQUEST, name: "ForceLevel", has_reply: false },
+    RequestDescription { opcode: INFO_REQUEST, name: "Info", has_reply: true },
+    RequestDescription { opcode: SELECT_INPUT_REQUEST, name: "SelectInput", has_reply: false },
+];
+
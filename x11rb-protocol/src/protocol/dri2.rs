@@ -22,6 +22,7 @@ use crate::BufWithFds;
 use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::x11_utils::RequestDescription;
 #[allow(unused_imports)]
 use super::xproto;
 
@@ -38,6 +39,7 @@ pub const X11_XML_VERSION: (u32, u32) = (1, 4);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Attachment(u32);
 impl Attachment {
     pub const BUFFER_FRONT_LEFT: Self = Self(0);
@@ -100,9 +102,34 @@ impl core::fmt::Debug for Attachment  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+impl Attachment {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("BufferFrontLeft"),
+            1 => Some("BufferBackLeft"),
+            2 => Some("BufferFrontRight"),
+            3 => Some("BufferBackRight"),
+            4 => Some("BufferDepth"),
+            5 => Some("BufferStencil"),
+            6 => Some("BufferAccum"),
+            7 => Some("BufferFakeFrontLeft"),
+            8 => Some("BufferFakeFrontRight"),
+            9 => Some("BufferDepthStencil"),
+            10 => Some("BufferHiz"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Attachment {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DriverType(u32);
 impl DriverType {
     pub const DRI: Self = Self(0);
@@ -147,9 +174,25 @@ impl core::fmt::Debug for DriverType  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+impl DriverType {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("DRI"),
+            1 => Some("VDPAU"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for DriverType {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct EventType(u16);
 impl EventType {
     pub const EXCHANGE_COMPLETE: Self = Self(1);
@@ -202,6 +245,22 @@ impl core::fmt::Debug for EventType  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl EventType {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("ExchangeComplete"),
+            2 => Some("BlitComplete"),
+            3 => Some("FlipComplete"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for EventType {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
@@ -214,6 +273,12 @@ pub struct DRI2Buffer {
     pub flags: u32,
 }
 impl_debug_if_no_extra_traits!(DRI2Buffer, "DRI2Buffer");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DRI2Buffer {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for DRI2Buffer {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (attachment, remaining) = u32::try_parse(remaining)?;
@@ -275,6 +340,12 @@ pub struct AttachFormat {
     pub format: u32,
 }
 impl_debug_if_no_extra_traits!(AttachFormat, "AttachFormat");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for AttachFormat {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for AttachFormat {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (attachment, remaining) = u32::try_parse(remaining)?;
@@ -312,6 +383,7 @@ pub const QUERY_VERSION_REQUEST: u8 = 0;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryVersionRequest {
     pub major_version: u32,
     pub minor_version: u32,
@@ -367,6 +439,38 @@ impl Request for QueryVersionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let major_version_bytes = self.major_version.serialize();
+        let minor_version_bytes = self.minor_version.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+            major_version_bytes[0],
+            major_version_bytes[1],
+            major_version_bytes[2],
+            major_version_bytes[3],
+            minor_version_bytes[0],
+            minor_version_bytes[1],
+            minor_version_bytes[2],
+            minor_version_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryVersionRequest {
     type Reply = QueryVersionReply;
@@ -382,6 +486,12 @@ pub struct QueryVersionReply {
     pub minor_version: u32,
 }
 impl_debug_if_no_extra_traits!(QueryVersionReply, "QueryVersionReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryVersionReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -445,6 +555,7 @@ pub const CONNECT_REQUEST: u8 = 1;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ConnectRequest {
     pub window: xproto::Window,
     pub driver_type: DriverType,
@@ -501,6 +612,38 @@ impl Request for ConnectRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let driver_type_bytes = u32::from(self.driver_type).serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CONNECT_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            driver_type_bytes[0],
+            driver_type_bytes[1],
+            driver_type_bytes[2],
+            driver_type_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for ConnectRequest {
     type Reply = ConnectReply;
@@ -517,6 +660,12 @@ pub struct ConnectReply {
     pub device_name: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(ConnectReply, "ConnectReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ConnectReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ConnectReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -566,6 +715,7 @@ impl Serialize for ConnectReply {
         assert_eq!(self.alignment_pad.len(), usize::try_from((u32::from(driver_name_length).checked_add(3u32).unwrap() & (!3u32)).checked_sub(u32::from(driver_name_length)).unwrap()).unwrap(), "`alignment_pad` has an incorrect length");
         bytes.extend_from_slice(&self.alignment_pad);
         bytes.extend_from_slice(&self.device_name);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl ConnectReply {
@@ -602,6 +752,7 @@ pub const AUTHENTICATE_REQUEST: u8 = 2;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct AuthenticateRequest {
     pub window: xproto::Window,
     pub magic: u32,
@@ -657,6 +808,38 @@ impl Request for AuthenticateRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let magic_bytes = self.magic.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            AUTHENTICATE_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            magic_bytes[0],
+            magic_bytes[1],
+            magic_bytes[2],
+            magic_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for AuthenticateRequest {
     type Reply = AuthenticateReply;
@@ -671,6 +854,12 @@ pub struct AuthenticateReply {
     pub authenticated: u32,
 }
 impl_debug_if_no_extra_traits!(AuthenticateReply, "AuthenticateReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for AuthenticateReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for AuthenticateReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -727,6 +916,7 @@ pub const CREATE_DRAWABLE_REQUEST: u8 = 3;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateDrawableRequest {
     pub drawable: xproto::Drawable,
 }
@@ -774,6 +964,33 @@ impl Request for CreateDrawableRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CREATE_DRAWABLE_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for CreateDrawableRequest {
 }
@@ -783,6 +1000,7 @@ pub const DESTROY_DRAWABLE_REQUEST: u8 = 4;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DestroyDrawableRequest {
     pub drawable: xproto::Drawable,
 }
@@ -830,6 +1048,33 @@ impl Request for DestroyDrawableRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            DESTROY_DRAWABLE_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for DestroyDrawableRequest {
 }
@@ -839,6 +1084,7 @@ pub const GET_BUFFERS_REQUEST: u8 = 5;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetBuffersRequest<'input> {
     pub drawable: xproto::Drawable,
     pub count: u32,
@@ -885,7 +1131,7 @@ impl<'input> GetBuffersRequest<'input> {
         let (count, remaining) = u32::try_parse(remaining)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut attachments = Vec::new();
+        let mut attachments = Vec::with_capacity(remaining.len() / 4);
         while !remaining.is_empty() {
             let (v, new_remaining) = u32::try_parse(remaining)?;
             remaining = new_remaining;
@@ -916,6 +1162,41 @@ impl<'input> Request for GetBuffersRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        let count_bytes = self.count.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_BUFFERS_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            count_bytes[0],
+            count_bytes[1],
+            count_bytes[2],
+            count_bytes[3],
+        ]);
+        self.attachments.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::ReplyRequest for GetBuffersRequest<'input> {
     type Reply = GetBuffersReply;
@@ -932,6 +1213,12 @@ pub struct GetBuffersReply {
     pub buffers: Vec<DRI2Buffer>,
 }
 impl_debug_if_no_extra_traits!(GetBuffersReply, "GetBuffersReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetBuffersReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetBuffersReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -974,6 +1261,7 @@ impl Serialize for GetBuffersReply {
         count.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.buffers.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetBuffersReply {
@@ -997,6 +1285,7 @@ pub const COPY_REGION_REQUEST: u8 = 6;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CopyRegionRequest {
     pub drawable: xproto::Drawable,
     pub region: u32,
@@ -1068,6 +1357,48 @@ impl Request for CopyRegionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        let region_bytes = self.region.serialize();
+        let dest_bytes = self.dest.serialize();
+        let src_bytes = self.src.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            COPY_REGION_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+            dest_bytes[0],
+            dest_bytes[1],
+            dest_bytes[2],
+            dest_bytes[3],
+            src_bytes[0],
+            src_bytes[1],
+            src_bytes[2],
+            src_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for CopyRegionRequest {
     type Reply = CopyRegionReply;
@@ -1081,6 +1412,12 @@ pub struct CopyRegionReply {
     pub length: u32,
 }
 impl_debug_if_no_extra_traits!(CopyRegionReply, "CopyRegionReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CopyRegionReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for CopyRegionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1130,6 +1467,7 @@ pub const GET_BUFFERS_WITH_FORMAT_REQUEST: u8 = 7;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetBuffersWithFormatRequest<'input> {
     pub drawable: xproto::Drawable,
     pub count: u32,
@@ -1176,7 +1514,7 @@ impl<'input> GetBuffersWithFormatRequest<'input> {
         let (count, remaining) = u32::try_parse(remaining)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut attachments = Vec::new();
+        let mut attachments = Vec::with_capacity(remaining.len() / 8);
         while !remaining.is_empty() {
             let (v, new_remaining) = AttachFormat::try_parse(remaining)?;
             remaining = new_remaining;
@@ -1207,6 +1545,41 @@ impl<'input> Request for GetBuffersWithFormatRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        let count_bytes = self.count.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_BUFFERS_WITH_FORMAT_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            count_bytes[0],
+            count_bytes[1],
+            count_bytes[2],
+            count_bytes[3],
+        ]);
+        self.attachments.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::ReplyRequest for GetBuffersWithFormatRequest<'input> {
     type Reply = GetBuffersWithFormatReply;
@@ -1223,6 +1596,12 @@ pub struct GetBuffersWithFormatReply {
     pub buffers: Vec<DRI2Buffer>,
 }
 impl_debug_if_no_extra_traits!(GetBuffersWithFormatReply, "GetBuffersWithFormatReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetBuffersWithFormatReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetBuffersWithFormatReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1265,6 +1644,7 @@ impl Serialize for GetBuffersWithFormatReply {
         count.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.buffers.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetBuffersWithFormatReply {
@@ -1288,6 +1668,7 @@ pub const SWAP_BUFFERS_REQUEST: u8 = 8;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SwapBuffersRequest {
     pub drawable: xproto::Drawable,
     pub target_msc_hi: u32,
@@ -1383,6 +1764,63 @@ impl Request for SwapBuffersRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        let target_msc_hi_bytes = self.target_msc_hi.serialize();
+        let target_msc_lo_bytes = self.target_msc_lo.serialize();
+        let divisor_hi_bytes = self.divisor_hi.serialize();
+        let divisor_lo_bytes = self.divisor_lo.serialize();
+        let remainder_hi_bytes = self.remainder_hi.serialize();
+        let remainder_lo_bytes = self.remainder_lo.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SWAP_BUFFERS_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            target_msc_hi_bytes[0],
+            target_msc_hi_bytes[1],
+            target_msc_hi_bytes[2],
+            target_msc_hi_bytes[3],
+            target_msc_lo_bytes[0],
+            target_msc_lo_bytes[1],
+            target_msc_lo_bytes[2],
+            target_msc_lo_bytes[3],
+            divisor_hi_bytes[0],
+            divisor_hi_bytes[1],
+            divisor_hi_bytes[2],
+            divisor_hi_bytes[3],
+            divisor_lo_bytes[0],
+            divisor_lo_bytes[1],
+            divisor_lo_bytes[2],
+            divisor_lo_bytes[3],
+            remainder_hi_bytes[0],
+            remainder_hi_bytes[1],
+            remainder_hi_bytes[2],
+            remainder_hi_bytes[3],
+            remainder_lo_bytes[0],
+            remainder_lo_bytes[1],
+            remainder_lo_bytes[2],
+            remainder_lo_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for SwapBuffersRequest {
     type Reply = SwapBuffersReply;
@@ -1398,6 +1836,12 @@ pub struct SwapBuffersReply {
     pub swap_lo: u32,
 }
 impl_debug_if_no_extra_traits!(SwapBuffersReply, "SwapBuffersReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SwapBuffersReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for SwapBuffersReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1461,6 +1905,7 @@ pub const GET_MSC_REQUEST: u8 = 9;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetMSCRequest {
     pub drawable: xproto::Drawable,
 }
@@ -1508,6 +1953,33 @@ impl Request for GetMSCRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_MSC_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetMSCRequest {
     type Reply = GetMSCReply;
@@ -1527,6 +1999,12 @@ pub struct GetMSCReply {
     pub sbc_lo: u32,
 }
 impl_debug_if_no_extra_traits!(GetMSCReply, "GetMSCReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetMSCReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetMSCReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1618,6 +2096,7 @@ pub const WAIT_MSC_REQUEST: u8 = 10;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct WaitMSCRequest {
     pub drawable: xproto::Drawable,
     pub target_msc_hi: u32,
@@ -1713,6 +2192,63 @@ impl Request for WaitMSCRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        let target_msc_hi_bytes = self.target_msc_hi.serialize();
+        let target_msc_lo_bytes = self.target_msc_lo.serialize();
+        let divisor_hi_bytes = self.divisor_hi.serialize();
+        let divisor_lo_bytes = self.divisor_lo.serialize();
+        let remainder_hi_bytes = self.remainder_hi.serialize();
+        let remainder_lo_bytes = self.remainder_lo.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            WAIT_MSC_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            target_msc_hi_bytes[0],
+            target_msc_hi_bytes[1],
+            target_msc_hi_bytes[2],
+            target_msc_hi_bytes[3],
+            target_msc_lo_bytes[0],
+            target_msc_lo_bytes[1],
+            target_msc_lo_bytes[2],
+            target_msc_lo_bytes[3],
+            divisor_hi_bytes[0],
+            divisor_hi_bytes[1],
+            divisor_hi_bytes[2],
+            divisor_hi_bytes[3],
+            divisor_lo_bytes[0],
+            divisor_lo_bytes[1],
+            divisor_lo_bytes[2],
+            divisor_lo_bytes[3],
+            remainder_hi_bytes[0],
+            remainder_hi_bytes[1],
+            remainder_hi_bytes[2],
+            remainder_hi_bytes[3],
+            remainder_lo_bytes[0],
+            remainder_lo_bytes[1],
+            remainder_lo_bytes[2],
+            remainder_lo_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for WaitMSCRequest {
     type Reply = WaitMSCReply;
@@ -1732,6 +2268,12 @@ pub struct WaitMSCReply {
     pub sbc_lo: u32,
 }
 impl_debug_if_no_extra_traits!(WaitMSCReply, "WaitMSCReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for WaitMSCReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for WaitMSCReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1823,6 +2365,7 @@ pub const WAIT_SBC_REQUEST: u8 = 11;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct WaitSBCRequest {
     pub drawable: xproto::Drawable,
     pub target_sbc_hi: u32,
@@ -1886,6 +2429,43 @@ impl Request for WaitSBCRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        let target_sbc_hi_bytes = self.target_sbc_hi.serialize();
+        let target_sbc_lo_bytes = self.target_sbc_lo.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            WAIT_SBC_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            target_sbc_hi_bytes[0],
+            target_sbc_hi_bytes[1],
+            target_sbc_hi_bytes[2],
+            target_sbc_hi_bytes[3],
+            target_sbc_lo_bytes[0],
+            target_sbc_lo_bytes[1],
+            target_sbc_lo_bytes[2],
+            target_sbc_lo_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for WaitSBCRequest {
     type Reply = WaitSBCReply;
@@ -1905,6 +2485,12 @@ pub struct WaitSBCReply {
     pub sbc_lo: u32,
 }
 impl_debug_if_no_extra_traits!(WaitSBCReply, "WaitSBCReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for WaitSBCReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for WaitSBCReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1996,6 +2582,7 @@ pub const SWAP_INTERVAL_REQUEST: u8 = 12;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SwapIntervalRequest {
     pub drawable: xproto::Drawable,
     pub interval: u32,
@@ -2051,6 +2638,38 @@ impl Request for SwapIntervalRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        let interval_bytes = self.interval.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SWAP_INTERVAL_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            interval_bytes[0],
+            interval_bytes[1],
+            interval_bytes[2],
+            interval_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SwapIntervalRequest {
 }
@@ -2060,6 +2679,7 @@ pub const GET_PARAM_REQUEST: u8 = 13;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetParamRequest {
     pub drawable: xproto::Drawable,
     pub param: u32,
@@ -2115,6 +2735,38 @@ impl Request for GetParamRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        let param_bytes = self.param.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_PARAM_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            param_bytes[0],
+            param_bytes[1],
+            param_bytes[2],
+            param_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetParamRequest {
     type Reply = GetParamReply;
@@ -2131,6 +2783,12 @@ pub struct GetParamReply {
     pub value_lo: u32,
 }
 impl_debug_if_no_extra_traits!(GetParamReply, "GetParamReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetParamReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetParamReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2207,6 +2865,12 @@ pub struct BufferSwapCompleteEvent {
     pub sbc: u32,
 }
 impl_debug_if_no_extra_traits!(BufferSwapCompleteEvent, "BufferSwapCompleteEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for BufferSwapCompleteEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for BufferSwapCompleteEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2355,6 +3019,12 @@ pub struct InvalidateBuffersEvent {
     pub drawable: xproto::Drawable,
 }
 impl_debug_if_no_extra_traits!(InvalidateBuffersEvent, "InvalidateBuffersEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for InvalidateBuffersEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for InvalidateBuffersEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2442,3 +3112,21 @@ impl From<InvalidateBuffersEvent> for [u8; 32] {
     }
 }
 
+/// All requests of this module.
+pub const REQUESTS: &[RequestDescription] = &[
+    RequestDescription { opcode: QUERY_VERSION_REQUEST, name: "QueryVersion", has_reply: true },
+    RequestDescription { opcode: CONNECT_REQUEST, name: "Connect", has_reply: true },
+    RequestDescription { opcode: AUTHENTICATE_REQUEST, name: "Authenticate", has_reply: true },
+    RequestDescription { opcode: CREATE_DRAWABLE_REQUEST, name: "CreateDrawable", has_reply: false },
+    RequestDescription { opcode: DESTROY_DRAWABLE_REQUEST, name: "DestroyDrawable", has_reply: false },
+    RequestDescription { opcode: GET_BUFFERS_REQUEST, name: "GetBuffers", has_reply: true },
+    RequestDescription { opcode: COPY_REGION_REQUEST, name: "CopyRegion", has_reply: true },
+    RequestDescription { opcode: GET_BUFFERS_WITH_FORMAT_REQUEST, name: "GetBuffersWithFormat", has_reply: true },
+    RequestDescription { opcode: SWAP_BUFFERS_REQUEST, name: "SwapBuffers", has_reply: true },
+    RequestDescription { opcode: GET_MSC_REQUEST, name: "GetMSC", has_reply: true },
+    RequestDescription { opcode: WAIT_MSC_REQUEST, name: "WaitMSC", has_reply: true },
+    RequestDescription { opcode: WAIT_SBC_REQUEST, name: "WaitSBC", has_reply: true },
+    RequestDescription { opcode: SWAP_INTERVAL_REQUEST, name: "SwapInterval", has_reply: false },
+    RequestDescription { opcode: GET_PARAM_REQUEST, name: "GetParam", has_reply: true },
+];
+
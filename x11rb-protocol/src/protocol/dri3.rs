@@ -22,6 +22,7 @@ use crate::BufWithFds;
 use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::x11_utils::RequestDescription;
 #[allow(unused_imports)]
 use super::xproto;
 
@@ -43,6 +44,7 @@ pub const QUERY_VERSION_REQUEST: u8 = 0;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryVersionRequest {
     pub major_version: u32,
     pub minor_version: u32,
@@ -98,6 +100,38 @@ impl Request for QueryVersionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let major_version_bytes = self.major_version.serialize();
+        let minor_version_bytes = self.minor_version.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+            major_version_bytes[0],
+            major_version_bytes[1],
+            major_version_bytes[2],
+            major_version_bytes[3],
+            minor_version_bytes[0],
+            minor_version_bytes[1],
+            minor_version_bytes[2],
+            minor_version_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryVersionRequest {
     type Reply = QueryVersionReply;
@@ -113,6 +147,12 @@ pub struct QueryVersionReply {
     pub minor_version: u32,
 }
 impl_debug_if_no_extra_traits!(QueryVersionReply, "QueryVersionReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryVersionReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -176,6 +216,7 @@ pub const OPEN_REQUEST: u8 = 1;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct OpenRequest {
     pub drawable: xproto::Drawable,
     pub provider: u32,
@@ -231,6 +272,38 @@ impl Request for OpenRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        let provider_bytes = self.provider.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            OPEN_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            provider_bytes[0],
+            provider_bytes[1],
+            provider_bytes[2],
+            provider_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyFDsRequest for OpenRequest {
     type Reply = OpenReply;
@@ -415,6 +488,56 @@ impl Request for PixmapFromBufferRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let pixmap_bytes = self.pixmap.serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let size_bytes = self.size.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        let stride_bytes = self.stride.serialize();
+        let depth_bytes = self.depth.serialize();
+        let bpp_bytes = self.bpp.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            PIXMAP_FROM_BUFFER_REQUEST,
+            0,
+            0,
+            pixmap_bytes[0],
+            pixmap_bytes[1],
+            pixmap_bytes[2],
+            pixmap_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            size_bytes[0],
+            size_bytes[1],
+            size_bytes[2],
+            size_bytes[3],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+            stride_bytes[0],
+            stride_bytes[1],
+            depth_bytes[0],
+            bpp_bytes[0],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![self.pixmap_fd]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for PixmapFromBufferRequest {
 }
@@ -424,6 +547,7 @@ pub const BUFFER_FROM_PIXMAP_REQUEST: u8 = 3;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct BufferFromPixmapRequest {
     pub pixmap: xproto::Pixmap,
 }
@@ -471,6 +595,33 @@ impl Request for BufferFromPixmapRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let pixmap_bytes = self.pixmap.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            BUFFER_FROM_PIXMAP_REQUEST,
+            0,
+            0,
+            pixmap_bytes[0],
+            pixmap_bytes[1],
+            pixmap_bytes[2],
+            pixmap_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyFDsRequest for BufferFromPixmapRequest {
     type Reply = BufferFromPixmapReply;
@@ -652,6 +803,43 @@ impl Request for FenceFromFDRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        let fence_bytes = self.fence.serialize();
+        let initially_triggered_bytes = self.initially_triggered.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            FENCE_FROM_FD_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            fence_bytes[0],
+            fence_bytes[1],
+            fence_bytes[2],
+            fence_bytes[3],
+            initially_triggered_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![self.fence_fd]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for FenceFromFDRequest {
 }
@@ -661,6 +849,7 @@ pub const FD_FROM_FENCE_REQUEST: u8 = 5;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct FDFromFenceRequest {
     pub drawable: xproto::Drawable,
     pub fence: u32,
@@ -716,6 +905,38 @@ impl Request for FDFromFenceRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        let fence_bytes = self.fence.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            FD_FROM_FENCE_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            fence_bytes[0],
+            fence_bytes[1],
+            fence_bytes[2],
+            fence_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyFDsRequest for FDFromFenceRequest {
     type Reply = FDFromFenceReply;
@@ -807,6 +1028,7 @@ pub const GET_SUPPORTED_MODIFIERS_REQUEST: u8 = 6;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetSupportedModifiersRequest {
     pub window: u32,
     pub depth: u8,
@@ -867,6 +1089,39 @@ impl Request for GetSupportedModifiersRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let depth_bytes = self.depth.serialize();
+        let bpp_bytes = self.bpp.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_SUPPORTED_MODIFIERS_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            depth_bytes[0],
+            bpp_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetSupportedModifiersRequest {
     type Reply = GetSupportedModifiersReply;
@@ -882,6 +1137,12 @@ pub struct GetSupportedModifiersReply {
     pub screen_modifiers: Vec<u64>,
 }
 impl_debug_if_no_extra_traits!(GetSupportedModifiersReply, "GetSupportedModifiersReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetSupportedModifiersReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetSupportedModifiersReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -925,6 +1186,7 @@ impl Serialize for GetSupportedModifiersReply {
         bytes.extend_from_slice(&[0; 16]);
         self.window_modifiers.serialize_into(bytes);
         self.screen_modifiers.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetSupportedModifiersReply {
@@ -1129,6 +1391,105 @@ impl Request for PixmapFromBuffersRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let pixmap_bytes = self.pixmap.serialize();
+        let window_bytes = self.window.serialize();
+        let num_buffers = u8::try_from(self.buffers.len()).expect("`buffers` has too many elements");
+        let num_buffers_bytes = num_buffers.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        let stride0_bytes = self.stride0.serialize();
+        let offset0_bytes = self.offset0.serialize();
+        let stride1_bytes = self.stride1.serialize();
+        let offset1_bytes = self.offset1.serialize();
+        let stride2_bytes = self.stride2.serialize();
+        let offset2_bytes = self.offset2.serialize();
+        let stride3_bytes = self.stride3.serialize();
+        let offset3_bytes = self.offset3.serialize();
+        let depth_bytes = self.depth.serialize();
+        let bpp_bytes = self.bpp.serialize();
+        let modifier_bytes = self.modifier.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            PIXMAP_FROM_BUFFERS_REQUEST,
+            0,
+            0,
+            pixmap_bytes[0],
+            pixmap_bytes[1],
+            pixmap_bytes[2],
+            pixmap_bytes[3],
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            num_buffers_bytes[0],
+            0,
+            0,
+            0,
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+            stride0_bytes[0],
+            stride0_bytes[1],
+            stride0_bytes[2],
+            stride0_bytes[3],
+            offset0_bytes[0],
+            offset0_bytes[1],
+            offset0_bytes[2],
+            offset0_bytes[3],
+            stride1_bytes[0],
+            stride1_bytes[1],
+            stride1_bytes[2],
+            stride1_bytes[3],
+            offset1_bytes[0],
+            offset1_bytes[1],
+            offset1_bytes[2],
+            offset1_bytes[3],
+            stride2_bytes[0],
+            stride2_bytes[1],
+            stride2_bytes[2],
+            stride2_bytes[3],
+            offset2_bytes[0],
+            offset2_bytes[1],
+            offset2_bytes[2],
+            offset2_bytes[3],
+            stride3_bytes[0],
+            stride3_bytes[1],
+            stride3_bytes[2],
+            stride3_bytes[3],
+            offset3_bytes[0],
+            offset3_bytes[1],
+            offset3_bytes[2],
+            offset3_bytes[3],
+            depth_bytes[0],
+            bpp_bytes[0],
+            0,
+            0,
+            modifier_bytes[0],
+            modifier_bytes[1],
+            modifier_bytes[2],
+            modifier_bytes[3],
+            modifier_bytes[4],
+            modifier_bytes[5],
+            modifier_bytes[6],
+            modifier_bytes[7],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        self.buffers
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for PixmapFromBuffersRequest {
 }
@@ -1138,6 +1499,7 @@ pub const BUFFERS_FROM_PIXMAP_REQUEST: u8 = 8;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct BuffersFromPixmapRequest {
     pub pixmap: xproto::Pixmap,
 }
@@ -1185,6 +1547,33 @@ impl Request for BuffersFromPixmapRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let pixmap_bytes = self.pixmap.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            BUFFERS_FROM_PIXMAP_REQUEST,
+            0,
+            0,
+            pixmap_bytes[0],
+            pixmap_bytes[1],
+            pixmap_bytes[2],
+            pixmap_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyFDsRequest for BuffersFromPixmapRequest {
     type Reply = BuffersFromPixmapReply;
@@ -1260,6 +1649,7 @@ impl Serialize for BuffersFromPixmapReply {
         assert_eq!(self.offsets.len(), usize::try_from(nfd).unwrap(), "`offsets` has an incorrect length");
         self.offsets.serialize_into(bytes);
         assert_eq!(self.buffers.len(), usize::try_from(nfd).unwrap(), "`buffers` has an incorrect length");
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl BuffersFromPixmapReply {
@@ -1283,6 +1673,7 @@ pub const SET_DRM_DEVICE_IN_USE_REQUEST: u8 = 9;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetDRMDeviceInUseRequest {
     pub window: xproto::Window,
     pub drm_major: u32,
@@ -1346,6 +1737,43 @@ impl Request for SetDRMDeviceInUseRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let drm_major_bytes = self.drm_major.serialize();
+        let drm_minor_bytes = self.drm_minor.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_DRM_DEVICE_IN_USE_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            drm_major_bytes[0],
+            drm_major_bytes[1],
+            drm_major_bytes[2],
+            drm_major_bytes[3],
+            drm_minor_bytes[0],
+            drm_minor_bytes[1],
+            drm_minor_bytes[2],
+            drm_minor_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SetDRMDeviceInUseRequest {
 }
@@ -1412,6 +1840,38 @@ impl Request for ImportSyncobjRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let syncobj_bytes = self.syncobj.serialize();
+        let drawable_bytes = self.drawable.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            IMPORT_SYNCOBJ_REQUEST,
+            0,
+            0,
+            syncobj_bytes[0],
+            syncobj_bytes[1],
+            syncobj_bytes[2],
+            syncobj_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![self.syncobj_fd]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for ImportSyncobjRequest {
 }
@@ -1421,6 +1881,7 @@ pub const FREE_SYNCOBJ_REQUEST: u8 = 11;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct FreeSyncobjRequest {
     pub syncobj: Syncobj,
 }
@@ -1468,7 +1929,50 @@ impl Request for FreeSyncobjRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let syncobj_bytes = self.syncobj.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            FREE_SYNCOBJ_REQUEST,
+            0,
+            0,
+            syncobj_bytes[0],
+            syncobj_bytes[1],
+            syncobj_bytes[2],
+            syncobj_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for FreeSyncobjRequest {
 }
 
+/// All requests of this module.
+pub const REQUESTS: &[RequestDescription] = &[
+    RequestDescription { opcode: QUERY_VERSION_REQUEST, name: "QueryVersion", has_reply: true },
+    RequestDescription { opcode: OPEN_REQUEST, name: "Open", has_reply: true },
+    RequestDescription { opcode: PIXMAP_FROM_BUFFER_REQUEST, name: "PixmapFromBuffer", has_reply: false },
+    RequestDescription { opcode: BUFFER_FROM_PIXMAP_REQUEST, name: "BufferFromPixmap", has_reply: true },
+    RequestDescription { opcode: FENCE_FROM_FD_REQUEST, name: "FenceFromFD", has_reply: false },
+    RequestDescription { opcode: FD_FROM_FENCE_REQUEST, name: "FDFromFence", has_reply: true },
+    RequestDescription { opcode: GET_SUPPORTED_MODIFIERS_REQUEST, name: "GetSupportedModifiers", has_reply: true },
+    RequestDescription { opcode: PIXMAP_FROM_BUFFERS_REQUEST, name: "PixmapFromBuffers", has_reply: false },
+    RequestDescription { opcode: BUFFERS_FROM_PIXMAP_REQUEST, name: "BuffersFromPixmap", has_reply: true },
+    RequestDescription { opcode: SET_DRM_DEVICE_IN_USE_REQUEST, name: "SetDRMDeviceInUse", has_reply: false },
+    RequestDescription { opcode: IMPORT_SYNCOBJ_REQUEST, name: "ImportSyncobj", has_reply: false },
+    RequestDescription { opcode: FREE_SYNCOBJ_REQUEST, name: "FreeSyncobj", has_reply: false },
+];
+
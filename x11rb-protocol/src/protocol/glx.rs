@@ -22,6 +22,7 @@ use crate::BufWithFds;
 use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::x11_utils::RequestDescription;
 #[allow(unused_imports)]
 use super::xproto;
 
@@ -119,6 +120,12 @@ pub struct PbufferClobberEvent {
     pub count: u16,
 }
 impl_debug_if_no_extra_traits!(PbufferClobberEvent, "PbufferClobberEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for PbufferClobberEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for PbufferClobberEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -284,6 +291,12 @@ pub struct BufferSwapCompleteEvent {
     pub sbc: u32,
 }
 impl_debug_if_no_extra_traits!(BufferSwapCompleteEvent, "BufferSwapCompleteEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for BufferSwapCompleteEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for BufferSwapCompleteEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -422,6 +435,7 @@ impl From<BufferSwapCompleteEvent> for [u8; 32] {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PBCET(u16);
 impl PBCET {
     pub const DAMAGED: Self = Self(32791);
@@ -472,9 +486,25 @@ impl core::fmt::Debug for PBCET  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl PBCET {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            32791 => Some("Damaged"),
+            32792 => Some("Saved"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for PBCET {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PBCDT(u16);
 impl PBCDT {
     pub const WINDOW: Self = Self(32793);
@@ -525,12 +555,28 @@ impl core::fmt::Debug for PBCDT  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl PBCDT {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            32793 => Some("Window"),
+            32794 => Some("Pbuffer"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for PBCDT {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the Render request
 pub const RENDER_REQUEST: u8 = 1;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct RenderRequest<'input> {
     pub context_tag: ContextTag,
     pub data: Cow<'input, [u8]>,
@@ -591,6 +637,36 @@ impl<'input> Request for RenderRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            RENDER_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+        ]);
+        buffer.extend_from_slice(&self.data[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for RenderRequest<'input> {
 }
@@ -600,6 +676,7 @@ pub const RENDER_LARGE_REQUEST: u8 = 2;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct RenderLargeRequest<'input> {
     pub context_tag: ContextTag,
     pub request_num: u16,
@@ -681,6 +758,48 @@ impl<'input> Request for RenderLargeRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let request_num_bytes = self.request_num.serialize();
+        let request_total_bytes = self.request_total.serialize();
+        let data_len = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        let data_len_bytes = data_len.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            RENDER_LARGE_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            request_num_bytes[0],
+            request_num_bytes[1],
+            request_total_bytes[0],
+            request_total_bytes[1],
+            data_len_bytes[0],
+            data_len_bytes[1],
+            data_len_bytes[2],
+            data_len_bytes[3],
+        ]);
+        buffer.extend_from_slice(&self.data[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for RenderLargeRequest<'input> {
 }
@@ -690,6 +809,7 @@ pub const CREATE_CONTEXT_REQUEST: u8 = 3;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateContextRequest {
     pub context: Context,
     pub visual: xproto::Visualid,
@@ -770,6 +890,53 @@ impl Request for CreateContextRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_bytes = self.context.serialize();
+        let visual_bytes = self.visual.serialize();
+        let screen_bytes = self.screen.serialize();
+        let share_list_bytes = self.share_list.serialize();
+        let is_direct_bytes = self.is_direct.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CREATE_CONTEXT_REQUEST,
+            0,
+            0,
+            context_bytes[0],
+            context_bytes[1],
+            context_bytes[2],
+            context_bytes[3],
+            visual_bytes[0],
+            visual_bytes[1],
+            visual_bytes[2],
+            visual_bytes[3],
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            share_list_bytes[0],
+            share_list_bytes[1],
+            share_list_bytes[2],
+            share_list_bytes[3],
+            is_direct_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for CreateContextRequest {
 }
@@ -779,6 +946,7 @@ pub const DESTROY_CONTEXT_REQUEST: u8 = 4;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DestroyContextRequest {
     pub context: Context,
 }
@@ -826,6 +994,33 @@ impl Request for DestroyContextRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_bytes = self.context.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            DESTROY_CONTEXT_REQUEST,
+            0,
+            0,
+            context_bytes[0],
+            context_bytes[1],
+            context_bytes[2],
+            context_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for DestroyContextRequest {
 }
@@ -835,6 +1030,7 @@ pub const MAKE_CURRENT_REQUEST: u8 = 5;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct MakeCurrentRequest {
     pub drawable: Drawable,
     pub context: Context,
@@ -898,6 +1094,43 @@ impl Request for MakeCurrentRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        let context_bytes = self.context.serialize();
+        let old_context_tag_bytes = self.old_context_tag.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            MAKE_CURRENT_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            context_bytes[0],
+            context_bytes[1],
+            context_bytes[2],
+            context_bytes[3],
+            old_context_tag_bytes[0],
+            old_context_tag_bytes[1],
+            old_context_tag_bytes[2],
+            old_context_tag_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for MakeCurrentRequest {
     type Reply = MakeCurrentReply;
@@ -912,6 +1145,12 @@ pub struct MakeCurrentReply {
     pub context_tag: ContextTag,
 }
 impl_debug_if_no_extra_traits!(MakeCurrentReply, "MakeCurrentReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for MakeCurrentReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for MakeCurrentReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -990,6 +1229,7 @@ pub const IS_DIRECT_REQUEST: u8 = 6;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct IsDirectRequest {
     pub context: Context,
 }
@@ -1037,6 +1277,33 @@ impl Request for IsDirectRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_bytes = self.context.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            IS_DIRECT_REQUEST,
+            0,
+            0,
+            context_bytes[0],
+            context_bytes[1],
+            context_bytes[2],
+            context_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for IsDirectRequest {
     type Reply = IsDirectReply;
@@ -1051,6 +1318,12 @@ pub struct IsDirectReply {
     pub is_direct: bool,
 }
 impl_debug_if_no_extra_traits!(IsDirectReply, "IsDirectReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for IsDirectReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for IsDirectReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1129,6 +1402,7 @@ pub const QUERY_VERSION_REQUEST: u8 = 7;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryVersionRequest {
     pub major_version: u32,
     pub minor_version: u32,
@@ -1184,6 +1458,38 @@ impl Request for QueryVersionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let major_version_bytes = self.major_version.serialize();
+        let minor_version_bytes = self.minor_version.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+            major_version_bytes[0],
+            major_version_bytes[1],
+            major_version_bytes[2],
+            major_version_bytes[3],
+            minor_version_bytes[0],
+            minor_version_bytes[1],
+            minor_version_bytes[2],
+            minor_version_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryVersionRequest {
     type Reply = QueryVersionReply;
@@ -1199,6 +1505,12 @@ pub struct QueryVersionReply {
     pub minor_version: u32,
 }
 impl_debug_if_no_extra_traits!(QueryVersionReply, "QueryVersionReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryVersionReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1280,6 +1592,7 @@ pub const WAIT_GL_REQUEST: u8 = 8;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct WaitGLRequest {
     pub context_tag: ContextTag,
 }
@@ -1327,6 +1640,33 @@ impl Request for WaitGLRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            WAIT_GL_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for WaitGLRequest {
 }
@@ -1336,6 +1676,7 @@ pub const WAIT_X_REQUEST: u8 = 9;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct WaitXRequest {
     pub context_tag: ContextTag,
 }
@@ -1383,6 +1724,33 @@ impl Request for WaitXRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            WAIT_X_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for WaitXRequest {
 }
@@ -1392,6 +1760,7 @@ pub const COPY_CONTEXT_REQUEST: u8 = 10;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CopyContextRequest {
     pub src: Context,
     pub dest: Context,
@@ -1463,12 +1832,55 @@ impl Request for CopyContextRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let src_bytes = self.src.serialize();
+        let dest_bytes = self.dest.serialize();
+        let mask_bytes = self.mask.serialize();
+        let src_context_tag_bytes = self.src_context_tag.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            COPY_CONTEXT_REQUEST,
+            0,
+            0,
+            src_bytes[0],
+            src_bytes[1],
+            src_bytes[2],
+            src_bytes[3],
+            dest_bytes[0],
+            dest_bytes[1],
+            dest_bytes[2],
+            dest_bytes[3],
+            mask_bytes[0],
+            mask_bytes[1],
+            mask_bytes[2],
+            mask_bytes[3],
+            src_context_tag_bytes[0],
+            src_context_tag_bytes[1],
+            src_context_tag_bytes[2],
+            src_context_tag_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for CopyContextRequest {
 }
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GC(u32);
 impl GC {
     pub const GL_CURRENT_BIT: Self = Self(1 << 0);
@@ -1551,12 +1963,47 @@ impl core::fmt::Debug for GC  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+impl GC {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("GL_CURRENT_BIT"),
+            2 => Some("GL_POINT_BIT"),
+            4 => Some("GL_LINE_BIT"),
+            8 => Some("GL_POLYGON_BIT"),
+            16 => Some("GL_POLYGON_STIPPLE_BIT"),
+            32 => Some("GL_PIXEL_MODE_BIT"),
+            64 => Some("GL_LIGHTING_BIT"),
+            128 => Some("GL_FOG_BIT"),
+            256 => Some("GL_DEPTH_BUFFER_BIT"),
+            512 => Some("GL_ACCUM_BUFFER_BIT"),
+            1024 => Some("GL_STENCIL_BUFFER_BIT"),
+            2048 => Some("GL_VIEWPORT_BIT"),
+            4096 => Some("GL_TRANSFORM_BIT"),
+            8192 => Some("GL_ENABLE_BIT"),
+            16384 => Some("GL_COLOR_BUFFER_BIT"),
+            32768 => Some("GL_HINT_BIT"),
+            65536 => Some("GL_EVAL_BIT"),
+            131_072 => Some("GL_LIST_BIT"),
+            262_144 => Some("GL_TEXTURE_BIT"),
+            524_288 => Some("GL_SCISSOR_BIT"),
+            16_777_215 => Some("GL_ALL_ATTRIB_BITS"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for GC {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the SwapBuffers request
 pub const SWAP_BUFFERS_REQUEST: u8 = 11;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SwapBuffersRequest {
     pub context_tag: ContextTag,
     pub drawable: Drawable,
@@ -1612,6 +2059,38 @@ impl Request for SwapBuffersRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let drawable_bytes = self.drawable.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SWAP_BUFFERS_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SwapBuffersRequest {
 }
@@ -1621,6 +2100,7 @@ pub const USE_X_FONT_REQUEST: u8 = 12;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct UseXFontRequest {
     pub context_tag: ContextTag,
     pub font: xproto::Font,
@@ -1700,15 +2180,63 @@ impl Request for UseXFontRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
-}
-impl crate::x11_utils::VoidRequest for UseXFontRequest {
-}
-
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let font_bytes = self.font.serialize();
+        let first_bytes = self.first.serialize();
+        let count_bytes = self.count.serialize();
+        let list_base_bytes = self.list_base.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            USE_X_FONT_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            font_bytes[0],
+            font_bytes[1],
+            font_bytes[2],
+            font_bytes[3],
+            first_bytes[0],
+            first_bytes[1],
+            first_bytes[2],
+            first_bytes[3],
+            count_bytes[0],
+            count_bytes[1],
+            count_bytes[2],
+            count_bytes[3],
+            list_base_bytes[0],
+            list_base_bytes[1],
+            list_base_bytes[2],
+            list_base_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
+}
+impl crate::x11_utils::VoidRequest for UseXFontRequest {
+}
+
 /// Opcode for the CreateGLXPixmap request
 pub const CREATE_GLX_PIXMAP_REQUEST: u8 = 13;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateGLXPixmapRequest {
     pub screen: u32,
     pub visual: xproto::Visualid,
@@ -1780,6 +2308,48 @@ impl Request for CreateGLXPixmapRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let screen_bytes = self.screen.serialize();
+        let visual_bytes = self.visual.serialize();
+        let pixmap_bytes = self.pixmap.serialize();
+        let glx_pixmap_bytes = self.glx_pixmap.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CREATE_GLX_PIXMAP_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            visual_bytes[0],
+            visual_bytes[1],
+            visual_bytes[2],
+            visual_bytes[3],
+            pixmap_bytes[0],
+            pixmap_bytes[1],
+            pixmap_bytes[2],
+            pixmap_bytes[3],
+            glx_pixmap_bytes[0],
+            glx_pixmap_bytes[1],
+            glx_pixmap_bytes[2],
+            glx_pixmap_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for CreateGLXPixmapRequest {
 }
@@ -1789,6 +2359,7 @@ pub const GET_VISUAL_CONFIGS_REQUEST: u8 = 14;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetVisualConfigsRequest {
     pub screen: u32,
 }
@@ -1836,6 +2407,33 @@ impl Request for GetVisualConfigsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let screen_bytes = self.screen.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_VISUAL_CONFIGS_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetVisualConfigsRequest {
     type Reply = GetVisualConfigsReply;
@@ -1851,6 +2449,12 @@ pub struct GetVisualConfigsReply {
     pub property_list: Vec<u32>,
 }
 impl_debug_if_no_extra_traits!(GetVisualConfigsReply, "GetVisualConfigsReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetVisualConfigsReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetVisualConfigsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1891,6 +2495,7 @@ impl Serialize for GetVisualConfigsReply {
         self.num_properties.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 16]);
         self.property_list.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetVisualConfigsReply {
@@ -1914,6 +2519,7 @@ pub const DESTROY_GLX_PIXMAP_REQUEST: u8 = 15;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DestroyGLXPixmapRequest {
     pub glx_pixmap: Pixmap,
 }
@@ -1961,6 +2567,33 @@ impl Request for DestroyGLXPixmapRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let glx_pixmap_bytes = self.glx_pixmap.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            DESTROY_GLX_PIXMAP_REQUEST,
+            0,
+            0,
+            glx_pixmap_bytes[0],
+            glx_pixmap_bytes[1],
+            glx_pixmap_bytes[2],
+            glx_pixmap_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for DestroyGLXPixmapRequest {
 }
@@ -1970,6 +2603,7 @@ pub const VENDOR_PRIVATE_REQUEST: u8 = 16;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct VendorPrivateRequest<'input> {
     pub vendor_code: u32,
     pub context_tag: ContextTag,
@@ -2039,6 +2673,41 @@ impl<'input> Request for VendorPrivateRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let vendor_code_bytes = self.vendor_code.serialize();
+        let context_tag_bytes = self.context_tag.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            VENDOR_PRIVATE_REQUEST,
+            0,
+            0,
+            vendor_code_bytes[0],
+            vendor_code_bytes[1],
+            vendor_code_bytes[2],
+            vendor_code_bytes[3],
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+        ]);
+        buffer.extend_from_slice(&self.data[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for VendorPrivateRequest<'input> {
 }
@@ -2048,6 +2717,7 @@ pub const VENDOR_PRIVATE_WITH_REPLY_REQUEST: u8 = 17;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct VendorPrivateWithReplyRequest<'input> {
     pub vendor_code: u32,
     pub context_tag: ContextTag,
@@ -2117,6 +2787,41 @@ impl<'input> Request for VendorPrivateWithReplyRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let vendor_code_bytes = self.vendor_code.serialize();
+        let context_tag_bytes = self.context_tag.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            VENDOR_PRIVATE_WITH_REPLY_REQUEST,
+            0,
+            0,
+            vendor_code_bytes[0],
+            vendor_code_bytes[1],
+            vendor_code_bytes[2],
+            vendor_code_bytes[3],
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+        ]);
+        buffer.extend_from_slice(&self.data[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::ReplyRequest for VendorPrivateWithReplyRequest<'input> {
     type Reply = VendorPrivateWithReplyReply;
@@ -2132,6 +2837,12 @@ pub struct VendorPrivateWithReplyReply {
     pub data2: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(VendorPrivateWithReplyReply, "VendorPrivateWithReplyReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for VendorPrivateWithReplyReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for VendorPrivateWithReplyReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2172,6 +2883,7 @@ impl Serialize for VendorPrivateWithReplyReply {
         self.retval.serialize_into(bytes);
         bytes.extend_from_slice(&self.data1);
         bytes.extend_from_slice(&self.data2);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl VendorPrivateWithReplyReply {
@@ -2196,6 +2908,7 @@ pub const QUERY_EXTENSIONS_STRING_REQUEST: u8 = 18;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryExtensionsStringRequest {
     pub screen: u32,
 }
@@ -2243,6 +2956,33 @@ impl Request for QueryExtensionsStringRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let screen_bytes = self.screen.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_EXTENSIONS_STRING_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryExtensionsStringRequest {
     type Reply = QueryExtensionsStringReply;
@@ -2257,6 +2997,12 @@ pub struct QueryExtensionsStringReply {
     pub n: u32,
 }
 impl_debug_if_no_extra_traits!(QueryExtensionsStringReply, "QueryExtensionsStringReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryExtensionsStringReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryExtensionsStringReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2337,6 +3083,7 @@ pub const QUERY_SERVER_STRING_REQUEST: u8 = 19;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryServerStringRequest {
     pub screen: u32,
     pub name: u32,
@@ -2392,6 +3139,38 @@ impl Request for QueryServerStringRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let screen_bytes = self.screen.serialize();
+        let name_bytes = self.name.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_SERVER_STRING_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            name_bytes[0],
+            name_bytes[1],
+            name_bytes[2],
+            name_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryServerStringRequest {
     type Reply = QueryServerStringReply;
@@ -2406,6 +3185,12 @@ pub struct QueryServerStringReply {
     pub string: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(QueryServerStringReply, "QueryServerStringReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryServerStringReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryServerStringReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2447,6 +3232,7 @@ impl Serialize for QueryServerStringReply {
         str_len.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 16]);
         bytes.extend_from_slice(&self.string);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl QueryServerStringReply {
@@ -2470,6 +3256,7 @@ pub const CLIENT_INFO_REQUEST: u8 = 20;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ClientInfoRequest<'input> {
     pub major_version: u32,
     pub minor_version: u32,
@@ -2546,6 +3333,47 @@ impl<'input> Request for ClientInfoRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let major_version_bytes = self.major_version.serialize();
+        let minor_version_bytes = self.minor_version.serialize();
+        let str_len = u32::try_from(self.string.len()).expect("`string` has too many elements");
+        let str_len_bytes = str_len.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CLIENT_INFO_REQUEST,
+            0,
+            0,
+            major_version_bytes[0],
+            major_version_bytes[1],
+            major_version_bytes[2],
+            major_version_bytes[3],
+            minor_version_bytes[0],
+            minor_version_bytes[1],
+            minor_version_bytes[2],
+            minor_version_bytes[3],
+            str_len_bytes[0],
+            str_len_bytes[1],
+            str_len_bytes[2],
+            str_len_bytes[3],
+        ]);
+        buffer.extend_from_slice(&self.string[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for ClientInfoRequest<'input> {
 }
@@ -2555,6 +3383,7 @@ pub const GET_FB_CONFIGS_REQUEST: u8 = 21;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetFBConfigsRequest {
     pub screen: u32,
 }
@@ -2602,6 +3431,33 @@ impl Request for GetFBConfigsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let screen_bytes = self.screen.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_FB_CONFIGS_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetFBConfigsRequest {
     type Reply = GetFBConfigsReply;
@@ -2617,6 +3473,12 @@ pub struct GetFBConfigsReply {
     pub property_list: Vec<u32>,
 }
 impl_debug_if_no_extra_traits!(GetFBConfigsReply, "GetFBConfigsReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetFBConfigsReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetFBConfigsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2657,6 +3519,7 @@ impl Serialize for GetFBConfigsReply {
         self.num_properties.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 16]);
         self.property_list.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetFBConfigsReply {
@@ -2680,6 +3543,7 @@ pub const CREATE_PIXMAP_REQUEST: u8 = 22;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreatePixmapRequest<'input> {
     pub screen: u32,
     pub fbconfig: Fbconfig,
@@ -2776,6 +3640,58 @@ impl<'input> Request for CreatePixmapRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let screen_bytes = self.screen.serialize();
+        let fbconfig_bytes = self.fbconfig.serialize();
+        let pixmap_bytes = self.pixmap.serialize();
+        let glx_pixmap_bytes = self.glx_pixmap.serialize();
+        assert_eq!(self.attribs.len() % 2, 0, "`attribs` has an incorrect length, must be a multiple of 2");
+        let num_attribs = u32::try_from(self.attribs.len() / 2).expect("`attribs` has too many elements");
+        let num_attribs_bytes = num_attribs.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CREATE_PIXMAP_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            fbconfig_bytes[0],
+            fbconfig_bytes[1],
+            fbconfig_bytes[2],
+            fbconfig_bytes[3],
+            pixmap_bytes[0],
+            pixmap_bytes[1],
+            pixmap_bytes[2],
+            pixmap_bytes[3],
+            glx_pixmap_bytes[0],
+            glx_pixmap_bytes[1],
+            glx_pixmap_bytes[2],
+            glx_pixmap_bytes[3],
+            num_attribs_bytes[0],
+            num_attribs_bytes[1],
+            num_attribs_bytes[2],
+            num_attribs_bytes[3],
+        ]);
+        self.attribs.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for CreatePixmapRequest<'input> {
 }
@@ -2785,6 +3701,7 @@ pub const DESTROY_PIXMAP_REQUEST: u8 = 23;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DestroyPixmapRequest {
     pub glx_pixmap: Pixmap,
 }
@@ -2832,15 +3749,43 @@ impl Request for DestroyPixmapRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
-}
-impl crate::x11_utils::VoidRequest for DestroyPixmapRequest {
-}
-
-/// Opcode for the CreateNewContext request
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let glx_pixmap_bytes = self.glx_pixmap.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            DESTROY_PIXMAP_REQUEST,
+            0,
+            0,
+            glx_pixmap_bytes[0],
+            glx_pixmap_bytes[1],
+            glx_pixmap_bytes[2],
+            glx_pixmap_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
+}
+impl crate::x11_utils::VoidRequest for DestroyPixmapRequest {
+}
+
+/// Opcode for the CreateNewContext request
 pub const CREATE_NEW_CONTEXT_REQUEST: u8 = 24;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateNewContextRequest {
     pub context: Context,
     pub fbconfig: Fbconfig,
@@ -2929,6 +3874,58 @@ impl Request for CreateNewContextRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_bytes = self.context.serialize();
+        let fbconfig_bytes = self.fbconfig.serialize();
+        let screen_bytes = self.screen.serialize();
+        let render_type_bytes = self.render_type.serialize();
+        let share_list_bytes = self.share_list.serialize();
+        let is_direct_bytes = self.is_direct.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CREATE_NEW_CONTEXT_REQUEST,
+            0,
+            0,
+            context_bytes[0],
+            context_bytes[1],
+            context_bytes[2],
+            context_bytes[3],
+            fbconfig_bytes[0],
+            fbconfig_bytes[1],
+            fbconfig_bytes[2],
+            fbconfig_bytes[3],
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            render_type_bytes[0],
+            render_type_bytes[1],
+            render_type_bytes[2],
+            render_type_bytes[3],
+            share_list_bytes[0],
+            share_list_bytes[1],
+            share_list_bytes[2],
+            share_list_bytes[3],
+            is_direct_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for CreateNewContextRequest {
 }
@@ -2938,6 +3935,7 @@ pub const QUERY_CONTEXT_REQUEST: u8 = 25;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryContextRequest {
     pub context: Context,
 }
@@ -2985,6 +3983,33 @@ impl Request for QueryContextRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_bytes = self.context.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_CONTEXT_REQUEST,
+            0,
+            0,
+            context_bytes[0],
+            context_bytes[1],
+            context_bytes[2],
+            context_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryContextRequest {
     type Reply = QueryContextReply;
@@ -2999,6 +4024,12 @@ pub struct QueryContextReply {
     pub attribs: Vec<u32>,
 }
 impl_debug_if_no_extra_traits!(QueryContextReply, "QueryContextReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryContextReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryContextReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -3038,6 +4069,7 @@ impl Serialize for QueryContextReply {
         num_attribs.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 20]);
         self.attribs.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl QueryContextReply {
@@ -3062,6 +4094,7 @@ pub const MAKE_CONTEXT_CURRENT_REQUEST: u8 = 26;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct MakeContextCurrentRequest {
     pub old_context_tag: ContextTag,
     pub drawable: Drawable,
@@ -3133,6 +4166,48 @@ impl Request for MakeContextCurrentRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let old_context_tag_bytes = self.old_context_tag.serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let read_drawable_bytes = self.read_drawable.serialize();
+        let context_bytes = self.context.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            MAKE_CONTEXT_CURRENT_REQUEST,
+            0,
+            0,
+            old_context_tag_bytes[0],
+            old_context_tag_bytes[1],
+            old_context_tag_bytes[2],
+            old_context_tag_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            read_drawable_bytes[0],
+            read_drawable_bytes[1],
+            read_drawable_bytes[2],
+            read_drawable_bytes[3],
+            context_bytes[0],
+            context_bytes[1],
+            context_bytes[2],
+            context_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for MakeContextCurrentRequest {
     type Reply = MakeContextCurrentReply;
@@ -3147,6 +4222,12 @@ pub struct MakeContextCurrentReply {
     pub context_tag: ContextTag,
 }
 impl_debug_if_no_extra_traits!(MakeContextCurrentReply, "MakeContextCurrentReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for MakeContextCurrentReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for MakeContextCurrentReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -3225,6 +4306,7 @@ pub const CREATE_PBUFFER_REQUEST: u8 = 27;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreatePbufferRequest<'input> {
     pub screen: u32,
     pub fbconfig: Fbconfig,
@@ -3312,6 +4394,53 @@ impl<'input> Request for CreatePbufferRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let screen_bytes = self.screen.serialize();
+        let fbconfig_bytes = self.fbconfig.serialize();
+        let pbuffer_bytes = self.pbuffer.serialize();
+        assert_eq!(self.attribs.len() % 2, 0, "`attribs` has an incorrect length, must be a multiple of 2");
+        let num_attribs = u32::try_from(self.attribs.len() / 2).expect("`attribs` has too many elements");
+        let num_attribs_bytes = num_attribs.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CREATE_PBUFFER_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            fbconfig_bytes[0],
+            fbconfig_bytes[1],
+            fbconfig_bytes[2],
+            fbconfig_bytes[3],
+            pbuffer_bytes[0],
+            pbuffer_bytes[1],
+            pbuffer_bytes[2],
+            pbuffer_bytes[3],
+            num_attribs_bytes[0],
+            num_attribs_bytes[1],
+            num_attribs_bytes[2],
+            num_attribs_bytes[3],
+        ]);
+        self.attribs.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for CreatePbufferRequest<'input> {
 }
@@ -3321,6 +4450,7 @@ pub const DESTROY_PBUFFER_REQUEST: u8 = 28;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DestroyPbufferRequest {
     pub pbuffer: Pbuffer,
 }
@@ -3368,6 +4498,33 @@ impl Request for DestroyPbufferRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let pbuffer_bytes = self.pbuffer.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            DESTROY_PBUFFER_REQUEST,
+            0,
+            0,
+            pbuffer_bytes[0],
+            pbuffer_bytes[1],
+            pbuffer_bytes[2],
+            pbuffer_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for DestroyPbufferRequest {
 }
@@ -3377,6 +4534,7 @@ pub const GET_DRAWABLE_ATTRIBUTES_REQUEST: u8 = 29;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetDrawableAttributesRequest {
     pub drawable: Drawable,
 }
@@ -3424,6 +4582,33 @@ impl Request for GetDrawableAttributesRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_DRAWABLE_ATTRIBUTES_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetDrawableAttributesRequest {
     type Reply = GetDrawableAttributesReply;
@@ -3438,6 +4623,12 @@ pub struct GetDrawableAttributesReply {
     pub attribs: Vec<u32>,
 }
 impl_debug_if_no_extra_traits!(GetDrawableAttributesReply, "GetDrawableAttributesReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetDrawableAttributesReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetDrawableAttributesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -3477,6 +4668,7 @@ impl Serialize for GetDrawableAttributesReply {
         num_attribs.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 20]);
         self.attribs.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetDrawableAttributesReply {
@@ -3501,6 +4693,7 @@ pub const CHANGE_DRAWABLE_ATTRIBUTES_REQUEST: u8 = 30;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangeDrawableAttributesRequest<'input> {
     pub drawable: Drawable,
     pub attribs: Cow<'input, [u32]>,
@@ -3570,6 +4763,43 @@ impl<'input> Request for ChangeDrawableAttributesRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        assert_eq!(self.attribs.len() % 2, 0, "`attribs` has an incorrect length, must be a multiple of 2");
+        let num_attribs = u32::try_from(self.attribs.len() / 2).expect("`attribs` has too many elements");
+        let num_attribs_bytes = num_attribs.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CHANGE_DRAWABLE_ATTRIBUTES_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            num_attribs_bytes[0],
+            num_attribs_bytes[1],
+            num_attribs_bytes[2],
+            num_attribs_bytes[3],
+        ]);
+        self.attribs.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for ChangeDrawableAttributesRequest<'input> {
 }
@@ -3579,6 +4809,7 @@ pub const CREATE_WINDOW_REQUEST: u8 = 31;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateWindowRequest<'input> {
     pub screen: u32,
     pub fbconfig: Fbconfig,
@@ -3675,6 +4906,58 @@ impl<'input> Request for CreateWindowRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let screen_bytes = self.screen.serialize();
+        let fbconfig_bytes = self.fbconfig.serialize();
+        let window_bytes = self.window.serialize();
+        let glx_window_bytes = self.glx_window.serialize();
+        assert_eq!(self.attribs.len() % 2, 0, "`attribs` has an incorrect length, must be a multiple of 2");
+        let num_attribs = u32::try_from(self.attribs.len() / 2).expect("`attribs` has too many elements");
+        let num_attribs_bytes = num_attribs.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CREATE_WINDOW_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            fbconfig_bytes[0],
+            fbconfig_bytes[1],
+            fbconfig_bytes[2],
+            fbconfig_bytes[3],
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            glx_window_bytes[0],
+            glx_window_bytes[1],
+            glx_window_bytes[2],
+            glx_window_bytes[3],
+            num_attribs_bytes[0],
+            num_attribs_bytes[1],
+            num_attribs_bytes[2],
+            num_attribs_bytes[3],
+        ]);
+        self.attribs.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for CreateWindowRequest<'input> {
 }
@@ -3684,6 +4967,7 @@ pub const DELETE_WINDOW_REQUEST: u8 = 32;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DeleteWindowRequest {
     pub glxwindow: Window,
 }
@@ -3731,6 +5015,33 @@ impl Request for DeleteWindowRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let glxwindow_bytes = self.glxwindow.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            DELETE_WINDOW_REQUEST,
+            0,
+            0,
+            glxwindow_bytes[0],
+            glxwindow_bytes[1],
+            glxwindow_bytes[2],
+            glxwindow_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for DeleteWindowRequest {
 }
@@ -3740,6 +5051,7 @@ pub const SET_CLIENT_INFO_ARB_REQUEST: u8 = 33;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetClientInfoARBRequest<'input> {
     pub major_version: u32,
     pub minor_version: u32,
@@ -3848,25 +5160,84 @@ impl<'input> Request for SetClientInfoARBRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
-}
-impl<'input> crate::x11_utils::VoidRequest for SetClientInfoARBRequest<'input> {
-}
-
-/// Opcode for the CreateContextAttribsARB request
-pub const CREATE_CONTEXT_ATTRIBS_ARB_REQUEST: u8 = 34;
-#[derive(Clone, Default)]
-#[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct CreateContextAttribsARBRequest<'input> {
-    pub context: Context,
-    pub fbconfig: Fbconfig,
-    pub screen: u32,
-    pub share_list: Context,
-    pub is_direct: bool,
-    pub attribs: Cow<'input, [u32]>,
-}
-impl_debug_if_no_extra_traits!(CreateContextAttribsARBRequest<'_>, "CreateContextAttribsARBRequest");
-impl<'input> CreateContextAttribsARBRequest<'input> {
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let major_version_bytes = self.major_version.serialize();
+        let minor_version_bytes = self.minor_version.serialize();
+        assert_eq!(self.gl_versions.len() % 2, 0, "`gl_versions` has an incorrect length, must be a multiple of 2");
+        let num_versions = u32::try_from(self.gl_versions.len() / 2).expect("`gl_versions` has too many elements");
+        let num_versions_bytes = num_versions.serialize();
+        let gl_str_len = u32::try_from(self.gl_extension_string.len()).expect("`gl_extension_string` has too many elements");
+        let gl_str_len_bytes = gl_str_len.serialize();
+        let glx_str_len = u32::try_from(self.glx_extension_string.len()).expect("`glx_extension_string` has too many elements");
+        let glx_str_len_bytes = glx_str_len.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_CLIENT_INFO_ARB_REQUEST,
+            0,
+            0,
+            major_version_bytes[0],
+            major_version_bytes[1],
+            major_version_bytes[2],
+            major_version_bytes[3],
+            minor_version_bytes[0],
+            minor_version_bytes[1],
+            minor_version_bytes[2],
+            minor_version_bytes[3],
+            num_versions_bytes[0],
+            num_versions_bytes[1],
+            num_versions_bytes[2],
+            num_versions_bytes[3],
+            gl_str_len_bytes[0],
+            gl_str_len_bytes[1],
+            gl_str_len_bytes[2],
+            gl_str_len_bytes[3],
+            glx_str_len_bytes[0],
+            glx_str_len_bytes[1],
+            glx_str_len_bytes[2],
+            glx_str_len_bytes[3],
+        ]);
+        self.gl_versions.serialize_into(buffer);
+        buffer.extend_from_slice(&self.gl_extension_string[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        buffer.extend_from_slice(&self.glx_extension_string[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
+}
+impl<'input> crate::x11_utils::VoidRequest for SetClientInfoARBRequest<'input> {
+}
+
+/// Opcode for the CreateContextAttribsARB request
+pub const CREATE_CONTEXT_ATTRIBS_ARB_REQUEST: u8 = 34;
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct CreateContextAttribsARBRequest<'input> {
+    pub context: Context,
+    pub fbconfig: Fbconfig,
+    pub screen: u32,
+    pub share_list: Context,
+    pub is_direct: bool,
+    pub attribs: Cow<'input, [u32]>,
+}
+impl_debug_if_no_extra_traits!(CreateContextAttribsARBRequest<'_>, "CreateContextAttribsARBRequest");
+impl<'input> CreateContextAttribsARBRequest<'input> {
     /// Serialize this request into bytes for the provided connection
     pub fn serialize(self, major_opcode: u8) -> BufWithFds<[Cow<'input, [u8]>; 3]> {
         let length_so_far = 0;
@@ -3963,6 +5334,63 @@ impl<'input> Request for CreateContextAttribsARBRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_bytes = self.context.serialize();
+        let fbconfig_bytes = self.fbconfig.serialize();
+        let screen_bytes = self.screen.serialize();
+        let share_list_bytes = self.share_list.serialize();
+        let is_direct_bytes = self.is_direct.serialize();
+        assert_eq!(self.attribs.len() % 2, 0, "`attribs` has an incorrect length, must be a multiple of 2");
+        let num_attribs = u32::try_from(self.attribs.len() / 2).expect("`attribs` has too many elements");
+        let num_attribs_bytes = num_attribs.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CREATE_CONTEXT_ATTRIBS_ARB_REQUEST,
+            0,
+            0,
+            context_bytes[0],
+            context_bytes[1],
+            context_bytes[2],
+            context_bytes[3],
+            fbconfig_bytes[0],
+            fbconfig_bytes[1],
+            fbconfig_bytes[2],
+            fbconfig_bytes[3],
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            share_list_bytes[0],
+            share_list_bytes[1],
+            share_list_bytes[2],
+            share_list_bytes[3],
+            is_direct_bytes[0],
+            0,
+            0,
+            0,
+            num_attribs_bytes[0],
+            num_attribs_bytes[1],
+            num_attribs_bytes[2],
+            num_attribs_bytes[3],
+        ]);
+        self.attribs.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for CreateContextAttribsARBRequest<'input> {
 }
@@ -3972,6 +5400,7 @@ pub const SET_CLIENT_INFO2_ARB_REQUEST: u8 = 35;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetClientInfo2ARBRequest<'input> {
     pub major_version: u32,
     pub minor_version: u32,
@@ -4080,6 +5509,64 @@ impl<'input> Request for SetClientInfo2ARBRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let major_version_bytes = self.major_version.serialize();
+        let minor_version_bytes = self.minor_version.serialize();
+        assert_eq!(self.gl_versions.len() % 3, 0, "`gl_versions` has an incorrect length, must be a multiple of 3");
+        let num_versions = u32::try_from(self.gl_versions.len() / 3).expect("`gl_versions` has too many elements");
+        let num_versions_bytes = num_versions.serialize();
+        let gl_str_len = u32::try_from(self.gl_extension_string.len()).expect("`gl_extension_string` has too many elements");
+        let gl_str_len_bytes = gl_str_len.serialize();
+        let glx_str_len = u32::try_from(self.glx_extension_string.len()).expect("`glx_extension_string` has too many elements");
+        let glx_str_len_bytes = glx_str_len.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_CLIENT_INFO2_ARB_REQUEST,
+            0,
+            0,
+            major_version_bytes[0],
+            major_version_bytes[1],
+            major_version_bytes[2],
+            major_version_bytes[3],
+            minor_version_bytes[0],
+            minor_version_bytes[1],
+            minor_version_bytes[2],
+            minor_version_bytes[3],
+            num_versions_bytes[0],
+            num_versions_bytes[1],
+            num_versions_bytes[2],
+            num_versions_bytes[3],
+            gl_str_len_bytes[0],
+            gl_str_len_bytes[1],
+            gl_str_len_bytes[2],
+            gl_str_len_bytes[3],
+            glx_str_len_bytes[0],
+            glx_str_len_bytes[1],
+            glx_str_len_bytes[2],
+            glx_str_len_bytes[3],
+        ]);
+        self.gl_versions.serialize_into(buffer);
+        buffer.extend_from_slice(&self.gl_extension_string[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        buffer.extend_from_slice(&self.glx_extension_string[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for SetClientInfo2ARBRequest<'input> {
 }
@@ -4089,6 +5576,7 @@ pub const NEW_LIST_REQUEST: u8 = 101;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct NewListRequest {
     pub context_tag: ContextTag,
     pub list: u32,
@@ -4152,6 +5640,43 @@ impl Request for NewListRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let list_bytes = self.list.serialize();
+        let mode_bytes = self.mode.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            NEW_LIST_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            list_bytes[0],
+            list_bytes[1],
+            list_bytes[2],
+            list_bytes[3],
+            mode_bytes[0],
+            mode_bytes[1],
+            mode_bytes[2],
+            mode_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for NewListRequest {
 }
@@ -4161,6 +5686,7 @@ pub const END_LIST_REQUEST: u8 = 102;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct EndListRequest {
     pub context_tag: ContextTag,
 }
@@ -4208,6 +5734,33 @@ impl Request for EndListRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            END_LIST_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for EndListRequest {
 }
@@ -4217,6 +5770,7 @@ pub const DELETE_LISTS_REQUEST: u8 = 103;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DeleteListsRequest {
     pub context_tag: ContextTag,
     pub list: u32,
@@ -4280,6 +5834,43 @@ impl Request for DeleteListsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let list_bytes = self.list.serialize();
+        let range_bytes = self.range.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            DELETE_LISTS_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            list_bytes[0],
+            list_bytes[1],
+            list_bytes[2],
+            list_bytes[3],
+            range_bytes[0],
+            range_bytes[1],
+            range_bytes[2],
+            range_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for DeleteListsRequest {
 }
@@ -4289,6 +5880,7 @@ pub const GEN_LISTS_REQUEST: u8 = 104;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GenListsRequest {
     pub context_tag: ContextTag,
     pub range: i32,
@@ -4344,6 +5936,38 @@ impl Request for GenListsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let range_bytes = self.range.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GEN_LISTS_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            range_bytes[0],
+            range_bytes[1],
+            range_bytes[2],
+            range_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GenListsRequest {
     type Reply = GenListsReply;
@@ -4358,6 +5982,12 @@ pub struct GenListsReply {
     pub ret_val: u32,
 }
 impl_debug_if_no_extra_traits!(GenListsReply, "GenListsReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GenListsReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GenListsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -4414,6 +6044,7 @@ pub const FEEDBACK_BUFFER_REQUEST: u8 = 105;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct FeedbackBufferRequest {
     pub context_tag: ContextTag,
     pub size: i32,
@@ -4477,6 +6108,43 @@ impl Request for FeedbackBufferRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let size_bytes = self.size.serialize();
+        let type_bytes = self.type_.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            FEEDBACK_BUFFER_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            size_bytes[0],
+            size_bytes[1],
+            size_bytes[2],
+            size_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for FeedbackBufferRequest {
 }
@@ -4486,6 +6154,7 @@ pub const SELECT_BUFFER_REQUEST: u8 = 106;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SelectBufferRequest {
     pub context_tag: ContextTag,
     pub size: i32,
@@ -4541,6 +6210,38 @@ impl Request for SelectBufferRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let size_bytes = self.size.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SELECT_BUFFER_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            size_bytes[0],
+            size_bytes[1],
+            size_bytes[2],
+            size_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SelectBufferRequest {
 }
@@ -4550,6 +6251,7 @@ pub const RENDER_MODE_REQUEST: u8 = 107;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct RenderModeRequest {
     pub context_tag: ContextTag,
     pub mode: u32,
@@ -4605,6 +6307,38 @@ impl Request for RenderModeRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let mode_bytes = self.mode.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            RENDER_MODE_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            mode_bytes[0],
+            mode_bytes[1],
+            mode_bytes[2],
+            mode_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for RenderModeRequest {
     type Reply = RenderModeReply;
@@ -4621,6 +6355,12 @@ pub struct RenderModeReply {
     pub data: Vec<u32>,
 }
 impl_debug_if_no_extra_traits!(RenderModeReply, "RenderModeReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for RenderModeReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for RenderModeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -4663,6 +6403,7 @@ impl Serialize for RenderModeReply {
         self.new_mode.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl RenderModeReply {
@@ -4683,6 +6424,7 @@ impl RenderModeReply {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct RM(u16);
 impl RM {
     pub const GL_RENDER: Self = Self(7168);
@@ -4735,12 +6477,29 @@ impl core::fmt::Debug for RM  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl RM {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            7168 => Some("GL_RENDER"),
+            7169 => Some("GL_FEEDBACK"),
+            7170 => Some("GL_SELECT"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for RM {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the Finish request
 pub const FINISH_REQUEST: u8 = 108;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct FinishRequest {
     pub context_tag: ContextTag,
 }
@@ -4788,6 +6547,33 @@ impl Request for FinishRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            FINISH_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for FinishRequest {
     type Reply = FinishReply;
@@ -4801,6 +6587,12 @@ pub struct FinishReply {
     pub length: u32,
 }
 impl_debug_if_no_extra_traits!(FinishReply, "FinishReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for FinishReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for FinishReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -4850,6 +6642,7 @@ pub const PIXEL_STOREF_REQUEST: u8 = 109;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, PartialOrd))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PixelStorefRequest {
     pub context_tag: ContextTag,
     pub pname: u32,
@@ -4909,9 +6702,46 @@ impl Request for PixelStorefRequest {
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
-        // Flatten the buffers into a single vector
-        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
-        (buf, fds)
+        // Flatten the buffers into a single vector
+        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
+        (buf, fds)
+    }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let pname_bytes = self.pname.serialize();
+        let datum_bytes = self.datum.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            PIXEL_STOREF_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+            datum_bytes[0],
+            datum_bytes[1],
+            datum_bytes[2],
+            datum_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
     }
 }
 impl crate::x11_utils::VoidRequest for PixelStorefRequest {
@@ -4922,6 +6752,7 @@ pub const PIXEL_STOREI_REQUEST: u8 = 110;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PixelStoreiRequest {
     pub context_tag: ContextTag,
     pub pname: u32,
@@ -4985,6 +6816,43 @@ impl Request for PixelStoreiRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let pname_bytes = self.pname.serialize();
+        let datum_bytes = self.datum.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            PIXEL_STOREI_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+            datum_bytes[0],
+            datum_bytes[1],
+            datum_bytes[2],
+            datum_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for PixelStoreiRequest {
 }
@@ -4994,6 +6862,7 @@ pub const READ_PIXELS_REQUEST: u8 = 111;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ReadPixelsRequest {
     pub context_tag: ContextTag,
     pub x: i32,
@@ -5101,6 +6970,69 @@ impl Request for ReadPixelsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let x_bytes = self.x.serialize();
+        let y_bytes = self.y.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        let format_bytes = self.format.serialize();
+        let type_bytes = self.type_.serialize();
+        let swap_bytes_bytes = self.swap_bytes.serialize();
+        let lsb_first_bytes = self.lsb_first.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            READ_PIXELS_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            x_bytes[0],
+            x_bytes[1],
+            x_bytes[2],
+            x_bytes[3],
+            y_bytes[0],
+            y_bytes[1],
+            y_bytes[2],
+            y_bytes[3],
+            width_bytes[0],
+            width_bytes[1],
+            width_bytes[2],
+            width_bytes[3],
+            height_bytes[0],
+            height_bytes[1],
+            height_bytes[2],
+            height_bytes[3],
+            format_bytes[0],
+            format_bytes[1],
+            format_bytes[2],
+            format_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            swap_bytes_bytes[0],
+            lsb_first_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for ReadPixelsRequest {
     type Reply = ReadPixelsReply;
@@ -5114,6 +7046,12 @@ pub struct ReadPixelsReply {
     pub data: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(ReadPixelsReply, "ReadPixelsReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ReadPixelsReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ReadPixelsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5152,6 +7090,7 @@ impl Serialize for ReadPixelsReply {
         length.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 24]);
         bytes.extend_from_slice(&self.data);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl ReadPixelsReply {
@@ -5176,6 +7115,7 @@ pub const GET_BOOLEANV_REQUEST: u8 = 112;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetBooleanvRequest {
     pub context_tag: ContextTag,
     pub pname: i32,
@@ -5231,6 +7171,38 @@ impl Request for GetBooleanvRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let pname_bytes = self.pname.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_BOOLEANV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetBooleanvRequest {
     type Reply = GetBooleanvReply;
@@ -5246,6 +7218,12 @@ pub struct GetBooleanvReply {
     pub data: Vec<bool>,
 }
 impl_debug_if_no_extra_traits!(GetBooleanvReply, "GetBooleanvReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetBooleanvReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetBooleanvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5288,6 +7266,7 @@ impl Serialize for GetBooleanvReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 15]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetBooleanvReply {
@@ -5311,6 +7290,7 @@ pub const GET_CLIP_PLANE_REQUEST: u8 = 113;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetClipPlaneRequest {
     pub context_tag: ContextTag,
     pub plane: i32,
@@ -5366,6 +7346,38 @@ impl Request for GetClipPlaneRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let plane_bytes = self.plane.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_CLIP_PLANE_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            plane_bytes[0],
+            plane_bytes[1],
+            plane_bytes[2],
+            plane_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetClipPlaneRequest {
     type Reply = GetClipPlaneReply;
@@ -5379,6 +7391,12 @@ pub struct GetClipPlaneReply {
     pub data: Vec<Float64>,
 }
 impl_debug_if_no_extra_traits!(GetClipPlaneReply, "GetClipPlaneReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetClipPlaneReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetClipPlaneReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5415,6 +7433,7 @@ impl Serialize for GetClipPlaneReply {
         length.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 24]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetClipPlaneReply {
@@ -5439,6 +7458,7 @@ pub const GET_DOUBLEV_REQUEST: u8 = 114;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetDoublevRequest {
     pub context_tag: ContextTag,
     pub pname: u32,
@@ -5494,6 +7514,38 @@ impl Request for GetDoublevRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let pname_bytes = self.pname.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_DOUBLEV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetDoublevRequest {
     type Reply = GetDoublevReply;
@@ -5509,6 +7561,12 @@ pub struct GetDoublevReply {
     pub data: Vec<Float64>,
 }
 impl_debug_if_no_extra_traits!(GetDoublevReply, "GetDoublevReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetDoublevReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetDoublevReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5551,6 +7609,7 @@ impl Serialize for GetDoublevReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 8]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetDoublevReply {
@@ -5574,6 +7633,7 @@ pub const GET_ERROR_REQUEST: u8 = 115;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetErrorRequest {
     pub context_tag: ContextTag,
 }
@@ -5621,6 +7681,33 @@ impl Request for GetErrorRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_ERROR_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetErrorRequest {
     type Reply = GetErrorReply;
@@ -5635,6 +7722,12 @@ pub struct GetErrorReply {
     pub error: i32,
 }
 impl_debug_if_no_extra_traits!(GetErrorReply, "GetErrorReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetErrorReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetErrorReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5691,6 +7784,7 @@ pub const GET_FLOATV_REQUEST: u8 = 116;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetFloatvRequest {
     pub context_tag: ContextTag,
     pub pname: u32,
@@ -5746,6 +7840,38 @@ impl Request for GetFloatvRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let pname_bytes = self.pname.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_FLOATV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetFloatvRequest {
     type Reply = GetFloatvReply;
@@ -5761,6 +7887,12 @@ pub struct GetFloatvReply {
     pub data: Vec<Float32>,
 }
 impl_debug_if_no_extra_traits!(GetFloatvReply, "GetFloatvReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetFloatvReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetFloatvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5803,6 +7935,7 @@ impl Serialize for GetFloatvReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetFloatvReply {
@@ -5826,6 +7959,7 @@ pub const GET_INTEGERV_REQUEST: u8 = 117;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetIntegervRequest {
     pub context_tag: ContextTag,
     pub pname: u32,
@@ -5881,6 +8015,38 @@ impl Request for GetIntegervRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let pname_bytes = self.pname.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_INTEGERV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetIntegervRequest {
     type Reply = GetIntegervReply;
@@ -5896,6 +8062,12 @@ pub struct GetIntegervReply {
     pub data: Vec<i32>,
 }
 impl_debug_if_no_extra_traits!(GetIntegervReply, "GetIntegervReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetIntegervReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetIntegervReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5938,6 +8110,7 @@ impl Serialize for GetIntegervReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetIntegervReply {
@@ -5961,6 +8134,7 @@ pub const GET_LIGHTFV_REQUEST: u8 = 118;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetLightfvRequest {
     pub context_tag: ContextTag,
     pub light: u32,
@@ -6024,6 +8198,43 @@ impl Request for GetLightfvRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let light_bytes = self.light.serialize();
+        let pname_bytes = self.pname.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_LIGHTFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            light_bytes[0],
+            light_bytes[1],
+            light_bytes[2],
+            light_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetLightfvRequest {
     type Reply = GetLightfvReply;
@@ -6039,6 +8250,12 @@ pub struct GetLightfvReply {
     pub data: Vec<Float32>,
 }
 impl_debug_if_no_extra_traits!(GetLightfvReply, "GetLightfvReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetLightfvReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetLightfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -6081,6 +8298,7 @@ impl Serialize for GetLightfvReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetLightfvReply {
@@ -6104,6 +8322,7 @@ pub const GET_LIGHTIV_REQUEST: u8 = 119;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetLightivRequest {
     pub context_tag: ContextTag,
     pub light: u32,
@@ -6157,15 +8376,52 @@ impl GetLightivRequest {
             pname,
         })
     }
-}
-impl Request for GetLightivRequest {
-    const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
-
-    fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
+}
+impl Request for GetLightivRequest {
+    const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+
+    fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
+        let (bufs, fds) = self.serialize(major_opcode);
+        // Flatten the buffers into a single vector
+        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
+        (buf, fds)
+    }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let light_bytes = self.light.serialize();
+        let pname_bytes = self.pname.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_LIGHTIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            light_bytes[0],
+            light_bytes[1],
+            light_bytes[2],
+            light_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
         let (bufs, fds) = self.serialize(major_opcode);
-        // Flatten the buffers into a single vector
-        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
-        (buf, fds)
+        slices.extend(bufs);
+        fds
     }
 }
 impl crate::x11_utils::ReplyRequest for GetLightivRequest {
@@ -6182,6 +8438,12 @@ pub struct GetLightivReply {
     pub data: Vec<i32>,
 }
 impl_debug_if_no_extra_traits!(GetLightivReply, "GetLightivReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetLightivReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetLightivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -6224,6 +8486,7 @@ impl Serialize for GetLightivReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetLightivReply {
@@ -6247,6 +8510,7 @@ pub const GET_MAPDV_REQUEST: u8 = 120;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetMapdvRequest {
     pub context_tag: ContextTag,
     pub target: u32,
@@ -6310,6 +8574,43 @@ impl Request for GetMapdvRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let query_bytes = self.query.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_MAPDV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            query_bytes[0],
+            query_bytes[1],
+            query_bytes[2],
+            query_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetMapdvRequest {
     type Reply = GetMapdvReply;
@@ -6325,6 +8626,12 @@ pub struct GetMapdvReply {
     pub data: Vec<Float64>,
 }
 impl_debug_if_no_extra_traits!(GetMapdvReply, "GetMapdvReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetMapdvReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetMapdvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -6367,6 +8674,7 @@ impl Serialize for GetMapdvReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 8]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetMapdvReply {
@@ -6390,6 +8698,7 @@ pub const GET_MAPFV_REQUEST: u8 = 121;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetMapfvRequest {
     pub context_tag: ContextTag,
     pub target: u32,
@@ -6453,6 +8762,43 @@ impl Request for GetMapfvRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let query_bytes = self.query.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_MAPFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            query_bytes[0],
+            query_bytes[1],
+            query_bytes[2],
+            query_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetMapfvRequest {
     type Reply = GetMapfvReply;
@@ -6468,6 +8814,12 @@ pub struct GetMapfvReply {
     pub data: Vec<Float32>,
 }
 impl_debug_if_no_extra_traits!(GetMapfvReply, "GetMapfvReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetMapfvReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetMapfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -6510,6 +8862,7 @@ impl Serialize for GetMapfvReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetMapfvReply {
@@ -6533,6 +8886,7 @@ pub const GET_MAPIV_REQUEST: u8 = 122;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetMapivRequest {
     pub context_tag: ContextTag,
     pub target: u32,
@@ -6596,6 +8950,43 @@ impl Request for GetMapivRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let query_bytes = self.query.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_MAPIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            query_bytes[0],
+            query_bytes[1],
+            query_bytes[2],
+            query_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetMapivRequest {
     type Reply = GetMapivReply;
@@ -6611,6 +9002,12 @@ pub struct GetMapivReply {
     pub data: Vec<i32>,
 }
 impl_debug_if_no_extra_traits!(GetMapivReply, "GetMapivReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetMapivReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetMapivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -6653,6 +9050,7 @@ impl Serialize for GetMapivReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetMapivReply {
@@ -6676,6 +9074,7 @@ pub const GET_MATERIALFV_REQUEST: u8 = 123;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetMaterialfvRequest {
     pub context_tag: ContextTag,
     pub face: u32,
@@ -6739,6 +9138,43 @@ impl Request for GetMaterialfvRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let face_bytes = self.face.serialize();
+        let pname_bytes = self.pname.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_MATERIALFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            face_bytes[0],
+            face_bytes[1],
+            face_bytes[2],
+            face_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetMaterialfvRequest {
     type Reply = GetMaterialfvReply;
@@ -6754,6 +9190,12 @@ pub struct GetMaterialfvReply {
     pub data: Vec<Float32>,
 }
 impl_debug_if_no_extra_traits!(GetMaterialfvReply, "GetMaterialfvReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetMaterialfvReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetMaterialfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -6796,6 +9238,7 @@ impl Serialize for GetMaterialfvReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetMaterialfvReply {
@@ -6819,6 +9262,7 @@ pub const GET_MATERIALIV_REQUEST: u8 = 124;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetMaterialivRequest {
     pub context_tag: ContextTag,
     pub face: u32,
@@ -6882,6 +9326,43 @@ impl Request for GetMaterialivRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let face_bytes = self.face.serialize();
+        let pname_bytes = self.pname.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_MATERIALIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            face_bytes[0],
+            face_bytes[1],
+            face_bytes[2],
+            face_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetMaterialivRequest {
     type Reply = GetMaterialivReply;
@@ -6897,6 +9378,12 @@ pub struct GetMaterialivReply {
     pub data: Vec<i32>,
 }
 impl_debug_if_no_extra_traits!(GetMaterialivReply, "GetMaterialivReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetMaterialivReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetMaterialivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -6939,6 +9426,7 @@ impl Serialize for GetMaterialivReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetMaterialivReply {
@@ -6962,6 +9450,7 @@ pub const GET_PIXEL_MAPFV_REQUEST: u8 = 125;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetPixelMapfvRequest {
     pub context_tag: ContextTag,
     pub map: u32,
@@ -7017,6 +9506,38 @@ impl Request for GetPixelMapfvRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let map_bytes = self.map.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_PIXEL_MAPFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            map_bytes[0],
+            map_bytes[1],
+            map_bytes[2],
+            map_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetPixelMapfvRequest {
     type Reply = GetPixelMapfvReply;
@@ -7032,6 +9553,12 @@ pub struct GetPixelMapfvReply {
     pub data: Vec<Float32>,
 }
 impl_debug_if_no_extra_traits!(GetPixelMapfvReply, "GetPixelMapfvReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetPixelMapfvReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetPixelMapfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -7074,6 +9601,7 @@ impl Serialize for GetPixelMapfvReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetPixelMapfvReply {
@@ -7097,6 +9625,7 @@ pub const GET_PIXEL_MAPUIV_REQUEST: u8 = 126;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetPixelMapuivRequest {
     pub context_tag: ContextTag,
     pub map: u32,
@@ -7152,6 +9681,38 @@ impl Request for GetPixelMapuivRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let map_bytes = self.map.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_PIXEL_MAPUIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            map_bytes[0],
+            map_bytes[1],
+            map_bytes[2],
+            map_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetPixelMapuivRequest {
     type Reply = GetPixelMapuivReply;
@@ -7167,6 +9728,12 @@ pub struct GetPixelMapuivReply {
     pub data: Vec<u32>,
 }
 impl_debug_if_no_extra_traits!(GetPixelMapuivReply, "GetPixelMapuivReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetPixelMapuivReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetPixelMapuivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -7209,6 +9776,7 @@ impl Serialize for GetPixelMapuivReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetPixelMapuivReply {
@@ -7232,6 +9800,7 @@ pub const GET_PIXEL_MAPUSV_REQUEST: u8 = 127;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetPixelMapusvRequest {
     pub context_tag: ContextTag,
     pub map: u32,
@@ -7287,6 +9856,38 @@ impl Request for GetPixelMapusvRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let map_bytes = self.map.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_PIXEL_MAPUSV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            map_bytes[0],
+            map_bytes[1],
+            map_bytes[2],
+            map_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetPixelMapusvRequest {
     type Reply = GetPixelMapusvReply;
@@ -7302,6 +9903,12 @@ pub struct GetPixelMapusvReply {
     pub data: Vec<u16>,
 }
 impl_debug_if_no_extra_traits!(GetPixelMapusvReply, "GetPixelMapusvReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetPixelMapusvReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetPixelMapusvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -7344,6 +9951,7 @@ impl Serialize for GetPixelMapusvReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 16]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetPixelMapusvReply {
@@ -7367,6 +9975,7 @@ pub const GET_POLYGON_STIPPLE_REQUEST: u8 = 128;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetPolygonStippleRequest {
     pub context_tag: ContextTag,
     pub lsb_first: bool,
@@ -7422,6 +10031,38 @@ impl Request for GetPolygonStippleRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let lsb_first_bytes = self.lsb_first.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_POLYGON_STIPPLE_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            lsb_first_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetPolygonStippleRequest {
     type Reply = GetPolygonStippleReply;
@@ -7435,6 +10076,12 @@ pub struct GetPolygonStippleReply {
     pub data: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(GetPolygonStippleReply, "GetPolygonStippleReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetPolygonStippleReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetPolygonStippleReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -7473,6 +10120,7 @@ impl Serialize for GetPolygonStippleReply {
         length.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 24]);
         bytes.extend_from_slice(&self.data);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetPolygonStippleReply {
@@ -7497,6 +10145,7 @@ pub const GET_STRING_REQUEST: u8 = 129;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetStringRequest {
     pub context_tag: ContextTag,
     pub name: u32,
@@ -7542,15 +10191,47 @@ impl GetStringRequest {
             name,
         })
     }
-}
-impl Request for GetStringRequest {
-    const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
-
-    fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
+}
+impl Request for GetStringRequest {
+    const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+
+    fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
+        let (bufs, fds) = self.serialize(major_opcode);
+        // Flatten the buffers into a single vector
+        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
+        (buf, fds)
+    }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let name_bytes = self.name.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_STRING_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            name_bytes[0],
+            name_bytes[1],
+            name_bytes[2],
+            name_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
         let (bufs, fds) = self.serialize(major_opcode);
-        // Flatten the buffers into a single vector
-        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
-        (buf, fds)
+        slices.extend(bufs);
+        fds
     }
 }
 impl crate::x11_utils::ReplyRequest for GetStringRequest {
@@ -7566,6 +10247,12 @@ pub struct GetStringReply {
     pub string: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(GetStringReply, "GetStringReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetStringReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetStringReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -7607,6 +10294,7 @@ impl Serialize for GetStringReply {
         n.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 16]);
         bytes.extend_from_slice(&self.string);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetStringReply {
@@ -7630,6 +10318,7 @@ pub const GET_TEX_ENVFV_REQUEST: u8 = 130;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetTexEnvfvRequest {
     pub context_tag: ContextTag,
     pub target: u32,
@@ -7693,6 +10382,43 @@ impl Request for GetTexEnvfvRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let pname_bytes = self.pname.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_TEX_ENVFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetTexEnvfvRequest {
     type Reply = GetTexEnvfvReply;
@@ -7708,6 +10434,12 @@ pub struct GetTexEnvfvReply {
     pub data: Vec<Float32>,
 }
 impl_debug_if_no_extra_traits!(GetTexEnvfvReply, "GetTexEnvfvReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetTexEnvfvReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetTexEnvfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -7750,6 +10482,7 @@ impl Serialize for GetTexEnvfvReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetTexEnvfvReply {
@@ -7773,6 +10506,7 @@ pub const GET_TEX_ENVIV_REQUEST: u8 = 131;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetTexEnvivRequest {
     pub context_tag: ContextTag,
     pub target: u32,
@@ -7836,6 +10570,43 @@ impl Request for GetTexEnvivRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let pname_bytes = self.pname.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_TEX_ENVIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetTexEnvivRequest {
     type Reply = GetTexEnvivReply;
@@ -7851,6 +10622,12 @@ pub struct GetTexEnvivReply {
     pub data: Vec<i32>,
 }
 impl_debug_if_no_extra_traits!(GetTexEnvivReply, "GetTexEnvivReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetTexEnvivReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetTexEnvivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -7893,6 +10670,7 @@ impl Serialize for GetTexEnvivReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetTexEnvivReply {
@@ -7916,6 +10694,7 @@ pub const GET_TEX_GENDV_REQUEST: u8 = 132;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetTexGendvRequest {
     pub context_tag: ContextTag,
     pub coord: u32,
@@ -7979,6 +10758,43 @@ impl Request for GetTexGendvRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let coord_bytes = self.coord.serialize();
+        let pname_bytes = self.pname.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_TEX_GENDV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            coord_bytes[0],
+            coord_bytes[1],
+            coord_bytes[2],
+            coord_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetTexGendvRequest {
     type Reply = GetTexGendvReply;
@@ -7994,6 +10810,12 @@ pub struct GetTexGendvReply {
     pub data: Vec<Float64>,
 }
 impl_debug_if_no_extra_traits!(GetTexGendvReply, "GetTexGendvReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetTexGendvReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetTexGendvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -8036,6 +10858,7 @@ impl Serialize for GetTexGendvReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 8]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetTexGendvReply {
@@ -8059,6 +10882,7 @@ pub const GET_TEX_GENFV_REQUEST: u8 = 133;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetTexGenfvRequest {
     pub context_tag: ContextTag,
     pub coord: u32,
@@ -8122,6 +10946,43 @@ impl Request for GetTexGenfvRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let coord_bytes = self.coord.serialize();
+        let pname_bytes = self.pname.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_TEX_GENFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            coord_bytes[0],
+            coord_bytes[1],
+            coord_bytes[2],
+            coord_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetTexGenfvRequest {
     type Reply = GetTexGenfvReply;
@@ -8137,6 +10998,12 @@ pub struct GetTexGenfvReply {
     pub data: Vec<Float32>,
 }
 impl_debug_if_no_extra_traits!(GetTexGenfvReply, "GetTexGenfvReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetTexGenfvReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetTexGenfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -8179,6 +11046,7 @@ impl Serialize for GetTexGenfvReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetTexGenfvReply {
@@ -8202,6 +11070,7 @@ pub const GET_TEX_GENIV_REQUEST: u8 = 134;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetTexGenivRequest {
     pub context_tag: ContextTag,
     pub coord: u32,
@@ -8265,6 +11134,43 @@ impl Request for GetTexGenivRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let coord_bytes = self.coord.serialize();
+        let pname_bytes = self.pname.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_TEX_GENIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            coord_bytes[0],
+            coord_bytes[1],
+            coord_bytes[2],
+            coord_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetTexGenivRequest {
     type Reply = GetTexGenivReply;
@@ -8280,6 +11186,12 @@ pub struct GetTexGenivReply {
     pub data: Vec<i32>,
 }
 impl_debug_if_no_extra_traits!(GetTexGenivReply, "GetTexGenivReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetTexGenivReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetTexGenivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -8322,6 +11234,7 @@ impl Serialize for GetTexGenivReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetTexGenivReply {
@@ -8345,6 +11258,7 @@ pub const GET_TEX_IMAGE_REQUEST: u8 = 135;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetTexImageRequest {
     pub context_tag: ContextTag,
     pub target: u32,
@@ -8432,6 +11346,58 @@ impl Request for GetTexImageRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let level_bytes = self.level.serialize();
+        let format_bytes = self.format.serialize();
+        let type_bytes = self.type_.serialize();
+        let swap_bytes_bytes = self.swap_bytes.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_TEX_IMAGE_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            level_bytes[0],
+            level_bytes[1],
+            level_bytes[2],
+            level_bytes[3],
+            format_bytes[0],
+            format_bytes[1],
+            format_bytes[2],
+            format_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            swap_bytes_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetTexImageRequest {
     type Reply = GetTexImageReply;
@@ -8448,6 +11414,12 @@ pub struct GetTexImageReply {
     pub data: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(GetTexImageReply, "GetTexImageReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetTexImageReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetTexImageReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -8494,6 +11466,7 @@ impl Serialize for GetTexImageReply {
         self.depth.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 4]);
         bytes.extend_from_slice(&self.data);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetTexImageReply {
@@ -8518,6 +11491,7 @@ pub const GET_TEX_PARAMETERFV_REQUEST: u8 = 136;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetTexParameterfvRequest {
     pub context_tag: ContextTag,
     pub target: u32,
@@ -8581,6 +11555,43 @@ impl Request for GetTexParameterfvRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let pname_bytes = self.pname.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_TEX_PARAMETERFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetTexParameterfvRequest {
     type Reply = GetTexParameterfvReply;
@@ -8596,6 +11607,12 @@ pub struct GetTexParameterfvReply {
     pub data: Vec<Float32>,
 }
 impl_debug_if_no_extra_traits!(GetTexParameterfvReply, "GetTexParameterfvReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetTexParameterfvReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetTexParameterfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -8638,6 +11655,7 @@ impl Serialize for GetTexParameterfvReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetTexParameterfvReply {
@@ -8661,6 +11679,7 @@ pub const GET_TEX_PARAMETERIV_REQUEST: u8 = 137;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetTexParameterivRequest {
     pub context_tag: ContextTag,
     pub target: u32,
@@ -8724,6 +11743,43 @@ impl Request for GetTexParameterivRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let pname_bytes = self.pname.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_TEX_PARAMETERIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetTexParameterivRequest {
     type Reply = GetTexParameterivReply;
@@ -8739,6 +11795,12 @@ pub struct GetTexParameterivReply {
     pub data: Vec<i32>,
 }
 impl_debug_if_no_extra_traits!(GetTexParameterivReply, "GetTexParameterivReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetTexParameterivReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetTexParameterivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -8781,6 +11843,7 @@ impl Serialize for GetTexParameterivReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetTexParameterivReply {
@@ -8804,6 +11867,7 @@ pub const GET_TEX_LEVEL_PARAMETERFV_REQUEST: u8 = 138;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetTexLevelParameterfvRequest {
     pub context_tag: ContextTag,
     pub target: u32,
@@ -8865,15 +11929,57 @@ impl GetTexLevelParameterfvRequest {
             pname,
         })
     }
-}
-impl Request for GetTexLevelParameterfvRequest {
-    const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
-
-    fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
+}
+impl Request for GetTexLevelParameterfvRequest {
+    const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+
+    fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
+        let (bufs, fds) = self.serialize(major_opcode);
+        // Flatten the buffers into a single vector
+        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
+        (buf, fds)
+    }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let level_bytes = self.level.serialize();
+        let pname_bytes = self.pname.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_TEX_LEVEL_PARAMETERFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            level_bytes[0],
+            level_bytes[1],
+            level_bytes[2],
+            level_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
         let (bufs, fds) = self.serialize(major_opcode);
-        // Flatten the buffers into a single vector
-        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
-        (buf, fds)
+        slices.extend(bufs);
+        fds
     }
 }
 impl crate::x11_utils::ReplyRequest for GetTexLevelParameterfvRequest {
@@ -8890,6 +11996,12 @@ pub struct GetTexLevelParameterfvReply {
     pub data: Vec<Float32>,
 }
 impl_debug_if_no_extra_traits!(GetTexLevelParameterfvReply, "GetTexLevelParameterfvReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetTexLevelParameterfvReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetTexLevelParameterfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -8932,6 +12044,7 @@ impl Serialize for GetTexLevelParameterfvReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetTexLevelParameterfvReply {
@@ -8955,6 +12068,7 @@ pub const GET_TEX_LEVEL_PARAMETERIV_REQUEST: u8 = 139;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetTexLevelParameterivRequest {
     pub context_tag: ContextTag,
     pub target: u32,
@@ -9026,6 +12140,48 @@ impl Request for GetTexLevelParameterivRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let level_bytes = self.level.serialize();
+        let pname_bytes = self.pname.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_TEX_LEVEL_PARAMETERIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            level_bytes[0],
+            level_bytes[1],
+            level_bytes[2],
+            level_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetTexLevelParameterivRequest {
     type Reply = GetTexLevelParameterivReply;
@@ -9041,6 +12197,12 @@ pub struct GetTexLevelParameterivReply {
     pub data: Vec<i32>,
 }
 impl_debug_if_no_extra_traits!(GetTexLevelParameterivReply, "GetTexLevelParameterivReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetTexLevelParameterivReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetTexLevelParameterivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -9083,6 +12245,7 @@ impl Serialize for GetTexLevelParameterivReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetTexLevelParameterivReply {
@@ -9106,6 +12269,7 @@ pub const IS_ENABLED_REQUEST: u8 = 140;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct IsEnabledRequest {
     pub context_tag: ContextTag,
     pub capability: u32,
@@ -9161,6 +12325,38 @@ impl Request for IsEnabledRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let capability_bytes = self.capability.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            IS_ENABLED_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            capability_bytes[0],
+            capability_bytes[1],
+            capability_bytes[2],
+            capability_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for IsEnabledRequest {
     type Reply = IsEnabledReply;
@@ -9175,6 +12371,12 @@ pub struct IsEnabledReply {
     pub ret_val: Bool32,
 }
 impl_debug_if_no_extra_traits!(IsEnabledReply, "IsEnabledReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for IsEnabledReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for IsEnabledReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -9231,6 +12433,7 @@ pub const IS_LIST_REQUEST: u8 = 141;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct IsListRequest {
     pub context_tag: ContextTag,
     pub list: u32,
@@ -9286,6 +12489,38 @@ impl Request for IsListRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let list_bytes = self.list.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            IS_LIST_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            list_bytes[0],
+            list_bytes[1],
+            list_bytes[2],
+            list_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for IsListRequest {
     type Reply = IsListReply;
@@ -9300,6 +12535,12 @@ pub struct IsListReply {
     pub ret_val: Bool32,
 }
 impl_debug_if_no_extra_traits!(IsListReply, "IsListReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for IsListReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for IsListReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -9356,6 +12597,7 @@ pub const FLUSH_REQUEST: u8 = 142;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct FlushRequest {
     pub context_tag: ContextTag,
 }
@@ -9403,6 +12645,33 @@ impl Request for FlushRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            FLUSH_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for FlushRequest {
 }
@@ -9412,6 +12681,7 @@ pub const ARE_TEXTURES_RESIDENT_REQUEST: u8 = 143;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct AreTexturesResidentRequest<'input> {
     pub context_tag: ContextTag,
     pub textures: Cow<'input, [u32]>,
@@ -9480,6 +12750,42 @@ impl<'input> Request for AreTexturesResidentRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let n = i32::try_from(self.textures.len()).expect("`textures` has too many elements");
+        let n_bytes = n.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            ARE_TEXTURES_RESIDENT_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            n_bytes[0],
+            n_bytes[1],
+            n_bytes[2],
+            n_bytes[3],
+        ]);
+        self.textures.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::ReplyRequest for AreTexturesResidentRequest<'input> {
     type Reply = AreTexturesResidentReply;
@@ -9494,6 +12800,12 @@ pub struct AreTexturesResidentReply {
     pub data: Vec<bool>,
 }
 impl_debug_if_no_extra_traits!(AreTexturesResidentReply, "AreTexturesResidentReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for AreTexturesResidentReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for AreTexturesResidentReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -9533,6 +12845,7 @@ impl Serialize for AreTexturesResidentReply {
         self.ret_val.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 20]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl AreTexturesResidentReply {
@@ -9557,6 +12870,7 @@ pub const DELETE_TEXTURES_REQUEST: u8 = 144;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DeleteTexturesRequest<'input> {
     pub context_tag: ContextTag,
     pub textures: Cow<'input, [u32]>,
@@ -9625,6 +12939,42 @@ impl<'input> Request for DeleteTexturesRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let n = i32::try_from(self.textures.len()).expect("`textures` has too many elements");
+        let n_bytes = n.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            DELETE_TEXTURES_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            n_bytes[0],
+            n_bytes[1],
+            n_bytes[2],
+            n_bytes[3],
+        ]);
+        self.textures.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for DeleteTexturesRequest<'input> {
 }
@@ -9634,6 +12984,7 @@ pub const GEN_TEXTURES_REQUEST: u8 = 145;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GenTexturesRequest {
     pub context_tag: ContextTag,
     pub n: i32,
@@ -9689,6 +13040,38 @@ impl Request for GenTexturesRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let n_bytes = self.n.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GEN_TEXTURES_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            n_bytes[0],
+            n_bytes[1],
+            n_bytes[2],
+            n_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GenTexturesRequest {
     type Reply = GenTexturesReply;
@@ -9702,6 +13085,12 @@ pub struct GenTexturesReply {
     pub data: Vec<u32>,
 }
 impl_debug_if_no_extra_traits!(GenTexturesReply, "GenTexturesReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GenTexturesReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GenTexturesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -9738,6 +13127,7 @@ impl Serialize for GenTexturesReply {
         length.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 24]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GenTexturesReply {
@@ -9761,6 +13151,7 @@ pub const IS_TEXTURE_REQUEST: u8 = 146;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct IsTextureRequest {
     pub context_tag: ContextTag,
     pub texture: u32,
@@ -9816,6 +13207,38 @@ impl Request for IsTextureRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let texture_bytes = self.texture.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            IS_TEXTURE_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            texture_bytes[0],
+            texture_bytes[1],
+            texture_bytes[2],
+            texture_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for IsTextureRequest {
     type Reply = IsTextureReply;
@@ -9830,6 +13253,12 @@ pub struct IsTextureReply {
     pub ret_val: Bool32,
 }
 impl_debug_if_no_extra_traits!(IsTextureReply, "IsTextureReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for IsTextureReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for IsTextureReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -9886,6 +13315,7 @@ pub const GET_COLOR_TABLE_REQUEST: u8 = 147;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetColorTableRequest {
     pub context_tag: ContextTag,
     pub target: u32,
@@ -9965,6 +13395,53 @@ impl Request for GetColorTableRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let format_bytes = self.format.serialize();
+        let type_bytes = self.type_.serialize();
+        let swap_bytes_bytes = self.swap_bytes.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_COLOR_TABLE_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            format_bytes[0],
+            format_bytes[1],
+            format_bytes[2],
+            format_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            swap_bytes_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetColorTableRequest {
     type Reply = GetColorTableReply;
@@ -9979,6 +13456,12 @@ pub struct GetColorTableReply {
     pub data: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(GetColorTableReply, "GetColorTableReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetColorTableReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetColorTableReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -10021,6 +13504,7 @@ impl Serialize for GetColorTableReply {
         self.width.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         bytes.extend_from_slice(&self.data);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetColorTableReply {
@@ -10045,6 +13529,7 @@ pub const GET_COLOR_TABLE_PARAMETERFV_REQUEST: u8 = 148;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetColorTableParameterfvRequest {
     pub context_tag: ContextTag,
     pub target: u32,
@@ -10098,15 +13583,52 @@ impl GetColorTableParameterfvRequest {
             pname,
         })
     }
-}
-impl Request for GetColorTableParameterfvRequest {
-    const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
-
-    fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
+}
+impl Request for GetColorTableParameterfvRequest {
+    const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+
+    fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
+        let (bufs, fds) = self.serialize(major_opcode);
+        // Flatten the buffers into a single vector
+        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
+        (buf, fds)
+    }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let pname_bytes = self.pname.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_COLOR_TABLE_PARAMETERFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
         let (bufs, fds) = self.serialize(major_opcode);
-        // Flatten the buffers into a single vector
-        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
-        (buf, fds)
+        slices.extend(bufs);
+        fds
     }
 }
 impl crate::x11_utils::ReplyRequest for GetColorTableParameterfvRequest {
@@ -10123,6 +13645,12 @@ pub struct GetColorTableParameterfvReply {
     pub data: Vec<Float32>,
 }
 impl_debug_if_no_extra_traits!(GetColorTableParameterfvReply, "GetColorTableParameterfvReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetColorTableParameterfvReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetColorTableParameterfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -10165,6 +13693,7 @@ impl Serialize for GetColorTableParameterfvReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetColorTableParameterfvReply {
@@ -10188,6 +13717,7 @@ pub const GET_COLOR_TABLE_PARAMETERIV_REQUEST: u8 = 149;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetColorTableParameterivRequest {
     pub context_tag: ContextTag,
     pub target: u32,
@@ -10251,6 +13781,43 @@ impl Request for GetColorTableParameterivRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let pname_bytes = self.pname.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_COLOR_TABLE_PARAMETERIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetColorTableParameterivRequest {
     type Reply = GetColorTableParameterivReply;
@@ -10266,6 +13833,12 @@ pub struct GetColorTableParameterivReply {
     pub data: Vec<i32>,
 }
 impl_debug_if_no_extra_traits!(GetColorTableParameterivReply, "GetColorTableParameterivReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetColorTableParameterivReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetColorTableParameterivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -10308,6 +13881,7 @@ impl Serialize for GetColorTableParameterivReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetColorTableParameterivReply {
@@ -10331,6 +13905,7 @@ pub const GET_CONVOLUTION_FILTER_REQUEST: u8 = 150;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetConvolutionFilterRequest {
     pub context_tag: ContextTag,
     pub target: u32,
@@ -10410,6 +13985,53 @@ impl Request for GetConvolutionFilterRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let format_bytes = self.format.serialize();
+        let type_bytes = self.type_.serialize();
+        let swap_bytes_bytes = self.swap_bytes.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_CONVOLUTION_FILTER_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            format_bytes[0],
+            format_bytes[1],
+            format_bytes[2],
+            format_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            swap_bytes_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetConvolutionFilterRequest {
     type Reply = GetConvolutionFilterReply;
@@ -10425,6 +14047,12 @@ pub struct GetConvolutionFilterReply {
     pub data: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(GetConvolutionFilterReply, "GetConvolutionFilterReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetConvolutionFilterReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetConvolutionFilterReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -10469,6 +14097,7 @@ impl Serialize for GetConvolutionFilterReply {
         self.height.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 8]);
         bytes.extend_from_slice(&self.data);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetConvolutionFilterReply {
@@ -10493,6 +14122,7 @@ pub const GET_CONVOLUTION_PARAMETERFV_REQUEST: u8 = 151;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetConvolutionParameterfvRequest {
     pub context_tag: ContextTag,
     pub target: u32,
@@ -10556,6 +14186,43 @@ impl Request for GetConvolutionParameterfvRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let pname_bytes = self.pname.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_CONVOLUTION_PARAMETERFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetConvolutionParameterfvRequest {
     type Reply = GetConvolutionParameterfvReply;
@@ -10571,6 +14238,12 @@ pub struct GetConvolutionParameterfvReply {
     pub data: Vec<Float32>,
 }
 impl_debug_if_no_extra_traits!(GetConvolutionParameterfvReply, "GetConvolutionParameterfvReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetConvolutionParameterfvReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetConvolutionParameterfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -10613,6 +14286,7 @@ impl Serialize for GetConvolutionParameterfvReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetConvolutionParameterfvReply {
@@ -10636,6 +14310,7 @@ pub const GET_CONVOLUTION_PARAMETERIV_REQUEST: u8 = 152;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetConvolutionParameterivRequest {
     pub context_tag: ContextTag,
     pub target: u32,
@@ -10699,6 +14374,43 @@ impl Request for GetConvolutionParameterivRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let pname_bytes = self.pname.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_CONVOLUTION_PARAMETERIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetConvolutionParameterivRequest {
     type Reply = GetConvolutionParameterivReply;
@@ -10714,6 +14426,12 @@ pub struct GetConvolutionParameterivReply {
     pub data: Vec<i32>,
 }
 impl_debug_if_no_extra_traits!(GetConvolutionParameterivReply, "GetConvolutionParameterivReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetConvolutionParameterivReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetConvolutionParameterivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -10756,6 +14474,7 @@ impl Serialize for GetConvolutionParameterivReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetConvolutionParameterivReply {
@@ -10779,6 +14498,7 @@ pub const GET_SEPARABLE_FILTER_REQUEST: u8 = 153;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetSeparableFilterRequest {
     pub context_tag: ContextTag,
     pub target: u32,
@@ -10858,6 +14578,53 @@ impl Request for GetSeparableFilterRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let format_bytes = self.format.serialize();
+        let type_bytes = self.type_.serialize();
+        let swap_bytes_bytes = self.swap_bytes.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_SEPARABLE_FILTER_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            format_bytes[0],
+            format_bytes[1],
+            format_bytes[2],
+            format_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            swap_bytes_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetSeparableFilterRequest {
     type Reply = GetSeparableFilterReply;
@@ -10873,6 +14640,12 @@ pub struct GetSeparableFilterReply {
     pub rows_and_cols: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(GetSeparableFilterReply, "GetSeparableFilterReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetSeparableFilterReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetSeparableFilterReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -10917,6 +14690,7 @@ impl Serialize for GetSeparableFilterReply {
         self.col_h.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 8]);
         bytes.extend_from_slice(&self.rows_and_cols);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetSeparableFilterReply {
@@ -10941,6 +14715,7 @@ pub const GET_HISTOGRAM_REQUEST: u8 = 154;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetHistogramRequest {
     pub context_tag: ContextTag,
     pub target: u32,
@@ -11024,6 +14799,54 @@ impl Request for GetHistogramRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let format_bytes = self.format.serialize();
+        let type_bytes = self.type_.serialize();
+        let swap_bytes_bytes = self.swap_bytes.serialize();
+        let reset_bytes = self.reset.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_HISTOGRAM_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            format_bytes[0],
+            format_bytes[1],
+            format_bytes[2],
+            format_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            swap_bytes_bytes[0],
+            reset_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetHistogramRequest {
     type Reply = GetHistogramReply;
@@ -11038,6 +14861,12 @@ pub struct GetHistogramReply {
     pub data: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(GetHistogramReply, "GetHistogramReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetHistogramReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetHistogramReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -11080,6 +14909,7 @@ impl Serialize for GetHistogramReply {
         self.width.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         bytes.extend_from_slice(&self.data);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetHistogramReply {
@@ -11104,6 +14934,7 @@ pub const GET_HISTOGRAM_PARAMETERFV_REQUEST: u8 = 155;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetHistogramParameterfvRequest {
     pub context_tag: ContextTag,
     pub target: u32,
@@ -11167,6 +14998,43 @@ impl Request for GetHistogramParameterfvRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let pname_bytes = self.pname.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_HISTOGRAM_PARAMETERFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetHistogramParameterfvRequest {
     type Reply = GetHistogramParameterfvReply;
@@ -11182,6 +15050,12 @@ pub struct GetHistogramParameterfvReply {
     pub data: Vec<Float32>,
 }
 impl_debug_if_no_extra_traits!(GetHistogramParameterfvReply, "GetHistogramParameterfvReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetHistogramParameterfvReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetHistogramParameterfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -11224,6 +15098,7 @@ impl Serialize for GetHistogramParameterfvReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetHistogramParameterfvReply {
@@ -11247,6 +15122,7 @@ pub const GET_HISTOGRAM_PARAMETERIV_REQUEST: u8 = 156;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetHistogramParameterivRequest {
     pub context_tag: ContextTag,
     pub target: u32,
@@ -11310,6 +15186,43 @@ impl Request for GetHistogramParameterivRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let pname_bytes = self.pname.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_HISTOGRAM_PARAMETERIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetHistogramParameterivRequest {
     type Reply = GetHistogramParameterivReply;
@@ -11325,6 +15238,12 @@ pub struct GetHistogramParameterivReply {
     pub data: Vec<i32>,
 }
 impl_debug_if_no_extra_traits!(GetHistogramParameterivReply, "GetHistogramParameterivReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetHistogramParameterivReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetHistogramParameterivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -11367,6 +15286,7 @@ impl Serialize for GetHistogramParameterivReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetHistogramParameterivReply {
@@ -11390,6 +15310,7 @@ pub const GET_MINMAX_REQUEST: u8 = 157;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetMinmaxRequest {
     pub context_tag: ContextTag,
     pub target: u32,
@@ -11473,6 +15394,54 @@ impl Request for GetMinmaxRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let format_bytes = self.format.serialize();
+        let type_bytes = self.type_.serialize();
+        let swap_bytes_bytes = self.swap_bytes.serialize();
+        let reset_bytes = self.reset.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_MINMAX_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            format_bytes[0],
+            format_bytes[1],
+            format_bytes[2],
+            format_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            swap_bytes_bytes[0],
+            reset_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetMinmaxRequest {
     type Reply = GetMinmaxReply;
@@ -11486,6 +15455,12 @@ pub struct GetMinmaxReply {
     pub data: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(GetMinmaxReply, "GetMinmaxReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetMinmaxReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetMinmaxReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -11524,6 +15499,7 @@ impl Serialize for GetMinmaxReply {
         length.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 24]);
         bytes.extend_from_slice(&self.data);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetMinmaxReply {
@@ -11548,6 +15524,7 @@ pub const GET_MINMAX_PARAMETERFV_REQUEST: u8 = 158;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetMinmaxParameterfvRequest {
     pub context_tag: ContextTag,
     pub target: u32,
@@ -11611,6 +15588,43 @@ impl Request for GetMinmaxParameterfvRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let pname_bytes = self.pname.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_MINMAX_PARAMETERFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetMinmaxParameterfvRequest {
     type Reply = GetMinmaxParameterfvReply;
@@ -11626,6 +15640,12 @@ pub struct GetMinmaxParameterfvReply {
     pub data: Vec<Float32>,
 }
 impl_debug_if_no_extra_traits!(GetMinmaxParameterfvReply, "GetMinmaxParameterfvReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetMinmaxParameterfvReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetMinmaxParameterfvReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -11668,6 +15688,7 @@ impl Serialize for GetMinmaxParameterfvReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetMinmaxParameterfvReply {
@@ -11691,6 +15712,7 @@ pub const GET_MINMAX_PARAMETERIV_REQUEST: u8 = 159;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetMinmaxParameterivRequest {
     pub context_tag: ContextTag,
     pub target: u32,
@@ -11754,6 +15776,43 @@ impl Request for GetMinmaxParameterivRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let pname_bytes = self.pname.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_MINMAX_PARAMETERIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetMinmaxParameterivRequest {
     type Reply = GetMinmaxParameterivReply;
@@ -11769,6 +15828,12 @@ pub struct GetMinmaxParameterivReply {
     pub data: Vec<i32>,
 }
 impl_debug_if_no_extra_traits!(GetMinmaxParameterivReply, "GetMinmaxParameterivReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetMinmaxParameterivReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetMinmaxParameterivReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -11811,6 +15876,7 @@ impl Serialize for GetMinmaxParameterivReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetMinmaxParameterivReply {
@@ -11834,6 +15900,7 @@ pub const GET_COMPRESSED_TEX_IMAGE_ARB_REQUEST: u8 = 160;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetCompressedTexImageARBRequest {
     pub context_tag: ContextTag,
     pub target: u32,
@@ -11897,6 +15964,43 @@ impl Request for GetCompressedTexImageARBRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let level_bytes = self.level.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_COMPRESSED_TEX_IMAGE_ARB_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            level_bytes[0],
+            level_bytes[1],
+            level_bytes[2],
+            level_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetCompressedTexImageARBRequest {
     type Reply = GetCompressedTexImageARBReply;
@@ -11911,6 +16015,12 @@ pub struct GetCompressedTexImageARBReply {
     pub data: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(GetCompressedTexImageARBReply, "GetCompressedTexImageARBReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetCompressedTexImageARBReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetCompressedTexImageARBReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -11953,6 +16063,7 @@ impl Serialize for GetCompressedTexImageARBReply {
         self.size.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         bytes.extend_from_slice(&self.data);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetCompressedTexImageARBReply {
@@ -11977,6 +16088,7 @@ pub const DELETE_QUERIES_ARB_REQUEST: u8 = 161;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DeleteQueriesARBRequest<'input> {
     pub context_tag: ContextTag,
     pub ids: Cow<'input, [u32]>,
@@ -12045,6 +16157,42 @@ impl<'input> Request for DeleteQueriesARBRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let n = i32::try_from(self.ids.len()).expect("`ids` has too many elements");
+        let n_bytes = n.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            DELETE_QUERIES_ARB_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            n_bytes[0],
+            n_bytes[1],
+            n_bytes[2],
+            n_bytes[3],
+        ]);
+        self.ids.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for DeleteQueriesARBRequest<'input> {
 }
@@ -12054,6 +16202,7 @@ pub const GEN_QUERIES_ARB_REQUEST: u8 = 162;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GenQueriesARBRequest {
     pub context_tag: ContextTag,
     pub n: i32,
@@ -12109,6 +16258,38 @@ impl Request for GenQueriesARBRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let n_bytes = self.n.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GEN_QUERIES_ARB_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            n_bytes[0],
+            n_bytes[1],
+            n_bytes[2],
+            n_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GenQueriesARBRequest {
     type Reply = GenQueriesARBReply;
@@ -12122,6 +16303,12 @@ pub struct GenQueriesARBReply {
     pub data: Vec<u32>,
 }
 impl_debug_if_no_extra_traits!(GenQueriesARBReply, "GenQueriesARBReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GenQueriesARBReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GenQueriesARBReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -12158,6 +16345,7 @@ impl Serialize for GenQueriesARBReply {
         length.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 24]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GenQueriesARBReply {
@@ -12181,6 +16369,7 @@ pub const IS_QUERY_ARB_REQUEST: u8 = 163;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct IsQueryARBRequest {
     pub context_tag: ContextTag,
     pub id: u32,
@@ -12236,6 +16425,38 @@ impl Request for IsQueryARBRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let id_bytes = self.id.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            IS_QUERY_ARB_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for IsQueryARBRequest {
     type Reply = IsQueryARBReply;
@@ -12250,6 +16471,12 @@ pub struct IsQueryARBReply {
     pub ret_val: Bool32,
 }
 impl_debug_if_no_extra_traits!(IsQueryARBReply, "IsQueryARBReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for IsQueryARBReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for IsQueryARBReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -12306,6 +16533,7 @@ pub const GET_QUERYIV_ARB_REQUEST: u8 = 164;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetQueryivARBRequest {
     pub context_tag: ContextTag,
     pub target: u32,
@@ -12369,6 +16597,43 @@ impl Request for GetQueryivARBRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let target_bytes = self.target.serialize();
+        let pname_bytes = self.pname.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_QUERYIV_ARB_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetQueryivARBRequest {
     type Reply = GetQueryivARBReply;
@@ -12384,6 +16649,12 @@ pub struct GetQueryivARBReply {
     pub data: Vec<i32>,
 }
 impl_debug_if_no_extra_traits!(GetQueryivARBReply, "GetQueryivARBReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetQueryivARBReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetQueryivARBReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -12426,6 +16697,7 @@ impl Serialize for GetQueryivARBReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetQueryivARBReply {
@@ -12449,6 +16721,7 @@ pub const GET_QUERY_OBJECTIV_ARB_REQUEST: u8 = 165;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetQueryObjectivARBRequest {
     pub context_tag: ContextTag,
     pub id: u32,
@@ -12512,6 +16785,43 @@ impl Request for GetQueryObjectivARBRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let id_bytes = self.id.serialize();
+        let pname_bytes = self.pname.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_QUERY_OBJECTIV_ARB_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetQueryObjectivARBRequest {
     type Reply = GetQueryObjectivARBReply;
@@ -12527,6 +16837,12 @@ pub struct GetQueryObjectivARBReply {
     pub data: Vec<i32>,
 }
 impl_debug_if_no_extra_traits!(GetQueryObjectivARBReply, "GetQueryObjectivARBReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetQueryObjectivARBReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetQueryObjectivARBReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -12569,6 +16885,7 @@ impl Serialize for GetQueryObjectivARBReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetQueryObjectivARBReply {
@@ -12592,6 +16909,7 @@ pub const GET_QUERY_OBJECTUIV_ARB_REQUEST: u8 = 166;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetQueryObjectuivARBRequest {
     pub context_tag: ContextTag,
     pub id: u32,
@@ -12655,6 +16973,43 @@ impl Request for GetQueryObjectuivARBRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let context_tag_bytes = self.context_tag.serialize();
+        let id_bytes = self.id.serialize();
+        let pname_bytes = self.pname.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_QUERY_OBJECTUIV_ARB_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetQueryObjectuivARBRequest {
     type Reply = GetQueryObjectuivARBReply;
@@ -12670,6 +17025,12 @@ pub struct GetQueryObjectuivARBReply {
     pub data: Vec<u32>,
 }
 impl_debug_if_no_extra_traits!(GetQueryObjectuivARBReply, "GetQueryObjectuivARBReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetQueryObjectuivARBReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetQueryObjectuivARBReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -12712,6 +17073,7 @@ impl Serialize for GetQueryObjectuivARBReply {
         self.datum.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.data.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetQueryObjectuivARBReply {
@@ -12730,3 +17092,108 @@ impl GetQueryObjectuivARBReply {
     }
 }
 
+/// All requests of this module.
+pub const REQUESTS: &[RequestDescription] = &[
+    RequestDescription { opcode: RENDER_REQUEST, name: "Render", has_reply: false },
+    RequestDescription { opcode: RENDER_LARGE_REQUEST, name: "RenderLarge", has_reply: false },
+    RequestDescription { opcode: CREATE_CONTEXT_REQUEST, name: "CreateContext", has_reply: false },
+    RequestDescription { opcode: DESTROY_CONTEXT_REQUEST, name: "DestroyContext", has_reply: false },
+    RequestDescription { opcode: MAKE_CURRENT_REQUEST, name: "MakeCurrent", has_reply: true },
+    RequestDescription { opcode: IS_DIRECT_REQUEST, name: "IsDirect", has_reply: true },
+    RequestDescription { opcode: QUERY_VERSION_REQUEST, name: "QueryVersion", has_reply: true },
+    RequestDescription { opcode: WAIT_GL_REQUEST, name: "WaitGL", has_reply: false },
+    RequestDescription { opcode: WAIT_X_REQUEST, name: "WaitX", has_reply: false },
+    RequestDescription { opcode: COPY_CONTEXT_REQUEST, name: "CopyContext", has_reply: false },
+    RequestDescription { opcode: SWAP_BUFFERS_REQUEST, name: "SwapBuffers", has_reply: false },
+    RequestDescription { opcode: USE_X_FONT_REQUEST, name: "UseXFont", has_reply: false },
+    RequestDescription { opcode: CREATE_GLX_PIXMAP_REQUEST, name: "CreateGLXPixmap", has_reply: false },
+    RequestDescription { opcode: GET_VISUAL_CONFIGS_REQUEST, name: "GetVisualConfigs", has_reply: true },
+    RequestDescription { opcode: DESTROY_GLX_PIXMAP_REQUEST, name: "DestroyGLXPixmap", has_reply: false },
+    RequestDescription { opcode: VENDOR_PRIVATE_REQUEST, name: "VendorPrivate", has_reply: false },
+    RequestDescription { opcode: VENDOR_PRIVATE_WITH_REPLY_REQUEST, name: "VendorPrivateWithReply", has_reply: true },
+    RequestDescription { opcode: QUERY_EXTENSIONS_STRING_REQUEST, name: "QueryExtensionsString", has_reply: true },
+    RequestDescription { opcode: QUERY_SERVER_STRING_REQUEST, name: "QueryServerString", has_reply: true },
+    RequestDescription { opcode: CLIENT_INFO_REQUEST, name: "ClientInfo", has_reply: false },
+    RequestDescription { opcode: GET_FB_CONFIGS_REQUEST, name: "GetFBConfigs", has_reply: true },
+    RequestDescription { opcode: CREATE_PIXMAP_REQUEST, name: "CreatePixmap", has_reply: false },
+    RequestDescription { opcode: DESTROY_PIXMAP_REQUEST, name: "DestroyPixmap", has_reply: false },
+    RequestDescription { opcode: CREATE_NEW_CONTEXT_REQUEST, name: "CreateNewContext", has_reply: false },
+    RequestDescription { opcode: QUERY_CONTEXT_REQUEST, name: "QueryContext", has_reply: true },
+    RequestDescription { opcode: MAKE_CONTEXT_CURRENT_REQUEST, name: "MakeContextCurrent", has_reply: true },
+    RequestDescription { opcode: CREATE_PBUFFER_REQUEST, name: "CreatePbuffer", has_reply: false },
+    RequestDescription { opcode: DESTROY_PBUFFER_REQUEST, name: "DestroyPbuffer", has_reply: false },
+    RequestDescription { opcode: GET_DRAWABLE_ATTRIBUTES_REQUEST, name: "GetDrawableAttributes", has_reply: true },
+    RequestDescription { opcode: CHANGE_DRAWABLE_ATTRIBUTES_REQUEST, name: "ChangeDrawableAttributes", has_reply: false },
+    RequestDescription { opcode: CREATE_WINDOW_REQUEST, name: "CreateWindow", has_reply: false },
+    RequestDescription { opcode: DELETE_WINDOW_REQUEST, name: "DeleteWindow", has_reply: false },
+    RequestDescription { opcode: SET_CLIENT_INFO_ARB_REQUEST, name: "SetClientInfoARB", has_reply: false },
+    RequestDescription { opcode: CREATE_CONTEXT_ATTRIBS_ARB_REQUEST, name: "CreateContextAttribsARB", has_reply: false },
+    RequestDescription { opcode: SET_CLIENT_INFO2_ARB_REQUEST, name: "SetClientInfo2ARB", has_reply: false },
+    RequestDescription { opcode: NEW_LIST_REQUEST, name: "NewList", has_reply: false },
+    RequestDescription { opcode: END_LIST_REQUEST, name: "EndList", has_reply: false },
+    RequestDescription { opcode: DELETE_LISTS_REQUEST, name: "DeleteLists", has_reply: false },
+    RequestDescription { opcode: GEN_LISTS_REQUEST, name: "GenLists", has_reply: true },
+    RequestDescription { opcode: FEEDBACK_BUFFER_REQUEST, name: "FeedbackBuffer", has_reply: false },
+    RequestDescription { opcode: SELECT_BUFFER_REQUEST, name: "SelectBuffer", has_reply: false },
+    RequestDescription { opcode: RENDER_MODE_REQUEST, name: "RenderMode", has_reply: true },
+    RequestDescription { opcode: FINISH_REQUEST, name: "Finish", has_reply: true },
+    RequestDescription { opcode: PIXEL_STOREF_REQUEST, name: "PixelStoref", has_reply: false },
+    RequestDescription { opcode: PIXEL_STOREI_REQUEST, name: "PixelStorei", has_reply: false },
+    RequestDescription { opcode: READ_PIXELS_REQUEST, name: "ReadPixels", has_reply: true },
+    RequestDescription { opcode: GET_BOOLEANV_REQUEST, name: "GetBooleanv", has_reply: true },
+    RequestDescription { opcode: GET_CLIP_PLANE_REQUEST, name: "GetClipPlane", has_reply: true },
+    RequestDescription { opcode: GET_DOUBLEV_REQUEST, name: "GetDoublev", has_reply: true },
+    RequestDescription { opcode: GET_ERROR_REQUEST, name: "GetError", has_reply: true },
+    RequestDescription { opcode: GET_FLOATV_REQUEST, name: "GetFloatv", has_reply: true },
+    RequestDescription { opcode: GET_INTEGERV_REQUEST, name: "GetIntegerv", has_reply: true },
+    RequestDescription { opcode: GET_LIGHTFV_REQUEST, name: "GetLightfv", has_reply: true },
+    RequestDescription { opcode: GET_LIGHTIV_REQUEST, name: "GetLightiv", has_reply: true },
+    RequestDescription { opcode: GET_MAPDV_REQUEST, name: "GetMapdv", has_reply: true },
+    RequestDescription { opcode: GET_MAPFV_REQUEST, name: "GetMapfv", has_reply: true },
+    RequestDescription { opcode: GET_MAPIV_REQUEST, name: "GetMapiv", has_reply: true },
+    RequestDescription { opcode: GET_MATERIALFV_REQUEST, name: "GetMaterialfv", has_reply: true },
+    RequestDescription { opcode: GET_MATERIALIV_REQUEST, name: "GetMaterialiv", has_reply: true },
+    RequestDescription { opcode: GET_PIXEL_MAPFV_REQUEST, name: "GetPixelMapfv", has_reply: true },
+    RequestDescription { opcode: GET_PIXEL_MAPUIV_REQUEST, name: "GetPixelMapuiv", has_reply: true },
+    RequestDescription { opcode: GET_PIXEL_MAPUSV_REQUEST, name: "GetPixelMapusv", has_reply: true },
+    RequestDescription { opcode: GET_POLYGON_STIPPLE_REQUEST, name: "GetPolygonStipple", has_reply: true },
+    RequestDescription { opcode: GET_STRING_REQUEST, name: "GetString", has_reply: true },
+    RequestDescription { opcode: GET_TEX_ENVFV_REQUEST, name: "GetTexEnvfv", has_reply: true },
+    RequestDescription { opcode: GET_TEX_ENVIV_REQUEST, name: "GetTexEnviv", has_reply: true },
+    RequestDescription { opcode: GET_TEX_GENDV_REQUEST, name: "GetTexGendv", has_reply: true },
+    RequestDescription { opcode: GET_TEX_GENFV_REQUEST, name: "GetTexGenfv", has_reply: true },
+    RequestDescription { opcode: GET_TEX_GENIV_REQUEST, name: "GetTexGeniv", has_reply: true },
+    RequestDescription { opcode: GET_TEX_IMAGE_REQUEST, name: "GetTexImage", has_reply: true },
+    RequestDescription { opcode: GET_TEX_PARAMETERFV_REQUEST, name: "GetTexParameterfv", has_reply: true },
+    RequestDescription { opcode: GET_TEX_PARAMETERIV_REQUEST, name: "GetTexParameteriv", has_reply: true },
+    RequestDescription { opcode: GET_TEX_LEVEL_PARAMETERFV_REQUEST, name: "GetTexLevelParameterfv", has_reply: true },
+    RequestDescription { opcode: GET_TEX_LEVEL_PARAMETERIV_REQUEST, name: "GetTexLevelParameteriv", has_reply: true },
+    RequestDescription { opcode: IS_ENABLED_REQUEST, name: "IsEnabled", has_reply: true },
+    RequestDescription { opcode: IS_LIST_REQUEST, name: "IsList", has_reply: true },
+    RequestDescription { opcode: FLUSH_REQUEST, name: "Flush", has_reply: false },
+    RequestDescription { opcode: ARE_TEXTURES_RESIDENT_REQUEST, name: "AreTexturesResident", has_reply: true },
+    RequestDescription { opcode: DELETE_TEXTURES_REQUEST, name: "DeleteTextures", has_reply: false },
+    RequestDescription { opcode: GEN_TEXTURES_REQUEST, name: "GenTextures", has_reply: true },
+    RequestDescription { opcode: IS_TEXTURE_REQUEST, name: "IsTexture", has_reply: true },
+    RequestDescription { opcode: GET_COLOR_TABLE_REQUEST, name: "GetColorTable", has_reply: true },
+    RequestDescription { opcode: GET_COLOR_TABLE_PARAMETERFV_REQUEST, name: "GetColorTableParameterfv", has_reply: true },
+    RequestDescription { opcode: GET_COLOR_TABLE_PARAMETERIV_REQUEST, name: "GetColorTableParameteriv", has_reply: true },
+    RequestDescription { opcode: GET_CONVOLUTION_FILTER_REQUEST, name: "GetConvolutionFilter", has_reply: true },
+    RequestDescription { opcode: GET_CONVOLUTION_PARAMETERFV_REQUEST, name: "GetConvolutionParameterfv", has_reply: true },
+    RequestDescription { opcode: GET_CONVOLUTION_PARAMETERIV_REQUEST, name: "GetConvolutionParameteriv", has_reply: true },
+    RequestDescription { opcode: GET_SEPARABLE_FILTER_REQUEST, name: "GetSeparableFilter", has_reply: true },
+    RequestDescription { opcode: GET_HISTOGRAM_REQUEST, name: "GetHistogram", has_reply: true },
+    RequestDescription { opcode: GET_HISTOGRAM_PARAMETERFV_REQUEST, name: "GetHistogramParameterfv", has_reply: true },
+    RequestDescription { opcode: GET_HISTOGRAM_PARAMETERIV_REQUEST, name: "GetHistogramParameteriv", has_reply: true },
+    RequestDescription { opcode: GET_MINMAX_REQUEST, name: "GetMinmax", has_reply: true },
+    RequestDescription { opcode: GET_MINMAX_PARAMETERFV_REQUEST, name: "GetMinmaxParameterfv", has_reply: true },
+    RequestDescription { opcode: GET_MINMAX_PARAMETERIV_REQUEST, name: "GetMinmaxParameteriv", has_reply: true },
+    RequestDescription { opcode: GET_COMPRESSED_TEX_IMAGE_ARB_REQUEST, name: "GetCompressedTexImageARB", has_reply: true },
+    RequestDescription { opcode: DELETE_QUERIES_ARB_REQUEST, name: "DeleteQueriesARB", has_reply: false },
+    RequestDescription { opcode: GEN_QUERIES_ARB_REQUEST, name: "GenQueriesARB", has_reply: true },
+    RequestDescription { opcode: IS_QUERY_ARB_REQUEST, name: "IsQueryARB", has_reply: true },
+    RequestDescription { opcode: GET_QUERYIV_ARB_REQUEST, name: "GetQueryivARB", has_reply: true },
+    RequestDescription { opcode: GET_QUERY_OBJECTIV_ARB_REQUEST, name: "GetQueryObjectivARB", has_reply: true },
+    RequestDescription { opcode: GET_QUERY_OBJECTUIV_ARB_REQUEST, name: "GetQueryObjectuivARB", has_reply: true },
+];
+
@@ -21,8 +21,8 @@ use alloc::vec::Vec;
 use core::convert::TryInto;
 use crate::errors::ParseError;
 use crate::RawFdContainer;
-use crate::x11_utils::{TryParse, TryParseFd, X11Error, ReplyRequest, ReplyFDsRequest};
-use crate::x11_utils::{ExtInfoProvider, ReplyParsingFunction, RequestHeader};
+use crate::x11_utils::{Serialize, TryParse, TryParseFd, X11Error, ReplyRequest, ReplyFDsRequest};
+use crate::x11_utils::{ExtInfoProvider, ExtensionInformation, ReplyParsingFunction, RequestDescription, RequestHeader};
 
 fn parse_reply<'a, R: ReplyRequest>(bytes: &'a [u8], _: &mut Vec<RawFdContainer>) -> Result<(Reply, &'a [u8]), ParseError> {
     let (reply, remaining) = R::Reply::try_parse(bytes)?;
@@ -8049,6 +8049,622 @@ impl From<xvmc::ListSubpictureTypesReply> for Reply {
   }
 }
 
+/// Get the reply parser for a request from the name of its extension and its opcode.
+///
+/// `extension` and `opcode` are interpreted as in [`request_name_by_opcode`].
+/// Returns `None` if the request is not known or does not have a reply.
+pub fn reply_parser_by_opcode(extension: Option<&str>, opcode: u8) -> Option<ReplyParsingFunction> {
+    match extension {
+        None => {
+            match opcode {
+                xproto::GET_WINDOW_ATTRIBUTES_REQUEST => Some(parse_reply::<xproto::GetWindowAttributesRequest>),
+                xproto::GET_GEOMETRY_REQUEST => Some(parse_reply::<xproto::GetGeometryRequest>),
+                xproto::QUERY_TREE_REQUEST => Some(parse_reply::<xproto::QueryTreeRequest>),
+                xproto::INTERN_ATOM_REQUEST => Some(parse_reply::<xproto::InternAtomRequest<'_>>),
+                xproto::GET_ATOM_NAME_REQUEST => Some(parse_reply::<xproto::GetAtomNameRequest>),
+                xproto::GET_PROPERTY_REQUEST => Some(parse_reply::<xproto::GetPropertyRequest>),
+                xproto::LIST_PROPERTIES_REQUEST => Some(parse_reply::<xproto::ListPropertiesRequest>),
+                xproto::GET_SELECTION_OWNER_REQUEST => Some(parse_reply::<xproto::GetSelectionOwnerRequest>),
+                xproto::GRAB_POINTER_REQUEST => Some(parse_reply::<xproto::GrabPointerRequest>),
+                xproto::GRAB_KEYBOARD_REQUEST => Some(parse_reply::<xproto::GrabKeyboardRequest>),
+                xproto::QUERY_POINTER_REQUEST => Some(parse_reply::<xproto::QueryPointerRequest>),
+                xproto::GET_MOTION_EVENTS_REQUEST => Some(parse_reply::<xproto::GetMotionEventsRequest>),
+                xproto::TRANSLATE_COORDINATES_REQUEST => Some(parse_reply::<xproto::TranslateCoordinatesRequest>),
+                xproto::GET_INPUT_FOCUS_REQUEST => Some(parse_reply::<xproto::GetInputFocusRequest>),
+                xproto::QUERY_KEYMAP_REQUEST => Some(parse_reply::<xproto::QueryKeymapRequest>),
+                xproto::QUERY_FONT_REQUEST => Some(parse_reply::<xproto::QueryFontRequest>),
+                xproto::QUERY_TEXT_EXTENTS_REQUEST => Some(parse_reply::<xproto::QueryTextExtentsRequest<'_>>),
+                xproto::LIST_FONTS_REQUEST => Some(parse_reply::<xproto::ListFontsRequest<'_>>),
+                xproto::LIST_FONTS_WITH_INFO_REQUEST => Some(parse_reply::<xproto::ListFontsWithInfoRequest<'_>>),
+                xproto::GET_FONT_PATH_REQUEST => Some(parse_reply::<xproto::GetFontPathRequest>),
+                xproto::GET_IMAGE_REQUEST => Some(parse_reply::<xproto::GetImageRequest>),
+                xproto::LIST_INSTALLED_COLORMAPS_REQUEST => Some(parse_reply::<xproto::ListInstalledColormapsRequest>),
+                xproto::ALLOC_COLOR_REQUEST => Some(parse_reply::<xproto::AllocColorRequest>),
+                xproto::ALLOC_NAMED_COLOR_REQUEST => Some(parse_reply::<xproto::AllocNamedColorRequest<'_>>),
+                xproto::ALLOC_COLOR_CELLS_REQUEST => Some(parse_reply::<xproto::AllocColorCellsRequest>),
+                xproto::ALLOC_COLOR_PLANES_REQUEST => Some(parse_reply::<xproto::AllocColorPlanesRequest>),
+                xproto::QUERY_COLORS_REQUEST => Some(parse_reply::<xproto::QueryColorsRequest<'_>>),
+                xproto::LOOKUP_COLOR_REQUEST => Some(parse_reply::<xproto::LookupColorRequest<'_>>),
+                xproto::QUERY_BEST_SIZE_REQUEST => Some(parse_reply::<xproto::QueryBestSizeRequest>),
+                xproto::QUERY_EXTENSION_REQUEST => Some(parse_reply::<xproto::QueryExtensionRequest<'_>>),
+                xproto::LIST_EXTENSIONS_REQUEST => Some(parse_reply::<xproto::ListExtensionsRequest>),
+                xproto::GET_KEYBOARD_MAPPING_REQUEST => Some(parse_reply::<xproto::GetKeyboardMappingRequest>),
+                xproto::GET_KEYBOARD_CONTROL_REQUEST => Some(parse_reply::<xproto::GetKeyboardControlRequest>),
+                xproto::GET_POINTER_CONTROL_REQUEST => Some(parse_reply::<xproto::GetPointerControlRequest>),
+                xproto::GET_SCREEN_SAVER_REQUEST => Some(parse_reply::<xproto::GetScreenSaverRequest>),
+                xproto::LIST_HOSTS_REQUEST => Some(parse_reply::<xproto::ListHostsRequest>),
+                xproto::SET_POINTER_MAPPING_REQUEST => Some(parse_reply::<xproto::SetPointerMappingRequest<'_>>),
+                xproto::GET_POINTER_MAPPING_REQUEST => Some(parse_reply::<xproto::GetPointerMappingRequest>),
+                xproto::SET_MODIFIER_MAPPING_REQUEST => Some(parse_reply::<xproto::SetModifierMappingRequest<'_>>),
+                xproto::GET_MODIFIER_MAPPING_REQUEST => Some(parse_reply::<xproto::GetModifierMappingRequest>),
+                _ => None,
+            }
+        }
+        Some(bigreq::X11_EXTENSION_NAME) => {
+            match opcode {
+                bigreq::ENABLE_REQUEST => Some(parse_reply::<bigreq::EnableRequest>),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "composite")]
+        Some(composite::X11_EXTENSION_NAME) => {
+            match opcode {
+                composite::QUERY_VERSION_REQUEST => Some(parse_reply::<composite::QueryVersionRequest>),
+                composite::GET_OVERLAY_WINDOW_REQUEST => Some(parse_reply::<composite::GetOverlayWindowRequest>),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "damage")]
+        Some(damage::X11_EXTENSION_NAME) => {
+            match opcode {
+                damage::QUERY_VERSION_REQUEST => Some(parse_reply::<damage::QueryVersionRequest>),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "dbe")]
+        Some(dbe::X11_EXTENSION_NAME) => {
+            match opcode {
+                dbe::QUERY_VERSION_REQUEST => Some(parse_reply::<dbe::QueryVersionRequest>),
+                dbe::GET_VISUAL_INFO_REQUEST => Some(parse_reply::<dbe::GetVisualInfoRequest<'_>>),
+                dbe::GET_BACK_BUFFER_ATTRIBUTES_REQUEST => Some(parse_reply::<dbe::GetBackBufferAttributesRequest>),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "dpms")]
+        Some(dpms::X11_EXTENSION_NAME) => {
+            match opcode {
+                dpms::GET_VERSION_REQUEST => Some(parse_reply::<dpms::GetVersionRequest>),
+                dpms::CAPABLE_REQUEST => Some(parse_reply::<dpms::CapableRequest>),
+                dpms::GET_TIMEOUTS_REQUEST => Some(parse_reply::<dpms::GetTimeoutsRequest>),
+                dpms::INFO_REQUEST => Some(parse_reply::<dpms::InfoRequest>),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "dri2")]
+        Some(dri2::X11_EXTENSION_NAME) => {
+            match opcode {
+                dri2::QUERY_VERSION_REQUEST => Some(parse_reply::<dri2::QueryVersionRequest>),
+                dri2::CONNECT_REQUEST => Some(parse_reply::<dri2::ConnectRequest>),
+                dri2::AUTHENTICATE_REQUEST => Some(parse_reply::<dri2::AuthenticateRequest>),
+                dri2::GET_BUFFERS_REQUEST => Some(parse_reply::<dri2::GetBuffersRequest<'_>>),
+                dri2::COPY_REGION_REQUEST => Some(parse_reply::<dri2::CopyRegionRequest>),
+                dri2::GET_BUFFERS_WITH_FORMAT_REQUEST => Some(parse_reply::<dri2::GetBuffersWithFormatRequest<'_>>),
+                dri2::SWAP_BUFFERS_REQUEST => Some(parse_reply::<dri2::SwapBuffersRequest>),
+                dri2::GET_MSC_REQUEST => Some(parse_reply::<dri2::GetMSCRequest>),
+                dri2::WAIT_MSC_REQUEST => Some(parse_reply::<dri2::WaitMSCRequest>),
+                dri2::WAIT_SBC_REQUEST => Some(parse_reply::<dri2::WaitSBCRequest>),
+                dri2::GET_PARAM_REQUEST => Some(parse_reply::<dri2::GetParamRequest>),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "dri3")]
+        Some(dri3::X11_EXTENSION_NAME) => {
+            match opcode {
+                dri3::QUERY_VERSION_REQUEST => Some(parse_reply::<dri3::QueryVersionRequest>),
+                dri3::OPEN_REQUEST => Some(parse_reply_fds::<dri3::OpenRequest>),
+                dri3::BUFFER_FROM_PIXMAP_REQUEST => Some(parse_reply_fds::<dri3::BufferFromPixmapRequest>),
+                dri3::FD_FROM_FENCE_REQUEST => Some(parse_reply_fds::<dri3::FDFromFenceRequest>),
+                dri3::GET_SUPPORTED_MODIFIERS_REQUEST => Some(parse_reply::<dri3::GetSupportedModifiersRequest>),
+                dri3::BUFFERS_FROM_PIXMAP_REQUEST => Some(parse_reply_fds::<dri3::BuffersFromPixmapRequest>),
+                _ => None,
+            }
+        }
+        Some(ge::X11_EXTENSION_NAME) => {
+            match opcode {
+                ge::QUERY_VERSION_REQUEST => Some(parse_reply::<ge::QueryVersionRequest>),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "glx")]
+        Some(glx::X11_EXTENSION_NAME) => {
+            match opcode {
+                glx::MAKE_CURRENT_REQUEST => Some(parse_reply::<glx::MakeCurrentRequest>),
+                glx::IS_DIRECT_REQUEST => Some(parse_reply::<glx::IsDirectRequest>),
+                glx::QUERY_VERSION_REQUEST => Some(parse_reply::<glx::QueryVersionRequest>),
+                glx::GET_VISUAL_CONFIGS_REQUEST => Some(parse_reply::<glx::GetVisualConfigsRequest>),
+                glx::VENDOR_PRIVATE_WITH_REPLY_REQUEST => Some(parse_reply::<glx::VendorPrivateWithReplyRequest<'_>>),
+                glx::QUERY_EXTENSIONS_STRING_REQUEST => Some(parse_reply::<glx::QueryExtensionsStringRequest>),
+                glx::QUERY_SERVER_STRING_REQUEST => Some(parse_reply::<glx::QueryServerStringRequest>),
+                glx::GET_FB_CONFIGS_REQUEST => Some(parse_reply::<glx::GetFBConfigsRequest>),
+                glx::QUERY_CONTEXT_REQUEST => Some(parse_reply::<glx::QueryContextRequest>),
+                glx::MAKE_CONTEXT_CURRENT_REQUEST => Some(parse_reply::<glx::MakeContextCurrentRequest>),
+                glx::GET_DRAWABLE_ATTRIBUTES_REQUEST => Some(parse_reply::<glx::GetDrawableAttributesRequest>),
+                glx::GEN_LISTS_REQUEST => Some(parse_reply::<glx::GenListsRequest>),
+                glx::RENDER_MODE_REQUEST => Some(parse_reply::<glx::RenderModeRequest>),
+                glx::FINISH_REQUEST => Some(parse_reply::<glx::FinishRequest>),
+                glx::READ_PIXELS_REQUEST => Some(parse_reply::<glx::ReadPixelsRequest>),
+                glx::GET_BOOLEANV_REQUEST => Some(parse_reply::<glx::GetBooleanvRequest>),
+                glx::GET_CLIP_PLANE_REQUEST => Some(parse_reply::<glx::GetClipPlaneRequest>),
+                glx::GET_DOUBLEV_REQUEST => Some(parse_reply::<glx::GetDoublevRequest>),
+                glx::GET_ERROR_REQUEST => Some(parse_reply::<glx::GetErrorRequest>),
+                glx::GET_FLOATV_REQUEST => Some(parse_reply::<glx::GetFloatvRequest>),
+                glx::GET_INTEGERV_REQUEST => Some(parse_reply::<glx::GetIntegervRequest>),
+                glx::GET_LIGHTFV_REQUEST => Some(parse_reply::<glx::GetLightfvRequest>),
+                glx::GET_LIGHTIV_REQUEST => Some(parse_reply::<glx::GetLightivRequest>),
+                glx::GET_MAPDV_REQUEST => Some(parse_reply::<glx::GetMapdvRequest>),
+                glx::GET_MAPFV_REQUEST => Some(parse_reply::<glx::GetMapfvRequest>),
+                glx::GET_MAPIV_REQUEST => Some(parse_reply::<glx::GetMapivRequest>),
+                glx::GET_MATERIALFV_REQUEST => Some(parse_reply::<glx::GetMaterialfvRequest>),
+                glx::GET_MATERIALIV_REQUEST => Some(parse_reply::<glx::GetMaterialivRequest>),
+                glx::GET_PIXEL_MAPFV_REQUEST => Some(parse_reply::<glx::GetPixelMapfvRequest>),
+                glx::GET_PIXEL_MAPUIV_REQUEST => Some(parse_reply::<glx::GetPixelMapuivRequest>),
+                glx::GET_PIXEL_MAPUSV_REQUEST => Some(parse_reply::<glx::GetPixelMapusvRequest>),
+                glx::GET_POLYGON_STIPPLE_REQUEST => Some(parse_reply::<glx::GetPolygonStippleRequest>),
+                glx::GET_STRING_REQUEST => Some(parse_reply::<glx::GetStringRequest>),
+                glx::GET_TEX_ENVFV_REQUEST => Some(parse_reply::<glx::GetTexEnvfvRequest>),
+                glx::GET_TEX_ENVIV_REQUEST => Some(parse_reply::<glx::GetTexEnvivRequest>),
+                glx::GET_TEX_GENDV_REQUEST => Some(parse_reply::<glx::GetTexGendvRequest>),
+                glx::GET_TEX_GENFV_REQUEST => Some(parse_reply::<glx::GetTexGenfvRequest>),
+                glx::GET_TEX_GENIV_REQUEST => Some(parse_reply::<glx::GetTexGenivRequest>),
+                glx::GET_TEX_IMAGE_REQUEST => Some(parse_reply::<glx::GetTexImageRequest>),
+                glx::GET_TEX_PARAMETERFV_REQUEST => Some(parse_reply::<glx::GetTexParameterfvRequest>),
+                glx::GET_TEX_PARAMETERIV_REQUEST => Some(parse_reply::<glx::GetTexParameterivRequest>),
+                glx::GET_TEX_LEVEL_PARAMETERFV_REQUEST => Some(parse_reply::<glx::GetTexLevelParameterfvRequest>),
+                glx::GET_TEX_LEVEL_PARAMETERIV_REQUEST => Some(parse_reply::<glx::GetTexLevelParameterivRequest>),
+                glx::IS_ENABLED_REQUEST => Some(parse_reply::<glx::IsEnabledRequest>),
+                glx::IS_LIST_REQUEST => Some(parse_reply::<glx::IsListRequest>),
+                glx::ARE_TEXTURES_RESIDENT_REQUEST => Some(parse_reply::<glx::AreTexturesResidentRequest<'_>>),
+                glx::GEN_TEXTURES_REQUEST => Some(parse_reply::<glx::GenTexturesRequest>),
+                glx::IS_TEXTURE_REQUEST => Some(parse_reply::<glx::IsTextureRequest>),
+                glx::GET_COLOR_TABLE_REQUEST => Some(parse_reply::<glx::GetColorTableRequest>),
+                glx::GET_COLOR_TABLE_PARAMETERFV_REQUEST => Some(parse_reply::<glx::GetColorTableParameterfvRequest>),
+                glx::GET_COLOR_TABLE_PARAMETERIV_REQUEST => Some(parse_reply::<glx::GetColorTableParameterivRequest>),
+                glx::GET_CONVOLUTION_FILTER_REQUEST => Some(parse_reply::<glx::GetConvolutionFilterRequest>),
+                glx::GET_CONVOLUTION_PARAMETERFV_REQUEST => Some(parse_reply::<glx::GetConvolutionParameterfvRequest>),
+                glx::GET_CONVOLUTION_PARAMETERIV_REQUEST => Some(parse_reply::<glx::GetConvolutionParameterivRequest>),
+                glx::GET_SEPARABLE_FILTER_REQUEST => Some(parse_reply::<glx::GetSeparableFilterRequest>),
+                glx::GET_HISTOGRAM_REQUEST => Some(parse_reply::<glx::GetHistogramRequest>),
+                glx::GET_HISTOGRAM_PARAMETERFV_REQUEST => Some(parse_reply::<glx::GetHistogramParameterfvRequest>),
+                glx::GET_HISTOGRAM_PARAMETERIV_REQUEST => Some(parse_reply::<glx::GetHistogramParameterivRequest>),
+                glx::GET_MINMAX_REQUEST => Some(parse_reply::<glx::GetMinmaxRequest>),
+                glx::GET_MINMAX_PARAMETERFV_REQUEST => Some(parse_reply::<glx::GetMinmaxParameterfvRequest>),
+                glx::GET_MINMAX_PARAMETERIV_REQUEST => Some(parse_reply::<glx::GetMinmaxParameterivRequest>),
+                glx::GET_COMPRESSED_TEX_IMAGE_ARB_REQUEST => Some(parse_reply::<glx::GetCompressedTexImageARBRequest>),
+                glx::GEN_QUERIES_ARB_REQUEST => Some(parse_reply::<glx::GenQueriesARBRequest>),
+                glx::IS_QUERY_ARB_REQUEST => Some(parse_reply::<glx::IsQueryARBRequest>),
+                glx::GET_QUERYIV_ARB_REQUEST => Some(parse_reply::<glx::GetQueryivARBRequest>),
+                glx::GET_QUERY_OBJECTIV_ARB_REQUEST => Some(parse_reply::<glx::GetQueryObjectivARBRequest>),
+                glx::GET_QUERY_OBJECTUIV_ARB_REQUEST => Some(parse_reply::<glx::GetQueryObjectuivARBRequest>),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "present")]
+        Some(present::X11_EXTENSION_NAME) => {
+            match opcode {
+                present::QUERY_VERSION_REQUEST => Some(parse_reply::<present::QueryVersionRequest>),
+                present::QUERY_CAPABILITIES_REQUEST => Some(parse_reply::<present::QueryCapabilitiesRequest>),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "randr")]
+        Some(randr::X11_EXTENSION_NAME) => {
+            match opcode {
+                randr::QUERY_VERSION_REQUEST => Some(parse_reply::<randr::QueryVersionRequest>),
+                randr::SET_SCREEN_CONFIG_REQUEST => Some(parse_reply::<randr::SetScreenConfigRequest>),
+                randr::GET_SCREEN_INFO_REQUEST => Some(parse_reply::<randr::GetScreenInfoRequest>),
+                randr::GET_SCREEN_SIZE_RANGE_REQUEST => Some(parse_reply::<randr::GetScreenSizeRangeRequest>),
+                randr::GET_SCREEN_RESOURCES_REQUEST => Some(parse_reply::<randr::GetScreenResourcesRequest>),
+                randr::GET_OUTPUT_INFO_REQUEST => Some(parse_reply::<randr::GetOutputInfoRequest>),
+                randr::LIST_OUTPUT_PROPERTIES_REQUEST => Some(parse_reply::<randr::ListOutputPropertiesRequest>),
+                randr::QUERY_OUTPUT_PROPERTY_REQUEST => Some(parse_reply::<randr::QueryOutputPropertyRequest>),
+                randr::GET_OUTPUT_PROPERTY_REQUEST => Some(parse_reply::<randr::GetOutputPropertyRequest>),
+                randr::CREATE_MODE_REQUEST => Some(parse_reply::<randr::CreateModeRequest<'_>>),
+                randr::GET_CRTC_INFO_REQUEST => Some(parse_reply::<randr::GetCrtcInfoRequest>),
+                randr::SET_CRTC_CONFIG_REQUEST => Some(parse_reply::<randr::SetCrtcConfigRequest<'_>>),
+                randr::GET_CRTC_GAMMA_SIZE_REQUEST => Some(parse_reply::<randr::GetCrtcGammaSizeRequest>),
+                randr::GET_CRTC_GAMMA_REQUEST => Some(parse_reply::<randr::GetCrtcGammaRequest>),
+                randr::GET_SCREEN_RESOURCES_CURRENT_REQUEST => Some(parse_reply::<randr::GetScreenResourcesCurrentRequest>),
+                randr::GET_CRTC_TRANSFORM_REQUEST => Some(parse_reply::<randr::GetCrtcTransformRequest>),
+                randr::GET_PANNING_REQUEST => Some(parse_reply::<randr::GetPanningRequest>),
+                randr::SET_PANNING_REQUEST => Some(parse_reply::<randr::SetPanningRequest>),
+                randr::GET_OUTPUT_PRIMARY_REQUEST => Some(parse_reply::<randr::GetOutputPrimaryRequest>),
+                randr::GET_PROVIDERS_REQUEST => Some(parse_reply::<randr::GetProvidersRequest>),
+                randr::GET_PROVIDER_INFO_REQUEST => Some(parse_reply::<randr::GetProviderInfoRequest>),
+                randr::LIST_PROVIDER_PROPERTIES_REQUEST => Some(parse_reply::<randr::ListProviderPropertiesRequest>),
+                randr::QUERY_PROVIDER_PROPERTY_REQUEST => Some(parse_reply::<randr::QueryProviderPropertyRequest>),
+                randr::GET_PROVIDER_PROPERTY_REQUEST => Some(parse_reply::<randr::GetProviderPropertyRequest>),
+                randr::GET_MONITORS_REQUEST => Some(parse_reply::<randr::GetMonitorsRequest>),
+                randr::CREATE_LEASE_REQUEST => Some(parse_reply_fds::<randr::CreateLeaseRequest<'_>>),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "record")]
+        Some(record::X11_EXTENSION_NAME) => {
+            match opcode {
+                record::QUERY_VERSION_REQUEST => Some(parse_reply::<record::QueryVersionRequest>),
+                record::GET_CONTEXT_REQUEST => Some(parse_reply::<record::GetContextRequest>),
+                record::ENABLE_CONTEXT_REQUEST => Some(parse_reply::<record::EnableContextRequest>),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "render")]
+        Some(render::X11_EXTENSION_NAME) => {
+            match opcode {
+                render::QUERY_VERSION_REQUEST => Some(parse_reply::<render::QueryVersionRequest>),
+                render::QUERY_PICT_FORMATS_REQUEST => Some(parse_reply::<render::QueryPictFormatsRequest>),
+                render::QUERY_PICT_INDEX_VALUES_REQUEST => Some(parse_reply::<render::QueryPictIndexValuesRequest>),
+                render::QUERY_FILTERS_REQUEST => Some(parse_reply::<render::QueryFiltersRequest>),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "res")]
+        Some(res::X11_EXTENSION_NAME) => {
+            match opcode {
+                res::QUERY_VERSION_REQUEST => Some(parse_reply::<res::QueryVersionRequest>),
+                res::QUERY_CLIENTS_REQUEST => Some(parse_reply::<res::QueryClientsRequest>),
+                res::QUERY_CLIENT_RESOURCES_REQUEST => Some(parse_reply::<res::QueryClientResourcesRequest>),
+                res::QUERY_CLIENT_PIXMAP_BYTES_REQUEST => Some(parse_reply::<res::QueryClientPixmapBytesRequest>),
+                res::QUERY_CLIENT_IDS_REQUEST => Some(parse_reply::<res::QueryClientIdsRequest<'_>>),
+                res::QUERY_RESOURCE_BYTES_REQUEST => Some(parse_reply::<res::QueryResourceBytesRequest<'_>>),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "screensaver")]
+        Some(screensaver::X11_EXTENSION_NAME) => {
+            match opcode {
+                screensaver::QUERY_VERSION_REQUEST => Some(parse_reply::<screensaver::QueryVersionRequest>),
+                screensaver::QUERY_INFO_REQUEST => Some(parse_reply::<screensaver::QueryInfoRequest>),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "shape")]
+        Some(shape::X11_EXTENSION_NAME) => {
+            match opcode {
+                shape::QUERY_VERSION_REQUEST => Some(parse_reply::<shape::QueryVersionRequest>),
+                shape::QUERY_EXTENTS_REQUEST => Some(parse_reply::<shape::QueryExtentsRequest>),
+                shape::INPUT_SELECTED_REQUEST => Some(parse_reply::<shape::InputSelectedRequest>),
+                shape::GET_RECTANGLES_REQUEST => Some(parse_reply::<shape::GetRectanglesRequest>),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "shm")]
+        Some(shm::X11_EXTENSION_NAME) => {
+            match opcode {
+                shm::QUERY_VERSION_REQUEST => Some(parse_reply::<shm::QueryVersionRequest>),
+                shm::GET_IMAGE_REQUEST => Some(parse_reply::<shm::GetImageRequest>),
+                shm::CREATE_SEGMENT_REQUEST => Some(parse_reply_fds::<shm::CreateSegmentRequest>),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "sync")]
+        Some(sync::X11_EXTENSION_NAME) => {
+            match opcode {
+                sync::INITIALIZE_REQUEST => Some(parse_reply::<sync::InitializeRequest>),
+                sync::LIST_SYSTEM_COUNTERS_REQUEST => Some(parse_reply::<sync::ListSystemCountersRequest>),
+                sync::QUERY_COUNTER_REQUEST => Some(parse_reply::<sync::QueryCounterRequest>),
+                sync::QUERY_ALARM_REQUEST => Some(parse_reply::<sync::QueryAlarmRequest>),
+                sync::GET_PRIORITY_REQUEST => Some(parse_reply::<sync::GetPriorityRequest>),
+                sync::QUERY_FENCE_REQUEST => Some(parse_reply::<sync::QueryFenceRequest>),
+                _ => None,
+            }
+        }
+        Some(xc_misc::X11_EXTENSION_NAME) => {
+            match opcode {
+                xc_misc::GET_VERSION_REQUEST => Some(parse_reply::<xc_misc::GetVersionRequest>),
+                xc_misc::GET_XID_RANGE_REQUEST => Some(parse_reply::<xc_misc::GetXIDRangeRequest>),
+                xc_misc::GET_XID_LIST_REQUEST => Some(parse_reply::<xc_misc::GetXIDListRequest>),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "xevie")]
+        Some(xevie::X11_EXTENSION_NAME) => {
+            match opcode {
+                xevie::QUERY_VERSION_REQUEST => Some(parse_reply::<xevie::QueryVersionRequest>),
+                xevie::START_REQUEST => Some(parse_reply::<xevie::StartRequest>),
+                xevie::END_REQUEST => Some(parse_reply::<xevie::EndRequest>),
+                xevie::SEND_REQUEST => Some(parse_reply::<xevie::SendRequest>),
+                xevie::SELECT_INPUT_REQUEST => Some(parse_reply::<xevie::SelectInputRequest>),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "xf86dri")]
+        Some(xf86dri::X11_EXTENSION_NAME) => {
+            match opcode {
+                xf86dri::QUERY_VERSION_REQUEST => Some(parse_reply::<xf86dri::QueryVersionRequest>),
+                xf86dri::QUERY_DIRECT_RENDERING_CAPABLE_REQUEST => Some(parse_reply::<xf86dri::QueryDirectRenderingCapableRequest>),
+                xf86dri::OPEN_CONNECTION_REQUEST => Some(parse_reply::<xf86dri::OpenConnectionRequest>),
+                xf86dri::GET_CLIENT_DRIVER_NAME_REQUEST => Some(parse_reply::<xf86dri::GetClientDriverNameRequest>),
+                xf86dri::CREATE_CONTEXT_REQUEST => Some(parse_reply::<xf86dri::CreateContextRequest>),
+                xf86dri::CREATE_DRAWABLE_REQUEST => Some(parse_reply::<xf86dri::CreateDrawableRequest>),
+                xf86dri::GET_DRAWABLE_INFO_REQUEST => Some(parse_reply::<xf86dri::GetDrawableInfoRequest>),
+                xf86dri::GET_DEVICE_INFO_REQUEST => Some(parse_reply::<xf86dri::GetDeviceInfoRequest>),
+                xf86dri::AUTH_CONNECTION_REQUEST => Some(parse_reply::<xf86dri::AuthConnectionRequest>),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "xf86vidmode")]
+        Some(xf86vidmode::X11_EXTENSION_NAME) => {
+            match opcode {
+                xf86vidmode::QUERY_VERSION_REQUEST => Some(parse_reply::<xf86vidmode::QueryVersionRequest>),
+                xf86vidmode::GET_MODE_LINE_REQUEST => Some(parse_reply::<xf86vidmode::GetModeLineRequest>),
+                xf86vidmode::GET_MONITOR_REQUEST => Some(parse_reply::<xf86vidmode::GetMonitorRequest>),
+                xf86vidmode::GET_ALL_MODE_LINES_REQUEST => Some(parse_reply::<xf86vidmode::GetAllModeLinesRequest>),
+                xf86vidmode::VALIDATE_MODE_LINE_REQUEST => Some(parse_reply::<xf86vidmode::ValidateModeLineRequest<'_>>),
+                xf86vidmode::GET_VIEW_PORT_REQUEST => Some(parse_reply::<xf86vidmode::GetViewPortRequest>),
+                xf86vidmode::GET_DOT_CLOCKS_REQUEST => Some(parse_reply::<xf86vidmode::GetDotClocksRequest>),
+                xf86vidmode::GET_GAMMA_REQUEST => Some(parse_reply::<xf86vidmode::GetGammaRequest>),
+                xf86vidmode::GET_GAMMA_RAMP_REQUEST => Some(parse_reply::<xf86vidmode::GetGammaRampRequest>),
+                xf86vidmode::GET_GAMMA_RAMP_SIZE_REQUEST => Some(parse_reply::<xf86vidmode::GetGammaRampSizeRequest>),
+                xf86vidmode::GET_PERMISSIONS_REQUEST => Some(parse_reply::<xf86vidmode::GetPermissionsRequest>),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "xfixes")]
+        Some(xfixes::X11_EXTENSION_NAME) => {
+            match opcode {
+                xfixes::QUERY_VERSION_REQUEST => Some(parse_reply::<xfixes::QueryVersionRequest>),
+                xfixes::GET_CURSOR_IMAGE_REQUEST => Some(parse_reply::<xfixes::GetCursorImageRequest>),
+                xfixes::FETCH_REGION_REQUEST => Some(parse_reply::<xfixes::FetchRegionRequest>),
+                xfixes::GET_CURSOR_NAME_REQUEST => Some(parse_reply::<xfixes::GetCursorNameRequest>),
+                xfixes::GET_CURSOR_IMAGE_AND_NAME_REQUEST => Some(parse_reply::<xfixes::GetCursorImageAndNameRequest>),
+                xfixes::GET_CLIENT_DISCONNECT_MODE_REQUEST => Some(parse_reply::<xfixes::GetClientDisconnectModeRequest>),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "xinerama")]
+        Some(xinerama::X11_EXTENSION_NAME) => {
+            match opcode {
+                xinerama::QUERY_VERSION_REQUEST => Some(parse_reply::<xinerama::QueryVersionRequest>),
+                xinerama::GET_STATE_REQUEST => Some(parse_reply::<xinerama::GetStateRequest>),
+                xinerama::GET_SCREEN_COUNT_REQUEST => Some(parse_reply::<xinerama::GetScreenCountRequest>),
+                xinerama::GET_SCREEN_SIZE_REQUEST => Some(parse_reply::<xinerama::GetScreenSizeRequest>),
+                xinerama::IS_ACTIVE_REQUEST => Some(parse_reply::<xinerama::IsActiveRequest>),
+                xinerama::QUERY_SCREENS_REQUEST => Some(parse_reply::<xinerama::QueryScreensRequest>),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "xinput")]
+        Some(xinput::X11_EXTENSION_NAME) => {
+            match opcode {
+                xinput::GET_EXTENSION_VERSION_REQUEST => Some(parse_reply::<xinput::GetExtensionVersionRequest<'_>>),
+                xinput::LIST_INPUT_DEVICES_REQUEST => Some(parse_reply::<xinput::ListInputDevicesRequest>),
+                xinput::OPEN_DEVICE_REQUEST => Some(parse_reply::<xinput::OpenDeviceRequest>),
+                xinput::SET_DEVICE_MODE_REQUEST => Some(parse_reply::<xinput::SetDeviceModeRequest>),
+                xinput::GET_SELECTED_EXTENSION_EVENTS_REQUEST => Some(parse_reply::<xinput::GetSelectedExtensionEventsRequest>),
+                xinput::GET_DEVICE_DONT_PROPAGATE_LIST_REQUEST => Some(parse_reply::<xinput::GetDeviceDontPropagateListRequest>),
+                xinput::GET_DEVICE_MOTION_EVENTS_REQUEST => Some(parse_reply::<xinput::GetDeviceMotionEventsRequest>),
+                xinput::CHANGE_KEYBOARD_DEVICE_REQUEST => Some(parse_reply::<xinput::ChangeKeyboardDeviceRequest>),
+                xinput::CHANGE_POINTER_DEVICE_REQUEST => Some(parse_reply::<xinput::ChangePointerDeviceRequest>),
+                xinput::GRAB_DEVICE_REQUEST => Some(parse_reply::<xinput::GrabDeviceRequest<'_>>),
+                xinput::GET_DEVICE_FOCUS_REQUEST => Some(parse_reply::<xinput::GetDeviceFocusRequest>),
+                xinput::GET_FEEDBACK_CONTROL_REQUEST => Some(parse_reply::<xinput::GetFeedbackControlRequest>),
+                xinput::GET_DEVICE_KEY_MAPPING_REQUEST => Some(parse_reply::<xinput::GetDeviceKeyMappingRequest>),
+                xinput::GET_DEVICE_MODIFIER_MAPPING_REQUEST => Some(parse_reply::<xinput::GetDeviceModifierMappingRequest>),
+                xinput::SET_DEVICE_MODIFIER_MAPPING_REQUEST => Some(parse_reply::<xinput::SetDeviceModifierMappingRequest<'_>>),
+                xinput::GET_DEVICE_BUTTON_MAPPING_REQUEST => Some(parse_reply::<xinput::GetDeviceButtonMappingRequest>),
+                xinput::SET_DEVICE_BUTTON_MAPPING_REQUEST => Some(parse_reply::<xinput::SetDeviceButtonMappingRequest<'_>>),
+                xinput::QUERY_DEVICE_STATE_REQUEST => Some(parse_reply::<xinput::QueryDeviceStateRequest>),
+                xinput::SET_DEVICE_VALUATORS_REQUEST => Some(parse_reply::<xinput::SetDeviceValuatorsRequest<'_>>),
+                xinput::GET_DEVICE_CONTROL_REQUEST => Some(parse_reply::<xinput::GetDeviceControlRequest>),
+                xinput::CHANGE_DEVICE_CONTROL_REQUEST => Some(parse_reply::<xinput::ChangeDeviceControlRequest>),
+                xinput::LIST_DEVICE_PROPERTIES_REQUEST => Some(parse_reply::<xinput::ListDevicePropertiesRequest>),
+                xinput::GET_DEVICE_PROPERTY_REQUEST => Some(parse_reply::<xinput::GetDevicePropertyRequest>),
+                xinput::XI_QUERY_POINTER_REQUEST => Some(parse_reply::<xinput::XIQueryPointerRequest>),
+                xinput::XI_GET_CLIENT_POINTER_REQUEST => Some(parse_reply::<xinput::XIGetClientPointerRequest>),
+                xinput::XI_QUERY_VERSION_REQUEST => Some(parse_reply::<xinput::XIQueryVersionRequest>),
+                xinput::XI_QUERY_DEVICE_REQUEST => Some(parse_reply::<xinput::XIQueryDeviceRequest>),
+                xinput::XI_GET_FOCUS_REQUEST => Some(parse_reply::<xinput::XIGetFocusRequest>),
+                xinput::XI_GRAB_DEVICE_REQUEST => Some(parse_reply::<xinput::XIGrabDeviceRequest<'_>>),
+                xinput::XI_PASSIVE_GRAB_DEVICE_REQUEST => Some(parse_reply::<xinput::XIPassiveGrabDeviceRequest<'_>>),
+                xinput::XI_LIST_PROPERTIES_REQUEST => Some(parse_reply::<xinput::XIListPropertiesRequest>),
+                xinput::XI_GET_PROPERTY_REQUEST => Some(parse_reply::<xinput::XIGetPropertyRequest>),
+                xinput::XI_GET_SELECTED_EVENTS_REQUEST => Some(parse_reply::<xinput::XIGetSelectedEventsRequest>),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "xkb")]
+        Some(xkb::X11_EXTENSION_NAME) => {
+            match opcode {
+                xkb::USE_EXTENSION_REQUEST => Some(parse_reply::<xkb::UseExtensionRequest>),
+                xkb::GET_STATE_REQUEST => Some(parse_reply::<xkb::GetStateRequest>),
+                xkb::GET_CONTROLS_REQUEST => Some(parse_reply::<xkb::GetControlsRequest>),
+                xkb::GET_MAP_REQUEST => Some(parse_reply::<xkb::GetMapRequest>),
+                xkb::GET_COMPAT_MAP_REQUEST => Some(parse_reply::<xkb::GetCompatMapRequest>),
+                xkb::GET_INDICATOR_STATE_REQUEST => Some(parse_reply::<xkb::GetIndicatorStateRequest>),
+                xkb::GET_INDICATOR_MAP_REQUEST => Some(parse_reply::<xkb::GetIndicatorMapRequest>),
+                xkb::GET_NAMED_INDICATOR_REQUEST => Some(parse_reply::<xkb::GetNamedIndicatorRequest>),
+                xkb::GET_NAMES_REQUEST => Some(parse_reply::<xkb::GetNamesRequest>),
+                xkb::PER_CLIENT_FLAGS_REQUEST => Some(parse_reply::<xkb::PerClientFlagsRequest>),
+                xkb::LIST_COMPONENTS_REQUEST => Some(parse_reply::<xkb::ListComponentsRequest>),
+                xkb::GET_KBD_BY_NAME_REQUEST => Some(parse_reply::<xkb::GetKbdByNameRequest>),
+                xkb::GET_DEVICE_INFO_REQUEST => Some(parse_reply::<xkb::GetDeviceInfoRequest>),
+                xkb::SET_DEBUGGING_FLAGS_REQUEST => Some(parse_reply::<xkb::SetDebuggingFlagsRequest<'_>>),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "xprint")]
+        Some(xprint::X11_EXTENSION_NAME) => {
+            match opcode {
+                xprint::PRINT_QUERY_VERSION_REQUEST => Some(parse_reply::<xprint::PrintQueryVersionRequest>),
+                xprint::PRINT_GET_PRINTER_LIST_REQUEST => Some(parse_reply::<xprint::PrintGetPrinterListRequest<'_>>),
+                xprint::PRINT_GET_CONTEXT_REQUEST => Some(parse_reply::<xprint::PrintGetContextRequest>),
+                xprint::PRINT_GET_SCREEN_OF_CONTEXT_REQUEST => Some(parse_reply::<xprint::PrintGetScreenOfContextRequest>),
+                xprint::PRINT_GET_DOCUMENT_DATA_REQUEST => Some(parse_reply::<xprint::PrintGetDocumentDataRequest>),
+                xprint::PRINT_INPUT_SELECTED_REQUEST => Some(parse_reply::<xprint::PrintInputSelectedRequest>),
+                xprint::PRINT_GET_ATTRIBUTES_REQUEST => Some(parse_reply::<xprint::PrintGetAttributesRequest>),
+                xprint::PRINT_GET_ONE_ATTRIBUTES_REQUEST => Some(parse_reply::<xprint::PrintGetOneAttributesRequest<'_>>),
+                xprint::PRINT_GET_PAGE_DIMENSIONS_REQUEST => Some(parse_reply::<xprint::PrintGetPageDimensionsRequest>),
+                xprint::PRINT_QUERY_SCREENS_REQUEST => Some(parse_reply::<xprint::PrintQueryScreensRequest>),
+                xprint::PRINT_SET_IMAGE_RESOLUTION_REQUEST => Some(parse_reply::<xprint::PrintSetImageResolutionRequest>),
+                xprint::PRINT_GET_IMAGE_RESOLUTION_REQUEST => Some(parse_reply::<xprint::PrintGetImageResolutionRequest>),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "xselinux")]
+        Some(xselinux::X11_EXTENSION_NAME) => {
+            match opcode {
+                xselinux::QUERY_VERSION_REQUEST => Some(parse_reply::<xselinux::QueryVersionRequest>),
+                xselinux::GET_DEVICE_CREATE_CONTEXT_REQUEST => Some(parse_reply::<xselinux::GetDeviceCreateContextRequest>),
+                xselinux::GET_DEVICE_CONTEXT_REQUEST => Some(parse_reply::<xselinux::GetDeviceContextRequest>),
+                xselinux::GET_WINDOW_CREATE_CONTEXT_REQUEST => Some(parse_reply::<xselinux::GetWindowCreateContextRequest>),
+                xselinux::GET_WINDOW_CONTEXT_REQUEST => Some(parse_reply::<xselinux::GetWindowContextRequest>),
+                xselinux::GET_PROPERTY_CREATE_CONTEXT_REQUEST => Some(parse_reply::<xselinux::GetPropertyCreateContextRequest>),
+                xselinux::GET_PROPERTY_USE_CONTEXT_REQUEST => Some(parse_reply::<xselinux::GetPropertyUseContextRequest>),
+                xselinux::GET_PROPERTY_CONTEXT_REQUEST => Some(parse_reply::<xselinux::GetPropertyContextRequest>),
+                xselinux::GET_PROPERTY_DATA_CONTEXT_REQUEST => Some(parse_reply::<xselinux::GetPropertyDataContextRequest>),
+                xselinux::LIST_PROPERTIES_REQUEST => Some(parse_reply::<xselinux::ListPropertiesRequest>),
+                xselinux::GET_SELECTION_CREATE_CONTEXT_REQUEST => Some(parse_reply::<xselinux::GetSelectionCreateContextRequest>),
+                xselinux::GET_SELECTION_USE_CONTEXT_REQUEST => Some(parse_reply::<xselinux::GetSelectionUseContextRequest>),
+                xselinux::GET_SELECTION_CONTEXT_REQUEST => Some(parse_reply::<xselinux::GetSelectionContextRequest>),
+                xselinux::GET_SELECTION_DATA_CONTEXT_REQUEST => Some(parse_reply::<xselinux::GetSelectionDataContextRequest>),
+                xselinux::LIST_SELECTIONS_REQUEST => Some(parse_reply::<xselinux::ListSelectionsRequest>),
+                xselinux::GET_CLIENT_CONTEXT_REQUEST => Some(parse_reply::<xselinux::GetClientContextRequest>),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "xtest")]
+        Some(xtest::X11_EXTENSION_NAME) => {
+            match opcode {
+                xtest::GET_VERSION_REQUEST => Some(parse_reply::<xtest::GetVersionRequest>),
+                xtest::COMPARE_CURSOR_REQUEST => Some(parse_reply::<xtest::CompareCursorRequest>),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "xv")]
+        Some(xv::X11_EXTENSION_NAME) => {
+            match opcode {
+                xv::QUERY_EXTENSION_REQUEST => Some(parse_reply::<xv::QueryExtensionRequest>),
+                xv::QUERY_ADAPTORS_REQUEST => Some(parse_reply::<xv::QueryAdaptorsRequest>),
+                xv::QUERY_ENCODINGS_REQUEST => Some(parse_reply::<xv::QueryEncodingsRequest>),
+                xv::GRAB_PORT_REQUEST => Some(parse_reply::<xv::GrabPortRequest>),
+                xv::QUERY_BEST_SIZE_REQUEST => Some(parse_reply::<xv::QueryBestSizeRequest>),
+                xv::GET_PORT_ATTRIBUTE_REQUEST => Some(parse_reply::<xv::GetPortAttributeRequest>),
+                xv::QUERY_PORT_ATTRIBUTES_REQUEST => Some(parse_reply::<xv::QueryPortAttributesRequest>),
+                xv::LIST_IMAGE_FORMATS_REQUEST => Some(parse_reply::<xv::ListImageFormatsRequest>),
+                xv::QUERY_IMAGE_ATTRIBUTES_REQUEST => Some(parse_reply::<xv::QueryImageAttributesRequest>),
+                _ => None,
+            }
+        }
+        #[cfg(feature = "xvmc")]
+        Some(xvmc::X11_EXTENSION_NAME) => {
+            match opcode {
+                xvmc::QUERY_VERSION_REQUEST => Some(parse_reply::<xvmc::QueryVersionRequest>),
+                xvmc::LIST_SURFACE_TYPES_REQUEST => Some(parse_reply::<xvmc::ListSurfaceTypesRequest>),
+                xvmc::CREATE_CONTEXT_REQUEST => Some(parse_reply::<xvmc::CreateContextRequest>),
+                xvmc::CREATE_SURFACE_REQUEST => Some(parse_reply::<xvmc::CreateSurfaceRequest>),
+                xvmc::CREATE_SUBPICTURE_REQUEST => Some(parse_reply::<xvmc::CreateSubpictureRequest>),
+                xvmc::LIST_SUBPICTURE_TYPES_REQUEST => Some(parse_reply::<xvmc::ListSubpictureTypesRequest>),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Parse a reply to a request given by the name of its extension and its opcode.
+///
+/// `extension` and `opcode` are interpreted as in [`request_name_by_opcode`].
+/// Returns `Ok(None)` if the request is not known or does not have a reply.
+pub fn parse_reply_by_opcode(
+    extension: Option<&str>,
+    opcode: u8,
+    bytes: &[u8],
+    fds: &mut Vec<RawFdContainer>,
+) -> Result<Option<Reply>, ParseError> {
+    match reply_parser_by_opcode(extension, opcode) {
+        Some(parser) => parser(bytes, fds).map(|(reply, _)| Some(reply)),
+        None => Ok(None),
+    }
+}
+
+/// Get the table of all requests of an extension.
+///
+/// `extension` is `None` for core requests and the name of the extension as used in
+/// `QueryExtension` otherwise. Returns `None` if the extension is not known.
+pub fn requests_of_extension(extension: Option<&str>) -> Option<&'static [RequestDescription]> {
+    match extension {
+        None => Some(xproto::REQUESTS),
+        Some(bigreq::X11_EXTENSION_NAME) => Some(bigreq::REQUESTS),
+        #[cfg(feature = "composite")]
+        Some(composite::X11_EXTENSION_NAME) => Some(composite::REQUESTS),
+        #[cfg(feature = "damage")]
+        Some(damage::X11_EXTENSION_NAME) => Some(damage::REQUESTS),
+        #[cfg(feature = "dbe")]
+        Some(dbe::X11_EXTENSION_NAME) => Some(dbe::REQUESTS),
+        #[cfg(feature = "dpms")]
+        Some(dpms::X11_EXTENSION_NAME) => Some(dpms::REQUESTS),
+        #[cfg(feature = "dri2")]
+        Some(dri2::X11_EXTENSION_NAME) => Some(dri2::REQUESTS),
+        #[cfg(feature = "dri3")]
+        Some(dri3::X11_EXTENSION_NAME) => Some(dri3::REQUESTS),
+        Some(ge::X11_EXTENSION_NAME) => Some(ge::REQUESTS),
+        #[cfg(feature = "glx")]
+        Some(glx::X11_EXTENSION_NAME) => Some(glx::REQUESTS),
+        #[cfg(feature = "present")]
+        Some(present::X11_EXTENSION_NAME) => Some(present::REQUESTS),
+        #[cfg(feature = "randr")]
+        Some(randr::X11_EXTENSION_NAME) => Some(randr::REQUESTS),
+        #[cfg(feature = "record")]
+        Some(record::X11_EXTENSION_NAME) => Some(record::REQUESTS),
+        #[cfg(feature = "render")]
+        Some(render::X11_EXTENSION_NAME) => Some(render::REQUESTS),
+        #[cfg(feature = "res")]
+        Some(res::X11_EXTENSION_NAME) => Some(res::REQUESTS),
+        #[cfg(feature = "screensaver")]
+        Some(screensaver::X11_EXTENSION_NAME) => Some(screensaver::REQUESTS),
+        #[cfg(feature = "shape")]
+        Some(shape::X11_EXTENSION_NAME) => Some(shape::REQUESTS),
+        #[cfg(feature = "shm")]
+        Some(shm::X11_EXTENSION_NAME) => Some(shm::REQUESTS),
+        #[cfg(feature = "sync")]
+        Some(sync::X11_EXTENSION_NAME) => Some(sync::REQUESTS),
+        Some(xc_misc::X11_EXTENSION_NAME) => Some(xc_misc::REQUESTS),
+        #[cfg(feature = "xevie")]
+        Some(xevie::X11_EXTENSION_NAME) => Some(xevie::REQUESTS),
+        #[cfg(feature = "xf86dri")]
+        Some(xf86dri::X11_EXTENSION_NAME) => Some(xf86dri::REQUESTS),
+        #[cfg(feature = "xf86vidmode")]
+        Some(xf86vidmode::X11_EXTENSION_NAME) => Some(xf86vidmode::REQUESTS),
+        #[cfg(feature = "xfixes")]
+        Some(xfixes::X11_EXTENSION_NAME) => Some(xfixes::REQUESTS),
+        #[cfg(feature = "xinerama")]
+        Some(xinerama::X11_EXTENSION_NAME) => Some(xinerama::REQUESTS),
+        #[cfg(feature = "xinput")]
+        Some(xinput::X11_EXTENSION_NAME) => Some(xinput::REQUESTS),
+        #[cfg(feature = "xkb")]
+        Some(xkb::X11_EXTENSION_NAME) => Some(xkb::REQUESTS),
+        #[cfg(feature = "xprint")]
+        Some(xprint::X11_EXTENSION_NAME) => Some(xprint::REQUESTS),
+        #[cfg(feature = "xselinux")]
+        Some(xselinux::X11_EXTENSION_NAME) => Some(xselinux::REQUESTS),
+        #[cfg(feature = "xtest")]
+        Some(xtest::X11_EXTENSION_NAME) => Some(xtest::REQUESTS),
+        #[cfg(feature = "xv")]
+        Some(xv::X11_EXTENSION_NAME) => Some(xv::REQUESTS),
+        #[cfg(feature = "xvmc")]
+        Some(xvmc::X11_EXTENSION_NAME) => Some(xvmc::REQUESTS),
+        _ => None,
+    }
+}
+
+/// Describe a request given by the name of its extension and its opcode.
+///
+/// `extension` and `opcode` are interpreted as in [`request_name_by_opcode`].
+/// Returns `None` if the request is not known.
+pub fn request_description(extension: Option<&str>, opcode: u8) -> Option<&'static RequestDescription> {
+    requests_of_extension(extension)?.iter().find(|request| request.opcode == opcode)
+}
+
 /// Get the name of a request from its extension name and opcodes.
 ///
 /// First result is the name of the extension, second the name of the request.
@@ -8073,6 +8689,17 @@ pub(crate) fn request_name(ext_info_provider: &dyn ExtInfoProvider, major_opcode
     (ext, info)
 }
 
+/// Get the name of a request from the name of its extension and its opcode.
+///
+/// For core requests, `extension` is `None` and `opcode` is the major opcode of the request.
+/// Otherwise, `extension` is the name of the extension as used in `QueryExtension`, e.g.
+/// `"RENDER"`, and `opcode` is the minor opcode of the request.
+///
+/// Returns `None` if the request is not known.
+pub fn request_name_by_opcode(extension: Option<&str>, opcode: u8) -> Option<&'static str> {
+    request_description(extension, opcode).map(|request| request.name)
+}
+
 /// Enumeration of all possible X11 error kinds.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[non_exhaustive]
@@ -8574,6 +9201,220 @@ pub enum Event {
     XvVideoNotify(xv::VideoNotifyEvent),
 }
 
+/// Enumeration of all possible X11 event kinds.
+///
+/// There is one kind for each variant of [`Event`], see [`Event::kind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum EventKind {
+    Unknown,
+    Error,
+    ButtonPress,
+    ButtonRelease,
+    CirculateNotify,
+    CirculateRequest,
+    ClientMessage,
+    ColormapNotify,
+    ConfigureNotify,
+    ConfigureRequest,
+    CreateNotify,
+    DestroyNotify,
+    EnterNotify,
+    Expose,
+    FocusIn,
+    FocusOut,
+    GeGeneric,
+    GraphicsExposure,
+    GravityNotify,
+    KeyPress,
+    KeyRelease,
+    KeymapNotify,
+    LeaveNotify,
+    MapNotify,
+    MapRequest,
+    MappingNotify,
+    MotionNotify,
+    NoExposure,
+    PropertyNotify,
+    ReparentNotify,
+    ResizeRequest,
+    SelectionClear,
+    SelectionNotify,
+    SelectionRequest,
+    UnmapNotify,
+    VisibilityNotify,
+    #[cfg(feature = "damage")]
+    DamageNotify,
+    #[cfg(feature = "dpms")]
+    DpmsInfoNotify,
+    #[cfg(feature = "dri2")]
+    Dri2BufferSwapComplete,
+    #[cfg(feature = "dri2")]
+    Dri2InvalidateBuffers,
+    #[cfg(feature = "glx")]
+    GlxBufferSwapComplete,
+    #[cfg(feature = "glx")]
+    GlxPbufferClobber,
+    #[cfg(feature = "present")]
+    PresentCompleteNotify,
+    #[cfg(feature = "present")]
+    PresentConfigureNotify,
+    #[cfg(feature = "present")]
+    PresentGeneric,
+    #[cfg(feature = "present")]
+    PresentIdleNotify,
+    #[cfg(feature = "present")]
+    PresentRedirectNotify,
+    #[cfg(feature = "randr")]
+    RandrNotify,
+    #[cfg(feature = "randr")]
+    RandrScreenChangeNotify,
+    #[cfg(feature = "screensaver")]
+    ScreensaverNotify,
+    #[cfg(feature = "shape")]
+    ShapeNotify,
+    #[cfg(feature = "shm")]
+    ShmCompletion,
+    #[cfg(feature = "sync")]
+    SyncAlarmNotify,
+    #[cfg(feature = "sync")]
+    SyncCounterNotify,
+    #[cfg(feature = "xfixes")]
+    XfixesCursorNotify,
+    #[cfg(feature = "xfixes")]
+    XfixesSelectionNotify,
+    #[cfg(feature = "xinput")]
+    XinputBarrierHit,
+    #[cfg(feature = "xinput")]
+    XinputBarrierLeave,
+    #[cfg(feature = "xinput")]
+    XinputButtonPress,
+    #[cfg(feature = "xinput")]
+    XinputButtonRelease,
+    #[cfg(feature = "xinput")]
+    XinputChangeDeviceNotify,
+    #[cfg(feature = "xinput")]
+    XinputDeviceButtonPress,
+    #[cfg(feature = "xinput")]
+    XinputDeviceButtonRelease,
+    #[cfg(feature = "xinput")]
+    XinputDeviceButtonStateNotify,
+    #[cfg(feature = "xinput")]
+    XinputDeviceChanged,
+    #[cfg(feature = "xinput")]
+    XinputDeviceFocusIn,
+    #[cfg(feature = "xinput")]
+    XinputDeviceFocusOut,
+    #[cfg(feature = "xinput")]
+    XinputDeviceKeyPress,
+    #[cfg(feature = "xinput")]
+    XinputDeviceKeyRelease,
+    #[cfg(feature = "xinput")]
+    XinputDeviceKeyStateNotify,
+    #[cfg(feature = "xinput")]
+    XinputDeviceMappingNotify,
+    #[cfg(feature = "xinput")]
+    XinputDeviceMotionNotify,
+    #[cfg(feature = "xinput")]
+    XinputDevicePresenceNotify,
+    #[cfg(feature = "xinput")]
+    XinputDevicePropertyNotify,
+    #[cfg(feature = "xinput")]
+    XinputDeviceStateNotify,
+    #[cfg(feature = "xinput")]
+    XinputDeviceValuator,
+    #[cfg(feature = "xinput")]
+    XinputEnter,
+    #[cfg(feature = "xinput")]
+    XinputFocusIn,
+    #[cfg(feature = "xinput")]
+    XinputFocusOut,
+    #[cfg(feature = "xinput")]
+    XinputGesturePinchBegin,
+    #[cfg(feature = "xinput")]
+    XinputGesturePinchEnd,
+    #[cfg(feature = "xinput")]
+    XinputGesturePinchUpdate,
+    #[cfg(feature = "xinput")]
+    XinputGestureSwipeBegin,
+    #[cfg(feature = "xinput")]
+    XinputGestureSwipeEnd,
+    #[cfg(feature = "xinput")]
+    XinputGestureSwipeUpdate,
+    #[cfg(feature = "xinput")]
+    XinputHierarchy,
+    #[cfg(feature = "xinput")]
+    XinputKeyPress,
+    #[cfg(feature = "xinput")]
+    XinputKeyRelease,
+    #[cfg(feature = "xinput")]
+    XinputLeave,
+    #[cfg(feature = "xinput")]
+    XinputMotion,
+    #[cfg(feature = "xinput")]
+    XinputProperty,
+    #[cfg(feature = "xinput")]
+    XinputProximityIn,
+    #[cfg(feature = "xinput")]
+    XinputProximityOut,
+    #[cfg(feature = "xinput")]
+    XinputRawButtonPress,
+    #[cfg(feature = "xinput")]
+    XinputRawButtonRelease,
+    #[cfg(feature = "xinput")]
+    XinputRawKeyPress,
+    #[cfg(feature = "xinput")]
+    XinputRawKeyRelease,
+    #[cfg(feature = "xinput")]
+    XinputRawMotion,
+    #[cfg(feature = "xinput")]
+    XinputRawTouchBegin,
+    #[cfg(feature = "xinput")]
+    XinputRawTouchEnd,
+    #[cfg(feature = "xinput")]
+    XinputRawTouchUpdate,
+    #[cfg(feature = "xinput")]
+    XinputTouchBegin,
+    #[cfg(feature = "xinput")]
+    XinputTouchEnd,
+    #[cfg(feature = "xinput")]
+    XinputTouchOwnership,
+    #[cfg(feature = "xinput")]
+    XinputTouchUpdate,
+    #[cfg(feature = "xkb")]
+    XkbAccessXNotify,
+    #[cfg(feature = "xkb")]
+    XkbActionMessage,
+    #[cfg(feature = "xkb")]
+    XkbBellNotify,
+    #[cfg(feature = "xkb")]
+    XkbCompatMapNotify,
+    #[cfg(feature = "xkb")]
+    XkbControlsNotify,
+    #[cfg(feature = "xkb")]
+    XkbExtensionDeviceNotify,
+    #[cfg(feature = "xkb")]
+    XkbIndicatorMapNotify,
+    #[cfg(feature = "xkb")]
+    XkbIndicatorStateNotify,
+    #[cfg(feature = "xkb")]
+    XkbMapNotify,
+    #[cfg(feature = "xkb")]
+    XkbNamesNotify,
+    #[cfg(feature = "xkb")]
+    XkbNewKeyboardNotify,
+    #[cfg(feature = "xkb")]
+    XkbStateNotify,
+    #[cfg(feature = "xprint")]
+    XprintAttributNotify,
+    #[cfg(feature = "xprint")]
+    XprintNotify,
+    #[cfg(feature = "xv")]
+    XvPortNotify,
+    #[cfg(feature = "xv")]
+    XvVideoNotify,
+}
+
 impl Event {
     /// Parse a generic X11 event into a concrete event type.
     #[allow(clippy::cognitive_complexity, clippy::match_single_binding)]
@@ -9045,251 +9886,4288 @@ impl Event {
         }
     }
 
-    /// Get the raw response type of this X11 event
-    ///
-    /// Response types have seven bits in X11. The eight bit indicates whether
-    /// the packet was generated through the `SendEvent` request. This function
-    /// returns all eight bits.
+    /// Get the name of this X11 event
     ///
-    /// See also the `response_type()`, `server_generated()` and `sent_event()` methods.
-    pub fn raw_response_type(&self) -> u8 {
+    /// Events from extensions are prefixed with the name of the extension, e.g. `RandR::Notify`.
+    pub fn name(&self) -> &'static str {
         match self {
-            Event::Unknown(value) => response_type(value).unwrap(),
-            Event::Error(_) => 0,
-            Event::ButtonPress(value) => value.response_type,
-            Event::ButtonRelease(value) => value.response_type,
-            Event::CirculateNotify(value) => value.response_type,
-            Event::CirculateRequest(value) => value.response_type,
-            Event::ClientMessage(value) => value.response_type,
-            Event::ColormapNotify(value) => value.response_type,
-            Event::ConfigureNotify(value) => value.response_type,
-            Event::ConfigureRequest(value) => value.response_type,
-            Event::CreateNotify(value) => value.response_type,
-            Event::DestroyNotify(value) => value.response_type,
-            Event::EnterNotify(value) => value.response_type,
-            Event::Expose(value) => value.response_type,
-            Event::FocusIn(value) => value.response_type,
-            Event::FocusOut(value) => value.response_type,
-            Event::GeGeneric(value) => value.response_type,
-            Event::GraphicsExposure(value) => value.response_type,
-            Event::GravityNotify(value) => value.response_type,
-            Event::KeyPress(value) => value.response_type,
-            Event::KeyRelease(value) => value.response_type,
-            Event::KeymapNotify(value) => value.response_type,
-            Event::LeaveNotify(value) => value.response_type,
-            Event::MapNotify(value) => value.response_type,
-            Event::MapRequest(value) => value.response_type,
-            Event::MappingNotify(value) => value.response_type,
-            Event::MotionNotify(value) => value.response_type,
-            Event::NoExposure(value) => value.response_type,
-            Event::PropertyNotify(value) => value.response_type,
-            Event::ReparentNotify(value) => value.response_type,
-            Event::ResizeRequest(value) => value.response_type,
-            Event::SelectionClear(value) => value.response_type,
-            Event::SelectionNotify(value) => value.response_type,
-            Event::SelectionRequest(value) => value.response_type,
-            Event::UnmapNotify(value) => value.response_type,
-            Event::VisibilityNotify(value) => value.response_type,
+            Event::Unknown(_) => "Unknown",
+            Event::Error(_) => "Error",
+            Event::ButtonPress(_) => "ButtonPress",
+            Event::ButtonRelease(_) => "ButtonRelease",
+            Event::CirculateNotify(_) => "CirculateNotify",
+            Event::CirculateRequest(_) => "CirculateRequest",
+            Event::ClientMessage(_) => "ClientMessage",
+            Event::ColormapNotify(_) => "ColormapNotify",
+            Event::ConfigureNotify(_) => "ConfigureNotify",
+            Event::ConfigureRequest(_) => "ConfigureRequest",
+            Event::CreateNotify(_) => "CreateNotify",
+            Event::DestroyNotify(_) => "DestroyNotify",
+            Event::EnterNotify(_) => "EnterNotify",
+            Event::Expose(_) => "Expose",
+            Event::FocusIn(_) => "FocusIn",
+            Event::FocusOut(_) => "FocusOut",
+            Event::GeGeneric(_) => "GeGeneric",
+            Event::GraphicsExposure(_) => "GraphicsExposure",
+            Event::GravityNotify(_) => "GravityNotify",
+            Event::KeyPress(_) => "KeyPress",
+            Event::KeyRelease(_) => "KeyRelease",
+            Event::KeymapNotify(_) => "KeymapNotify",
+            Event::LeaveNotify(_) => "LeaveNotify",
+            Event::MapNotify(_) => "MapNotify",
+            Event::MapRequest(_) => "MapRequest",
+            Event::MappingNotify(_) => "MappingNotify",
+            Event::MotionNotify(_) => "MotionNotify",
+            Event::NoExposure(_) => "NoExposure",
+            Event::PropertyNotify(_) => "PropertyNotify",
+            Event::ReparentNotify(_) => "ReparentNotify",
+            Event::ResizeRequest(_) => "ResizeRequest",
+            Event::SelectionClear(_) => "SelectionClear",
+            Event::SelectionNotify(_) => "SelectionNotify",
+            Event::SelectionRequest(_) => "SelectionRequest",
+            Event::UnmapNotify(_) => "UnmapNotify",
+            Event::VisibilityNotify(_) => "VisibilityNotify",
             #[cfg(feature = "damage")]
-            Event::DamageNotify(value) => value.response_type,
+            Event::DamageNotify(_) => "Damage::Notify",
             #[cfg(feature = "dpms")]
-            Event::DpmsInfoNotify(value) => value.response_type,
+            Event::DpmsInfoNotify(_) => "DPMS::InfoNotify",
             #[cfg(feature = "dri2")]
-            Event::Dri2BufferSwapComplete(value) => value.response_type,
+            Event::Dri2BufferSwapComplete(_) => "DRI2::BufferSwapComplete",
             #[cfg(feature = "dri2")]
-            Event::Dri2InvalidateBuffers(value) => value.response_type,
+            Event::Dri2InvalidateBuffers(_) => "DRI2::InvalidateBuffers",
             #[cfg(feature = "glx")]
-            Event::GlxBufferSwapComplete(value) => value.response_type,
+            Event::GlxBufferSwapComplete(_) => "Glx::BufferSwapComplete",
             #[cfg(feature = "glx")]
-            Event::GlxPbufferClobber(value) => value.response_type,
+            Event::GlxPbufferClobber(_) => "Glx::PbufferClobber",
             #[cfg(feature = "present")]
-            Event::PresentCompleteNotify(value) => value.response_type,
+            Event::PresentCompleteNotify(_) => "Present::CompleteNotify",
             #[cfg(feature = "present")]
-            Event::PresentConfigureNotify(value) => value.response_type,
+            Event::PresentConfigureNotify(_) => "Present::ConfigureNotify",
             #[cfg(feature = "present")]
-            Event::PresentGeneric(value) => value.response_type,
+            Event::PresentGeneric(_) => "Present::Generic",
             #[cfg(feature = "present")]
-            Event::PresentIdleNotify(value) => value.response_type,
+            Event::PresentIdleNotify(_) => "Present::IdleNotify",
             #[cfg(feature = "present")]
-            Event::PresentRedirectNotify(value) => value.response_type,
+            Event::PresentRedirectNotify(_) => "Present::RedirectNotify",
             #[cfg(feature = "randr")]
-            Event::RandrNotify(value) => value.response_type,
+            Event::RandrNotify(_) => "RandR::Notify",
             #[cfg(feature = "randr")]
-            Event::RandrScreenChangeNotify(value) => value.response_type,
+            Event::RandrScreenChangeNotify(_) => "RandR::ScreenChangeNotify",
             #[cfg(feature = "screensaver")]
-            Event::ScreensaverNotify(value) => value.response_type,
+            Event::ScreensaverNotify(_) => "ScreenSaver::Notify",
             #[cfg(feature = "shape")]
-            Event::ShapeNotify(value) => value.response_type,
+            Event::ShapeNotify(_) => "Shape::Notify",
             #[cfg(feature = "shm")]
-            Event::ShmCompletion(value) => value.response_type,
+            Event::ShmCompletion(_) => "Shm::Completion",
             #[cfg(feature = "sync")]
-            Event::SyncAlarmNotify(value) => value.response_type,
+            Event::SyncAlarmNotify(_) => "Sync::AlarmNotify",
             #[cfg(feature = "sync")]
-            Event::SyncCounterNotify(value) => value.response_type,
+            Event::SyncCounterNotify(_) => "Sync::CounterNotify",
             #[cfg(feature = "xfixes")]
-            Event::XfixesCursorNotify(value) => value.response_type,
+            Event::XfixesCursorNotify(_) => "XFixes::CursorNotify",
             #[cfg(feature = "xfixes")]
-            Event::XfixesSelectionNotify(value) => value.response_type,
+            Event::XfixesSelectionNotify(_) => "XFixes::SelectionNotify",
             #[cfg(feature = "xinput")]
-            Event::XinputBarrierHit(value) => value.response_type,
+            Event::XinputBarrierHit(_) => "Input::BarrierHit",
             #[cfg(feature = "xinput")]
-            Event::XinputBarrierLeave(value) => value.response_type,
+            Event::XinputBarrierLeave(_) => "Input::BarrierLeave",
             #[cfg(feature = "xinput")]
-            Event::XinputButtonPress(value) => value.response_type,
+            Event::XinputButtonPress(_) => "Input::ButtonPress",
             #[cfg(feature = "xinput")]
-            Event::XinputButtonRelease(value) => value.response_type,
+            Event::XinputButtonRelease(_) => "Input::ButtonRelease",
             #[cfg(feature = "xinput")]
-            Event::XinputChangeDeviceNotify(value) => value.response_type,
+            Event::XinputChangeDeviceNotify(_) => "Input::ChangeDeviceNotify",
             #[cfg(feature = "xinput")]
-            Event::XinputDeviceButtonPress(value) => value.response_type,
+            Event::XinputDeviceButtonPress(_) => "Input::DeviceButtonPress",
             #[cfg(feature = "xinput")]
-            Event::XinputDeviceButtonRelease(value) => value.response_type,
+            Event::XinputDeviceButtonRelease(_) => "Input::DeviceButtonRelease",
             #[cfg(feature = "xinput")]
-            Event::XinputDeviceButtonStateNotify(value) => value.response_type,
+            Event::XinputDeviceButtonStateNotify(_) => "Input::DeviceButtonStateNotify",
             #[cfg(feature = "xinput")]
-            Event::XinputDeviceChanged(value) => value.response_type,
+            Event::XinputDeviceChanged(_) => "Input::DeviceChanged",
             #[cfg(feature = "xinput")]
-            Event::XinputDeviceFocusIn(value) => value.response_type,
+            Event::XinputDeviceFocusIn(_) => "Input::DeviceFocusIn",
             #[cfg(feature = "xinput")]
-            Event::XinputDeviceFocusOut(value) => value.response_type,
+            Event::XinputDeviceFocusOut(_) => "Input::DeviceFocusOut",
             #[cfg(feature = "xinput")]
-            Event::XinputDeviceKeyPress(value) => value.response_type,
+            Event::XinputDeviceKeyPress(_) => "Input::DeviceKeyPress",
             #[cfg(feature = "xinput")]
-            Event::XinputDeviceKeyRelease(value) => value.response_type,
+            Event::XinputDeviceKeyRelease(_) => "Input::DeviceKeyRelease",
             #[cfg(feature = "xinput")]
-            Event::XinputDeviceKeyStateNotify(value) => value.response_type,
+            Event::XinputDeviceKeyStateNotify(_) => "Input::DeviceKeyStateNotify",
             #[cfg(feature = "xinput")]
-            Event::XinputDeviceMappingNotify(value) => value.response_type,
+            Event::XinputDeviceMappingNotify(_) => "Input::DeviceMappingNotify",
             #[cfg(feature = "xinput")]
-            Event::XinputDeviceMotionNotify(value) => value.response_type,
+            Event::XinputDeviceMotionNotify(_) => "Input::DeviceMotionNotify",
             #[cfg(feature = "xinput")]
-            Event::XinputDevicePresenceNotify(value) => value.response_type,
+            Event::XinputDevicePresenceNotify(_) => "Input::DevicePresenceNotify",
             #[cfg(feature = "xinput")]
-            Event::XinputDevicePropertyNotify(value) => value.response_type,
+            Event::XinputDevicePropertyNotify(_) => "Input::DevicePropertyNotify",
             #[cfg(feature = "xinput")]
-            Event::XinputDeviceStateNotify(value) => value.response_type,
+            Event::XinputDeviceStateNotify(_) => "Input::DeviceStateNotify",
             #[cfg(feature = "xinput")]
-            Event::XinputDeviceValuator(value) => value.response_type,
+            Event::XinputDeviceValuator(_) => "Input::DeviceValuator",
             #[cfg(feature = "xinput")]
-            Event::XinputEnter(value) => value.response_type,
+            Event::XinputEnter(_) => "Input::Enter",
             #[cfg(feature = "xinput")]
-            Event::XinputFocusIn(value) => value.response_type,
+            Event::XinputFocusIn(_) => "Input::FocusIn",
             #[cfg(feature = "xinput")]
-            Event::XinputFocusOut(value) => value.response_type,
+            Event::XinputFocusOut(_) => "Input::FocusOut",
             #[cfg(feature = "xinput")]
-            Event::XinputGesturePinchBegin(value) => value.response_type,
+            Event::XinputGesturePinchBegin(_) => "Input::GesturePinchBegin",
             #[cfg(feature = "xinput")]
-            Event::XinputGesturePinchEnd(value) => value.response_type,
+            Event::XinputGesturePinchEnd(_) => "Input::GesturePinchEnd",
             #[cfg(feature = "xinput")]
-            Event::XinputGesturePinchUpdate(value) => value.response_type,
+            Event::XinputGesturePinchUpdate(_) => "Input::GesturePinchUpdate",
             #[cfg(feature = "xinput")]
-            Event::XinputGestureSwipeBegin(value) => value.response_type,
+            Event::XinputGestureSwipeBegin(_) => "Input::GestureSwipeBegin",
             #[cfg(feature = "xinput")]
-            Event::XinputGestureSwipeEnd(value) => value.response_type,
+            Event::XinputGestureSwipeEnd(_) => "Input::GestureSwipeEnd",
             #[cfg(feature = "xinput")]
-            Event::XinputGestureSwipeUpdate(value) => value.response_type,
+            Event::XinputGestureSwipeUpdate(_) => "Input::GestureSwipeUpdate",
             #[cfg(feature = "xinput")]
-            Event::XinputHierarchy(value) => value.response_type,
+            Event::XinputHierarchy(_) => "Input::Hierarchy",
             #[cfg(feature = "xinput")]
-            Event::XinputKeyPress(value) => value.response_type,
+            Event::XinputKeyPress(_) => "Input::KeyPress",
             #[cfg(feature = "xinput")]
-            Event::XinputKeyRelease(value) => value.response_type,
+            Event::XinputKeyRelease(_) => "Input::KeyRelease",
             #[cfg(feature = "xinput")]
-            Event::XinputLeave(value) => value.response_type,
+            Event::XinputLeave(_) => "Input::Leave",
             #[cfg(feature = "xinput")]
-            Event::XinputMotion(value) => value.response_type,
+            Event::XinputMotion(_) => "Input::Motion",
             #[cfg(feature = "xinput")]
-            Event::XinputProperty(value) => value.response_type,
+            Event::XinputProperty(_) => "Input::Property",
             #[cfg(feature = "xinput")]
-            Event::XinputProximityIn(value) => value.response_type,
+            Event::XinputProximityIn(_) => "Input::ProximityIn",
             #[cfg(feature = "xinput")]
-            Event::XinputProximityOut(value) => value.response_type,
+            Event::XinputProximityOut(_) => "Input::ProximityOut",
             #[cfg(feature = "xinput")]
-            Event::XinputRawButtonPress(value) => value.response_type,
+            Event::XinputRawButtonPress(_) => "Input::RawButtonPress",
             #[cfg(feature = "xinput")]
-            Event::XinputRawButtonRelease(value) => value.response_type,
+            Event::XinputRawButtonRelease(_) => "Input::RawButtonRelease",
             #[cfg(feature = "xinput")]
-            Event::XinputRawKeyPress(value) => value.response_type,
+            Event::XinputRawKeyPress(_) => "Input::RawKeyPress",
             #[cfg(feature = "xinput")]
-            Event::XinputRawKeyRelease(value) => value.response_type,
+            Event::XinputRawKeyRelease(_) => "Input::RawKeyRelease",
             #[cfg(feature = "xinput")]
-            Event::XinputRawMotion(value) => value.response_type,
+            Event::XinputRawMotion(_) => "Input::RawMotion",
             #[cfg(feature = "xinput")]
-            Event::XinputRawTouchBegin(value) => value.response_type,
+            Event::XinputRawTouchBegin(_) => "Input::RawTouchBegin",
             #[cfg(feature = "xinput")]
-            Event::XinputRawTouchEnd(value) => value.response_type,
+            Event::XinputRawTouchEnd(_) => "Input::RawTouchEnd",
             #[cfg(feature = "xinput")]
-            Event::XinputRawTouchUpdate(value) => value.response_type,
+            Event::XinputRawTouchUpdate(_) => "Input::RawTouchUpdate",
             #[cfg(feature = "xinput")]
-            Event::XinputTouchBegin(value) => value.response_type,
+            Event::XinputTouchBegin(_) => "Input::TouchBegin",
             #[cfg(feature = "xinput")]
-            Event::XinputTouchEnd(value) => value.response_type,
+            Event::XinputTouchEnd(_) => "Input::TouchEnd",
             #[cfg(feature = "xinput")]
-            Event::XinputTouchOwnership(value) => value.response_type,
+            Event::XinputTouchOwnership(_) => "Input::TouchOwnership",
             #[cfg(feature = "xinput")]
-            Event::XinputTouchUpdate(value) => value.response_type,
+            Event::XinputTouchUpdate(_) => "Input::TouchUpdate",
             #[cfg(feature = "xkb")]
-            Event::XkbAccessXNotify(value) => value.response_type,
+            Event::XkbAccessXNotify(_) => "xkb::AccessXNotify",
             #[cfg(feature = "xkb")]
-            Event::XkbActionMessage(value) => value.response_type,
+            Event::XkbActionMessage(_) => "xkb::ActionMessage",
             #[cfg(feature = "xkb")]
-            Event::XkbBellNotify(value) => value.response_type,
+            Event::XkbBellNotify(_) => "xkb::BellNotify",
             #[cfg(feature = "xkb")]
-            Event::XkbCompatMapNotify(value) => value.response_type,
+            Event::XkbCompatMapNotify(_) => "xkb::CompatMapNotify",
             #[cfg(feature = "xkb")]
-            Event::XkbControlsNotify(value) => value.response_type,
+            Event::XkbControlsNotify(_) => "xkb::ControlsNotify",
             #[cfg(feature = "xkb")]
-            Event::XkbExtensionDeviceNotify(value) => value.response_type,
+            Event::XkbExtensionDeviceNotify(_) => "xkb::ExtensionDeviceNotify",
             #[cfg(feature = "xkb")]
-            Event::XkbIndicatorMapNotify(value) => value.response_type,
+            Event::XkbIndicatorMapNotify(_) => "xkb::IndicatorMapNotify",
             #[cfg(feature = "xkb")]
-            Event::XkbIndicatorStateNotify(value) => value.response_type,
+            Event::XkbIndicatorStateNotify(_) => "xkb::IndicatorStateNotify",
             #[cfg(feature = "xkb")]
-            Event::XkbMapNotify(value) => value.response_type,
+            Event::XkbMapNotify(_) => "xkb::MapNotify",
             #[cfg(feature = "xkb")]
-            Event::XkbNamesNotify(value) => value.response_type,
+            Event::XkbNamesNotify(_) => "xkb::NamesNotify",
             #[cfg(feature = "xkb")]
-            Event::XkbNewKeyboardNotify(value) => value.response_type,
+            Event::XkbNewKeyboardNotify(_) => "xkb::NewKeyboardNotify",
             #[cfg(feature = "xkb")]
-            Event::XkbStateNotify(value) => value.response_type,
+            Event::XkbStateNotify(_) => "xkb::StateNotify",
             #[cfg(feature = "xprint")]
-            Event::XprintAttributNotify(value) => value.response_type,
+            Event::XprintAttributNotify(_) => "XPrint::AttributNotify",
             #[cfg(feature = "xprint")]
-            Event::XprintNotify(value) => value.response_type,
+            Event::XprintNotify(_) => "XPrint::Notify",
             #[cfg(feature = "xv")]
-            Event::XvPortNotify(value) => value.response_type,
+            Event::XvPortNotify(_) => "Xv::PortNotify",
             #[cfg(feature = "xv")]
-            Event::XvVideoNotify(value) => value.response_type,
+            Event::XvVideoNotify(_) => "Xv::VideoNotify",
         }
     }
 
-    /// Get the response type of this X11 event
-    pub fn response_type(&self) -> u8 {
-        self.raw_response_type() & 0x7f
+    /// Get the kind of this X11 event
+    pub fn kind(&self) -> EventKind {
+        match self {
+            Event::Unknown(_) => EventKind::Unknown,
+            Event::Error(_) => EventKind::Error,
+            Event::ButtonPress(_) => EventKind::ButtonPress,
+            Event::ButtonRelease(_) => EventKind::ButtonRelease,
+            Event::CirculateNotify(_) => EventKind::CirculateNotify,
+            Event::CirculateRequest(_) => EventKind::CirculateRequest,
+            Event::ClientMessage(_) => EventKind::ClientMessage,
+            Event::ColormapNotify(_) => EventKind::ColormapNotify,
+            Event::ConfigureNotify(_) => EventKind::ConfigureNotify,
+            Event::ConfigureRequest(_) => EventKind::ConfigureRequest,
+            Event::CreateNotify(_) => EventKind::CreateNotify,
+            Event::DestroyNotify(_) => EventKind::DestroyNotify,
+            Event::EnterNotify(_) => EventKind::EnterNotify,
+            Event::Expose(_) => EventKind::Expose,
+            Event::FocusIn(_) => EventKind::FocusIn,
+            Event::FocusOut(_) => EventKind::FocusOut,
+            Event::GeGeneric(_) => EventKind::GeGeneric,
+            Event::GraphicsExposure(_) => EventKind::GraphicsExposure,
+            Event::GravityNotify(_) => EventKind::GravityNotify,
+            Event::KeyPress(_) => EventKind::KeyPress,
+            Event::KeyRelease(_) => EventKind::KeyRelease,
+            Event::KeymapNotify(_) => EventKind::KeymapNotify,
+            Event::LeaveNotify(_) => EventKind::LeaveNotify,
+            Event::MapNotify(_) => EventKind::MapNotify,
+            Event::MapRequest(_) => EventKind::MapRequest,
+            Event::MappingNotify(_) => EventKind::MappingNotify,
+            Event::MotionNotify(_) => EventKind::MotionNotify,
+            Event::NoExposure(_) => EventKind::NoExposure,
+            Event::PropertyNotify(_) => EventKind::PropertyNotify,
+            Event::ReparentNotify(_) => EventKind::ReparentNotify,
+            Event::ResizeRequest(_) => EventKind::ResizeRequest,
+            Event::SelectionClear(_) => EventKind::SelectionClear,
+            Event::SelectionNotify(_) => EventKind::SelectionNotify,
+            Event::SelectionRequest(_) => EventKind::SelectionRequest,
+            Event::UnmapNotify(_) => EventKind::UnmapNotify,
+            Event::VisibilityNotify(_) => EventKind::VisibilityNotify,
+            #[cfg(feature = "damage")]
+            Event::DamageNotify(_) => EventKind::DamageNotify,
+            #[cfg(feature = "dpms")]
+            Event::DpmsInfoNotify(_) => EventKind::DpmsInfoNotify,
+            #[cfg(feature = "dri2")]
+            Event::Dri2BufferSwapComplete(_) => EventKind::Dri2BufferSwapComplete,
+            #[cfg(feature = "dri2")]
+            Event::Dri2InvalidateBuffers(_) => EventKind::Dri2InvalidateBuffers,
+            #[cfg(feature = "glx")]
+            Event::GlxBufferSwapComplete(_) => EventKind::GlxBufferSwapComplete,
+            #[cfg(feature = "glx")]
+            Event::GlxPbufferClobber(_) => EventKind::GlxPbufferClobber,
+            #[cfg(feature = "present")]
+            Event::PresentCompleteNotify(_) => EventKind::PresentCompleteNotify,
+            #[cfg(feature = "present")]
+            Event::PresentConfigureNotify(_) => EventKind::PresentConfigureNotify,
+            #[cfg(feature = "present")]
+            Event::PresentGeneric(_) => EventKind::PresentGeneric,
+            #[cfg(feature = "present")]
+            Event::PresentIdleNotify(_) => EventKind::PresentIdleNotify,
+            #[cfg(feature = "present")]
+            Event::PresentRedirectNotify(_) => EventKind::PresentRedirectNotify,
+            #[cfg(feature = "randr")]
+            Event::RandrNotify(_) => EventKind::RandrNotify,
+            #[cfg(feature = "randr")]
+            Event::RandrScreenChangeNotify(_) => EventKind::RandrScreenChangeNotify,
+            #[cfg(feature = "screensaver")]
+            Event::ScreensaverNotify(_) => EventKind::ScreensaverNotify,
+            #[cfg(feature = "shape")]
+            Event::ShapeNotify(_) => EventKind::ShapeNotify,
+            #[cfg(feature = "shm")]
+            Event::ShmCompletion(_) => EventKind::ShmCompletion,
+            #[cfg(feature = "sync")]
+            Event::SyncAlarmNotify(_) => EventKind::SyncAlarmNotify,
+            #[cfg(feature = "sync")]
+            Event::SyncCounterNotify(_) => EventKind::SyncCounterNotify,
+            #[cfg(feature = "xfixes")]
+            Event::XfixesCursorNotify(_) => EventKind::XfixesCursorNotify,
+            #[cfg(feature = "xfixes")]
+            Event::XfixesSelectionNotify(_) => EventKind::XfixesSelectionNotify,
+            #[cfg(feature = "xinput")]
+            Event::XinputBarrierHit(_) => EventKind::XinputBarrierHit,
+            #[cfg(feature = "xinput")]
+            Event::XinputBarrierLeave(_) => EventKind::XinputBarrierLeave,
+            #[cfg(feature = "xinput")]
+            Event::XinputButtonPress(_) => EventKind::XinputButtonPress,
+            #[cfg(feature = "xinput")]
+            Event::XinputButtonRelease(_) => EventKind::XinputButtonRelease,
+            #[cfg(feature = "xinput")]
+            Event::XinputChangeDeviceNotify(_) => EventKind::XinputChangeDeviceNotify,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonPress(_) => EventKind::XinputDeviceButtonPress,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonRelease(_) => EventKind::XinputDeviceButtonRelease,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonStateNotify(_) => EventKind::XinputDeviceButtonStateNotify,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceChanged(_) => EventKind::XinputDeviceChanged,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceFocusIn(_) => EventKind::XinputDeviceFocusIn,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceFocusOut(_) => EventKind::XinputDeviceFocusOut,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyPress(_) => EventKind::XinputDeviceKeyPress,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyRelease(_) => EventKind::XinputDeviceKeyRelease,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyStateNotify(_) => EventKind::XinputDeviceKeyStateNotify,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceMappingNotify(_) => EventKind::XinputDeviceMappingNotify,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceMotionNotify(_) => EventKind::XinputDeviceMotionNotify,
+            #[cfg(feature = "xinput")]
+            Event::XinputDevicePresenceNotify(_) => EventKind::XinputDevicePresenceNotify,
+            #[cfg(feature = "xinput")]
+            Event::XinputDevicePropertyNotify(_) => EventKind::XinputDevicePropertyNotify,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceStateNotify(_) => EventKind::XinputDeviceStateNotify,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceValuator(_) => EventKind::XinputDeviceValuator,
+            #[cfg(feature = "xinput")]
+            Event::XinputEnter(_) => EventKind::XinputEnter,
+            #[cfg(feature = "xinput")]
+            Event::XinputFocusIn(_) => EventKind::XinputFocusIn,
+            #[cfg(feature = "xinput")]
+            Event::XinputFocusOut(_) => EventKind::XinputFocusOut,
+            #[cfg(feature = "xinput")]
+            Event::XinputGesturePinchBegin(_) => EventKind::XinputGesturePinchBegin,
+            #[cfg(feature = "xinput")]
+            Event::XinputGesturePinchEnd(_) => EventKind::XinputGesturePinchEnd,
+            #[cfg(feature = "xinput")]
+            Event::XinputGesturePinchUpdate(_) => EventKind::XinputGesturePinchUpdate,
+            #[cfg(feature = "xinput")]
+            Event::XinputGestureSwipeBegin(_) => EventKind::XinputGestureSwipeBegin,
+            #[cfg(feature = "xinput")]
+            Event::XinputGestureSwipeEnd(_) => EventKind::XinputGestureSwipeEnd,
+            #[cfg(feature = "xinput")]
+            Event::XinputGestureSwipeUpdate(_) => EventKind::XinputGestureSwipeUpdate,
+            #[cfg(feature = "xinput")]
+            Event::XinputHierarchy(_) => EventKind::XinputHierarchy,
+            #[cfg(feature = "xinput")]
+            Event::XinputKeyPress(_) => EventKind::XinputKeyPress,
+            #[cfg(feature = "xinput")]
+            Event::XinputKeyRelease(_) => EventKind::XinputKeyRelease,
+            #[cfg(feature = "xinput")]
+            Event::XinputLeave(_) => EventKind::XinputLeave,
+            #[cfg(feature = "xinput")]
+            Event::XinputMotion(_) => EventKind::XinputMotion,
+            #[cfg(feature = "xinput")]
+            Event::XinputProperty(_) => EventKind::XinputProperty,
+            #[cfg(feature = "xinput")]
+            Event::XinputProximityIn(_) => EventKind::XinputProximityIn,
+            #[cfg(feature = "xinput")]
+            Event::XinputProximityOut(_) => EventKind::XinputProximityOut,
+            #[cfg(feature = "xinput")]
+            Event::XinputRawButtonPress(_) => EventKind::XinputRawButtonPress,
+            #[cfg(feature = "xinput")]
+            Event::XinputRawButtonRelease(_) => EventKind::XinputRawButtonRelease,
+            #[cfg(feature = "xinput")]
+            Event::XinputRawKeyPress(_) => EventKind::XinputRawKeyPress,
+            #[cfg(feature = "xinput")]
+            Event::XinputRawKeyRelease(_) => EventKind::XinputRawKeyRelease,
+            #[cfg(feature = "xinput")]
+            Event::XinputRawMotion(_) => EventKind::XinputRawMotion,
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchBegin(_) => EventKind::XinputRawTouchBegin,
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchEnd(_) => EventKind::XinputRawTouchEnd,
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchUpdate(_) => EventKind::XinputRawTouchUpdate,
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchBegin(_) => EventKind::XinputTouchBegin,
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchEnd(_) => EventKind::XinputTouchEnd,
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchOwnership(_) => EventKind::XinputTouchOwnership,
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchUpdate(_) => EventKind::XinputTouchUpdate,
+            #[cfg(feature = "xkb")]
+            Event::XkbAccessXNotify(_) => EventKind::XkbAccessXNotify,
+            #[cfg(feature = "xkb")]
+            Event::XkbActionMessage(_) => EventKind::XkbActionMessage,
+            #[cfg(feature = "xkb")]
+            Event::XkbBellNotify(_) => EventKind::XkbBellNotify,
+            #[cfg(feature = "xkb")]
+            Event::XkbCompatMapNotify(_) => EventKind::XkbCompatMapNotify,
+            #[cfg(feature = "xkb")]
+            Event::XkbControlsNotify(_) => EventKind::XkbControlsNotify,
+            #[cfg(feature = "xkb")]
+            Event::XkbExtensionDeviceNotify(_) => EventKind::XkbExtensionDeviceNotify,
+            #[cfg(feature = "xkb")]
+            Event::XkbIndicatorMapNotify(_) => EventKind::XkbIndicatorMapNotify,
+            #[cfg(feature = "xkb")]
+            Event::XkbIndicatorStateNotify(_) => EventKind::XkbIndicatorStateNotify,
+            #[cfg(feature = "xkb")]
+            Event::XkbMapNotify(_) => EventKind::XkbMapNotify,
+            #[cfg(feature = "xkb")]
+            Event::XkbNamesNotify(_) => EventKind::XkbNamesNotify,
+            #[cfg(feature = "xkb")]
+            Event::XkbNewKeyboardNotify(_) => EventKind::XkbNewKeyboardNotify,
+            #[cfg(feature = "xkb")]
+            Event::XkbStateNotify(_) => EventKind::XkbStateNotify,
+            #[cfg(feature = "xprint")]
+            Event::XprintAttributNotify(_) => EventKind::XprintAttributNotify,
+            #[cfg(feature = "xprint")]
+            Event::XprintNotify(_) => EventKind::XprintNotify,
+            #[cfg(feature = "xv")]
+            Event::XvPortNotify(_) => EventKind::XvPortNotify,
+            #[cfg(feature = "xv")]
+            Event::XvVideoNotify(_) => EventKind::XvVideoNotify,
+        }
     }
 
-    /// Was this event generated by the X11 server?
+    /// Get the number of this X11 event in the definition of its extension
     ///
-    /// If this function returns true, then this event comes from the X11 server.
-    /// Otherwise, it was sent from another client via the `SendEvent` request.
-    pub fn server_generated(&self) -> bool {
-        self.raw_response_type() & 0x80 == 0
+    /// For core events, this is the response type, e.g. [`xproto::EXPOSE_EVENT`]. For
+    /// extension events, this is the number relative to the extension's first event or,
+    /// for generic events, the event type. Errors and unknown events return `None`.
+    pub fn event_code(&self) -> Option<u16> {
+        match self {
+            Event::Unknown(_) | Event::Error(_) => None,
+            Event::ButtonPress(_) => Some(u16::from(xproto::BUTTON_PRESS_EVENT)),
+            Event::ButtonRelease(_) => Some(u16::from(xproto::BUTTON_RELEASE_EVENT)),
+            Event::CirculateNotify(_) => Some(u16::from(xproto::CIRCULATE_NOTIFY_EVENT)),
+            Event::CirculateRequest(_) => Some(u16::from(xproto::CIRCULATE_REQUEST_EVENT)),
+            Event::ClientMessage(_) => Some(u16::from(xproto::CLIENT_MESSAGE_EVENT)),
+            Event::ColormapNotify(_) => Some(u16::from(xproto::COLORMAP_NOTIFY_EVENT)),
+            Event::ConfigureNotify(_) => Some(u16::from(xproto::CONFIGURE_NOTIFY_EVENT)),
+            Event::ConfigureRequest(_) => Some(u16::from(xproto::CONFIGURE_REQUEST_EVENT)),
+            Event::CreateNotify(_) => Some(u16::from(xproto::CREATE_NOTIFY_EVENT)),
+            Event::DestroyNotify(_) => Some(u16::from(xproto::DESTROY_NOTIFY_EVENT)),
+            Event::EnterNotify(_) => Some(u16::from(xproto::ENTER_NOTIFY_EVENT)),
+            Event::Expose(_) => Some(u16::from(xproto::EXPOSE_EVENT)),
+            Event::FocusIn(_) => Some(u16::from(xproto::FOCUS_IN_EVENT)),
+            Event::FocusOut(_) => Some(u16::from(xproto::FOCUS_OUT_EVENT)),
+            Event::GeGeneric(_) => Some(u16::from(xproto::GE_GENERIC_EVENT)),
+            Event::GraphicsExposure(_) => Some(u16::from(xproto::GRAPHICS_EXPOSURE_EVENT)),
+            Event::GravityNotify(_) => Some(u16::from(xproto::GRAVITY_NOTIFY_EVENT)),
+            Event::KeyPress(_) => Some(u16::from(xproto::KEY_PRESS_EVENT)),
+            Event::KeyRelease(_) => Some(u16::from(xproto::KEY_RELEASE_EVENT)),
+            Event::KeymapNotify(_) => Some(u16::from(xproto::KEYMAP_NOTIFY_EVENT)),
+            Event::LeaveNotify(_) => Some(u16::from(xproto::LEAVE_NOTIFY_EVENT)),
+            Event::MapNotify(_) => Some(u16::from(xproto::MAP_NOTIFY_EVENT)),
+            Event::MapRequest(_) => Some(u16::from(xproto::MAP_REQUEST_EVENT)),
+            Event::MappingNotify(_) => Some(u16::from(xproto::MAPPING_NOTIFY_EVENT)),
+            Event::MotionNotify(_) => Some(u16::from(xproto::MOTION_NOTIFY_EVENT)),
+            Event::NoExposure(_) => Some(u16::from(xproto::NO_EXPOSURE_EVENT)),
+            Event::PropertyNotify(_) => Some(u16::from(xproto::PROPERTY_NOTIFY_EVENT)),
+            Event::ReparentNotify(_) => Some(u16::from(xproto::REPARENT_NOTIFY_EVENT)),
+            Event::ResizeRequest(_) => Some(u16::from(xproto::RESIZE_REQUEST_EVENT)),
+            Event::SelectionClear(_) => Some(u16::from(xproto::SELECTION_CLEAR_EVENT)),
+            Event::SelectionNotify(_) => Some(u16::from(xproto::SELECTION_NOTIFY_EVENT)),
+            Event::SelectionRequest(_) => Some(u16::from(xproto::SELECTION_REQUEST_EVENT)),
+            Event::UnmapNotify(_) => Some(u16::from(xproto::UNMAP_NOTIFY_EVENT)),
+            Event::VisibilityNotify(_) => Some(u16::from(xproto::VISIBILITY_NOTIFY_EVENT)),
+            #[cfg(feature = "damage")]
+            Event::DamageNotify(_) => Some(u16::from(damage::NOTIFY_EVENT)),
+            #[cfg(feature = "dpms")]
+            Event::DpmsInfoNotify(_) => Some(dpms::INFO_NOTIFY_EVENT),
+            #[cfg(feature = "dri2")]
+            Event::Dri2BufferSwapComplete(_) => Some(u16::from(dri2::BUFFER_SWAP_COMPLETE_EVENT)),
+            #[cfg(feature = "dri2")]
+            Event::Dri2InvalidateBuffers(_) => Some(u16::from(dri2::INVALIDATE_BUFFERS_EVENT)),
+            #[cfg(feature = "glx")]
+            Event::GlxBufferSwapComplete(_) => Some(u16::from(glx::BUFFER_SWAP_COMPLETE_EVENT)),
+            #[cfg(feature = "glx")]
+            Event::GlxPbufferClobber(_) => Some(u16::from(glx::PBUFFER_CLOBBER_EVENT)),
+            #[cfg(feature = "present")]
+            Event::PresentCompleteNotify(_) => Some(present::COMPLETE_NOTIFY_EVENT),
+            #[cfg(feature = "present")]
+            Event::PresentConfigureNotify(_) => Some(present::CONFIGURE_NOTIFY_EVENT),
+            #[cfg(feature = "present")]
+            Event::PresentGeneric(_) => Some(u16::from(present::GENERIC_EVENT)),
+            #[cfg(feature = "present")]
+            Event::PresentIdleNotify(_) => Some(present::IDLE_NOTIFY_EVENT),
+            #[cfg(feature = "present")]
+            Event::PresentRedirectNotify(_) => Some(present::REDIRECT_NOTIFY_EVENT),
+            #[cfg(feature = "randr")]
+            Event::RandrNotify(_) => Some(u16::from(randr::NOTIFY_EVENT)),
+            #[cfg(feature = "randr")]
+            Event::RandrScreenChangeNotify(_) => Some(u16::from(randr::SCREEN_CHANGE_NOTIFY_EVENT)),
+            #[cfg(feature = "screensaver")]
+            Event::ScreensaverNotify(_) => Some(u16::from(screensaver::NOTIFY_EVENT)),
+            #[cfg(feature = "shape")]
+            Event::ShapeNotify(_) => Some(u16::from(shape::NOTIFY_EVENT)),
+            #[cfg(feature = "shm")]
+            Event::ShmCompletion(_) => Some(u16::from(shm::COMPLETION_EVENT)),
+            #[cfg(feature = "sync")]
+            Event::SyncAlarmNotify(_) => Some(u16::from(sync::ALARM_NOTIFY_EVENT)),
+            #[cfg(feature = "sync")]
+            Event::SyncCounterNotify(_) => Some(u16::from(sync::COUNTER_NOTIFY_EVENT)),
+            #[cfg(feature = "xfixes")]
+            Event::XfixesCursorNotify(_) => Some(u16::from(xfixes::CURSOR_NOTIFY_EVENT)),
+            #[cfg(feature = "xfixes")]
+            Event::XfixesSelectionNotify(_) => Some(u16::from(xfixes::SELECTION_NOTIFY_EVENT)),
+            #[cfg(feature = "xinput")]
+            Event::XinputBarrierHit(_) => Some(xinput::BARRIER_HIT_EVENT),
+            #[cfg(feature = "xinput")]
+            Event::XinputBarrierLeave(_) => Some(xinput::BARRIER_LEAVE_EVENT),
+            #[cfg(feature = "xinput")]
+            Event::XinputButtonPress(_) => Some(xinput::BUTTON_PRESS_EVENT),
+            #[cfg(feature = "xinput")]
+            Event::XinputButtonRelease(_) => Some(xinput::BUTTON_RELEASE_EVENT),
+            #[cfg(feature = "xinput")]
+            Event::XinputChangeDeviceNotify(_) => Some(u16::from(xinput::CHANGE_DEVICE_NOTIFY_EVENT)),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonPress(_) => Some(u16::from(xinput::DEVICE_BUTTON_PRESS_EVENT)),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonRelease(_) => Some(u16::from(xinput::DEVICE_BUTTON_RELEASE_EVENT)),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonStateNotify(_) => Some(u16::from(xinput::DEVICE_BUTTON_STATE_NOTIFY_EVENT)),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceChanged(_) => Some(xinput::DEVICE_CHANGED_EVENT),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceFocusIn(_) => Some(u16::from(xinput::DEVICE_FOCUS_IN_EVENT)),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceFocusOut(_) => Some(u16::from(xinput::DEVICE_FOCUS_OUT_EVENT)),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyPress(_) => Some(u16::from(xinput::DEVICE_KEY_PRESS_EVENT)),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyRelease(_) => Some(u16::from(xinput::DEVICE_KEY_RELEASE_EVENT)),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyStateNotify(_) => Some(u16::from(xinput::DEVICE_KEY_STATE_NOTIFY_EVENT)),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceMappingNotify(_) => Some(u16::from(xinput::DEVICE_MAPPING_NOTIFY_EVENT)),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceMotionNotify(_) => Some(u16::from(xinput::DEVICE_MOTION_NOTIFY_EVENT)),
+            #[cfg(feature = "xinput")]
+            Event::XinputDevicePresenceNotify(_) => Some(u16::from(xinput::DEVICE_PRESENCE_NOTIFY_EVENT)),
+            #[cfg(feature = "xinput")]
+            Event::XinputDevicePropertyNotify(_) => Some(u16::from(xinput::DEVICE_PROPERTY_NOTIFY_EVENT)),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceStateNotify(_) => Some(u16::from(xinput::DEVICE_STATE_NOTIFY_EVENT)),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceValuator(_) => Some(u16::from(xinput::DEVICE_VALUATOR_EVENT)),
+            #[cfg(feature = "xinput")]
+            Event::XinputEnter(_) => Some(xinput::ENTER_EVENT),
+            #[cfg(feature = "xinput")]
+            Event::XinputFocusIn(_) => Some(xinput::FOCUS_IN_EVENT),
+            #[cfg(feature = "xinput")]
+            Event::XinputFocusOut(_) => Some(xinput::FOCUS_OUT_EVENT),
+            #[cfg(feature = "xinput")]
+            Event::XinputGesturePinchBegin(_) => Some(xinput::GESTURE_PINCH_BEGIN_EVENT),
+            #[cfg(feature = "xinput")]
+            Event::XinputGesturePinchEnd(_) => Some(xinput::GESTURE_PINCH_END_EVENT),
+            #[cfg(feature = "xinput")]
+            Event::XinputGesturePinchUpdate(_) => Some(xinput::GESTURE_PINCH_UPDATE_EVENT),
+            #[cfg(feature = "xinput")]
+            Event::XinputGestureSwipeBegin(_) => Some(xinput::GESTURE_SWIPE_BEGIN_EVENT),
+            #[cfg(feature = "xinput")]
+            Event::XinputGestureSwipeEnd(_) => Some(xinput::GESTURE_SWIPE_END_EVENT),
+            #[cfg(feature = "xinput")]
+            Event::XinputGestureSwipeUpdate(_) => Some(xinput::GESTURE_SWIPE_UPDATE_EVENT),
+            #[cfg(feature = "xinput")]
+            Event::XinputHierarchy(_) => Some(xinput::HIERARCHY_EVENT),
+            #[cfg(feature = "xinput")]
+            Event::XinputKeyPress(_) => Some(xinput::KEY_PRESS_EVENT),
+            #[cfg(feature = "xinput")]
+            Event::XinputKeyRelease(_) => Some(xinput::KEY_RELEASE_EVENT),
+            #[cfg(feature = "xinput")]
+            Event::XinputLeave(_) => Some(xinput::LEAVE_EVENT),
+            #[cfg(feature = "xinput")]
+            Event::XinputMotion(_) => Some(xinput::MOTION_EVENT),
+            #[cfg(feature = "xinput")]
+            Event::XinputProperty(_) => Some(xinput::PROPERTY_EVENT),
+            #[cfg(feature = "xinput")]
+            Event::XinputProximityIn(_) => Some(u16::from(xinput::PROXIMITY_IN_EVENT)),
+            #[cfg(feature = "xinput")]
+            Event::XinputProximityOut(_) => Some(u16::from(xinput::PROXIMITY_OUT_EVENT)),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawButtonPress(_) => Some(xinput::RAW_BUTTON_PRESS_EVENT),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawButtonRelease(_) => Some(xinput::RAW_BUTTON_RELEASE_EVENT),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawKeyPress(_) => Some(xinput::RAW_KEY_PRESS_EVENT),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawKeyRelease(_) => Some(xinput::RAW_KEY_RELEASE_EVENT),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawMotion(_) => Some(xinput::RAW_MOTION_EVENT),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchBegin(_) => Some(xinput::RAW_TOUCH_BEGIN_EVENT),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchEnd(_) => Some(xinput::RAW_TOUCH_END_EVENT),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchUpdate(_) => Some(xinput::RAW_TOUCH_UPDATE_EVENT),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchBegin(_) => Some(xinput::TOUCH_BEGIN_EVENT),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchEnd(_) => Some(xinput::TOUCH_END_EVENT),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchOwnership(_) => Some(xinput::TOUCH_OWNERSHIP_EVENT),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchUpdate(_) => Some(xinput::TOUCH_UPDATE_EVENT),
+            #[cfg(feature = "xkb")]
+            Event::XkbAccessXNotify(_) => Some(u16::from(xkb::ACCESS_X_NOTIFY_EVENT)),
+            #[cfg(feature = "xkb")]
+            Event::XkbActionMessage(_) => Some(u16::from(xkb::ACTION_MESSAGE_EVENT)),
+            #[cfg(feature = "xkb")]
+            Event::XkbBellNotify(_) => Some(u16::from(xkb::BELL_NOTIFY_EVENT)),
+            #[cfg(feature = "xkb")]
+            Event::XkbCompatMapNotify(_) => Some(u16::from(xkb::COMPAT_MAP_NOTIFY_EVENT)),
+            #[cfg(feature = "xkb")]
+            Event::XkbControlsNotify(_) => Some(u16::from(xkb::CONTROLS_NOTIFY_EVENT)),
+            #[cfg(feature = "xkb")]
+            Event::XkbExtensionDeviceNotify(_) => Some(u16::from(xkb::EXTENSION_DEVICE_NOTIFY_EVENT)),
+            #[cfg(feature = "xkb")]
+            Event::XkbIndicatorMapNotify(_) => Some(u16::from(xkb::INDICATOR_MAP_NOTIFY_EVENT)),
+            #[cfg(feature = "xkb")]
+            Event::XkbIndicatorStateNotify(_) => Some(u16::from(xkb::INDICATOR_STATE_NOTIFY_EVENT)),
+            #[cfg(feature = "xkb")]
+            Event::XkbMapNotify(_) => Some(u16::from(xkb::MAP_NOTIFY_EVENT)),
+            #[cfg(feature = "xkb")]
+            Event::XkbNamesNotify(_) => Some(u16::from(xkb::NAMES_NOTIFY_EVENT)),
+            #[cfg(feature = "xkb")]
+            Event::XkbNewKeyboardNotify(_) => Some(u16::from(xkb::NEW_KEYBOARD_NOTIFY_EVENT)),
+            #[cfg(feature = "xkb")]
+            Event::XkbStateNotify(_) => Some(u16::from(xkb::STATE_NOTIFY_EVENT)),
+            #[cfg(feature = "xprint")]
+            Event::XprintAttributNotify(_) => Some(u16::from(xprint::ATTRIBUT_NOTIFY_EVENT)),
+            #[cfg(feature = "xprint")]
+            Event::XprintNotify(_) => Some(u16::from(xprint::NOTIFY_EVENT)),
+            #[cfg(feature = "xv")]
+            Event::XvPortNotify(_) => Some(u16::from(xv::PORT_NOTIFY_EVENT)),
+            #[cfg(feature = "xv")]
+            Event::XvVideoNotify(_) => Some(u16::from(xv::VIDEO_NOTIFY_EVENT)),
+        }
     }
 
-    /// Was this event generated by another X11 client?
+    /// Get the window that this X11 event is reported for
     ///
-    /// If this function returns true, then this event comes from another client via
-    /// the `SendEvent` request. Otherwise, it was generated by the X11 server.
-    pub fn sent_event(&self) -> bool {
-        self.raw_response_type() & 0x80 != 0
-    }
-}
-
-/// Get the response type out of the raw bytes of an X11 error or event.
-fn response_type(raw_bytes: &[u8]) -> Result<u8, ParseError> {
-    raw_bytes.first()
-        .map(|x| x & 0x7f)
-        .ok_or(ParseError::InsufficientData)
+    /// Like in Xlib, this is the window on which the event was selected, if possible.
+    /// This is the `event` field of events that have one, e.g. `ConfigureNotify`, the
+    /// `parent` field of e.g. `MapRequest`, and otherwise the `window` field. For
+    /// selection events, the `owner` or `requestor` is returned. Events without such
+    /// a field return `None`.
+    pub fn window(&self) -> Option<xproto::Window> {
+        match self {
+            Event::ButtonPress(value) => Some(value.event),
+            Event::ButtonRelease(value) => Some(value.event),
+            Event::CirculateNotify(value) => Some(value.event),
+            Event::CirculateRequest(value) => Some(value.event),
+            Event::ClientMessage(value) => Some(value.window),
+            Event::ColormapNotify(value) => Some(value.window),
+            Event::ConfigureNotify(value) => Some(value.event),
+            Event::ConfigureRequest(value) => Some(value.parent),
+            Event::CreateNotify(value) => Some(value.parent),
+            Event::DestroyNotify(value) => Some(value.event),
+            Event::EnterNotify(value) => Some(value.event),
+            Event::Expose(value) => Some(value.window),
+            Event::FocusIn(value) => Some(value.event),
+            Event::FocusOut(value) => Some(value.event),
+            Event::GravityNotify(value) => Some(value.event),
+            Event::KeyPress(value) => Some(value.event),
+            Event::KeyRelease(value) => Some(value.event),
+            Event::LeaveNotify(value) => Some(value.event),
+            Event::MapNotify(value) => Some(value.event),
+            Event::MapRequest(value) => Some(value.parent),
+            Event::MotionNotify(value) => Some(value.event),
+            Event::PropertyNotify(value) => Some(value.window),
+            Event::ReparentNotify(value) => Some(value.event),
+            Event::ResizeRequest(value) => Some(value.window),
+            Event::SelectionClear(value) => Some(value.owner),
+            Event::SelectionNotify(value) => Some(value.requestor),
+            Event::SelectionRequest(value) => Some(value.owner),
+            Event::UnmapNotify(value) => Some(value.event),
+            Event::VisibilityNotify(value) => Some(value.window),
+            #[cfg(feature = "present")]
+            Event::PresentCompleteNotify(value) => Some(value.window),
+            #[cfg(feature = "present")]
+            Event::PresentConfigureNotify(value) => Some(value.window),
+            #[cfg(feature = "present")]
+            Event::PresentIdleNotify(value) => Some(value.window),
+            #[cfg(feature = "present")]
+            Event::PresentRedirectNotify(value) => Some(value.window),
+            #[cfg(feature = "screensaver")]
+            Event::ScreensaverNotify(value) => Some(value.window),
+            #[cfg(feature = "xfixes")]
+            Event::XfixesCursorNotify(value) => Some(value.window),
+            #[cfg(feature = "xfixes")]
+            Event::XfixesSelectionNotify(value) => Some(value.window),
+            #[cfg(feature = "xinput")]
+            Event::XinputBarrierHit(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputBarrierLeave(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputButtonPress(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputButtonRelease(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonPress(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonRelease(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceFocusIn(value) => Some(value.window),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceFocusOut(value) => Some(value.window),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyPress(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyRelease(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceMotionNotify(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputEnter(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputFocusIn(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputFocusOut(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputGesturePinchBegin(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputGesturePinchEnd(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputGesturePinchUpdate(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputGestureSwipeBegin(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputGestureSwipeEnd(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputGestureSwipeUpdate(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputKeyPress(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputKeyRelease(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputLeave(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputMotion(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputProximityIn(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputProximityOut(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchBegin(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchEnd(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchOwnership(value) => Some(value.event),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchUpdate(value) => Some(value.event),
+            #[cfg(feature = "xkb")]
+            Event::XkbBellNotify(value) => Some(value.window),
+            _ => None,
+        }
+    }
+
+    /// Get the name of the extension that this X11 event belongs to
+    ///
+    /// Returns `None` for core events, errors, and unknown events.
+    pub fn extension(&self) -> Option<&'static str> {
+        match self {
+            #[cfg(feature = "damage")]
+            Event::DamageNotify(_) => Some(damage::X11_EXTENSION_NAME),
+            #[cfg(feature = "dpms")]
+            Event::DpmsInfoNotify(_) => Some(dpms::X11_EXTENSION_NAME),
+            #[cfg(feature = "dri2")]
+            Event::Dri2BufferSwapComplete(_) => Some(dri2::X11_EXTENSION_NAME),
+            #[cfg(feature = "dri2")]
+            Event::Dri2InvalidateBuffers(_) => Some(dri2::X11_EXTENSION_NAME),
+            #[cfg(feature = "glx")]
+            Event::GlxBufferSwapComplete(_) => Some(glx::X11_EXTENSION_NAME),
+            #[cfg(feature = "glx")]
+            Event::GlxPbufferClobber(_) => Some(glx::X11_EXTENSION_NAME),
+            #[cfg(feature = "present")]
+            Event::PresentCompleteNotify(_) => Some(present::X11_EXTENSION_NAME),
+            #[cfg(feature = "present")]
+            Event::PresentConfigureNotify(_) => Some(present::X11_EXTENSION_NAME),
+            #[cfg(feature = "present")]
+            Event::PresentGeneric(_) => Some(present::X11_EXTENSION_NAME),
+            #[cfg(feature = "present")]
+            Event::PresentIdleNotify(_) => Some(present::X11_EXTENSION_NAME),
+            #[cfg(feature = "present")]
+            Event::PresentRedirectNotify(_) => Some(present::X11_EXTENSION_NAME),
+            #[cfg(feature = "randr")]
+            Event::RandrNotify(_) => Some(randr::X11_EXTENSION_NAME),
+            #[cfg(feature = "randr")]
+            Event::RandrScreenChangeNotify(_) => Some(randr::X11_EXTENSION_NAME),
+            #[cfg(feature = "screensaver")]
+            Event::ScreensaverNotify(_) => Some(screensaver::X11_EXTENSION_NAME),
+            #[cfg(feature = "shape")]
+            Event::ShapeNotify(_) => Some(shape::X11_EXTENSION_NAME),
+            #[cfg(feature = "shm")]
+            Event::ShmCompletion(_) => Some(shm::X11_EXTENSION_NAME),
+            #[cfg(feature = "sync")]
+            Event::SyncAlarmNotify(_) => Some(sync::X11_EXTENSION_NAME),
+            #[cfg(feature = "sync")]
+            Event::SyncCounterNotify(_) => Some(sync::X11_EXTENSION_NAME),
+            #[cfg(feature = "xfixes")]
+            Event::XfixesCursorNotify(_) => Some(xfixes::X11_EXTENSION_NAME),
+            #[cfg(feature = "xfixes")]
+            Event::XfixesSelectionNotify(_) => Some(xfixes::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputBarrierHit(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputBarrierLeave(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputButtonPress(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputButtonRelease(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputChangeDeviceNotify(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonPress(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonRelease(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonStateNotify(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceChanged(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceFocusIn(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceFocusOut(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyPress(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyRelease(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyStateNotify(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceMappingNotify(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceMotionNotify(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputDevicePresenceNotify(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputDevicePropertyNotify(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceStateNotify(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceValuator(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputEnter(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputFocusIn(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputFocusOut(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputGesturePinchBegin(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputGesturePinchEnd(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputGesturePinchUpdate(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputGestureSwipeBegin(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputGestureSwipeEnd(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputGestureSwipeUpdate(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputHierarchy(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputKeyPress(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputKeyRelease(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputLeave(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputMotion(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputProperty(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputProximityIn(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputProximityOut(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawButtonPress(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawButtonRelease(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawKeyPress(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawKeyRelease(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawMotion(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchBegin(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchEnd(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchUpdate(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchBegin(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchEnd(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchOwnership(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchUpdate(_) => Some(xinput::X11_EXTENSION_NAME),
+            #[cfg(feature = "xkb")]
+            Event::XkbAccessXNotify(_) => Some(xkb::X11_EXTENSION_NAME),
+            #[cfg(feature = "xkb")]
+            Event::XkbActionMessage(_) => Some(xkb::X11_EXTENSION_NAME),
+            #[cfg(feature = "xkb")]
+            Event::XkbBellNotify(_) => Some(xkb::X11_EXTENSION_NAME),
+            #[cfg(feature = "xkb")]
+            Event::XkbCompatMapNotify(_) => Some(xkb::X11_EXTENSION_NAME),
+            #[cfg(feature = "xkb")]
+            Event::XkbControlsNotify(_) => Some(xkb::X11_EXTENSION_NAME),
+            #[cfg(feature = "xkb")]
+            Event::XkbExtensionDeviceNotify(_) => Some(xkb::X11_EXTENSION_NAME),
+            #[cfg(feature = "xkb")]
+            Event::XkbIndicatorMapNotify(_) => Some(xkb::X11_EXTENSION_NAME),
+            #[cfg(feature = "xkb")]
+            Event::XkbIndicatorStateNotify(_) => Some(xkb::X11_EXTENSION_NAME),
+            #[cfg(feature = "xkb")]
+            Event::XkbMapNotify(_) => Some(xkb::X11_EXTENSION_NAME),
+            #[cfg(feature = "xkb")]
+            Event::XkbNamesNotify(_) => Some(xkb::X11_EXTENSION_NAME),
+            #[cfg(feature = "xkb")]
+            Event::XkbNewKeyboardNotify(_) => Some(xkb::X11_EXTENSION_NAME),
+            #[cfg(feature = "xkb")]
+            Event::XkbStateNotify(_) => Some(xkb::X11_EXTENSION_NAME),
+            #[cfg(feature = "xprint")]
+            Event::XprintAttributNotify(_) => Some(xprint::X11_EXTENSION_NAME),
+            #[cfg(feature = "xprint")]
+            Event::XprintNotify(_) => Some(xprint::X11_EXTENSION_NAME),
+            #[cfg(feature = "xv")]
+            Event::XvPortNotify(_) => Some(xv::X11_EXTENSION_NAME),
+            #[cfg(feature = "xv")]
+            Event::XvVideoNotify(_) => Some(xv::X11_EXTENSION_NAME),
+            _ => None,
+        }
+    }
+
+    /// Serialize this X11 event into its wire format
+    ///
+    /// The response type is set according to the kind of this event, keeping the bit
+    /// that marks events sent via `SendEvent`. Generic events also get their extension,
+    /// event type and length fields set. For events from extensions, `ext_info` must
+    /// describe the extension returned by [`Event::extension`]. `None` is returned
+    /// if `ext_info` is needed, but missing.
+    pub fn serialize(
+        &self,
+        // Might not be used if none of the extensions with events is enabled
+        #[allow(unused_variables)]
+        ext_info: Option<&ExtensionInformation>,
+    ) -> Option<Vec<u8>> {
+        let mut bytes = Vec::new();
+        match self {
+            Event::Unknown(value) => bytes.extend_from_slice(value),
+            Event::Error(value) => value.serialize_into(&mut bytes),
+            Event::ButtonPress(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::BUTTON_PRESS_EVENT);
+            }
+            Event::ButtonRelease(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::BUTTON_RELEASE_EVENT);
+            }
+            Event::CirculateNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::CIRCULATE_NOTIFY_EVENT);
+            }
+            Event::CirculateRequest(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::CIRCULATE_REQUEST_EVENT);
+            }
+            Event::ClientMessage(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::CLIENT_MESSAGE_EVENT);
+            }
+            Event::ColormapNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::COLORMAP_NOTIFY_EVENT);
+            }
+            Event::ConfigureNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::CONFIGURE_NOTIFY_EVENT);
+            }
+            Event::ConfigureRequest(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::CONFIGURE_REQUEST_EVENT);
+            }
+            Event::CreateNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::CREATE_NOTIFY_EVENT);
+            }
+            Event::DestroyNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::DESTROY_NOTIFY_EVENT);
+            }
+            Event::EnterNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::ENTER_NOTIFY_EVENT);
+            }
+            Event::Expose(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::EXPOSE_EVENT);
+            }
+            Event::FocusIn(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::FOCUS_IN_EVENT);
+            }
+            Event::FocusOut(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::FOCUS_OUT_EVENT);
+            }
+            Event::GeGeneric(value) => {
+                value.serialize_into(&mut bytes);
+                set_response_type(&mut bytes, xproto::GE_GENERIC_EVENT);
+            }
+            Event::GraphicsExposure(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::GRAPHICS_EXPOSURE_EVENT);
+            }
+            Event::GravityNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::GRAVITY_NOTIFY_EVENT);
+            }
+            Event::KeyPress(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::KEY_PRESS_EVENT);
+            }
+            Event::KeyRelease(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::KEY_RELEASE_EVENT);
+            }
+            Event::KeymapNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::KEYMAP_NOTIFY_EVENT);
+            }
+            Event::LeaveNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::LEAVE_NOTIFY_EVENT);
+            }
+            Event::MapNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::MAP_NOTIFY_EVENT);
+            }
+            Event::MapRequest(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::MAP_REQUEST_EVENT);
+            }
+            Event::MappingNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::MAPPING_NOTIFY_EVENT);
+            }
+            Event::MotionNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::MOTION_NOTIFY_EVENT);
+            }
+            Event::NoExposure(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::NO_EXPOSURE_EVENT);
+            }
+            Event::PropertyNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::PROPERTY_NOTIFY_EVENT);
+            }
+            Event::ReparentNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::REPARENT_NOTIFY_EVENT);
+            }
+            Event::ResizeRequest(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::RESIZE_REQUEST_EVENT);
+            }
+            Event::SelectionClear(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::SELECTION_CLEAR_EVENT);
+            }
+            Event::SelectionNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::SELECTION_NOTIFY_EVENT);
+            }
+            Event::SelectionRequest(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::SELECTION_REQUEST_EVENT);
+            }
+            Event::UnmapNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::UNMAP_NOTIFY_EVENT);
+            }
+            Event::VisibilityNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, xproto::VISIBILITY_NOTIFY_EVENT);
+            }
+            #[cfg(feature = "damage")]
+            Event::DamageNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(damage::NOTIFY_EVENT));
+            }
+            #[cfg(feature = "dpms")]
+            Event::DpmsInfoNotify(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, dpms::INFO_NOTIFY_EVENT);
+            }
+            #[cfg(feature = "dri2")]
+            Event::Dri2BufferSwapComplete(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(dri2::BUFFER_SWAP_COMPLETE_EVENT));
+            }
+            #[cfg(feature = "dri2")]
+            Event::Dri2InvalidateBuffers(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(dri2::INVALIDATE_BUFFERS_EVENT));
+            }
+            #[cfg(feature = "glx")]
+            Event::GlxBufferSwapComplete(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(glx::BUFFER_SWAP_COMPLETE_EVENT));
+            }
+            #[cfg(feature = "glx")]
+            Event::GlxPbufferClobber(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(glx::PBUFFER_CLOBBER_EVENT));
+            }
+            #[cfg(feature = "present")]
+            Event::PresentCompleteNotify(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, present::COMPLETE_NOTIFY_EVENT);
+            }
+            #[cfg(feature = "present")]
+            Event::PresentConfigureNotify(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, present::CONFIGURE_NOTIFY_EVENT);
+            }
+            #[cfg(feature = "present")]
+            Event::PresentGeneric(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(present::GENERIC_EVENT));
+            }
+            #[cfg(feature = "present")]
+            Event::PresentIdleNotify(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, present::IDLE_NOTIFY_EVENT);
+            }
+            #[cfg(feature = "present")]
+            Event::PresentRedirectNotify(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, present::REDIRECT_NOTIFY_EVENT);
+            }
+            #[cfg(feature = "randr")]
+            Event::RandrNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(randr::NOTIFY_EVENT));
+            }
+            #[cfg(feature = "randr")]
+            Event::RandrScreenChangeNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(randr::SCREEN_CHANGE_NOTIFY_EVENT));
+            }
+            #[cfg(feature = "screensaver")]
+            Event::ScreensaverNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(screensaver::NOTIFY_EVENT));
+            }
+            #[cfg(feature = "shape")]
+            Event::ShapeNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(shape::NOTIFY_EVENT));
+            }
+            #[cfg(feature = "shm")]
+            Event::ShmCompletion(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(shm::COMPLETION_EVENT));
+            }
+            #[cfg(feature = "sync")]
+            Event::SyncAlarmNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(sync::ALARM_NOTIFY_EVENT));
+            }
+            #[cfg(feature = "sync")]
+            Event::SyncCounterNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(sync::COUNTER_NOTIFY_EVENT));
+            }
+            #[cfg(feature = "xfixes")]
+            Event::XfixesCursorNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(xfixes::CURSOR_NOTIFY_EVENT));
+            }
+            #[cfg(feature = "xfixes")]
+            Event::XfixesSelectionNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(xfixes::SELECTION_NOTIFY_EVENT));
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputBarrierHit(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, xinput::BARRIER_HIT_EVENT);
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputBarrierLeave(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, xinput::BARRIER_LEAVE_EVENT);
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputButtonPress(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, xinput::BUTTON_PRESS_EVENT);
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputButtonRelease(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, xinput::BUTTON_RELEASE_EVENT);
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputChangeDeviceNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(xinput::CHANGE_DEVICE_NOTIFY_EVENT));
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonPress(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(xinput::DEVICE_BUTTON_PRESS_EVENT));
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonRelease(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(xinput::DEVICE_BUTTON_RELEASE_EVENT));
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonStateNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(xinput::DEVICE_BUTTON_STATE_NOTIFY_EVENT));
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceChanged(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, xinput::DEVICE_CHANGED_EVENT);
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceFocusIn(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(xinput::DEVICE_FOCUS_IN_EVENT));
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceFocusOut(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(xinput::DEVICE_FOCUS_OUT_EVENT));
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyPress(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(xinput::DEVICE_KEY_PRESS_EVENT));
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyRelease(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(xinput::DEVICE_KEY_RELEASE_EVENT));
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyStateNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(xinput::DEVICE_KEY_STATE_NOTIFY_EVENT));
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceMappingNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(xinput::DEVICE_MAPPING_NOTIFY_EVENT));
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceMotionNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(xinput::DEVICE_MOTION_NOTIFY_EVENT));
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputDevicePresenceNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(xinput::DEVICE_PRESENCE_NOTIFY_EVENT));
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputDevicePropertyNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(xinput::DEVICE_PROPERTY_NOTIFY_EVENT));
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceStateNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(xinput::DEVICE_STATE_NOTIFY_EVENT));
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceValuator(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(xinput::DEVICE_VALUATOR_EVENT));
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputEnter(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, xinput::ENTER_EVENT);
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputFocusIn(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, xinput::FOCUS_IN_EVENT);
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputFocusOut(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, xinput::FOCUS_OUT_EVENT);
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputGesturePinchBegin(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, xinput::GESTURE_PINCH_BEGIN_EVENT);
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputGesturePinchEnd(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, xinput::GESTURE_PINCH_END_EVENT);
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputGesturePinchUpdate(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, xinput::GESTURE_PINCH_UPDATE_EVENT);
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputGestureSwipeBegin(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, xinput::GESTURE_SWIPE_BEGIN_EVENT);
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputGestureSwipeEnd(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, xinput::GESTURE_SWIPE_END_EVENT);
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputGestureSwipeUpdate(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, xinput::GESTURE_SWIPE_UPDATE_EVENT);
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputHierarchy(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, xinput::HIERARCHY_EVENT);
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputKeyPress(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, xinput::KEY_PRESS_EVENT);
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputKeyRelease(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, xinput::KEY_RELEASE_EVENT);
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputLeave(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, xinput::LEAVE_EVENT);
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputMotion(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, xinput::MOTION_EVENT);
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputProperty(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, xinput::PROPERTY_EVENT);
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputProximityIn(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(xinput::PROXIMITY_IN_EVENT));
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputProximityOut(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(xinput::PROXIMITY_OUT_EVENT));
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputRawButtonPress(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, xinput::RAW_BUTTON_PRESS_EVENT);
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputRawButtonRelease(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, xinput::RAW_BUTTON_RELEASE_EVENT);
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputRawKeyPress(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, xinput::RAW_KEY_PRESS_EVENT);
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputRawKeyRelease(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, xinput::RAW_KEY_RELEASE_EVENT);
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputRawMotion(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, xinput::RAW_MOTION_EVENT);
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchBegin(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, xinput::RAW_TOUCH_BEGIN_EVENT);
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchEnd(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, xinput::RAW_TOUCH_END_EVENT);
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchUpdate(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, xinput::RAW_TOUCH_UPDATE_EVENT);
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchBegin(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, xinput::TOUCH_BEGIN_EVENT);
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchEnd(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, xinput::TOUCH_END_EVENT);
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchOwnership(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, xinput::TOUCH_OWNERSHIP_EVENT);
+            }
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchUpdate(value) => {
+                value.serialize_into(&mut bytes);
+                set_generic_event_header(&mut bytes, ext_info?.major_opcode, xinput::TOUCH_UPDATE_EVENT);
+            }
+            #[cfg(feature = "xkb")]
+            Event::XkbAccessXNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event);
+                bytes[1] = xkb::ACCESS_X_NOTIFY_EVENT;
+            }
+            #[cfg(feature = "xkb")]
+            Event::XkbActionMessage(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event);
+                bytes[1] = xkb::ACTION_MESSAGE_EVENT;
+            }
+            #[cfg(feature = "xkb")]
+            Event::XkbBellNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event);
+                bytes[1] = xkb::BELL_NOTIFY_EVENT;
+            }
+            #[cfg(feature = "xkb")]
+            Event::XkbCompatMapNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event);
+                bytes[1] = xkb::COMPAT_MAP_NOTIFY_EVENT;
+            }
+            #[cfg(feature = "xkb")]
+            Event::XkbControlsNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event);
+                bytes[1] = xkb::CONTROLS_NOTIFY_EVENT;
+            }
+            #[cfg(feature = "xkb")]
+            Event::XkbExtensionDeviceNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event);
+                bytes[1] = xkb::EXTENSION_DEVICE_NOTIFY_EVENT;
+            }
+            #[cfg(feature = "xkb")]
+            Event::XkbIndicatorMapNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event);
+                bytes[1] = xkb::INDICATOR_MAP_NOTIFY_EVENT;
+            }
+            #[cfg(feature = "xkb")]
+            Event::XkbIndicatorStateNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event);
+                bytes[1] = xkb::INDICATOR_STATE_NOTIFY_EVENT;
+            }
+            #[cfg(feature = "xkb")]
+            Event::XkbMapNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event);
+                bytes[1] = xkb::MAP_NOTIFY_EVENT;
+            }
+            #[cfg(feature = "xkb")]
+            Event::XkbNamesNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event);
+                bytes[1] = xkb::NAMES_NOTIFY_EVENT;
+            }
+            #[cfg(feature = "xkb")]
+            Event::XkbNewKeyboardNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event);
+                bytes[1] = xkb::NEW_KEYBOARD_NOTIFY_EVENT;
+            }
+            #[cfg(feature = "xkb")]
+            Event::XkbStateNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event);
+                bytes[1] = xkb::STATE_NOTIFY_EVENT;
+            }
+            #[cfg(feature = "xprint")]
+            Event::XprintAttributNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(xprint::ATTRIBUT_NOTIFY_EVENT));
+            }
+            #[cfg(feature = "xprint")]
+            Event::XprintNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(xprint::NOTIFY_EVENT));
+            }
+            #[cfg(feature = "xv")]
+            Event::XvPortNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(xv::PORT_NOTIFY_EVENT));
+            }
+            #[cfg(feature = "xv")]
+            Event::XvVideoNotify(value) => {
+                bytes.extend_from_slice(&<[u8; 32]>::from(value));
+                set_response_type(&mut bytes, ext_info?.first_event.wrapping_add(xv::VIDEO_NOTIFY_EVENT));
+            }
+        }
+        Some(bytes)
+    }
+
+    /// Get the raw response type of this X11 event
+    ///
+    /// Response types have seven bits in X11. The eight bit indicates whether
+    /// the packet was generated through the `SendEvent` request. This function
+    /// returns all eight bits.
+    ///
+    /// See also the `response_type()`, `server_generated()` and `sent_event()` methods.
+    pub fn raw_response_type(&self) -> u8 {
+        match self {
+            Event::Unknown(value) => response_type(value).unwrap(),
+            Event::Error(_) => 0,
+            Event::ButtonPress(value) => value.response_type,
+            Event::ButtonRelease(value) => value.response_type,
+            Event::CirculateNotify(value) => value.response_type,
+            Event::CirculateRequest(value) => value.response_type,
+            Event::ClientMessage(value) => value.response_type,
+            Event::ColormapNotify(value) => value.response_type,
+            Event::ConfigureNotify(value) => value.response_type,
+            Event::ConfigureRequest(value) => value.response_type,
+            Event::CreateNotify(value) => value.response_type,
+            Event::DestroyNotify(value) => value.response_type,
+            Event::EnterNotify(value) => value.response_type,
+            Event::Expose(value) => value.response_type,
+            Event::FocusIn(value) => value.response_type,
+            Event::FocusOut(value) => value.response_type,
+            Event::GeGeneric(value) => value.response_type,
+            Event::GraphicsExposure(value) => value.response_type,
+            Event::GravityNotify(value) => value.response_type,
+            Event::KeyPress(value) => value.response_type,
+            Event::KeyRelease(value) => value.response_type,
+            Event::KeymapNotify(value) => value.response_type,
+            Event::LeaveNotify(value) => value.response_type,
+            Event::MapNotify(value) => value.response_type,
+            Event::MapRequest(value) => value.response_type,
+            Event::MappingNotify(value) => value.response_type,
+            Event::MotionNotify(value) => value.response_type,
+            Event::NoExposure(value) => value.response_type,
+            Event::PropertyNotify(value) => value.response_type,
+            Event::ReparentNotify(value) => value.response_type,
+            Event::ResizeRequest(value) => value.response_type,
+            Event::SelectionClear(value) => value.response_type,
+            Event::SelectionNotify(value) => value.response_type,
+            Event::SelectionRequest(value) => value.response_type,
+            Event::UnmapNotify(value) => value.response_type,
+            Event::VisibilityNotify(value) => value.response_type,
+            #[cfg(feature = "damage")]
+            Event::DamageNotify(value) => value.response_type,
+            #[cfg(feature = "dpms")]
+            Event::DpmsInfoNotify(value) => value.response_type,
+            #[cfg(feature = "dri2")]
+            Event::Dri2BufferSwapComplete(value) => value.response_type,
+            #[cfg(feature = "dri2")]
+            Event::Dri2InvalidateBuffers(value) => value.response_type,
+            #[cfg(feature = "glx")]
+            Event::GlxBufferSwapComplete(value) => value.response_type,
+            #[cfg(feature = "glx")]
+            Event::GlxPbufferClobber(value) => value.response_type,
+            #[cfg(feature = "present")]
+            Event::PresentCompleteNotify(value) => value.response_type,
+            #[cfg(feature = "present")]
+            Event::PresentConfigureNotify(value) => value.response_type,
+            #[cfg(feature = "present")]
+            Event::PresentGeneric(value) => value.response_type,
+            #[cfg(feature = "present")]
+            Event::PresentIdleNotify(value) => value.response_type,
+            #[cfg(feature = "present")]
+            Event::PresentRedirectNotify(value) => value.response_type,
+            #[cfg(feature = "randr")]
+            Event::RandrNotify(value) => value.response_type,
+            #[cfg(feature = "randr")]
+            Event::RandrScreenChangeNotify(value) => value.response_type,
+            #[cfg(feature = "screensaver")]
+            Event::ScreensaverNotify(value) => value.response_type,
+            #[cfg(feature = "shape")]
+            Event::ShapeNotify(value) => value.response_type,
+            #[cfg(feature = "shm")]
+            Event::ShmCompletion(value) => value.response_type,
+            #[cfg(feature = "sync")]
+            Event::SyncAlarmNotify(value) => value.response_type,
+            #[cfg(feature = "sync")]
+            Event::SyncCounterNotify(value) => value.response_type,
+            #[cfg(feature = "xfixes")]
+            Event::XfixesCursorNotify(value) => value.response_type,
+            #[cfg(feature = "xfixes")]
+            Event::XfixesSelectionNotify(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputBarrierHit(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputBarrierLeave(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputButtonPress(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputButtonRelease(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputChangeDeviceNotify(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonPress(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonRelease(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceButtonStateNotify(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceChanged(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceFocusIn(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceFocusOut(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyPress(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyRelease(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceKeyStateNotify(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceMappingNotify(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceMotionNotify(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputDevicePresenceNotify(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputDevicePropertyNotify(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceStateNotify(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputDeviceValuator(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputEnter(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputFocusIn(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputFocusOut(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputGesturePinchBegin(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputGesturePinchEnd(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputGesturePinchUpdate(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputGestureSwipeBegin(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputGestureSwipeEnd(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputGestureSwipeUpdate(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputHierarchy(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputKeyPress(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputKeyRelease(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputLeave(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputMotion(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputProperty(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputProximityIn(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputProximityOut(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputRawButtonPress(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputRawButtonRelease(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputRawKeyPress(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputRawKeyRelease(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputRawMotion(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchBegin(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchEnd(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputRawTouchUpdate(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchBegin(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchEnd(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchOwnership(value) => value.response_type,
+            #[cfg(feature = "xinput")]
+            Event::XinputTouchUpdate(value) => value.response_type,
+            #[cfg(feature = "xkb")]
+            Event::XkbAccessXNotify(value) => value.response_type,
+            #[cfg(feature = "xkb")]
+            Event::XkbActionMessage(value) => value.response_type,
+            #[cfg(feature = "xkb")]
+            Event::XkbBellNotify(value) => value.response_type,
+            #[cfg(feature = "xkb")]
+            Event::XkbCompatMapNotify(value) => value.response_type,
+            #[cfg(feature = "xkb")]
+            Event::XkbControlsNotify(value) => value.response_type,
+            #[cfg(feature = "xkb")]
+            Event::XkbExtensionDeviceNotify(value) => value.response_type,
+            #[cfg(feature = "xkb")]
+            Event::XkbIndicatorMapNotify(value) => value.response_type,
+            #[cfg(feature = "xkb")]
+            Event::XkbIndicatorStateNotify(value) => value.response_type,
+            #[cfg(feature = "xkb")]
+            Event::XkbMapNotify(value) => value.response_type,
+            #[cfg(feature = "xkb")]
+            Event::XkbNamesNotify(value) => value.response_type,
+            #[cfg(feature = "xkb")]
+            Event::XkbNewKeyboardNotify(value) => value.response_type,
+            #[cfg(feature = "xkb")]
+            Event::XkbStateNotify(value) => value.response_type,
+            #[cfg(feature = "xprint")]
+            Event::XprintAttributNotify(value) => value.response_type,
+            #[cfg(feature = "xprint")]
+            Event::XprintNotify(value) => value.response_type,
+            #[cfg(feature = "xv")]
+            Event::XvPortNotify(value) => value.response_type,
+            #[cfg(feature = "xv")]
+            Event::XvVideoNotify(value) => value.response_type,
+        }
+    }
+
+    /// Get the response type of this X11 event
+    pub fn response_type(&self) -> u8 {
+        self.raw_response_type() & 0x7f
+    }
+
+    /// Was this event generated by the X11 server?
+    ///
+    /// If this function returns true, then this event comes from the X11 server.
+    /// Otherwise, it was sent from another client via the `SendEvent` request.
+    pub fn server_generated(&self) -> bool {
+        self.raw_response_type() & 0x80 == 0
+    }
+
+    /// Was this event generated by another X11 client?
+    ///
+    /// If this function returns true, then this event comes from another client via
+    /// the `SendEvent` request. Otherwise, it was generated by the X11 server.
+    pub fn sent_event(&self) -> bool {
+        self.raw_response_type() & 0x80 != 0
+    }
+}
+
+/// Besides `Event::ButtonPress`, this also accepts `Event::ButtonRelease`. They use the same struct; match on [`Event`] to tell them apart.
+impl TryFrom<Event> for xproto::ButtonPressEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::ButtonPress(value) | Event::ButtonRelease(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+/// Besides `Event::ButtonPress`, this also accepts `Event::ButtonRelease`. They use the same struct; match on [`Event`] to tell them apart.
+impl<'a> TryFrom<&'a Event> for &'a xproto::ButtonPressEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::ButtonPress(value) | Event::ButtonRelease(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+/// Besides `Event::CirculateNotify`, this also accepts `Event::CirculateRequest`. They use the same struct; match on [`Event`] to tell them apart.
+impl TryFrom<Event> for xproto::CirculateNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::CirculateNotify(value) | Event::CirculateRequest(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+/// Besides `Event::CirculateNotify`, this also accepts `Event::CirculateRequest`. They use the same struct; match on [`Event`] to tell them apart.
+impl<'a> TryFrom<&'a Event> for &'a xproto::CirculateNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::CirculateNotify(value) | Event::CirculateRequest(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::ClientMessageEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::ClientMessage(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+impl<'a> TryFrom<&'a Event> for &'a xproto::ClientMessageEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::ClientMessage(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::ColormapNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::ColormapNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+impl<'a> TryFrom<&'a Event> for &'a xproto::ColormapNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::ColormapNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::ConfigureNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::ConfigureNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+impl<'a> TryFrom<&'a Event> for &'a xproto::ConfigureNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::ConfigureNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::ConfigureRequestEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::ConfigureRequest(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+impl<'a> TryFrom<&'a Event> for &'a xproto::ConfigureRequestEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::ConfigureRequest(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::CreateNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::CreateNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+impl<'a> TryFrom<&'a Event> for &'a xproto::CreateNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::CreateNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::DestroyNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::DestroyNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+impl<'a> TryFrom<&'a Event> for &'a xproto::DestroyNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::DestroyNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+/// Besides `Event::EnterNotify`, this also accepts `Event::LeaveNotify`. They use the same struct; match on [`Event`] to tell them apart.
+impl TryFrom<Event> for xproto::EnterNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::EnterNotify(value) | Event::LeaveNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+/// Besides `Event::EnterNotify`, this also accepts `Event::LeaveNotify`. They use the same struct; match on [`Event`] to tell them apart.
+impl<'a> TryFrom<&'a Event> for &'a xproto::EnterNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::EnterNotify(value) | Event::LeaveNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::ExposeEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::Expose(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+impl<'a> TryFrom<&'a Event> for &'a xproto::ExposeEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::Expose(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+/// Besides `Event::FocusIn`, this also accepts `Event::FocusOut`. They use the same struct; match on [`Event`] to tell them apart.
+impl TryFrom<Event> for xproto::FocusInEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::FocusIn(value) | Event::FocusOut(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+/// Besides `Event::FocusIn`, this also accepts `Event::FocusOut`. They use the same struct; match on [`Event`] to tell them apart.
+impl<'a> TryFrom<&'a Event> for &'a xproto::FocusInEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::FocusIn(value) | Event::FocusOut(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::GeGenericEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::GeGeneric(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+impl<'a> TryFrom<&'a Event> for &'a xproto::GeGenericEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::GeGeneric(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::GraphicsExposureEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::GraphicsExposure(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+impl<'a> TryFrom<&'a Event> for &'a xproto::GraphicsExposureEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::GraphicsExposure(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::GravityNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::GravityNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+impl<'a> TryFrom<&'a Event> for &'a xproto::GravityNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::GravityNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+/// Besides `Event::KeyPress`, this also accepts `Event::KeyRelease`. They use the same struct; match on [`Event`] to tell them apart.
+impl TryFrom<Event> for xproto::KeyPressEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::KeyPress(value) | Event::KeyRelease(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+/// Besides `Event::KeyPress`, this also accepts `Event::KeyRelease`. They use the same struct; match on [`Event`] to tell them apart.
+impl<'a> TryFrom<&'a Event> for &'a xproto::KeyPressEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::KeyPress(value) | Event::KeyRelease(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::KeymapNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::KeymapNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+impl<'a> TryFrom<&'a Event> for &'a xproto::KeymapNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::KeymapNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::MapNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::MapNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+impl<'a> TryFrom<&'a Event> for &'a xproto::MapNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::MapNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::MapRequestEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::MapRequest(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+impl<'a> TryFrom<&'a Event> for &'a xproto::MapRequestEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::MapRequest(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::MappingNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::MappingNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+impl<'a> TryFrom<&'a Event> for &'a xproto::MappingNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::MappingNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::MotionNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::MotionNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+impl<'a> TryFrom<&'a Event> for &'a xproto::MotionNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::MotionNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::NoExposureEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::NoExposure(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+impl<'a> TryFrom<&'a Event> for &'a xproto::NoExposureEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::NoExposure(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::PropertyNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::PropertyNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+impl<'a> TryFrom<&'a Event> for &'a xproto::PropertyNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::PropertyNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::ReparentNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::ReparentNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+impl<'a> TryFrom<&'a Event> for &'a xproto::ReparentNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::ReparentNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::ResizeRequestEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::ResizeRequest(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+impl<'a> TryFrom<&'a Event> for &'a xproto::ResizeRequestEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::ResizeRequest(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::SelectionClearEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::SelectionClear(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+impl<'a> TryFrom<&'a Event> for &'a xproto::SelectionClearEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::SelectionClear(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::SelectionNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::SelectionNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+impl<'a> TryFrom<&'a Event> for &'a xproto::SelectionNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::SelectionNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::SelectionRequestEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::SelectionRequest(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+impl<'a> TryFrom<&'a Event> for &'a xproto::SelectionRequestEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::SelectionRequest(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::UnmapNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::UnmapNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+impl<'a> TryFrom<&'a Event> for &'a xproto::UnmapNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::UnmapNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<Event> for xproto::VisibilityNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::VisibilityNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+impl<'a> TryFrom<&'a Event> for &'a xproto::VisibilityNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::VisibilityNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "damage")]
+impl TryFrom<Event> for damage::NotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::DamageNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "damage")]
+impl<'a> TryFrom<&'a Event> for &'a damage::NotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::DamageNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "dpms")]
+impl TryFrom<Event> for dpms::InfoNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::DpmsInfoNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "dpms")]
+impl<'a> TryFrom<&'a Event> for &'a dpms::InfoNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::DpmsInfoNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "dri2")]
+impl TryFrom<Event> for dri2::BufferSwapCompleteEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::Dri2BufferSwapComplete(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "dri2")]
+impl<'a> TryFrom<&'a Event> for &'a dri2::BufferSwapCompleteEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::Dri2BufferSwapComplete(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "dri2")]
+impl TryFrom<Event> for dri2::InvalidateBuffersEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::Dri2InvalidateBuffers(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "dri2")]
+impl<'a> TryFrom<&'a Event> for &'a dri2::InvalidateBuffersEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::Dri2InvalidateBuffers(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "glx")]
+impl TryFrom<Event> for glx::BufferSwapCompleteEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::GlxBufferSwapComplete(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "glx")]
+impl<'a> TryFrom<&'a Event> for &'a glx::BufferSwapCompleteEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::GlxBufferSwapComplete(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "glx")]
+impl TryFrom<Event> for glx::PbufferClobberEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::GlxPbufferClobber(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "glx")]
+impl<'a> TryFrom<&'a Event> for &'a glx::PbufferClobberEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::GlxPbufferClobber(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "present")]
+impl TryFrom<Event> for present::CompleteNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::PresentCompleteNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "present")]
+impl<'a> TryFrom<&'a Event> for &'a present::CompleteNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::PresentCompleteNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "present")]
+impl TryFrom<Event> for present::ConfigureNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::PresentConfigureNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "present")]
+impl<'a> TryFrom<&'a Event> for &'a present::ConfigureNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::PresentConfigureNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "present")]
+impl TryFrom<Event> for present::GenericEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::PresentGeneric(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "present")]
+impl<'a> TryFrom<&'a Event> for &'a present::GenericEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::PresentGeneric(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "present")]
+impl TryFrom<Event> for present::IdleNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::PresentIdleNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "present")]
+impl<'a> TryFrom<&'a Event> for &'a present::IdleNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::PresentIdleNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "present")]
+impl TryFrom<Event> for present::RedirectNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::PresentRedirectNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "present")]
+impl<'a> TryFrom<&'a Event> for &'a present::RedirectNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::PresentRedirectNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "randr")]
+impl TryFrom<Event> for randr::NotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::RandrNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "randr")]
+impl<'a> TryFrom<&'a Event> for &'a randr::NotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::RandrNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "randr")]
+impl TryFrom<Event> for randr::ScreenChangeNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::RandrScreenChangeNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "randr")]
+impl<'a> TryFrom<&'a Event> for &'a randr::ScreenChangeNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::RandrScreenChangeNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "screensaver")]
+impl TryFrom<Event> for screensaver::NotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::ScreensaverNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "screensaver")]
+impl<'a> TryFrom<&'a Event> for &'a screensaver::NotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::ScreensaverNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "shape")]
+impl TryFrom<Event> for shape::NotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::ShapeNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "shape")]
+impl<'a> TryFrom<&'a Event> for &'a shape::NotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::ShapeNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "shm")]
+impl TryFrom<Event> for shm::CompletionEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::ShmCompletion(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "shm")]
+impl<'a> TryFrom<&'a Event> for &'a shm::CompletionEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::ShmCompletion(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "sync")]
+impl TryFrom<Event> for sync::AlarmNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::SyncAlarmNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "sync")]
+impl<'a> TryFrom<&'a Event> for &'a sync::AlarmNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::SyncAlarmNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "sync")]
+impl TryFrom<Event> for sync::CounterNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::SyncCounterNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "sync")]
+impl<'a> TryFrom<&'a Event> for &'a sync::CounterNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::SyncCounterNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xfixes")]
+impl TryFrom<Event> for xfixes::CursorNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XfixesCursorNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "xfixes")]
+impl<'a> TryFrom<&'a Event> for &'a xfixes::CursorNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XfixesCursorNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xfixes")]
+impl TryFrom<Event> for xfixes::SelectionNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XfixesSelectionNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "xfixes")]
+impl<'a> TryFrom<&'a Event> for &'a xfixes::SelectionNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XfixesSelectionNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+/// Besides `Event::XinputBarrierHit`, this also accepts `Event::XinputBarrierLeave`. They use the same struct; match on [`Event`] to tell them apart.
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::BarrierHitEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XinputBarrierHit(value) | Event::XinputBarrierLeave(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+/// Besides `Event::XinputBarrierHit`, this also accepts `Event::XinputBarrierLeave`. They use the same struct; match on [`Event`] to tell them apart.
+#[cfg(feature = "xinput")]
+impl<'a> TryFrom<&'a Event> for &'a xinput::BarrierHitEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XinputBarrierHit(value) | Event::XinputBarrierLeave(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+/// Besides `Event::XinputButtonPress`, this also accepts `Event::XinputButtonRelease`, `Event::XinputMotion`. They use the same struct; match on [`Event`] to tell them apart.
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::ButtonPressEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XinputButtonPress(value) | Event::XinputButtonRelease(value) | Event::XinputMotion(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+/// Besides `Event::XinputButtonPress`, this also accepts `Event::XinputButtonRelease`, `Event::XinputMotion`. They use the same struct; match on [`Event`] to tell them apart.
+#[cfg(feature = "xinput")]
+impl<'a> TryFrom<&'a Event> for &'a xinput::ButtonPressEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XinputButtonPress(value) | Event::XinputButtonRelease(value) | Event::XinputMotion(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::ChangeDeviceNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XinputChangeDeviceNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "xinput")]
+impl<'a> TryFrom<&'a Event> for &'a xinput::ChangeDeviceNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XinputChangeDeviceNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+/// Besides `Event::XinputDeviceButtonPress`, this also accepts `Event::XinputDeviceButtonRelease`, `Event::XinputDeviceKeyPress`, `Event::XinputDeviceKeyRelease`, `Event::XinputDeviceMotionNotify`, `Event::XinputProximityIn`, `Event::XinputProximityOut`. They use the same struct; match on [`Event`] to tell them apart.
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::DeviceKeyPressEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XinputDeviceButtonPress(value) | Event::XinputDeviceButtonRelease(value) | Event::XinputDeviceKeyPress(value) | Event::XinputDeviceKeyRelease(value) | Event::XinputDeviceMotionNotify(value) | Event::XinputProximityIn(value) | Event::XinputProximityOut(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+/// Besides `Event::XinputDeviceButtonPress`, this also accepts `Event::XinputDeviceButtonRelease`, `Event::XinputDeviceKeyPress`, `Event::XinputDeviceKeyRelease`, `Event::XinputDeviceMotionNotify`, `Event::XinputProximityIn`, `Event::XinputProximityOut`. They use the same struct; match on [`Event`] to tell them apart.
+#[cfg(feature = "xinput")]
+impl<'a> TryFrom<&'a Event> for &'a xinput::DeviceKeyPressEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XinputDeviceButtonPress(value) | Event::XinputDeviceButtonRelease(value) | Event::XinputDeviceKeyPress(value) | Event::XinputDeviceKeyRelease(value) | Event::XinputDeviceMotionNotify(value) | Event::XinputProximityIn(value) | Event::XinputProximityOut(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::DeviceButtonStateNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XinputDeviceButtonStateNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "xinput")]
+impl<'a> TryFrom<&'a Event> for &'a xinput::DeviceButtonStateNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XinputDeviceButtonStateNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::DeviceChangedEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XinputDeviceChanged(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "xinput")]
+impl<'a> TryFrom<&'a Event> for &'a xinput::DeviceChangedEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XinputDeviceChanged(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+/// Besides `Event::XinputDeviceFocusIn`, this also accepts `Event::XinputDeviceFocusOut`. They use the same struct; match on [`Event`] to tell them apart.
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::DeviceFocusInEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XinputDeviceFocusIn(value) | Event::XinputDeviceFocusOut(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+/// Besides `Event::XinputDeviceFocusIn`, this also accepts `Event::XinputDeviceFocusOut`. They use the same struct; match on [`Event`] to tell them apart.
+#[cfg(feature = "xinput")]
+impl<'a> TryFrom<&'a Event> for &'a xinput::DeviceFocusInEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XinputDeviceFocusIn(value) | Event::XinputDeviceFocusOut(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::DeviceKeyStateNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XinputDeviceKeyStateNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "xinput")]
+impl<'a> TryFrom<&'a Event> for &'a xinput::DeviceKeyStateNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XinputDeviceKeyStateNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::DeviceMappingNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XinputDeviceMappingNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "xinput")]
+impl<'a> TryFrom<&'a Event> for &'a xinput::DeviceMappingNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XinputDeviceMappingNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::DevicePresenceNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XinputDevicePresenceNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "xinput")]
+impl<'a> TryFrom<&'a Event> for &'a xinput::DevicePresenceNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XinputDevicePresenceNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::DevicePropertyNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XinputDevicePropertyNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "xinput")]
+impl<'a> TryFrom<&'a Event> for &'a xinput::DevicePropertyNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XinputDevicePropertyNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::DeviceStateNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XinputDeviceStateNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "xinput")]
+impl<'a> TryFrom<&'a Event> for &'a xinput::DeviceStateNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XinputDeviceStateNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::DeviceValuatorEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XinputDeviceValuator(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "xinput")]
+impl<'a> TryFrom<&'a Event> for &'a xinput::DeviceValuatorEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XinputDeviceValuator(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+/// Besides `Event::XinputEnter`, this also accepts `Event::XinputFocusIn`, `Event::XinputFocusOut`, `Event::XinputLeave`. They use the same struct; match on [`Event`] to tell them apart.
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::EnterEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XinputEnter(value) | Event::XinputFocusIn(value) | Event::XinputFocusOut(value) | Event::XinputLeave(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+/// Besides `Event::XinputEnter`, this also accepts `Event::XinputFocusIn`, `Event::XinputFocusOut`, `Event::XinputLeave`. They use the same struct; match on [`Event`] to tell them apart.
+#[cfg(feature = "xinput")]
+impl<'a> TryFrom<&'a Event> for &'a xinput::EnterEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XinputEnter(value) | Event::XinputFocusIn(value) | Event::XinputFocusOut(value) | Event::XinputLeave(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+/// Besides `Event::XinputGesturePinchBegin`, this also accepts `Event::XinputGesturePinchEnd`, `Event::XinputGesturePinchUpdate`. They use the same struct; match on [`Event`] to tell them apart.
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::GesturePinchBeginEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XinputGesturePinchBegin(value) | Event::XinputGesturePinchEnd(value) | Event::XinputGesturePinchUpdate(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+/// Besides `Event::XinputGesturePinchBegin`, this also accepts `Event::XinputGesturePinchEnd`, `Event::XinputGesturePinchUpdate`. They use the same struct; match on [`Event`] to tell them apart.
+#[cfg(feature = "xinput")]
+impl<'a> TryFrom<&'a Event> for &'a xinput::GesturePinchBeginEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XinputGesturePinchBegin(value) | Event::XinputGesturePinchEnd(value) | Event::XinputGesturePinchUpdate(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+/// Besides `Event::XinputGestureSwipeBegin`, this also accepts `Event::XinputGestureSwipeEnd`, `Event::XinputGestureSwipeUpdate`. They use the same struct; match on [`Event`] to tell them apart.
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::GestureSwipeBeginEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XinputGestureSwipeBegin(value) | Event::XinputGestureSwipeEnd(value) | Event::XinputGestureSwipeUpdate(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+/// Besides `Event::XinputGestureSwipeBegin`, this also accepts `Event::XinputGestureSwipeEnd`, `Event::XinputGestureSwipeUpdate`. They use the same struct; match on [`Event`] to tell them apart.
+#[cfg(feature = "xinput")]
+impl<'a> TryFrom<&'a Event> for &'a xinput::GestureSwipeBeginEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XinputGestureSwipeBegin(value) | Event::XinputGestureSwipeEnd(value) | Event::XinputGestureSwipeUpdate(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::HierarchyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XinputHierarchy(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "xinput")]
+impl<'a> TryFrom<&'a Event> for &'a xinput::HierarchyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XinputHierarchy(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+/// Besides `Event::XinputKeyPress`, this also accepts `Event::XinputKeyRelease`. They use the same struct; match on [`Event`] to tell them apart.
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::KeyPressEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XinputKeyPress(value) | Event::XinputKeyRelease(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+/// Besides `Event::XinputKeyPress`, this also accepts `Event::XinputKeyRelease`. They use the same struct; match on [`Event`] to tell them apart.
+#[cfg(feature = "xinput")]
+impl<'a> TryFrom<&'a Event> for &'a xinput::KeyPressEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XinputKeyPress(value) | Event::XinputKeyRelease(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::PropertyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XinputProperty(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "xinput")]
+impl<'a> TryFrom<&'a Event> for &'a xinput::PropertyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XinputProperty(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+/// Besides `Event::XinputRawButtonPress`, this also accepts `Event::XinputRawButtonRelease`, `Event::XinputRawMotion`. They use the same struct; match on [`Event`] to tell them apart.
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::RawButtonPressEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XinputRawButtonPress(value) | Event::XinputRawButtonRelease(value) | Event::XinputRawMotion(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+/// Besides `Event::XinputRawButtonPress`, this also accepts `Event::XinputRawButtonRelease`, `Event::XinputRawMotion`. They use the same struct; match on [`Event`] to tell them apart.
+#[cfg(feature = "xinput")]
+impl<'a> TryFrom<&'a Event> for &'a xinput::RawButtonPressEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XinputRawButtonPress(value) | Event::XinputRawButtonRelease(value) | Event::XinputRawMotion(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+/// Besides `Event::XinputRawKeyPress`, this also accepts `Event::XinputRawKeyRelease`. They use the same struct; match on [`Event`] to tell them apart.
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::RawKeyPressEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XinputRawKeyPress(value) | Event::XinputRawKeyRelease(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+/// Besides `Event::XinputRawKeyPress`, this also accepts `Event::XinputRawKeyRelease`. They use the same struct; match on [`Event`] to tell them apart.
+#[cfg(feature = "xinput")]
+impl<'a> TryFrom<&'a Event> for &'a xinput::RawKeyPressEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XinputRawKeyPress(value) | Event::XinputRawKeyRelease(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+/// Besides `Event::XinputRawTouchBegin`, this also accepts `Event::XinputRawTouchEnd`, `Event::XinputRawTouchUpdate`. They use the same struct; match on [`Event`] to tell them apart.
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::RawTouchBeginEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XinputRawTouchBegin(value) | Event::XinputRawTouchEnd(value) | Event::XinputRawTouchUpdate(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+/// Besides `Event::XinputRawTouchBegin`, this also accepts `Event::XinputRawTouchEnd`, `Event::XinputRawTouchUpdate`. They use the same struct; match on [`Event`] to tell them apart.
+#[cfg(feature = "xinput")]
+impl<'a> TryFrom<&'a Event> for &'a xinput::RawTouchBeginEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XinputRawTouchBegin(value) | Event::XinputRawTouchEnd(value) | Event::XinputRawTouchUpdate(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+/// Besides `Event::XinputTouchBegin`, this also accepts `Event::XinputTouchEnd`, `Event::XinputTouchUpdate`. They use the same struct; match on [`Event`] to tell them apart.
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::TouchBeginEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XinputTouchBegin(value) | Event::XinputTouchEnd(value) | Event::XinputTouchUpdate(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+/// Besides `Event::XinputTouchBegin`, this also accepts `Event::XinputTouchEnd`, `Event::XinputTouchUpdate`. They use the same struct; match on [`Event`] to tell them apart.
+#[cfg(feature = "xinput")]
+impl<'a> TryFrom<&'a Event> for &'a xinput::TouchBeginEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XinputTouchBegin(value) | Event::XinputTouchEnd(value) | Event::XinputTouchUpdate(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for xinput::TouchOwnershipEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XinputTouchOwnership(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "xinput")]
+impl<'a> TryFrom<&'a Event> for &'a xinput::TouchOwnershipEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XinputTouchOwnership(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xkb")]
+impl TryFrom<Event> for xkb::AccessXNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XkbAccessXNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "xkb")]
+impl<'a> TryFrom<&'a Event> for &'a xkb::AccessXNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XkbAccessXNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xkb")]
+impl TryFrom<Event> for xkb::ActionMessageEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XkbActionMessage(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "xkb")]
+impl<'a> TryFrom<&'a Event> for &'a xkb::ActionMessageEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XkbActionMessage(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xkb")]
+impl TryFrom<Event> for xkb::BellNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XkbBellNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "xkb")]
+impl<'a> TryFrom<&'a Event> for &'a xkb::BellNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XkbBellNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xkb")]
+impl TryFrom<Event> for xkb::CompatMapNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XkbCompatMapNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "xkb")]
+impl<'a> TryFrom<&'a Event> for &'a xkb::CompatMapNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XkbCompatMapNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xkb")]
+impl TryFrom<Event> for xkb::ControlsNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XkbControlsNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "xkb")]
+impl<'a> TryFrom<&'a Event> for &'a xkb::ControlsNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XkbControlsNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xkb")]
+impl TryFrom<Event> for xkb::ExtensionDeviceNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XkbExtensionDeviceNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "xkb")]
+impl<'a> TryFrom<&'a Event> for &'a xkb::ExtensionDeviceNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XkbExtensionDeviceNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xkb")]
+impl TryFrom<Event> for xkb::IndicatorMapNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XkbIndicatorMapNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "xkb")]
+impl<'a> TryFrom<&'a Event> for &'a xkb::IndicatorMapNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XkbIndicatorMapNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xkb")]
+impl TryFrom<Event> for xkb::IndicatorStateNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XkbIndicatorStateNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "xkb")]
+impl<'a> TryFrom<&'a Event> for &'a xkb::IndicatorStateNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XkbIndicatorStateNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xkb")]
+impl TryFrom<Event> for xkb::MapNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XkbMapNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "xkb")]
+impl<'a> TryFrom<&'a Event> for &'a xkb::MapNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XkbMapNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xkb")]
+impl TryFrom<Event> for xkb::NamesNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XkbNamesNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "xkb")]
+impl<'a> TryFrom<&'a Event> for &'a xkb::NamesNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XkbNamesNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xkb")]
+impl TryFrom<Event> for xkb::NewKeyboardNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XkbNewKeyboardNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "xkb")]
+impl<'a> TryFrom<&'a Event> for &'a xkb::NewKeyboardNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XkbNewKeyboardNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xkb")]
+impl TryFrom<Event> for xkb::StateNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XkbStateNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "xkb")]
+impl<'a> TryFrom<&'a Event> for &'a xkb::StateNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XkbStateNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xprint")]
+impl TryFrom<Event> for xprint::AttributNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XprintAttributNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "xprint")]
+impl<'a> TryFrom<&'a Event> for &'a xprint::AttributNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XprintAttributNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xprint")]
+impl TryFrom<Event> for xprint::NotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XprintNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "xprint")]
+impl<'a> TryFrom<&'a Event> for &'a xprint::NotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XprintNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xv")]
+impl TryFrom<Event> for xv::PortNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XvPortNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "xv")]
+impl<'a> TryFrom<&'a Event> for &'a xv::PortNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XvPortNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+#[cfg(feature = "xv")]
+impl TryFrom<Event> for xv::VideoNotifyEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XvVideoNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+#[cfg(feature = "xv")]
+impl<'a> TryFrom<&'a Event> for &'a xv::VideoNotifyEvent {
+    type Error = &'a Event;
+    fn try_from(event: &'a Event) -> Result<Self, &'a Event> {
+        match event {
+            Event::XvVideoNotify(value) => Ok(value),
+            event => Err(event),
+        }
+    }
+}
+
+/// Enumeration of the events of the `damage` extension.
+///
+/// An [`Event`] can be converted into this enum with `TryFrom`, which allows to
+/// match on the events of one extension without listing the events of all others.
+#[cfg(feature = "damage")]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum DamageEvent {
+    Notify(damage::NotifyEvent),
+}
+#[cfg(feature = "damage")]
+impl From<DamageEvent> for Event {
+    fn from(event: DamageEvent) -> Self {
+        match event {
+            DamageEvent::Notify(value) => Event::DamageNotify(value),
+        }
+    }
+}
+#[cfg(feature = "damage")]
+impl TryFrom<Event> for DamageEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::DamageNotify(value) => Ok(DamageEvent::Notify(value)),
+            event => Err(event),
+        }
+    }
+}
+
+/// Enumeration of the events of the `dpms` extension.
+///
+/// An [`Event`] can be converted into this enum with `TryFrom`, which allows to
+/// match on the events of one extension without listing the events of all others.
+#[cfg(feature = "dpms")]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum DpmsEvent {
+    InfoNotify(dpms::InfoNotifyEvent),
+}
+#[cfg(feature = "dpms")]
+impl From<DpmsEvent> for Event {
+    fn from(event: DpmsEvent) -> Self {
+        match event {
+            DpmsEvent::InfoNotify(value) => Event::DpmsInfoNotify(value),
+        }
+    }
+}
+#[cfg(feature = "dpms")]
+impl TryFrom<Event> for DpmsEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::DpmsInfoNotify(value) => Ok(DpmsEvent::InfoNotify(value)),
+            event => Err(event),
+        }
+    }
+}
+
+/// Enumeration of the events of the `dri2` extension.
+///
+/// An [`Event`] can be converted into this enum with `TryFrom`, which allows to
+/// match on the events of one extension without listing the events of all others.
+#[cfg(feature = "dri2")]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum Dri2Event {
+    BufferSwapComplete(dri2::BufferSwapCompleteEvent),
+    InvalidateBuffers(dri2::InvalidateBuffersEvent),
+}
+#[cfg(feature = "dri2")]
+impl From<Dri2Event> for Event {
+    fn from(event: Dri2Event) -> Self {
+        match event {
+            Dri2Event::BufferSwapComplete(value) => Event::Dri2BufferSwapComplete(value),
+            Dri2Event::InvalidateBuffers(value) => Event::Dri2InvalidateBuffers(value),
+        }
+    }
+}
+#[cfg(feature = "dri2")]
+impl TryFrom<Event> for Dri2Event {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::Dri2BufferSwapComplete(value) => Ok(Dri2Event::BufferSwapComplete(value)),
+            Event::Dri2InvalidateBuffers(value) => Ok(Dri2Event::InvalidateBuffers(value)),
+            event => Err(event),
+        }
+    }
+}
+
+/// Enumeration of the events of the `glx` extension.
+///
+/// An [`Event`] can be converted into this enum with `TryFrom`, which allows to
+/// match on the events of one extension without listing the events of all others.
+#[cfg(feature = "glx")]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum GlxEvent {
+    BufferSwapComplete(glx::BufferSwapCompleteEvent),
+    PbufferClobber(glx::PbufferClobberEvent),
+}
+#[cfg(feature = "glx")]
+impl From<GlxEvent> for Event {
+    fn from(event: GlxEvent) -> Self {
+        match event {
+            GlxEvent::BufferSwapComplete(value) => Event::GlxBufferSwapComplete(value),
+            GlxEvent::PbufferClobber(value) => Event::GlxPbufferClobber(value),
+        }
+    }
+}
+#[cfg(feature = "glx")]
+impl TryFrom<Event> for GlxEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::GlxBufferSwapComplete(value) => Ok(GlxEvent::BufferSwapComplete(value)),
+            Event::GlxPbufferClobber(value) => Ok(GlxEvent::PbufferClobber(value)),
+            event => Err(event),
+        }
+    }
+}
+
+/// Enumeration of the events of the `present` extension.
+///
+/// An [`Event`] can be converted into this enum with `TryFrom`, which allows to
+/// match on the events of one extension without listing the events of all others.
+#[cfg(feature = "present")]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum PresentEvent {
+    CompleteNotify(present::CompleteNotifyEvent),
+    ConfigureNotify(present::ConfigureNotifyEvent),
+    Generic(present::GenericEvent),
+    IdleNotify(present::IdleNotifyEvent),
+    RedirectNotify(present::RedirectNotifyEvent),
+}
+#[cfg(feature = "present")]
+impl From<PresentEvent> for Event {
+    fn from(event: PresentEvent) -> Self {
+        match event {
+            PresentEvent::CompleteNotify(value) => Event::PresentCompleteNotify(value),
+            PresentEvent::ConfigureNotify(value) => Event::PresentConfigureNotify(value),
+            PresentEvent::Generic(value) => Event::PresentGeneric(value),
+            PresentEvent::IdleNotify(value) => Event::PresentIdleNotify(value),
+            PresentEvent::RedirectNotify(value) => Event::PresentRedirectNotify(value),
+        }
+    }
+}
+#[cfg(feature = "present")]
+impl TryFrom<Event> for PresentEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::PresentCompleteNotify(value) => Ok(PresentEvent::CompleteNotify(value)),
+            Event::PresentConfigureNotify(value) => Ok(PresentEvent::ConfigureNotify(value)),
+            Event::PresentGeneric(value) => Ok(PresentEvent::Generic(value)),
+            Event::PresentIdleNotify(value) => Ok(PresentEvent::IdleNotify(value)),
+            Event::PresentRedirectNotify(value) => Ok(PresentEvent::RedirectNotify(value)),
+            event => Err(event),
+        }
+    }
+}
+
+/// Enumeration of the events of the `randr` extension.
+///
+/// An [`Event`] can be converted into this enum with `TryFrom`, which allows to
+/// match on the events of one extension without listing the events of all others.
+#[cfg(feature = "randr")]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum RandrEvent {
+    Notify(randr::NotifyEvent),
+    ScreenChangeNotify(randr::ScreenChangeNotifyEvent),
+}
+#[cfg(feature = "randr")]
+impl From<RandrEvent> for Event {
+    fn from(event: RandrEvent) -> Self {
+        match event {
+            RandrEvent::Notify(value) => Event::RandrNotify(value),
+            RandrEvent::ScreenChangeNotify(value) => Event::RandrScreenChangeNotify(value),
+        }
+    }
+}
+#[cfg(feature = "randr")]
+impl TryFrom<Event> for RandrEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::RandrNotify(value) => Ok(RandrEvent::Notify(value)),
+            Event::RandrScreenChangeNotify(value) => Ok(RandrEvent::ScreenChangeNotify(value)),
+            event => Err(event),
+        }
+    }
+}
+
+/// Enumeration of the events of the `screensaver` extension.
+///
+/// An [`Event`] can be converted into this enum with `TryFrom`, which allows to
+/// match on the events of one extension without listing the events of all others.
+#[cfg(feature = "screensaver")]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum ScreensaverEvent {
+    Notify(screensaver::NotifyEvent),
+}
+#[cfg(feature = "screensaver")]
+impl From<ScreensaverEvent> for Event {
+    fn from(event: ScreensaverEvent) -> Self {
+        match event {
+            ScreensaverEvent::Notify(value) => Event::ScreensaverNotify(value),
+        }
+    }
+}
+#[cfg(feature = "screensaver")]
+impl TryFrom<Event> for ScreensaverEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::ScreensaverNotify(value) => Ok(ScreensaverEvent::Notify(value)),
+            event => Err(event),
+        }
+    }
+}
+
+/// Enumeration of the events of the `shape` extension.
+///
+/// An [`Event`] can be converted into this enum with `TryFrom`, which allows to
+/// match on the events of one extension without listing the events of all others.
+#[cfg(feature = "shape")]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum ShapeEvent {
+    Notify(shape::NotifyEvent),
+}
+#[cfg(feature = "shape")]
+impl From<ShapeEvent> for Event {
+    fn from(event: ShapeEvent) -> Self {
+        match event {
+            ShapeEvent::Notify(value) => Event::ShapeNotify(value),
+        }
+    }
+}
+#[cfg(feature = "shape")]
+impl TryFrom<Event> for ShapeEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::ShapeNotify(value) => Ok(ShapeEvent::Notify(value)),
+            event => Err(event),
+        }
+    }
+}
+
+/// Enumeration of the events of the `shm` extension.
+///
+/// An [`Event`] can be converted into this enum with `TryFrom`, which allows to
+/// match on the events of one extension without listing the events of all others.
+#[cfg(feature = "shm")]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum ShmEvent {
+    Completion(shm::CompletionEvent),
+}
+#[cfg(feature = "shm")]
+impl From<ShmEvent> for Event {
+    fn from(event: ShmEvent) -> Self {
+        match event {
+            ShmEvent::Completion(value) => Event::ShmCompletion(value),
+        }
+    }
+}
+#[cfg(feature = "shm")]
+impl TryFrom<Event> for ShmEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::ShmCompletion(value) => Ok(ShmEvent::Completion(value)),
+            event => Err(event),
+        }
+    }
+}
+
+/// Enumeration of the events of the `sync` extension.
+///
+/// An [`Event`] can be converted into this enum with `TryFrom`, which allows to
+/// match on the events of one extension without listing the events of all others.
+#[cfg(feature = "sync")]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum SyncEvent {
+    AlarmNotify(sync::AlarmNotifyEvent),
+    CounterNotify(sync::CounterNotifyEvent),
+}
+#[cfg(feature = "sync")]
+impl From<SyncEvent> for Event {
+    fn from(event: SyncEvent) -> Self {
+        match event {
+            SyncEvent::AlarmNotify(value) => Event::SyncAlarmNotify(value),
+            SyncEvent::CounterNotify(value) => Event::SyncCounterNotify(value),
+        }
+    }
+}
+#[cfg(feature = "sync")]
+impl TryFrom<Event> for SyncEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::SyncAlarmNotify(value) => Ok(SyncEvent::AlarmNotify(value)),
+            Event::SyncCounterNotify(value) => Ok(SyncEvent::CounterNotify(value)),
+            event => Err(event),
+        }
+    }
+}
+
+/// Enumeration of the events of the `xfixes` extension.
+///
+/// An [`Event`] can be converted into this enum with `TryFrom`, which allows to
+/// match on the events of one extension without listing the events of all others.
+#[cfg(feature = "xfixes")]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum XfixesEvent {
+    CursorNotify(xfixes::CursorNotifyEvent),
+    SelectionNotify(xfixes::SelectionNotifyEvent),
+}
+#[cfg(feature = "xfixes")]
+impl From<XfixesEvent> for Event {
+    fn from(event: XfixesEvent) -> Self {
+        match event {
+            XfixesEvent::CursorNotify(value) => Event::XfixesCursorNotify(value),
+            XfixesEvent::SelectionNotify(value) => Event::XfixesSelectionNotify(value),
+        }
+    }
+}
+#[cfg(feature = "xfixes")]
+impl TryFrom<Event> for XfixesEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XfixesCursorNotify(value) => Ok(XfixesEvent::CursorNotify(value)),
+            Event::XfixesSelectionNotify(value) => Ok(XfixesEvent::SelectionNotify(value)),
+            event => Err(event),
+        }
+    }
+}
+
+/// Enumeration of the events of the `xinput` extension.
+///
+/// An [`Event`] can be converted into this enum with `TryFrom`, which allows to
+/// match on the events of one extension without listing the events of all others.
+#[cfg(feature = "xinput")]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum XinputEvent {
+    BarrierHit(xinput::BarrierHitEvent),
+    BarrierLeave(xinput::BarrierLeaveEvent),
+    ButtonPress(xinput::ButtonPressEvent),
+    ButtonRelease(xinput::ButtonReleaseEvent),
+    ChangeDeviceNotify(xinput::ChangeDeviceNotifyEvent),
+    DeviceButtonPress(xinput::DeviceButtonPressEvent),
+    DeviceButtonRelease(xinput::DeviceButtonReleaseEvent),
+    DeviceButtonStateNotify(xinput::DeviceButtonStateNotifyEvent),
+    DeviceChanged(xinput::DeviceChangedEvent),
+    DeviceFocusIn(xinput::DeviceFocusInEvent),
+    DeviceFocusOut(xinput::DeviceFocusOutEvent),
+    DeviceKeyPress(xinput::DeviceKeyPressEvent),
+    DeviceKeyRelease(xinput::DeviceKeyReleaseEvent),
+    DeviceKeyStateNotify(xinput::DeviceKeyStateNotifyEvent),
+    DeviceMappingNotify(xinput::DeviceMappingNotifyEvent),
+    DeviceMotionNotify(xinput::DeviceMotionNotifyEvent),
+    DevicePresenceNotify(xinput::DevicePresenceNotifyEvent),
+    DevicePropertyNotify(xinput::DevicePropertyNotifyEvent),
+    DeviceStateNotify(xinput::DeviceStateNotifyEvent),
+    DeviceValuator(xinput::DeviceValuatorEvent),
+    Enter(xinput::EnterEvent),
+    FocusIn(xinput::FocusInEvent),
+    FocusOut(xinput::FocusOutEvent),
+    GesturePinchBegin(xinput::GesturePinchBeginEvent),
+    GesturePinchEnd(xinput::GesturePinchEndEvent),
+    GesturePinchUpdate(xinput::GesturePinchUpdateEvent),
+    GestureSwipeBegin(xinput::GestureSwipeBeginEvent),
+    GestureSwipeEnd(xinput::GestureSwipeEndEvent),
+    GestureSwipeUpdate(xinput::GestureSwipeUpdateEvent),
+    Hierarchy(xinput::HierarchyEvent),
+    KeyPress(xinput::KeyPressEvent),
+    KeyRelease(xinput::KeyReleaseEvent),
+    Leave(xinput::LeaveEvent),
+    Motion(xinput::MotionEvent),
+    Property(xinput::PropertyEvent),
+    ProximityIn(xinput::ProximityInEvent),
+    ProximityOut(xinput::ProximityOutEvent),
+    RawButtonPress(xinput::RawButtonPressEvent),
+    RawButtonRelease(xinput::RawButtonReleaseEvent),
+    RawKeyPress(xinput::RawKeyPressEvent),
+    RawKeyRelease(xinput::RawKeyReleaseEvent),
+    RawMotion(xinput::RawMotionEvent),
+    RawTouchBegin(xinput::RawTouchBeginEvent),
+    RawTouchEnd(xinput::RawTouchEndEvent),
+    RawTouchUpdate(xinput::RawTouchUpdateEvent),
+    TouchBegin(xinput::TouchBeginEvent),
+    TouchEnd(xinput::TouchEndEvent),
+    TouchOwnership(xinput::TouchOwnershipEvent),
+    TouchUpdate(xinput::TouchUpdateEvent),
+}
+#[cfg(feature = "xinput")]
+impl From<XinputEvent> for Event {
+    fn from(event: XinputEvent) -> Self {
+        match event {
+            XinputEvent::BarrierHit(value) => Event::XinputBarrierHit(value),
+            XinputEvent::BarrierLeave(value) => Event::XinputBarrierLeave(value),
+            XinputEvent::ButtonPress(value) => Event::XinputButtonPress(value),
+            XinputEvent::ButtonRelease(value) => Event::XinputButtonRelease(value),
+            XinputEvent::ChangeDeviceNotify(value) => Event::XinputChangeDeviceNotify(value),
+            XinputEvent::DeviceButtonPress(value) => Event::XinputDeviceButtonPress(value),
+            XinputEvent::DeviceButtonRelease(value) => Event::XinputDeviceButtonRelease(value),
+            XinputEvent::DeviceButtonStateNotify(value) => Event::XinputDeviceButtonStateNotify(value),
+            XinputEvent::DeviceChanged(value) => Event::XinputDeviceChanged(value),
+            XinputEvent::DeviceFocusIn(value) => Event::XinputDeviceFocusIn(value),
+            XinputEvent::DeviceFocusOut(value) => Event::XinputDeviceFocusOut(value),
+            XinputEvent::DeviceKeyPress(value) => Event::XinputDeviceKeyPress(value),
+            XinputEvent::DeviceKeyRelease(value) => Event::XinputDeviceKeyRelease(value),
+            XinputEvent::DeviceKeyStateNotify(value) => Event::XinputDeviceKeyStateNotify(value),
+            XinputEvent::DeviceMappingNotify(value) => Event::XinputDeviceMappingNotify(value),
+            XinputEvent::DeviceMotionNotify(value) => Event::XinputDeviceMotionNotify(value),
+            XinputEvent::DevicePresenceNotify(value) => Event::XinputDevicePresenceNotify(value),
+            XinputEvent::DevicePropertyNotify(value) => Event::XinputDevicePropertyNotify(value),
+            XinputEvent::DeviceStateNotify(value) => Event::XinputDeviceStateNotify(value),
+            XinputEvent::DeviceValuator(value) => Event::XinputDeviceValuator(value),
+            XinputEvent::Enter(value) => Event::XinputEnter(value),
+            XinputEvent::FocusIn(value) => Event::XinputFocusIn(value),
+            XinputEvent::FocusOut(value) => Event::XinputFocusOut(value),
+            XinputEvent::GesturePinchBegin(value) => Event::XinputGesturePinchBegin(value),
+            XinputEvent::GesturePinchEnd(value) => Event::XinputGesturePinchEnd(value),
+            XinputEvent::GesturePinchUpdate(value) => Event::XinputGesturePinchUpdate(value),
+            XinputEvent::GestureSwipeBegin(value) => Event::XinputGestureSwipeBegin(value),
+            XinputEvent::GestureSwipeEnd(value) => Event::XinputGestureSwipeEnd(value),
+            XinputEvent::GestureSwipeUpdate(value) => Event::XinputGestureSwipeUpdate(value),
+            XinputEvent::Hierarchy(value) => Event::XinputHierarchy(value),
+            XinputEvent::KeyPress(value) => Event::XinputKeyPress(value),
+            XinputEvent::KeyRelease(value) => Event::XinputKeyRelease(value),
+            XinputEvent::Leave(value) => Event::XinputLeave(value),
+            XinputEvent::Motion(value) => Event::XinputMotion(value),
+            XinputEvent::Property(value) => Event::XinputProperty(value),
+            XinputEvent::ProximityIn(value) => Event::XinputProximityIn(value),
+            XinputEvent::ProximityOut(value) => Event::XinputProximityOut(value),
+            XinputEvent::RawButtonPress(value) => Event::XinputRawButtonPress(value),
+            XinputEvent::RawButtonRelease(value) => Event::XinputRawButtonRelease(value),
+            XinputEvent::RawKeyPress(value) => Event::XinputRawKeyPress(value),
+            XinputEvent::RawKeyRelease(value) => Event::XinputRawKeyRelease(value),
+            XinputEvent::RawMotion(value) => Event::XinputRawMotion(value),
+            XinputEvent::RawTouchBegin(value) => Event::XinputRawTouchBegin(value),
+            XinputEvent::RawTouchEnd(value) => Event::XinputRawTouchEnd(value),
+            XinputEvent::RawTouchUpdate(value) => Event::XinputRawTouchUpdate(value),
+            XinputEvent::TouchBegin(value) => Event::XinputTouchBegin(value),
+            XinputEvent::TouchEnd(value) => Event::XinputTouchEnd(value),
+            XinputEvent::TouchOwnership(value) => Event::XinputTouchOwnership(value),
+            XinputEvent::TouchUpdate(value) => Event::XinputTouchUpdate(value),
+        }
+    }
+}
+#[cfg(feature = "xinput")]
+impl TryFrom<Event> for XinputEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XinputBarrierHit(value) => Ok(XinputEvent::BarrierHit(value)),
+            Event::XinputBarrierLeave(value) => Ok(XinputEvent::BarrierLeave(value)),
+            Event::XinputButtonPress(value) => Ok(XinputEvent::ButtonPress(value)),
+            Event::XinputButtonRelease(value) => Ok(XinputEvent::ButtonRelease(value)),
+            Event::XinputChangeDeviceNotify(value) => Ok(XinputEvent::ChangeDeviceNotify(value)),
+            Event::XinputDeviceButtonPress(value) => Ok(XinputEvent::DeviceButtonPress(value)),
+            Event::XinputDeviceButtonRelease(value) => Ok(XinputEvent::DeviceButtonRelease(value)),
+            Event::XinputDeviceButtonStateNotify(value) => Ok(XinputEvent::DeviceButtonStateNotify(value)),
+            Event::XinputDeviceChanged(value) => Ok(XinputEvent::DeviceChanged(value)),
+            Event::XinputDeviceFocusIn(value) => Ok(XinputEvent::DeviceFocusIn(value)),
+            Event::XinputDeviceFocusOut(value) => Ok(XinputEvent::DeviceFocusOut(value)),
+            Event::XinputDeviceKeyPress(value) => Ok(XinputEvent::DeviceKeyPress(value)),
+            Event::XinputDeviceKeyRelease(value) => Ok(XinputEvent::DeviceKeyRelease(value)),
+            Event::XinputDeviceKeyStateNotify(value) => Ok(XinputEvent::DeviceKeyStateNotify(value)),
+            Event::XinputDeviceMappingNotify(value) => Ok(XinputEvent::DeviceMappingNotify(value)),
+            Event::XinputDeviceMotionNotify(value) => Ok(XinputEvent::DeviceMotionNotify(value)),
+            Event::XinputDevicePresenceNotify(value) => Ok(XinputEvent::DevicePresenceNotify(value)),
+            Event::XinputDevicePropertyNotify(value) => Ok(XinputEvent::DevicePropertyNotify(value)),
+            Event::XinputDeviceStateNotify(value) => Ok(XinputEvent::DeviceStateNotify(value)),
+            Event::XinputDeviceValuator(value) => Ok(XinputEvent::DeviceValuator(value)),
+            Event::XinputEnter(value) => Ok(XinputEvent::Enter(value)),
+            Event::XinputFocusIn(value) => Ok(XinputEvent::FocusIn(value)),
+            Event::XinputFocusOut(value) => Ok(XinputEvent::FocusOut(value)),
+            Event::XinputGesturePinchBegin(value) => Ok(XinputEvent::GesturePinchBegin(value)),
+            Event::XinputGesturePinchEnd(value) => Ok(XinputEvent::GesturePinchEnd(value)),
+            Event::XinputGesturePinchUpdate(value) => Ok(XinputEvent::GesturePinchUpdate(value)),
+            Event::XinputGestureSwipeBegin(value) => Ok(XinputEvent::GestureSwipeBegin(value)),
+            Event::XinputGestureSwipeEnd(value) => Ok(XinputEvent::GestureSwipeEnd(value)),
+            Event::XinputGestureSwipeUpdate(value) => Ok(XinputEvent::GestureSwipeUpdate(value)),
+            Event::XinputHierarchy(value) => Ok(XinputEvent::Hierarchy(value)),
+            Event::XinputKeyPress(value) => Ok(XinputEvent::KeyPress(value)),
+            Event::XinputKeyRelease(value) => Ok(XinputEvent::KeyRelease(value)),
+            Event::XinputLeave(value) => Ok(XinputEvent::Leave(value)),
+            Event::XinputMotion(value) => Ok(XinputEvent::Motion(value)),
+            Event::XinputProperty(value) => Ok(XinputEvent::Property(value)),
+            Event::XinputProximityIn(value) => Ok(XinputEvent::ProximityIn(value)),
+            Event::XinputProximityOut(value) => Ok(XinputEvent::ProximityOut(value)),
+            Event::XinputRawButtonPress(value) => Ok(XinputEvent::RawButtonPress(value)),
+            Event::XinputRawButtonRelease(value) => Ok(XinputEvent::RawButtonRelease(value)),
+            Event::XinputRawKeyPress(value) => Ok(XinputEvent::RawKeyPress(value)),
+            Event::XinputRawKeyRelease(value) => Ok(XinputEvent::RawKeyRelease(value)),
+            Event::XinputRawMotion(value) => Ok(XinputEvent::RawMotion(value)),
+            Event::XinputRawTouchBegin(value) => Ok(XinputEvent::RawTouchBegin(value)),
+            Event::XinputRawTouchEnd(value) => Ok(XinputEvent::RawTouchEnd(value)),
+            Event::XinputRawTouchUpdate(value) => Ok(XinputEvent::RawTouchUpdate(value)),
+            Event::XinputTouchBegin(value) => Ok(XinputEvent::TouchBegin(value)),
+            Event::XinputTouchEnd(value) => Ok(XinputEvent::TouchEnd(value)),
+            Event::XinputTouchOwnership(value) => Ok(XinputEvent::TouchOwnership(value)),
+            Event::XinputTouchUpdate(value) => Ok(XinputEvent::TouchUpdate(value)),
+            event => Err(event),
+        }
+    }
+}
+
+/// Enumeration of the events of the `xkb` extension.
+///
+/// An [`Event`] can be converted into this enum with `TryFrom`, which allows to
+/// match on the events of one extension without listing the events of all others.
+#[cfg(feature = "xkb")]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum XkbEvent {
+    AccessXNotify(xkb::AccessXNotifyEvent),
+    ActionMessage(xkb::ActionMessageEvent),
+    BellNotify(xkb::BellNotifyEvent),
+    CompatMapNotify(xkb::CompatMapNotifyEvent),
+    ControlsNotify(xkb::ControlsNotifyEvent),
+    ExtensionDeviceNotify(xkb::ExtensionDeviceNotifyEvent),
+    IndicatorMapNotify(xkb::IndicatorMapNotifyEvent),
+    IndicatorStateNotify(xkb::IndicatorStateNotifyEvent),
+    MapNotify(xkb::MapNotifyEvent),
+    NamesNotify(xkb::NamesNotifyEvent),
+    NewKeyboardNotify(xkb::NewKeyboardNotifyEvent),
+    StateNotify(xkb::StateNotifyEvent),
+}
+#[cfg(feature = "xkb")]
+impl From<XkbEvent> for Event {
+    fn from(event: XkbEvent) -> Self {
+        match event {
+            XkbEvent::AccessXNotify(value) => Event::XkbAccessXNotify(value),
+            XkbEvent::ActionMessage(value) => Event::XkbActionMessage(value),
+            XkbEvent::BellNotify(value) => Event::XkbBellNotify(value),
+            XkbEvent::CompatMapNotify(value) => Event::XkbCompatMapNotify(value),
+            XkbEvent::ControlsNotify(value) => Event::XkbControlsNotify(value),
+            XkbEvent::ExtensionDeviceNotify(value) => Event::XkbExtensionDeviceNotify(value),
+            XkbEvent::IndicatorMapNotify(value) => Event::XkbIndicatorMapNotify(value),
+            XkbEvent::IndicatorStateNotify(value) => Event::XkbIndicatorStateNotify(value),
+            XkbEvent::MapNotify(value) => Event::XkbMapNotify(value),
+            XkbEvent::NamesNotify(value) => Event::XkbNamesNotify(value),
+            XkbEvent::NewKeyboardNotify(value) => Event::XkbNewKeyboardNotify(value),
+            XkbEvent::StateNotify(value) => Event::XkbStateNotify(value),
+        }
+    }
+}
+#[cfg(feature = "xkb")]
+impl TryFrom<Event> for XkbEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XkbAccessXNotify(value) => Ok(XkbEvent::AccessXNotify(value)),
+            Event::XkbActionMessage(value) => Ok(XkbEvent::ActionMessage(value)),
+            Event::XkbBellNotify(value) => Ok(XkbEvent::BellNotify(value)),
+            Event::XkbCompatMapNotify(value) => Ok(XkbEvent::CompatMapNotify(value)),
+            Event::XkbControlsNotify(value) => Ok(XkbEvent::ControlsNotify(value)),
+            Event::XkbExtensionDeviceNotify(value) => Ok(XkbEvent::ExtensionDeviceNotify(value)),
+            Event::XkbIndicatorMapNotify(value) => Ok(XkbEvent::IndicatorMapNotify(value)),
+            Event::XkbIndicatorStateNotify(value) => Ok(XkbEvent::IndicatorStateNotify(value)),
+            Event::XkbMapNotify(value) => Ok(XkbEvent::MapNotify(value)),
+            Event::XkbNamesNotify(value) => Ok(XkbEvent::NamesNotify(value)),
+            Event::XkbNewKeyboardNotify(value) => Ok(XkbEvent::NewKeyboardNotify(value)),
+            Event::XkbStateNotify(value) => Ok(XkbEvent::StateNotify(value)),
+            event => Err(event),
+        }
+    }
+}
+
+/// Enumeration of the events of the `xprint` extension.
+///
+/// An [`Event`] can be converted into this enum with `TryFrom`, which allows to
+/// match on the events of one extension without listing the events of all others.
+#[cfg(feature = "xprint")]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum XprintEvent {
+    AttributNotify(xprint::AttributNotifyEvent),
+    Notify(xprint::NotifyEvent),
+}
+#[cfg(feature = "xprint")]
+impl From<XprintEvent> for Event {
+    fn from(event: XprintEvent) -> Self {
+        match event {
+            XprintEvent::AttributNotify(value) => Event::XprintAttributNotify(value),
+            XprintEvent::Notify(value) => Event::XprintNotify(value),
+        }
+    }
+}
+#[cfg(feature = "xprint")]
+impl TryFrom<Event> for XprintEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XprintAttributNotify(value) => Ok(XprintEvent::AttributNotify(value)),
+            Event::XprintNotify(value) => Ok(XprintEvent::Notify(value)),
+            event => Err(event),
+        }
+    }
+}
+
+/// Enumeration of the events of the `xv` extension.
+///
+/// An [`Event`] can be converted into this enum with `TryFrom`, which allows to
+/// match on the events of one extension without listing the events of all others.
+#[cfg(feature = "xv")]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum XvEvent {
+    PortNotify(xv::PortNotifyEvent),
+    VideoNotify(xv::VideoNotifyEvent),
+}
+#[cfg(feature = "xv")]
+impl From<XvEvent> for Event {
+    fn from(event: XvEvent) -> Self {
+        match event {
+            XvEvent::PortNotify(value) => Event::XvPortNotify(value),
+            XvEvent::VideoNotify(value) => Event::XvVideoNotify(value),
+        }
+    }
+}
+#[cfg(feature = "xv")]
+impl TryFrom<Event> for XvEvent {
+    type Error = Event;
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::XvPortNotify(value) => Ok(XvEvent::PortNotify(value)),
+            Event::XvVideoNotify(value) => Ok(XvEvent::VideoNotify(value)),
+            event => Err(event),
+        }
+    }
+}
+
+/// Get the response type out of the raw bytes of an X11 error or event.
+fn response_type(raw_bytes: &[u8]) -> Result<u8, ParseError> {
+    raw_bytes.first()
+        .map(|x| x & 0x7f)
+        .ok_or(ParseError::InsufficientData)
+}
+
+/// Set the response type of a serialized X11 event, keeping the `SendEvent` bit.
+fn set_response_type(raw_bytes: &mut [u8], response_type: u8) {
+    raw_bytes[0] = (raw_bytes[0] & 0x80) | response_type;
+}
+
+/// Set the header fields of a serialized generic X11 event.
+#[allow(dead_code)]
+fn set_generic_event_header(raw_bytes: &mut [u8], extension: u8, event_type: u16) {
+    let length = u32::try_from((raw_bytes.len() - 32) / 4).unwrap();
+    set_response_type(raw_bytes, xproto::GE_GENERIC_EVENT);
+    raw_bytes[1] = extension;
+    raw_bytes[4..8].copy_from_slice(&length.to_ne_bytes());
+    raw_bytes[8..10].copy_from_slice(&event_type.to_ne_bytes());
 }
 
 /// Get the sequence number out of an X11 packet.
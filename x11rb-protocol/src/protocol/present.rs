@@ -22,6 +22,7 @@ use crate::BufWithFds;
 use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::x11_utils::RequestDescription;
 #[allow(unused_imports)]
 use super::dri3;
 #[allow(unused_imports)]
@@ -46,6 +47,7 @@ pub const X11_XML_VERSION: (u32, u32) = (1, 4);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct EventEnum(u8);
 impl EventEnum {
     pub const CONFIGURE_NOTIFY: Self = Self(0);
@@ -106,9 +108,27 @@ impl core::fmt::Debug for EventEnum  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl EventEnum {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> core::option::Option<&'static str> {
+        match self.0 {
+            0 => Some("ConfigureNotify"),
+            1 => Some("CompleteNotify"),
+            2 => Some("IdleNotify"),
+            3 => Some("RedirectNotify"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for EventEnum {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct EventMask(u32);
 impl EventMask {
     pub const NO_EVENT: Self = Self(0);
@@ -159,10 +179,29 @@ impl core::fmt::Debug for EventMask  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl EventMask {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> core::option::Option<&'static str> {
+        match self.0 {
+            0 => Some("NoEvent"),
+            1 => Some("ConfigureNotify"),
+            2 => Some("CompleteNotify"),
+            4 => Some("IdleNotify"),
+            8 => Some("RedirectNotify"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for EventMask {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(EventMask, u32);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Option(u8);
 impl Option {
     pub const NONE: Self = Self(0);
@@ -227,10 +266,30 @@ impl core::fmt::Debug for Option  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl Option {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> core::option::Option<&'static str> {
+        match self.0 {
+            0 => Some("None"),
+            1 => Some("Async"),
+            2 => Some("Copy"),
+            4 => Some("UST"),
+            8 => Some("Suboptimal"),
+            16 => Some("AsyncMayTear"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Option {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(Option, u8);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Capability(u8);
 impl Capability {
     pub const NONE: Self = Self(0);
@@ -295,10 +354,30 @@ impl core::fmt::Debug for Capability  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl Capability {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> core::option::Option<&'static str> {
+        match self.0 {
+            0 => Some("None"),
+            1 => Some("Async"),
+            2 => Some("Fence"),
+            4 => Some("UST"),
+            8 => Some("AsyncMayTear"),
+            16 => Some("Syncobj"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Capability {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(Capability, u8);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CompleteKind(u8);
 impl CompleteKind {
     pub const PIXMAP: Self = Self(0);
@@ -355,9 +434,25 @@ impl core::fmt::Debug for CompleteKind  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl CompleteKind {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> core::option::Option<&'static str> {
+        match self.0 {
+            0 => Some("Pixmap"),
+            1 => Some("NotifyMSC"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for CompleteKind {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CompleteMode(u8);
 impl CompleteMode {
     pub const COPY: Self = Self(0);
@@ -418,6 +513,23 @@ impl core::fmt::Debug for CompleteMode  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl CompleteMode {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> core::option::Option<&'static str> {
+        match self.0 {
+            0 => Some("Copy"),
+            1 => Some("Flip"),
+            2 => Some("Skip"),
+            3 => Some("SuboptimalCopy"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for CompleteMode {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
@@ -427,6 +539,12 @@ pub struct Notify {
     pub serial: u32,
 }
 impl_debug_if_no_extra_traits!(Notify, "Notify");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Notify {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Notify {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (window, remaining) = xproto::Window::try_parse(remaining)?;
@@ -463,6 +581,7 @@ pub const QUERY_VERSION_REQUEST: u8 = 0;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryVersionRequest {
     pub major_version: u32,
     pub minor_version: u32,
@@ -518,6 +637,38 @@ impl Request for QueryVersionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let major_version_bytes = self.major_version.serialize();
+        let minor_version_bytes = self.minor_version.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+            major_version_bytes[0],
+            major_version_bytes[1],
+            major_version_bytes[2],
+            major_version_bytes[3],
+            minor_version_bytes[0],
+            minor_version_bytes[1],
+            minor_version_bytes[2],
+            minor_version_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryVersionRequest {
     type Reply = QueryVersionReply;
@@ -533,6 +684,12 @@ pub struct QueryVersionReply {
     pub minor_version: u32,
 }
 impl_debug_if_no_extra_traits!(QueryVersionReply, "QueryVersionReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryVersionReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -596,6 +753,7 @@ pub const PIXMAP_REQUEST: u8 = 1;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PixmapRequest<'input> {
     pub window: xproto::Window,
     pub pixmap: xproto::Pixmap,
@@ -739,7 +897,7 @@ impl<'input> PixmapRequest<'input> {
         let (remainder, remaining) = u64::try_parse(remaining)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut notifies = Vec::new();
+        let mut notifies = Vec::with_capacity(remaining.len() / 8);
         while !remaining.is_empty() {
             let (v, new_remaining) = Notify::try_parse(remaining)?;
             remaining = new_remaining;
@@ -794,6 +952,113 @@ impl<'input> Request for PixmapRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let pixmap_bytes = self.pixmap.serialize();
+        let serial_bytes = self.serial.serialize();
+        let valid_bytes = self.valid.serialize();
+        let update_bytes = self.update.serialize();
+        let x_off_bytes = self.x_off.serialize();
+        let y_off_bytes = self.y_off.serialize();
+        let target_crtc_bytes = self.target_crtc.serialize();
+        let wait_fence_bytes = self.wait_fence.serialize();
+        let idle_fence_bytes = self.idle_fence.serialize();
+        let options_bytes = self.options.serialize();
+        let target_msc_bytes = self.target_msc.serialize();
+        let divisor_bytes = self.divisor.serialize();
+        let remainder_bytes = self.remainder.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            PIXMAP_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            pixmap_bytes[0],
+            pixmap_bytes[1],
+            pixmap_bytes[2],
+            pixmap_bytes[3],
+            serial_bytes[0],
+            serial_bytes[1],
+            serial_bytes[2],
+            serial_bytes[3],
+            valid_bytes[0],
+            valid_bytes[1],
+            valid_bytes[2],
+            valid_bytes[3],
+            update_bytes[0],
+            update_bytes[1],
+            update_bytes[2],
+            update_bytes[3],
+            x_off_bytes[0],
+            x_off_bytes[1],
+            y_off_bytes[0],
+            y_off_bytes[1],
+            target_crtc_bytes[0],
+            target_crtc_bytes[1],
+            target_crtc_bytes[2],
+            target_crtc_bytes[3],
+            wait_fence_bytes[0],
+            wait_fence_bytes[1],
+            wait_fence_bytes[2],
+            wait_fence_bytes[3],
+            idle_fence_bytes[0],
+            idle_fence_bytes[1],
+            idle_fence_bytes[2],
+            idle_fence_bytes[3],
+            options_bytes[0],
+            options_bytes[1],
+            options_bytes[2],
+            options_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            target_msc_bytes[0],
+            target_msc_bytes[1],
+            target_msc_bytes[2],
+            target_msc_bytes[3],
+            target_msc_bytes[4],
+            target_msc_bytes[5],
+            target_msc_bytes[6],
+            target_msc_bytes[7],
+            divisor_bytes[0],
+            divisor_bytes[1],
+            divisor_bytes[2],
+            divisor_bytes[3],
+            divisor_bytes[4],
+            divisor_bytes[5],
+            divisor_bytes[6],
+            divisor_bytes[7],
+            remainder_bytes[0],
+            remainder_bytes[1],
+            remainder_bytes[2],
+            remainder_bytes[3],
+            remainder_bytes[4],
+            remainder_bytes[5],
+            remainder_bytes[6],
+            remainder_bytes[7],
+        ]);
+        self.notifies.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for PixmapRequest<'input> {
 }
@@ -803,6 +1068,7 @@ pub const NOTIFY_MSC_REQUEST: u8 = 2;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct NotifyMSCRequest {
     pub window: xproto::Window,
     pub serial: u32,
@@ -899,6 +1165,69 @@ impl Request for NotifyMSCRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let serial_bytes = self.serial.serialize();
+        let target_msc_bytes = self.target_msc.serialize();
+        let divisor_bytes = self.divisor.serialize();
+        let remainder_bytes = self.remainder.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            NOTIFY_MSC_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            serial_bytes[0],
+            serial_bytes[1],
+            serial_bytes[2],
+            serial_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            target_msc_bytes[0],
+            target_msc_bytes[1],
+            target_msc_bytes[2],
+            target_msc_bytes[3],
+            target_msc_bytes[4],
+            target_msc_bytes[5],
+            target_msc_bytes[6],
+            target_msc_bytes[7],
+            divisor_bytes[0],
+            divisor_bytes[1],
+            divisor_bytes[2],
+            divisor_bytes[3],
+            divisor_bytes[4],
+            divisor_bytes[5],
+            divisor_bytes[6],
+            divisor_bytes[7],
+            remainder_bytes[0],
+            remainder_bytes[1],
+            remainder_bytes[2],
+            remainder_bytes[3],
+            remainder_bytes[4],
+            remainder_bytes[5],
+            remainder_bytes[6],
+            remainder_bytes[7],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for NotifyMSCRequest {
 }
@@ -910,6 +1239,7 @@ pub const SELECT_INPUT_REQUEST: u8 = 3;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SelectInputRequest {
     pub eid: Event,
     pub window: xproto::Window,
@@ -974,6 +1304,43 @@ impl Request for SelectInputRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let eid_bytes = self.eid.serialize();
+        let window_bytes = self.window.serialize();
+        let event_mask_bytes = u32::from(self.event_mask).serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SELECT_INPUT_REQUEST,
+            0,
+            0,
+            eid_bytes[0],
+            eid_bytes[1],
+            eid_bytes[2],
+            eid_bytes[3],
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            event_mask_bytes[0],
+            event_mask_bytes[1],
+            event_mask_bytes[2],
+            event_mask_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SelectInputRequest {
 }
@@ -983,6 +1350,7 @@ pub const QUERY_CAPABILITIES_REQUEST: u8 = 4;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryCapabilitiesRequest {
     pub target: u32,
 }
@@ -1030,6 +1398,33 @@ impl Request for QueryCapabilitiesRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let target_bytes = self.target.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_CAPABILITIES_REQUEST,
+            0,
+            0,
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryCapabilitiesRequest {
     type Reply = QueryCapabilitiesReply;
@@ -1044,6 +1439,12 @@ pub struct QueryCapabilitiesReply {
     pub capabilities: u32,
 }
 impl_debug_if_no_extra_traits!(QueryCapabilitiesReply, "QueryCapabilitiesReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryCapabilitiesReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryCapabilitiesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1100,6 +1501,7 @@ pub const PIXMAP_SYNCED_REQUEST: u8 = 5;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PixmapSyncedRequest<'input> {
     pub window: xproto::Window,
     pub pixmap: xproto::Pixmap,
@@ -1265,7 +1667,7 @@ impl<'input> PixmapSyncedRequest<'input> {
         let (remainder, remaining) = u64::try_parse(remaining)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut notifies = Vec::new();
+        let mut notifies = Vec::with_capacity(remaining.len() / 8);
         while !remaining.is_empty() {
             let (v, new_remaining) = Notify::try_parse(remaining)?;
             remaining = new_remaining;
@@ -1324,6 +1726,131 @@ impl<'input> Request for PixmapSyncedRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let pixmap_bytes = self.pixmap.serialize();
+        let serial_bytes = self.serial.serialize();
+        let valid_bytes = self.valid.serialize();
+        let update_bytes = self.update.serialize();
+        let x_off_bytes = self.x_off.serialize();
+        let y_off_bytes = self.y_off.serialize();
+        let target_crtc_bytes = self.target_crtc.serialize();
+        let acquire_syncobj_bytes = self.acquire_syncobj.serialize();
+        let release_syncobj_bytes = self.release_syncobj.serialize();
+        let acquire_point_bytes = self.acquire_point.serialize();
+        let release_point_bytes = self.release_point.serialize();
+        let options_bytes = self.options.serialize();
+        let target_msc_bytes = self.target_msc.serialize();
+        let divisor_bytes = self.divisor.serialize();
+        let remainder_bytes = self.remainder.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            PIXMAP_SYNCED_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            pixmap_bytes[0],
+            pixmap_bytes[1],
+            pixmap_bytes[2],
+            pixmap_bytes[3],
+            serial_bytes[0],
+            serial_bytes[1],
+            serial_bytes[2],
+            serial_bytes[3],
+            valid_bytes[0],
+            valid_bytes[1],
+            valid_bytes[2],
+            valid_bytes[3],
+            update_bytes[0],
+            update_bytes[1],
+            update_bytes[2],
+            update_bytes[3],
+            x_off_bytes[0],
+            x_off_bytes[1],
+            y_off_bytes[0],
+            y_off_bytes[1],
+            target_crtc_bytes[0],
+            target_crtc_bytes[1],
+            target_crtc_bytes[2],
+            target_crtc_bytes[3],
+            acquire_syncobj_bytes[0],
+            acquire_syncobj_bytes[1],
+            acquire_syncobj_bytes[2],
+            acquire_syncobj_bytes[3],
+            release_syncobj_bytes[0],
+            release_syncobj_bytes[1],
+            release_syncobj_bytes[2],
+            release_syncobj_bytes[3],
+            acquire_point_bytes[0],
+            acquire_point_bytes[1],
+            acquire_point_bytes[2],
+            acquire_point_bytes[3],
+            acquire_point_bytes[4],
+            acquire_point_bytes[5],
+            acquire_point_bytes[6],
+            acquire_point_bytes[7],
+            release_point_bytes[0],
+            release_point_bytes[1],
+            release_point_bytes[2],
+            release_point_bytes[3],
+            release_point_bytes[4],
+            release_point_bytes[5],
+            release_point_bytes[6],
+            release_point_bytes[7],
+            options_bytes[0],
+            options_bytes[1],
+            options_bytes[2],
+            options_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            target_msc_bytes[0],
+            target_msc_bytes[1],
+            target_msc_bytes[2],
+            target_msc_bytes[3],
+            target_msc_bytes[4],
+            target_msc_bytes[5],
+            target_msc_bytes[6],
+            target_msc_bytes[7],
+            divisor_bytes[0],
+            divisor_bytes[1],
+            divisor_bytes[2],
+            divisor_bytes[3],
+            divisor_bytes[4],
+            divisor_bytes[5],
+            divisor_bytes[6],
+            divisor_bytes[7],
+            remainder_bytes[0],
+            remainder_bytes[1],
+            remainder_bytes[2],
+            remainder_bytes[3],
+            remainder_bytes[4],
+            remainder_bytes[5],
+            remainder_bytes[6],
+            remainder_bytes[7],
+        ]);
+        self.notifies.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for PixmapSyncedRequest<'input> {
 }
@@ -1342,6 +1869,12 @@ pub struct GenericEvent {
     pub event: Event,
 }
 impl_debug_if_no_extra_traits!(GenericEvent, "GenericEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GenericEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GenericEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1473,6 +2006,12 @@ pub struct ConfigureNotifyEvent {
     pub pixmap_flags: u32,
 }
 impl_debug_if_no_extra_traits!(ConfigureNotifyEvent, "ConfigureNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ConfigureNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ConfigureNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1604,6 +2143,12 @@ pub struct CompleteNotifyEvent {
     pub msc: u64,
 }
 impl_debug_if_no_extra_traits!(CompleteNotifyEvent, "CompleteNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CompleteNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for CompleteNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1721,6 +2266,12 @@ pub struct IdleNotifyEvent {
     pub idle_fence: sync::Fence,
 }
 impl_debug_if_no_extra_traits!(IdleNotifyEvent, "IdleNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for IdleNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for IdleNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1839,6 +2390,12 @@ pub struct RedirectNotifyEvent {
     pub notifies: Vec<Notify>,
 }
 impl_debug_if_no_extra_traits!(RedirectNotifyEvent, "RedirectNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for RedirectNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for RedirectNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1870,7 +2427,7 @@ impl TryParse for RedirectNotifyEvent {
         let (remainder, remaining) = u64::try_parse(remaining)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut notifies = Vec::new();
+        let mut notifies = Vec::with_capacity(remaining.len() / 8);
         while !remaining.is_empty() {
             let (v, new_remaining) = Notify::try_parse(remaining)?;
             remaining = new_remaining;
@@ -1919,9 +2476,17 @@ impl Serialize for RedirectNotifyEvent {
         self.divisor.serialize_into(bytes);
         self.remainder.serialize_into(bytes);
         self.notifies.serialize_into(bytes);
-        let notifies_len_bytes = u32::try_from(self.notifies.len()).unwrap();
-        let notifies_len_bytes = notifies_len_bytes.to_ne_bytes();
-        bytes.extend_from_slice(&notifies_len_bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 
+/// All requests of this module.
+pub const REQUESTS: &[RequestDescription] = &[
+    RequestDescription { opcode: QUERY_VERSION_REQUEST, name: "QueryVersion", has_reply: true },
+    RequestDescription { opcode: PIXMAP_REQUEST, name: "Pixmap", has_reply: false },
+    RequestDescription { opcode: NOTIFY_MSC_REQUEST, name: "NotifyMSC", has_reply: false },
+    RequestDescription { opcode: SELECT_INPUT_REQUEST, name: "SelectInput", has_reply: false },
+    RequestDescription { opcode: QUERY_CAPABILITIES_REQUEST, name: "QueryCapabilities", has_reply: true },
+    RequestDescription { opcode: PIXMAP_SYNCED_REQUEST, name: "PixmapSynced", has_reply: false },
+];
+
@@ -22,6 +22,7 @@ use crate::BufWithFds;
 use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::x11_utils::RequestDescription;
 #[allow(unused_imports)]
 use super::render;
 #[allow(unused_imports)]
@@ -62,6 +63,7 @@ pub const BAD_PROVIDER_ERROR: u8 = 3;
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Rotation(u16);
 impl Rotation {
     pub const ROTATE0: Self = Self(1 << 0);
@@ -120,6 +122,25 @@ impl core::fmt::Debug for Rotation  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl Rotation {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("Rotate0"),
+            2 => Some("Rotate90"),
+            4 => Some("Rotate180"),
+            8 => Some("Rotate270"),
+            16 => Some("ReflectX"),
+            32 => Some("ReflectY"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Rotation {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(Rotation, u16);
 
 #[derive(Clone, Copy, Default)]
@@ -132,6 +153,12 @@ pub struct ScreenSize {
     pub mheight: u16,
 }
 impl_debug_if_no_extra_traits!(ScreenSize, "ScreenSize");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ScreenSize {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for ScreenSize {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (width, remaining) = u16::try_parse(remaining)?;
@@ -176,6 +203,12 @@ pub struct RefreshRates {
     pub rates: Vec<u16>,
 }
 impl_debug_if_no_extra_traits!(RefreshRates, "RefreshRates");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for RefreshRates {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for RefreshRates {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (n_rates, remaining) = u16::try_parse(remaining)?;
@@ -218,6 +251,7 @@ pub const QUERY_VERSION_REQUEST: u8 = 0;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryVersionRequest {
     pub major_version: u32,
     pub minor_version: u32,
@@ -273,6 +307,38 @@ impl Request for QueryVersionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let major_version_bytes = self.major_version.serialize();
+        let minor_version_bytes = self.minor_version.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+            major_version_bytes[0],
+            major_version_bytes[1],
+            major_version_bytes[2],
+            major_version_bytes[3],
+            minor_version_bytes[0],
+            minor_version_bytes[1],
+            minor_version_bytes[2],
+            minor_version_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryVersionRequest {
     type Reply = QueryVersionReply;
@@ -288,6 +354,12 @@ pub struct QueryVersionReply {
     pub minor_version: u32,
 }
 impl_debug_if_no_extra_traits!(QueryVersionReply, "QueryVersionReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryVersionReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -366,6 +438,7 @@ impl Serialize for QueryVersionReply {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetConfig(u8);
 impl SetConfig {
     pub const SUCCESS: Self = Self(0);
@@ -426,12 +499,30 @@ impl core::fmt::Debug for SetConfig  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl SetConfig {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Success"),
+            1 => Some("InvalidConfigTime"),
+            2 => Some("InvalidTime"),
+            3 => Some("Failed"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for SetConfig {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the SetScreenConfig request
 pub const SET_SCREEN_CONFIG_REQUEST: u8 = 2;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetScreenConfigRequest {
     pub window: xproto::Window,
     pub timestamp: xproto::Timestamp,
@@ -517,6 +608,54 @@ impl Request for SetScreenConfigRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let timestamp_bytes = self.timestamp.serialize();
+        let config_timestamp_bytes = self.config_timestamp.serialize();
+        let size_id_bytes = self.size_id.serialize();
+        let rotation_bytes = u16::from(self.rotation).serialize();
+        let rate_bytes = self.rate.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_SCREEN_CONFIG_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            timestamp_bytes[0],
+            timestamp_bytes[1],
+            timestamp_bytes[2],
+            timestamp_bytes[3],
+            config_timestamp_bytes[0],
+            config_timestamp_bytes[1],
+            config_timestamp_bytes[2],
+            config_timestamp_bytes[3],
+            size_id_bytes[0],
+            size_id_bytes[1],
+            rotation_bytes[0],
+            rotation_bytes[1],
+            rate_bytes[0],
+            rate_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for SetScreenConfigRequest {
     type Reply = SetScreenConfigReply;
@@ -535,6 +674,12 @@ pub struct SetScreenConfigReply {
     pub subpixel_order: render::SubPixel,
 }
 impl_debug_if_no_extra_traits!(SetScreenConfigReply, "SetScreenConfigReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SetScreenConfigReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for SetScreenConfigReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -622,6 +767,7 @@ impl Serialize for SetScreenConfigReply {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct NotifyMask(u16);
 impl NotifyMask {
     pub const SCREEN_CHANGE: Self = Self(1 << 0);
@@ -684,6 +830,27 @@ impl core::fmt::Debug for NotifyMask  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl NotifyMask {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("ScreenChange"),
+            2 => Some("CrtcChange"),
+            4 => Some("OutputChange"),
+            8 => Some("OutputProperty"),
+            16 => Some("ProviderChange"),
+            32 => Some("ProviderProperty"),
+            64 => Some("ResourceChange"),
+            128 => Some("Lease"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for NotifyMask {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(NotifyMask, u16);
 
 /// Opcode for the SelectInput request
@@ -691,6 +858,7 @@ pub const SELECT_INPUT_REQUEST: u8 = 4;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SelectInputRequest {
     pub window: xproto::Window,
     pub enable: NotifyMask,
@@ -748,6 +916,38 @@ impl Request for SelectInputRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let enable_bytes = u16::from(self.enable).serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SELECT_INPUT_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            enable_bytes[0],
+            enable_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SelectInputRequest {
 }
@@ -757,6 +957,7 @@ pub const GET_SCREEN_INFO_REQUEST: u8 = 5;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetScreenInfoRequest {
     pub window: xproto::Window,
 }
@@ -804,6 +1005,33 @@ impl Request for GetScreenInfoRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_SCREEN_INFO_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetScreenInfoRequest {
     type Reply = GetScreenInfoReply;
@@ -827,6 +1055,12 @@ pub struct GetScreenInfoReply {
     pub rates: Vec<RefreshRates>,
 }
 impl_debug_if_no_extra_traits!(GetScreenInfoReply, "GetScreenInfoReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetScreenInfoReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetScreenInfoReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -884,6 +1118,7 @@ impl Serialize for GetScreenInfoReply {
         self.sizes.serialize_into(bytes);
         assert_eq!(self.rates.len(), usize::try_from(u32::from(self.n_info).checked_sub(u32::from(n_sizes)).unwrap()).unwrap(), "`rates` has an incorrect length");
         self.rates.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetScreenInfoReply {
@@ -907,6 +1142,7 @@ pub const GET_SCREEN_SIZE_RANGE_REQUEST: u8 = 6;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetScreenSizeRangeRequest {
     pub window: xproto::Window,
 }
@@ -954,6 +1190,33 @@ impl Request for GetScreenSizeRangeRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_SCREEN_SIZE_RANGE_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetScreenSizeRangeRequest {
     type Reply = GetScreenSizeRangeReply;
@@ -971,6 +1234,12 @@ pub struct GetScreenSizeRangeReply {
     pub max_height: u16,
 }
 impl_debug_if_no_extra_traits!(GetScreenSizeRangeReply, "GetScreenSizeRangeReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetScreenSizeRangeReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetScreenSizeRangeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1058,6 +1327,7 @@ pub const SET_SCREEN_SIZE_REQUEST: u8 = 7;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetScreenSizeRequest {
     pub window: xproto::Window,
     pub width: u16,
@@ -1133,12 +1403,56 @@ impl Request for SetScreenSizeRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        let mm_width_bytes = self.mm_width.serialize();
+        let mm_height_bytes = self.mm_height.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_SCREEN_SIZE_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+            mm_width_bytes[0],
+            mm_width_bytes[1],
+            mm_width_bytes[2],
+            mm_width_bytes[3],
+            mm_height_bytes[0],
+            mm_height_bytes[1],
+            mm_height_bytes[2],
+            mm_height_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SetScreenSizeRequest {
 }
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ModeFlag(u32);
 impl ModeFlag {
     pub const HSYNC_POSITIVE: Self = Self(1 << 0);
@@ -1207,6 +1521,33 @@ impl core::fmt::Debug for ModeFlag  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl ModeFlag {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("HsyncPositive"),
+            2 => Some("HsyncNegative"),
+            4 => Some("VsyncPositive"),
+            8 => Some("VsyncNegative"),
+            16 => Some("Interlace"),
+            32 => Some("DoubleScan"),
+            64 => Some("Csync"),
+            128 => Some("CsyncPositive"),
+            256 => Some("CsyncNegative"),
+            512 => Some("HskewPresent"),
+            1024 => Some("Bcast"),
+            2048 => Some("PixelMultiplex"),
+            4096 => Some("DoubleClock"),
+            8192 => Some("HalveClock"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ModeFlag {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(ModeFlag, u32);
 
 #[derive(Clone, Copy, Default)]
@@ -1228,6 +1569,12 @@ pub struct ModeInfo {
     pub mode_flags: ModeFlag,
 }
 impl_debug_if_no_extra_traits!(ModeInfo, "ModeInfo");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ModeInfo {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for ModeInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (id, remaining) = u32::try_parse(remaining)?;
@@ -1322,6 +1669,7 @@ pub const GET_SCREEN_RESOURCES_REQUEST: u8 = 8;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetScreenResourcesRequest {
     pub window: xproto::Window,
 }
@@ -1369,6 +1717,33 @@ impl Request for GetScreenResourcesRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_SCREEN_RESOURCES_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetScreenResourcesRequest {
     type Reply = GetScreenResourcesReply;
@@ -1388,6 +1763,12 @@ pub struct GetScreenResourcesReply {
     pub names: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(GetScreenResourcesReply, "GetScreenResourcesReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetScreenResourcesReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetScreenResourcesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1446,6 +1827,7 @@ impl Serialize for GetScreenResourcesReply {
         self.outputs.serialize_into(bytes);
         self.modes.serialize_into(bytes);
         bytes.extend_from_slice(&self.names);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetScreenResourcesReply {
@@ -1505,6 +1887,7 @@ impl GetScreenResourcesReply {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Connection(u8);
 impl Connection {
     pub const CONNECTED: Self = Self(0);
@@ -1563,12 +1946,29 @@ impl core::fmt::Debug for Connection  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl Connection {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Connected"),
+            1 => Some("Disconnected"),
+            2 => Some("Unknown"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Connection {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the GetOutputInfo request
 pub const GET_OUTPUT_INFO_REQUEST: u8 = 9;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetOutputInfoRequest {
     pub output: Output,
     pub config_timestamp: xproto::Timestamp,
@@ -1624,6 +2024,38 @@ impl Request for GetOutputInfoRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let output_bytes = self.output.serialize();
+        let config_timestamp_bytes = self.config_timestamp.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_OUTPUT_INFO_REQUEST,
+            0,
+            0,
+            output_bytes[0],
+            output_bytes[1],
+            output_bytes[2],
+            output_bytes[3],
+            config_timestamp_bytes[0],
+            config_timestamp_bytes[1],
+            config_timestamp_bytes[2],
+            config_timestamp_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetOutputInfoRequest {
     type Reply = GetOutputInfoReply;
@@ -1649,6 +2081,12 @@ pub struct GetOutputInfoReply {
     pub name: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(GetOutputInfoReply, "GetOutputInfoReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetOutputInfoReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetOutputInfoReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1718,6 +2156,7 @@ impl Serialize for GetOutputInfoReply {
         self.modes.serialize_into(bytes);
         self.clones.serialize_into(bytes);
         bytes.extend_from_slice(&self.name);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetOutputInfoReply {
@@ -1780,6 +2219,7 @@ pub const LIST_OUTPUT_PROPERTIES_REQUEST: u8 = 10;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ListOutputPropertiesRequest {
     pub output: Output,
 }
@@ -1827,6 +2267,33 @@ impl Request for ListOutputPropertiesRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let output_bytes = self.output.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            LIST_OUTPUT_PROPERTIES_REQUEST,
+            0,
+            0,
+            output_bytes[0],
+            output_bytes[1],
+            output_bytes[2],
+            output_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for ListOutputPropertiesRequest {
     type Reply = ListOutputPropertiesReply;
@@ -1841,6 +2308,12 @@ pub struct ListOutputPropertiesReply {
     pub atoms: Vec<xproto::Atom>,
 }
 impl_debug_if_no_extra_traits!(ListOutputPropertiesReply, "ListOutputPropertiesReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ListOutputPropertiesReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ListOutputPropertiesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1879,6 +2352,7 @@ impl Serialize for ListOutputPropertiesReply {
         num_atoms.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 22]);
         self.atoms.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl ListOutputPropertiesReply {
@@ -1902,6 +2376,7 @@ pub const QUERY_OUTPUT_PROPERTY_REQUEST: u8 = 11;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryOutputPropertyRequest {
     pub output: Output,
     pub property: xproto::Atom,
@@ -1957,6 +2432,38 @@ impl Request for QueryOutputPropertyRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let output_bytes = self.output.serialize();
+        let property_bytes = self.property.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_OUTPUT_PROPERTY_REQUEST,
+            0,
+            0,
+            output_bytes[0],
+            output_bytes[1],
+            output_bytes[2],
+            output_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryOutputPropertyRequest {
     type Reply = QueryOutputPropertyReply;
@@ -1973,6 +2480,12 @@ pub struct QueryOutputPropertyReply {
     pub valid_values: Vec<i32>,
 }
 impl_debug_if_no_extra_traits!(QueryOutputPropertyReply, "QueryOutputPropertyReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryOutputPropertyReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryOutputPropertyReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2015,6 +2528,7 @@ impl Serialize for QueryOutputPropertyReply {
         self.immutable.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 21]);
         self.valid_values.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl QueryOutputPropertyReply {
@@ -2038,6 +2552,7 @@ pub const CONFIGURE_OUTPUT_PROPERTY_REQUEST: u8 = 12;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ConfigureOutputPropertyRequest<'input> {
     pub output: Output,
     pub property: xproto::Atom,
@@ -2095,7 +2610,7 @@ impl<'input> ConfigureOutputPropertyRequest<'input> {
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut values = Vec::new();
+        let mut values = Vec::with_capacity(remaining.len() / 4);
         while !remaining.is_empty() {
             let (v, new_remaining) = i32::try_parse(remaining)?;
             remaining = new_remaining;
@@ -2130,6 +2645,47 @@ impl<'input> Request for ConfigureOutputPropertyRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let output_bytes = self.output.serialize();
+        let property_bytes = self.property.serialize();
+        let pending_bytes = self.pending.serialize();
+        let range_bytes = self.range.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CONFIGURE_OUTPUT_PROPERTY_REQUEST,
+            0,
+            0,
+            output_bytes[0],
+            output_bytes[1],
+            output_bytes[2],
+            output_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            pending_bytes[0],
+            range_bytes[0],
+            0,
+            0,
+        ]);
+        self.values.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for ConfigureOutputPropertyRequest<'input> {
 }
@@ -2139,6 +2695,7 @@ pub const CHANGE_OUTPUT_PROPERTY_REQUEST: u8 = 13;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangeOutputPropertyRequest<'input> {
     pub output: Output,
     pub property: xproto::Atom,
@@ -2243,6 +2800,58 @@ impl<'input> Request for ChangeOutputPropertyRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let output_bytes = self.output.serialize();
+        let property_bytes = self.property.serialize();
+        let type_bytes = self.type_.serialize();
+        let format_bytes = self.format.serialize();
+        let mode_bytes = u8::from(self.mode).serialize();
+        let num_units_bytes = self.num_units.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CHANGE_OUTPUT_PROPERTY_REQUEST,
+            0,
+            0,
+            output_bytes[0],
+            output_bytes[1],
+            output_bytes[2],
+            output_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            format_bytes[0],
+            mode_bytes[0],
+            0,
+            0,
+            num_units_bytes[0],
+            num_units_bytes[1],
+            num_units_bytes[2],
+            num_units_bytes[3],
+        ]);
+        assert_eq!(self.data.len(), usize::try_from(u32::from(self.num_units).checked_mul(u32::from(self.format)).unwrap().checked_div(8u32).unwrap()).unwrap(), "`data` has an incorrect length");
+        buffer.extend_from_slice(&self.data[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for ChangeOutputPropertyRequest<'input> {
 }
@@ -2252,6 +2861,7 @@ pub const DELETE_OUTPUT_PROPERTY_REQUEST: u8 = 14;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DeleteOutputPropertyRequest {
     pub output: Output,
     pub property: xproto::Atom,
@@ -2307,6 +2917,38 @@ impl Request for DeleteOutputPropertyRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let output_bytes = self.output.serialize();
+        let property_bytes = self.property.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            DELETE_OUTPUT_PROPERTY_REQUEST,
+            0,
+            0,
+            output_bytes[0],
+            output_bytes[1],
+            output_bytes[2],
+            output_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for DeleteOutputPropertyRequest {
 }
@@ -2316,6 +2958,7 @@ pub const GET_OUTPUT_PROPERTY_REQUEST: u8 = 15;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetOutputPropertyRequest {
     pub output: Output,
     pub property: xproto::Atom,
@@ -2408,6 +3051,59 @@ impl Request for GetOutputPropertyRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let output_bytes = self.output.serialize();
+        let property_bytes = self.property.serialize();
+        let type_bytes = self.type_.serialize();
+        let long_offset_bytes = self.long_offset.serialize();
+        let long_length_bytes = self.long_length.serialize();
+        let delete_bytes = self.delete.serialize();
+        let pending_bytes = self.pending.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_OUTPUT_PROPERTY_REQUEST,
+            0,
+            0,
+            output_bytes[0],
+            output_bytes[1],
+            output_bytes[2],
+            output_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            long_offset_bytes[0],
+            long_offset_bytes[1],
+            long_offset_bytes[2],
+            long_offset_bytes[3],
+            long_length_bytes[0],
+            long_length_bytes[1],
+            long_length_bytes[2],
+            long_length_bytes[3],
+            delete_bytes[0],
+            pending_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetOutputPropertyRequest {
     type Reply = GetOutputPropertyReply;
@@ -2426,6 +3122,12 @@ pub struct GetOutputPropertyReply {
     pub data: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(GetOutputPropertyReply, "GetOutputPropertyReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetOutputPropertyReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetOutputPropertyReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2469,6 +3171,7 @@ impl Serialize for GetOutputPropertyReply {
         bytes.extend_from_slice(&[0; 12]);
         assert_eq!(self.data.len(), usize::try_from(u32::from(self.num_items).checked_mul(u32::from(self.format).checked_div(8u32).unwrap()).unwrap()).unwrap(), "`data` has an incorrect length");
         bytes.extend_from_slice(&self.data);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 
@@ -2477,6 +3180,7 @@ pub const CREATE_MODE_REQUEST: u8 = 16;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateModeRequest<'input> {
     pub window: xproto::Window,
     pub mode_info: ModeInfo,
@@ -2574,6 +3278,69 @@ impl<'input> Request for CreateModeRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let mode_info_bytes = self.mode_info.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CREATE_MODE_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            mode_info_bytes[0],
+            mode_info_bytes[1],
+            mode_info_bytes[2],
+            mode_info_bytes[3],
+            mode_info_bytes[4],
+            mode_info_bytes[5],
+            mode_info_bytes[6],
+            mode_info_bytes[7],
+            mode_info_bytes[8],
+            mode_info_bytes[9],
+            mode_info_bytes[10],
+            mode_info_bytes[11],
+            mode_info_bytes[12],
+            mode_info_bytes[13],
+            mode_info_bytes[14],
+            mode_info_bytes[15],
+            mode_info_bytes[16],
+            mode_info_bytes[17],
+            mode_info_bytes[18],
+            mode_info_bytes[19],
+            mode_info_bytes[20],
+            mode_info_bytes[21],
+            mode_info_bytes[22],
+            mode_info_bytes[23],
+            mode_info_bytes[24],
+            mode_info_bytes[25],
+            mode_info_bytes[26],
+            mode_info_bytes[27],
+            mode_info_bytes[28],
+            mode_info_bytes[29],
+            mode_info_bytes[30],
+            mode_info_bytes[31],
+        ]);
+        buffer.extend_from_slice(&self.name[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::ReplyRequest for CreateModeRequest<'input> {
     type Reply = CreateModeReply;
@@ -2588,6 +3355,12 @@ pub struct CreateModeReply {
     pub mode: Mode,
 }
 impl_debug_if_no_extra_traits!(CreateModeReply, "CreateModeReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CreateModeReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for CreateModeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2666,6 +3439,7 @@ pub const DESTROY_MODE_REQUEST: u8 = 17;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DestroyModeRequest {
     pub mode: Mode,
 }
@@ -2713,6 +3487,33 @@ impl Request for DestroyModeRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let mode_bytes = self.mode.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            DESTROY_MODE_REQUEST,
+            0,
+            0,
+            mode_bytes[0],
+            mode_bytes[1],
+            mode_bytes[2],
+            mode_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for DestroyModeRequest {
 }
@@ -2722,6 +3523,7 @@ pub const ADD_OUTPUT_MODE_REQUEST: u8 = 18;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct AddOutputModeRequest {
     pub output: Output,
     pub mode: Mode,
@@ -2777,6 +3579,38 @@ impl Request for AddOutputModeRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let output_bytes = self.output.serialize();
+        let mode_bytes = self.mode.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            ADD_OUTPUT_MODE_REQUEST,
+            0,
+            0,
+            output_bytes[0],
+            output_bytes[1],
+            output_bytes[2],
+            output_bytes[3],
+            mode_bytes[0],
+            mode_bytes[1],
+            mode_bytes[2],
+            mode_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for AddOutputModeRequest {
 }
@@ -2786,6 +3620,7 @@ pub const DELETE_OUTPUT_MODE_REQUEST: u8 = 19;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DeleteOutputModeRequest {
     pub output: Output,
     pub mode: Mode,
@@ -2841,6 +3676,38 @@ impl Request for DeleteOutputModeRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let output_bytes = self.output.serialize();
+        let mode_bytes = self.mode.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            DELETE_OUTPUT_MODE_REQUEST,
+            0,
+            0,
+            output_bytes[0],
+            output_bytes[1],
+            output_bytes[2],
+            output_bytes[3],
+            mode_bytes[0],
+            mode_bytes[1],
+            mode_bytes[2],
+            mode_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for DeleteOutputModeRequest {
 }
@@ -2850,6 +3717,7 @@ pub const GET_CRTC_INFO_REQUEST: u8 = 20;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetCrtcInfoRequest {
     pub crtc: Crtc,
     pub config_timestamp: xproto::Timestamp,
@@ -2905,6 +3773,38 @@ impl Request for GetCrtcInfoRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let crtc_bytes = self.crtc.serialize();
+        let config_timestamp_bytes = self.config_timestamp.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_CRTC_INFO_REQUEST,
+            0,
+            0,
+            crtc_bytes[0],
+            crtc_bytes[1],
+            crtc_bytes[2],
+            crtc_bytes[3],
+            config_timestamp_bytes[0],
+            config_timestamp_bytes[1],
+            config_timestamp_bytes[2],
+            config_timestamp_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetCrtcInfoRequest {
     type Reply = GetCrtcInfoReply;
@@ -2929,6 +3829,12 @@ pub struct GetCrtcInfoReply {
     pub possible: Vec<Output>,
 }
 impl_debug_if_no_extra_traits!(GetCrtcInfoReply, "GetCrtcInfoReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetCrtcInfoReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetCrtcInfoReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2989,6 +3895,7 @@ impl Serialize for GetCrtcInfoReply {
         num_possible_outputs.serialize_into(bytes);
         self.outputs.serialize_into(bytes);
         self.possible.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetCrtcInfoReply {
@@ -3025,6 +3932,7 @@ pub const SET_CRTC_CONFIG_REQUEST: u8 = 21;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetCrtcConfigRequest<'input> {
     pub crtc: Crtc,
     pub timestamp: xproto::Timestamp,
@@ -3104,7 +4012,7 @@ impl<'input> SetCrtcConfigRequest<'input> {
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut outputs = Vec::new();
+        let mut outputs = Vec::with_capacity(remaining.len() / 4);
         while !remaining.is_empty() {
             let (v, new_remaining) = Output::try_parse(remaining)?;
             remaining = new_remaining;
@@ -3145,6 +4053,62 @@ impl<'input> Request for SetCrtcConfigRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let crtc_bytes = self.crtc.serialize();
+        let timestamp_bytes = self.timestamp.serialize();
+        let config_timestamp_bytes = self.config_timestamp.serialize();
+        let x_bytes = self.x.serialize();
+        let y_bytes = self.y.serialize();
+        let mode_bytes = self.mode.serialize();
+        let rotation_bytes = u16::from(self.rotation).serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_CRTC_CONFIG_REQUEST,
+            0,
+            0,
+            crtc_bytes[0],
+            crtc_bytes[1],
+            crtc_bytes[2],
+            crtc_bytes[3],
+            timestamp_bytes[0],
+            timestamp_bytes[1],
+            timestamp_bytes[2],
+            timestamp_bytes[3],
+            config_timestamp_bytes[0],
+            config_timestamp_bytes[1],
+            config_timestamp_bytes[2],
+            config_timestamp_bytes[3],
+            x_bytes[0],
+            x_bytes[1],
+            y_bytes[0],
+            y_bytes[1],
+            mode_bytes[0],
+            mode_bytes[1],
+            mode_bytes[2],
+            mode_bytes[3],
+            rotation_bytes[0],
+            rotation_bytes[1],
+            0,
+            0,
+        ]);
+        self.outputs.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::ReplyRequest for SetCrtcConfigRequest<'input> {
     type Reply = SetCrtcConfigReply;
@@ -3160,6 +4124,12 @@ pub struct SetCrtcConfigReply {
     pub timestamp: xproto::Timestamp,
 }
 impl_debug_if_no_extra_traits!(SetCrtcConfigReply, "SetCrtcConfigReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SetCrtcConfigReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for SetCrtcConfigReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -3240,6 +4210,7 @@ pub const GET_CRTC_GAMMA_SIZE_REQUEST: u8 = 22;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetCrtcGammaSizeRequest {
     pub crtc: Crtc,
 }
@@ -3287,6 +4258,33 @@ impl Request for GetCrtcGammaSizeRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let crtc_bytes = self.crtc.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_CRTC_GAMMA_SIZE_REQUEST,
+            0,
+            0,
+            crtc_bytes[0],
+            crtc_bytes[1],
+            crtc_bytes[2],
+            crtc_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetCrtcGammaSizeRequest {
     type Reply = GetCrtcGammaSizeReply;
@@ -3301,6 +4299,12 @@ pub struct GetCrtcGammaSizeReply {
     pub size: u16,
 }
 impl_debug_if_no_extra_traits!(GetCrtcGammaSizeReply, "GetCrtcGammaSizeReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetCrtcGammaSizeReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetCrtcGammaSizeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -3379,6 +4383,7 @@ pub const GET_CRTC_GAMMA_REQUEST: u8 = 23;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetCrtcGammaRequest {
     pub crtc: Crtc,
 }
@@ -3426,6 +4431,33 @@ impl Request for GetCrtcGammaRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let crtc_bytes = self.crtc.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_CRTC_GAMMA_REQUEST,
+            0,
+            0,
+            crtc_bytes[0],
+            crtc_bytes[1],
+            crtc_bytes[2],
+            crtc_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetCrtcGammaRequest {
     type Reply = GetCrtcGammaReply;
@@ -3442,6 +4474,12 @@ pub struct GetCrtcGammaReply {
     pub blue: Vec<u16>,
 }
 impl_debug_if_no_extra_traits!(GetCrtcGammaReply, "GetCrtcGammaReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetCrtcGammaReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetCrtcGammaReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -3486,6 +4524,7 @@ impl Serialize for GetCrtcGammaReply {
         self.green.serialize_into(bytes);
         assert_eq!(self.blue.len(), usize::try_from(size).unwrap(), "`blue` has an incorrect length");
         self.blue.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetCrtcGammaReply {
@@ -3509,6 +4548,7 @@ pub const SET_CRTC_GAMMA_REQUEST: u8 = 24;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetCrtcGammaRequest<'input> {
     pub crtc: Crtc,
     pub red: Cow<'input, [u16]>,
@@ -3592,6 +4632,46 @@ impl<'input> Request for SetCrtcGammaRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let crtc_bytes = self.crtc.serialize();
+        let size = u16::try_from(self.red.len()).expect("`red` has too many elements");
+        let size_bytes = size.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_CRTC_GAMMA_REQUEST,
+            0,
+            0,
+            crtc_bytes[0],
+            crtc_bytes[1],
+            crtc_bytes[2],
+            crtc_bytes[3],
+            size_bytes[0],
+            size_bytes[1],
+            0,
+            0,
+        ]);
+        self.red.serialize_into(buffer);
+        assert_eq!(self.green.len(), usize::try_from(size).unwrap(), "`green` has an incorrect length");
+        self.green.serialize_into(buffer);
+        assert_eq!(self.blue.len(), usize::try_from(size).unwrap(), "`blue` has an incorrect length");
+        self.blue.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for SetCrtcGammaRequest<'input> {
 }
@@ -3601,6 +4681,7 @@ pub const GET_SCREEN_RESOURCES_CURRENT_REQUEST: u8 = 25;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetScreenResourcesCurrentRequest {
     pub window: xproto::Window,
 }
@@ -3648,6 +4729,33 @@ impl Request for GetScreenResourcesCurrentRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_SCREEN_RESOURCES_CURRENT_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetScreenResourcesCurrentRequest {
     type Reply = GetScreenResourcesCurrentReply;
@@ -3667,6 +4775,12 @@ pub struct GetScreenResourcesCurrentReply {
     pub names: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(GetScreenResourcesCurrentReply, "GetScreenResourcesCurrentReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetScreenResourcesCurrentReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetScreenResourcesCurrentReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -3725,6 +4839,7 @@ impl Serialize for GetScreenResourcesCurrentReply {
         self.outputs.serialize_into(bytes);
         self.modes.serialize_into(bytes);
         bytes.extend_from_slice(&self.names);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetScreenResourcesCurrentReply {
@@ -3784,6 +4899,7 @@ impl GetScreenResourcesCurrentReply {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Transform(u8);
 impl Transform {
     pub const UNIT: Self = Self(1 << 0);
@@ -3844,6 +4960,23 @@ impl core::fmt::Debug for Transform  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl Transform {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("Unit"),
+            2 => Some("ScaleUp"),
+            4 => Some("ScaleDown"),
+            8 => Some("Projective"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Transform {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(Transform, u8);
 
 /// Opcode for the SetCrtcTransform request
@@ -3851,6 +4984,7 @@ pub const SET_CRTC_TRANSFORM_REQUEST: u8 = 26;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetCrtcTransformRequest<'input> {
     pub crtc: Crtc,
     pub transform: render::Transform,
@@ -3946,7 +5080,7 @@ impl<'input> SetCrtcTransformRequest<'input> {
         let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut filter_params = Vec::new();
+        let mut filter_params = Vec::with_capacity(remaining.len() / 4);
         while !remaining.is_empty() {
             let (v, new_remaining) = render::Fixed::try_parse(remaining)?;
             remaining = new_remaining;
@@ -3979,6 +5113,82 @@ impl<'input> Request for SetCrtcTransformRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let crtc_bytes = self.crtc.serialize();
+        let transform_bytes = self.transform.serialize();
+        let filter_len = u16::try_from(self.filter_name.len()).expect("`filter_name` has too many elements");
+        let filter_len_bytes = filter_len.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_CRTC_TRANSFORM_REQUEST,
+            0,
+            0,
+            crtc_bytes[0],
+            crtc_bytes[1],
+            crtc_bytes[2],
+            crtc_bytes[3],
+            transform_bytes[0],
+            transform_bytes[1],
+            transform_bytes[2],
+            transform_bytes[3],
+            transform_bytes[4],
+            transform_bytes[5],
+            transform_bytes[6],
+            transform_bytes[7],
+            transform_bytes[8],
+            transform_bytes[9],
+            transform_bytes[10],
+            transform_bytes[11],
+            transform_bytes[12],
+            transform_bytes[13],
+            transform_bytes[14],
+            transform_bytes[15],
+            transform_bytes[16],
+            transform_bytes[17],
+            transform_bytes[18],
+            transform_bytes[19],
+            transform_bytes[20],
+            transform_bytes[21],
+            transform_bytes[22],
+            transform_bytes[23],
+            transform_bytes[24],
+            transform_bytes[25],
+            transform_bytes[26],
+            transform_bytes[27],
+            transform_bytes[28],
+            transform_bytes[29],
+            transform_bytes[30],
+            transform_bytes[31],
+            transform_bytes[32],
+            transform_bytes[33],
+            transform_bytes[34],
+            transform_bytes[35],
+            filter_len_bytes[0],
+            filter_len_bytes[1],
+            0,
+            0,
+        ]);
+        buffer.extend_from_slice(&self.filter_name[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        self.filter_params.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for SetCrtcTransformRequest<'input> {
 }
@@ -3988,6 +5198,7 @@ pub const GET_CRTC_TRANSFORM_REQUEST: u8 = 27;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetCrtcTransformRequest {
     pub crtc: Crtc,
 }
@@ -4035,6 +5246,33 @@ impl Request for GetCrtcTransformRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let crtc_bytes = self.crtc.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_CRTC_TRANSFORM_REQUEST,
+            0,
+            0,
+            crtc_bytes[0],
+            crtc_bytes[1],
+            crtc_bytes[2],
+            crtc_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetCrtcTransformRequest {
     type Reply = GetCrtcTransformReply;
@@ -4055,6 +5293,12 @@ pub struct GetCrtcTransformReply {
     pub current_params: Vec<render::Fixed>,
 }
 impl_debug_if_no_extra_traits!(GetCrtcTransformReply, "GetCrtcTransformReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetCrtcTransformReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetCrtcTransformReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -4129,6 +5373,7 @@ impl Serialize for GetCrtcTransformReply {
         bytes.extend_from_slice(&self.current_filter_name);
         bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
         self.current_params.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetCrtcTransformReply {
@@ -4191,6 +5436,7 @@ pub const GET_PANNING_REQUEST: u8 = 28;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetPanningRequest {
     pub crtc: Crtc,
 }
@@ -4238,6 +5484,33 @@ impl Request for GetPanningRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let crtc_bytes = self.crtc.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_PANNING_REQUEST,
+            0,
+            0,
+            crtc_bytes[0],
+            crtc_bytes[1],
+            crtc_bytes[2],
+            crtc_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetPanningRequest {
     type Reply = GetPanningReply;
@@ -4265,6 +5538,12 @@ pub struct GetPanningReply {
     pub border_bottom: i16,
 }
 impl_debug_if_no_extra_traits!(GetPanningReply, "GetPanningReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetPanningReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetPanningReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -4383,6 +5662,7 @@ pub const SET_PANNING_REQUEST: u8 = 29;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetPanningRequest {
     pub crtc: Crtc,
     pub timestamp: xproto::Timestamp,
@@ -4510,6 +5790,74 @@ impl Request for SetPanningRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let crtc_bytes = self.crtc.serialize();
+        let timestamp_bytes = self.timestamp.serialize();
+        let left_bytes = self.left.serialize();
+        let top_bytes = self.top.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        let track_left_bytes = self.track_left.serialize();
+        let track_top_bytes = self.track_top.serialize();
+        let track_width_bytes = self.track_width.serialize();
+        let track_height_bytes = self.track_height.serialize();
+        let border_left_bytes = self.border_left.serialize();
+        let border_top_bytes = self.border_top.serialize();
+        let border_right_bytes = self.border_right.serialize();
+        let border_bottom_bytes = self.border_bottom.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_PANNING_REQUEST,
+            0,
+            0,
+            crtc_bytes[0],
+            crtc_bytes[1],
+            crtc_bytes[2],
+            crtc_bytes[3],
+            timestamp_bytes[0],
+            timestamp_bytes[1],
+            timestamp_bytes[2],
+            timestamp_bytes[3],
+            left_bytes[0],
+            left_bytes[1],
+            top_bytes[0],
+            top_bytes[1],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+            track_left_bytes[0],
+            track_left_bytes[1],
+            track_top_bytes[0],
+            track_top_bytes[1],
+            track_width_bytes[0],
+            track_width_bytes[1],
+            track_height_bytes[0],
+            track_height_bytes[1],
+            border_left_bytes[0],
+            border_left_bytes[1],
+            border_top_bytes[0],
+            border_top_bytes[1],
+            border_right_bytes[0],
+            border_right_bytes[1],
+            border_bottom_bytes[0],
+            border_bottom_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for SetPanningRequest {
     type Reply = SetPanningReply;
@@ -4525,6 +5873,12 @@ pub struct SetPanningReply {
     pub timestamp: xproto::Timestamp,
 }
 impl_debug_if_no_extra_traits!(SetPanningReply, "SetPanningReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SetPanningReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for SetPanningReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -4583,6 +5937,7 @@ pub const SET_OUTPUT_PRIMARY_REQUEST: u8 = 30;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetOutputPrimaryRequest {
     pub window: xproto::Window,
     pub output: Output,
@@ -4638,6 +5993,38 @@ impl Request for SetOutputPrimaryRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let output_bytes = self.output.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_OUTPUT_PRIMARY_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            output_bytes[0],
+            output_bytes[1],
+            output_bytes[2],
+            output_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SetOutputPrimaryRequest {
 }
@@ -4647,6 +6034,7 @@ pub const GET_OUTPUT_PRIMARY_REQUEST: u8 = 31;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetOutputPrimaryRequest {
     pub window: xproto::Window,
 }
@@ -4694,6 +6082,33 @@ impl Request for GetOutputPrimaryRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_OUTPUT_PRIMARY_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetOutputPrimaryRequest {
     type Reply = GetOutputPrimaryReply;
@@ -4708,6 +6123,12 @@ pub struct GetOutputPrimaryReply {
     pub output: Output,
 }
 impl_debug_if_no_extra_traits!(GetOutputPrimaryReply, "GetOutputPrimaryReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetOutputPrimaryReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetOutputPrimaryReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -4764,6 +6185,7 @@ pub const GET_PROVIDERS_REQUEST: u8 = 32;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetProvidersRequest {
     pub window: xproto::Window,
 }
@@ -4811,6 +6233,33 @@ impl Request for GetProvidersRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_PROVIDERS_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetProvidersRequest {
     type Reply = GetProvidersReply;
@@ -4826,6 +6275,12 @@ pub struct GetProvidersReply {
     pub providers: Vec<Provider>,
 }
 impl_debug_if_no_extra_traits!(GetProvidersReply, "GetProvidersReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetProvidersReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetProvidersReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -4866,6 +6321,7 @@ impl Serialize for GetProvidersReply {
         num_providers.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 18]);
         self.providers.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetProvidersReply {
@@ -4886,6 +6342,7 @@ impl GetProvidersReply {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ProviderCapability(u32);
 impl ProviderCapability {
     pub const SOURCE_OUTPUT: Self = Self(1 << 0);
@@ -4934,6 +6391,23 @@ impl core::fmt::Debug for ProviderCapability  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl ProviderCapability {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("SourceOutput"),
+            2 => Some("SinkOutput"),
+            4 => Some("SourceOffload"),
+            8 => Some("SinkOffload"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ProviderCapability {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(ProviderCapability, u32);
 
 /// Opcode for the GetProviderInfo request
@@ -4941,6 +6415,7 @@ pub const GET_PROVIDER_INFO_REQUEST: u8 = 33;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetProviderInfoRequest {
     pub provider: Provider,
     pub config_timestamp: xproto::Timestamp,
@@ -4996,6 +6471,38 @@ impl Request for GetProviderInfoRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let provider_bytes = self.provider.serialize();
+        let config_timestamp_bytes = self.config_timestamp.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_PROVIDER_INFO_REQUEST,
+            0,
+            0,
+            provider_bytes[0],
+            provider_bytes[1],
+            provider_bytes[2],
+            provider_bytes[3],
+            config_timestamp_bytes[0],
+            config_timestamp_bytes[1],
+            config_timestamp_bytes[2],
+            config_timestamp_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetProviderInfoRequest {
     type Reply = GetProviderInfoReply;
@@ -5017,6 +6524,12 @@ pub struct GetProviderInfoReply {
     pub name: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(GetProviderInfoReply, "GetProviderInfoReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetProviderInfoReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetProviderInfoReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5079,6 +6592,7 @@ impl Serialize for GetProviderInfoReply {
         assert_eq!(self.associated_capability.len(), usize::try_from(num_associated_providers).unwrap(), "`associated_capability` has an incorrect length");
         self.associated_capability.serialize_into(bytes);
         bytes.extend_from_slice(&self.name);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetProviderInfoReply {
@@ -5141,6 +6655,7 @@ pub const SET_PROVIDER_OFFLOAD_SINK_REQUEST: u8 = 34;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetProviderOffloadSinkRequest {
     pub provider: Provider,
     pub sink_provider: Provider,
@@ -5204,6 +6719,43 @@ impl Request for SetProviderOffloadSinkRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let provider_bytes = self.provider.serialize();
+        let sink_provider_bytes = self.sink_provider.serialize();
+        let config_timestamp_bytes = self.config_timestamp.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_PROVIDER_OFFLOAD_SINK_REQUEST,
+            0,
+            0,
+            provider_bytes[0],
+            provider_bytes[1],
+            provider_bytes[2],
+            provider_bytes[3],
+            sink_provider_bytes[0],
+            sink_provider_bytes[1],
+            sink_provider_bytes[2],
+            sink_provider_bytes[3],
+            config_timestamp_bytes[0],
+            config_timestamp_bytes[1],
+            config_timestamp_bytes[2],
+            config_timestamp_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SetProviderOffloadSinkRequest {
 }
@@ -5213,6 +6765,7 @@ pub const SET_PROVIDER_OUTPUT_SOURCE_REQUEST: u8 = 35;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetProviderOutputSourceRequest {
     pub provider: Provider,
     pub source_provider: Provider,
@@ -5276,6 +6829,43 @@ impl Request for SetProviderOutputSourceRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let provider_bytes = self.provider.serialize();
+        let source_provider_bytes = self.source_provider.serialize();
+        let config_timestamp_bytes = self.config_timestamp.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_PROVIDER_OUTPUT_SOURCE_REQUEST,
+            0,
+            0,
+            provider_bytes[0],
+            provider_bytes[1],
+            provider_bytes[2],
+            provider_bytes[3],
+            source_provider_bytes[0],
+            source_provider_bytes[1],
+            source_provider_bytes[2],
+            source_provider_bytes[3],
+            config_timestamp_bytes[0],
+            config_timestamp_bytes[1],
+            config_timestamp_bytes[2],
+            config_timestamp_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SetProviderOutputSourceRequest {
 }
@@ -5285,6 +6875,7 @@ pub const LIST_PROVIDER_PROPERTIES_REQUEST: u8 = 36;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ListProviderPropertiesRequest {
     pub provider: Provider,
 }
@@ -5332,6 +6923,33 @@ impl Request for ListProviderPropertiesRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let provider_bytes = self.provider.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            LIST_PROVIDER_PROPERTIES_REQUEST,
+            0,
+            0,
+            provider_bytes[0],
+            provider_bytes[1],
+            provider_bytes[2],
+            provider_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for ListProviderPropertiesRequest {
     type Reply = ListProviderPropertiesReply;
@@ -5346,6 +6964,12 @@ pub struct ListProviderPropertiesReply {
     pub atoms: Vec<xproto::Atom>,
 }
 impl_debug_if_no_extra_traits!(ListProviderPropertiesReply, "ListProviderPropertiesReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ListProviderPropertiesReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ListProviderPropertiesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5384,6 +7008,7 @@ impl Serialize for ListProviderPropertiesReply {
         num_atoms.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 22]);
         self.atoms.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl ListProviderPropertiesReply {
@@ -5407,6 +7032,7 @@ pub const QUERY_PROVIDER_PROPERTY_REQUEST: u8 = 37;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryProviderPropertyRequest {
     pub provider: Provider,
     pub property: xproto::Atom,
@@ -5462,6 +7088,38 @@ impl Request for QueryProviderPropertyRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let provider_bytes = self.provider.serialize();
+        let property_bytes = self.property.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_PROVIDER_PROPERTY_REQUEST,
+            0,
+            0,
+            provider_bytes[0],
+            provider_bytes[1],
+            provider_bytes[2],
+            provider_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryProviderPropertyRequest {
     type Reply = QueryProviderPropertyReply;
@@ -5478,6 +7136,12 @@ pub struct QueryProviderPropertyReply {
     pub valid_values: Vec<i32>,
 }
 impl_debug_if_no_extra_traits!(QueryProviderPropertyReply, "QueryProviderPropertyReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryProviderPropertyReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryProviderPropertyReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5520,6 +7184,7 @@ impl Serialize for QueryProviderPropertyReply {
         self.immutable.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 21]);
         self.valid_values.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl QueryProviderPropertyReply {
@@ -5543,6 +7208,7 @@ pub const CONFIGURE_PROVIDER_PROPERTY_REQUEST: u8 = 38;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ConfigureProviderPropertyRequest<'input> {
     pub provider: Provider,
     pub property: xproto::Atom,
@@ -5600,7 +7266,7 @@ impl<'input> ConfigureProviderPropertyRequest<'input> {
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut values = Vec::new();
+        let mut values = Vec::with_capacity(remaining.len() / 4);
         while !remaining.is_empty() {
             let (v, new_remaining) = i32::try_parse(remaining)?;
             remaining = new_remaining;
@@ -5635,6 +7301,47 @@ impl<'input> Request for ConfigureProviderPropertyRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let provider_bytes = self.provider.serialize();
+        let property_bytes = self.property.serialize();
+        let pending_bytes = self.pending.serialize();
+        let range_bytes = self.range.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CONFIGURE_PROVIDER_PROPERTY_REQUEST,
+            0,
+            0,
+            provider_bytes[0],
+            provider_bytes[1],
+            provider_bytes[2],
+            provider_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            pending_bytes[0],
+            range_bytes[0],
+            0,
+            0,
+        ]);
+        self.values.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for ConfigureProviderPropertyRequest<'input> {
 }
@@ -5644,6 +7351,7 @@ pub const CHANGE_PROVIDER_PROPERTY_REQUEST: u8 = 39;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangeProviderPropertyRequest<'input> {
     pub provider: Provider,
     pub property: xproto::Atom,
@@ -5747,6 +7455,58 @@ impl<'input> Request for ChangeProviderPropertyRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let provider_bytes = self.provider.serialize();
+        let property_bytes = self.property.serialize();
+        let type_bytes = self.type_.serialize();
+        let format_bytes = self.format.serialize();
+        let mode_bytes = self.mode.serialize();
+        let num_items_bytes = self.num_items.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CHANGE_PROVIDER_PROPERTY_REQUEST,
+            0,
+            0,
+            provider_bytes[0],
+            provider_bytes[1],
+            provider_bytes[2],
+            provider_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            format_bytes[0],
+            mode_bytes[0],
+            0,
+            0,
+            num_items_bytes[0],
+            num_items_bytes[1],
+            num_items_bytes[2],
+            num_items_bytes[3],
+        ]);
+        assert_eq!(self.data.len(), usize::try_from(u32::from(self.num_items).checked_mul(u32::from(self.format).checked_div(8u32).unwrap()).unwrap()).unwrap(), "`data` has an incorrect length");
+        buffer.extend_from_slice(&self.data[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for ChangeProviderPropertyRequest<'input> {
 }
@@ -5756,6 +7516,7 @@ pub const DELETE_PROVIDER_PROPERTY_REQUEST: u8 = 40;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DeleteProviderPropertyRequest {
     pub provider: Provider,
     pub property: xproto::Atom,
@@ -5811,6 +7572,38 @@ impl Request for DeleteProviderPropertyRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let provider_bytes = self.provider.serialize();
+        let property_bytes = self.property.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            DELETE_PROVIDER_PROPERTY_REQUEST,
+            0,
+            0,
+            provider_bytes[0],
+            provider_bytes[1],
+            provider_bytes[2],
+            provider_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for DeleteProviderPropertyRequest {
 }
@@ -5820,6 +7613,7 @@ pub const GET_PROVIDER_PROPERTY_REQUEST: u8 = 41;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetProviderPropertyRequest {
     pub provider: Provider,
     pub property: xproto::Atom,
@@ -5912,6 +7706,59 @@ impl Request for GetProviderPropertyRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let provider_bytes = self.provider.serialize();
+        let property_bytes = self.property.serialize();
+        let type_bytes = self.type_.serialize();
+        let long_offset_bytes = self.long_offset.serialize();
+        let long_length_bytes = self.long_length.serialize();
+        let delete_bytes = self.delete.serialize();
+        let pending_bytes = self.pending.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_PROVIDER_PROPERTY_REQUEST,
+            0,
+            0,
+            provider_bytes[0],
+            provider_bytes[1],
+            provider_bytes[2],
+            provider_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            long_offset_bytes[0],
+            long_offset_bytes[1],
+            long_offset_bytes[2],
+            long_offset_bytes[3],
+            long_length_bytes[0],
+            long_length_bytes[1],
+            long_length_bytes[2],
+            long_length_bytes[3],
+            delete_bytes[0],
+            pending_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetProviderPropertyRequest {
     type Reply = GetProviderPropertyReply;
@@ -5930,6 +7777,12 @@ pub struct GetProviderPropertyReply {
     pub data: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(GetProviderPropertyReply, "GetProviderPropertyReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetProviderPropertyReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetProviderPropertyReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5973,6 +7826,7 @@ impl Serialize for GetProviderPropertyReply {
         bytes.extend_from_slice(&[0; 12]);
         assert_eq!(self.data.len(), usize::try_from(u32::from(self.num_items).checked_mul(u32::from(self.format).checked_div(8u32).unwrap()).unwrap()).unwrap(), "`data` has an incorrect length");
         bytes.extend_from_slice(&self.data);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 
@@ -5997,6 +7851,12 @@ pub struct ScreenChangeNotifyEvent {
     pub mheight: u16,
 }
 impl_debug_if_no_extra_traits!(ScreenChangeNotifyEvent, "ScreenChangeNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ScreenChangeNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ScreenChangeNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -6149,6 +8009,7 @@ impl From<ScreenChangeNotifyEvent> for [u8; 32] {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Notify(u8);
 impl Notify {
     pub const CRTC_CHANGE: Self = Self(0);
@@ -6215,6 +8076,26 @@ impl core::fmt::Debug for Notify  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl Notify {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("CrtcChange"),
+            1 => Some("OutputChange"),
+            2 => Some("OutputProperty"),
+            3 => Some("ProviderChange"),
+            4 => Some("ProviderProperty"),
+            5 => Some("ResourceChange"),
+            6 => Some("Lease"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Notify {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
@@ -6231,6 +8112,12 @@ pub struct CrtcChange {
     pub height: u16,
 }
 impl_debug_if_no_extra_traits!(CrtcChange, "CrtcChange");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CrtcChange {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for CrtcChange {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
@@ -6321,6 +8208,12 @@ pub struct OutputChange {
     pub subpixel_order: render::SubPixel,
 }
 impl_debug_if_no_extra_traits!(OutputChange, "OutputChange");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for OutputChange {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for OutputChange {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
@@ -6407,6 +8300,12 @@ pub struct OutputProperty {
     pub status: xproto::Property,
 }
 impl_debug_if_no_extra_traits!(OutputProperty, "OutputProperty");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for OutputProperty {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for OutputProperty {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (window, remaining) = xproto::Window::try_parse(remaining)?;
@@ -6479,6 +8378,12 @@ pub struct ProviderChange {
     pub provider: Provider,
 }
 impl_debug_if_no_extra_traits!(ProviderChange, "ProviderChange");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ProviderChange {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for ProviderChange {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
@@ -6546,6 +8451,12 @@ pub struct ProviderProperty {
     pub state: u8,
 }
 impl_debug_if_no_extra_traits!(ProviderProperty, "ProviderProperty");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ProviderProperty {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for ProviderProperty {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (window, remaining) = xproto::Window::try_parse(remaining)?;
@@ -6616,6 +8527,12 @@ pub struct ResourceChange {
     pub window: xproto::Window,
 }
 impl_debug_if_no_extra_traits!(ResourceChange, "ResourceChange");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ResourceChange {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for ResourceChange {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
@@ -6685,6 +8602,12 @@ pub struct MonitorInfo {
     pub outputs: Vec<Output>,
 }
 impl_debug_if_no_extra_traits!(MonitorInfo, "MonitorInfo");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for MonitorInfo {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for MonitorInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (name, remaining) = xproto::Atom::try_parse(remaining)?;
@@ -6746,6 +8669,7 @@ pub const GET_MONITORS_REQUEST: u8 = 42;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetMonitorsRequest {
     pub window: xproto::Window,
     pub get_active: bool,
@@ -6801,6 +8725,38 @@ impl Request for GetMonitorsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let get_active_bytes = self.get_active.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_MONITORS_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            get_active_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetMonitorsRequest {
     type Reply = GetMonitorsReply;
@@ -6817,6 +8773,12 @@ pub struct GetMonitorsReply {
     pub monitors: Vec<MonitorInfo>,
 }
 impl_debug_if_no_extra_traits!(GetMonitorsReply, "GetMonitorsReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetMonitorsReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetMonitorsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -6859,6 +8821,7 @@ impl Serialize for GetMonitorsReply {
         self.n_outputs.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 12]);
         self.monitors.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetMonitorsReply {
@@ -6882,6 +8845,7 @@ pub const SET_MONITOR_REQUEST: u8 = 43;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetMonitorRequest {
     pub window: xproto::Window,
     pub monitorinfo: MonitorInfo,
@@ -6936,6 +8900,36 @@ impl Request for SetMonitorRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_MONITOR_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        self.monitorinfo.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SetMonitorRequest {
 }
@@ -6945,6 +8939,7 @@ pub const DELETE_MONITOR_REQUEST: u8 = 44;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DeleteMonitorRequest {
     pub window: xproto::Window,
     pub name: xproto::Atom,
@@ -7000,6 +8995,38 @@ impl Request for DeleteMonitorRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let name_bytes = self.name.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            DELETE_MONITOR_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            name_bytes[0],
+            name_bytes[1],
+            name_bytes[2],
+            name_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for DeleteMonitorRequest {
 }
@@ -7009,6 +9036,7 @@ pub const CREATE_LEASE_REQUEST: u8 = 45;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateLeaseRequest<'input> {
     pub window: xproto::Window,
     pub lid: Lease,
@@ -7095,6 +9123,50 @@ impl<'input> Request for CreateLeaseRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let lid_bytes = self.lid.serialize();
+        let num_crtcs = u16::try_from(self.crtcs.len()).expect("`crtcs` has too many elements");
+        let num_crtcs_bytes = num_crtcs.serialize();
+        let num_outputs = u16::try_from(self.outputs.len()).expect("`outputs` has too many elements");
+        let num_outputs_bytes = num_outputs.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CREATE_LEASE_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            lid_bytes[0],
+            lid_bytes[1],
+            lid_bytes[2],
+            lid_bytes[3],
+            num_crtcs_bytes[0],
+            num_crtcs_bytes[1],
+            num_outputs_bytes[0],
+            num_outputs_bytes[1],
+        ]);
+        self.crtcs.serialize_into(buffer);
+        self.outputs.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::ReplyFDsRequest for CreateLeaseRequest<'input> {
     type Reply = CreateLeaseReply;
@@ -7186,6 +9258,7 @@ pub const FREE_LEASE_REQUEST: u8 = 46;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct FreeLeaseRequest {
     pub lid: Lease,
     pub terminate: u8,
@@ -7241,6 +9314,38 @@ impl Request for FreeLeaseRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let lid_bytes = self.lid.serialize();
+        let terminate_bytes = self.terminate.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            FREE_LEASE_REQUEST,
+            0,
+            0,
+            lid_bytes[0],
+            lid_bytes[1],
+            lid_bytes[2],
+            lid_bytes[3],
+            terminate_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for FreeLeaseRequest {
 }
@@ -7255,6 +9360,12 @@ pub struct LeaseNotify {
     pub created: u8,
 }
 impl_debug_if_no_extra_traits!(LeaseNotify, "LeaseNotify");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for LeaseNotify {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for LeaseNotify {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (timestamp, remaining) = xproto::Timestamp::try_parse(remaining)?;
@@ -7316,6 +9427,7 @@ impl Serialize for LeaseNotify {
 
 #[derive(Debug, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct NotifyData([u8; 28]);
 impl NotifyData {
     pub fn as_cc(&self) -> CrtcChange {
@@ -7449,6 +9561,12 @@ pub struct NotifyEvent {
     pub u: NotifyData,
 }
 impl_debug_if_no_extra_traits!(NotifyEvent, "NotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for NotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for NotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -7562,3 +9680,52 @@ impl From<NotifyEvent> for [u8; 32] {
     }
 }
 
+/// All requests of this module.
+pub const REQUESTS: &[RequestDescription] = &[
+    RequestDescription { opcode: QUERY_VERSION_REQUEST, name: "QueryVersion", has_reply: true },
+    RequestDescription { opcode: SET_SCREEN_CONFIG_REQUEST, name: "SetScreenConfig", has_reply: true },
+    RequestDescription { opcode: SELECT_INPUT_REQUEST, name: "SelectInput", has_reply: false },
+    RequestDescription { opcode: GET_SCREEN_INFO_REQUEST, name: "GetScreenInfo", has_reply: true },
+    RequestDescription { opcode: GET_SCREEN_SIZE_RANGE_REQUEST, name: "GetScreenSizeRange", has_reply: true },
+    RequestDescription { opcode: SET_SCREEN_SIZE_REQUEST, name: "SetScreenSize", has_reply: false },
+    RequestDescription { opcode: GET_SCREEN_RESOURCES_REQUEST, name: "GetScreenResources", has_reply: true },
+    RequestDescription { opcode: GET_OUTPUT_INFO_REQUEST, name: "GetOutputInfo", has_reply: true },
+    RequestDescription { opcode: LIST_OUTPUT_PROPERTIES_REQUEST, name: "ListOutputProperties", has_reply: true },
+    RequestDescription { opcode: QUERY_OUTPUT_PROPERTY_REQUEST, name: "QueryOutputProperty", has_reply: true },
+    RequestDescription { opcode: CONFIGURE_OUTPUT_PROPERTY_REQUEST, name: "ConfigureOutputProperty", has_reply: false },
+    RequestDescription { opcode: CHANGE_OUTPUT_PROPERTY_REQUEST, name: "ChangeOutputProperty", has_reply: false },
+    RequestDescription { opcode: DELETE_OUTPUT_PROPERTY_REQUEST, name: "DeleteOutputProperty", has_reply: false },
+    RequestDescription { opcode: GET_OUTPUT_PROPERTY_REQUEST, name: "GetOutputProperty", has_reply: true },
+    RequestDescription { opcode: CREATE_MODE_REQUEST, name: "CreateMode", has_reply: true },
+    RequestDescription { opcode: DESTROY_MODE_REQUEST, name: "DestroyMode", has_reply: false },
+    RequestDescription { opcode: ADD_OUTPUT_MODE_REQUEST, name: "AddOutputMode", has_reply: false },
+    RequestDescription { opcode: DELETE_OUTPUT_MODE_REQUEST, name: "DeleteOutputMode", has_reply: false },
+    RequestDescription { opcode: GET_CRTC_INFO_REQUEST, name: "GetCrtcInfo", has_reply: true },
+    RequestDescription { opcode: SET_CRTC_CONFIG_REQUEST, name: "SetCrtcConfig", has_reply: true },
+    RequestDescription { opcode: GET_CRTC_GAMMA_SIZE_REQUEST, name: "GetCrtcGammaSize", has_reply: true },
+    RequestDescription { opcode: GET_CRTC_GAMMA_REQUEST, name: "GetCrtcGamma", has_reply: true },
+    RequestDescription { opcode: SET_CRTC_GAMMA_REQUEST, name: "SetCrtcGamma", has_reply: false },
+    RequestDescription { opcode: GET_SCREEN_RESOURCES_CURRENT_REQUEST, name: "GetScreenResourcesCurrent", has_reply: true },
+    RequestDescription { opcode: SET_CRTC_TRANSFORM_REQUEST, name: "SetCrtcTransform", has_reply: false },
+    RequestDescription { opcode: GET_CRTC_TRANSFORM_REQUEST, name: "GetCrtcTransform", has_reply: true },
+    RequestDescription { opcode: GET_PANNING_REQUEST, name: "GetPanning", has_reply: true },
+    RequestDescription { opcode: SET_PANNING_REQUEST, name: "SetPanning", has_reply: true },
+    RequestDescription { opcode: SET_OUTPUT_PRIMARY_REQUEST, name: "SetOutputPrimary", has_reply: false },
+    RequestDescription { opcode: GET_OUTPUT_PRIMARY_REQUEST, name: "GetOutputPrimary", has_reply: true },
+    RequestDescription { opcode: GET_PROVIDERS_REQUEST, name: "GetProviders", has_reply: true },
+    RequestDescription { opcode: GET_PROVIDER_INFO_REQUEST, name: "GetProviderInfo", has_reply: true },
+    RequestDescription { opcode: SET_PROVIDER_OFFLOAD_SINK_REQUEST, name: "SetProviderOffloadSink", has_reply: false },
+    RequestDescription { opcode: SET_PROVIDER_OUTPUT_SOURCE_REQUEST, name: "SetProviderOutputSource", has_reply: false },
+    RequestDescription { opcode: LIST_PROVIDER_PROPERTIES_REQUEST, name: "ListProviderProperties", has_reply: true },
+    RequestDescription { opcode: QUERY_PROVIDER_PROPERTY_REQUEST, name: "QueryProviderProperty", has_reply: true },
+    RequestDescription { opcode: CONFIGURE_PROVIDER_PROPERTY_REQUEST, name: "ConfigureProviderProperty", has_reply: false },
+    RequestDescription { opcode: CHANGE_PROVIDER_PROPERTY_REQUEST, name: "ChangeProviderProperty", has_reply: false },
+    RequestDescription { opcode: DELETE_PROVIDER_PROPERTY_REQUEST, name: "DeleteProviderProperty", has_reply: false },
+    RequestDescription { opcode: GET_PROVIDER_PROPERTY_REQUEST, name: "GetProviderProperty", has_reply: true },
+    RequestDescription { opcode: GET_MONITORS_REQUEST, name: "GetMonitors", has_reply: true },
+    RequestDescription { opcode: SET_MONITOR_REQUEST, name: "SetMonitor", has_reply: false },
+    RequestDescription { opcode: DELETE_MONITOR_REQUEST, name: "DeleteMonitor", has_reply: false },
+    RequestDescription { opcode: CREATE_LEASE_REQUEST, name: "CreateLease", has_reply: true },
+    RequestDescription { opcode: FREE_LEASE_REQUEST, name: "FreeLease", has_reply: false },
+];
+
@@ -22,6 +22,7 @@ use crate::BufWithFds;
 use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::x11_utils::RequestDescription;
 #[allow(unused_imports)]
 use super::xproto;
 
@@ -38,6 +39,7 @@ pub const X11_XML_VERSION: (u32, u32) = (0, 11);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PictType(u8);
 impl PictType {
     pub const INDEXED: Self = Self(0);
@@ -94,9 +96,25 @@ impl core::fmt::Debug for PictType  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl PictType {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Indexed"),
+            1 => Some("Direct"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for PictType {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PictureEnum(u8);
 impl PictureEnum {
     pub const NONE: Self = Self(0);
@@ -151,9 +169,24 @@ impl core::fmt::Debug for PictureEnum  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl PictureEnum {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("None"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for PictureEnum {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PictOp(u8);
 impl PictOp {
     pub const CLEAR: Self = Self(0);
@@ -312,9 +345,76 @@ impl core::fmt::Debug for PictOp  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl PictOp {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Clear"),
+            1 => Some("Src"),
+            2 => Some("Dst"),
+            3 => Some("Over"),
+            4 => Some("OverReverse"),
+            5 => Some("In"),
+            6 => Some("InReverse"),
+            7 => Some("Out"),
+            8 => Some("OutReverse"),
+            9 => Some("Atop"),
+            10 => Some("AtopReverse"),
+            11 => Some("Xor"),
+            12 => Some("Add"),
+            13 => Some("Saturate"),
+            16 => Some("DisjointClear"),
+            17 => Some("DisjointSrc"),
+            18 => Some("DisjointDst"),
+            19 => Some("DisjointOver"),
+            20 => Some("DisjointOverReverse"),
+            21 => Some("DisjointIn"),
+            22 => Some("DisjointInReverse"),
+            23 => Some("DisjointOut"),
+            24 => Some("DisjointOutReverse"),
+            25 => Some("DisjointAtop"),
+            26 => Some("DisjointAtopReverse"),
+            27 => Some("DisjointXor"),
+            32 => Some("ConjointClear"),
+            33 => Some("ConjointSrc"),
+            34 => Some("ConjointDst"),
+            35 => Some("ConjointOver"),
+            36 => Some("ConjointOverReverse"),
+            37 => Some("ConjointIn"),
+            38 => Some("ConjointInReverse"),
+            39 => Some("ConjointOut"),
+            40 => Some("ConjointOutReverse"),
+            41 => Some("ConjointAtop"),
+            42 => Some("ConjointAtopReverse"),
+            43 => Some("ConjointXor"),
+            48 => Some("Multiply"),
+            49 => Some("Screen"),
+            50 => Some("Overlay"),
+            51 => Some("Darken"),
+            52 => Some("Lighten"),
+            53 => Some("ColorDodge"),
+            54 => Some("ColorBurn"),
+            55 => Some("HardLight"),
+            56 => Some("SoftLight"),
+            57 => Some("Difference"),
+            58 => Some("Exclusion"),
+            59 => Some("HSLHue"),
+            60 => Some("HSLSaturation"),
+            61 => Some("HSLColor"),
+            62 => Some("HSLLuminosity"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for PictOp {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PolyEdge(u32);
 impl PolyEdge {
     pub const SHARP: Self = Self(0);
@@ -359,9 +459,25 @@ impl core::fmt::Debug for PolyEdge  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+impl PolyEdge {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Sharp"),
+            1 => Some("Smooth"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for PolyEdge {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PolyMode(u32);
 impl PolyMode {
     pub const PRECISE: Self = Self(0);
@@ -406,9 +522,25 @@ impl core::fmt::Debug for PolyMode  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+impl PolyMode {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Precise"),
+            1 => Some("Imprecise"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for PolyMode {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CP(u32);
 impl CP {
     pub const REPEAT: Self = Self(1 << 0);
@@ -475,10 +607,37 @@ impl core::fmt::Debug for CP  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl CP {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("Repeat"),
+            2 => Some("AlphaMap"),
+            4 => Some("AlphaXOrigin"),
+            8 => Some("AlphaYOrigin"),
+            16 => Some("ClipXOrigin"),
+            32 => Some("ClipYOrigin"),
+            64 => Some("ClipMask"),
+            128 => Some("GraphicsExposure"),
+            256 => Some("SubwindowMode"),
+            512 => Some("PolyEdge"),
+            1024 => Some("PolyMode"),
+            2048 => Some("Dither"),
+            4096 => Some("ComponentAlpha"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for CP {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(CP, u32);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SubPixel(u32);
 impl SubPixel {
     pub const UNKNOWN: Self = Self(0);
@@ -531,9 +690,29 @@ impl core::fmt::Debug for SubPixel  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+impl SubPixel {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Unknown"),
+            1 => Some("HorizontalRGB"),
+            2 => Some("HorizontalBGR"),
+            3 => Some("VerticalRGB"),
+            4 => Some("VerticalBGR"),
+            5 => Some("None"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for SubPixel {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Repeat(u32);
 impl Repeat {
     pub const NONE: Self = Self(0);
@@ -582,6 +761,23 @@ impl core::fmt::Debug for Repeat  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+impl Repeat {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("None"),
+            1 => Some("Normal"),
+            2 => Some("Pad"),
+            3 => Some("Reflect"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Repeat {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 pub type Glyph = u32;
 
@@ -622,6 +818,12 @@ pub struct Directformat {
     pub alpha_mask: u16,
 }
 impl_debug_if_no_extra_traits!(Directformat, "Directformat");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Directformat {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Directformat {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (red_shift, remaining) = u16::try_parse(remaining)?;
@@ -690,6 +892,12 @@ pub struct Pictforminfo {
     pub colormap: xproto::Colormap,
 }
 impl_debug_if_no_extra_traits!(Pictforminfo, "Pictforminfo");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Pictforminfo {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Pictforminfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (id, remaining) = Pictformat::try_parse(remaining)?;
@@ -761,6 +969,12 @@ pub struct Pictvisual {
     pub format: Pictformat,
 }
 impl_debug_if_no_extra_traits!(Pictvisual, "Pictvisual");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Pictvisual {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Pictvisual {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (visual, remaining) = xproto::Visualid::try_parse(remaining)?;
@@ -800,6 +1014,12 @@ pub struct Pictdepth {
     pub visuals: Vec<Pictvisual>,
 }
 impl_debug_if_no_extra_traits!(Pictdepth, "Pictdepth");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Pictdepth {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Pictdepth {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (depth, remaining) = u8::try_parse(remaining)?;
@@ -852,6 +1072,12 @@ pub struct Pictscreen {
     pub depths: Vec<Pictdepth>,
 }
 impl_debug_if_no_extra_traits!(Pictscreen, "Pictscreen");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Pictscreen {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Pictscreen {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (num_depths, remaining) = u32::try_parse(remaining)?;
@@ -903,6 +1129,12 @@ pub struct Indexvalue {
     pub alpha: u16,
 }
 impl_debug_if_no_extra_traits!(Indexvalue, "Indexvalue");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Indexvalue {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Indexvalue {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (pixel, remaining) = u32::try_parse(remaining)?;
@@ -957,6 +1189,12 @@ pub struct Color {
     pub alpha: u16,
 }
 impl_debug_if_no_extra_traits!(Color, "Color");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Color {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Color {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (red, remaining) = u16::try_parse(remaining)?;
@@ -1002,6 +1240,12 @@ pub struct Pointfix {
     pub y: Fixed,
 }
 impl_debug_if_no_extra_traits!(Pointfix, "Pointfix");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Pointfix {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Pointfix {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (x, remaining) = Fixed::try_parse(remaining)?;
@@ -1041,6 +1285,12 @@ pub struct Linefix {
     pub p2: Pointfix,
 }
 impl_debug_if_no_extra_traits!(Linefix, "Linefix");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Linefix {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Linefix {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (p1, remaining) = Pointfix::try_parse(remaining)?;
@@ -1089,6 +1339,12 @@ pub struct Triangle {
     pub p3: Pointfix,
 }
 impl_debug_if_no_extra_traits!(Triangle, "Triangle");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Triangle {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Triangle {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (p1, remaining) = Pointfix::try_parse(remaining)?;
@@ -1149,6 +1405,12 @@ pub struct Trapezoid {
     pub right: Linefix,
 }
 impl_debug_if_no_extra_traits!(Trapezoid, "Trapezoid");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Trapezoid {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Trapezoid {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (top, remaining) = Fixed::try_parse(remaining)?;
@@ -1230,6 +1492,12 @@ pub struct Glyphinfo {
     pub y_off: i16,
 }
 impl_debug_if_no_extra_traits!(Glyphinfo, "Glyphinfo");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Glyphinfo {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Glyphinfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (width, remaining) = u16::try_parse(remaining)?;
@@ -1282,6 +1550,7 @@ pub const QUERY_VERSION_REQUEST: u8 = 0;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryVersionRequest {
     pub client_major_version: u32,
     pub client_minor_version: u32,
@@ -1337,6 +1606,38 @@ impl Request for QueryVersionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let client_major_version_bytes = self.client_major_version.serialize();
+        let client_minor_version_bytes = self.client_minor_version.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+            client_major_version_bytes[0],
+            client_major_version_bytes[1],
+            client_major_version_bytes[2],
+            client_major_version_bytes[3],
+            client_minor_version_bytes[0],
+            client_minor_version_bytes[1],
+            client_minor_version_bytes[2],
+            client_minor_version_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryVersionRequest {
     type Reply = QueryVersionReply;
@@ -1352,6 +1653,12 @@ pub struct QueryVersionReply {
     pub minor_version: u32,
 }
 impl_debug_if_no_extra_traits!(QueryVersionReply, "QueryVersionReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryVersionReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1433,6 +1740,7 @@ pub const QUERY_PICT_FORMATS_REQUEST: u8 = 1;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryPictFormatsRequest;
 impl_debug_if_no_extra_traits!(QueryPictFormatsRequest, "QueryPictFormatsRequest");
 impl QueryPictFormatsRequest {
@@ -1471,6 +1779,28 @@ impl Request for QueryPictFormatsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_PICT_FORMATS_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryPictFormatsRequest {
     type Reply = QueryPictFormatsReply;
@@ -1489,6 +1819,12 @@ pub struct QueryPictFormatsReply {
     pub subpixels: Vec<SubPixel>,
 }
 impl_debug_if_no_extra_traits!(QueryPictFormatsReply, "QueryPictFormatsReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryPictFormatsReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryPictFormatsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1506,7 +1842,7 @@ impl TryParse for QueryPictFormatsReply {
         let (screens, remaining) = crate::x11_utils::parse_list::<Pictscreen>(remaining, num_screens.try_to_usize()?)?;
         let mut remaining = remaining;
         let list_length = num_subpixel.try_to_usize()?;
-        let mut subpixels = Vec::with_capacity(list_length);
+        let mut subpixels = Vec::with_capacity(list_length.min(remaining.len()));
         for _ in 0..list_length {
             let (v, new_remaining) = u32::try_parse(remaining)?;
             let v = v.into();
@@ -1551,6 +1887,7 @@ impl Serialize for QueryPictFormatsReply {
         for element in self.subpixels.iter().copied() {
             u32::from(element).serialize_into(bytes);
         }
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl QueryPictFormatsReply {
@@ -1600,6 +1937,7 @@ pub const QUERY_PICT_INDEX_VALUES_REQUEST: u8 = 2;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryPictIndexValuesRequest {
     pub format: Pictformat,
 }
@@ -1647,6 +1985,33 @@ impl Request for QueryPictIndexValuesRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let format_bytes = self.format.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_PICT_INDEX_VALUES_REQUEST,
+            0,
+            0,
+            format_bytes[0],
+            format_bytes[1],
+            format_bytes[2],
+            format_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryPictIndexValuesRequest {
     type Reply = QueryPictIndexValuesReply;
@@ -1661,6 +2026,12 @@ pub struct QueryPictIndexValuesReply {
     pub values: Vec<Indexvalue>,
 }
 impl_debug_if_no_extra_traits!(QueryPictIndexValuesReply, "QueryPictIndexValuesReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryPictIndexValuesReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryPictIndexValuesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1699,6 +2070,7 @@ impl Serialize for QueryPictIndexValuesReply {
         num_values.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 20]);
         self.values.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl QueryPictIndexValuesReply {
@@ -1721,6 +2093,7 @@ impl QueryPictIndexValuesReply {
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreatePictureAux {
     pub repeat: Option<Repeat>,
     pub alphamap: Option<Picture>,
@@ -2032,6 +2405,54 @@ impl CreatePictureAux {
         self.componentalpha = value.into();
         self
     }
+    /// Get the changes that are needed to go from `old` to `self`.
+    ///
+    /// The result contains all fields that are set in `self` and that are either unset in
+    /// `old` or set to a different value. This allows to only send values that changed.
+    #[must_use]
+    pub fn changes_from(&self, old: &Self) -> Self {
+        let mut result = Self::new();
+        if self.repeat.is_some() && self.repeat != old.repeat {
+            result.repeat = self.repeat;
+        }
+        if self.alphamap.is_some() && self.alphamap != old.alphamap {
+            result.alphamap = self.alphamap;
+        }
+        if self.alphaxorigin.is_some() && self.alphaxorigin != old.alphaxorigin {
+            result.alphaxorigin = self.alphaxorigin;
+        }
+        if self.alphayorigin.is_some() && self.alphayorigin != old.alphayorigin {
+            result.alphayorigin = self.alphayorigin;
+        }
+        if self.clipxorigin.is_some() && self.clipxorigin != old.clipxorigin {
+            result.clipxorigin = self.clipxorigin;
+        }
+        if self.clipyorigin.is_some() && self.clipyorigin != old.clipyorigin {
+            result.clipyorigin = self.clipyorigin;
+        }
+        if self.clipmask.is_some() && self.clipmask != old.clipmask {
+            result.clipmask = self.clipmask;
+        }
+        if self.graphicsexposure.is_some() && self.graphicsexposure != old.graphicsexposure {
+            result.graphicsexposure = self.graphicsexposure;
+        }
+        if self.subwindowmode.is_some() && self.subwindowmode != old.subwindowmode {
+            result.subwindowmode = self.subwindowmode;
+        }
+        if self.polyedge.is_some() && self.polyedge != old.polyedge {
+            result.polyedge = self.polyedge;
+        }
+        if self.polymode.is_some() && self.polymode != old.polymode {
+            result.polymode = self.polymode;
+        }
+        if self.dither.is_some() && self.dither != old.dither {
+            result.dither = self.dither;
+        }
+        if self.componentalpha.is_some() && self.componentalpha != old.componentalpha {
+            result.componentalpha = self.componentalpha;
+        }
+        result
+    }
 }
 
 /// Opcode for the CreatePicture request
@@ -2039,6 +2460,7 @@ pub const CREATE_PICTURE_REQUEST: u8 = 4;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreatePictureRequest<'input> {
     pub pid: Picture,
     pub drawable: xproto::Drawable,
@@ -2125,14 +2547,154 @@ impl<'input> Request for CreatePictureRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let pid_bytes = self.pid.serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let format_bytes = self.format.serialize();
+        let value_mask: u32 = self.value_list.switch_expr();
+        let value_mask_bytes = value_mask.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CREATE_PICTURE_REQUEST,
+            0,
+            0,
+            pid_bytes[0],
+            pid_bytes[1],
+            pid_bytes[2],
+            pid_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            format_bytes[0],
+            format_bytes[1],
+            format_bytes[2],
+            format_bytes[3],
+            value_mask_bytes[0],
+            value_mask_bytes[1],
+            value_mask_bytes[2],
+            value_mask_bytes[3],
+        ]);
+        self.value_list.serialize_into(buffer, u32::from(value_mask));
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for CreatePictureRequest<'input> {
 }
+impl CreatePictureRequest<'static> {
+    /// Create a new request with all fields of `value_list` unset.
+    ///
+    /// The optional fields can then be set with the builder methods of this request.
+    pub fn new(pid: Picture, drawable: xproto::Drawable, format: Pictformat) -> Self {
+        CreatePictureRequest {
+            pid,
+            drawable,
+            format,
+            value_list: Cow::Owned(CreatePictureAux::new()),
+        }
+    }
+}
+impl<'input> CreatePictureRequest<'input> {
+    /// Set the `repeat` field of `value_list`.
+    #[must_use]
+    pub fn repeat<I>(mut self, value: I) -> Self where I: Into<Option<Repeat>> {
+        self.value_list.to_mut().repeat = value.into();
+        self
+    }
+    /// Set the `alphamap` field of `value_list`.
+    #[must_use]
+    pub fn alphamap<I>(mut self, value: I) -> Self where I: Into<Option<Picture>> {
+        self.value_list.to_mut().alphamap = value.into();
+        self
+    }
+    /// Set the `alphaxorigin` field of `value_list`.
+    #[must_use]
+    pub fn alphaxorigin<I>(mut self, value: I) -> Self where I: Into<Option<i32>> {
+        self.value_list.to_mut().alphaxorigin = value.into();
+        self
+    }
+    /// Set the `alphayorigin` field of `value_list`.
+    #[must_use]
+    pub fn alphayorigin<I>(mut self, value: I) -> Self where I: Into<Option<i32>> {
+        self.value_list.to_mut().alphayorigin = value.into();
+        self
+    }
+    /// Set the `clipxorigin` field of `value_list`.
+    #[must_use]
+    pub fn clipxorigin<I>(mut self, value: I) -> Self where I: Into<Option<i32>> {
+        self.value_list.to_mut().clipxorigin = value.into();
+        self
+    }
+    /// Set the `clipyorigin` field of `value_list`.
+    #[must_use]
+    pub fn clipyorigin<I>(mut self, value: I) -> Self where I: Into<Option<i32>> {
+        self.value_list.to_mut().clipyorigin = value.into();
+        self
+    }
+    /// Set the `clipmask` field of `value_list`.
+    #[must_use]
+    pub fn clipmask<I>(mut self, value: I) -> Self where I: Into<Option<xproto::Pixmap>> {
+        self.value_list.to_mut().clipmask = value.into();
+        self
+    }
+    /// Set the `graphicsexposure` field of `value_list`.
+    #[must_use]
+    pub fn graphicsexposure<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().graphicsexposure = value.into();
+        self
+    }
+    /// Set the `subwindowmode` field of `value_list`.
+    #[must_use]
+    pub fn subwindowmode<I>(mut self, value: I) -> Self where I: Into<Option<xproto::SubwindowMode>> {
+        self.value_list.to_mut().subwindowmode = value.into();
+        self
+    }
+    /// Set the `polyedge` field of `value_list`.
+    #[must_use]
+    pub fn polyedge<I>(mut self, value: I) -> Self where I: Into<Option<PolyEdge>> {
+        self.value_list.to_mut().polyedge = value.into();
+        self
+    }
+    /// Set the `polymode` field of `value_list`.
+    #[must_use]
+    pub fn polymode<I>(mut self, value: I) -> Self where I: Into<Option<PolyMode>> {
+        self.value_list.to_mut().polymode = value.into();
+        self
+    }
+    /// Set the `dither` field of `value_list`.
+    #[must_use]
+    pub fn dither<I>(mut self, value: I) -> Self where I: Into<Option<xproto::Atom>> {
+        self.value_list.to_mut().dither = value.into();
+        self
+    }
+    /// Set the `componentalpha` field of `value_list`.
+    #[must_use]
+    pub fn componentalpha<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().componentalpha = value.into();
+        self
+    }
+}
 
 /// Auxiliary and optional information for the `change_picture` function
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangePictureAux {
     pub repeat: Option<Repeat>,
     pub alphamap: Option<Picture>,
@@ -2444,6 +3006,54 @@ impl ChangePictureAux {
         self.componentalpha = value.into();
         self
     }
+    /// Get the changes that are needed to go from `old` to `self`.
+    ///
+    /// The result contains all fields that are set in `self` and that are either unset in
+    /// `old` or set to a different value. This allows to only send values that changed.
+    #[must_use]
+    pub fn changes_from(&self, old: &Self) -> Self {
+        let mut result = Self::new();
+        if self.repeat.is_some() && self.repeat != old.repeat {
+            result.repeat = self.repeat;
+        }
+        if self.alphamap.is_some() && self.alphamap != old.alphamap {
+            result.alphamap = self.alphamap;
+        }
+        if self.alphaxorigin.is_some() && self.alphaxorigin != old.alphaxorigin {
+            result.alphaxorigin = self.alphaxorigin;
+        }
+        if self.alphayorigin.is_some() && self.alphayorigin != old.alphayorigin {
+            result.alphayorigin = self.alphayorigin;
+        }
+        if self.clipxorigin.is_some() && self.clipxorigin != old.clipxorigin {
+            result.clipxorigin = self.clipxorigin;
+        }
+        if self.clipyorigin.is_some() && self.clipyorigin != old.clipyorigin {
+            result.clipyorigin = self.clipyorigin;
+        }
+        if self.clipmask.is_some() && self.clipmask != old.clipmask {
+            result.clipmask = self.clipmask;
+        }
+        if self.graphicsexposure.is_some() && self.graphicsexposure != old.graphicsexposure {
+            result.graphicsexposure = self.graphicsexposure;
+        }
+        if self.subwindowmode.is_some() && self.subwindowmode != old.subwindowmode {
+            result.subwindowmode = self.subwindowmode;
+        }
+        if self.polyedge.is_some() && self.polyedge != old.polyedge {
+            result.polyedge = self.polyedge;
+        }
+        if self.polymode.is_some() && self.polymode != old.polymode {
+            result.polymode = self.polymode;
+        }
+        if self.dither.is_some() && self.dither != old.dither {
+            result.dither = self.dither;
+        }
+        if self.componentalpha.is_some() && self.componentalpha != old.componentalpha {
+            result.componentalpha = self.componentalpha;
+        }
+        result
+    }
 }
 
 /// Opcode for the ChangePicture request
@@ -2451,6 +3061,7 @@ pub const CHANGE_PICTURE_REQUEST: u8 = 5;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangePictureRequest<'input> {
     pub picture: Picture,
     pub value_list: Cow<'input, ChangePictureAux>,
@@ -2519,15 +3130,143 @@ impl<'input> Request for ChangePictureRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let picture_bytes = self.picture.serialize();
+        let value_mask: u32 = self.value_list.switch_expr();
+        let value_mask_bytes = value_mask.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CHANGE_PICTURE_REQUEST,
+            0,
+            0,
+            picture_bytes[0],
+            picture_bytes[1],
+            picture_bytes[2],
+            picture_bytes[3],
+            value_mask_bytes[0],
+            value_mask_bytes[1],
+            value_mask_bytes[2],
+            value_mask_bytes[3],
+        ]);
+        self.value_list.serialize_into(buffer, u32::from(value_mask));
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
+}
+impl<'input> crate::x11_utils::VoidRequest for ChangePictureRequest<'input> {
+}
+impl ChangePictureRequest<'static> {
+    /// Create a new request with all fields of `value_list` unset.
+    ///
+    /// The optional fields can then be set with the builder methods of this request.
+    pub fn new(picture: Picture) -> Self {
+        ChangePictureRequest {
+            picture,
+            value_list: Cow::Owned(ChangePictureAux::new()),
+        }
+    }
+}
+impl<'input> ChangePictureRequest<'input> {
+    /// Set the `repeat` field of `value_list`.
+    #[must_use]
+    pub fn repeat<I>(mut self, value: I) -> Self where I: Into<Option<Repeat>> {
+        self.value_list.to_mut().repeat = value.into();
+        self
+    }
+    /// Set the `alphamap` field of `value_list`.
+    #[must_use]
+    pub fn alphamap<I>(mut self, value: I) -> Self where I: Into<Option<Picture>> {
+        self.value_list.to_mut().alphamap = value.into();
+        self
+    }
+    /// Set the `alphaxorigin` field of `value_list`.
+    #[must_use]
+    pub fn alphaxorigin<I>(mut self, value: I) -> Self where I: Into<Option<i32>> {
+        self.value_list.to_mut().alphaxorigin = value.into();
+        self
+    }
+    /// Set the `alphayorigin` field of `value_list`.
+    #[must_use]
+    pub fn alphayorigin<I>(mut self, value: I) -> Self where I: Into<Option<i32>> {
+        self.value_list.to_mut().alphayorigin = value.into();
+        self
+    }
+    /// Set the `clipxorigin` field of `value_list`.
+    #[must_use]
+    pub fn clipxorigin<I>(mut self, value: I) -> Self where I: Into<Option<i32>> {
+        self.value_list.to_mut().clipxorigin = value.into();
+        self
+    }
+    /// Set the `clipyorigin` field of `value_list`.
+    #[must_use]
+    pub fn clipyorigin<I>(mut self, value: I) -> Self where I: Into<Option<i32>> {
+        self.value_list.to_mut().clipyorigin = value.into();
+        self
+    }
+    /// Set the `clipmask` field of `value_list`.
+    #[must_use]
+    pub fn clipmask<I>(mut self, value: I) -> Self where I: Into<Option<xproto::Pixmap>> {
+        self.value_list.to_mut().clipmask = value.into();
+        self
+    }
+    /// Set the `graphicsexposure` field of `value_list`.
+    #[must_use]
+    pub fn graphicsexposure<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().graphicsexposure = value.into();
+        self
+    }
+    /// Set the `subwindowmode` field of `value_list`.
+    #[must_use]
+    pub fn subwindowmode<I>(mut self, value: I) -> Self where I: Into<Option<xproto::SubwindowMode>> {
+        self.value_list.to_mut().subwindowmode = value.into();
+        self
+    }
+    /// Set the `polyedge` field of `value_list`.
+    #[must_use]
+    pub fn polyedge<I>(mut self, value: I) -> Self where I: Into<Option<PolyEdge>> {
+        self.value_list.to_mut().polyedge = value.into();
+        self
+    }
+    /// Set the `polymode` field of `value_list`.
+    #[must_use]
+    pub fn polymode<I>(mut self, value: I) -> Self where I: Into<Option<PolyMode>> {
+        self.value_list.to_mut().polymode = value.into();
+        self
+    }
+    /// Set the `dither` field of `value_list`.
+    #[must_use]
+    pub fn dither<I>(mut self, value: I) -> Self where I: Into<Option<xproto::Atom>> {
+        self.value_list.to_mut().dither = value.into();
+        self
+    }
+    /// Set the `componentalpha` field of `value_list`.
+    #[must_use]
+    pub fn componentalpha<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().componentalpha = value.into();
+        self
+    }
 }
-impl<'input> crate::x11_utils::VoidRequest for ChangePictureRequest<'input> {
-}
 
 /// Opcode for the SetPictureClipRectangles request
 pub const SET_PICTURE_CLIP_RECTANGLES_REQUEST: u8 = 6;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetPictureClipRectanglesRequest<'input> {
     pub picture: Picture,
     pub clip_x_origin: i16,
@@ -2577,7 +3316,7 @@ impl<'input> SetPictureClipRectanglesRequest<'input> {
         let (clip_y_origin, remaining) = i16::try_parse(remaining)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut rectangles = Vec::new();
+        let mut rectangles = Vec::with_capacity(remaining.len() / 8);
         while !remaining.is_empty() {
             let (v, new_remaining) = xproto::Rectangle::try_parse(remaining)?;
             remaining = new_remaining;
@@ -2610,6 +3349,42 @@ impl<'input> Request for SetPictureClipRectanglesRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let picture_bytes = self.picture.serialize();
+        let clip_x_origin_bytes = self.clip_x_origin.serialize();
+        let clip_y_origin_bytes = self.clip_y_origin.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_PICTURE_CLIP_RECTANGLES_REQUEST,
+            0,
+            0,
+            picture_bytes[0],
+            picture_bytes[1],
+            picture_bytes[2],
+            picture_bytes[3],
+            clip_x_origin_bytes[0],
+            clip_x_origin_bytes[1],
+            clip_y_origin_bytes[0],
+            clip_y_origin_bytes[1],
+        ]);
+        self.rectangles.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for SetPictureClipRectanglesRequest<'input> {
 }
@@ -2619,6 +3394,7 @@ pub const FREE_PICTURE_REQUEST: u8 = 7;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct FreePictureRequest {
     pub picture: Picture,
 }
@@ -2666,6 +3442,33 @@ impl Request for FreePictureRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let picture_bytes = self.picture.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            FREE_PICTURE_REQUEST,
+            0,
+            0,
+            picture_bytes[0],
+            picture_bytes[1],
+            picture_bytes[2],
+            picture_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for FreePictureRequest {
 }
@@ -2675,6 +3478,7 @@ pub const COMPOSITE_REQUEST: u8 = 8;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CompositeRequest {
     pub op: PictOp,
     pub src: Picture,
@@ -2796,6 +3600,72 @@ impl Request for CompositeRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let op_bytes = u8::from(self.op).serialize();
+        let src_bytes = self.src.serialize();
+        let mask_bytes = self.mask.serialize();
+        let dst_bytes = self.dst.serialize();
+        let src_x_bytes = self.src_x.serialize();
+        let src_y_bytes = self.src_y.serialize();
+        let mask_x_bytes = self.mask_x.serialize();
+        let mask_y_bytes = self.mask_y.serialize();
+        let dst_x_bytes = self.dst_x.serialize();
+        let dst_y_bytes = self.dst_y.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            COMPOSITE_REQUEST,
+            0,
+            0,
+            op_bytes[0],
+            0,
+            0,
+            0,
+            src_bytes[0],
+            src_bytes[1],
+            src_bytes[2],
+            src_bytes[3],
+            mask_bytes[0],
+            mask_bytes[1],
+            mask_bytes[2],
+            mask_bytes[3],
+            dst_bytes[0],
+            dst_bytes[1],
+            dst_bytes[2],
+            dst_bytes[3],
+            src_x_bytes[0],
+            src_x_bytes[1],
+            src_y_bytes[0],
+            src_y_bytes[1],
+            mask_x_bytes[0],
+            mask_x_bytes[1],
+            mask_y_bytes[0],
+            mask_y_bytes[1],
+            dst_x_bytes[0],
+            dst_x_bytes[1],
+            dst_y_bytes[0],
+            dst_y_bytes[1],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for CompositeRequest {
 }
@@ -2805,6 +3675,7 @@ pub const TRAPEZOIDS_REQUEST: u8 = 10;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct TrapezoidsRequest<'input> {
     pub op: PictOp,
     pub src: Picture,
@@ -2877,7 +3748,7 @@ impl<'input> TrapezoidsRequest<'input> {
         let (src_y, remaining) = i16::try_parse(remaining)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut traps = Vec::new();
+        let mut traps = Vec::with_capacity(remaining.len() / 40);
         while !remaining.is_empty() {
             let (v, new_remaining) = Trapezoid::try_parse(remaining)?;
             remaining = new_remaining;
@@ -2916,6 +3787,57 @@ impl<'input> Request for TrapezoidsRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let op_bytes = u8::from(self.op).serialize();
+        let src_bytes = self.src.serialize();
+        let dst_bytes = self.dst.serialize();
+        let mask_format_bytes = self.mask_format.serialize();
+        let src_x_bytes = self.src_x.serialize();
+        let src_y_bytes = self.src_y.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            TRAPEZOIDS_REQUEST,
+            0,
+            0,
+            op_bytes[0],
+            0,
+            0,
+            0,
+            src_bytes[0],
+            src_bytes[1],
+            src_bytes[2],
+            src_bytes[3],
+            dst_bytes[0],
+            dst_bytes[1],
+            dst_bytes[2],
+            dst_bytes[3],
+            mask_format_bytes[0],
+            mask_format_bytes[1],
+            mask_format_bytes[2],
+            mask_format_bytes[3],
+            src_x_bytes[0],
+            src_x_bytes[1],
+            src_y_bytes[0],
+            src_y_bytes[1],
+        ]);
+        self.traps.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for TrapezoidsRequest<'input> {
 }
@@ -2925,6 +3847,7 @@ pub const TRIANGLES_REQUEST: u8 = 11;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct TrianglesRequest<'input> {
     pub op: PictOp,
     pub src: Picture,
@@ -2997,7 +3920,7 @@ impl<'input> TrianglesRequest<'input> {
         let (src_y, remaining) = i16::try_parse(remaining)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut triangles = Vec::new();
+        let mut triangles = Vec::with_capacity(remaining.len() / 24);
         while !remaining.is_empty() {
             let (v, new_remaining) = Triangle::try_parse(remaining)?;
             remaining = new_remaining;
@@ -3036,6 +3959,57 @@ impl<'input> Request for TrianglesRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let op_bytes = u8::from(self.op).serialize();
+        let src_bytes = self.src.serialize();
+        let dst_bytes = self.dst.serialize();
+        let mask_format_bytes = self.mask_format.serialize();
+        let src_x_bytes = self.src_x.serialize();
+        let src_y_bytes = self.src_y.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            TRIANGLES_REQUEST,
+            0,
+            0,
+            op_bytes[0],
+            0,
+            0,
+            0,
+            src_bytes[0],
+            src_bytes[1],
+            src_bytes[2],
+            src_bytes[3],
+            dst_bytes[0],
+            dst_bytes[1],
+            dst_bytes[2],
+            dst_bytes[3],
+            mask_format_bytes[0],
+            mask_format_bytes[1],
+            mask_format_bytes[2],
+            mask_format_bytes[3],
+            src_x_bytes[0],
+            src_x_bytes[1],
+            src_y_bytes[0],
+            src_y_bytes[1],
+        ]);
+        self.triangles.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for TrianglesRequest<'input> {
 }
@@ -3045,6 +4019,7 @@ pub const TRI_STRIP_REQUEST: u8 = 12;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct TriStripRequest<'input> {
     pub op: PictOp,
     pub src: Picture,
@@ -3117,7 +4092,7 @@ impl<'input> TriStripRequest<'input> {
         let (src_y, remaining) = i16::try_parse(remaining)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut points = Vec::new();
+        let mut points = Vec::with_capacity(remaining.len() / 8);
         while !remaining.is_empty() {
             let (v, new_remaining) = Pointfix::try_parse(remaining)?;
             remaining = new_remaining;
@@ -3156,6 +4131,57 @@ impl<'input> Request for TriStripRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let op_bytes = u8::from(self.op).serialize();
+        let src_bytes = self.src.serialize();
+        let dst_bytes = self.dst.serialize();
+        let mask_format_bytes = self.mask_format.serialize();
+        let src_x_bytes = self.src_x.serialize();
+        let src_y_bytes = self.src_y.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            TRI_STRIP_REQUEST,
+            0,
+            0,
+            op_bytes[0],
+            0,
+            0,
+            0,
+            src_bytes[0],
+            src_bytes[1],
+            src_bytes[2],
+            src_bytes[3],
+            dst_bytes[0],
+            dst_bytes[1],
+            dst_bytes[2],
+            dst_bytes[3],
+            mask_format_bytes[0],
+            mask_format_bytes[1],
+            mask_format_bytes[2],
+            mask_format_bytes[3],
+            src_x_bytes[0],
+            src_x_bytes[1],
+            src_y_bytes[0],
+            src_y_bytes[1],
+        ]);
+        self.points.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for TriStripRequest<'input> {
 }
@@ -3165,6 +4191,7 @@ pub const TRI_FAN_REQUEST: u8 = 13;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct TriFanRequest<'input> {
     pub op: PictOp,
     pub src: Picture,
@@ -3237,7 +4264,7 @@ impl<'input> TriFanRequest<'input> {
         let (src_y, remaining) = i16::try_parse(remaining)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut points = Vec::new();
+        let mut points = Vec::with_capacity(remaining.len() / 8);
         while !remaining.is_empty() {
             let (v, new_remaining) = Pointfix::try_parse(remaining)?;
             remaining = new_remaining;
@@ -3276,6 +4303,57 @@ impl<'input> Request for TriFanRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let op_bytes = u8::from(self.op).serialize();
+        let src_bytes = self.src.serialize();
+        let dst_bytes = self.dst.serialize();
+        let mask_format_bytes = self.mask_format.serialize();
+        let src_x_bytes = self.src_x.serialize();
+        let src_y_bytes = self.src_y.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            TRI_FAN_REQUEST,
+            0,
+            0,
+            op_bytes[0],
+            0,
+            0,
+            0,
+            src_bytes[0],
+            src_bytes[1],
+            src_bytes[2],
+            src_bytes[3],
+            dst_bytes[0],
+            dst_bytes[1],
+            dst_bytes[2],
+            dst_bytes[3],
+            mask_format_bytes[0],
+            mask_format_bytes[1],
+            mask_format_bytes[2],
+            mask_format_bytes[3],
+            src_x_bytes[0],
+            src_x_bytes[1],
+            src_y_bytes[0],
+            src_y_bytes[1],
+        ]);
+        self.points.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for TriFanRequest<'input> {
 }
@@ -3285,6 +4363,7 @@ pub const CREATE_GLYPH_SET_REQUEST: u8 = 17;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateGlyphSetRequest {
     pub gsid: Glyphset,
     pub format: Pictformat,
@@ -3340,6 +4419,38 @@ impl Request for CreateGlyphSetRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let gsid_bytes = self.gsid.serialize();
+        let format_bytes = self.format.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CREATE_GLYPH_SET_REQUEST,
+            0,
+            0,
+            gsid_bytes[0],
+            gsid_bytes[1],
+            gsid_bytes[2],
+            gsid_bytes[3],
+            format_bytes[0],
+            format_bytes[1],
+            format_bytes[2],
+            format_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for CreateGlyphSetRequest {
 }
@@ -3349,6 +4460,7 @@ pub const REFERENCE_GLYPH_SET_REQUEST: u8 = 18;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ReferenceGlyphSetRequest {
     pub gsid: Glyphset,
     pub existing: Glyphset,
@@ -3404,6 +4516,38 @@ impl Request for ReferenceGlyphSetRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let gsid_bytes = self.gsid.serialize();
+        let existing_bytes = self.existing.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            REFERENCE_GLYPH_SET_REQUEST,
+            0,
+            0,
+            gsid_bytes[0],
+            gsid_bytes[1],
+            gsid_bytes[2],
+            gsid_bytes[3],
+            existing_bytes[0],
+            existing_bytes[1],
+            existing_bytes[2],
+            existing_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for ReferenceGlyphSetRequest {
 }
@@ -3413,6 +4557,7 @@ pub const FREE_GLYPH_SET_REQUEST: u8 = 19;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct FreeGlyphSetRequest {
     pub glyphset: Glyphset,
 }
@@ -3460,6 +4605,33 @@ impl Request for FreeGlyphSetRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let glyphset_bytes = self.glyphset.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            FREE_GLYPH_SET_REQUEST,
+            0,
+            0,
+            glyphset_bytes[0],
+            glyphset_bytes[1],
+            glyphset_bytes[2],
+            glyphset_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for FreeGlyphSetRequest {
 }
@@ -3469,6 +4641,7 @@ pub const ADD_GLYPHS_REQUEST: u8 = 20;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct AddGlyphsRequest<'input> {
     pub glyphset: Glyphset,
     pub glyphids: Cow<'input, [u32]>,
@@ -3549,6 +4722,45 @@ impl<'input> Request for AddGlyphsRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let glyphset_bytes = self.glyphset.serialize();
+        let glyphs_len = u32::try_from(self.glyphids.len()).expect("`glyphids` has too many elements");
+        let glyphs_len_bytes = glyphs_len.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            ADD_GLYPHS_REQUEST,
+            0,
+            0,
+            glyphset_bytes[0],
+            glyphset_bytes[1],
+            glyphset_bytes[2],
+            glyphset_bytes[3],
+            glyphs_len_bytes[0],
+            glyphs_len_bytes[1],
+            glyphs_len_bytes[2],
+            glyphs_len_bytes[3],
+        ]);
+        self.glyphids.serialize_into(buffer);
+        assert_eq!(self.glyphs.len(), usize::try_from(glyphs_len).unwrap(), "`glyphs` has an incorrect length");
+        self.glyphs.serialize_into(buffer);
+        buffer.extend_from_slice(&self.data[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for AddGlyphsRequest<'input> {
 }
@@ -3558,6 +4770,7 @@ pub const FREE_GLYPHS_REQUEST: u8 = 22;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct FreeGlyphsRequest<'input> {
     pub glyphset: Glyphset,
     pub glyphs: Cow<'input, [Glyph]>,
@@ -3597,7 +4810,7 @@ impl<'input> FreeGlyphsRequest<'input> {
         let (glyphset, remaining) = Glyphset::try_parse(value)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut glyphs = Vec::new();
+        let mut glyphs = Vec::with_capacity(remaining.len() / 4);
         while !remaining.is_empty() {
             let (v, new_remaining) = Glyph::try_parse(remaining)?;
             remaining = new_remaining;
@@ -3616,15 +4829,45 @@ impl<'input> FreeGlyphsRequest<'input> {
             glyphs: Cow::Owned(self.glyphs.into_owned()),
         }
     }
-}
-impl<'input> Request for FreeGlyphsRequest<'input> {
-    const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
-
-    fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
+}
+impl<'input> Request for FreeGlyphsRequest<'input> {
+    const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+
+    fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
+        let (bufs, fds) = self.serialize(major_opcode);
+        // Flatten the buffers into a single vector
+        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
+        (buf, fds)
+    }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let glyphset_bytes = self.glyphset.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            FREE_GLYPHS_REQUEST,
+            0,
+            0,
+            glyphset_bytes[0],
+            glyphset_bytes[1],
+            glyphset_bytes[2],
+            glyphset_bytes[3],
+        ]);
+        self.glyphs.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
         let (bufs, fds) = self.serialize(major_opcode);
-        // Flatten the buffers into a single vector
-        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
-        (buf, fds)
+        slices.extend(bufs);
+        fds
     }
 }
 impl<'input> crate::x11_utils::VoidRequest for FreeGlyphsRequest<'input> {
@@ -3635,6 +4878,7 @@ pub const COMPOSITE_GLYPHS8_REQUEST: u8 = 23;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CompositeGlyphs8Request<'input> {
     pub op: PictOp,
     pub src: Picture,
@@ -3747,6 +4991,62 @@ impl<'input> Request for CompositeGlyphs8Request<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let op_bytes = u8::from(self.op).serialize();
+        let src_bytes = self.src.serialize();
+        let dst_bytes = self.dst.serialize();
+        let mask_format_bytes = self.mask_format.serialize();
+        let glyphset_bytes = self.glyphset.serialize();
+        let src_x_bytes = self.src_x.serialize();
+        let src_y_bytes = self.src_y.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            COMPOSITE_GLYPHS8_REQUEST,
+            0,
+            0,
+            op_bytes[0],
+            0,
+            0,
+            0,
+            src_bytes[0],
+            src_bytes[1],
+            src_bytes[2],
+            src_bytes[3],
+            dst_bytes[0],
+            dst_bytes[1],
+            dst_bytes[2],
+            dst_bytes[3],
+            mask_format_bytes[0],
+            mask_format_bytes[1],
+            mask_format_bytes[2],
+            mask_format_bytes[3],
+            glyphset_bytes[0],
+            glyphset_bytes[1],
+            glyphset_bytes[2],
+            glyphset_bytes[3],
+            src_x_bytes[0],
+            src_x_bytes[1],
+            src_y_bytes[0],
+            src_y_bytes[1],
+        ]);
+        buffer.extend_from_slice(&self.glyphcmds[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for CompositeGlyphs8Request<'input> {
 }
@@ -3756,6 +5056,7 @@ pub const COMPOSITE_GLYPHS16_REQUEST: u8 = 24;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CompositeGlyphs16Request<'input> {
     pub op: PictOp,
     pub src: Picture,
@@ -3868,6 +5169,62 @@ impl<'input> Request for CompositeGlyphs16Request<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let op_bytes = u8::from(self.op).serialize();
+        let src_bytes = self.src.serialize();
+        let dst_bytes = self.dst.serialize();
+        let mask_format_bytes = self.mask_format.serialize();
+        let glyphset_bytes = self.glyphset.serialize();
+        let src_x_bytes = self.src_x.serialize();
+        let src_y_bytes = self.src_y.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            COMPOSITE_GLYPHS16_REQUEST,
+            0,
+            0,
+            op_bytes[0],
+            0,
+            0,
+            0,
+            src_bytes[0],
+            src_bytes[1],
+            src_bytes[2],
+            src_bytes[3],
+            dst_bytes[0],
+            dst_bytes[1],
+            dst_bytes[2],
+            dst_bytes[3],
+            mask_format_bytes[0],
+            mask_format_bytes[1],
+            mask_format_bytes[2],
+            mask_format_bytes[3],
+            glyphset_bytes[0],
+            glyphset_bytes[1],
+            glyphset_bytes[2],
+            glyphset_bytes[3],
+            src_x_bytes[0],
+            src_x_bytes[1],
+            src_y_bytes[0],
+            src_y_bytes[1],
+        ]);
+        buffer.extend_from_slice(&self.glyphcmds[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for CompositeGlyphs16Request<'input> {
 }
@@ -3877,6 +5234,7 @@ pub const COMPOSITE_GLYPHS32_REQUEST: u8 = 25;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CompositeGlyphs32Request<'input> {
     pub op: PictOp,
     pub src: Picture,
@@ -3989,6 +5347,62 @@ impl<'input> Request for CompositeGlyphs32Request<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let op_bytes = u8::from(self.op).serialize();
+        let src_bytes = self.src.serialize();
+        let dst_bytes = self.dst.serialize();
+        let mask_format_bytes = self.mask_format.serialize();
+        let glyphset_bytes = self.glyphset.serialize();
+        let src_x_bytes = self.src_x.serialize();
+        let src_y_bytes = self.src_y.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            COMPOSITE_GLYPHS32_REQUEST,
+            0,
+            0,
+            op_bytes[0],
+            0,
+            0,
+            0,
+            src_bytes[0],
+            src_bytes[1],
+            src_bytes[2],
+            src_bytes[3],
+            dst_bytes[0],
+            dst_bytes[1],
+            dst_bytes[2],
+            dst_bytes[3],
+            mask_format_bytes[0],
+            mask_format_bytes[1],
+            mask_format_bytes[2],
+            mask_format_bytes[3],
+            glyphset_bytes[0],
+            glyphset_bytes[1],
+            glyphset_bytes[2],
+            glyphset_bytes[3],
+            src_x_bytes[0],
+            src_x_bytes[1],
+            src_y_bytes[0],
+            src_y_bytes[1],
+        ]);
+        buffer.extend_from_slice(&self.glyphcmds[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for CompositeGlyphs32Request<'input> {
 }
@@ -3998,6 +5412,7 @@ pub const FILL_RECTANGLES_REQUEST: u8 = 26;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct FillRectanglesRequest<'input> {
     pub op: PictOp,
     pub dst: Picture,
@@ -4057,7 +5472,7 @@ impl<'input> FillRectanglesRequest<'input> {
         let (color, remaining) = Color::try_parse(remaining)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut rects = Vec::new();
+        let mut rects = Vec::with_capacity(remaining.len() / 8);
         while !remaining.is_empty() {
             let (v, new_remaining) = xproto::Rectangle::try_parse(remaining)?;
             remaining = new_remaining;
@@ -4090,6 +5505,50 @@ impl<'input> Request for FillRectanglesRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let op_bytes = u8::from(self.op).serialize();
+        let dst_bytes = self.dst.serialize();
+        let color_bytes = self.color.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            FILL_RECTANGLES_REQUEST,
+            0,
+            0,
+            op_bytes[0],
+            0,
+            0,
+            0,
+            dst_bytes[0],
+            dst_bytes[1],
+            dst_bytes[2],
+            dst_bytes[3],
+            color_bytes[0],
+            color_bytes[1],
+            color_bytes[2],
+            color_bytes[3],
+            color_bytes[4],
+            color_bytes[5],
+            color_bytes[6],
+            color_bytes[7],
+        ]);
+        self.rects.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for FillRectanglesRequest<'input> {
 }
@@ -4099,6 +5558,7 @@ pub const CREATE_CURSOR_REQUEST: u8 = 27;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateCursorRequest {
     pub cid: xproto::Cursor,
     pub source: Picture,
@@ -4166,6 +5626,44 @@ impl Request for CreateCursorRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let cid_bytes = self.cid.serialize();
+        let source_bytes = self.source.serialize();
+        let x_bytes = self.x.serialize();
+        let y_bytes = self.y.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CREATE_CURSOR_REQUEST,
+            0,
+            0,
+            cid_bytes[0],
+            cid_bytes[1],
+            cid_bytes[2],
+            cid_bytes[3],
+            source_bytes[0],
+            source_bytes[1],
+            source_bytes[2],
+            source_bytes[3],
+            x_bytes[0],
+            x_bytes[1],
+            y_bytes[0],
+            y_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for CreateCursorRequest {
 }
@@ -4185,6 +5683,12 @@ pub struct Transform {
     pub matrix33: Fixed,
 }
 impl_debug_if_no_extra_traits!(Transform, "Transform");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Transform {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Transform {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (matrix11, remaining) = Fixed::try_parse(remaining)?;
@@ -4270,6 +5774,7 @@ pub const SET_PICTURE_TRANSFORM_REQUEST: u8 = 28;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetPictureTransformRequest {
     pub picture: Picture,
     pub transform: Transform,
@@ -4281,7 +5786,87 @@ impl SetPictureTransformRequest {
         let length_so_far = 0;
         let picture_bytes = self.picture.serialize();
         let transform_bytes = self.transform.serialize();
-        let mut request0 = vec![
+        let mut request0 = vec![
+            major_opcode,
+            SET_PICTURE_TRANSFORM_REQUEST,
+            0,
+            0,
+            picture_bytes[0],
+            picture_bytes[1],
+            picture_bytes[2],
+            picture_bytes[3],
+            transform_bytes[0],
+            transform_bytes[1],
+            transform_bytes[2],
+            transform_bytes[3],
+            transform_bytes[4],
+            transform_bytes[5],
+            transform_bytes[6],
+            transform_bytes[7],
+            transform_bytes[8],
+            transform_bytes[9],
+            transform_bytes[10],
+            transform_bytes[11],
+            transform_bytes[12],
+            transform_bytes[13],
+            transform_bytes[14],
+            transform_bytes[15],
+            transform_bytes[16],
+            transform_bytes[17],
+            transform_bytes[18],
+            transform_bytes[19],
+            transform_bytes[20],
+            transform_bytes[21],
+            transform_bytes[22],
+            transform_bytes[23],
+            transform_bytes[24],
+            transform_bytes[25],
+            transform_bytes[26],
+            transform_bytes[27],
+            transform_bytes[28],
+            transform_bytes[29],
+            transform_bytes[30],
+            transform_bytes[31],
+            transform_bytes[32],
+            transform_bytes[33],
+            transform_bytes[34],
+            transform_bytes[35],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.to_ne_bytes());
+        ([request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    #[cfg(feature = "request-parsing")]
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_PICTURE_TRANSFORM_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (picture, remaining) = Picture::try_parse(value)?;
+        let (transform, remaining) = Transform::try_parse(remaining)?;
+        let _ = remaining;
+        Ok(SetPictureTransformRequest {
+            picture,
+            transform,
+        })
+    }
+}
+impl Request for SetPictureTransformRequest {
+    const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+
+    fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
+        let (bufs, fds) = self.serialize(major_opcode);
+        // Flatten the buffers into a single vector
+        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
+        (buf, fds)
+    }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let picture_bytes = self.picture.serialize();
+        let transform_bytes = self.transform.serialize();
+        buffer.extend_from_slice(&[
             major_opcode,
             SET_PICTURE_TRANSFORM_REQUEST,
             0,
@@ -4326,36 +5911,20 @@ impl SetPictureTransformRequest {
             transform_bytes[33],
             transform_bytes[34],
             transform_bytes[35],
-        ];
-        let length_so_far = length_so_far + request0.len();
+        ]);
+        let length_so_far = buffer.len() - request_start;
         assert_eq!(length_so_far % 4, 0);
         let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-        request0[2..4].copy_from_slice(&length.to_ne_bytes());
-        ([request0.into()], vec![])
-    }
-    /// Parse this request given its header, its body, and any fds that go along with it
-    #[cfg(feature = "request-parsing")]
-    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
-        if header.minor_opcode != SET_PICTURE_TRANSFORM_REQUEST {
-            return Err(ParseError::InvalidValue);
-        }
-        let (picture, remaining) = Picture::try_parse(value)?;
-        let (transform, remaining) = Transform::try_parse(remaining)?;
-        let _ = remaining;
-        Ok(SetPictureTransformRequest {
-            picture,
-            transform,
-        })
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
     }
-}
-impl Request for SetPictureTransformRequest {
-    const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
-
-    fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
         let (bufs, fds) = self.serialize(major_opcode);
-        // Flatten the buffers into a single vector
-        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
-        (buf, fds)
+        slices.extend(bufs);
+        fds
     }
 }
 impl crate::x11_utils::VoidRequest for SetPictureTransformRequest {
@@ -4366,6 +5935,7 @@ pub const QUERY_FILTERS_REQUEST: u8 = 29;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryFiltersRequest {
     pub drawable: xproto::Drawable,
 }
@@ -4413,6 +5983,33 @@ impl Request for QueryFiltersRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_FILTERS_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryFiltersRequest {
     type Reply = QueryFiltersReply;
@@ -4428,6 +6025,12 @@ pub struct QueryFiltersReply {
     pub filters: Vec<xproto::Str>,
 }
 impl_debug_if_no_extra_traits!(QueryFiltersReply, "QueryFiltersReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryFiltersReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryFiltersReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -4471,6 +6074,7 @@ impl Serialize for QueryFiltersReply {
         bytes.extend_from_slice(&[0; 16]);
         self.aliases.serialize_into(bytes);
         self.filters.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl QueryFiltersReply {
@@ -4507,6 +6111,7 @@ pub const SET_PICTURE_FILTER_REQUEST: u8 = 30;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetPictureFilterRequest<'input> {
     pub picture: Picture,
     pub filter: Cow<'input, [u8]>,
@@ -4563,7 +6168,7 @@ impl<'input> SetPictureFilterRequest<'input> {
         let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut values = Vec::new();
+        let mut values = Vec::with_capacity(remaining.len() / 4);
         while !remaining.is_empty() {
             let (v, new_remaining) = Fixed::try_parse(remaining)?;
             remaining = new_remaining;
@@ -4594,6 +6199,45 @@ impl<'input> Request for SetPictureFilterRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let picture_bytes = self.picture.serialize();
+        let filter_len = u16::try_from(self.filter.len()).expect("`filter` has too many elements");
+        let filter_len_bytes = filter_len.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_PICTURE_FILTER_REQUEST,
+            0,
+            0,
+            picture_bytes[0],
+            picture_bytes[1],
+            picture_bytes[2],
+            picture_bytes[3],
+            filter_len_bytes[0],
+            filter_len_bytes[1],
+            0,
+            0,
+        ]);
+        buffer.extend_from_slice(&self.filter[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        self.values.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for SetPictureFilterRequest<'input> {
 }
@@ -4606,6 +6250,12 @@ pub struct Animcursorelt {
     pub delay: u32,
 }
 impl_debug_if_no_extra_traits!(Animcursorelt, "Animcursorelt");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Animcursorelt {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Animcursorelt {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (cursor, remaining) = xproto::Cursor::try_parse(remaining)?;
@@ -4642,6 +6292,7 @@ pub const CREATE_ANIM_CURSOR_REQUEST: u8 = 31;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateAnimCursorRequest<'input> {
     pub cid: xproto::Cursor,
     pub cursors: Cow<'input, [Animcursorelt]>,
@@ -4681,7 +6332,7 @@ impl<'input> CreateAnimCursorRequest<'input> {
         let (cid, remaining) = xproto::Cursor::try_parse(value)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut cursors = Vec::new();
+        let mut cursors = Vec::with_capacity(remaining.len() / 8);
         while !remaining.is_empty() {
             let (v, new_remaining) = Animcursorelt::try_parse(remaining)?;
             remaining = new_remaining;
@@ -4710,6 +6361,36 @@ impl<'input> Request for CreateAnimCursorRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let cid_bytes = self.cid.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CREATE_ANIM_CURSOR_REQUEST,
+            0,
+            0,
+            cid_bytes[0],
+            cid_bytes[1],
+            cid_bytes[2],
+            cid_bytes[3],
+        ]);
+        self.cursors.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for CreateAnimCursorRequest<'input> {
 }
@@ -4723,6 +6404,12 @@ pub struct Spanfix {
     pub y: Fixed,
 }
 impl_debug_if_no_extra_traits!(Spanfix, "Spanfix");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Spanfix {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Spanfix {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (l, remaining) = Fixed::try_parse(remaining)?;
@@ -4769,6 +6456,12 @@ pub struct Trap {
     pub bot: Spanfix,
 }
 impl_debug_if_no_extra_traits!(Trap, "Trap");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Trap {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Trap {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (top, remaining) = Spanfix::try_parse(remaining)?;
@@ -4821,6 +6514,7 @@ pub const ADD_TRAPS_REQUEST: u8 = 32;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct AddTrapsRequest<'input> {
     pub picture: Picture,
     pub x_off: i16,
@@ -4870,7 +6564,7 @@ impl<'input> AddTrapsRequest<'input> {
         let (y_off, remaining) = i16::try_parse(remaining)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut traps = Vec::new();
+        let mut traps = Vec::with_capacity(remaining.len() / 24);
         while !remaining.is_empty() {
             let (v, new_remaining) = Trap::try_parse(remaining)?;
             remaining = new_remaining;
@@ -4903,6 +6597,42 @@ impl<'input> Request for AddTrapsRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let picture_bytes = self.picture.serialize();
+        let x_off_bytes = self.x_off.serialize();
+        let y_off_bytes = self.y_off.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            ADD_TRAPS_REQUEST,
+            0,
+            0,
+            picture_bytes[0],
+            picture_bytes[1],
+            picture_bytes[2],
+            picture_bytes[3],
+            x_off_bytes[0],
+            x_off_bytes[1],
+            y_off_bytes[0],
+            y_off_bytes[1],
+        ]);
+        self.traps.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for AddTrapsRequest<'input> {
 }
@@ -4912,6 +6642,7 @@ pub const CREATE_SOLID_FILL_REQUEST: u8 = 33;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateSolidFillRequest {
     pub picture: Picture,
     pub color: Color,
@@ -4971,6 +6702,42 @@ impl Request for CreateSolidFillRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let picture_bytes = self.picture.serialize();
+        let color_bytes = self.color.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CREATE_SOLID_FILL_REQUEST,
+            0,
+            0,
+            picture_bytes[0],
+            picture_bytes[1],
+            picture_bytes[2],
+            picture_bytes[3],
+            color_bytes[0],
+            color_bytes[1],
+            color_bytes[2],
+            color_bytes[3],
+            color_bytes[4],
+            color_bytes[5],
+            color_bytes[6],
+            color_bytes[7],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for CreateSolidFillRequest {
 }
@@ -4980,6 +6747,7 @@ pub const CREATE_LINEAR_GRADIENT_REQUEST: u8 = 34;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateLinearGradientRequest<'input> {
     pub picture: Picture,
     pub p1: Pointfix,
@@ -5081,6 +6849,62 @@ impl<'input> Request for CreateLinearGradientRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let picture_bytes = self.picture.serialize();
+        let p1_bytes = self.p1.serialize();
+        let p2_bytes = self.p2.serialize();
+        let num_stops = u32::try_from(self.stops.len()).expect("`stops` has too many elements");
+        let num_stops_bytes = num_stops.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CREATE_LINEAR_GRADIENT_REQUEST,
+            0,
+            0,
+            picture_bytes[0],
+            picture_bytes[1],
+            picture_bytes[2],
+            picture_bytes[3],
+            p1_bytes[0],
+            p1_bytes[1],
+            p1_bytes[2],
+            p1_bytes[3],
+            p1_bytes[4],
+            p1_bytes[5],
+            p1_bytes[6],
+            p1_bytes[7],
+            p2_bytes[0],
+            p2_bytes[1],
+            p2_bytes[2],
+            p2_bytes[3],
+            p2_bytes[4],
+            p2_bytes[5],
+            p2_bytes[6],
+            p2_bytes[7],
+            num_stops_bytes[0],
+            num_stops_bytes[1],
+            num_stops_bytes[2],
+            num_stops_bytes[3],
+        ]);
+        self.stops.serialize_into(buffer);
+        assert_eq!(self.colors.len(), usize::try_from(num_stops).unwrap(), "`colors` has an incorrect length");
+        self.colors.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for CreateLinearGradientRequest<'input> {
 }
@@ -5090,6 +6914,7 @@ pub const CREATE_RADIAL_GRADIENT_REQUEST: u8 = 35;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateRadialGradientRequest<'input> {
     pub picture: Picture,
     pub inner: Pointfix,
@@ -5209,6 +7034,72 @@ impl<'input> Request for CreateRadialGradientRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let picture_bytes = self.picture.serialize();
+        let inner_bytes = self.inner.serialize();
+        let outer_bytes = self.outer.serialize();
+        let inner_radius_bytes = self.inner_radius.serialize();
+        let outer_radius_bytes = self.outer_radius.serialize();
+        let num_stops = u32::try_from(self.stops.len()).expect("`stops` has too many elements");
+        let num_stops_bytes = num_stops.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CREATE_RADIAL_GRADIENT_REQUEST,
+            0,
+            0,
+            picture_bytes[0],
+            picture_bytes[1],
+            picture_bytes[2],
+            picture_bytes[3],
+            inner_bytes[0],
+            inner_bytes[1],
+            inner_bytes[2],
+            inner_bytes[3],
+            inner_bytes[4],
+            inner_bytes[5],
+            inner_bytes[6],
+            inner_bytes[7],
+            outer_bytes[0],
+            outer_bytes[1],
+            outer_bytes[2],
+            outer_bytes[3],
+            outer_bytes[4],
+            outer_bytes[5],
+            outer_bytes[6],
+            outer_bytes[7],
+            inner_radius_bytes[0],
+            inner_radius_bytes[1],
+            inner_radius_bytes[2],
+            inner_radius_bytes[3],
+            outer_radius_bytes[0],
+            outer_radius_bytes[1],
+            outer_radius_bytes[2],
+            outer_radius_bytes[3],
+            num_stops_bytes[0],
+            num_stops_bytes[1],
+            num_stops_bytes[2],
+            num_stops_bytes[3],
+        ]);
+        self.stops.serialize_into(buffer);
+        assert_eq!(self.colors.len(), usize::try_from(num_stops).unwrap(), "`colors` has an incorrect length");
+        self.colors.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for CreateRadialGradientRequest<'input> {
 }
@@ -5218,6 +7109,7 @@ pub const CREATE_CONICAL_GRADIENT_REQUEST: u8 = 36;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateConicalGradientRequest<'input> {
     pub picture: Picture,
     pub center: Pointfix,
@@ -5315,7 +7207,94 @@ impl<'input> Request for CreateConicalGradientRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let picture_bytes = self.picture.serialize();
+        let center_bytes = self.center.serialize();
+        let angle_bytes = self.angle.serialize();
+        let num_stops = u32::try_from(self.stops.len()).expect("`stops` has too many elements");
+        let num_stops_bytes = num_stops.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CREATE_CONICAL_GRADIENT_REQUEST,
+            0,
+            0,
+            picture_bytes[0],
+            picture_bytes[1],
+            picture_bytes[2],
+            picture_bytes[3],
+            center_bytes[0],
+            center_bytes[1],
+            center_bytes[2],
+            center_bytes[3],
+            center_bytes[4],
+            center_bytes[5],
+            center_bytes[6],
+            center_bytes[7],
+            angle_bytes[0],
+            angle_bytes[1],
+            angle_bytes[2],
+            angle_bytes[3],
+            num_stops_bytes[0],
+            num_stops_bytes[1],
+            num_stops_bytes[2],
+            num_stops_bytes[3],
+        ]);
+        self.stops.serialize_into(buffer);
+        assert_eq!(self.colors.len(), usize::try_from(num_stops).unwrap(), "`colors` has an incorrect length");
+        self.colors.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for CreateConicalGradientRequest<'input> {
 }
 
+/// All requests of this module.
+pub const REQUESTS: &[RequestDescription] = &[
+    RequestDescription { opcode: QUERY_VERSION_REQUEST, name: "QueryVersion", has_reply: true },
+    RequestDescription { opcode: QUERY_PICT_FORMATS_REQUEST, name: "QueryPictFormats", has_reply: true },
+    RequestDescription { opcode: QUERY_PICT_INDEX_VALUES_REQUEST, name: "QueryPictIndexValues", has_reply: true },
+    RequestDescription { opcode: CREATE_PICTURE_REQUEST, name: "CreatePicture", has_reply: false },
+    RequestDescription { opcode: CHANGE_PICTURE_REQUEST, name: "ChangePicture", has_reply: false },
+    RequestDescription { opcode: SET_PICTURE_CLIP_RECTANGLES_REQUEST, name: "SetPictureClipRectangles", has_reply: false },
+    RequestDescription { opcode: FREE_PICTURE_REQUEST, name: "FreePicture", has_reply: false },
+    RequestDescription { opcode: COMPOSITE_REQUEST, name: "Composite", has_reply: false },
+    RequestDescription { opcode: TRAPEZOIDS_REQUEST, name: "Trapezoids", has_reply: false },
+    RequestDescription { opcode: TRIANGLES_REQUEST, name: "Triangles", has_reply: false },
+    RequestDescription { opcode: TRI_STRIP_REQUEST, name: "TriStrip", has_reply: false },
+    RequestDescription { opcode: TRI_FAN_REQUEST, name: "TriFan", has_reply: false },
+    RequestDescription { opcode: CREATE_GLYPH_SET_REQUEST, name: "CreateGlyphSet", has_reply: false },
+    RequestDescription { opcode: REFERENCE_GLYPH_SET_REQUEST, name: "ReferenceGlyphSet", has_reply: false },
+    RequestDescription { opcode: FREE_GLYPH_SET_REQUEST, name: "FreeGlyphSet", has_reply: false },
+    RequestDescription { opcode: ADD_GLYPHS_REQUEST, name: "AddGlyphs", has_reply: false },
+    RequestDescription { opcode: FREE_GLYPHS_REQUEST, name: "FreeGlyphs", has_reply: false },
+    RequestDescription { opcode: COMPOSITE_GLYPHS8_REQUEST, name: "CompositeGlyphs8", has_reply: false },
+    RequestDescription { opcode: COMPOSITE_GLYPHS16_REQUEST, name: "CompositeGlyphs16", has_reply: false },
+    RequestDescription { opcode: COMPOSITE_GLYPHS32_REQUEST, name: "CompositeGlyphs32", has_reply: false },
+    RequestDescription { opcode: FILL_RECTANGLES_REQUEST, name: "FillRectangles", has_reply: false },
+    RequestDescription { opcode: CREATE_CURSOR_REQUEST, name: "CreateCursor", has_reply: false },
+    RequestDescription { opcode: SET_PICTURE_TRANSFORM_REQUEST, name: "SetPictureTransform", has_reply: false },
+    RequestDescription { opcode: QUERY_FILTERS_REQUEST, name: "QueryFilters", has_reply: true },
+    RequestDescription { opcode: SET_PICTURE_FILTER_REQUEST, name: "SetPictureFilter", has_reply: false },
+    RequestDescription { opcode: CREATE_ANIM_CURSOR_REQUEST, name: "CreateAnimCursor", has_reply: false },
+    RequestDescription { opcode: ADD_TRAPS_REQUEST, name: "AddTraps", has_reply: false },
+    RequestDescription { opcode: CREATE_SOLID_FILL_REQUEST, name: "CreateSolidFill", has_reply: false },
+    RequestDescription { opcode: CREATE_LINEAR_GRADIENT_REQUEST, name: "CreateLinearGradient", has_reply: false },
+    RequestDescription { opcode: CREATE_RADIAL_GRADIENT_REQUEST, name: "CreateRadialGradient", has_reply: false },
+    RequestDescription { opcode: CREATE_CONICAL_GRADIENT_REQUEST, name: "CreateConicalGradient", has_reply: false },
+];
+
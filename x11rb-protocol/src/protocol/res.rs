@@ -22,6 +22,7 @@ use crate::BufWithFds;
 use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::x11_utils::RequestDescription;
 #[allow(unused_imports)]
 use super::xproto;
 
@@ -44,6 +45,12 @@ pub struct Client {
     pub resource_mask: u32,
 }
 impl_debug_if_no_extra_traits!(Client, "Client");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Client {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Client {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (resource_base, remaining) = u32::try_parse(remaining)?;
@@ -83,6 +90,12 @@ pub struct Type {
     pub count: u32,
 }
 impl_debug_if_no_extra_traits!(Type, "Type");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Type {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Type {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (resource_type, remaining) = xproto::Atom::try_parse(remaining)?;
@@ -116,6 +129,7 @@ impl Serialize for Type {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ClientIdMask(u32);
 impl ClientIdMask {
     pub const CLIENT_XID: Self = Self(1 << 0);
@@ -160,6 +174,21 @@ impl core::fmt::Debug for ClientIdMask  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl ClientIdMask {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("ClientXID"),
+            2 => Some("LocalClientPID"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ClientIdMask {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(ClientIdMask, u32);
 
 #[derive(Clone, Copy, Default)]
@@ -170,6 +199,12 @@ pub struct ClientIdSpec {
     pub mask: ClientIdMask,
 }
 impl_debug_if_no_extra_traits!(ClientIdSpec, "ClientIdSpec");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ClientIdSpec {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for ClientIdSpec {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (client, remaining) = u32::try_parse(remaining)?;
@@ -210,6 +245,12 @@ pub struct ClientIdValue {
     pub value: Vec<u32>,
 }
 impl_debug_if_no_extra_traits!(ClientIdValue, "ClientIdValue");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ClientIdValue {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for ClientIdValue {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (spec, remaining) = ClientIdSpec::try_parse(remaining)?;
@@ -259,6 +300,12 @@ pub struct ResourceIdSpec {
     pub type_: u32,
 }
 impl_debug_if_no_extra_traits!(ResourceIdSpec, "ResourceIdSpec");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ResourceIdSpec {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for ResourceIdSpec {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (resource, remaining) = u32::try_parse(remaining)?;
@@ -300,6 +347,12 @@ pub struct ResourceSizeSpec {
     pub use_count: u32,
 }
 impl_debug_if_no_extra_traits!(ResourceSizeSpec, "ResourceSizeSpec");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ResourceSizeSpec {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for ResourceSizeSpec {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (spec, remaining) = ResourceIdSpec::try_parse(remaining)?;
@@ -357,6 +410,12 @@ pub struct ResourceSizeValue {
     pub cross_references: Vec<ResourceSizeSpec>,
 }
 impl_debug_if_no_extra_traits!(ResourceSizeValue, "ResourceSizeValue");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ResourceSizeValue {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for ResourceSizeValue {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (size, remaining) = ResourceSizeSpec::try_parse(remaining)?;
@@ -402,6 +461,7 @@ pub const QUERY_VERSION_REQUEST: u8 = 0;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryVersionRequest {
     pub client_major: u8,
     pub client_minor: u8,
@@ -453,6 +513,34 @@ impl Request for QueryVersionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let client_major_bytes = self.client_major.serialize();
+        let client_minor_bytes = self.client_minor.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+            client_major_bytes[0],
+            client_minor_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryVersionRequest {
     type Reply = QueryVersionReply;
@@ -468,6 +556,12 @@ pub struct QueryVersionReply {
     pub server_minor: u16,
 }
 impl_debug_if_no_extra_traits!(QueryVersionReply, "QueryVersionReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryVersionReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -527,6 +621,7 @@ pub const QUERY_CLIENTS_REQUEST: u8 = 1;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryClientsRequest;
 impl_debug_if_no_extra_traits!(QueryClientsRequest, "QueryClientsRequest");
 impl QueryClientsRequest {
@@ -565,6 +660,28 @@ impl Request for QueryClientsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_CLIENTS_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryClientsRequest {
     type Reply = QueryClientsReply;
@@ -579,6 +696,12 @@ pub struct QueryClientsReply {
     pub clients: Vec<Client>,
 }
 impl_debug_if_no_extra_traits!(QueryClientsReply, "QueryClientsReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryClientsReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryClientsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -617,6 +740,7 @@ impl Serialize for QueryClientsReply {
         num_clients.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 20]);
         self.clients.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl QueryClientsReply {
@@ -640,6 +764,7 @@ pub const QUERY_CLIENT_RESOURCES_REQUEST: u8 = 2;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryClientResourcesRequest {
     pub xid: u32,
 }
@@ -687,6 +812,33 @@ impl Request for QueryClientResourcesRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let xid_bytes = self.xid.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_CLIENT_RESOURCES_REQUEST,
+            0,
+            0,
+            xid_bytes[0],
+            xid_bytes[1],
+            xid_bytes[2],
+            xid_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryClientResourcesRequest {
     type Reply = QueryClientResourcesReply;
@@ -701,6 +853,12 @@ pub struct QueryClientResourcesReply {
     pub types: Vec<Type>,
 }
 impl_debug_if_no_extra_traits!(QueryClientResourcesReply, "QueryClientResourcesReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryClientResourcesReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryClientResourcesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -739,6 +897,7 @@ impl Serialize for QueryClientResourcesReply {
         num_types.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 20]);
         self.types.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl QueryClientResourcesReply {
@@ -762,6 +921,7 @@ pub const QUERY_CLIENT_PIXMAP_BYTES_REQUEST: u8 = 3;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryClientPixmapBytesRequest {
     pub xid: u32,
 }
@@ -809,6 +969,33 @@ impl Request for QueryClientPixmapBytesRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let xid_bytes = self.xid.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_CLIENT_PIXMAP_BYTES_REQUEST,
+            0,
+            0,
+            xid_bytes[0],
+            xid_bytes[1],
+            xid_bytes[2],
+            xid_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryClientPixmapBytesRequest {
     type Reply = QueryClientPixmapBytesReply;
@@ -824,6 +1011,12 @@ pub struct QueryClientPixmapBytesReply {
     pub bytes_overflow: u32,
 }
 impl_debug_if_no_extra_traits!(QueryClientPixmapBytesReply, "QueryClientPixmapBytesReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryClientPixmapBytesReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryClientPixmapBytesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -887,6 +1080,7 @@ pub const QUERY_CLIENT_IDS_REQUEST: u8 = 4;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryClientIdsRequest<'input> {
     pub specs: Cow<'input, [ClientIdSpec]>,
 }
@@ -946,6 +1140,37 @@ impl<'input> Request for QueryClientIdsRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let num_specs = u32::try_from(self.specs.len()).expect("`specs` has too many elements");
+        let num_specs_bytes = num_specs.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_CLIENT_IDS_REQUEST,
+            0,
+            0,
+            num_specs_bytes[0],
+            num_specs_bytes[1],
+            num_specs_bytes[2],
+            num_specs_bytes[3],
+        ]);
+        self.specs.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::ReplyRequest for QueryClientIdsRequest<'input> {
     type Reply = QueryClientIdsReply;
@@ -960,6 +1185,12 @@ pub struct QueryClientIdsReply {
     pub ids: Vec<ClientIdValue>,
 }
 impl_debug_if_no_extra_traits!(QueryClientIdsReply, "QueryClientIdsReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryClientIdsReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryClientIdsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -998,6 +1229,7 @@ impl Serialize for QueryClientIdsReply {
         num_ids.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 20]);
         self.ids.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl QueryClientIdsReply {
@@ -1021,6 +1253,7 @@ pub const QUERY_RESOURCE_BYTES_REQUEST: u8 = 5;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryResourceBytesRequest<'input> {
     pub client: u32,
     pub specs: Cow<'input, [ResourceIdSpec]>,
@@ -1089,6 +1322,42 @@ impl<'input> Request for QueryResourceBytesRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let client_bytes = self.client.serialize();
+        let num_specs = u32::try_from(self.specs.len()).expect("`specs` has too many elements");
+        let num_specs_bytes = num_specs.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_RESOURCE_BYTES_REQUEST,
+            0,
+            0,
+            client_bytes[0],
+            client_bytes[1],
+            client_bytes[2],
+            client_bytes[3],
+            num_specs_bytes[0],
+            num_specs_bytes[1],
+            num_specs_bytes[2],
+            num_specs_bytes[3],
+        ]);
+        self.specs.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::ReplyRequest for QueryResourceBytesRequest<'input> {
     type Reply = QueryResourceBytesReply;
@@ -1103,6 +1372,12 @@ pub struct QueryResourceBytesReply {
     pub sizes: Vec<ResourceSizeValue>,
 }
 impl_debug_if_no_extra_traits!(QueryResourceBytesReply, "QueryResourceBytesReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryResourceBytesReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryResourceBytesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1141,6 +1416,7 @@ impl Serialize for QueryResourceBytesReply {
         num_sizes.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 20]);
         self.sizes.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl QueryResourceBytesReply {
@@ -1159,3 +1435,13 @@ impl QueryResourceBytesReply {
     }
 }
 
+/// All requests of this module.
+pub const REQUESTS: &[RequestDescription] = &[
+    RequestDescription { opcode: QUERY_VERSION_REQUEST, name: "QueryVersion", has_reply: true },
+    RequestDescription { opcode: QUERY_CLIENTS_REQUEST, name: "QueryClients", has_reply: true },
+    RequestDescription { opcode: QUERY_CLIENT_RESOURCES_REQUEST, name: "QueryClientResources", has_reply: true },
+    RequestDescription { opcode: QUERY_CLIENT_PIXMAP_BYTES_REQUEST, name: "QueryClientPixmapBytes", has_reply: true },
+    RequestDescription { opcode: QUERY_CLIENT_IDS_REQUEST, name: "QueryClientIds", has_reply: true },
+    RequestDescription { opcode: QUERY_RESOURCE_BYTES_REQUEST, name: "QueryResourceBytes", has_reply: true },
+];
+
@@ -22,6 +22,7 @@ use crate::BufWithFds;
 use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::x11_utils::RequestDescription;
 #[allow(unused_imports)]
 use super::xproto;
 
@@ -38,6 +39,7 @@ pub const X11_XML_VERSION: (u32, u32) = (1, 1);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Kind(u8);
 impl Kind {
     pub const BLANKED: Self = Self(0);
@@ -96,9 +98,26 @@ impl core::fmt::Debug for Kind  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl Kind {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Blanked"),
+            1 => Some("Internal"),
+            2 => Some("External"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Kind {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Event(u32);
 impl Event {
     pub const NOTIFY_MASK: Self = Self(1 << 0);
@@ -143,10 +162,26 @@ impl core::fmt::Debug for Event  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl Event {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("NotifyMask"),
+            2 => Some("CycleMask"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Event {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(Event, u32);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct State(u8);
 impl State {
     pub const OFF: Self = Self(0);
@@ -207,12 +242,30 @@ impl core::fmt::Debug for State  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl State {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Off"),
+            1 => Some("On"),
+            2 => Some("Cycle"),
+            3 => Some("Disabled"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for State {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the QueryVersion request
 pub const QUERY_VERSION_REQUEST: u8 = 0;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryVersionRequest {
     pub client_major_version: u8,
     pub client_minor_version: u8,
@@ -265,6 +318,34 @@ impl Request for QueryVersionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let client_major_version_bytes = self.client_major_version.serialize();
+        let client_minor_version_bytes = self.client_minor_version.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+            client_major_version_bytes[0],
+            client_minor_version_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryVersionRequest {
     type Reply = QueryVersionReply;
@@ -280,6 +361,12 @@ pub struct QueryVersionReply {
     pub server_minor_version: u16,
 }
 impl_debug_if_no_extra_traits!(QueryVersionReply, "QueryVersionReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryVersionReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -361,6 +448,7 @@ pub const QUERY_INFO_REQUEST: u8 = 1;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryInfoRequest {
     pub drawable: xproto::Drawable,
 }
@@ -408,6 +496,33 @@ impl Request for QueryInfoRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_INFO_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryInfoRequest {
     type Reply = QueryInfoReply;
@@ -427,6 +542,12 @@ pub struct QueryInfoReply {
     pub kind: Kind,
 }
 impl_debug_if_no_extra_traits!(QueryInfoReply, "QueryInfoReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryInfoReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryInfoReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -519,6 +640,7 @@ pub const SELECT_INPUT_REQUEST: u8 = 2;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SelectInputRequest {
     pub drawable: xproto::Drawable,
     pub event_mask: Event,
@@ -575,6 +697,38 @@ impl Request for SelectInputRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        let event_mask_bytes = u32::from(self.event_mask).serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SELECT_INPUT_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            event_mask_bytes[0],
+            event_mask_bytes[1],
+            event_mask_bytes[2],
+            event_mask_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SelectInputRequest {
 }
@@ -583,6 +737,7 @@ impl crate::x11_utils::VoidRequest for SelectInputRequest {
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetAttributesAux {
     pub background_pixmap: Option<xproto::Pixmap>,
     pub background_pixel: Option<u32>,
@@ -937,6 +1092,60 @@ impl SetAttributesAux {
         self.cursor = value.into();
         self
     }
+    /// Get the changes that are needed to go from `old` to `self`.
+    ///
+    /// The result contains all fields that are set in `self` and that are either unset in
+    /// `old` or set to a different value. This allows to only send values that changed.
+    #[must_use]
+    pub fn changes_from(&self, old: &Self) -> Self {
+        let mut result = Self::new();
+        if self.background_pixmap.is_some() && self.background_pixmap != old.background_pixmap {
+            result.background_pixmap = self.background_pixmap;
+        }
+        if self.background_pixel.is_some() && self.background_pixel != old.background_pixel {
+            result.background_pixel = self.background_pixel;
+        }
+        if self.border_pixmap.is_some() && self.border_pixmap != old.border_pixmap {
+            result.border_pixmap = self.border_pixmap;
+        }
+        if self.border_pixel.is_some() && self.border_pixel != old.border_pixel {
+            result.border_pixel = self.border_pixel;
+        }
+        if self.bit_gravity.is_some() && self.bit_gravity != old.bit_gravity {
+            result.bit_gravity = self.bit_gravity;
+        }
+        if self.win_gravity.is_some() && self.win_gravity != old.win_gravity {
+            result.win_gravity = self.win_gravity;
+        }
+        if self.backing_store.is_some() && self.backing_store != old.backing_store {
+            result.backing_store = self.backing_store;
+        }
+        if self.backing_planes.is_some() && self.backing_planes != old.backing_planes {
+            result.backing_planes = self.backing_planes;
+        }
+        if self.backing_pixel.is_some() && self.backing_pixel != old.backing_pixel {
+            result.backing_pixel = self.backing_pixel;
+        }
+        if self.override_redirect.is_some() && self.override_redirect != old.override_redirect {
+            result.override_redirect = self.override_redirect;
+        }
+        if self.save_under.is_some() && self.save_under != old.save_under {
+            result.save_under = self.save_under;
+        }
+        if self.event_mask.is_some() && self.event_mask != old.event_mask {
+            result.event_mask = self.event_mask;
+        }
+        if self.do_not_propogate_mask.is_some() && self.do_not_propogate_mask != old.do_not_propogate_mask {
+            result.do_not_propogate_mask = self.do_not_propogate_mask;
+        }
+        if self.colormap.is_some() && self.colormap != old.colormap {
+            result.colormap = self.colormap;
+        }
+        if self.cursor.is_some() && self.cursor != old.cursor {
+            result.cursor = self.cursor;
+        }
+        result
+    }
 }
 
 /// Opcode for the SetAttributes request
@@ -944,6 +1153,7 @@ pub const SET_ATTRIBUTES_REQUEST: u8 = 3;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetAttributesRequest<'input> {
     pub drawable: xproto::Drawable,
     pub x: i16,
@@ -1069,15 +1279,187 @@ impl<'input> Request for SetAttributesRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        let x_bytes = self.x.serialize();
+        let y_bytes = self.y.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        let border_width_bytes = self.border_width.serialize();
+        let class_bytes = (u16::from(self.class) as u8).serialize();
+        let depth_bytes = self.depth.serialize();
+        let visual_bytes = self.visual.serialize();
+        let value_mask: u32 = self.value_list.switch_expr();
+        let value_mask_bytes = value_mask.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_ATTRIBUTES_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            x_bytes[0],
+            x_bytes[1],
+            y_bytes[0],
+            y_bytes[1],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+            border_width_bytes[0],
+            border_width_bytes[1],
+            class_bytes[0],
+            depth_bytes[0],
+            visual_bytes[0],
+            visual_bytes[1],
+            visual_bytes[2],
+            visual_bytes[3],
+            value_mask_bytes[0],
+            value_mask_bytes[1],
+            value_mask_bytes[2],
+            value_mask_bytes[3],
+        ]);
+        self.value_list.serialize_into(buffer, u32::from(value_mask));
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for SetAttributesRequest<'input> {
 }
+impl SetAttributesRequest<'static> {
+    /// Create a new request with all fields of `value_list` unset.
+    ///
+    /// The optional fields can then be set with the builder methods of this request.
+    pub fn new(drawable: xproto::Drawable, x: i16, y: i16, width: u16, height: u16, border_width: u16, class: xproto::WindowClass, depth: u8, visual: xproto::Visualid) -> Self {
+        SetAttributesRequest {
+            drawable,
+            x,
+            y,
+            width,
+            height,
+            border_width,
+            class,
+            depth,
+            visual,
+            value_list: Cow::Owned(SetAttributesAux::new()),
+        }
+    }
+}
+impl<'input> SetAttributesRequest<'input> {
+    /// Set the `background_pixmap` field of `value_list`.
+    #[must_use]
+    pub fn background_pixmap<I>(mut self, value: I) -> Self where I: Into<Option<xproto::Pixmap>> {
+        self.value_list.to_mut().background_pixmap = value.into();
+        self
+    }
+    /// Set the `background_pixel` field of `value_list`.
+    #[must_use]
+    pub fn background_pixel<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().background_pixel = value.into();
+        self
+    }
+    /// Set the `border_pixmap` field of `value_list`.
+    #[must_use]
+    pub fn border_pixmap<I>(mut self, value: I) -> Self where I: Into<Option<xproto::Pixmap>> {
+        self.value_list.to_mut().border_pixmap = value.into();
+        self
+    }
+    /// Set the `border_pixel` field of `value_list`.
+    #[must_use]
+    pub fn border_pixel<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().border_pixel = value.into();
+        self
+    }
+    /// Set the `bit_gravity` field of `value_list`.
+    #[must_use]
+    pub fn bit_gravity<I>(mut self, value: I) -> Self where I: Into<Option<xproto::Gravity>> {
+        self.value_list.to_mut().bit_gravity = value.into();
+        self
+    }
+    /// Set the `win_gravity` field of `value_list`.
+    #[must_use]
+    pub fn win_gravity<I>(mut self, value: I) -> Self where I: Into<Option<xproto::Gravity>> {
+        self.value_list.to_mut().win_gravity = value.into();
+        self
+    }
+    /// Set the `backing_store` field of `value_list`.
+    #[must_use]
+    pub fn backing_store<I>(mut self, value: I) -> Self where I: Into<Option<xproto::BackingStore>> {
+        self.value_list.to_mut().backing_store = value.into();
+        self
+    }
+    /// Set the `backing_planes` field of `value_list`.
+    #[must_use]
+    pub fn backing_planes<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().backing_planes = value.into();
+        self
+    }
+    /// Set the `backing_pixel` field of `value_list`.
+    #[must_use]
+    pub fn backing_pixel<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().backing_pixel = value.into();
+        self
+    }
+    /// Set the `override_redirect` field of `value_list`.
+    #[must_use]
+    pub fn override_redirect<I>(mut self, value: I) -> Self where I: Into<Option<xproto::Bool32>> {
+        self.value_list.to_mut().override_redirect = value.into();
+        self
+    }
+    /// Set the `save_under` field of `value_list`.
+    #[must_use]
+    pub fn save_under<I>(mut self, value: I) -> Self where I: Into<Option<xproto::Bool32>> {
+        self.value_list.to_mut().save_under = value.into();
+        self
+    }
+    /// Set the `event_mask` field of `value_list`.
+    #[must_use]
+    pub fn event_mask<I>(mut self, value: I) -> Self where I: Into<Option<xproto::EventMask>> {
+        self.value_list.to_mut().event_mask = value.into();
+        self
+    }
+    /// Set the `do_not_propogate_mask` field of `value_list`.
+    #[must_use]
+    pub fn do_not_propogate_mask<I>(mut self, value: I) -> Self where I: Into<Option<xproto::EventMask>> {
+        self.value_list.to_mut().do_not_propogate_mask = value.into();
+        self
+    }
+    /// Set the `colormap` field of `value_list`.
+    #[must_use]
+    pub fn colormap<I>(mut self, value: I) -> Self where I: Into<Option<xproto::Colormap>> {
+        self.value_list.to_mut().colormap = value.into();
+        self
+    }
+    /// Set the `cursor` field of `value_list`.
+    #[must_use]
+    pub fn cursor<I>(mut self, value: I) -> Self where I: Into<Option<xproto::Cursor>> {
+        self.value_list.to_mut().cursor = value.into();
+        self
+    }
+}
 
 /// Opcode for the UnsetAttributes request
 pub const UNSET_ATTRIBUTES_REQUEST: u8 = 4;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct UnsetAttributesRequest {
     pub drawable: xproto::Drawable,
 }
@@ -1125,6 +1507,33 @@ impl Request for UnsetAttributesRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            UNSET_ATTRIBUTES_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for UnsetAttributesRequest {
 }
@@ -1134,6 +1543,7 @@ pub const SUSPEND_REQUEST: u8 = 5;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SuspendRequest {
     pub suspend: u32,
 }
@@ -1181,6 +1591,33 @@ impl Request for SuspendRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let suspend_bytes = self.suspend.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SUSPEND_REQUEST,
+            0,
+            0,
+            suspend_bytes[0],
+            suspend_bytes[1],
+            suspend_bytes[2],
+            suspend_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SuspendRequest {
 }
@@ -1201,6 +1638,12 @@ pub struct NotifyEvent {
     pub forced: bool,
 }
 impl_debug_if_no_extra_traits!(NotifyEvent, "NotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for NotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for NotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1333,3 +1776,13 @@ impl From<NotifyEvent> for [u8; 32] {
     }
 }
 
+/// All requests of this module.
+pub const REQUESTS: &[RequestDescription] = &[
+    RequestDescription { opcode: QUERY_VERSION_REQUEST, name: "QueryVersion", has_reply: true },
+    RequestDescription { opcode: QUERY_INFO_REQUEST, name: "QueryInfo", has_reply: true },
+    RequestDescription { opcode: SELECT_INPUT_REQUEST, name: "SelectInput", has_reply: false },
+    RequestDescription { opcode: SET_ATTRIBUTES_REQUEST, name: "SetAttributes", has_reply: false },
+    RequestDescription { opcode: UNSET_ATTRIBUTES_REQUEST, name: "UnsetAttributes", has_reply: false },
+    RequestDescription { opcode: SUSPEND_REQUEST, name: "Suspend", has_reply: false },
+];
+
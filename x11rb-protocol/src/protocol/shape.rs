@@ -22,6 +22,7 @@ use crate::BufWithFds;
 use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::x11_utils::RequestDescription;
 #[allow(unused_imports)]
 use super::xproto;
 
@@ -42,6 +43,7 @@ pub type Kind = u8;
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SO(u8);
 impl SO {
     pub const SET: Self = Self(0);
@@ -104,9 +106,28 @@ impl core::fmt::Debug for SO  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl SO {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Set"),
+            1 => Some("Union"),
+            2 => Some("Intersect"),
+            3 => Some("Subtract"),
+            4 => Some("Invert"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for SO {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SK(u8);
 impl SK {
     pub const BOUNDING: Self = Self(0);
@@ -165,6 +186,22 @@ impl core::fmt::Debug for SK  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl SK {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Bounding"),
+            1 => Some("Clip"),
+            2 => Some("Input"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for SK {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the Notify event
 pub const NOTIFY_EVENT: u8 = 0;
@@ -184,6 +221,12 @@ pub struct NotifyEvent {
     pub shaped: bool,
 }
 impl_debug_if_no_extra_traits!(NotifyEvent, "NotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for NotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for NotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -328,6 +371,7 @@ pub const QUERY_VERSION_REQUEST: u8 = 0;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryVersionRequest;
 impl_debug_if_no_extra_traits!(QueryVersionRequest, "QueryVersionRequest");
 impl QueryVersionRequest {
@@ -366,6 +410,28 @@ impl Request for QueryVersionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryVersionRequest {
     type Reply = QueryVersionReply;
@@ -381,6 +447,12 @@ pub struct QueryVersionReply {
     pub minor_version: u16,
 }
 impl_debug_if_no_extra_traits!(QueryVersionReply, "QueryVersionReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryVersionReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -440,6 +512,7 @@ pub const RECTANGLES_REQUEST: u8 = 1;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct RectanglesRequest<'input> {
     pub operation: SO,
     pub destination_kind: SK,
@@ -506,7 +579,7 @@ impl<'input> RectanglesRequest<'input> {
         let (y_offset, remaining) = i16::try_parse(remaining)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut rectangles = Vec::new();
+        let mut rectangles = Vec::with_capacity(remaining.len() / 8);
         while !remaining.is_empty() {
             let (v, new_remaining) = xproto::Rectangle::try_parse(remaining)?;
             remaining = new_remaining;
@@ -545,6 +618,49 @@ impl<'input> Request for RectanglesRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let operation_bytes = Op::from(self.operation).serialize();
+        let destination_kind_bytes = Kind::from(self.destination_kind).serialize();
+        let ordering_bytes = u8::from(self.ordering).serialize();
+        let destination_window_bytes = self.destination_window.serialize();
+        let x_offset_bytes = self.x_offset.serialize();
+        let y_offset_bytes = self.y_offset.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            RECTANGLES_REQUEST,
+            0,
+            0,
+            operation_bytes[0],
+            destination_kind_bytes[0],
+            ordering_bytes[0],
+            0,
+            destination_window_bytes[0],
+            destination_window_bytes[1],
+            destination_window_bytes[2],
+            destination_window_bytes[3],
+            x_offset_bytes[0],
+            x_offset_bytes[1],
+            y_offset_bytes[0],
+            y_offset_bytes[1],
+        ]);
+        self.rectangles.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for RectanglesRequest<'input> {
 }
@@ -554,6 +670,7 @@ pub const MASK_REQUEST: u8 = 2;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct MaskRequest {
     pub operation: SO,
     pub destination_kind: SK,
@@ -636,6 +753,50 @@ impl Request for MaskRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let operation_bytes = Op::from(self.operation).serialize();
+        let destination_kind_bytes = Kind::from(self.destination_kind).serialize();
+        let destination_window_bytes = self.destination_window.serialize();
+        let x_offset_bytes = self.x_offset.serialize();
+        let y_offset_bytes = self.y_offset.serialize();
+        let source_bitmap_bytes = self.source_bitmap.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            MASK_REQUEST,
+            0,
+            0,
+            operation_bytes[0],
+            destination_kind_bytes[0],
+            0,
+            0,
+            destination_window_bytes[0],
+            destination_window_bytes[1],
+            destination_window_bytes[2],
+            destination_window_bytes[3],
+            x_offset_bytes[0],
+            x_offset_bytes[1],
+            y_offset_bytes[0],
+            y_offset_bytes[1],
+            source_bitmap_bytes[0],
+            source_bitmap_bytes[1],
+            source_bitmap_bytes[2],
+            source_bitmap_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for MaskRequest {
 }
@@ -645,6 +806,7 @@ pub const COMBINE_REQUEST: u8 = 3;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CombineRequest {
     pub operation: SO,
     pub destination_kind: SK,
@@ -732,6 +894,51 @@ impl Request for CombineRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let operation_bytes = Op::from(self.operation).serialize();
+        let destination_kind_bytes = Kind::from(self.destination_kind).serialize();
+        let source_kind_bytes = Kind::from(self.source_kind).serialize();
+        let destination_window_bytes = self.destination_window.serialize();
+        let x_offset_bytes = self.x_offset.serialize();
+        let y_offset_bytes = self.y_offset.serialize();
+        let source_window_bytes = self.source_window.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            COMBINE_REQUEST,
+            0,
+            0,
+            operation_bytes[0],
+            destination_kind_bytes[0],
+            source_kind_bytes[0],
+            0,
+            destination_window_bytes[0],
+            destination_window_bytes[1],
+            destination_window_bytes[2],
+            destination_window_bytes[3],
+            x_offset_bytes[0],
+            x_offset_bytes[1],
+            y_offset_bytes[0],
+            y_offset_bytes[1],
+            source_window_bytes[0],
+            source_window_bytes[1],
+            source_window_bytes[2],
+            source_window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for CombineRequest {
 }
@@ -741,6 +948,7 @@ pub const OFFSET_REQUEST: u8 = 4;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct OffsetRequest {
     pub destination_kind: SK,
     pub destination_window: xproto::Window,
@@ -810,6 +1018,44 @@ impl Request for OffsetRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let destination_kind_bytes = Kind::from(self.destination_kind).serialize();
+        let destination_window_bytes = self.destination_window.serialize();
+        let x_offset_bytes = self.x_offset.serialize();
+        let y_offset_bytes = self.y_offset.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            OFFSET_REQUEST,
+            0,
+            0,
+            destination_kind_bytes[0],
+            0,
+            0,
+            0,
+            destination_window_bytes[0],
+            destination_window_bytes[1],
+            destination_window_bytes[2],
+            destination_window_bytes[3],
+            x_offset_bytes[0],
+            x_offset_bytes[1],
+            y_offset_bytes[0],
+            y_offset_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for OffsetRequest {
 }
@@ -819,6 +1065,7 @@ pub const QUERY_EXTENTS_REQUEST: u8 = 5;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryExtentsRequest {
     pub destination_window: xproto::Window,
 }
@@ -866,6 +1113,33 @@ impl Request for QueryExtentsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let destination_window_bytes = self.destination_window.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_EXTENTS_REQUEST,
+            0,
+            0,
+            destination_window_bytes[0],
+            destination_window_bytes[1],
+            destination_window_bytes[2],
+            destination_window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryExtentsRequest {
     type Reply = QueryExtentsReply;
@@ -889,6 +1163,12 @@ pub struct QueryExtentsReply {
     pub clip_shape_extents_height: u16,
 }
 impl_debug_if_no_extra_traits!(QueryExtentsReply, "QueryExtentsReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryExtentsReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryExtentsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -990,6 +1270,7 @@ pub const SELECT_INPUT_REQUEST: u8 = 6;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SelectInputRequest {
     pub destination_window: xproto::Window,
     pub enable: bool,
@@ -1046,6 +1327,38 @@ impl Request for SelectInputRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let destination_window_bytes = self.destination_window.serialize();
+        let enable_bytes = self.enable.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SELECT_INPUT_REQUEST,
+            0,
+            0,
+            destination_window_bytes[0],
+            destination_window_bytes[1],
+            destination_window_bytes[2],
+            destination_window_bytes[3],
+            enable_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SelectInputRequest {
 }
@@ -1055,6 +1368,7 @@ pub const INPUT_SELECTED_REQUEST: u8 = 7;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct InputSelectedRequest {
     pub destination_window: xproto::Window,
 }
@@ -1102,6 +1416,33 @@ impl Request for InputSelectedRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let destination_window_bytes = self.destination_window.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            INPUT_SELECTED_REQUEST,
+            0,
+            0,
+            destination_window_bytes[0],
+            destination_window_bytes[1],
+            destination_window_bytes[2],
+            destination_window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for InputSelectedRequest {
     type Reply = InputSelectedReply;
@@ -1116,6 +1457,12 @@ pub struct InputSelectedReply {
     pub length: u32,
 }
 impl_debug_if_no_extra_traits!(InputSelectedReply, "InputSelectedReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for InputSelectedReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for InputSelectedReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1166,6 +1513,7 @@ pub const GET_RECTANGLES_REQUEST: u8 = 8;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetRectanglesRequest {
     pub window: xproto::Window,
     pub source_kind: SK,
@@ -1223,6 +1571,38 @@ impl Request for GetRectanglesRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let source_kind_bytes = Kind::from(self.source_kind).serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_RECTANGLES_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            source_kind_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetRectanglesRequest {
     type Reply = GetRectanglesReply;
@@ -1238,6 +1618,12 @@ pub struct GetRectanglesReply {
     pub rectangles: Vec<xproto::Rectangle>,
 }
 impl_debug_if_no_extra_traits!(GetRectanglesReply, "GetRectanglesReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetRectanglesReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetRectanglesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1277,6 +1663,7 @@ impl Serialize for GetRectanglesReply {
         rectangles_len.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 20]);
         self.rectangles.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetRectanglesReply {
@@ -1295,3 +1682,16 @@ impl GetRectanglesReply {
     }
 }
 
+/// All requests of this module.
+pub const REQUESTS: &[RequestDescription] = &[
+    RequestDescription { opcode: QUERY_VERSION_REQUEST, name: "QueryVersion", has_reply: true },
+    RequestDescription { opcode: RECTANGLES_REQUEST, name: "Rectangles", has_reply: false },
+    RequestDescription { opcode: MASK_REQUEST, name: "Mask", has_reply: false },
+    RequestDescription { opcode: COMBINE_REQUEST, name: "Combine", has_reply: false },
+    RequestDescription { opcode: OFFSET_REQUEST, name: "Offset", has_reply: false },
+    RequestDescription { opcode: QUERY_EXTENTS_REQUEST, name: "QueryExtents", has_reply: true },
+    RequestDescription { opcode: SELECT_INPUT_REQUEST, name: "SelectInput", has_reply: false },
+    RequestDescription { opcode: INPUT_SELECTED_REQUEST, name: "InputSelected", has_reply: true },
+    RequestDescription { opcode: GET_RECTANGLES_REQUEST, name: "GetRectangles", has_reply: true },
+];
+
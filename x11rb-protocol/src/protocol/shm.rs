@@ -22,6 +22,7 @@ use crate::BufWithFds;
 use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::x11_utils::RequestDescription;
 #[allow(unused_imports)]
 use super::xproto;
 
@@ -59,13 +60,25 @@ pub const COMPLETION_EVENT: u8 = 0;
 pub struct CompletionEvent {
     pub response_type: u8,
     pub sequence: u16,
+    /// The drawable used in the XCB_SHM_PUT_IMAGE request.
     pub drawable: xproto::Drawable,
+    /// The minor opcode used in the request.  Always XCB_SHM_PUT_IMAGE.
     pub minor_event: u16,
+    /// The major opcode used in the request.  Always the opcode of the MIT-SHM
+    /// extension.
     pub major_event: u8,
+    /// The shared memory segment used in the request.
     pub shmseg: Seg,
+    /// The offset in the shared memory segment used in the request.
     pub offset: u32,
 }
 impl_debug_if_no_extra_traits!(CompletionEvent, "CompletionEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CompletionEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for CompletionEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -196,6 +209,7 @@ pub const QUERY_VERSION_REQUEST: u8 = 0;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryVersionRequest;
 impl_debug_if_no_extra_traits!(QueryVersionRequest, "QueryVersionRequest");
 impl QueryVersionRequest {
@@ -234,6 +248,28 @@ impl Request for QueryVersionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryVersionRequest {
     type Reply = QueryVersionReply;
@@ -261,16 +297,27 @@ impl crate::x11_utils::ReplyRequest for QueryVersionRequest {
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct QueryVersionReply {
+    /// True if the server supports shared pixmaps, otherwise false.
     pub shared_pixmaps: bool,
     pub sequence: u16,
     pub length: u32,
+    /// The major version of the extension supported.
     pub major_version: u16,
+    /// The minor version of the extension supported.
     pub minor_version: u16,
+    /// The UID of the server.
     pub uid: u16,
+    /// The GID of the server.
     pub gid: u16,
     pub pixmap_format: u8,
 }
 impl_debug_if_no_extra_traits!(QueryVersionReply, "QueryVersionReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryVersionReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -374,9 +421,13 @@ pub const ATTACH_REQUEST: u8 = 1;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct AttachRequest {
+    /// A shared memory segment ID created with xcb_generate_id().
     pub shmseg: Seg,
+    /// The System V shared memory segment the server should map.
     pub shmid: u32,
+    /// True if the segment shall be mapped read only by the X11 server, otherwise false.
     pub read_only: bool,
 }
 impl_debug_if_no_extra_traits!(AttachRequest, "AttachRequest");
@@ -438,6 +489,43 @@ impl Request for AttachRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let shmseg_bytes = self.shmseg.serialize();
+        let shmid_bytes = self.shmid.serialize();
+        let read_only_bytes = self.read_only.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            ATTACH_REQUEST,
+            0,
+            0,
+            shmseg_bytes[0],
+            shmseg_bytes[1],
+            shmseg_bytes[2],
+            shmseg_bytes[3],
+            shmid_bytes[0],
+            shmid_bytes[1],
+            shmid_bytes[2],
+            shmid_bytes[3],
+            read_only_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for AttachRequest {
 }
@@ -455,7 +543,9 @@ pub const DETACH_REQUEST: u8 = 2;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DetachRequest {
+    /// The segment to be destroyed.
     pub shmseg: Seg,
 }
 impl_debug_if_no_extra_traits!(DetachRequest, "DetachRequest");
@@ -502,6 +592,33 @@ impl Request for DetachRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let shmseg_bytes = self.shmseg.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            DETACH_REQUEST,
+            0,
+            0,
+            shmseg_bytes[0],
+            shmseg_bytes[1],
+            shmseg_bytes[2],
+            shmseg_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for DetachRequest {
 }
@@ -542,21 +659,45 @@ pub const PUT_IMAGE_REQUEST: u8 = 3;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PutImageRequest {
+    /// The drawable to draw to.
     pub drawable: xproto::Drawable,
+    /// The graphics context to use.
     pub gc: xproto::Gcontext,
+    /// The total width of the source image.
     pub total_width: u16,
+    /// The total height of the source image.
     pub total_height: u16,
+    /// The source X coordinate of the sub-image to copy.
     pub src_x: u16,
+    /// The source Y coordinate of the sub-image to copy.
     pub src_y: u16,
+    /// The width, in source image coordinates, of the data to copy from the source.
+    /// The X server will use this to determine the amount of data to copy.  The amount
+    /// of the destination image that is overwritten is determined automatically.
     pub src_width: u16,
+    /// The height, in source image coordinates, of the data to copy from the source.
+    /// The X server will use this to determine the amount of data to copy.  The amount
+    /// of the destination image that is overwritten is determined automatically.
     pub src_height: u16,
+    /// The X coordinate on the destination drawable to copy to.
     pub dst_x: i16,
+    /// The Y coordinate on the destination drawable to copy to.
     pub dst_y: i16,
+    /// The depth to use.
     pub depth: u8,
+    /// The format of the image being drawn.  If it is XYBitmap, depth must be 1, or a
+    /// "BadMatch" error results.  The foreground pixel in the GC determines the source
+    /// for the one bits in the image, and the background pixel determines the source
+    /// for the zero bits.  For XYPixmap and ZPixmap, the depth must match the depth of
+    /// the drawable, or a "BadMatch" error results.
     pub format: u8,
+    /// True if the server should send an XCB_SHM_COMPLETION event when the blit
+    /// completes.
     pub send_event: bool,
     pub shmseg: Seg,
+    /// The offset that the source image starts at.
     pub offset: u32,
 }
 impl_debug_if_no_extra_traits!(PutImageRequest, "PutImageRequest");
@@ -678,6 +819,79 @@ impl Request for PutImageRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        let total_width_bytes = self.total_width.serialize();
+        let total_height_bytes = self.total_height.serialize();
+        let src_x_bytes = self.src_x.serialize();
+        let src_y_bytes = self.src_y.serialize();
+        let src_width_bytes = self.src_width.serialize();
+        let src_height_bytes = self.src_height.serialize();
+        let dst_x_bytes = self.dst_x.serialize();
+        let dst_y_bytes = self.dst_y.serialize();
+        let depth_bytes = self.depth.serialize();
+        let format_bytes = self.format.serialize();
+        let send_event_bytes = self.send_event.serialize();
+        let shmseg_bytes = self.shmseg.serialize();
+        let offset_bytes = self.offset.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            PUT_IMAGE_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            total_width_bytes[0],
+            total_width_bytes[1],
+            total_height_bytes[0],
+            total_height_bytes[1],
+            src_x_bytes[0],
+            src_x_bytes[1],
+            src_y_bytes[0],
+            src_y_bytes[1],
+            src_width_bytes[0],
+            src_width_bytes[1],
+            src_height_bytes[0],
+            src_height_bytes[1],
+            dst_x_bytes[0],
+            dst_x_bytes[1],
+            dst_y_bytes[0],
+            dst_y_bytes[1],
+            depth_bytes[0],
+            format_bytes[0],
+            send_event_bytes[0],
+            0,
+            shmseg_bytes[0],
+            shmseg_bytes[1],
+            shmseg_bytes[2],
+            shmseg_bytes[3],
+            offset_bytes[0],
+            offset_bytes[1],
+            offset_bytes[2],
+            offset_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for PutImageRequest {
 }
@@ -704,15 +918,25 @@ pub const GET_IMAGE_REQUEST: u8 = 4;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetImageRequest {
+    /// The drawable to copy the image out of.
     pub drawable: xproto::Drawable,
+    /// The X coordinate in the drawable to begin copying at.
     pub x: i16,
+    /// The Y coordinate in the drawable to begin copying at.
     pub y: i16,
+    /// The width of the image to copy.
     pub width: u16,
+    /// The height of the image to copy.
     pub height: u16,
+    /// A mask that determines which planes are used.
     pub plane_mask: u32,
+    /// The format to use for the copy (???).
     pub format: u8,
+    /// The destination shared memory segment.
     pub shmseg: Seg,
+    /// The offset in the shared memory segment to copy data to.
     pub offset: u32,
 }
 impl_debug_if_no_extra_traits!(GetImageRequest, "GetImageRequest");
@@ -808,6 +1032,65 @@ impl Request for GetImageRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        let x_bytes = self.x.serialize();
+        let y_bytes = self.y.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        let plane_mask_bytes = self.plane_mask.serialize();
+        let format_bytes = self.format.serialize();
+        let shmseg_bytes = self.shmseg.serialize();
+        let offset_bytes = self.offset.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_IMAGE_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            x_bytes[0],
+            x_bytes[1],
+            y_bytes[0],
+            y_bytes[1],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+            plane_mask_bytes[0],
+            plane_mask_bytes[1],
+            plane_mask_bytes[2],
+            plane_mask_bytes[3],
+            format_bytes[0],
+            0,
+            0,
+            0,
+            shmseg_bytes[0],
+            shmseg_bytes[1],
+            shmseg_bytes[2],
+            shmseg_bytes[3],
+            offset_bytes[0],
+            offset_bytes[1],
+            offset_bytes[2],
+            offset_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetImageRequest {
     type Reply = GetImageReply;
@@ -826,13 +1109,22 @@ impl crate::x11_utils::ReplyRequest for GetImageRequest {
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetImageReply {
+    /// The depth of the source drawable.
     pub depth: u8,
     pub sequence: u16,
     pub length: u32,
+    /// The visual ID of the source drawable.
     pub visual: xproto::Visualid,
+    /// The number of bytes copied.
     pub size: u32,
 }
 impl_debug_if_no_extra_traits!(GetImageReply, "GetImageReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetImageReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetImageReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -912,13 +1204,21 @@ pub const CREATE_PIXMAP_REQUEST: u8 = 5;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreatePixmapRequest {
+    /// A pixmap ID created with xcb_generate_id().
     pub pid: xproto::Pixmap,
+    /// The drawable to create the pixmap in.
     pub drawable: xproto::Drawable,
+    /// The width of the pixmap to create.  Must be nonzero, or a Value error results.
     pub width: u16,
+    /// The height of the pixmap to create.  Must be nonzero, or a Value error results.
     pub height: u16,
+    /// The depth of the pixmap to create.  Must be nonzero, or a Value error results.
     pub depth: u8,
+    /// The shared memory segment to use to create the pixmap.
     pub shmseg: Seg,
+    /// The offset in the segment to create the pixmap at.
     pub offset: u32,
 }
 impl_debug_if_no_extra_traits!(CreatePixmapRequest, "CreatePixmapRequest");
@@ -1004,6 +1304,59 @@ impl Request for CreatePixmapRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let pid_bytes = self.pid.serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        let depth_bytes = self.depth.serialize();
+        let shmseg_bytes = self.shmseg.serialize();
+        let offset_bytes = self.offset.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CREATE_PIXMAP_REQUEST,
+            0,
+            0,
+            pid_bytes[0],
+            pid_bytes[1],
+            pid_bytes[2],
+            pid_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+            depth_bytes[0],
+            0,
+            0,
+            0,
+            shmseg_bytes[0],
+            shmseg_bytes[1],
+            shmseg_bytes[2],
+            shmseg_bytes[3],
+            offset_bytes[0],
+            offset_bytes[1],
+            offset_bytes[2],
+            offset_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for CreatePixmapRequest {
 }
@@ -1023,8 +1376,11 @@ pub const ATTACH_FD_REQUEST: u8 = 6;
 /// * `read_only` - True if the segment shall be mapped read only by the X11 server, otherwise false.
 #[cfg_attr(feature = "extra-traits", derive(Debug))]
 pub struct AttachFdRequest {
+    /// A shared memory segment ID created with xcb_generate_id().
     pub shmseg: Seg,
+    /// The file descriptor the server should mmap().
     pub shm_fd: RawFdContainer,
+    /// True if the segment shall be mapped read only by the X11 server, otherwise false.
     pub read_only: bool,
 }
 impl_debug_if_no_extra_traits!(AttachFdRequest, "AttachFdRequest");
@@ -1082,6 +1438,38 @@ impl Request for AttachFdRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let shmseg_bytes = self.shmseg.serialize();
+        let read_only_bytes = self.read_only.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            ATTACH_FD_REQUEST,
+            0,
+            0,
+            shmseg_bytes[0],
+            shmseg_bytes[1],
+            shmseg_bytes[2],
+            shmseg_bytes[3],
+            read_only_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![self.shm_fd]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for AttachFdRequest {
 }
@@ -1101,9 +1489,13 @@ pub const CREATE_SEGMENT_REQUEST: u8 = 7;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateSegmentRequest {
+    /// A shared memory segment ID created with xcb_generate_id().
     pub shmseg: Seg,
+    /// The size of the segment to create.
     pub size: u32,
+    /// True if the server should map the segment read-only; otherwise false.
     pub read_only: bool,
 }
 impl_debug_if_no_extra_traits!(CreateSegmentRequest, "CreateSegmentRequest");
@@ -1165,6 +1557,43 @@ impl Request for CreateSegmentRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let shmseg_bytes = self.shmseg.serialize();
+        let size_bytes = self.size.serialize();
+        let read_only_bytes = self.read_only.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CREATE_SEGMENT_REQUEST,
+            0,
+            0,
+            shmseg_bytes[0],
+            shmseg_bytes[1],
+            shmseg_bytes[2],
+            shmseg_bytes[3],
+            size_bytes[0],
+            size_bytes[1],
+            size_bytes[2],
+            size_bytes[3],
+            read_only_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyFDsRequest for CreateSegmentRequest {
     type Reply = CreateSegmentReply;
@@ -1180,6 +1609,7 @@ impl crate::x11_utils::ReplyFDsRequest for CreateSegmentRequest {
 /// * `nfd` - The number of file descriptors sent by the server.  Will always be 1.
 #[cfg_attr(feature = "extra-traits", derive(Debug))]
 pub struct CreateSegmentReply {
+    /// The number of file descriptors sent by the server.  Will always be 1.
     pub nfd: u8,
     pub sequence: u16,
     pub length: u32,
@@ -1259,3 +1689,15 @@ impl Serialize for CreateSegmentReply {
     }
 }
 
+/// All requests of this module.
+pub const REQUESTS: &[RequestDescription] = &[
+    RequestDescription { opcode: QUERY_VERSION_REQUEST, name: "QueryVersion", has_reply: true },
+    RequestDescription { opcode: ATTACH_REQUEST, name: "Attach", has_reply: false },
+    RequestDescription { opcode: DETACH_REQUEST, name: "Detach", has_reply: false },
+    RequestDescription { opcode: PUT_IMAGE_REQUEST, name: "PutImage", has_reply: false },
+    RequestDescription { opcode: GET_IMAGE_REQUEST, name: "GetImage", has_reply: true },
+    RequestDescription { opcode: CREATE_PIXMAP_REQUEST, name: "CreatePixmap", has_reply: false },
+    RequestDescription { opcode: ATTACH_FD_REQUEST, name: "AttachFd", has_reply: false },
+    RequestDescription { opcode: CREATE_SEGMENT_REQUEST, name: "CreateSegment", has_reply: true },
+];
+
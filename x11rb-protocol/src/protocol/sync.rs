@@ -22,6 +22,7 @@ use crate::BufWithFds;
 use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::x11_utils::RequestDescription;
 #[allow(unused_imports)]
 use super::xproto;
 
@@ -40,6 +41,7 @@ pub type Alarm = u32;
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ALARMSTATE(u8);
 impl ALARMSTATE {
     pub const ACTIVE: Self = Self(0);
@@ -98,6 +100,22 @@ impl core::fmt::Debug for ALARMSTATE  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl ALARMSTATE {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Active"),
+            1 => Some("Inactive"),
+            2 => Some("Destroyed"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ALARMSTATE {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 pub type Counter = u32;
 
@@ -105,6 +123,7 @@ pub type Fence = u32;
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct TESTTYPE(u32);
 impl TESTTYPE {
     pub const POSITIVE_TRANSITION: Self = Self(0);
@@ -153,9 +172,27 @@ impl core::fmt::Debug for TESTTYPE  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+impl TESTTYPE {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("PositiveTransition"),
+            1 => Some("NegativeTransition"),
+            2 => Some("PositiveComparison"),
+            3 => Some("NegativeComparison"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for TESTTYPE {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct VALUETYPE(u32);
 impl VALUETYPE {
     pub const ABSOLUTE: Self = Self(0);
@@ -200,9 +237,25 @@ impl core::fmt::Debug for VALUETYPE  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+impl VALUETYPE {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Absolute"),
+            1 => Some("Relative"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for VALUETYPE {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CA(u32);
 impl CA {
     pub const COUNTER: Self = Self(1 << 0);
@@ -255,6 +308,25 @@ impl core::fmt::Debug for CA  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl CA {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("Counter"),
+            2 => Some("ValueType"),
+            4 => Some("Value"),
+            8 => Some("TestType"),
+            16 => Some("Delta"),
+            32 => Some("Events"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for CA {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(CA, u32);
 
 #[derive(Clone, Copy, Default)]
@@ -265,6 +337,12 @@ pub struct Int64 {
     pub lo: u32,
 }
 impl_debug_if_no_extra_traits!(Int64, "Int64");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Int64 {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Int64 {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (hi, remaining) = i32::try_parse(remaining)?;
@@ -305,6 +383,12 @@ pub struct Systemcounter {
     pub name: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(Systemcounter, "Systemcounter");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Systemcounter {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Systemcounter {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
@@ -364,6 +448,12 @@ pub struct Trigger {
     pub test_type: TESTTYPE,
 }
 impl_debug_if_no_extra_traits!(Trigger, "Trigger");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Trigger {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Trigger {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (counter, remaining) = Counter::try_parse(remaining)?;
@@ -423,6 +513,12 @@ pub struct Waitcondition {
     pub event_threshold: Int64,
 }
 impl_debug_if_no_extra_traits!(Waitcondition, "Waitcondition");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Waitcondition {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Waitcondition {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (trigger, remaining) = Trigger::try_parse(remaining)?;
@@ -485,6 +581,7 @@ pub const INITIALIZE_REQUEST: u8 = 0;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct InitializeRequest {
     pub desired_major_version: u8,
     pub desired_minor_version: u8,
@@ -536,6 +633,34 @@ impl Request for InitializeRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let desired_major_version_bytes = self.desired_major_version.serialize();
+        let desired_minor_version_bytes = self.desired_minor_version.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            INITIALIZE_REQUEST,
+            0,
+            0,
+            desired_major_version_bytes[0],
+            desired_minor_version_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for InitializeRequest {
     type Reply = InitializeReply;
@@ -551,6 +676,12 @@ pub struct InitializeReply {
     pub minor_version: u8,
 }
 impl_debug_if_no_extra_traits!(InitializeReply, "InitializeReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for InitializeReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for InitializeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -632,6 +763,7 @@ pub const LIST_SYSTEM_COUNTERS_REQUEST: u8 = 1;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ListSystemCountersRequest;
 impl_debug_if_no_extra_traits!(ListSystemCountersRequest, "ListSystemCountersRequest");
 impl ListSystemCountersRequest {
@@ -670,6 +802,28 @@ impl Request for ListSystemCountersRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            LIST_SYSTEM_COUNTERS_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for ListSystemCountersRequest {
     type Reply = ListSystemCountersReply;
@@ -684,6 +838,12 @@ pub struct ListSystemCountersReply {
     pub counters: Vec<Systemcounter>,
 }
 impl_debug_if_no_extra_traits!(ListSystemCountersReply, "ListSystemCountersReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ListSystemCountersReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ListSystemCountersReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -722,6 +882,7 @@ impl Serialize for ListSystemCountersReply {
         counters_len.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 20]);
         self.counters.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl ListSystemCountersReply {
@@ -745,6 +906,7 @@ pub const CREATE_COUNTER_REQUEST: u8 = 2;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateCounterRequest {
     pub id: Counter,
     pub initial_value: Int64,
@@ -804,6 +966,42 @@ impl Request for CreateCounterRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let id_bytes = self.id.serialize();
+        let initial_value_bytes = self.initial_value.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CREATE_COUNTER_REQUEST,
+            0,
+            0,
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3],
+            initial_value_bytes[0],
+            initial_value_bytes[1],
+            initial_value_bytes[2],
+            initial_value_bytes[3],
+            initial_value_bytes[4],
+            initial_value_bytes[5],
+            initial_value_bytes[6],
+            initial_value_bytes[7],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for CreateCounterRequest {
 }
@@ -813,6 +1011,7 @@ pub const DESTROY_COUNTER_REQUEST: u8 = 6;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DestroyCounterRequest {
     pub counter: Counter,
 }
@@ -860,6 +1059,33 @@ impl Request for DestroyCounterRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let counter_bytes = self.counter.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            DESTROY_COUNTER_REQUEST,
+            0,
+            0,
+            counter_bytes[0],
+            counter_bytes[1],
+            counter_bytes[2],
+            counter_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for DestroyCounterRequest {
 }
@@ -869,6 +1095,7 @@ pub const QUERY_COUNTER_REQUEST: u8 = 5;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryCounterRequest {
     pub counter: Counter,
 }
@@ -916,6 +1143,33 @@ impl Request for QueryCounterRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let counter_bytes = self.counter.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_COUNTER_REQUEST,
+            0,
+            0,
+            counter_bytes[0],
+            counter_bytes[1],
+            counter_bytes[2],
+            counter_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryCounterRequest {
     type Reply = QueryCounterReply;
@@ -930,6 +1184,12 @@ pub struct QueryCounterReply {
     pub counter_value: Int64,
 }
 impl_debug_if_no_extra_traits!(QueryCounterReply, "QueryCounterReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryCounterReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryCounterReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -990,6 +1250,7 @@ pub const AWAIT_REQUEST: u8 = 7;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct AwaitRequest<'input> {
     pub wait_list: Cow<'input, [Waitcondition]>,
 }
@@ -1022,7 +1283,7 @@ impl<'input> AwaitRequest<'input> {
         }
         let mut remaining = value;
         // Length is 'everything left in the input'
-        let mut wait_list = Vec::new();
+        let mut wait_list = Vec::with_capacity(remaining.len() / 28);
         while !remaining.is_empty() {
             let (v, new_remaining) = Waitcondition::try_parse(value)?;
             remaining = new_remaining;
@@ -1049,6 +1310,31 @@ impl<'input> Request for AwaitRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            AWAIT_REQUEST,
+            0,
+            0,
+        ]);
+        self.wait_list.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for AwaitRequest<'input> {
 }
@@ -1058,6 +1344,7 @@ pub const CHANGE_COUNTER_REQUEST: u8 = 4;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangeCounterRequest {
     pub counter: Counter,
     pub amount: Int64,
@@ -1117,6 +1404,42 @@ impl Request for ChangeCounterRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let counter_bytes = self.counter.serialize();
+        let amount_bytes = self.amount.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CHANGE_COUNTER_REQUEST,
+            0,
+            0,
+            counter_bytes[0],
+            counter_bytes[1],
+            counter_bytes[2],
+            counter_bytes[3],
+            amount_bytes[0],
+            amount_bytes[1],
+            amount_bytes[2],
+            amount_bytes[3],
+            amount_bytes[4],
+            amount_bytes[5],
+            amount_bytes[6],
+            amount_bytes[7],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for ChangeCounterRequest {
 }
@@ -1126,6 +1449,7 @@ pub const SET_COUNTER_REQUEST: u8 = 3;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetCounterRequest {
     pub counter: Counter,
     pub value: Int64,
@@ -1185,6 +1509,42 @@ impl Request for SetCounterRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let counter_bytes = self.counter.serialize();
+        let value_bytes = self.value.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_COUNTER_REQUEST,
+            0,
+            0,
+            counter_bytes[0],
+            counter_bytes[1],
+            counter_bytes[2],
+            counter_bytes[3],
+            value_bytes[0],
+            value_bytes[1],
+            value_bytes[2],
+            value_bytes[3],
+            value_bytes[4],
+            value_bytes[5],
+            value_bytes[6],
+            value_bytes[7],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SetCounterRequest {
 }
@@ -1193,6 +1553,7 @@ impl crate::x11_utils::VoidRequest for SetCounterRequest {
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateAlarmAux {
     pub counter: Option<Counter>,
     pub value_type: Option<VALUETYPE>,
@@ -1362,6 +1723,7 @@ pub const CREATE_ALARM_REQUEST: u8 = 8;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateAlarmRequest<'input> {
     pub id: Alarm,
     pub value_list: Cow<'input, CreateAlarmAux>,
@@ -1430,14 +1792,100 @@ impl<'input> Request for CreateAlarmRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let id_bytes = self.id.serialize();
+        let value_mask: u32 = self.value_list.switch_expr();
+        let value_mask_bytes = value_mask.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CREATE_ALARM_REQUEST,
+            0,
+            0,
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3],
+            value_mask_bytes[0],
+            value_mask_bytes[1],
+            value_mask_bytes[2],
+            value_mask_bytes[3],
+        ]);
+        self.value_list.serialize_into(buffer, u32::from(value_mask));
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for CreateAlarmRequest<'input> {
 }
+impl CreateAlarmRequest<'static> {
+    /// Create a new request with all fields of `value_list` unset.
+    ///
+    /// The optional fields can then be set with the builder methods of this request.
+    pub fn new(id: Alarm) -> Self {
+        CreateAlarmRequest {
+            id,
+            value_list: Cow::Owned(CreateAlarmAux::new()),
+        }
+    }
+}
+impl<'input> CreateAlarmRequest<'input> {
+    /// Set the `counter` field of `value_list`.
+    #[must_use]
+    pub fn counter<I>(mut self, value: I) -> Self where I: Into<Option<Counter>> {
+        self.value_list.to_mut().counter = value.into();
+        self
+    }
+    /// Set the `value_type` field of `value_list`.
+    #[must_use]
+    pub fn value_type<I>(mut self, value: I) -> Self where I: Into<Option<VALUETYPE>> {
+        self.value_list.to_mut().value_type = value.into();
+        self
+    }
+    /// Set the `value` field of `value_list`.
+    #[must_use]
+    pub fn value<I>(mut self, value: I) -> Self where I: Into<Option<Int64>> {
+        self.value_list.to_mut().value = value.into();
+        self
+    }
+    /// Set the `test_type` field of `value_list`.
+    #[must_use]
+    pub fn test_type<I>(mut self, value: I) -> Self where I: Into<Option<TESTTYPE>> {
+        self.value_list.to_mut().test_type = value.into();
+        self
+    }
+    /// Set the `delta` field of `value_list`.
+    #[must_use]
+    pub fn delta<I>(mut self, value: I) -> Self where I: Into<Option<Int64>> {
+        self.value_list.to_mut().delta = value.into();
+        self
+    }
+    /// Set the `events` field of `value_list`.
+    #[must_use]
+    pub fn events<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().events = value.into();
+        self
+    }
+}
 
 /// Auxiliary and optional information for the `change_alarm` function
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangeAlarmAux {
     pub counter: Option<Counter>,
     pub value_type: Option<VALUETYPE>,
@@ -1607,6 +2055,7 @@ pub const CHANGE_ALARM_REQUEST: u8 = 9;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangeAlarmRequest<'input> {
     pub id: Alarm,
     pub value_list: Cow<'input, ChangeAlarmAux>,
@@ -1675,15 +2124,101 @@ impl<'input> Request for ChangeAlarmRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let id_bytes = self.id.serialize();
+        let value_mask: u32 = self.value_list.switch_expr();
+        let value_mask_bytes = value_mask.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CHANGE_ALARM_REQUEST,
+            0,
+            0,
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3],
+            value_mask_bytes[0],
+            value_mask_bytes[1],
+            value_mask_bytes[2],
+            value_mask_bytes[3],
+        ]);
+        self.value_list.serialize_into(buffer, u32::from(value_mask));
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for ChangeAlarmRequest<'input> {
 }
+impl ChangeAlarmRequest<'static> {
+    /// Create a new request with all fields of `value_list` unset.
+    ///
+    /// The optional fields can then be set with the builder methods of this request.
+    pub fn new(id: Alarm) -> Self {
+        ChangeAlarmRequest {
+            id,
+            value_list: Cow::Owned(ChangeAlarmAux::new()),
+        }
+    }
+}
+impl<'input> ChangeAlarmRequest<'input> {
+    /// Set the `counter` field of `value_list`.
+    #[must_use]
+    pub fn counter<I>(mut self, value: I) -> Self where I: Into<Option<Counter>> {
+        self.value_list.to_mut().counter = value.into();
+        self
+    }
+    /// Set the `value_type` field of `value_list`.
+    #[must_use]
+    pub fn value_type<I>(mut self, value: I) -> Self where I: Into<Option<VALUETYPE>> {
+        self.value_list.to_mut().value_type = value.into();
+        self
+    }
+    /// Set the `value` field of `value_list`.
+    #[must_use]
+    pub fn value<I>(mut self, value: I) -> Self where I: Into<Option<Int64>> {
+        self.value_list.to_mut().value = value.into();
+        self
+    }
+    /// Set the `test_type` field of `value_list`.
+    #[must_use]
+    pub fn test_type<I>(mut self, value: I) -> Self where I: Into<Option<TESTTYPE>> {
+        self.value_list.to_mut().test_type = value.into();
+        self
+    }
+    /// Set the `delta` field of `value_list`.
+    #[must_use]
+    pub fn delta<I>(mut self, value: I) -> Self where I: Into<Option<Int64>> {
+        self.value_list.to_mut().delta = value.into();
+        self
+    }
+    /// Set the `events` field of `value_list`.
+    #[must_use]
+    pub fn events<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().events = value.into();
+        self
+    }
+}
 
 /// Opcode for the DestroyAlarm request
 pub const DESTROY_ALARM_REQUEST: u8 = 11;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DestroyAlarmRequest {
     pub alarm: Alarm,
 }
@@ -1731,6 +2266,33 @@ impl Request for DestroyAlarmRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let alarm_bytes = self.alarm.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            DESTROY_ALARM_REQUEST,
+            0,
+            0,
+            alarm_bytes[0],
+            alarm_bytes[1],
+            alarm_bytes[2],
+            alarm_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for DestroyAlarmRequest {
 }
@@ -1740,6 +2302,7 @@ pub const QUERY_ALARM_REQUEST: u8 = 10;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryAlarmRequest {
     pub alarm: Alarm,
 }
@@ -1787,6 +2350,33 @@ impl Request for QueryAlarmRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let alarm_bytes = self.alarm.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_ALARM_REQUEST,
+            0,
+            0,
+            alarm_bytes[0],
+            alarm_bytes[1],
+            alarm_bytes[2],
+            alarm_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryAlarmRequest {
     type Reply = QueryAlarmReply;
@@ -1804,6 +2394,12 @@ pub struct QueryAlarmReply {
     pub state: ALARMSTATE,
 }
 impl_debug_if_no_extra_traits!(QueryAlarmReply, "QueryAlarmReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryAlarmReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryAlarmReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1900,6 +2496,7 @@ pub const SET_PRIORITY_REQUEST: u8 = 12;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetPriorityRequest {
     pub id: u32,
     pub priority: i32,
@@ -1955,6 +2552,38 @@ impl Request for SetPriorityRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let id_bytes = self.id.serialize();
+        let priority_bytes = self.priority.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_PRIORITY_REQUEST,
+            0,
+            0,
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3],
+            priority_bytes[0],
+            priority_bytes[1],
+            priority_bytes[2],
+            priority_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SetPriorityRequest {
 }
@@ -1964,6 +2593,7 @@ pub const GET_PRIORITY_REQUEST: u8 = 13;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetPriorityRequest {
     pub id: u32,
 }
@@ -2011,6 +2641,33 @@ impl Request for GetPriorityRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let id_bytes = self.id.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_PRIORITY_REQUEST,
+            0,
+            0,
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetPriorityRequest {
     type Reply = GetPriorityReply;
@@ -2025,6 +2682,12 @@ pub struct GetPriorityReply {
     pub priority: i32,
 }
 impl_debug_if_no_extra_traits!(GetPriorityReply, "GetPriorityReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetPriorityReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetPriorityReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2081,6 +2744,7 @@ pub const CREATE_FENCE_REQUEST: u8 = 14;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateFenceRequest {
     pub drawable: xproto::Drawable,
     pub fence: Fence,
@@ -2144,6 +2808,43 @@ impl Request for CreateFenceRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        let fence_bytes = self.fence.serialize();
+        let initially_triggered_bytes = self.initially_triggered.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CREATE_FENCE_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            fence_bytes[0],
+            fence_bytes[1],
+            fence_bytes[2],
+            fence_bytes[3],
+            initially_triggered_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for CreateFenceRequest {
 }
@@ -2153,6 +2854,7 @@ pub const TRIGGER_FENCE_REQUEST: u8 = 15;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct TriggerFenceRequest {
     pub fence: Fence,
 }
@@ -2200,6 +2902,33 @@ impl Request for TriggerFenceRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let fence_bytes = self.fence.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            TRIGGER_FENCE_REQUEST,
+            0,
+            0,
+            fence_bytes[0],
+            fence_bytes[1],
+            fence_bytes[2],
+            fence_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for TriggerFenceRequest {
 }
@@ -2209,6 +2938,7 @@ pub const RESET_FENCE_REQUEST: u8 = 16;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ResetFenceRequest {
     pub fence: Fence,
 }
@@ -2256,6 +2986,33 @@ impl Request for ResetFenceRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let fence_bytes = self.fence.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            RESET_FENCE_REQUEST,
+            0,
+            0,
+            fence_bytes[0],
+            fence_bytes[1],
+            fence_bytes[2],
+            fence_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for ResetFenceRequest {
 }
@@ -2265,6 +3022,7 @@ pub const DESTROY_FENCE_REQUEST: u8 = 17;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DestroyFenceRequest {
     pub fence: Fence,
 }
@@ -2312,6 +3070,33 @@ impl Request for DestroyFenceRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let fence_bytes = self.fence.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            DESTROY_FENCE_REQUEST,
+            0,
+            0,
+            fence_bytes[0],
+            fence_bytes[1],
+            fence_bytes[2],
+            fence_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for DestroyFenceRequest {
 }
@@ -2321,6 +3106,7 @@ pub const QUERY_FENCE_REQUEST: u8 = 18;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryFenceRequest {
     pub fence: Fence,
 }
@@ -2368,6 +3154,33 @@ impl Request for QueryFenceRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let fence_bytes = self.fence.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_FENCE_REQUEST,
+            0,
+            0,
+            fence_bytes[0],
+            fence_bytes[1],
+            fence_bytes[2],
+            fence_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryFenceRequest {
     type Reply = QueryFenceReply;
@@ -2382,6 +3195,12 @@ pub struct QueryFenceReply {
     pub triggered: bool,
 }
 impl_debug_if_no_extra_traits!(QueryFenceReply, "QueryFenceReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryFenceReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryFenceReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2460,6 +3279,7 @@ pub const AWAIT_FENCE_REQUEST: u8 = 19;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct AwaitFenceRequest<'input> {
     pub fence_list: Cow<'input, [Fence]>,
 }
@@ -2492,7 +3312,7 @@ impl<'input> AwaitFenceRequest<'input> {
         }
         let mut remaining = value;
         // Length is 'everything left in the input'
-        let mut fence_list = Vec::new();
+        let mut fence_list = Vec::with_capacity(remaining.len() / 4);
         while !remaining.is_empty() {
             let (v, new_remaining) = Fence::try_parse(value)?;
             remaining = new_remaining;
@@ -2519,6 +3339,31 @@ impl<'input> Request for AwaitFenceRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            AWAIT_FENCE_REQUEST,
+            0,
+            0,
+        ]);
+        self.fence_list.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for AwaitFenceRequest<'input> {
 }
@@ -2540,6 +3385,12 @@ pub struct CounterNotifyEvent {
     pub destroyed: bool,
 }
 impl_debug_if_no_extra_traits!(CounterNotifyEvent, "CounterNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CounterNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for CounterNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2690,6 +3541,12 @@ pub struct AlarmNotifyEvent {
     pub state: ALARMSTATE,
 }
 impl_debug_if_no_extra_traits!(AlarmNotifyEvent, "AlarmNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for AlarmNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for AlarmNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2821,3 +3678,27 @@ impl From<AlarmNotifyEvent> for [u8; 32] {
     }
 }
 
+/// All requests of this module.
+pub const REQUESTS: &[RequestDescription] = &[
+    RequestDescription { opcode: INITIALIZE_REQUEST, name: "Initialize", has_reply: true },
+    RequestDescription { opcode: LIST_SYSTEM_COUNTERS_REQUEST, name: "ListSystemCounters", has_reply: true },
+    RequestDescription { opcode: CREATE_COUNTER_REQUEST, name: "CreateCounter", has_reply: false },
+    RequestDescription { opcode: DESTROY_COUNTER_REQUEST, name: "DestroyCounter", has_reply: false },
+    RequestDescription { opcode: QUERY_COUNTER_REQUEST, name: "QueryCounter", has_reply: true },
+    RequestDescription { opcode: AWAIT_REQUEST, name: "Await", has_reply: false },
+    RequestDescription { opcode: CHANGE_COUNTER_REQUEST, name: "ChangeCounter", has_reply: false },
+    RequestDescription { opcode: SET_COUNTER_REQUEST, name: "SetCounter", has_reply: false },
+    RequestDescription { opcode: CREATE_ALARM_REQUEST, name: "CreateAlarm", has_reply: false },
+    RequestDescription { opcode: CHANGE_ALARM_REQUEST, name: "ChangeAlarm", has_reply: false },
+    RequestDescription { opcode: DESTROY_ALARM_REQUEST, name: "DestroyAlarm", has_reply: false },
+    RequestDescription { opcode: QUERY_ALARM_REQUEST, name: "QueryAlarm", has_reply: true },
+    RequestDescription { opcode: SET_PRIORITY_REQUEST, name: "SetPriority", has_reply: false },
+    RequestDescription { opcode: GET_PRIORITY_REQUEST, name: "GetPriority", has_reply: true },
+    RequestDescription { opcode: CREATE_FENCE_REQUEST, name: "CreateFence", has_reply: false },
+    RequestDescription { opcode: TRIGGER_FENCE_REQUEST, name: "TriggerFence", has_reply: false },
+    RequestDescription { opcode: RESET_FENCE_REQUEST, name: "ResetFence", has_reply: false },
+    RequestDescription { opcode: DESTROY_FENCE_REQUEST, name: "DestroyFence", has_reply: false },
+    RequestDescription { opcode: QUERY_FENCE_REQUEST, name: "QueryFence", has_reply: true },
+    RequestDescription { opcode: AWAIT_FENCE_REQUEST, name: "AwaitFence", has_reply: false },
+];
+
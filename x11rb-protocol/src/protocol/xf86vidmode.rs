@@ -22,6 +22,7 @@ use crate::BufWithFds;
 use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::x11_utils::RequestDescription;
 
 /// The X11 name of the extension for QueryExtension
 pub const X11_EXTENSION_NAME: &str = "XFree86-VidModeExtension";
@@ -40,6 +41,7 @@ pub type Dotclock = u32;
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ModeFlag(u32);
 impl ModeFlag {
     pub const POSITIVE_H_SYNC: Self = Self(1 << 0);
@@ -106,10 +108,37 @@ impl core::fmt::Debug for ModeFlag  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl ModeFlag {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("PositiveHSync"),
+            2 => Some("NegativeHSync"),
+            4 => Some("PositiveVSync"),
+            8 => Some("NegativeVSync"),
+            16 => Some("Interlace"),
+            32 => Some("CompositeSync"),
+            64 => Some("PositiveCSync"),
+            128 => Some("NegativeCSync"),
+            256 => Some("HSkew"),
+            512 => Some("Broadcast"),
+            1024 => Some("Pixmux"),
+            2048 => Some("DoubleClock"),
+            4096 => Some("HalfClock"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ModeFlag {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(ModeFlag, u32);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ClockFlag(u32);
 impl ClockFlag {
     pub const PROGRAMABLE: Self = Self(1 << 0);
@@ -152,10 +181,25 @@ impl core::fmt::Debug for ClockFlag  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl ClockFlag {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("Programable"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ClockFlag {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(ClockFlag, u32);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Permission(u32);
 impl Permission {
     pub const READ: Self = Self(1 << 0);
@@ -200,6 +244,21 @@ impl core::fmt::Debug for Permission  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl Permission {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("Read"),
+            2 => Some("Write"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Permission {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(Permission, u32);
 
 #[derive(Clone, Copy, Default)]
@@ -220,6 +279,12 @@ pub struct ModeInfo {
     pub privsize: u32,
 }
 impl_debug_if_no_extra_traits!(ModeInfo, "ModeInfo");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ModeInfo {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for ModeInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (dotclock, remaining) = Dotclock::try_parse(remaining)?;
@@ -331,6 +396,7 @@ pub const QUERY_VERSION_REQUEST: u8 = 0;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryVersionRequest;
 impl_debug_if_no_extra_traits!(QueryVersionRequest, "QueryVersionRequest");
 impl QueryVersionRequest {
@@ -369,6 +435,28 @@ impl Request for QueryVersionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryVersionRequest {
     type Reply = QueryVersionReply;
@@ -384,6 +472,12 @@ pub struct QueryVersionReply {
     pub minor_version: u16,
 }
 impl_debug_if_no_extra_traits!(QueryVersionReply, "QueryVersionReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryVersionReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -443,6 +537,7 @@ pub const GET_MODE_LINE_REQUEST: u8 = 1;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetModeLineRequest {
     pub screen: u16,
 }
@@ -491,6 +586,33 @@ impl Request for GetModeLineRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let screen_bytes = self.screen.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_MODE_LINE_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetModeLineRequest {
     type Reply = GetModeLineReply;
@@ -516,6 +638,12 @@ pub struct GetModeLineReply {
     pub private: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(GetModeLineReply, "GetModeLineReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetModeLineReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetModeLineReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -580,6 +708,7 @@ impl Serialize for GetModeLineReply {
         let privsize = u32::try_from(self.private.len()).expect("`private` has too many elements");
         privsize.serialize_into(bytes);
         bytes.extend_from_slice(&self.private);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetModeLineReply {
@@ -603,6 +732,7 @@ pub const MOD_MODE_LINE_REQUEST: u8 = 2;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ModModeLineRequest<'input> {
     pub screen: u32,
     pub hdisplay: u16,
@@ -759,6 +889,88 @@ impl<'input> Request for ModModeLineRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let screen_bytes = self.screen.serialize();
+        let hdisplay_bytes = self.hdisplay.serialize();
+        let hsyncstart_bytes = self.hsyncstart.serialize();
+        let hsyncend_bytes = self.hsyncend.serialize();
+        let htotal_bytes = self.htotal.serialize();
+        let hskew_bytes = self.hskew.serialize();
+        let vdisplay_bytes = self.vdisplay.serialize();
+        let vsyncstart_bytes = self.vsyncstart.serialize();
+        let vsyncend_bytes = self.vsyncend.serialize();
+        let vtotal_bytes = self.vtotal.serialize();
+        let flags_bytes = u32::from(self.flags).serialize();
+        let privsize = u32::try_from(self.private.len()).expect("`private` has too many elements");
+        let privsize_bytes = privsize.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            MOD_MODE_LINE_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            hdisplay_bytes[0],
+            hdisplay_bytes[1],
+            hsyncstart_bytes[0],
+            hsyncstart_bytes[1],
+            hsyncend_bytes[0],
+            hsyncend_bytes[1],
+            htotal_bytes[0],
+            htotal_bytes[1],
+            hskew_bytes[0],
+            hskew_bytes[1],
+            vdisplay_bytes[0],
+            vdisplay_bytes[1],
+            vsyncstart_bytes[0],
+            vsyncstart_bytes[1],
+            vsyncend_bytes[0],
+            vsyncend_bytes[1],
+            vtotal_bytes[0],
+            vtotal_bytes[1],
+            0,
+            0,
+            flags_bytes[0],
+            flags_bytes[1],
+            flags_bytes[2],
+            flags_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            privsize_bytes[0],
+            privsize_bytes[1],
+            privsize_bytes[2],
+            privsize_bytes[3],
+        ]);
+        buffer.extend_from_slice(&self.private[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for ModModeLineRequest<'input> {
 }
@@ -768,6 +980,7 @@ pub const SWITCH_MODE_REQUEST: u8 = 3;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SwitchModeRequest {
     pub screen: u16,
     pub zoom: u16,
@@ -819,6 +1032,34 @@ impl Request for SwitchModeRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let screen_bytes = self.screen.serialize();
+        let zoom_bytes = self.zoom.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SWITCH_MODE_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            zoom_bytes[0],
+            zoom_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SwitchModeRequest {
 }
@@ -828,6 +1069,7 @@ pub const GET_MONITOR_REQUEST: u8 = 4;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetMonitorRequest {
     pub screen: u16,
 }
@@ -876,6 +1118,33 @@ impl Request for GetMonitorRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let screen_bytes = self.screen.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_MONITOR_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetMonitorRequest {
     type Reply = GetMonitorReply;
@@ -894,6 +1163,12 @@ pub struct GetMonitorReply {
     pub model: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(GetMonitorReply, "GetMonitorReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetMonitorReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetMonitorReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -953,6 +1228,7 @@ impl Serialize for GetMonitorReply {
         assert_eq!(self.alignment_pad.len(), usize::try_from((u32::from(vendor_length).checked_add(3u32).unwrap() & (!3u32)).checked_sub(u32::from(vendor_length)).unwrap()).unwrap(), "`alignment_pad` has an incorrect length");
         bytes.extend_from_slice(&self.alignment_pad);
         bytes.extend_from_slice(&self.model);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetMonitorReply {
@@ -1015,6 +1291,7 @@ pub const LOCK_MODE_SWITCH_REQUEST: u8 = 5;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct LockModeSwitchRequest {
     pub screen: u16,
     pub lock: u16,
@@ -1066,6 +1343,34 @@ impl Request for LockModeSwitchRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let screen_bytes = self.screen.serialize();
+        let lock_bytes = self.lock.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            LOCK_MODE_SWITCH_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            lock_bytes[0],
+            lock_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for LockModeSwitchRequest {
 }
@@ -1075,6 +1380,7 @@ pub const GET_ALL_MODE_LINES_REQUEST: u8 = 6;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetAllModeLinesRequest {
     pub screen: u16,
 }
@@ -1123,6 +1429,33 @@ impl Request for GetAllModeLinesRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let screen_bytes = self.screen.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_ALL_MODE_LINES_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetAllModeLinesRequest {
     type Reply = GetAllModeLinesReply;
@@ -1137,6 +1470,12 @@ pub struct GetAllModeLinesReply {
     pub modeinfo: Vec<ModeInfo>,
 }
 impl_debug_if_no_extra_traits!(GetAllModeLinesReply, "GetAllModeLinesReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetAllModeLinesReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetAllModeLinesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1175,6 +1514,7 @@ impl Serialize for GetAllModeLinesReply {
         modecount.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 20]);
         self.modeinfo.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetAllModeLinesReply {
@@ -1198,6 +1538,7 @@ pub const ADD_MODE_LINE_REQUEST: u8 = 7;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct AddModeLineRequest<'input> {
     pub screen: u32,
     pub dotclock: Dotclock,
@@ -1461,35 +1802,8 @@ impl<'input> Request for AddModeLineRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
-}
-impl<'input> crate::x11_utils::VoidRequest for AddModeLineRequest<'input> {
-}
-
-/// Opcode for the DeleteModeLine request
-pub const DELETE_MODE_LINE_REQUEST: u8 = 8;
-#[derive(Clone, Default)]
-#[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct DeleteModeLineRequest<'input> {
-    pub screen: u32,
-    pub dotclock: Dotclock,
-    pub hdisplay: u16,
-    pub hsyncstart: u16,
-    pub hsyncend: u16,
-    pub htotal: u16,
-    pub hskew: u16,
-    pub vdisplay: u16,
-    pub vsyncstart: u16,
-    pub vsyncend: u16,
-    pub vtotal: u16,
-    pub flags: ModeFlag,
-    pub private: Cow<'input, [u8]>,
-}
-impl_debug_if_no_extra_traits!(DeleteModeLineRequest<'_>, "DeleteModeLineRequest");
-impl<'input> DeleteModeLineRequest<'input> {
-    /// Serialize this request into bytes for the provided connection
-    pub fn serialize(self, major_opcode: u8) -> BufWithFds<[Cow<'input, [u8]>; 3]> {
-        let length_so_far = 0;
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
         let screen_bytes = self.screen.serialize();
         let dotclock_bytes = self.dotclock.serialize();
         let hdisplay_bytes = self.hdisplay.serialize();
@@ -1504,9 +1818,20 @@ impl<'input> DeleteModeLineRequest<'input> {
         let flags_bytes = u32::from(self.flags).serialize();
         let privsize = u32::try_from(self.private.len()).expect("`private` has too many elements");
         let privsize_bytes = privsize.serialize();
-        let mut request0 = vec![
+        let after_dotclock_bytes = self.after_dotclock.serialize();
+        let after_hdisplay_bytes = self.after_hdisplay.serialize();
+        let after_hsyncstart_bytes = self.after_hsyncstart.serialize();
+        let after_hsyncend_bytes = self.after_hsyncend.serialize();
+        let after_htotal_bytes = self.after_htotal.serialize();
+        let after_hskew_bytes = self.after_hskew.serialize();
+        let after_vdisplay_bytes = self.after_vdisplay.serialize();
+        let after_vsyncstart_bytes = self.after_vsyncstart.serialize();
+        let after_vsyncend_bytes = self.after_vsyncend.serialize();
+        let after_vtotal_bytes = self.after_vtotal.serialize();
+        let after_flags_bytes = u32::from(self.after_flags).serialize();
+        buffer.extend_from_slice(&[
             major_opcode,
-            DELETE_MODE_LINE_REQUEST,
+            ADD_MODE_LINE_REQUEST,
             0,
             0,
             screen_bytes[0],
@@ -1557,41 +1882,196 @@ impl<'input> DeleteModeLineRequest<'input> {
             privsize_bytes[1],
             privsize_bytes[2],
             privsize_bytes[3],
-        ];
-        let length_so_far = length_so_far + request0.len();
-        let length_so_far = length_so_far + self.private.len();
-        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
-        let length_so_far = length_so_far + padding0.len();
-        assert_eq!(length_so_far % 4, 0);
-        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-        request0[2..4].copy_from_slice(&length.to_ne_bytes());
-        ([request0.into(), self.private, padding0.into()], vec![])
-    }
-    /// Parse this request given its header, its body, and any fds that go along with it
-    #[cfg(feature = "request-parsing")]
-    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
-        if header.minor_opcode != DELETE_MODE_LINE_REQUEST {
-            return Err(ParseError::InvalidValue);
-        }
-        let (screen, remaining) = u32::try_parse(value)?;
-        let (dotclock, remaining) = Dotclock::try_parse(remaining)?;
-        let (hdisplay, remaining) = u16::try_parse(remaining)?;
-        let (hsyncstart, remaining) = u16::try_parse(remaining)?;
-        let (hsyncend, remaining) = u16::try_parse(remaining)?;
-        let (htotal, remaining) = u16::try_parse(remaining)?;
-        let (hskew, remaining) = u16::try_parse(remaining)?;
-        let (vdisplay, remaining) = u16::try_parse(remaining)?;
-        let (vsyncstart, remaining) = u16::try_parse(remaining)?;
-        let (vsyncend, remaining) = u16::try_parse(remaining)?;
-        let (vtotal, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
-        let (flags, remaining) = u32::try_parse(remaining)?;
-        let flags = flags.into();
-        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
-        let (privsize, remaining) = u32::try_parse(remaining)?;
-        let (private, remaining) = crate::x11_utils::parse_u8_list(remaining, privsize.try_to_usize()?)?;
-        let _ = remaining;
-        Ok(DeleteModeLineRequest {
+            after_dotclock_bytes[0],
+            after_dotclock_bytes[1],
+            after_dotclock_bytes[2],
+            after_dotclock_bytes[3],
+            after_hdisplay_bytes[0],
+            after_hdisplay_bytes[1],
+            after_hsyncstart_bytes[0],
+            after_hsyncstart_bytes[1],
+            after_hsyncend_bytes[0],
+            after_hsyncend_bytes[1],
+            after_htotal_bytes[0],
+            after_htotal_bytes[1],
+            after_hskew_bytes[0],
+            after_hskew_bytes[1],
+            after_vdisplay_bytes[0],
+            after_vdisplay_bytes[1],
+            after_vsyncstart_bytes[0],
+            after_vsyncstart_bytes[1],
+            after_vsyncend_bytes[0],
+            after_vsyncend_bytes[1],
+            after_vtotal_bytes[0],
+            after_vtotal_bytes[1],
+            0,
+            0,
+            after_flags_bytes[0],
+            after_flags_bytes[1],
+            after_flags_bytes[2],
+            after_flags_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]);
+        buffer.extend_from_slice(&self.private[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
+}
+impl<'input> crate::x11_utils::VoidRequest for AddModeLineRequest<'input> {
+}
+
+/// Opcode for the DeleteModeLine request
+pub const DELETE_MODE_LINE_REQUEST: u8 = 8;
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct DeleteModeLineRequest<'input> {
+    pub screen: u32,
+    pub dotclock: Dotclock,
+    pub hdisplay: u16,
+    pub hsyncstart: u16,
+    pub hsyncend: u16,
+    pub htotal: u16,
+    pub hskew: u16,
+    pub vdisplay: u16,
+    pub vsyncstart: u16,
+    pub vsyncend: u16,
+    pub vtotal: u16,
+    pub flags: ModeFlag,
+    pub private: Cow<'input, [u8]>,
+}
+impl_debug_if_no_extra_traits!(DeleteModeLineRequest<'_>, "DeleteModeLineRequest");
+impl<'input> DeleteModeLineRequest<'input> {
+    /// Serialize this request into bytes for the provided connection
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<[Cow<'input, [u8]>; 3]> {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize();
+        let dotclock_bytes = self.dotclock.serialize();
+        let hdisplay_bytes = self.hdisplay.serialize();
+        let hsyncstart_bytes = self.hsyncstart.serialize();
+        let hsyncend_bytes = self.hsyncend.serialize();
+        let htotal_bytes = self.htotal.serialize();
+        let hskew_bytes = self.hskew.serialize();
+        let vdisplay_bytes = self.vdisplay.serialize();
+        let vsyncstart_bytes = self.vsyncstart.serialize();
+        let vsyncend_bytes = self.vsyncend.serialize();
+        let vtotal_bytes = self.vtotal.serialize();
+        let flags_bytes = u32::from(self.flags).serialize();
+        let privsize = u32::try_from(self.private.len()).expect("`private` has too many elements");
+        let privsize_bytes = privsize.serialize();
+        let mut request0 = vec![
+            major_opcode,
+            DELETE_MODE_LINE_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            dotclock_bytes[0],
+            dotclock_bytes[1],
+            dotclock_bytes[2],
+            dotclock_bytes[3],
+            hdisplay_bytes[0],
+            hdisplay_bytes[1],
+            hsyncstart_bytes[0],
+            hsyncstart_bytes[1],
+            hsyncend_bytes[0],
+            hsyncend_bytes[1],
+            htotal_bytes[0],
+            htotal_bytes[1],
+            hskew_bytes[0],
+            hskew_bytes[1],
+            vdisplay_bytes[0],
+            vdisplay_bytes[1],
+            vsyncstart_bytes[0],
+            vsyncstart_bytes[1],
+            vsyncend_bytes[0],
+            vsyncend_bytes[1],
+            vtotal_bytes[0],
+            vtotal_bytes[1],
+            0,
+            0,
+            flags_bytes[0],
+            flags_bytes[1],
+            flags_bytes[2],
+            flags_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            privsize_bytes[0],
+            privsize_bytes[1],
+            privsize_bytes[2],
+            privsize_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let length_so_far = length_so_far + self.private.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.to_ne_bytes());
+        ([request0.into(), self.private, padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    #[cfg(feature = "request-parsing")]
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        if header.minor_opcode != DELETE_MODE_LINE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (screen, remaining) = u32::try_parse(value)?;
+        let (dotclock, remaining) = Dotclock::try_parse(remaining)?;
+        let (hdisplay, remaining) = u16::try_parse(remaining)?;
+        let (hsyncstart, remaining) = u16::try_parse(remaining)?;
+        let (hsyncend, remaining) = u16::try_parse(remaining)?;
+        let (htotal, remaining) = u16::try_parse(remaining)?;
+        let (hskew, remaining) = u16::try_parse(remaining)?;
+        let (vdisplay, remaining) = u16::try_parse(remaining)?;
+        let (vsyncstart, remaining) = u16::try_parse(remaining)?;
+        let (vsyncend, remaining) = u16::try_parse(remaining)?;
+        let (vtotal, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (flags, remaining) = u32::try_parse(remaining)?;
+        let flags = flags.into();
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (privsize, remaining) = u32::try_parse(remaining)?;
+        let (private, remaining) = crate::x11_utils::parse_u8_list(remaining, privsize.try_to_usize()?)?;
+        let _ = remaining;
+        Ok(DeleteModeLineRequest {
             screen,
             dotclock,
             hdisplay,
@@ -1635,6 +2115,93 @@ impl<'input> Request for DeleteModeLineRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let screen_bytes = self.screen.serialize();
+        let dotclock_bytes = self.dotclock.serialize();
+        let hdisplay_bytes = self.hdisplay.serialize();
+        let hsyncstart_bytes = self.hsyncstart.serialize();
+        let hsyncend_bytes = self.hsyncend.serialize();
+        let htotal_bytes = self.htotal.serialize();
+        let hskew_bytes = self.hskew.serialize();
+        let vdisplay_bytes = self.vdisplay.serialize();
+        let vsyncstart_bytes = self.vsyncstart.serialize();
+        let vsyncend_bytes = self.vsyncend.serialize();
+        let vtotal_bytes = self.vtotal.serialize();
+        let flags_bytes = u32::from(self.flags).serialize();
+        let privsize = u32::try_from(self.private.len()).expect("`private` has too many elements");
+        let privsize_bytes = privsize.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            DELETE_MODE_LINE_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            dotclock_bytes[0],
+            dotclock_bytes[1],
+            dotclock_bytes[2],
+            dotclock_bytes[3],
+            hdisplay_bytes[0],
+            hdisplay_bytes[1],
+            hsyncstart_bytes[0],
+            hsyncstart_bytes[1],
+            hsyncend_bytes[0],
+            hsyncend_bytes[1],
+            htotal_bytes[0],
+            htotal_bytes[1],
+            hskew_bytes[0],
+            hskew_bytes[1],
+            vdisplay_bytes[0],
+            vdisplay_bytes[1],
+            vsyncstart_bytes[0],
+            vsyncstart_bytes[1],
+            vsyncend_bytes[0],
+            vsyncend_bytes[1],
+            vtotal_bytes[0],
+            vtotal_bytes[1],
+            0,
+            0,
+            flags_bytes[0],
+            flags_bytes[1],
+            flags_bytes[2],
+            flags_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            privsize_bytes[0],
+            privsize_bytes[1],
+            privsize_bytes[2],
+            privsize_bytes[3],
+        ]);
+        buffer.extend_from_slice(&self.private[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for DeleteModeLineRequest<'input> {
 }
@@ -1644,6 +2211,7 @@ pub const VALIDATE_MODE_LINE_REQUEST: u8 = 9;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ValidateModeLineRequest<'input> {
     pub screen: u32,
     pub dotclock: Dotclock,
@@ -1809,6 +2377,93 @@ impl<'input> Request for ValidateModeLineRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let screen_bytes = self.screen.serialize();
+        let dotclock_bytes = self.dotclock.serialize();
+        let hdisplay_bytes = self.hdisplay.serialize();
+        let hsyncstart_bytes = self.hsyncstart.serialize();
+        let hsyncend_bytes = self.hsyncend.serialize();
+        let htotal_bytes = self.htotal.serialize();
+        let hskew_bytes = self.hskew.serialize();
+        let vdisplay_bytes = self.vdisplay.serialize();
+        let vsyncstart_bytes = self.vsyncstart.serialize();
+        let vsyncend_bytes = self.vsyncend.serialize();
+        let vtotal_bytes = self.vtotal.serialize();
+        let flags_bytes = u32::from(self.flags).serialize();
+        let privsize = u32::try_from(self.private.len()).expect("`private` has too many elements");
+        let privsize_bytes = privsize.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            VALIDATE_MODE_LINE_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            dotclock_bytes[0],
+            dotclock_bytes[1],
+            dotclock_bytes[2],
+            dotclock_bytes[3],
+            hdisplay_bytes[0],
+            hdisplay_bytes[1],
+            hsyncstart_bytes[0],
+            hsyncstart_bytes[1],
+            hsyncend_bytes[0],
+            hsyncend_bytes[1],
+            htotal_bytes[0],
+            htotal_bytes[1],
+            hskew_bytes[0],
+            hskew_bytes[1],
+            vdisplay_bytes[0],
+            vdisplay_bytes[1],
+            vsyncstart_bytes[0],
+            vsyncstart_bytes[1],
+            vsyncend_bytes[0],
+            vsyncend_bytes[1],
+            vtotal_bytes[0],
+            vtotal_bytes[1],
+            0,
+            0,
+            flags_bytes[0],
+            flags_bytes[1],
+            flags_bytes[2],
+            flags_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            privsize_bytes[0],
+            privsize_bytes[1],
+            privsize_bytes[2],
+            privsize_bytes[3],
+        ]);
+        buffer.extend_from_slice(&self.private[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::ReplyRequest for ValidateModeLineRequest<'input> {
     type Reply = ValidateModeLineReply;
@@ -1823,6 +2478,12 @@ pub struct ValidateModeLineReply {
     pub status: u32,
 }
 impl_debug_if_no_extra_traits!(ValidateModeLineReply, "ValidateModeLineReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ValidateModeLineReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ValidateModeLineReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1901,6 +2562,7 @@ pub const SWITCH_TO_MODE_REQUEST: u8 = 10;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SwitchToModeRequest<'input> {
     pub screen: u32,
     pub dotclock: Dotclock,
@@ -2056,15 +2718,102 @@ impl<'input> SwitchToModeRequest<'input> {
             private: Cow::Owned(self.private.into_owned()),
         }
     }
-}
-impl<'input> Request for SwitchToModeRequest<'input> {
-    const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
-
-    fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
+}
+impl<'input> Request for SwitchToModeRequest<'input> {
+    const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+
+    fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
+        let (bufs, fds) = self.serialize(major_opcode);
+        // Flatten the buffers into a single vector
+        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
+        (buf, fds)
+    }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let screen_bytes = self.screen.serialize();
+        let dotclock_bytes = self.dotclock.serialize();
+        let hdisplay_bytes = self.hdisplay.serialize();
+        let hsyncstart_bytes = self.hsyncstart.serialize();
+        let hsyncend_bytes = self.hsyncend.serialize();
+        let htotal_bytes = self.htotal.serialize();
+        let hskew_bytes = self.hskew.serialize();
+        let vdisplay_bytes = self.vdisplay.serialize();
+        let vsyncstart_bytes = self.vsyncstart.serialize();
+        let vsyncend_bytes = self.vsyncend.serialize();
+        let vtotal_bytes = self.vtotal.serialize();
+        let flags_bytes = u32::from(self.flags).serialize();
+        let privsize = u32::try_from(self.private.len()).expect("`private` has too many elements");
+        let privsize_bytes = privsize.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SWITCH_TO_MODE_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            dotclock_bytes[0],
+            dotclock_bytes[1],
+            dotclock_bytes[2],
+            dotclock_bytes[3],
+            hdisplay_bytes[0],
+            hdisplay_bytes[1],
+            hsyncstart_bytes[0],
+            hsyncstart_bytes[1],
+            hsyncend_bytes[0],
+            hsyncend_bytes[1],
+            htotal_bytes[0],
+            htotal_bytes[1],
+            hskew_bytes[0],
+            hskew_bytes[1],
+            vdisplay_bytes[0],
+            vdisplay_bytes[1],
+            vsyncstart_bytes[0],
+            vsyncstart_bytes[1],
+            vsyncend_bytes[0],
+            vsyncend_bytes[1],
+            vtotal_bytes[0],
+            vtotal_bytes[1],
+            0,
+            0,
+            flags_bytes[0],
+            flags_bytes[1],
+            flags_bytes[2],
+            flags_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            privsize_bytes[0],
+            privsize_bytes[1],
+            privsize_bytes[2],
+            privsize_bytes[3],
+        ]);
+        buffer.extend_from_slice(&self.private[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
         let (bufs, fds) = self.serialize(major_opcode);
-        // Flatten the buffers into a single vector
-        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
-        (buf, fds)
+        slices.extend(bufs);
+        fds
     }
 }
 impl<'input> crate::x11_utils::VoidRequest for SwitchToModeRequest<'input> {
@@ -2075,6 +2824,7 @@ pub const GET_VIEW_PORT_REQUEST: u8 = 11;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetViewPortRequest {
     pub screen: u16,
 }
@@ -2123,6 +2873,33 @@ impl Request for GetViewPortRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let screen_bytes = self.screen.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_VIEW_PORT_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetViewPortRequest {
     type Reply = GetViewPortReply;
@@ -2138,6 +2915,12 @@ pub struct GetViewPortReply {
     pub y: u32,
 }
 impl_debug_if_no_extra_traits!(GetViewPortReply, "GetViewPortReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetViewPortReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetViewPortReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2219,6 +3002,7 @@ pub const SET_VIEW_PORT_REQUEST: u8 = 12;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetViewPortRequest {
     pub screen: u16,
     pub x: u32,
@@ -2283,6 +3067,43 @@ impl Request for SetViewPortRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let screen_bytes = self.screen.serialize();
+        let x_bytes = self.x.serialize();
+        let y_bytes = self.y.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_VIEW_PORT_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            0,
+            0,
+            x_bytes[0],
+            x_bytes[1],
+            x_bytes[2],
+            x_bytes[3],
+            y_bytes[0],
+            y_bytes[1],
+            y_bytes[2],
+            y_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SetViewPortRequest {
 }
@@ -2292,6 +3113,7 @@ pub const GET_DOT_CLOCKS_REQUEST: u8 = 13;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetDotClocksRequest {
     pub screen: u16,
 }
@@ -2340,6 +3162,33 @@ impl Request for GetDotClocksRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let screen_bytes = self.screen.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_DOT_CLOCKS_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetDotClocksRequest {
     type Reply = GetDotClocksReply;
@@ -2357,6 +3206,12 @@ pub struct GetDotClocksReply {
     pub clock: Vec<u32>,
 }
 impl_debug_if_no_extra_traits!(GetDotClocksReply, "GetDotClocksReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetDotClocksReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetDotClocksReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2400,6 +3255,7 @@ impl Serialize for GetDotClocksReply {
         bytes.extend_from_slice(&[0; 12]);
         assert_eq!(self.clock.len(), usize::try_from(1u32.checked_sub(u32::from(self.flags) & 1u32).unwrap().checked_mul(u32::from(self.clocks)).unwrap()).unwrap(), "`clock` has an incorrect length");
         self.clock.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 
@@ -2408,6 +3264,7 @@ pub const SET_CLIENT_VERSION_REQUEST: u8 = 14;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetClientVersionRequest {
     pub major: u16,
     pub minor: u16,
@@ -2459,6 +3316,34 @@ impl Request for SetClientVersionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let major_bytes = self.major.serialize();
+        let minor_bytes = self.minor.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_CLIENT_VERSION_REQUEST,
+            0,
+            0,
+            major_bytes[0],
+            major_bytes[1],
+            minor_bytes[0],
+            minor_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SetClientVersionRequest {
 }
@@ -2468,6 +3353,7 @@ pub const SET_GAMMA_REQUEST: u8 = 15;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetGammaRequest {
     pub screen: u16,
     pub red: u32,
@@ -2553,6 +3439,60 @@ impl Request for SetGammaRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let screen_bytes = self.screen.serialize();
+        let red_bytes = self.red.serialize();
+        let green_bytes = self.green.serialize();
+        let blue_bytes = self.blue.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_GAMMA_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            0,
+            0,
+            red_bytes[0],
+            red_bytes[1],
+            red_bytes[2],
+            red_bytes[3],
+            green_bytes[0],
+            green_bytes[1],
+            green_bytes[2],
+            green_bytes[3],
+            blue_bytes[0],
+            blue_bytes[1],
+            blue_bytes[2],
+            blue_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SetGammaRequest {
 }
@@ -2562,6 +3502,7 @@ pub const GET_GAMMA_REQUEST: u8 = 16;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetGammaRequest {
     pub screen: u16,
 }
@@ -2634,6 +3575,57 @@ impl Request for GetGammaRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let screen_bytes = self.screen.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_GAMMA_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetGammaRequest {
     type Reply = GetGammaReply;
@@ -2650,6 +3642,12 @@ pub struct GetGammaReply {
     pub blue: u32,
 }
 impl_debug_if_no_extra_traits!(GetGammaReply, "GetGammaReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetGammaReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetGammaReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2734,6 +3732,7 @@ pub const GET_GAMMA_RAMP_REQUEST: u8 = 17;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetGammaRampRequest {
     pub screen: u16,
     pub size: u16,
@@ -2785,6 +3784,34 @@ impl Request for GetGammaRampRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let screen_bytes = self.screen.serialize();
+        let size_bytes = self.size.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_GAMMA_RAMP_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            size_bytes[0],
+            size_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetGammaRampRequest {
     type Reply = GetGammaRampReply;
@@ -2802,6 +3829,12 @@ pub struct GetGammaRampReply {
     pub blue: Vec<u16>,
 }
 impl_debug_if_no_extra_traits!(GetGammaRampReply, "GetGammaRampReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetGammaRampReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetGammaRampReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2846,6 +3879,7 @@ impl Serialize for GetGammaRampReply {
         self.green.serialize_into(bytes);
         assert_eq!(self.blue.len(), usize::try_from(u32::from(self.size).checked_add(1u32).unwrap() & (!1u32)).unwrap(), "`blue` has an incorrect length");
         self.blue.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 
@@ -2854,6 +3888,7 @@ pub const SET_GAMMA_RAMP_REQUEST: u8 = 18;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetGammaRampRequest<'input> {
     pub screen: u16,
     pub size: u16,
@@ -2935,6 +3970,42 @@ impl<'input> Request for SetGammaRampRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let screen_bytes = self.screen.serialize();
+        let size_bytes = self.size.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_GAMMA_RAMP_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            size_bytes[0],
+            size_bytes[1],
+        ]);
+        assert_eq!(self.red.len(), usize::try_from(u32::from(self.size).checked_add(1u32).unwrap() & (!1u32)).unwrap(), "`red` has an incorrect length");
+        self.red.serialize_into(buffer);
+        assert_eq!(self.green.len(), usize::try_from(u32::from(self.size).checked_add(1u32).unwrap() & (!1u32)).unwrap(), "`green` has an incorrect length");
+        self.green.serialize_into(buffer);
+        assert_eq!(self.blue.len(), usize::try_from(u32::from(self.size).checked_add(1u32).unwrap() & (!1u32)).unwrap(), "`blue` has an incorrect length");
+        self.blue.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for SetGammaRampRequest<'input> {
 }
@@ -2944,6 +4015,7 @@ pub const GET_GAMMA_RAMP_SIZE_REQUEST: u8 = 19;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetGammaRampSizeRequest {
     pub screen: u16,
 }
@@ -2992,6 +4064,33 @@ impl Request for GetGammaRampSizeRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let screen_bytes = self.screen.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_GAMMA_RAMP_SIZE_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetGammaRampSizeRequest {
     type Reply = GetGammaRampSizeReply;
@@ -3006,6 +4105,12 @@ pub struct GetGammaRampSizeReply {
     pub size: u16,
 }
 impl_debug_if_no_extra_traits!(GetGammaRampSizeReply, "GetGammaRampSizeReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetGammaRampSizeReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetGammaRampSizeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -3084,6 +4189,7 @@ pub const GET_PERMISSIONS_REQUEST: u8 = 20;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetPermissionsRequest {
     pub screen: u16,
 }
@@ -3132,6 +4238,33 @@ impl Request for GetPermissionsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let screen_bytes = self.screen.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_PERMISSIONS_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetPermissionsRequest {
     type Reply = GetPermissionsReply;
@@ -3146,6 +4279,12 @@ pub struct GetPermissionsReply {
     pub permissions: Permission,
 }
 impl_debug_if_no_extra_traits!(GetPermissionsReply, "GetPermissionsReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetPermissionsReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetPermissionsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -3241,3 +4380,28 @@ pub const CLIENT_NOT_LOCAL_ERROR: u8 = 5;
 /// Opcode for the ZoomLocked error
 pub const ZOOM_LOCKED_ERROR: u8 = 6;
 
+/// All requests of this module.
+pub const REQUESTS: &[RequestDescription] = &[
+    RequestDescription { opcode: QUERY_VERSION_REQUEST, name: "QueryVersion", has_reply: true },
+    RequestDescription { opcode: GET_MODE_LINE_REQUEST, name: "GetModeLine", has_reply: true },
+    RequestDescription { opcode: MOD_MODE_LINE_REQUEST, name: "ModModeLine", has_reply: false },
+    RequestDescription { opcode: SWITCH_MODE_REQUEST, name: "SwitchMode", has_reply: false },
+    RequestDescription { opcode: GET_MONITOR_REQUEST, name: "GetMonitor", has_reply: true },
+    RequestDescription { opcode: LOCK_MODE_SWITCH_REQUEST, name: "LockModeSwitch", has_reply: false },
+    RequestDescription { opcode: GET_ALL_MODE_LINES_REQUEST, name: "GetAllModeLines", has_reply: true },
+    RequestDescription { opcode: ADD_MODE_LINE_REQUEST, name: "AddModeLine", has_reply: false },
+    RequestDescription { opcode: DELETE_MODE_LINE_REQUEST, name: "DeleteModeLine", has_reply: false },
+    RequestDescription { opcode: VALIDATE_MODE_LINE_REQUEST, name: "ValidateModeLine", has_reply: true },
+    RequestDescription { opcode: SWITCH_TO_MODE_REQUEST, name: "SwitchToMode", has_reply: false },
+    RequestDescription { opcode: GET_VIEW_PORT_REQUEST, name: "GetViewPort", has_reply: true },
+    RequestDescription { opcode: SET_VIEW_PORT_REQUEST, name: "SetViewPort", has_reply: false },
+    RequestDescription { opcode: GET_DOT_CLOCKS_REQUEST, name: "GetDotClocks", has_reply: true },
+    RequestDescription { opcode: SET_CLIENT_VERSION_REQUEST, name: "SetClientVersion", has_reply: false },
+    RequestDescription { opcode: SET_GAMMA_REQUEST, name: "SetGamma", has_reply: false },
+    RequestDescription { opcode: GET_GAMMA_REQUEST, name: "GetGamma", has_reply: true },
+    RequestDescription { opcode: GET_GAMMA_RAMP_REQUEST, name: "GetGammaRamp", has_reply: true },
+    RequestDescription { opcode: SET_GAMMA_RAMP_REQUEST, name: "SetGammaRamp", has_reply: false },
+    RequestDescription { opcode: GET_GAMMA_RAMP_SIZE_REQUEST, name: "GetGammaRampSize", has_reply: true },
+    RequestDescription { opcode: GET_PERMISSIONS_REQUEST, name: "GetPermissions", has_reply: true },
+];
+
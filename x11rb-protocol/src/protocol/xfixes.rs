@@ -22,6 +22,7 @@ use crate::BufWithFds;
 use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::x11_utils::RequestDescription;
 #[allow(unused_imports)]
 use super::render;
 #[allow(unused_imports)]
@@ -45,6 +46,7 @@ pub const QUERY_VERSION_REQUEST: u8 = 0;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryVersionRequest {
     pub client_major_version: u32,
     pub client_minor_version: u32,
@@ -100,6 +102,38 @@ impl Request for QueryVersionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let client_major_version_bytes = self.client_major_version.serialize();
+        let client_minor_version_bytes = self.client_minor_version.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+            client_major_version_bytes[0],
+            client_major_version_bytes[1],
+            client_major_version_bytes[2],
+            client_major_version_bytes[3],
+            client_minor_version_bytes[0],
+            client_minor_version_bytes[1],
+            client_minor_version_bytes[2],
+            client_minor_version_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryVersionRequest {
     type Reply = QueryVersionReply;
@@ -115,6 +149,12 @@ pub struct QueryVersionReply {
     pub minor_version: u32,
 }
 impl_debug_if_no_extra_traits!(QueryVersionReply, "QueryVersionReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryVersionReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -193,6 +233,7 @@ impl Serialize for QueryVersionReply {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SaveSetMode(u8);
 impl SaveSetMode {
     pub const INSERT: Self = Self(0);
@@ -249,9 +290,25 @@ impl core::fmt::Debug for SaveSetMode  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl SaveSetMode {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Insert"),
+            1 => Some("Delete"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for SaveSetMode {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SaveSetTarget(u8);
 impl SaveSetTarget {
     pub const NEAREST: Self = Self(0);
@@ -308,9 +365,25 @@ impl core::fmt::Debug for SaveSetTarget  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl SaveSetTarget {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Nearest"),
+            1 => Some("Root"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for SaveSetTarget {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SaveSetMapping(u8);
 impl SaveSetMapping {
     pub const MAP: Self = Self(0);
@@ -367,12 +440,28 @@ impl core::fmt::Debug for SaveSetMapping  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl SaveSetMapping {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Map"),
+            1 => Some("Unmap"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for SaveSetMapping {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the ChangeSaveSet request
 pub const CHANGE_SAVE_SET_REQUEST: u8 = 1;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangeSaveSetRequest {
     pub mode: SaveSetMode,
     pub target: SaveSetTarget,
@@ -440,12 +529,47 @@ impl Request for ChangeSaveSetRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let mode_bytes = u8::from(self.mode).serialize();
+        let target_bytes = u8::from(self.target).serialize();
+        let map_bytes = u8::from(self.map).serialize();
+        let window_bytes = self.window.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CHANGE_SAVE_SET_REQUEST,
+            0,
+            0,
+            mode_bytes[0],
+            target_bytes[0],
+            map_bytes[0],
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for ChangeSaveSetRequest {
 }
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SelectionEvent(u8);
 impl SelectionEvent {
     pub const SET_SELECTION_OWNER: Self = Self(0);
@@ -504,9 +628,26 @@ impl core::fmt::Debug for SelectionEvent  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl SelectionEvent {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("SetSelectionOwner"),
+            1 => Some("SelectionWindowDestroy"),
+            2 => Some("SelectionClientClose"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for SelectionEvent {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SelectionEventMask(u32);
 impl SelectionEventMask {
     pub const SET_SELECTION_OWNER: Self = Self(1 << 0);
@@ -553,6 +694,22 @@ impl core::fmt::Debug for SelectionEventMask  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl SelectionEventMask {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("SetSelectionOwner"),
+            2 => Some("SelectionWindowDestroy"),
+            4 => Some("SelectionClientClose"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for SelectionEventMask {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(SelectionEventMask, u32);
 
 /// Opcode for the SelectionNotify event
@@ -571,6 +728,12 @@ pub struct SelectionNotifyEvent {
     pub selection_timestamp: xproto::Timestamp,
 }
 impl_debug_if_no_extra_traits!(SelectionNotifyEvent, "SelectionNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SelectionNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for SelectionNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -707,6 +870,7 @@ pub const SELECT_SELECTION_INPUT_REQUEST: u8 = 2;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SelectSelectionInputRequest {
     pub window: xproto::Window,
     pub selection: xproto::Atom,
@@ -771,12 +935,50 @@ impl Request for SelectSelectionInputRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let selection_bytes = self.selection.serialize();
+        let event_mask_bytes = u32::from(self.event_mask).serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SELECT_SELECTION_INPUT_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            selection_bytes[0],
+            selection_bytes[1],
+            selection_bytes[2],
+            selection_bytes[3],
+            event_mask_bytes[0],
+            event_mask_bytes[1],
+            event_mask_bytes[2],
+            event_mask_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SelectSelectionInputRequest {
 }
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CursorNotify(u8);
 impl CursorNotify {
     pub const DISPLAY_CURSOR: Self = Self(0);
@@ -831,9 +1033,24 @@ impl core::fmt::Debug for CursorNotify  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl CursorNotify {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("DisplayCursor"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for CursorNotify {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CursorNotifyMask(u32);
 impl CursorNotifyMask {
     pub const DISPLAY_CURSOR: Self = Self(1 << 0);
@@ -876,6 +1093,20 @@ impl core::fmt::Debug for CursorNotifyMask  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl CursorNotifyMask {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("DisplayCursor"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for CursorNotifyMask {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(CursorNotifyMask, u32);
 
 /// Opcode for the CursorNotify event
@@ -893,6 +1124,12 @@ pub struct CursorNotifyEvent {
     pub name: xproto::Atom,
 }
 impl_debug_if_no_extra_traits!(CursorNotifyEvent, "CursorNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CursorNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for CursorNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1025,6 +1262,7 @@ pub const SELECT_CURSOR_INPUT_REQUEST: u8 = 3;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SelectCursorInputRequest {
     pub window: xproto::Window,
     pub event_mask: CursorNotifyMask,
@@ -1081,6 +1319,38 @@ impl Request for SelectCursorInputRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let event_mask_bytes = u32::from(self.event_mask).serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SELECT_CURSOR_INPUT_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            event_mask_bytes[0],
+            event_mask_bytes[1],
+            event_mask_bytes[2],
+            event_mask_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SelectCursorInputRequest {
 }
@@ -1090,6 +1360,7 @@ pub const GET_CURSOR_IMAGE_REQUEST: u8 = 4;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetCursorImageRequest;
 impl_debug_if_no_extra_traits!(GetCursorImageRequest, "GetCursorImageRequest");
 impl GetCursorImageRequest {
@@ -1128,6 +1399,28 @@ impl Request for GetCursorImageRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_CURSOR_IMAGE_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetCursorImageRequest {
     type Reply = GetCursorImageReply;
@@ -1149,6 +1442,12 @@ pub struct GetCursorImageReply {
     pub cursor_image: Vec<u32>,
 }
 impl_debug_if_no_extra_traits!(GetCursorImageReply, "GetCursorImageReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetCursorImageReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetCursorImageReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1199,6 +1498,7 @@ impl Serialize for GetCursorImageReply {
         bytes.extend_from_slice(&[0; 8]);
         assert_eq!(self.cursor_image.len(), usize::try_from(u32::from(self.width).checked_mul(u32::from(self.height)).unwrap()).unwrap(), "`cursor_image` has an incorrect length");
         self.cursor_image.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 
@@ -1209,6 +1509,7 @@ pub const BAD_REGION_ERROR: u8 = 0;
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct RegionEnum(u8);
 impl RegionEnum {
     pub const NONE: Self = Self(0);
@@ -1263,12 +1564,27 @@ impl core::fmt::Debug for RegionEnum  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl RegionEnum {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("None"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for RegionEnum {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the CreateRegion request
 pub const CREATE_REGION_REQUEST: u8 = 5;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateRegionRequest<'input> {
     pub region: Region,
     pub rectangles: Cow<'input, [xproto::Rectangle]>,
@@ -1308,7 +1624,7 @@ impl<'input> CreateRegionRequest<'input> {
         let (region, remaining) = Region::try_parse(value)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut rectangles = Vec::new();
+        let mut rectangles = Vec::with_capacity(remaining.len() / 8);
         while !remaining.is_empty() {
             let (v, new_remaining) = xproto::Rectangle::try_parse(remaining)?;
             remaining = new_remaining;
@@ -1337,6 +1653,36 @@ impl<'input> Request for CreateRegionRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let region_bytes = self.region.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CREATE_REGION_REQUEST,
+            0,
+            0,
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+        ]);
+        self.rectangles.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for CreateRegionRequest<'input> {
 }
@@ -1346,6 +1692,7 @@ pub const CREATE_REGION_FROM_BITMAP_REQUEST: u8 = 6;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateRegionFromBitmapRequest {
     pub region: Region,
     pub bitmap: xproto::Pixmap,
@@ -1401,6 +1748,38 @@ impl Request for CreateRegionFromBitmapRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let region_bytes = self.region.serialize();
+        let bitmap_bytes = self.bitmap.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CREATE_REGION_FROM_BITMAP_REQUEST,
+            0,
+            0,
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+            bitmap_bytes[0],
+            bitmap_bytes[1],
+            bitmap_bytes[2],
+            bitmap_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for CreateRegionFromBitmapRequest {
 }
@@ -1410,6 +1789,7 @@ pub const CREATE_REGION_FROM_WINDOW_REQUEST: u8 = 7;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateRegionFromWindowRequest {
     pub region: Region,
     pub window: xproto::Window,
@@ -1475,6 +1855,43 @@ impl Request for CreateRegionFromWindowRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let region_bytes = self.region.serialize();
+        let window_bytes = self.window.serialize();
+        let kind_bytes = shape::Kind::from(self.kind).serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CREATE_REGION_FROM_WINDOW_REQUEST,
+            0,
+            0,
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            kind_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for CreateRegionFromWindowRequest {
 }
@@ -1484,6 +1901,7 @@ pub const CREATE_REGION_FROM_GC_REQUEST: u8 = 8;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateRegionFromGCRequest {
     pub region: Region,
     pub gc: xproto::Gcontext,
@@ -1539,6 +1957,38 @@ impl Request for CreateRegionFromGCRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let region_bytes = self.region.serialize();
+        let gc_bytes = self.gc.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CREATE_REGION_FROM_GC_REQUEST,
+            0,
+            0,
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for CreateRegionFromGCRequest {
 }
@@ -1548,6 +1998,7 @@ pub const CREATE_REGION_FROM_PICTURE_REQUEST: u8 = 9;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateRegionFromPictureRequest {
     pub region: Region,
     pub picture: render::Picture,
@@ -1603,6 +2054,38 @@ impl Request for CreateRegionFromPictureRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let region_bytes = self.region.serialize();
+        let picture_bytes = self.picture.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CREATE_REGION_FROM_PICTURE_REQUEST,
+            0,
+            0,
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+            picture_bytes[0],
+            picture_bytes[1],
+            picture_bytes[2],
+            picture_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for CreateRegionFromPictureRequest {
 }
@@ -1612,6 +2095,7 @@ pub const DESTROY_REGION_REQUEST: u8 = 10;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DestroyRegionRequest {
     pub region: Region,
 }
@@ -1659,15 +2143,43 @@ impl Request for DestroyRegionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
-}
-impl crate::x11_utils::VoidRequest for DestroyRegionRequest {
-}
-
-/// Opcode for the SetRegion request
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let region_bytes = self.region.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            DESTROY_REGION_REQUEST,
+            0,
+            0,
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
+}
+impl crate::x11_utils::VoidRequest for DestroyRegionRequest {
+}
+
+/// Opcode for the SetRegion request
 pub const SET_REGION_REQUEST: u8 = 11;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetRegionRequest<'input> {
     pub region: Region,
     pub rectangles: Cow<'input, [xproto::Rectangle]>,
@@ -1707,7 +2219,7 @@ impl<'input> SetRegionRequest<'input> {
         let (region, remaining) = Region::try_parse(value)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut rectangles = Vec::new();
+        let mut rectangles = Vec::with_capacity(remaining.len() / 8);
         while !remaining.is_empty() {
             let (v, new_remaining) = xproto::Rectangle::try_parse(remaining)?;
             remaining = new_remaining;
@@ -1736,6 +2248,36 @@ impl<'input> Request for SetRegionRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let region_bytes = self.region.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_REGION_REQUEST,
+            0,
+            0,
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+        ]);
+        self.rectangles.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for SetRegionRequest<'input> {
 }
@@ -1745,6 +2287,7 @@ pub const COPY_REGION_REQUEST: u8 = 12;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CopyRegionRequest {
     pub source: Region,
     pub destination: Region,
@@ -1800,6 +2343,38 @@ impl Request for CopyRegionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let source_bytes = self.source.serialize();
+        let destination_bytes = self.destination.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            COPY_REGION_REQUEST,
+            0,
+            0,
+            source_bytes[0],
+            source_bytes[1],
+            source_bytes[2],
+            source_bytes[3],
+            destination_bytes[0],
+            destination_bytes[1],
+            destination_bytes[2],
+            destination_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for CopyRegionRequest {
 }
@@ -1809,6 +2384,7 @@ pub const UNION_REGION_REQUEST: u8 = 13;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct UnionRegionRequest {
     pub source1: Region,
     pub source2: Region,
@@ -1872,6 +2448,43 @@ impl Request for UnionRegionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let source1_bytes = self.source1.serialize();
+        let source2_bytes = self.source2.serialize();
+        let destination_bytes = self.destination.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            UNION_REGION_REQUEST,
+            0,
+            0,
+            source1_bytes[0],
+            source1_bytes[1],
+            source1_bytes[2],
+            source1_bytes[3],
+            source2_bytes[0],
+            source2_bytes[1],
+            source2_bytes[2],
+            source2_bytes[3],
+            destination_bytes[0],
+            destination_bytes[1],
+            destination_bytes[2],
+            destination_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for UnionRegionRequest {
 }
@@ -1881,6 +2494,7 @@ pub const INTERSECT_REGION_REQUEST: u8 = 14;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct IntersectRegionRequest {
     pub source1: Region,
     pub source2: Region,
@@ -1944,6 +2558,43 @@ impl Request for IntersectRegionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let source1_bytes = self.source1.serialize();
+        let source2_bytes = self.source2.serialize();
+        let destination_bytes = self.destination.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            INTERSECT_REGION_REQUEST,
+            0,
+            0,
+            source1_bytes[0],
+            source1_bytes[1],
+            source1_bytes[2],
+            source1_bytes[3],
+            source2_bytes[0],
+            source2_bytes[1],
+            source2_bytes[2],
+            source2_bytes[3],
+            destination_bytes[0],
+            destination_bytes[1],
+            destination_bytes[2],
+            destination_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for IntersectRegionRequest {
 }
@@ -1953,6 +2604,7 @@ pub const SUBTRACT_REGION_REQUEST: u8 = 15;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SubtractRegionRequest {
     pub source1: Region,
     pub source2: Region,
@@ -2016,6 +2668,43 @@ impl Request for SubtractRegionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let source1_bytes = self.source1.serialize();
+        let source2_bytes = self.source2.serialize();
+        let destination_bytes = self.destination.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SUBTRACT_REGION_REQUEST,
+            0,
+            0,
+            source1_bytes[0],
+            source1_bytes[1],
+            source1_bytes[2],
+            source1_bytes[3],
+            source2_bytes[0],
+            source2_bytes[1],
+            source2_bytes[2],
+            source2_bytes[3],
+            destination_bytes[0],
+            destination_bytes[1],
+            destination_bytes[2],
+            destination_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SubtractRegionRequest {
 }
@@ -2025,6 +2714,7 @@ pub const INVERT_REGION_REQUEST: u8 = 16;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct InvertRegionRequest {
     pub source: Region,
     pub bounds: xproto::Rectangle,
@@ -2092,6 +2782,47 @@ impl Request for InvertRegionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let source_bytes = self.source.serialize();
+        let bounds_bytes = self.bounds.serialize();
+        let destination_bytes = self.destination.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            INVERT_REGION_REQUEST,
+            0,
+            0,
+            source_bytes[0],
+            source_bytes[1],
+            source_bytes[2],
+            source_bytes[3],
+            bounds_bytes[0],
+            bounds_bytes[1],
+            bounds_bytes[2],
+            bounds_bytes[3],
+            bounds_bytes[4],
+            bounds_bytes[5],
+            bounds_bytes[6],
+            bounds_bytes[7],
+            destination_bytes[0],
+            destination_bytes[1],
+            destination_bytes[2],
+            destination_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for InvertRegionRequest {
 }
@@ -2101,6 +2832,7 @@ pub const TRANSLATE_REGION_REQUEST: u8 = 17;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct TranslateRegionRequest {
     pub region: Region,
     pub dx: i16,
@@ -2160,6 +2892,39 @@ impl Request for TranslateRegionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let region_bytes = self.region.serialize();
+        let dx_bytes = self.dx.serialize();
+        let dy_bytes = self.dy.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            TRANSLATE_REGION_REQUEST,
+            0,
+            0,
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+            dx_bytes[0],
+            dx_bytes[1],
+            dy_bytes[0],
+            dy_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for TranslateRegionRequest {
 }
@@ -2169,6 +2934,7 @@ pub const REGION_EXTENTS_REQUEST: u8 = 18;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct RegionExtentsRequest {
     pub source: Region,
     pub destination: Region,
@@ -2224,6 +2990,38 @@ impl Request for RegionExtentsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let source_bytes = self.source.serialize();
+        let destination_bytes = self.destination.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            REGION_EXTENTS_REQUEST,
+            0,
+            0,
+            source_bytes[0],
+            source_bytes[1],
+            source_bytes[2],
+            source_bytes[3],
+            destination_bytes[0],
+            destination_bytes[1],
+            destination_bytes[2],
+            destination_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for RegionExtentsRequest {
 }
@@ -2233,6 +3031,7 @@ pub const FETCH_REGION_REQUEST: u8 = 19;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct FetchRegionRequest {
     pub region: Region,
 }
@@ -2280,6 +3079,33 @@ impl Request for FetchRegionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let region_bytes = self.region.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            FETCH_REGION_REQUEST,
+            0,
+            0,
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for FetchRegionRequest {
     type Reply = FetchRegionReply;
@@ -2294,6 +3120,12 @@ pub struct FetchRegionReply {
     pub rectangles: Vec<xproto::Rectangle>,
 }
 impl_debug_if_no_extra_traits!(FetchRegionReply, "FetchRegionReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for FetchRegionReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for FetchRegionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2332,6 +3164,7 @@ impl Serialize for FetchRegionReply {
         self.extents.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 16]);
         self.rectangles.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl FetchRegionReply {
@@ -2356,6 +3189,7 @@ pub const SET_GC_CLIP_REGION_REQUEST: u8 = 20;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetGCClipRegionRequest {
     pub gc: xproto::Gcontext,
     pub region: Region,
@@ -2423,6 +3257,44 @@ impl Request for SetGCClipRegionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let gc_bytes = self.gc.serialize();
+        let region_bytes = self.region.serialize();
+        let x_origin_bytes = self.x_origin.serialize();
+        let y_origin_bytes = self.y_origin.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_GC_CLIP_REGION_REQUEST,
+            0,
+            0,
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+            x_origin_bytes[0],
+            x_origin_bytes[1],
+            y_origin_bytes[0],
+            y_origin_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SetGCClipRegionRequest {
 }
@@ -2432,6 +3304,7 @@ pub const SET_WINDOW_SHAPE_REGION_REQUEST: u8 = 21;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetWindowShapeRegionRequest {
     pub dest: xproto::Window,
     pub dest_kind: shape::SK,
@@ -2509,6 +3382,49 @@ impl Request for SetWindowShapeRegionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let dest_bytes = self.dest.serialize();
+        let dest_kind_bytes = shape::Kind::from(self.dest_kind).serialize();
+        let x_offset_bytes = self.x_offset.serialize();
+        let y_offset_bytes = self.y_offset.serialize();
+        let region_bytes = self.region.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_WINDOW_SHAPE_REGION_REQUEST,
+            0,
+            0,
+            dest_bytes[0],
+            dest_bytes[1],
+            dest_bytes[2],
+            dest_bytes[3],
+            dest_kind_bytes[0],
+            0,
+            0,
+            0,
+            x_offset_bytes[0],
+            x_offset_bytes[1],
+            y_offset_bytes[0],
+            y_offset_bytes[1],
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SetWindowShapeRegionRequest {
 }
@@ -2518,6 +3434,7 @@ pub const SET_PICTURE_CLIP_REGION_REQUEST: u8 = 22;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetPictureClipRegionRequest {
     pub picture: render::Picture,
     pub region: Region,
@@ -2575,15 +3492,53 @@ impl SetPictureClipRegionRequest {
             y_origin,
         })
     }
-}
-impl Request for SetPictureClipRegionRequest {
-    const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
-
-    fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
+}
+impl Request for SetPictureClipRegionRequest {
+    const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+
+    fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
+        let (bufs, fds) = self.serialize(major_opcode);
+        // Flatten the buffers into a single vector
+        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
+        (buf, fds)
+    }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let picture_bytes = self.picture.serialize();
+        let region_bytes = self.region.serialize();
+        let x_origin_bytes = self.x_origin.serialize();
+        let y_origin_bytes = self.y_origin.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_PICTURE_CLIP_REGION_REQUEST,
+            0,
+            0,
+            picture_bytes[0],
+            picture_bytes[1],
+            picture_bytes[2],
+            picture_bytes[3],
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+            x_origin_bytes[0],
+            x_origin_bytes[1],
+            y_origin_bytes[0],
+            y_origin_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
         let (bufs, fds) = self.serialize(major_opcode);
-        // Flatten the buffers into a single vector
-        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
-        (buf, fds)
+        slices.extend(bufs);
+        fds
     }
 }
 impl crate::x11_utils::VoidRequest for SetPictureClipRegionRequest {
@@ -2594,6 +3549,7 @@ pub const SET_CURSOR_NAME_REQUEST: u8 = 23;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetCursorNameRequest<'input> {
     pub cursor: xproto::Cursor,
     pub name: Cow<'input, [u8]>,
@@ -2662,6 +3618,42 @@ impl<'input> Request for SetCursorNameRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let cursor_bytes = self.cursor.serialize();
+        let nbytes = u16::try_from(self.name.len()).expect("`name` has too many elements");
+        let nbytes_bytes = nbytes.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_CURSOR_NAME_REQUEST,
+            0,
+            0,
+            cursor_bytes[0],
+            cursor_bytes[1],
+            cursor_bytes[2],
+            cursor_bytes[3],
+            nbytes_bytes[0],
+            nbytes_bytes[1],
+            0,
+            0,
+        ]);
+        buffer.extend_from_slice(&self.name[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for SetCursorNameRequest<'input> {
 }
@@ -2671,6 +3663,7 @@ pub const GET_CURSOR_NAME_REQUEST: u8 = 24;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetCursorNameRequest {
     pub cursor: xproto::Cursor,
 }
@@ -2718,6 +3711,33 @@ impl Request for GetCursorNameRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let cursor_bytes = self.cursor.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_CURSOR_NAME_REQUEST,
+            0,
+            0,
+            cursor_bytes[0],
+            cursor_bytes[1],
+            cursor_bytes[2],
+            cursor_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetCursorNameRequest {
     type Reply = GetCursorNameReply;
@@ -2733,6 +3753,12 @@ pub struct GetCursorNameReply {
     pub name: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(GetCursorNameReply, "GetCursorNameReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetCursorNameReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetCursorNameReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2774,6 +3800,7 @@ impl Serialize for GetCursorNameReply {
         nbytes.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 18]);
         bytes.extend_from_slice(&self.name);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetCursorNameReply {
@@ -2797,6 +3824,7 @@ pub const GET_CURSOR_IMAGE_AND_NAME_REQUEST: u8 = 25;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetCursorImageAndNameRequest;
 impl_debug_if_no_extra_traits!(GetCursorImageAndNameRequest, "GetCursorImageAndNameRequest");
 impl GetCursorImageAndNameRequest {
@@ -2835,6 +3863,28 @@ impl Request for GetCursorImageAndNameRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_CURSOR_IMAGE_AND_NAME_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetCursorImageAndNameRequest {
     type Reply = GetCursorImageAndNameReply;
@@ -2858,6 +3908,12 @@ pub struct GetCursorImageAndNameReply {
     pub name: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(GetCursorImageAndNameReply, "GetCursorImageAndNameReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetCursorImageAndNameReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetCursorImageAndNameReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2916,6 +3972,7 @@ impl Serialize for GetCursorImageAndNameReply {
         assert_eq!(self.cursor_image.len(), usize::try_from(u32::from(self.width).checked_mul(u32::from(self.height)).unwrap()).unwrap(), "`cursor_image` has an incorrect length");
         self.cursor_image.serialize_into(bytes);
         bytes.extend_from_slice(&self.name);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetCursorImageAndNameReply {
@@ -2939,6 +3996,7 @@ pub const CHANGE_CURSOR_REQUEST: u8 = 26;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangeCursorRequest {
     pub source: xproto::Cursor,
     pub destination: xproto::Cursor,
@@ -2994,6 +4052,38 @@ impl Request for ChangeCursorRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let source_bytes = self.source.serialize();
+        let destination_bytes = self.destination.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CHANGE_CURSOR_REQUEST,
+            0,
+            0,
+            source_bytes[0],
+            source_bytes[1],
+            source_bytes[2],
+            source_bytes[3],
+            destination_bytes[0],
+            destination_bytes[1],
+            destination_bytes[2],
+            destination_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for ChangeCursorRequest {
 }
@@ -3003,6 +4093,7 @@ pub const CHANGE_CURSOR_BY_NAME_REQUEST: u8 = 27;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangeCursorByNameRequest<'input> {
     pub src: xproto::Cursor,
     pub name: Cow<'input, [u8]>,
@@ -3071,6 +4162,42 @@ impl<'input> Request for ChangeCursorByNameRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let src_bytes = self.src.serialize();
+        let nbytes = u16::try_from(self.name.len()).expect("`name` has too many elements");
+        let nbytes_bytes = nbytes.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CHANGE_CURSOR_BY_NAME_REQUEST,
+            0,
+            0,
+            src_bytes[0],
+            src_bytes[1],
+            src_bytes[2],
+            src_bytes[3],
+            nbytes_bytes[0],
+            nbytes_bytes[1],
+            0,
+            0,
+        ]);
+        buffer.extend_from_slice(&self.name[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for ChangeCursorByNameRequest<'input> {
 }
@@ -3080,6 +4207,7 @@ pub const EXPAND_REGION_REQUEST: u8 = 28;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ExpandRegionRequest {
     pub source: Region,
     pub destination: Region,
@@ -3159,6 +4287,50 @@ impl Request for ExpandRegionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let source_bytes = self.source.serialize();
+        let destination_bytes = self.destination.serialize();
+        let left_bytes = self.left.serialize();
+        let right_bytes = self.right.serialize();
+        let top_bytes = self.top.serialize();
+        let bottom_bytes = self.bottom.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            EXPAND_REGION_REQUEST,
+            0,
+            0,
+            source_bytes[0],
+            source_bytes[1],
+            source_bytes[2],
+            source_bytes[3],
+            destination_bytes[0],
+            destination_bytes[1],
+            destination_bytes[2],
+            destination_bytes[3],
+            left_bytes[0],
+            left_bytes[1],
+            right_bytes[0],
+            right_bytes[1],
+            top_bytes[0],
+            top_bytes[1],
+            bottom_bytes[0],
+            bottom_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for ExpandRegionRequest {
 }
@@ -3168,6 +4340,7 @@ pub const HIDE_CURSOR_REQUEST: u8 = 29;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct HideCursorRequest {
     pub window: xproto::Window,
 }
@@ -3215,6 +4388,33 @@ impl Request for HideCursorRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            HIDE_CURSOR_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for HideCursorRequest {
 }
@@ -3224,6 +4424,7 @@ pub const SHOW_CURSOR_REQUEST: u8 = 30;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ShowCursorRequest {
     pub window: xproto::Window,
 }
@@ -3271,6 +4472,33 @@ impl Request for ShowCursorRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SHOW_CURSOR_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for ShowCursorRequest {
 }
@@ -3279,6 +4507,7 @@ pub type Barrier = u32;
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct BarrierDirections(u32);
 impl BarrierDirections {
     pub const POSITIVE_X: Self = Self(1 << 0);
@@ -3327,6 +4556,23 @@ impl core::fmt::Debug for BarrierDirections  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl BarrierDirections {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("PositiveX"),
+            2 => Some("PositiveY"),
+            4 => Some("NegativeX"),
+            8 => Some("NegativeY"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for BarrierDirections {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(BarrierDirections, u32);
 
 /// Opcode for the CreatePointerBarrier request
@@ -3334,6 +4580,7 @@ pub const CREATE_POINTER_BARRIER_REQUEST: u8 = 31;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreatePointerBarrierRequest<'input> {
     pub barrier: Barrier,
     pub window: xproto::Window,
@@ -3450,6 +4697,64 @@ impl<'input> Request for CreatePointerBarrierRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let barrier_bytes = self.barrier.serialize();
+        let window_bytes = self.window.serialize();
+        let x1_bytes = self.x1.serialize();
+        let y1_bytes = self.y1.serialize();
+        let x2_bytes = self.x2.serialize();
+        let y2_bytes = self.y2.serialize();
+        let directions_bytes = u32::from(self.directions).serialize();
+        let num_devices = u16::try_from(self.devices.len()).expect("`devices` has too many elements");
+        let num_devices_bytes = num_devices.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CREATE_POINTER_BARRIER_REQUEST,
+            0,
+            0,
+            barrier_bytes[0],
+            barrier_bytes[1],
+            barrier_bytes[2],
+            barrier_bytes[3],
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            x1_bytes[0],
+            x1_bytes[1],
+            y1_bytes[0],
+            y1_bytes[1],
+            x2_bytes[0],
+            x2_bytes[1],
+            y2_bytes[0],
+            y2_bytes[1],
+            directions_bytes[0],
+            directions_bytes[1],
+            directions_bytes[2],
+            directions_bytes[3],
+            0,
+            0,
+            num_devices_bytes[0],
+            num_devices_bytes[1],
+        ]);
+        self.devices.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for CreatePointerBarrierRequest<'input> {
 }
@@ -3459,6 +4764,7 @@ pub const DELETE_POINTER_BARRIER_REQUEST: u8 = 32;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DeletePointerBarrierRequest {
     pub barrier: Barrier,
 }
@@ -3506,6 +4812,33 @@ impl Request for DeletePointerBarrierRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let barrier_bytes = self.barrier.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            DELETE_POINTER_BARRIER_REQUEST,
+            0,
+            0,
+            barrier_bytes[0],
+            barrier_bytes[1],
+            barrier_bytes[2],
+            barrier_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for DeletePointerBarrierRequest {
 }
@@ -3520,6 +4853,7 @@ impl crate::x11_utils::VoidRequest for DeletePointerBarrierRequest {
 ///   even though the client is still connected to the X11 server.
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ClientDisconnectFlags(u32);
 impl ClientDisconnectFlags {
     pub const DEFAULT: Self = Self(0);
@@ -3564,6 +4898,21 @@ impl core::fmt::Debug for ClientDisconnectFlags  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl ClientDisconnectFlags {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Default"),
+            1 => Some("Terminate"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ClientDisconnectFlags {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(ClientDisconnectFlags, u32);
 
 /// Opcode for the SetClientDisconnectMode request
@@ -3576,7 +4925,9 @@ pub const SET_CLIENT_DISCONNECT_MODE_REQUEST: u8 = 33;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetClientDisconnectModeRequest {
+    /// The new disconnect mode.
     pub disconnect_mode: ClientDisconnectFlags,
 }
 impl_debug_if_no_extra_traits!(SetClientDisconnectModeRequest, "SetClientDisconnectModeRequest");
@@ -3624,6 +4975,33 @@ impl Request for SetClientDisconnectModeRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let disconnect_mode_bytes = u32::from(self.disconnect_mode).serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_CLIENT_DISCONNECT_MODE_REQUEST,
+            0,
+            0,
+            disconnect_mode_bytes[0],
+            disconnect_mode_bytes[1],
+            disconnect_mode_bytes[2],
+            disconnect_mode_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SetClientDisconnectModeRequest {
 }
@@ -3633,6 +5011,7 @@ pub const GET_CLIENT_DISCONNECT_MODE_REQUEST: u8 = 34;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetClientDisconnectModeRequest;
 impl_debug_if_no_extra_traits!(GetClientDisconnectModeRequest, "GetClientDisconnectModeRequest");
 impl GetClientDisconnectModeRequest {
@@ -3671,6 +5050,28 @@ impl Request for GetClientDisconnectModeRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_CLIENT_DISCONNECT_MODE_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetClientDisconnectModeRequest {
     type Reply = GetClientDisconnectModeReply;
@@ -3687,9 +5088,16 @@ impl crate::x11_utils::ReplyRequest for GetClientDisconnectModeRequest {
 pub struct GetClientDisconnectModeReply {
     pub sequence: u16,
     pub length: u32,
+    /// The current disconnect mode.
     pub disconnect_mode: ClientDisconnectFlags,
 }
 impl_debug_if_no_extra_traits!(GetClientDisconnectModeReply, "GetClientDisconnectModeReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetClientDisconnectModeReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetClientDisconnectModeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -3764,3 +5172,42 @@ impl Serialize for GetClientDisconnectModeReply {
     }
 }
 
+/// All requests of this module.
+pub const REQUESTS: &[RequestDescription] = &[
+    RequestDescription { opcode: QUERY_VERSION_REQUEST, name: "QueryVersion", has_reply: true },
+    RequestDescription { opcode: CHANGE_SAVE_SET_REQUEST, name: "ChangeSaveSet", has_reply: false },
+    RequestDescription { opcode: SELECT_SELECTION_INPUT_REQUEST, name: "SelectSelectionInput", has_reply: false },
+    RequestDescription { opcode: SELECT_CURSOR_INPUT_REQUEST, name: "SelectCursorInput", has_reply: false },
+    RequestDescription { opcode: GET_CURSOR_IMAGE_REQUEST, name: "GetCursorImage", has_reply: true },
+    RequestDescription { opcode: CREATE_REGION_REQUEST, name: "CreateRegion", has_reply: false },
+    RequestDescription { opcode: CREATE_REGION_FROM_BITMAP_REQUEST, name: "CreateRegionFromBitmap", has_reply: false },
+    RequestDescription { opcode: CREATE_REGION_FROM_WINDOW_REQUEST, name: "CreateRegionFromWindow", has_reply: false },
+    RequestDescription { opcode: CREATE_REGION_FROM_GC_REQUEST, name: "CreateRegionFromGC", has_reply: false },
+    RequestDescription { opcode: CREATE_REGION_FROM_PICTURE_REQUEST, name: "CreateRegionFromPicture", has_reply: false },
+    RequestDescription { opcode: DESTROY_REGION_REQUEST, name: "DestroyRegion", has_reply: false },
+    RequestDescription { opcode: SET_REGION_REQUEST, name: "SetRegion", has_reply: false },
+    RequestDescription { opcode: COPY_REGION_REQUEST, name: "CopyRegion", has_reply: false },
+    RequestDescription { opcode: UNION_REGION_REQUEST, name: "UnionRegion", has_reply: false },
+    RequestDescription { opcode: INTERSECT_REGION_REQUEST, name: "IntersectRegion", has_reply: false },
+    RequestDescription { opcode: SUBTRACT_REGION_REQUEST, name: "SubtractRegion", has_reply: false },
+    RequestDescription { opcode: INVERT_REGION_REQUEST, name: "InvertRegion", has_reply: false },
+    RequestDescription { opcode: TRANSLATE_REGION_REQUEST, name: "TranslateRegion", has_reply: false },
+    RequestDescription { opcode: REGION_EXTENTS_REQUEST, name: "RegionExtents", has_reply: false },
+    RequestDescription { opcode: FETCH_REGION_REQUEST, name: "FetchRegion", has_reply: true },
+    RequestDescription { opcode: SET_GC_CLIP_REGION_REQUEST, name: "SetGCClipRegion", has_reply: false },
+    RequestDescription { opcode: SET_WINDOW_SHAPE_REGION_REQUEST, name: "SetWindowShapeRegion", has_reply: false },
+    RequestDescription { opcode: SET_PICTURE_CLIP_REGION_REQUEST, name: "SetPictureClipRegion", has_reply: false },
+    RequestDescription { opcode: SET_CURSOR_NAME_REQUEST, name: "SetCursorName", has_reply: false },
+    RequestDescription { opcode: GET_CURSOR_NAME_REQUEST, name: "GetCursorName", has_reply: true },
+    RequestDescription { opcode: GET_CURSOR_IMAGE_AND_NAME_REQUEST, name: "GetCursorImageAndName", has_reply: true },
+    RequestDescription { opcode: CHANGE_CURSOR_REQUEST, name: "ChangeCursor", has_reply: false },
+    RequestDescription { opcode: CHANGE_CURSOR_BY_NAME_REQUEST, name: "ChangeCursorByName", has_reply: false },
+    RequestDescription { opcode: EXPAND_REGION_REQUEST, name: "ExpandRegion", has_reply: false },
+    RequestDescription { opcode: HIDE_CURSOR_REQUEST, name: "HideCursor", has_reply: false },
+    RequestDescription { opcode: SHOW_CURSOR_REQUEST, name: "ShowCursor", has_reply: false },
+    RequestDescription { opcode: CREATE_POINTER_BARRIER_REQUEST, name: "CreatePointerBarrier", has_reply: false },
+    RequestDescription { opcode: DELETE_POINTER_BARRIER_REQUEST, name: "DeletePointerBarrier", has_reply: false },
+    RequestDescription { opcode: SET_CLIENT_DISCONNECT_MODE_REQUEST, name: "SetClientDisconnectMode", has_reply: false },
+    RequestDescription { opcode: GET_CLIENT_DISCONNECT_MODE_REQUEST, name: "GetClientDisconnectMode", has_reply: true },
+];
+
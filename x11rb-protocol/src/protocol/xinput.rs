@@ -22,6 +22,7 @@ use crate::BufWithFds;
 use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::x11_utils::RequestDescription;
 #[allow(unused_imports)]
 use super::xfixes;
 #[allow(unused_imports)]
@@ -54,6 +55,12 @@ pub struct Fp3232 {
     pub frac: u32,
 }
 impl_debug_if_no_extra_traits!(Fp3232, "Fp3232");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Fp3232 {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Fp3232 {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (integral, remaining) = i32::try_parse(remaining)?;
@@ -90,6 +97,7 @@ pub const GET_EXTENSION_VERSION_REQUEST: u8 = 1;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetExtensionVersionRequest<'input> {
     pub name: Cow<'input, [u8]>,
 }
@@ -149,6 +157,37 @@ impl<'input> Request for GetExtensionVersionRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let name_len = u16::try_from(self.name.len()).expect("`name` has too many elements");
+        let name_len_bytes = name_len.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_EXTENSION_VERSION_REQUEST,
+            0,
+            0,
+            name_len_bytes[0],
+            name_len_bytes[1],
+            0,
+            0,
+        ]);
+        buffer.extend_from_slice(&self.name[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::ReplyRequest for GetExtensionVersionRequest<'input> {
     type Reply = GetExtensionVersionReply;
@@ -166,6 +205,12 @@ pub struct GetExtensionVersionReply {
     pub present: bool,
 }
 impl_debug_if_no_extra_traits!(GetExtensionVersionReply, "GetExtensionVersionReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetExtensionVersionReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetExtensionVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -248,6 +293,7 @@ impl Serialize for GetExtensionVersionReply {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DeviceUse(u8);
 impl DeviceUse {
     pub const IS_X_POINTER: Self = Self(0);
@@ -310,9 +356,28 @@ impl core::fmt::Debug for DeviceUse  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl DeviceUse {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("IsXPointer"),
+            1 => Some("IsXKeyboard"),
+            2 => Some("IsXExtensionDevice"),
+            3 => Some("IsXExtensionKeyboard"),
+            4 => Some("IsXExtensionPointer"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for DeviceUse {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct InputClass(u8);
 impl InputClass {
     pub const KEY: Self = Self(0);
@@ -379,9 +444,30 @@ impl core::fmt::Debug for InputClass  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl InputClass {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Key"),
+            1 => Some("Button"),
+            2 => Some("Valuator"),
+            3 => Some("Feedback"),
+            4 => Some("Proximity"),
+            5 => Some("Focus"),
+            6 => Some("Other"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for InputClass {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ValuatorMode(u8);
 impl ValuatorMode {
     pub const RELATIVE: Self = Self(0);
@@ -438,6 +524,21 @@ impl core::fmt::Debug for ValuatorMode  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl ValuatorMode {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Relative"),
+            1 => Some("Absolute"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ValuatorMode {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
@@ -449,6 +550,12 @@ pub struct DeviceInfo {
     pub device_use: DeviceUse,
 }
 impl_debug_if_no_extra_traits!(DeviceInfo, "DeviceInfo");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceInfo {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for DeviceInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (device_type, remaining) = xproto::Atom::try_parse(remaining)?;
@@ -500,6 +607,12 @@ pub struct KeyInfo {
     pub num_keys: u16,
 }
 impl_debug_if_no_extra_traits!(KeyInfo, "KeyInfo");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for KeyInfo {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for KeyInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (class_id, remaining) = u8::try_parse(remaining)?;
@@ -552,6 +665,12 @@ pub struct ButtonInfo {
     pub num_buttons: u16,
 }
 impl_debug_if_no_extra_traits!(ButtonInfo, "ButtonInfo");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ButtonInfo {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for ButtonInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (class_id, remaining) = u8::try_parse(remaining)?;
@@ -592,6 +711,12 @@ pub struct AxisInfo {
     pub maximum: i32,
 }
 impl_debug_if_no_extra_traits!(AxisInfo, "AxisInfo");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for AxisInfo {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for AxisInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (resolution, remaining) = u32::try_parse(remaining)?;
@@ -641,6 +766,12 @@ pub struct ValuatorInfo {
     pub axes: Vec<AxisInfo>,
 }
 impl_debug_if_no_extra_traits!(ValuatorInfo, "ValuatorInfo");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ValuatorInfo {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for ValuatorInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (class_id, remaining) = u8::try_parse(remaining)?;
@@ -698,6 +829,12 @@ pub struct InputInfoInfoKey {
     pub num_keys: u16,
 }
 impl_debug_if_no_extra_traits!(InputInfoInfoKey, "InputInfoInfoKey");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for InputInfoInfoKey {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for InputInfoInfoKey {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (min_keycode, remaining) = KeyCode::try_parse(remaining)?;
@@ -738,6 +875,12 @@ pub struct InputInfoInfoButton {
     pub num_buttons: u16,
 }
 impl_debug_if_no_extra_traits!(InputInfoInfoButton, "InputInfoInfoButton");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for InputInfoInfoButton {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for InputInfoInfoButton {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (num_buttons, remaining) = u16::try_parse(remaining)?;
@@ -768,6 +911,12 @@ pub struct InputInfoInfoValuator {
     pub axes: Vec<AxisInfo>,
 }
 impl_debug_if_no_extra_traits!(InputInfoInfoValuator, "InputInfoInfoValuator");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for InputInfoInfoValuator {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for InputInfoInfoValuator {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (axes_len, remaining) = u8::try_parse(remaining)?;
@@ -813,6 +962,7 @@ impl InputInfoInfoValuator {
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum InputInfoInfo {
     Key(InputInfoInfoKey),
     Button(InputInfoInfoButton),
@@ -905,6 +1055,18 @@ impl InputInfoInfo {
         }
     }
 }
+#[cfg(feature = "arbitrary")]
+impl InputInfoInfo {
+    /// Whether this value can be serialized, i.e. does not contain an `InvalidValue`.
+    pub(crate) fn is_encodable(&self) -> bool {
+        match self {
+            InputInfoInfo::Key(_) => true,
+            InputInfoInfo::Button(_) => true,
+            InputInfoInfo::Valuator(_) => true,
+            InputInfoInfo::InvalidValue(_) => false,
+        }
+    }
+}
 
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
@@ -914,6 +1076,24 @@ pub struct InputInfo {
     pub info: InputInfoInfo,
 }
 impl_debug_if_no_extra_traits!(InputInfo, "InputInfo");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for InputInfo {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let (value, size): (Self, usize) = crate::x11_utils::arbitrary_parse(u)?;
+        if !value.is_encodable() {
+            return Err(arbitrary::Error::IncorrectFormat);
+        }
+        crate::x11_utils::arbitrary_check_size(&value, size, 0)?;
+        Ok(value)
+    }
+}
+#[cfg(feature = "arbitrary")]
+impl InputInfo {
+    /// Whether this value can be serialized, i.e. does not contain an `InvalidValue`.
+    pub(crate) fn is_encodable(&self) -> bool {
+        self.info.is_encodable()
+    }
+}
 impl TryParse for InputInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (class_id, remaining) = u8::try_parse(remaining)?;
@@ -946,6 +1126,12 @@ pub struct DeviceName {
     pub string: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(DeviceName, "DeviceName");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceName {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for DeviceName {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (len, remaining) = u8::try_parse(remaining)?;
@@ -990,6 +1176,7 @@ pub const LIST_INPUT_DEVICES_REQUEST: u8 = 2;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ListInputDevicesRequest;
 impl_debug_if_no_extra_traits!(ListInputDevicesRequest, "ListInputDevicesRequest");
 impl ListInputDevicesRequest {
@@ -1028,6 +1215,28 @@ impl Request for ListInputDevicesRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            LIST_INPUT_DEVICES_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for ListInputDevicesRequest {
     type Reply = ListInputDevicesReply;
@@ -1045,6 +1254,24 @@ pub struct ListInputDevicesReply {
     pub names: Vec<xproto::Str>,
 }
 impl_debug_if_no_extra_traits!(ListInputDevicesReply, "ListInputDevicesReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ListInputDevicesReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let (value, size): (Self, usize) = crate::x11_utils::arbitrary_parse(u)?;
+        if !value.is_encodable() {
+            return Err(arbitrary::Error::IncorrectFormat);
+        }
+        crate::x11_utils::arbitrary_check_size(&value, size, 32)?;
+        Ok(value)
+    }
+}
+#[cfg(feature = "arbitrary")]
+impl ListInputDevicesReply {
+    /// Whether this value can be serialized, i.e. does not contain an `InvalidValue`.
+    pub(crate) fn is_encodable(&self) -> bool {
+        self.infos.iter().all(|item| item.is_encodable())
+    }
+}
 impl TryParse for ListInputDevicesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1095,6 +1322,7 @@ impl Serialize for ListInputDevicesReply {
         assert_eq!(self.names.len(), usize::try_from(devices_len).unwrap(), "`names` has an incorrect length");
         self.names.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl ListInputDevicesReply {
@@ -1123,6 +1351,12 @@ pub struct InputClassInfo {
     pub event_type_base: EventTypeBase,
 }
 impl_debug_if_no_extra_traits!(InputClassInfo, "InputClassInfo");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for InputClassInfo {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for InputClassInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (class_id, remaining) = u8::try_parse(remaining)?;
@@ -1154,6 +1388,7 @@ pub const OPEN_DEVICE_REQUEST: u8 = 3;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct OpenDeviceRequest {
     pub device_id: u8,
 }
@@ -1202,6 +1437,33 @@ impl Request for OpenDeviceRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_id_bytes = self.device_id.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            OPEN_DEVICE_REQUEST,
+            0,
+            0,
+            device_id_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for OpenDeviceRequest {
     type Reply = OpenDeviceReply;
@@ -1217,6 +1479,12 @@ pub struct OpenDeviceReply {
     pub class_info: Vec<InputClassInfo>,
 }
 impl_debug_if_no_extra_traits!(OpenDeviceReply, "OpenDeviceReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for OpenDeviceReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for OpenDeviceReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1261,6 +1529,7 @@ impl Serialize for OpenDeviceReply {
         bytes.extend_from_slice(&[0; 23]);
         self.class_info.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl OpenDeviceReply {
@@ -1284,6 +1553,7 @@ pub const CLOSE_DEVICE_REQUEST: u8 = 4;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CloseDeviceRequest {
     pub device_id: u8,
 }
@@ -1332,6 +1602,33 @@ impl Request for CloseDeviceRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_id_bytes = self.device_id.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CLOSE_DEVICE_REQUEST,
+            0,
+            0,
+            device_id_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for CloseDeviceRequest {
 }
@@ -1341,6 +1638,7 @@ pub const SET_DEVICE_MODE_REQUEST: u8 = 5;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetDeviceModeRequest {
     pub device_id: u8,
     pub mode: ValuatorMode,
@@ -1394,6 +1692,34 @@ impl Request for SetDeviceModeRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_id_bytes = self.device_id.serialize();
+        let mode_bytes = u8::from(self.mode).serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_DEVICE_MODE_REQUEST,
+            0,
+            0,
+            device_id_bytes[0],
+            mode_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for SetDeviceModeRequest {
     type Reply = SetDeviceModeReply;
@@ -1409,6 +1735,12 @@ pub struct SetDeviceModeReply {
     pub status: xproto::GrabStatus,
 }
 impl_debug_if_no_extra_traits!(SetDeviceModeReply, "SetDeviceModeReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SetDeviceModeReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for SetDeviceModeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1489,6 +1821,7 @@ pub const SELECT_EXTENSION_EVENT_REQUEST: u8 = 6;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SelectExtensionEventRequest<'input> {
     pub window: xproto::Window,
     pub classes: Cow<'input, [EventClass]>,
@@ -1558,6 +1891,42 @@ impl<'input> Request for SelectExtensionEventRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let num_classes = u16::try_from(self.classes.len()).expect("`classes` has too many elements");
+        let num_classes_bytes = num_classes.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SELECT_EXTENSION_EVENT_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            num_classes_bytes[0],
+            num_classes_bytes[1],
+            0,
+            0,
+        ]);
+        self.classes.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for SelectExtensionEventRequest<'input> {
 }
@@ -1567,6 +1936,7 @@ pub const GET_SELECTED_EXTENSION_EVENTS_REQUEST: u8 = 7;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetSelectedExtensionEventsRequest {
     pub window: xproto::Window,
 }
@@ -1614,6 +1984,33 @@ impl Request for GetSelectedExtensionEventsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_SELECTED_EXTENSION_EVENTS_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetSelectedExtensionEventsRequest {
     type Reply = GetSelectedExtensionEventsReply;
@@ -1630,6 +2027,12 @@ pub struct GetSelectedExtensionEventsReply {
     pub all_classes: Vec<EventClass>,
 }
 impl_debug_if_no_extra_traits!(GetSelectedExtensionEventsReply, "GetSelectedExtensionEventsReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetSelectedExtensionEventsReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetSelectedExtensionEventsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1673,6 +2076,7 @@ impl Serialize for GetSelectedExtensionEventsReply {
         bytes.extend_from_slice(&[0; 20]);
         self.this_classes.serialize_into(bytes);
         self.all_classes.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetSelectedExtensionEventsReply {
@@ -1706,6 +2110,7 @@ impl GetSelectedExtensionEventsReply {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PropagateMode(u8);
 impl PropagateMode {
     pub const ADD_TO_LIST: Self = Self(0);
@@ -1762,12 +2167,28 @@ impl core::fmt::Debug for PropagateMode  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl PropagateMode {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("AddToList"),
+            1 => Some("DeleteFromList"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for PropagateMode {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the ChangeDeviceDontPropagateList request
 pub const CHANGE_DEVICE_DONT_PROPAGATE_LIST_REQUEST: u8 = 8;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangeDeviceDontPropagateListRequest<'input> {
     pub window: xproto::Window,
     pub mode: PropagateMode,
@@ -1843,6 +2264,43 @@ impl<'input> Request for ChangeDeviceDontPropagateListRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let num_classes = u16::try_from(self.classes.len()).expect("`classes` has too many elements");
+        let num_classes_bytes = num_classes.serialize();
+        let mode_bytes = u8::from(self.mode).serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CHANGE_DEVICE_DONT_PROPAGATE_LIST_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            num_classes_bytes[0],
+            num_classes_bytes[1],
+            mode_bytes[0],
+            0,
+        ]);
+        self.classes.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for ChangeDeviceDontPropagateListRequest<'input> {
 }
@@ -1852,6 +2310,7 @@ pub const GET_DEVICE_DONT_PROPAGATE_LIST_REQUEST: u8 = 9;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetDeviceDontPropagateListRequest {
     pub window: xproto::Window,
 }
@@ -1899,6 +2358,33 @@ impl Request for GetDeviceDontPropagateListRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_DEVICE_DONT_PROPAGATE_LIST_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetDeviceDontPropagateListRequest {
     type Reply = GetDeviceDontPropagateListReply;
@@ -1914,6 +2400,12 @@ pub struct GetDeviceDontPropagateListReply {
     pub classes: Vec<EventClass>,
 }
 impl_debug_if_no_extra_traits!(GetDeviceDontPropagateListReply, "GetDeviceDontPropagateListReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetDeviceDontPropagateListReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetDeviceDontPropagateListReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1952,6 +2444,7 @@ impl Serialize for GetDeviceDontPropagateListReply {
         num_classes.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 22]);
         self.classes.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetDeviceDontPropagateListReply {
@@ -1973,6 +2466,7 @@ impl GetDeviceDontPropagateListReply {
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DeviceTimeCoord {
     pub time: xproto::Timestamp,
     pub axisvalues: Vec<i32>,
@@ -2005,6 +2499,7 @@ pub const GET_DEVICE_MOTION_EVENTS_REQUEST: u8 = 10;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetDeviceMotionEventsRequest {
     pub start: xproto::Timestamp,
     pub stop: xproto::Timestamp,
@@ -2069,6 +2564,43 @@ impl Request for GetDeviceMotionEventsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let start_bytes = self.start.serialize();
+        let stop_bytes = self.stop.serialize();
+        let device_id_bytes = self.device_id.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_DEVICE_MOTION_EVENTS_REQUEST,
+            0,
+            0,
+            start_bytes[0],
+            start_bytes[1],
+            start_bytes[2],
+            start_bytes[3],
+            stop_bytes[0],
+            stop_bytes[1],
+            stop_bytes[2],
+            stop_bytes[3],
+            device_id_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetDeviceMotionEventsRequest {
     type Reply = GetDeviceMotionEventsReply;
@@ -2086,6 +2618,12 @@ pub struct GetDeviceMotionEventsReply {
     pub events: Vec<DeviceTimeCoord>,
 }
 impl_debug_if_no_extra_traits!(GetDeviceMotionEventsReply, "GetDeviceMotionEventsReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetDeviceMotionEventsReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetDeviceMotionEventsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2099,7 +2637,7 @@ impl TryParse for GetDeviceMotionEventsReply {
         let remaining = remaining.get(18..).ok_or(ParseError::InsufficientData)?;
         let mut remaining = remaining;
         let list_length = num_events.try_to_usize()?;
-        let mut events = Vec::with_capacity(list_length);
+        let mut events = Vec::with_capacity(list_length.min(remaining.len()));
         for _ in 0..list_length {
             let (v, new_remaining) = DeviceTimeCoord::try_parse(remaining, num_axes)?;
             remaining = new_remaining;
@@ -2138,6 +2676,7 @@ impl Serialize for GetDeviceMotionEventsReply {
         for element in self.events.iter() {
             element.serialize_into(bytes, u8::from(self.num_axes));
         }
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetDeviceMotionEventsReply {
@@ -2161,6 +2700,7 @@ pub const CHANGE_KEYBOARD_DEVICE_REQUEST: u8 = 11;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangeKeyboardDeviceRequest {
     pub device_id: u8,
 }
@@ -2209,21 +2749,54 @@ impl Request for ChangeKeyboardDeviceRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
-}
-impl crate::x11_utils::ReplyRequest for ChangeKeyboardDeviceRequest {
-    type Reply = ChangeKeyboardDeviceReply;
-}
-
-#[derive(Clone, Copy, Default)]
-#[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct ChangeKeyboardDeviceReply {
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_id_bytes = self.device_id.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CHANGE_KEYBOARD_DEVICE_REQUEST,
+            0,
+            0,
+            device_id_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
+}
+impl crate::x11_utils::ReplyRequest for ChangeKeyboardDeviceRequest {
+    type Reply = ChangeKeyboardDeviceReply;
+}
+
+#[derive(Clone, Copy, Default)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChangeKeyboardDeviceReply {
     pub xi_reply_type: u8,
     pub sequence: u16,
     pub length: u32,
     pub status: xproto::GrabStatus,
 }
 impl_debug_if_no_extra_traits!(ChangeKeyboardDeviceReply, "ChangeKeyboardDeviceReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ChangeKeyboardDeviceReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ChangeKeyboardDeviceReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2304,6 +2877,7 @@ pub const CHANGE_POINTER_DEVICE_REQUEST: u8 = 12;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangePointerDeviceRequest {
     pub x_axis: u8,
     pub y_axis: u8,
@@ -2360,6 +2934,35 @@ impl Request for ChangePointerDeviceRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let x_axis_bytes = self.x_axis.serialize();
+        let y_axis_bytes = self.y_axis.serialize();
+        let device_id_bytes = self.device_id.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CHANGE_POINTER_DEVICE_REQUEST,
+            0,
+            0,
+            x_axis_bytes[0],
+            y_axis_bytes[0],
+            device_id_bytes[0],
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for ChangePointerDeviceRequest {
     type Reply = ChangePointerDeviceReply;
@@ -2375,6 +2978,12 @@ pub struct ChangePointerDeviceReply {
     pub status: xproto::GrabStatus,
 }
 impl_debug_if_no_extra_traits!(ChangePointerDeviceReply, "ChangePointerDeviceReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ChangePointerDeviceReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ChangePointerDeviceReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2455,6 +3064,7 @@ pub const GRAB_DEVICE_REQUEST: u8 = 13;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GrabDeviceRequest<'input> {
     pub grab_window: xproto::Window,
     pub time: xproto::Timestamp,
@@ -2559,6 +3169,55 @@ impl<'input> Request for GrabDeviceRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let grab_window_bytes = self.grab_window.serialize();
+        let time_bytes = self.time.serialize();
+        let num_classes = u16::try_from(self.classes.len()).expect("`classes` has too many elements");
+        let num_classes_bytes = num_classes.serialize();
+        let this_device_mode_bytes = u8::from(self.this_device_mode).serialize();
+        let other_device_mode_bytes = u8::from(self.other_device_mode).serialize();
+        let owner_events_bytes = self.owner_events.serialize();
+        let device_id_bytes = self.device_id.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GRAB_DEVICE_REQUEST,
+            0,
+            0,
+            grab_window_bytes[0],
+            grab_window_bytes[1],
+            grab_window_bytes[2],
+            grab_window_bytes[3],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            num_classes_bytes[0],
+            num_classes_bytes[1],
+            this_device_mode_bytes[0],
+            other_device_mode_bytes[0],
+            owner_events_bytes[0],
+            device_id_bytes[0],
+            0,
+            0,
+        ]);
+        self.classes.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::ReplyRequest for GrabDeviceRequest<'input> {
     type Reply = GrabDeviceReply;
@@ -2574,6 +3233,12 @@ pub struct GrabDeviceReply {
     pub status: xproto::GrabStatus,
 }
 impl_debug_if_no_extra_traits!(GrabDeviceReply, "GrabDeviceReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GrabDeviceReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GrabDeviceReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2654,6 +3319,7 @@ pub const UNGRAB_DEVICE_REQUEST: u8 = 14;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct UngrabDeviceRequest {
     pub time: xproto::Timestamp,
     pub device_id: u8,
@@ -2710,12 +3376,45 @@ impl Request for UngrabDeviceRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let time_bytes = self.time.serialize();
+        let device_id_bytes = self.device_id.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            UNGRAB_DEVICE_REQUEST,
+            0,
+            0,
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            device_id_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for UngrabDeviceRequest {
 }
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ModifierDevice(u8);
 impl ModifierDevice {
     pub const USE_X_KEYBOARD: Self = Self(255);
@@ -2770,12 +3469,27 @@ impl core::fmt::Debug for ModifierDevice  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl ModifierDevice {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            255 => Some("UseXKeyboard"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ModifierDevice {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the GrabDeviceKey request
 pub const GRAB_DEVICE_KEY_REQUEST: u8 = 15;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GrabDeviceKeyRequest<'input> {
     pub grab_window: xproto::Window,
     pub modifiers: xproto::ModMask,
@@ -2891,6 +3605,57 @@ impl<'input> Request for GrabDeviceKeyRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let grab_window_bytes = self.grab_window.serialize();
+        let num_classes = u16::try_from(self.classes.len()).expect("`classes` has too many elements");
+        let num_classes_bytes = num_classes.serialize();
+        let modifiers_bytes = u16::from(self.modifiers).serialize();
+        let modifier_device_bytes = self.modifier_device.serialize();
+        let grabbed_device_bytes = self.grabbed_device.serialize();
+        let key_bytes = self.key.serialize();
+        let this_device_mode_bytes = u8::from(self.this_device_mode).serialize();
+        let other_device_mode_bytes = u8::from(self.other_device_mode).serialize();
+        let owner_events_bytes = self.owner_events.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GRAB_DEVICE_KEY_REQUEST,
+            0,
+            0,
+            grab_window_bytes[0],
+            grab_window_bytes[1],
+            grab_window_bytes[2],
+            grab_window_bytes[3],
+            num_classes_bytes[0],
+            num_classes_bytes[1],
+            modifiers_bytes[0],
+            modifiers_bytes[1],
+            modifier_device_bytes[0],
+            grabbed_device_bytes[0],
+            key_bytes[0],
+            this_device_mode_bytes[0],
+            other_device_mode_bytes[0],
+            owner_events_bytes[0],
+            0,
+            0,
+        ]);
+        self.classes.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for GrabDeviceKeyRequest<'input> {
 }
@@ -2900,6 +3665,7 @@ pub const UNGRAB_DEVICE_KEY_REQUEST: u8 = 16;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct UngrabDeviceKeyRequest {
     pub grab_window: xproto::Window,
     pub modifiers: xproto::ModMask,
@@ -2972,6 +3738,45 @@ impl Request for UngrabDeviceKeyRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let grab_window_bytes = self.grab_window.serialize();
+        let modifiers_bytes = u16::from(self.modifiers).serialize();
+        let modifier_device_bytes = self.modifier_device.serialize();
+        let key_bytes = self.key.serialize();
+        let grabbed_device_bytes = self.grabbed_device.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            UNGRAB_DEVICE_KEY_REQUEST,
+            0,
+            0,
+            grab_window_bytes[0],
+            grab_window_bytes[1],
+            grab_window_bytes[2],
+            grab_window_bytes[3],
+            modifiers_bytes[0],
+            modifiers_bytes[1],
+            modifier_device_bytes[0],
+            key_bytes[0],
+            grabbed_device_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for UngrabDeviceKeyRequest {
 }
@@ -2981,6 +3786,7 @@ pub const GRAB_DEVICE_BUTTON_REQUEST: u8 = 17;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GrabDeviceButtonRequest<'input> {
     pub grab_window: xproto::Window,
     pub grabbed_device: u8,
@@ -3096,6 +3902,57 @@ impl<'input> Request for GrabDeviceButtonRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let grab_window_bytes = self.grab_window.serialize();
+        let grabbed_device_bytes = self.grabbed_device.serialize();
+        let modifier_device_bytes = self.modifier_device.serialize();
+        let num_classes = u16::try_from(self.classes.len()).expect("`classes` has too many elements");
+        let num_classes_bytes = num_classes.serialize();
+        let modifiers_bytes = u16::from(self.modifiers).serialize();
+        let this_device_mode_bytes = u8::from(self.this_device_mode).serialize();
+        let other_device_mode_bytes = u8::from(self.other_device_mode).serialize();
+        let button_bytes = self.button.serialize();
+        let owner_events_bytes = self.owner_events.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GRAB_DEVICE_BUTTON_REQUEST,
+            0,
+            0,
+            grab_window_bytes[0],
+            grab_window_bytes[1],
+            grab_window_bytes[2],
+            grab_window_bytes[3],
+            grabbed_device_bytes[0],
+            modifier_device_bytes[0],
+            num_classes_bytes[0],
+            num_classes_bytes[1],
+            modifiers_bytes[0],
+            modifiers_bytes[1],
+            this_device_mode_bytes[0],
+            other_device_mode_bytes[0],
+            button_bytes[0],
+            owner_events_bytes[0],
+            0,
+            0,
+        ]);
+        self.classes.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for GrabDeviceButtonRequest<'input> {
 }
@@ -3105,6 +3962,7 @@ pub const UNGRAB_DEVICE_BUTTON_REQUEST: u8 = 18;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct UngrabDeviceButtonRequest {
     pub grab_window: xproto::Window,
     pub modifiers: xproto::ModMask,
@@ -3178,12 +4036,52 @@ impl Request for UngrabDeviceButtonRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let grab_window_bytes = self.grab_window.serialize();
+        let modifiers_bytes = u16::from(self.modifiers).serialize();
+        let modifier_device_bytes = self.modifier_device.serialize();
+        let button_bytes = self.button.serialize();
+        let grabbed_device_bytes = self.grabbed_device.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            UNGRAB_DEVICE_BUTTON_REQUEST,
+            0,
+            0,
+            grab_window_bytes[0],
+            grab_window_bytes[1],
+            grab_window_bytes[2],
+            grab_window_bytes[3],
+            modifiers_bytes[0],
+            modifiers_bytes[1],
+            modifier_device_bytes[0],
+            button_bytes[0],
+            grabbed_device_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for UngrabDeviceButtonRequest {
 }
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DeviceInputMode(u8);
 impl DeviceInputMode {
     pub const ASYNC_THIS_DEVICE: Self = Self(0);
@@ -3248,12 +4146,32 @@ impl core::fmt::Debug for DeviceInputMode  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl DeviceInputMode {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("AsyncThisDevice"),
+            1 => Some("SyncThisDevice"),
+            2 => Some("ReplayThisDevice"),
+            3 => Some("AsyncOtherDevices"),
+            4 => Some("AsyncAll"),
+            5 => Some("SyncAll"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for DeviceInputMode {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the AllowDeviceEvents request
 pub const ALLOW_DEVICE_EVENTS_REQUEST: u8 = 19;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct AllowDeviceEventsRequest {
     pub time: xproto::Timestamp,
     pub mode: DeviceInputMode,
@@ -3315,6 +4233,39 @@ impl Request for AllowDeviceEventsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let time_bytes = self.time.serialize();
+        let mode_bytes = u8::from(self.mode).serialize();
+        let device_id_bytes = self.device_id.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            ALLOW_DEVICE_EVENTS_REQUEST,
+            0,
+            0,
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            mode_bytes[0],
+            device_id_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for AllowDeviceEventsRequest {
 }
@@ -3324,6 +4275,7 @@ pub const GET_DEVICE_FOCUS_REQUEST: u8 = 20;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetDeviceFocusRequest {
     pub device_id: u8,
 }
@@ -3372,6 +4324,33 @@ impl Request for GetDeviceFocusRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_id_bytes = self.device_id.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_DEVICE_FOCUS_REQUEST,
+            0,
+            0,
+            device_id_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetDeviceFocusRequest {
     type Reply = GetDeviceFocusReply;
@@ -3389,6 +4368,12 @@ pub struct GetDeviceFocusReply {
     pub revert_to: xproto::InputFocus,
 }
 impl_debug_if_no_extra_traits!(GetDeviceFocusReply, "GetDeviceFocusReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetDeviceFocusReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetDeviceFocusReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -3475,6 +4460,7 @@ pub const SET_DEVICE_FOCUS_REQUEST: u8 = 21;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetDeviceFocusRequest {
     pub focus: xproto::Window,
     pub time: xproto::Timestamp,
@@ -3534,15 +4520,53 @@ impl SetDeviceFocusRequest {
             device_id,
         })
     }
-}
-impl Request for SetDeviceFocusRequest {
-    const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
-
-    fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
+}
+impl Request for SetDeviceFocusRequest {
+    const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+
+    fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
+        let (bufs, fds) = self.serialize(major_opcode);
+        // Flatten the buffers into a single vector
+        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
+        (buf, fds)
+    }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let focus_bytes = self.focus.serialize();
+        let time_bytes = self.time.serialize();
+        let revert_to_bytes = u8::from(self.revert_to).serialize();
+        let device_id_bytes = self.device_id.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_DEVICE_FOCUS_REQUEST,
+            0,
+            0,
+            focus_bytes[0],
+            focus_bytes[1],
+            focus_bytes[2],
+            focus_bytes[3],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            revert_to_bytes[0],
+            device_id_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
         let (bufs, fds) = self.serialize(major_opcode);
-        // Flatten the buffers into a single vector
-        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
-        (buf, fds)
+        slices.extend(bufs);
+        fds
     }
 }
 impl crate::x11_utils::VoidRequest for SetDeviceFocusRequest {
@@ -3550,6 +4574,7 @@ impl crate::x11_utils::VoidRequest for SetDeviceFocusRequest {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct FeedbackClass(u8);
 impl FeedbackClass {
     pub const KEYBOARD: Self = Self(0);
@@ -3614,6 +4639,25 @@ impl core::fmt::Debug for FeedbackClass  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl FeedbackClass {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Keyboard"),
+            1 => Some("Pointer"),
+            2 => Some("String"),
+            3 => Some("Integer"),
+            4 => Some("Led"),
+            5 => Some("Bell"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for FeedbackClass {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
@@ -3632,6 +4676,12 @@ pub struct KbdFeedbackState {
     pub auto_repeats: [u8; 32],
 }
 impl_debug_if_no_extra_traits!(KbdFeedbackState, "KbdFeedbackState");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for KbdFeedbackState {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for KbdFeedbackState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (class_id, remaining) = u8::try_parse(remaining)?;
@@ -3748,6 +4798,12 @@ pub struct PtrFeedbackState {
     pub threshold: u16,
 }
 impl_debug_if_no_extra_traits!(PtrFeedbackState, "PtrFeedbackState");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for PtrFeedbackState {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for PtrFeedbackState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (class_id, remaining) = u8::try_parse(remaining)?;
@@ -3810,6 +4866,12 @@ pub struct IntegerFeedbackState {
     pub max_value: i32,
 }
 impl_debug_if_no_extra_traits!(IntegerFeedbackState, "IntegerFeedbackState");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for IntegerFeedbackState {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for IntegerFeedbackState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (class_id, remaining) = u8::try_parse(remaining)?;
@@ -3873,6 +4935,12 @@ pub struct StringFeedbackState {
     pub keysyms: Vec<xproto::Keysym>,
 }
 impl_debug_if_no_extra_traits!(StringFeedbackState, "StringFeedbackState");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for StringFeedbackState {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for StringFeedbackState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (class_id, remaining) = u8::try_parse(remaining)?;
@@ -3932,6 +5000,12 @@ pub struct BellFeedbackState {
     pub duration: u16,
 }
 impl_debug_if_no_extra_traits!(BellFeedbackState, "BellFeedbackState");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for BellFeedbackState {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for BellFeedbackState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (class_id, remaining) = u8::try_parse(remaining)?;
@@ -3993,6 +5067,12 @@ pub struct LedFeedbackState {
     pub led_values: u32,
 }
 impl_debug_if_no_extra_traits!(LedFeedbackState, "LedFeedbackState");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for LedFeedbackState {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for LedFeedbackState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (class_id, remaining) = u8::try_parse(remaining)?;
@@ -4052,6 +5132,12 @@ pub struct FeedbackStateDataKeyboard {
     pub auto_repeats: [u8; 32],
 }
 impl_debug_if_no_extra_traits!(FeedbackStateDataKeyboard, "FeedbackStateDataKeyboard");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for FeedbackStateDataKeyboard {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for FeedbackStateDataKeyboard {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (pitch, remaining) = u16::try_parse(remaining)?;
@@ -4150,6 +5236,12 @@ pub struct FeedbackStateDataPointer {
     pub threshold: u16,
 }
 impl_debug_if_no_extra_traits!(FeedbackStateDataPointer, "FeedbackStateDataPointer");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for FeedbackStateDataPointer {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for FeedbackStateDataPointer {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
@@ -4193,6 +5285,12 @@ pub struct FeedbackStateDataString {
     pub keysyms: Vec<xproto::Keysym>,
 }
 impl_debug_if_no_extra_traits!(FeedbackStateDataString, "FeedbackStateDataString");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for FeedbackStateDataString {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for FeedbackStateDataString {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (max_symbols, remaining) = u16::try_parse(remaining)?;
@@ -4241,6 +5339,12 @@ pub struct FeedbackStateDataInteger {
     pub max_value: i32,
 }
 impl_debug_if_no_extra_traits!(FeedbackStateDataInteger, "FeedbackStateDataInteger");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for FeedbackStateDataInteger {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for FeedbackStateDataInteger {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (resolution, remaining) = u32::try_parse(remaining)?;
@@ -4286,6 +5390,12 @@ pub struct FeedbackStateDataLed {
     pub led_values: u32,
 }
 impl_debug_if_no_extra_traits!(FeedbackStateDataLed, "FeedbackStateDataLed");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for FeedbackStateDataLed {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for FeedbackStateDataLed {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (led_mask, remaining) = u32::try_parse(remaining)?;
@@ -4325,6 +5435,12 @@ pub struct FeedbackStateDataBell {
     pub duration: u16,
 }
 impl_debug_if_no_extra_traits!(FeedbackStateDataBell, "FeedbackStateDataBell");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for FeedbackStateDataBell {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for FeedbackStateDataBell {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (percent, remaining) = u8::try_parse(remaining)?;
@@ -4363,6 +5479,7 @@ impl Serialize for FeedbackStateDataBell {
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum FeedbackStateData {
     Keyboard(FeedbackStateDataKeyboard),
     Pointer(FeedbackStateDataPointer),
@@ -4500,6 +5617,21 @@ impl FeedbackStateData {
         }
     }
 }
+#[cfg(feature = "arbitrary")]
+impl FeedbackStateData {
+    /// Whether this value can be serialized, i.e. does not contain an `InvalidValue`.
+    pub(crate) fn is_encodable(&self) -> bool {
+        match self {
+            FeedbackStateData::Keyboard(_) => true,
+            FeedbackStateData::Pointer(_) => true,
+            FeedbackStateData::String(_) => true,
+            FeedbackStateData::Integer(_) => true,
+            FeedbackStateData::Led(_) => true,
+            FeedbackStateData::Bell(_) => true,
+            FeedbackStateData::InvalidValue(_) => false,
+        }
+    }
+}
 
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
@@ -4510,6 +5642,24 @@ pub struct FeedbackState {
     pub data: FeedbackStateData,
 }
 impl_debug_if_no_extra_traits!(FeedbackState, "FeedbackState");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for FeedbackState {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let (value, size): (Self, usize) = crate::x11_utils::arbitrary_parse(u)?;
+        if !value.is_encodable() {
+            return Err(arbitrary::Error::IncorrectFormat);
+        }
+        crate::x11_utils::arbitrary_check_size(&value, size, 0)?;
+        Ok(value)
+    }
+}
+#[cfg(feature = "arbitrary")]
+impl FeedbackState {
+    /// Whether this value can be serialized, i.e. does not contain an `InvalidValue`.
+    pub(crate) fn is_encodable(&self) -> bool {
+        self.data.is_encodable()
+    }
+}
 impl TryParse for FeedbackState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (class_id, remaining) = u8::try_parse(remaining)?;
@@ -4542,6 +5692,7 @@ pub const GET_FEEDBACK_CONTROL_REQUEST: u8 = 22;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetFeedbackControlRequest {
     pub device_id: u8,
 }
@@ -4590,6 +5741,33 @@ impl Request for GetFeedbackControlRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_id_bytes = self.device_id.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_FEEDBACK_CONTROL_REQUEST,
+            0,
+            0,
+            device_id_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetFeedbackControlRequest {
     type Reply = GetFeedbackControlReply;
@@ -4605,6 +5783,24 @@ pub struct GetFeedbackControlReply {
     pub feedbacks: Vec<FeedbackState>,
 }
 impl_debug_if_no_extra_traits!(GetFeedbackControlReply, "GetFeedbackControlReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetFeedbackControlReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let (value, size): (Self, usize) = crate::x11_utils::arbitrary_parse(u)?;
+        if !value.is_encodable() {
+            return Err(arbitrary::Error::IncorrectFormat);
+        }
+        crate::x11_utils::arbitrary_check_size(&value, size, 32)?;
+        Ok(value)
+    }
+}
+#[cfg(feature = "arbitrary")]
+impl GetFeedbackControlReply {
+    /// Whether this value can be serialized, i.e. does not contain an `InvalidValue`.
+    pub(crate) fn is_encodable(&self) -> bool {
+        self.feedbacks.iter().all(|item| item.is_encodable())
+    }
+}
 impl TryParse for GetFeedbackControlReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -4643,6 +5839,7 @@ impl Serialize for GetFeedbackControlReply {
         num_feedbacks.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 22]);
         self.feedbacks.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetFeedbackControlReply {
@@ -4678,6 +5875,12 @@ pub struct KbdFeedbackCtl {
     pub led_values: u32,
 }
 impl_debug_if_no_extra_traits!(KbdFeedbackCtl, "KbdFeedbackCtl");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for KbdFeedbackCtl {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for KbdFeedbackCtl {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (class_id, remaining) = u8::try_parse(remaining)?;
@@ -4761,6 +5964,12 @@ pub struct PtrFeedbackCtl {
     pub threshold: i16,
 }
 impl_debug_if_no_extra_traits!(PtrFeedbackCtl, "PtrFeedbackCtl");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for PtrFeedbackCtl {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for PtrFeedbackCtl {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (class_id, remaining) = u8::try_parse(remaining)?;
@@ -4821,6 +6030,12 @@ pub struct IntegerFeedbackCtl {
     pub int_to_display: i32,
 }
 impl_debug_if_no_extra_traits!(IntegerFeedbackCtl, "IntegerFeedbackCtl");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for IntegerFeedbackCtl {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for IntegerFeedbackCtl {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (class_id, remaining) = u8::try_parse(remaining)?;
@@ -4869,6 +6084,12 @@ pub struct StringFeedbackCtl {
     pub keysyms: Vec<xproto::Keysym>,
 }
 impl_debug_if_no_extra_traits!(StringFeedbackCtl, "StringFeedbackCtl");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for StringFeedbackCtl {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for StringFeedbackCtl {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (class_id, remaining) = u8::try_parse(remaining)?;
@@ -4928,6 +6149,12 @@ pub struct BellFeedbackCtl {
     pub duration: i16,
 }
 impl_debug_if_no_extra_traits!(BellFeedbackCtl, "BellFeedbackCtl");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for BellFeedbackCtl {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for BellFeedbackCtl {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (class_id, remaining) = u8::try_parse(remaining)?;
@@ -4989,6 +6216,12 @@ pub struct LedFeedbackCtl {
     pub led_values: u32,
 }
 impl_debug_if_no_extra_traits!(LedFeedbackCtl, "LedFeedbackCtl");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for LedFeedbackCtl {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for LedFeedbackCtl {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (class_id, remaining) = u8::try_parse(remaining)?;
@@ -5048,6 +6281,12 @@ pub struct FeedbackCtlDataKeyboard {
     pub led_values: u32,
 }
 impl_debug_if_no_extra_traits!(FeedbackCtlDataKeyboard, "FeedbackCtlDataKeyboard");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for FeedbackCtlDataKeyboard {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for FeedbackCtlDataKeyboard {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (key, remaining) = KeyCode::try_parse(remaining)?;
@@ -5113,6 +6352,12 @@ pub struct FeedbackCtlDataPointer {
     pub threshold: i16,
 }
 impl_debug_if_no_extra_traits!(FeedbackCtlDataPointer, "FeedbackCtlDataPointer");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for FeedbackCtlDataPointer {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for FeedbackCtlDataPointer {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
@@ -5155,6 +6400,12 @@ pub struct FeedbackCtlDataString {
     pub keysyms: Vec<xproto::Keysym>,
 }
 impl_debug_if_no_extra_traits!(FeedbackCtlDataString, "FeedbackCtlDataString");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for FeedbackCtlDataString {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for FeedbackCtlDataString {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
@@ -5201,6 +6452,12 @@ pub struct FeedbackCtlDataInteger {
     pub int_to_display: i32,
 }
 impl_debug_if_no_extra_traits!(FeedbackCtlDataInteger, "FeedbackCtlDataInteger");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for FeedbackCtlDataInteger {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for FeedbackCtlDataInteger {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (int_to_display, remaining) = i32::try_parse(remaining)?;
@@ -5232,6 +6489,12 @@ pub struct FeedbackCtlDataLed {
     pub led_values: u32,
 }
 impl_debug_if_no_extra_traits!(FeedbackCtlDataLed, "FeedbackCtlDataLed");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for FeedbackCtlDataLed {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for FeedbackCtlDataLed {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (led_mask, remaining) = u32::try_parse(remaining)?;
@@ -5271,6 +6534,12 @@ pub struct FeedbackCtlDataBell {
     pub duration: i16,
 }
 impl_debug_if_no_extra_traits!(FeedbackCtlDataBell, "FeedbackCtlDataBell");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for FeedbackCtlDataBell {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for FeedbackCtlDataBell {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (percent, remaining) = i8::try_parse(remaining)?;
@@ -5309,6 +6578,7 @@ impl Serialize for FeedbackCtlDataBell {
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum FeedbackCtlData {
     Keyboard(FeedbackCtlDataKeyboard),
     Pointer(FeedbackCtlDataPointer),
@@ -5446,6 +6716,21 @@ impl FeedbackCtlData {
         }
     }
 }
+#[cfg(feature = "arbitrary")]
+impl FeedbackCtlData {
+    /// Whether this value can be serialized, i.e. does not contain an `InvalidValue`.
+    pub(crate) fn is_encodable(&self) -> bool {
+        match self {
+            FeedbackCtlData::Keyboard(_) => true,
+            FeedbackCtlData::Pointer(_) => true,
+            FeedbackCtlData::String(_) => true,
+            FeedbackCtlData::Integer(_) => true,
+            FeedbackCtlData::Led(_) => true,
+            FeedbackCtlData::Bell(_) => true,
+            FeedbackCtlData::InvalidValue(_) => false,
+        }
+    }
+}
 
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
@@ -5456,6 +6741,24 @@ pub struct FeedbackCtl {
     pub data: FeedbackCtlData,
 }
 impl_debug_if_no_extra_traits!(FeedbackCtl, "FeedbackCtl");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for FeedbackCtl {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let (value, size): (Self, usize) = crate::x11_utils::arbitrary_parse(u)?;
+        if !value.is_encodable() {
+            return Err(arbitrary::Error::IncorrectFormat);
+        }
+        crate::x11_utils::arbitrary_check_size(&value, size, 0)?;
+        Ok(value)
+    }
+}
+#[cfg(feature = "arbitrary")]
+impl FeedbackCtl {
+    /// Whether this value can be serialized, i.e. does not contain an `InvalidValue`.
+    pub(crate) fn is_encodable(&self) -> bool {
+        self.data.is_encodable()
+    }
+}
 impl TryParse for FeedbackCtl {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (class_id, remaining) = u8::try_parse(remaining)?;
@@ -5485,6 +6788,7 @@ impl Serialize for FeedbackCtl {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangeFeedbackControlMask(u32);
 impl ChangeFeedbackControlMask {
     pub const KEY_CLICK_PERCENT: Self = Self(1 << 0);
@@ -5551,6 +6855,27 @@ impl core::fmt::Debug for ChangeFeedbackControlMask  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl ChangeFeedbackControlMask {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("KeyClickPercent"),
+            2 => Some("Percent"),
+            4 => Some("Pitch"),
+            8 => Some("Duration"),
+            16 => Some("Led"),
+            32 => Some("LedMode"),
+            64 => Some("Key"),
+            128 => Some("AutoRepeatMode"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ChangeFeedbackControlMask {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(ChangeFeedbackControlMask, u32);
 
 /// Opcode for the ChangeFeedbackControl request
@@ -5558,6 +6883,7 @@ pub const CHANGE_FEEDBACK_CONTROL_REQUEST: u8 = 23;
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangeFeedbackControlRequest {
     pub mask: ChangeFeedbackControlMask,
     pub device_id: u8,
@@ -5626,6 +6952,42 @@ impl Request for ChangeFeedbackControlRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let mask_bytes = u32::from(self.mask).serialize();
+        let device_id_bytes = self.device_id.serialize();
+        let feedback_id_bytes = self.feedback_id.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CHANGE_FEEDBACK_CONTROL_REQUEST,
+            0,
+            0,
+            mask_bytes[0],
+            mask_bytes[1],
+            mask_bytes[2],
+            mask_bytes[3],
+            device_id_bytes[0],
+            feedback_id_bytes[0],
+            0,
+            0,
+        ]);
+        self.feedback.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for ChangeFeedbackControlRequest {
 }
@@ -5635,6 +6997,7 @@ pub const GET_DEVICE_KEY_MAPPING_REQUEST: u8 = 24;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetDeviceKeyMappingRequest {
     pub device_id: u8,
     pub first_keycode: KeyCode,
@@ -5691,6 +7054,35 @@ impl Request for GetDeviceKeyMappingRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_id_bytes = self.device_id.serialize();
+        let first_keycode_bytes = self.first_keycode.serialize();
+        let count_bytes = self.count.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_DEVICE_KEY_MAPPING_REQUEST,
+            0,
+            0,
+            device_id_bytes[0],
+            first_keycode_bytes[0],
+            count_bytes[0],
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetDeviceKeyMappingRequest {
     type Reply = GetDeviceKeyMappingReply;
@@ -5706,6 +7098,12 @@ pub struct GetDeviceKeyMappingReply {
     pub keysyms: Vec<xproto::Keysym>,
 }
 impl_debug_if_no_extra_traits!(GetDeviceKeyMappingReply, "GetDeviceKeyMappingReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetDeviceKeyMappingReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetDeviceKeyMappingReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5744,6 +7142,7 @@ impl Serialize for GetDeviceKeyMappingReply {
         self.keysyms_per_keycode.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 23]);
         self.keysyms.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetDeviceKeyMappingReply {
@@ -5767,6 +7166,7 @@ pub const CHANGE_DEVICE_KEY_MAPPING_REQUEST: u8 = 25;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangeDeviceKeyMappingRequest<'input> {
     pub device_id: u8,
     pub first_keycode: KeyCode,
@@ -5844,6 +7244,40 @@ impl<'input> Request for ChangeDeviceKeyMappingRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_id_bytes = self.device_id.serialize();
+        let first_keycode_bytes = self.first_keycode.serialize();
+        let keysyms_per_keycode_bytes = self.keysyms_per_keycode.serialize();
+        let keycode_count_bytes = self.keycode_count.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CHANGE_DEVICE_KEY_MAPPING_REQUEST,
+            0,
+            0,
+            device_id_bytes[0],
+            first_keycode_bytes[0],
+            keysyms_per_keycode_bytes[0],
+            keycode_count_bytes[0],
+        ]);
+        assert_eq!(self.keysyms.len(), usize::try_from(u32::from(self.keycode_count).checked_mul(u32::from(self.keysyms_per_keycode)).unwrap()).unwrap(), "`keysyms` has an incorrect length");
+        self.keysyms.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for ChangeDeviceKeyMappingRequest<'input> {
 }
@@ -5853,6 +7287,7 @@ pub const GET_DEVICE_MODIFIER_MAPPING_REQUEST: u8 = 26;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetDeviceModifierMappingRequest {
     pub device_id: u8,
 }
@@ -5901,6 +7336,33 @@ impl Request for GetDeviceModifierMappingRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_id_bytes = self.device_id.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_DEVICE_MODIFIER_MAPPING_REQUEST,
+            0,
+            0,
+            device_id_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetDeviceModifierMappingRequest {
     type Reply = GetDeviceModifierMappingReply;
@@ -5916,6 +7378,12 @@ pub struct GetDeviceModifierMappingReply {
     pub keymaps: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(GetDeviceModifierMappingReply, "GetDeviceModifierMappingReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetDeviceModifierMappingReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetDeviceModifierMappingReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5956,6 +7424,7 @@ impl Serialize for GetDeviceModifierMappingReply {
         keycodes_per_modifier.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 23]);
         bytes.extend_from_slice(&self.keymaps);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetDeviceModifierMappingReply {
@@ -5980,6 +7449,7 @@ pub const SET_DEVICE_MODIFIER_MAPPING_REQUEST: u8 = 27;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetDeviceModifierMappingRequest<'input> {
     pub device_id: u8,
     pub keymaps: Cow<'input, [u8]>,
@@ -6045,6 +7515,39 @@ impl<'input> Request for SetDeviceModifierMappingRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_id_bytes = self.device_id.serialize();
+        assert_eq!(self.keymaps.len() % 8, 0, "`keymaps` has an incorrect length, must be a multiple of 8");
+        let keycodes_per_modifier = u8::try_from(self.keymaps.len() / 8).expect("`keymaps` has too many elements");
+        let keycodes_per_modifier_bytes = keycodes_per_modifier.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_DEVICE_MODIFIER_MAPPING_REQUEST,
+            0,
+            0,
+            device_id_bytes[0],
+            keycodes_per_modifier_bytes[0],
+            0,
+            0,
+        ]);
+        buffer.extend_from_slice(&self.keymaps[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::ReplyRequest for SetDeviceModifierMappingRequest<'input> {
     type Reply = SetDeviceModifierMappingReply;
@@ -6060,6 +7563,12 @@ pub struct SetDeviceModifierMappingReply {
     pub status: xproto::MappingStatus,
 }
 impl_debug_if_no_extra_traits!(SetDeviceModifierMappingReply, "SetDeviceModifierMappingReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SetDeviceModifierMappingReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for SetDeviceModifierMappingReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -6140,6 +7649,7 @@ pub const GET_DEVICE_BUTTON_MAPPING_REQUEST: u8 = 28;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetDeviceButtonMappingRequest {
     pub device_id: u8,
 }
@@ -6188,6 +7698,33 @@ impl Request for GetDeviceButtonMappingRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_id_bytes = self.device_id.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_DEVICE_BUTTON_MAPPING_REQUEST,
+            0,
+            0,
+            device_id_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetDeviceButtonMappingRequest {
     type Reply = GetDeviceButtonMappingReply;
@@ -6203,6 +7740,12 @@ pub struct GetDeviceButtonMappingReply {
     pub map: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(GetDeviceButtonMappingReply, "GetDeviceButtonMappingReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetDeviceButtonMappingReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetDeviceButtonMappingReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -6248,6 +7791,7 @@ impl Serialize for GetDeviceButtonMappingReply {
         bytes.extend_from_slice(&[0; 23]);
         bytes.extend_from_slice(&self.map);
         bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetDeviceButtonMappingReply {
@@ -6271,6 +7815,7 @@ pub const SET_DEVICE_BUTTON_MAPPING_REQUEST: u8 = 29;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetDeviceButtonMappingRequest<'input> {
     pub device_id: u8,
     pub map: Cow<'input, [u8]>,
@@ -6335,6 +7880,38 @@ impl<'input> Request for SetDeviceButtonMappingRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_id_bytes = self.device_id.serialize();
+        let map_size = u8::try_from(self.map.len()).expect("`map` has too many elements");
+        let map_size_bytes = map_size.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_DEVICE_BUTTON_MAPPING_REQUEST,
+            0,
+            0,
+            device_id_bytes[0],
+            map_size_bytes[0],
+            0,
+            0,
+        ]);
+        buffer.extend_from_slice(&self.map[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::ReplyRequest for SetDeviceButtonMappingRequest<'input> {
     type Reply = SetDeviceButtonMappingReply;
@@ -6350,6 +7927,12 @@ pub struct SetDeviceButtonMappingReply {
     pub status: xproto::MappingStatus,
 }
 impl_debug_if_no_extra_traits!(SetDeviceButtonMappingReply, "SetDeviceButtonMappingReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SetDeviceButtonMappingReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for SetDeviceButtonMappingReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -6435,6 +8018,12 @@ pub struct KeyState {
     pub keys: [u8; 32],
 }
 impl_debug_if_no_extra_traits!(KeyState, "KeyState");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for KeyState {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for KeyState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (class_id, remaining) = u8::try_parse(remaining)?;
@@ -6512,6 +8101,12 @@ pub struct ButtonState {
     pub buttons: [u8; 32],
 }
 impl_debug_if_no_extra_traits!(ButtonState, "ButtonState");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ButtonState {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for ButtonState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (class_id, remaining) = u8::try_parse(remaining)?;
@@ -6581,6 +8176,7 @@ impl Serialize for ButtonState {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ValuatorStateModeMask(u8);
 impl ValuatorStateModeMask {
     pub const DEVICE_MODE_ABSOLUTE: Self = Self(1 << 0);
@@ -6637,6 +8233,21 @@ impl core::fmt::Debug for ValuatorStateModeMask  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl ValuatorStateModeMask {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("DeviceModeAbsolute"),
+            2 => Some("OutOfProximity"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ValuatorStateModeMask {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(ValuatorStateModeMask, u8);
 
 #[derive(Clone, Default)]
@@ -6649,6 +8260,12 @@ pub struct ValuatorState {
     pub valuators: Vec<i32>,
 }
 impl_debug_if_no_extra_traits!(ValuatorState, "ValuatorState");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ValuatorState {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for ValuatorState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (class_id, remaining) = u8::try_parse(remaining)?;
@@ -6703,6 +8320,12 @@ pub struct InputStateDataKey {
     pub keys: [u8; 32],
 }
 impl_debug_if_no_extra_traits!(InputStateDataKey, "InputStateDataKey");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for InputStateDataKey {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for InputStateDataKey {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (num_keys, remaining) = u8::try_parse(remaining)?;
@@ -6768,6 +8391,12 @@ pub struct InputStateDataButton {
     pub buttons: [u8; 32],
 }
 impl_debug_if_no_extra_traits!(InputStateDataButton, "InputStateDataButton");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for InputStateDataButton {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for InputStateDataButton {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (num_buttons, remaining) = u8::try_parse(remaining)?;
@@ -6833,6 +8462,12 @@ pub struct InputStateDataValuator {
     pub valuators: Vec<i32>,
 }
 impl_debug_if_no_extra_traits!(InputStateDataValuator, "InputStateDataValuator");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for InputStateDataValuator {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for InputStateDataValuator {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (num_valuators, remaining) = u8::try_parse(remaining)?;
@@ -6876,6 +8511,7 @@ impl InputStateDataValuator {
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum InputStateData {
     Key(InputStateDataKey),
     Button(InputStateDataButton),
@@ -6968,6 +8604,18 @@ impl InputStateData {
         }
     }
 }
+#[cfg(feature = "arbitrary")]
+impl InputStateData {
+    /// Whether this value can be serialized, i.e. does not contain an `InvalidValue`.
+    pub(crate) fn is_encodable(&self) -> bool {
+        match self {
+            InputStateData::Key(_) => true,
+            InputStateData::Button(_) => true,
+            InputStateData::Valuator(_) => true,
+            InputStateData::InvalidValue(_) => false,
+        }
+    }
+}
 
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
@@ -6977,6 +8625,24 @@ pub struct InputState {
     pub data: InputStateData,
 }
 impl_debug_if_no_extra_traits!(InputState, "InputState");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for InputState {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let (value, size): (Self, usize) = crate::x11_utils::arbitrary_parse(u)?;
+        if !value.is_encodable() {
+            return Err(arbitrary::Error::IncorrectFormat);
+        }
+        crate::x11_utils::arbitrary_check_size(&value, size, 0)?;
+        Ok(value)
+    }
+}
+#[cfg(feature = "arbitrary")]
+impl InputState {
+    /// Whether this value can be serialized, i.e. does not contain an `InvalidValue`.
+    pub(crate) fn is_encodable(&self) -> bool {
+        self.data.is_encodable()
+    }
+}
 impl TryParse for InputState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (class_id, remaining) = u8::try_parse(remaining)?;
@@ -7007,6 +8673,7 @@ pub const QUERY_DEVICE_STATE_REQUEST: u8 = 30;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryDeviceStateRequest {
     pub device_id: u8,
 }
@@ -7055,6 +8722,33 @@ impl Request for QueryDeviceStateRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_id_bytes = self.device_id.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_DEVICE_STATE_REQUEST,
+            0,
+            0,
+            device_id_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryDeviceStateRequest {
     type Reply = QueryDeviceStateReply;
@@ -7070,6 +8764,24 @@ pub struct QueryDeviceStateReply {
     pub classes: Vec<InputState>,
 }
 impl_debug_if_no_extra_traits!(QueryDeviceStateReply, "QueryDeviceStateReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryDeviceStateReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let (value, size): (Self, usize) = crate::x11_utils::arbitrary_parse(u)?;
+        if !value.is_encodable() {
+            return Err(arbitrary::Error::IncorrectFormat);
+        }
+        crate::x11_utils::arbitrary_check_size(&value, size, 32)?;
+        Ok(value)
+    }
+}
+#[cfg(feature = "arbitrary")]
+impl QueryDeviceStateReply {
+    /// Whether this value can be serialized, i.e. does not contain an `InvalidValue`.
+    pub(crate) fn is_encodable(&self) -> bool {
+        self.classes.iter().all(|item| item.is_encodable())
+    }
+}
 impl TryParse for QueryDeviceStateReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -7108,6 +8820,7 @@ impl Serialize for QueryDeviceStateReply {
         num_classes.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 23]);
         self.classes.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl QueryDeviceStateReply {
@@ -7131,6 +8844,7 @@ pub const DEVICE_BELL_REQUEST: u8 = 32;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DeviceBellRequest {
     pub device_id: u8,
     pub feedback_id: u8,
@@ -7190,6 +8904,36 @@ impl Request for DeviceBellRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_id_bytes = self.device_id.serialize();
+        let feedback_id_bytes = self.feedback_id.serialize();
+        let feedback_class_bytes = self.feedback_class.serialize();
+        let percent_bytes = self.percent.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            DEVICE_BELL_REQUEST,
+            0,
+            0,
+            device_id_bytes[0],
+            feedback_id_bytes[0],
+            feedback_class_bytes[0],
+            percent_bytes[0],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for DeviceBellRequest {
 }
@@ -7199,6 +8943,7 @@ pub const SET_DEVICE_VALUATORS_REQUEST: u8 = 33;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetDeviceValuatorsRequest<'input> {
     pub device_id: u8,
     pub first_valuator: u8,
@@ -7269,6 +9014,39 @@ impl<'input> Request for SetDeviceValuatorsRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_id_bytes = self.device_id.serialize();
+        let first_valuator_bytes = self.first_valuator.serialize();
+        let num_valuators = u8::try_from(self.valuators.len()).expect("`valuators` has too many elements");
+        let num_valuators_bytes = num_valuators.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_DEVICE_VALUATORS_REQUEST,
+            0,
+            0,
+            device_id_bytes[0],
+            first_valuator_bytes[0],
+            num_valuators_bytes[0],
+            0,
+        ]);
+        self.valuators.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::ReplyRequest for SetDeviceValuatorsRequest<'input> {
     type Reply = SetDeviceValuatorsReply;
@@ -7284,6 +9062,12 @@ pub struct SetDeviceValuatorsReply {
     pub status: xproto::GrabStatus,
 }
 impl_debug_if_no_extra_traits!(SetDeviceValuatorsReply, "SetDeviceValuatorsReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SetDeviceValuatorsReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for SetDeviceValuatorsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -7361,6 +9145,7 @@ impl Serialize for SetDeviceValuatorsReply {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DeviceControl(u16);
 impl DeviceControl {
     pub const RESOLUTION: Self = Self(1);
@@ -7417,6 +9202,24 @@ impl core::fmt::Debug for DeviceControl  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl DeviceControl {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("Resolution"),
+            2 => Some("Abscalib"),
+            3 => Some("Core"),
+            4 => Some("Enable"),
+            5 => Some("Absarea"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for DeviceControl {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
@@ -7429,6 +9232,12 @@ pub struct DeviceResolutionState {
     pub resolution_max: Vec<u32>,
 }
 impl_debug_if_no_extra_traits!(DeviceResolutionState, "DeviceResolutionState");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceResolutionState {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for DeviceResolutionState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (control_id, remaining) = u16::try_parse(remaining)?;
@@ -7494,6 +9303,12 @@ pub struct DeviceAbsCalibState {
     pub button_threshold: u32,
 }
 impl_debug_if_no_extra_traits!(DeviceAbsCalibState, "DeviceAbsCalibState");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceAbsCalibState {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for DeviceAbsCalibState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (control_id, remaining) = u16::try_parse(remaining)?;
@@ -7592,6 +9407,12 @@ pub struct DeviceAbsAreaState {
     pub following: u32,
 }
 impl_debug_if_no_extra_traits!(DeviceAbsAreaState, "DeviceAbsAreaState");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceAbsAreaState {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for DeviceAbsAreaState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (control_id, remaining) = u16::try_parse(remaining)?;
@@ -7672,6 +9493,12 @@ pub struct DeviceCoreState {
     pub iscore: u8,
 }
 impl_debug_if_no_extra_traits!(DeviceCoreState, "DeviceCoreState");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceCoreState {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for DeviceCoreState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (control_id, remaining) = u16::try_parse(remaining)?;
@@ -7721,6 +9548,12 @@ pub struct DeviceEnableState {
     pub enable: u8,
 }
 impl_debug_if_no_extra_traits!(DeviceEnableState, "DeviceEnableState");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceEnableState {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for DeviceEnableState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (control_id, remaining) = u16::try_parse(remaining)?;
@@ -7767,6 +9600,12 @@ pub struct DeviceStateDataResolution {
     pub resolution_max: Vec<u32>,
 }
 impl_debug_if_no_extra_traits!(DeviceStateDataResolution, "DeviceStateDataResolution");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceStateDataResolution {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for DeviceStateDataResolution {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (num_valuators, remaining) = u32::try_parse(remaining)?;
@@ -7823,6 +9662,12 @@ pub struct DeviceStateDataAbsCalib {
     pub button_threshold: u32,
 }
 impl_debug_if_no_extra_traits!(DeviceStateDataAbsCalib, "DeviceStateDataAbsCalib");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceStateDataAbsCalib {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for DeviceStateDataAbsCalib {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (min_x, remaining) = i32::try_parse(remaining)?;
@@ -7903,6 +9748,12 @@ pub struct DeviceStateDataCore {
     pub iscore: u8,
 }
 impl_debug_if_no_extra_traits!(DeviceStateDataCore, "DeviceStateDataCore");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceStateDataCore {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for DeviceStateDataCore {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (status, remaining) = u8::try_parse(remaining)?;
@@ -7943,6 +9794,12 @@ pub struct DeviceStateDataAbsArea {
     pub following: u32,
 }
 impl_debug_if_no_extra_traits!(DeviceStateDataAbsArea, "DeviceStateDataAbsArea");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceStateDataAbsArea {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for DeviceStateDataAbsArea {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (offset_x, remaining) = u32::try_parse(remaining)?;
@@ -8004,6 +9861,7 @@ impl Serialize for DeviceStateDataAbsArea {
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum DeviceStateData {
     Resolution(DeviceStateDataResolution),
     AbsCalib(DeviceStateDataAbsCalib),
@@ -8132,6 +9990,20 @@ impl DeviceStateData {
         }
     }
 }
+#[cfg(feature = "arbitrary")]
+impl DeviceStateData {
+    /// Whether this value can be serialized, i.e. does not contain an `InvalidValue`.
+    pub(crate) fn is_encodable(&self) -> bool {
+        match self {
+            DeviceStateData::Resolution(_) => true,
+            DeviceStateData::AbsCalib(_) => true,
+            DeviceStateData::Core(_) => true,
+            DeviceStateData::Enable(_) => true,
+            DeviceStateData::AbsArea(_) => true,
+            DeviceStateData::InvalidValue(_) => false,
+        }
+    }
+}
 
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
@@ -8141,6 +10013,24 @@ pub struct DeviceState {
     pub data: DeviceStateData,
 }
 impl_debug_if_no_extra_traits!(DeviceState, "DeviceState");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceState {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let (value, size): (Self, usize) = crate::x11_utils::arbitrary_parse(u)?;
+        if !value.is_encodable() {
+            return Err(arbitrary::Error::IncorrectFormat);
+        }
+        crate::x11_utils::arbitrary_check_size(&value, size, 0)?;
+        Ok(value)
+    }
+}
+#[cfg(feature = "arbitrary")]
+impl DeviceState {
+    /// Whether this value can be serialized, i.e. does not contain an `InvalidValue`.
+    pub(crate) fn is_encodable(&self) -> bool {
+        self.data.is_encodable()
+    }
+}
 impl TryParse for DeviceState {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (control_id, remaining) = u16::try_parse(remaining)?;
@@ -8171,6 +10061,7 @@ pub const GET_DEVICE_CONTROL_REQUEST: u8 = 34;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetDeviceControlRequest {
     pub control_id: DeviceControl,
     pub device_id: u8,
@@ -8224,6 +10115,34 @@ impl Request for GetDeviceControlRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let control_id_bytes = u16::from(self.control_id).serialize();
+        let device_id_bytes = self.device_id.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_DEVICE_CONTROL_REQUEST,
+            0,
+            0,
+            control_id_bytes[0],
+            control_id_bytes[1],
+            device_id_bytes[0],
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetDeviceControlRequest {
     type Reply = GetDeviceControlReply;
@@ -8240,6 +10159,24 @@ pub struct GetDeviceControlReply {
     pub control: DeviceState,
 }
 impl_debug_if_no_extra_traits!(GetDeviceControlReply, "GetDeviceControlReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetDeviceControlReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let (value, size): (Self, usize) = crate::x11_utils::arbitrary_parse(u)?;
+        if !value.is_encodable() {
+            return Err(arbitrary::Error::IncorrectFormat);
+        }
+        crate::x11_utils::arbitrary_check_size(&value, size, 32)?;
+        Ok(value)
+    }
+}
+#[cfg(feature = "arbitrary")]
+impl GetDeviceControlReply {
+    /// Whether this value can be serialized, i.e. does not contain an `InvalidValue`.
+    pub(crate) fn is_encodable(&self) -> bool {
+        self.control.is_encodable()
+    }
+}
 impl TryParse for GetDeviceControlReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -8277,6 +10214,7 @@ impl Serialize for GetDeviceControlReply {
         self.status.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 23]);
         self.control.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 
@@ -8290,6 +10228,12 @@ pub struct DeviceResolutionCtl {
     pub resolution_values: Vec<u32>,
 }
 impl_debug_if_no_extra_traits!(DeviceResolutionCtl, "DeviceResolutionCtl");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceResolutionCtl {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for DeviceResolutionCtl {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (control_id, remaining) = u16::try_parse(remaining)?;
@@ -8353,6 +10297,12 @@ pub struct DeviceAbsCalibCtl {
     pub button_threshold: u32,
 }
 impl_debug_if_no_extra_traits!(DeviceAbsCalibCtl, "DeviceAbsCalibCtl");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceAbsCalibCtl {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for DeviceAbsCalibCtl {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (control_id, remaining) = u16::try_parse(remaining)?;
@@ -8451,6 +10401,12 @@ pub struct DeviceAbsAreaCtrl {
     pub following: u32,
 }
 impl_debug_if_no_extra_traits!(DeviceAbsAreaCtrl, "DeviceAbsAreaCtrl");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceAbsAreaCtrl {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for DeviceAbsAreaCtrl {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (control_id, remaining) = u16::try_parse(remaining)?;
@@ -8530,6 +10486,12 @@ pub struct DeviceCoreCtrl {
     pub status: u8,
 }
 impl_debug_if_no_extra_traits!(DeviceCoreCtrl, "DeviceCoreCtrl");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceCoreCtrl {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for DeviceCoreCtrl {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (control_id, remaining) = u16::try_parse(remaining)?;
@@ -8576,6 +10538,12 @@ pub struct DeviceEnableCtrl {
     pub enable: u8,
 }
 impl_debug_if_no_extra_traits!(DeviceEnableCtrl, "DeviceEnableCtrl");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceEnableCtrl {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for DeviceEnableCtrl {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (control_id, remaining) = u16::try_parse(remaining)?;
@@ -8621,6 +10589,12 @@ pub struct DeviceCtlDataResolution {
     pub resolution_values: Vec<u32>,
 }
 impl_debug_if_no_extra_traits!(DeviceCtlDataResolution, "DeviceCtlDataResolution");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceCtlDataResolution {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for DeviceCtlDataResolution {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (first_valuator, remaining) = u8::try_parse(remaining)?;
@@ -8676,6 +10650,12 @@ pub struct DeviceCtlDataAbsCalib {
     pub button_threshold: u32,
 }
 impl_debug_if_no_extra_traits!(DeviceCtlDataAbsCalib, "DeviceCtlDataAbsCalib");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceCtlDataAbsCalib {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for DeviceCtlDataAbsCalib {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (min_x, remaining) = i32::try_parse(remaining)?;
@@ -8755,6 +10735,12 @@ pub struct DeviceCtlDataCore {
     pub status: u8,
 }
 impl_debug_if_no_extra_traits!(DeviceCtlDataCore, "DeviceCtlDataCore");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceCtlDataCore {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for DeviceCtlDataCore {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (status, remaining) = u8::try_parse(remaining)?;
@@ -8792,6 +10778,12 @@ pub struct DeviceCtlDataAbsArea {
     pub following: u32,
 }
 impl_debug_if_no_extra_traits!(DeviceCtlDataAbsArea, "DeviceCtlDataAbsArea");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceCtlDataAbsArea {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for DeviceCtlDataAbsArea {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (offset_x, remaining) = u32::try_parse(remaining)?;
@@ -8853,6 +10845,7 @@ impl Serialize for DeviceCtlDataAbsArea {
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum DeviceCtlData {
     Resolution(DeviceCtlDataResolution),
     AbsCalib(DeviceCtlDataAbsCalib),
@@ -8981,6 +10974,20 @@ impl DeviceCtlData {
         }
     }
 }
+#[cfg(feature = "arbitrary")]
+impl DeviceCtlData {
+    /// Whether this value can be serialized, i.e. does not contain an `InvalidValue`.
+    pub(crate) fn is_encodable(&self) -> bool {
+        match self {
+            DeviceCtlData::Resolution(_) => true,
+            DeviceCtlData::AbsCalib(_) => true,
+            DeviceCtlData::Core(_) => true,
+            DeviceCtlData::Enable(_) => true,
+            DeviceCtlData::AbsArea(_) => true,
+            DeviceCtlData::InvalidValue(_) => false,
+        }
+    }
+}
 
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
@@ -8990,6 +10997,24 @@ pub struct DeviceCtl {
     pub data: DeviceCtlData,
 }
 impl_debug_if_no_extra_traits!(DeviceCtl, "DeviceCtl");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceCtl {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let (value, size): (Self, usize) = crate::x11_utils::arbitrary_parse(u)?;
+        if !value.is_encodable() {
+            return Err(arbitrary::Error::IncorrectFormat);
+        }
+        crate::x11_utils::arbitrary_check_size(&value, size, 0)?;
+        Ok(value)
+    }
+}
+#[cfg(feature = "arbitrary")]
+impl DeviceCtl {
+    /// Whether this value can be serialized, i.e. does not contain an `InvalidValue`.
+    pub(crate) fn is_encodable(&self) -> bool {
+        self.data.is_encodable()
+    }
+}
 impl TryParse for DeviceCtl {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (control_id, remaining) = u16::try_parse(remaining)?;
@@ -9020,6 +11045,7 @@ pub const CHANGE_DEVICE_CONTROL_REQUEST: u8 = 35;
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangeDeviceControlRequest {
     pub control_id: DeviceControl,
     pub device_id: u8,
@@ -9080,6 +11106,37 @@ impl Request for ChangeDeviceControlRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let control_id_bytes = u16::from(self.control_id).serialize();
+        let device_id_bytes = self.device_id.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CHANGE_DEVICE_CONTROL_REQUEST,
+            0,
+            0,
+            control_id_bytes[0],
+            control_id_bytes[1],
+            device_id_bytes[0],
+            0,
+        ]);
+        self.control.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for ChangeDeviceControlRequest {
     type Reply = ChangeDeviceControlReply;
@@ -9095,6 +11152,12 @@ pub struct ChangeDeviceControlReply {
     pub status: u8,
 }
 impl_debug_if_no_extra_traits!(ChangeDeviceControlReply, "ChangeDeviceControlReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ChangeDeviceControlReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ChangeDeviceControlReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -9174,6 +11237,7 @@ pub const LIST_DEVICE_PROPERTIES_REQUEST: u8 = 36;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ListDevicePropertiesRequest {
     pub device_id: u8,
 }
@@ -9222,6 +11286,33 @@ impl Request for ListDevicePropertiesRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_id_bytes = self.device_id.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            LIST_DEVICE_PROPERTIES_REQUEST,
+            0,
+            0,
+            device_id_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for ListDevicePropertiesRequest {
     type Reply = ListDevicePropertiesReply;
@@ -9237,6 +11328,12 @@ pub struct ListDevicePropertiesReply {
     pub atoms: Vec<xproto::Atom>,
 }
 impl_debug_if_no_extra_traits!(ListDevicePropertiesReply, "ListDevicePropertiesReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ListDevicePropertiesReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ListDevicePropertiesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -9275,6 +11372,7 @@ impl Serialize for ListDevicePropertiesReply {
         num_atoms.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 22]);
         self.atoms.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl ListDevicePropertiesReply {
@@ -9295,6 +11393,7 @@ impl ListDevicePropertiesReply {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PropertyFormat(u8);
 impl PropertyFormat {
     pub const M8_BITS: Self = Self(8);
@@ -9353,10 +11452,27 @@ impl core::fmt::Debug for PropertyFormat  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl PropertyFormat {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            8 => Some("M8Bits"),
+            16 => Some("M16Bits"),
+            32 => Some("M32Bits"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for PropertyFormat {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum ChangeDevicePropertyAux {
     Data8(Vec<u8>),
     Data16(Vec<u16>),
@@ -9480,6 +11596,7 @@ pub const CHANGE_DEVICE_PROPERTY_REQUEST: u8 = 37;
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangeDevicePropertyRequest<'input> {
     pub property: xproto::Atom,
     pub type_: xproto::Atom,
@@ -9578,6 +11695,54 @@ impl<'input> Request for ChangeDevicePropertyRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let property_bytes = self.property.serialize();
+        let type_bytes = self.type_.serialize();
+        let device_id_bytes = self.device_id.serialize();
+        let format: u8 = self.items.switch_expr();
+        let format_bytes = format.serialize();
+        let mode_bytes = u8::from(self.mode).serialize();
+        let num_items_bytes = self.num_items.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            CHANGE_DEVICE_PROPERTY_REQUEST,
+            0,
+            0,
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            device_id_bytes[0],
+            format_bytes[0],
+            mode_bytes[0],
+            0,
+            num_items_bytes[0],
+            num_items_bytes[1],
+            num_items_bytes[2],
+            num_items_bytes[3],
+        ]);
+        self.items.serialize_into(buffer, u8::from(format), u32::from(self.num_items));
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for ChangeDevicePropertyRequest<'input> {
 }
@@ -9587,6 +11752,7 @@ pub const DELETE_DEVICE_PROPERTY_REQUEST: u8 = 38;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DeleteDevicePropertyRequest {
     pub property: xproto::Atom,
     pub device_id: u8,
@@ -9639,9 +11805,41 @@ impl Request for DeleteDevicePropertyRequest {
 
     fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize(major_opcode);
-        // Flatten the buffers into a single vector
-        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
-        (buf, fds)
+        // Flatten the buffers into a single vector
+        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
+        (buf, fds)
+    }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let property_bytes = self.property.serialize();
+        let device_id_bytes = self.device_id.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            DELETE_DEVICE_PROPERTY_REQUEST,
+            0,
+            0,
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            device_id_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
     }
 }
 impl crate::x11_utils::VoidRequest for DeleteDevicePropertyRequest {
@@ -9652,6 +11850,7 @@ pub const GET_DEVICE_PROPERTY_REQUEST: u8 = 39;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetDevicePropertyRequest {
     pub property: xproto::Atom,
     pub type_: xproto::Atom,
@@ -9736,6 +11935,54 @@ impl Request for GetDevicePropertyRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let property_bytes = self.property.serialize();
+        let type_bytes = self.type_.serialize();
+        let offset_bytes = self.offset.serialize();
+        let len_bytes = self.len.serialize();
+        let device_id_bytes = self.device_id.serialize();
+        let delete_bytes = self.delete.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_DEVICE_PROPERTY_REQUEST,
+            0,
+            0,
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            offset_bytes[0],
+            offset_bytes[1],
+            offset_bytes[2],
+            offset_bytes[3],
+            len_bytes[0],
+            len_bytes[1],
+            len_bytes[2],
+            len_bytes[3],
+            device_id_bytes[0],
+            delete_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetDevicePropertyRequest {
     type Reply = GetDevicePropertyReply;
@@ -9744,6 +11991,7 @@ impl crate::x11_utils::ReplyRequest for GetDevicePropertyRequest {
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum GetDevicePropertyItems {
     Data8(Vec<u8>),
     Data16(Vec<u16>),
@@ -9861,6 +12109,18 @@ impl GetDevicePropertyItems {
         }
     }
 }
+#[cfg(feature = "arbitrary")]
+impl GetDevicePropertyItems {
+    /// Whether this value can be serialized, i.e. does not contain an `InvalidValue`.
+    pub(crate) fn is_encodable(&self) -> bool {
+        match self {
+            GetDevicePropertyItems::Data8(_) => true,
+            GetDevicePropertyItems::Data16(_) => true,
+            GetDevicePropertyItems::Data32(_) => true,
+            GetDevicePropertyItems::InvalidValue(_) => false,
+        }
+    }
+}
 
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
@@ -9876,6 +12136,24 @@ pub struct GetDevicePropertyReply {
     pub items: GetDevicePropertyItems,
 }
 impl_debug_if_no_extra_traits!(GetDevicePropertyReply, "GetDevicePropertyReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetDevicePropertyReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let (value, size): (Self, usize) = crate::x11_utils::arbitrary_parse(u)?;
+        if !value.is_encodable() {
+            return Err(arbitrary::Error::IncorrectFormat);
+        }
+        crate::x11_utils::arbitrary_check_size(&value, size, 32)?;
+        Ok(value)
+    }
+}
+#[cfg(feature = "arbitrary")]
+impl GetDevicePropertyReply {
+    /// Whether this value can be serialized, i.e. does not contain an `InvalidValue`.
+    pub(crate) fn is_encodable(&self) -> bool {
+        self.items.is_encodable()
+    }
+}
 impl TryParse for GetDevicePropertyReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -9922,11 +12200,13 @@ impl Serialize for GetDevicePropertyReply {
         self.device_id.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 10]);
         self.items.serialize_into(bytes, u8::from(format), u32::from(self.num_items));
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Device(bool);
 impl Device {
     pub const ALL: Self = Self(false);
@@ -9995,6 +12275,20 @@ impl core::fmt::Debug for Device  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl Device {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            false => Some("All"),
+            true => Some("AllMaster"),
+        }
+    }
+}
+impl core::fmt::Display for Device {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
@@ -10006,6 +12300,12 @@ pub struct GroupInfo {
     pub effective: u8,
 }
 impl_debug_if_no_extra_traits!(GroupInfo, "GroupInfo");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GroupInfo {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for GroupInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (base, remaining) = u8::try_parse(remaining)?;
@@ -10049,6 +12349,12 @@ pub struct ModifierInfo {
     pub effective: u32,
 }
 impl_debug_if_no_extra_traits!(ModifierInfo, "ModifierInfo");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ModifierInfo {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for ModifierInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (base, remaining) = u32::try_parse(remaining)?;
@@ -10099,6 +12405,7 @@ pub const XI_QUERY_POINTER_REQUEST: u8 = 40;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct XIQueryPointerRequest {
     pub window: xproto::Window,
     pub deviceid: DeviceId,
@@ -10155,6 +12462,38 @@ impl Request for XIQueryPointerRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let deviceid_bytes = self.deviceid.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            XI_QUERY_POINTER_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            deviceid_bytes[0],
+            deviceid_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for XIQueryPointerRequest {
     type Reply = XIQueryPointerReply;
@@ -10178,6 +12517,12 @@ pub struct XIQueryPointerReply {
     pub buttons: Vec<u32>,
 }
 impl_debug_if_no_extra_traits!(XIQueryPointerReply, "XIQueryPointerReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for XIQueryPointerReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for XIQueryPointerReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -10234,6 +12579,7 @@ impl Serialize for XIQueryPointerReply {
         self.mods.serialize_into(bytes);
         self.group.serialize_into(bytes);
         self.buttons.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl XIQueryPointerReply {
@@ -10257,6 +12603,7 @@ pub const XI_WARP_POINTER_REQUEST: u8 = 41;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct XIWarpPointerRequest {
     pub src_win: xproto::Window,
     pub dst_win: xproto::Window,
@@ -10365,6 +12712,69 @@ impl Request for XIWarpPointerRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let src_win_bytes = self.src_win.serialize();
+        let dst_win_bytes = self.dst_win.serialize();
+        let src_x_bytes = self.src_x.serialize();
+        let src_y_bytes = self.src_y.serialize();
+        let src_width_bytes = self.src_width.serialize();
+        let src_height_bytes = self.src_height.serialize();
+        let dst_x_bytes = self.dst_x.serialize();
+        let dst_y_bytes = self.dst_y.serialize();
+        let deviceid_bytes = self.deviceid.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            XI_WARP_POINTER_REQUEST,
+            0,
+            0,
+            src_win_bytes[0],
+            src_win_bytes[1],
+            src_win_bytes[2],
+            src_win_bytes[3],
+            dst_win_bytes[0],
+            dst_win_bytes[1],
+            dst_win_bytes[2],
+            dst_win_bytes[3],
+            src_x_bytes[0],
+            src_x_bytes[1],
+            src_x_bytes[2],
+            src_x_bytes[3],
+            src_y_bytes[0],
+            src_y_bytes[1],
+            src_y_bytes[2],
+            src_y_bytes[3],
+            src_width_bytes[0],
+            src_width_bytes[1],
+            src_height_bytes[0],
+            src_height_bytes[1],
+            dst_x_bytes[0],
+            dst_x_bytes[1],
+            dst_x_bytes[2],
+            dst_x_bytes[3],
+            dst_y_bytes[0],
+            dst_y_bytes[1],
+            dst_y_bytes[2],
+            dst_y_bytes[3],
+            deviceid_bytes[0],
+            deviceid_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for XIWarpPointerRequest {
 }
@@ -10374,6 +12784,7 @@ pub const XI_CHANGE_CURSOR_REQUEST: u8 = 42;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct XIChangeCursorRequest {
     pub window: xproto::Window,
     pub cursor: xproto::Cursor,
@@ -10438,12 +12849,50 @@ impl Request for XIChangeCursorRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let cursor_bytes = self.cursor.serialize();
+        let deviceid_bytes = self.deviceid.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            XI_CHANGE_CURSOR_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            cursor_bytes[0],
+            cursor_bytes[1],
+            cursor_bytes[2],
+            cursor_bytes[3],
+            deviceid_bytes[0],
+            deviceid_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for XIChangeCursorRequest {
 }
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct HierarchyChangeType(u16);
 impl HierarchyChangeType {
     pub const ADD_MASTER: Self = Self(1);
@@ -10498,9 +12947,27 @@ impl core::fmt::Debug for HierarchyChangeType  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl HierarchyChangeType {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("AddMaster"),
+            2 => Some("RemoveMaster"),
+            3 => Some("AttachSlave"),
+            4 => Some("DetachSlave"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for HierarchyChangeType {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangeMode(u8);
 impl ChangeMode {
     pub const ATTACH: Self = Self(1);
@@ -10557,6 +13024,21 @@ impl core::fmt::Debug for ChangeMode  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl ChangeMode {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("Attach"),
+            2 => Some("Float"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ChangeMode {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
@@ -10569,6 +13051,12 @@ pub struct AddMaster {
     pub name: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(AddMaster, "AddMaster");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for AddMaster {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for AddMaster {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
@@ -10635,6 +13123,12 @@ pub struct RemoveMaster {
     pub return_keyboard: DeviceId,
 }
 impl_debug_if_no_extra_traits!(RemoveMaster, "RemoveMaster");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for RemoveMaster {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for RemoveMaster {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u16::try_parse(remaining)?;
@@ -10696,6 +13190,12 @@ pub struct AttachSlave {
     pub master: DeviceId,
 }
 impl_debug_if_no_extra_traits!(AttachSlave, "AttachSlave");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for AttachSlave {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for AttachSlave {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u16::try_parse(remaining)?;
@@ -10743,6 +13243,12 @@ pub struct DetachSlave {
     pub deviceid: DeviceId,
 }
 impl_debug_if_no_extra_traits!(DetachSlave, "DetachSlave");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DetachSlave {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for DetachSlave {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u16::try_parse(remaining)?;
@@ -10789,6 +13295,12 @@ pub struct HierarchyChangeDataAddMaster {
     pub name: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(HierarchyChangeDataAddMaster, "HierarchyChangeDataAddMaster");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for HierarchyChangeDataAddMaster {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for HierarchyChangeDataAddMaster {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
@@ -10847,6 +13359,12 @@ pub struct HierarchyChangeDataRemoveMaster {
     pub return_keyboard: DeviceId,
 }
 impl_debug_if_no_extra_traits!(HierarchyChangeDataRemoveMaster, "HierarchyChangeDataRemoveMaster");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for HierarchyChangeDataRemoveMaster {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for HierarchyChangeDataRemoveMaster {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
@@ -10894,6 +13412,12 @@ pub struct HierarchyChangeDataAttachSlave {
     pub master: DeviceId,
 }
 impl_debug_if_no_extra_traits!(HierarchyChangeDataAttachSlave, "HierarchyChangeDataAttachSlave");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for HierarchyChangeDataAttachSlave {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for HierarchyChangeDataAttachSlave {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
@@ -10927,6 +13451,12 @@ pub struct HierarchyChangeDataDetachSlave {
     pub deviceid: DeviceId,
 }
 impl_debug_if_no_extra_traits!(HierarchyChangeDataDetachSlave, "HierarchyChangeDataDetachSlave");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for HierarchyChangeDataDetachSlave {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for HierarchyChangeDataDetachSlave {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
@@ -10955,6 +13485,7 @@ impl Serialize for HierarchyChangeDataDetachSlave {
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum HierarchyChangeData {
     AddMaster(HierarchyChangeDataAddMaster),
     RemoveMaster(HierarchyChangeDataRemoveMaster),
@@ -11062,6 +13593,19 @@ impl HierarchyChangeData {
         }
     }
 }
+#[cfg(feature = "arbitrary")]
+impl HierarchyChangeData {
+    /// Whether this value can be serialized, i.e. does not contain an `InvalidValue`.
+    pub(crate) fn is_encodable(&self) -> bool {
+        match self {
+            HierarchyChangeData::AddMaster(_) => true,
+            HierarchyChangeData::RemoveMaster(_) => true,
+            HierarchyChangeData::AttachSlave(_) => true,
+            HierarchyChangeData::DetachSlave(_) => true,
+            HierarchyChangeData::InvalidValue(_) => false,
+        }
+    }
+}
 
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
@@ -11071,6 +13615,24 @@ pub struct HierarchyChange {
     pub data: HierarchyChangeData,
 }
 impl_debug_if_no_extra_traits!(HierarchyChange, "HierarchyChange");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for HierarchyChange {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let (value, size): (Self, usize) = crate::x11_utils::arbitrary_parse(u)?;
+        if !value.is_encodable() {
+            return Err(arbitrary::Error::IncorrectFormat);
+        }
+        crate::x11_utils::arbitrary_check_size(&value, size, 0)?;
+        Ok(value)
+    }
+}
+#[cfg(feature = "arbitrary")]
+impl HierarchyChange {
+    /// Whether this value can be serialized, i.e. does not contain an `InvalidValue`.
+    pub(crate) fn is_encodable(&self) -> bool {
+        self.data.is_encodable()
+    }
+}
 impl TryParse for HierarchyChange {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u16::try_parse(remaining)?;
@@ -11101,6 +13663,7 @@ pub const XI_CHANGE_HIERARCHY_REQUEST: u8 = 43;
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct XIChangeHierarchyRequest<'input> {
     pub changes: Cow<'input, [HierarchyChange]>,
 }
@@ -11161,6 +13724,37 @@ impl<'input> Request for XIChangeHierarchyRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let num_changes = u8::try_from(self.changes.len()).expect("`changes` has too many elements");
+        let num_changes_bytes = num_changes.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            XI_CHANGE_HIERARCHY_REQUEST,
+            0,
+            0,
+            num_changes_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        self.changes.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for XIChangeHierarchyRequest<'input> {
 }
@@ -11170,6 +13764,7 @@ pub const XI_SET_CLIENT_POINTER_REQUEST: u8 = 44;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct XISetClientPointerRequest {
     pub window: xproto::Window,
     pub deviceid: DeviceId,
@@ -11226,6 +13821,38 @@ impl Request for XISetClientPointerRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let deviceid_bytes = self.deviceid.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            XI_SET_CLIENT_POINTER_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            deviceid_bytes[0],
+            deviceid_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for XISetClientPointerRequest {
 }
@@ -11235,6 +13862,7 @@ pub const XI_GET_CLIENT_POINTER_REQUEST: u8 = 45;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct XIGetClientPointerRequest {
     pub window: xproto::Window,
 }
@@ -11282,6 +13910,33 @@ impl Request for XIGetClientPointerRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            XI_GET_CLIENT_POINTER_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for XIGetClientPointerRequest {
     type Reply = XIGetClientPointerReply;
@@ -11297,6 +13952,12 @@ pub struct XIGetClientPointerReply {
     pub deviceid: DeviceId,
 }
 impl_debug_if_no_extra_traits!(XIGetClientPointerReply, "XIGetClientPointerReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for XIGetClientPointerReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for XIGetClientPointerReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -11377,6 +14038,7 @@ impl Serialize for XIGetClientPointerReply {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct XIEventMask(u32);
 impl XIEventMask {
     pub const DEVICE_CHANGED: Self = Self(1 << 1);
@@ -11469,6 +14131,45 @@ impl core::fmt::Debug for XIEventMask  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl XIEventMask {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            2 => Some("DeviceChanged"),
+            4 => Some("KeyPress"),
+            8 => Some("KeyRelease"),
+            16 => Some("ButtonPress"),
+            32 => Some("ButtonRelease"),
+            64 => Some("Motion"),
+            128 => Some("Enter"),
+            256 => Some("Leave"),
+            512 => Some("FocusIn"),
+            1024 => Some("FocusOut"),
+            2048 => Some("Hierarchy"),
+            4096 => Some("Property"),
+            8192 => Some("RawKeyPress"),
+            16384 => Some("RawKeyRelease"),
+            32768 => Some("RawButtonPress"),
+            65536 => Some("RawButtonRelease"),
+            131_072 => Some("RawMotion"),
+            262_144 => Some("TouchBegin"),
+            524_288 => Some("TouchUpdate"),
+            1_048_576 => Some("TouchEnd"),
+            2_097_152 => Some("TouchOwnership"),
+            4_194_304 => Some("RawTouchBegin"),
+            8_388_608 => Some("RawTouchUpdate"),
+            16_777_216 => Some("RawTouchEnd"),
+            33_554_432 => Some("BarrierHit"),
+            67_108_864 => Some("BarrierLeave"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for XIEventMask {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(XIEventMask, u32);
 
 #[derive(Clone, Default)]
@@ -11479,13 +14180,19 @@ pub struct EventMask {
     pub mask: Vec<XIEventMask>,
 }
 impl_debug_if_no_extra_traits!(EventMask, "EventMask");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for EventMask {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for EventMask {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
         let (mask_len, remaining) = u16::try_parse(remaining)?;
         let mut remaining = remaining;
         let list_length = mask_len.try_to_usize()?;
-        let mut mask = Vec::with_capacity(list_length);
+        let mut mask = Vec::with_capacity(list_length.min(remaining.len()));
         for _ in 0..list_length {
             let (v, new_remaining) = u32::try_parse(remaining)?;
             let v = v.into();
@@ -11534,6 +14241,7 @@ pub const XI_SELECT_EVENTS_REQUEST: u8 = 46;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct XISelectEventsRequest<'input> {
     pub window: xproto::Window,
     pub masks: Cow<'input, [EventMask]>,
@@ -11603,6 +14311,42 @@ impl<'input> Request for XISelectEventsRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let num_mask = u16::try_from(self.masks.len()).expect("`masks` has too many elements");
+        let num_mask_bytes = num_mask.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            XI_SELECT_EVENTS_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            num_mask_bytes[0],
+            num_mask_bytes[1],
+            0,
+            0,
+        ]);
+        self.masks.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for XISelectEventsRequest<'input> {
 }
@@ -11612,6 +14356,7 @@ pub const XI_QUERY_VERSION_REQUEST: u8 = 47;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct XIQueryVersionRequest {
     pub major_version: u16,
     pub minor_version: u16,
@@ -11663,6 +14408,34 @@ impl Request for XIQueryVersionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let major_version_bytes = self.major_version.serialize();
+        let minor_version_bytes = self.minor_version.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            XI_QUERY_VERSION_REQUEST,
+            0,
+            0,
+            major_version_bytes[0],
+            major_version_bytes[1],
+            minor_version_bytes[0],
+            minor_version_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for XIQueryVersionRequest {
     type Reply = XIQueryVersionReply;
@@ -11678,6 +14451,12 @@ pub struct XIQueryVersionReply {
     pub minor_version: u16,
 }
 impl_debug_if_no_extra_traits!(XIQueryVersionReply, "XIQueryVersionReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for XIQueryVersionReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for XIQueryVersionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -11756,6 +14535,7 @@ impl Serialize for XIQueryVersionReply {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DeviceClassType(u16);
 impl DeviceClassType {
     pub const KEY: Self = Self(0);
@@ -11814,9 +14594,29 @@ impl core::fmt::Debug for DeviceClassType  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl DeviceClassType {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Key"),
+            1 => Some("Button"),
+            2 => Some("Valuator"),
+            3 => Some("Scroll"),
+            8 => Some("Touch"),
+            9 => Some("Gesture"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for DeviceClassType {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DeviceType(u16);
 impl DeviceType {
     pub const MASTER_POINTER: Self = Self(1);
@@ -11873,9 +14673,28 @@ impl core::fmt::Debug for DeviceType  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl DeviceType {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("MasterPointer"),
+            2 => Some("MasterKeyboard"),
+            3 => Some("SlavePointer"),
+            4 => Some("SlaveKeyboard"),
+            5 => Some("FloatingSlave"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for DeviceType {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ScrollFlags(u32);
 impl ScrollFlags {
     pub const NO_EMULATION: Self = Self(1 << 0);
@@ -11920,10 +14739,26 @@ impl core::fmt::Debug for ScrollFlags  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl ScrollFlags {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("NoEmulation"),
+            2 => Some("Preferred"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ScrollFlags {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(ScrollFlags, u32);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ScrollType(u16);
 impl ScrollType {
     pub const VERTICAL: Self = Self(1);
@@ -11974,9 +14809,25 @@ impl core::fmt::Debug for ScrollType  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl ScrollType {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("Vertical"),
+            2 => Some("Horizontal"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ScrollType {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct TouchMode(u8);
 impl TouchMode {
     pub const DIRECT: Self = Self(1);
@@ -12033,6 +14884,21 @@ impl core::fmt::Debug for TouchMode  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl TouchMode {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("Direct"),
+            2 => Some("Dependent"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for TouchMode {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
@@ -12045,6 +14911,12 @@ pub struct ButtonClass {
     pub labels: Vec<xproto::Atom>,
 }
 impl_debug_if_no_extra_traits!(ButtonClass, "ButtonClass");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ButtonClass {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for ButtonClass {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u16::try_parse(remaining)?;
@@ -12103,6 +14975,12 @@ pub struct KeyClass {
     pub keys: Vec<u32>,
 }
 impl_debug_if_no_extra_traits!(KeyClass, "KeyClass");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for KeyClass {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for KeyClass {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u16::try_parse(remaining)?;
@@ -12161,6 +15039,12 @@ pub struct ScrollClass {
     pub increment: Fp3232,
 }
 impl_debug_if_no_extra_traits!(ScrollClass, "ScrollClass");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ScrollClass {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for ScrollClass {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u16::try_parse(remaining)?;
@@ -12239,6 +15123,12 @@ pub struct TouchClass {
     pub num_touches: u8,
 }
 impl_debug_if_no_extra_traits!(TouchClass, "TouchClass");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for TouchClass {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for TouchClass {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u16::try_parse(remaining)?;
@@ -12291,6 +15181,12 @@ pub struct GestureClass {
     pub num_touches: u8,
 }
 impl_debug_if_no_extra_traits!(GestureClass, "GestureClass");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GestureClass {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for GestureClass {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u16::try_parse(remaining)?;
@@ -12347,6 +15243,12 @@ pub struct ValuatorClass {
     pub mode: ValuatorMode,
 }
 impl_debug_if_no_extra_traits!(ValuatorClass, "ValuatorClass");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ValuatorClass {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for ValuatorClass {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u16::try_parse(remaining)?;
@@ -12449,6 +15351,12 @@ pub struct DeviceClassDataKey {
     pub keys: Vec<u32>,
 }
 impl_debug_if_no_extra_traits!(DeviceClassDataKey, "DeviceClassDataKey");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceClassDataKey {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for DeviceClassDataKey {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (num_keys, remaining) = u16::try_parse(remaining)?;
@@ -12493,6 +15401,12 @@ pub struct DeviceClassDataButton {
     pub labels: Vec<xproto::Atom>,
 }
 impl_debug_if_no_extra_traits!(DeviceClassDataButton, "DeviceClassDataButton");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceClassDataButton {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for DeviceClassDataButton {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (num_buttons, remaining) = u16::try_parse(remaining)?;
@@ -12545,6 +15459,12 @@ pub struct DeviceClassDataValuator {
     pub mode: ValuatorMode,
 }
 impl_debug_if_no_extra_traits!(DeviceClassDataValuator, "DeviceClassDataValuator");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceClassDataValuator {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for DeviceClassDataValuator {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (number, remaining) = u16::try_parse(remaining)?;
@@ -12633,6 +15553,12 @@ pub struct DeviceClassDataScroll {
     pub increment: Fp3232,
 }
 impl_debug_if_no_extra_traits!(DeviceClassDataScroll, "DeviceClassDataScroll");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceClassDataScroll {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for DeviceClassDataScroll {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (number, remaining) = u16::try_parse(remaining)?;
@@ -12691,6 +15617,12 @@ pub struct DeviceClassDataTouch {
     pub num_touches: u8,
 }
 impl_debug_if_no_extra_traits!(DeviceClassDataTouch, "DeviceClassDataTouch");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceClassDataTouch {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for DeviceClassDataTouch {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (mode, remaining) = u8::try_parse(remaining)?;
@@ -12723,6 +15655,12 @@ pub struct DeviceClassDataGesture {
     pub num_touches: u8,
 }
 impl_debug_if_no_extra_traits!(DeviceClassDataGesture, "DeviceClassDataGesture");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceClassDataGesture {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for DeviceClassDataGesture {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (num_touches, remaining) = u8::try_parse(remaining)?;
@@ -12749,6 +15687,7 @@ impl Serialize for DeviceClassDataGesture {
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum DeviceClassData {
     Key(DeviceClassDataKey),
     Button(DeviceClassDataButton),
@@ -12886,6 +15825,21 @@ impl DeviceClassData {
         }
     }
 }
+#[cfg(feature = "arbitrary")]
+impl DeviceClassData {
+    /// Whether this value can be serialized, i.e. does not contain an `InvalidValue`.
+    pub(crate) fn is_encodable(&self) -> bool {
+        match self {
+            DeviceClassData::Key(_) => true,
+            DeviceClassData::Button(_) => true,
+            DeviceClassData::Valuator(_) => true,
+            DeviceClassData::Scroll(_) => true,
+            DeviceClassData::Touch(_) => true,
+            DeviceClassData::Gesture(_) => true,
+            DeviceClassData::InvalidValue(_) => false,
+        }
+    }
+}
 
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
@@ -12896,6 +15850,24 @@ pub struct DeviceClass {
     pub data: DeviceClassData,
 }
 impl_debug_if_no_extra_traits!(DeviceClass, "DeviceClass");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceClass {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let (value, size): (Self, usize) = crate::x11_utils::arbitrary_parse(u)?;
+        if !value.is_encodable() {
+            return Err(arbitrary::Error::IncorrectFormat);
+        }
+        crate::x11_utils::arbitrary_check_size(&value, size, 0)?;
+        Ok(value)
+    }
+}
+#[cfg(feature = "arbitrary")]
+impl DeviceClass {
+    /// Whether this value can be serialized, i.e. does not contain an `InvalidValue`.
+    pub(crate) fn is_encodable(&self) -> bool {
+        self.data.is_encodable()
+    }
+}
 impl TryParse for DeviceClass {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -12940,6 +15912,24 @@ pub struct XIDeviceInfo {
     pub classes: Vec<DeviceClass>,
 }
 impl_debug_if_no_extra_traits!(XIDeviceInfo, "XIDeviceInfo");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for XIDeviceInfo {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let (value, size): (Self, usize) = crate::x11_utils::arbitrary_parse(u)?;
+        if !value.is_encodable() {
+            return Err(arbitrary::Error::IncorrectFormat);
+        }
+        crate::x11_utils::arbitrary_check_size(&value, size, 0)?;
+        Ok(value)
+    }
+}
+#[cfg(feature = "arbitrary")]
+impl XIDeviceInfo {
+    /// Whether this value can be serialized, i.e. does not contain an `InvalidValue`.
+    pub(crate) fn is_encodable(&self) -> bool {
+        self.classes.iter().all(|item| item.is_encodable())
+    }
+}
 impl TryParse for XIDeviceInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
@@ -13019,6 +16009,7 @@ pub const XI_QUERY_DEVICE_REQUEST: u8 = 48;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct XIQueryDeviceRequest {
     pub deviceid: DeviceId,
 }
@@ -13067,6 +16058,33 @@ impl Request for XIQueryDeviceRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let deviceid_bytes = self.deviceid.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            XI_QUERY_DEVICE_REQUEST,
+            0,
+            0,
+            deviceid_bytes[0],
+            deviceid_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for XIQueryDeviceRequest {
     type Reply = XIQueryDeviceReply;
@@ -13081,6 +16099,24 @@ pub struct XIQueryDeviceReply {
     pub infos: Vec<XIDeviceInfo>,
 }
 impl_debug_if_no_extra_traits!(XIQueryDeviceReply, "XIQueryDeviceReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for XIQueryDeviceReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let (value, size): (Self, usize) = crate::x11_utils::arbitrary_parse(u)?;
+        if !value.is_encodable() {
+            return Err(arbitrary::Error::IncorrectFormat);
+        }
+        crate::x11_utils::arbitrary_check_size(&value, size, 32)?;
+        Ok(value)
+    }
+}
+#[cfg(feature = "arbitrary")]
+impl XIQueryDeviceReply {
+    /// Whether this value can be serialized, i.e. does not contain an `InvalidValue`.
+    pub(crate) fn is_encodable(&self) -> bool {
+        self.infos.iter().all(|item| item.is_encodable())
+    }
+}
 impl TryParse for XIQueryDeviceReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -13119,6 +16155,7 @@ impl Serialize for XIQueryDeviceReply {
         num_infos.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 22]);
         self.infos.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl XIQueryDeviceReply {
@@ -13142,6 +16179,7 @@ pub const XI_SET_FOCUS_REQUEST: u8 = 49;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct XISetFocusRequest {
     pub window: xproto::Window,
     pub time: xproto::Timestamp,
@@ -13206,6 +16244,43 @@ impl Request for XISetFocusRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let time_bytes = self.time.serialize();
+        let deviceid_bytes = self.deviceid.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            XI_SET_FOCUS_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            deviceid_bytes[0],
+            deviceid_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for XISetFocusRequest {
 }
@@ -13215,6 +16290,7 @@ pub const XI_GET_FOCUS_REQUEST: u8 = 50;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct XIGetFocusRequest {
     pub deviceid: DeviceId,
 }
@@ -13263,6 +16339,33 @@ impl Request for XIGetFocusRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let deviceid_bytes = self.deviceid.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            XI_GET_FOCUS_REQUEST,
+            0,
+            0,
+            deviceid_bytes[0],
+            deviceid_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for XIGetFocusRequest {
     type Reply = XIGetFocusReply;
@@ -13277,6 +16380,12 @@ pub struct XIGetFocusReply {
     pub focus: xproto::Window,
 }
 impl_debug_if_no_extra_traits!(XIGetFocusReply, "XIGetFocusReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for XIGetFocusReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for XIGetFocusReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -13352,6 +16461,7 @@ impl Serialize for XIGetFocusReply {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GrabOwner(bool);
 impl GrabOwner {
     pub const NO_OWNER: Self = Self(false);
@@ -13420,12 +16530,27 @@ impl core::fmt::Debug for GrabOwner  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl GrabOwner {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            false => Some("NoOwner"),
+            true => Some("Owner"),
+        }
+    }
+}
+impl core::fmt::Display for GrabOwner {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the XIGrabDevice request
 pub const XI_GRAB_DEVICE_REQUEST: u8 = 51;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct XIGrabDeviceRequest<'input> {
     pub window: xproto::Window,
     pub time: xproto::Timestamp,
@@ -13540,6 +16665,60 @@ impl<'input> Request for XIGrabDeviceRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let time_bytes = self.time.serialize();
+        let cursor_bytes = self.cursor.serialize();
+        let deviceid_bytes = self.deviceid.serialize();
+        let mode_bytes = u8::from(self.mode).serialize();
+        let paired_device_mode_bytes = u8::from(self.paired_device_mode).serialize();
+        let owner_events_bytes = bool::from(self.owner_events).serialize();
+        let mask_len = u16::try_from(self.mask.len()).expect("`mask` has too many elements");
+        let mask_len_bytes = mask_len.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            XI_GRAB_DEVICE_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            cursor_bytes[0],
+            cursor_bytes[1],
+            cursor_bytes[2],
+            cursor_bytes[3],
+            deviceid_bytes[0],
+            deviceid_bytes[1],
+            mode_bytes[0],
+            paired_device_mode_bytes[0],
+            owner_events_bytes[0],
+            0,
+            mask_len_bytes[0],
+            mask_len_bytes[1],
+        ]);
+        self.mask.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::ReplyRequest for XIGrabDeviceRequest<'input> {
     type Reply = XIGrabDeviceReply;
@@ -13554,6 +16733,12 @@ pub struct XIGrabDeviceReply {
     pub status: xproto::GrabStatus,
 }
 impl_debug_if_no_extra_traits!(XIGrabDeviceReply, "XIGrabDeviceReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for XIGrabDeviceReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for XIGrabDeviceReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -13633,6 +16818,7 @@ pub const XI_UNGRAB_DEVICE_REQUEST: u8 = 52;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct XIUngrabDeviceRequest {
     pub time: xproto::Timestamp,
     pub deviceid: DeviceId,
@@ -13689,12 +16875,45 @@ impl Request for XIUngrabDeviceRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let time_bytes = self.time.serialize();
+        let deviceid_bytes = self.deviceid.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            XI_UNGRAB_DEVICE_REQUEST,
+            0,
+            0,
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            deviceid_bytes[0],
+            deviceid_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for XIUngrabDeviceRequest {
 }
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct EventMode(u8);
 impl EventMode {
     pub const ASYNC_DEVICE: Self = Self(0);
@@ -13763,12 +16982,34 @@ impl core::fmt::Debug for EventMode  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl EventMode {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("AsyncDevice"),
+            1 => Some("SyncDevice"),
+            2 => Some("ReplayDevice"),
+            3 => Some("AsyncPairedDevice"),
+            4 => Some("AsyncPair"),
+            5 => Some("SyncPair"),
+            6 => Some("AcceptTouch"),
+            7 => Some("RejectTouch"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for EventMode {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the XIAllowEvents request
 pub const XI_ALLOW_EVENTS_REQUEST: u8 = 53;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct XIAllowEventsRequest {
     pub time: xproto::Timestamp,
     pub deviceid: DeviceId,
@@ -13846,12 +17087,56 @@ impl Request for XIAllowEventsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let time_bytes = self.time.serialize();
+        let deviceid_bytes = self.deviceid.serialize();
+        let event_mode_bytes = u8::from(self.event_mode).serialize();
+        let touchid_bytes = self.touchid.serialize();
+        let grab_window_bytes = self.grab_window.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            XI_ALLOW_EVENTS_REQUEST,
+            0,
+            0,
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            deviceid_bytes[0],
+            deviceid_bytes[1],
+            event_mode_bytes[0],
+            0,
+            touchid_bytes[0],
+            touchid_bytes[1],
+            touchid_bytes[2],
+            touchid_bytes[3],
+            grab_window_bytes[0],
+            grab_window_bytes[1],
+            grab_window_bytes[2],
+            grab_window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for XIAllowEventsRequest {
 }
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GrabMode22(u8);
 impl GrabMode22 {
     pub const SYNC: Self = Self(0);
@@ -13910,9 +17195,26 @@ impl core::fmt::Debug for GrabMode22  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl GrabMode22 {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Sync"),
+            1 => Some("Async"),
+            2 => Some("Touch"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for GrabMode22 {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GrabType(u8);
 impl GrabType {
     pub const BUTTON: Self = Self(0);
@@ -13979,9 +17281,30 @@ impl core::fmt::Debug for GrabType  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl GrabType {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Button"),
+            1 => Some("Keycode"),
+            2 => Some("Enter"),
+            3 => Some("FocusIn"),
+            4 => Some("TouchBegin"),
+            5 => Some("GesturePinchBegin"),
+            6 => Some("GestureSwipeBegin"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for GrabType {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ModifierMask(u32);
 impl ModifierMask {
     pub const ANY: Self = Self(1 << 31);
@@ -14024,6 +17347,20 @@ impl core::fmt::Debug for ModifierMask  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl ModifierMask {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            2_147_483_648 => Some("Any"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ModifierMask {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(ModifierMask, u32);
 
 #[derive(Clone, Copy, Default)]
@@ -14034,6 +17371,12 @@ pub struct GrabModifierInfo {
     pub status: xproto::GrabStatus,
 }
 impl_debug_if_no_extra_traits!(GrabModifierInfo, "GrabModifierInfo");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GrabModifierInfo {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for GrabModifierInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (modifiers, remaining) = u32::try_parse(remaining)?;
@@ -14073,6 +17416,7 @@ pub const XI_PASSIVE_GRAB_DEVICE_REQUEST: u8 = 54;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct XIPassiveGrabDeviceRequest<'input> {
     pub time: xproto::Timestamp,
     pub grab_window: xproto::Window,
@@ -14215,6 +17559,73 @@ impl<'input> Request for XIPassiveGrabDeviceRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let time_bytes = self.time.serialize();
+        let grab_window_bytes = self.grab_window.serialize();
+        let cursor_bytes = self.cursor.serialize();
+        let detail_bytes = self.detail.serialize();
+        let deviceid_bytes = self.deviceid.serialize();
+        let num_modifiers = u16::try_from(self.modifiers.len()).expect("`modifiers` has too many elements");
+        let num_modifiers_bytes = num_modifiers.serialize();
+        let mask_len = u16::try_from(self.mask.len()).expect("`mask` has too many elements");
+        let mask_len_bytes = mask_len.serialize();
+        let grab_type_bytes = u8::from(self.grab_type).serialize();
+        let grab_mode_bytes = u8::from(self.grab_mode).serialize();
+        let paired_device_mode_bytes = u8::from(self.paired_device_mode).serialize();
+        let owner_events_bytes = bool::from(self.owner_events).serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            XI_PASSIVE_GRAB_DEVICE_REQUEST,
+            0,
+            0,
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            grab_window_bytes[0],
+            grab_window_bytes[1],
+            grab_window_bytes[2],
+            grab_window_bytes[3],
+            cursor_bytes[0],
+            cursor_bytes[1],
+            cursor_bytes[2],
+            cursor_bytes[3],
+            detail_bytes[0],
+            detail_bytes[1],
+            detail_bytes[2],
+            detail_bytes[3],
+            deviceid_bytes[0],
+            deviceid_bytes[1],
+            num_modifiers_bytes[0],
+            num_modifiers_bytes[1],
+            mask_len_bytes[0],
+            mask_len_bytes[1],
+            grab_type_bytes[0],
+            grab_mode_bytes[0],
+            paired_device_mode_bytes[0],
+            owner_events_bytes[0],
+            0,
+            0,
+        ]);
+        self.mask.serialize_into(buffer);
+        self.modifiers.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::ReplyRequest for XIPassiveGrabDeviceRequest<'input> {
     type Reply = XIPassiveGrabDeviceReply;
@@ -14229,6 +17640,12 @@ pub struct XIPassiveGrabDeviceReply {
     pub modifiers: Vec<GrabModifierInfo>,
 }
 impl_debug_if_no_extra_traits!(XIPassiveGrabDeviceReply, "XIPassiveGrabDeviceReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for XIPassiveGrabDeviceReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for XIPassiveGrabDeviceReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -14267,6 +17684,7 @@ impl Serialize for XIPassiveGrabDeviceReply {
         num_modifiers.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 22]);
         self.modifiers.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl XIPassiveGrabDeviceReply {
@@ -14290,6 +17708,7 @@ pub const XI_PASSIVE_UNGRAB_DEVICE_REQUEST: u8 = 55;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct XIPassiveUngrabDeviceRequest<'input> {
     pub grab_window: xproto::Window,
     pub detail: u32,
@@ -14383,6 +17802,53 @@ impl<'input> Request for XIPassiveUngrabDeviceRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let grab_window_bytes = self.grab_window.serialize();
+        let detail_bytes = self.detail.serialize();
+        let deviceid_bytes = self.deviceid.serialize();
+        let num_modifiers = u16::try_from(self.modifiers.len()).expect("`modifiers` has too many elements");
+        let num_modifiers_bytes = num_modifiers.serialize();
+        let grab_type_bytes = u8::from(self.grab_type).serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            XI_PASSIVE_UNGRAB_DEVICE_REQUEST,
+            0,
+            0,
+            grab_window_bytes[0],
+            grab_window_bytes[1],
+            grab_window_bytes[2],
+            grab_window_bytes[3],
+            detail_bytes[0],
+            detail_bytes[1],
+            detail_bytes[2],
+            detail_bytes[3],
+            deviceid_bytes[0],
+            deviceid_bytes[1],
+            num_modifiers_bytes[0],
+            num_modifiers_bytes[1],
+            grab_type_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        self.modifiers.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for XIPassiveUngrabDeviceRequest<'input> {
 }
@@ -14392,6 +17858,7 @@ pub const XI_LIST_PROPERTIES_REQUEST: u8 = 56;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct XIListPropertiesRequest {
     pub deviceid: DeviceId,
 }
@@ -14440,6 +17907,33 @@ impl Request for XIListPropertiesRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let deviceid_bytes = self.deviceid.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            XI_LIST_PROPERTIES_REQUEST,
+            0,
+            0,
+            deviceid_bytes[0],
+            deviceid_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for XIListPropertiesRequest {
     type Reply = XIListPropertiesReply;
@@ -14454,6 +17948,12 @@ pub struct XIListPropertiesReply {
     pub properties: Vec<xproto::Atom>,
 }
 impl_debug_if_no_extra_traits!(XIListPropertiesReply, "XIListPropertiesReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for XIListPropertiesReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for XIListPropertiesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -14492,6 +17992,7 @@ impl Serialize for XIListPropertiesReply {
         num_properties.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 22]);
         self.properties.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl XIListPropertiesReply {
@@ -14513,6 +18014,7 @@ impl XIListPropertiesReply {
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum XIChangePropertyAux {
     Data8(Vec<u8>),
     Data16(Vec<u16>),
@@ -14636,6 +18138,7 @@ pub const XI_CHANGE_PROPERTY_REQUEST: u8 = 57;
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct XIChangePropertyRequest<'input> {
     pub deviceid: DeviceId,
     pub mode: xproto::PropMode,
@@ -14733,6 +18236,54 @@ impl<'input> Request for XIChangePropertyRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let deviceid_bytes = self.deviceid.serialize();
+        let mode_bytes = u8::from(self.mode).serialize();
+        let format: u8 = self.items.switch_expr();
+        let format_bytes = format.serialize();
+        let property_bytes = self.property.serialize();
+        let type_bytes = self.type_.serialize();
+        let num_items_bytes = self.num_items.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            XI_CHANGE_PROPERTY_REQUEST,
+            0,
+            0,
+            deviceid_bytes[0],
+            deviceid_bytes[1],
+            mode_bytes[0],
+            format_bytes[0],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            num_items_bytes[0],
+            num_items_bytes[1],
+            num_items_bytes[2],
+            num_items_bytes[3],
+        ]);
+        self.items.serialize_into(buffer, u8::from(format), u32::from(self.num_items));
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for XIChangePropertyRequest<'input> {
 }
@@ -14742,6 +18293,7 @@ pub const XI_DELETE_PROPERTY_REQUEST: u8 = 58;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct XIDeletePropertyRequest {
     pub deviceid: DeviceId,
     pub property: xproto::Atom,
@@ -14798,6 +18350,38 @@ impl Request for XIDeletePropertyRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let deviceid_bytes = self.deviceid.serialize();
+        let property_bytes = self.property.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            XI_DELETE_PROPERTY_REQUEST,
+            0,
+            0,
+            deviceid_bytes[0],
+            deviceid_bytes[1],
+            0,
+            0,
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for XIDeletePropertyRequest {
 }
@@ -14807,6 +18391,7 @@ pub const XI_GET_PROPERTY_REQUEST: u8 = 59;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct XIGetPropertyRequest {
     pub deviceid: DeviceId,
     pub delete: bool,
@@ -14891,6 +18476,54 @@ impl Request for XIGetPropertyRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let deviceid_bytes = self.deviceid.serialize();
+        let delete_bytes = self.delete.serialize();
+        let property_bytes = self.property.serialize();
+        let type_bytes = self.type_.serialize();
+        let offset_bytes = self.offset.serialize();
+        let len_bytes = self.len.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            XI_GET_PROPERTY_REQUEST,
+            0,
+            0,
+            deviceid_bytes[0],
+            deviceid_bytes[1],
+            delete_bytes[0],
+            0,
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            offset_bytes[0],
+            offset_bytes[1],
+            offset_bytes[2],
+            offset_bytes[3],
+            len_bytes[0],
+            len_bytes[1],
+            len_bytes[2],
+            len_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for XIGetPropertyRequest {
     type Reply = XIGetPropertyReply;
@@ -14899,6 +18532,7 @@ impl crate::x11_utils::ReplyRequest for XIGetPropertyRequest {
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum XIGetPropertyItems {
     Data8(Vec<u8>),
     Data16(Vec<u16>),
@@ -15016,6 +18650,18 @@ impl XIGetPropertyItems {
         }
     }
 }
+#[cfg(feature = "arbitrary")]
+impl XIGetPropertyItems {
+    /// Whether this value can be serialized, i.e. does not contain an `InvalidValue`.
+    pub(crate) fn is_encodable(&self) -> bool {
+        match self {
+            XIGetPropertyItems::Data8(_) => true,
+            XIGetPropertyItems::Data16(_) => true,
+            XIGetPropertyItems::Data32(_) => true,
+            XIGetPropertyItems::InvalidValue(_) => false,
+        }
+    }
+}
 
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
@@ -15028,7 +18674,25 @@ pub struct XIGetPropertyReply {
     pub num_items: u32,
     pub items: XIGetPropertyItems,
 }
-impl_debug_if_no_extra_traits!(XIGetPropertyReply, "XIGetPropertyReply");
+impl_debug_if_no_extra_traits!(XIGetPropertyReply, "XIGetPropertyReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for XIGetPropertyReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let (value, size): (Self, usize) = crate::x11_utils::arbitrary_parse(u)?;
+        if !value.is_encodable() {
+            return Err(arbitrary::Error::IncorrectFormat);
+        }
+        crate::x11_utils::arbitrary_check_size(&value, size, 32)?;
+        Ok(value)
+    }
+}
+#[cfg(feature = "arbitrary")]
+impl XIGetPropertyReply {
+    /// Whether this value can be serialized, i.e. does not contain an `InvalidValue`.
+    pub(crate) fn is_encodable(&self) -> bool {
+        self.items.is_encodable()
+    }
+}
 impl TryParse for XIGetPropertyReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -15073,6 +18737,7 @@ impl Serialize for XIGetPropertyReply {
         format.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 11]);
         self.items.serialize_into(bytes, u8::from(format), u32::from(self.num_items));
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 
@@ -15081,6 +18746,7 @@ pub const XI_GET_SELECTED_EVENTS_REQUEST: u8 = 60;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct XIGetSelectedEventsRequest {
     pub window: xproto::Window,
 }
@@ -15128,6 +18794,33 @@ impl Request for XIGetSelectedEventsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            XI_GET_SELECTED_EVENTS_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for XIGetSelectedEventsRequest {
     type Reply = XIGetSelectedEventsReply;
@@ -15142,6 +18835,12 @@ pub struct XIGetSelectedEventsReply {
     pub masks: Vec<EventMask>,
 }
 impl_debug_if_no_extra_traits!(XIGetSelectedEventsReply, "XIGetSelectedEventsReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for XIGetSelectedEventsReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for XIGetSelectedEventsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -15180,6 +18879,7 @@ impl Serialize for XIGetSelectedEventsReply {
         num_masks.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 22]);
         self.masks.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl XIGetSelectedEventsReply {
@@ -15207,6 +18907,12 @@ pub struct BarrierReleasePointerInfo {
     pub eventid: u32,
 }
 impl_debug_if_no_extra_traits!(BarrierReleasePointerInfo, "BarrierReleasePointerInfo");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for BarrierReleasePointerInfo {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for BarrierReleasePointerInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
@@ -15252,6 +18958,7 @@ pub const XI_BARRIER_RELEASE_POINTER_REQUEST: u8 = 61;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct XIBarrierReleasePointerRequest<'input> {
     pub barriers: Cow<'input, [BarrierReleasePointerInfo]>,
 }
@@ -15311,6 +19018,37 @@ impl<'input> Request for XIBarrierReleasePointerRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let num_barriers = u32::try_from(self.barriers.len()).expect("`barriers` has too many elements");
+        let num_barriers_bytes = num_barriers.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            XI_BARRIER_RELEASE_POINTER_REQUEST,
+            0,
+            0,
+            num_barriers_bytes[0],
+            num_barriers_bytes[1],
+            num_barriers_bytes[2],
+            num_barriers_bytes[3],
+        ]);
+        self.barriers.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for XIBarrierReleasePointerRequest<'input> {
 }
@@ -15330,6 +19068,12 @@ pub struct DeviceValuatorEvent {
     pub valuators: [i32; 6],
 }
 impl_debug_if_no_extra_traits!(DeviceValuatorEvent, "DeviceValuatorEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceValuatorEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for DeviceValuatorEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -15479,6 +19223,7 @@ impl From<DeviceValuatorEvent> for [u8; 32] {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct MoreEventsMask(u8);
 impl MoreEventsMask {
     pub const MORE_EVENTS: Self = Self(1 << 7);
@@ -15533,6 +19278,20 @@ impl core::fmt::Debug for MoreEventsMask  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl MoreEventsMask {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            128 => Some("MoreEvents"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for MoreEventsMask {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(MoreEventsMask, u8);
 
 /// Opcode for the DeviceKeyPress event
@@ -15557,6 +19316,12 @@ pub struct DeviceKeyPressEvent {
     pub device_id: u8,
 }
 impl_debug_if_no_extra_traits!(DeviceKeyPressEvent, "DeviceKeyPressEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceKeyPressEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for DeviceKeyPressEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -15741,6 +19506,12 @@ pub struct DeviceFocusInEvent {
     pub device_id: u8,
 }
 impl_debug_if_no_extra_traits!(DeviceFocusInEvent, "DeviceFocusInEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceFocusInEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for DeviceFocusInEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -15883,6 +19654,7 @@ pub type ProximityOutEvent = DeviceKeyPressEvent;
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ClassesReportedMask(u8);
 impl ClassesReportedMask {
     pub const OUT_OF_PROXIMITY: Self = Self(1 << 7);
@@ -15945,6 +19717,24 @@ impl core::fmt::Debug for ClassesReportedMask  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl ClassesReportedMask {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            128 => Some("OutOfProximity"),
+            64 => Some("DeviceModeAbsolute"),
+            4 => Some("ReportingValuators"),
+            2 => Some("ReportingButtons"),
+            1 => Some("ReportingKeys"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ClassesReportedMask {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(ClassesReportedMask, u8);
 
 /// Opcode for the DeviceStateNotify event
@@ -15966,6 +19756,12 @@ pub struct DeviceStateNotifyEvent {
     pub valuators: [u32; 3],
 }
 impl_debug_if_no_extra_traits!(DeviceStateNotifyEvent, "DeviceStateNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceStateNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for DeviceStateNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -16129,6 +19925,12 @@ pub struct DeviceMappingNotifyEvent {
     pub time: xproto::Timestamp,
 }
 impl_debug_if_no_extra_traits!(DeviceMappingNotifyEvent, "DeviceMappingNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceMappingNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for DeviceMappingNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -16260,6 +20062,7 @@ impl From<DeviceMappingNotifyEvent> for [u8; 32] {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangeDevice(u8);
 impl ChangeDevice {
     pub const NEW_POINTER: Self = Self(0);
@@ -16316,6 +20119,21 @@ impl core::fmt::Debug for ChangeDevice  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl ChangeDevice {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("NewPointer"),
+            1 => Some("NewKeyboard"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ChangeDevice {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the ChangeDeviceNotify event
 pub const CHANGE_DEVICE_NOTIFY_EVENT: u8 = 12;
@@ -16330,6 +20148,12 @@ pub struct ChangeDeviceNotifyEvent {
     pub request: ChangeDevice,
 }
 impl_debug_if_no_extra_traits!(ChangeDeviceNotifyEvent, "ChangeDeviceNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ChangeDeviceNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ChangeDeviceNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -16461,6 +20285,12 @@ pub struct DeviceKeyStateNotifyEvent {
     pub keys: [u8; 28],
 }
 impl_debug_if_no_extra_traits!(DeviceKeyStateNotifyEvent, "DeviceKeyStateNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceKeyStateNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for DeviceKeyStateNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -16583,6 +20413,12 @@ pub struct DeviceButtonStateNotifyEvent {
     pub buttons: [u8; 28],
 }
 impl_debug_if_no_extra_traits!(DeviceButtonStateNotifyEvent, "DeviceButtonStateNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceButtonStateNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for DeviceButtonStateNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -16695,6 +20531,7 @@ impl From<DeviceButtonStateNotifyEvent> for [u8; 32] {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DeviceChange(u8);
 impl DeviceChange {
     pub const ADDED: Self = Self(0);
@@ -16759,6 +20596,25 @@ impl core::fmt::Debug for DeviceChange  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl DeviceChange {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Added"),
+            1 => Some("Removed"),
+            2 => Some("Enabled"),
+            3 => Some("Disabled"),
+            4 => Some("Unrecoverable"),
+            5 => Some("ControlChanged"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for DeviceChange {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the DevicePresenceNotify event
 pub const DEVICE_PRESENCE_NOTIFY_EVENT: u8 = 15;
@@ -16774,6 +20630,12 @@ pub struct DevicePresenceNotifyEvent {
     pub control: u16,
 }
 impl_debug_if_no_extra_traits!(DevicePresenceNotifyEvent, "DevicePresenceNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DevicePresenceNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for DevicePresenceNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -16913,6 +20775,12 @@ pub struct DevicePropertyNotifyEvent {
     pub device_id: u8,
 }
 impl_debug_if_no_extra_traits!(DevicePropertyNotifyEvent, "DevicePropertyNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DevicePropertyNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for DevicePropertyNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -17038,6 +20906,7 @@ impl From<DevicePropertyNotifyEvent> for [u8; 32] {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangeReason(u8);
 impl ChangeReason {
     pub const SLAVE_SWITCH: Self = Self(1);
@@ -17094,6 +20963,21 @@ impl core::fmt::Debug for ChangeReason  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl ChangeReason {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("SlaveSwitch"),
+            2 => Some("DeviceChange"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ChangeReason {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the DeviceChanged event
 pub const DEVICE_CHANGED_EVENT: u16 = 1;
@@ -17113,6 +20997,24 @@ pub struct DeviceChangedEvent {
     pub classes: Vec<DeviceClass>,
 }
 impl_debug_if_no_extra_traits!(DeviceChangedEvent, "DeviceChangedEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceChangedEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let (value, size): (Self, usize) = crate::x11_utils::arbitrary_parse(u)?;
+        if !value.is_encodable() {
+            return Err(arbitrary::Error::IncorrectFormat);
+        }
+        crate::x11_utils::arbitrary_check_size(&value, size, 32)?;
+        Ok(value)
+    }
+}
+#[cfg(feature = "arbitrary")]
+impl DeviceChangedEvent {
+    /// Whether this value can be serialized, i.e. does not contain an `InvalidValue`.
+    pub(crate) fn is_encodable(&self) -> bool {
+        self.classes.iter().all(|item| item.is_encodable())
+    }
+}
 impl TryParse for DeviceChangedEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -17158,6 +21060,7 @@ impl Serialize for DeviceChangedEvent {
         u8::from(self.reason).serialize_into(bytes);
         bytes.extend_from_slice(&[0; 11]);
         self.classes.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl DeviceChangedEvent {
@@ -17178,6 +21081,7 @@ impl DeviceChangedEvent {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct KeyEventFlags(u32);
 impl KeyEventFlags {
     pub const KEY_REPEAT: Self = Self(1 << 16);
@@ -17220,6 +21124,20 @@ impl core::fmt::Debug for KeyEventFlags  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl KeyEventFlags {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            65536 => Some("KeyRepeat"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for KeyEventFlags {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(KeyEventFlags, u32);
 
 /// Opcode for the KeyPress event
@@ -17252,6 +21170,12 @@ pub struct KeyPressEvent {
     pub axisvalues: Vec<Fp3232>,
 }
 impl_debug_if_no_extra_traits!(KeyPressEvent, "KeyPressEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for KeyPressEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for KeyPressEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -17325,6 +21249,7 @@ impl Serialize for KeyPressEvent {
         self.valuator_mask.serialize_into(bytes);
         assert_eq!(self.axisvalues.len(), usize::try_from(self.valuator_mask.iter().fold(0u32, |acc, x| acc.checked_add(u32::from(*x).count_ones()).unwrap())).unwrap(), "`axisvalues` has an incorrect length");
         self.axisvalues.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl KeyPressEvent {
@@ -17362,6 +21287,7 @@ pub type KeyReleaseEvent = KeyPressEvent;
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PointerEventFlags(u32);
 impl PointerEventFlags {
     pub const POINTER_EMULATED: Self = Self(1 << 16);
@@ -17404,6 +21330,20 @@ impl core::fmt::Debug for PointerEventFlags  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl PointerEventFlags {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            65536 => Some("PointerEmulated"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for PointerEventFlags {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(PointerEventFlags, u32);
 
 /// Opcode for the ButtonPress event
@@ -17436,6 +21376,12 @@ pub struct ButtonPressEvent {
     pub axisvalues: Vec<Fp3232>,
 }
 impl_debug_if_no_extra_traits!(ButtonPressEvent, "ButtonPressEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ButtonPressEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ButtonPressEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -17509,6 +21455,7 @@ impl Serialize for ButtonPressEvent {
         self.valuator_mask.serialize_into(bytes);
         assert_eq!(self.axisvalues.len(), usize::try_from(self.valuator_mask.iter().fold(0u32, |acc, x| acc.checked_add(u32::from(*x).count_ones()).unwrap())).unwrap(), "`axisvalues` has an incorrect length");
         self.axisvalues.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl ButtonPressEvent {
@@ -17550,6 +21497,7 @@ pub type MotionEvent = ButtonPressEvent;
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct NotifyMode(u8);
 impl NotifyMode {
     pub const NORMAL: Self = Self(0);
@@ -17614,9 +21562,29 @@ impl core::fmt::Debug for NotifyMode  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl NotifyMode {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Normal"),
+            1 => Some("Grab"),
+            2 => Some("Ungrab"),
+            3 => Some("WhileGrabbed"),
+            4 => Some("PassiveGrab"),
+            5 => Some("PassiveUngrab"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for NotifyMode {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct NotifyDetail(u8);
 impl NotifyDetail {
     pub const ANCESTOR: Self = Self(0);
@@ -17685,6 +21653,27 @@ impl core::fmt::Debug for NotifyDetail  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl NotifyDetail {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Ancestor"),
+            1 => Some("Virtual"),
+            2 => Some("Inferior"),
+            3 => Some("Nonlinear"),
+            4 => Some("NonlinearVirtual"),
+            5 => Some("Pointer"),
+            6 => Some("PointerRoot"),
+            7 => Some("None"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for NotifyDetail {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the Enter event
 pub const ENTER_EVENT: u16 = 7;
@@ -17716,6 +21705,12 @@ pub struct EnterEvent {
     pub buttons: Vec<u32>,
 }
 impl_debug_if_no_extra_traits!(EnterEvent, "EnterEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for EnterEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for EnterEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -17784,6 +21779,7 @@ impl Serialize for EnterEvent {
         self.mods.serialize_into(bytes);
         self.group.serialize_into(bytes);
         self.buttons.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl EnterEvent {
@@ -17816,6 +21812,7 @@ pub type FocusOutEvent = EnterEvent;
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct HierarchyMask(u32);
 impl HierarchyMask {
     pub const MASTER_ADDED: Self = Self(1 << 0);
@@ -17872,6 +21869,27 @@ impl core::fmt::Debug for HierarchyMask  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl HierarchyMask {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("MasterAdded"),
+            2 => Some("MasterRemoved"),
+            4 => Some("SlaveAdded"),
+            8 => Some("SlaveRemoved"),
+            16 => Some("SlaveAttached"),
+            32 => Some("SlaveDetached"),
+            64 => Some("DeviceEnabled"),
+            128 => Some("DeviceDisabled"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for HierarchyMask {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(HierarchyMask, u32);
 
 #[derive(Clone, Copy, Default)]
@@ -17885,6 +21903,12 @@ pub struct HierarchyInfo {
     pub flags: HierarchyMask,
 }
 impl_debug_if_no_extra_traits!(HierarchyInfo, "HierarchyInfo");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for HierarchyInfo {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for HierarchyInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (deviceid, remaining) = DeviceId::try_parse(remaining)?;
@@ -17950,6 +21974,12 @@ pub struct HierarchyEvent {
     pub infos: Vec<HierarchyInfo>,
 }
 impl_debug_if_no_extra_traits!(HierarchyEvent, "HierarchyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for HierarchyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for HierarchyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -17993,6 +22023,7 @@ impl Serialize for HierarchyEvent {
         num_infos.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 10]);
         self.infos.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl HierarchyEvent {
@@ -18013,6 +22044,7 @@ impl HierarchyEvent {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PropertyFlag(u8);
 impl PropertyFlag {
     pub const DELETED: Self = Self(0);
@@ -18071,6 +22103,22 @@ impl core::fmt::Debug for PropertyFlag  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl PropertyFlag {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Deleted"),
+            1 => Some("Created"),
+            2 => Some("Modified"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for PropertyFlag {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the Property event
 pub const PROPERTY_EVENT: u16 = 12;
@@ -18089,6 +22137,12 @@ pub struct PropertyEvent {
     pub what: PropertyFlag,
 }
 impl_debug_if_no_extra_traits!(PropertyEvent, "PropertyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for PropertyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for PropertyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -18193,6 +22247,12 @@ pub struct RawKeyPressEvent {
     pub axisvalues_raw: Vec<Fp3232>,
 }
 impl_debug_if_no_extra_traits!(RawKeyPressEvent, "RawKeyPressEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for RawKeyPressEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for RawKeyPressEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -18246,6 +22306,7 @@ impl Serialize for RawKeyPressEvent {
         self.axisvalues.serialize_into(bytes);
         assert_eq!(self.axisvalues_raw.len(), usize::try_from(self.valuator_mask.iter().fold(0u32, |acc, x| acc.checked_add(u32::from(*x).count_ones()).unwrap())).unwrap(), "`axisvalues_raw` has an incorrect length");
         self.axisvalues_raw.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl RawKeyPressEvent {
@@ -18289,6 +22350,12 @@ pub struct RawButtonPressEvent {
     pub axisvalues_raw: Vec<Fp3232>,
 }
 impl_debug_if_no_extra_traits!(RawButtonPressEvent, "RawButtonPressEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for RawButtonPressEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for RawButtonPressEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -18342,6 +22409,7 @@ impl Serialize for RawButtonPressEvent {
         self.axisvalues.serialize_into(bytes);
         assert_eq!(self.axisvalues_raw.len(), usize::try_from(self.valuator_mask.iter().fold(0u32, |acc, x| acc.checked_add(u32::from(*x).count_ones()).unwrap())).unwrap(), "`axisvalues_raw` has an incorrect length");
         self.axisvalues_raw.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl RawButtonPressEvent {
@@ -18370,6 +22438,7 @@ pub type RawMotionEvent = RawButtonPressEvent;
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct TouchEventFlags(u32);
 impl TouchEventFlags {
     pub const TOUCH_PENDING_END: Self = Self(1 << 16);
@@ -18414,6 +22483,21 @@ impl core::fmt::Debug for TouchEventFlags  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl TouchEventFlags {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            65536 => Some("TouchPendingEnd"),
+            131_072 => Some("TouchEmulatingPointer"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for TouchEventFlags {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(TouchEventFlags, u32);
 
 /// Opcode for the TouchBegin event
@@ -18446,6 +22530,12 @@ pub struct TouchBeginEvent {
     pub axisvalues: Vec<Fp3232>,
 }
 impl_debug_if_no_extra_traits!(TouchBeginEvent, "TouchBeginEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for TouchBeginEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for TouchBeginEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -18519,6 +22609,7 @@ impl Serialize for TouchBeginEvent {
         self.valuator_mask.serialize_into(bytes);
         assert_eq!(self.axisvalues.len(), usize::try_from(self.valuator_mask.iter().fold(0u32, |acc, x| acc.checked_add(u32::from(*x).count_ones()).unwrap())).unwrap(), "`axisvalues` has an incorrect length");
         self.axisvalues.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl TouchBeginEvent {
@@ -18560,6 +22651,7 @@ pub type TouchEndEvent = TouchBeginEvent;
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct TouchOwnershipFlags(u32);
 impl TouchOwnershipFlags {
     pub const NONE: Self = Self(0);
@@ -18602,6 +22694,20 @@ impl core::fmt::Debug for TouchOwnershipFlags  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+impl TouchOwnershipFlags {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("None"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for TouchOwnershipFlags {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the TouchOwnership event
 pub const TOUCH_OWNERSHIP_EVENT: u16 = 21;
@@ -18624,6 +22730,12 @@ pub struct TouchOwnershipEvent {
     pub flags: TouchOwnershipFlags,
 }
 impl_debug_if_no_extra_traits!(TouchOwnershipEvent, "TouchOwnershipEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for TouchOwnershipEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for TouchOwnershipEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -18758,6 +22870,12 @@ pub struct RawTouchBeginEvent {
     pub axisvalues_raw: Vec<Fp3232>,
 }
 impl_debug_if_no_extra_traits!(RawTouchBeginEvent, "RawTouchBeginEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for RawTouchBeginEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for RawTouchBeginEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -18811,6 +22929,7 @@ impl Serialize for RawTouchBeginEvent {
         self.axisvalues.serialize_into(bytes);
         assert_eq!(self.axisvalues_raw.len(), usize::try_from(self.valuator_mask.iter().fold(0u32, |acc, x| acc.checked_add(u32::from(*x).count_ones()).unwrap())).unwrap(), "`axisvalues_raw` has an incorrect length");
         self.axisvalues_raw.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl RawTouchBeginEvent {
@@ -18839,6 +22958,7 @@ pub type RawTouchEndEvent = RawTouchBeginEvent;
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct BarrierFlags(u32);
 impl BarrierFlags {
     pub const POINTER_RELEASED: Self = Self(1 << 0);
@@ -18883,6 +23003,21 @@ impl core::fmt::Debug for BarrierFlags  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl BarrierFlags {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("PointerReleased"),
+            2 => Some("DeviceIsGrabbed"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for BarrierFlags {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(BarrierFlags, u32);
 
 /// Opcode for the BarrierHit event
@@ -18911,6 +23046,12 @@ pub struct BarrierHitEvent {
     pub dy: Fp3232,
 }
 impl_debug_if_no_extra_traits!(BarrierHitEvent, "BarrierHitEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for BarrierHitEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for BarrierHitEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -19063,6 +23204,7 @@ pub type BarrierLeaveEvent = BarrierHitEvent;
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GesturePinchEventFlags(u32);
 impl GesturePinchEventFlags {
     pub const GESTURE_PINCH_CANCELLED: Self = Self(1 << 0);
@@ -19105,6 +23247,20 @@ impl core::fmt::Debug for GesturePinchEventFlags  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl GesturePinchEventFlags {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("GesturePinchCancelled"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for GesturePinchEventFlags {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(GesturePinchEventFlags, u32);
 
 /// Opcode for the GesturePinchBegin event
@@ -19140,6 +23296,12 @@ pub struct GesturePinchBeginEvent {
     pub flags: GesturePinchEventFlags,
 }
 impl_debug_if_no_extra_traits!(GesturePinchBeginEvent, "GesturePinchBeginEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GesturePinchBeginEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GesturePinchBeginEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -19349,6 +23511,7 @@ pub type GesturePinchEndEvent = GesturePinchBeginEvent;
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GestureSwipeEventFlags(u32);
 impl GestureSwipeEventFlags {
     pub const GESTURE_SWIPE_CANCELLED: Self = Self(1 << 0);
@@ -19391,6 +23554,20 @@ impl core::fmt::Debug for GestureSwipeEventFlags  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl GestureSwipeEventFlags {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("GestureSwipeCancelled"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for GestureSwipeEventFlags {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(GestureSwipeEventFlags, u32);
 
 /// Opcode for the GestureSwipeBegin event
@@ -19424,6 +23601,12 @@ pub struct GestureSwipeBeginEvent {
     pub flags: GestureSwipeEventFlags,
 }
 impl_debug_if_no_extra_traits!(GestureSwipeBeginEvent, "GestureSwipeBeginEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GestureSwipeBeginEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GestureSwipeBeginEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -19619,6 +23802,7 @@ pub type GestureSwipeEndEvent = GestureSwipeBeginEvent;
 
 #[derive(Debug, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct EventForSend([u8; 32]);
 impl EventForSend {
     pub fn as_device_valuator_event(&self) -> Result<DeviceValuatorEvent, ParseError> {
@@ -19815,6 +23999,7 @@ pub const SEND_EXTENSION_EVENT_REQUEST: u8 = 31;
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SendExtensionEventRequest<'input> {
     pub destination: xproto::Window,
     pub device_id: u8,
@@ -19907,6 +24092,51 @@ impl<'input> Request for SendExtensionEventRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let destination_bytes = self.destination.serialize();
+        let device_id_bytes = self.device_id.serialize();
+        let propagate_bytes = self.propagate.serialize();
+        let num_classes = u16::try_from(self.classes.len()).expect("`classes` has too many elements");
+        let num_classes_bytes = num_classes.serialize();
+        let num_events = u8::try_from(self.events.len()).expect("`events` has too many elements");
+        let num_events_bytes = num_events.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SEND_EXTENSION_EVENT_REQUEST,
+            0,
+            0,
+            destination_bytes[0],
+            destination_bytes[1],
+            destination_bytes[2],
+            destination_bytes[3],
+            device_id_bytes[0],
+            propagate_bytes[0],
+            num_classes_bytes[0],
+            num_classes_bytes[1],
+            num_events_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        self.events.serialize_into(buffer);
+        self.classes.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for SendExtensionEventRequest<'input> {
 }
@@ -19926,3 +24156,68 @@ pub const DEVICE_BUSY_ERROR: u8 = 3;
 /// Opcode for the Class error
 pub const CLASS_ERROR: u8 = 4;
 
+/// All requests of this module.
+pub const REQUESTS: &[RequestDescription] = &[
+    RequestDescription { opcode: GET_EXTENSION_VERSION_REQUEST, name: "GetExtensionVersion", has_reply: true },
+    RequestDescription { opcode: LIST_INPUT_DEVICES_REQUEST, name: "ListInputDevices", has_reply: true },
+    RequestDescription { opcode: OPEN_DEVICE_REQUEST, name: "OpenDevice", has_reply: true },
+    RequestDescription { opcode: CLOSE_DEVICE_REQUEST, name: "CloseDevice", has_reply: false },
+    RequestDescription { opcode: SET_DEVICE_MODE_REQUEST, name: "SetDeviceMode", has_reply: true },
+    RequestDescription { opcode: SELECT_EXTENSION_EVENT_REQUEST, name: "SelectExtensionEvent", has_reply: false },
+    RequestDescription { opcode: GET_SELECTED_EXTENSION_EVENTS_REQUEST, name: "GetSelectedExtensionEvents", has_reply: true },
+    RequestDescription { opcode: CHANGE_DEVICE_DONT_PROPAGATE_LIST_REQUEST, name: "ChangeDeviceDontPropagateList", has_reply: false },
+    RequestDescription { opcode: GET_DEVICE_DONT_PROPAGATE_LIST_REQUEST, name: "GetDeviceDontPropagateList", has_reply: true },
+    RequestDescription { opcode: GET_DEVICE_MOTION_EVENTS_REQUEST, name: "GetDeviceMotionEvents", has_reply: true },
+    RequestDescription { opcode: CHANGE_KEYBOARD_DEVICE_REQUEST, name: "ChangeKeyboardDevice", has_reply: true },
+    RequestDescription { opcode: CHANGE_POINTER_DEVICE_REQUEST, name: "ChangePointerDevice", has_reply: true },
+    RequestDescription { opcode: GRAB_DEVICE_REQUEST, name: "GrabDevice", has_reply: true },
+    RequestDescription { opcode: UNGRAB_DEVICE_REQUEST, name: "UngrabDevice", has_reply: false },
+    RequestDescription { opcode: GRAB_DEVICE_KEY_REQUEST, name: "GrabDeviceKey", has_reply: false },
+    RequestDescription { opcode: UNGRAB_DEVICE_KEY_REQUEST, name: "UngrabDeviceKey", has_reply: false },
+    RequestDescription { opcode: GRAB_DEVICE_BUTTON_REQUEST, name: "GrabDeviceButton", has_reply: false },
+    RequestDescription { opcode: UNGRAB_DEVICE_BUTTON_REQUEST, name: "UngrabDeviceButton", has_reply: false },
+    RequestDescription { opcode: ALLOW_DEVICE_EVENTS_REQUEST, name: "AllowDeviceEvents", has_reply: false },
+    RequestDescription { opcode: GET_DEVICE_FOCUS_REQUEST, name: "GetDeviceFocus", has_reply: true },
+    RequestDescription { opcode: SET_DEVICE_FOCUS_REQUEST, name: "SetDeviceFocus", has_reply: false },
+    RequestDescription { opcode: GET_FEEDBACK_CONTROL_REQUEST, name: "GetFeedbackControl", has_reply: true },
+    RequestDescription { opcode: CHANGE_FEEDBACK_CONTROL_REQUEST, name: "ChangeFeedbackControl", has_reply: false },
+    RequestDescription { opcode: GET_DEVICE_KEY_MAPPING_REQUEST, name: "GetDeviceKeyMapping", has_reply: true },
+    RequestDescription { opcode: CHANGE_DEVICE_KEY_MAPPING_REQUEST, name: "ChangeDeviceKeyMapping", has_reply: false },
+    RequestDescription { opcode: GET_DEVICE_MODIFIER_MAPPING_REQUEST, name: "GetDeviceModifierMapping", has_reply: true },
+    RequestDescription { opcode: SET_DEVICE_MODIFIER_MAPPING_REQUEST, name: "SetDeviceModifierMapping", has_reply: true },
+    RequestDescription { opcode: GET_DEVICE_BUTTON_MAPPING_REQUEST, name: "GetDeviceButtonMapping", has_reply: true },
+    RequestDescription { opcode: SET_DEVICE_BUTTON_MAPPING_REQUEST, name: "SetDeviceButtonMapping", has_reply: true },
+    RequestDescription { opcode: QUERY_DEVICE_STATE_REQUEST, name: "QueryDeviceState", has_reply: true },
+    RequestDescription { opcode: DEVICE_BELL_REQUEST, name: "DeviceBell", has_reply: false },
+    RequestDescription { opcode: SET_DEVICE_VALUATORS_REQUEST, name: "SetDeviceValuators", has_reply: true },
+    RequestDescription { opcode: GET_DEVICE_CONTROL_REQUEST, name: "GetDeviceControl", has_reply: true },
+    RequestDescription { opcode: CHANGE_DEVICE_CONTROL_REQUEST, name: "ChangeDeviceControl", has_reply: true },
+    RequestDescription { opcode: LIST_DEVICE_PROPERTIES_REQUEST, name: "ListDeviceProperties", has_reply: true },
+    RequestDescription { opcode: CHANGE_DEVICE_PROPERTY_REQUEST, name: "ChangeDeviceProperty", has_reply: false },
+    RequestDescription { opcode: DELETE_DEVICE_PROPERTY_REQUEST, name: "DeleteDeviceProperty", has_reply: false },
+    RequestDescription { opcode: GET_DEVICE_PROPERTY_REQUEST, name: "GetDeviceProperty", has_reply: true },
+    RequestDescription { opcode: XI_QUERY_POINTER_REQUEST, name: "XIQueryPointer", has_reply: true },
+    RequestDescription { opcode: XI_WARP_POINTER_REQUEST, name: "XIWarpPointer", has_reply: false },
+    RequestDescription { opcode: XI_CHANGE_CURSOR_REQUEST, name: "XIChangeCursor", has_reply: false },
+    RequestDescription { opcode: XI_CHANGE_HIERARCHY_REQUEST, name: "XIChangeHierarchy", has_reply: false },
+    RequestDescription { opcode: XI_SET_CLIENT_POINTER_REQUEST, name: "XISetClientPointer", has_reply: false },
+    RequestDescription { opcode: XI_GET_CLIENT_POINTER_REQUEST, name: "XIGetClientPointer", has_reply: true },
+    RequestDescription { opcode: XI_SELECT_EVENTS_REQUEST, name: "XISelectEvents", has_reply: false },
+    RequestDescription { opcode: XI_QUERY_VERSION_REQUEST, name: "XIQueryVersion", has_reply: true },
+    RequestDescription { opcode: XI_QUERY_DEVICE_REQUEST, name: "XIQueryDevice", has_reply: true },
+    RequestDescription { opcode: XI_SET_FOCUS_REQUEST, name: "XISetFocus", has_reply: false },
+    RequestDescription { opcode: XI_GET_FOCUS_REQUEST, name: "XIGetFocus", has_reply: true },
+    RequestDescription { opcode: XI_GRAB_DEVICE_REQUEST, name: "XIGrabDevice", has_reply: true },
+    RequestDescription { opcode: XI_UNGRAB_DEVICE_REQUEST, name: "XIUngrabDevice", has_reply: false },
+    RequestDescription { opcode: XI_ALLOW_EVENTS_REQUEST, name: "XIAllowEvents", has_reply: false },
+    RequestDescription { opcode: XI_PASSIVE_GRAB_DEVICE_REQUEST, name: "XIPassiveGrabDevice", has_reply: true },
+    RequestDescription { opcode: XI_PASSIVE_UNGRAB_DEVICE_REQUEST, name: "XIPassiveUngrabDevice", has_reply: false },
+    RequestDescription { opcode: XI_LIST_PROPERTIES_REQUEST, name: "XIListProperties", has_reply: true },
+    RequestDescription { opcode: XI_CHANGE_PROPERTY_REQUEST, name: "XIChangeProperty", has_reply: false },
+    RequestDescription { opcode: XI_DELETE_PROPERTY_REQUEST, name: "XIDeleteProperty", has_reply: false },
+    RequestDescription { opcode: XI_GET_PROPERTY_REQUEST, name: "XIGetProperty", has_reply: true },
+    RequestDescription { opcode: XI_GET_SELECTED_EVENTS_REQUEST, name: "XIGetSelectedEvents", has_reply: true },
+    RequestDescription { opcode: XI_BARRIER_RELEASE_POINTER_REQUEST, name: "XIBarrierReleasePointer", has_reply: false },
+    RequestDescription { opcode: SEND_EXTENSION_EVENT_REQUEST, name: "SendExtensionEvent", has_reply: false },
+];
+
@@ -22,6 +22,7 @@ use crate::BufWithFds;
 use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::x11_utils::RequestDescription;
 #[allow(unused_imports)]
 use super::xproto;
 
@@ -38,6 +39,7 @@ pub const X11_XML_VERSION: (u32, u32) = (1, 0);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Const(u8);
 impl Const {
     pub const MAX_LEGAL_KEY_CODE: Self = Self(255);
@@ -96,9 +98,26 @@ impl core::fmt::Debug for Const  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl Const {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            255 => Some("MaxLegalKeyCode"),
+            32 => Some("PerKeyBitArraySize"),
+            4 => Some("KeyNameLength"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Const {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct EventType(u16);
 impl EventType {
     pub const NEW_KEYBOARD_NOTIFY: Self = Self(1 << 0);
@@ -169,10 +188,36 @@ impl core::fmt::Debug for EventType  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl EventType {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("NewKeyboardNotify"),
+            2 => Some("MapNotify"),
+            4 => Some("StateNotify"),
+            8 => Some("ControlsNotify"),
+            16 => Some("IndicatorStateNotify"),
+            32 => Some("IndicatorMapNotify"),
+            64 => Some("NamesNotify"),
+            128 => Some("CompatMapNotify"),
+            256 => Some("BellNotify"),
+            512 => Some("ActionMessage"),
+            1024 => Some("AccessXNotify"),
+            2048 => Some("ExtensionDeviceNotify"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for EventType {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(EventType, u16);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct NKNDetail(u16);
 impl NKNDetail {
     pub const KEYCODES: Self = Self(1 << 0);
@@ -225,10 +270,27 @@ impl core::fmt::Debug for NKNDetail  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl NKNDetail {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("Keycodes"),
+            2 => Some("Geometry"),
+            4 => Some("DeviceID"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for NKNDetail {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(NKNDetail, u16);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct AXNDetail(u16);
 impl AXNDetail {
     pub const SK_PRESS: Self = Self(1 << 0);
@@ -289,10 +351,31 @@ impl core::fmt::Debug for AXNDetail  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl AXNDetail {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("SKPress"),
+            2 => Some("SKAccept"),
+            4 => Some("SKReject"),
+            8 => Some("SKRelease"),
+            16 => Some("BKAccept"),
+            32 => Some("BKReject"),
+            64 => Some("AXKWarning"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for AXNDetail {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(AXNDetail, u16);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct MapPart(u16);
 impl MapPart {
     pub const KEY_TYPES: Self = Self(1 << 0);
@@ -355,10 +438,32 @@ impl core::fmt::Debug for MapPart  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl MapPart {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("KeyTypes"),
+            2 => Some("KeySyms"),
+            4 => Some("ModifierMap"),
+            8 => Some("ExplicitComponents"),
+            16 => Some("KeyActions"),
+            32 => Some("KeyBehaviors"),
+            64 => Some("VirtualMods"),
+            128 => Some("VirtualModMap"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for MapPart {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(MapPart, u16);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetMapFlags(u16);
 impl SetMapFlags {
     pub const RESIZE_TYPES: Self = Self(1 << 0);
@@ -409,10 +514,26 @@ impl core::fmt::Debug for SetMapFlags  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl SetMapFlags {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("ResizeTypes"),
+            2 => Some("RecomputeActions"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for SetMapFlags {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(SetMapFlags, u16);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct StatePart(u16);
 impl StatePart {
     pub const MODIFIER_STATE: Self = Self(1 << 0);
@@ -487,10 +608,38 @@ impl core::fmt::Debug for StatePart  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl StatePart {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("ModifierState"),
+            2 => Some("ModifierBase"),
+            4 => Some("ModifierLatch"),
+            8 => Some("ModifierLock"),
+            16 => Some("GroupState"),
+            32 => Some("GroupBase"),
+            64 => Some("GroupLatch"),
+            128 => Some("GroupLock"),
+            256 => Some("CompatState"),
+            512 => Some("GrabMods"),
+            1024 => Some("CompatGrabMods"),
+            2048 => Some("LookupMods"),
+            4096 => Some("CompatLookupMods"),
+            8192 => Some("PointerButtons"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for StatePart {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(StatePart, u16);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct BoolCtrl(u32);
 impl BoolCtrl {
     pub const REPEAT_KEYS: Self = Self(1 << 0);
@@ -557,10 +706,37 @@ impl core::fmt::Debug for BoolCtrl  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl BoolCtrl {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("RepeatKeys"),
+            2 => Some("SlowKeys"),
+            4 => Some("BounceKeys"),
+            8 => Some("StickyKeys"),
+            16 => Some("MouseKeys"),
+            32 => Some("MouseKeysAccel"),
+            64 => Some("AccessXKeys"),
+            128 => Some("AccessXTimeoutMask"),
+            256 => Some("AccessXFeedbackMask"),
+            512 => Some("AudibleBellMask"),
+            1024 => Some("Overlay1Mask"),
+            2048 => Some("Overlay2Mask"),
+            4096 => Some("IgnoreGroupLockMask"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for BoolCtrl {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(BoolCtrl, u32);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Control(u32);
 impl Control {
     pub const GROUPS_WRAP: Self = Self(1 << 27);
@@ -611,10 +787,29 @@ impl core::fmt::Debug for Control  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl Control {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            134_217_728 => Some("GroupsWrap"),
+            268_435_456 => Some("InternalMods"),
+            536_870_912 => Some("IgnoreLockMods"),
+            1_073_741_824 => Some("PerKeyRepeat"),
+            2_147_483_648 => Some("ControlsEnabled"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Control {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(Control, u32);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct AXOption(u16);
 impl AXOption {
     pub const SK_PRESS_FB: Self = Self(1 << 0);
@@ -685,12 +880,38 @@ impl core::fmt::Debug for AXOption  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl AXOption {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("SKPressFB"),
+            2 => Some("SKAcceptFB"),
+            4 => Some("FeatureFB"),
+            8 => Some("SlowWarnFB"),
+            16 => Some("IndicatorFB"),
+            32 => Some("StickyKeysFB"),
+            64 => Some("TwoKeys"),
+            128 => Some("LatchToLock"),
+            256 => Some("SKReleaseFB"),
+            512 => Some("SKRejectFB"),
+            1024 => Some("BKRejectFB"),
+            2048 => Some("DumbBell"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for AXOption {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(AXOption, u16);
 
 pub type DeviceSpec = u16;
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct LedClassResult(u16);
 impl LedClassResult {
     pub const KBD_FEEDBACK_CLASS: Self = Self(0);
@@ -741,9 +962,25 @@ impl core::fmt::Debug for LedClassResult  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl LedClassResult {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("KbdFeedbackClass"),
+            4 => Some("LedFeedbackClass"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for LedClassResult {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct LedClass(u16);
 impl LedClass {
     pub const KBD_FEEDBACK_CLASS: Self = Self(0);
@@ -798,11 +1035,29 @@ impl core::fmt::Debug for LedClass  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl LedClass {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("KbdFeedbackClass"),
+            4 => Some("LedFeedbackClass"),
+            768 => Some("DfltXIClass"),
+            1280 => Some("AllXIClasses"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for LedClass {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 pub type LedClassSpec = u16;
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct BellClassResult(u8);
 impl BellClassResult {
     pub const KBD_FEEDBACK_CLASS: Self = Self(0);
@@ -859,9 +1114,25 @@ impl core::fmt::Debug for BellClassResult  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl BellClassResult {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("KbdFeedbackClass"),
+            5 => Some("BellFeedbackClass"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for BellClassResult {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct BellClass(u16);
 impl BellClass {
     pub const KBD_FEEDBACK_CLASS: Self = Self(0);
@@ -914,11 +1185,28 @@ impl core::fmt::Debug for BellClass  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl BellClass {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("KbdFeedbackClass"),
+            5 => Some("BellFeedbackClass"),
+            768 => Some("DfltXIClass"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for BellClass {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 pub type BellClassSpec = u16;
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ID(u16);
 impl ID {
     pub const USE_CORE_KBD: Self = Self(256);
@@ -979,11 +1267,32 @@ impl core::fmt::Debug for ID  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl ID {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            256 => Some("UseCoreKbd"),
+            512 => Some("UseCorePtr"),
+            768 => Some("DfltXIClass"),
+            1024 => Some("DfltXIId"),
+            1280 => Some("AllXIClass"),
+            1536 => Some("AllXIId"),
+            65280 => Some("XINone"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ID {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 pub type IDSpec = u16;
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Group(u8);
 impl Group {
     pub const M1: Self = Self(0);
@@ -1044,9 +1353,27 @@ impl core::fmt::Debug for Group  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl Group {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("M1"),
+            1 => Some("M2"),
+            2 => Some("M3"),
+            3 => Some("M4"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Group {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Groups(u8);
 impl Groups {
     pub const ANY: Self = Self(254);
@@ -1103,9 +1430,25 @@ impl core::fmt::Debug for Groups  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl Groups {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            254 => Some("Any"),
+            255 => Some("All"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Groups {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetOfGroup(u8);
 impl SetOfGroup {
     pub const GROUP1: Self = Self(1 << 0);
@@ -1166,10 +1509,28 @@ impl core::fmt::Debug for SetOfGroup  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl SetOfGroup {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("Group1"),
+            2 => Some("Group2"),
+            4 => Some("Group3"),
+            8 => Some("Group4"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for SetOfGroup {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(SetOfGroup, u8);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetOfGroups(u8);
 impl SetOfGroups {
     pub const ANY: Self = Self(1 << 7);
@@ -1224,10 +1585,25 @@ impl core::fmt::Debug for SetOfGroups  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl SetOfGroups {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            128 => Some("Any"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for SetOfGroups {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(SetOfGroups, u8);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GroupsWrap(u8);
 impl GroupsWrap {
     pub const WRAP_INTO_RANGE: Self = Self(0);
@@ -1286,10 +1662,27 @@ impl core::fmt::Debug for GroupsWrap  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl GroupsWrap {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("WrapIntoRange"),
+            64 => Some("ClampIntoRange"),
+            128 => Some("RedirectIntoRange"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for GroupsWrap {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(GroupsWrap, u8);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct VModsHigh(u8);
 impl VModsHigh {
     pub const M15: Self = Self(1 << 7);
@@ -1358,10 +1751,32 @@ impl core::fmt::Debug for VModsHigh  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl VModsHigh {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            128 => Some("M15"),
+            64 => Some("M14"),
+            32 => Some("M13"),
+            16 => Some("M12"),
+            8 => Some("M11"),
+            4 => Some("M10"),
+            2 => Some("M9"),
+            1 => Some("M8"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for VModsHigh {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(VModsHigh, u8);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct VModsLow(u8);
 impl VModsLow {
     pub const M7: Self = Self(1 << 7);
@@ -1430,10 +1845,32 @@ impl core::fmt::Debug for VModsLow  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl VModsLow {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            128 => Some("M7"),
+            64 => Some("M6"),
+            32 => Some("M5"),
+            16 => Some("M4"),
+            8 => Some("M3"),
+            4 => Some("M2"),
+            2 => Some("M1"),
+            1 => Some("M0"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for VModsLow {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(VModsLow, u8);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct VMod(u16);
 impl VMod {
     pub const M15: Self = Self(1 << 15);
@@ -1512,10 +1949,40 @@ impl core::fmt::Debug for VMod  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl VMod {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            32768 => Some("M15"),
+            16384 => Some("M14"),
+            8192 => Some("M13"),
+            4096 => Some("M12"),
+            2048 => Some("M11"),
+            1024 => Some("M10"),
+            512 => Some("M9"),
+            256 => Some("M8"),
+            128 => Some("M7"),
+            64 => Some("M6"),
+            32 => Some("M5"),
+            16 => Some("M4"),
+            8 => Some("M3"),
+            4 => Some("M2"),
+            2 => Some("M1"),
+            1 => Some("M0"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for VMod {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(VMod, u16);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Explicit(u8);
 impl Explicit {
     pub const V_MOD_MAP: Self = Self(1 << 7);
@@ -1584,10 +2051,32 @@ impl core::fmt::Debug for Explicit  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl Explicit {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            128 => Some("VModMap"),
+            64 => Some("Behavior"),
+            32 => Some("AutoRepeat"),
+            16 => Some("Interpret"),
+            8 => Some("KeyType4"),
+            4 => Some("KeyType3"),
+            2 => Some("KeyType2"),
+            1 => Some("KeyType1"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Explicit {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(Explicit, u8);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SymInterpretMatch(u8);
 impl SymInterpretMatch {
     pub const NONE_OF: Self = Self(0);
@@ -1650,9 +2139,28 @@ impl core::fmt::Debug for SymInterpretMatch  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl SymInterpretMatch {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("NoneOf"),
+            1 => Some("AnyOfOrNone"),
+            2 => Some("AnyOf"),
+            3 => Some("AllOf"),
+            4 => Some("Exactly"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for SymInterpretMatch {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SymInterpMatch(u8);
 impl SymInterpMatch {
     pub const LEVEL_ONE_ONLY: Self = Self(1 << 7);
@@ -1709,9 +2217,25 @@ impl core::fmt::Debug for SymInterpMatch  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl SymInterpMatch {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            128 => Some("LevelOneOnly"),
+            127 => Some("OpMask"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for SymInterpMatch {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct IMFlag(u8);
 impl IMFlag {
     pub const NO_EXPLICIT: Self = Self(1 << 7);
@@ -1770,10 +2294,27 @@ impl core::fmt::Debug for IMFlag  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl IMFlag {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            128 => Some("NoExplicit"),
+            64 => Some("NoAutomatic"),
+            32 => Some("LEDDrivesKB"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for IMFlag {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(IMFlag, u8);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct IMModsWhich(u8);
 impl IMModsWhich {
     pub const USE_COMPAT: Self = Self(1 << 4);
@@ -1836,10 +2377,29 @@ impl core::fmt::Debug for IMModsWhich  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl IMModsWhich {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            16 => Some("UseCompat"),
+            8 => Some("UseEffective"),
+            4 => Some("UseLocked"),
+            2 => Some("UseLatched"),
+            1 => Some("UseBase"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for IMModsWhich {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(IMModsWhich, u8);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct IMGroupsWhich(u8);
 impl IMGroupsWhich {
     pub const USE_COMPAT: Self = Self(1 << 4);
@@ -1902,6 +2462,24 @@ impl core::fmt::Debug for IMGroupsWhich  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl IMGroupsWhich {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            16 => Some("UseCompat"),
+            8 => Some("UseEffective"),
+            4 => Some("UseLocked"),
+            2 => Some("UseLatched"),
+            1 => Some("UseBase"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for IMGroupsWhich {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(IMGroupsWhich, u8);
 
 #[derive(Clone, Copy, Default)]
@@ -1918,6 +2496,12 @@ pub struct IndicatorMap {
     pub ctrls: BoolCtrl,
 }
 impl_debug_if_no_extra_traits!(IndicatorMap, "IndicatorMap");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for IndicatorMap {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for IndicatorMap {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (flags, remaining) = u8::try_parse(remaining)?;
@@ -1981,6 +2565,7 @@ impl Serialize for IndicatorMap {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CMDetail(u8);
 impl CMDetail {
     pub const SYM_INTERP: Self = Self(1 << 0);
@@ -2037,10 +2622,26 @@ impl core::fmt::Debug for CMDetail  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl CMDetail {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("SymInterp"),
+            2 => Some("GroupCompat"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for CMDetail {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(CMDetail, u8);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct NameDetail(u32);
 impl NameDetail {
     pub const KEYCODES: Self = Self(1 << 0);
@@ -2109,10 +2710,38 @@ impl core::fmt::Debug for NameDetail  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl NameDetail {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("Keycodes"),
+            2 => Some("Geometry"),
+            4 => Some("Symbols"),
+            8 => Some("PhysSymbols"),
+            16 => Some("Types"),
+            32 => Some("Compat"),
+            64 => Some("KeyTypeNames"),
+            128 => Some("KTLevelNames"),
+            256 => Some("IndicatorNames"),
+            512 => Some("KeyNames"),
+            1024 => Some("KeyAliases"),
+            2048 => Some("VirtualModNames"),
+            4096 => Some("GroupNames"),
+            8192 => Some("RGNames"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for NameDetail {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(NameDetail, u32);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GBNDetail(u16);
 impl GBNDetail {
     pub const TYPES: Self = Self(1 << 0);
@@ -2175,10 +2804,32 @@ impl core::fmt::Debug for GBNDetail  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl GBNDetail {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("Types"),
+            2 => Some("CompatMap"),
+            4 => Some("ClientSymbols"),
+            8 => Some("ServerSymbols"),
+            16 => Some("IndicatorMaps"),
+            32 => Some("KeyNames"),
+            64 => Some("Geometry"),
+            128 => Some("OtherNames"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for GBNDetail {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(GBNDetail, u16);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct XIFeature(u16);
 impl XIFeature {
     pub const KEYBOARDS: Self = Self(1 << 0);
@@ -2235,10 +2886,29 @@ impl core::fmt::Debug for XIFeature  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl XIFeature {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("Keyboards"),
+            2 => Some("ButtonActions"),
+            4 => Some("IndicatorNames"),
+            8 => Some("IndicatorMaps"),
+            16 => Some("IndicatorState"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for XIFeature {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(XIFeature, u16);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PerClientFlag(u32);
 impl PerClientFlag {
     pub const DETECTABLE_AUTO_REPEAT: Self = Self(1 << 0);
@@ -2289,6 +2959,24 @@ impl core::fmt::Debug for PerClientFlag  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl PerClientFlag {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("DetectableAutoRepeat"),
+            2 => Some("GrabsUseXKBState"),
+            4 => Some("AutoResetControls"),
+            8 => Some("LookupStateWhenGrabbed"),
+            16 => Some("SendEventUsesXKBState"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for PerClientFlag {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(PerClientFlag, u32);
 
 #[derive(Clone, Copy, Default)]
@@ -2300,6 +2988,12 @@ pub struct ModDef {
     pub vmods: VMod,
 }
 impl_debug_if_no_extra_traits!(ModDef, "ModDef");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ModDef {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for ModDef {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (mask, remaining) = u8::try_parse(remaining)?;
@@ -2340,6 +3034,12 @@ pub struct KeyName {
     pub name: [u8; 4],
 }
 impl_debug_if_no_extra_traits!(KeyName, "KeyName");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for KeyName {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for KeyName {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (name, remaining) = crate::x11_utils::parse_u8_array::<4>(remaining)?;
@@ -2371,6 +3071,12 @@ pub struct KeyAlias {
     pub alias: [u8; 4],
 }
 impl_debug_if_no_extra_traits!(KeyAlias, "KeyAlias");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for KeyAlias {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for KeyAlias {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (real, remaining) = crate::x11_utils::parse_u8_array::<4>(remaining)?;
@@ -2408,6 +3114,12 @@ pub struct CountedString16 {
     pub alignment_pad: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(CountedString16, "CountedString16");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CountedString16 {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for CountedString16 {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (length, remaining) = u16::try_parse(remaining)?;
@@ -2461,6 +3173,12 @@ pub struct KTMapEntry {
     pub mods_vmods: VMod,
 }
 impl_debug_if_no_extra_traits!(KTMapEntry, "KTMapEntry");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for KTMapEntry {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for KTMapEntry {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (active, remaining) = bool::try_parse(remaining)?;
@@ -2519,6 +3237,12 @@ pub struct KeyType {
     pub preserve: Vec<ModDef>,
 }
 impl_debug_if_no_extra_traits!(KeyType, "KeyType");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for KeyType {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for KeyType {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (mods_mask, remaining) = u8::try_parse(remaining)?;
@@ -2585,6 +3309,12 @@ pub struct KeySymMap {
     pub syms: Vec<xproto::Keysym>,
 }
 impl_debug_if_no_extra_traits!(KeySymMap, "KeySymMap");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for KeySymMap {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for KeySymMap {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (kt_index, remaining) = crate::x11_utils::parse_u8_array::<4>(remaining)?;
@@ -2637,6 +3367,12 @@ pub struct CommonBehavior {
     pub data: u8,
 }
 impl_debug_if_no_extra_traits!(CommonBehavior, "CommonBehavior");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CommonBehavior {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for CommonBehavior {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u8::try_parse(remaining)?;
@@ -2669,6 +3405,12 @@ pub struct DefaultBehavior {
     pub type_: u8,
 }
 impl_debug_if_no_extra_traits!(DefaultBehavior, "DefaultBehavior");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DefaultBehavior {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for DefaultBehavior {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u8::try_parse(remaining)?;
@@ -2703,6 +3445,12 @@ pub struct RadioGroupBehavior {
     pub group: u8,
 }
 impl_debug_if_no_extra_traits!(RadioGroupBehavior, "RadioGroupBehavior");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for RadioGroupBehavior {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for RadioGroupBehavior {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u8::try_parse(remaining)?;
@@ -2736,6 +3484,12 @@ pub struct OverlayBehavior {
     pub key: xproto::Keycode,
 }
 impl_debug_if_no_extra_traits!(OverlayBehavior, "OverlayBehavior");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for OverlayBehavior {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for OverlayBehavior {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u8::try_parse(remaining)?;
@@ -2769,6 +3523,7 @@ pub type PermamentOverlayBehavior = OverlayBehavior;
 
 #[derive(Debug, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Behavior([u8; 2]);
 impl Behavior {
     pub fn as_common(&self) -> CommonBehavior {
@@ -2916,6 +3671,7 @@ impl From<u8> for Behavior {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct BehaviorType(u8);
 impl BehaviorType {
     pub const DEFAULT: Self = Self(0);
@@ -2986,6 +3742,28 @@ impl core::fmt::Debug for BehaviorType  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl BehaviorType {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Default"),
+            1 => Some("Lock"),
+            2 => Some("RadioGroup"),
+            3 => Some("Overlay1"),
+            4 => Some("Overlay2"),
+            129 => Some("PermamentLock"),
+            130 => Some("PermamentRadioGroup"),
+            131 => Some("PermamentOverlay1"),
+            132 => Some("PermamentOverlay2"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for BehaviorType {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy)]
 #[cfg_attr(feature = "extra-traits", derive(Debug))]
@@ -2995,6 +3773,12 @@ pub struct SetBehavior {
     pub behavior: Behavior,
 }
 impl_debug_if_no_extra_traits!(SetBehavior, "SetBehavior");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SetBehavior {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SetBehavior {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (keycode, remaining) = xproto::Keycode::try_parse(remaining)?;
@@ -3032,6 +3816,12 @@ pub struct SetExplicit {
     pub explicit: Explicit,
 }
 impl_debug_if_no_extra_traits!(SetExplicit, "SetExplicit");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SetExplicit {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SetExplicit {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (keycode, remaining) = xproto::Keycode::try_parse(remaining)?;
@@ -3066,6 +3856,12 @@ pub struct KeyModMap {
     pub mods: xproto::ModMask,
 }
 impl_debug_if_no_extra_traits!(KeyModMap, "KeyModMap");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for KeyModMap {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for KeyModMap {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (keycode, remaining) = xproto::Keycode::try_parse(remaining)?;
@@ -3100,6 +3896,12 @@ pub struct KeyVModMap {
     pub vmods: VMod,
 }
 impl_debug_if_no_extra_traits!(KeyVModMap, "KeyVModMap");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for KeyVModMap {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for KeyVModMap {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (keycode, remaining) = xproto::Keycode::try_parse(remaining)?;
@@ -3139,6 +3941,12 @@ pub struct KTSetMapEntry {
     pub virtual_mods: VMod,
 }
 impl_debug_if_no_extra_traits!(KTSetMapEntry, "KTSetMapEntry");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for KTSetMapEntry {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for KTSetMapEntry {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (level, remaining) = u8::try_parse(remaining)?;
@@ -3184,6 +3992,12 @@ pub struct SetKeyType {
     pub preserve_entries: Vec<KTSetMapEntry>,
 }
 impl_debug_if_no_extra_traits!(SetKeyType, "SetKeyType");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SetKeyType {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SetKeyType {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (mask, remaining) = u8::try_parse(remaining)?;
@@ -3250,6 +4064,12 @@ pub struct Outline {
     pub points: Vec<xproto::Point>,
 }
 impl_debug_if_no_extra_traits!(Outline, "Outline");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Outline {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Outline {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (n_points, remaining) = u8::try_parse(remaining)?;
@@ -3302,6 +4122,12 @@ pub struct Shape {
     pub outlines: Vec<Outline>,
 }
 impl_debug_if_no_extra_traits!(Shape, "Shape");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Shape {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Shape {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (name, remaining) = xproto::Atom::try_parse(remaining)?;
@@ -3358,6 +4184,12 @@ pub struct Key {
     pub color_ndx: u8,
 }
 impl_debug_if_no_extra_traits!(Key, "Key");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Key {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Key {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (name, remaining) = crate::x11_utils::parse_u8_array::<4>(remaining)?;
@@ -3402,6 +4234,12 @@ pub struct OverlayKey {
     pub under: [String8; 4],
 }
 impl_debug_if_no_extra_traits!(OverlayKey, "OverlayKey");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for OverlayKey {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for OverlayKey {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (over, remaining) = crate::x11_utils::parse_u8_array::<4>(remaining)?;
@@ -3439,6 +4277,12 @@ pub struct OverlayRow {
     pub keys: Vec<OverlayKey>,
 }
 impl_debug_if_no_extra_traits!(OverlayRow, "OverlayRow");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for OverlayRow {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for OverlayRow {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (row_under, remaining) = u8::try_parse(remaining)?;
@@ -3489,6 +4333,12 @@ pub struct Overlay {
     pub rows: Vec<OverlayRow>,
 }
 impl_debug_if_no_extra_traits!(Overlay, "Overlay");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Overlay {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Overlay {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (name, remaining) = xproto::Atom::try_parse(remaining)?;
@@ -3541,6 +4391,12 @@ pub struct Row {
     pub keys: Vec<Key>,
 }
 impl_debug_if_no_extra_traits!(Row, "Row");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Row {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Row {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (top, remaining) = i16::try_parse(remaining)?;
@@ -3589,6 +4445,7 @@ impl Row {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DoodadType(u8);
 impl DoodadType {
     pub const OUTLINE: Self = Self(1);
@@ -3651,6 +4508,24 @@ impl core::fmt::Debug for DoodadType  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl DoodadType {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("Outline"),
+            2 => Some("Solid"),
+            3 => Some("Text"),
+            4 => Some("Indicator"),
+            5 => Some("Logo"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for DoodadType {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
@@ -3660,6 +4535,12 @@ pub struct Listing {
     pub string: Vec<String8>,
 }
 impl_debug_if_no_extra_traits!(Listing, "Listing");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Listing {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Listing {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
@@ -3721,6 +4602,12 @@ pub struct DeviceLedInfo {
     pub maps: Vec<IndicatorMap>,
 }
 impl_debug_if_no_extra_traits!(DeviceLedInfo, "DeviceLedInfo");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeviceLedInfo {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for DeviceLedInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (led_class, remaining) = LedClassSpec::try_parse(remaining)?;
@@ -3760,6 +4647,7 @@ impl Serialize for DeviceLedInfo {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Error(u8);
 impl Error {
     pub const BAD_DEVICE: Self = Self(255);
@@ -3818,12 +4706,29 @@ impl core::fmt::Debug for Error  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl Error {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            255 => Some("BadDevice"),
+            254 => Some("BadClass"),
+            253 => Some("BadId"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Error {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the Keyboard error
 pub const KEYBOARD_ERROR: u8 = 0;
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SA(u8);
 impl SA {
     pub const CLEAR_LOCKS: Self = Self(1 << 0);
@@ -3884,10 +4789,27 @@ impl core::fmt::Debug for SA  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl SA {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("ClearLocks"),
+            2 => Some("LatchToLock"),
+            4 => Some("UseModMapMods"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for SA {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(SA, u8);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SAType(u8);
 impl SAType {
     pub const NO_ACTION: Self = Self(0);
@@ -3982,6 +4904,40 @@ impl core::fmt::Debug for SAType  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl SAType {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("NoAction"),
+            1 => Some("SetMods"),
+            2 => Some("LatchMods"),
+            3 => Some("LockMods"),
+            4 => Some("SetGroup"),
+            5 => Some("LatchGroup"),
+            6 => Some("LockGroup"),
+            7 => Some("MovePtr"),
+            8 => Some("PtrBtn"),
+            9 => Some("LockPtrBtn"),
+            10 => Some("SetPtrDflt"),
+            11 => Some("ISOLock"),
+            12 => Some("Terminate"),
+            13 => Some("SwitchScreen"),
+            14 => Some("SetControls"),
+            15 => Some("LockControls"),
+            16 => Some("ActionMessage"),
+            17 => Some("RedirectKey"),
+            18 => Some("DeviceBtn"),
+            19 => Some("LockDeviceBtn"),
+            20 => Some("DeviceValuator"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for SAType {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
@@ -3990,6 +4946,12 @@ pub struct SANoAction {
     pub type_: SAType,
 }
 impl_debug_if_no_extra_traits!(SANoAction, "SANoAction");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SANoAction {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SANoAction {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u8::try_parse(remaining)?;
@@ -4033,6 +4995,12 @@ pub struct SASetMods {
     pub vmods_low: VModsLow,
 }
 impl_debug_if_no_extra_traits!(SASetMods, "SASetMods");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SASetMods {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SASetMods {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u8::try_parse(remaining)?;
@@ -4097,6 +5065,12 @@ pub struct SASetGroup {
     pub group: i8,
 }
 impl_debug_if_no_extra_traits!(SASetGroup, "SASetGroup");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SASetGroup {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SASetGroup {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u8::try_parse(remaining)?;
@@ -4141,6 +5115,7 @@ pub type SALockGroup = SASetGroup;
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SAMovePtrFlag(u8);
 impl SAMovePtrFlag {
     pub const NO_ACCELERATION: Self = Self(1 << 0);
@@ -4199,6 +5174,22 @@ impl core::fmt::Debug for SAMovePtrFlag  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl SAMovePtrFlag {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("NoAcceleration"),
+            2 => Some("MoveAbsoluteX"),
+            4 => Some("MoveAbsoluteY"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for SAMovePtrFlag {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(SAMovePtrFlag, u8);
 
 #[derive(Clone, Copy, Default)]
@@ -4213,6 +5204,12 @@ pub struct SAMovePtr {
     pub y_low: u8,
 }
 impl_debug_if_no_extra_traits!(SAMovePtr, "SAMovePtr");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SAMovePtr {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SAMovePtr {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u8::try_parse(remaining)?;
@@ -4270,6 +5267,12 @@ pub struct SAPtrBtn {
     pub button: u8,
 }
 impl_debug_if_no_extra_traits!(SAPtrBtn, "SAPtrBtn");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SAPtrBtn {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SAPtrBtn {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u8::try_parse(remaining)?;
@@ -4319,6 +5322,12 @@ pub struct SALockPtrBtn {
     pub button: u8,
 }
 impl_debug_if_no_extra_traits!(SALockPtrBtn, "SALockPtrBtn");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SALockPtrBtn {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SALockPtrBtn {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u8::try_parse(remaining)?;
@@ -4360,6 +5369,7 @@ impl Serialize for SALockPtrBtn {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SASetPtrDfltFlag(u8);
 impl SASetPtrDfltFlag {
     pub const DFLT_BTN_ABSOLUTE: Self = Self(1 << 2);
@@ -4416,6 +5426,21 @@ impl core::fmt::Debug for SASetPtrDfltFlag  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl SASetPtrDfltFlag {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            4 => Some("DfltBtnAbsolute"),
+            1 => Some("AffectDfltButton"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for SASetPtrDfltFlag {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(SASetPtrDfltFlag, u8);
 
 #[derive(Clone, Copy, Default)]
@@ -4428,6 +5453,12 @@ pub struct SASetPtrDflt {
     pub value: i8,
 }
 impl_debug_if_no_extra_traits!(SASetPtrDflt, "SASetPtrDflt");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SASetPtrDflt {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SASetPtrDflt {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u8::try_parse(remaining)?;
@@ -4472,6 +5503,7 @@ impl Serialize for SASetPtrDflt {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SAIsoLockFlag(u8);
 impl SAIsoLockFlag {
     pub const NO_LOCK: Self = Self(1 << 0);
@@ -4534,10 +5566,28 @@ impl core::fmt::Debug for SAIsoLockFlag  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl SAIsoLockFlag {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("NoLock"),
+            2 => Some("NoUnlock"),
+            4 => Some("UseModMapMods"),
+            8 => Some("ISODfltIsGroup"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for SAIsoLockFlag {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(SAIsoLockFlag, u8);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SAIsoLockNoAffect(u8);
 impl SAIsoLockNoAffect {
     pub const CTRLS: Self = Self(1 << 3);
@@ -4598,6 +5648,23 @@ impl core::fmt::Debug for SAIsoLockNoAffect  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl SAIsoLockNoAffect {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            8 => Some("Ctrls"),
+            16 => Some("Ptr"),
+            32 => Some("Group"),
+            64 => Some("Mods"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for SAIsoLockNoAffect {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(SAIsoLockNoAffect, u8);
 
 #[derive(Clone, Copy, Default)]
@@ -4614,6 +5681,12 @@ pub struct SAIsoLock {
     pub vmods_low: VModsLow,
 }
 impl_debug_if_no_extra_traits!(SAIsoLock, "SAIsoLock");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SAIsoLock {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SAIsoLock {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u8::try_parse(remaining)?;
@@ -4677,6 +5750,12 @@ pub struct SATerminate {
     pub type_: SAType,
 }
 impl_debug_if_no_extra_traits!(SATerminate, "SATerminate");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SATerminate {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SATerminate {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u8::try_parse(remaining)?;
@@ -4710,6 +5789,7 @@ impl Serialize for SATerminate {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SwitchScreenFlag(u8);
 impl SwitchScreenFlag {
     pub const APPLICATION: Self = Self(1 << 0);
@@ -4766,6 +5846,21 @@ impl core::fmt::Debug for SwitchScreenFlag  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl SwitchScreenFlag {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("Application"),
+            4 => Some("Absolute"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for SwitchScreenFlag {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(SwitchScreenFlag, u8);
 
 #[derive(Clone, Copy, Default)]
@@ -4777,6 +5872,12 @@ pub struct SASwitchScreen {
     pub new_screen: i8,
 }
 impl_debug_if_no_extra_traits!(SASwitchScreen, "SASwitchScreen");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SASwitchScreen {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SASwitchScreen {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u8::try_parse(remaining)?;
@@ -4816,6 +5917,7 @@ impl Serialize for SASwitchScreen {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct BoolCtrlsHigh(u8);
 impl BoolCtrlsHigh {
     pub const ACCESS_X_FEEDBACK: Self = Self(1 << 0);
@@ -4878,10 +5980,29 @@ impl core::fmt::Debug for BoolCtrlsHigh  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl BoolCtrlsHigh {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("AccessXFeedback"),
+            2 => Some("AudibleBell"),
+            4 => Some("Overlay1"),
+            8 => Some("Overlay2"),
+            16 => Some("IgnoreGroupLock"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for BoolCtrlsHigh {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(BoolCtrlsHigh, u8);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct BoolCtrlsLow(u8);
 impl BoolCtrlsLow {
     pub const REPEAT_KEYS: Self = Self(1 << 0);
@@ -4950,6 +6071,27 @@ impl core::fmt::Debug for BoolCtrlsLow  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl BoolCtrlsLow {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("RepeatKeys"),
+            2 => Some("SlowKeys"),
+            4 => Some("BounceKeys"),
+            8 => Some("StickyKeys"),
+            16 => Some("MouseKeys"),
+            32 => Some("MouseKeysAccel"),
+            64 => Some("AccessXKeys"),
+            128 => Some("AccessXTimeout"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for BoolCtrlsLow {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(BoolCtrlsLow, u8);
 
 #[derive(Clone, Copy, Default)]
@@ -4961,6 +6103,12 @@ pub struct SASetControls {
     pub bool_ctrls_low: BoolCtrlsLow,
 }
 impl_debug_if_no_extra_traits!(SASetControls, "SASetControls");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SASetControls {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SASetControls {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u8::try_parse(remaining)?;
@@ -5006,6 +6154,7 @@ pub type SALockControls = SASetControls;
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ActionMessageFlag(u8);
 impl ActionMessageFlag {
     pub const ON_PRESS: Self = Self(1 << 0);
@@ -5064,6 +6213,22 @@ impl core::fmt::Debug for ActionMessageFlag  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl ActionMessageFlag {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("OnPress"),
+            2 => Some("OnRelease"),
+            4 => Some("GenKeyEvent"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ActionMessageFlag {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(ActionMessageFlag, u8);
 
 #[derive(Clone, Copy, Default)]
@@ -5075,6 +6240,12 @@ pub struct SAActionMessage {
     pub message: [u8; 6],
 }
 impl_debug_if_no_extra_traits!(SAActionMessage, "SAActionMessage");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SAActionMessage {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SAActionMessage {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u8::try_parse(remaining)?;
@@ -5124,6 +6295,12 @@ pub struct SARedirectKey {
     pub vmods_low: VModsLow,
 }
 impl_debug_if_no_extra_traits!(SARedirectKey, "SARedirectKey");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SARedirectKey {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SARedirectKey {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u8::try_parse(remaining)?;
@@ -5191,6 +6368,12 @@ pub struct SADeviceBtn {
     pub device: u8,
 }
 impl_debug_if_no_extra_traits!(SADeviceBtn, "SADeviceBtn");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SADeviceBtn {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SADeviceBtn {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u8::try_parse(remaining)?;
@@ -5236,6 +6419,7 @@ impl Serialize for SADeviceBtn {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct LockDeviceFlags(u8);
 impl LockDeviceFlags {
     pub const NO_LOCK: Self = Self(1 << 0);
@@ -5292,6 +6476,21 @@ impl core::fmt::Debug for LockDeviceFlags  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl LockDeviceFlags {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("NoLock"),
+            2 => Some("NoUnlock"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for LockDeviceFlags {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(LockDeviceFlags, u8);
 
 #[derive(Clone, Copy, Default)]
@@ -5304,6 +6503,12 @@ pub struct SALockDeviceBtn {
     pub device: u8,
 }
 impl_debug_if_no_extra_traits!(SALockDeviceBtn, "SALockDeviceBtn");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SALockDeviceBtn {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SALockDeviceBtn {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u8::try_parse(remaining)?;
@@ -5349,6 +6554,7 @@ impl Serialize for SALockDeviceBtn {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SAValWhat(u8);
 impl SAValWhat {
     pub const IGNORE_VAL: Self = Self(0);
@@ -5413,6 +6619,25 @@ impl core::fmt::Debug for SAValWhat  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl SAValWhat {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("IgnoreVal"),
+            1 => Some("SetValMin"),
+            2 => Some("SetValCenter"),
+            3 => Some("SetValMax"),
+            4 => Some("SetValRelative"),
+            5 => Some("SetValAbsolute"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for SAValWhat {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
@@ -5428,6 +6653,12 @@ pub struct SADeviceValuator {
     pub val2value: u8,
 }
 impl_debug_if_no_extra_traits!(SADeviceValuator, "SADeviceValuator");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SADeviceValuator {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SADeviceValuator {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u8::try_parse(remaining)?;
@@ -5488,6 +6719,12 @@ pub struct SIAction {
     pub data: [u8; 7],
 }
 impl_debug_if_no_extra_traits!(SIAction, "SIAction");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SIAction {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SIAction {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (type_, remaining) = u8::try_parse(remaining)?;
@@ -5531,6 +6768,12 @@ pub struct SymInterpret {
     pub action: SIAction,
 }
 impl_debug_if_no_extra_traits!(SymInterpret, "SymInterpret");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SymInterpret {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SymInterpret {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (sym, remaining) = xproto::Keysym::try_parse(remaining)?;
@@ -5586,6 +6829,7 @@ impl Serialize for SymInterpret {
 
 #[derive(Debug, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Action([u8; 8]);
 impl Action {
     pub fn as_noaction(&self) -> SANoAction {
@@ -5903,6 +7147,7 @@ pub const USE_EXTENSION_REQUEST: u8 = 0;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct UseExtensionRequest {
     pub wanted_major: u16,
     pub wanted_minor: u16,
@@ -5954,6 +7199,34 @@ impl Request for UseExtensionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let wanted_major_bytes = self.wanted_major.serialize();
+        let wanted_minor_bytes = self.wanted_minor.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            USE_EXTENSION_REQUEST,
+            0,
+            0,
+            wanted_major_bytes[0],
+            wanted_major_bytes[1],
+            wanted_minor_bytes[0],
+            wanted_minor_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for UseExtensionRequest {
     type Reply = UseExtensionReply;
@@ -5970,6 +7243,12 @@ pub struct UseExtensionReply {
     pub server_minor: u16,
 }
 impl_debug_if_no_extra_traits!(UseExtensionReply, "UseExtensionReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for UseExtensionReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for UseExtensionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -6055,6 +7334,12 @@ pub struct SelectEventsAuxNewKeyboardNotify {
     pub new_keyboard_details: NKNDetail,
 }
 impl_debug_if_no_extra_traits!(SelectEventsAuxNewKeyboardNotify, "SelectEventsAuxNewKeyboardNotify");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SelectEventsAuxNewKeyboardNotify {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SelectEventsAuxNewKeyboardNotify {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (affect_new_keyboard, remaining) = u16::try_parse(remaining)?;
@@ -6091,6 +7376,12 @@ pub struct SelectEventsAuxStateNotify {
     pub state_details: StatePart,
 }
 impl_debug_if_no_extra_traits!(SelectEventsAuxStateNotify, "SelectEventsAuxStateNotify");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SelectEventsAuxStateNotify {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SelectEventsAuxStateNotify {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (affect_state, remaining) = u16::try_parse(remaining)?;
@@ -6127,6 +7418,12 @@ pub struct SelectEventsAuxControlsNotify {
     pub ctrl_details: Control,
 }
 impl_debug_if_no_extra_traits!(SelectEventsAuxControlsNotify, "SelectEventsAuxControlsNotify");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SelectEventsAuxControlsNotify {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SelectEventsAuxControlsNotify {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (affect_ctrls, remaining) = u32::try_parse(remaining)?;
@@ -6167,6 +7464,12 @@ pub struct SelectEventsAuxIndicatorStateNotify {
     pub indicator_state_details: u32,
 }
 impl_debug_if_no_extra_traits!(SelectEventsAuxIndicatorStateNotify, "SelectEventsAuxIndicatorStateNotify");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SelectEventsAuxIndicatorStateNotify {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SelectEventsAuxIndicatorStateNotify {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (affect_indicator_state, remaining) = u32::try_parse(remaining)?;
@@ -6205,6 +7508,12 @@ pub struct SelectEventsAuxIndicatorMapNotify {
     pub indicator_map_details: u32,
 }
 impl_debug_if_no_extra_traits!(SelectEventsAuxIndicatorMapNotify, "SelectEventsAuxIndicatorMapNotify");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SelectEventsAuxIndicatorMapNotify {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SelectEventsAuxIndicatorMapNotify {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (affect_indicator_map, remaining) = u32::try_parse(remaining)?;
@@ -6243,6 +7552,12 @@ pub struct SelectEventsAuxNamesNotify {
     pub names_details: NameDetail,
 }
 impl_debug_if_no_extra_traits!(SelectEventsAuxNamesNotify, "SelectEventsAuxNamesNotify");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SelectEventsAuxNamesNotify {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SelectEventsAuxNamesNotify {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (affect_names, remaining) = u16::try_parse(remaining)?;
@@ -6279,6 +7594,12 @@ pub struct SelectEventsAuxCompatMapNotify {
     pub compat_details: CMDetail,
 }
 impl_debug_if_no_extra_traits!(SelectEventsAuxCompatMapNotify, "SelectEventsAuxCompatMapNotify");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SelectEventsAuxCompatMapNotify {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SelectEventsAuxCompatMapNotify {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (affect_compat, remaining) = u8::try_parse(remaining)?;
@@ -6313,6 +7634,12 @@ pub struct SelectEventsAuxBellNotify {
     pub bell_details: u8,
 }
 impl_debug_if_no_extra_traits!(SelectEventsAuxBellNotify, "SelectEventsAuxBellNotify");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SelectEventsAuxBellNotify {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SelectEventsAuxBellNotify {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (affect_bell, remaining) = u8::try_parse(remaining)?;
@@ -6345,6 +7672,12 @@ pub struct SelectEventsAuxActionMessage {
     pub msg_details: u8,
 }
 impl_debug_if_no_extra_traits!(SelectEventsAuxActionMessage, "SelectEventsAuxActionMessage");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SelectEventsAuxActionMessage {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SelectEventsAuxActionMessage {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (affect_msg_details, remaining) = u8::try_parse(remaining)?;
@@ -6377,6 +7710,12 @@ pub struct SelectEventsAuxAccessXNotify {
     pub access_x_details: AXNDetail,
 }
 impl_debug_if_no_extra_traits!(SelectEventsAuxAccessXNotify, "SelectEventsAuxAccessXNotify");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SelectEventsAuxAccessXNotify {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SelectEventsAuxAccessXNotify {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (affect_access_x, remaining) = u16::try_parse(remaining)?;
@@ -6413,6 +7752,12 @@ pub struct SelectEventsAuxExtensionDeviceNotify {
     pub extdev_details: XIFeature,
 }
 impl_debug_if_no_extra_traits!(SelectEventsAuxExtensionDeviceNotify, "SelectEventsAuxExtensionDeviceNotify");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SelectEventsAuxExtensionDeviceNotify {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SelectEventsAuxExtensionDeviceNotify {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (affect_ext_dev, remaining) = u16::try_parse(remaining)?;
@@ -6445,6 +7790,7 @@ impl Serialize for SelectEventsAuxExtensionDeviceNotify {
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SelectEventsAux {
     pub new_keyboard_notify: Option<SelectEventsAuxNewKeyboardNotify>,
     pub state_notify: Option<SelectEventsAuxStateNotify>,
@@ -6706,6 +8052,7 @@ pub const SELECT_EVENTS_REQUEST: u8 = 1;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SelectEventsRequest<'input> {
     pub device_spec: DeviceSpec,
     pub clear: EventType,
@@ -6802,21 +8149,149 @@ impl<'input> Request for SelectEventsRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_spec_bytes = self.device_spec.serialize();
+        let affect_which: u16 = self.details.switch_expr() | (u16::from(self.clear) | u16::from(self.select_all));
+        let affect_which_bytes = affect_which.serialize();
+        let clear_bytes = u16::from(self.clear).serialize();
+        let select_all_bytes = u16::from(self.select_all).serialize();
+        let affect_map_bytes = u16::from(self.affect_map).serialize();
+        let map_bytes = u16::from(self.map).serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SELECT_EVENTS_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            affect_which_bytes[0],
+            affect_which_bytes[1],
+            clear_bytes[0],
+            clear_bytes[1],
+            select_all_bytes[0],
+            select_all_bytes[1],
+            affect_map_bytes[0],
+            affect_map_bytes[1],
+            map_bytes[0],
+            map_bytes[1],
+        ]);
+        self.details.serialize_into(buffer, u16::from(affect_which), u16::from(self.clear), u16::from(self.select_all));
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for SelectEventsRequest<'input> {
 }
-
-/// Opcode for the Bell request
-pub const BELL_REQUEST: u8 = 3;
-#[derive(Clone, Copy, Default)]
-#[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct BellRequest {
-    pub device_spec: DeviceSpec,
-    pub bell_class: BellClassSpec,
-    pub bell_id: IDSpec,
-    pub percent: i8,
-    pub force_sound: bool,
+impl SelectEventsRequest<'static> {
+    /// Create a new request with all fields of `details` unset.
+    ///
+    /// The optional fields can then be set with the builder methods of this request.
+    pub fn new(device_spec: DeviceSpec, clear: EventType, select_all: EventType, affect_map: MapPart, map: MapPart) -> Self {
+        SelectEventsRequest {
+            device_spec,
+            clear,
+            select_all,
+            affect_map,
+            map,
+            details: Cow::Owned(SelectEventsAux::new()),
+        }
+    }
+}
+impl<'input> SelectEventsRequest<'input> {
+    /// Set the `new_keyboard_notify` field of `details`.
+    #[must_use]
+    pub fn new_keyboard_notify<I>(mut self, value: I) -> Self where I: Into<Option<SelectEventsAuxNewKeyboardNotify>> {
+        self.details.to_mut().new_keyboard_notify = value.into();
+        self
+    }
+    /// Set the `state_notify` field of `details`.
+    #[must_use]
+    pub fn state_notify<I>(mut self, value: I) -> Self where I: Into<Option<SelectEventsAuxStateNotify>> {
+        self.details.to_mut().state_notify = value.into();
+        self
+    }
+    /// Set the `controls_notify` field of `details`.
+    #[must_use]
+    pub fn controls_notify<I>(mut self, value: I) -> Self where I: Into<Option<SelectEventsAuxControlsNotify>> {
+        self.details.to_mut().controls_notify = value.into();
+        self
+    }
+    /// Set the `indicator_state_notify` field of `details`.
+    #[must_use]
+    pub fn indicator_state_notify<I>(mut self, value: I) -> Self where I: Into<Option<SelectEventsAuxIndicatorStateNotify>> {
+        self.details.to_mut().indicator_state_notify = value.into();
+        self
+    }
+    /// Set the `indicator_map_notify` field of `details`.
+    #[must_use]
+    pub fn indicator_map_notify<I>(mut self, value: I) -> Self where I: Into<Option<SelectEventsAuxIndicatorMapNotify>> {
+        self.details.to_mut().indicator_map_notify = value.into();
+        self
+    }
+    /// Set the `names_notify` field of `details`.
+    #[must_use]
+    pub fn names_notify<I>(mut self, value: I) -> Self where I: Into<Option<SelectEventsAuxNamesNotify>> {
+        self.details.to_mut().names_notify = value.into();
+        self
+    }
+    /// Set the `compat_map_notify` field of `details`.
+    #[must_use]
+    pub fn compat_map_notify<I>(mut self, value: I) -> Self where I: Into<Option<SelectEventsAuxCompatMapNotify>> {
+        self.details.to_mut().compat_map_notify = value.into();
+        self
+    }
+    /// Set the `bell_notify` field of `details`.
+    #[must_use]
+    pub fn bell_notify<I>(mut self, value: I) -> Self where I: Into<Option<SelectEventsAuxBellNotify>> {
+        self.details.to_mut().bell_notify = value.into();
+        self
+    }
+    /// Set the `action_message` field of `details`.
+    #[must_use]
+    pub fn action_message<I>(mut self, value: I) -> Self where I: Into<Option<SelectEventsAuxActionMessage>> {
+        self.details.to_mut().action_message = value.into();
+        self
+    }
+    /// Set the `access_x_notify` field of `details`.
+    #[must_use]
+    pub fn access_x_notify<I>(mut self, value: I) -> Self where I: Into<Option<SelectEventsAuxAccessXNotify>> {
+        self.details.to_mut().access_x_notify = value.into();
+        self
+    }
+    /// Set the `extension_device_notify` field of `details`.
+    #[must_use]
+    pub fn extension_device_notify<I>(mut self, value: I) -> Self where I: Into<Option<SelectEventsAuxExtensionDeviceNotify>> {
+        self.details.to_mut().extension_device_notify = value.into();
+        self
+    }
+}
+
+/// Opcode for the Bell request
+pub const BELL_REQUEST: u8 = 3;
+#[derive(Clone, Copy, Default)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct BellRequest {
+    pub device_spec: DeviceSpec,
+    pub bell_class: BellClassSpec,
+    pub bell_id: IDSpec,
+    pub percent: i8,
+    pub force_sound: bool,
     pub event_only: bool,
     pub pitch: i16,
     pub duration: i16,
@@ -6916,6 +8391,62 @@ impl Request for BellRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_spec_bytes = self.device_spec.serialize();
+        let bell_class_bytes = self.bell_class.serialize();
+        let bell_id_bytes = self.bell_id.serialize();
+        let percent_bytes = self.percent.serialize();
+        let force_sound_bytes = self.force_sound.serialize();
+        let event_only_bytes = self.event_only.serialize();
+        let pitch_bytes = self.pitch.serialize();
+        let duration_bytes = self.duration.serialize();
+        let name_bytes = self.name.serialize();
+        let window_bytes = self.window.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            BELL_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            bell_class_bytes[0],
+            bell_class_bytes[1],
+            bell_id_bytes[0],
+            bell_id_bytes[1],
+            percent_bytes[0],
+            force_sound_bytes[0],
+            event_only_bytes[0],
+            0,
+            pitch_bytes[0],
+            pitch_bytes[1],
+            duration_bytes[0],
+            duration_bytes[1],
+            0,
+            0,
+            name_bytes[0],
+            name_bytes[1],
+            name_bytes[2],
+            name_bytes[3],
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for BellRequest {
 }
@@ -6925,6 +8456,7 @@ pub const GET_STATE_REQUEST: u8 = 4;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetStateRequest {
     pub device_spec: DeviceSpec,
 }
@@ -6973,6 +8505,33 @@ impl Request for GetStateRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_spec_bytes = self.device_spec.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_STATE_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetStateRequest {
     type Reply = GetStateReply;
@@ -7001,6 +8560,12 @@ pub struct GetStateReply {
     pub ptr_btn_state: xproto::KeyButMask,
 }
 impl_debug_if_no_extra_traits!(GetStateReply, "GetStateReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetStateReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetStateReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -7133,6 +8698,7 @@ pub const LATCH_LOCK_STATE_REQUEST: u8 = 5;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct LatchLockStateRequest {
     pub device_spec: DeviceSpec,
     pub affect_mod_locks: xproto::ModMask,
@@ -7222,6 +8788,48 @@ impl Request for LatchLockStateRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_spec_bytes = self.device_spec.serialize();
+        let affect_mod_locks_bytes = (u16::from(self.affect_mod_locks) as u8).serialize();
+        let mod_locks_bytes = (u16::from(self.mod_locks) as u8).serialize();
+        let lock_group_bytes = self.lock_group.serialize();
+        let group_lock_bytes = u8::from(self.group_lock).serialize();
+        let affect_mod_latches_bytes = (u16::from(self.affect_mod_latches) as u8).serialize();
+        let latch_group_bytes = self.latch_group.serialize();
+        let group_latch_bytes = self.group_latch.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            LATCH_LOCK_STATE_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            affect_mod_locks_bytes[0],
+            mod_locks_bytes[0],
+            lock_group_bytes[0],
+            group_lock_bytes[0],
+            affect_mod_latches_bytes[0],
+            0,
+            0,
+            latch_group_bytes[0],
+            group_latch_bytes[0],
+            group_latch_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for LatchLockStateRequest {
 }
@@ -7231,6 +8839,7 @@ pub const GET_CONTROLS_REQUEST: u8 = 6;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetControlsRequest {
     pub device_spec: DeviceSpec,
 }
@@ -7279,6 +8888,33 @@ impl Request for GetControlsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_spec_bytes = self.device_spec.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_CONTROLS_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetControlsRequest {
     type Reply = GetControlsReply;
@@ -7319,6 +8955,12 @@ pub struct GetControlsReply {
     pub per_key_repeat: [u8; 32],
 }
 impl_debug_if_no_extra_traits!(GetControlsReply, "GetControlsReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetControlsReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetControlsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -7546,6 +9188,7 @@ pub const SET_CONTROLS_REQUEST: u8 = 7;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetControlsRequest<'input> {
     pub device_spec: DeviceSpec,
     pub affect_internal_real_mods: xproto::ModMask,
@@ -7821,6 +9464,122 @@ impl<'input> Request for SetControlsRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_spec_bytes = self.device_spec.serialize();
+        let affect_internal_real_mods_bytes = (u16::from(self.affect_internal_real_mods) as u8).serialize();
+        let internal_real_mods_bytes = (u16::from(self.internal_real_mods) as u8).serialize();
+        let affect_ignore_lock_real_mods_bytes = (u16::from(self.affect_ignore_lock_real_mods) as u8).serialize();
+        let ignore_lock_real_mods_bytes = (u16::from(self.ignore_lock_real_mods) as u8).serialize();
+        let affect_internal_virtual_mods_bytes = u16::from(self.affect_internal_virtual_mods).serialize();
+        let internal_virtual_mods_bytes = u16::from(self.internal_virtual_mods).serialize();
+        let affect_ignore_lock_virtual_mods_bytes = u16::from(self.affect_ignore_lock_virtual_mods).serialize();
+        let ignore_lock_virtual_mods_bytes = u16::from(self.ignore_lock_virtual_mods).serialize();
+        let mouse_keys_dflt_btn_bytes = self.mouse_keys_dflt_btn.serialize();
+        let groups_wrap_bytes = self.groups_wrap.serialize();
+        let access_x_options_bytes = u16::from(self.access_x_options).serialize();
+        let affect_enabled_controls_bytes = u32::from(self.affect_enabled_controls).serialize();
+        let enabled_controls_bytes = u32::from(self.enabled_controls).serialize();
+        let change_controls_bytes = u32::from(self.change_controls).serialize();
+        let repeat_delay_bytes = self.repeat_delay.serialize();
+        let repeat_interval_bytes = self.repeat_interval.serialize();
+        let slow_keys_delay_bytes = self.slow_keys_delay.serialize();
+        let debounce_delay_bytes = self.debounce_delay.serialize();
+        let mouse_keys_delay_bytes = self.mouse_keys_delay.serialize();
+        let mouse_keys_interval_bytes = self.mouse_keys_interval.serialize();
+        let mouse_keys_time_to_max_bytes = self.mouse_keys_time_to_max.serialize();
+        let mouse_keys_max_speed_bytes = self.mouse_keys_max_speed.serialize();
+        let mouse_keys_curve_bytes = self.mouse_keys_curve.serialize();
+        let access_x_timeout_bytes = self.access_x_timeout.serialize();
+        let access_x_timeout_mask_bytes = u32::from(self.access_x_timeout_mask).serialize();
+        let access_x_timeout_values_bytes = u32::from(self.access_x_timeout_values).serialize();
+        let access_x_timeout_options_mask_bytes = u16::from(self.access_x_timeout_options_mask).serialize();
+        let access_x_timeout_options_values_bytes = u16::from(self.access_x_timeout_options_values).serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_CONTROLS_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            affect_internal_real_mods_bytes[0],
+            internal_real_mods_bytes[0],
+            affect_ignore_lock_real_mods_bytes[0],
+            ignore_lock_real_mods_bytes[0],
+            affect_internal_virtual_mods_bytes[0],
+            affect_internal_virtual_mods_bytes[1],
+            internal_virtual_mods_bytes[0],
+            internal_virtual_mods_bytes[1],
+            affect_ignore_lock_virtual_mods_bytes[0],
+            affect_ignore_lock_virtual_mods_bytes[1],
+            ignore_lock_virtual_mods_bytes[0],
+            ignore_lock_virtual_mods_bytes[1],
+            mouse_keys_dflt_btn_bytes[0],
+            groups_wrap_bytes[0],
+            access_x_options_bytes[0],
+            access_x_options_bytes[1],
+            0,
+            0,
+            affect_enabled_controls_bytes[0],
+            affect_enabled_controls_bytes[1],
+            affect_enabled_controls_bytes[2],
+            affect_enabled_controls_bytes[3],
+            enabled_controls_bytes[0],
+            enabled_controls_bytes[1],
+            enabled_controls_bytes[2],
+            enabled_controls_bytes[3],
+            change_controls_bytes[0],
+            change_controls_bytes[1],
+            change_controls_bytes[2],
+            change_controls_bytes[3],
+            repeat_delay_bytes[0],
+            repeat_delay_bytes[1],
+            repeat_interval_bytes[0],
+            repeat_interval_bytes[1],
+            slow_keys_delay_bytes[0],
+            slow_keys_delay_bytes[1],
+            debounce_delay_bytes[0],
+            debounce_delay_bytes[1],
+            mouse_keys_delay_bytes[0],
+            mouse_keys_delay_bytes[1],
+            mouse_keys_interval_bytes[0],
+            mouse_keys_interval_bytes[1],
+            mouse_keys_time_to_max_bytes[0],
+            mouse_keys_time_to_max_bytes[1],
+            mouse_keys_max_speed_bytes[0],
+            mouse_keys_max_speed_bytes[1],
+            mouse_keys_curve_bytes[0],
+            mouse_keys_curve_bytes[1],
+            access_x_timeout_bytes[0],
+            access_x_timeout_bytes[1],
+            access_x_timeout_mask_bytes[0],
+            access_x_timeout_mask_bytes[1],
+            access_x_timeout_mask_bytes[2],
+            access_x_timeout_mask_bytes[3],
+            access_x_timeout_values_bytes[0],
+            access_x_timeout_values_bytes[1],
+            access_x_timeout_values_bytes[2],
+            access_x_timeout_values_bytes[3],
+            access_x_timeout_options_mask_bytes[0],
+            access_x_timeout_options_mask_bytes[1],
+            access_x_timeout_options_values_bytes[0],
+            access_x_timeout_options_values_bytes[1],
+        ]);
+        buffer.extend_from_slice(&self.per_key_repeat[..]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for SetControlsRequest<'input> {
 }
@@ -7830,6 +9589,7 @@ pub const GET_MAP_REQUEST: u8 = 8;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetMapRequest {
     pub device_spec: DeviceSpec,
     pub full: MapPart,
@@ -7959,15 +9719,79 @@ impl GetMapRequest {
             n_v_mod_map_keys,
         })
     }
-}
-impl Request for GetMapRequest {
-    const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
-
-    fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
+}
+impl Request for GetMapRequest {
+    const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+
+    fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
+        let (bufs, fds) = self.serialize(major_opcode);
+        // Flatten the buffers into a single vector
+        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
+        (buf, fds)
+    }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_spec_bytes = self.device_spec.serialize();
+        let full_bytes = u16::from(self.full).serialize();
+        let partial_bytes = u16::from(self.partial).serialize();
+        let first_type_bytes = self.first_type.serialize();
+        let n_types_bytes = self.n_types.serialize();
+        let first_key_sym_bytes = self.first_key_sym.serialize();
+        let n_key_syms_bytes = self.n_key_syms.serialize();
+        let first_key_action_bytes = self.first_key_action.serialize();
+        let n_key_actions_bytes = self.n_key_actions.serialize();
+        let first_key_behavior_bytes = self.first_key_behavior.serialize();
+        let n_key_behaviors_bytes = self.n_key_behaviors.serialize();
+        let virtual_mods_bytes = u16::from(self.virtual_mods).serialize();
+        let first_key_explicit_bytes = self.first_key_explicit.serialize();
+        let n_key_explicit_bytes = self.n_key_explicit.serialize();
+        let first_mod_map_key_bytes = self.first_mod_map_key.serialize();
+        let n_mod_map_keys_bytes = self.n_mod_map_keys.serialize();
+        let first_v_mod_map_key_bytes = self.first_v_mod_map_key.serialize();
+        let n_v_mod_map_keys_bytes = self.n_v_mod_map_keys.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_MAP_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            full_bytes[0],
+            full_bytes[1],
+            partial_bytes[0],
+            partial_bytes[1],
+            first_type_bytes[0],
+            n_types_bytes[0],
+            first_key_sym_bytes[0],
+            n_key_syms_bytes[0],
+            first_key_action_bytes[0],
+            n_key_actions_bytes[0],
+            first_key_behavior_bytes[0],
+            n_key_behaviors_bytes[0],
+            virtual_mods_bytes[0],
+            virtual_mods_bytes[1],
+            first_key_explicit_bytes[0],
+            n_key_explicit_bytes[0],
+            first_mod_map_key_bytes[0],
+            n_mod_map_keys_bytes[0],
+            first_v_mod_map_key_bytes[0],
+            n_v_mod_map_keys_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
         let (bufs, fds) = self.serialize(major_opcode);
-        // Flatten the buffers into a single vector
-        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
-        (buf, fds)
+        slices.extend(bufs);
+        fds
     }
 }
 impl crate::x11_utils::ReplyRequest for GetMapRequest {
@@ -7977,6 +9801,7 @@ impl crate::x11_utils::ReplyRequest for GetMapRequest {
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetMapMapKeyActions {
     pub acts_rtrn_count: Vec<u8>,
     pub acts_rtrn_acts: Vec<Action>,
@@ -8014,6 +9839,7 @@ impl GetMapMapKeyActions {
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetMapMap {
     pub types_rtrn: Option<Vec<KeyType>>,
     pub syms_rtrn: Option<Vec<KeySymMap>>,
@@ -8066,7 +9892,7 @@ impl GetMapMap {
             let value = remaining;
             let mut remaining = remaining;
             let list_length = u32::from(virtual_mods).count_ones().try_to_usize()?;
-            let mut vmods_rtrn = Vec::with_capacity(list_length);
+            let mut vmods_rtrn = Vec::with_capacity(list_length.min(remaining.len()));
             for _ in 0..list_length {
                 let (v, new_remaining) = u8::try_parse(remaining)?;
                 let v = v.into();
@@ -8232,6 +10058,12 @@ pub struct GetMapReply {
     pub map: GetMapMap,
 }
 impl_debug_if_no_extra_traits!(GetMapReply, "GetMapReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetMapReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetMapReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -8321,12 +10153,14 @@ impl Serialize for GetMapReply {
         bytes.extend_from_slice(&[0; 1]);
         u16::from(self.virtual_mods).serialize_into(bytes);
         self.map.serialize_into(bytes, u16::from(present), u8::from(self.n_types), u8::from(self.n_key_syms), u8::from(self.n_key_actions), u16::from(self.total_actions), u8::from(self.total_key_behaviors), u16::from(self.virtual_mods), u8::from(self.total_key_explicit), u8::from(self.total_mod_map_keys), u8::from(self.total_v_mod_map_keys));
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetMapAuxKeyActions {
     pub actions_count: Vec<u8>,
     pub actions: Vec<Action>,
@@ -8365,6 +10199,7 @@ impl SetMapAuxKeyActions {
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetMapAux {
     pub types: Option<Vec<SetKeyType>>,
     pub syms: Option<Vec<KeySymMap>>,
@@ -8587,6 +10422,7 @@ pub const SET_MAP_REQUEST: u8 = 9;
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetMapRequest<'input> {
     pub device_spec: DeviceSpec,
     pub flags: SetMapFlags,
@@ -8801,15 +10637,185 @@ impl<'input> Request for SetMapRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_spec_bytes = self.device_spec.serialize();
+        let present: u16 = self.values.switch_expr();
+        let present_bytes = present.serialize();
+        let flags_bytes = u16::from(self.flags).serialize();
+        let min_key_code_bytes = self.min_key_code.serialize();
+        let max_key_code_bytes = self.max_key_code.serialize();
+        let first_type_bytes = self.first_type.serialize();
+        let n_types_bytes = self.n_types.serialize();
+        let first_key_sym_bytes = self.first_key_sym.serialize();
+        let n_key_syms_bytes = self.n_key_syms.serialize();
+        let total_syms_bytes = self.total_syms.serialize();
+        let first_key_action_bytes = self.first_key_action.serialize();
+        let n_key_actions_bytes = self.n_key_actions.serialize();
+        let total_actions_bytes = self.total_actions.serialize();
+        let first_key_behavior_bytes = self.first_key_behavior.serialize();
+        let n_key_behaviors_bytes = self.n_key_behaviors.serialize();
+        let total_key_behaviors_bytes = self.total_key_behaviors.serialize();
+        let first_key_explicit_bytes = self.first_key_explicit.serialize();
+        let n_key_explicit_bytes = self.n_key_explicit.serialize();
+        let total_key_explicit_bytes = self.total_key_explicit.serialize();
+        let first_mod_map_key_bytes = self.first_mod_map_key.serialize();
+        let n_mod_map_keys_bytes = self.n_mod_map_keys.serialize();
+        let total_mod_map_keys_bytes = self.total_mod_map_keys.serialize();
+        let first_v_mod_map_key_bytes = self.first_v_mod_map_key.serialize();
+        let n_v_mod_map_keys_bytes = self.n_v_mod_map_keys.serialize();
+        let total_v_mod_map_keys_bytes = self.total_v_mod_map_keys.serialize();
+        let virtual_mods_bytes = u16::from(self.virtual_mods).serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_MAP_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            present_bytes[0],
+            present_bytes[1],
+            flags_bytes[0],
+            flags_bytes[1],
+            min_key_code_bytes[0],
+            max_key_code_bytes[0],
+            first_type_bytes[0],
+            n_types_bytes[0],
+            first_key_sym_bytes[0],
+            n_key_syms_bytes[0],
+            total_syms_bytes[0],
+            total_syms_bytes[1],
+            first_key_action_bytes[0],
+            n_key_actions_bytes[0],
+            total_actions_bytes[0],
+            total_actions_bytes[1],
+            first_key_behavior_bytes[0],
+            n_key_behaviors_bytes[0],
+            total_key_behaviors_bytes[0],
+            first_key_explicit_bytes[0],
+            n_key_explicit_bytes[0],
+            total_key_explicit_bytes[0],
+            first_mod_map_key_bytes[0],
+            n_mod_map_keys_bytes[0],
+            total_mod_map_keys_bytes[0],
+            first_v_mod_map_key_bytes[0],
+            n_v_mod_map_keys_bytes[0],
+            total_v_mod_map_keys_bytes[0],
+            virtual_mods_bytes[0],
+            virtual_mods_bytes[1],
+        ]);
+        self.values.serialize_into(buffer, u16::from(present), u8::from(self.n_types), u8::from(self.n_key_syms), u8::from(self.n_key_actions), u16::from(self.total_actions), u8::from(self.total_key_behaviors), u16::from(self.virtual_mods), u8::from(self.total_key_explicit), u8::from(self.total_mod_map_keys), u8::from(self.total_v_mod_map_keys));
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for SetMapRequest<'input> {
 }
+impl SetMapRequest<'static> {
+    /// Create a new request with all fields of `values` unset.
+    ///
+    /// The optional fields can then be set with the builder methods of this request.
+    pub fn new(device_spec: DeviceSpec, flags: SetMapFlags, min_key_code: xproto::Keycode, max_key_code: xproto::Keycode, first_type: u8, n_types: u8, first_key_sym: xproto::Keycode, n_key_syms: u8, total_syms: u16, first_key_action: xproto::Keycode, n_key_actions: u8, total_actions: u16, first_key_behavior: xproto::Keycode, n_key_behaviors: u8, total_key_behaviors: u8, first_key_explicit: xproto::Keycode, n_key_explicit: u8, total_key_explicit: u8, first_mod_map_key: xproto::Keycode, n_mod_map_keys: u8, total_mod_map_keys: u8, first_v_mod_map_key: xproto::Keycode, n_v_mod_map_keys: u8, total_v_mod_map_keys: u8, virtual_mods: VMod) -> Self {
+        SetMapRequest {
+            device_spec,
+            flags,
+            min_key_code,
+            max_key_code,
+            first_type,
+            n_types,
+            first_key_sym,
+            n_key_syms,
+            total_syms,
+            first_key_action,
+            n_key_actions,
+            total_actions,
+            first_key_behavior,
+            n_key_behaviors,
+            total_key_behaviors,
+            first_key_explicit,
+            n_key_explicit,
+            total_key_explicit,
+            first_mod_map_key,
+            n_mod_map_keys,
+            total_mod_map_keys,
+            first_v_mod_map_key,
+            n_v_mod_map_keys,
+            total_v_mod_map_keys,
+            virtual_mods,
+            values: Cow::Owned(SetMapAux::new()),
+        }
+    }
+}
+impl<'input> SetMapRequest<'input> {
+    /// Set the `types` field of `values`.
+    #[must_use]
+    pub fn types<I>(mut self, value: I) -> Self where I: Into<Option<Vec<SetKeyType>>> {
+        self.values.to_mut().types = value.into();
+        self
+    }
+    /// Set the `syms` field of `values`.
+    #[must_use]
+    pub fn syms<I>(mut self, value: I) -> Self where I: Into<Option<Vec<KeySymMap>>> {
+        self.values.to_mut().syms = value.into();
+        self
+    }
+    /// Set the `key_actions` field of `values`.
+    #[must_use]
+    pub fn key_actions<I>(mut self, value: I) -> Self where I: Into<Option<SetMapAuxKeyActions>> {
+        self.values.to_mut().key_actions = value.into();
+        self
+    }
+    /// Set the `behaviors` field of `values`.
+    #[must_use]
+    pub fn behaviors<I>(mut self, value: I) -> Self where I: Into<Option<Vec<SetBehavior>>> {
+        self.values.to_mut().behaviors = value.into();
+        self
+    }
+    /// Set the `vmods` field of `values`.
+    #[must_use]
+    pub fn vmods<I>(mut self, value: I) -> Self where I: Into<Option<Vec<u8>>> {
+        self.values.to_mut().vmods = value.into();
+        self
+    }
+    /// Set the `explicit` field of `values`.
+    #[must_use]
+    pub fn explicit<I>(mut self, value: I) -> Self where I: Into<Option<Vec<SetExplicit>>> {
+        self.values.to_mut().explicit = value.into();
+        self
+    }
+    /// Set the `modmap` field of `values`.
+    #[must_use]
+    pub fn modmap<I>(mut self, value: I) -> Self where I: Into<Option<Vec<KeyModMap>>> {
+        self.values.to_mut().modmap = value.into();
+        self
+    }
+    /// Set the `vmodmap` field of `values`.
+    #[must_use]
+    pub fn vmodmap<I>(mut self, value: I) -> Self where I: Into<Option<Vec<KeyVModMap>>> {
+        self.values.to_mut().vmodmap = value.into();
+        self
+    }
+}
 
 /// Opcode for the GetCompatMap request
 pub const GET_COMPAT_MAP_REQUEST: u8 = 10;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetCompatMapRequest {
     pub device_spec: DeviceSpec,
     pub groups: SetOfGroup,
@@ -8878,6 +10884,41 @@ impl Request for GetCompatMapRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_spec_bytes = self.device_spec.serialize();
+        let groups_bytes = u8::from(self.groups).serialize();
+        let get_all_si_bytes = self.get_all_si.serialize();
+        let first_si_bytes = self.first_si.serialize();
+        let n_si_bytes = self.n_si.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_COMPAT_MAP_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            groups_bytes[0],
+            get_all_si_bytes[0],
+            first_si_bytes[0],
+            first_si_bytes[1],
+            n_si_bytes[0],
+            n_si_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetCompatMapRequest {
     type Reply = GetCompatMapReply;
@@ -8897,6 +10938,12 @@ pub struct GetCompatMapReply {
     pub group_rtrn: Vec<ModDef>,
 }
 impl_debug_if_no_extra_traits!(GetCompatMapReply, "GetCompatMapReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetCompatMapReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetCompatMapReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -8947,6 +10994,7 @@ impl Serialize for GetCompatMapReply {
         self.si_rtrn.serialize_into(bytes);
         assert_eq!(self.group_rtrn.len(), usize::try_from(u32::from(self.groups_rtrn).count_ones()).unwrap(), "`group_rtrn` has an incorrect length");
         self.group_rtrn.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetCompatMapReply {
@@ -8970,6 +11018,7 @@ pub const SET_COMPAT_MAP_REQUEST: u8 = 11;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetCompatMapRequest<'input> {
     pub device_spec: DeviceSpec,
     pub recompute_actions: bool,
@@ -9072,6 +11121,52 @@ impl<'input> Request for SetCompatMapRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_spec_bytes = self.device_spec.serialize();
+        let recompute_actions_bytes = self.recompute_actions.serialize();
+        let truncate_si_bytes = self.truncate_si.serialize();
+        let groups_bytes = u8::from(self.groups).serialize();
+        let first_si_bytes = self.first_si.serialize();
+        let n_si = u16::try_from(self.si.len()).expect("`si` has too many elements");
+        let n_si_bytes = n_si.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_COMPAT_MAP_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            0,
+            recompute_actions_bytes[0],
+            truncate_si_bytes[0],
+            groups_bytes[0],
+            first_si_bytes[0],
+            first_si_bytes[1],
+            n_si_bytes[0],
+            n_si_bytes[1],
+            0,
+            0,
+        ]);
+        self.si.serialize_into(buffer);
+        assert_eq!(self.group_maps.len(), usize::try_from(u32::from(self.groups).count_ones()).unwrap(), "`group_maps` has an incorrect length");
+        self.group_maps.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for SetCompatMapRequest<'input> {
 }
@@ -9081,6 +11176,7 @@ pub const GET_INDICATOR_STATE_REQUEST: u8 = 12;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetIndicatorStateRequest {
     pub device_spec: DeviceSpec,
 }
@@ -9129,6 +11225,33 @@ impl Request for GetIndicatorStateRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_spec_bytes = self.device_spec.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_INDICATOR_STATE_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetIndicatorStateRequest {
     type Reply = GetIndicatorStateReply;
@@ -9144,6 +11267,12 @@ pub struct GetIndicatorStateReply {
     pub state: u32,
 }
 impl_debug_if_no_extra_traits!(GetIndicatorStateReply, "GetIndicatorStateReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetIndicatorStateReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetIndicatorStateReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -9223,6 +11352,7 @@ pub const GET_INDICATOR_MAP_REQUEST: u8 = 13;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetIndicatorMapRequest {
     pub device_spec: DeviceSpec,
     pub which: u32,
@@ -9279,6 +11409,38 @@ impl Request for GetIndicatorMapRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_spec_bytes = self.device_spec.serialize();
+        let which_bytes = self.which.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_INDICATOR_MAP_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            0,
+            0,
+            which_bytes[0],
+            which_bytes[1],
+            which_bytes[2],
+            which_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetIndicatorMapRequest {
     type Reply = GetIndicatorMapReply;
@@ -9297,6 +11459,12 @@ pub struct GetIndicatorMapReply {
     pub maps: Vec<IndicatorMap>,
 }
 impl_debug_if_no_extra_traits!(GetIndicatorMapReply, "GetIndicatorMapReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetIndicatorMapReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetIndicatorMapReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -9339,6 +11507,7 @@ impl Serialize for GetIndicatorMapReply {
         bytes.extend_from_slice(&[0; 15]);
         assert_eq!(self.maps.len(), usize::try_from(u32::from(self.which).count_ones()).unwrap(), "`maps` has an incorrect length");
         self.maps.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 
@@ -9347,6 +11516,7 @@ pub const SET_INDICATOR_MAP_REQUEST: u8 = 14;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetIndicatorMapRequest<'input> {
     pub device_spec: DeviceSpec,
     pub which: u32,
@@ -9409,15 +11579,51 @@ impl<'input> SetIndicatorMapRequest<'input> {
             maps: Cow::Owned(self.maps.into_owned()),
         }
     }
-}
-impl<'input> Request for SetIndicatorMapRequest<'input> {
-    const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
-
-    fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
+}
+impl<'input> Request for SetIndicatorMapRequest<'input> {
+    const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+
+    fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
+        let (bufs, fds) = self.serialize(major_opcode);
+        // Flatten the buffers into a single vector
+        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
+        (buf, fds)
+    }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_spec_bytes = self.device_spec.serialize();
+        let which_bytes = self.which.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_INDICATOR_MAP_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            0,
+            0,
+            which_bytes[0],
+            which_bytes[1],
+            which_bytes[2],
+            which_bytes[3],
+        ]);
+        assert_eq!(self.maps.len(), usize::try_from(u32::from(self.which).count_ones()).unwrap(), "`maps` has an incorrect length");
+        self.maps.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
         let (bufs, fds) = self.serialize(major_opcode);
-        // Flatten the buffers into a single vector
-        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
-        (buf, fds)
+        slices.extend(bufs);
+        fds
     }
 }
 impl<'input> crate::x11_utils::VoidRequest for SetIndicatorMapRequest<'input> {
@@ -9428,6 +11634,7 @@ pub const GET_NAMED_INDICATOR_REQUEST: u8 = 15;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetNamedIndicatorRequest {
     pub device_spec: DeviceSpec,
     pub led_class: LedClass,
@@ -9497,6 +11704,44 @@ impl Request for GetNamedIndicatorRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_spec_bytes = self.device_spec.serialize();
+        let led_class_bytes = LedClassSpec::from(self.led_class).serialize();
+        let led_id_bytes = self.led_id.serialize();
+        let indicator_bytes = self.indicator.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_NAMED_INDICATOR_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            led_class_bytes[0],
+            led_class_bytes[1],
+            led_id_bytes[0],
+            led_id_bytes[1],
+            0,
+            0,
+            indicator_bytes[0],
+            indicator_bytes[1],
+            indicator_bytes[2],
+            indicator_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetNamedIndicatorRequest {
     type Reply = GetNamedIndicatorReply;
@@ -9525,6 +11770,12 @@ pub struct GetNamedIndicatorReply {
     pub supported: bool,
 }
 impl_debug_if_no_extra_traits!(GetNamedIndicatorReply, "GetNamedIndicatorReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetNamedIndicatorReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetNamedIndicatorReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -9651,6 +11902,7 @@ pub const SET_NAMED_INDICATOR_REQUEST: u8 = 16;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetNamedIndicatorRequest {
     pub device_spec: DeviceSpec,
     pub led_class: LedClass,
@@ -9788,6 +12040,71 @@ impl Request for SetNamedIndicatorRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_spec_bytes = self.device_spec.serialize();
+        let led_class_bytes = LedClassSpec::from(self.led_class).serialize();
+        let led_id_bytes = self.led_id.serialize();
+        let indicator_bytes = self.indicator.serialize();
+        let set_state_bytes = self.set_state.serialize();
+        let on_bytes = self.on.serialize();
+        let set_map_bytes = self.set_map.serialize();
+        let create_map_bytes = self.create_map.serialize();
+        let map_flags_bytes = u8::from(self.map_flags).serialize();
+        let map_which_groups_bytes = u8::from(self.map_which_groups).serialize();
+        let map_groups_bytes = u8::from(self.map_groups).serialize();
+        let map_which_mods_bytes = u8::from(self.map_which_mods).serialize();
+        let map_real_mods_bytes = (u16::from(self.map_real_mods) as u8).serialize();
+        let map_vmods_bytes = u16::from(self.map_vmods).serialize();
+        let map_ctrls_bytes = u32::from(self.map_ctrls).serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_NAMED_INDICATOR_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            led_class_bytes[0],
+            led_class_bytes[1],
+            led_id_bytes[0],
+            led_id_bytes[1],
+            0,
+            0,
+            indicator_bytes[0],
+            indicator_bytes[1],
+            indicator_bytes[2],
+            indicator_bytes[3],
+            set_state_bytes[0],
+            on_bytes[0],
+            set_map_bytes[0],
+            create_map_bytes[0],
+            0,
+            map_flags_bytes[0],
+            map_which_groups_bytes[0],
+            map_groups_bytes[0],
+            map_which_mods_bytes[0],
+            map_real_mods_bytes[0],
+            map_vmods_bytes[0],
+            map_vmods_bytes[1],
+            map_ctrls_bytes[0],
+            map_ctrls_bytes[1],
+            map_ctrls_bytes[2],
+            map_ctrls_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SetNamedIndicatorRequest {
 }
@@ -9797,6 +12114,7 @@ pub const GET_NAMES_REQUEST: u8 = 17;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetNamesRequest {
     pub device_spec: DeviceSpec,
     pub which: NameDetail,
@@ -9854,6 +12172,38 @@ impl Request for GetNamesRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_spec_bytes = self.device_spec.serialize();
+        let which_bytes = u32::from(self.which).serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_NAMES_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            0,
+            0,
+            which_bytes[0],
+            which_bytes[1],
+            which_bytes[2],
+            which_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetNamesRequest {
     type Reply = GetNamesReply;
@@ -9862,6 +12212,7 @@ impl crate::x11_utils::ReplyRequest for GetNamesRequest {
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetNamesValueListKTLevelNames {
     pub n_levels_per_type: Vec<u8>,
     pub kt_level_names: Vec<xproto::Atom>,
@@ -9899,6 +12250,7 @@ impl GetNamesValueListKTLevelNames {
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetNamesValueList {
     pub keycodes_name: Option<xproto::Atom>,
     pub geometry_name: Option<xproto::Atom>,
@@ -10166,6 +12518,12 @@ pub struct GetNamesReply {
     pub value_list: GetNamesValueList,
 }
 impl_debug_if_no_extra_traits!(GetNamesReply, "GetNamesReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetNamesReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetNamesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -10228,12 +12586,14 @@ impl Serialize for GetNamesReply {
         self.n_kt_levels.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 4]);
         self.value_list.serialize_into(bytes, u32::from(which), u8::from(self.n_types), u32::from(self.indicators), u16::from(self.virtual_mods), u8::from(self.group_names), u8::from(self.n_keys), u8::from(self.n_key_aliases), u8::from(self.n_radio_groups));
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetNamesAuxKTLevelNames {
     pub n_levels_per_type: Vec<u8>,
     pub kt_level_names: Vec<xproto::Atom>,
@@ -10272,6 +12632,7 @@ impl SetNamesAuxKTLevelNames {
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetNamesAux {
     pub keycodes_name: Option<xproto::Atom>,
     pub geometry_name: Option<xproto::Atom>,
@@ -10613,6 +12974,7 @@ pub const SET_NAMES_REQUEST: u8 = 18;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetNamesRequest<'input> {
     pub device_spec: DeviceSpec,
     pub virtual_mods: VMod,
@@ -10731,37 +13093,210 @@ impl<'input> SetNamesRequest<'input> {
             values: Cow::Owned(values),
         })
     }
-    /// Clone all borrowed data in this SetNamesRequest.
-    pub fn into_owned(self) -> SetNamesRequest<'static> {
-        SetNamesRequest {
-            device_spec: self.device_spec,
-            virtual_mods: self.virtual_mods,
-            first_type: self.first_type,
-            n_types: self.n_types,
-            first_kt_levelt: self.first_kt_levelt,
-            n_kt_levels: self.n_kt_levels,
-            indicators: self.indicators,
-            group_names: self.group_names,
-            n_radio_groups: self.n_radio_groups,
-            first_key: self.first_key,
-            n_keys: self.n_keys,
-            n_key_aliases: self.n_key_aliases,
-            total_kt_level_names: self.total_kt_level_names,
-            values: Cow::Owned(self.values.into_owned()),
-        }
+    /// Clone all borrowed data in this SetNamesRequest.
+    pub fn into_owned(self) -> SetNamesRequest<'static> {
+        SetNamesRequest {
+            device_spec: self.device_spec,
+            virtual_mods: self.virtual_mods,
+            first_type: self.first_type,
+            n_types: self.n_types,
+            first_kt_levelt: self.first_kt_levelt,
+            n_kt_levels: self.n_kt_levels,
+            indicators: self.indicators,
+            group_names: self.group_names,
+            n_radio_groups: self.n_radio_groups,
+            first_key: self.first_key,
+            n_keys: self.n_keys,
+            n_key_aliases: self.n_key_aliases,
+            total_kt_level_names: self.total_kt_level_names,
+            values: Cow::Owned(self.values.into_owned()),
+        }
+    }
+}
+impl<'input> Request for SetNamesRequest<'input> {
+    const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
+
+    fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
+        let (bufs, fds) = self.serialize(major_opcode);
+        // Flatten the buffers into a single vector
+        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
+        (buf, fds)
+    }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_spec_bytes = self.device_spec.serialize();
+        let virtual_mods_bytes = u16::from(self.virtual_mods).serialize();
+        let which: u32 = self.values.switch_expr();
+        let which_bytes = which.serialize();
+        let first_type_bytes = self.first_type.serialize();
+        let n_types_bytes = self.n_types.serialize();
+        let first_kt_levelt_bytes = self.first_kt_levelt.serialize();
+        let n_kt_levels_bytes = self.n_kt_levels.serialize();
+        let indicators_bytes = self.indicators.serialize();
+        let group_names_bytes = u8::from(self.group_names).serialize();
+        let n_radio_groups_bytes = self.n_radio_groups.serialize();
+        let first_key_bytes = self.first_key.serialize();
+        let n_keys_bytes = self.n_keys.serialize();
+        let n_key_aliases_bytes = self.n_key_aliases.serialize();
+        let total_kt_level_names_bytes = self.total_kt_level_names.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_NAMES_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            virtual_mods_bytes[0],
+            virtual_mods_bytes[1],
+            which_bytes[0],
+            which_bytes[1],
+            which_bytes[2],
+            which_bytes[3],
+            first_type_bytes[0],
+            n_types_bytes[0],
+            first_kt_levelt_bytes[0],
+            n_kt_levels_bytes[0],
+            indicators_bytes[0],
+            indicators_bytes[1],
+            indicators_bytes[2],
+            indicators_bytes[3],
+            group_names_bytes[0],
+            n_radio_groups_bytes[0],
+            first_key_bytes[0],
+            n_keys_bytes[0],
+            n_key_aliases_bytes[0],
+            0,
+            total_kt_level_names_bytes[0],
+            total_kt_level_names_bytes[1],
+        ]);
+        self.values.serialize_into(buffer, u32::from(which), u8::from(self.n_types), u32::from(self.indicators), u16::from(self.virtual_mods), u8::from(self.group_names), u8::from(self.n_keys), u8::from(self.n_key_aliases), u8::from(self.n_radio_groups));
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
+}
+impl<'input> crate::x11_utils::VoidRequest for SetNamesRequest<'input> {
+}
+impl SetNamesRequest<'static> {
+    /// Create a new request with all fields of `values` unset.
+    ///
+    /// The optional fields can then be set with the builder methods of this request.
+    pub fn new(device_spec: DeviceSpec, virtual_mods: VMod, first_type: u8, n_types: u8, first_kt_levelt: u8, n_kt_levels: u8, indicators: u32, group_names: SetOfGroup, n_radio_groups: u8, first_key: xproto::Keycode, n_keys: u8, n_key_aliases: u8, total_kt_level_names: u16) -> Self {
+        SetNamesRequest {
+            device_spec,
+            virtual_mods,
+            first_type,
+            n_types,
+            first_kt_levelt,
+            n_kt_levels,
+            indicators,
+            group_names,
+            n_radio_groups,
+            first_key,
+            n_keys,
+            n_key_aliases,
+            total_kt_level_names,
+            values: Cow::Owned(SetNamesAux::new()),
+        }
+    }
+}
+impl<'input> SetNamesRequest<'input> {
+    /// Set the `keycodes_name` field of `values`.
+    #[must_use]
+    pub fn keycodes_name<I>(mut self, value: I) -> Self where I: Into<Option<xproto::Atom>> {
+        self.values.to_mut().keycodes_name = value.into();
+        self
+    }
+    /// Set the `geometry_name` field of `values`.
+    #[must_use]
+    pub fn geometry_name<I>(mut self, value: I) -> Self where I: Into<Option<xproto::Atom>> {
+        self.values.to_mut().geometry_name = value.into();
+        self
+    }
+    /// Set the `symbols_name` field of `values`.
+    #[must_use]
+    pub fn symbols_name<I>(mut self, value: I) -> Self where I: Into<Option<xproto::Atom>> {
+        self.values.to_mut().symbols_name = value.into();
+        self
+    }
+    /// Set the `phys_symbols_name` field of `values`.
+    #[must_use]
+    pub fn phys_symbols_name<I>(mut self, value: I) -> Self where I: Into<Option<xproto::Atom>> {
+        self.values.to_mut().phys_symbols_name = value.into();
+        self
+    }
+    /// Set the `types_name` field of `values`.
+    #[must_use]
+    pub fn types_name<I>(mut self, value: I) -> Self where I: Into<Option<xproto::Atom>> {
+        self.values.to_mut().types_name = value.into();
+        self
+    }
+    /// Set the `compat_name` field of `values`.
+    #[must_use]
+    pub fn compat_name<I>(mut self, value: I) -> Self where I: Into<Option<xproto::Atom>> {
+        self.values.to_mut().compat_name = value.into();
+        self
+    }
+    /// Set the `type_names` field of `values`.
+    #[must_use]
+    pub fn type_names<I>(mut self, value: I) -> Self where I: Into<Option<Vec<xproto::Atom>>> {
+        self.values.to_mut().type_names = value.into();
+        self
+    }
+    /// Set the `kt_level_names` field of `values`.
+    #[must_use]
+    pub fn kt_level_names<I>(mut self, value: I) -> Self where I: Into<Option<SetNamesAuxKTLevelNames>> {
+        self.values.to_mut().kt_level_names = value.into();
+        self
+    }
+    /// Set the `indicator_names` field of `values`.
+    #[must_use]
+    pub fn indicator_names<I>(mut self, value: I) -> Self where I: Into<Option<Vec<xproto::Atom>>> {
+        self.values.to_mut().indicator_names = value.into();
+        self
+    }
+    /// Set the `virtual_mod_names` field of `values`.
+    #[must_use]
+    pub fn virtual_mod_names<I>(mut self, value: I) -> Self where I: Into<Option<Vec<xproto::Atom>>> {
+        self.values.to_mut().virtual_mod_names = value.into();
+        self
+    }
+    /// Set the `groups` field of `values`.
+    #[must_use]
+    pub fn groups<I>(mut self, value: I) -> Self where I: Into<Option<Vec<xproto::Atom>>> {
+        self.values.to_mut().groups = value.into();
+        self
     }
-}
-impl<'input> Request for SetNamesRequest<'input> {
-    const EXTENSION_NAME: Option<&'static str> = Some(X11_EXTENSION_NAME);
-
-    fn serialize(self, major_opcode: u8) -> BufWithFds<Vec<u8>> {
-        let (bufs, fds) = self.serialize(major_opcode);
-        // Flatten the buffers into a single vector
-        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
-        (buf, fds)
+    /// Set the `key_names` field of `values`.
+    #[must_use]
+    pub fn key_names<I>(mut self, value: I) -> Self where I: Into<Option<Vec<KeyName>>> {
+        self.values.to_mut().key_names = value.into();
+        self
+    }
+    /// Set the `key_aliases` field of `values`.
+    #[must_use]
+    pub fn key_aliases<I>(mut self, value: I) -> Self where I: Into<Option<Vec<KeyAlias>>> {
+        self.values.to_mut().key_aliases = value.into();
+        self
+    }
+    /// Set the `radio_group_names` field of `values`.
+    #[must_use]
+    pub fn radio_group_names<I>(mut self, value: I) -> Self where I: Into<Option<Vec<xproto::Atom>>> {
+        self.values.to_mut().radio_group_names = value.into();
+        self
     }
-}
-impl<'input> crate::x11_utils::VoidRequest for SetNamesRequest<'input> {
 }
 
 /// Opcode for the PerClientFlags request
@@ -10769,6 +13304,7 @@ pub const PER_CLIENT_FLAGS_REQUEST: u8 = 21;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PerClientFlagsRequest {
     pub device_spec: DeviceSpec,
     pub change: PerClientFlag,
@@ -10862,6 +13398,58 @@ impl Request for PerClientFlagsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_spec_bytes = self.device_spec.serialize();
+        let change_bytes = u32::from(self.change).serialize();
+        let value_bytes = u32::from(self.value).serialize();
+        let ctrls_to_change_bytes = u32::from(self.ctrls_to_change).serialize();
+        let auto_ctrls_bytes = u32::from(self.auto_ctrls).serialize();
+        let auto_ctrls_values_bytes = u32::from(self.auto_ctrls_values).serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            PER_CLIENT_FLAGS_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            0,
+            0,
+            change_bytes[0],
+            change_bytes[1],
+            change_bytes[2],
+            change_bytes[3],
+            value_bytes[0],
+            value_bytes[1],
+            value_bytes[2],
+            value_bytes[3],
+            ctrls_to_change_bytes[0],
+            ctrls_to_change_bytes[1],
+            ctrls_to_change_bytes[2],
+            ctrls_to_change_bytes[3],
+            auto_ctrls_bytes[0],
+            auto_ctrls_bytes[1],
+            auto_ctrls_bytes[2],
+            auto_ctrls_bytes[3],
+            auto_ctrls_values_bytes[0],
+            auto_ctrls_values_bytes[1],
+            auto_ctrls_values_bytes[2],
+            auto_ctrls_values_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for PerClientFlagsRequest {
     type Reply = PerClientFlagsReply;
@@ -10880,6 +13468,12 @@ pub struct PerClientFlagsReply {
     pub auto_ctrls_values: BoolCtrl,
 }
 impl_debug_if_no_extra_traits!(PerClientFlagsReply, "PerClientFlagsReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for PerClientFlagsReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for PerClientFlagsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -10972,6 +13566,7 @@ pub const LIST_COMPONENTS_REQUEST: u8 = 22;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ListComponentsRequest {
     pub device_spec: DeviceSpec,
     pub max_names: u16,
@@ -11023,6 +13618,34 @@ impl Request for ListComponentsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_spec_bytes = self.device_spec.serialize();
+        let max_names_bytes = self.max_names.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            LIST_COMPONENTS_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            max_names_bytes[0],
+            max_names_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for ListComponentsRequest {
     type Reply = ListComponentsReply;
@@ -11044,6 +13667,12 @@ pub struct ListComponentsReply {
     pub geometries: Vec<Listing>,
 }
 impl_debug_if_no_extra_traits!(ListComponentsReply, "ListComponentsReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ListComponentsReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ListComponentsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -11109,6 +13738,7 @@ impl Serialize for ListComponentsReply {
         self.compat_maps.serialize_into(bytes);
         self.symbols.serialize_into(bytes);
         self.geometries.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl ListComponentsReply {
@@ -11197,6 +13827,7 @@ pub const GET_KBD_BY_NAME_REQUEST: u8 = 23;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetKbdByNameRequest {
     pub device_spec: DeviceSpec,
     pub need: GBNDetail,
@@ -11263,6 +13894,40 @@ impl Request for GetKbdByNameRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_spec_bytes = self.device_spec.serialize();
+        let need_bytes = u16::from(self.need).serialize();
+        let want_bytes = u16::from(self.want).serialize();
+        let load_bytes = self.load.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_KBD_BY_NAME_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            need_bytes[0],
+            need_bytes[1],
+            want_bytes[0],
+            want_bytes[1],
+            load_bytes[0],
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetKbdByNameRequest {
     type Reply = GetKbdByNameReply;
@@ -11271,6 +13936,7 @@ impl crate::x11_utils::ReplyRequest for GetKbdByNameRequest {
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetKbdByNameRepliesTypesMapKeyActions {
     pub acts_rtrn_count: Vec<u8>,
     pub acts_rtrn_acts: Vec<Action>,
@@ -11308,6 +13974,7 @@ impl GetKbdByNameRepliesTypesMapKeyActions {
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetKbdByNameRepliesTypesMap {
     pub types_rtrn: Option<Vec<KeyType>>,
     pub syms_rtrn: Option<Vec<KeySymMap>>,
@@ -11360,7 +14027,7 @@ impl GetKbdByNameRepliesTypesMap {
             let value = remaining;
             let mut remaining = remaining;
             let list_length = u32::from(virtual_mods).count_ones().try_to_usize()?;
-            let mut vmods_rtrn = Vec::with_capacity(list_length);
+            let mut vmods_rtrn = Vec::with_capacity(list_length.min(remaining.len()));
             for _ in 0..list_length {
                 let (v, new_remaining) = u8::try_parse(remaining)?;
                 let v = v.into();
@@ -11527,6 +14194,12 @@ pub struct GetKbdByNameRepliesTypes {
     pub map: GetKbdByNameRepliesTypesMap,
 }
 impl_debug_if_no_extra_traits!(GetKbdByNameRepliesTypes, "GetKbdByNameRepliesTypes");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetKbdByNameRepliesTypes {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for GetKbdByNameRepliesTypes {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (getmap_type, remaining) = u8::try_parse(remaining)?;
@@ -11625,6 +14298,12 @@ pub struct GetKbdByNameRepliesCompatMap {
     pub group_rtrn: Vec<ModDef>,
 }
 impl_debug_if_no_extra_traits!(GetKbdByNameRepliesCompatMap, "GetKbdByNameRepliesCompatMap");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetKbdByNameRepliesCompatMap {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for GetKbdByNameRepliesCompatMap {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (compatmap_type, remaining) = u8::try_parse(remaining)?;
@@ -11697,6 +14376,12 @@ pub struct GetKbdByNameRepliesIndicatorMaps {
     pub maps: Vec<IndicatorMap>,
 }
 impl_debug_if_no_extra_traits!(GetKbdByNameRepliesIndicatorMaps, "GetKbdByNameRepliesIndicatorMaps");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetKbdByNameRepliesIndicatorMaps {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for GetKbdByNameRepliesIndicatorMaps {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (indicatormap_type, remaining) = u8::try_parse(remaining)?;
@@ -11751,6 +14436,7 @@ impl GetKbdByNameRepliesIndicatorMaps {
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetKbdByNameRepliesKeyNamesValueListKTLevelNames {
     pub n_levels_per_type: Vec<u8>,
     pub kt_level_names: Vec<xproto::Atom>,
@@ -11788,6 +14474,7 @@ impl GetKbdByNameRepliesKeyNamesValueListKTLevelNames {
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetKbdByNameRepliesKeyNamesValueList {
     pub keycodes_name: Option<xproto::Atom>,
     pub geometry_name: Option<xproto::Atom>,
@@ -12056,6 +14743,12 @@ pub struct GetKbdByNameRepliesKeyNames {
     pub value_list: GetKbdByNameRepliesKeyNamesValueList,
 }
 impl_debug_if_no_extra_traits!(GetKbdByNameRepliesKeyNames, "GetKbdByNameRepliesKeyNames");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetKbdByNameRepliesKeyNames {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for GetKbdByNameRepliesKeyNames {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (keyname_type, remaining) = u8::try_parse(remaining)?;
@@ -12135,6 +14828,12 @@ pub struct GetKbdByNameRepliesGeometry {
     pub label_font: CountedString16,
 }
 impl_debug_if_no_extra_traits!(GetKbdByNameRepliesGeometry, "GetKbdByNameRepliesGeometry");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetKbdByNameRepliesGeometry {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for GetKbdByNameRepliesGeometry {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (geometry_type, remaining) = u8::try_parse(remaining)?;
@@ -12191,6 +14890,7 @@ impl Serialize for GetKbdByNameRepliesGeometry {
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetKbdByNameReplies {
     pub types: Option<GetKbdByNameRepliesTypes>,
     pub compat_map: Option<GetKbdByNameRepliesCompatMap>,
@@ -12286,6 +14986,12 @@ pub struct GetKbdByNameReply {
     pub replies: GetKbdByNameReplies,
 }
 impl_debug_if_no_extra_traits!(GetKbdByNameReply, "GetKbdByNameReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetKbdByNameReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetKbdByNameReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -12335,6 +15041,7 @@ impl Serialize for GetKbdByNameReply {
         u16::from(self.reported).serialize_into(bytes);
         bytes.extend_from_slice(&[0; 16]);
         self.replies.serialize_into(bytes, u16::from(self.reported));
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 
@@ -12343,6 +15050,7 @@ pub const GET_DEVICE_INFO_REQUEST: u8 = 24;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetDeviceInfoRequest {
     pub device_spec: DeviceSpec,
     pub wanted: XIFeature,
@@ -12425,6 +15133,47 @@ impl Request for GetDeviceInfoRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_spec_bytes = self.device_spec.serialize();
+        let wanted_bytes = u16::from(self.wanted).serialize();
+        let all_buttons_bytes = self.all_buttons.serialize();
+        let first_button_bytes = self.first_button.serialize();
+        let n_buttons_bytes = self.n_buttons.serialize();
+        let led_class_bytes = LedClassSpec::from(self.led_class).serialize();
+        let led_id_bytes = self.led_id.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_DEVICE_INFO_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            wanted_bytes[0],
+            wanted_bytes[1],
+            all_buttons_bytes[0],
+            first_button_bytes[0],
+            n_buttons_bytes[0],
+            0,
+            led_class_bytes[0],
+            led_class_bytes[1],
+            led_id_bytes[0],
+            led_id_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetDeviceInfoRequest {
     type Reply = GetDeviceInfoReply;
@@ -12453,6 +15202,12 @@ pub struct GetDeviceInfoReply {
     pub leds: Vec<DeviceLedInfo>,
 }
 impl_debug_if_no_extra_traits!(GetDeviceInfoReply, "GetDeviceInfoReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetDeviceInfoReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetDeviceInfoReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -12533,6 +15288,7 @@ impl Serialize for GetDeviceInfoReply {
         bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
         self.btn_actions.serialize_into(bytes);
         self.leds.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetDeviceInfoReply {
@@ -12582,6 +15338,7 @@ pub const SET_DEVICE_INFO_REQUEST: u8 = 25;
 #[derive(Clone)]
 #[cfg_attr(feature = "extra-traits", derive(Debug))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetDeviceInfoRequest<'input> {
     pub device_spec: DeviceSpec,
     pub first_btn: u8,
@@ -12670,6 +15427,47 @@ impl<'input> Request for SetDeviceInfoRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let device_spec_bytes = self.device_spec.serialize();
+        let first_btn_bytes = self.first_btn.serialize();
+        let n_btns = u8::try_from(self.btn_actions.len()).expect("`btn_actions` has too many elements");
+        let n_btns_bytes = n_btns.serialize();
+        let change_bytes = u16::from(self.change).serialize();
+        let n_device_led_f_bs = u16::try_from(self.leds.len()).expect("`leds` has too many elements");
+        let n_device_led_f_bs_bytes = n_device_led_f_bs.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_DEVICE_INFO_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            first_btn_bytes[0],
+            n_btns_bytes[0],
+            change_bytes[0],
+            change_bytes[1],
+            n_device_led_f_bs_bytes[0],
+            n_device_led_f_bs_bytes[1],
+        ]);
+        self.btn_actions.serialize_into(buffer);
+        self.leds.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for SetDeviceInfoRequest<'input> {
 }
@@ -12679,6 +15477,7 @@ pub const SET_DEBUGGING_FLAGS_REQUEST: u8 = 101;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetDebuggingFlagsRequest<'input> {
     pub affect_flags: u32,
     pub flags: u32,
@@ -12774,6 +15573,57 @@ impl<'input> Request for SetDebuggingFlagsRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let msg_length = u16::try_from(self.message.len()).expect("`message` has too many elements");
+        let msg_length_bytes = msg_length.serialize();
+        let affect_flags_bytes = self.affect_flags.serialize();
+        let flags_bytes = self.flags.serialize();
+        let affect_ctrls_bytes = self.affect_ctrls.serialize();
+        let ctrls_bytes = self.ctrls.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_DEBUGGING_FLAGS_REQUEST,
+            0,
+            0,
+            msg_length_bytes[0],
+            msg_length_bytes[1],
+            0,
+            0,
+            affect_flags_bytes[0],
+            affect_flags_bytes[1],
+            affect_flags_bytes[2],
+            affect_flags_bytes[3],
+            flags_bytes[0],
+            flags_bytes[1],
+            flags_bytes[2],
+            flags_bytes[3],
+            affect_ctrls_bytes[0],
+            affect_ctrls_bytes[1],
+            affect_ctrls_bytes[2],
+            affect_ctrls_bytes[3],
+            ctrls_bytes[0],
+            ctrls_bytes[1],
+            ctrls_bytes[2],
+            ctrls_bytes[3],
+        ]);
+        buffer.extend_from_slice(&self.message[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::ReplyRequest for SetDebuggingFlagsRequest<'input> {
     type Reply = SetDebuggingFlagsReply;
@@ -12791,6 +15641,12 @@ pub struct SetDebuggingFlagsReply {
     pub supported_ctrls: u32,
 }
 impl_debug_if_no_extra_traits!(SetDebuggingFlagsReply, "SetDebuggingFlagsReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SetDebuggingFlagsReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for SetDebuggingFlagsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -12894,6 +15750,12 @@ pub struct NewKeyboardNotifyEvent {
     pub changed: NKNDetail,
 }
 impl_debug_if_no_extra_traits!(NewKeyboardNotifyEvent, "NewKeyboardNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for NewKeyboardNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for NewKeyboardNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -13077,6 +15939,12 @@ pub struct MapNotifyEvent {
     pub virtual_mods: VMod,
 }
 impl_debug_if_no_extra_traits!(MapNotifyEvent, "MapNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for MapNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for MapNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -13305,6 +16173,12 @@ pub struct StateNotifyEvent {
     pub request_minor: u8,
 }
 impl_debug_if_no_extra_traits!(StateNotifyEvent, "StateNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for StateNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for StateNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -13531,6 +16405,12 @@ pub struct ControlsNotifyEvent {
     pub request_minor: u8,
 }
 impl_debug_if_no_extra_traits!(ControlsNotifyEvent, "ControlsNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ControlsNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ControlsNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -13701,6 +16581,12 @@ pub struct IndicatorStateNotifyEvent {
     pub state_changed: u32,
 }
 impl_debug_if_no_extra_traits!(IndicatorStateNotifyEvent, "IndicatorStateNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for IndicatorStateNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for IndicatorStateNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -13844,6 +16730,12 @@ pub struct IndicatorMapNotifyEvent {
     pub map_changed: u32,
 }
 impl_debug_if_no_extra_traits!(IndicatorMapNotifyEvent, "IndicatorMapNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for IndicatorMapNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for IndicatorMapNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -13997,6 +16889,12 @@ pub struct NamesNotifyEvent {
     pub changed_indicators: u32,
 }
 impl_debug_if_no_extra_traits!(NamesNotifyEvent, "NamesNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for NamesNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for NamesNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -14187,6 +17085,12 @@ pub struct CompatMapNotifyEvent {
     pub n_total_si: u16,
 }
 impl_debug_if_no_extra_traits!(CompatMapNotifyEvent, "CompatMapNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CompatMapNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for CompatMapNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -14343,6 +17247,12 @@ pub struct BellNotifyEvent {
     pub event_only: bool,
 }
 impl_debug_if_no_extra_traits!(BellNotifyEvent, "BellNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for BellNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for BellNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -14513,6 +17423,12 @@ pub struct ActionMessageEvent {
     pub message: [String8; 8],
 }
 impl_debug_if_no_extra_traits!(ActionMessageEvent, "ActionMessageEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ActionMessageEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ActionMessageEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -14672,6 +17588,12 @@ pub struct AccessXNotifyEvent {
     pub debounce_delay: u16,
 }
 impl_debug_if_no_extra_traits!(AccessXNotifyEvent, "AccessXNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for AccessXNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for AccessXNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -14829,6 +17751,12 @@ pub struct ExtensionDeviceNotifyEvent {
     pub unsupported: XIFeature,
 }
 impl_debug_if_no_extra_traits!(ExtensionDeviceNotifyEvent, "ExtensionDeviceNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ExtensionDeviceNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ExtensionDeviceNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -14989,3 +17917,31 @@ impl From<ExtensionDeviceNotifyEvent> for [u8; 32] {
     }
 }
 
+/// All requests of this module.
+pub const REQUESTS: &[RequestDescription] = &[
+    RequestDescription { opcode: USE_EXTENSION_REQUEST, name: "UseExtension", has_reply: true },
+    RequestDescription { opcode: SELECT_EVENTS_REQUEST, name: "SelectEvents", has_reply: false },
+    RequestDescription { opcode: BELL_REQUEST, name: "Bell", has_reply: false },
+    RequestDescription { opcode: GET_STATE_REQUEST, name: "GetState", has_reply: true },
+    RequestDescription { opcode: LATCH_LOCK_STATE_REQUEST, name: "LatchLockState", has_reply: false },
+    RequestDescription { opcode: GET_CONTROLS_REQUEST, name: "GetControls", has_reply: true },
+    RequestDescription { opcode: SET_CONTROLS_REQUEST, name: "SetControls", has_reply: false },
+    RequestDescription { opcode: GET_MAP_REQUEST, name: "GetMap", has_reply: true },
+    RequestDescription { opcode: SET_MAP_REQUEST, name: "SetMap", has_reply: false },
+    RequestDescription { opcode: GET_COMPAT_MAP_REQUEST, name: "GetCompatMap", has_reply: true },
+    RequestDescription { opcode: SET_COMPAT_MAP_REQUEST, name: "SetCompatMap", has_reply: false },
+    RequestDescription { opcode: GET_INDICATOR_STATE_REQUEST, name: "GetIndicatorState", has_reply: true },
+    RequestDescription { opcode: GET_INDICATOR_MAP_REQUEST, name: "GetIndicatorMap", has_reply: true },
+    RequestDescription { opcode: SET_INDICATOR_MAP_REQUEST, name: "SetIndicatorMap", has_reply: false },
+    RequestDescription { opcode: GET_NAMED_INDICATOR_REQUEST, name: "GetNamedIndicator", has_reply: true },
+    RequestDescription { opcode: SET_NAMED_INDICATOR_REQUEST, name: "SetNamedIndicator", has_reply: false },
+    RequestDescription { opcode: GET_NAMES_REQUEST, name: "GetNames", has_reply: true },
+    RequestDescription { opcode: SET_NAMES_REQUEST, name: "SetNames", has_reply: false },
+    RequestDescription { opcode: PER_CLIENT_FLAGS_REQUEST, name: "PerClientFlags", has_reply: true },
+    RequestDescription { opcode: LIST_COMPONENTS_REQUEST, name: "ListComponents", has_reply: true },
+    RequestDescription { opcode: GET_KBD_BY_NAME_REQUEST, name: "GetKbdByName", has_reply: true },
+    RequestDescription { opcode: GET_DEVICE_INFO_REQUEST, name: "GetDeviceInfo", has_reply: true },
+    RequestDescription { opcode: SET_DEVICE_INFO_REQUEST, name: "SetDeviceInfo", has_reply: false },
+    RequestDescription { opcode: SET_DEBUGGING_FLAGS_REQUEST, name: "SetDebuggingFlags", has_reply: true },
+];
+
@@ -27,6 +27,7 @@ use crate::BufWithFds;
 use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::x11_utils::RequestDescription;
 
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
@@ -36,6 +37,12 @@ pub struct Char2b {
     pub byte2: u8,
 }
 impl_debug_if_no_extra_traits!(Char2b, "Char2b");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Char2b {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Char2b {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (byte1, remaining) = u8::try_parse(remaining)?;
@@ -101,6 +108,12 @@ pub struct Point {
     pub y: i16,
 }
 impl_debug_if_no_extra_traits!(Point, "Point");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Point {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Point {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (x, remaining) = i16::try_parse(remaining)?;
@@ -138,6 +151,12 @@ pub struct Rectangle {
     pub height: u16,
 }
 impl_debug_if_no_extra_traits!(Rectangle, "Rectangle");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Rectangle {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Rectangle {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (x, remaining) = i16::try_parse(remaining)?;
@@ -187,6 +206,12 @@ pub struct Arc {
     pub angle2: i16,
 }
 impl_debug_if_no_extra_traits!(Arc, "Arc");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Arc {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Arc {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (x, remaining) = i16::try_parse(remaining)?;
@@ -243,6 +268,12 @@ pub struct Format {
     pub scanline_pad: u8,
 }
 impl_debug_if_no_extra_traits!(Format, "Format");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Format {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Format {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (depth, remaining) = u8::try_parse(remaining)?;
@@ -281,6 +312,7 @@ impl Serialize for Format {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct VisualClass(u8);
 impl VisualClass {
     pub const STATIC_GRAY: Self = Self(0);
@@ -345,6 +377,25 @@ impl core::fmt::Debug for VisualClass  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl VisualClass {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("StaticGray"),
+            1 => Some("GrayScale"),
+            2 => Some("StaticColor"),
+            3 => Some("PseudoColor"),
+            4 => Some("TrueColor"),
+            5 => Some("DirectColor"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for VisualClass {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
@@ -359,6 +410,12 @@ pub struct Visualtype {
     pub blue_mask: u32,
 }
 impl_debug_if_no_extra_traits!(Visualtype, "Visualtype");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Visualtype {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Visualtype {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (visual_id, remaining) = Visualid::try_parse(remaining)?;
@@ -432,6 +489,12 @@ pub struct Depth {
     pub visuals: Vec<Visualtype>,
 }
 impl_debug_if_no_extra_traits!(Depth, "Depth");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Depth {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Depth {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (depth, remaining) = u8::try_parse(remaining)?;
@@ -478,6 +541,7 @@ impl Depth {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct EventMask(u32);
 impl EventMask {
     pub const NO_EVENT: Self = Self(0);
@@ -570,10 +634,50 @@ impl core::fmt::Debug for EventMask  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl EventMask {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("NoEvent"),
+            1 => Some("KeyPress"),
+            2 => Some("KeyRelease"),
+            4 => Some("ButtonPress"),
+            8 => Some("ButtonRelease"),
+            16 => Some("EnterWindow"),
+            32 => Some("LeaveWindow"),
+            64 => Some("PointerMotion"),
+            128 => Some("PointerMotionHint"),
+            256 => Some("Button1Motion"),
+            512 => Some("Button2Motion"),
+            1024 => Some("Button3Motion"),
+            2048 => Some("Button4Motion"),
+            4096 => Some("Button5Motion"),
+            8192 => Some("ButtonMotion"),
+            16384 => Some("KeymapState"),
+            32768 => Some("Exposure"),
+            65536 => Some("VisibilityChange"),
+            131_072 => Some("StructureNotify"),
+            262_144 => Some("ResizeRedirect"),
+            524_288 => Some("SubstructureNotify"),
+            1_048_576 => Some("SubstructureRedirect"),
+            2_097_152 => Some("FocusChange"),
+            4_194_304 => Some("PropertyChange"),
+            8_388_608 => Some("ColorMapChange"),
+            16_777_216 => Some("OwnerGrabButton"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for EventMask {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(EventMask, u32);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct BackingStore(u32);
 impl BackingStore {
     pub const NOT_USEFUL: Self = Self(0);
@@ -620,6 +724,22 @@ impl core::fmt::Debug for BackingStore  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+impl BackingStore {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("NotUseful"),
+            1 => Some("WhenMapped"),
+            2 => Some("Always"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for BackingStore {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
@@ -643,6 +763,12 @@ pub struct Screen {
     pub allowed_depths: Vec<Depth>,
 }
 impl_debug_if_no_extra_traits!(Screen, "Screen");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Screen {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Screen {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (root, remaining) = Window::try_parse(remaining)?;
@@ -724,6 +850,12 @@ pub struct SetupRequest {
     pub authorization_protocol_data: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(SetupRequest, "SetupRequest");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SetupRequest {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SetupRequest {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
@@ -814,6 +946,12 @@ pub struct SetupFailed {
     pub reason: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(SetupFailed, "SetupFailed");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SetupFailed {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SetupFailed {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (status, remaining) = u8::try_parse(remaining)?;
@@ -869,6 +1007,12 @@ pub struct SetupAuthenticate {
     pub reason: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(SetupAuthenticate, "SetupAuthenticate");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SetupAuthenticate {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for SetupAuthenticate {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (status, remaining) = u8::try_parse(remaining)?;
@@ -916,6 +1060,7 @@ impl SetupAuthenticate {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ImageOrder(u8);
 impl ImageOrder {
     pub const LSB_FIRST: Self = Self(0);
@@ -972,6 +1117,21 @@ impl core::fmt::Debug for ImageOrder  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl ImageOrder {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("LSBFirst"),
+            1 => Some("MSBFirst"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ImageOrder {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
@@ -997,6 +1157,12 @@ pub struct Setup {
     pub roots: Vec<Screen>,
 }
 impl_debug_if_no_extra_traits!(Setup, "Setup");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Setup {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Setup {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
@@ -1116,6 +1282,7 @@ impl Setup {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ModMask(u16);
 impl ModMask {
     pub const SHIFT: Self = Self(1 << 0);
@@ -1180,10 +1347,33 @@ impl core::fmt::Debug for ModMask  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl ModMask {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("Shift"),
+            2 => Some("Lock"),
+            4 => Some("Control"),
+            8 => Some("M1"),
+            16 => Some("M2"),
+            32 => Some("M3"),
+            64 => Some("M4"),
+            128 => Some("M5"),
+            32768 => Some("Any"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ModMask {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(ModMask, u16);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct KeyButMask(u16);
 impl KeyButMask {
     pub const SHIFT: Self = Self(1 << 0);
@@ -1256,10 +1446,37 @@ impl core::fmt::Debug for KeyButMask  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl KeyButMask {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("Shift"),
+            2 => Some("Lock"),
+            4 => Some("Control"),
+            8 => Some("Mod1"),
+            16 => Some("Mod2"),
+            32 => Some("Mod3"),
+            64 => Some("Mod4"),
+            128 => Some("Mod5"),
+            256 => Some("Button1"),
+            512 => Some("Button2"),
+            1024 => Some("Button3"),
+            2048 => Some("Button4"),
+            4096 => Some("Button5"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for KeyButMask {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(KeyButMask, u16);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct WindowEnum(u8);
 impl WindowEnum {
     pub const NONE: Self = Self(0);
@@ -1314,6 +1531,20 @@ impl core::fmt::Debug for WindowEnum  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl WindowEnum {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("None"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for WindowEnum {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the KeyPress event
 pub const KEY_PRESS_EVENT: u8 = 2;
@@ -1346,20 +1577,41 @@ pub const KEY_PRESS_EVENT: u8 = 2;
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct KeyPressEvent {
     pub response_type: u8,
+    /// The keycode (a number representing a physical key on the keyboard) of the key
+    /// which was pressed.
     pub detail: Keycode,
     pub sequence: u16,
+    /// Time when the event was generated (in milliseconds).
     pub time: Timestamp,
+    /// The root window of `child`.
     pub root: Window,
     pub event: Window,
     pub child: Window,
+    /// The X coordinate of the pointer relative to the `root` window at the time of
+    /// the event.
     pub root_x: i16,
+    /// The Y coordinate of the pointer relative to the `root` window at the time of
+    /// the event.
     pub root_y: i16,
+    /// If `same_screen` is true, this is the X coordinate relative to the `event`
+    /// window's origin. Otherwise, `event_x` will be set to zero.
     pub event_x: i16,
+    /// If `same_screen` is true, this is the Y coordinate relative to the `event`
+    /// window's origin. Otherwise, `event_y` will be set to zero.
     pub event_y: i16,
+    /// The logical state of the pointer buttons and modifier keys just prior to the
+    /// event.
     pub state: KeyButMask,
+    /// Whether the `event` window is on the same screen as the `root` window.
     pub same_screen: bool,
 }
 impl_debug_if_no_extra_traits!(KeyPressEvent, "KeyPressEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for KeyPressEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for KeyPressEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1517,6 +1769,7 @@ pub type KeyReleaseEvent = KeyPressEvent;
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ButtonMask(u16);
 impl ButtonMask {
     pub const M1: Self = Self(1 << 8);
@@ -1575,6 +1828,25 @@ impl core::fmt::Debug for ButtonMask  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl ButtonMask {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            256 => Some("M1"),
+            512 => Some("M2"),
+            1024 => Some("M3"),
+            2048 => Some("M4"),
+            4096 => Some("M5"),
+            32768 => Some("Any"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ButtonMask {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(ButtonMask, u16);
 
 /// Opcode for the ButtonPress event
@@ -1608,20 +1880,41 @@ pub const BUTTON_PRESS_EVENT: u8 = 4;
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ButtonPressEvent {
     pub response_type: u8,
+    /// The keycode (a number representing a physical key on the keyboard) of the key
+    /// which was pressed.
     pub detail: Button,
     pub sequence: u16,
+    /// Time when the event was generated (in milliseconds).
     pub time: Timestamp,
+    /// The root window of `child`.
     pub root: Window,
     pub event: Window,
     pub child: Window,
+    /// The X coordinate of the pointer relative to the `root` window at the time of
+    /// the event.
     pub root_x: i16,
+    /// The Y coordinate of the pointer relative to the `root` window at the time of
+    /// the event.
     pub root_y: i16,
+    /// If `same_screen` is true, this is the X coordinate relative to the `event`
+    /// window's origin. Otherwise, `event_x` will be set to zero.
     pub event_x: i16,
+    /// If `same_screen` is true, this is the Y coordinate relative to the `event`
+    /// window's origin. Otherwise, `event_y` will be set to zero.
     pub event_y: i16,
+    /// The logical state of the pointer buttons and modifier keys just prior to the
+    /// event.
     pub state: KeyButMask,
+    /// Whether the `event` window is on the same screen as the `root` window.
     pub same_screen: bool,
 }
 impl_debug_if_no_extra_traits!(ButtonPressEvent, "ButtonPressEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ButtonPressEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ButtonPressEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1779,6 +2072,7 @@ pub type ButtonReleaseEvent = ButtonPressEvent;
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Motion(u8);
 impl Motion {
     pub const NORMAL: Self = Self(0);
@@ -1835,6 +2129,21 @@ impl core::fmt::Debug for Motion  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl Motion {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Normal"),
+            1 => Some("Hint"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Motion {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the MotionNotify event
 pub const MOTION_NOTIFY_EVENT: u8 = 6;
@@ -1867,20 +2176,41 @@ pub const MOTION_NOTIFY_EVENT: u8 = 6;
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MotionNotifyEvent {
     pub response_type: u8,
+    /// The keycode (a number representing a physical key on the keyboard) of the key
+    /// which was pressed.
     pub detail: Motion,
     pub sequence: u16,
+    /// Time when the event was generated (in milliseconds).
     pub time: Timestamp,
+    /// The root window of `child`.
     pub root: Window,
     pub event: Window,
     pub child: Window,
+    /// The X coordinate of the pointer relative to the `root` window at the time of
+    /// the event.
     pub root_x: i16,
+    /// The Y coordinate of the pointer relative to the `root` window at the time of
+    /// the event.
     pub root_y: i16,
+    /// If `same_screen` is true, this is the X coordinate relative to the `event`
+    /// window's origin. Otherwise, `event_x` will be set to zero.
     pub event_x: i16,
+    /// If `same_screen` is true, this is the Y coordinate relative to the `event`
+    /// window's origin. Otherwise, `event_y` will be set to zero.
     pub event_y: i16,
+    /// The logical state of the pointer buttons and modifier keys just prior to the
+    /// event.
     pub state: KeyButMask,
+    /// Whether the `event` window is on the same screen as the `root` window.
     pub same_screen: bool,
 }
 impl_debug_if_no_extra_traits!(MotionNotifyEvent, "MotionNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for MotionNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for MotionNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2035,6 +2365,7 @@ impl From<MotionNotifyEvent> for [u8; 32] {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct NotifyDetail(u8);
 impl NotifyDetail {
     pub const ANCESTOR: Self = Self(0);
@@ -2103,9 +2434,31 @@ impl core::fmt::Debug for NotifyDetail  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl NotifyDetail {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Ancestor"),
+            1 => Some("Virtual"),
+            2 => Some("Inferior"),
+            3 => Some("Nonlinear"),
+            4 => Some("NonlinearVirtual"),
+            5 => Some("Pointer"),
+            6 => Some("PointerRoot"),
+            7 => Some("None"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for NotifyDetail {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct NotifyMode(u8);
 impl NotifyMode {
     pub const NORMAL: Self = Self(0);
@@ -2166,6 +2519,23 @@ impl core::fmt::Debug for NotifyMode  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl NotifyMode {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Normal"),
+            1 => Some("Grab"),
+            2 => Some("Ungrab"),
+            3 => Some("WhileGrabbed"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for NotifyMode {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the EnterNotify event
 pub const ENTER_NOTIFY_EVENT: u8 = 7;
@@ -2192,18 +2562,34 @@ pub struct EnterNotifyEvent {
     pub detail: NotifyDetail,
     pub sequence: u16,
     pub time: Timestamp,
+    /// The root window for the final cursor position.
     pub root: Window,
+    /// The window on which the event was generated.
     pub event: Window,
+    /// If the `event` window has subwindows and the final pointer position is in one
+    /// of them, then `child` is set to that subwindow, `XCB_WINDOW_NONE` otherwise.
     pub child: Window,
+    /// The pointer X coordinate relative to `root`'s origin at the time of the event.
     pub root_x: i16,
+    /// The pointer Y coordinate relative to `root`'s origin at the time of the event.
     pub root_y: i16,
+    /// If `event` is on the same screen as `root`, this is the pointer X coordinate
+    /// relative to the event window's origin.
     pub event_x: i16,
+    /// If `event` is on the same screen as `root`, this is the pointer Y coordinate
+    /// relative to the event window's origin.
     pub event_y: i16,
     pub state: KeyButMask,
     pub mode: NotifyMode,
     pub same_screen_focus: u8,
 }
 impl_debug_if_no_extra_traits!(EnterNotifyEvent, "EnterNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for EnterNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for EnterNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2380,10 +2766,18 @@ pub struct FocusInEvent {
     pub response_type: u8,
     pub detail: NotifyDetail,
     pub sequence: u16,
+    /// The window on which the focus event was generated. This is the window used by
+    /// the X server to report the event.
     pub event: Window,
     pub mode: NotifyMode,
 }
 impl_debug_if_no_extra_traits!(FocusInEvent, "FocusInEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for FocusInEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for FocusInEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2499,6 +2893,12 @@ pub struct KeymapNotifyEvent {
     pub keys: [u8; 31],
 }
 impl_debug_if_no_extra_traits!(KeymapNotifyEvent, "KeymapNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for KeymapNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for KeymapNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2624,14 +3024,31 @@ pub const EXPOSE_EVENT: u8 = 12;
 pub struct ExposeEvent {
     pub response_type: u8,
     pub sequence: u16,
+    /// The exposed (damaged) window.
     pub window: Window,
+    /// The X coordinate of the left-upper corner of the exposed rectangle, relative to
+    /// the `window`'s origin.
     pub x: u16,
+    /// The Y coordinate of the left-upper corner of the exposed rectangle, relative to
+    /// the `window`'s origin.
     pub y: u16,
+    /// The width of the exposed rectangle.
     pub width: u16,
+    /// The height of the exposed rectangle.
     pub height: u16,
+    /// The amount of `Expose` events following this one. Simple applications that do
+    /// not want to optimize redisplay by distinguishing between subareas of its window
+    /// can just ignore all Expose events with nonzero counts and perform full
+    /// redisplays on events with zero counts.
     pub count: u16,
 }
 impl_debug_if_no_extra_traits!(ExposeEvent, "ExposeEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ExposeEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ExposeEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2771,6 +3188,12 @@ pub struct GraphicsExposureEvent {
     pub major_opcode: u8,
 }
 impl_debug_if_no_extra_traits!(GraphicsExposureEvent, "GraphicsExposureEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GraphicsExposureEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GraphicsExposureEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2917,6 +3340,12 @@ pub struct NoExposureEvent {
     pub major_opcode: u8,
 }
 impl_debug_if_no_extra_traits!(NoExposureEvent, "NoExposureEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for NoExposureEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for NoExposureEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -3020,6 +3449,7 @@ impl From<NoExposureEvent> for [u8; 32] {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Visibility(u8);
 impl Visibility {
     pub const UNOBSCURED: Self = Self(0);
@@ -3078,6 +3508,22 @@ impl core::fmt::Debug for Visibility  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl Visibility {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Unobscured"),
+            1 => Some("PartiallyObscured"),
+            2 => Some("FullyObscured"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Visibility {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the VisibilityNotify event
 pub const VISIBILITY_NOTIFY_EVENT: u8 = 15;
@@ -3091,6 +3537,12 @@ pub struct VisibilityNotifyEvent {
     pub state: Visibility,
 }
 impl_debug_if_no_extra_traits!(VisibilityNotifyEvent, "VisibilityNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for VisibilityNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for VisibilityNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -3207,6 +3659,12 @@ pub struct CreateNotifyEvent {
     pub override_redirect: bool,
 }
 impl_debug_if_no_extra_traits!(CreateNotifyEvent, "CreateNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CreateNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for CreateNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -3359,10 +3817,19 @@ pub const DESTROY_NOTIFY_EVENT: u8 = 17;
 pub struct DestroyNotifyEvent {
     pub response_type: u8,
     pub sequence: u16,
+    /// The reconfigured window or its parent, depending on whether `StructureNotify`
+    /// or `SubstructureNotify` was selected.
     pub event: Window,
+    /// The window that is destroyed.
     pub window: Window,
 }
 impl_debug_if_no_extra_traits!(DestroyNotifyEvent, "DestroyNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DestroyNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for DestroyNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -3479,11 +3946,22 @@ pub const UNMAP_NOTIFY_EVENT: u8 = 18;
 pub struct UnmapNotifyEvent {
     pub response_type: u8,
     pub sequence: u16,
+    /// The reconfigured window or its parent, depending on whether `StructureNotify`
+    /// or `SubstructureNotify` was selected.
     pub event: Window,
+    /// The window that was unmapped.
     pub window: Window,
+    /// Set to 1 if the event was generated as a result of a resizing of the window's
+    /// parent when `window` had a win_gravity of `UnmapGravity`.
     pub from_configure: bool,
 }
 impl_debug_if_no_extra_traits!(UnmapNotifyEvent, "UnmapNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for UnmapNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for UnmapNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -3609,11 +4087,21 @@ pub const MAP_NOTIFY_EVENT: u8 = 19;
 pub struct MapNotifyEvent {
     pub response_type: u8,
     pub sequence: u16,
+    /// The window which was mapped or its parent, depending on whether
+    /// `StructureNotify` or `SubstructureNotify` was selected.
     pub event: Window,
+    /// The window that was mapped.
     pub window: Window,
+    /// Window managers should ignore this window if `override_redirect` is 1.
     pub override_redirect: bool,
 }
 impl_debug_if_no_extra_traits!(MapNotifyEvent, "MapNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for MapNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for MapNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -3737,10 +4225,18 @@ pub const MAP_REQUEST_EVENT: u8 = 20;
 pub struct MapRequestEvent {
     pub response_type: u8,
     pub sequence: u16,
+    /// The parent of `window`.
     pub parent: Window,
+    /// The window to be mapped.
     pub window: Window,
 }
 impl_debug_if_no_extra_traits!(MapRequestEvent, "MapRequestEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for MapRequestEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for MapRequestEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -3852,6 +4348,12 @@ pub struct ReparentNotifyEvent {
     pub override_redirect: bool,
 }
 impl_debug_if_no_extra_traits!(ReparentNotifyEvent, "ReparentNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ReparentNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ReparentNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -4007,17 +4509,37 @@ pub const CONFIGURE_NOTIFY_EVENT: u8 = 22;
 pub struct ConfigureNotifyEvent {
     pub response_type: u8,
     pub sequence: u16,
+    /// The reconfigured window or its parent, depending on whether `StructureNotify`
+    /// or `SubstructureNotify` was selected.
     pub event: Window,
+    /// The window whose size, position, border, and/or stacking order was changed.
     pub window: Window,
+    /// If `XCB_NONE`, the `window` is on the bottom of the stack with respect to
+    /// sibling windows. However, if set to a sibling window, the `window` is placed on
+    /// top of this sibling window.
     pub above_sibling: Window,
+    /// The X coordinate of the upper-left outside corner of `window`, relative to the
+    /// parent window's origin.
     pub x: i16,
+    /// The Y coordinate of the upper-left outside corner of `window`, relative to the
+    /// parent window's origin.
     pub y: i16,
+    /// The inside width of `window`, not including the border.
     pub width: u16,
+    /// The inside height of `window`, not including the border.
     pub height: u16,
+    /// The border width of `window`.
     pub border_width: u16,
+    /// Window managers should ignore this window if `override_redirect` is 1.
     pub override_redirect: bool,
 }
 impl_debug_if_no_extra_traits!(ConfigureNotifyEvent, "ConfigureNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ConfigureNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ConfigureNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -4179,6 +4701,12 @@ pub struct ConfigureRequestEvent {
     pub value_mask: ConfigWindow,
 }
 impl_debug_if_no_extra_traits!(ConfigureRequestEvent, "ConfigureRequestEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ConfigureRequestEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ConfigureRequestEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -4336,6 +4864,12 @@ pub struct GravityNotifyEvent {
     pub y: i16,
 }
 impl_debug_if_no_extra_traits!(GravityNotifyEvent, "GravityNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GravityNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GravityNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -4456,6 +4990,12 @@ pub struct ResizeRequestEvent {
     pub height: u16,
 }
 impl_debug_if_no_extra_traits!(ResizeRequestEvent, "ResizeRequestEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ResizeRequestEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ResizeRequestEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -4561,6 +5101,7 @@ impl From<ResizeRequestEvent> for [u8; 32] {
 /// * `OnBottom` - The window is now below all siblings.
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Place(u8);
 impl Place {
     pub const ON_TOP: Self = Self(0);
@@ -4617,6 +5158,21 @@ impl core::fmt::Debug for Place  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl Place {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("OnTop"),
+            1 => Some("OnBottom"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Place {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the CirculateNotify event
 pub const CIRCULATE_NOTIFY_EVENT: u8 = 26;
@@ -4638,11 +5194,20 @@ pub const CIRCULATE_NOTIFY_EVENT: u8 = 26;
 pub struct CirculateNotifyEvent {
     pub response_type: u8,
     pub sequence: u16,
+    /// Either the restacked window or its parent, depending on whether
+    /// `StructureNotify` or `SubstructureNotify` was selected.
     pub event: Window,
+    /// The restacked window.
     pub window: Window,
     pub place: Place,
 }
 impl_debug_if_no_extra_traits!(CirculateNotifyEvent, "CirculateNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CirculateNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for CirculateNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -4761,6 +5326,7 @@ pub type CirculateRequestEvent = CirculateNotifyEvent;
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Property(u8);
 impl Property {
     pub const NEW_VALUE: Self = Self(0);
@@ -4817,6 +5383,21 @@ impl core::fmt::Debug for Property  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl Property {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("NewValue"),
+            1 => Some("Delete"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Property {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the PropertyNotify event
 pub const PROPERTY_NOTIFY_EVENT: u8 = 28;
@@ -4838,12 +5419,21 @@ pub const PROPERTY_NOTIFY_EVENT: u8 = 28;
 pub struct PropertyNotifyEvent {
     pub response_type: u8,
     pub sequence: u16,
+    /// The window whose associated property was changed.
     pub window: Window,
+    /// The property's atom, to indicate which property was changed.
     pub atom: Atom,
+    /// A timestamp of the server time when the property was changed.
     pub time: Timestamp,
     pub state: Property,
 }
 impl_debug_if_no_extra_traits!(PropertyNotifyEvent, "PropertyNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for PropertyNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for PropertyNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -4971,6 +5561,12 @@ pub struct SelectionClearEvent {
     pub selection: Atom,
 }
 impl_debug_if_no_extra_traits!(SelectionClearEvent, "SelectionClearEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SelectionClearEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for SelectionClearEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5076,6 +5672,7 @@ impl From<SelectionClearEvent> for [u8; 32] {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Time(u8);
 impl Time {
     pub const CURRENT_TIME: Self = Self(0);
@@ -5130,9 +5727,24 @@ impl core::fmt::Debug for Time  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl Time {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("CurrentTime"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Time {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct AtomEnum(u8);
 impl AtomEnum {
     pub const NONE: Self = Self(0);
@@ -5325,6 +5937,88 @@ impl core::fmt::Debug for AtomEnum  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl AtomEnum {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("None"),
+            1 => Some("PRIMARY"),
+            2 => Some("SECONDARY"),
+            3 => Some("ARC"),
+            4 => Some("ATOM"),
+            5 => Some("BITMAP"),
+            6 => Some("CARDINAL"),
+            7 => Some("COLORMAP"),
+            8 => Some("CURSOR"),
+            9 => Some("CUT_BUFFER0"),
+            10 => Some("CUT_BUFFER1"),
+            11 => Some("CUT_BUFFER2"),
+            12 => Some("CUT_BUFFER3"),
+            13 => Some("CUT_BUFFER4"),
+            14 => Some("CUT_BUFFER5"),
+            15 => Some("CUT_BUFFER6"),
+            16 => Some("CUT_BUFFER7"),
+            17 => Some("DRAWABLE"),
+            18 => Some("FONT"),
+            19 => Some("INTEGER"),
+            20 => Some("PIXMAP"),
+            21 => Some("POINT"),
+            22 => Some("RECTANGLE"),
+            23 => Some("RESOURCE_MANAGER"),
+            24 => Some("RGB_COLOR_MAP"),
+            25 => Some("RGB_BEST_MAP"),
+            26 => Some("RGB_BLUE_MAP"),
+            27 => Some("RGB_DEFAULT_MAP"),
+            28 => Some("RGB_GRAY_MAP"),
+            29 => Some("RGB_GREEN_MAP"),
+            30 => Some("RGB_RED_MAP"),
+            31 => Some("STRING"),
+            32 => Some("VISUALID"),
+            33 => Some("WINDOW"),
+            34 => Some("WM_COMMAND"),
+            35 => Some("WM_HINTS"),
+            36 => Some("WM_CLIENT_MACHINE"),
+            37 => Some("WM_ICON_NAME"),
+            38 => Some("WM_ICON_SIZE"),
+            39 => Some("WM_NAME"),
+            40 => Some("WM_NORMAL_HINTS"),
+            41 => Some("WM_SIZE_HINTS"),
+            42 => Some("WM_ZOOM_HINTS"),
+            43 => Some("MIN_SPACE"),
+            44 => Some("NORM_SPACE"),
+            45 => Some("MAX_SPACE"),
+            46 => Some("END_SPACE"),
+            47 => Some("SUPERSCRIPT_X"),
+            48 => Some("SUPERSCRIPT_Y"),
+            49 => Some("SUBSCRIPT_X"),
+            50 => Some("SUBSCRIPT_Y"),
+            51 => Some("UNDERLINE_POSITION"),
+            52 => Some("UNDERLINE_THICKNESS"),
+            53 => Some("STRIKEOUT_ASCENT"),
+            54 => Some("STRIKEOUT_DESCENT"),
+            55 => Some("ITALIC_ANGLE"),
+            56 => Some("X_HEIGHT"),
+            57 => Some("QUAD_WIDTH"),
+            58 => Some("WEIGHT"),
+            59 => Some("POINT_SIZE"),
+            60 => Some("RESOLUTION"),
+            61 => Some("COPYRIGHT"),
+            62 => Some("NOTICE"),
+            63 => Some("FONT_NAME"),
+            64 => Some("FAMILY_NAME"),
+            65 => Some("FULL_NAME"),
+            66 => Some("CAP_HEIGHT"),
+            67 => Some("WM_CLASS"),
+            68 => Some("WM_TRANSIENT_FOR"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for AtomEnum {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the SelectionRequest event
 pub const SELECTION_REQUEST_EVENT: u8 = 30;
@@ -5342,6 +6036,12 @@ pub struct SelectionRequestEvent {
     pub property: Atom,
 }
 impl_debug_if_no_extra_traits!(SelectionRequestEvent, "SelectionRequestEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SelectionRequestEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for SelectionRequestEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5484,6 +6184,12 @@ pub struct SelectionNotifyEvent {
     pub property: Atom,
 }
 impl_debug_if_no_extra_traits!(SelectionNotifyEvent, "SelectionNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SelectionNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for SelectionNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5609,6 +6315,7 @@ impl From<SelectionNotifyEvent> for [u8; 32] {
 /// * `Installed` - The colormap was installed.
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ColormapState(u8);
 impl ColormapState {
     pub const UNINSTALLED: Self = Self(0);
@@ -5665,9 +6372,25 @@ impl core::fmt::Debug for ColormapState  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl ColormapState {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Uninstalled"),
+            1 => Some("Installed"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ColormapState {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ColormapEnum(u8);
 impl ColormapEnum {
     pub const NONE: Self = Self(0);
@@ -5722,6 +6445,20 @@ impl core::fmt::Debug for ColormapEnum  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl ColormapEnum {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("None"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ColormapEnum {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the ColormapNotify event
 pub const COLORMAP_NOTIFY_EVENT: u8 = 32;
@@ -5744,12 +6481,21 @@ pub const COLORMAP_NOTIFY_EVENT: u8 = 32;
 pub struct ColormapNotifyEvent {
     pub response_type: u8,
     pub sequence: u16,
+    /// The window whose associated colormap is changed, installed or uninstalled.
     pub window: Window,
+    /// The colormap which is changed, installed or uninstalled. This is `XCB_NONE`
+    /// when the colormap is changed by a call to `FreeColormap`.
     pub colormap: Colormap,
     pub new: bool,
     pub state: ColormapState,
 }
 impl_debug_if_no_extra_traits!(ColormapNotifyEvent, "ColormapNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ColormapNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ColormapNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -5862,6 +6608,7 @@ impl From<ColormapNotifyEvent> for [u8; 32] {
 
 #[derive(Debug, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ClientMessageData([u8; 20]);
 impl ClientMessageData {
     pub fn as_data8(&self) -> [u8; 20] {
@@ -6039,13 +6786,23 @@ pub const CLIENT_MESSAGE_EVENT: u8 = 33;
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClientMessageEvent {
     pub response_type: u8,
+    /// Specifies how to interpret `data`. Can be either 8, 16 or 32.
     pub format: u8,
     pub sequence: u16,
     pub window: Window,
+    /// An atom which indicates how the data should be interpreted by the receiving
+    /// client.
     pub type_: Atom,
+    /// The data itself (20 bytes max).
     pub data: ClientMessageData,
 }
 impl_debug_if_no_extra_traits!(ClientMessageEvent, "ClientMessageEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ClientMessageEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ClientMessageEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -6188,6 +6945,7 @@ impl ClientMessageEvent {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Mapping(u8);
 impl Mapping {
     pub const MODIFIER: Self = Self(0);
@@ -6246,6 +7004,22 @@ impl core::fmt::Debug for Mapping  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl Mapping {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Modifier"),
+            1 => Some("Keyboard"),
+            2 => Some("Pointer"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Mapping {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the MappingNotify event
 pub const MAPPING_NOTIFY_EVENT: u8 = 34;
@@ -6263,10 +7037,18 @@ pub struct MappingNotifyEvent {
     pub response_type: u8,
     pub sequence: u16,
     pub request: Mapping,
+    /// The first number in the range of the altered mapping.
     pub first_keycode: Keycode,
+    /// The number of keycodes altered.
     pub count: u8,
 }
 impl_debug_if_no_extra_traits!(MappingNotifyEvent, "MappingNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for MappingNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for MappingNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -6379,12 +7161,20 @@ pub const GE_GENERIC_EVENT: u8 = 35;
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GeGenericEvent {
     pub response_type: u8,
+    /// The major opcode of the extension creating this event
     pub extension: u8,
     pub sequence: u16,
+    /// The amount (in 4-byte units) of data beyond 32 bytes
     pub length: u32,
     pub event_type: u16,
 }
 impl_debug_if_no_extra_traits!(GeGenericEvent, "GeGenericEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GeGenericEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GeGenericEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -6508,6 +7298,7 @@ pub const IMPLEMENTATION_ERROR: u8 = 17;
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct WindowClass(u16);
 impl WindowClass {
     pub const COPY_FROM_PARENT: Self = Self(0);
@@ -6560,6 +7351,22 @@ impl core::fmt::Debug for WindowClass  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl WindowClass {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("CopyFromParent"),
+            1 => Some("InputOutput"),
+            2 => Some("InputOnly"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for WindowClass {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// # Fields
 ///
@@ -6633,6 +7440,7 @@ impl core::fmt::Debug for WindowClass  {
 ///   parent's cursor will cause an immediate change in the displayed cursor.
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CW(u32);
 impl CW {
     pub const BACK_PIXMAP: Self = Self(1 << 0);
@@ -6703,10 +7511,39 @@ impl core::fmt::Debug for CW  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl CW {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("BackPixmap"),
+            2 => Some("BackPixel"),
+            4 => Some("BorderPixmap"),
+            8 => Some("BorderPixel"),
+            16 => Some("BitGravity"),
+            32 => Some("WinGravity"),
+            64 => Some("BackingStore"),
+            128 => Some("BackingPlanes"),
+            256 => Some("BackingPixel"),
+            512 => Some("OverrideRedirect"),
+            1024 => Some("SaveUnder"),
+            2048 => Some("EventMask"),
+            4096 => Some("DontPropagate"),
+            8192 => Some("Colormap"),
+            16384 => Some("Cursor"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for CW {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(CW, u32);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct BackPixmap(bool);
 impl BackPixmap {
     pub const NONE: Self = Self(false);
@@ -6775,9 +7612,24 @@ impl core::fmt::Debug for BackPixmap  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl BackPixmap {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            false => Some("None"),
+            true => Some("ParentRelative"),
+        }
+    }
+}
+impl core::fmt::Display for BackPixmap {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Gravity(u32);
 impl Gravity {
     pub const BIT_FORGET: Self = Self(0);
@@ -6842,11 +7694,36 @@ impl core::fmt::Debug for Gravity  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+impl Gravity {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("BitForget"),
+            1 => Some("NorthWest"),
+            2 => Some("North"),
+            3 => Some("NorthEast"),
+            4 => Some("West"),
+            5 => Some("Center"),
+            6 => Some("East"),
+            7 => Some("SouthWest"),
+            8 => Some("South"),
+            9 => Some("SouthEast"),
+            10 => Some("Static"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Gravity {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Auxiliary and optional information for the `create_window` function
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateWindowAux {
     pub background_pixmap: Option<Pixmap>,
     pub background_pixel: Option<u32>,
@@ -7201,6 +8078,60 @@ impl CreateWindowAux {
         self.cursor = value.into();
         self
     }
+    /// Get the changes that are needed to go from `old` to `self`.
+    ///
+    /// The result contains all fields that are set in `self` and that are either unset in
+    /// `old` or set to a different value. This allows to only send values that changed.
+    #[must_use]
+    pub fn changes_from(&self, old: &Self) -> Self {
+        let mut result = Self::new();
+        if self.background_pixmap.is_some() && self.background_pixmap != old.background_pixmap {
+            result.background_pixmap = self.background_pixmap;
+        }
+        if self.background_pixel.is_some() && self.background_pixel != old.background_pixel {
+            result.background_pixel = self.background_pixel;
+        }
+        if self.border_pixmap.is_some() && self.border_pixmap != old.border_pixmap {
+            result.border_pixmap = self.border_pixmap;
+        }
+        if self.border_pixel.is_some() && self.border_pixel != old.border_pixel {
+            result.border_pixel = self.border_pixel;
+        }
+        if self.bit_gravity.is_some() && self.bit_gravity != old.bit_gravity {
+            result.bit_gravity = self.bit_gravity;
+        }
+        if self.win_gravity.is_some() && self.win_gravity != old.win_gravity {
+            result.win_gravity = self.win_gravity;
+        }
+        if self.backing_store.is_some() && self.backing_store != old.backing_store {
+            result.backing_store = self.backing_store;
+        }
+        if self.backing_planes.is_some() && self.backing_planes != old.backing_planes {
+            result.backing_planes = self.backing_planes;
+        }
+        if self.backing_pixel.is_some() && self.backing_pixel != old.backing_pixel {
+            result.backing_pixel = self.backing_pixel;
+        }
+        if self.override_redirect.is_some() && self.override_redirect != old.override_redirect {
+            result.override_redirect = self.override_redirect;
+        }
+        if self.save_under.is_some() && self.save_under != old.save_under {
+            result.save_under = self.save_under;
+        }
+        if self.event_mask.is_some() && self.event_mask != old.event_mask {
+            result.event_mask = self.event_mask;
+        }
+        if self.do_not_propogate_mask.is_some() && self.do_not_propogate_mask != old.do_not_propogate_mask {
+            result.do_not_propogate_mask = self.do_not_propogate_mask;
+        }
+        if self.colormap.is_some() && self.colormap != old.colormap {
+            result.colormap = self.colormap;
+        }
+        if self.cursor.is_some() && self.cursor != old.cursor {
+            result.cursor = self.cursor;
+        }
+        result
+    }
 }
 
 /// Opcode for the CreateWindow request
@@ -7262,16 +8193,35 @@ pub const CREATE_WINDOW_REQUEST: u8 = 1;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateWindowRequest<'input> {
+    /// Specifies the new window's depth (TODO: what unit?).
+    ///
+    /// The special value `XCB_COPY_FROM_PARENT` means the depth is taken from the
+    /// `parent` window.
     pub depth: u8,
+    /// The ID with which you will refer to the new window, created by
+    /// `xcb_generate_id`.
     pub wid: Window,
+    /// The parent window of the new window.
     pub parent: Window,
+    /// The X coordinate of the new window.
     pub x: i16,
+    /// The Y coordinate of the new window.
     pub y: i16,
+    /// The width of the new window.
     pub width: u16,
+    /// The height of the new window.
     pub height: u16,
+    /// TODO:
+    ///
+    /// Must be zero if the `class` is `InputOnly` or a `xcb_match_error_t` occurs.
     pub border_width: u16,
     pub class: WindowClass,
+    /// Specifies the id for the new window's visual.
+    ///
+    /// The special value `XCB_COPY_FROM_PARENT` means the visual is taken from the
+    /// `parent` window.
     pub visual: Visualid,
     pub value_list: Cow<'input, CreateWindowAux>,
 }
@@ -7398,17 +8348,195 @@ impl<'input> Request for CreateWindowRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
-}
-impl<'input> crate::x11_utils::VoidRequest for CreateWindowRequest<'input> {
-}
-
-/// Auxiliary and optional information for the `change_window_attributes` function
-#[derive(Clone, Copy, Default)]
-#[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct ChangeWindowAttributesAux {
-    pub background_pixmap: Option<Pixmap>,
-    pub background_pixel: Option<u32>,
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let depth_bytes = self.depth.serialize();
+        let wid_bytes = self.wid.serialize();
+        let parent_bytes = self.parent.serialize();
+        let x_bytes = self.x.serialize();
+        let y_bytes = self.y.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        let border_width_bytes = self.border_width.serialize();
+        let class_bytes = u16::from(self.class).serialize();
+        let visual_bytes = self.visual.serialize();
+        let value_mask: u32 = self.value_list.switch_expr();
+        let value_mask_bytes = value_mask.serialize();
+        buffer.extend_from_slice(&[
+            CREATE_WINDOW_REQUEST,
+            depth_bytes[0],
+            0,
+            0,
+            wid_bytes[0],
+            wid_bytes[1],
+            wid_bytes[2],
+            wid_bytes[3],
+            parent_bytes[0],
+            parent_bytes[1],
+            parent_bytes[2],
+            parent_bytes[3],
+            x_bytes[0],
+            x_bytes[1],
+            y_bytes[0],
+            y_bytes[1],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+            border_width_bytes[0],
+            border_width_bytes[1],
+            class_bytes[0],
+            class_bytes[1],
+            visual_bytes[0],
+            visual_bytes[1],
+            visual_bytes[2],
+            visual_bytes[3],
+            value_mask_bytes[0],
+            value_mask_bytes[1],
+            value_mask_bytes[2],
+            value_mask_bytes[3],
+        ]);
+        self.value_list.serialize_into(buffer, u32::from(value_mask));
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
+}
+impl<'input> crate::x11_utils::VoidRequest for CreateWindowRequest<'input> {
+}
+impl CreateWindowRequest<'static> {
+    /// Create a new request with all fields of `value_list` unset.
+    ///
+    /// The optional fields can then be set with the builder methods of this request.
+    pub fn new(depth: u8, wid: Window, parent: Window, x: i16, y: i16, width: u16, height: u16, border_width: u16, class: WindowClass, visual: Visualid) -> Self {
+        CreateWindowRequest {
+            depth,
+            wid,
+            parent,
+            x,
+            y,
+            width,
+            height,
+            border_width,
+            class,
+            visual,
+            value_list: Cow::Owned(CreateWindowAux::new()),
+        }
+    }
+}
+impl<'input> CreateWindowRequest<'input> {
+    /// Set the `background_pixmap` field of `value_list`.
+    #[must_use]
+    pub fn background_pixmap<I>(mut self, value: I) -> Self where I: Into<Option<Pixmap>> {
+        self.value_list.to_mut().background_pixmap = value.into();
+        self
+    }
+    /// Set the `background_pixel` field of `value_list`.
+    #[must_use]
+    pub fn background_pixel<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().background_pixel = value.into();
+        self
+    }
+    /// Set the `border_pixmap` field of `value_list`.
+    #[must_use]
+    pub fn border_pixmap<I>(mut self, value: I) -> Self where I: Into<Option<Pixmap>> {
+        self.value_list.to_mut().border_pixmap = value.into();
+        self
+    }
+    /// Set the `border_pixel` field of `value_list`.
+    #[must_use]
+    pub fn border_pixel<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().border_pixel = value.into();
+        self
+    }
+    /// Set the `bit_gravity` field of `value_list`.
+    #[must_use]
+    pub fn bit_gravity<I>(mut self, value: I) -> Self where I: Into<Option<Gravity>> {
+        self.value_list.to_mut().bit_gravity = value.into();
+        self
+    }
+    /// Set the `win_gravity` field of `value_list`.
+    #[must_use]
+    pub fn win_gravity<I>(mut self, value: I) -> Self where I: Into<Option<Gravity>> {
+        self.value_list.to_mut().win_gravity = value.into();
+        self
+    }
+    /// Set the `backing_store` field of `value_list`.
+    #[must_use]
+    pub fn backing_store<I>(mut self, value: I) -> Self where I: Into<Option<BackingStore>> {
+        self.value_list.to_mut().backing_store = value.into();
+        self
+    }
+    /// Set the `backing_planes` field of `value_list`.
+    #[must_use]
+    pub fn backing_planes<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().backing_planes = value.into();
+        self
+    }
+    /// Set the `backing_pixel` field of `value_list`.
+    #[must_use]
+    pub fn backing_pixel<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().backing_pixel = value.into();
+        self
+    }
+    /// Set the `override_redirect` field of `value_list`.
+    #[must_use]
+    pub fn override_redirect<I>(mut self, value: I) -> Self where I: Into<Option<Bool32>> {
+        self.value_list.to_mut().override_redirect = value.into();
+        self
+    }
+    /// Set the `save_under` field of `value_list`.
+    #[must_use]
+    pub fn save_under<I>(mut self, value: I) -> Self where I: Into<Option<Bool32>> {
+        self.value_list.to_mut().save_under = value.into();
+        self
+    }
+    /// Set the `event_mask` field of `value_list`.
+    #[must_use]
+    pub fn event_mask<I>(mut self, value: I) -> Self where I: Into<Option<EventMask>> {
+        self.value_list.to_mut().event_mask = value.into();
+        self
+    }
+    /// Set the `do_not_propogate_mask` field of `value_list`.
+    #[must_use]
+    pub fn do_not_propogate_mask<I>(mut self, value: I) -> Self where I: Into<Option<EventMask>> {
+        self.value_list.to_mut().do_not_propogate_mask = value.into();
+        self
+    }
+    /// Set the `colormap` field of `value_list`.
+    #[must_use]
+    pub fn colormap<I>(mut self, value: I) -> Self where I: Into<Option<Colormap>> {
+        self.value_list.to_mut().colormap = value.into();
+        self
+    }
+    /// Set the `cursor` field of `value_list`.
+    #[must_use]
+    pub fn cursor<I>(mut self, value: I) -> Self where I: Into<Option<Cursor>> {
+        self.value_list.to_mut().cursor = value.into();
+        self
+    }
+}
+
+/// Auxiliary and optional information for the `change_window_attributes` function
+#[derive(Clone, Copy, Default)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ChangeWindowAttributesAux {
+    pub background_pixmap: Option<Pixmap>,
+    pub background_pixel: Option<u32>,
     pub border_pixmap: Option<Pixmap>,
     pub border_pixel: Option<u32>,
     pub bit_gravity: Option<Gravity>,
@@ -7760,6 +8888,60 @@ impl ChangeWindowAttributesAux {
         self.cursor = value.into();
         self
     }
+    /// Get the changes that are needed to go from `old` to `self`.
+    ///
+    /// The result contains all fields that are set in `self` and that are either unset in
+    /// `old` or set to a different value. This allows to only send values that changed.
+    #[must_use]
+    pub fn changes_from(&self, old: &Self) -> Self {
+        let mut result = Self::new();
+        if self.background_pixmap.is_some() && self.background_pixmap != old.background_pixmap {
+            result.background_pixmap = self.background_pixmap;
+        }
+        if self.background_pixel.is_some() && self.background_pixel != old.background_pixel {
+            result.background_pixel = self.background_pixel;
+        }
+        if self.border_pixmap.is_some() && self.border_pixmap != old.border_pixmap {
+            result.border_pixmap = self.border_pixmap;
+        }
+        if self.border_pixel.is_some() && self.border_pixel != old.border_pixel {
+            result.border_pixel = self.border_pixel;
+        }
+        if self.bit_gravity.is_some() && self.bit_gravity != old.bit_gravity {
+            result.bit_gravity = self.bit_gravity;
+        }
+        if self.win_gravity.is_some() && self.win_gravity != old.win_gravity {
+            result.win_gravity = self.win_gravity;
+        }
+        if self.backing_store.is_some() && self.backing_store != old.backing_store {
+            result.backing_store = self.backing_store;
+        }
+        if self.backing_planes.is_some() && self.backing_planes != old.backing_planes {
+            result.backing_planes = self.backing_planes;
+        }
+        if self.backing_pixel.is_some() && self.backing_pixel != old.backing_pixel {
+            result.backing_pixel = self.backing_pixel;
+        }
+        if self.override_redirect.is_some() && self.override_redirect != old.override_redirect {
+            result.override_redirect = self.override_redirect;
+        }
+        if self.save_under.is_some() && self.save_under != old.save_under {
+            result.save_under = self.save_under;
+        }
+        if self.event_mask.is_some() && self.event_mask != old.event_mask {
+            result.event_mask = self.event_mask;
+        }
+        if self.do_not_propogate_mask.is_some() && self.do_not_propogate_mask != old.do_not_propogate_mask {
+            result.do_not_propogate_mask = self.do_not_propogate_mask;
+        }
+        if self.colormap.is_some() && self.colormap != old.colormap {
+            result.colormap = self.colormap;
+        }
+        if self.cursor.is_some() && self.cursor != old.cursor {
+            result.cursor = self.cursor;
+        }
+        result
+    }
 }
 
 /// Opcode for the ChangeWindowAttributes request
@@ -7787,8 +8969,13 @@ pub const CHANGE_WINDOW_ATTRIBUTES_REQUEST: u8 = 2;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangeWindowAttributesRequest<'input> {
+    /// The window to change.
     pub window: Window,
+    /// Values for each of the attributes specified in the bitmask `value_mask`. The
+    /// order has to correspond to the order of possible `value_mask` bits. See the
+    /// example.
     pub value_list: Cow<'input, ChangeWindowAttributesAux>,
 }
 impl_debug_if_no_extra_traits!(ChangeWindowAttributesRequest<'_>, "ChangeWindowAttributesRequest");
@@ -7858,12 +9045,152 @@ impl<'input> Request for ChangeWindowAttributesRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let value_mask: u32 = self.value_list.switch_expr();
+        let value_mask_bytes = value_mask.serialize();
+        buffer.extend_from_slice(&[
+            CHANGE_WINDOW_ATTRIBUTES_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            value_mask_bytes[0],
+            value_mask_bytes[1],
+            value_mask_bytes[2],
+            value_mask_bytes[3],
+        ]);
+        self.value_list.serialize_into(buffer, u32::from(value_mask));
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for ChangeWindowAttributesRequest<'input> {
 }
+impl ChangeWindowAttributesRequest<'static> {
+    /// Create a new request with all fields of `value_list` unset.
+    ///
+    /// The optional fields can then be set with the builder methods of this request.
+    pub fn new(window: Window) -> Self {
+        ChangeWindowAttributesRequest {
+            window,
+            value_list: Cow::Owned(ChangeWindowAttributesAux::new()),
+        }
+    }
+}
+impl<'input> ChangeWindowAttributesRequest<'input> {
+    /// Set the `background_pixmap` field of `value_list`.
+    #[must_use]
+    pub fn background_pixmap<I>(mut self, value: I) -> Self where I: Into<Option<Pixmap>> {
+        self.value_list.to_mut().background_pixmap = value.into();
+        self
+    }
+    /// Set the `background_pixel` field of `value_list`.
+    #[must_use]
+    pub fn background_pixel<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().background_pixel = value.into();
+        self
+    }
+    /// Set the `border_pixmap` field of `value_list`.
+    #[must_use]
+    pub fn border_pixmap<I>(mut self, value: I) -> Self where I: Into<Option<Pixmap>> {
+        self.value_list.to_mut().border_pixmap = value.into();
+        self
+    }
+    /// Set the `border_pixel` field of `value_list`.
+    #[must_use]
+    pub fn border_pixel<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().border_pixel = value.into();
+        self
+    }
+    /// Set the `bit_gravity` field of `value_list`.
+    #[must_use]
+    pub fn bit_gravity<I>(mut self, value: I) -> Self where I: Into<Option<Gravity>> {
+        self.value_list.to_mut().bit_gravity = value.into();
+        self
+    }
+    /// Set the `win_gravity` field of `value_list`.
+    #[must_use]
+    pub fn win_gravity<I>(mut self, value: I) -> Self where I: Into<Option<Gravity>> {
+        self.value_list.to_mut().win_gravity = value.into();
+        self
+    }
+    /// Set the `backing_store` field of `value_list`.
+    #[must_use]
+    pub fn backing_store<I>(mut self, value: I) -> Self where I: Into<Option<BackingStore>> {
+        self.value_list.to_mut().backing_store = value.into();
+        self
+    }
+    /// Set the `backing_planes` field of `value_list`.
+    #[must_use]
+    pub fn backing_planes<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().backing_planes = value.into();
+        self
+    }
+    /// Set the `backing_pixel` field of `value_list`.
+    #[must_use]
+    pub fn backing_pixel<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().backing_pixel = value.into();
+        self
+    }
+    /// Set the `override_redirect` field of `value_list`.
+    #[must_use]
+    pub fn override_redirect<I>(mut self, value: I) -> Self where I: Into<Option<Bool32>> {
+        self.value_list.to_mut().override_redirect = value.into();
+        self
+    }
+    /// Set the `save_under` field of `value_list`.
+    #[must_use]
+    pub fn save_under<I>(mut self, value: I) -> Self where I: Into<Option<Bool32>> {
+        self.value_list.to_mut().save_under = value.into();
+        self
+    }
+    /// Set the `event_mask` field of `value_list`.
+    #[must_use]
+    pub fn event_mask<I>(mut self, value: I) -> Self where I: Into<Option<EventMask>> {
+        self.value_list.to_mut().event_mask = value.into();
+        self
+    }
+    /// Set the `do_not_propogate_mask` field of `value_list`.
+    #[must_use]
+    pub fn do_not_propogate_mask<I>(mut self, value: I) -> Self where I: Into<Option<EventMask>> {
+        self.value_list.to_mut().do_not_propogate_mask = value.into();
+        self
+    }
+    /// Set the `colormap` field of `value_list`.
+    #[must_use]
+    pub fn colormap<I>(mut self, value: I) -> Self where I: Into<Option<Colormap>> {
+        self.value_list.to_mut().colormap = value.into();
+        self
+    }
+    /// Set the `cursor` field of `value_list`.
+    #[must_use]
+    pub fn cursor<I>(mut self, value: I) -> Self where I: Into<Option<Cursor>> {
+        self.value_list.to_mut().cursor = value.into();
+        self
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct MapState(u8);
 impl MapState {
     pub const UNMAPPED: Self = Self(0);
@@ -7922,6 +9249,22 @@ impl core::fmt::Debug for MapState  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl MapState {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Unmapped"),
+            1 => Some("Unviewable"),
+            2 => Some("Viewable"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for MapState {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the GetWindowAttributes request
 pub const GET_WINDOW_ATTRIBUTES_REQUEST: u8 = 3;
@@ -7940,7 +9283,9 @@ pub const GET_WINDOW_ATTRIBUTES_REQUEST: u8 = 3;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetWindowAttributesRequest {
+    /// The window to get the attributes from.
     pub window: Window,
 }
 impl_debug_if_no_extra_traits!(GetWindowAttributesRequest, "GetWindowAttributesRequest");
@@ -7990,6 +9335,33 @@ impl Request for GetWindowAttributesRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        buffer.extend_from_slice(&[
+            GET_WINDOW_ATTRIBUTES_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetWindowAttributesRequest {
     type Reply = GetWindowAttributesReply;
@@ -8018,22 +9390,37 @@ pub struct GetWindowAttributesReply {
     pub backing_store: BackingStore,
     pub sequence: u16,
     pub length: u32,
+    /// The associated visual structure of `window`.
     pub visual: Visualid,
     pub class: WindowClass,
     pub bit_gravity: Gravity,
     pub win_gravity: Gravity,
+    /// Planes to be preserved if possible.
     pub backing_planes: u32,
+    /// Value to be used when restoring planes.
     pub backing_pixel: u32,
+    /// Boolean, should bits under be saved?
     pub save_under: bool,
     pub map_is_installed: bool,
     pub map_state: MapState,
+    /// Window managers should ignore this window if `override_redirect` is 1.
     pub override_redirect: bool,
+    /// Color map to be associated with window.
     pub colormap: Colormap,
+    /// Set of events all people have interest in.
     pub all_event_masks: EventMask,
+    /// My event mask.
     pub your_event_mask: EventMask,
+    /// Set of events that should not propagate.
     pub do_not_propagate_mask: EventMask,
 }
 impl_debug_if_no_extra_traits!(GetWindowAttributesReply, "GetWindowAttributesReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetWindowAttributesReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetWindowAttributesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -8194,7 +9581,9 @@ pub const DESTROY_WINDOW_REQUEST: u8 = 4;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DestroyWindowRequest {
+    /// The window to destroy.
     pub window: Window,
 }
 impl_debug_if_no_extra_traits!(DestroyWindowRequest, "DestroyWindowRequest");
@@ -8244,6 +9633,33 @@ impl Request for DestroyWindowRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        buffer.extend_from_slice(&[
+            DESTROY_WINDOW_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for DestroyWindowRequest {
 }
@@ -8253,6 +9669,7 @@ pub const DESTROY_SUBWINDOWS_REQUEST: u8 = 5;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DestroySubwindowsRequest {
     pub window: Window,
 }
@@ -8303,12 +9720,40 @@ impl Request for DestroySubwindowsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        buffer.extend_from_slice(&[
+            DESTROY_SUBWINDOWS_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for DestroySubwindowsRequest {
 }
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetMode(u8);
 impl SetMode {
     pub const INSERT: Self = Self(0);
@@ -8365,6 +9810,21 @@ impl core::fmt::Debug for SetMode  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl SetMode {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Insert"),
+            1 => Some("Delete"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for SetMode {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the ChangeSaveSet request
 pub const CHANGE_SAVE_SET_REQUEST: u8 = 6;
@@ -8393,8 +9853,11 @@ pub const CHANGE_SAVE_SET_REQUEST: u8 = 6;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangeSaveSetRequest {
+    /// Insert to add the specified window to the save set or Delete to delete it from the save set.
     pub mode: SetMode,
+    /// The window to add or delete to/from your save set.
     pub window: Window,
 }
 impl_debug_if_no_extra_traits!(ChangeSaveSetRequest, "ChangeSaveSetRequest");
@@ -8447,6 +9910,34 @@ impl Request for ChangeSaveSetRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let mode_bytes = u8::from(self.mode).serialize();
+        let window_bytes = self.window.serialize();
+        buffer.extend_from_slice(&[
+            CHANGE_SAVE_SET_REQUEST,
+            mode_bytes[0],
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for ChangeSaveSetRequest {
 }
@@ -8488,10 +9979,15 @@ pub const REPARENT_WINDOW_REQUEST: u8 = 7;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ReparentWindowRequest {
+    /// The window to reparent.
     pub window: Window,
+    /// The new parent of the window.
     pub parent: Window,
+    /// The X position of the window within its new parent.
     pub x: i16,
+    /// The Y position of the window within its new parent.
     pub y: i16,
 }
 impl_debug_if_no_extra_traits!(ReparentWindowRequest, "ReparentWindowRequest");
@@ -8558,6 +10054,44 @@ impl Request for ReparentWindowRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let parent_bytes = self.parent.serialize();
+        let x_bytes = self.x.serialize();
+        let y_bytes = self.y.serialize();
+        buffer.extend_from_slice(&[
+            REPARENT_WINDOW_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            parent_bytes[0],
+            parent_bytes[1],
+            parent_bytes[2],
+            parent_bytes[3],
+            x_bytes[0],
+            x_bytes[1],
+            y_bytes[0],
+            y_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for ReparentWindowRequest {
 }
@@ -8602,7 +10136,9 @@ pub const MAP_WINDOW_REQUEST: u8 = 8;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct MapWindowRequest {
+    /// The window to make visible.
     pub window: Window,
 }
 impl_debug_if_no_extra_traits!(MapWindowRequest, "MapWindowRequest");
@@ -8652,6 +10188,33 @@ impl Request for MapWindowRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        buffer.extend_from_slice(&[
+            MAP_WINDOW_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for MapWindowRequest {
 }
@@ -8661,6 +10224,7 @@ pub const MAP_SUBWINDOWS_REQUEST: u8 = 9;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct MapSubwindowsRequest {
     pub window: Window,
 }
@@ -8711,6 +10275,33 @@ impl Request for MapSubwindowsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        buffer.extend_from_slice(&[
+            MAP_SUBWINDOWS_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for MapSubwindowsRequest {
 }
@@ -8741,7 +10332,9 @@ pub const UNMAP_WINDOW_REQUEST: u8 = 10;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct UnmapWindowRequest {
+    /// The window to make invisible.
     pub window: Window,
 }
 impl_debug_if_no_extra_traits!(UnmapWindowRequest, "UnmapWindowRequest");
@@ -8791,6 +10384,33 @@ impl Request for UnmapWindowRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        buffer.extend_from_slice(&[
+            UNMAP_WINDOW_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for UnmapWindowRequest {
 }
@@ -8800,6 +10420,7 @@ pub const UNMAP_SUBWINDOWS_REQUEST: u8 = 11;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct UnmapSubwindowsRequest {
     pub window: Window,
 }
@@ -8850,12 +10471,40 @@ impl Request for UnmapSubwindowsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        buffer.extend_from_slice(&[
+            UNMAP_SUBWINDOWS_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for UnmapSubwindowsRequest {
 }
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ConfigWindow(u16);
 impl ConfigWindow {
     pub const X: Self = Self(1 << 0);
@@ -8916,10 +10565,31 @@ impl core::fmt::Debug for ConfigWindow  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl ConfigWindow {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("X"),
+            2 => Some("Y"),
+            4 => Some("Width"),
+            8 => Some("Height"),
+            16 => Some("BorderWidth"),
+            32 => Some("Sibling"),
+            64 => Some("StackMode"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ConfigWindow {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(ConfigWindow, u16);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct StackMode(u32);
 impl StackMode {
     pub const ABOVE: Self = Self(0);
@@ -8970,11 +10640,30 @@ impl core::fmt::Debug for StackMode  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+impl StackMode {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Above"),
+            1 => Some("Below"),
+            2 => Some("TopIf"),
+            3 => Some("BottomIf"),
+            4 => Some("Opposite"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for StackMode {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Auxiliary and optional information for the `configure_window` function
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ConfigureWindowAux {
     pub x: Option<i32>,
     pub y: Option<i32>,
@@ -9157,6 +10846,36 @@ impl ConfigureWindowAux {
         self.stack_mode = value.into();
         self
     }
+    /// Get the changes that are needed to go from `old` to `self`.
+    ///
+    /// The result contains all fields that are set in `self` and that are either unset in
+    /// `old` or set to a different value. This allows to only send values that changed.
+    #[must_use]
+    pub fn changes_from(&self, old: &Self) -> Self {
+        let mut result = Self::new();
+        if self.x.is_some() && self.x != old.x {
+            result.x = self.x;
+        }
+        if self.y.is_some() && self.y != old.y {
+            result.y = self.y;
+        }
+        if self.width.is_some() && self.width != old.width {
+            result.width = self.width;
+        }
+        if self.height.is_some() && self.height != old.height {
+            result.height = self.height;
+        }
+        if self.border_width.is_some() && self.border_width != old.border_width {
+            result.border_width = self.border_width;
+        }
+        if self.sibling.is_some() && self.sibling != old.sibling {
+            result.sibling = self.sibling;
+        }
+        if self.stack_mode.is_some() && self.stack_mode != old.stack_mode {
+            result.stack_mode = self.stack_mode;
+        }
+        result
+    }
 }
 impl ConfigureWindowAux {
     /// Construct from a [`ConfigureRequestEvent`].
@@ -9246,8 +10965,12 @@ pub const CONFIGURE_WINDOW_REQUEST: u8 = 12;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ConfigureWindowRequest<'input> {
+    /// The window to configure.
     pub window: Window,
+    /// New values, corresponding to the attributes in value_mask. The order has to
+    /// correspond to the order of possible `value_mask` bits. See the example.
     pub value_list: Cow<'input, ConfigureWindowAux>,
 }
 impl_debug_if_no_extra_traits!(ConfigureWindowRequest<'_>, "ConfigureWindowRequest");
@@ -9318,12 +11041,104 @@ impl<'input> Request for ConfigureWindowRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let value_mask: u16 = self.value_list.switch_expr();
+        let value_mask_bytes = value_mask.serialize();
+        buffer.extend_from_slice(&[
+            CONFIGURE_WINDOW_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            value_mask_bytes[0],
+            value_mask_bytes[1],
+            0,
+            0,
+        ]);
+        self.value_list.serialize_into(buffer, u16::from(value_mask));
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for ConfigureWindowRequest<'input> {
 }
+impl ConfigureWindowRequest<'static> {
+    /// Create a new request with all fields of `value_list` unset.
+    ///
+    /// The optional fields can then be set with the builder methods of this request.
+    pub fn new(window: Window) -> Self {
+        ConfigureWindowRequest {
+            window,
+            value_list: Cow::Owned(ConfigureWindowAux::new()),
+        }
+    }
+}
+impl<'input> ConfigureWindowRequest<'input> {
+    /// Set the `x` field of `value_list`.
+    #[must_use]
+    pub fn x<I>(mut self, value: I) -> Self where I: Into<Option<i32>> {
+        self.value_list.to_mut().x = value.into();
+        self
+    }
+    /// Set the `y` field of `value_list`.
+    #[must_use]
+    pub fn y<I>(mut self, value: I) -> Self where I: Into<Option<i32>> {
+        self.value_list.to_mut().y = value.into();
+        self
+    }
+    /// Set the `width` field of `value_list`.
+    #[must_use]
+    pub fn width<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().width = value.into();
+        self
+    }
+    /// Set the `height` field of `value_list`.
+    #[must_use]
+    pub fn height<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().height = value.into();
+        self
+    }
+    /// Set the `border_width` field of `value_list`.
+    #[must_use]
+    pub fn border_width<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().border_width = value.into();
+        self
+    }
+    /// Set the `sibling` field of `value_list`.
+    #[must_use]
+    pub fn sibling<I>(mut self, value: I) -> Self where I: Into<Option<Window>> {
+        self.value_list.to_mut().sibling = value.into();
+        self
+    }
+    /// Set the `stack_mode` field of `value_list`.
+    #[must_use]
+    pub fn stack_mode<I>(mut self, value: I) -> Self where I: Into<Option<StackMode>> {
+        self.value_list.to_mut().stack_mode = value.into();
+        self
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Circulate(u8);
 impl Circulate {
     pub const RAISE_LOWEST: Self = Self(0);
@@ -9380,6 +11195,21 @@ impl core::fmt::Debug for Circulate  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl Circulate {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("RaiseLowest"),
+            1 => Some("LowerHighest"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Circulate {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the CirculateWindow request
 pub const CIRCULATE_WINDOW_REQUEST: u8 = 13;
@@ -9403,8 +11233,10 @@ pub const CIRCULATE_WINDOW_REQUEST: u8 = 13;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CirculateWindowRequest {
     pub direction: Circulate,
+    /// The window to raise/lower (depending on `direction`).
     pub window: Window,
 }
 impl_debug_if_no_extra_traits!(CirculateWindowRequest, "CirculateWindowRequest");
@@ -9457,6 +11289,34 @@ impl Request for CirculateWindowRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let direction_bytes = u8::from(self.direction).serialize();
+        let window_bytes = self.window.serialize();
+        buffer.extend_from_slice(&[
+            CIRCULATE_WINDOW_REQUEST,
+            direction_bytes[0],
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for CirculateWindowRequest {
 }
@@ -9502,7 +11362,9 @@ pub const GET_GEOMETRY_REQUEST: u8 = 14;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetGeometryRequest {
+    /// The drawable (`Window` or `Pixmap`) of which the geometry will be received.
     pub drawable: Drawable,
 }
 impl_debug_if_no_extra_traits!(GetGeometryRequest, "GetGeometryRequest");
@@ -9552,6 +11414,33 @@ impl Request for GetGeometryRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        buffer.extend_from_slice(&[
+            GET_GEOMETRY_REQUEST,
+            0,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetGeometryRequest {
     type Reply = GetGeometryReply;
@@ -9574,17 +11463,34 @@ impl crate::x11_utils::ReplyRequest for GetGeometryRequest {
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetGeometryReply {
+    /// The depth of the drawable (bits per pixel for the object).
     pub depth: u8,
     pub sequence: u16,
     pub length: u32,
+    /// Root window of the screen containing `drawable`.
     pub root: Window,
+    /// The X coordinate of `drawable`. If `drawable` is a window, the coordinate
+    /// specifies the upper-left outer corner relative to its parent's origin. If
+    /// `drawable` is a pixmap, the X coordinate is always 0.
     pub x: i16,
+    /// The Y coordinate of `drawable`. If `drawable` is a window, the coordinate
+    /// specifies the upper-left outer corner relative to its parent's origin. If
+    /// `drawable` is a pixmap, the Y coordinate is always 0.
     pub y: i16,
+    /// The width of `drawable`.
     pub width: u16,
+    /// The height of `drawable`.
     pub height: u16,
+    /// The border width (in pixels).
     pub border_width: u16,
 }
 impl_debug_if_no_extra_traits!(GetGeometryReply, "GetGeometryReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetGeometryReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetGeometryReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -9708,7 +11614,9 @@ pub const QUERY_TREE_REQUEST: u8 = 15;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryTreeRequest {
+    /// The `window` to query.
     pub window: Window,
 }
 impl_debug_if_no_extra_traits!(QueryTreeRequest, "QueryTreeRequest");
@@ -9758,6 +11666,33 @@ impl Request for QueryTreeRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        buffer.extend_from_slice(&[
+            QUERY_TREE_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryTreeRequest {
     type Reply = QueryTreeReply;
@@ -9773,11 +11708,19 @@ impl crate::x11_utils::ReplyRequest for QueryTreeRequest {
 pub struct QueryTreeReply {
     pub sequence: u16,
     pub length: u32,
+    /// The root window of `window`.
     pub root: Window,
+    /// The parent window of `window`.
     pub parent: Window,
     pub children: Vec<Window>,
 }
 impl_debug_if_no_extra_traits!(QueryTreeReply, "QueryTreeReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryTreeReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryTreeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -9820,6 +11763,7 @@ impl Serialize for QueryTreeReply {
         children_len.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 14]);
         self.children.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl QueryTreeReply {
@@ -9887,8 +11831,11 @@ pub const INTERN_ATOM_REQUEST: u8 = 16;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct InternAtomRequest<'input> {
+    /// Return a valid atom id only if the atom already exists.
     pub only_if_exists: bool,
+    /// The name of the atom.
     pub name: Cow<'input, [u8]>,
 }
 impl_debug_if_no_extra_traits!(InternAtomRequest<'_>, "InternAtomRequest");
@@ -9953,6 +11900,38 @@ impl<'input> Request for InternAtomRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let only_if_exists_bytes = self.only_if_exists.serialize();
+        let name_len = u16::try_from(self.name.len()).expect("`name` has too many elements");
+        let name_len_bytes = name_len.serialize();
+        buffer.extend_from_slice(&[
+            INTERN_ATOM_REQUEST,
+            only_if_exists_bytes[0],
+            0,
+            0,
+            name_len_bytes[0],
+            name_len_bytes[1],
+            0,
+            0,
+        ]);
+        buffer.extend_from_slice(&self.name[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::ReplyRequest for InternAtomRequest<'input> {
     type Reply = InternAtomReply;
@@ -9967,6 +11946,12 @@ pub struct InternAtomReply {
     pub atom: Atom,
 }
 impl_debug_if_no_extra_traits!(InternAtomReply, "InternAtomReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for InternAtomReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for InternAtomReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -10023,6 +12008,7 @@ pub const GET_ATOM_NAME_REQUEST: u8 = 17;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetAtomNameRequest {
     pub atom: Atom,
 }
@@ -10073,6 +12059,33 @@ impl Request for GetAtomNameRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let atom_bytes = self.atom.serialize();
+        buffer.extend_from_slice(&[
+            GET_ATOM_NAME_REQUEST,
+            0,
+            0,
+            0,
+            atom_bytes[0],
+            atom_bytes[1],
+            atom_bytes[2],
+            atom_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetAtomNameRequest {
     type Reply = GetAtomNameReply;
@@ -10087,6 +12100,12 @@ pub struct GetAtomNameReply {
     pub name: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(GetAtomNameReply, "GetAtomNameReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetAtomNameReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetAtomNameReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -10126,6 +12145,7 @@ impl Serialize for GetAtomNameReply {
         name_len.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 22]);
         bytes.extend_from_slice(&self.name);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetAtomNameReply {
@@ -10155,6 +12175,7 @@ impl GetAtomNameReply {
 ///   defined with the correct type and format with zero-length data.
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PropMode(u8);
 impl PropMode {
     pub const REPLACE: Self = Self(0);
@@ -10213,6 +12234,22 @@ impl core::fmt::Debug for PropMode  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl PropMode {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Replace"),
+            1 => Some("Prepend"),
+            2 => Some("Append"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for PropMode {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the ChangeProperty request
 pub const CHANGE_PROPERTY_REQUEST: u8 = 18;
@@ -10270,13 +12307,22 @@ pub const CHANGE_PROPERTY_REQUEST: u8 = 18;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangePropertyRequest<'input> {
     pub mode: PropMode,
+    /// The window whose property you want to change.
     pub window: Window,
+    /// The property you want to change (an atom).
     pub property: Atom,
+    /// The type of the property you want to change (an atom).
     pub type_: Atom,
+    /// Specifies whether the data should be viewed as a list of 8-bit, 16-bit or
+    /// 32-bit quantities. Possible values are 8, 16 and 32. This information allows
+    /// the X server to correctly perform byte-swap operations as necessary.
     pub format: u8,
+    /// Specifies the number of elements (see `format`).
     pub data_len: u32,
+    /// The property data.
     pub data: Cow<'input, [u8]>,
 }
 impl_debug_if_no_extra_traits!(ChangePropertyRequest<'_>, "ChangePropertyRequest");
@@ -10376,6 +12422,58 @@ impl<'input> Request for ChangePropertyRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let mode_bytes = u8::from(self.mode).serialize();
+        let window_bytes = self.window.serialize();
+        let property_bytes = self.property.serialize();
+        let type_bytes = self.type_.serialize();
+        let format_bytes = self.format.serialize();
+        let data_len_bytes = self.data_len.serialize();
+        buffer.extend_from_slice(&[
+            CHANGE_PROPERTY_REQUEST,
+            mode_bytes[0],
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            format_bytes[0],
+            0,
+            0,
+            0,
+            data_len_bytes[0],
+            data_len_bytes[1],
+            data_len_bytes[2],
+            data_len_bytes[3],
+        ]);
+        assert_eq!(self.data.len(), usize::try_from(u32::from(self.data_len).checked_mul(u32::from(self.format)).unwrap().checked_div(8u32).unwrap()).unwrap(), "`data` has an incorrect length");
+        buffer.extend_from_slice(&self.data[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for ChangePropertyRequest<'input> {
 }
@@ -10385,6 +12483,7 @@ pub const DELETE_PROPERTY_REQUEST: u8 = 19;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DeletePropertyRequest {
     pub window: Window,
     pub property: Atom,
@@ -10443,12 +12542,45 @@ impl Request for DeletePropertyRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let property_bytes = self.property.serialize();
+        buffer.extend_from_slice(&[
+            DELETE_PROPERTY_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for DeletePropertyRequest {
 }
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetPropertyType(u8);
 impl GetPropertyType {
     pub const ANY: Self = Self(0);
@@ -10503,6 +12635,20 @@ impl core::fmt::Debug for GetPropertyType  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl GetPropertyType {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Any"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for GetPropertyType {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the GetProperty request
 pub const GET_PROPERTY_REQUEST: u8 = 20;
@@ -10577,12 +12723,22 @@ pub const GET_PROPERTY_REQUEST: u8 = 20;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetPropertyRequest {
+    /// Whether the property should actually be deleted. For deleting a property, the
+    /// specified `type` has to match the actual property type.
     pub delete: bool,
+    /// The window whose property you want to get.
     pub window: Window,
+    /// The property you want to get (an atom).
     pub property: Atom,
+    /// The type of the property you want to get (an atom).
     pub type_: Atom,
+    /// Specifies the offset (in 32-bit multiples) in the specified property where the
+    /// data is to be retrieved.
     pub long_offset: u32,
+    /// Specifies how many 32-bit multiples of data should be retrieved (e.g. if you
+    /// set `long_length` to 4, you will receive 16 bytes of data).
     pub long_length: u32,
 }
 impl_debug_if_no_extra_traits!(GetPropertyRequest, "GetPropertyRequest");
@@ -10658,9 +12814,57 @@ impl Request for GetPropertyRequest {
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
-        // Flatten the buffers into a single vector
-        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
-        (buf, fds)
+        // Flatten the buffers into a single vector
+        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
+        (buf, fds)
+    }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let delete_bytes = self.delete.serialize();
+        let window_bytes = self.window.serialize();
+        let property_bytes = self.property.serialize();
+        let type_bytes = self.type_.serialize();
+        let long_offset_bytes = self.long_offset.serialize();
+        let long_length_bytes = self.long_length.serialize();
+        buffer.extend_from_slice(&[
+            GET_PROPERTY_REQUEST,
+            delete_bytes[0],
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            long_offset_bytes[0],
+            long_offset_bytes[1],
+            long_offset_bytes[2],
+            long_offset_bytes[3],
+            long_length_bytes[0],
+            long_length_bytes[1],
+            long_length_bytes[2],
+            long_length_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
     }
 }
 impl crate::x11_utils::ReplyRequest for GetPropertyRequest {
@@ -10838,15 +13042,29 @@ impl GetPropertyReply {
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GetPropertyReply {
+    /// Specifies whether the data should be viewed as a list of 8-bit, 16-bit, or
+    /// 32-bit quantities. Possible values are 8, 16, and 32. This information allows
+    /// the X server to correctly perform byte-swap operations as necessary.
     pub format: u8,
     pub sequence: u16,
     pub length: u32,
+    /// The actual type of the property (an atom).
     pub type_: Atom,
+    /// The number of bytes remaining to be read in the property if a partial read was
+    /// performed.
     pub bytes_after: u32,
+    /// The length of value. You should use the corresponding accessor instead of this
+    /// field.
     pub value_len: u32,
     pub value: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(GetPropertyReply, "GetPropertyReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetPropertyReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetPropertyReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -10890,6 +13108,7 @@ impl Serialize for GetPropertyReply {
         bytes.extend_from_slice(&[0; 12]);
         assert_eq!(self.value.len(), usize::try_from(u32::from(self.value_len).checked_mul(u32::from(self.format).checked_div(8u32).unwrap()).unwrap()).unwrap(), "`value` has an incorrect length");
         bytes.extend_from_slice(&self.value);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 
@@ -10898,6 +13117,7 @@ pub const LIST_PROPERTIES_REQUEST: u8 = 21;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ListPropertiesRequest {
     pub window: Window,
 }
@@ -10948,6 +13168,33 @@ impl Request for ListPropertiesRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        buffer.extend_from_slice(&[
+            LIST_PROPERTIES_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for ListPropertiesRequest {
     type Reply = ListPropertiesReply;
@@ -10962,6 +13209,12 @@ pub struct ListPropertiesReply {
     pub atoms: Vec<Atom>,
 }
 impl_debug_if_no_extra_traits!(ListPropertiesReply, "ListPropertiesReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ListPropertiesReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ListPropertiesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -11000,6 +13253,7 @@ impl Serialize for ListPropertiesReply {
         atoms_len.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 22]);
         self.atoms.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl ListPropertiesReply {
@@ -11052,9 +13306,22 @@ pub const SET_SELECTION_OWNER_REQUEST: u8 = 22;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetSelectionOwnerRequest {
+    /// The new owner of the selection.
+    ///
+    /// The special value `XCB_NONE` means that the selection will have no owner.
     pub owner: Window,
+    /// The selection.
     pub selection: Atom,
+    /// Timestamp to avoid race conditions when running X over the network.
+    ///
+    /// The selection will not be changed if `time` is earlier than the current
+    /// last-change time of the `selection` or is later than the current X server time.
+    /// Otherwise, the last-change time is set to the specified time.
+    ///
+    /// The special value `XCB_CURRENT_TIME` will be replaced with the current server
+    /// time.
     pub time: Timestamp,
 }
 impl_debug_if_no_extra_traits!(SetSelectionOwnerRequest, "SetSelectionOwnerRequest");
@@ -11118,6 +13385,43 @@ impl Request for SetSelectionOwnerRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let owner_bytes = self.owner.serialize();
+        let selection_bytes = self.selection.serialize();
+        let time_bytes = self.time.serialize();
+        buffer.extend_from_slice(&[
+            SET_SELECTION_OWNER_REQUEST,
+            0,
+            0,
+            0,
+            owner_bytes[0],
+            owner_bytes[1],
+            owner_bytes[2],
+            owner_bytes[3],
+            selection_bytes[0],
+            selection_bytes[1],
+            selection_bytes[2],
+            selection_bytes[3],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SetSelectionOwnerRequest {
 }
@@ -11144,7 +13448,9 @@ pub const GET_SELECTION_OWNER_REQUEST: u8 = 23;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetSelectionOwnerRequest {
+    /// The selection.
     pub selection: Atom,
 }
 impl_debug_if_no_extra_traits!(GetSelectionOwnerRequest, "GetSelectionOwnerRequest");
@@ -11194,6 +13500,33 @@ impl Request for GetSelectionOwnerRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let selection_bytes = self.selection.serialize();
+        buffer.extend_from_slice(&[
+            GET_SELECTION_OWNER_REQUEST,
+            0,
+            0,
+            0,
+            selection_bytes[0],
+            selection_bytes[1],
+            selection_bytes[2],
+            selection_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetSelectionOwnerRequest {
     type Reply = GetSelectionOwnerReply;
@@ -11208,9 +13541,16 @@ impl crate::x11_utils::ReplyRequest for GetSelectionOwnerRequest {
 pub struct GetSelectionOwnerReply {
     pub sequence: u16,
     pub length: u32,
+    /// The current selection owner window.
     pub owner: Window,
 }
 impl_debug_if_no_extra_traits!(GetSelectionOwnerReply, "GetSelectionOwnerReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetSelectionOwnerReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetSelectionOwnerReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -11267,6 +13607,7 @@ pub const CONVERT_SELECTION_REQUEST: u8 = 24;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ConvertSelectionRequest {
     pub requestor: Window,
     pub selection: Atom,
@@ -11349,12 +13690,60 @@ impl Request for ConvertSelectionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let requestor_bytes = self.requestor.serialize();
+        let selection_bytes = self.selection.serialize();
+        let target_bytes = self.target.serialize();
+        let property_bytes = self.property.serialize();
+        let time_bytes = self.time.serialize();
+        buffer.extend_from_slice(&[
+            CONVERT_SELECTION_REQUEST,
+            0,
+            0,
+            0,
+            requestor_bytes[0],
+            requestor_bytes[1],
+            requestor_bytes[2],
+            requestor_bytes[3],
+            selection_bytes[0],
+            selection_bytes[1],
+            selection_bytes[2],
+            selection_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for ConvertSelectionRequest {
 }
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SendEventDest(bool);
 impl SendEventDest {
     pub const POINTER_WINDOW: Self = Self(false);
@@ -11423,6 +13812,20 @@ impl core::fmt::Debug for SendEventDest  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl SendEventDest {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            false => Some("PointerWindow"),
+            true => Some("ItemFocus"),
+        }
+    }
+}
+impl core::fmt::Display for SendEventDest {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the SendEvent request
 pub const SEND_EVENT_REQUEST: u8 = 25;
@@ -11502,10 +13905,30 @@ pub const SEND_EVENT_REQUEST: u8 = 25;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SendEventRequest<'input> {
+    /// If `propagate` is true and no clients have selected any event on `destination`,
+    /// the destination is replaced with the closest ancestor of `destination` for
+    /// which some client has selected a type in `event_mask` and for which no
+    /// intervening window has that type in its do-not-propagate-mask. If no such
+    /// window exists or if the window is an ancestor of the focus window and
+    /// `InputFocus` was originally specified as the destination, the event is not sent
+    /// to any clients. Otherwise, the event is reported to every client selecting on
+    /// the final destination any of the types specified in `event_mask`.
     pub propagate: bool,
+    /// The window to send this event to. Every client which selects any event within
+    /// `event_mask` on `destination` will get the event.
+    ///
+    /// The special value `XCB_SEND_EVENT_DEST_POINTER_WINDOW` refers to the window
+    /// that contains the mouse pointer.
+    ///
+    /// The special value `XCB_SEND_EVENT_DEST_ITEM_FOCUS` refers to the window which
+    /// has the keyboard focus.
     pub destination: Window,
+    /// Event_mask for determining which clients should receive the specified event.
+    /// See `destination` and `propagate`.
     pub event_mask: EventMask,
+    /// The event to send to the specified `destination`.
     pub event: Cow<'input, [u8; 32]>,
 }
 impl_debug_if_no_extra_traits!(SendEventRequest<'_>, "SendEventRequest");
@@ -11577,6 +14000,40 @@ impl<'input> Request for SendEventRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let propagate_bytes = self.propagate.serialize();
+        let destination_bytes = self.destination.serialize();
+        let event_mask_bytes = u32::from(self.event_mask).serialize();
+        buffer.extend_from_slice(&[
+            SEND_EVENT_REQUEST,
+            propagate_bytes[0],
+            0,
+            0,
+            destination_bytes[0],
+            destination_bytes[1],
+            destination_bytes[2],
+            destination_bytes[3],
+            event_mask_bytes[0],
+            event_mask_bytes[1],
+            event_mask_bytes[2],
+            event_mask_bytes[3],
+        ]);
+        buffer.extend_from_slice(&self.event[..]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for SendEventRequest<'input> {
 }
@@ -11589,6 +14046,7 @@ impl<'input> crate::x11_utils::VoidRequest for SendEventRequest<'input> {
 /// * `Async` - Keyboard event processing continues normally.
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GrabMode(u8);
 impl GrabMode {
     pub const SYNC: Self = Self(0);
@@ -11645,9 +14103,25 @@ impl core::fmt::Debug for GrabMode  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl GrabMode {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Sync"),
+            1 => Some("Async"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for GrabMode {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GrabStatus(u8);
 impl GrabStatus {
     pub const SUCCESS: Self = Self(0);
@@ -11710,9 +14184,28 @@ impl core::fmt::Debug for GrabStatus  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl GrabStatus {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Success"),
+            1 => Some("AlreadyGrabbed"),
+            2 => Some("InvalidTime"),
+            3 => Some("NotViewable"),
+            4 => Some("Frozen"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for GrabStatus {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CursorEnum(u8);
 impl CursorEnum {
     pub const NONE: Self = Self(0);
@@ -11767,6 +14260,20 @@ impl core::fmt::Debug for CursorEnum  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl CursorEnum {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("None"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for CursorEnum {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the GrabPointer request
 pub const GRAB_POINTER_REQUEST: u8 = 26;
@@ -11844,14 +14351,38 @@ pub const GRAB_POINTER_REQUEST: u8 = 26;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GrabPointerRequest {
+    /// If 1, the `grab_window` will still get the pointer events. If 0, events are not
+    /// reported to the `grab_window`.
     pub owner_events: bool,
+    /// Specifies the window on which the pointer should be grabbed.
     pub grab_window: Window,
+    /// Specifies which pointer events are reported to the client.
+    ///
+    /// TODO: which values?
     pub event_mask: EventMask,
     pub pointer_mode: GrabMode,
     pub keyboard_mode: GrabMode,
+    /// Specifies the window to confine the pointer in (the user will not be able to
+    /// move the pointer out of that window).
+    ///
+    /// The special value `XCB_NONE` means don't confine the pointer.
     pub confine_to: Window,
+    /// Specifies the cursor that should be displayed or `XCB_NONE` to not change the
+    /// cursor.
     pub cursor: Cursor,
+    /// The time argument allows you to avoid certain circumstances that come up if
+    /// applications take a long time to respond or if there are long network delays.
+    /// Consider a situation where you have two applications, both of which normally
+    /// grab the pointer when clicked on. If both applications specify the timestamp
+    /// from the event, the second application may wake up faster and successfully grab
+    /// the pointer before the first application. The first application then will get
+    /// an indication that the other application grabbed the pointer before its request
+    /// was processed.
+    ///
+    /// The special value `XCB_CURRENT_TIME` will be replaced with the current server
+    /// time.
     pub time: Timestamp,
 }
 impl_debug_if_no_extra_traits!(GrabPointerRequest, "GrabPointerRequest");
@@ -11940,6 +14471,56 @@ impl Request for GrabPointerRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let owner_events_bytes = self.owner_events.serialize();
+        let grab_window_bytes = self.grab_window.serialize();
+        let event_mask_bytes = (u32::from(self.event_mask) as u16).serialize();
+        let pointer_mode_bytes = u8::from(self.pointer_mode).serialize();
+        let keyboard_mode_bytes = u8::from(self.keyboard_mode).serialize();
+        let confine_to_bytes = self.confine_to.serialize();
+        let cursor_bytes = self.cursor.serialize();
+        let time_bytes = self.time.serialize();
+        buffer.extend_from_slice(&[
+            GRAB_POINTER_REQUEST,
+            owner_events_bytes[0],
+            0,
+            0,
+            grab_window_bytes[0],
+            grab_window_bytes[1],
+            grab_window_bytes[2],
+            grab_window_bytes[3],
+            event_mask_bytes[0],
+            event_mask_bytes[1],
+            pointer_mode_bytes[0],
+            keyboard_mode_bytes[0],
+            confine_to_bytes[0],
+            confine_to_bytes[1],
+            confine_to_bytes[2],
+            confine_to_bytes[3],
+            cursor_bytes[0],
+            cursor_bytes[1],
+            cursor_bytes[2],
+            cursor_bytes[3],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GrabPointerRequest {
     type Reply = GrabPointerReply;
@@ -11954,6 +14535,12 @@ pub struct GrabPointerReply {
     pub length: u32,
 }
 impl_debug_if_no_extra_traits!(GrabPointerReply, "GrabPointerReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GrabPointerReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GrabPointerReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -12028,7 +14615,12 @@ pub const UNGRAB_POINTER_REQUEST: u8 = 27;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct UngrabPointerRequest {
+    /// Timestamp to avoid race conditions when running X over the network.
+    ///
+    /// The pointer will not be released if `time` is earlier than the
+    /// last-pointer-grab time or later than the current X server time.
     pub time: Timestamp,
 }
 impl_debug_if_no_extra_traits!(UngrabPointerRequest, "UngrabPointerRequest");
@@ -12078,6 +14670,33 @@ impl Request for UngrabPointerRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let time_bytes = self.time.serialize();
+        buffer.extend_from_slice(&[
+            UNGRAB_POINTER_REQUEST,
+            0,
+            0,
+            0,
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for UngrabPointerRequest {
 }
@@ -12092,6 +14711,7 @@ impl crate::x11_utils::VoidRequest for UngrabPointerRequest {
 /// * `5` - Scroll wheel. TODO: direction?
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ButtonIndex(u8);
 impl ButtonIndex {
     pub const ANY: Self = Self(0);
@@ -12156,6 +14776,25 @@ impl core::fmt::Debug for ButtonIndex  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl ButtonIndex {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Any"),
+            1 => Some("M1"),
+            2 => Some("M2"),
+            3 => Some("M3"),
+            4 => Some("M4"),
+            5 => Some("M5"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ButtonIndex {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the GrabButton request
 pub const GRAB_BUTTON_REQUEST: u8 = 28;
@@ -12229,15 +14868,32 @@ pub const GRAB_BUTTON_REQUEST: u8 = 28;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GrabButtonRequest {
+    /// If 1, the `grab_window` will still get the pointer events. If 0, events are not
+    /// reported to the `grab_window`.
     pub owner_events: bool,
+    /// Specifies the window on which the pointer should be grabbed.
     pub grab_window: Window,
+    /// Specifies which pointer events are reported to the client.
+    ///
+    /// TODO: which values?
     pub event_mask: EventMask,
     pub pointer_mode: GrabMode,
     pub keyboard_mode: GrabMode,
+    /// Specifies the window to confine the pointer in (the user will not be able to
+    /// move the pointer out of that window).
+    ///
+    /// The special value `XCB_NONE` means don't confine the pointer.
     pub confine_to: Window,
+    /// Specifies the cursor that should be displayed or `XCB_NONE` to not change the
+    /// cursor.
     pub cursor: Cursor,
     pub button: ButtonIndex,
+    /// The modifiers to grab.
+    ///
+    /// Using the special value `XCB_MOD_MASK_ANY` means grab the pointer with all
+    /// possible modifier combinations.
     pub modifiers: ModMask,
 }
 impl_debug_if_no_extra_traits!(GrabButtonRequest, "GrabButtonRequest");
@@ -12328,9 +14984,60 @@ impl Request for GrabButtonRequest {
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
-        // Flatten the buffers into a single vector
-        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
-        (buf, fds)
+        // Flatten the buffers into a single vector
+        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
+        (buf, fds)
+    }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let owner_events_bytes = self.owner_events.serialize();
+        let grab_window_bytes = self.grab_window.serialize();
+        let event_mask_bytes = (u32::from(self.event_mask) as u16).serialize();
+        let pointer_mode_bytes = u8::from(self.pointer_mode).serialize();
+        let keyboard_mode_bytes = u8::from(self.keyboard_mode).serialize();
+        let confine_to_bytes = self.confine_to.serialize();
+        let cursor_bytes = self.cursor.serialize();
+        let button_bytes = u8::from(self.button).serialize();
+        let modifiers_bytes = u16::from(self.modifiers).serialize();
+        buffer.extend_from_slice(&[
+            GRAB_BUTTON_REQUEST,
+            owner_events_bytes[0],
+            0,
+            0,
+            grab_window_bytes[0],
+            grab_window_bytes[1],
+            grab_window_bytes[2],
+            grab_window_bytes[3],
+            event_mask_bytes[0],
+            event_mask_bytes[1],
+            pointer_mode_bytes[0],
+            keyboard_mode_bytes[0],
+            confine_to_bytes[0],
+            confine_to_bytes[1],
+            confine_to_bytes[2],
+            confine_to_bytes[3],
+            cursor_bytes[0],
+            cursor_bytes[1],
+            cursor_bytes[2],
+            cursor_bytes[3],
+            button_bytes[0],
+            0,
+            modifiers_bytes[0],
+            modifiers_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
     }
 }
 impl crate::x11_utils::VoidRequest for GrabButtonRequest {
@@ -12341,6 +15048,7 @@ pub const UNGRAB_BUTTON_REQUEST: u8 = 29;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct UngrabButtonRequest {
     pub button: ButtonIndex,
     pub grab_window: Window,
@@ -12405,6 +15113,39 @@ impl Request for UngrabButtonRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let button_bytes = u8::from(self.button).serialize();
+        let grab_window_bytes = self.grab_window.serialize();
+        let modifiers_bytes = u16::from(self.modifiers).serialize();
+        buffer.extend_from_slice(&[
+            UNGRAB_BUTTON_REQUEST,
+            button_bytes[0],
+            0,
+            0,
+            grab_window_bytes[0],
+            grab_window_bytes[1],
+            grab_window_bytes[2],
+            grab_window_bytes[3],
+            modifiers_bytes[0],
+            modifiers_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for UngrabButtonRequest {
 }
@@ -12414,6 +15155,7 @@ pub const CHANGE_ACTIVE_POINTER_GRAB_REQUEST: u8 = 30;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangeActivePointerGrabRequest {
     pub cursor: Cursor,
     pub time: Timestamp,
@@ -12482,6 +15224,43 @@ impl Request for ChangeActivePointerGrabRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let cursor_bytes = self.cursor.serialize();
+        let time_bytes = self.time.serialize();
+        let event_mask_bytes = (u32::from(self.event_mask) as u16).serialize();
+        buffer.extend_from_slice(&[
+            CHANGE_ACTIVE_POINTER_GRAB_REQUEST,
+            0,
+            0,
+            0,
+            cursor_bytes[0],
+            cursor_bytes[1],
+            cursor_bytes[2],
+            cursor_bytes[3],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            event_mask_bytes[0],
+            event_mask_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for ChangeActivePointerGrabRequest {
 }
@@ -12553,9 +15332,17 @@ pub const GRAB_KEYBOARD_REQUEST: u8 = 31;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GrabKeyboardRequest {
+    /// If 1, the `grab_window` will still get the pointer events. If 0, events are not
+    /// reported to the `grab_window`.
     pub owner_events: bool,
+    /// Specifies the window on which the pointer should be grabbed.
     pub grab_window: Window,
+    /// Timestamp to avoid race conditions when running X over the network.
+    ///
+    /// The special value `XCB_CURRENT_TIME` will be replaced with the current server
+    /// time.
     pub time: Timestamp,
     pub pointer_mode: GrabMode,
     pub keyboard_mode: GrabMode,
@@ -12629,6 +15416,45 @@ impl Request for GrabKeyboardRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let owner_events_bytes = self.owner_events.serialize();
+        let grab_window_bytes = self.grab_window.serialize();
+        let time_bytes = self.time.serialize();
+        let pointer_mode_bytes = u8::from(self.pointer_mode).serialize();
+        let keyboard_mode_bytes = u8::from(self.keyboard_mode).serialize();
+        buffer.extend_from_slice(&[
+            GRAB_KEYBOARD_REQUEST,
+            owner_events_bytes[0],
+            0,
+            0,
+            grab_window_bytes[0],
+            grab_window_bytes[1],
+            grab_window_bytes[2],
+            grab_window_bytes[3],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            pointer_mode_bytes[0],
+            keyboard_mode_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GrabKeyboardRequest {
     type Reply = GrabKeyboardReply;
@@ -12643,6 +15469,12 @@ pub struct GrabKeyboardReply {
     pub length: u32,
 }
 impl_debug_if_no_extra_traits!(GrabKeyboardReply, "GrabKeyboardReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GrabKeyboardReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GrabKeyboardReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -12694,6 +15526,7 @@ pub const UNGRAB_KEYBOARD_REQUEST: u8 = 32;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct UngrabKeyboardRequest {
     pub time: Timestamp,
 }
@@ -12744,12 +15577,40 @@ impl Request for UngrabKeyboardRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let time_bytes = self.time.serialize();
+        buffer.extend_from_slice(&[
+            UNGRAB_KEYBOARD_REQUEST,
+            0,
+            0,
+            0,
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for UngrabKeyboardRequest {
 }
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Grab(u8);
 impl Grab {
     pub const ANY: Self = Self(0);
@@ -12804,6 +15665,20 @@ impl core::fmt::Debug for Grab  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl Grab {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Any"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Grab {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the GrabKey request
 pub const GRAB_KEY_REQUEST: u8 = 33;
@@ -12869,10 +15744,21 @@ pub const GRAB_KEY_REQUEST: u8 = 33;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GrabKeyRequest {
+    /// If 1, the `grab_window` will still get the key events. If 0, events are not
+    /// reported to the `grab_window`.
     pub owner_events: bool,
+    /// Specifies the window on which the key should be grabbed.
     pub grab_window: Window,
+    /// The modifiers to grab.
+    ///
+    /// Using the special value `XCB_MOD_MASK_ANY` means grab the key with all
+    /// possible modifier combinations.
     pub modifiers: ModMask,
+    /// The keycode of the key to grab.
+    ///
+    /// The special value `XCB_GRAB_ANY` means grab any key.
     pub key: Keycode,
     pub pointer_mode: GrabMode,
     pub keyboard_mode: GrabMode,
@@ -12950,6 +15836,46 @@ impl Request for GrabKeyRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let owner_events_bytes = self.owner_events.serialize();
+        let grab_window_bytes = self.grab_window.serialize();
+        let modifiers_bytes = u16::from(self.modifiers).serialize();
+        let key_bytes = self.key.serialize();
+        let pointer_mode_bytes = u8::from(self.pointer_mode).serialize();
+        let keyboard_mode_bytes = u8::from(self.keyboard_mode).serialize();
+        buffer.extend_from_slice(&[
+            GRAB_KEY_REQUEST,
+            owner_events_bytes[0],
+            0,
+            0,
+            grab_window_bytes[0],
+            grab_window_bytes[1],
+            grab_window_bytes[2],
+            grab_window_bytes[3],
+            modifiers_bytes[0],
+            modifiers_bytes[1],
+            key_bytes[0],
+            pointer_mode_bytes[0],
+            keyboard_mode_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for GrabKeyRequest {
 }
@@ -12984,9 +15910,18 @@ pub const UNGRAB_KEY_REQUEST: u8 = 34;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct UngrabKeyRequest {
+    /// The keycode of the specified key combination.
+    ///
+    /// Using the special value `XCB_GRAB_ANY` means releasing all possible key codes.
     pub key: Keycode,
+    /// The window on which the grabbed key combination will be released.
     pub grab_window: Window,
+    /// The modifiers of the specified key combination.
+    ///
+    /// Using the special value `XCB_MOD_MASK_ANY` means releasing the key combination
+    /// with every possible modifier combination.
     pub modifiers: ModMask,
 }
 impl_debug_if_no_extra_traits!(UngrabKeyRequest, "UngrabKeyRequest");
@@ -13047,6 +15982,39 @@ impl Request for UngrabKeyRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let key_bytes = self.key.serialize();
+        let grab_window_bytes = self.grab_window.serialize();
+        let modifiers_bytes = u16::from(self.modifiers).serialize();
+        buffer.extend_from_slice(&[
+            UNGRAB_KEY_REQUEST,
+            key_bytes[0],
+            0,
+            0,
+            grab_window_bytes[0],
+            grab_window_bytes[1],
+            grab_window_bytes[2],
+            grab_window_bytes[3],
+            modifiers_bytes[0],
+            modifiers_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for UngrabKeyRequest {
 }
@@ -13112,6 +16080,7 @@ impl crate::x11_utils::VoidRequest for UngrabKeyRequest {
 ///   has no effect unless both pointer and keyboard are frozen by the client.
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Allow(u8);
 impl Allow {
     pub const ASYNC_POINTER: Self = Self(0);
@@ -13180,6 +16149,27 @@ impl core::fmt::Debug for Allow  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl Allow {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("AsyncPointer"),
+            1 => Some("SyncPointer"),
+            2 => Some("ReplayPointer"),
+            3 => Some("AsyncKeyboard"),
+            4 => Some("SyncKeyboard"),
+            5 => Some("ReplayKeyboard"),
+            6 => Some("AsyncBoth"),
+            7 => Some("SyncBoth"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Allow {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the AllowEvents request
 pub const ALLOW_EVENTS_REQUEST: u8 = 35;
@@ -13204,8 +16194,13 @@ pub const ALLOW_EVENTS_REQUEST: u8 = 35;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct AllowEventsRequest {
     pub mode: Allow,
+    /// Timestamp to avoid race conditions when running X over the network.
+    ///
+    /// The special value `XCB_CURRENT_TIME` will be replaced with the current server
+    /// time.
     pub time: Timestamp,
 }
 impl_debug_if_no_extra_traits!(AllowEventsRequest, "AllowEventsRequest");
@@ -13258,6 +16253,34 @@ impl Request for AllowEventsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let mode_bytes = u8::from(self.mode).serialize();
+        let time_bytes = self.time.serialize();
+        buffer.extend_from_slice(&[
+            ALLOW_EVENTS_REQUEST,
+            mode_bytes[0],
+            0,
+            0,
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for AllowEventsRequest {
 }
@@ -13267,6 +16290,7 @@ pub const GRAB_SERVER_REQUEST: u8 = 36;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GrabServerRequest;
 impl_debug_if_no_extra_traits!(GrabServerRequest, "GrabServerRequest");
 impl GrabServerRequest {
@@ -13308,6 +16332,28 @@ impl Request for GrabServerRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            GRAB_SERVER_REQUEST,
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for GrabServerRequest {
 }
@@ -13317,6 +16363,7 @@ pub const UNGRAB_SERVER_REQUEST: u8 = 37;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct UngrabServerRequest;
 impl_debug_if_no_extra_traits!(UngrabServerRequest, "UngrabServerRequest");
 impl UngrabServerRequest {
@@ -13358,6 +16405,28 @@ impl Request for UngrabServerRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            UNGRAB_SERVER_REQUEST,
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for UngrabServerRequest {
 }
@@ -13380,7 +16449,10 @@ pub const QUERY_POINTER_REQUEST: u8 = 38;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryPointerRequest {
+    /// A window to check if the pointer is on the same screen as `window` (see the
+    /// `same_screen` field in the reply).
     pub window: Window,
 }
 impl_debug_if_no_extra_traits!(QueryPointerRequest, "QueryPointerRequest");
@@ -13430,6 +16502,33 @@ impl Request for QueryPointerRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        buffer.extend_from_slice(&[
+            QUERY_POINTER_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryPointerRequest {
     type Reply = QueryPointerReply;
@@ -13458,18 +16557,41 @@ impl crate::x11_utils::ReplyRequest for QueryPointerRequest {
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct QueryPointerReply {
+    /// If `same_screen` is False, then the pointer is not on the same screen as the
+    /// argument window, `child` is None, and `win_x` and `win_y` are zero. If
+    /// `same_screen` is True, then `win_x` and `win_y` are the pointer coordinates
+    /// relative to the argument window's origin, and child is the child containing the
+    /// pointer, if any.
     pub same_screen: bool,
     pub sequence: u16,
     pub length: u32,
+    /// The root window the pointer is logically on.
     pub root: Window,
+    /// The child window containing the pointer, if any, if `same_screen` is true. If
+    /// `same_screen` is false, `XCB_NONE` is returned.
     pub child: Window,
+    /// The pointer X position, relative to `root`.
     pub root_x: i16,
+    /// The pointer Y position, relative to `root`.
     pub root_y: i16,
+    /// The pointer X coordinate, relative to `child`, if `same_screen` is true. Zero
+    /// otherwise.
     pub win_x: i16,
+    /// The pointer Y coordinate, relative to `child`, if `same_screen` is true. Zero
+    /// otherwise.
     pub win_y: i16,
+    /// The current logical state of the modifier keys and the buttons. Note that the
+    /// logical state of a device (as seen by means of the protocol) may lag the
+    /// physical state if device event processing is frozen.
     pub mask: KeyButMask,
 }
 impl_debug_if_no_extra_traits!(QueryPointerReply, "QueryPointerReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryPointerReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryPointerReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -13568,6 +16690,12 @@ pub struct Timecoord {
     pub y: i16,
 }
 impl_debug_if_no_extra_traits!(Timecoord, "Timecoord");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Timecoord {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Timecoord {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (time, remaining) = Timestamp::try_parse(remaining)?;
@@ -13607,6 +16735,7 @@ pub const GET_MOTION_EVENTS_REQUEST: u8 = 39;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetMotionEventsRequest {
     pub window: Window,
     pub start: Timestamp,
@@ -13673,6 +16802,43 @@ impl Request for GetMotionEventsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let start_bytes = self.start.serialize();
+        let stop_bytes = self.stop.serialize();
+        buffer.extend_from_slice(&[
+            GET_MOTION_EVENTS_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            start_bytes[0],
+            start_bytes[1],
+            start_bytes[2],
+            start_bytes[3],
+            stop_bytes[0],
+            stop_bytes[1],
+            stop_bytes[2],
+            stop_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetMotionEventsRequest {
     type Reply = GetMotionEventsReply;
@@ -13687,6 +16853,12 @@ pub struct GetMotionEventsReply {
     pub events: Vec<Timecoord>,
 }
 impl_debug_if_no_extra_traits!(GetMotionEventsReply, "GetMotionEventsReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetMotionEventsReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetMotionEventsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -13725,6 +16897,7 @@ impl Serialize for GetMotionEventsReply {
         events_len.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 20]);
         self.events.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetMotionEventsReply {
@@ -13748,6 +16921,7 @@ pub const TRANSLATE_COORDINATES_REQUEST: u8 = 40;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct TranslateCoordinatesRequest {
     pub src_window: Window,
     pub dst_window: Window,
@@ -13808,15 +16982,53 @@ impl TranslateCoordinatesRequest {
             src_y,
         })
     }
-}
-impl Request for TranslateCoordinatesRequest {
-    const EXTENSION_NAME: Option<&'static str> = None;
-
-    fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
+}
+impl Request for TranslateCoordinatesRequest {
+    const EXTENSION_NAME: Option<&'static str> = None;
+
+    fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
+        let (bufs, fds) = self.serialize();
+        // Flatten the buffers into a single vector
+        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
+        (buf, fds)
+    }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let src_window_bytes = self.src_window.serialize();
+        let dst_window_bytes = self.dst_window.serialize();
+        let src_x_bytes = self.src_x.serialize();
+        let src_y_bytes = self.src_y.serialize();
+        buffer.extend_from_slice(&[
+            TRANSLATE_COORDINATES_REQUEST,
+            0,
+            0,
+            0,
+            src_window_bytes[0],
+            src_window_bytes[1],
+            src_window_bytes[2],
+            src_window_bytes[3],
+            dst_window_bytes[0],
+            dst_window_bytes[1],
+            dst_window_bytes[2],
+            dst_window_bytes[3],
+            src_x_bytes[0],
+            src_x_bytes[1],
+            src_y_bytes[0],
+            src_y_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
         let (bufs, fds) = self.serialize();
-        // Flatten the buffers into a single vector
-        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
-        (buf, fds)
+        slices.extend(bufs);
+        fds
     }
 }
 impl crate::x11_utils::ReplyRequest for TranslateCoordinatesRequest {
@@ -13835,6 +17047,12 @@ pub struct TranslateCoordinatesReply {
     pub dst_y: i16,
 }
 impl_debug_if_no_extra_traits!(TranslateCoordinatesReply, "TranslateCoordinatesReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for TranslateCoordinatesReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for TranslateCoordinatesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -13934,8 +17152,17 @@ pub const WARP_POINTER_REQUEST: u8 = 41;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct WarpPointerRequest {
+    /// If `src_window` is not `XCB_NONE` (TODO), the move will only take place if the
+    /// pointer is inside `src_window` and within the rectangle specified by (`src_x`,
+    /// `src_y`, `src_width`, `src_height`). The rectangle coordinates are relative to
+    /// `src_window`.
     pub src_window: Window,
+    /// If `dst_window` is not `XCB_NONE` (TODO), the pointer will be moved to the
+    /// offsets (`dst_x`, `dst_y`) relative to `dst_window`. If `dst_window` is
+    /// `XCB_NONE` (TODO), the pointer will be moved by the offsets (`dst_x`, `dst_y`)
+    /// relative to the current position of the pointer.
     pub dst_window: Window,
     pub src_x: i16,
     pub src_y: i16,
@@ -14028,6 +17255,56 @@ impl Request for WarpPointerRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let src_window_bytes = self.src_window.serialize();
+        let dst_window_bytes = self.dst_window.serialize();
+        let src_x_bytes = self.src_x.serialize();
+        let src_y_bytes = self.src_y.serialize();
+        let src_width_bytes = self.src_width.serialize();
+        let src_height_bytes = self.src_height.serialize();
+        let dst_x_bytes = self.dst_x.serialize();
+        let dst_y_bytes = self.dst_y.serialize();
+        buffer.extend_from_slice(&[
+            WARP_POINTER_REQUEST,
+            0,
+            0,
+            0,
+            src_window_bytes[0],
+            src_window_bytes[1],
+            src_window_bytes[2],
+            src_window_bytes[3],
+            dst_window_bytes[0],
+            dst_window_bytes[1],
+            dst_window_bytes[2],
+            dst_window_bytes[3],
+            src_x_bytes[0],
+            src_x_bytes[1],
+            src_y_bytes[0],
+            src_y_bytes[1],
+            src_width_bytes[0],
+            src_width_bytes[1],
+            src_height_bytes[0],
+            src_height_bytes[1],
+            dst_x_bytes[0],
+            dst_x_bytes[1],
+            dst_y_bytes[0],
+            dst_y_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for WarpPointerRequest {
 }
@@ -14043,6 +17320,7 @@ impl crate::x11_utils::VoidRequest for WarpPointerRequest {
 /// * `FollowKeyboard` - NOT YET DOCUMENTED. Only relevant for the xinput extension.
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct InputFocus(u8);
 impl InputFocus {
     pub const NONE: Self = Self(0);
@@ -14103,6 +17381,23 @@ impl core::fmt::Debug for InputFocus  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl InputFocus {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("None"),
+            1 => Some("PointerRoot"),
+            2 => Some("Parent"),
+            3 => Some("FollowKeyboard"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for InputFocus {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the SetInputFocus request
 pub const SET_INPUT_FOCUS_REQUEST: u8 = 42;
@@ -14144,9 +17439,24 @@ pub const SET_INPUT_FOCUS_REQUEST: u8 = 42;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetInputFocusRequest {
+    /// Specifies what happens when the `focus` window becomes unviewable (if `focus`
+    /// is neither `XCB_NONE` nor `XCB_POINTER_ROOT`).
     pub revert_to: InputFocus,
+    /// The window to focus. All keyboard events will be reported to this window. The
+    /// window must be viewable (TODO), or a `xcb_match_error_t` occurs (TODO).
+    ///
+    /// If `focus` is `XCB_NONE` (TODO), all keyboard events are
+    /// discarded until a new focus window is set.
+    ///
+    /// If `focus` is `XCB_POINTER_ROOT` (TODO), focus is on the root window of the
+    /// screen on which the pointer is on currently.
     pub focus: Window,
+    /// Timestamp to avoid race conditions when running X over the network.
+    ///
+    /// The special value `XCB_CURRENT_TIME` will be replaced with the current server
+    /// time.
     pub time: Timestamp,
 }
 impl_debug_if_no_extra_traits!(SetInputFocusRequest, "SetInputFocusRequest");
@@ -14206,6 +17516,39 @@ impl Request for SetInputFocusRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let revert_to_bytes = u8::from(self.revert_to).serialize();
+        let focus_bytes = self.focus.serialize();
+        let time_bytes = self.time.serialize();
+        buffer.extend_from_slice(&[
+            SET_INPUT_FOCUS_REQUEST,
+            revert_to_bytes[0],
+            0,
+            0,
+            focus_bytes[0],
+            focus_bytes[1],
+            focus_bytes[2],
+            focus_bytes[3],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SetInputFocusRequest {
 }
@@ -14215,6 +17558,7 @@ pub const GET_INPUT_FOCUS_REQUEST: u8 = 43;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetInputFocusRequest;
 impl_debug_if_no_extra_traits!(GetInputFocusRequest, "GetInputFocusRequest");
 impl GetInputFocusRequest {
@@ -14256,6 +17600,28 @@ impl Request for GetInputFocusRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            GET_INPUT_FOCUS_REQUEST,
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetInputFocusRequest {
     type Reply = GetInputFocusReply;
@@ -14271,6 +17637,12 @@ pub struct GetInputFocusReply {
     pub focus: Window,
 }
 impl_debug_if_no_extra_traits!(GetInputFocusReply, "GetInputFocusReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetInputFocusReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetInputFocusReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -14329,6 +17701,7 @@ pub const QUERY_KEYMAP_REQUEST: u8 = 44;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryKeymapRequest;
 impl_debug_if_no_extra_traits!(QueryKeymapRequest, "QueryKeymapRequest");
 impl QueryKeymapRequest {
@@ -14370,6 +17743,28 @@ impl Request for QueryKeymapRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            QUERY_KEYMAP_REQUEST,
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryKeymapRequest {
     type Reply = QueryKeymapReply;
@@ -14384,6 +17779,12 @@ pub struct QueryKeymapReply {
     pub keys: [u8; 32],
 }
 impl_debug_if_no_extra_traits!(QueryKeymapReply, "QueryKeymapReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryKeymapReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryKeymapReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -14486,8 +17887,11 @@ pub const OPEN_FONT_REQUEST: u8 = 45;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct OpenFontRequest<'input> {
+    /// The ID with which you will refer to the font, created by `xcb_generate_id`.
     pub fid: Font,
+    /// A pattern describing an X core font.
     pub name: Cow<'input, [u8]>,
 }
 impl_debug_if_no_extra_traits!(OpenFontRequest<'_>, "OpenFontRequest");
@@ -14557,6 +17961,42 @@ impl<'input> Request for OpenFontRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let fid_bytes = self.fid.serialize();
+        let name_len = u16::try_from(self.name.len()).expect("`name` has too many elements");
+        let name_len_bytes = name_len.serialize();
+        buffer.extend_from_slice(&[
+            OPEN_FONT_REQUEST,
+            0,
+            0,
+            0,
+            fid_bytes[0],
+            fid_bytes[1],
+            fid_bytes[2],
+            fid_bytes[3],
+            name_len_bytes[0],
+            name_len_bytes[1],
+            0,
+            0,
+        ]);
+        buffer.extend_from_slice(&self.name[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for OpenFontRequest<'input> {
 }
@@ -14566,6 +18006,7 @@ pub const CLOSE_FONT_REQUEST: u8 = 46;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CloseFontRequest {
     pub font: Font,
 }
@@ -14616,12 +18057,40 @@ impl Request for CloseFontRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let font_bytes = self.font.serialize();
+        buffer.extend_from_slice(&[
+            CLOSE_FONT_REQUEST,
+            0,
+            0,
+            0,
+            font_bytes[0],
+            font_bytes[1],
+            font_bytes[2],
+            font_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for CloseFontRequest {
 }
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct FontDraw(u8);
 impl FontDraw {
     pub const LEFT_TO_RIGHT: Self = Self(0);
@@ -14678,6 +18147,21 @@ impl core::fmt::Debug for FontDraw  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl FontDraw {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("LeftToRight"),
+            1 => Some("RightToLeft"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for FontDraw {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
@@ -14687,6 +18171,12 @@ pub struct Fontprop {
     pub value: u32,
 }
 impl_debug_if_no_extra_traits!(Fontprop, "Fontprop");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Fontprop {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Fontprop {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (name, remaining) = Atom::try_parse(remaining)?;
@@ -14730,6 +18220,12 @@ pub struct Charinfo {
     pub attributes: u16,
 }
 impl_debug_if_no_extra_traits!(Charinfo, "Charinfo");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Charinfo {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Charinfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (left_side_bearing, remaining) = i16::try_parse(remaining)?;
@@ -14789,7 +18285,9 @@ pub const QUERY_FONT_REQUEST: u8 = 47;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryFontRequest {
+    /// The fontable (Font or Graphics Context) to query.
     pub font: Fontable,
 }
 impl_debug_if_no_extra_traits!(QueryFontRequest, "QueryFontRequest");
@@ -14839,6 +18337,33 @@ impl Request for QueryFontRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let font_bytes = self.font.serialize();
+        buffer.extend_from_slice(&[
+            QUERY_FONT_REQUEST,
+            0,
+            0,
+            0,
+            font_bytes[0],
+            font_bytes[1],
+            font_bytes[2],
+            font_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryFontRequest {
     type Reply = QueryFontReply;
@@ -14861,21 +18386,35 @@ impl crate::x11_utils::ReplyRequest for QueryFontRequest {
 pub struct QueryFontReply {
     pub sequence: u16,
     pub length: u32,
+    /// minimum bounds over all existing char
     pub min_bounds: Charinfo,
+    /// maximum bounds over all existing char
     pub max_bounds: Charinfo,
+    /// first character
     pub min_char_or_byte2: u16,
+    /// last character
     pub max_char_or_byte2: u16,
+    /// char to print for undefined character
     pub default_char: u16,
     pub draw_direction: FontDraw,
     pub min_byte1: u8,
     pub max_byte1: u8,
+    /// flag if all characters have nonzero size
     pub all_chars_exist: bool,
+    /// baseline to top edge of raster
     pub font_ascent: i16,
+    /// baseline to bottom edge of raster
     pub font_descent: i16,
     pub properties: Vec<Fontprop>,
     pub char_infos: Vec<Charinfo>,
 }
 impl_debug_if_no_extra_traits!(QueryFontReply, "QueryFontReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryFontReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryFontReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -14944,6 +18483,7 @@ impl Serialize for QueryFontReply {
         char_infos_len.serialize_into(bytes);
         self.properties.serialize_into(bytes);
         self.char_infos.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl QueryFontReply {
@@ -15014,8 +18554,11 @@ pub const QUERY_TEXT_EXTENTS_REQUEST: u8 = 48;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryTextExtentsRequest<'input> {
+    /// The `font` to calculate text extents in. You can also pass a graphics context.
     pub font: Fontable,
+    /// The text to get text extents for.
     pub string: Cow<'input, [Char2b]>,
 }
 impl_debug_if_no_extra_traits!(QueryTextExtentsRequest<'_>, "QueryTextExtentsRequest");
@@ -15059,7 +18602,7 @@ impl<'input> QueryTextExtentsRequest<'input> {
         let (font, remaining) = Fontable::try_parse(value)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut string = Vec::new();
+        let mut string = Vec::with_capacity(remaining.len() / 2);
         while !remaining.is_empty() {
             let (v, new_remaining) = Char2b::try_parse(remaining)?;
             remaining = new_remaining;
@@ -15094,6 +18637,39 @@ impl<'input> Request for QueryTextExtentsRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let string_len = u32::try_from(self.string.len()).unwrap();
+        let request_start = buffer.len();
+        let odd_length = (u32::from(string_len) & 1u32) != 0;
+        let odd_length_bytes = odd_length.serialize();
+        let font_bytes = self.font.serialize();
+        buffer.extend_from_slice(&[
+            QUERY_TEXT_EXTENTS_REQUEST,
+            odd_length_bytes[0],
+            0,
+            0,
+            font_bytes[0],
+            font_bytes[1],
+            font_bytes[2],
+            font_bytes[3],
+        ]);
+        self.string.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::ReplyRequest for QueryTextExtentsRequest<'input> {
     type Reply = QueryTextExtentsReply;
@@ -15115,6 +18691,12 @@ pub struct QueryTextExtentsReply {
     pub overall_right: i32,
 }
 impl_debug_if_no_extra_traits!(QueryTextExtentsReply, "QueryTextExtentsReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryTextExtentsReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryTextExtentsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -15209,6 +18791,12 @@ pub struct Str {
     pub name: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(Str, "Str");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Str {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Str {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (name_len, remaining) = u8::try_parse(remaining)?;
@@ -15264,8 +18852,15 @@ pub const LIST_FONTS_REQUEST: u8 = 49;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ListFontsRequest<'input> {
+    /// The maximum number of fonts to be returned.
     pub max_names: u16,
+    /// A font pattern, for example "-misc-fixed-*".
+    ///
+    /// The asterisk (*) is a wildcard for any number of characters. The question mark
+    /// (?) is a wildcard for a single character. Use of uppercase or lowercase does
+    /// not matter.
     pub pattern: Cow<'input, [u8]>,
 }
 impl_debug_if_no_extra_traits!(ListFontsRequest<'_>, "ListFontsRequest");
@@ -15330,6 +18925,38 @@ impl<'input> Request for ListFontsRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let max_names_bytes = self.max_names.serialize();
+        let pattern_len = u16::try_from(self.pattern.len()).expect("`pattern` has too many elements");
+        let pattern_len_bytes = pattern_len.serialize();
+        buffer.extend_from_slice(&[
+            LIST_FONTS_REQUEST,
+            0,
+            0,
+            0,
+            max_names_bytes[0],
+            max_names_bytes[1],
+            pattern_len_bytes[0],
+            pattern_len_bytes[1],
+        ]);
+        buffer.extend_from_slice(&self.pattern[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::ReplyRequest for ListFontsRequest<'input> {
     type Reply = ListFontsReply;
@@ -15346,6 +18973,12 @@ pub struct ListFontsReply {
     pub names: Vec<Str>,
 }
 impl_debug_if_no_extra_traits!(ListFontsReply, "ListFontsReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ListFontsReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ListFontsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -15384,6 +19017,7 @@ impl Serialize for ListFontsReply {
         names_len.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 22]);
         self.names.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl ListFontsReply {
@@ -15419,8 +19053,15 @@ pub const LIST_FONTS_WITH_INFO_REQUEST: u8 = 50;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ListFontsWithInfoRequest<'input> {
+    /// The maximum number of fonts to be returned.
     pub max_names: u16,
+    /// A font pattern, for example "-misc-fixed-*".
+    ///
+    /// The asterisk (*) is a wildcard for any number of characters. The question mark
+    /// (?) is a wildcard for a single character. Use of uppercase or lowercase does
+    /// not matter.
     pub pattern: Cow<'input, [u8]>,
 }
 impl_debug_if_no_extra_traits!(ListFontsWithInfoRequest<'_>, "ListFontsWithInfoRequest");
@@ -15485,6 +19126,38 @@ impl<'input> Request for ListFontsWithInfoRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let max_names_bytes = self.max_names.serialize();
+        let pattern_len = u16::try_from(self.pattern.len()).expect("`pattern` has too many elements");
+        let pattern_len_bytes = pattern_len.serialize();
+        buffer.extend_from_slice(&[
+            LIST_FONTS_WITH_INFO_REQUEST,
+            0,
+            0,
+            0,
+            max_names_bytes[0],
+            max_names_bytes[1],
+            pattern_len_bytes[0],
+            pattern_len_bytes[1],
+        ]);
+        buffer.extend_from_slice(&self.pattern[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::ReplyRequest for ListFontsWithInfoRequest<'input> {
     type Reply = ListFontsWithInfoReply;
@@ -15510,22 +19183,39 @@ impl<'input> crate::x11_utils::ReplyRequest for ListFontsWithInfoRequest<'input>
 pub struct ListFontsWithInfoReply {
     pub sequence: u16,
     pub length: u32,
+    /// minimum bounds over all existing char
     pub min_bounds: Charinfo,
+    /// maximum bounds over all existing char
     pub max_bounds: Charinfo,
+    /// first character
     pub min_char_or_byte2: u16,
+    /// last character
     pub max_char_or_byte2: u16,
+    /// char to print for undefined character
     pub default_char: u16,
     pub draw_direction: FontDraw,
     pub min_byte1: u8,
     pub max_byte1: u8,
+    /// flag if all characters have nonzero size
     pub all_chars_exist: bool,
+    /// baseline to top edge of raster
     pub font_ascent: i16,
+    /// baseline to bottom edge of raster
     pub font_descent: i16,
+    /// An indication of how many more fonts will be returned. This is only a hint and
+    /// may be larger or smaller than the number of fonts actually returned. A zero
+    /// value does not guarantee that no more fonts will be returned.
     pub replies_hint: u32,
     pub properties: Vec<Fontprop>,
     pub name: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(ListFontsWithInfoReply, "ListFontsWithInfoReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ListFontsWithInfoReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ListFontsWithInfoReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -15595,6 +19285,7 @@ impl Serialize for ListFontsWithInfoReply {
         self.replies_hint.serialize_into(bytes);
         self.properties.serialize_into(bytes);
         bytes.extend_from_slice(&self.name);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl ListFontsWithInfoReply {
@@ -15631,6 +19322,7 @@ pub const SET_FONT_PATH_REQUEST: u8 = 51;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetFontPathRequest<'input> {
     pub font: Cow<'input, [Str]>,
 }
@@ -15694,6 +19386,37 @@ impl<'input> Request for SetFontPathRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let font_qty = u16::try_from(self.font.len()).expect("`font` has too many elements");
+        let font_qty_bytes = font_qty.serialize();
+        buffer.extend_from_slice(&[
+            SET_FONT_PATH_REQUEST,
+            0,
+            0,
+            0,
+            font_qty_bytes[0],
+            font_qty_bytes[1],
+            0,
+            0,
+        ]);
+        self.font.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for SetFontPathRequest<'input> {
 }
@@ -15703,6 +19426,7 @@ pub const GET_FONT_PATH_REQUEST: u8 = 52;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetFontPathRequest;
 impl_debug_if_no_extra_traits!(GetFontPathRequest, "GetFontPathRequest");
 impl GetFontPathRequest {
@@ -15744,6 +19468,28 @@ impl Request for GetFontPathRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            GET_FONT_PATH_REQUEST,
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetFontPathRequest {
     type Reply = GetFontPathReply;
@@ -15758,6 +19504,12 @@ pub struct GetFontPathReply {
     pub path: Vec<Str>,
 }
 impl_debug_if_no_extra_traits!(GetFontPathReply, "GetFontPathReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetFontPathReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetFontPathReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -15796,6 +19548,7 @@ impl Serialize for GetFontPathReply {
         path_len.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 22]);
         self.path.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetFontPathReply {
@@ -15842,11 +19595,18 @@ pub const CREATE_PIXMAP_REQUEST: u8 = 53;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreatePixmapRequest {
+    /// TODO
     pub depth: u8,
+    /// The ID with which you will refer to the new pixmap, created by
+    /// `xcb_generate_id`.
     pub pid: Pixmap,
+    /// Drawable to get the screen from.
     pub drawable: Drawable,
+    /// The width of the new pixmap.
     pub width: u16,
+    /// The height of the new pixmap.
     pub height: u16,
 }
 impl_debug_if_no_extra_traits!(CreatePixmapRequest, "CreatePixmapRequest");
@@ -15915,6 +19675,45 @@ impl Request for CreatePixmapRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let depth_bytes = self.depth.serialize();
+        let pid_bytes = self.pid.serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        buffer.extend_from_slice(&[
+            CREATE_PIXMAP_REQUEST,
+            depth_bytes[0],
+            0,
+            0,
+            pid_bytes[0],
+            pid_bytes[1],
+            pid_bytes[2],
+            pid_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for CreatePixmapRequest {
 }
@@ -15936,7 +19735,9 @@ pub const FREE_PIXMAP_REQUEST: u8 = 54;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct FreePixmapRequest {
+    /// The pixmap to destroy.
     pub pixmap: Pixmap,
 }
 impl_debug_if_no_extra_traits!(FreePixmapRequest, "FreePixmapRequest");
@@ -15986,6 +19787,33 @@ impl Request for FreePixmapRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let pixmap_bytes = self.pixmap.serialize();
+        buffer.extend_from_slice(&[
+            FREE_PIXMAP_REQUEST,
+            0,
+            0,
+            0,
+            pixmap_bytes[0],
+            pixmap_bytes[1],
+            pixmap_bytes[2],
+            pixmap_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for FreePixmapRequest {
 }
@@ -16094,6 +19922,7 @@ impl crate::x11_utils::VoidRequest for FreePixmapRequest {
 /// * `ArcMode` - TODO
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GC(u32);
 impl GC {
     pub const FUNCTION: Self = Self(1 << 0);
@@ -16180,10 +20009,47 @@ impl core::fmt::Debug for GC  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl GC {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("Function"),
+            2 => Some("PlaneMask"),
+            4 => Some("Foreground"),
+            8 => Some("Background"),
+            16 => Some("LineWidth"),
+            32 => Some("LineStyle"),
+            64 => Some("CapStyle"),
+            128 => Some("JoinStyle"),
+            256 => Some("FillStyle"),
+            512 => Some("FillRule"),
+            1024 => Some("Tile"),
+            2048 => Some("Stipple"),
+            4096 => Some("TileStippleOriginX"),
+            8192 => Some("TileStippleOriginY"),
+            16384 => Some("Font"),
+            32768 => Some("SubwindowMode"),
+            65536 => Some("GraphicsExposures"),
+            131_072 => Some("ClipOriginX"),
+            262_144 => Some("ClipOriginY"),
+            524_288 => Some("ClipMask"),
+            1_048_576 => Some("DashOffset"),
+            2_097_152 => Some("DashList"),
+            4_194_304 => Some("ArcMode"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for GC {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(GC, u32);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GX(u32);
 impl GX {
     pub const CLEAR: Self = Self(0);
@@ -16256,9 +20122,39 @@ impl core::fmt::Debug for GX  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+impl GX {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Clear"),
+            1 => Some("And"),
+            2 => Some("AndReverse"),
+            3 => Some("Copy"),
+            4 => Some("AndInverted"),
+            5 => Some("Noop"),
+            6 => Some("Xor"),
+            7 => Some("Or"),
+            8 => Some("Nor"),
+            9 => Some("Equiv"),
+            10 => Some("Invert"),
+            11 => Some("OrReverse"),
+            12 => Some("CopyInverted"),
+            13 => Some("OrInverted"),
+            14 => Some("Nand"),
+            15 => Some("Set"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for GX {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct LineStyle(u32);
 impl LineStyle {
     pub const SOLID: Self = Self(0);
@@ -16305,9 +20201,26 @@ impl core::fmt::Debug for LineStyle  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+impl LineStyle {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Solid"),
+            1 => Some("OnOffDash"),
+            2 => Some("DoubleDash"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for LineStyle {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CapStyle(u32);
 impl CapStyle {
     pub const NOT_LAST: Self = Self(0);
@@ -16356,9 +20269,27 @@ impl core::fmt::Debug for CapStyle  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+impl CapStyle {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("NotLast"),
+            1 => Some("Butt"),
+            2 => Some("Round"),
+            3 => Some("Projecting"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for CapStyle {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct JoinStyle(u32);
 impl JoinStyle {
     pub const MITER: Self = Self(0);
@@ -16405,9 +20336,26 @@ impl core::fmt::Debug for JoinStyle  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+impl JoinStyle {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Miter"),
+            1 => Some("Round"),
+            2 => Some("Bevel"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for JoinStyle {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct FillStyle(u32);
 impl FillStyle {
     pub const SOLID: Self = Self(0);
@@ -16456,9 +20404,27 @@ impl core::fmt::Debug for FillStyle  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+impl FillStyle {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Solid"),
+            1 => Some("Tiled"),
+            2 => Some("Stippled"),
+            3 => Some("OpaqueStippled"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for FillStyle {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct FillRule(u32);
 impl FillRule {
     pub const EVEN_ODD: Self = Self(0);
@@ -16503,9 +20469,25 @@ impl core::fmt::Debug for FillRule  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+impl FillRule {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("EvenOdd"),
+            1 => Some("Winding"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for FillRule {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SubwindowMode(u32);
 impl SubwindowMode {
     pub const CLIP_BY_CHILDREN: Self = Self(0);
@@ -16550,9 +20532,25 @@ impl core::fmt::Debug for SubwindowMode  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+impl SubwindowMode {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("ClipByChildren"),
+            1 => Some("IncludeInferiors"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for SubwindowMode {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ArcMode(u32);
 impl ArcMode {
     pub const CHORD: Self = Self(0);
@@ -16597,11 +20595,27 @@ impl core::fmt::Debug for ArcMode  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+impl ArcMode {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Chord"),
+            1 => Some("PieSlice"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ArcMode {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Auxiliary and optional information for the `create_gc` function
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateGCAux {
     pub function: Option<GX>,
     pub plane_mask: Option<u32>,
@@ -17127,6 +21141,84 @@ impl CreateGCAux {
         self.arc_mode = value.into();
         self
     }
+    /// Get the changes that are needed to go from `old` to `self`.
+    ///
+    /// The result contains all fields that are set in `self` and that are either unset in
+    /// `old` or set to a different value. This allows to only send values that changed.
+    #[must_use]
+    pub fn changes_from(&self, old: &Self) -> Self {
+        let mut result = Self::new();
+        if self.function.is_some() && self.function != old.function {
+            result.function = self.function;
+        }
+        if self.plane_mask.is_some() && self.plane_mask != old.plane_mask {
+            result.plane_mask = self.plane_mask;
+        }
+        if self.foreground.is_some() && self.foreground != old.foreground {
+            result.foreground = self.foreground;
+        }
+        if self.background.is_some() && self.background != old.background {
+            result.background = self.background;
+        }
+        if self.line_width.is_some() && self.line_width != old.line_width {
+            result.line_width = self.line_width;
+        }
+        if self.line_style.is_some() && self.line_style != old.line_style {
+            result.line_style = self.line_style;
+        }
+        if self.cap_style.is_some() && self.cap_style != old.cap_style {
+            result.cap_style = self.cap_style;
+        }
+        if self.join_style.is_some() && self.join_style != old.join_style {
+            result.join_style = self.join_style;
+        }
+        if self.fill_style.is_some() && self.fill_style != old.fill_style {
+            result.fill_style = self.fill_style;
+        }
+        if self.fill_rule.is_some() && self.fill_rule != old.fill_rule {
+            result.fill_rule = self.fill_rule;
+        }
+        if self.tile.is_some() && self.tile != old.tile {
+            result.tile = self.tile;
+        }
+        if self.stipple.is_some() && self.stipple != old.stipple {
+            result.stipple = self.stipple;
+        }
+        if self.tile_stipple_x_origin.is_some() && self.tile_stipple_x_origin != old.tile_stipple_x_origin {
+            result.tile_stipple_x_origin = self.tile_stipple_x_origin;
+        }
+        if self.tile_stipple_y_origin.is_some() && self.tile_stipple_y_origin != old.tile_stipple_y_origin {
+            result.tile_stipple_y_origin = self.tile_stipple_y_origin;
+        }
+        if self.font.is_some() && self.font != old.font {
+            result.font = self.font;
+        }
+        if self.subwindow_mode.is_some() && self.subwindow_mode != old.subwindow_mode {
+            result.subwindow_mode = self.subwindow_mode;
+        }
+        if self.graphics_exposures.is_some() && self.graphics_exposures != old.graphics_exposures {
+            result.graphics_exposures = self.graphics_exposures;
+        }
+        if self.clip_x_origin.is_some() && self.clip_x_origin != old.clip_x_origin {
+            result.clip_x_origin = self.clip_x_origin;
+        }
+        if self.clip_y_origin.is_some() && self.clip_y_origin != old.clip_y_origin {
+            result.clip_y_origin = self.clip_y_origin;
+        }
+        if self.clip_mask.is_some() && self.clip_mask != old.clip_mask {
+            result.clip_mask = self.clip_mask;
+        }
+        if self.dash_offset.is_some() && self.dash_offset != old.dash_offset {
+            result.dash_offset = self.dash_offset;
+        }
+        if self.dashes.is_some() && self.dashes != old.dashes {
+            result.dashes = self.dashes;
+        }
+        if self.arc_mode.is_some() && self.arc_mode != old.arc_mode {
+            result.arc_mode = self.arc_mode;
+        }
+        result
+    }
 }
 
 /// Opcode for the CreateGC request
@@ -17157,8 +21249,12 @@ pub const CREATE_GC_REQUEST: u8 = 55;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateGCRequest<'input> {
+    /// The ID with which you will refer to the graphics context, created by
+    /// `xcb_generate_id`.
     pub cid: Gcontext,
+    /// Drawable to get the root/depth from.
     pub drawable: Drawable,
     pub value_list: Cow<'input, CreateGCAux>,
 }
@@ -17219,32 +21315,226 @@ impl<'input> CreateGCRequest<'input> {
             value_list: Cow::Owned(value_list),
         })
     }
-    /// Clone all borrowed data in this CreateGCRequest.
-    pub fn into_owned(self) -> CreateGCRequest<'static> {
-        CreateGCRequest {
-            cid: self.cid,
-            drawable: self.drawable,
-            value_list: Cow::Owned(self.value_list.into_owned()),
-        }
+    /// Clone all borrowed data in this CreateGCRequest.
+    pub fn into_owned(self) -> CreateGCRequest<'static> {
+        CreateGCRequest {
+            cid: self.cid,
+            drawable: self.drawable,
+            value_list: Cow::Owned(self.value_list.into_owned()),
+        }
+    }
+}
+impl<'input> Request for CreateGCRequest<'input> {
+    const EXTENSION_NAME: Option<&'static str> = None;
+
+    fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
+        let (bufs, fds) = self.serialize();
+        // Flatten the buffers into a single vector
+        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
+        (buf, fds)
+    }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let cid_bytes = self.cid.serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let value_mask: u32 = self.value_list.switch_expr();
+        let value_mask_bytes = value_mask.serialize();
+        buffer.extend_from_slice(&[
+            CREATE_GC_REQUEST,
+            0,
+            0,
+            0,
+            cid_bytes[0],
+            cid_bytes[1],
+            cid_bytes[2],
+            cid_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            value_mask_bytes[0],
+            value_mask_bytes[1],
+            value_mask_bytes[2],
+            value_mask_bytes[3],
+        ]);
+        self.value_list.serialize_into(buffer, u32::from(value_mask));
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
+}
+impl<'input> crate::x11_utils::VoidRequest for CreateGCRequest<'input> {
+}
+impl CreateGCRequest<'static> {
+    /// Create a new request with all fields of `value_list` unset.
+    ///
+    /// The optional fields can then be set with the builder methods of this request.
+    pub fn new(cid: Gcontext, drawable: Drawable) -> Self {
+        CreateGCRequest {
+            cid,
+            drawable,
+            value_list: Cow::Owned(CreateGCAux::new()),
+        }
+    }
+}
+impl<'input> CreateGCRequest<'input> {
+    /// Set the `function` field of `value_list`.
+    #[must_use]
+    pub fn function<I>(mut self, value: I) -> Self where I: Into<Option<GX>> {
+        self.value_list.to_mut().function = value.into();
+        self
+    }
+    /// Set the `plane_mask` field of `value_list`.
+    #[must_use]
+    pub fn plane_mask<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().plane_mask = value.into();
+        self
+    }
+    /// Set the `foreground` field of `value_list`.
+    #[must_use]
+    pub fn foreground<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().foreground = value.into();
+        self
+    }
+    /// Set the `background` field of `value_list`.
+    #[must_use]
+    pub fn background<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().background = value.into();
+        self
+    }
+    /// Set the `line_width` field of `value_list`.
+    #[must_use]
+    pub fn line_width<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().line_width = value.into();
+        self
+    }
+    /// Set the `line_style` field of `value_list`.
+    #[must_use]
+    pub fn line_style<I>(mut self, value: I) -> Self where I: Into<Option<LineStyle>> {
+        self.value_list.to_mut().line_style = value.into();
+        self
+    }
+    /// Set the `cap_style` field of `value_list`.
+    #[must_use]
+    pub fn cap_style<I>(mut self, value: I) -> Self where I: Into<Option<CapStyle>> {
+        self.value_list.to_mut().cap_style = value.into();
+        self
+    }
+    /// Set the `join_style` field of `value_list`.
+    #[must_use]
+    pub fn join_style<I>(mut self, value: I) -> Self where I: Into<Option<JoinStyle>> {
+        self.value_list.to_mut().join_style = value.into();
+        self
+    }
+    /// Set the `fill_style` field of `value_list`.
+    #[must_use]
+    pub fn fill_style<I>(mut self, value: I) -> Self where I: Into<Option<FillStyle>> {
+        self.value_list.to_mut().fill_style = value.into();
+        self
+    }
+    /// Set the `fill_rule` field of `value_list`.
+    #[must_use]
+    pub fn fill_rule<I>(mut self, value: I) -> Self where I: Into<Option<FillRule>> {
+        self.value_list.to_mut().fill_rule = value.into();
+        self
+    }
+    /// Set the `tile` field of `value_list`.
+    #[must_use]
+    pub fn tile<I>(mut self, value: I) -> Self where I: Into<Option<Pixmap>> {
+        self.value_list.to_mut().tile = value.into();
+        self
+    }
+    /// Set the `stipple` field of `value_list`.
+    #[must_use]
+    pub fn stipple<I>(mut self, value: I) -> Self where I: Into<Option<Pixmap>> {
+        self.value_list.to_mut().stipple = value.into();
+        self
+    }
+    /// Set the `tile_stipple_x_origin` field of `value_list`.
+    #[must_use]
+    pub fn tile_stipple_x_origin<I>(mut self, value: I) -> Self where I: Into<Option<i32>> {
+        self.value_list.to_mut().tile_stipple_x_origin = value.into();
+        self
+    }
+    /// Set the `tile_stipple_y_origin` field of `value_list`.
+    #[must_use]
+    pub fn tile_stipple_y_origin<I>(mut self, value: I) -> Self where I: Into<Option<i32>> {
+        self.value_list.to_mut().tile_stipple_y_origin = value.into();
+        self
+    }
+    /// Set the `font` field of `value_list`.
+    #[must_use]
+    pub fn font<I>(mut self, value: I) -> Self where I: Into<Option<Font>> {
+        self.value_list.to_mut().font = value.into();
+        self
+    }
+    /// Set the `subwindow_mode` field of `value_list`.
+    #[must_use]
+    pub fn subwindow_mode<I>(mut self, value: I) -> Self where I: Into<Option<SubwindowMode>> {
+        self.value_list.to_mut().subwindow_mode = value.into();
+        self
+    }
+    /// Set the `graphics_exposures` field of `value_list`.
+    #[must_use]
+    pub fn graphics_exposures<I>(mut self, value: I) -> Self where I: Into<Option<Bool32>> {
+        self.value_list.to_mut().graphics_exposures = value.into();
+        self
+    }
+    /// Set the `clip_x_origin` field of `value_list`.
+    #[must_use]
+    pub fn clip_x_origin<I>(mut self, value: I) -> Self where I: Into<Option<i32>> {
+        self.value_list.to_mut().clip_x_origin = value.into();
+        self
+    }
+    /// Set the `clip_y_origin` field of `value_list`.
+    #[must_use]
+    pub fn clip_y_origin<I>(mut self, value: I) -> Self where I: Into<Option<i32>> {
+        self.value_list.to_mut().clip_y_origin = value.into();
+        self
+    }
+    /// Set the `clip_mask` field of `value_list`.
+    #[must_use]
+    pub fn clip_mask<I>(mut self, value: I) -> Self where I: Into<Option<Pixmap>> {
+        self.value_list.to_mut().clip_mask = value.into();
+        self
     }
-}
-impl<'input> Request for CreateGCRequest<'input> {
-    const EXTENSION_NAME: Option<&'static str> = None;
-
-    fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
-        let (bufs, fds) = self.serialize();
-        // Flatten the buffers into a single vector
-        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
-        (buf, fds)
+    /// Set the `dash_offset` field of `value_list`.
+    #[must_use]
+    pub fn dash_offset<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().dash_offset = value.into();
+        self
+    }
+    /// Set the `dashes` field of `value_list`.
+    #[must_use]
+    pub fn dashes<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().dashes = value.into();
+        self
+    }
+    /// Set the `arc_mode` field of `value_list`.
+    #[must_use]
+    pub fn arc_mode<I>(mut self, value: I) -> Self where I: Into<Option<ArcMode>> {
+        self.value_list.to_mut().arc_mode = value.into();
+        self
     }
-}
-impl<'input> crate::x11_utils::VoidRequest for CreateGCRequest<'input> {
 }
 
 /// Auxiliary and optional information for the `change_gc` function
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangeGCAux {
     pub function: Option<GX>,
     pub plane_mask: Option<u32>,
@@ -17770,6 +22060,84 @@ impl ChangeGCAux {
         self.arc_mode = value.into();
         self
     }
+    /// Get the changes that are needed to go from `old` to `self`.
+    ///
+    /// The result contains all fields that are set in `self` and that are either unset in
+    /// `old` or set to a different value. This allows to only send values that changed.
+    #[must_use]
+    pub fn changes_from(&self, old: &Self) -> Self {
+        let mut result = Self::new();
+        if self.function.is_some() && self.function != old.function {
+            result.function = self.function;
+        }
+        if self.plane_mask.is_some() && self.plane_mask != old.plane_mask {
+            result.plane_mask = self.plane_mask;
+        }
+        if self.foreground.is_some() && self.foreground != old.foreground {
+            result.foreground = self.foreground;
+        }
+        if self.background.is_some() && self.background != old.background {
+            result.background = self.background;
+        }
+        if self.line_width.is_some() && self.line_width != old.line_width {
+            result.line_width = self.line_width;
+        }
+        if self.line_style.is_some() && self.line_style != old.line_style {
+            result.line_style = self.line_style;
+        }
+        if self.cap_style.is_some() && self.cap_style != old.cap_style {
+            result.cap_style = self.cap_style;
+        }
+        if self.join_style.is_some() && self.join_style != old.join_style {
+            result.join_style = self.join_style;
+        }
+        if self.fill_style.is_some() && self.fill_style != old.fill_style {
+            result.fill_style = self.fill_style;
+        }
+        if self.fill_rule.is_some() && self.fill_rule != old.fill_rule {
+            result.fill_rule = self.fill_rule;
+        }
+        if self.tile.is_some() && self.tile != old.tile {
+            result.tile = self.tile;
+        }
+        if self.stipple.is_some() && self.stipple != old.stipple {
+            result.stipple = self.stipple;
+        }
+        if self.tile_stipple_x_origin.is_some() && self.tile_stipple_x_origin != old.tile_stipple_x_origin {
+            result.tile_stipple_x_origin = self.tile_stipple_x_origin;
+        }
+        if self.tile_stipple_y_origin.is_some() && self.tile_stipple_y_origin != old.tile_stipple_y_origin {
+            result.tile_stipple_y_origin = self.tile_stipple_y_origin;
+        }
+        if self.font.is_some() && self.font != old.font {
+            result.font = self.font;
+        }
+        if self.subwindow_mode.is_some() && self.subwindow_mode != old.subwindow_mode {
+            result.subwindow_mode = self.subwindow_mode;
+        }
+        if self.graphics_exposures.is_some() && self.graphics_exposures != old.graphics_exposures {
+            result.graphics_exposures = self.graphics_exposures;
+        }
+        if self.clip_x_origin.is_some() && self.clip_x_origin != old.clip_x_origin {
+            result.clip_x_origin = self.clip_x_origin;
+        }
+        if self.clip_y_origin.is_some() && self.clip_y_origin != old.clip_y_origin {
+            result.clip_y_origin = self.clip_y_origin;
+        }
+        if self.clip_mask.is_some() && self.clip_mask != old.clip_mask {
+            result.clip_mask = self.clip_mask;
+        }
+        if self.dash_offset.is_some() && self.dash_offset != old.dash_offset {
+            result.dash_offset = self.dash_offset;
+        }
+        if self.dashes.is_some() && self.dashes != old.dashes {
+            result.dashes = self.dashes;
+        }
+        if self.arc_mode.is_some() && self.arc_mode != old.arc_mode {
+            result.arc_mode = self.arc_mode;
+        }
+        result
+    }
 }
 
 /// Opcode for the ChangeGC request
@@ -17821,8 +22189,13 @@ pub const CHANGE_GC_REQUEST: u8 = 56;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangeGCRequest<'input> {
+    /// The graphics context to change.
     pub gc: Gcontext,
+    /// Values for each of the components specified in the bitmask `value_mask`. The
+    /// order has to correspond to the order of possible `value_mask` bits. See the
+    /// example.
     pub value_list: Cow<'input, ChangeGCAux>,
 }
 impl_debug_if_no_extra_traits!(ChangeGCRequest<'_>, "ChangeGCRequest");
@@ -17892,15 +22265,203 @@ impl<'input> Request for ChangeGCRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let gc_bytes = self.gc.serialize();
+        let value_mask: u32 = self.value_list.switch_expr();
+        let value_mask_bytes = value_mask.serialize();
+        buffer.extend_from_slice(&[
+            CHANGE_GC_REQUEST,
+            0,
+            0,
+            0,
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            value_mask_bytes[0],
+            value_mask_bytes[1],
+            value_mask_bytes[2],
+            value_mask_bytes[3],
+        ]);
+        self.value_list.serialize_into(buffer, u32::from(value_mask));
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for ChangeGCRequest<'input> {
 }
+impl ChangeGCRequest<'static> {
+    /// Create a new request with all fields of `value_list` unset.
+    ///
+    /// The optional fields can then be set with the builder methods of this request.
+    pub fn new(gc: Gcontext) -> Self {
+        ChangeGCRequest {
+            gc,
+            value_list: Cow::Owned(ChangeGCAux::new()),
+        }
+    }
+}
+impl<'input> ChangeGCRequest<'input> {
+    /// Set the `function` field of `value_list`.
+    #[must_use]
+    pub fn function<I>(mut self, value: I) -> Self where I: Into<Option<GX>> {
+        self.value_list.to_mut().function = value.into();
+        self
+    }
+    /// Set the `plane_mask` field of `value_list`.
+    #[must_use]
+    pub fn plane_mask<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().plane_mask = value.into();
+        self
+    }
+    /// Set the `foreground` field of `value_list`.
+    #[must_use]
+    pub fn foreground<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().foreground = value.into();
+        self
+    }
+    /// Set the `background` field of `value_list`.
+    #[must_use]
+    pub fn background<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().background = value.into();
+        self
+    }
+    /// Set the `line_width` field of `value_list`.
+    #[must_use]
+    pub fn line_width<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().line_width = value.into();
+        self
+    }
+    /// Set the `line_style` field of `value_list`.
+    #[must_use]
+    pub fn line_style<I>(mut self, value: I) -> Self where I: Into<Option<LineStyle>> {
+        self.value_list.to_mut().line_style = value.into();
+        self
+    }
+    /// Set the `cap_style` field of `value_list`.
+    #[must_use]
+    pub fn cap_style<I>(mut self, value: I) -> Self where I: Into<Option<CapStyle>> {
+        self.value_list.to_mut().cap_style = value.into();
+        self
+    }
+    /// Set the `join_style` field of `value_list`.
+    #[must_use]
+    pub fn join_style<I>(mut self, value: I) -> Self where I: Into<Option<JoinStyle>> {
+        self.value_list.to_mut().join_style = value.into();
+        self
+    }
+    /// Set the `fill_style` field of `value_list`.
+    #[must_use]
+    pub fn fill_style<I>(mut self, value: I) -> Self where I: Into<Option<FillStyle>> {
+        self.value_list.to_mut().fill_style = value.into();
+        self
+    }
+    /// Set the `fill_rule` field of `value_list`.
+    #[must_use]
+    pub fn fill_rule<I>(mut self, value: I) -> Self where I: Into<Option<FillRule>> {
+        self.value_list.to_mut().fill_rule = value.into();
+        self
+    }
+    /// Set the `tile` field of `value_list`.
+    #[must_use]
+    pub fn tile<I>(mut self, value: I) -> Self where I: Into<Option<Pixmap>> {
+        self.value_list.to_mut().tile = value.into();
+        self
+    }
+    /// Set the `stipple` field of `value_list`.
+    #[must_use]
+    pub fn stipple<I>(mut self, value: I) -> Self where I: Into<Option<Pixmap>> {
+        self.value_list.to_mut().stipple = value.into();
+        self
+    }
+    /// Set the `tile_stipple_x_origin` field of `value_list`.
+    #[must_use]
+    pub fn tile_stipple_x_origin<I>(mut self, value: I) -> Self where I: Into<Option<i32>> {
+        self.value_list.to_mut().tile_stipple_x_origin = value.into();
+        self
+    }
+    /// Set the `tile_stipple_y_origin` field of `value_list`.
+    #[must_use]
+    pub fn tile_stipple_y_origin<I>(mut self, value: I) -> Self where I: Into<Option<i32>> {
+        self.value_list.to_mut().tile_stipple_y_origin = value.into();
+        self
+    }
+    /// Set the `font` field of `value_list`.
+    #[must_use]
+    pub fn font<I>(mut self, value: I) -> Self where I: Into<Option<Font>> {
+        self.value_list.to_mut().font = value.into();
+        self
+    }
+    /// Set the `subwindow_mode` field of `value_list`.
+    #[must_use]
+    pub fn subwindow_mode<I>(mut self, value: I) -> Self where I: Into<Option<SubwindowMode>> {
+        self.value_list.to_mut().subwindow_mode = value.into();
+        self
+    }
+    /// Set the `graphics_exposures` field of `value_list`.
+    #[must_use]
+    pub fn graphics_exposures<I>(mut self, value: I) -> Self where I: Into<Option<Bool32>> {
+        self.value_list.to_mut().graphics_exposures = value.into();
+        self
+    }
+    /// Set the `clip_x_origin` field of `value_list`.
+    #[must_use]
+    pub fn clip_x_origin<I>(mut self, value: I) -> Self where I: Into<Option<i32>> {
+        self.value_list.to_mut().clip_x_origin = value.into();
+        self
+    }
+    /// Set the `clip_y_origin` field of `value_list`.
+    #[must_use]
+    pub fn clip_y_origin<I>(mut self, value: I) -> Self where I: Into<Option<i32>> {
+        self.value_list.to_mut().clip_y_origin = value.into();
+        self
+    }
+    /// Set the `clip_mask` field of `value_list`.
+    #[must_use]
+    pub fn clip_mask<I>(mut self, value: I) -> Self where I: Into<Option<Pixmap>> {
+        self.value_list.to_mut().clip_mask = value.into();
+        self
+    }
+    /// Set the `dash_offset` field of `value_list`.
+    #[must_use]
+    pub fn dash_offset<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().dash_offset = value.into();
+        self
+    }
+    /// Set the `dashes` field of `value_list`.
+    #[must_use]
+    pub fn dashes<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().dashes = value.into();
+        self
+    }
+    /// Set the `arc_mode` field of `value_list`.
+    #[must_use]
+    pub fn arc_mode<I>(mut self, value: I) -> Self where I: Into<Option<ArcMode>> {
+        self.value_list.to_mut().arc_mode = value.into();
+        self
+    }
+}
 
 /// Opcode for the CopyGC request
 pub const COPY_GC_REQUEST: u8 = 57;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CopyGCRequest {
     pub src_gc: Gcontext,
     pub dst_gc: Gcontext,
@@ -17968,6 +22529,43 @@ impl Request for CopyGCRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let src_gc_bytes = self.src_gc.serialize();
+        let dst_gc_bytes = self.dst_gc.serialize();
+        let value_mask_bytes = u32::from(self.value_mask).serialize();
+        buffer.extend_from_slice(&[
+            COPY_GC_REQUEST,
+            0,
+            0,
+            0,
+            src_gc_bytes[0],
+            src_gc_bytes[1],
+            src_gc_bytes[2],
+            src_gc_bytes[3],
+            dst_gc_bytes[0],
+            dst_gc_bytes[1],
+            dst_gc_bytes[2],
+            dst_gc_bytes[3],
+            value_mask_bytes[0],
+            value_mask_bytes[1],
+            value_mask_bytes[2],
+            value_mask_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for CopyGCRequest {
 }
@@ -17977,6 +22575,7 @@ pub const SET_DASHES_REQUEST: u8 = 58;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetDashesRequest<'input> {
     pub gc: Gcontext,
     pub dash_offset: u16,
@@ -18052,12 +22651,50 @@ impl<'input> Request for SetDashesRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let gc_bytes = self.gc.serialize();
+        let dash_offset_bytes = self.dash_offset.serialize();
+        let dashes_len = u16::try_from(self.dashes.len()).expect("`dashes` has too many elements");
+        let dashes_len_bytes = dashes_len.serialize();
+        buffer.extend_from_slice(&[
+            SET_DASHES_REQUEST,
+            0,
+            0,
+            0,
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            dash_offset_bytes[0],
+            dash_offset_bytes[1],
+            dashes_len_bytes[0],
+            dashes_len_bytes[1],
+        ]);
+        buffer.extend_from_slice(&self.dashes[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for SetDashesRequest<'input> {
 }
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ClipOrdering(u8);
 impl ClipOrdering {
     pub const UNSORTED: Self = Self(0);
@@ -18118,12 +22755,30 @@ impl core::fmt::Debug for ClipOrdering  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl ClipOrdering {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Unsorted"),
+            1 => Some("YSorted"),
+            2 => Some("YXSorted"),
+            3 => Some("YXBanded"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ClipOrdering {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the SetClipRectangles request
 pub const SET_CLIP_RECTANGLES_REQUEST: u8 = 59;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetClipRectanglesRequest<'input> {
     pub ordering: ClipOrdering,
     pub gc: Gcontext,
@@ -18179,7 +22834,7 @@ impl<'input> SetClipRectanglesRequest<'input> {
         let (clip_y_origin, remaining) = i16::try_parse(remaining)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut rectangles = Vec::new();
+        let mut rectangles = Vec::with_capacity(remaining.len() / 8);
         while !remaining.is_empty() {
             let (v, new_remaining) = Rectangle::try_parse(remaining)?;
             remaining = new_remaining;
@@ -18204,15 +22859,52 @@ impl<'input> SetClipRectanglesRequest<'input> {
             rectangles: Cow::Owned(self.rectangles.into_owned()),
         }
     }
-}
-impl<'input> Request for SetClipRectanglesRequest<'input> {
-    const EXTENSION_NAME: Option<&'static str> = None;
-
-    fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
+}
+impl<'input> Request for SetClipRectanglesRequest<'input> {
+    const EXTENSION_NAME: Option<&'static str> = None;
+
+    fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
+        let (bufs, fds) = self.serialize();
+        // Flatten the buffers into a single vector
+        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
+        (buf, fds)
+    }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let ordering_bytes = u8::from(self.ordering).serialize();
+        let gc_bytes = self.gc.serialize();
+        let clip_x_origin_bytes = self.clip_x_origin.serialize();
+        let clip_y_origin_bytes = self.clip_y_origin.serialize();
+        buffer.extend_from_slice(&[
+            SET_CLIP_RECTANGLES_REQUEST,
+            ordering_bytes[0],
+            0,
+            0,
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            clip_x_origin_bytes[0],
+            clip_x_origin_bytes[1],
+            clip_y_origin_bytes[0],
+            clip_y_origin_bytes[1],
+        ]);
+        self.rectangles.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
         let (bufs, fds) = self.serialize();
-        // Flatten the buffers into a single vector
-        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
-        (buf, fds)
+        slices.extend(bufs);
+        fds
     }
 }
 impl<'input> crate::x11_utils::VoidRequest for SetClipRectanglesRequest<'input> {
@@ -18234,7 +22926,9 @@ pub const FREE_GC_REQUEST: u8 = 60;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct FreeGCRequest {
+    /// The graphics context to destroy.
     pub gc: Gcontext,
 }
 impl_debug_if_no_extra_traits!(FreeGCRequest, "FreeGCRequest");
@@ -18284,6 +22978,33 @@ impl Request for FreeGCRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let gc_bytes = self.gc.serialize();
+        buffer.extend_from_slice(&[
+            FREE_GC_REQUEST,
+            0,
+            0,
+            0,
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for FreeGCRequest {
 }
@@ -18293,6 +23014,7 @@ pub const CLEAR_AREA_REQUEST: u8 = 61;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ClearAreaRequest {
     pub exposures: bool,
     pub window: Window,
@@ -18370,6 +23092,46 @@ impl Request for ClearAreaRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let exposures_bytes = self.exposures.serialize();
+        let window_bytes = self.window.serialize();
+        let x_bytes = self.x.serialize();
+        let y_bytes = self.y.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        buffer.extend_from_slice(&[
+            CLEAR_AREA_REQUEST,
+            exposures_bytes[0],
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            x_bytes[0],
+            x_bytes[1],
+            y_bytes[0],
+            y_bytes[1],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for ClearAreaRequest {
 }
@@ -18400,15 +23162,25 @@ pub const COPY_AREA_REQUEST: u8 = 62;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CopyAreaRequest {
+    /// The source drawable (Window or Pixmap).
     pub src_drawable: Drawable,
+    /// The destination drawable (Window or Pixmap).
     pub dst_drawable: Drawable,
+    /// The graphics context to use.
     pub gc: Gcontext,
+    /// The source X coordinate.
     pub src_x: i16,
+    /// The source Y coordinate.
     pub src_y: i16,
+    /// The destination X coordinate.
     pub dst_x: i16,
+    /// The destination Y coordinate.
     pub dst_y: i16,
+    /// The width of the area to copy (in pixels).
     pub width: u16,
+    /// The height of the area to copy (in pixels).
     pub height: u16,
 }
 impl_debug_if_no_extra_traits!(CopyAreaRequest, "CopyAreaRequest");
@@ -18502,6 +23274,61 @@ impl Request for CopyAreaRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let src_drawable_bytes = self.src_drawable.serialize();
+        let dst_drawable_bytes = self.dst_drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        let src_x_bytes = self.src_x.serialize();
+        let src_y_bytes = self.src_y.serialize();
+        let dst_x_bytes = self.dst_x.serialize();
+        let dst_y_bytes = self.dst_y.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        buffer.extend_from_slice(&[
+            COPY_AREA_REQUEST,
+            0,
+            0,
+            0,
+            src_drawable_bytes[0],
+            src_drawable_bytes[1],
+            src_drawable_bytes[2],
+            src_drawable_bytes[3],
+            dst_drawable_bytes[0],
+            dst_drawable_bytes[1],
+            dst_drawable_bytes[2],
+            dst_drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            src_x_bytes[0],
+            src_x_bytes[1],
+            src_y_bytes[0],
+            src_y_bytes[1],
+            dst_x_bytes[0],
+            dst_x_bytes[1],
+            dst_y_bytes[0],
+            dst_y_bytes[1],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for CopyAreaRequest {
 }
@@ -18511,6 +23338,7 @@ pub const COPY_PLANE_REQUEST: u8 = 63;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CopyPlaneRequest {
     pub src_drawable: Drawable,
     pub dst_drawable: Drawable,
@@ -18621,6 +23449,66 @@ impl Request for CopyPlaneRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let src_drawable_bytes = self.src_drawable.serialize();
+        let dst_drawable_bytes = self.dst_drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        let src_x_bytes = self.src_x.serialize();
+        let src_y_bytes = self.src_y.serialize();
+        let dst_x_bytes = self.dst_x.serialize();
+        let dst_y_bytes = self.dst_y.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        let bit_plane_bytes = self.bit_plane.serialize();
+        buffer.extend_from_slice(&[
+            COPY_PLANE_REQUEST,
+            0,
+            0,
+            0,
+            src_drawable_bytes[0],
+            src_drawable_bytes[1],
+            src_drawable_bytes[2],
+            src_drawable_bytes[3],
+            dst_drawable_bytes[0],
+            dst_drawable_bytes[1],
+            dst_drawable_bytes[2],
+            dst_drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            src_x_bytes[0],
+            src_x_bytes[1],
+            src_y_bytes[0],
+            src_y_bytes[1],
+            dst_x_bytes[0],
+            dst_x_bytes[1],
+            dst_y_bytes[0],
+            dst_y_bytes[1],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+            bit_plane_bytes[0],
+            bit_plane_bytes[1],
+            bit_plane_bytes[2],
+            bit_plane_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for CopyPlaneRequest {
 }
@@ -18631,6 +23519,7 @@ impl crate::x11_utils::VoidRequest for CopyPlaneRequest {
 /// * `Previous` - Treats all coordinates after the first as relative to the previous coordinate.
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CoordMode(u8);
 impl CoordMode {
     pub const ORIGIN: Self = Self(0);
@@ -18687,12 +23576,28 @@ impl core::fmt::Debug for CoordMode  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl CoordMode {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Origin"),
+            1 => Some("Previous"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for CoordMode {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the PolyPoint request
 pub const POLY_POINT_REQUEST: u8 = 64;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PolyPointRequest<'input> {
     pub coordinate_mode: CoordMode,
     pub drawable: Drawable,
@@ -18745,7 +23650,7 @@ impl<'input> PolyPointRequest<'input> {
         let (gc, remaining) = Gcontext::try_parse(remaining)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut points = Vec::new();
+        let mut points = Vec::with_capacity(remaining.len() / 4);
         while !remaining.is_empty() {
             let (v, new_remaining) = Point::try_parse(remaining)?;
             remaining = new_remaining;
@@ -18778,6 +23683,42 @@ impl<'input> Request for PolyPointRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let coordinate_mode_bytes = u8::from(self.coordinate_mode).serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        buffer.extend_from_slice(&[
+            POLY_POINT_REQUEST,
+            coordinate_mode_bytes[0],
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+        ]);
+        self.points.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for PolyPointRequest<'input> {
 }
@@ -18826,10 +23767,14 @@ pub const POLY_LINE_REQUEST: u8 = 65;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PolyLineRequest<'input> {
     pub coordinate_mode: CoordMode,
+    /// The drawable to draw the line(s) on.
     pub drawable: Drawable,
+    /// The graphics context to use.
     pub gc: Gcontext,
+    /// An array of points.
     pub points: Cow<'input, [Point]>,
 }
 impl_debug_if_no_extra_traits!(PolyLineRequest<'_>, "PolyLineRequest");
@@ -18878,7 +23823,7 @@ impl<'input> PolyLineRequest<'input> {
         let (gc, remaining) = Gcontext::try_parse(remaining)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut points = Vec::new();
+        let mut points = Vec::with_capacity(remaining.len() / 4);
         while !remaining.is_empty() {
             let (v, new_remaining) = Point::try_parse(remaining)?;
             remaining = new_remaining;
@@ -18911,6 +23856,42 @@ impl<'input> Request for PolyLineRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let coordinate_mode_bytes = u8::from(self.coordinate_mode).serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        buffer.extend_from_slice(&[
+            POLY_LINE_REQUEST,
+            coordinate_mode_bytes[0],
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+        ]);
+        self.points.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for PolyLineRequest<'input> {
 }
@@ -18925,6 +23906,12 @@ pub struct Segment {
     pub y2: i16,
 }
 impl_debug_if_no_extra_traits!(Segment, "Segment");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Segment {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Segment {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (x1, remaining) = i16::try_parse(remaining)?;
@@ -18993,9 +23980,15 @@ pub const POLY_SEGMENT_REQUEST: u8 = 66;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PolySegmentRequest<'input> {
+    /// A drawable (Window or Pixmap) to draw on.
     pub drawable: Drawable,
+    /// The graphics context to use.
+    ///
+    /// TODO: document which attributes of a gc are used
     pub gc: Gcontext,
+    /// An array of `xcb_segment_t` structures.
     pub segments: Cow<'input, [Segment]>,
 }
 impl_debug_if_no_extra_traits!(PolySegmentRequest<'_>, "PolySegmentRequest");
@@ -19042,7 +24035,7 @@ impl<'input> PolySegmentRequest<'input> {
         let (gc, remaining) = Gcontext::try_parse(remaining)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut segments = Vec::new();
+        let mut segments = Vec::with_capacity(remaining.len() / 8);
         while !remaining.is_empty() {
             let (v, new_remaining) = Segment::try_parse(remaining)?;
             remaining = new_remaining;
@@ -19073,6 +24066,41 @@ impl<'input> Request for PolySegmentRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        buffer.extend_from_slice(&[
+            POLY_SEGMENT_REQUEST,
+            0,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+        ]);
+        self.segments.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for PolySegmentRequest<'input> {
 }
@@ -19082,6 +24110,7 @@ pub const POLY_RECTANGLE_REQUEST: u8 = 67;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PolyRectangleRequest<'input> {
     pub drawable: Drawable,
     pub gc: Gcontext,
@@ -19131,7 +24160,7 @@ impl<'input> PolyRectangleRequest<'input> {
         let (gc, remaining) = Gcontext::try_parse(remaining)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut rectangles = Vec::new();
+        let mut rectangles = Vec::with_capacity(remaining.len() / 8);
         while !remaining.is_empty() {
             let (v, new_remaining) = Rectangle::try_parse(remaining)?;
             remaining = new_remaining;
@@ -19162,6 +24191,41 @@ impl<'input> Request for PolyRectangleRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        buffer.extend_from_slice(&[
+            POLY_RECTANGLE_REQUEST,
+            0,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+        ]);
+        self.rectangles.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for PolyRectangleRequest<'input> {
 }
@@ -19171,6 +24235,7 @@ pub const POLY_ARC_REQUEST: u8 = 68;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PolyArcRequest<'input> {
     pub drawable: Drawable,
     pub gc: Gcontext,
@@ -19220,7 +24285,7 @@ impl<'input> PolyArcRequest<'input> {
         let (gc, remaining) = Gcontext::try_parse(remaining)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut arcs = Vec::new();
+        let mut arcs = Vec::with_capacity(remaining.len() / 12);
         while !remaining.is_empty() {
             let (v, new_remaining) = Arc::try_parse(remaining)?;
             remaining = new_remaining;
@@ -19247,9 +24312,44 @@ impl<'input> Request for PolyArcRequest<'input> {
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
-        // Flatten the buffers into a single vector
-        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
-        (buf, fds)
+        // Flatten the buffers into a single vector
+        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
+        (buf, fds)
+    }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        buffer.extend_from_slice(&[
+            POLY_ARC_REQUEST,
+            0,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+        ]);
+        self.arcs.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
     }
 }
 impl<'input> crate::x11_utils::VoidRequest for PolyArcRequest<'input> {
@@ -19257,6 +24357,7 @@ impl<'input> crate::x11_utils::VoidRequest for PolyArcRequest<'input> {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PolyShape(u8);
 impl PolyShape {
     pub const COMPLEX: Self = Self(0);
@@ -19315,12 +24416,29 @@ impl core::fmt::Debug for PolyShape  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl PolyShape {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Complex"),
+            1 => Some("Nonconvex"),
+            2 => Some("Convex"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for PolyShape {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the FillPoly request
 pub const FILL_POLY_REQUEST: u8 = 69;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct FillPolyRequest<'input> {
     pub drawable: Drawable,
     pub gc: Gcontext,
@@ -19383,7 +24501,7 @@ impl<'input> FillPolyRequest<'input> {
         let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut points = Vec::new();
+        let mut points = Vec::with_capacity(remaining.len() / 4);
         while !remaining.is_empty() {
             let (v, new_remaining) = Point::try_parse(remaining)?;
             remaining = new_remaining;
@@ -19418,6 +24536,47 @@ impl<'input> Request for FillPolyRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        let shape_bytes = u8::from(self.shape).serialize();
+        let coordinate_mode_bytes = u8::from(self.coordinate_mode).serialize();
+        buffer.extend_from_slice(&[
+            FILL_POLY_REQUEST,
+            0,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            shape_bytes[0],
+            coordinate_mode_bytes[0],
+            0,
+            0,
+        ]);
+        self.points.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for FillPolyRequest<'input> {
 }
@@ -19452,9 +24611,20 @@ pub const POLY_FILL_RECTANGLE_REQUEST: u8 = 70;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PolyFillRectangleRequest<'input> {
+    /// The drawable (Window or Pixmap) to draw on.
     pub drawable: Drawable,
+    /// The graphics context to use.
+    ///
+    /// The following graphics context components are used: function, plane-mask,
+    /// fill-style, subwindow-mode, clip-x-origin, clip-y-origin, and clip-mask.
+    ///
+    /// The following graphics context mode-dependent components are used:
+    /// foreground, background, tile, stipple, tile-stipple-x-origin, and
+    /// tile-stipple-y-origin.
     pub gc: Gcontext,
+    /// The rectangles to fill.
     pub rectangles: Cow<'input, [Rectangle]>,
 }
 impl_debug_if_no_extra_traits!(PolyFillRectangleRequest<'_>, "PolyFillRectangleRequest");
@@ -19501,7 +24671,7 @@ impl<'input> PolyFillRectangleRequest<'input> {
         let (gc, remaining) = Gcontext::try_parse(remaining)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut rectangles = Vec::new();
+        let mut rectangles = Vec::with_capacity(remaining.len() / 8);
         while !remaining.is_empty() {
             let (v, new_remaining) = Rectangle::try_parse(remaining)?;
             remaining = new_remaining;
@@ -19532,6 +24702,41 @@ impl<'input> Request for PolyFillRectangleRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        buffer.extend_from_slice(&[
+            POLY_FILL_RECTANGLE_REQUEST,
+            0,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+        ]);
+        self.rectangles.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for PolyFillRectangleRequest<'input> {
 }
@@ -19541,6 +24746,7 @@ pub const POLY_FILL_ARC_REQUEST: u8 = 71;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PolyFillArcRequest<'input> {
     pub drawable: Drawable,
     pub gc: Gcontext,
@@ -19590,7 +24796,7 @@ impl<'input> PolyFillArcRequest<'input> {
         let (gc, remaining) = Gcontext::try_parse(remaining)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut arcs = Vec::new();
+        let mut arcs = Vec::with_capacity(remaining.len() / 12);
         while !remaining.is_empty() {
             let (v, new_remaining) = Arc::try_parse(remaining)?;
             remaining = new_remaining;
@@ -19621,12 +24827,48 @@ impl<'input> Request for PolyFillArcRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        buffer.extend_from_slice(&[
+            POLY_FILL_ARC_REQUEST,
+            0,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+        ]);
+        self.arcs.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for PolyFillArcRequest<'input> {
 }
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ImageFormat(u8);
 impl ImageFormat {
     pub const XY_BITMAP: Self = Self(0);
@@ -19685,12 +24927,29 @@ impl core::fmt::Debug for ImageFormat  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl ImageFormat {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("XYBitmap"),
+            1 => Some("XYPixmap"),
+            2 => Some("ZPixmap"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ImageFormat {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the PutImage request
 pub const PUT_IMAGE_REQUEST: u8 = 72;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PutImageRequest<'input> {
     pub format: ImageFormat,
     pub drawable: Drawable,
@@ -19811,6 +25070,60 @@ impl<'input> Request for PutImageRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let format_bytes = u8::from(self.format).serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        let dst_x_bytes = self.dst_x.serialize();
+        let dst_y_bytes = self.dst_y.serialize();
+        let left_pad_bytes = self.left_pad.serialize();
+        let depth_bytes = self.depth.serialize();
+        buffer.extend_from_slice(&[
+            PUT_IMAGE_REQUEST,
+            format_bytes[0],
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+            dst_x_bytes[0],
+            dst_x_bytes[1],
+            dst_y_bytes[0],
+            dst_y_bytes[1],
+            left_pad_bytes[0],
+            depth_bytes[0],
+            0,
+            0,
+        ]);
+        buffer.extend_from_slice(&self.data[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for PutImageRequest<'input> {
 }
@@ -19820,6 +25133,7 @@ pub const GET_IMAGE_REQUEST: u8 = 73;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetImageRequest {
     pub format: ImageFormat,
     pub drawable: Drawable,
@@ -19906,6 +25220,51 @@ impl Request for GetImageRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let format_bytes = u8::from(self.format).serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let x_bytes = self.x.serialize();
+        let y_bytes = self.y.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        let plane_mask_bytes = self.plane_mask.serialize();
+        buffer.extend_from_slice(&[
+            GET_IMAGE_REQUEST,
+            format_bytes[0],
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            x_bytes[0],
+            x_bytes[1],
+            y_bytes[0],
+            y_bytes[1],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+            plane_mask_bytes[0],
+            plane_mask_bytes[1],
+            plane_mask_bytes[2],
+            plane_mask_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetImageRequest {
     type Reply = GetImageReply;
@@ -19921,6 +25280,12 @@ pub struct GetImageReply {
     pub data: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(GetImageReply, "GetImageReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetImageReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetImageReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -19961,6 +25326,7 @@ impl Serialize for GetImageReply {
         self.visual.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 20]);
         bytes.extend_from_slice(&self.data);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetImageReply {
@@ -19985,6 +25351,7 @@ pub const POLY_TEXT8_REQUEST: u8 = 74;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PolyText8Request<'input> {
     pub drawable: Drawable,
     pub gc: Gcontext,
@@ -20071,6 +25438,47 @@ impl<'input> Request for PolyText8Request<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        let x_bytes = self.x.serialize();
+        let y_bytes = self.y.serialize();
+        buffer.extend_from_slice(&[
+            POLY_TEXT8_REQUEST,
+            0,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            x_bytes[0],
+            x_bytes[1],
+            y_bytes[0],
+            y_bytes[1],
+        ]);
+        buffer.extend_from_slice(&self.items[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for PolyText8Request<'input> {
 }
@@ -20080,6 +25488,7 @@ pub const POLY_TEXT16_REQUEST: u8 = 75;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PolyText16Request<'input> {
     pub drawable: Drawable,
     pub gc: Gcontext,
@@ -20166,6 +25575,47 @@ impl<'input> Request for PolyText16Request<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        let x_bytes = self.x.serialize();
+        let y_bytes = self.y.serialize();
+        buffer.extend_from_slice(&[
+            POLY_TEXT16_REQUEST,
+            0,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            x_bytes[0],
+            x_bytes[1],
+            y_bytes[0],
+            y_bytes[1],
+        ]);
+        buffer.extend_from_slice(&self.items[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for PolyText16Request<'input> {
 }
@@ -20207,11 +25657,21 @@ pub const IMAGE_TEXT8_REQUEST: u8 = 76;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ImageText8Request<'input> {
+    /// The drawable (Window or Pixmap) to draw text on.
     pub drawable: Drawable,
+    /// The graphics context to use.
+    ///
+    /// The following graphics context components are used: plane-mask, foreground,
+    /// background, font, subwindow-mode, clip-x-origin, clip-y-origin, and clip-mask.
     pub gc: Gcontext,
+    /// The x coordinate of the first character, relative to the origin of `drawable`.
     pub x: i16,
+    /// The y coordinate of the first character, relative to the origin of `drawable`.
     pub y: i16,
+    /// The string to draw. Only the first 255 characters are relevant due to the data
+    /// type of `string_len`.
     pub string: Cow<'input, [u8]>,
 }
 impl_debug_if_no_extra_traits!(ImageText8Request<'_>, "ImageText8Request");
@@ -20295,6 +25755,49 @@ impl<'input> Request for ImageText8Request<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let string_len = u8::try_from(self.string.len()).expect("`string` has too many elements");
+        let string_len_bytes = string_len.serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        let x_bytes = self.x.serialize();
+        let y_bytes = self.y.serialize();
+        buffer.extend_from_slice(&[
+            IMAGE_TEXT8_REQUEST,
+            string_len_bytes[0],
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            x_bytes[0],
+            x_bytes[1],
+            y_bytes[0],
+            y_bytes[1],
+        ]);
+        buffer.extend_from_slice(&self.string[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for ImageText8Request<'input> {
 }
@@ -20337,11 +25840,22 @@ pub const IMAGE_TEXT16_REQUEST: u8 = 77;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ImageText16Request<'input> {
+    /// The drawable (Window or Pixmap) to draw text on.
     pub drawable: Drawable,
+    /// The graphics context to use.
+    ///
+    /// The following graphics context components are used: plane-mask, foreground,
+    /// background, font, subwindow-mode, clip-x-origin, clip-y-origin, and clip-mask.
     pub gc: Gcontext,
+    /// The x coordinate of the first character, relative to the origin of `drawable`.
     pub x: i16,
+    /// The y coordinate of the first character, relative to the origin of `drawable`.
     pub y: i16,
+    /// The string to draw. Only the first 255 characters are relevant due to the data
+    /// type of `string_len`. Every character uses 2 bytes (hence the 16 in this
+    /// request's name).
     pub string: Cow<'input, [Char2b]>,
 }
 impl_debug_if_no_extra_traits!(ImageText16Request<'_>, "ImageText16Request");
@@ -20416,15 +25930,58 @@ impl<'input> ImageText16Request<'input> {
             string: Cow::Owned(self.string.into_owned()),
         }
     }
-}
-impl<'input> Request for ImageText16Request<'input> {
-    const EXTENSION_NAME: Option<&'static str> = None;
-
-    fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
+}
+impl<'input> Request for ImageText16Request<'input> {
+    const EXTENSION_NAME: Option<&'static str> = None;
+
+    fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
+        let (bufs, fds) = self.serialize();
+        // Flatten the buffers into a single vector
+        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
+        (buf, fds)
+    }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let string_len = u8::try_from(self.string.len()).expect("`string` has too many elements");
+        let string_len_bytes = string_len.serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        let x_bytes = self.x.serialize();
+        let y_bytes = self.y.serialize();
+        buffer.extend_from_slice(&[
+            IMAGE_TEXT16_REQUEST,
+            string_len_bytes[0],
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            x_bytes[0],
+            x_bytes[1],
+            y_bytes[0],
+            y_bytes[1],
+        ]);
+        self.string.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
         let (bufs, fds) = self.serialize();
-        // Flatten the buffers into a single vector
-        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
-        (buf, fds)
+        slices.extend(bufs);
+        fds
     }
 }
 impl<'input> crate::x11_utils::VoidRequest for ImageText16Request<'input> {
@@ -20432,6 +25989,7 @@ impl<'input> crate::x11_utils::VoidRequest for ImageText16Request<'input> {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ColormapAlloc(u8);
 impl ColormapAlloc {
     pub const NONE: Self = Self(0);
@@ -20488,12 +26046,28 @@ impl core::fmt::Debug for ColormapAlloc  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl ColormapAlloc {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("None"),
+            1 => Some("All"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ColormapAlloc {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the CreateColormap request
 pub const CREATE_COLORMAP_REQUEST: u8 = 78;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateColormapRequest {
     pub alloc: ColormapAlloc,
     pub mid: Colormap,
@@ -20564,6 +26138,44 @@ impl Request for CreateColormapRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let alloc_bytes = u8::from(self.alloc).serialize();
+        let mid_bytes = self.mid.serialize();
+        let window_bytes = self.window.serialize();
+        let visual_bytes = self.visual.serialize();
+        buffer.extend_from_slice(&[
+            CREATE_COLORMAP_REQUEST,
+            alloc_bytes[0],
+            0,
+            0,
+            mid_bytes[0],
+            mid_bytes[1],
+            mid_bytes[2],
+            mid_bytes[3],
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            visual_bytes[0],
+            visual_bytes[1],
+            visual_bytes[2],
+            visual_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for CreateColormapRequest {
 }
@@ -20573,6 +26185,7 @@ pub const FREE_COLORMAP_REQUEST: u8 = 79;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct FreeColormapRequest {
     pub cmap: Colormap,
 }
@@ -20623,6 +26236,33 @@ impl Request for FreeColormapRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let cmap_bytes = self.cmap.serialize();
+        buffer.extend_from_slice(&[
+            FREE_COLORMAP_REQUEST,
+            0,
+            0,
+            0,
+            cmap_bytes[0],
+            cmap_bytes[1],
+            cmap_bytes[2],
+            cmap_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for FreeColormapRequest {
 }
@@ -20632,6 +26272,7 @@ pub const COPY_COLORMAP_AND_FREE_REQUEST: u8 = 80;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CopyColormapAndFreeRequest {
     pub mid: Colormap,
     pub src_cmap: Colormap,
@@ -20690,6 +26331,38 @@ impl Request for CopyColormapAndFreeRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let mid_bytes = self.mid.serialize();
+        let src_cmap_bytes = self.src_cmap.serialize();
+        buffer.extend_from_slice(&[
+            COPY_COLORMAP_AND_FREE_REQUEST,
+            0,
+            0,
+            0,
+            mid_bytes[0],
+            mid_bytes[1],
+            mid_bytes[2],
+            mid_bytes[3],
+            src_cmap_bytes[0],
+            src_cmap_bytes[1],
+            src_cmap_bytes[2],
+            src_cmap_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for CopyColormapAndFreeRequest {
 }
@@ -20699,6 +26372,7 @@ pub const INSTALL_COLORMAP_REQUEST: u8 = 81;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct InstallColormapRequest {
     pub cmap: Colormap,
 }
@@ -20749,6 +26423,33 @@ impl Request for InstallColormapRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let cmap_bytes = self.cmap.serialize();
+        buffer.extend_from_slice(&[
+            INSTALL_COLORMAP_REQUEST,
+            0,
+            0,
+            0,
+            cmap_bytes[0],
+            cmap_bytes[1],
+            cmap_bytes[2],
+            cmap_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for InstallColormapRequest {
 }
@@ -20758,6 +26459,7 @@ pub const UNINSTALL_COLORMAP_REQUEST: u8 = 82;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct UninstallColormapRequest {
     pub cmap: Colormap,
 }
@@ -20808,6 +26510,33 @@ impl Request for UninstallColormapRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let cmap_bytes = self.cmap.serialize();
+        buffer.extend_from_slice(&[
+            UNINSTALL_COLORMAP_REQUEST,
+            0,
+            0,
+            0,
+            cmap_bytes[0],
+            cmap_bytes[1],
+            cmap_bytes[2],
+            cmap_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for UninstallColormapRequest {
 }
@@ -20817,6 +26546,7 @@ pub const LIST_INSTALLED_COLORMAPS_REQUEST: u8 = 83;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ListInstalledColormapsRequest {
     pub window: Window,
 }
@@ -20867,6 +26597,33 @@ impl Request for ListInstalledColormapsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        buffer.extend_from_slice(&[
+            LIST_INSTALLED_COLORMAPS_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for ListInstalledColormapsRequest {
     type Reply = ListInstalledColormapsReply;
@@ -20881,6 +26638,12 @@ pub struct ListInstalledColormapsReply {
     pub cmaps: Vec<Colormap>,
 }
 impl_debug_if_no_extra_traits!(ListInstalledColormapsReply, "ListInstalledColormapsReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ListInstalledColormapsReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ListInstalledColormapsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -20919,6 +26682,7 @@ impl Serialize for ListInstalledColormapsReply {
         cmaps_len.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 22]);
         self.cmaps.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl ListInstalledColormapsReply {
@@ -20960,10 +26724,15 @@ pub const ALLOC_COLOR_REQUEST: u8 = 84;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct AllocColorRequest {
+    /// TODO
     pub cmap: Colormap,
+    /// The red value of your color.
     pub red: u16,
+    /// The green value of your color.
     pub green: u16,
+    /// The blue value of your color.
     pub blue: u16,
 }
 impl_debug_if_no_extra_traits!(AllocColorRequest, "AllocColorRequest");
@@ -21031,6 +26800,44 @@ impl Request for AllocColorRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let cmap_bytes = self.cmap.serialize();
+        let red_bytes = self.red.serialize();
+        let green_bytes = self.green.serialize();
+        let blue_bytes = self.blue.serialize();
+        buffer.extend_from_slice(&[
+            ALLOC_COLOR_REQUEST,
+            0,
+            0,
+            0,
+            cmap_bytes[0],
+            cmap_bytes[1],
+            cmap_bytes[2],
+            cmap_bytes[3],
+            red_bytes[0],
+            red_bytes[1],
+            green_bytes[0],
+            green_bytes[1],
+            blue_bytes[0],
+            blue_bytes[1],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for AllocColorRequest {
     type Reply = AllocColorReply;
@@ -21048,6 +26855,12 @@ pub struct AllocColorReply {
     pub pixel: u32,
 }
 impl_debug_if_no_extra_traits!(AllocColorReply, "AllocColorReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for AllocColorReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for AllocColorReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -21123,6 +26936,7 @@ pub const ALLOC_NAMED_COLOR_REQUEST: u8 = 85;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct AllocNamedColorRequest<'input> {
     pub cmap: Colormap,
     pub name: Cow<'input, [u8]>,
@@ -21194,6 +27008,42 @@ impl<'input> Request for AllocNamedColorRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let cmap_bytes = self.cmap.serialize();
+        let name_len = u16::try_from(self.name.len()).expect("`name` has too many elements");
+        let name_len_bytes = name_len.serialize();
+        buffer.extend_from_slice(&[
+            ALLOC_NAMED_COLOR_REQUEST,
+            0,
+            0,
+            0,
+            cmap_bytes[0],
+            cmap_bytes[1],
+            cmap_bytes[2],
+            cmap_bytes[3],
+            name_len_bytes[0],
+            name_len_bytes[1],
+            0,
+            0,
+        ]);
+        buffer.extend_from_slice(&self.name[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::ReplyRequest for AllocNamedColorRequest<'input> {
     type Reply = AllocNamedColorReply;
@@ -21214,6 +27064,12 @@ pub struct AllocNamedColorReply {
     pub visual_blue: u16,
 }
 impl_debug_if_no_extra_traits!(AllocNamedColorReply, "AllocNamedColorReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for AllocNamedColorReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for AllocNamedColorReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -21300,6 +27156,7 @@ pub const ALLOC_COLOR_CELLS_REQUEST: u8 = 86;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct AllocColorCellsRequest {
     pub contiguous: bool,
     pub cmap: Colormap,
@@ -21365,6 +27222,40 @@ impl Request for AllocColorCellsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let contiguous_bytes = self.contiguous.serialize();
+        let cmap_bytes = self.cmap.serialize();
+        let colors_bytes = self.colors.serialize();
+        let planes_bytes = self.planes.serialize();
+        buffer.extend_from_slice(&[
+            ALLOC_COLOR_CELLS_REQUEST,
+            contiguous_bytes[0],
+            0,
+            0,
+            cmap_bytes[0],
+            cmap_bytes[1],
+            cmap_bytes[2],
+            cmap_bytes[3],
+            colors_bytes[0],
+            colors_bytes[1],
+            planes_bytes[0],
+            planes_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for AllocColorCellsRequest {
     type Reply = AllocColorCellsReply;
@@ -21380,6 +27271,12 @@ pub struct AllocColorCellsReply {
     pub masks: Vec<u32>,
 }
 impl_debug_if_no_extra_traits!(AllocColorCellsReply, "AllocColorCellsReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for AllocColorCellsReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for AllocColorCellsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -21423,6 +27320,7 @@ impl Serialize for AllocColorCellsReply {
         bytes.extend_from_slice(&[0; 20]);
         self.pixels.serialize_into(bytes);
         self.masks.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl AllocColorCellsReply {
@@ -21459,6 +27357,7 @@ pub const ALLOC_COLOR_PLANES_REQUEST: u8 = 87;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct AllocColorPlanesRequest {
     pub contiguous: bool,
     pub cmap: Colormap,
@@ -21536,6 +27435,46 @@ impl Request for AllocColorPlanesRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let contiguous_bytes = self.contiguous.serialize();
+        let cmap_bytes = self.cmap.serialize();
+        let colors_bytes = self.colors.serialize();
+        let reds_bytes = self.reds.serialize();
+        let greens_bytes = self.greens.serialize();
+        let blues_bytes = self.blues.serialize();
+        buffer.extend_from_slice(&[
+            ALLOC_COLOR_PLANES_REQUEST,
+            contiguous_bytes[0],
+            0,
+            0,
+            cmap_bytes[0],
+            cmap_bytes[1],
+            cmap_bytes[2],
+            cmap_bytes[3],
+            colors_bytes[0],
+            colors_bytes[1],
+            reds_bytes[0],
+            reds_bytes[1],
+            greens_bytes[0],
+            greens_bytes[1],
+            blues_bytes[0],
+            blues_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for AllocColorPlanesRequest {
     type Reply = AllocColorPlanesReply;
@@ -21553,6 +27492,12 @@ pub struct AllocColorPlanesReply {
     pub pixels: Vec<u32>,
 }
 impl_debug_if_no_extra_traits!(AllocColorPlanesReply, "AllocColorPlanesReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for AllocColorPlanesReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for AllocColorPlanesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -21599,6 +27544,7 @@ impl Serialize for AllocColorPlanesReply {
         self.blue_mask.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 8]);
         self.pixels.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl AllocColorPlanesReply {
@@ -21622,6 +27568,7 @@ pub const FREE_COLORS_REQUEST: u8 = 88;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct FreeColorsRequest<'input> {
     pub cmap: Colormap,
     pub plane_mask: u32,
@@ -21671,7 +27618,7 @@ impl<'input> FreeColorsRequest<'input> {
         let (plane_mask, remaining) = u32::try_parse(remaining)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut pixels = Vec::new();
+        let mut pixels = Vec::with_capacity(remaining.len() / 4);
         while !remaining.is_empty() {
             let (v, new_remaining) = u32::try_parse(remaining)?;
             remaining = new_remaining;
@@ -21698,9 +27645,44 @@ impl<'input> Request for FreeColorsRequest<'input> {
 
     fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
         let (bufs, fds) = self.serialize();
-        // Flatten the buffers into a single vector
-        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
-        (buf, fds)
+        // Flatten the buffers into a single vector
+        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
+        (buf, fds)
+    }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let cmap_bytes = self.cmap.serialize();
+        let plane_mask_bytes = self.plane_mask.serialize();
+        buffer.extend_from_slice(&[
+            FREE_COLORS_REQUEST,
+            0,
+            0,
+            0,
+            cmap_bytes[0],
+            cmap_bytes[1],
+            cmap_bytes[2],
+            cmap_bytes[3],
+            plane_mask_bytes[0],
+            plane_mask_bytes[1],
+            plane_mask_bytes[2],
+            plane_mask_bytes[3],
+        ]);
+        self.pixels.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
     }
 }
 impl<'input> crate::x11_utils::VoidRequest for FreeColorsRequest<'input> {
@@ -21708,6 +27690,7 @@ impl<'input> crate::x11_utils::VoidRequest for FreeColorsRequest<'input> {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ColorFlag(u8);
 impl ColorFlag {
     pub const RED: Self = Self(1 << 0);
@@ -21766,6 +27749,22 @@ impl core::fmt::Debug for ColorFlag  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl ColorFlag {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("Red"),
+            2 => Some("Green"),
+            4 => Some("Blue"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ColorFlag {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(ColorFlag, u8);
 
 #[derive(Clone, Copy, Default)]
@@ -21779,6 +27778,12 @@ pub struct Coloritem {
     pub flags: ColorFlag,
 }
 impl_debug_if_no_extra_traits!(Coloritem, "Coloritem");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Coloritem {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Coloritem {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (pixel, remaining) = u32::try_parse(remaining)?;
@@ -21831,6 +27836,7 @@ pub const STORE_COLORS_REQUEST: u8 = 89;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct StoreColorsRequest<'input> {
     pub cmap: Colormap,
     pub items: Cow<'input, [Coloritem]>,
@@ -21873,7 +27879,7 @@ impl<'input> StoreColorsRequest<'input> {
         let (cmap, remaining) = Colormap::try_parse(value)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut items = Vec::new();
+        let mut items = Vec::with_capacity(remaining.len() / 12);
         while !remaining.is_empty() {
             let (v, new_remaining) = Coloritem::try_parse(remaining)?;
             remaining = new_remaining;
@@ -21902,6 +27908,36 @@ impl<'input> Request for StoreColorsRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let cmap_bytes = self.cmap.serialize();
+        buffer.extend_from_slice(&[
+            STORE_COLORS_REQUEST,
+            0,
+            0,
+            0,
+            cmap_bytes[0],
+            cmap_bytes[1],
+            cmap_bytes[2],
+            cmap_bytes[3],
+        ]);
+        self.items.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for StoreColorsRequest<'input> {
 }
@@ -21911,6 +27947,7 @@ pub const STORE_NAMED_COLOR_REQUEST: u8 = 90;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct StoreNamedColorRequest<'input> {
     pub flags: ColorFlag,
     pub cmap: Colormap,
@@ -21996,6 +28033,48 @@ impl<'input> Request for StoreNamedColorRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let flags_bytes = u8::from(self.flags).serialize();
+        let cmap_bytes = self.cmap.serialize();
+        let pixel_bytes = self.pixel.serialize();
+        let name_len = u16::try_from(self.name.len()).expect("`name` has too many elements");
+        let name_len_bytes = name_len.serialize();
+        buffer.extend_from_slice(&[
+            STORE_NAMED_COLOR_REQUEST,
+            flags_bytes[0],
+            0,
+            0,
+            cmap_bytes[0],
+            cmap_bytes[1],
+            cmap_bytes[2],
+            cmap_bytes[3],
+            pixel_bytes[0],
+            pixel_bytes[1],
+            pixel_bytes[2],
+            pixel_bytes[3],
+            name_len_bytes[0],
+            name_len_bytes[1],
+            0,
+            0,
+        ]);
+        buffer.extend_from_slice(&self.name[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for StoreNamedColorRequest<'input> {
 }
@@ -22009,6 +28088,12 @@ pub struct Rgb {
     pub blue: u16,
 }
 impl_debug_if_no_extra_traits!(Rgb, "Rgb");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Rgb {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Rgb {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (red, remaining) = u16::try_parse(remaining)?;
@@ -22050,6 +28135,7 @@ pub const QUERY_COLORS_REQUEST: u8 = 91;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryColorsRequest<'input> {
     pub cmap: Colormap,
     pub pixels: Cow<'input, [u32]>,
@@ -22092,7 +28178,7 @@ impl<'input> QueryColorsRequest<'input> {
         let (cmap, remaining) = Colormap::try_parse(value)?;
         let mut remaining = remaining;
         // Length is 'everything left in the input'
-        let mut pixels = Vec::new();
+        let mut pixels = Vec::with_capacity(remaining.len() / 4);
         while !remaining.is_empty() {
             let (v, new_remaining) = u32::try_parse(remaining)?;
             remaining = new_remaining;
@@ -22121,6 +28207,36 @@ impl<'input> Request for QueryColorsRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let cmap_bytes = self.cmap.serialize();
+        buffer.extend_from_slice(&[
+            QUERY_COLORS_REQUEST,
+            0,
+            0,
+            0,
+            cmap_bytes[0],
+            cmap_bytes[1],
+            cmap_bytes[2],
+            cmap_bytes[3],
+        ]);
+        self.pixels.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::ReplyRequest for QueryColorsRequest<'input> {
     type Reply = QueryColorsReply;
@@ -22135,6 +28251,12 @@ pub struct QueryColorsReply {
     pub colors: Vec<Rgb>,
 }
 impl_debug_if_no_extra_traits!(QueryColorsReply, "QueryColorsReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryColorsReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryColorsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -22173,6 +28295,7 @@ impl Serialize for QueryColorsReply {
         colors_len.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 22]);
         self.colors.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl QueryColorsReply {
@@ -22196,6 +28319,7 @@ pub const LOOKUP_COLOR_REQUEST: u8 = 92;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct LookupColorRequest<'input> {
     pub cmap: Colormap,
     pub name: Cow<'input, [u8]>,
@@ -22267,6 +28391,42 @@ impl<'input> Request for LookupColorRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let cmap_bytes = self.cmap.serialize();
+        let name_len = u16::try_from(self.name.len()).expect("`name` has too many elements");
+        let name_len_bytes = name_len.serialize();
+        buffer.extend_from_slice(&[
+            LOOKUP_COLOR_REQUEST,
+            0,
+            0,
+            0,
+            cmap_bytes[0],
+            cmap_bytes[1],
+            cmap_bytes[2],
+            cmap_bytes[3],
+            name_len_bytes[0],
+            name_len_bytes[1],
+            0,
+            0,
+        ]);
+        buffer.extend_from_slice(&self.name[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::ReplyRequest for LookupColorRequest<'input> {
     type Reply = LookupColorReply;
@@ -22286,6 +28446,12 @@ pub struct LookupColorReply {
     pub visual_blue: u16,
 }
 impl_debug_if_no_extra_traits!(LookupColorReply, "LookupColorReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for LookupColorReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for LookupColorReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -22362,6 +28528,7 @@ impl Serialize for LookupColorReply {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PixmapEnum(u8);
 impl PixmapEnum {
     pub const NONE: Self = Self(0);
@@ -22416,12 +28583,27 @@ impl core::fmt::Debug for PixmapEnum  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl PixmapEnum {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("None"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for PixmapEnum {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the CreateCursor request
 pub const CREATE_CURSOR_REQUEST: u8 = 93;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateCursorRequest {
     pub cid: Cursor,
     pub source: Pixmap,
@@ -22536,12 +28718,74 @@ impl Request for CreateCursorRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let cid_bytes = self.cid.serialize();
+        let source_bytes = self.source.serialize();
+        let mask_bytes = self.mask.serialize();
+        let fore_red_bytes = self.fore_red.serialize();
+        let fore_green_bytes = self.fore_green.serialize();
+        let fore_blue_bytes = self.fore_blue.serialize();
+        let back_red_bytes = self.back_red.serialize();
+        let back_green_bytes = self.back_green.serialize();
+        let back_blue_bytes = self.back_blue.serialize();
+        let x_bytes = self.x.serialize();
+        let y_bytes = self.y.serialize();
+        buffer.extend_from_slice(&[
+            CREATE_CURSOR_REQUEST,
+            0,
+            0,
+            0,
+            cid_bytes[0],
+            cid_bytes[1],
+            cid_bytes[2],
+            cid_bytes[3],
+            source_bytes[0],
+            source_bytes[1],
+            source_bytes[2],
+            source_bytes[3],
+            mask_bytes[0],
+            mask_bytes[1],
+            mask_bytes[2],
+            mask_bytes[3],
+            fore_red_bytes[0],
+            fore_red_bytes[1],
+            fore_green_bytes[0],
+            fore_green_bytes[1],
+            fore_blue_bytes[0],
+            fore_blue_bytes[1],
+            back_red_bytes[0],
+            back_red_bytes[1],
+            back_green_bytes[0],
+            back_green_bytes[1],
+            back_blue_bytes[0],
+            back_blue_bytes[1],
+            x_bytes[0],
+            x_bytes[1],
+            y_bytes[0],
+            y_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for CreateCursorRequest {
 }
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct FontEnum(u8);
 impl FontEnum {
     pub const NONE: Self = Self(0);
@@ -22596,6 +28840,20 @@ impl core::fmt::Debug for FontEnum  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl FontEnum {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("None"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for FontEnum {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the CreateGlyphCursor request
 pub const CREATE_GLYPH_CURSOR_REQUEST: u8 = 94;
@@ -22635,17 +28893,31 @@ pub const CREATE_GLYPH_CURSOR_REQUEST: u8 = 94;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CreateGlyphCursorRequest {
+    /// The ID with which you will refer to the cursor, created by `xcb_generate_id`.
     pub cid: Cursor,
+    /// In which font to look for the cursor glyph.
     pub source_font: Font,
+    /// In which font to look for the mask glyph.
     pub mask_font: Font,
+    /// The glyph of `source_font` to use.
     pub source_char: u16,
+    /// The glyph of `mask_font` to use as a mask: Pixels which are set to 1 define
+    /// which source pixels are displayed. All pixels which are set to 0 are not
+    /// displayed.
     pub mask_char: u16,
+    /// The red value of the foreground color.
     pub fore_red: u16,
+    /// The green value of the foreground color.
     pub fore_green: u16,
+    /// The blue value of the foreground color.
     pub fore_blue: u16,
+    /// The red value of the background color.
     pub back_red: u16,
+    /// The green value of the background color.
     pub back_green: u16,
+    /// The blue value of the background color.
     pub back_blue: u16,
 }
 impl_debug_if_no_extra_traits!(CreateGlyphCursorRequest, "CreateGlyphCursorRequest");
@@ -22749,6 +29021,67 @@ impl Request for CreateGlyphCursorRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let cid_bytes = self.cid.serialize();
+        let source_font_bytes = self.source_font.serialize();
+        let mask_font_bytes = self.mask_font.serialize();
+        let source_char_bytes = self.source_char.serialize();
+        let mask_char_bytes = self.mask_char.serialize();
+        let fore_red_bytes = self.fore_red.serialize();
+        let fore_green_bytes = self.fore_green.serialize();
+        let fore_blue_bytes = self.fore_blue.serialize();
+        let back_red_bytes = self.back_red.serialize();
+        let back_green_bytes = self.back_green.serialize();
+        let back_blue_bytes = self.back_blue.serialize();
+        buffer.extend_from_slice(&[
+            CREATE_GLYPH_CURSOR_REQUEST,
+            0,
+            0,
+            0,
+            cid_bytes[0],
+            cid_bytes[1],
+            cid_bytes[2],
+            cid_bytes[3],
+            source_font_bytes[0],
+            source_font_bytes[1],
+            source_font_bytes[2],
+            source_font_bytes[3],
+            mask_font_bytes[0],
+            mask_font_bytes[1],
+            mask_font_bytes[2],
+            mask_font_bytes[3],
+            source_char_bytes[0],
+            source_char_bytes[1],
+            mask_char_bytes[0],
+            mask_char_bytes[1],
+            fore_red_bytes[0],
+            fore_red_bytes[1],
+            fore_green_bytes[0],
+            fore_green_bytes[1],
+            fore_blue_bytes[0],
+            fore_blue_bytes[1],
+            back_red_bytes[0],
+            back_red_bytes[1],
+            back_green_bytes[0],
+            back_green_bytes[1],
+            back_blue_bytes[0],
+            back_blue_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for CreateGlyphCursorRequest {
 }
@@ -22770,7 +29103,9 @@ pub const FREE_CURSOR_REQUEST: u8 = 95;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct FreeCursorRequest {
+    /// The cursor to destroy.
     pub cursor: Cursor,
 }
 impl_debug_if_no_extra_traits!(FreeCursorRequest, "FreeCursorRequest");
@@ -22820,6 +29155,33 @@ impl Request for FreeCursorRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let cursor_bytes = self.cursor.serialize();
+        buffer.extend_from_slice(&[
+            FREE_CURSOR_REQUEST,
+            0,
+            0,
+            0,
+            cursor_bytes[0],
+            cursor_bytes[1],
+            cursor_bytes[2],
+            cursor_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for FreeCursorRequest {
 }
@@ -22829,6 +29191,7 @@ pub const RECOLOR_CURSOR_REQUEST: u8 = 96;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct RecolorCursorRequest {
     pub cursor: Cursor,
     pub fore_red: u16,
@@ -22905,15 +29268,60 @@ impl RecolorCursorRequest {
             back_blue,
         })
     }
-}
-impl Request for RecolorCursorRequest {
-    const EXTENSION_NAME: Option<&'static str> = None;
-
-    fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
+}
+impl Request for RecolorCursorRequest {
+    const EXTENSION_NAME: Option<&'static str> = None;
+
+    fn serialize(self, _major_opcode: u8) -> BufWithFds<Vec<u8>> {
+        let (bufs, fds) = self.serialize();
+        // Flatten the buffers into a single vector
+        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
+        (buf, fds)
+    }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let cursor_bytes = self.cursor.serialize();
+        let fore_red_bytes = self.fore_red.serialize();
+        let fore_green_bytes = self.fore_green.serialize();
+        let fore_blue_bytes = self.fore_blue.serialize();
+        let back_red_bytes = self.back_red.serialize();
+        let back_green_bytes = self.back_green.serialize();
+        let back_blue_bytes = self.back_blue.serialize();
+        buffer.extend_from_slice(&[
+            RECOLOR_CURSOR_REQUEST,
+            0,
+            0,
+            0,
+            cursor_bytes[0],
+            cursor_bytes[1],
+            cursor_bytes[2],
+            cursor_bytes[3],
+            fore_red_bytes[0],
+            fore_red_bytes[1],
+            fore_green_bytes[0],
+            fore_green_bytes[1],
+            fore_blue_bytes[0],
+            fore_blue_bytes[1],
+            back_red_bytes[0],
+            back_red_bytes[1],
+            back_green_bytes[0],
+            back_green_bytes[1],
+            back_blue_bytes[0],
+            back_blue_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
         let (bufs, fds) = self.serialize();
-        // Flatten the buffers into a single vector
-        let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
-        (buf, fds)
+        slices.extend(bufs);
+        fds
     }
 }
 impl crate::x11_utils::VoidRequest for RecolorCursorRequest {
@@ -22921,6 +29329,7 @@ impl crate::x11_utils::VoidRequest for RecolorCursorRequest {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryShapeOf(u8);
 impl QueryShapeOf {
     pub const LARGEST_CURSOR: Self = Self(0);
@@ -22979,12 +29388,29 @@ impl core::fmt::Debug for QueryShapeOf  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl QueryShapeOf {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("LargestCursor"),
+            1 => Some("FastestTile"),
+            2 => Some("FastestStipple"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for QueryShapeOf {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the QueryBestSize request
 pub const QUERY_BEST_SIZE_REQUEST: u8 = 97;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryBestSizeRequest {
     pub class: QueryShapeOf,
     pub drawable: Drawable,
@@ -23051,6 +29477,40 @@ impl Request for QueryBestSizeRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let class_bytes = u8::from(self.class).serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        buffer.extend_from_slice(&[
+            QUERY_BEST_SIZE_REQUEST,
+            class_bytes[0],
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryBestSizeRequest {
     type Reply = QueryBestSizeReply;
@@ -23066,6 +29526,12 @@ pub struct QueryBestSizeReply {
     pub height: u16,
 }
 impl_debug_if_no_extra_traits!(QueryBestSizeReply, "QueryBestSizeReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryBestSizeReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryBestSizeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -23146,7 +29612,10 @@ pub const QUERY_EXTENSION_REQUEST: u8 = 98;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryExtensionRequest<'input> {
+    /// The name of the extension to query, for example "RANDR". This is case
+    /// sensitive!
     pub name: Cow<'input, [u8]>,
 }
 impl_debug_if_no_extra_traits!(QueryExtensionRequest<'_>, "QueryExtensionRequest");
@@ -23208,6 +29677,37 @@ impl<'input> Request for QueryExtensionRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let name_len = u16::try_from(self.name.len()).expect("`name` has too many elements");
+        let name_len_bytes = name_len.serialize();
+        buffer.extend_from_slice(&[
+            QUERY_EXTENSION_REQUEST,
+            0,
+            0,
+            0,
+            name_len_bytes[0],
+            name_len_bytes[1],
+            0,
+            0,
+        ]);
+        buffer.extend_from_slice(&self.name[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::ReplyRequest for QueryExtensionRequest<'input> {
     type Reply = QueryExtensionReply;
@@ -23225,12 +29725,22 @@ impl<'input> crate::x11_utils::ReplyRequest for QueryExtensionRequest<'input> {
 pub struct QueryExtensionReply {
     pub sequence: u16,
     pub length: u32,
+    /// Whether the extension is present on this X11 server.
     pub present: bool,
+    /// The major opcode for requests.
     pub major_opcode: u8,
+    /// The first event code, if any.
     pub first_event: u8,
+    /// The first error code, if any.
     pub first_error: u8,
 }
 impl_debug_if_no_extra_traits!(QueryExtensionReply, "QueryExtensionReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryExtensionReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryExtensionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -23296,6 +29806,7 @@ pub const LIST_EXTENSIONS_REQUEST: u8 = 99;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ListExtensionsRequest;
 impl_debug_if_no_extra_traits!(ListExtensionsRequest, "ListExtensionsRequest");
 impl ListExtensionsRequest {
@@ -23337,6 +29848,28 @@ impl Request for ListExtensionsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            LIST_EXTENSIONS_REQUEST,
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for ListExtensionsRequest {
     type Reply = ListExtensionsReply;
@@ -23351,6 +29884,12 @@ pub struct ListExtensionsReply {
     pub names: Vec<Str>,
 }
 impl_debug_if_no_extra_traits!(ListExtensionsReply, "ListExtensionsReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ListExtensionsReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ListExtensionsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -23387,6 +29926,7 @@ impl Serialize for ListExtensionsReply {
         self.length.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 24]);
         self.names.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl ListExtensionsReply {
@@ -23410,6 +29950,7 @@ pub const CHANGE_KEYBOARD_MAPPING_REQUEST: u8 = 100;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangeKeyboardMappingRequest<'input> {
     pub keycode_count: u8,
     pub first_keycode: Keycode,
@@ -23485,6 +30026,39 @@ impl<'input> Request for ChangeKeyboardMappingRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let keycode_count_bytes = self.keycode_count.serialize();
+        let first_keycode_bytes = self.first_keycode.serialize();
+        let keysyms_per_keycode_bytes = self.keysyms_per_keycode.serialize();
+        buffer.extend_from_slice(&[
+            CHANGE_KEYBOARD_MAPPING_REQUEST,
+            keycode_count_bytes[0],
+            0,
+            0,
+            first_keycode_bytes[0],
+            keysyms_per_keycode_bytes[0],
+            0,
+            0,
+        ]);
+        assert_eq!(self.keysyms.len(), usize::try_from(u32::from(self.keycode_count).checked_mul(u32::from(self.keysyms_per_keycode)).unwrap()).unwrap(), "`keysyms` has an incorrect length");
+        self.keysyms.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for ChangeKeyboardMappingRequest<'input> {
 }
@@ -23494,6 +30068,7 @@ pub const GET_KEYBOARD_MAPPING_REQUEST: u8 = 101;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetKeyboardMappingRequest {
     pub first_keycode: Keycode,
     pub count: u8,
@@ -23548,6 +30123,34 @@ impl Request for GetKeyboardMappingRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let first_keycode_bytes = self.first_keycode.serialize();
+        let count_bytes = self.count.serialize();
+        buffer.extend_from_slice(&[
+            GET_KEYBOARD_MAPPING_REQUEST,
+            0,
+            0,
+            0,
+            first_keycode_bytes[0],
+            count_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetKeyboardMappingRequest {
     type Reply = GetKeyboardMappingReply;
@@ -23562,6 +30165,12 @@ pub struct GetKeyboardMappingReply {
     pub keysyms: Vec<Keysym>,
 }
 impl_debug_if_no_extra_traits!(GetKeyboardMappingReply, "GetKeyboardMappingReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetKeyboardMappingReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetKeyboardMappingReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -23598,6 +30207,7 @@ impl Serialize for GetKeyboardMappingReply {
         length.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 24]);
         self.keysyms.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetKeyboardMappingReply {
@@ -23618,6 +30228,7 @@ impl GetKeyboardMappingReply {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct KB(u32);
 impl KB {
     pub const KEY_CLICK_PERCENT: Self = Self(1 << 0);
@@ -23674,10 +30285,32 @@ impl core::fmt::Debug for KB  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl KB {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("KeyClickPercent"),
+            2 => Some("BellPercent"),
+            4 => Some("BellPitch"),
+            8 => Some("BellDuration"),
+            16 => Some("Led"),
+            32 => Some("LedMode"),
+            64 => Some("Key"),
+            128 => Some("AutoRepeatMode"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for KB {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(KB, u32);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct LedMode(u32);
 impl LedMode {
     pub const OFF: Self = Self(0);
@@ -23722,9 +30355,25 @@ impl core::fmt::Debug for LedMode  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+impl LedMode {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Off"),
+            1 => Some("On"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for LedMode {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct AutoRepeatMode(u32);
 impl AutoRepeatMode {
     pub const OFF: Self = Self(0);
@@ -23771,11 +30420,28 @@ impl core::fmt::Debug for AutoRepeatMode  {
         pretty_print_enum(fmt, self.0, &variants)
     }
 }
+impl AutoRepeatMode {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Off"),
+            1 => Some("On"),
+            2 => Some("Default"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for AutoRepeatMode {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Auxiliary and optional information for the `change_keyboard_control` function
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangeKeyboardControlAux {
     pub key_click_percent: Option<i32>,
     pub bell_percent: Option<i32>,
@@ -23980,6 +30646,39 @@ impl ChangeKeyboardControlAux {
         self.auto_repeat_mode = value.into();
         self
     }
+    /// Get the changes that are needed to go from `old` to `self`.
+    ///
+    /// The result contains all fields that are set in `self` and that are either unset in
+    /// `old` or set to a different value. This allows to only send values that changed.
+    #[must_use]
+    pub fn changes_from(&self, old: &Self) -> Self {
+        let mut result = Self::new();
+        if self.key_click_percent.is_some() && self.key_click_percent != old.key_click_percent {
+            result.key_click_percent = self.key_click_percent;
+        }
+        if self.bell_percent.is_some() && self.bell_percent != old.bell_percent {
+            result.bell_percent = self.bell_percent;
+        }
+        if self.bell_pitch.is_some() && self.bell_pitch != old.bell_pitch {
+            result.bell_pitch = self.bell_pitch;
+        }
+        if self.bell_duration.is_some() && self.bell_duration != old.bell_duration {
+            result.bell_duration = self.bell_duration;
+        }
+        if self.led.is_some() && self.led != old.led {
+            result.led = self.led;
+        }
+        if self.led_mode.is_some() && self.led_mode != old.led_mode {
+            result.led_mode = self.led_mode;
+        }
+        if self.key.is_some() && self.key != old.key {
+            result.key = self.key;
+        }
+        if self.auto_repeat_mode.is_some() && self.auto_repeat_mode != old.auto_repeat_mode {
+            result.auto_repeat_mode = self.auto_repeat_mode;
+        }
+        result
+    }
 }
 
 /// Opcode for the ChangeKeyboardControl request
@@ -23987,6 +30686,7 @@ pub const CHANGE_KEYBOARD_CONTROL_REQUEST: u8 = 102;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangeKeyboardControlRequest<'input> {
     pub value_list: Cow<'input, ChangeKeyboardControlAux>,
 }
@@ -24049,15 +30749,107 @@ impl<'input> Request for ChangeKeyboardControlRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let value_mask: u32 = self.value_list.switch_expr();
+        let value_mask_bytes = value_mask.serialize();
+        buffer.extend_from_slice(&[
+            CHANGE_KEYBOARD_CONTROL_REQUEST,
+            0,
+            0,
+            0,
+            value_mask_bytes[0],
+            value_mask_bytes[1],
+            value_mask_bytes[2],
+            value_mask_bytes[3],
+        ]);
+        self.value_list.serialize_into(buffer, u32::from(value_mask));
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for ChangeKeyboardControlRequest<'input> {
 }
+impl ChangeKeyboardControlRequest<'static> {
+    /// Create a new request with all fields of `value_list` unset.
+    ///
+    /// The optional fields can then be set with the builder methods of this request.
+    pub fn new() -> Self {
+        ChangeKeyboardControlRequest {
+            value_list: Cow::Owned(ChangeKeyboardControlAux::new()),
+        }
+    }
+}
+impl<'input> ChangeKeyboardControlRequest<'input> {
+    /// Set the `key_click_percent` field of `value_list`.
+    #[must_use]
+    pub fn key_click_percent<I>(mut self, value: I) -> Self where I: Into<Option<i32>> {
+        self.value_list.to_mut().key_click_percent = value.into();
+        self
+    }
+    /// Set the `bell_percent` field of `value_list`.
+    #[must_use]
+    pub fn bell_percent<I>(mut self, value: I) -> Self where I: Into<Option<i32>> {
+        self.value_list.to_mut().bell_percent = value.into();
+        self
+    }
+    /// Set the `bell_pitch` field of `value_list`.
+    #[must_use]
+    pub fn bell_pitch<I>(mut self, value: I) -> Self where I: Into<Option<i32>> {
+        self.value_list.to_mut().bell_pitch = value.into();
+        self
+    }
+    /// Set the `bell_duration` field of `value_list`.
+    #[must_use]
+    pub fn bell_duration<I>(mut self, value: I) -> Self where I: Into<Option<i32>> {
+        self.value_list.to_mut().bell_duration = value.into();
+        self
+    }
+    /// Set the `led` field of `value_list`.
+    #[must_use]
+    pub fn led<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.value_list.to_mut().led = value.into();
+        self
+    }
+    /// Set the `led_mode` field of `value_list`.
+    #[must_use]
+    pub fn led_mode<I>(mut self, value: I) -> Self where I: Into<Option<LedMode>> {
+        self.value_list.to_mut().led_mode = value.into();
+        self
+    }
+    /// Set the `key` field of `value_list`.
+    #[must_use]
+    pub fn key<I>(mut self, value: I) -> Self where I: Into<Option<Keycode32>> {
+        self.value_list.to_mut().key = value.into();
+        self
+    }
+    /// Set the `auto_repeat_mode` field of `value_list`.
+    #[must_use]
+    pub fn auto_repeat_mode<I>(mut self, value: I) -> Self where I: Into<Option<AutoRepeatMode>> {
+        self.value_list.to_mut().auto_repeat_mode = value.into();
+        self
+    }
+}
 
 /// Opcode for the GetKeyboardControl request
 pub const GET_KEYBOARD_CONTROL_REQUEST: u8 = 103;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetKeyboardControlRequest;
 impl_debug_if_no_extra_traits!(GetKeyboardControlRequest, "GetKeyboardControlRequest");
 impl GetKeyboardControlRequest {
@@ -24099,6 +30891,28 @@ impl Request for GetKeyboardControlRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            GET_KEYBOARD_CONTROL_REQUEST,
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetKeyboardControlRequest {
     type Reply = GetKeyboardControlReply;
@@ -24119,6 +30933,12 @@ pub struct GetKeyboardControlReply {
     pub auto_repeats: [u8; 32],
 }
 impl_debug_if_no_extra_traits!(GetKeyboardControlReply, "GetKeyboardControlReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetKeyboardControlReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetKeyboardControlReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -24233,6 +31053,7 @@ pub const BELL_REQUEST: u8 = 104;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct BellRequest {
     pub percent: i8,
 }
@@ -24278,6 +31099,29 @@ impl Request for BellRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let percent_bytes = self.percent.serialize();
+        buffer.extend_from_slice(&[
+            BELL_REQUEST,
+            percent_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for BellRequest {
 }
@@ -24287,6 +31131,7 @@ pub const CHANGE_POINTER_CONTROL_REQUEST: u8 = 105;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangePointerControlRequest {
     pub acceleration_numerator: i16,
     pub acceleration_denominator: i16,
@@ -24357,6 +31202,41 @@ impl Request for ChangePointerControlRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let acceleration_numerator_bytes = self.acceleration_numerator.serialize();
+        let acceleration_denominator_bytes = self.acceleration_denominator.serialize();
+        let threshold_bytes = self.threshold.serialize();
+        let do_acceleration_bytes = self.do_acceleration.serialize();
+        let do_threshold_bytes = self.do_threshold.serialize();
+        buffer.extend_from_slice(&[
+            CHANGE_POINTER_CONTROL_REQUEST,
+            0,
+            0,
+            0,
+            acceleration_numerator_bytes[0],
+            acceleration_numerator_bytes[1],
+            acceleration_denominator_bytes[0],
+            acceleration_denominator_bytes[1],
+            threshold_bytes[0],
+            threshold_bytes[1],
+            do_acceleration_bytes[0],
+            do_threshold_bytes[0],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for ChangePointerControlRequest {
 }
@@ -24366,6 +31246,7 @@ pub const GET_POINTER_CONTROL_REQUEST: u8 = 106;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetPointerControlRequest;
 impl_debug_if_no_extra_traits!(GetPointerControlRequest, "GetPointerControlRequest");
 impl GetPointerControlRequest {
@@ -24407,6 +31288,28 @@ impl Request for GetPointerControlRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            GET_POINTER_CONTROL_REQUEST,
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetPointerControlRequest {
     type Reply = GetPointerControlReply;
@@ -24423,6 +31326,12 @@ pub struct GetPointerControlReply {
     pub threshold: u16,
 }
 impl_debug_if_no_extra_traits!(GetPointerControlReply, "GetPointerControlReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetPointerControlReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetPointerControlReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -24504,6 +31413,7 @@ impl Serialize for GetPointerControlReply {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Blanking(u8);
 impl Blanking {
     pub const NOT_PREFERRED: Self = Self(0);
@@ -24554,17 +31464,34 @@ impl From<u8> for Blanking {
 }
 impl core::fmt::Debug for Blanking  {
     fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let variants = [
-            (Self::NOT_PREFERRED.0.into(), "NOT_PREFERRED", "NotPreferred"),
-            (Self::PREFERRED.0.into(), "PREFERRED", "Preferred"),
-            (Self::DEFAULT.0.into(), "DEFAULT", "Default"),
-        ];
-        pretty_print_enum(fmt, self.0.into(), &variants)
+        let variants = [
+            (Self::NOT_PREFERRED.0.into(), "NOT_PREFERRED", "NotPreferred"),
+            (Self::PREFERRED.0.into(), "PREFERRED", "Preferred"),
+            (Self::DEFAULT.0.into(), "DEFAULT", "Default"),
+        ];
+        pretty_print_enum(fmt, self.0.into(), &variants)
+    }
+}
+impl Blanking {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("NotPreferred"),
+            1 => Some("Preferred"),
+            2 => Some("Default"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Blanking {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
     }
 }
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Exposures(u8);
 impl Exposures {
     pub const NOT_ALLOWED: Self = Self(0);
@@ -24623,12 +31550,29 @@ impl core::fmt::Debug for Exposures  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl Exposures {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("NotAllowed"),
+            1 => Some("Allowed"),
+            2 => Some("Default"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Exposures {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the SetScreenSaver request
 pub const SET_SCREEN_SAVER_REQUEST: u8 = 107;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetScreenSaverRequest {
     pub timeout: i16,
     pub interval: i16,
@@ -24697,6 +31641,40 @@ impl Request for SetScreenSaverRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let timeout_bytes = self.timeout.serialize();
+        let interval_bytes = self.interval.serialize();
+        let prefer_blanking_bytes = u8::from(self.prefer_blanking).serialize();
+        let allow_exposures_bytes = u8::from(self.allow_exposures).serialize();
+        buffer.extend_from_slice(&[
+            SET_SCREEN_SAVER_REQUEST,
+            0,
+            0,
+            0,
+            timeout_bytes[0],
+            timeout_bytes[1],
+            interval_bytes[0],
+            interval_bytes[1],
+            prefer_blanking_bytes[0],
+            allow_exposures_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SetScreenSaverRequest {
 }
@@ -24706,6 +31684,7 @@ pub const GET_SCREEN_SAVER_REQUEST: u8 = 108;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetScreenSaverRequest;
 impl_debug_if_no_extra_traits!(GetScreenSaverRequest, "GetScreenSaverRequest");
 impl GetScreenSaverRequest {
@@ -24747,6 +31726,28 @@ impl Request for GetScreenSaverRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            GET_SCREEN_SAVER_REQUEST,
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetScreenSaverRequest {
     type Reply = GetScreenSaverReply;
@@ -24764,6 +31765,12 @@ pub struct GetScreenSaverReply {
     pub allow_exposures: Exposures,
 }
 impl_debug_if_no_extra_traits!(GetScreenSaverReply, "GetScreenSaverReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetScreenSaverReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetScreenSaverReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -24850,6 +31857,7 @@ impl Serialize for GetScreenSaverReply {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct HostMode(u8);
 impl HostMode {
     pub const INSERT: Self = Self(0);
@@ -24906,9 +31914,25 @@ impl core::fmt::Debug for HostMode  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl HostMode {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Insert"),
+            1 => Some("Delete"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for HostMode {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Family(u8);
 impl Family {
     pub const INTERNET: Self = Self(0);
@@ -24971,12 +31995,31 @@ impl core::fmt::Debug for Family  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl Family {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Internet"),
+            1 => Some("DECnet"),
+            2 => Some("Chaos"),
+            5 => Some("ServerInterpreted"),
+            6 => Some("Internet6"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Family {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the ChangeHosts request
 pub const CHANGE_HOSTS_REQUEST: u8 = 109;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ChangeHostsRequest<'input> {
     pub mode: HostMode,
     pub family: Family,
@@ -25050,6 +32093,39 @@ impl<'input> Request for ChangeHostsRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let mode_bytes = u8::from(self.mode).serialize();
+        let family_bytes = u8::from(self.family).serialize();
+        let address_len = u16::try_from(self.address.len()).expect("`address` has too many elements");
+        let address_len_bytes = address_len.serialize();
+        buffer.extend_from_slice(&[
+            CHANGE_HOSTS_REQUEST,
+            mode_bytes[0],
+            0,
+            0,
+            family_bytes[0],
+            0,
+            address_len_bytes[0],
+            address_len_bytes[1],
+        ]);
+        buffer.extend_from_slice(&self.address[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for ChangeHostsRequest<'input> {
 }
@@ -25062,6 +32138,12 @@ pub struct Host {
     pub address: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(Host, "Host");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Host {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Host {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
@@ -25117,6 +32199,7 @@ pub const LIST_HOSTS_REQUEST: u8 = 110;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ListHostsRequest;
 impl_debug_if_no_extra_traits!(ListHostsRequest, "ListHostsRequest");
 impl ListHostsRequest {
@@ -25158,6 +32241,28 @@ impl Request for ListHostsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            LIST_HOSTS_REQUEST,
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for ListHostsRequest {
     type Reply = ListHostsReply;
@@ -25173,6 +32278,12 @@ pub struct ListHostsReply {
     pub hosts: Vec<Host>,
 }
 impl_debug_if_no_extra_traits!(ListHostsReply, "ListHostsReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ListHostsReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ListHostsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -25212,6 +32323,7 @@ impl Serialize for ListHostsReply {
         hosts_len.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 22]);
         self.hosts.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl ListHostsReply {
@@ -25232,6 +32344,7 @@ impl ListHostsReply {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct AccessControl(u8);
 impl AccessControl {
     pub const DISABLE: Self = Self(0);
@@ -25288,12 +32401,28 @@ impl core::fmt::Debug for AccessControl  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl AccessControl {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Disable"),
+            1 => Some("Enable"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for AccessControl {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the SetAccessControl request
 pub const SET_ACCESS_CONTROL_REQUEST: u8 = 111;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetAccessControlRequest {
     pub mode: AccessControl,
 }
@@ -25340,12 +32469,36 @@ impl Request for SetAccessControlRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let mode_bytes = u8::from(self.mode).serialize();
+        buffer.extend_from_slice(&[
+            SET_ACCESS_CONTROL_REQUEST,
+            mode_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SetAccessControlRequest {
 }
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CloseDown(u8);
 impl CloseDown {
     pub const DESTROY_ALL: Self = Self(0);
@@ -25404,12 +32557,29 @@ impl core::fmt::Debug for CloseDown  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl CloseDown {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("DestroyAll"),
+            1 => Some("RetainPermanent"),
+            2 => Some("RetainTemporary"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for CloseDown {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the SetCloseDownMode request
 pub const SET_CLOSE_DOWN_MODE_REQUEST: u8 = 112;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetCloseDownModeRequest {
     pub mode: CloseDown,
 }
@@ -25456,12 +32626,36 @@ impl Request for SetCloseDownModeRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let mode_bytes = u8::from(self.mode).serialize();
+        buffer.extend_from_slice(&[
+            SET_CLOSE_DOWN_MODE_REQUEST,
+            mode_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SetCloseDownModeRequest {
 }
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Kill(u8);
 impl Kill {
     pub const ALL_TEMPORARY: Self = Self(0);
@@ -25516,6 +32710,20 @@ impl core::fmt::Debug for Kill  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl Kill {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("AllTemporary"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Kill {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the KillClient request
 pub const KILL_CLIENT_REQUEST: u8 = 113;
@@ -25541,7 +32749,13 @@ pub const KILL_CLIENT_REQUEST: u8 = 113;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct KillClientRequest {
+    /// Any resource belonging to the client (for example a Window), used to identify
+    /// the client connection.
+    ///
+    /// The special value of `XCB_KILL_ALL_TEMPORARY`, the resources of all clients
+    /// that have terminated in `RetainTemporary` (TODO) are destroyed.
     pub resource: u32,
 }
 impl_debug_if_no_extra_traits!(KillClientRequest, "KillClientRequest");
@@ -25591,6 +32805,33 @@ impl Request for KillClientRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let resource_bytes = self.resource.serialize();
+        buffer.extend_from_slice(&[
+            KILL_CLIENT_REQUEST,
+            0,
+            0,
+            0,
+            resource_bytes[0],
+            resource_bytes[1],
+            resource_bytes[2],
+            resource_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for KillClientRequest {
 }
@@ -25600,6 +32841,7 @@ pub const ROTATE_PROPERTIES_REQUEST: u8 = 114;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct RotatePropertiesRequest<'input> {
     pub window: Window,
     pub delta: i16,
@@ -25676,12 +32918,50 @@ impl<'input> Request for RotatePropertiesRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        let atoms_len = u16::try_from(self.atoms.len()).expect("`atoms` has too many elements");
+        let atoms_len_bytes = atoms_len.serialize();
+        let delta_bytes = self.delta.serialize();
+        buffer.extend_from_slice(&[
+            ROTATE_PROPERTIES_REQUEST,
+            0,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            atoms_len_bytes[0],
+            atoms_len_bytes[1],
+            delta_bytes[0],
+            delta_bytes[1],
+        ]);
+        self.atoms.serialize_into(buffer);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for RotatePropertiesRequest<'input> {
 }
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ScreenSaver(u8);
 impl ScreenSaver {
     pub const RESET: Self = Self(0);
@@ -25738,12 +33018,28 @@ impl core::fmt::Debug for ScreenSaver  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl ScreenSaver {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Reset"),
+            1 => Some("Active"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ScreenSaver {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the ForceScreenSaver request
 pub const FORCE_SCREEN_SAVER_REQUEST: u8 = 115;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ForceScreenSaverRequest {
     pub mode: ScreenSaver,
 }
@@ -25790,12 +33086,36 @@ impl Request for ForceScreenSaverRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let mode_bytes = u8::from(self.mode).serialize();
+        buffer.extend_from_slice(&[
+            FORCE_SCREEN_SAVER_REQUEST,
+            mode_bytes[0],
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for ForceScreenSaverRequest {
 }
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct MappingStatus(u8);
 impl MappingStatus {
     pub const SUCCESS: Self = Self(0);
@@ -25854,12 +33174,29 @@ impl core::fmt::Debug for MappingStatus  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl MappingStatus {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Success"),
+            1 => Some("Busy"),
+            2 => Some("Failure"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for MappingStatus {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the SetPointerMapping request
 pub const SET_POINTER_MAPPING_REQUEST: u8 = 116;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetPointerMappingRequest<'input> {
     pub map: Cow<'input, [u8]>,
 }
@@ -25916,6 +33253,33 @@ impl<'input> Request for SetPointerMappingRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let map_len = u8::try_from(self.map.len()).expect("`map` has too many elements");
+        let map_len_bytes = map_len.serialize();
+        buffer.extend_from_slice(&[
+            SET_POINTER_MAPPING_REQUEST,
+            map_len_bytes[0],
+            0,
+            0,
+        ]);
+        buffer.extend_from_slice(&self.map[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::ReplyRequest for SetPointerMappingRequest<'input> {
     type Reply = SetPointerMappingReply;
@@ -25930,6 +33294,12 @@ pub struct SetPointerMappingReply {
     pub length: u32,
 }
 impl_debug_if_no_extra_traits!(SetPointerMappingReply, "SetPointerMappingReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SetPointerMappingReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for SetPointerMappingReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -25981,6 +33351,7 @@ pub const GET_POINTER_MAPPING_REQUEST: u8 = 117;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetPointerMappingRequest;
 impl_debug_if_no_extra_traits!(GetPointerMappingRequest, "GetPointerMappingRequest");
 impl GetPointerMappingRequest {
@@ -26022,6 +33393,28 @@ impl Request for GetPointerMappingRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            GET_POINTER_MAPPING_REQUEST,
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetPointerMappingRequest {
     type Reply = GetPointerMappingReply;
@@ -26036,6 +33429,12 @@ pub struct GetPointerMappingReply {
     pub map: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(GetPointerMappingReply, "GetPointerMappingReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetPointerMappingReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetPointerMappingReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -26073,6 +33472,7 @@ impl Serialize for GetPointerMappingReply {
         self.length.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 24]);
         bytes.extend_from_slice(&self.map);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetPointerMappingReply {
@@ -26093,6 +33493,7 @@ impl GetPointerMappingReply {
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct MapIndex(u8);
 impl MapIndex {
     pub const SHIFT: Self = Self(0);
@@ -26161,12 +33562,34 @@ impl core::fmt::Debug for MapIndex  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl MapIndex {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Shift"),
+            1 => Some("Lock"),
+            2 => Some("Control"),
+            3 => Some("M1"),
+            4 => Some("M2"),
+            5 => Some("M3"),
+            6 => Some("M4"),
+            7 => Some("M5"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for MapIndex {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 /// Opcode for the SetModifierMapping request
 pub const SET_MODIFIER_MAPPING_REQUEST: u8 = 118;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetModifierMappingRequest<'input> {
     pub keycodes: Cow<'input, [Keycode]>,
 }
@@ -26224,6 +33647,34 @@ impl<'input> Request for SetModifierMappingRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        assert_eq!(self.keycodes.len() % 8, 0, "`keycodes` has an incorrect length, must be a multiple of 8");
+        let keycodes_per_modifier = u8::try_from(self.keycodes.len() / 8).expect("`keycodes` has too many elements");
+        let keycodes_per_modifier_bytes = keycodes_per_modifier.serialize();
+        buffer.extend_from_slice(&[
+            SET_MODIFIER_MAPPING_REQUEST,
+            keycodes_per_modifier_bytes[0],
+            0,
+            0,
+        ]);
+        buffer.extend_from_slice(&self.keycodes[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::ReplyRequest for SetModifierMappingRequest<'input> {
     type Reply = SetModifierMappingReply;
@@ -26238,6 +33689,12 @@ pub struct SetModifierMappingReply {
     pub length: u32,
 }
 impl_debug_if_no_extra_traits!(SetModifierMappingReply, "SetModifierMappingReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SetModifierMappingReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for SetModifierMappingReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -26289,6 +33746,7 @@ pub const GET_MODIFIER_MAPPING_REQUEST: u8 = 119;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetModifierMappingRequest;
 impl_debug_if_no_extra_traits!(GetModifierMappingRequest, "GetModifierMappingRequest");
 impl GetModifierMappingRequest {
@@ -26330,6 +33788,28 @@ impl Request for GetModifierMappingRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            GET_MODIFIER_MAPPING_REQUEST,
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetModifierMappingRequest {
     type Reply = GetModifierMappingReply;
@@ -26344,6 +33824,12 @@ pub struct GetModifierMappingReply {
     pub keycodes: Vec<Keycode>,
 }
 impl_debug_if_no_extra_traits!(GetModifierMappingReply, "GetModifierMappingReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetModifierMappingReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetModifierMappingReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -26382,6 +33868,7 @@ impl Serialize for GetModifierMappingReply {
         self.length.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 24]);
         bytes.extend_from_slice(&self.keycodes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl GetModifierMappingReply {
@@ -26406,6 +33893,7 @@ pub const NO_OPERATION_REQUEST: u8 = 127;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct NoOperationRequest;
 impl_debug_if_no_extra_traits!(NoOperationRequest, "NoOperationRequest");
 impl NoOperationRequest {
@@ -26447,7 +33935,153 @@ impl Request for NoOperationRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, _major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            NO_OPERATION_REQUEST,
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, _major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize();
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for NoOperationRequest {
 }
 
+/// All requests of this module.
+pub const REQUESTS: &[RequestDescription] = &[
+    RequestDescription { opcode: CREATE_WINDOW_REQUEST, name: "CreateWindow", has_reply: false },
+    RequestDescription { opcode: CHANGE_WINDOW_ATTRIBUTES_REQUEST, name: "ChangeWindowAttributes", has_reply: false },
+    RequestDescription { opcode: GET_WINDOW_ATTRIBUTES_REQUEST, name: "GetWindowAttributes", has_reply: true },
+    RequestDescription { opcode: DESTROY_WINDOW_REQUEST, name: "DestroyWindow", has_reply: false },
+    RequestDescription { opcode: DESTROY_SUBWINDOWS_REQUEST, name: "DestroySubwindows", has_reply: false },
+    RequestDescription { opcode: CHANGE_SAVE_SET_REQUEST, name: "ChangeSaveSet", has_reply: false },
+    RequestDescription { opcode: REPARENT_WINDOW_REQUEST, name: "ReparentWindow", has_reply: false },
+    RequestDescription { opcode: MAP_WINDOW_REQUEST, name: "MapWindow", has_reply: false },
+    RequestDescription { opcode: MAP_SUBWINDOWS_REQUEST, name: "MapSubwindows", has_reply: false },
+    RequestDescription { opcode: UNMAP_WINDOW_REQUEST, name: "UnmapWindow", has_reply: false },
+    RequestDescription { opcode: UNMAP_SUBWINDOWS_REQUEST, name: "UnmapSubwindows", has_reply: false },
+    RequestDescription { opcode: CONFIGURE_WINDOW_REQUEST, name: "ConfigureWindow", has_reply: false },
+    RequestDescription { opcode: CIRCULATE_WINDOW_REQUEST, name: "CirculateWindow", has_reply: false },
+    RequestDescription { opcode: GET_GEOMETRY_REQUEST, name: "GetGeometry", has_reply: true },
+    RequestDescription { opcode: QUERY_TREE_REQUEST, name: "QueryTree", has_reply: true },
+    RequestDescription { opcode: INTERN_ATOM_REQUEST, name: "InternAtom", has_reply: true },
+    RequestDescription { opcode: GET_ATOM_NAME_REQUEST, name: "GetAtomName", has_reply: true },
+    RequestDescription { opcode: CHANGE_PROPERTY_REQUEST, name: "ChangeProperty", has_reply: false },
+    RequestDescription { opcode: DELETE_PROPERTY_REQUEST, name: "DeleteProperty", has_reply: false },
+    RequestDescription { opcode: GET_PROPERTY_REQUEST, name: "GetProperty", has_reply: true },
+    RequestDescription { opcode: LIST_PROPERTIES_REQUEST, name: "ListProperties", has_reply: true },
+    RequestDescription { opcode: SET_SELECTION_OWNER_REQUEST, name: "SetSelectionOwner", has_reply: false },
+    RequestDescription { opcode: GET_SELECTION_OWNER_REQUEST, name: "GetSelectionOwner", has_reply: true },
+    RequestDescription { opcode: CONVERT_SELECTION_REQUEST, name: "ConvertSelection", has_reply: false },
+    RequestDescription { opcode: SEND_EVENT_REQUEST, name: "SendEvent", has_reply: false },
+    RequestDescription { opcode: GRAB_POINTER_REQUEST, name: "GrabPointer", has_reply: true },
+    RequestDescription { opcode: UNGRAB_POINTER_REQUEST, name: "UngrabPointer", has_reply: false },
+    RequestDescription { opcode: GRAB_BUTTON_REQUEST, name: "GrabButton", has_reply: false },
+    RequestDescription { opcode: UNGRAB_BUTTON_REQUEST, name: "UngrabButton", has_reply: false },
+    RequestDescription { opcode: CHANGE_ACTIVE_POINTER_GRAB_REQUEST, name: "ChangeActivePointerGrab", has_reply: false },
+    RequestDescription { opcode: GRAB_KEYBOARD_REQUEST, name: "GrabKeyboard", has_reply: true },
+    RequestDescription { opcode: UNGRAB_KEYBOARD_REQUEST, name: "UngrabKeyboard", has_reply: false },
+    RequestDescription { opcode: GRAB_KEY_REQUEST, name: "GrabKey", has_reply: false },
+    RequestDescription { opcode: UNGRAB_KEY_REQUEST, name: "UngrabKey", has_reply: false },
+    RequestDescription { opcode: ALLOW_EVENTS_REQUEST, name: "AllowEvents", has_reply: false },
+    RequestDescription { opcode: GRAB_SERVER_REQUEST, name: "GrabServer", has_reply: false },
+    RequestDescription { opcode: UNGRAB_SERVER_REQUEST, name: "UngrabServer", has_reply: false },
+    RequestDescription { opcode: QUERY_POINTER_REQUEST, name: "QueryPointer", has_reply: true },
+    RequestDescription { opcode: GET_MOTION_EVENTS_REQUEST, name: "GetMotionEvents", has_reply: true },
+    RequestDescription { opcode: TRANSLATE_COORDINATES_REQUEST, name: "TranslateCoordinates", has_reply: true },
+    RequestDescription { opcode: WARP_POINTER_REQUEST, name: "WarpPointer", has_reply: false },
+    RequestDescription { opcode: SET_INPUT_FOCUS_REQUEST, name: "SetInputFocus", has_reply: false },
+    RequestDescription { opcode: GET_INPUT_FOCUS_REQUEST, name: "GetInputFocus", has_reply: true },
+    RequestDescription { opcode: QUERY_KEYMAP_REQUEST, name: "QueryKeymap", has_reply: true },
+    RequestDescription { opcode: OPEN_FONT_REQUEST, name: "OpenFont", has_reply: false },
+    RequestDescription { opcode: CLOSE_FONT_REQUEST, name: "CloseFont", has_reply: false },
+    RequestDescription { opcode: QUERY_FONT_REQUEST, name: "QueryFont", has_reply: true },
+    RequestDescription { opcode: QUERY_TEXT_EXTENTS_REQUEST, name: "QueryTextExtents", has_reply: true },
+    RequestDescription { opcode: LIST_FONTS_REQUEST, name: "ListFonts", has_reply: true },
+    RequestDescription { opcode: LIST_FONTS_WITH_INFO_REQUEST, name: "ListFontsWithInfo", has_reply: true },
+    RequestDescription { opcode: SET_FONT_PATH_REQUEST, name: "SetFontPath", has_reply: false },
+    RequestDescription { opcode: GET_FONT_PATH_REQUEST, name: "GetFontPath", has_reply: true },
+    RequestDescription { opcode: CREATE_PIXMAP_REQUEST, name: "CreatePixmap", has_reply: false },
+    RequestDescription { opcode: FREE_PIXMAP_REQUEST, name: "FreePixmap", has_reply: false },
+    RequestDescription { opcode: CREATE_GC_REQUEST, name: "CreateGC", has_reply: false },
+    RequestDescription { opcode: CHANGE_GC_REQUEST, name: "ChangeGC", has_reply: false },
+    RequestDescription { opcode: COPY_GC_REQUEST, name: "CopyGC", has_reply: false },
+    RequestDescription { opcode: SET_DASHES_REQUEST, name: "SetDashes", has_reply: false },
+    RequestDescription { opcode: SET_CLIP_RECTANGLES_REQUEST, name: "SetClipRectangles", has_reply: false },
+    RequestDescription { opcode: FREE_GC_REQUEST, name: "FreeGC", has_reply: false },
+    RequestDescription { opcode: CLEAR_AREA_REQUEST, name: "ClearArea", has_reply: false },
+    RequestDescription { opcode: COPY_AREA_REQUEST, name: "CopyArea", has_reply: false },
+    RequestDescription { opcode: COPY_PLANE_REQUEST, name: "CopyPlane", has_reply: false },
+    RequestDescription { opcode: POLY_POINT_REQUEST, name: "PolyPoint", has_reply: false },
+    RequestDescription { opcode: POLY_LINE_REQUEST, name: "PolyLine", has_reply: false },
+    RequestDescription { opcode: POLY_SEGMENT_REQUEST, name: "PolySegment", has_reply: false },
+    RequestDescription { opcode: POLY_RECTANGLE_REQUEST, name: "PolyRectangle", has_reply: false },
+    RequestDescription { opcode: POLY_ARC_REQUEST, name: "PolyArc", has_reply: false },
+    RequestDescription { opcode: FILL_POLY_REQUEST, name: "FillPoly", has_reply: false },
+    RequestDescription { opcode: POLY_FILL_RECTANGLE_REQUEST, name: "PolyFillRectangle", has_reply: false },
+    RequestDescription { opcode: POLY_FILL_ARC_REQUEST, name: "PolyFillArc", has_reply: false },
+    RequestDescription { opcode: PUT_IMAGE_REQUEST, name: "PutImage", has_reply: false },
+    RequestDescription { opcode: GET_IMAGE_REQUEST, name: "GetImage", has_reply: true },
+    RequestDescription { opcode: POLY_TEXT8_REQUEST, name: "PolyText8", has_reply: false },
+    RequestDescription { opcode: POLY_TEXT16_REQUEST, name: "PolyText16", has_reply: false },
+    RequestDescription { opcode: IMAGE_TEXT8_REQUEST, name: "ImageText8", has_reply: false },
+    RequestDescription { opcode: IMAGE_TEXT16_REQUEST, name: "ImageText16", has_reply: false },
+    RequestDescription { opcode: CREATE_COLORMAP_REQUEST, name: "CreateColormap", has_reply: false },
+    RequestDescription { opcode: FREE_COLORMAP_REQUEST, name: "FreeColormap", has_reply: false },
+    RequestDescription { opcode: COPY_COLORMAP_AND_FREE_REQUEST, name: "CopyColormapAndFree", has_reply: false },
+    RequestDescription { opcode: INSTALL_COLORMAP_REQUEST, name: "InstallColormap", has_reply: false },
+    RequestDescription { opcode: UNINSTALL_COLORMAP_REQUEST, name: "UninstallColormap", has_reply: false },
+    RequestDescription { opcode: LIST_INSTALLED_COLORMAPS_REQUEST, name: "ListInstalledColormaps", has_reply: true },
+    RequestDescription { opcode: ALLOC_COLOR_REQUEST, name: "AllocColor", has_reply: true },
+    RequestDescription { opcode: ALLOC_NAMED_COLOR_REQUEST, name: "AllocNamedColor", has_reply: true },
+    RequestDescription { opcode: ALLOC_COLOR_CELLS_REQUEST, name: "AllocColorCells", has_reply: true },
+    RequestDescription { opcode: ALLOC_COLOR_PLANES_REQUEST, name: "AllocColorPlanes", has_reply: true },
+    RequestDescription { opcode: FREE_COLORS_REQUEST, name: "FreeColors", has_reply: false },
+    RequestDescription { opcode: STORE_COLORS_REQUEST, name: "StoreColors", has_reply: false },
+    RequestDescription { opcode: STORE_NAMED_COLOR_REQUEST, name: "StoreNamedColor", has_reply: false },
+    RequestDescription { opcode: QUERY_COLORS_REQUEST, name: "QueryColors", has_reply: true },
+    RequestDescription { opcode: LOOKUP_COLOR_REQUEST, name: "LookupColor", has_reply: true },
+    RequestDescription { opcode: CREATE_CURSOR_REQUEST, name: "CreateCursor", has_reply: false },
+    RequestDescription { opcode: CREATE_GLYPH_CURSOR_REQUEST, name: "CreateGlyphCursor", has_reply: false },
+    RequestDescription { opcode: FREE_CURSOR_REQUEST, name: "FreeCursor", has_reply: false },
+    RequestDescription { opcode: RECOLOR_CURSOR_REQUEST, name: "RecolorCursor", has_reply: false },
+    RequestDescription { opcode: QUERY_BEST_SIZE_REQUEST, name: "QueryBestSize", has_reply: true },
+    RequestDescription { opcode: QUERY_EXTENSION_REQUEST, name: "QueryExtension", has_reply: true },
+    RequestDescription { opcode: LIST_EXTENSIONS_REQUEST, name: "ListExtensions", has_reply: true },
+    RequestDescription { opcode: CHANGE_KEYBOARD_MAPPING_REQUEST, name: "ChangeKeyboardMapping", has_reply: false },
+    RequestDescription { opcode: GET_KEYBOARD_MAPPING_REQUEST, name: "GetKeyboardMapping", has_reply: true },
+    RequestDescription { opcode: CHANGE_KEYBOARD_CONTROL_REQUEST, name: "ChangeKeyboardControl", has_reply: false },
+    RequestDescription { opcode: GET_KEYBOARD_CONTROL_REQUEST, name: "GetKeyboardControl", has_reply: true },
+    RequestDescription { opcode: BELL_REQUEST, name: "Bell", has_reply: false },
+    RequestDescription { opcode: CHANGE_POINTER_CONTROL_REQUEST, name: "ChangePointerControl", has_reply: false },
+    RequestDescription { opcode: GET_POINTER_CONTROL_REQUEST, name: "GetPointerControl", has_reply: true },
+    RequestDescription { opcode: SET_SCREEN_SAVER_REQUEST, name: "SetScreenSaver", has_reply: false },
+    RequestDescription { opcode: GET_SCREEN_SAVER_REQUEST, name: "GetScreenSaver", has_reply: true },
+    RequestDescription { opcode: CHANGE_HOSTS_REQUEST, name: "ChangeHosts", has_reply: false },
+    RequestDescription { opcode: LIST_HOSTS_REQUEST, name: "ListHosts", has_reply: true },
+    RequestDescription { opcode: SET_ACCESS_CONTROL_REQUEST, name: "SetAccessControl", has_reply: false },
+    RequestDescription { opcode: SET_CLOSE_DOWN_MODE_REQUEST, name: "SetCloseDownMode", has_reply: false },
+    RequestDescription { opcode: KILL_CLIENT_REQUEST, name: "KillClient", has_reply: false },
+    RequestDescription { opcode: ROTATE_PROPERTIES_REQUEST, name: "RotateProperties", has_reply: false },
+    RequestDescription { opcode: FORCE_SCREEN_SAVER_REQUEST, name: "ForceScreenSaver", has_reply: false },
+    RequestDescription { opcode: SET_POINTER_MAPPING_REQUEST, name: "SetPointerMapping", has_reply: true },
+    RequestDescription { opcode: GET_POINTER_MAPPING_REQUEST, name: "GetPointerMapping", has_reply: true },
+    RequestDescription { opcode: SET_MODIFIER_MAPPING_REQUEST, name: "SetModifierMapping", has_reply: true },
+    RequestDescription { opcode: GET_MODIFIER_MAPPING_REQUEST, name: "GetModifierMapping", has_reply: true },
+    RequestDescription { opcode: NO_OPERATION_REQUEST, name: "NoOperation", has_reply: false },
+];
+
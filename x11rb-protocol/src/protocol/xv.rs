@@ -22,6 +22,7 @@ use crate::BufWithFds;
 use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
 #[allow(unused_imports)]
 use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::x11_utils::RequestDescription;
 #[allow(unused_imports)]
 use super::shm;
 #[allow(unused_imports)]
@@ -44,6 +45,7 @@ pub type Encoding = u32;
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Type(u8);
 impl Type {
     pub const INPUT_MASK: Self = Self(1 << 0);
@@ -106,10 +108,29 @@ impl core::fmt::Debug for Type  {
         pretty_print_bitmask(fmt, self.0.into(), &variants)
     }
 }
+impl Type {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("InputMask"),
+            2 => Some("OutputMask"),
+            4 => Some("VideoMask"),
+            8 => Some("StillMask"),
+            16 => Some("ImageMask"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for Type {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(Type, u8);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ImageFormatInfoType(u8);
 impl ImageFormatInfoType {
     pub const RGB: Self = Self(0);
@@ -166,9 +187,25 @@ impl core::fmt::Debug for ImageFormatInfoType  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl ImageFormatInfoType {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("RGB"),
+            1 => Some("YUV"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ImageFormatInfoType {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ImageFormatInfoFormat(u8);
 impl ImageFormatInfoFormat {
     pub const PACKED: Self = Self(0);
@@ -225,9 +262,25 @@ impl core::fmt::Debug for ImageFormatInfoFormat  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl ImageFormatInfoFormat {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Packed"),
+            1 => Some("Planar"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ImageFormatInfoFormat {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct AttributeFlag(u32);
 impl AttributeFlag {
     pub const GETTABLE: Self = Self(1 << 0);
@@ -272,10 +325,26 @@ impl core::fmt::Debug for AttributeFlag  {
         pretty_print_bitmask(fmt, self.0, &variants)
     }
 }
+impl AttributeFlag {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            1 => Some("Gettable"),
+            2 => Some("Settable"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for AttributeFlag {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 bitmask_binop!(AttributeFlag, u32);
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct VideoNotifyReason(u8);
 impl VideoNotifyReason {
     pub const STARTED: Self = Self(0);
@@ -338,9 +407,28 @@ impl core::fmt::Debug for VideoNotifyReason  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl VideoNotifyReason {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Started"),
+            1 => Some("Stopped"),
+            2 => Some("Busy"),
+            3 => Some("Preempted"),
+            4 => Some("HardError"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for VideoNotifyReason {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ScanlineOrder(u8);
 impl ScanlineOrder {
     pub const TOP_TO_BOTTOM: Self = Self(0);
@@ -397,9 +485,25 @@ impl core::fmt::Debug for ScanlineOrder  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl ScanlineOrder {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("TopToBottom"),
+            1 => Some("BottomToTop"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for ScanlineOrder {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GrabPortStatus(u8);
 impl GrabPortStatus {
     pub const SUCCESS: Self = Self(0);
@@ -464,6 +568,25 @@ impl core::fmt::Debug for GrabPortStatus  {
         pretty_print_enum(fmt, self.0.into(), &variants)
     }
 }
+impl GrabPortStatus {
+    /// Get the name of this value if it is exactly one of the known values.
+    pub fn name(&self) -> Option<&'static str> {
+        match self.0 {
+            0 => Some("Success"),
+            1 => Some("BadExtension"),
+            2 => Some("AlreadyGrabbed"),
+            3 => Some("InvalidTime"),
+            4 => Some("BadReply"),
+            5 => Some("BadAlloc"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Display for GrabPortStatus {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "{:#?}", self)
+    }
+}
 
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
@@ -473,6 +596,12 @@ pub struct Rational {
     pub denominator: i32,
 }
 impl_debug_if_no_extra_traits!(Rational, "Rational");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Rational {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Rational {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (numerator, remaining) = i32::try_parse(remaining)?;
@@ -512,6 +641,12 @@ pub struct Format {
     pub depth: u8,
 }
 impl_debug_if_no_extra_traits!(Format, "Format");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Format {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Format {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (visual, remaining) = xproto::Visualid::try_parse(remaining)?;
@@ -556,6 +691,12 @@ pub struct AdaptorInfo {
     pub formats: Vec<Format>,
 }
 impl_debug_if_no_extra_traits!(AdaptorInfo, "AdaptorInfo");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for AdaptorInfo {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for AdaptorInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
@@ -639,6 +780,12 @@ pub struct EncodingInfo {
     pub name: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(EncodingInfo, "EncodingInfo");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for EncodingInfo {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for EncodingInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
@@ -706,6 +853,12 @@ pub struct Image {
     pub data: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(Image, "Image");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Image {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for Image {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (id, remaining) = u32::try_parse(remaining)?;
@@ -782,6 +935,12 @@ pub struct AttributeInfo {
     pub name: Vec<u8>,
 }
 impl_debug_if_no_extra_traits!(AttributeInfo, "AttributeInfo");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for AttributeInfo {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for AttributeInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let value = remaining;
@@ -862,6 +1021,12 @@ pub struct ImageFormatInfo {
     pub vscanline_order: ScanlineOrder,
 }
 impl_debug_if_no_extra_traits!(ImageFormatInfo, "ImageFormatInfo");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ImageFormatInfo {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 0)
+    }
+}
 impl TryParse for ImageFormatInfo {
     fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let (id, remaining) = u32::try_parse(remaining)?;
@@ -1108,6 +1273,12 @@ pub struct VideoNotifyEvent {
     pub port: Port,
 }
 impl_debug_if_no_extra_traits!(VideoNotifyEvent, "VideoNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for VideoNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for VideoNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1228,6 +1399,12 @@ pub struct PortNotifyEvent {
     pub value: i32,
 }
 impl_debug_if_no_extra_traits!(PortNotifyEvent, "PortNotifyEvent");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for PortNotifyEvent {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for PortNotifyEvent {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1344,6 +1521,7 @@ pub const QUERY_EXTENSION_REQUEST: u8 = 0;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryExtensionRequest;
 impl_debug_if_no_extra_traits!(QueryExtensionRequest, "QueryExtensionRequest");
 impl QueryExtensionRequest {
@@ -1382,6 +1560,28 @@ impl Request for QueryExtensionRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_EXTENSION_REQUEST,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryExtensionRequest {
     type Reply = QueryExtensionReply;
@@ -1397,6 +1597,12 @@ pub struct QueryExtensionReply {
     pub minor: u16,
 }
 impl_debug_if_no_extra_traits!(QueryExtensionReply, "QueryExtensionReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryExtensionReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryExtensionReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1456,6 +1662,7 @@ pub const QUERY_ADAPTORS_REQUEST: u8 = 1;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryAdaptorsRequest {
     pub window: xproto::Window,
 }
@@ -1503,6 +1710,33 @@ impl Request for QueryAdaptorsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let window_bytes = self.window.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_ADAPTORS_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryAdaptorsRequest {
     type Reply = QueryAdaptorsReply;
@@ -1517,6 +1751,12 @@ pub struct QueryAdaptorsReply {
     pub info: Vec<AdaptorInfo>,
 }
 impl_debug_if_no_extra_traits!(QueryAdaptorsReply, "QueryAdaptorsReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryAdaptorsReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryAdaptorsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1555,6 +1795,7 @@ impl Serialize for QueryAdaptorsReply {
         num_adaptors.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 22]);
         self.info.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl QueryAdaptorsReply {
@@ -1578,6 +1819,7 @@ pub const QUERY_ENCODINGS_REQUEST: u8 = 2;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryEncodingsRequest {
     pub port: Port,
 }
@@ -1625,6 +1867,33 @@ impl Request for QueryEncodingsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let port_bytes = self.port.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_ENCODINGS_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryEncodingsRequest {
     type Reply = QueryEncodingsReply;
@@ -1639,6 +1908,12 @@ pub struct QueryEncodingsReply {
     pub info: Vec<EncodingInfo>,
 }
 impl_debug_if_no_extra_traits!(QueryEncodingsReply, "QueryEncodingsReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryEncodingsReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryEncodingsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1677,6 +1952,7 @@ impl Serialize for QueryEncodingsReply {
         num_encodings.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 22]);
         self.info.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl QueryEncodingsReply {
@@ -1700,6 +1976,7 @@ pub const GRAB_PORT_REQUEST: u8 = 3;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GrabPortRequest {
     pub port: Port,
     pub time: xproto::Timestamp,
@@ -1755,6 +2032,38 @@ impl Request for GrabPortRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let port_bytes = self.port.serialize();
+        let time_bytes = self.time.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GRAB_PORT_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GrabPortRequest {
     type Reply = GrabPortReply;
@@ -1769,6 +2078,12 @@ pub struct GrabPortReply {
     pub length: u32,
 }
 impl_debug_if_no_extra_traits!(GrabPortReply, "GrabPortReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GrabPortReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GrabPortReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -1820,6 +2135,7 @@ pub const UNGRAB_PORT_REQUEST: u8 = 4;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct UngrabPortRequest {
     pub port: Port,
     pub time: xproto::Timestamp,
@@ -1875,6 +2191,38 @@ impl Request for UngrabPortRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let port_bytes = self.port.serialize();
+        let time_bytes = self.time.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            UNGRAB_PORT_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for UngrabPortRequest {
 }
@@ -1884,6 +2232,7 @@ pub const PUT_VIDEO_REQUEST: u8 = 5;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PutVideoRequest {
     pub port: Port,
     pub drawable: xproto::Drawable,
@@ -1995,6 +2344,67 @@ impl Request for PutVideoRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let port_bytes = self.port.serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        let vid_x_bytes = self.vid_x.serialize();
+        let vid_y_bytes = self.vid_y.serialize();
+        let vid_w_bytes = self.vid_w.serialize();
+        let vid_h_bytes = self.vid_h.serialize();
+        let drw_x_bytes = self.drw_x.serialize();
+        let drw_y_bytes = self.drw_y.serialize();
+        let drw_w_bytes = self.drw_w.serialize();
+        let drw_h_bytes = self.drw_h.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            PUT_VIDEO_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            vid_x_bytes[0],
+            vid_x_bytes[1],
+            vid_y_bytes[0],
+            vid_y_bytes[1],
+            vid_w_bytes[0],
+            vid_w_bytes[1],
+            vid_h_bytes[0],
+            vid_h_bytes[1],
+            drw_x_bytes[0],
+            drw_x_bytes[1],
+            drw_y_bytes[0],
+            drw_y_bytes[1],
+            drw_w_bytes[0],
+            drw_w_bytes[1],
+            drw_h_bytes[0],
+            drw_h_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for PutVideoRequest {
 }
@@ -2004,6 +2414,7 @@ pub const PUT_STILL_REQUEST: u8 = 6;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PutStillRequest {
     pub port: Port,
     pub drawable: xproto::Drawable,
@@ -2115,6 +2526,67 @@ impl Request for PutStillRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let port_bytes = self.port.serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        let vid_x_bytes = self.vid_x.serialize();
+        let vid_y_bytes = self.vid_y.serialize();
+        let vid_w_bytes = self.vid_w.serialize();
+        let vid_h_bytes = self.vid_h.serialize();
+        let drw_x_bytes = self.drw_x.serialize();
+        let drw_y_bytes = self.drw_y.serialize();
+        let drw_w_bytes = self.drw_w.serialize();
+        let drw_h_bytes = self.drw_h.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            PUT_STILL_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            vid_x_bytes[0],
+            vid_x_bytes[1],
+            vid_y_bytes[0],
+            vid_y_bytes[1],
+            vid_w_bytes[0],
+            vid_w_bytes[1],
+            vid_h_bytes[0],
+            vid_h_bytes[1],
+            drw_x_bytes[0],
+            drw_x_bytes[1],
+            drw_y_bytes[0],
+            drw_y_bytes[1],
+            drw_w_bytes[0],
+            drw_w_bytes[1],
+            drw_h_bytes[0],
+            drw_h_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for PutStillRequest {
 }
@@ -2124,6 +2596,7 @@ pub const GET_VIDEO_REQUEST: u8 = 7;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetVideoRequest {
     pub port: Port,
     pub drawable: xproto::Drawable,
@@ -2235,15 +2708,77 @@ impl Request for GetVideoRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
-}
-impl crate::x11_utils::VoidRequest for GetVideoRequest {
-}
-
-/// Opcode for the GetStill request
-pub const GET_STILL_REQUEST: u8 = 8;
-#[derive(Clone, Copy, Default)]
-#[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let port_bytes = self.port.serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        let vid_x_bytes = self.vid_x.serialize();
+        let vid_y_bytes = self.vid_y.serialize();
+        let vid_w_bytes = self.vid_w.serialize();
+        let vid_h_bytes = self.vid_h.serialize();
+        let drw_x_bytes = self.drw_x.serialize();
+        let drw_y_bytes = self.drw_y.serialize();
+        let drw_w_bytes = self.drw_w.serialize();
+        let drw_h_bytes = self.drw_h.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_VIDEO_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            vid_x_bytes[0],
+            vid_x_bytes[1],
+            vid_y_bytes[0],
+            vid_y_bytes[1],
+            vid_w_bytes[0],
+            vid_w_bytes[1],
+            vid_h_bytes[0],
+            vid_h_bytes[1],
+            drw_x_bytes[0],
+            drw_x_bytes[1],
+            drw_y_bytes[0],
+            drw_y_bytes[1],
+            drw_w_bytes[0],
+            drw_w_bytes[1],
+            drw_h_bytes[0],
+            drw_h_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
+}
+impl crate::x11_utils::VoidRequest for GetVideoRequest {
+}
+
+/// Opcode for the GetStill request
+pub const GET_STILL_REQUEST: u8 = 8;
+#[derive(Clone, Copy, Default)]
+#[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetStillRequest {
     pub port: Port,
     pub drawable: xproto::Drawable,
@@ -2355,6 +2890,67 @@ impl Request for GetStillRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let port_bytes = self.port.serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        let vid_x_bytes = self.vid_x.serialize();
+        let vid_y_bytes = self.vid_y.serialize();
+        let vid_w_bytes = self.vid_w.serialize();
+        let vid_h_bytes = self.vid_h.serialize();
+        let drw_x_bytes = self.drw_x.serialize();
+        let drw_y_bytes = self.drw_y.serialize();
+        let drw_w_bytes = self.drw_w.serialize();
+        let drw_h_bytes = self.drw_h.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_STILL_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            vid_x_bytes[0],
+            vid_x_bytes[1],
+            vid_y_bytes[0],
+            vid_y_bytes[1],
+            vid_w_bytes[0],
+            vid_w_bytes[1],
+            vid_h_bytes[0],
+            vid_h_bytes[1],
+            drw_x_bytes[0],
+            drw_x_bytes[1],
+            drw_y_bytes[0],
+            drw_y_bytes[1],
+            drw_w_bytes[0],
+            drw_w_bytes[1],
+            drw_h_bytes[0],
+            drw_h_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for GetStillRequest {
 }
@@ -2364,6 +2960,7 @@ pub const STOP_VIDEO_REQUEST: u8 = 9;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct StopVideoRequest {
     pub port: Port,
     pub drawable: xproto::Drawable,
@@ -2419,6 +3016,38 @@ impl Request for StopVideoRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let port_bytes = self.port.serialize();
+        let drawable_bytes = self.drawable.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            STOP_VIDEO_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for StopVideoRequest {
 }
@@ -2428,6 +3057,7 @@ pub const SELECT_VIDEO_NOTIFY_REQUEST: u8 = 10;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SelectVideoNotifyRequest {
     pub drawable: xproto::Drawable,
     pub onoff: bool,
@@ -2484,6 +3114,38 @@ impl Request for SelectVideoNotifyRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let drawable_bytes = self.drawable.serialize();
+        let onoff_bytes = self.onoff.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SELECT_VIDEO_NOTIFY_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            onoff_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SelectVideoNotifyRequest {
 }
@@ -2493,6 +3155,7 @@ pub const SELECT_PORT_NOTIFY_REQUEST: u8 = 11;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SelectPortNotifyRequest {
     pub port: Port,
     pub onoff: bool,
@@ -2549,6 +3212,38 @@ impl Request for SelectPortNotifyRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let port_bytes = self.port.serialize();
+        let onoff_bytes = self.onoff.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SELECT_PORT_NOTIFY_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+            onoff_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SelectPortNotifyRequest {
 }
@@ -2558,6 +3253,7 @@ pub const QUERY_BEST_SIZE_REQUEST: u8 = 12;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryBestSizeRequest {
     pub port: Port,
     pub vid_w: u16,
@@ -2638,6 +3334,50 @@ impl Request for QueryBestSizeRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let port_bytes = self.port.serialize();
+        let vid_w_bytes = self.vid_w.serialize();
+        let vid_h_bytes = self.vid_h.serialize();
+        let drw_w_bytes = self.drw_w.serialize();
+        let drw_h_bytes = self.drw_h.serialize();
+        let motion_bytes = self.motion.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_BEST_SIZE_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+            vid_w_bytes[0],
+            vid_w_bytes[1],
+            vid_h_bytes[0],
+            vid_h_bytes[1],
+            drw_w_bytes[0],
+            drw_w_bytes[1],
+            drw_h_bytes[0],
+            drw_h_bytes[1],
+            motion_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryBestSizeRequest {
     type Reply = QueryBestSizeReply;
@@ -2653,6 +3393,12 @@ pub struct QueryBestSizeReply {
     pub actual_height: u16,
 }
 impl_debug_if_no_extra_traits!(QueryBestSizeReply, "QueryBestSizeReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryBestSizeReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryBestSizeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2712,6 +3458,7 @@ pub const SET_PORT_ATTRIBUTE_REQUEST: u8 = 13;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SetPortAttributeRequest {
     pub port: Port,
     pub attribute: xproto::Atom,
@@ -2775,6 +3522,43 @@ impl Request for SetPortAttributeRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let port_bytes = self.port.serialize();
+        let attribute_bytes = self.attribute.serialize();
+        let value_bytes = self.value.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SET_PORT_ATTRIBUTE_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+            attribute_bytes[0],
+            attribute_bytes[1],
+            attribute_bytes[2],
+            attribute_bytes[3],
+            value_bytes[0],
+            value_bytes[1],
+            value_bytes[2],
+            value_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for SetPortAttributeRequest {
 }
@@ -2784,6 +3568,7 @@ pub const GET_PORT_ATTRIBUTE_REQUEST: u8 = 14;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GetPortAttributeRequest {
     pub port: Port,
     pub attribute: xproto::Atom,
@@ -2839,6 +3624,38 @@ impl Request for GetPortAttributeRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let port_bytes = self.port.serialize();
+        let attribute_bytes = self.attribute.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            GET_PORT_ATTRIBUTE_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+            attribute_bytes[0],
+            attribute_bytes[1],
+            attribute_bytes[2],
+            attribute_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for GetPortAttributeRequest {
     type Reply = GetPortAttributeReply;
@@ -2853,6 +3670,12 @@ pub struct GetPortAttributeReply {
     pub value: i32,
 }
 impl_debug_if_no_extra_traits!(GetPortAttributeReply, "GetPortAttributeReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GetPortAttributeReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for GetPortAttributeReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -2909,6 +3732,7 @@ pub const QUERY_PORT_ATTRIBUTES_REQUEST: u8 = 15;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryPortAttributesRequest {
     pub port: Port,
 }
@@ -2956,6 +3780,33 @@ impl Request for QueryPortAttributesRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let port_bytes = self.port.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_PORT_ATTRIBUTES_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryPortAttributesRequest {
     type Reply = QueryPortAttributesReply;
@@ -2971,6 +3822,12 @@ pub struct QueryPortAttributesReply {
     pub attributes: Vec<AttributeInfo>,
 }
 impl_debug_if_no_extra_traits!(QueryPortAttributesReply, "QueryPortAttributesReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryPortAttributesReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryPortAttributesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -3011,6 +3868,7 @@ impl Serialize for QueryPortAttributesReply {
         self.text_size.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 16]);
         self.attributes.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl QueryPortAttributesReply {
@@ -3034,6 +3892,7 @@ pub const LIST_IMAGE_FORMATS_REQUEST: u8 = 16;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ListImageFormatsRequest {
     pub port: Port,
 }
@@ -3081,6 +3940,33 @@ impl Request for ListImageFormatsRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let port_bytes = self.port.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            LIST_IMAGE_FORMATS_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for ListImageFormatsRequest {
     type Reply = ListImageFormatsReply;
@@ -3095,6 +3981,12 @@ pub struct ListImageFormatsReply {
     pub format: Vec<ImageFormatInfo>,
 }
 impl_debug_if_no_extra_traits!(ListImageFormatsReply, "ListImageFormatsReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ListImageFormatsReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for ListImageFormatsReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -3133,6 +4025,7 @@ impl Serialize for ListImageFormatsReply {
         num_formats.serialize_into(bytes);
         bytes.extend_from_slice(&[0; 20]);
         self.format.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl ListImageFormatsReply {
@@ -3156,6 +4049,7 @@ pub const QUERY_IMAGE_ATTRIBUTES_REQUEST: u8 = 17;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QueryImageAttributesRequest {
     pub port: Port,
     pub id: u32,
@@ -3223,6 +4117,44 @@ impl Request for QueryImageAttributesRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let port_bytes = self.port.serialize();
+        let id_bytes = self.id.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            QUERY_IMAGE_ATTRIBUTES_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::ReplyRequest for QueryImageAttributesRequest {
     type Reply = QueryImageAttributesReply;
@@ -3241,6 +4173,12 @@ pub struct QueryImageAttributesReply {
     pub offsets: Vec<u32>,
 }
 impl_debug_if_no_extra_traits!(QueryImageAttributesReply, "QueryImageAttributesReply");
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for QueryImageAttributesReply {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::x11_utils::arbitrary_from_wire(u, 32)
+    }
+}
 impl TryParse for QueryImageAttributesReply {
     fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
         let remaining = initial_value;
@@ -3288,6 +4226,7 @@ impl Serialize for QueryImageAttributesReply {
         self.pitches.serialize_into(bytes);
         assert_eq!(self.offsets.len(), usize::try_from(num_planes).unwrap(), "`offsets` has an incorrect length");
         self.offsets.serialize_into(bytes);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
     }
 }
 impl QueryImageAttributesReply {
@@ -3311,6 +4250,7 @@ pub const PUT_IMAGE_REQUEST: u8 = 18;
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PutImageRequest<'input> {
     pub port: Port,
     pub drawable: xproto::Drawable,
@@ -3468,6 +4408,81 @@ impl<'input> Request for PutImageRequest<'input> {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let port_bytes = self.port.serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        let id_bytes = self.id.serialize();
+        let src_x_bytes = self.src_x.serialize();
+        let src_y_bytes = self.src_y.serialize();
+        let src_w_bytes = self.src_w.serialize();
+        let src_h_bytes = self.src_h.serialize();
+        let drw_x_bytes = self.drw_x.serialize();
+        let drw_y_bytes = self.drw_y.serialize();
+        let drw_w_bytes = self.drw_w.serialize();
+        let drw_h_bytes = self.drw_h.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            PUT_IMAGE_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3],
+            src_x_bytes[0],
+            src_x_bytes[1],
+            src_y_bytes[0],
+            src_y_bytes[1],
+            src_w_bytes[0],
+            src_w_bytes[1],
+            src_h_bytes[0],
+            src_h_bytes[1],
+            drw_x_bytes[0],
+            drw_x_bytes[1],
+            drw_y_bytes[0],
+            drw_y_bytes[1],
+            drw_w_bytes[0],
+            drw_w_bytes[1],
+            drw_h_bytes[0],
+            drw_h_bytes[1],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+        ]);
+        buffer.extend_from_slice(&self.data[..]);
+        let length_so_far = buffer.len() - request_start;
+        buffer.extend_from_slice(&[0; 3][..(4 - (length_so_far % 4)) % 4]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl<'input> crate::x11_utils::VoidRequest for PutImageRequest<'input> {
 }
@@ -3477,6 +4492,7 @@ pub const SHM_PUT_IMAGE_REQUEST: u8 = 19;
 #[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ShmPutImageRequest {
     pub port: Port,
     pub drawable: xproto::Drawable,
@@ -3633,7 +4649,118 @@ impl Request for ShmPutImageRequest {
         let buf = bufs.iter().flat_map(|buf| buf.iter().copied()).collect();
         (buf, fds)
     }
+    fn serialize_into(self, major_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer> {
+        let request_start = buffer.len();
+        let port_bytes = self.port.serialize();
+        let drawable_bytes = self.drawable.serialize();
+        let gc_bytes = self.gc.serialize();
+        let shmseg_bytes = self.shmseg.serialize();
+        let id_bytes = self.id.serialize();
+        let offset_bytes = self.offset.serialize();
+        let src_x_bytes = self.src_x.serialize();
+        let src_y_bytes = self.src_y.serialize();
+        let src_w_bytes = self.src_w.serialize();
+        let src_h_bytes = self.src_h.serialize();
+        let drw_x_bytes = self.drw_x.serialize();
+        let drw_y_bytes = self.drw_y.serialize();
+        let drw_w_bytes = self.drw_w.serialize();
+        let drw_h_bytes = self.drw_h.serialize();
+        let width_bytes = self.width.serialize();
+        let height_bytes = self.height.serialize();
+        let send_event_bytes = self.send_event.serialize();
+        buffer.extend_from_slice(&[
+            major_opcode,
+            SHM_PUT_IMAGE_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            shmseg_bytes[0],
+            shmseg_bytes[1],
+            shmseg_bytes[2],
+            shmseg_bytes[3],
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3],
+            offset_bytes[0],
+            offset_bytes[1],
+            offset_bytes[2],
+            offset_bytes[3],
+            src_x_bytes[0],
+            src_x_bytes[1],
+            src_y_bytes[0],
+            src_y_bytes[1],
+            src_w_bytes[0],
+            src_w_bytes[1],
+            src_h_bytes[0],
+            src_h_bytes[1],
+            drw_x_bytes[0],
+            drw_x_bytes[1],
+            drw_y_bytes[0],
+            drw_y_bytes[1],
+            drw_w_bytes[0],
+            drw_w_bytes[1],
+            drw_h_bytes[0],
+            drw_h_bytes[1],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+            send_event_bytes[0],
+            0,
+            0,
+            0,
+        ]);
+        let length_so_far = buffer.len() - request_start;
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        buffer[request_start + 2..request_start + 4].copy_from_slice(&length.to_ne_bytes());
+        vec![]
+    }
+    fn serialize_into_slices<'s>(self, major_opcode: u8, slices: &mut Vec<Cow<'s, [u8]>>) -> Vec<RawFdContainer>
+    where
+        Self: 's,
+    {
+        let (bufs, fds) = self.serialize(major_opcode);
+        slices.extend(bufs);
+        fds
+    }
 }
 impl crate::x11_utils::VoidRequest for ShmPutImageRequest {
 }
 
+/// All requests of this module.
+pub const REQUESTS: &[RequestDescription] = &[
+    RequestDescription { opcode: QUERY_EXTENSION_REQUEST, name: "QueryExtension", has_reply: true },
+    RequestDescription { opcode: QUERY_ADAPTORS_REQUEST, name: "QueryAdaptors", has_reply: true },
+    RequestDescription { opcode: QUERY_ENCODINGS_REQUEST, name: "QueryEncodings", has_reply: true },
+    RequestDescription { opcode: GRAB_PORT_REQUEST, name: "GrabPort", has_reply: true },
+    RequestDescription { opcode: UNGRAB_PORT_REQUEST, name: "UngrabPort", has_reply: false },
+    RequestDescription { opcode: PUT_VIDEO_REQUEST, name: "PutVideo", has_reply: false },
+    RequestDescription { opcode: PUT_STILL_REQUEST, name: "PutStill", has_reply: false },
+    RequestDescription { opcode: GET_VIDEO_REQUEST, name: "GetVideo", has_reply: false },
+    RequestDescription { opcode: GET_STILL_REQUEST, name: "GetStill", has_reply: false },
+    RequestDescription { opcode: STOP_VIDEO_REQUEST, name: "StopVideo", has_reply: false },
+    RequestDescription { opcode: SELECT_VIDEO_NOTIFY_REQUEST, name: "SelectVideoNotify", has_reply: false },
+    RequestDescription { opcode: SELECT_PORT_NOTIFY_REQUEST, name: "SelectPortNotify", has_reply: false },
+    RequestDescription { opcode: QUERY_BEST_SIZE_REQUEST, name: "QueryBestSize", has_reply: true },
+    RequestDescription { opcode: SET_PORT_ATTRIBUTE_REQUEST, name: "SetPortAttribute", has_reply: false },
+    RequestDescription { opcode: GET_PORT_ATTRIBUTE_REQUEST, name: "GetPortAttribute", has_reply: true },
+    RequestDescription { opcode: QUERY_PORT_ATTRIBUTES_REQUEST, name: "QueryPortAttributes", has_reply: true },
+    RequestDescription { opcode: LIST_IMAGE_FORMATS_REQUEST, name: "ListImageFormats", has_reply: true },
+    RequestDescription { opcode: QUERY_IMAGE_ATTRIBUTES_REQUEST, name: "QueryImageAttributes", has_reply: true },
+    RequestDescription { opcode: PUT_IMAGE_REQUEST, name: "PutImage", has_reply: false },
+    RequestDescription { opcode: SHM_PUT_IMAGE_REQUEST, name: "ShmPutImage", has_reply: false },
+];
+
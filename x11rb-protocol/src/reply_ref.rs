@@ -0,0 +1,342 @@
+//! Borrowed variants of replies that can carry a lot of data.
+//!
+//! Parsing a reply via [`TryParse`] copies all lists out of the received packet. For replies like
+//! `GetImage` that can be many megabytes large, this copy is noticeable. The types in this module
+//! instead borrow their lists from the packet.
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::errors::ParseError;
+use crate::protocol::xproto::{
+    Atom, GetImageReply, GetPropertyReply, QueryTreeReply, Visualid, Window,
+};
+use crate::x11_utils::{BorrowedReply, TryIntoUSize, TryParse, TryParseRef};
+
+/// A list of fixed-size elements that are parsed on access.
+pub struct ListRef<'a, T> {
+    data: &'a [u8],
+    len: usize,
+    phantom: PhantomData<T>,
+}
+
+impl<'a, T: TryParse> ListRef<'a, T> {
+    fn new(data: &'a [u8], len: usize) -> Self {
+        Self {
+            data,
+            len,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Get the number of elements in this list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check whether this list is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get the raw bytes of this list.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Iterate over the elements of this list.
+    pub fn iter(&self) -> ListRefIter<'a, T> {
+        ListRefIter {
+            remaining: self.data,
+            len: self.len,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> Clone for ListRef<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ListRef<'_, T> {}
+
+impl<T: TryParse + fmt::Debug> fmt::Debug for ListRef<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T: TryParse> IntoIterator for ListRef<'a, T> {
+    type Item = T;
+    type IntoIter = ListRefIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the elements of a [`ListRef`].
+#[derive(Debug, Clone)]
+pub struct ListRefIter<'a, T> {
+    remaining: &'a [u8],
+    len: usize,
+    phantom: PhantomData<T>,
+}
+
+impl<T: TryParse> Iterator for ListRefIter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        // The length of the list was checked when it was parsed
+        let (value, remaining) = T::try_parse(self.remaining).ok()?;
+        self.remaining = remaining;
+        self.len -= 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T: TryParse> ExactSizeIterator for ListRefIter<'_, T> {}
+
+/// Parse the header of a reply and check that it really is a reply.
+///
+/// Returns the second byte of the reply, the sequence number, the length field and the data after
+/// the length field.
+fn parse_reply_header(value: &[u8]) -> Result<(u8, u16, u32, &[u8]), ParseError> {
+    let (response_type, remaining) = u8::try_parse(value)?;
+    let (data, remaining) = u8::try_parse(remaining)?;
+    let (sequence, remaining) = u16::try_parse(remaining)?;
+    let (length, remaining) = u32::try_parse(remaining)?;
+    if response_type != 1 {
+        return Err(ParseError::InvalidValue);
+    }
+    Ok((data, sequence, length, remaining))
+}
+
+/// Get the data after the end of the reply with the given length field.
+fn reply_end(value: &[u8], length: u32) -> Result<&[u8], ParseError> {
+    let length = length
+        .checked_mul(4)
+        .ok_or(ParseError::InvalidExpression)?
+        .try_to_usize()?;
+    value.get(32 + length..).ok_or(ParseError::InsufficientData)
+}
+
+/// A borrowed variant of [`GetImageReply`].
+#[derive(Debug, Clone, Copy)]
+pub struct GetImageReplyRef<'a> {
+    /// The depth of the image.
+    pub depth: u8,
+    /// The sequence number of the request.
+    pub sequence: u16,
+    /// The visual of the window, or zero for pixmaps.
+    pub visual: Visualid,
+    /// The image data.
+    pub data: &'a [u8],
+}
+
+impl<'a> TryParseRef<'a> for GetImageReplyRef<'a> {
+    fn try_parse_ref(value: &'a [u8]) -> Result<(Self, &'a [u8]), ParseError> {
+        let (depth, sequence, length, remaining) = parse_reply_header(value)?;
+        let (visual, remaining) = Visualid::try_parse(remaining)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let after = reply_end(value, length)?;
+        let data = &remaining[..remaining.len() - after.len()];
+        let result = GetImageReplyRef {
+            depth,
+            sequence,
+            visual,
+            data,
+        };
+        Ok((result, after))
+    }
+}
+
+impl<'a> BorrowedReply<'a> for GetImageReply {
+    type Ref = GetImageReplyRef<'a>;
+}
+
+/// A borrowed variant of [`QueryTreeReply`].
+#[derive(Debug, Clone, Copy)]
+pub struct QueryTreeReplyRef<'a> {
+    /// The sequence number of the request.
+    pub sequence: u16,
+    /// The root window.
+    pub root: Window,
+    /// The parent window.
+    pub parent: Window,
+    /// The children in bottom-to-top stacking order.
+    pub children: ListRef<'a, Window>,
+}
+
+impl<'a> TryParseRef<'a> for QueryTreeReplyRef<'a> {
+    fn try_parse_ref(value: &'a [u8]) -> Result<(Self, &'a [u8]), ParseError> {
+        let (_, sequence, length, remaining) = parse_reply_header(value)?;
+        let (root, remaining) = Window::try_parse(remaining)?;
+        let (parent, remaining) = Window::try_parse(remaining)?;
+        let (children_len, remaining) = u16::try_parse(remaining)?;
+        let remaining = remaining.get(14..).ok_or(ParseError::InsufficientData)?;
+        let children_len = children_len.try_to_usize()?;
+        let children = remaining
+            .get(..children_len * 4)
+            .ok_or(ParseError::InsufficientData)?;
+        let result = QueryTreeReplyRef {
+            sequence,
+            root,
+            parent,
+            children: ListRef::new(children, children_len),
+        };
+        Ok((result, reply_end(value, length)?))
+    }
+}
+
+impl<'a> BorrowedReply<'a> for QueryTreeReply {
+    type Ref = QueryTreeReplyRef<'a>;
+}
+
+/// A borrowed variant of [`GetPropertyReply`].
+#[derive(Debug, Clone, Copy)]
+pub struct GetPropertyReplyRef<'a> {
+    /// The format of the property value: 8, 16 or 32, or 0 if the property does not exist.
+    pub format: u8,
+    /// The sequence number of the request.
+    pub sequence: u16,
+    /// The type of the property.
+    pub type_: Atom,
+    /// The number of bytes of the property that were not returned.
+    pub bytes_after: u32,
+    /// The number of elements in the value.
+    pub value_len: u32,
+    /// The raw value of the property.
+    pub value: &'a [u8],
+}
+
+impl<'a> TryParseRef<'a> for GetPropertyReplyRef<'a> {
+    fn try_parse_ref(value: &'a [u8]) -> Result<(Self, &'a [u8]), ParseError> {
+        let initial_value = value;
+        let (format, sequence, length, remaining) = parse_reply_header(value)?;
+        // Other formats would make the length of the value meaningless
+        if ![0, 8, 16, 32].contains(&format) {
+            return Err(ParseError::InvalidValue);
+        }
+        let (type_, remaining) = Atom::try_parse(remaining)?;
+        let (bytes_after, remaining) = u32::try_parse(remaining)?;
+        let (value_len, remaining) = u32::try_parse(remaining)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let value_bytes = value_len
+            .checked_mul(u32::from(format) / 8)
+            .ok_or(ParseError::InvalidExpression)?
+            .try_to_usize()?;
+        let value = remaining
+            .get(..value_bytes)
+            .ok_or(ParseError::InsufficientData)?;
+        let result = GetPropertyReplyRef {
+            format,
+            sequence,
+            type_,
+            bytes_after,
+            value_len,
+            value,
+        };
+        Ok((result, reply_end(initial_value, length)?))
+    }
+}
+
+impl<'a> BorrowedReply<'a> for GetPropertyReply {
+    type Ref = GetPropertyReplyRef<'a>;
+}
+
+#[cfg(test)]
+mod test {
+    use super::{GetImageReplyRef, GetPropertyReplyRef, QueryTreeReplyRef};
+    use crate::errors::ParseError;
+    use crate::protocol::xproto::{GetImageReply, GetPropertyReply, QueryTreeReply};
+    use crate::x11_utils::{Serialize, TryParseRef};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn get_image() {
+        let reply = GetImageReply {
+            depth: 24,
+            sequence: 7,
+            visual: 0x21,
+            data: vec![1, 2, 3, 4, 5, 6, 7, 8],
+        };
+        let bytes = reply.serialize();
+        let (reply_ref, remaining) = GetImageReplyRef::try_parse_ref(&bytes).unwrap();
+        assert!(remaining.is_empty());
+        assert_eq!(
+            (reply_ref.depth, reply_ref.sequence, reply_ref.visual),
+            (24, 7, 0x21)
+        );
+        assert_eq!(reply_ref.data, &reply.data[..]);
+    }
+
+    #[test]
+    fn query_tree() {
+        let reply = QueryTreeReply {
+            sequence: 3,
+            length: 3,
+            root: 1,
+            parent: 2,
+            children: vec![10, 11, 12],
+        };
+        let bytes = reply.serialize();
+        let (reply_ref, remaining) = QueryTreeReplyRef::try_parse_ref(&bytes).unwrap();
+        assert!(remaining.is_empty());
+        assert_eq!((reply_ref.root, reply_ref.parent), (1, 2));
+        assert_eq!(reply_ref.children.len(), 3);
+        assert_eq!(
+            reply_ref.children.iter().collect::<Vec<_>>(),
+            reply.children
+        );
+    }
+
+    #[test]
+    fn get_property_format() {
+        let reply = GetPropertyReply {
+            format: 16,
+            sequence: 5,
+            length: 1,
+            type_: 19,
+            bytes_after: 0,
+            value_len: 2,
+            value: vec![1, 2, 3, 4],
+        };
+        let mut bytes = reply.serialize();
+        let (reply_ref, remaining) = GetPropertyReplyRef::try_parse_ref(&bytes).unwrap();
+        assert!(remaining.is_empty());
+        assert_eq!((reply_ref.format, reply_ref.value_len), (16, 2));
+        assert_eq!(reply_ref.value, &reply.value[..]);
+
+        // Formats other than 0, 8, 16 and 32 are rejected
+        for format in [1, 7, 24, 64] {
+            bytes[1] = format;
+            assert_eq!(
+                GetPropertyReplyRef::try_parse_ref(&bytes).unwrap_err(),
+                ParseError::InvalidValue
+            );
+        }
+    }
+
+    #[test]
+    fn truncated_reply() {
+        let reply = GetImageReply {
+            depth: 24,
+            sequence: 7,
+            visual: 0x21,
+            data: vec![0; 16],
+        };
+        let bytes = reply.serialize();
+        assert!(GetImageReplyRef::try_parse_ref(&bytes[..bytes.len() - 4]).is_err());
+    }
+}
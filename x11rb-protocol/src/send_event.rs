@@ -0,0 +1,192 @@
+//! Building `SendEvent` requests from typed events.
+//!
+//! The `SendEvent` request contains an event in its 32 byte wire format. All event structs can be
+//! converted into this format, but the X11 server rejects some events with a `Value` error, e.g.
+//! when the `response_type` field was left at zero. [`SendEventBuilder`] checks for these cases
+//! before anything is sent.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use x11rb_protocol::send_event::SendEventBuilder;
+//! # use x11rb_protocol::protocol::xproto::{ClientMessageEvent, EventMask, Window};
+//! let window: Window = 0x1234;
+//! let root: Window = 0x1;
+//! let net_active_window = 0x42u32;
+//! let event = ClientMessageEvent::new(32, window, net_active_window, [1u32, 0, 0, 0, 0]);
+//! let request = SendEventBuilder::new(root, event)
+//!     .event_mask(EventMask::SUBSTRUCTURE_REDIRECT | EventMask::SUBSTRUCTURE_NOTIFY)
+//!     .build()
+//!     .unwrap();
+//! // The request can now be sent with e.g. `send_trait_request_without_reply`.
+//! # let _ = request;
+//! ```
+
+use alloc::borrow::Cow;
+
+use crate::errors::InvalidSendEvent;
+use crate::protocol::xproto::{
+    EventMask, SendEventRequest, Window, CLIENT_MESSAGE_EVENT, GE_GENERIC_EVENT, KEY_PRESS_EVENT,
+};
+
+/// The first event code that is not used by the core protocol.
+const LAST_CORE_EVENT: u8 = GE_GENERIC_EVENT;
+
+/// The first event code that is used by extensions.
+const FIRST_EXTENSION_EVENT: u8 = 64;
+
+/// All bits that may be set in an event mask.
+const ALL_EVENT_MASKS: u32 = (1 << 25) - 1;
+
+/// The bit that the X11 server sets in the response type of events sent via `SendEvent`.
+const SEND_EVENT_BIT: u8 = 0x80;
+
+/// A builder for `SendEvent` requests.
+///
+/// By default, the event is not propagated and sent with an empty event mask. This means that the
+/// event is sent to the client that created the destination window.
+#[derive(Debug, Clone, Copy)]
+pub struct SendEventBuilder {
+    destination: Window,
+    propagate: bool,
+    event_mask: EventMask,
+    event: [u8; 32],
+}
+
+impl SendEventBuilder {
+    /// Create a builder for sending `event` to `destination`.
+    ///
+    /// `destination` can also be one of the values of
+    /// [`SendEventDest`](crate::protocol::xproto::SendEventDest).
+    pub fn new(destination: impl Into<Window>, event: impl Into<[u8; 32]>) -> Self {
+        Self {
+            destination: destination.into(),
+            propagate: false,
+            event_mask: EventMask::NO_EVENT,
+            event: event.into(),
+        }
+    }
+
+    /// Set whether the event is propagated to ancestors of the destination that select it.
+    pub fn propagate(mut self, propagate: bool) -> Self {
+        self.propagate = propagate;
+        self
+    }
+
+    /// Set the event mask that selects which clients receive the event.
+    pub fn event_mask(mut self, event_mask: impl Into<EventMask>) -> Self {
+        self.event_mask = event_mask.into();
+        self
+    }
+
+    /// Check that the X11 server would accept the request.
+    ///
+    /// Extension events are only checked for being in the range of extension event codes.
+    pub fn validate(&self) -> Result<(), InvalidSendEvent> {
+        let response_type = self.event[0];
+        if response_type & SEND_EVENT_BIT != 0 {
+            return Err(InvalidSendEvent::SendEventFlag);
+        }
+        if response_type == GE_GENERIC_EVENT {
+            return Err(InvalidSendEvent::GenericEvent);
+        }
+        if !(KEY_PRESS_EVENT..LAST_CORE_EVENT).contains(&response_type)
+            && response_type < FIRST_EXTENSION_EVENT
+        {
+            return Err(InvalidSendEvent::ResponseType(response_type));
+        }
+        if response_type == CLIENT_MESSAGE_EVENT && ![8, 16, 32].contains(&self.event[1]) {
+            return Err(InvalidSendEvent::ClientMessageFormat(self.event[1]));
+        }
+        let event_mask = u32::from(self.event_mask);
+        if event_mask & !ALL_EVENT_MASKS != 0 {
+            return Err(InvalidSendEvent::EventMask(event_mask));
+        }
+        Ok(())
+    }
+
+    /// Validate the event and produce the `SendEvent` request.
+    pub fn build(self) -> Result<SendEventRequest<'static>, InvalidSendEvent> {
+        self.validate()?;
+        Ok(SendEventRequest {
+            propagate: self.propagate,
+            destination: self.destination,
+            event_mask: self.event_mask,
+            event: Cow::Owned(self.event),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SendEventBuilder;
+    use crate::errors::InvalidSendEvent;
+    use crate::protocol::xproto::{
+        ClientMessageEvent, ConfigureNotifyEvent, EventMask, SendEventDest, CONFIGURE_NOTIFY_EVENT,
+    };
+
+    fn configure_notify(response_type: u8) -> ConfigureNotifyEvent {
+        ConfigureNotifyEvent {
+            response_type,
+            sequence: 0,
+            event: 1,
+            window: 1,
+            above_sibling: 0,
+            x: 10,
+            y: 20,
+            width: 100,
+            height: 50,
+            border_width: 0,
+            override_redirect: false,
+        }
+    }
+
+    #[test]
+    fn valid_event() {
+        let request = SendEventBuilder::new(SendEventDest::ITEM_FOCUS, configure_notify(22))
+            .propagate(true)
+            .event_mask(EventMask::STRUCTURE_NOTIFY)
+            .build()
+            .unwrap();
+        assert!(request.propagate);
+        assert_eq!(request.destination, 1);
+        assert_eq!(request.event_mask, EventMask::STRUCTURE_NOTIFY);
+        assert_eq!(request.event[0], CONFIGURE_NOTIFY_EVENT);
+    }
+
+    #[test]
+    fn invalid_response_type() {
+        for (response_type, error) in [
+            (0, InvalidSendEvent::ResponseType(0)),
+            (1, InvalidSendEvent::ResponseType(1)),
+            (35, InvalidSendEvent::GenericEvent),
+            (40, InvalidSendEvent::ResponseType(40)),
+            (0x80 | 22, InvalidSendEvent::SendEventFlag),
+        ] {
+            let builder = SendEventBuilder::new(1u32, configure_notify(response_type));
+            assert_eq!(builder.validate(), Err(error));
+        }
+        // Extension events are accepted
+        let builder = SendEventBuilder::new(1u32, configure_notify(90));
+        assert_eq!(builder.validate(), Ok(()));
+    }
+
+    #[test]
+    fn client_message_format() {
+        let event = ClientMessageEvent::new(24, 1, 2u32, [0u32; 5]);
+        let builder = SendEventBuilder::new(1u32, event);
+        assert_eq!(
+            builder.validate(),
+            Err(InvalidSendEvent::ClientMessageFormat(24))
+        );
+    }
+
+    #[test]
+    fn invalid_event_mask() {
+        let builder = SendEventBuilder::new(1u32, configure_notify(22)).event_mask(1u32 << 25);
+        assert_eq!(
+            builder.validate(),
+            Err(InvalidSendEvent::EventMask(1 << 25))
+        );
+    }
+}
@@ -157,3 +157,262 @@ fn test_unknown_extension_request_names() {
         (None, Cow::from("ext FOO-BAR::opcode 123"))
     );
 }
+
+#[test]
+fn test_aux_changes_from() {
+    use crate::protocol::xproto::{ConfigureWindowAux, StackMode};
+
+    let old = ConfigureWindowAux::new().x(10).y(20).width(100);
+    let new = ConfigureWindowAux::new()
+        .x(10)
+        .y(25)
+        .height(50)
+        .stack_mode(StackMode::ABOVE);
+    let changes = new.changes_from(&old);
+    assert_eq!(changes.x, None);
+    assert_eq!(changes.y, Some(25));
+    // Fields that are unset in `new` are not reset
+    assert_eq!(changes.width, None);
+    assert_eq!(changes.height, Some(50));
+    assert_eq!(changes.stack_mode, Some(StackMode::ABOVE));
+}
+
+#[test]
+fn test_bitmask_from_iterator() {
+    use crate::protocol::xproto::EventMask;
+
+    let mask = [EventMask::KEY_PRESS, EventMask::EXPOSURE]
+        .into_iter()
+        .collect::<EventMask>();
+    assert_eq!(mask, EventMask::KEY_PRESS | EventMask::EXPOSURE);
+    assert_eq!(
+        core::iter::empty().collect::<EventMask>(),
+        EventMask::NO_EVENT
+    );
+}
+
+#[test]
+fn test_enum_names() {
+    use crate::protocol::xproto::{EventMask, StackMode};
+    use alloc::string::ToString;
+
+    assert_eq!(StackMode::BOTTOM_IF.name(), Some("BottomIf"));
+    assert_eq!(StackMode::from(42u8).name(), None);
+    assert_eq!(StackMode::BOTTOM_IF.to_string(), "BottomIf");
+    assert_eq!(StackMode::from(42u8).to_string(), "42");
+
+    assert_eq!(EventMask::EXPOSURE.name(), Some("Exposure"));
+    assert_eq!((EventMask::KEY_PRESS | EventMask::EXPOSURE).name(), None);
+    assert_eq!(
+        (EventMask::KEY_PRESS | EventMask::EXPOSURE).to_string(),
+        "KeyPress | Exposure"
+    );
+}
+
+#[test]
+fn test_event_names() {
+    use crate::protocol::xproto::ExposeEvent;
+    use crate::protocol::Event;
+
+    assert_eq!(Event::Expose(ExposeEvent::default()).name(), "Expose");
+    assert_eq!(Event::Unknown(alloc::vec![]).name(), "Unknown");
+}
+
+#[test]
+fn test_request_name_by_opcode() {
+    use crate::protocol::request_name_by_opcode;
+
+    assert_eq!(request_name_by_opcode(None, 1), Some("CreateWindow"));
+    assert_eq!(request_name_by_opcode(None, 200), None);
+    assert_eq!(
+        request_name_by_opcode(Some("XC-MISC"), 1),
+        Some("GetXIDRange")
+    );
+    assert_eq!(request_name_by_opcode(Some("XC-MISC"), 100), None);
+    assert_eq!(request_name_by_opcode(Some("not an extension"), 1), None);
+}
+
+#[test]
+fn test_parse_reply_by_opcode() {
+    use crate::protocol::xproto::{InputFocus, GET_INPUT_FOCUS_REQUEST, NO_OPERATION_REQUEST};
+    use crate::protocol::{parse_reply_by_opcode, Reply};
+
+    let mut reply = [0; 32];
+    reply[0] = 1;
+    reply[1] = InputFocus::PARENT.into();
+    reply[2..4].copy_from_slice(&7u16.to_ne_bytes());
+    reply[8..12].copy_from_slice(&0x1234u32.to_ne_bytes());
+
+    let mut fds = alloc::vec::Vec::new();
+    match parse_reply_by_opcode(None, GET_INPUT_FOCUS_REQUEST, &reply, &mut fds) {
+        Ok(Some(Reply::GetInputFocus(reply))) => {
+            assert_eq!(reply.revert_to, InputFocus::PARENT);
+            assert_eq!((reply.sequence, reply.focus), (7, 0x1234));
+        }
+        other => panic!("Unexpected result: {:?}", other),
+    }
+    assert!(
+        parse_reply_by_opcode(None, NO_OPERATION_REQUEST, &reply, &mut fds)
+            .unwrap()
+            .is_none()
+    );
+    assert!(
+        parse_reply_by_opcode(Some("XC-MISC"), 100, &reply, &mut fds)
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[test]
+fn test_request_description() {
+    use crate::protocol::{request_description, requests_of_extension, xc_misc, xproto};
+    use crate::x11_utils::RequestDescription;
+
+    assert_eq!(
+        request_description(None, xproto::GET_INPUT_FOCUS_REQUEST),
+        Some(&RequestDescription {
+            opcode: 43,
+            name: "GetInputFocus",
+            has_reply: true,
+        })
+    );
+    let description = request_description(Some("XC-MISC"), 2).unwrap();
+    assert_eq!(
+        (description.name, description.has_reply),
+        ("GetXIDList", true)
+    );
+    assert!(!request_description(None, 1).unwrap().has_reply);
+    assert_eq!(request_description(None, 200), None);
+    assert_eq!(
+        requests_of_extension(Some(xc_misc::X11_EXTENSION_NAME)),
+        Some(xc_misc::REQUESTS)
+    );
+    assert_eq!(requests_of_extension(Some("not an extension")), None);
+}
+
+#[test]
+fn test_event_kind_and_window() {
+    use crate::protocol::xproto::{ConfigureRequestEvent, KeymapNotifyEvent};
+    use crate::protocol::{Event, EventKind};
+
+    let event = Event::ConfigureRequest(ConfigureRequestEvent {
+        parent: 1,
+        window: 2,
+        ..Default::default()
+    });
+    assert_eq!(event.kind(), EventKind::ConfigureRequest);
+    assert_eq!(event.window(), Some(1));
+
+    let event = Event::KeymapNotify(KeymapNotifyEvent::default());
+    assert_eq!(event.kind(), EventKind::KeymapNotify);
+    assert_eq!(event.window(), None);
+    assert_eq!(
+        Event::Unknown(Default::default()).kind(),
+        EventKind::Unknown
+    );
+}
+
+#[test]
+fn test_event_serialize() {
+    use crate::protocol::xproto::{ExposeEvent, EXPOSE_EVENT};
+    use crate::protocol::Event;
+
+    let event = Event::Expose(ExposeEvent {
+        window: 5,
+        ..Default::default()
+    });
+    assert_eq!(event.extension(), None);
+    let bytes = event.serialize(None).unwrap();
+    assert_eq!(bytes[0], EXPOSE_EVENT);
+    let provider = ExtInfo {
+        extension: None,
+        major_opcode: 0,
+    };
+    match Event::parse(&bytes, &provider).unwrap() {
+        Event::Expose(event) => assert_eq!(event.window, 5),
+        event => panic!("Unexpected event {:?}", event),
+    }
+}
+
+#[test]
+#[cfg(feature = "xinput")]
+fn test_generic_event_serialize() {
+    use crate::protocol::xinput::{self, EnterEvent};
+    use crate::protocol::Event;
+    use alloc::vec;
+
+    let event = Event::XinputFocusIn(EnterEvent {
+        event: 7,
+        buttons: vec![1, 2],
+        ..Default::default()
+    });
+    assert_eq!(event.extension(), Some(xinput::X11_EXTENSION_NAME));
+    assert!(event.serialize(None).is_none());
+    let ext_info = ExtensionInformation {
+        major_opcode: 131,
+        first_event: 0,
+        first_error: 0,
+    };
+    let bytes = event.serialize(Some(&ext_info)).unwrap();
+    let provider = ExtInfo {
+        extension: Some(xinput::X11_EXTENSION_NAME),
+        major_opcode: 131,
+    };
+    match Event::parse(&bytes, &provider).unwrap() {
+        Event::XinputFocusIn(event) => {
+            assert_eq!((event.event, event.buttons), (7, vec![1, 2]));
+            // 40 bytes of fixed fields after the first 32 bytes, and two buttons
+            assert_eq!(event.length, 12);
+        }
+        event => panic!("Unexpected event {:?}", event),
+    }
+}
+
+#[test]
+fn test_event_try_from() {
+    use crate::protocol::xproto::{ButtonPressEvent, ExposeEvent};
+    use crate::protocol::Event;
+    use core::convert::TryFrom;
+
+    let event = Event::ButtonRelease(ButtonPressEvent {
+        detail: 3,
+        ..Default::default()
+    });
+    assert!(<&ExposeEvent>::try_from(&event).is_err());
+    assert_eq!(<&ButtonPressEvent>::try_from(&event).unwrap().detail, 3);
+    let event = ExposeEvent::try_from(event).unwrap_err();
+    assert_eq!(ButtonPressEvent::try_from(event).unwrap().detail, 3);
+}
+
+#[test]
+fn test_event_code() {
+    use crate::protocol::xproto::{ExposeEvent, EXPOSE_EVENT};
+    use crate::protocol::Event;
+
+    let event = Event::Expose(ExposeEvent::default());
+    assert_eq!(event.event_code(), Some(u16::from(EXPOSE_EVENT)));
+    assert_eq!(Event::Unknown(Default::default()).event_code(), None);
+}
+
+#[test]
+#[cfg(feature = "xinput")]
+fn test_extension_event_enum() {
+    use crate::protocol::xinput::{self, EnterEvent};
+    use crate::protocol::xproto::ExposeEvent;
+    use crate::protocol::{Event, XinputEvent};
+
+    let event = Event::XinputFocusIn(EnterEvent {
+        deviceid: 3,
+        ..Default::default()
+    });
+    assert_eq!(event.event_code(), Some(xinput::FOCUS_IN_EVENT));
+    let event = match XinputEvent::try_from(event) {
+        Ok(XinputEvent::FocusIn(event)) => {
+            assert_eq!(event.deviceid, 3);
+            XinputEvent::FocusIn(event)
+        }
+        event => panic!("Unexpected event {:?}", event),
+    };
+    assert!(matches!(Event::from(event), Event::XinputFocusIn(_)));
+    assert!(XinputEvent::try_from(Event::Expose(ExposeEvent::default())).is_err());
+}
@@ -0,0 +1,269 @@
+//! Helpers for checking that values survive being serialized and parsed again.
+//!
+//! Every generated struct, event and reply can be serialized with [`Serialize`] and parsed with
+//! [`TryParse`]. Doing both in a row should produce the original value. The functions in this
+//! module check this property. Together with the `arbitrary` feature, they can be used to fuzz the
+//! parsers, e.g. with `cargo fuzz`:
+//!
+//! ```ignore
+//! fuzz_target!(|data: &[u8]| {
+//!     check_arbitrary_roundtrip::<x11rb_protocol::protocol::xproto::Setup>(data).unwrap();
+//! });
+//! ```
+//!
+//! Comparing values needs `PartialEq`, which is only implemented with the `extra-traits` feature.
+//! Note that values whose explicit length fields do not match their contents, e.g. the `length`
+//! field of replies, cannot survive a roundtrip. The `Arbitrary` implementations of parseable types
+//! thus parse the unstructured data instead of filling in each field independently, and
+//! [`check_arbitrary_roundtrip`] compares the serialized bytes instead of the values.
+//!
+//! The opposite direction is checked by [`check_wire_roundtrip`]: Raw bytes are parsed and
+//! serialized again. The [`fixtures`] contain such raw bytes for types whose wire format is easy
+//! to get wrong. [`check_fixtures`] checks all of them that are enabled via feature flags.
+
+use alloc::vec::Vec;
+
+use crate::errors::{ParseError, RoundtripError};
+use crate::x11_utils::{Serialize, TryParse};
+
+/// Serialize a value and parse the result again.
+///
+/// Events and replies take at least 32 bytes on the wire, but their serialization does not
+/// include trailing padding. If parsing runs out of data, the bytes are thus padded with zeros to
+/// 32 bytes and parsed again.
+///
+/// An error is returned if parsing fails or does not consume all bytes.
+pub fn roundtrip<T: Serialize + TryParse>(value: &T) -> Result<T, RoundtripError> {
+    let mut bytes = Vec::new();
+    value.serialize_into(&mut bytes);
+    let (parsed, remaining) = match T::try_parse(&bytes) {
+        Err(ParseError::InsufficientData) if bytes.len() < 32 => {
+            bytes.resize(32, 0);
+            T::try_parse(&bytes)?
+        }
+        result => result?,
+    };
+    if remaining.is_empty() {
+        Ok(parsed)
+    } else {
+        Err(RoundtripError::TrailingData(remaining.len()))
+    }
+}
+
+/// Check that a value is unchanged after being serialized and parsed again.
+pub fn check_roundtrip<T>(value: &T) -> Result<(), RoundtripError>
+where
+    T: Serialize + TryParse + PartialEq,
+{
+    if roundtrip(value)? == *value {
+        Ok(())
+    } else {
+        Err(RoundtripError::Mismatch)
+    }
+}
+
+/// Check that the default value of a type survives a roundtrip.
+pub fn check_default_roundtrip<T>() -> Result<(), RoundtripError>
+where
+    T: Default + Serialize + TryParse + PartialEq,
+{
+    check_roundtrip(&T::default())
+}
+
+/// Check that parsing `bytes` and serializing the result produces the same bytes.
+///
+/// In contrast to [`check_roundtrip`], this does not need `PartialEq`.
+pub fn check_wire_roundtrip<T: Serialize + TryParse>(bytes: &[u8]) -> Result<(), RoundtripError> {
+    let (value, remaining) = T::try_parse(bytes)?;
+    if !remaining.is_empty() {
+        return Err(RoundtripError::TrailingData(remaining.len()));
+    }
+    let mut serialized = Vec::new();
+    value.serialize_into(&mut serialized);
+    if serialized == bytes {
+        Ok(())
+    } else {
+        Err(RoundtripError::Mismatch)
+    }
+}
+
+/// Check all [`fixtures`] that are available with the enabled features.
+///
+/// On error, the name of the failing fixture is returned together with the error.
+#[cfg(target_endian = "little")]
+pub fn check_fixtures() -> Result<(), (&'static str, RoundtripError)> {
+    fn check<T: Serialize + TryParse>(
+        name: &'static str,
+        bytes: &[u8],
+    ) -> Result<(), (&'static str, RoundtripError)> {
+        check_wire_roundtrip::<T>(bytes).map_err(|err| (name, err))
+    }
+
+    check::<crate::protocol::xproto::Depth>("XPROTO_DEPTH", fixtures::XPROTO_DEPTH)?;
+    #[cfg(feature = "xinput")]
+    {
+        use crate::protocol::xinput::{DeviceClass, XIDeviceInfo};
+        check::<DeviceClass>("XINPUT_VALUATOR_CLASS", fixtures::XINPUT_VALUATOR_CLASS)?;
+        check::<XIDeviceInfo>("XINPUT_DEVICE_INFO", fixtures::XINPUT_DEVICE_INFO)?;
+    }
+    #[cfg(feature = "xkb")]
+    check::<crate::protocol::xkb::KeyType>("XKB_KEY_TYPE", fixtures::XKB_KEY_TYPE)?;
+    Ok(())
+}
+
+/// Raw bytes of values whose wire format is easy to get wrong.
+///
+/// All fixtures are in little endian byte order and are thus only available on little endian
+/// targets.
+#[cfg(target_endian = "little")]
+pub mod fixtures {
+    /// A `Depth` with one `TrueColor` visual, a struct containing a list of structs.
+    pub const XPROTO_DEPTH: &[u8] = &[
+        24, 0, 1, 0, 0, 0, 0, 0, // depth, visuals_len
+        0x21, 0, 0, 0, 4, 8, 0, 1, // visual_id, class, bits_per_rgb_value, colormap_entries
+        0, 0, 0xff, 0, 0, 0xff, 0, 0, // red_mask, green_mask
+        0xff, 0, 0, 0, 0, 0, 0, 0, // blue_mask
+    ];
+
+    /// A valuator `DeviceClass`, whose contents depend on its type and whose size is given in
+    /// its `len` field.
+    #[cfg(feature = "xinput")]
+    pub const XINPUT_VALUATOR_CLASS: &[u8] = &[
+        2, 0, 11, 0, 5, 0, 1, 0, // type, len, sourceid, number
+        0x23, 1, 0, 0, // label
+        0xff, 0xff, 0xff, 0xff, 0, 0, 0, 0, // min
+        0xe8, 3, 0, 0, 0, 0, 0, 0, // max
+        0xf4, 1, 0, 0, 0, 0, 0, 0x80, // value
+        1, 0, 0, 0, 1, 0, 0, 0, // resolution, mode
+    ];
+
+    /// An `XIDeviceInfo` with a name that needs padding, followed by one valuator class.
+    #[cfg(feature = "xinput")]
+    pub const XINPUT_DEVICE_INFO: &[u8] = &[
+        5, 0, 3, 0, 2, 0, 1, 0, // deviceid, type, attachment, num_classes
+        5, 0, 1, 0, b'm', b'o', b'u', b's', // name_len, enabled, name
+        b'e', 0, 0, 0, // name, alignment
+        2, 0, 11, 0, 5, 0, 1, 0, // type, len, sourceid, number
+        0x23, 1, 0, 0, // label
+        0xff, 0xff, 0xff, 0xff, 0, 0, 0, 0, // min
+        0xe8, 3, 0, 0, 0, 0, 0, 0, // max
+        0xf4, 1, 0, 0, 0, 0, 0, 0x80, // value
+        1, 0, 0, 0, 1, 0, 0, 0, // resolution, mode
+    ];
+
+    /// A `KeyType` with a `preserve` list, whose length depends on two other fields.
+    #[cfg(feature = "xkb")]
+    pub const XKB_KEY_TYPE: &[u8] = &[
+        1, 1, 0, 0, 2, 1, 1, 0, // mods, num_levels, n_map_entries, has_preserve
+        1, 1, 1, 1, 0, 0, 0, 0, // map
+        1, 1, 0, 0, // preserve
+    ];
+}
+
+/// Generate a value from unstructured data and check that it survives a roundtrip.
+///
+/// The value is serialized, parsed and serialized again. Both serializations must be equal. In
+/// contrast to [`check_roundtrip`], this does not need `PartialEq`.
+///
+/// If no value can be generated from `data`, there is nothing to check and `Ok(())` is returned.
+/// This includes data that only parses into a value that cannot be serialized, e.g. a switch with
+/// an unknown discriminant.
+#[cfg(feature = "arbitrary")]
+pub fn check_arbitrary_roundtrip<T>(data: &[u8]) -> Result<(), RoundtripError>
+where
+    T: for<'a> arbitrary::Arbitrary<'a> + Serialize + TryParse,
+{
+    let mut unstructured = arbitrary::Unstructured::new(data);
+    let value = match T::arbitrary(&mut unstructured) {
+        Ok(value) => value,
+        Err(_) => return Ok(()),
+    };
+    let mut bytes = Vec::new();
+    value.serialize_into(&mut bytes);
+    let mut reserialized = Vec::new();
+    roundtrip(&value)?.serialize_into(&mut reserialized);
+    if reserialized == bytes {
+        Ok(())
+    } else {
+        Err(RoundtripError::Mismatch)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{check_roundtrip, roundtrip};
+    use crate::protocol::xproto::{Rectangle, StackMode};
+
+    #[test]
+    fn roundtrip_struct() {
+        let rect = Rectangle {
+            x: -5,
+            y: 10,
+            width: 300,
+            height: 200,
+        };
+        let parsed = roundtrip(&rect).unwrap();
+        assert_eq!(
+            (parsed.x, parsed.y, parsed.width, parsed.height),
+            (-5, 10, 300, 200)
+        );
+    }
+
+    #[test]
+    #[cfg(target_endian = "little")]
+    fn fixtures() {
+        assert_eq!(super::check_fixtures(), Ok(()));
+    }
+
+    #[test]
+    fn wire_roundtrip() {
+        use super::check_wire_roundtrip;
+        use crate::errors::RoundtripError;
+
+        let bytes = [1, 0, 2, 0, 3, 0, 4, 0];
+        assert_eq!(check_wire_roundtrip::<Rectangle>(&bytes), Ok(()));
+        assert_eq!(
+            check_wire_roundtrip::<Rectangle>(&[bytes, bytes].concat()),
+            Err(RoundtripError::TrailingData(8))
+        );
+    }
+
+    #[test]
+    fn roundtrip_primitive() {
+        assert_eq!(check_roundtrip(&0x1234_5678u32), Ok(()));
+        assert_eq!(check_roundtrip(&u32::from(StackMode::OPPOSITE)), Ok(()));
+    }
+
+    #[test]
+    fn roundtrip_event() {
+        use crate::protocol::xproto::DestroyNotifyEvent;
+
+        let event = DestroyNotifyEvent {
+            response_type: 17,
+            sequence: 10,
+            event: 1,
+            window: 2,
+        };
+        let parsed = roundtrip(&event).unwrap();
+        assert_eq!((parsed.sequence, parsed.window), (10, 2));
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn arbitrary_roundtrip() {
+        use super::check_arbitrary_roundtrip;
+        use crate::protocol::xproto::{GetPropertyReply, QueryTreeReply, Screen, Setup};
+        use alloc::vec::Vec;
+
+        for seed in 0..=255u8 {
+            let mut data = (0..=255u8)
+                .map(|i| i.wrapping_mul(seed) % 3)
+                .collect::<Vec<_>>();
+            data[0] = 1;
+            assert_eq!(check_arbitrary_roundtrip::<Screen>(&data), Ok(()));
+            assert_eq!(check_arbitrary_roundtrip::<Setup>(&data), Ok(()));
+            assert_eq!(check_arbitrary_roundtrip::<QueryTreeReply>(&data), Ok(()));
+            assert_eq!(check_arbitrary_roundtrip::<GetPropertyReply>(&data), Ok(()));
+        }
+    }
+}
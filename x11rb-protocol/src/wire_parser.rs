@@ -0,0 +1,235 @@
+//! Splitting the bytes sent by an X11 server into packets.
+//!
+//! [`WireParser`] does not do any I/O. It accepts data in chunks of arbitrary size and produces
+//! complete packets together with their full sequence number. This is useful for tools that
+//! observe X11 traffic, e.g. from a packet capture, instead of acting as a client.
+
+use alloc::vec::Vec;
+
+use crate::connection::full_sequence_number;
+use crate::packet_reader::{extra_length, MINIMAL_PACKET_LENGTH};
+use crate::SequenceNumber;
+
+/// Length of the fixed part of the server's answer to the setup request.
+const SETUP_HEADER_LENGTH: usize = 8;
+
+/// The kind of a packet sent by the X11 server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PacketKind {
+    /// The answer to the setup request that starts a connection.
+    ///
+    /// This is one of `SetupFailed`, `SetupAuthenticate` or `Setup`, depending on the first byte.
+    Setup,
+    /// A reply to a request.
+    Reply,
+    /// An X11 error.
+    Error,
+    /// An event, including generic events.
+    Event,
+}
+
+/// A complete packet sent by the X11 server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Packet {
+    /// The kind of this packet.
+    pub kind: PacketKind,
+    /// The full sequence number of the packet.
+    ///
+    /// The X11 server only sends the lower 16 bits of the sequence number. The higher bits are
+    /// reconstructed from the previous packets. `KeymapNotify` events do not have a sequence
+    /// number and get the one of the previous packet. The setup packet has sequence number zero.
+    pub sequence: SequenceNumber,
+    /// The raw bytes of the packet.
+    pub data: Vec<u8>,
+}
+
+/// An incremental parser for the data sent by an X11 server.
+///
+/// # Example
+///
+/// ```rust
+/// # use x11rb_protocol::wire_parser::{PacketKind, WireParser};
+/// let mut parser = WireParser::new();
+/// let mut error = [0; 32];
+/// error[2..4].copy_from_slice(&7u16.to_ne_bytes());
+///
+/// // The packet is split across two chunks
+/// parser.push(&error[..10]);
+/// assert!(parser.next_packet().is_none());
+/// parser.push(&error[10..]);
+///
+/// let packet = parser.next_packet().unwrap();
+/// assert_eq!((packet.kind, packet.sequence), (PacketKind::Error, 7));
+/// ```
+#[derive(Debug, Default)]
+pub struct WireParser {
+    /// Data that was pushed, but not yet returned as a packet.
+    buffer: Vec<u8>,
+    /// The position in `buffer` where the next packet starts.
+    start: usize,
+    /// Whether the next packet is the answer to the setup request.
+    expect_setup: bool,
+    /// The full sequence number of the last packet.
+    last_sequence_read: SequenceNumber,
+}
+
+impl WireParser {
+    /// Create a parser for data that the server sends after the connection setup.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Create a parser for data starting at the beginning of a connection.
+    ///
+    /// The first packet that is returned is the server's answer to the setup request.
+    pub fn expecting_setup() -> Self {
+        Self {
+            expect_setup: true,
+            ..Default::default()
+        }
+    }
+
+    /// Add data that was received from the X11 server.
+    pub fn push(&mut self, data: &[u8]) {
+        if self.start > 0 {
+            let _ = self.buffer.drain(..self.start);
+            self.start = 0;
+        }
+        self.buffer.extend_from_slice(data);
+    }
+
+    /// Get the next complete packet.
+    ///
+    /// Returns `None` if not enough data was pushed yet.
+    pub fn next_packet(&mut self) -> Option<Packet> {
+        let remaining = &self.buffer[self.start..];
+        let length = self.next_packet_length(remaining)?;
+        if remaining.len() < length {
+            return None;
+        }
+        let data = remaining[..length].to_vec();
+        self.start += length;
+        if self.start == self.buffer.len() {
+            self.buffer.clear();
+            self.start = 0;
+        }
+
+        let (kind, sequence) = if self.expect_setup {
+            self.expect_setup = false;
+            (PacketKind::Setup, 0)
+        } else {
+            let kind = match data[0] {
+                0 => PacketKind::Error,
+                1 => PacketKind::Reply,
+                _ => PacketKind::Event,
+            };
+            if let Some(sequence) = full_sequence_number(self.last_sequence_read, &data) {
+                self.last_sequence_read = sequence;
+            }
+            (kind, self.last_sequence_read)
+        };
+        Some(Packet {
+            kind,
+            sequence,
+            data,
+        })
+    }
+
+    /// Get the number of bytes that were pushed, but are not yet part of a returned packet.
+    pub fn buffered_len(&self) -> usize {
+        self.buffer.len() - self.start
+    }
+
+    /// Get the full sequence number of the last packet that was returned.
+    pub fn last_sequence_number(&self) -> SequenceNumber {
+        self.last_sequence_read
+    }
+
+    /// Compute the length of the packet at the start of `data`, if enough is known for that.
+    fn next_packet_length(&self, data: &[u8]) -> Option<usize> {
+        if self.expect_setup {
+            let length = data.get(6..SETUP_HEADER_LENGTH)?;
+            let length = usize::from(u16::from_ne_bytes([length[0], length[1]]));
+            Some(SETUP_HEADER_LENGTH + 4 * length)
+        } else if data.len() < MINIMAL_PACKET_LENGTH {
+            None
+        } else {
+            Some(MINIMAL_PACKET_LENGTH + extra_length(data))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{PacketKind, WireParser};
+    use crate::protocol::xproto::KEYMAP_NOTIFY_EVENT;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    fn packet(response_type: u8, sequence: u16, extra_words: u32) -> Vec<u8> {
+        let mut packet = vec![0; 32 + 4 * extra_words as usize];
+        packet[0] = response_type;
+        packet[2..4].copy_from_slice(&sequence.to_ne_bytes());
+        if response_type == 1 {
+            packet[4..8].copy_from_slice(&extra_words.to_ne_bytes());
+        }
+        packet
+    }
+
+    #[test]
+    fn byte_by_byte() {
+        let packets = [packet(1, 1, 3), packet(0, 2, 0), packet(2, 3, 0)];
+        let data = packets.concat();
+        let mut parser = WireParser::new();
+        let mut result = Vec::new();
+        for byte in data {
+            parser.push(&[byte]);
+            result.extend(parser.next_packet());
+        }
+        assert_eq!(parser.buffered_len(), 0);
+        let kinds = result
+            .iter()
+            .map(|p| (p.kind, p.sequence))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            [
+                (PacketKind::Reply, 1),
+                (PacketKind::Error, 2),
+                (PacketKind::Event, 3)
+            ]
+        );
+        let data = result.into_iter().map(|p| p.data).collect::<Vec<_>>();
+        assert_eq!(data, packets);
+    }
+
+    #[test]
+    fn sequence_wrap_around() {
+        let mut parser = WireParser::new();
+        parser.push(&packet(2, 0xffff, 0));
+        parser.push(&packet(KEYMAP_NOTIFY_EVENT, 0x1234, 0));
+        parser.push(&packet(2, 1, 0));
+        let sequences = core::iter::from_fn(|| parser.next_packet())
+            .map(|p| p.sequence)
+            .collect::<Vec<_>>();
+        assert_eq!(sequences, [0xffff, 0xffff, 0x1_0001]);
+        assert_eq!(parser.last_sequence_number(), 0x1_0001);
+    }
+
+    #[test]
+    fn setup() {
+        let mut setup = vec![1, 0, 11, 0, 0, 0, 2, 0];
+        setup.extend([0; 8]);
+        let mut parser = WireParser::expecting_setup();
+        parser.push(&setup[..7]);
+        assert!(parser.next_packet().is_none());
+        parser.push(&setup[7..]);
+        parser.push(&packet(1, 1, 0));
+
+        let packet = parser.next_packet().unwrap();
+        assert_eq!((packet.kind, packet.data.len()), (PacketKind::Setup, 16));
+        let packet = parser.next_packet().unwrap();
+        assert_eq!((packet.kind, packet.sequence), (PacketKind::Reply, 1));
+        assert!(parser.next_packet().is_none());
+    }
+}
@@ -4,6 +4,7 @@
 //! [`Serialize`] traits. These traits are used internally for parsing incoming data and producing
 //! outgoing data when talking with the X11 server.
 
+use alloc::borrow::Cow;
 use alloc::string::String;
 use alloc::vec::Vec;
 
@@ -159,6 +160,16 @@ impl From<X11Error> for [u8; 32] {
     }
 }
 
+impl Serialize for X11Error {
+    type Bytes = [u8; 32];
+    fn serialize(&self) -> [u8; 32] {
+        self.into()
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(&self.serialize());
+    }
+}
+
 #[cfg(test)]
 mod serialise_x11error_test {
     use super::{ErrorKind, X11Error};
@@ -218,6 +229,25 @@ pub trait TryParse: Sized {
     fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError>;
 }
 
+/// A type implementing this trait can be parsed from some raw bytes without copying them.
+///
+/// Unlike [`TryParse`], the parsed value can borrow from the given bytes.
+pub trait TryParseRef<'a>: Sized {
+    /// Try to parse the given values into an instance of this type.
+    ///
+    /// If parsing is successful, an instance of the type and a slice for the remaining data should
+    /// be returned. Otherwise, an error is returned.
+    fn try_parse_ref(value: &'a [u8]) -> Result<(Self, &'a [u8]), ParseError>;
+}
+
+/// A reply that has a variant which borrows its data from the received packet.
+///
+/// See [`crate::reply_ref`] for the replies that implement this trait.
+pub trait BorrowedReply<'a> {
+    /// The borrowed variant of this reply.
+    type Ref: TryParseRef<'a>;
+}
+
 /// A type implementing this trait can be parsed from some raw bytes and a list of fds.
 pub trait TryParseFd: Sized {
     /// Try to parse the given values into an instance of this type.
@@ -259,6 +289,22 @@ pub struct RequestHeader {
     pub remaining_length: u32,
 }
 
+/// A description of a request as found in the tables of the protocol modules.
+///
+/// Each module in [`crate::protocol`] has a `REQUESTS` constant listing all its requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestDescription {
+    /// The opcode of the request.
+    ///
+    /// For core requests, this is the major opcode. For extension requests, this is the minor
+    /// opcode.
+    pub opcode: u8,
+    /// The name of the request, e.g. `"CreateWindow"`.
+    pub name: &'static str,
+    /// Whether the X11 server sends a reply to the request.
+    pub has_reply: bool,
+}
+
 /// A type implementing this trait is an X11 request.
 pub trait Request {
     /// The protocol name of the extension that this request belongs to, or None for core requests
@@ -269,6 +315,45 @@ pub trait Request {
     /// The argument is the major opcode of the extension that this request belongs to. For core
     /// requests, the argument may not have any influence
     fn serialize(self, extension_opcode: u8) -> BufWithFds<Vec<u8>>;
+
+    /// Serialize this request into its X11 protocol wire representation, appending the result
+    /// to `buffer`.
+    ///
+    /// This is like [`Request::serialize`], but allows to reuse the same buffer for many
+    /// requests. The generated implementations write the request directly into `buffer`
+    /// without allocating. Padding is computed relative to the start of `buffer`, so its length
+    /// must be a multiple of four, which is the case if it only contains complete requests. The
+    /// file descriptors that belong to the request are returned.
+    fn serialize_into(self, extension_opcode: u8, buffer: &mut Vec<u8>) -> Vec<RawFdContainer>
+    where
+        Self: Sized,
+    {
+        let mut slices = Vec::new();
+        let fds = self.serialize_into_slices(extension_opcode, &mut slices);
+        for slice in slices {
+            buffer.extend_from_slice(&slice);
+        }
+        fds
+    }
+
+    /// Serialize this request into its X11 protocol wire representation, appending the pieces
+    /// of the result to `slices`.
+    ///
+    /// Unlike [`Request::serialize_into`], this does not copy data that the request borrows, e.g.
+    /// the image data of a `PutImage` request. The pieces can be written with vectored I/O. The
+    /// file descriptors that belong to the request are returned.
+    fn serialize_into_slices<'s>(
+        self,
+        extension_opcode: u8,
+        slices: &mut Vec<Cow<'s, [u8]>>,
+    ) -> Vec<RawFdContainer>
+    where
+        Self: Sized + 's,
+    {
+        let (buf, fds) = self.serialize(extension_opcode);
+        slices.push(Cow::Owned(buf));
+        fds
+    }
 }
 
 /// A type alias for reply parsers (matches the signature of TryParseFd).
@@ -539,7 +624,8 @@ where
     T: TryParse,
 {
     let mut remaining = data;
-    let mut result = Vec::with_capacity(list_length);
+    // Every entry needs at least one byte, so do not trust a huge list_length
+    let mut result = Vec::with_capacity(list_length.min(data.len()));
     for _ in 0..list_length {
         let (entry, new_remaining) = T::try_parse(remaining)?;
         result.push(entry);
@@ -577,6 +663,52 @@ pub(crate) fn parse_u8_array<const N: usize>(data: &[u8]) -> Result<([u8; N], &[
     Ok((*array, remaining))
 }
 
+/// Generate a value by parsing the unstructured data as its wire format.
+///
+/// In contrast to derived implementations, this only produces values that satisfy the invariants
+/// of the wire format, e.g. length fields that match the length of their lists. Values that do
+/// not serialize into the bytes they were parsed from are rejected, see [`arbitrary_check_size`].
+#[cfg(feature = "arbitrary")]
+pub(crate) fn arbitrary_from_wire<T: TryParse + Serialize>(
+    u: &mut arbitrary::Unstructured<'_>,
+    minimum_size: usize,
+) -> arbitrary::Result<T> {
+    let (value, size) = arbitrary_parse(u)?;
+    arbitrary_check_size(&value, size, minimum_size)?;
+    Ok(value)
+}
+
+/// Parse a value from the unstructured data and return it with the number of parsed bytes.
+#[cfg(feature = "arbitrary")]
+pub(crate) fn arbitrary_parse<T: TryParse>(
+    u: &mut arbitrary::Unstructured<'_>,
+) -> arbitrary::Result<(T, usize)> {
+    let data = u.peek_bytes(u.len()).unwrap_or_default();
+    let (value, remaining) = T::try_parse(data).map_err(|_| arbitrary::Error::IncorrectFormat)?;
+    let size = data.len() - remaining.len();
+    let _ = u.bytes(size)?;
+    Ok((value, size))
+}
+
+/// Check that a value that was parsed from `size` bytes also serializes to `size` bytes.
+///
+/// Otherwise, some length field covers more data than was parsed, which cannot be reproduced.
+/// Events and replies are padded to `minimum_size` bytes on the wire, but not when serialized.
+#[cfg(feature = "arbitrary")]
+pub(crate) fn arbitrary_check_size(
+    value: &impl Serialize,
+    size: usize,
+    minimum_size: usize,
+) -> arbitrary::Result<()> {
+    let mut bytes = Vec::new();
+    value.serialize_into(&mut bytes);
+    if bytes.len().max(minimum_size) == size {
+        Ok(())
+    } else {
+        Err(arbitrary::Error::IncorrectFormat)
+    }
+}
+
 impl<T: Serialize> Serialize for [T] {
     type Bytes = Vec<u8>;
     fn serialize(&self) -> Self::Bytes {
@@ -661,6 +793,12 @@ macro_rules! bitmask_binop {
                 self.0 &= other
             }
         }
+        impl core::iter::FromIterator<$t> for $t {
+            fn from_iter<I: IntoIterator<Item = $t>>(iter: I) -> Self {
+                iter.into_iter()
+                    .fold(Self::from(<$u>::default()), |acc, flag| acc | flag)
+            }
+        }
         impl $t {
             /// Check if this object has all bits set that are also set in `flag`.
             ///
@@ -765,3 +903,41 @@ pub fn parse_request_header(
         finally_remaining,
     ))
 }
+
+/// Serialize a reply so that it can be sent to an X11 client.
+///
+/// The `Serialize` implementations of replies only produce the fields of the reply and use the
+/// value of their `length` field as-is. This function produces a complete reply packet: The
+/// reply is padded to the minimal size of 32 bytes, its length field is computed from the actual
+/// size of the reply and its sequence number is set to `sequence`.
+///
+/// # Example
+///
+/// ```rust
+/// # use x11rb_protocol::x11_utils::serialize_reply;
+/// # use x11rb_protocol::protocol::xproto::{GetInputFocusReply, InputFocus};
+/// let reply = GetInputFocusReply {
+///     revert_to: InputFocus::PARENT,
+///     sequence: 0,
+///     length: 0,
+///     focus: 0x1234,
+/// };
+/// let bytes = serialize_reply(&reply, 7);
+/// assert_eq!(bytes.len(), 32);
+/// assert_eq!(&bytes[2..4], &7u16.to_ne_bytes());
+/// ```
+pub fn serialize_reply(reply: &impl Serialize, sequence: u16) -> Vec<u8> {
+    const MINIMAL_REPLY_LENGTH: usize = 32;
+
+    let mut bytes = Vec::with_capacity(MINIMAL_REPLY_LENGTH);
+    reply.serialize_into(&mut bytes);
+    assert_eq!(bytes.first(), Some(&1), "Only replies can be serialized");
+    if bytes.len() < MINIMAL_REPLY_LENGTH {
+        bytes.resize(MINIMAL_REPLY_LENGTH, 0);
+    }
+    let length =
+        u32::try_from((bytes.len() - MINIMAL_REPLY_LENGTH) / 4).expect("The reply is too large");
+    bytes[2..4].copy_from_slice(&sequence.serialize());
+    bytes[4..8].copy_from_slice(&length.serialize());
+    bytes
+}
@@ -1,4 +1,8 @@
 //! Helpers for working with `~/.Xauthority`.
+//!
+//! Besides looking up the credentials for a connection, this module can also create and modify
+//! `.Xauthority` files, e.g. to install the cookie of an Xvfb server that was just started.
+//! Modifications take the same lock as libXau, so that they do not race with `xauth`.
 
 #![cfg(feature = "std")]
 
@@ -52,33 +56,79 @@ impl From<u16> for Family {
     }
 }
 
+impl From<Family> for u16 {
+    fn from(value: Family) -> Self {
+        value.0
+    }
+}
+
 /// A single entry of an `.Xauthority` file.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub(crate) struct AuthEntry {
+pub struct AuthEntry {
     /// The protocol family to which the entry applies
-    family: Family,
+    pub family: Family,
     /// The address of the peer in a family-specific format
-    address: Vec<u8>,
+    pub address: Vec<u8>,
     /// The display number
-    number: Vec<u8>,
+    ///
+    /// This is the display number as a decimal string. An empty number matches all displays.
+    pub number: Vec<u8>,
     /// The name of the authentication method to use for the X11 server described by the previous
     /// fields.
-    name: Vec<u8>,
+    pub name: Vec<u8>,
     /// Extra data for the authentication method.
-    data: Vec<u8>,
+    pub data: Vec<u8>,
+}
+
+impl AuthEntry {
+    /// Create an entry for the `MIT-MAGIC-COOKIE-1` authentication method.
+    ///
+    /// For local connections, `family` is [`Family::LOCAL`] and `address` is the host name.
+    pub fn mit_magic_cookie(
+        family: Family,
+        address: Vec<u8>,
+        display: u16,
+        cookie: Vec<u8>,
+    ) -> Self {
+        Self {
+            family,
+            address,
+            number: display.to_string().into_bytes(),
+            name: MIT_MAGIC_COOKIE_1.to_vec(),
+            data: cookie,
+        }
+    }
+
+    /// Check whether both entries describe the same display and authentication method.
+    fn same_key(&self, other: &Self) -> bool {
+        (self.family, &self.address, &self.number, &self.name)
+            == (other.family, &other.address, &other.number, &other.name)
+    }
 }
 
 mod file {
-    //! Code for actually reading `~/.Xauthority`.
+    //! Code for actually reading and writing `~/.Xauthority`.
 
     use alloc::{vec, vec::Vec};
+    use core::convert::TryFrom;
+    use core::time::Duration;
     use std::env::var_os;
-    use std::fs::File;
-    use std::io::{BufReader, Error, ErrorKind, Read};
-    use std::path::PathBuf;
+    use std::ffi::OsString;
+    use std::fs::{self, File, OpenOptions};
+    use std::io::{BufReader, BufWriter, Error, ErrorKind, Read, Write};
+    use std::path::{Path, PathBuf};
 
     use super::AuthEntry;
 
+    /// Number of attempts to take the lock of an `.Xauthority` file.
+    const LOCK_RETRIES: u32 = 10;
+
+    /// Time to wait between attempts to take the lock of an `.Xauthority` file.
+    const LOCK_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+    /// Age after which a lock is assumed to be left over from a crashed process.
+    const STALE_LOCK_AGE: Duration = Duration::from_secs(60);
+
     /// Read a single `u16` from an `~/.Xauthority` file.
     ///
     /// The file stores these entries in big endian.
@@ -124,15 +174,202 @@ mod file {
         }))
     }
 
+    /// Write a single `u16` to an `~/.Xauthority` file.
+    fn write_u16<W: Write>(write: &mut W, value: u16) -> Result<(), Error> {
+        write.write_all(&value.to_be_bytes())
+    }
+
+    /// Write a single "byte array" to an `~/.Xauthority` file.
+    fn write_string<W: Write>(write: &mut W, value: &[u8]) -> Result<(), Error> {
+        let length = u16::try_from(value.len())
+            .map_err(|_| Error::new(ErrorKind::InvalidInput, "Xauthority field is too long"))?;
+        write_u16(write, length)?;
+        write.write_all(value)
+    }
+
+    /// Write a single entry to an `~/.Xauthority` file.
+    fn write_entry<W: Write>(write: &mut W, entry: &AuthEntry) -> Result<(), Error> {
+        write_u16(write, entry.family.into())?;
+        write_string(write, &entry.address)?;
+        write_string(write, &entry.number)?;
+        write_string(write, &entry.name)?;
+        write_string(write, &entry.data)
+    }
+
+    /// Append `suffix` to the file name of `path`.
+    fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+        let mut name = OsString::from(path.as_os_str());
+        name.push(suffix);
+        name.into()
+    }
+
+    /// Check whether a lock file is so old that it can be removed.
+    fn is_stale(path: &Path) -> bool {
+        fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .map_or(false, |age| age > STALE_LOCK_AGE)
+    }
+
+    /// A lock on an `.Xauthority` file.
+    ///
+    /// Just like libXau's `XauLockAuth`, the lock is taken by exclusively creating `<file>-c` and
+    /// then hard linking it to `<file>-l`. Both files are removed when this is dropped.
+    #[derive(Debug)]
+    struct FileLock {
+        creat: PathBuf,
+        link: PathBuf,
+    }
+
+    impl FileLock {
+        fn new(path: &Path) -> Result<Self, Error> {
+            let creat = with_suffix(path, "-c");
+            let link = with_suffix(path, "-l");
+            for _ in 0..LOCK_RETRIES {
+                for file in [&creat, &link] {
+                    if is_stale(file) {
+                        let _ = fs::remove_file(file);
+                    }
+                }
+                match OpenOptions::new().write(true).create_new(true).open(&creat) {
+                    Ok(_) => {}
+                    Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+                        std::thread::sleep(LOCK_RETRY_DELAY);
+                        continue;
+                    }
+                    Err(e) => return Err(e),
+                }
+                let result = fs::hard_link(&creat, &link);
+                match result {
+                    Ok(()) => return Ok(Self { creat, link }),
+                    Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+                        let _ = fs::remove_file(&creat);
+                        std::thread::sleep(LOCK_RETRY_DELAY);
+                    }
+                    Err(e) => {
+                        let _ = fs::remove_file(&creat);
+                        return Err(e);
+                    }
+                }
+            }
+            Err(Error::new(
+                ErrorKind::TimedOut,
+                "Timeout while locking the Xauthority file",
+            ))
+        }
+    }
+
+    impl Drop for FileLock {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.link);
+            let _ = fs::remove_file(&self.creat);
+        }
+    }
+
+    /// Read all entries of an `.Xauthority` file without locking it.
+    fn read_file(path: &Path) -> Result<Vec<AuthEntry>, Error> {
+        XAuthorityEntries(BufReader::new(File::open(path)?)).collect()
+    }
+
+    /// Replace the contents of an `.Xauthority` file. The caller has to hold the lock.
+    ///
+    /// The entries are first written to `<file>-n`, which then replaces the file. A leftover
+    /// `<file>-n` is removed first, so that the entries never end up in a file with other
+    /// permissions.
+    fn write_file(path: &Path, entries: &[AuthEntry]) -> Result<(), Error> {
+        let new_path = with_suffix(path, "-n");
+        match fs::remove_file(&new_path) {
+            Err(e) if e.kind() != ErrorKind::NotFound => return Err(e),
+            _ => {}
+        }
+        let mut options = OpenOptions::new();
+        let _ = options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            let _ = options.mode(0o600);
+        }
+        let mut writer = BufWriter::new(options.open(&new_path)?);
+        for entry in entries {
+            write_entry(&mut writer, entry)?;
+        }
+        writer.flush()?;
+        writer.get_ref().sync_all()?;
+        drop(writer);
+        fs::rename(&new_path, path)
+    }
+
+    /// Read all entries of an `.Xauthority` file.
+    ///
+    /// Like libXau, this does not lock the file, so that it also works for read-only files and
+    /// directories. Since writers replace the file atomically, this never sees partial writes.
+    pub fn read_auth_file(path: &Path) -> Result<Vec<AuthEntry>, Error> {
+        read_file(path)
+    }
+
+    /// Replace all entries of an `.Xauthority` file.
+    ///
+    /// The file is created if it does not exist yet. On Unix, new files are only accessible by
+    /// their owner.
+    pub fn write_auth_file(path: &Path, entries: &[AuthEntry]) -> Result<(), Error> {
+        let _lock = FileLock::new(path)?;
+        write_file(path, entries)
+    }
+
+    /// Add entries to an `.Xauthority` file.
+    ///
+    /// Like `xauth add`, this replaces existing entries for the same family, address, display
+    /// number and authentication method. The new entries are put in front of all other entries,
+    /// so that they take precedence when looking up credentials. The file is created if it does
+    /// not exist yet.
+    pub fn add_auth_entries(path: &Path, entries: &[AuthEntry]) -> Result<(), Error> {
+        let _lock = FileLock::new(path)?;
+        let existing = match read_file(path) {
+            Ok(existing) => existing,
+            Err(e) if e.kind() == ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(e),
+        };
+        let mut result = entries.to_vec();
+        result.extend(
+            existing
+                .into_iter()
+                .filter(|old| !entries.iter().any(|new| new.same_key(old))),
+        );
+        write_file(path, &result)
+    }
+
     /// Get the file name for `~/.Xauthority` based on environment variables.
     ///
-    /// The code in libXau contains a special case for Windows (looks like cygwin) that is not
-    /// handled here (yet?).
-    fn get_xauthority_file_name() -> Option<PathBuf> {
-        if let Some(name) = var_os("XAUTHORITY") {
+    /// This is the value of `XAUTHORITY` or `.Xauthority` in the home directory.
+    pub fn xauthority_file_path() -> Option<PathBuf> {
+        xauthority_file_name(|name| var_os(name), cfg!(windows))
+    }
+
+    /// Get the file name for `~/.Xauthority` based on the given environment variables.
+    ///
+    /// Just like libXau, on Windows `%HOMEDRIVE%%HOMEPATH%` is used when `%HOME%` is not set.
+    /// Additionally, `%USERPROFILE%` is tried, since `%HOMEPATH%` is not set for all users.
+    fn xauthority_file_name(
+        var: impl Fn(&str) -> Option<OsString>,
+        windows: bool,
+    ) -> Option<PathBuf> {
+        if let Some(name) = var("XAUTHORITY") {
             return Some(name.into());
         }
-        var_os("HOME").map(|prefix| {
+        let home = var("HOME").or_else(|| {
+            if !windows {
+                return None;
+            }
+            match (var("HOMEDRIVE"), var("HOMEPATH")) {
+                (Some(mut drive), Some(path)) => {
+                    drive.push(path);
+                    Some(drive)
+                }
+                _ => var("USERPROFILE"),
+            }
+        });
+        home.map(|prefix| {
             let mut result = PathBuf::new();
             result.push(prefix);
             result.push(".Xauthority");
@@ -151,7 +388,7 @@ mod file {
         /// be determined. If opening the file failed (for example, because it does not exist),
         /// that error is returned.
         pub(crate) fn new() -> Result<Option<XAuthorityEntries>, Error> {
-            get_xauthority_file_name()
+            xauthority_file_path()
                 .map(File::open)
                 .transpose()?
                 // At this point we have Option<File> and errors while opening the file were
@@ -172,9 +409,105 @@ mod file {
     #[cfg(test)]
     mod test {
         use super::super::{AuthEntry, Family};
-        use super::read_entry;
+        use super::{
+            add_auth_entries, read_auth_file, read_entry, write_auth_file, write_entry,
+            xauthority_file_name,
+        };
         use alloc::vec;
+        use std::ffi::OsString;
         use std::io::Cursor;
+        use std::path::PathBuf;
+
+        #[test]
+        fn test_write() {
+            let entry = AuthEntry::mit_magic_cookie(
+                Family::LOCAL,
+                b"ZweiLED".to_vec(),
+                1,
+                u32::to_be_bytes(0xdead_beef).to_vec(),
+            );
+            let mut data = vec![];
+            write_entry(&mut data, &entry).unwrap();
+            let mut cursor = Cursor::new(&data[..]);
+            assert_eq!(read_entry(&mut cursor).unwrap(), Some(entry));
+            assert_eq!(read_entry(&mut cursor).unwrap(), None);
+        }
+
+        #[test]
+        fn test_add_entries() {
+            let dir = std::env::temp_dir()
+                .join(alloc::format!("x11rb-xauth-test-{}", std::process::id()));
+            std::fs::create_dir_all(&dir).unwrap();
+            let path = dir.join(".Xauthority");
+
+            let entry = |display, cookie: &[u8]| {
+                AuthEntry::mit_magic_cookie(
+                    Family::LOCAL,
+                    b"host".to_vec(),
+                    display,
+                    cookie.to_vec(),
+                )
+            };
+            write_auth_file(&path, &[entry(0, b"old"), entry(1, b"one")]).unwrap();
+            add_auth_entries(&path, &[entry(0, b"new"), entry(2, b"two")]).unwrap();
+            let entries = read_auth_file(&path).unwrap();
+            assert_eq!(
+                entries,
+                [entry(0, b"new"), entry(2, b"two"), entry(1, b"one")]
+            );
+            assert!(!dir.join(".Xauthority-c").exists());
+            assert!(!dir.join(".Xauthority-l").exists());
+
+            std::fs::remove_dir_all(&dir).unwrap();
+        }
+
+        #[cfg(unix)]
+        #[test]
+        fn test_write_replaces_leftover_temp_file() {
+            use std::os::unix::fs::PermissionsExt;
+
+            let dir = std::env::temp_dir().join(alloc::format!(
+                "x11rb-xauth-temp-test-{}",
+                std::process::id()
+            ));
+            std::fs::create_dir_all(&dir).unwrap();
+            let path = dir.join(".Xauthority");
+
+            // A world-readable leftover from an interrupted write
+            let leftover = dir.join(".Xauthority-n");
+            std::fs::write(&leftover, b"junk").unwrap();
+            std::fs::set_permissions(&leftover, std::fs::Permissions::from_mode(0o644)).unwrap();
+
+            let entry =
+                AuthEntry::mit_magic_cookie(Family::LOCAL, b"host".to_vec(), 0, b"key".to_vec());
+            write_auth_file(&path, core::slice::from_ref(&entry)).unwrap();
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+            assert_eq!(read_auth_file(&path).unwrap(), [entry]);
+
+            std::fs::remove_dir_all(&dir).unwrap();
+        }
+
+        #[cfg(unix)]
+        #[test]
+        fn test_read_without_lock() {
+            let dir = std::env::temp_dir().join(alloc::format!(
+                "x11rb-xauth-read-test-{}",
+                std::process::id()
+            ));
+            std::fs::create_dir_all(&dir).unwrap();
+            let path = dir.join(".Xauthority");
+            let entry =
+                AuthEntry::mit_magic_cookie(Family::LOCAL, b"host".to_vec(), 0, b"key".to_vec());
+            write_auth_file(&path, core::slice::from_ref(&entry)).unwrap();
+
+            // A lock held by someone else does not block readers
+            std::fs::write(dir.join(".Xauthority-c"), b"").unwrap();
+            assert_eq!(read_auth_file(&path).unwrap(), [entry]);
+            assert!(!dir.join(".Xauthority-l").exists());
+
+            std::fs::remove_dir_all(&dir).unwrap();
+        }
 
         #[test]
         fn test_read() {
@@ -231,9 +564,40 @@ mod file {
             let entry = read_entry(&mut cursor).unwrap();
             assert_eq!(entry, None);
         }
+
+        #[test]
+        fn test_file_name() {
+            let env = |vars: &'static [(&str, &str)]| {
+                move |name: &str| {
+                    vars.iter()
+                        .find(|(var, _)| *var == name)
+                        .map(|(_, value)| OsString::from(value))
+                }
+            };
+            let windows = &[
+                ("HOMEDRIVE", "C:"),
+                ("HOMEPATH", "\\Users\\me"),
+                ("USERPROFILE", "D:\\me"),
+            ];
+
+            let name = xauthority_file_name(env(&[("XAUTHORITY", "/a"), ("HOME", "/b")]), false);
+            assert_eq!(name, Some(PathBuf::from("/a")));
+            let name = xauthority_file_name(env(&[("HOME", "/b")]), true);
+            assert_eq!(name, Some(PathBuf::from("/b").join(".Xauthority")));
+            assert_eq!(xauthority_file_name(env(windows), false), None);
+            let name = xauthority_file_name(env(windows), true);
+            assert_eq!(
+                name,
+                Some(PathBuf::from("C:\\Users\\me").join(".Xauthority"))
+            );
+            let name = xauthority_file_name(env(&windows[2..]), true);
+            assert_eq!(name, Some(PathBuf::from("D:\\me").join(".Xauthority")));
+        }
     }
 }
 
+pub use file::{add_auth_entries, read_auth_file, write_auth_file, xauthority_file_path};
+
 pub(crate) type AuthInfo = (Vec<u8>, Vec<u8>);
 
 /// Get the authentication information necessary for connecting to the given display.
@@ -0,0 +1,225 @@
+//! Decoding the valuators of XInput 2 raw events.
+//!
+//! Raw events like `RawMotion` or `RawButtonPress` only contain the values of valuators that
+//! changed. A bit mask describes which valuators these are and the values are sent as `FP3232`
+//! fixed-point numbers, once with and once without acceleration applied. [`RawValuators`] pairs
+//! the valuator numbers with their values.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use x11rb_protocol::protocol::xinput::{Fp3232, RawButtonPressEvent};
+//! # let event = RawButtonPressEvent {
+//! #     valuator_mask: vec![0b101],
+//! #     axisvalues: vec![Fp3232 { integral: 1, frac: 1 << 31 }, Fp3232 { integral: -2, frac: 0 }],
+//! #     axisvalues_raw: vec![Fp3232 { integral: 1, frac: 0 }, Fp3232 { integral: -1, frac: 0 }],
+//! #     ..Default::default()
+//! # };
+//! let valuators = event.valuators().collect::<Vec<_>>();
+//! assert_eq!((valuators[0].number, valuators[0].value), (0, 1.5));
+//! assert_eq!((valuators[1].number, valuators[1].raw_value), (2, -1.0));
+//! ```
+
+use alloc::vec::Vec;
+use core::slice::Iter;
+
+use crate::protocol::xinput::{
+    DeviceClass, DeviceClassData, Fp3232, RawButtonPressEvent, RawKeyPressEvent, RawTouchBeginEvent,
+};
+use crate::protocol::xproto::Atom;
+
+impl Fp3232 {
+    /// Convert this fixed-point number into a floating point number.
+    pub fn to_f64(self) -> f64 {
+        f64::from(self.integral) + f64::from(self.frac) / (1u64 << 32) as f64
+    }
+}
+
+impl From<Fp3232> for f64 {
+    fn from(value: Fp3232) -> f64 {
+        value.to_f64()
+    }
+}
+
+/// The value of a single valuator in a raw event.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RawValuator {
+    /// The number of the valuator on its device.
+    pub number: u16,
+    /// The value after acceleration was applied.
+    pub value: f64,
+    /// The value as reported by the device.
+    pub raw_value: f64,
+}
+
+/// Iterator over the valuators of a raw event.
+///
+/// This is returned by e.g. [`RawButtonPressEvent::valuators`].
+#[derive(Debug, Clone)]
+pub struct RawValuators<'a> {
+    mask: &'a [u32],
+    next_number: usize,
+    values: Iter<'a, Fp3232>,
+    raw_values: Iter<'a, Fp3232>,
+}
+
+impl<'a> RawValuators<'a> {
+    fn new(mask: &'a [u32], values: &'a [Fp3232], raw_values: &'a [Fp3232]) -> Self {
+        Self {
+            mask,
+            next_number: 0,
+            values: values.iter(),
+            raw_values: raw_values.iter(),
+        }
+    }
+}
+
+impl Iterator for RawValuators<'_> {
+    type Item = RawValuator;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.next_number < 32 * self.mask.len() {
+            let number = self.next_number;
+            self.next_number += 1;
+            if self.mask[number / 32] & (1 << (number % 32)) != 0 {
+                let value = self.values.next()?;
+                let raw_value = self.raw_values.next()?;
+                return Some(RawValuator {
+                    number: number as u16,
+                    value: value.to_f64(),
+                    raw_value: raw_value.to_f64(),
+                });
+            }
+        }
+        None
+    }
+}
+
+macro_rules! impl_raw_valuators {
+    ($($event:ident,)*) => {
+        $(
+            impl $event {
+                /// Get the valuators that are set in this event together with their values.
+                pub fn valuators(&self) -> RawValuators<'_> {
+                    RawValuators::new(&self.valuator_mask, &self.axisvalues, &self.axisvalues_raw)
+                }
+            }
+        )*
+    };
+}
+
+impl_raw_valuators! {
+    RawKeyPressEvent,
+    RawButtonPressEvent,
+    RawTouchBeginEvent,
+}
+
+/// The labels of the valuators of a device.
+///
+/// Valuators are identified by their number in events. The label describes what a valuator
+/// measures, e.g. `Rel X` or `Abs Pressure`. It is an atom that is part of the device's
+/// classes as returned by `XIQueryDevice`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValuatorLabels(Vec<(u16, Atom)>);
+
+impl ValuatorLabels {
+    /// Collect the labels of all valuator classes in `classes`.
+    pub fn from_classes(classes: &[DeviceClass]) -> Self {
+        let mut labels = classes
+            .iter()
+            .filter_map(|class| match &class.data {
+                DeviceClassData::Valuator(valuator) => Some((valuator.number, valuator.label)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        labels.sort_unstable();
+        Self(labels)
+    }
+
+    /// Get the label of the valuator with the given number.
+    pub fn label(&self, number: u16) -> Option<Atom> {
+        self.0
+            .binary_search_by_key(&number, |&(number, _)| number)
+            .ok()
+            .map(|index| self.0[index].1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{RawValuator, ValuatorLabels};
+    use crate::protocol::xinput::{
+        DeviceClass, DeviceClassData, DeviceClassDataValuator, Fp3232, RawMotionEvent, ValuatorMode,
+    };
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    fn fp(integral: i32, frac: u32) -> Fp3232 {
+        Fp3232 { integral, frac }
+    }
+
+    #[test]
+    fn fp3232_to_f64() {
+        assert_eq!(fp(3, 0).to_f64(), 3.0);
+        assert_eq!(fp(3, 1 << 30).to_f64(), 3.25);
+        assert_eq!(fp(-1, 1 << 31).to_f64(), -0.5);
+    }
+
+    #[test]
+    fn sparse_mask() {
+        let event = RawMotionEvent {
+            valuator_mask: vec![1 << 1, 1 << 3],
+            axisvalues: vec![fp(10, 0), fp(20, 0)],
+            axisvalues_raw: vec![fp(5, 0), fp(7, 0)],
+            ..Default::default()
+        };
+        let valuators = event.valuators().collect::<Vec<_>>();
+        assert_eq!(
+            valuators,
+            [
+                RawValuator {
+                    number: 1,
+                    value: 10.0,
+                    raw_value: 5.0
+                },
+                RawValuator {
+                    number: 35,
+                    value: 20.0,
+                    raw_value: 7.0
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_values() {
+        let event = RawMotionEvent {
+            valuator_mask: vec![0b11],
+            axisvalues: vec![fp(1, 0)],
+            axisvalues_raw: vec![fp(1, 0)],
+            ..Default::default()
+        };
+        assert_eq!(event.valuators().count(), 1);
+    }
+
+    #[test]
+    fn labels() {
+        let valuator = |number, label| DeviceClass {
+            len: 0,
+            sourceid: 2,
+            data: DeviceClassData::Valuator(DeviceClassDataValuator {
+                number,
+                label,
+                min: fp(0, 0),
+                max: fp(100, 0),
+                value: fp(0, 0),
+                resolution: 1,
+                mode: ValuatorMode::ABSOLUTE,
+            }),
+        };
+        let labels = ValuatorLabels::from_classes(&[valuator(1, 42), valuator(0, 41)]);
+        assert_eq!(labels.label(0), Some(41));
+        assert_eq!(labels.label(1), Some(42));
+        assert_eq!(labels.label(2), None);
+    }
+}
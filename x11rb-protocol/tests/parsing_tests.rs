@@ -168,3 +168,143 @@ fn parse_xi_get_property_reply_format_0() {
         )),
     );
 }
+
+#[test]
+fn serialize_requests_into_buffer() {
+    use x11rb_protocol::protocol::xproto::{CopyAreaRequest, Point, PolyPointRequest};
+    use x11rb_protocol::x11_utils::Request;
+
+    let copy_area = CopyAreaRequest {
+        src_drawable: 1,
+        dst_drawable: 2,
+        gc: 3,
+        width: 10,
+        height: 20,
+        ..Default::default()
+    };
+    let points = [Point { x: 1, y: 2 }, Point { x: 3, y: 4 }];
+    let poly_point = PolyPointRequest {
+        drawable: 2,
+        gc: 3,
+        points: points[..].into(),
+        ..Default::default()
+    };
+
+    let mut expected = Request::serialize(copy_area, 0).0;
+    expected.extend(Request::serialize(poly_point.clone(), 0).0);
+
+    let mut buffer = Vec::new();
+    assert!(copy_area.serialize_into(0, &mut buffer).is_empty());
+    assert!(poly_point.clone().serialize_into(0, &mut buffer).is_empty());
+    assert_eq!(buffer, expected);
+
+    let mut slices = Vec::new();
+    assert!(copy_area.serialize_into_slices(0, &mut slices).is_empty());
+    assert!(poly_point.serialize_into_slices(0, &mut slices).is_empty());
+    assert_eq!(slices.concat(), expected);
+}
+
+#[test]
+fn serialize_variable_length_requests_into_buffer() {
+    use std::borrow::Cow;
+    use x11rb_protocol::protocol::xproto::{
+        CreateWindowAux, CreateWindowRequest, EventMask, PutImageRequest,
+    };
+    use x11rb_protocol::x11_utils::Request;
+
+    let create_window = CreateWindowRequest {
+        wid: 1,
+        parent: 2,
+        width: 3,
+        height: 4,
+        value_list: Cow::Owned(
+            CreateWindowAux::new()
+                .background_pixel(5)
+                .event_mask(EventMask::EXPOSURE),
+        ),
+        ..Default::default()
+    };
+    // The data needs padding
+    let put_image = PutImageRequest {
+        drawable: 1,
+        gc: 2,
+        data: Cow::Owned(vec![1, 2, 3, 4, 5]),
+        ..Default::default()
+    };
+
+    let mut expected = Request::serialize(create_window.clone(), 0).0;
+    expected.extend(Request::serialize(put_image.clone(), 0).0);
+
+    let mut buffer = Vec::new();
+    assert!(create_window.serialize_into(0, &mut buffer).is_empty());
+    assert!(put_image.serialize_into(0, &mut buffer).is_empty());
+    assert_eq!(buffer, expected);
+}
+
+#[test]
+fn serialize_request_into_borrowed_slices() {
+    use std::borrow::Cow;
+    use x11rb_protocol::protocol::xproto::PutImageRequest;
+    use x11rb_protocol::x11_utils::Request;
+
+    let data = [1, 2, 3, 4, 5, 6, 7, 8];
+    let request = PutImageRequest {
+        drawable: 1,
+        gc: 2,
+        width: 2,
+        height: 1,
+        depth: 24,
+        data: Cow::Borrowed(&data[..]),
+        ..Default::default()
+    };
+    let expected = Request::serialize(request.clone(), 0).0;
+
+    let mut slices = Vec::new();
+    assert!(request.serialize_into_slices(0, &mut slices).is_empty());
+    // The image data is not copied
+    assert!(slices
+        .iter()
+        .any(|slice| matches!(slice, Cow::Borrowed(slice) if slice.as_ptr() == data.as_ptr())));
+    assert_eq!(slices.concat(), expected);
+}
+
+#[test]
+fn serialize_replies_and_errors() {
+    use x11rb_protocol::protocol::xproto::{QueryTreeReply, REQUEST_ERROR};
+    use x11rb_protocol::protocol::ErrorKind;
+    use x11rb_protocol::x11_utils::{serialize_reply, Serialize, X11Error};
+
+    let reply = QueryTreeReply {
+        sequence: 0,
+        length: 0,
+        root: 1,
+        parent: 2,
+        children: vec![3, 4],
+    };
+    let bytes = serialize_reply(&reply, 42);
+    assert_eq!(bytes.len(), 40);
+    let (parsed, remaining) = QueryTreeReply::try_parse(&bytes).unwrap();
+    assert!(remaining.is_empty());
+    assert_eq!(
+        parsed,
+        QueryTreeReply {
+            sequence: 42,
+            length: 2,
+            ..reply
+        }
+    );
+
+    let error = X11Error {
+        error_kind: ErrorKind::Request,
+        error_code: REQUEST_ERROR,
+        sequence: 42,
+        bad_value: 0,
+        minor_opcode: 0,
+        major_opcode: 200,
+        extension_name: None,
+        request_name: None,
+    };
+    let bytes = error.serialize();
+    assert_eq!(bytes[..2], [0, REQUEST_ERROR]);
+    assert_eq!(bytes[2..4], 42u16.to_ne_bytes());
+}
@@ -0,0 +1,156 @@
+//! Integration with the [calloop](https://docs.rs/calloop) event loop.
+//!
+//! [`X11Source`] is a calloop event source that produces the events of an X11 connection. It
+//! works with every connection that provides its file descriptor, i.e. with
+//! [`RustConnection`](crate::rust_connection::RustConnection) and
+//! [`XCBConnection`](crate::xcb_ffi::XCBConnection).
+//!
+//! The source takes care of the pitfalls that are described in the
+//! [`event_loop_integration`](crate::event_loop_integration) module: Events that were already
+//! read from the connection, e.g. while waiting for a reply, are delivered without waiting for
+//! the file descriptor to become readable, and the connection is flushed with
+//! [`Connection::flush_all`] before the event loop goes to sleep, even if deferred flushing is
+//! enabled.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//! use calloop::EventLoop;
+//! use x11rb::calloop_source::X11Source;
+//!
+//! let (conn, _) = x11rb::connect(None)?;
+//! let conn = Arc::new(conn);
+//! let mut event_loop = EventLoop::try_new()?;
+//! event_loop
+//!     .handle()
+//!     .insert_source(X11Source::new(Arc::clone(&conn)), |event, _, _: &mut ()| {
+//!         println!("Got event {:?}", event);
+//!     })?;
+//! loop {
+//!     event_loop.dispatch(None, &mut ())?;
+//! }
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::os::unix::io::{AsFd, BorrowedFd};
+use std::sync::Arc;
+
+use calloop::generic::Generic;
+use calloop::{EventSource, Interest, Mode, Poll, PostAction, Readiness, Token, TokenFactory};
+
+use crate::connection::Connection;
+use crate::errors::ConnectionError;
+use crate::protocol::Event;
+
+/// The file descriptor of a shared connection.
+#[derive(Debug)]
+struct ConnectionFd<C>(Arc<C>);
+
+impl<C: AsFd> AsFd for ConnectionFd<C> {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.0.as_fd()
+    }
+}
+
+/// A calloop event source for the events of an X11 connection.
+///
+/// The connection is shared with the rest of the program via an [`Arc`]. The event source must be
+/// the only place where events are fetched from the connection.
+#[derive(Debug)]
+pub struct X11Source<C: Connection + AsFd> {
+    conn: Arc<C>,
+    source: Generic<ConnectionFd<C>>,
+    token: Option<Token>,
+    pending: Option<Event>,
+}
+
+impl<C: Connection + AsFd> X11Source<C> {
+    /// Create a new event source for the given connection.
+    pub fn new(conn: Arc<C>) -> Self {
+        let source = Generic::new(ConnectionFd(Arc::clone(&conn)), Interest::READ, Mode::Level);
+        Self {
+            conn,
+            source,
+            token: None,
+            pending: None,
+        }
+    }
+
+    /// Get the connection of this event source.
+    pub fn connection(&self) -> &Arc<C> {
+        &self.conn
+    }
+}
+
+fn to_calloop_error(error: ConnectionError) -> calloop::Error {
+    calloop::Error::OtherError(Box::new(error))
+}
+
+impl<C: Connection + AsFd> EventSource for X11Source<C> {
+    type Event = Event;
+    type Metadata = ();
+    type Ret = ();
+    type Error = ConnectionError;
+
+    const NEEDS_EXTRA_LIFECYCLE_EVENTS: bool = true;
+
+    fn process_events<F>(
+        &mut self,
+        _: Readiness,
+        _: Token,
+        mut callback: F,
+    ) -> Result<PostAction, Self::Error>
+    where
+        F: FnMut(Self::Event, &mut Self::Metadata) -> Self::Ret,
+    {
+        // The readiness does not matter: Events might have been read from the file descriptor
+        // while waiting for a reply, so the queue is always drained completely.
+        if let Some(event) = self.pending.take() {
+            callback(event, &mut ());
+        }
+        while let Some(event) = self.conn.poll_for_event()? {
+            callback(event, &mut ());
+        }
+        // The callback might have sent requests
+        self.conn.flush_all()?;
+        Ok(PostAction::Continue)
+    }
+
+    fn register(
+        &mut self,
+        poll: &mut Poll,
+        token_factory: &mut TokenFactory,
+    ) -> calloop::Result<()> {
+        self.token = Some(token_factory.token());
+        self.source.register(poll, token_factory)
+    }
+
+    fn reregister(
+        &mut self,
+        poll: &mut Poll,
+        token_factory: &mut TokenFactory,
+    ) -> calloop::Result<()> {
+        self.token = Some(token_factory.token());
+        self.source.reregister(poll, token_factory)
+    }
+
+    fn unregister(&mut self, poll: &mut Poll) -> calloop::Result<()> {
+        self.token = None;
+        self.source.unregister(poll)
+    }
+
+    fn before_sleep(&mut self) -> calloop::Result<Option<(Readiness, Token)>> {
+        // Requests that are still buffered might cause the events that we are waiting for
+        self.conn.flush_all().map_err(to_calloop_error)?;
+        // An event that is already queued would not make the file descriptor readable. Wake up
+        // the event loop immediately in this case.
+        if self.pending.is_none() {
+            self.pending = self.conn.poll_for_event().map_err(to_calloop_error)?;
+        }
+        Ok(self
+            .token
+            .filter(|_| self.pending.is_some())
+            .map(|token| (Readiness::EMPTY, token)))
+    }
+}
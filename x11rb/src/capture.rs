@@ -0,0 +1,247 @@
+//! Taking screenshots of windows and screens.
+//!
+//! [`window`] and [`screen`] copy pixels from the X11 server into an RGBA image, see
+//! [`Image::to_rgba8888`]. Shared memory is used for the transfer if possible, see
+//! [`ShmImage`]. The mouse cursor is not part of the pixels of a window or screen; it can be
+//! drawn into a screenshot with [`draw_cursor`].
+//!
+//! Windows that are redirected with the Composite extension, e.g. by a compositing manager, are
+//! captured from their backing pixmap, which also works when they are covered by other windows.
+//! Other windows must be visible on the screen. With a reparenting window manager, the top-level
+//! window that is redirected is the frame of the window manager.
+//!
+//! The code in this module is only available on Unix when the `image`, `shm`, `composite` and
+//! `allow-unsafe-code` features of the library are enabled.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use x11rb::capture;
+//! use x11rb::connection::Connection;
+//! use x11rb::protocol::xproto::Rectangle;
+//!
+//! let (conn, screen_num) = x11rb::connect(None)?;
+//! let screen = &conn.setup().roots[screen_num];
+//! let region = Rectangle {
+//!     x: 0,
+//!     y: 0,
+//!     width: screen.width_in_pixels,
+//!     height: screen.height_in_pixels,
+//! };
+//! let mut image = capture::screen(&conn, screen_num, region)?;
+//! capture::draw_cursor(&conn, &mut image, (0, 0))?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::connection::Connection;
+use crate::errors::{ParseError, ReplyError, ReplyOrIdError};
+use crate::image::{Image, PixelFormat};
+use crate::protocol::composite;
+use crate::protocol::xfixes;
+use crate::protocol::xproto::{self, Drawable, MapState, Rectangle, Visualid, Window};
+use crate::shm_image::{clip, ShmImage};
+
+/// Capture the content of a window.
+///
+/// The result has the size of the window without its border. If the window is redirected, its
+/// content is taken from the pixmap of the window. Otherwise, the window must be viewable and
+/// completely on the screen, or the X11 server reports an error.
+pub fn window<C: Connection>(conn: &C, window: Window) -> Result<Image<'static>, ReplyOrIdError> {
+    let attributes = xproto::get_window_attributes(conn, window)?.reply()?;
+    let geometry = xproto::get_geometry(conn, window)?.reply()?;
+    let area = Rectangle {
+        x: 0,
+        y: 0,
+        width: geometry.width,
+        height: geometry.height,
+    };
+    if attributes.map_state == MapState::VIEWABLE {
+        if let Some(pixmap) = name_window_pixmap(conn, window)? {
+            // The pixmap of a window includes its border
+            let border = geometry.border_width as i16;
+            let area = Rectangle {
+                x: border,
+                y: border,
+                ..area
+            };
+            let result = capture(conn, pixmap, geometry.depth, attributes.visual, area);
+            let _ = xproto::free_pixmap(conn, pixmap)?;
+            return result;
+        }
+    }
+    capture(conn, window, geometry.depth, attributes.visual, area)
+}
+
+/// Capture a region of a screen.
+///
+/// The region is clipped to the screen. Fails with [`ParseError::InvalidValue`] if the screen
+/// does not exist or the region is completely outside of it.
+pub fn screen<C: Connection>(
+    conn: &C,
+    screen: usize,
+    region: Rectangle,
+) -> Result<Image<'static>, ReplyOrIdError> {
+    let screen = conn
+        .setup()
+        .roots
+        .get(screen)
+        .ok_or(ParseError::InvalidValue)?;
+    let region = clip(region, screen.width_in_pixels, screen.height_in_pixels)
+        .ok_or(ParseError::InvalidValue)?;
+    capture(
+        conn,
+        screen.root,
+        screen.root_depth,
+        screen.root_visual,
+        region,
+    )
+}
+
+/// Draw the mouse cursor into a screenshot.
+///
+/// `image` must be an RGBA image as returned by the functions of this module and `origin` is the
+/// position of its top left corner relative to the root window. The cursor image is queried with
+/// the XFIXES extension. Returns `false` if the extension is not supported, in which case nothing
+/// is drawn. Fails with [`ParseError::InvalidValue`] if `image` does not have 32 bits per pixel.
+pub fn draw_cursor<C: Connection>(
+    conn: &C,
+    image: &mut Image<'_>,
+    origin: (i16, i16),
+) -> Result<bool, ReplyError> {
+    if image.depth() != 32 {
+        return Err(ParseError::InvalidValue.into());
+    }
+    if conn
+        .extension_information(xfixes::X11_EXTENSION_NAME)?
+        .is_none()
+    {
+        return Ok(false);
+    }
+    // Clients have to announce their version before using XFIXES
+    let _ = xfixes::query_version(conn, 1, 0)?.reply()?;
+    let cursor = xfixes::get_cursor_image(conn)?.reply()?;
+    let position = (
+        i32::from(cursor.x) - i32::from(cursor.xhot) - i32::from(origin.0),
+        i32::from(cursor.y) - i32::from(cursor.yhot) - i32::from(origin.1),
+    );
+    blend_cursor(
+        image,
+        position,
+        cursor.width,
+        cursor.height,
+        &cursor.cursor_image,
+    );
+    Ok(true)
+}
+
+/// Copy an area of a drawable into an RGBA image.
+fn capture<C: Connection>(
+    conn: &C,
+    drawable: Drawable,
+    depth: u8,
+    visual: Visualid,
+    area: Rectangle,
+) -> Result<Image<'static>, ReplyOrIdError> {
+    let layout = PixelFormat::for_visual(conn.setup(), visual)?
+        .layout()
+        .ok_or(ParseError::InvalidValue)?;
+    let mut image = ShmImage::new(conn, area.width, area.height, depth)?;
+    image.get(drawable, area.x, area.y)?;
+    Ok(image.image().to_rgba8888(layout))
+}
+
+/// Get the pixmap of a window that is redirected with the Composite extension.
+///
+/// Returns `None` if the extension is not supported or the window is not redirected.
+fn name_window_pixmap<C: Connection>(
+    conn: &C,
+    window: Window,
+) -> Result<Option<xproto::Pixmap>, ReplyOrIdError> {
+    if conn
+        .extension_information(composite::X11_EXTENSION_NAME)?
+        .is_none()
+    {
+        return Ok(None);
+    }
+    // NameWindowPixmap was added in version 0.2
+    let version = composite::query_version(conn, 0, 2)?.reply()?;
+    if (version.major_version, version.minor_version) < (0, 2) {
+        return Ok(None);
+    }
+    let pixmap = conn.generate_id()?;
+    match composite::name_window_pixmap(conn, window, pixmap)?.check() {
+        Ok(()) => Ok(Some(pixmap)),
+        Err(ReplyError::X11Error(_)) => Ok(None),
+        Err(ReplyError::ConnectionError(err)) => Err(err.into()),
+    }
+}
+
+/// Draw a cursor image with premultiplied ARGB pixels onto an RGBA image.
+///
+/// `position` is the position of the top left corner of the cursor in the image. Parts of the
+/// cursor that are outside of the image are skipped.
+fn blend_cursor(
+    image: &mut Image<'_>,
+    position: (i32, i32),
+    width: u16,
+    height: u16,
+    pixels: &[u32],
+) {
+    for (index, argb) in pixels
+        .iter()
+        .take(usize::from(width) * usize::from(height))
+        .enumerate()
+    {
+        let x = position.0 + (index % usize::from(width)) as i32;
+        let y = position.1 + (index / usize::from(width)) as i32;
+        let (x, y) = match (u16::try_from(x), u16::try_from(y)) {
+            (Ok(x), Ok(y)) if x < image.width() && y < image.height() => (x, y),
+            _ => continue,
+        };
+        let [blue, green, red, alpha] = argb.to_le_bytes();
+        let dst = image.get_pixel(x, y).to_le_bytes();
+        let over = |src: u8, dst: u8| {
+            let dst = u32::from(dst) * (255 - u32::from(alpha)) / 255;
+            (u32::from(src) + dst).min(255) as u8
+        };
+        let result = [
+            over(red, dst[0]),
+            over(green, dst[1]),
+            over(blue, dst[2]),
+            over(alpha, dst[3]),
+        ];
+        image.put_pixel(x, y, u32::from_le_bytes(result));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::blend_cursor;
+    use crate::image::{BitsPerPixel, Image, ImageOrder, ScanlinePad};
+
+    #[test]
+    fn blend_cursor_clipped() {
+        let mut image = Image::allocate(
+            2,
+            2,
+            ScanlinePad::Pad32,
+            32,
+            BitsPerPixel::B32,
+            ImageOrder::LsbFirst,
+        );
+        for (x, y) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+            image.put_pixel(x, y, u32::from_le_bytes([200, 100, 0, 255]));
+        }
+        // An opaque blue pixel, a half transparent red pixel and a transparent pixel
+        let cursor = [0xff00_00ff, 0x8080_0000, 0, 0xffff_ffff];
+        blend_cursor(&mut image, (1, 0), 2, 2, &cursor);
+        assert_eq!(image.get_pixel(0, 0).to_le_bytes(), [200, 100, 0, 255]);
+        assert_eq!(image.get_pixel(1, 0).to_le_bytes(), [0, 0, 255, 255]);
+        assert_eq!(image.get_pixel(0, 1).to_le_bytes(), [200, 100, 0, 255]);
+        assert_eq!(image.get_pixel(1, 1).to_le_bytes(), [200, 100, 0, 255]);
+
+        blend_cursor(&mut image, (0, 1), 2, 1, &cursor[1..]);
+        assert_eq!(image.get_pixel(0, 1).to_le_bytes(), [227, 49, 0, 255]);
+        assert_eq!(image.get_pixel(1, 1).to_le_bytes(), [200, 100, 0, 255]);
+    }
+}
@@ -0,0 +1,175 @@
+//! Information about the clients of an X11 server via the X-Resource extension.
+//!
+//! Task manager style tools need to map windows to processes and find out how many resources each
+//! client uses. This module implements this on top of the `res` extension. Version 1.2 of the
+//! extension is needed to get process IDs and the memory usage of all resources. With older
+//! versions, the `_NET_WM_PID` property and the memory usage of pixmaps are used instead.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use x11rb::client_info::client_usage;
+//!
+//! let (conn, _) = x11rb::connect(None)?;
+//! for client in client_usage(&conn)? {
+//!     println!(
+//!         "Client {:#x} (pid {:?}) uses {} bytes",
+//!         client.resource_base, client.pid, client.bytes
+//!     );
+//! }
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::collections::HashMap;
+
+use crate::connection::RequestConnection;
+use crate::errors::ReplyError;
+use crate::protocol::res::{self, ClientIdMask, ClientIdSpec, ConnectionExt as _, ResourceIdSpec};
+use crate::protocol::xproto::{AtomEnum, ConnectionExt as _, Window};
+
+/// The first version of the X-Resource extension that supports `QueryClientIds` and
+/// `QueryResourceBytes`.
+const CLIENT_IDS_VERSION: (u32, u32) = (1, 2);
+
+/// Resource usage of a client of the X11 server.
+#[derive(Debug, Clone)]
+pub struct ClientUsage {
+    /// The first XID that belongs to the client.
+    pub resource_base: u32,
+    /// The mask of XIDs that belong to the client.
+    pub resource_mask: u32,
+    /// The process ID of the client, if known.
+    ///
+    /// This is only available with version 1.2 of the X-Resource extension and for clients that
+    /// are connected to the server via a local transport.
+    pub pid: Option<u32>,
+    /// The number of resources of the client for each resource type.
+    pub resources: Vec<res::Type>,
+    /// The number of bytes of server memory used by the resources of the client.
+    ///
+    /// With versions of the X-Resource extension before 1.2, only the memory used by pixmaps is
+    /// counted.
+    pub bytes: u64,
+}
+
+/// Check whether the server supports version 1.2 of the X-Resource extension.
+fn supports_client_ids<C: RequestConnection + ?Sized>(conn: &C) -> Result<bool, ReplyError> {
+    if conn
+        .extension_information(res::X11_EXTENSION_NAME)?
+        .is_none()
+    {
+        return Ok(false);
+    }
+    let (major, minor) = CLIENT_IDS_VERSION;
+    let reply = conn.res_query_version(major as u8, minor as u8)?.reply()?;
+    Ok((u32::from(reply.server_major), u32::from(reply.server_minor)) >= CLIENT_IDS_VERSION)
+}
+
+/// Find the process ID of the client that created a window.
+///
+/// This uses `QueryClientIds` of the X-Resource extension if available. Otherwise, the
+/// `_NET_WM_PID` property of the window is used. That property is set by the client itself, so it
+/// can be missing or wrong. `None` is returned if the process ID cannot be determined.
+pub fn window_pid<C: RequestConnection + ?Sized>(
+    conn: &C,
+    window: Window,
+) -> Result<Option<u32>, ReplyError> {
+    if supports_client_ids(conn)? {
+        let spec = ClientIdSpec {
+            client: window,
+            mask: ClientIdMask::LOCAL_CLIENT_PID,
+        };
+        let reply = conn.res_query_client_ids(&[spec])?.reply()?;
+        let pid = reply
+            .ids
+            .iter()
+            .find(|id| id.spec.mask.contains(ClientIdMask::LOCAL_CLIENT_PID))
+            .and_then(|id| id.value.first().copied());
+        if pid.is_some() {
+            return Ok(pid);
+        }
+    }
+
+    let net_wm_pid = conn.intern_atom(true, b"_NET_WM_PID")?.reply()?.atom;
+    if net_wm_pid == u32::from(AtomEnum::NONE) {
+        return Ok(None);
+    }
+    let reply = conn
+        .get_property(false, window, net_wm_pid, AtomEnum::CARDINAL, 0, 1)?
+        .reply()?;
+    Ok(reply.value32().and_then(|mut value| value.next()))
+}
+
+/// Get the resource usage of all clients of the X11 server.
+///
+/// The X-Resource extension must be supported by the server. Clients that disconnect while their
+/// resources are queried are left out of the result.
+pub fn client_usage<C: RequestConnection + ?Sized>(
+    conn: &C,
+) -> Result<Vec<ClientUsage>, ReplyError> {
+    let client_ids = supports_client_ids(conn)?;
+    let clients = conn.res_query_clients()?.reply()?.clients;
+
+    let mut pids = HashMap::new();
+    if client_ids {
+        let specs = clients
+            .iter()
+            .map(|client| ClientIdSpec {
+                client: client.resource_base,
+                mask: ClientIdMask::LOCAL_CLIENT_PID,
+            })
+            .collect::<Vec<_>>();
+        for id in conn.res_query_client_ids(&specs)?.reply()?.ids {
+            if let Some(&pid) = id.value.first() {
+                let _ = pids.insert(id.spec.client, pid);
+            }
+        }
+    }
+
+    // Send all requests before waiting for the first reply
+    let all_resources = [ResourceIdSpec {
+        resource: 0,
+        type_: 0,
+    }];
+    let mut cookies = Vec::with_capacity(clients.len());
+    for client in clients.iter() {
+        let resources = conn.res_query_client_resources(client.resource_base)?;
+        let bytes = if client_ids {
+            Ok(conn.res_query_resource_bytes(client.resource_base, &all_resources)?)
+        } else {
+            Err(conn.res_query_client_pixmap_bytes(client.resource_base)?)
+        };
+        cookies.push((resources, bytes));
+    }
+
+    let mut result = Vec::with_capacity(clients.len());
+    for (client, (resources, bytes)) in clients.into_iter().zip(cookies) {
+        let resources = resources.reply();
+        let bytes = match bytes {
+            Ok(cookie) => cookie.reply().map(|reply| {
+                reply
+                    .sizes
+                    .iter()
+                    .map(|size| u64::from(size.size.bytes))
+                    .sum()
+            }),
+            Err(cookie) => cookie
+                .reply()
+                .map(|reply| (u64::from(reply.bytes_overflow) << 32) | u64::from(reply.bytes)),
+        };
+        let (resources, bytes) = match (resources, bytes) {
+            (Ok(resources), Ok(bytes)) => (resources.types, bytes),
+            // The client disconnected in the meantime
+            (Err(ReplyError::X11Error(_)), _) | (_, Err(ReplyError::X11Error(_))) => continue,
+            (Err(e), _) | (_, Err(e)) => return Err(e),
+        };
+        result.push(ClientUsage {
+            resource_base: client.resource_base,
+            resource_mask: client.resource_mask,
+            pid: pids.get(&client.resource_base).copied(),
+            resources,
+            bytes,
+        });
+    }
+    Ok(result)
+}
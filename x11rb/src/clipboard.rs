@@ -0,0 +1,1407 @@
+//! Owning and reading selections, e.g. the clipboard.
+//!
+//! [`Clipboard`] implements the selection protocol of ICCCM §2 on top of a [`Connection`]: It
+//! takes ownership of selections and answers the requests of other clients, including the
+//! `TARGETS`, `MULTIPLE` and `TIMESTAMP` targets, and it reads selections that other clients own.
+//! Data that does not fit into a single request is transferred with the `INCR` mechanism in both
+//! directions. Before exiting, [`Clipboard::save`] hands the `CLIPBOARD` selection over to a
+//! clipboard manager, see also [`crate::clipboard_manager`].
+//!
+//! The protocol logic itself does not send any requests. [`SelectionOwner`] and
+//! [`IncomingTransfer`] consume events and property replies and return the [`Action`]s that have
+//! to be performed, so that other frontends, e.g. the one in `x11rb-async`, can share it.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::os::unix::io::AsFd;
+//! use x11rb::clipboard::Clipboard;
+//! use x11rb::connection::Connection;
+//!
+//! let (conn, screen_num) = x11rb::connect(None)?;
+//! let mut clipboard = Clipboard::new(&conn, conn.stream().as_fd(), screen_num)?;
+//! let time = conn.fetch_server_time(screen_num)?;
+//! if let Some(text) = clipboard.get_text(clipboard.atoms().CLIPBOARD, time)? {
+//!     println!("The clipboard contains {:?}", text);
+//! }
+//! clipboard.set_text(clipboard.atoms().CLIPBOARD, time, "Hello")?;
+//! loop {
+//!     let event = conn.wait_for_event()?;
+//!     if !clipboard.handle_event(&event)? {
+//!         println!("Unrelated event {:?}", event);
+//!     }
+//!     conn.flush()?;
+//! }
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::collections::HashMap;
+
+use crate::connection::RequestConnection;
+use crate::errors::ConnectionError;
+use crate::protocol::xproto::{
+    self, Atom, AtomEnum, ChangeWindowAttributesAux, EventMask, GetPropertyReply, PropMode,
+    Property, PropertyNotifyEvent, SelectionClearEvent, SelectionNotifyEvent,
+    SelectionRequestEvent, Timestamp, Window, SELECTION_NOTIFY_EVENT,
+};
+use crate::protocol::Event;
+use crate::{CURRENT_TIME, NONE};
+
+// The blocking frontend needs to poll the file descriptor of the connection
+#[cfg(not(target_arch = "wasm32"))]
+use {
+    crate::connection::Connection,
+    crate::errors::{ParseError, ReplyError, ReplyOrIdError},
+    crate::protocol::xproto::{CreateWindowAux, WindowClass},
+    crate::utils::poll_until,
+    crate::COPY_DEPTH_FROM_PARENT,
+    rustix::event::PollFlags,
+    rustix::fd::BorrowedFd,
+    std::collections::VecDeque,
+    std::time::{Duration, Instant},
+};
+
+pub use atoms::{ClipboardAtoms, ClipboardAtomsCookie};
+
+mod atoms {
+    // The code that the macro generates has no documentation and a `new` function that returns a
+    // cookie
+    #![allow(missing_docs, clippy::new_ret_no_self)]
+
+    crate::atom_manager! {
+        /// The atoms of the selection protocol.
+        pub ClipboardAtoms:
+        /// A cookie for interning the atoms of the selection protocol.
+        ClipboardAtomsCookie {
+            CLIPBOARD,
+            TARGETS,
+            MULTIPLE,
+            TIMESTAMP,
+            INCR,
+            ATOM_PAIR,
+            UTF8_STRING,
+            CLIPBOARD_MANAGER,
+            SAVE_TARGETS,
+            MANAGER,
+            X11RB_SELECTION,
+        }
+    }
+}
+
+/// The largest chunk of an `INCR` transfer.
+const MAX_CHUNK_SIZE: usize = 1 << 18;
+
+/// How long [`Clipboard`] waits for the owner of a selection by default.
+#[cfg(not(target_arch = "wasm32"))]
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Get the size of the chunks of `INCR` transfers for a maximum request size in bytes.
+///
+/// The result is a multiple of four bytes, so that chunks do not split 16 or 32 bit values.
+pub fn chunk_size(maximum_request_bytes: usize) -> usize {
+    // Leave room for the header of a ChangeProperty request
+    maximum_request_bytes
+        .saturating_sub(32)
+        .clamp(4, MAX_CHUNK_SIZE)
+        & !3
+}
+
+/// Check whether server time `a` is before server time `b`, taking wrap-around into account.
+fn time_before(a: Timestamp, b: Timestamp) -> bool {
+    (a.wrapping_sub(b) as i32) < 0
+}
+
+/// The content of a selection in one format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectionData {
+    /// The type of the data, e.g. `UTF8_STRING` or `ATOM`.
+    pub type_: Atom,
+
+    /// Whether the data consists of 8, 16 or 32 bit values.
+    pub format: u8,
+
+    /// The data. 16 and 32 bit values are in the byte order of this client, which is also the
+    /// byte order that the X11 server uses for this connection.
+    pub data: Vec<u8>,
+}
+
+impl SelectionData {
+    /// Create data with 8 bit values, e.g. text.
+    pub fn bytes(type_: impl Into<Atom>, data: Vec<u8>) -> Self {
+        Self {
+            type_: type_.into(),
+            format: 8,
+            data,
+        }
+    }
+
+    /// Create data with 32 bit values, e.g. atoms.
+    pub fn values32(type_: impl Into<Atom>, values: &[u32]) -> Self {
+        Self {
+            type_: type_.into(),
+            format: 32,
+            data: values
+                .iter()
+                .flat_map(|value| value.to_ne_bytes())
+                .collect(),
+        }
+    }
+
+    /// Get the data of a `GetProperty` reply.
+    pub fn from_reply(reply: GetPropertyReply) -> Self {
+        Self {
+            type_: reply.type_,
+            format: reply.format,
+            data: reply.value,
+        }
+    }
+
+    /// Get the values of data with 32 bit values, e.g. the atoms of the `TARGETS` target.
+    ///
+    /// Returns `None` if the data has another format.
+    pub fn parse_values32(&self) -> Option<Vec<u32>> {
+        if self.format != 32 {
+            return None;
+        }
+        let values = self
+            .data
+            .chunks_exact(4)
+            .map(|value| u32::from_ne_bytes([value[0], value[1], value[2], value[3]]))
+            .collect();
+        Some(values)
+    }
+
+    /// Get the number of values in the data, e.g. for a `ChangeProperty` request.
+    pub fn value_len(&self) -> u32 {
+        let size = usize::from(self.format.max(8) / 8);
+        u32::try_from(self.data.len() / size).unwrap_or(u32::MAX)
+    }
+}
+
+/// The data that the owner of a selection offers, in one or more formats.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SelectionContents {
+    targets: Vec<(Atom, SelectionData)>,
+}
+
+impl SelectionContents {
+    /// Create contents without any formats.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create contents with a text as `UTF8_STRING` and, if possible, as Latin-1 `STRING`.
+    pub fn text(atoms: &ClipboardAtoms, text: &str) -> Self {
+        let utf8 = SelectionData::bytes(atoms.UTF8_STRING, text.as_bytes().to_vec());
+        let contents = Self::new().with(atoms.UTF8_STRING, utf8);
+        let latin1 = text
+            .chars()
+            .map(|c| u8::try_from(c).ok())
+            .collect::<Option<Vec<_>>>();
+        match latin1 {
+            Some(latin1) => contents.with(
+                AtomEnum::STRING,
+                SelectionData::bytes(AtomEnum::STRING, latin1),
+            ),
+            None => contents,
+        }
+    }
+
+    /// Add a format, e.g. the `image/png` target with PNG data.
+    ///
+    /// The formats should be added in the order of preference. A format that was added before
+    /// with the same target is replaced.
+    #[must_use]
+    pub fn with(mut self, target: impl Into<Atom>, data: SelectionData) -> Self {
+        let target = target.into();
+        self.targets.retain(|(t, _)| *t != target);
+        self.targets.push((target, data));
+        self
+    }
+
+    /// Get the data for a target.
+    pub fn get(&self, target: Atom) -> Option<&SelectionData> {
+        self.targets
+            .iter()
+            .find(|(t, _)| *t == target)
+            .map(|(_, data)| data)
+    }
+
+    /// Iterate over the targets of the contents.
+    pub fn targets(&self) -> impl Iterator<Item = Atom> + '_ {
+        self.targets.iter().map(|(target, _)| *target)
+    }
+}
+
+/// A request that a frontend of the selection protocol has to send to the X11 server.
+#[derive(Debug, Clone)]
+pub enum Action {
+    /// Replace a property of a window.
+    ChangeProperty {
+        /// The window with the property.
+        window: Window,
+        /// The property.
+        property: Atom,
+        /// The new value.
+        data: SelectionData,
+    },
+
+    /// Select or deselect `PropertyChange` events on a window of another client.
+    SelectPropertyChange {
+        /// The window.
+        window: Window,
+        /// Whether the events should be selected.
+        select: bool,
+    },
+
+    /// Send a `SelectionNotify` event to the requestor of a selection.
+    SendSelectionNotify(SelectionNotifyEvent),
+}
+
+impl Action {
+    /// Send the request for this action.
+    pub fn execute<C: RequestConnection + ?Sized>(self, conn: &C) -> Result<(), ConnectionError> {
+        match self {
+            Action::ChangeProperty {
+                window,
+                property,
+                data,
+            } => {
+                let len = data.value_len();
+                let _ = xproto::change_property(
+                    conn,
+                    PropMode::REPLACE,
+                    window,
+                    property,
+                    data.type_,
+                    data.format,
+                    len,
+                    &data.data,
+                )?;
+            }
+            Action::SelectPropertyChange { window, select } => {
+                let mask = if select {
+                    EventMask::PROPERTY_CHANGE
+                } else {
+                    EventMask::NO_EVENT
+                };
+                let aux = ChangeWindowAttributesAux::new().event_mask(mask);
+                let _ = xproto::change_window_attributes(conn, window, &aux)?;
+            }
+            Action::SendSelectionNotify(event) => {
+                let requestor = event.requestor;
+                let _ = xproto::send_event(conn, false, requestor, EventMask::NO_EVENT, event)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A selection that is owned.
+#[derive(Debug, Clone)]
+struct OwnedSelection {
+    time: Timestamp,
+    contents: SelectionContents,
+}
+
+/// An outgoing `INCR` transfer.
+#[derive(Debug, Clone)]
+struct OutgoingTransfer {
+    requestor: Window,
+    property: Atom,
+    data: SelectionData,
+    offset: usize,
+    /// Whether the transfer made progress since the last [`SelectionOwner::expire_transfers`].
+    active: bool,
+}
+
+/// The protocol logic of the owner of selections.
+///
+/// The owner answers `SelectionRequest` events with the contents of its selections. Large data
+/// is sent in chunks with the `INCR` mechanism, which needs the `PropertyNotify` events of the
+/// requestor windows.
+#[derive(Debug, Clone)]
+pub struct SelectionOwner {
+    window: Window,
+    atoms: ClipboardAtoms,
+    chunk_size: usize,
+    owned: HashMap<Atom, OwnedSelection>,
+    transfers: Vec<OutgoingTransfer>,
+}
+
+impl SelectionOwner {
+    /// Create the logic for a window that owns selections.
+    ///
+    /// Data that is larger than `chunk_size` bytes is sent with `INCR`, see [`chunk_size`].
+    pub fn new(window: Window, atoms: ClipboardAtoms, chunk_size: usize) -> Self {
+        Self {
+            window,
+            atoms,
+            chunk_size,
+            owned: HashMap::new(),
+            transfers: Vec::new(),
+        }
+    }
+
+    /// Remember the contents of a selection after `SetSelectionOwner` succeeded.
+    pub fn set(&mut self, selection: Atom, time: Timestamp, contents: SelectionContents) {
+        let _ = self
+            .owned
+            .insert(selection, OwnedSelection { time, contents });
+    }
+
+    /// Forget the contents of a selection.
+    pub fn clear(&mut self, selection: Atom) -> Option<SelectionContents> {
+        self.owned.remove(&selection).map(|owned| owned.contents)
+    }
+
+    /// Get the contents of a selection, if it is owned.
+    pub fn contents(&self, selection: Atom) -> Option<&SelectionContents> {
+        self.owned.get(&selection).map(|owned| &owned.contents)
+    }
+
+    /// Get the targets of an owned selection for a `SAVE_TARGETS` request.
+    ///
+    /// The targets that the owner answers itself, e.g. `TARGETS`, are not included.
+    pub fn save_targets(&self, selection: Atom) -> Option<SelectionData> {
+        let targets = self.contents(selection)?.targets().collect::<Vec<_>>();
+        Some(SelectionData::values32(AtomEnum::ATOM, &targets))
+    }
+
+    /// Get an owned selection in the format of a target, as it would be sent to a requestor.
+    ///
+    /// This includes the targets that the owner answers itself, e.g. `TARGETS`.
+    pub fn lookup(&self, selection: Atom, target: Atom) -> Option<SelectionData> {
+        let owned = self.owned.get(&selection)?;
+        if target == self.atoms.TARGETS {
+            let targets = [
+                self.atoms.TARGETS,
+                self.atoms.MULTIPLE,
+                self.atoms.TIMESTAMP,
+            ]
+            .into_iter()
+            .chain(owned.contents.targets())
+            .collect::<Vec<_>>();
+            Some(SelectionData::values32(AtomEnum::ATOM, &targets))
+        } else if target == self.atoms.TIMESTAMP {
+            Some(SelectionData::values32(AtomEnum::INTEGER, &[owned.time]))
+        } else {
+            owned.contents.get(target).cloned()
+        }
+    }
+
+    /// Check whether data is still being sent with `INCR`.
+    pub fn has_transfers(&self) -> bool {
+        !self.transfers.is_empty()
+    }
+
+    /// Drop the `INCR` transfers that made no progress since the last call.
+    ///
+    /// A requestor may give up on a transfer or be destroyed before the transfer finished. Such
+    /// transfers are never completed. Frontends should call this periodically, e.g. once per
+    /// timeout, so that they do not pile up.
+    pub fn expire_transfers(&mut self) -> Vec<Action> {
+        let (active, expired): (Vec<_>, Vec<_>) = std::mem::take(&mut self.transfers)
+            .into_iter()
+            .partition(|transfer| transfer.active);
+        self.transfers = active;
+        for transfer in &mut self.transfers {
+            transfer.active = false;
+        }
+        let mut windows = expired
+            .into_iter()
+            .map(|transfer| transfer.requestor)
+            .collect::<Vec<_>>();
+        windows.sort_unstable();
+        windows.dedup();
+        windows
+            .into_iter()
+            .filter(|window| {
+                *window != self.window && !self.transfers.iter().any(|t| t.requestor == *window)
+            })
+            .map(|window| {
+                crate::debug!("Dropping stalled INCR transfers to window {}", window);
+                Action::SelectPropertyChange {
+                    window,
+                    select: false,
+                }
+            })
+            .collect()
+    }
+
+    /// Answer a `SelectionRequest`.
+    ///
+    /// For the `MULTIPLE` target, `multiple` has to contain the pairs of targets and properties
+    /// from the `ATOM_PAIR` property of the requestor. The request is refused otherwise.
+    pub fn handle_request(
+        &mut self,
+        event: &SelectionRequestEvent,
+        multiple: Option<&[(Atom, Atom)]>,
+    ) -> Vec<Action> {
+        let mut actions = Vec::new();
+        // Obsolete clients do not give a property
+        let property = if event.property == NONE {
+            event.target
+        } else {
+            event.property
+        };
+        let owned = self.owned.get(&event.selection);
+        let valid = owned.map_or(false, |owned| {
+            event.owner == self.window
+                && (event.time == CURRENT_TIME || !time_before(event.time, owned.time))
+        });
+        let converted = if !valid {
+            false
+        } else if event.target == self.atoms.MULTIPLE {
+            match multiple {
+                Some(pairs) => {
+                    let mut result = Vec::with_capacity(pairs.len() * 2);
+                    for (target, property) in pairs {
+                        let property = if self.convert(event, *target, *property, &mut actions) {
+                            *property
+                        } else {
+                            NONE
+                        };
+                        result.extend([*target, property]);
+                    }
+                    actions.push(Action::ChangeProperty {
+                        window: event.requestor,
+                        property,
+                        data: SelectionData::values32(self.atoms.ATOM_PAIR, &result),
+                    });
+                    true
+                }
+                None => false,
+            }
+        } else {
+            self.convert(event, event.target, property, &mut actions)
+        };
+        let notify = SelectionNotifyEvent {
+            response_type: SELECTION_NOTIFY_EVENT,
+            sequence: 0,
+            time: event.time,
+            requestor: event.requestor,
+            selection: event.selection,
+            target: event.target,
+            property: if converted { property } else { NONE },
+        };
+        // The notification has to come after all properties were written
+        actions.push(Action::SendSelectionNotify(notify));
+        actions
+    }
+
+    /// Store one target of a selection in a property of the requestor.
+    ///
+    /// Returns `false` if the target is not available.
+    fn convert(
+        &mut self,
+        event: &SelectionRequestEvent,
+        target: Atom,
+        property: Atom,
+        actions: &mut Vec<Action>,
+    ) -> bool {
+        let data = match self.lookup(event.selection, target) {
+            Some(data) => data,
+            None => return false,
+        };
+        let window = event.requestor;
+        if data.data.len() <= self.chunk_size {
+            actions.push(Action::ChangeProperty {
+                window,
+                property,
+                data,
+            });
+            return true;
+        }
+        // The events have to be selected before the requestor can react to the INCR property,
+        // or else we could miss the deletion that starts the transfer. The window of the owner
+        // itself always has them selected.
+        let selected = window == self.window
+            || actions.iter().any(|action| {
+                matches!(action, Action::SelectPropertyChange { window: w, select: true } if *w == window)
+            });
+        if !selected {
+            actions.push(Action::SelectPropertyChange {
+                window,
+                select: true,
+            });
+        }
+        let size = u32::try_from(data.data.len()).unwrap_or(u32::MAX);
+        actions.push(Action::ChangeProperty {
+            window,
+            property,
+            data: SelectionData::values32(self.atoms.INCR, &[size]),
+        });
+        // A new request for the same property replaces an old transfer
+        self.transfers
+            .retain(|t| t.requestor != window || t.property != property);
+        self.transfers.push(OutgoingTransfer {
+            requestor: window,
+            property,
+            data,
+            offset: 0,
+            active: true,
+        });
+        true
+    }
+
+    /// Send the next chunk of an `INCR` transfer after the requestor deleted the property.
+    pub fn handle_property_notify(&mut self, event: &PropertyNotifyEvent) -> Vec<Action> {
+        if event.state != Property::DELETE {
+            return Vec::new();
+        }
+        let index = match self
+            .transfers
+            .iter()
+            .position(|t| t.requestor == event.window && t.property == event.atom)
+        {
+            Some(index) => index,
+            None => return Vec::new(),
+        };
+        let transfer = &mut self.transfers[index];
+        let end = transfer
+            .data
+            .data
+            .len()
+            .min(transfer.offset + self.chunk_size);
+        let chunk = SelectionData {
+            data: transfer.data.data[transfer.offset..end].to_vec(),
+            ..transfer.data
+        };
+        let done = chunk.data.is_empty();
+        transfer.offset = end;
+        transfer.active = true;
+        let mut actions = vec![Action::ChangeProperty {
+            window: event.window,
+            property: event.atom,
+            data: chunk,
+        }];
+        if done {
+            let _ = self.transfers.remove(index);
+            if event.window != self.window
+                && !self.transfers.iter().any(|t| t.requestor == event.window)
+            {
+                actions.push(Action::SelectPropertyChange {
+                    window: event.window,
+                    select: false,
+                });
+            }
+        }
+        actions
+    }
+
+    /// Handle the loss of a selection.
+    ///
+    /// Returns `true` if the event is about a selection of this owner, which is forgotten.
+    pub fn handle_selection_clear(&mut self, event: &SelectionClearEvent) -> bool {
+        event.owner == self.window && self.owned.remove(&event.selection).is_some()
+    }
+}
+
+/// The progress of an [`IncomingTransfer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferStep {
+    /// The event does not belong to the transfer.
+    Ignore,
+    /// The property has to be read with `GetProperty` and `delete` set, see
+    /// [`IncomingTransfer::handle_property`].
+    ReadProperty,
+    /// The owner refused to convert the selection.
+    Refused,
+}
+
+/// The protocol logic for reading a selection.
+///
+/// The frontend sends a `ConvertSelection` request for the window and the property of the
+/// transfer. The window must have `PropertyChange` selected for `INCR` transfers.
+#[derive(Debug, Clone)]
+pub struct IncomingTransfer {
+    window: Window,
+    selection: Atom,
+    target: Atom,
+    property: Atom,
+    incr: Option<SelectionData>,
+}
+
+impl IncomingTransfer {
+    /// Start reading a selection into a property of a window.
+    pub fn new(window: Window, selection: Atom, target: Atom, property: Atom) -> Self {
+        Self {
+            window,
+            selection,
+            target,
+            property,
+            incr: None,
+        }
+    }
+
+    /// Handle an event while waiting for the data.
+    pub fn handle_event(&self, event: &Event) -> TransferStep {
+        match event {
+            Event::SelectionNotify(event)
+                if self.incr.is_none()
+                    && event.requestor == self.window
+                    && event.selection == self.selection
+                    && event.target == self.target =>
+            {
+                if event.property == NONE {
+                    TransferStep::Refused
+                } else {
+                    TransferStep::ReadProperty
+                }
+            }
+            Event::PropertyNotify(event)
+                if self.incr.is_some()
+                    && event.window == self.window
+                    && event.atom == self.property
+                    && event.state == Property::NEW_VALUE =>
+            {
+                TransferStep::ReadProperty
+            }
+            _ => TransferStep::Ignore,
+        }
+    }
+
+    /// Get the selection that is read.
+    pub fn selection(&self) -> Atom {
+        self.selection
+    }
+
+    /// Get the target that the selection is converted to.
+    pub fn target(&self) -> Atom {
+        self.target
+    }
+
+    /// Get the window of the transfer.
+    pub fn window(&self) -> Window {
+        self.window
+    }
+
+    /// Get the property of the transfer.
+    pub fn property(&self) -> Atom {
+        self.property
+    }
+
+    /// Handle the value of the property, which was deleted when it was read.
+    ///
+    /// Returns the data when the transfer is complete.
+    pub fn handle_property(
+        &mut self,
+        reply: GetPropertyReply,
+        atoms: &ClipboardAtoms,
+    ) -> Option<SelectionData> {
+        match self.incr.as_mut() {
+            None if reply.type_ == atoms.INCR => {
+                // Deleting the property started the transfer
+                self.incr = Some(SelectionData::bytes(NONE, Vec::new()));
+                None
+            }
+            None => Some(SelectionData::from_reply(reply)),
+            Some(data) if reply.value.is_empty() => Some(std::mem::replace(
+                data,
+                SelectionData::bytes(NONE, Vec::new()),
+            )),
+            Some(data) => {
+                data.type_ = reply.type_;
+                data.format = reply.format;
+                data.data.extend_from_slice(&reply.value);
+                None
+            }
+        }
+    }
+}
+
+/// Get the pairs of targets and properties from the `ATOM_PAIR` property of a `MULTIPLE` request.
+pub fn atom_pairs(reply: &GetPropertyReply) -> Option<Vec<(Atom, Atom)>> {
+    let values = reply.value32()?.collect::<Vec<_>>();
+    Some(
+        values
+            .chunks_exact(2)
+            .map(|pair| (pair[0], pair[1]))
+            .collect(),
+    )
+}
+
+/// Owns and reads selections through a window.
+///
+/// Events that arrive while [`Clipboard::get`] waits for the data of a selection and that do not
+/// belong to the selection protocol are set aside, see [`Clipboard::take_deferred_events`].
+/// Owned selections are released when this is dropped.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug)]
+pub struct Clipboard<'c, C: Connection> {
+    conn: &'c C,
+    fd: BorrowedFd<'c>,
+    window: Window,
+    atoms: ClipboardAtoms,
+    owner: SelectionOwner,
+    timeout: Duration,
+    /// When [`SelectionOwner::expire_transfers`] has to be called next.
+    expire_transfers_at: Instant,
+    deferred: VecDeque<Event>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<'c, C: Connection> Clipboard<'c, C> {
+    /// Create a window for the selections of a screen.
+    ///
+    /// `fd` must be the file descriptor of the connection, e.g. `conn.stream().as_fd()` for a
+    /// [`RustConnection`](crate::rust_connection::RustConnection). It is used to wait for the
+    /// owner of a selection without blocking forever.
+    ///
+    /// This function fails with [`ParseError::InvalidValue`] if `screen` does not exist.
+    pub fn new(conn: &'c C, fd: BorrowedFd<'c>, screen: usize) -> Result<Self, ReplyOrIdError> {
+        let root = conn
+            .setup()
+            .roots
+            .get(screen)
+            .ok_or(ParseError::InvalidValue)?
+            .root;
+        let atoms = ClipboardAtoms::new(conn)?;
+        let window = conn.generate_id()?;
+        let aux = CreateWindowAux::new().event_mask(EventMask::PROPERTY_CHANGE);
+        let _ = xproto::create_window(
+            conn,
+            COPY_DEPTH_FROM_PARENT,
+            window,
+            root,
+            -1,
+            -1,
+            1,
+            1,
+            0,
+            WindowClass::INPUT_ONLY,
+            0,
+            &aux,
+        )?;
+        let atoms = atoms.reply()?;
+        let chunk_size = chunk_size(conn.maximum_request_bytes());
+        Ok(Self {
+            conn,
+            fd,
+            window,
+            atoms,
+            owner: SelectionOwner::new(window, atoms, chunk_size),
+            timeout: DEFAULT_TIMEOUT,
+            expire_transfers_at: Instant::now() + DEFAULT_TIMEOUT,
+            deferred: VecDeque::new(),
+        })
+    }
+
+    /// Get the window that owns and reads selections.
+    pub fn window(&self) -> Window {
+        self.window
+    }
+
+    /// Get the atoms of the selection protocol.
+    pub fn atoms(&self) -> &ClipboardAtoms {
+        &self.atoms
+    }
+
+    /// Set how long [`Clipboard::get`] waits for the owner of a selection.
+    ///
+    /// The default is five seconds.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Take the events that were set aside while waiting for the data of a selection.
+    pub fn take_deferred_events(&mut self) -> VecDeque<Event> {
+        std::mem::take(&mut self.deferred)
+    }
+
+    /// Check whether this owns a selection.
+    pub fn is_owner(&self, selection: Atom) -> bool {
+        self.owner.contents(selection).is_some()
+    }
+
+    /// Take ownership of a selection, e.g. `CLIPBOARD` or `PRIMARY`.
+    ///
+    /// `time` must be the server time of the user action that caused this. Returns `false` if
+    /// the selection could not be acquired.
+    pub fn set(
+        &mut self,
+        selection: impl Into<Atom>,
+        time: Timestamp,
+        contents: SelectionContents,
+    ) -> Result<bool, ReplyError> {
+        let selection = selection.into();
+        let _ = xproto::set_selection_owner(self.conn, self.window, selection, time)?;
+        let owner = xproto::get_selection_owner(self.conn, selection)?
+            .reply()?
+            .owner;
+        if owner != self.window {
+            return Ok(false);
+        }
+        self.owner.set(selection, time, contents);
+        Ok(true)
+    }
+
+    /// Take ownership of a selection with a text.
+    pub fn set_text(
+        &mut self,
+        selection: impl Into<Atom>,
+        time: Timestamp,
+        text: &str,
+    ) -> Result<bool, ReplyError> {
+        let contents = SelectionContents::text(&self.atoms, text);
+        self.set(selection, time, contents)
+    }
+
+    /// Give up the ownership of a selection.
+    pub fn clear(
+        &mut self,
+        selection: impl Into<Atom>,
+        time: Timestamp,
+    ) -> Result<(), ConnectionError> {
+        let selection = selection.into();
+        if self.owner.clear(selection).is_some() {
+            let _ = xproto::set_selection_owner(self.conn, NONE, selection, time)?;
+        }
+        Ok(())
+    }
+
+    /// Handle an event of the selection protocol.
+    ///
+    /// Requests for owned selections are answered and `INCR` transfers are continued. Returns
+    /// `false` if the event does not belong to the selection protocol.
+    ///
+    /// `INCR` transfers that made no progress for the timeout are dropped, see
+    /// [`Clipboard::set_timeout`].
+    pub fn handle_event(&mut self, event: &Event) -> Result<bool, ReplyError> {
+        let now = Instant::now();
+        if now >= self.expire_transfers_at {
+            self.expire_transfers_at = now + self.timeout;
+            for action in self.owner.expire_transfers() {
+                action.execute(self.conn)?;
+            }
+        }
+        let actions = match event {
+            Event::SelectionRequest(event) if event.owner == self.window => {
+                let pairs = if event.target == self.atoms.MULTIPLE && event.property != NONE {
+                    let reply = xproto::get_property(
+                        self.conn,
+                        false,
+                        event.requestor,
+                        event.property,
+                        AtomEnum::ANY,
+                        0,
+                        u32::MAX,
+                    )?
+                    .reply()?;
+                    atom_pairs(&reply)
+                } else {
+                    None
+                };
+                self.owner.handle_request(event, pairs.as_deref())
+            }
+            // This includes our own window, which is the requestor when we read our own selection
+            Event::PropertyNotify(event) => self.owner.handle_property_notify(event),
+            Event::SelectionClear(event) => return Ok(self.owner.handle_selection_clear(event)),
+            _ => return Ok(false),
+        };
+        let handled = !actions.is_empty();
+        for action in actions {
+            action.execute(self.conn)?;
+        }
+        Ok(handled)
+    }
+
+    /// Read a selection in the format of a target, e.g. `UTF8_STRING`.
+    ///
+    /// This waits until the owner of the selection sent the data. Returns `None` if the
+    /// selection has no owner, the owner refused to convert it to the target or did not answer
+    /// in time, see [`Clipboard::set_timeout`]. If this owns the selection, the data is returned
+    /// without asking the X11 server.
+    pub fn get(
+        &mut self,
+        selection: impl Into<Atom>,
+        target: impl Into<Atom>,
+        time: Timestamp,
+    ) -> Result<Option<SelectionData>, ReplyError> {
+        let (selection, target) = (selection.into(), target.into());
+        if self.is_owner(selection) {
+            return Ok(self.owner.lookup(selection, target));
+        }
+        let property = self.atoms.X11RB_SELECTION;
+        let transfer = IncomingTransfer::new(self.window, selection, target, property);
+        let _ = xproto::delete_property(self.conn, self.window, property)?;
+        self.receive(transfer, time)
+    }
+
+    /// Ask the clipboard manager to save the contents of the `CLIPBOARD` selection.
+    ///
+    /// Applications should call this before they exit, so that copied data persists. This
+    /// waits until the clipboard manager has read the data, while answering its requests.
+    /// Returns `false` if the `CLIPBOARD` selection is not owned, there is no clipboard manager
+    /// or it failed to save the data.
+    pub fn save(&mut self, time: Timestamp) -> Result<bool, ReplyError> {
+        let targets = match self.owner.save_targets(self.atoms.CLIPBOARD) {
+            Some(targets) => targets,
+            None => return Ok(false),
+        };
+        let manager = xproto::get_selection_owner(self.conn, self.atoms.CLIPBOARD_MANAGER)?
+            .reply()?
+            .owner;
+        if manager == NONE {
+            return Ok(false);
+        }
+        let property = self.atoms.X11RB_SELECTION;
+        let transfer = IncomingTransfer::new(
+            self.window,
+            self.atoms.CLIPBOARD_MANAGER,
+            self.atoms.SAVE_TARGETS,
+            property,
+        );
+        Action::ChangeProperty {
+            window: self.window,
+            property,
+            data: targets,
+        }
+        .execute(self.conn)?;
+        Ok(self.receive(transfer, time)?.is_some())
+    }
+
+    /// Convert a selection and wait for the data.
+    fn receive(
+        &mut self,
+        mut transfer: IncomingTransfer,
+        time: Timestamp,
+    ) -> Result<Option<SelectionData>, ReplyError> {
+        let property = transfer.property();
+        let _ = xproto::convert_selection(
+            self.conn,
+            self.window,
+            transfer.selection(),
+            transfer.target(),
+            property,
+            time,
+        )?;
+        let mut deadline = Instant::now() + self.timeout;
+        loop {
+            let event = match self.wait_for_event(deadline)? {
+                Some(event) => event,
+                None => return Ok(None),
+            };
+            match transfer.handle_event(&event) {
+                TransferStep::Refused => return Ok(None),
+                TransferStep::ReadProperty => {
+                    let reply = xproto::get_property(
+                        self.conn,
+                        true,
+                        self.window,
+                        property,
+                        AtomEnum::ANY,
+                        0,
+                        u32::MAX,
+                    )?
+                    .reply()?;
+                    if let Some(data) = transfer.handle_property(reply, &self.atoms) {
+                        return Ok(Some(data));
+                    }
+                    // Each chunk of an INCR transfer may take as long as the whole transfer
+                    deadline = Instant::now() + self.timeout;
+                }
+                TransferStep::Ignore => self.set_aside(event)?,
+            }
+        }
+    }
+
+    /// Get the current server time.
+    ///
+    /// This appends nothing to a property of the window and waits for the resulting
+    /// `PropertyNotify` event, which contains the server time. Events that arrive in the
+    /// meantime are handled like in [`Clipboard::get`]. Returns `None` if the event did not
+    /// arrive in time.
+    pub fn fetch_server_time(&mut self) -> Result<Option<Timestamp>, ReplyError> {
+        let _ = xproto::change_property(
+            self.conn,
+            PropMode::APPEND,
+            self.window,
+            AtomEnum::WM_NAME,
+            AtomEnum::STRING,
+            8,
+            0,
+            &[],
+        )?;
+        let deadline = Instant::now() + self.timeout;
+        loop {
+            let event = match self.wait_for_event(deadline)? {
+                Some(event) => event,
+                None => return Ok(None),
+            };
+            match event {
+                Event::PropertyNotify(event)
+                    if event.window == self.window && event.atom == AtomEnum::WM_NAME.into() =>
+                {
+                    return Ok(Some(event.time))
+                }
+                event => self.set_aside(event)?,
+            }
+        }
+    }
+
+    /// Wait for the next event until the deadline passed.
+    fn wait_for_event(&self, deadline: Instant) -> Result<Option<Event>, ConnectionError> {
+        loop {
+            match self.conn.poll_for_event()? {
+                Some(event) => return Ok(Some(event)),
+                None if Instant::now() >= deadline => return Ok(None),
+                None => {
+                    self.conn.flush_all()?;
+                    let _ = poll_until(self.fd, PollFlags::IN, Some(deadline))?;
+                }
+            }
+        }
+    }
+
+    /// Handle an event that arrived while waiting for something else.
+    fn set_aside(&mut self, event: Event) -> Result<(), ReplyError> {
+        // The selection may be owned by ourselves
+        if !self.handle_event(&event)?
+            && !matches!(event, Event::PropertyNotify(ref e) if e.window == self.window)
+        {
+            self.deferred.push_back(event);
+        }
+        Ok(())
+    }
+
+    /// Read a selection as text.
+    ///
+    /// `UTF8_STRING` is tried first and Latin-1 `STRING` afterwards.
+    pub fn get_text(
+        &mut self,
+        selection: impl Into<Atom>,
+        time: Timestamp,
+    ) -> Result<Option<String>, ReplyError> {
+        let selection = selection.into();
+        if let Some(data) = self.get(selection, self.atoms.UTF8_STRING, time)? {
+            return Ok(Some(String::from_utf8_lossy(&data.data).into_owned()));
+        }
+        let data = self.get(selection, AtomEnum::STRING, time)?;
+        Ok(data.map(|data| data.data.iter().map(|byte| char::from(*byte)).collect()))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<C: Connection> Drop for Clipboard<'_, C> {
+    fn drop(&mut self) {
+        // Destroying the window releases its selections
+        let _ = xproto::destroy_window(self.conn, self.window);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        chunk_size, Action, ClipboardAtoms, IncomingTransfer, SelectionContents, SelectionData,
+        SelectionOwner, TransferStep,
+    };
+    use crate::protocol::xproto::{
+        AtomEnum, GetPropertyReply, Property, PropertyNotifyEvent, SelectionNotifyEvent,
+        SelectionRequestEvent,
+    };
+    use crate::protocol::Event;
+
+    const ATOMS: ClipboardAtoms = ClipboardAtoms {
+        CLIPBOARD: 100,
+        TARGETS: 101,
+        MULTIPLE: 102,
+        TIMESTAMP: 103,
+        INCR: 104,
+        ATOM_PAIR: 105,
+        UTF8_STRING: 106,
+        CLIPBOARD_MANAGER: 108,
+        SAVE_TARGETS: 109,
+        MANAGER: 110,
+        X11RB_SELECTION: 107,
+    };
+
+    fn request(target: u32, property: u32) -> SelectionRequestEvent {
+        SelectionRequestEvent {
+            time: 50,
+            owner: 1,
+            requestor: 2,
+            selection: ATOMS.CLIPBOARD,
+            target,
+            property,
+            ..Default::default()
+        }
+    }
+
+    fn notify_property(actions: &[Action]) -> Option<u32> {
+        actions.iter().find_map(|action| match action {
+            Action::SendSelectionNotify(event) => Some(event.property),
+            _ => None,
+        })
+    }
+
+    fn reply(type_: u32, value: &[u8]) -> GetPropertyReply {
+        GetPropertyReply {
+            format: 8,
+            type_,
+            value_len: value.len() as u32,
+            value: value.to_vec(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn chunk_sizes() {
+        assert_eq!(chunk_size(262_140), 262_108);
+        assert_eq!(chunk_size(16 << 20), 1 << 18);
+        assert_eq!(chunk_size(0), 4);
+        let targets = SelectionData::values32(AtomEnum::ATOM, &[1, 0x1234_5678]);
+        assert_eq!(targets.parse_values32(), Some(vec![1, 0x1234_5678]));
+        assert_eq!(
+            SelectionData::bytes(31u32, vec![0; 4]).parse_values32(),
+            None
+        );
+    }
+
+    #[test]
+    fn answer_requests() {
+        let mut owner = SelectionOwner::new(1, ATOMS, 1024);
+        let contents = SelectionContents::text(&ATOMS, "Grüße");
+        assert_eq!(contents.targets().collect::<Vec<_>>(), [106, 31]);
+        owner.set(ATOMS.CLIPBOARD, 40, contents);
+        let saved = SelectionData::values32(AtomEnum::ATOM, &[106, 31]);
+        assert_eq!(owner.save_targets(ATOMS.CLIPBOARD), Some(saved));
+        assert_eq!(owner.save_targets(ATOMS.CLIPBOARD_MANAGER), None);
+
+        let actions = owner.handle_request(&request(ATOMS.UTF8_STRING, 9), None);
+        let data = SelectionData::bytes(ATOMS.UTF8_STRING, "Grüße".as_bytes().to_vec());
+        assert!(matches!(
+            &actions[0],
+            Action::ChangeProperty { window: 2, property: 9, data: d } if *d == data
+        ));
+        assert_eq!(notify_property(&actions), Some(9));
+
+        let actions = owner.handle_request(&request(ATOMS.TARGETS, 9), None);
+        let targets = SelectionData::values32(AtomEnum::ATOM, &[101, 102, 103, 106, 31]);
+        assert!(matches!(&actions[0], Action::ChangeProperty { data, .. } if *data == targets));
+
+        // Unknown targets, MULTIPLE without pairs and requests before the ownership are refused
+        let actions = owner.handle_request(&request(999, 9), None);
+        assert_eq!(actions.len(), 1);
+        assert_eq!(notify_property(&actions), Some(0));
+        assert_eq!(
+            notify_property(&owner.handle_request(&request(ATOMS.MULTIPLE, 9), None)),
+            Some(0)
+        );
+        let mut early = request(ATOMS.UTF8_STRING, 9);
+        early.time = 30;
+        assert_eq!(
+            notify_property(&owner.handle_request(&early, None)),
+            Some(0)
+        );
+
+        // Obsolete clients get the data in the property named like the target
+        let actions = owner.handle_request(&request(31, 0), None);
+        assert_eq!(notify_property(&actions), Some(31));
+
+        let pairs = [(ATOMS.UTF8_STRING, 10), (999, 11)];
+        let actions = owner.handle_request(&request(ATOMS.MULTIPLE, 9), Some(&pairs));
+        assert_eq!(actions.len(), 3);
+        let result = SelectionData::values32(ATOMS.ATOM_PAIR, &[106, 10, 999, 0]);
+        assert!(
+            matches!(&actions[1], Action::ChangeProperty { property: 9, data, .. } if *data == result)
+        );
+        assert_eq!(notify_property(&actions), Some(9));
+    }
+
+    #[test]
+    fn incr_transfers() {
+        let text = "0123456789".repeat(3);
+        let mut owner = SelectionOwner::new(1, ATOMS, 12);
+        owner.set(ATOMS.CLIPBOARD, 40, SelectionContents::text(&ATOMS, &text));
+        let actions = owner.handle_request(&request(ATOMS.UTF8_STRING, 9), None);
+        assert_eq!(actions.len(), 3);
+        let incr = SelectionData::values32(ATOMS.INCR, &[30]);
+        assert!(matches!(
+            actions[0],
+            Action::SelectPropertyChange {
+                window: 2,
+                select: true
+            }
+        ));
+        assert!(matches!(&actions[1], Action::ChangeProperty { data, .. } if *data == incr));
+        assert!(matches!(actions[2], Action::SendSelectionNotify(_)));
+        assert!(owner.has_transfers());
+
+        // The requestor side of the transfer
+        let mut transfer = IncomingTransfer::new(2, ATOMS.CLIPBOARD, ATOMS.UTF8_STRING, 9);
+        let notify = Event::SelectionNotify(SelectionNotifyEvent {
+            requestor: 2,
+            selection: ATOMS.CLIPBOARD,
+            target: ATOMS.UTF8_STRING,
+            property: 9,
+            ..Default::default()
+        });
+        assert_eq!(transfer.handle_event(&notify), TransferStep::ReadProperty);
+        assert_eq!(
+            transfer.handle_property(reply(ATOMS.INCR, &[0; 4]), &ATOMS),
+            None
+        );
+
+        let deleted = PropertyNotifyEvent {
+            window: 2,
+            atom: 9,
+            state: Property::DELETE,
+            ..Default::default()
+        };
+        let mut received = None;
+        for _ in 0..4 {
+            assert!(received.is_none());
+            let actions = owner.handle_property_notify(&deleted);
+            let chunk = match &actions[0] {
+                Action::ChangeProperty { data, .. } => data.data.clone(),
+                action => panic!("Unexpected action {:?}", action),
+            };
+            let new_value = Event::PropertyNotify(PropertyNotifyEvent {
+                state: Property::NEW_VALUE,
+                ..deleted
+            });
+            assert_eq!(
+                transfer.handle_event(&new_value),
+                TransferStep::ReadProperty
+            );
+            received = transfer.handle_property(reply(ATOMS.UTF8_STRING, &chunk), &ATOMS);
+        }
+        assert!(!owner.has_transfers());
+        assert_eq!(received.unwrap().data, text.as_bytes());
+    }
+
+    #[test]
+    fn multiple_with_incr() {
+        let text = "0123456789".repeat(3);
+        let contents = SelectionContents::text(&ATOMS, &text)
+            .with(31u32, SelectionData::bytes(31u32, b"short".to_vec()));
+        let mut owner = SelectionOwner::new(1, ATOMS, 12);
+        owner.set(ATOMS.CLIPBOARD, 40, contents);
+        let pairs = [(ATOMS.UTF8_STRING, 10), (31, 11), (ATOMS.UTF8_STRING, 12)];
+        let actions = owner.handle_request(&request(ATOMS.MULTIPLE, 9), Some(&pairs));
+
+        // PropertyChange events are selected once and before any INCR property is written
+        assert!(matches!(
+            actions[0],
+            Action::SelectPropertyChange {
+                window: 2,
+                select: true
+            }
+        ));
+        let selects = actions
+            .iter()
+            .filter(|action| matches!(action, Action::SelectPropertyChange { .. }))
+            .count();
+        assert_eq!(selects, 1);
+        let incr = SelectionData::values32(ATOMS.INCR, &[30]);
+        let incr_properties = actions
+            .iter()
+            .filter_map(|action| match action {
+                Action::ChangeProperty { property, data, .. } if *data == incr => Some(*property),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(incr_properties, [10, 12]);
+
+        // The notification comes last
+        assert_eq!(actions.len(), 6);
+        assert!(matches!(
+            actions.last(),
+            Some(Action::SendSelectionNotify(SelectionNotifyEvent {
+                property: 9,
+                ..
+            }))
+        ));
+        let result = SelectionData::values32(ATOMS.ATOM_PAIR, &[106, 10, 31, 11, 106, 12]);
+        assert!(
+            matches!(&actions[4], Action::ChangeProperty { property: 9, data, .. } if *data == result)
+        );
+        assert!(owner.has_transfers());
+    }
+
+    #[test]
+    fn incr_transfer_to_own_window() {
+        let text = "0123456789".repeat(3);
+        let mut owner = SelectionOwner::new(1, ATOMS, 12);
+        owner.set(ATOMS.CLIPBOARD, 40, SelectionContents::text(&ATOMS, &text));
+        let data = owner.lookup(ATOMS.CLIPBOARD, ATOMS.UTF8_STRING).unwrap();
+        assert_eq!(data.data, text.as_bytes());
+
+        // The window of the owner already has PropertyChange selected
+        let own_request = SelectionRequestEvent {
+            requestor: 1,
+            ..request(ATOMS.UTF8_STRING, 9)
+        };
+        let actions = owner.handle_request(&own_request, None);
+        assert_eq!(actions.len(), 2);
+        assert!(matches!(
+            actions[0],
+            Action::ChangeProperty { window: 1, .. }
+        ));
+
+        let deleted = PropertyNotifyEvent {
+            window: 1,
+            atom: 9,
+            state: Property::DELETE,
+            ..Default::default()
+        };
+        for _ in 0..4 {
+            let actions = owner.handle_property_notify(&deleted);
+            assert_eq!(actions.len(), 1);
+            assert!(matches!(
+                actions[0],
+                Action::ChangeProperty { window: 1, .. }
+            ));
+        }
+        assert!(!owner.has_transfers());
+    }
+
+    #[test]
+    fn expire_transfers() {
+        let mut owner = SelectionOwner::new(1, ATOMS, 12);
+        let text = "0123456789".repeat(3);
+        owner.set(ATOMS.CLIPBOARD, 40, SelectionContents::text(&ATOMS, &text));
+        let _ = owner.handle_request(&request(ATOMS.UTF8_STRING, 9), None);
+        let _ = owner.handle_request(&request(ATOMS.UTF8_STRING, 10), None);
+
+        // Only transfers without progress since the last check are dropped
+        assert!(owner.expire_transfers().is_empty());
+        let deleted = PropertyNotifyEvent {
+            window: 2,
+            atom: 9,
+            state: Property::DELETE,
+            ..Default::default()
+        };
+        let _ = owner.handle_property_notify(&deleted);
+        assert!(owner.expire_transfers().is_empty());
+        assert!(owner.has_transfers());
+        let actions = owner.expire_transfers();
+        assert!(matches!(
+            actions[..],
+            [Action::SelectPropertyChange {
+                window: 2,
+                select: false
+            }]
+        ));
+        assert!(!owner.has_transfers());
+    }
+
+    #[test]
+    fn refused_transfer() {
+        let transfer = IncomingTransfer::new(2, ATOMS.CLIPBOARD, ATOMS.UTF8_STRING, 9);
+        let mut event = SelectionNotifyEvent {
+            requestor: 2,
+            selection: ATOMS.CLIPBOARD,
+            target: ATOMS.UTF8_STRING,
+            property: 0,
+            ..Default::default()
+        };
+        let step = transfer.handle_event(&Event::SelectionNotify(event));
+        assert_eq!(step, TransferStep::Refused);
+        event.target = 31;
+        let step = transfer.handle_event(&Event::SelectionNotify(event));
+        assert_eq!(step, TransferStep::Ignore);
+    }
+}
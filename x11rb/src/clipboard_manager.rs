@@ -0,0 +1,294 @@
+//! A minimal clipboard manager.
+//!
+//! The contents of the `CLIPBOARD` selection are lost when the application that owns it exits.
+//! A clipboard manager prevents this as described in the freedesktop.org clipboard manager
+//! specification: It owns the `CLIPBOARD_MANAGER` selection, and applications convert that
+//! selection to `SAVE_TARGETS` before they exit, see [`Clipboard::save`]. The clipboard manager
+//! then reads the data from the application. When the window of the application is destroyed and
+//! nobody else owns the `CLIPBOARD` selection, the clipboard manager takes it over.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::os::unix::io::AsFd;
+//! use x11rb::clipboard_manager::{ClipboardManager, ManagerEvent};
+//! use x11rb::connection::Connection;
+//!
+//! let (conn, screen_num) = x11rb::connect(None)?;
+//! let time = conn.fetch_server_time(screen_num)?;
+//! let mut manager = ClipboardManager::new(&conn, conn.stream().as_fd(), screen_num, time)?
+//!     .expect("Another clipboard manager is running");
+//! conn.flush()?;
+//! loop {
+//!     let event = conn.wait_for_event()?;
+//!     if let Some(ManagerEvent::Replaced) = manager.handle_event(&event)? {
+//!         break;
+//!     }
+//!     conn.flush()?;
+//! }
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use rustix::fd::BorrowedFd;
+
+use crate::clipboard::{Clipboard, ClipboardAtoms, SelectionContents};
+use crate::connection::Connection;
+use crate::errors::{ReplyError, ReplyOrIdError};
+use crate::protocol::xproto::{
+    self, Atom, AtomEnum, ChangeWindowAttributesAux, ClientMessageEvent, EventMask,
+    SelectionNotifyEvent, SelectionRequestEvent, Timestamp, Window, SELECTION_NOTIFY_EVENT,
+};
+use crate::protocol::Event;
+use crate::NONE;
+
+/// Something that happened to a [`ClipboardManager`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ManagerEvent {
+    /// The contents of the `CLIPBOARD` selection were saved on request of its owner.
+    Saved,
+
+    /// The owner of the `CLIPBOARD` selection was destroyed and the clipboard manager now owns
+    /// the selection with the saved contents.
+    TookOver,
+
+    /// Another clipboard manager acquired the `CLIPBOARD_MANAGER` selection.
+    ///
+    /// The clipboard manager should exit, e.g. by dropping the [`ClipboardManager`].
+    Replaced,
+}
+
+/// Get the targets of a `SAVE_TARGETS` request that can be saved.
+///
+/// The targets that every owner answers itself are skipped, as well as duplicates.
+fn storable_targets(targets: &[Atom], atoms: &ClipboardAtoms) -> Vec<Atom> {
+    let special = [
+        atoms.TARGETS,
+        atoms.MULTIPLE,
+        atoms.TIMESTAMP,
+        atoms.SAVE_TARGETS,
+        NONE,
+    ];
+    let mut result = Vec::with_capacity(targets.len());
+    for target in targets {
+        if !special.contains(target) && !result.contains(target) {
+            result.push(*target);
+        }
+    }
+    result
+}
+
+/// Owns the `CLIPBOARD_MANAGER` selection and saves the `CLIPBOARD` selection.
+///
+/// The selections are served by a [`Clipboard`]. Events that arrive while the clipboard manager
+/// reads data are set aside, see [`Clipboard::take_deferred_events`].
+#[derive(Debug)]
+pub struct ClipboardManager<'c, C: Connection> {
+    conn: &'c C,
+    clipboard: Clipboard<'c, C>,
+    saved: Option<SelectionContents>,
+    watched: Window,
+}
+
+impl<'c, C: Connection> ClipboardManager<'c, C> {
+    /// Become the clipboard manager of the display.
+    ///
+    /// `fd` is the file descriptor of the connection, see [`Clipboard::new`]. `screen` determines
+    /// the root window for the `MANAGER` announcement. Returns `None` if the `CLIPBOARD_MANAGER`
+    /// selection could not be acquired.
+    pub fn new(
+        conn: &'c C,
+        fd: BorrowedFd<'c>,
+        screen: usize,
+        time: Timestamp,
+    ) -> Result<Option<Self>, ReplyOrIdError> {
+        // This checks that the screen exists
+        let clipboard = Clipboard::new(conn, fd, screen)?;
+        let root = conn.setup().roots[screen].root;
+        let window = clipboard.window();
+        let selection = clipboard.atoms().CLIPBOARD_MANAGER;
+        let _ = xproto::set_selection_owner(conn, window, selection, time)?;
+        let owner = xproto::get_selection_owner(conn, selection)?.reply()?.owner;
+        if owner != window {
+            return Ok(None);
+        }
+        let manager = clipboard.atoms().MANAGER;
+        let event = ClientMessageEvent::new(32, root, manager, [time, selection, window, 0, 0]);
+        let _ = xproto::send_event(conn, false, root, EventMask::STRUCTURE_NOTIFY, event)?;
+        Ok(Some(Self {
+            conn,
+            clipboard,
+            saved: None,
+            watched: NONE,
+        }))
+    }
+
+    /// Get the clipboard that owns the selections.
+    pub fn clipboard(&mut self) -> &mut Clipboard<'c, C> {
+        &mut self.clipboard
+    }
+
+    /// Get the saved contents of the `CLIPBOARD` selection.
+    pub fn saved(&self) -> Option<&SelectionContents> {
+        self.saved.as_ref()
+    }
+
+    /// Save the contents of the `CLIPBOARD` selection.
+    ///
+    /// If `targets` is empty, all targets that the owner offers are saved. The owner's window is
+    /// watched afterwards, so that the selection can be taken over when it is destroyed. Returns
+    /// `false` if nothing could be saved.
+    pub fn save(&mut self, targets: &[Atom], time: Timestamp) -> Result<bool, ReplyError> {
+        let atoms = *self.clipboard.atoms();
+        let owner = xproto::get_selection_owner(self.conn, atoms.CLIPBOARD)?
+            .reply()?
+            .owner;
+        if owner == NONE || owner == self.clipboard.window() {
+            return Ok(false);
+        }
+        let targets = if targets.is_empty() {
+            match self.clipboard.get(atoms.CLIPBOARD, atoms.TARGETS, time)? {
+                Some(data) => data.parse_values32().unwrap_or_default(),
+                None => return Ok(false),
+            }
+        } else {
+            targets.to_vec()
+        };
+        let mut contents = SelectionContents::new();
+        for target in storable_targets(&targets, &atoms) {
+            if let Some(data) = self.clipboard.get(atoms.CLIPBOARD, target, time)? {
+                contents = contents.with(target, data);
+            }
+        }
+        if contents.targets().next().is_none() {
+            return Ok(false);
+        }
+        if self.watched != NONE && self.watched != owner {
+            let aux = ChangeWindowAttributesAux::new().event_mask(EventMask::NO_EVENT);
+            let _ = xproto::change_window_attributes(self.conn, self.watched, &aux)?;
+        }
+        let aux = ChangeWindowAttributesAux::new().event_mask(EventMask::STRUCTURE_NOTIFY);
+        let _ = xproto::change_window_attributes(self.conn, owner, &aux)?;
+        self.watched = owner;
+        self.saved = Some(contents);
+        Ok(true)
+    }
+
+    /// Handle a `SAVE_TARGETS` request.
+    fn handle_request(&mut self, event: &SelectionRequestEvent) -> Result<bool, ReplyError> {
+        let atoms = *self.clipboard.atoms();
+        let property = if event.property == NONE {
+            event.target
+        } else {
+            event.property
+        };
+        let mut saved = false;
+        if event.target == atoms.SAVE_TARGETS {
+            let targets = if event.property == NONE {
+                Vec::new()
+            } else {
+                xproto::get_property(
+                    self.conn,
+                    false,
+                    event.requestor,
+                    event.property,
+                    AtomEnum::ATOM,
+                    0,
+                    u32::MAX,
+                )?
+                .reply()?
+                .value32()
+                .map(|targets| targets.collect())
+                .unwrap_or_default()
+            };
+            saved = self.save(&targets, event.time)?;
+        }
+        let notify = SelectionNotifyEvent {
+            response_type: SELECTION_NOTIFY_EVENT,
+            sequence: 0,
+            time: event.time,
+            requestor: event.requestor,
+            selection: event.selection,
+            target: event.target,
+            property: if saved { property } else { NONE },
+        };
+        let _ = xproto::send_event(
+            self.conn,
+            false,
+            event.requestor,
+            EventMask::NO_EVENT,
+            notify,
+        )?;
+        Ok(saved)
+    }
+
+    /// Handle an event.
+    ///
+    /// Events of the selection protocol are passed on to the [`Clipboard`].
+    pub fn handle_event(&mut self, event: &Event) -> Result<Option<ManagerEvent>, ReplyError> {
+        let window = self.clipboard.window();
+        let selection = self.clipboard.atoms().CLIPBOARD_MANAGER;
+        match event {
+            Event::SelectionRequest(event)
+                if event.owner == window && event.selection == selection =>
+            {
+                let saved = self.handle_request(event)?;
+                Ok(Some(ManagerEvent::Saved).filter(|_| saved))
+            }
+            Event::SelectionClear(event)
+                if event.owner == window && event.selection == selection =>
+            {
+                Ok(Some(ManagerEvent::Replaced))
+            }
+            Event::DestroyNotify(event) if event.window == self.watched => {
+                self.watched = NONE;
+                let clipboard = self.clipboard.atoms().CLIPBOARD;
+                let owner = xproto::get_selection_owner(self.conn, clipboard)?
+                    .reply()?
+                    .owner;
+                let contents = match self.saved.take() {
+                    // Somebody else copied something in the meantime
+                    Some(_) if owner != NONE => return Ok(None),
+                    Some(contents) => contents,
+                    None => return Ok(None),
+                };
+                // The ownership has to be taken with a real server time, which is also the
+                // answer to the `TIMESTAMP` target and is checked against requests
+                let time = match self.clipboard.fetch_server_time()? {
+                    Some(time) => time,
+                    None => return Ok(None),
+                };
+                let taken = self.clipboard.set(clipboard, time, contents)?;
+                Ok(Some(ManagerEvent::TookOver).filter(|_| taken))
+            }
+            _ => {
+                let _ = self.clipboard.handle_event(event)?;
+                Ok(None)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::storable_targets;
+    use crate::clipboard::ClipboardAtoms;
+
+    #[test]
+    fn skip_special_targets() {
+        let atoms = ClipboardAtoms {
+            CLIPBOARD: 100,
+            TARGETS: 101,
+            MULTIPLE: 102,
+            TIMESTAMP: 103,
+            INCR: 104,
+            ATOM_PAIR: 105,
+            UTF8_STRING: 106,
+            CLIPBOARD_MANAGER: 107,
+            SAVE_TARGETS: 108,
+            MANAGER: 109,
+            X11RB_SELECTION: 110,
+        };
+        let targets = [101, 106, 31, 103, 106, 0, 200, 108, 102];
+        assert_eq!(storable_targets(&targets, &atoms), [106, 31, 200]);
+    }
+}
@@ -0,0 +1,302 @@
+//! Allocating colors in colormaps.
+//!
+//! On `TrueColor` visuals, the pixel value of a color follows from the color masks of the visual,
+//! see [`true_color_pixel`]. Other visuals, e.g. the `PseudoColor` visuals of 8 bit servers, map
+//! pixel values to colors with a colormap. A [`ColorCache`] allocates colors in a colormap and
+//! remembers them, so that users of the same color share one colormap cell. When a colormap is
+//! full, the closest color that is already in it is used instead.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use x11rb::colormap::ColorCache;
+//!
+//! let (conn, screen_num) = x11rb::connect(None)?;
+//! let mut colors = ColorCache::for_screen(&conn, screen_num)?;
+//! let orange = colors.alloc((0xffff, 0x8000, 0))?;
+//! let sky_blue = colors.alloc_named("sky blue")?.expect("Unknown color name");
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::collections::HashMap;
+
+use crate::connection::Connection;
+use crate::errors::{ConnectionError, ParseError, ReplyError};
+use crate::protocol::xproto::{self, Colormap, Rgb, Setup, VisualClass, Visualid, Visualtype};
+use crate::protocol::ErrorKind;
+
+/// Compute the pixel value of a color on a `TrueColor` visual.
+///
+/// Each component is truncated to the width of its color mask. Returns `None` if the visual has a
+/// different class.
+///
+/// ```
+/// # use x11rb::colormap::true_color_pixel;
+/// # use x11rb::protocol::xproto::{VisualClass, Visualtype};
+/// let visual = Visualtype {
+///     class: VisualClass::TRUE_COLOR,
+///     red_mask: 0xf800,
+///     green_mask: 0x07e0,
+///     blue_mask: 0x001f,
+///     ..Default::default()
+/// };
+/// assert_eq!(true_color_pixel(&visual, (0xffff, 0x8000, 0)), Some(0xfc00));
+/// ```
+pub fn true_color_pixel(visual: &Visualtype, (red, green, blue): (u16, u16, u16)) -> Option<u32> {
+    if visual.class != VisualClass::TRUE_COLOR {
+        return None;
+    }
+    Some(
+        scale_to_mask(red, visual.red_mask)
+            | scale_to_mask(green, visual.green_mask)
+            | scale_to_mask(blue, visual.blue_mask),
+    )
+}
+
+/// Find the description of a visual in the setup of a connection.
+pub fn find_visual(setup: &Setup, visual: Visualid) -> Option<&Visualtype> {
+    setup
+        .roots
+        .iter()
+        .flat_map(|screen| &screen.allowed_depths)
+        .flat_map(|depth| &depth.visuals)
+        .find(|info| info.visual_id == visual)
+}
+
+/// A color that was requested from a [`ColorCache`].
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    pixel: u32,
+    /// The number of users of an allocated cell, or zero if the cell is not owned by the cache.
+    references: usize,
+}
+
+/// Allocates colors in a colormap and shares them between their users.
+///
+/// Each color is allocated only once. Further requests for the same color return the same pixel
+/// until the color was released as often as it was allocated. All colors that are still
+/// allocated are freed when this is dropped.
+#[derive(Debug)]
+pub struct ColorCache<'c, C: Connection> {
+    conn: &'c C,
+    colormap: Colormap,
+    visual: Visualtype,
+    entries: HashMap<(u16, u16, u16), Entry>,
+}
+
+impl<'c, C: Connection> ColorCache<'c, C> {
+    /// Create a cache for a colormap of the given visual.
+    ///
+    /// Fails with [`ParseError::InvalidValue`] if the visual does not exist.
+    pub fn new(conn: &'c C, colormap: Colormap, visual: Visualid) -> Result<Self, ParseError> {
+        let visual = *find_visual(conn.setup(), visual).ok_or(ParseError::InvalidValue)?;
+        Ok(Self {
+            conn,
+            colormap,
+            visual,
+            entries: HashMap::new(),
+        })
+    }
+
+    /// Create a cache for the default colormap of a screen.
+    ///
+    /// Fails with [`ParseError::InvalidValue`] if the screen does not exist.
+    pub fn for_screen(conn: &'c C, screen: usize) -> Result<Self, ParseError> {
+        let screen = conn
+            .setup()
+            .roots
+            .get(screen)
+            .ok_or(ParseError::InvalidValue)?;
+        Self::new(conn, screen.default_colormap, screen.root_visual)
+    }
+
+    /// Get the colormap of this cache.
+    pub fn colormap(&self) -> Colormap {
+        self.colormap
+    }
+
+    /// Get the visual of the colormap.
+    pub fn visual(&self) -> &Visualtype {
+        &self.visual
+    }
+
+    /// Get the pixel value for a color, allocating it if necessary.
+    ///
+    /// On `TrueColor` visuals, the pixel is computed without contacting the X11 server. Read-only
+    /// colormaps return the closest color that the server offers. If a `PseudoColor` or
+    /// `GrayScale` colormap is full, the closest color in the colormap is used without
+    /// allocating it, so that it can change if another client frees it.
+    pub fn alloc(&mut self, color: (u16, u16, u16)) -> Result<u32, ReplyError> {
+        if let Some(pixel) = true_color_pixel(&self.visual, color) {
+            return Ok(pixel);
+        }
+        if let Some(entry) = self.entries.get_mut(&color) {
+            if entry.references > 0 {
+                entry.references += 1;
+            }
+            return Ok(entry.pixel);
+        }
+        let (red, green, blue) = color;
+        let entry = match xproto::alloc_color(self.conn, self.colormap, red, green, blue)?.reply() {
+            Ok(reply) => Entry {
+                pixel: reply.pixel,
+                references: usize::from(has_writable_cells(self.visual.class)),
+            },
+            Err(ReplyError::X11Error(err))
+                if err.error_kind == ErrorKind::Alloc && has_palette(self.visual.class) =>
+            {
+                Entry {
+                    pixel: self.closest_pixel(color)?,
+                    references: 0,
+                }
+            }
+            Err(err) => return Err(err),
+        };
+        let _ = self.entries.insert(color, entry);
+        Ok(entry.pixel)
+    }
+
+    /// Get the pixel value for a color by its name, e.g. `"sky blue"`.
+    ///
+    /// The name is looked up in the color database of the X11 server and the color is allocated
+    /// as with [`ColorCache::alloc`]. Returns `None` if the name is unknown.
+    pub fn alloc_named(&mut self, name: &str) -> Result<Option<u32>, ReplyError> {
+        let reply = match xproto::lookup_color(self.conn, self.colormap, name.as_bytes())?.reply() {
+            Ok(reply) => reply,
+            Err(ReplyError::X11Error(err)) if err.error_kind == ErrorKind::Name => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        let color = (reply.exact_red, reply.exact_green, reply.exact_blue);
+        self.alloc(color).map(Some)
+    }
+
+    /// Release a pixel that was returned by [`ColorCache::alloc`] or [`ColorCache::alloc_named`].
+    ///
+    /// The colormap cell is freed when the pixel was released as often as it was allocated.
+    pub fn release(&mut self, pixel: u32) -> Result<(), ConnectionError> {
+        let color = match self
+            .entries
+            .iter_mut()
+            .find(|(_, entry)| entry.pixel == pixel && entry.references > 0)
+        {
+            Some((_, entry)) if entry.references > 1 => {
+                entry.references -= 1;
+                return Ok(());
+            }
+            Some((color, _)) => *color,
+            None => return Ok(()),
+        };
+        let _ = self.entries.remove(&color);
+        let _ = xproto::free_colors(self.conn, self.colormap, 0, &[pixel])?;
+        Ok(())
+    }
+
+    /// Find the color in the colormap that is closest to `color`.
+    fn closest_pixel(&self, color: (u16, u16, u16)) -> Result<u32, ReplyError> {
+        let pixels = (0..u32::from(self.visual.colormap_entries)).collect::<Vec<_>>();
+        let colors = xproto::query_colors(self.conn, self.colormap, &pixels)?
+            .reply()?
+            .colors;
+        let index = closest(&colors, color).ok_or(ParseError::InvalidValue)?;
+        Ok(pixels[index])
+    }
+}
+
+impl<C: Connection> Drop for ColorCache<'_, C> {
+    fn drop(&mut self) {
+        let pixels = self
+            .entries
+            .values()
+            .filter(|entry| entry.references > 0)
+            .map(|entry| entry.pixel)
+            .collect::<Vec<_>>();
+        if !pixels.is_empty() {
+            let _ = xproto::free_colors(self.conn, self.colormap, 0, &pixels);
+        }
+    }
+}
+
+/// Check whether colormaps of a visual class have cells that clients allocate.
+fn has_writable_cells(class: VisualClass) -> bool {
+    [
+        VisualClass::PSEUDO_COLOR,
+        VisualClass::GRAY_SCALE,
+        VisualClass::DIRECT_COLOR,
+    ]
+    .contains(&class)
+}
+
+/// Check whether the pixel values of a visual class are indices into a single list of colors.
+fn has_palette(class: VisualClass) -> bool {
+    class == VisualClass::PSEUDO_COLOR || class == VisualClass::GRAY_SCALE
+}
+
+/// Truncate a color component and place it into a color mask.
+fn scale_to_mask(intensity: u16, mask: u32) -> u32 {
+    if mask == 0 {
+        return 0;
+    }
+    let width = mask.count_ones().min(16);
+    ((u32::from(intensity) >> (16 - width)) << mask.trailing_zeros()) & mask
+}
+
+/// Get the index of the color that is closest to `color`.
+fn closest(colors: &[Rgb], (red, green, blue): (u16, u16, u16)) -> Option<usize> {
+    let distance = |a: u16, b: u16| {
+        let diff = u64::from(a.abs_diff(b));
+        diff * diff
+    };
+    colors
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, rgb)| {
+            distance(rgb.red, red) + distance(rgb.green, green) + distance(rgb.blue, blue)
+        })
+        .map(|(index, _)| index)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{closest, true_color_pixel};
+    use crate::protocol::xproto::{Rgb, VisualClass, Visualtype};
+
+    #[test]
+    fn true_color_pixels() {
+        let mut visual = Visualtype {
+            class: VisualClass::TRUE_COLOR,
+            red_mask: 0xff_0000,
+            green_mask: 0x00_ff00,
+            blue_mask: 0x00_00ff,
+            ..Default::default()
+        };
+        assert_eq!(
+            true_color_pixel(&visual, (0xabcd, 0x1234, 0xff00)),
+            Some(0xab_12ff)
+        );
+        // Three bits for red and green and two bits for blue
+        visual.red_mask = 0xe0;
+        visual.green_mask = 0x1c;
+        visual.blue_mask = 0x03;
+        assert_eq!(
+            true_color_pixel(&visual, (0xffff, 0x4000, 0xc000)),
+            Some(0xeb)
+        );
+        visual.class = VisualClass::PSEUDO_COLOR;
+        assert_eq!(true_color_pixel(&visual, (0, 0, 0)), None);
+    }
+
+    #[test]
+    fn closest_color() {
+        let rgb = |red, green, blue| Rgb { red, green, blue };
+        let colors = [
+            rgb(0, 0, 0),
+            rgb(0xffff, 0, 0),
+            rgb(0xffff, 0xffff, 0xffff),
+            rgb(0x8000, 0x8000, 0x8000),
+        ];
+        assert_eq!(closest(&colors, (0xe000, 0x1000, 0x2000)), Some(1));
+        assert_eq!(closest(&colors, (0x7000, 0x9000, 0x8000)), Some(3));
+        assert_eq!(closest(&colors, (0xf000, 0xf000, 0xe000)), Some(2));
+        assert_eq!(closest(&[], (0, 0, 0)), None);
+    }
+}
@@ -17,8 +17,8 @@ use crate::utils::RawFdContainer;
 use crate::x11_utils::{ExtensionInformation, TryParse, TryParseFd, X11Error};
 
 macro_rules! impl_deref_request_connection_inner {
-    () => {
-        type Buf = C::Buf;
+    ($target:ty) => {
+        type Buf = <$target as RequestConnection>::Buf;
 
         fn send_request_with_reply<R>(
             &self,
@@ -103,6 +103,13 @@ macro_rules! impl_deref_request_connection_inner {
             (**self).prefetch_extension_information(extension_name)
         }
 
+        fn prefetch_all_extensions(
+            &self,
+            extension_names: &[&'static str],
+        ) -> Result<(), ConnectionError> {
+            (**self).prefetch_all_extensions(extension_names)
+        }
+
         fn extension_information(
             &self,
             extension_name: &'static str,
@@ -218,6 +225,10 @@ macro_rules! impl_deref_connection_inner {
             (**self).flush()
         }
 
+        fn flush_all(&self) -> Result<(), ConnectionError> {
+            (**self).flush_all()
+        }
+
         fn setup(&self) -> &Setup {
             (**self).setup()
         }
@@ -231,7 +242,7 @@ macro_rules! impl_deref_connection_inner {
 macro_rules! impl_deref_connection {
     ($type:ty) => {
         impl<C: RequestConnection + ?Sized> RequestConnection for $type {
-            impl_deref_request_connection_inner!();
+            impl_deref_request_connection_inner!(C);
         }
         impl<C: Connection + ?Sized> Connection for $type {
             impl_deref_connection_inner!();
@@ -246,8 +257,17 @@ impl_deref_connection!(std::sync::Arc<C>);
 impl_deref_connection!(std::rc::Rc<C>);
 
 impl<C: RequestConnection + ToOwned + ?Sized> RequestConnection for std::borrow::Cow<'_, C> {
-    impl_deref_request_connection_inner!();
+    impl_deref_request_connection_inner!(C);
 }
 impl<C: Connection + ToOwned + ?Sized> Connection for std::borrow::Cow<'_, C> {
     impl_deref_connection_inner!();
 }
+
+#[cfg(feature = "xlib")]
+impl RequestConnection for crate::xcb_ffi::XlibXcbConnection {
+    impl_deref_request_connection_inner!(crate::xcb_ffi::XCBConnection);
+}
+#[cfg(feature = "xlib")]
+impl Connection for crate::xcb_ffi::XlibXcbConnection {
+    impl_deref_connection_inner!();
+}
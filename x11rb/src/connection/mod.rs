@@ -218,6 +218,23 @@ pub trait RequestConnection {
         extension_name: &'static str,
     ) -> Result<(), ConnectionError>;
 
+    /// Prefetches information about several extensions.
+    ///
+    /// This sends a `QueryExtension` request for every extension whose information is not cached
+    /// yet, but it does not wait for any of the replies. The requests are sent together, so
+    /// querying many extensions at startup only costs a single round trip instead of one round
+    /// trip per extension.
+    ///
+    /// The default implementation calls `prefetch_extension_information()` for each extension.
+    fn prefetch_all_extensions(
+        &self,
+        extension_names: &[&'static str],
+    ) -> Result<(), ConnectionError> {
+        extension_names
+            .iter()
+            .try_for_each(|name| self.prefetch_extension_information(name))
+    }
+
     /// Get information about an extension.
     ///
     /// To send a request for some extension, information about the extension (major opcode,
@@ -411,6 +428,19 @@ pub trait Connection: RequestConnection {
     /// wait for was not yet sent, it will be sent by `wait_for_reply()`.
     fn flush(&self) -> Result<(), ConnectionError>;
 
+    /// Send all pending requests to the server, even if the connection would hold them back.
+    ///
+    /// Some connections do not send everything in [`Connection::flush`], for example a
+    /// [`RustConnection`](crate::rust_connection::RustConnection) with deferred flushing. Code
+    /// that waits for the connection's file descriptor itself, like an event loop, has to call
+    /// this method before waiting. Otherwise, the X11 server might never see the requests that
+    /// would cause the awaited events.
+    ///
+    /// The default implementation calls [`Connection::flush`].
+    fn flush_all(&self) -> Result<(), ConnectionError> {
+        self.flush()
+    }
+
     /// Get the setup information sent by the X11 server.
     ///
     /// The setup information contains X11 server, for example the window id of the root window.
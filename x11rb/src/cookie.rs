@@ -43,15 +43,18 @@
 //! | ------ | ---------------------------------- | ------------------------- |
 //! | Get    | `Cookie::reply`                    | `Cookie::reply_unchecked` |
 //! | Ignore | `Cookie::discard_reply_and_errors` | Just drop the cookie      |
+//!
+//! For some replies that can contain a lot of data, `Cookie::reply_ref` returns a [`ReplyView`]
+//! which allows to access the reply without copying its data.
 
 use std::marker::PhantomData;
 
 use crate::connection::{BufWithFds, RequestConnection, RequestKind};
-use crate::errors::{ConnectionError, ReplyError};
+use crate::errors::{ConnectionError, ParseError, ReplyError};
 #[cfg(feature = "record")]
 use crate::protocol::record::EnableContextReply;
 use crate::protocol::xproto::ListFontsWithInfoReply;
-use crate::x11_utils::{TryParse, TryParseFd};
+use crate::x11_utils::{BorrowedReply, TryParse, TryParseFd, TryParseRef};
 
 use x11rb_protocol::{DiscardMode, SequenceNumber};
 
@@ -303,6 +306,58 @@ where
     }
 }
 
+impl<C, R> Cookie<'_, C, R>
+where
+    R: TryParse + for<'b> BorrowedReply<'b>,
+    C: RequestConnection + ?Sized,
+{
+    /// Get the reply that the server sent without copying its data.
+    ///
+    /// The returned [`ReplyView`] keeps the buffer in which the reply was received. Its fields
+    /// borrow from this buffer.
+    pub fn reply_ref(self) -> Result<ReplyView<C::Buf, R>, ReplyError> {
+        Ok(ReplyView::new(self.raw_reply()?)?)
+    }
+}
+
+/// A reply together with the buffer in which it was received.
+///
+/// This allows to access the reply via its borrowed variant, e.g.
+/// [`crate::reply_ref::GetImageReplyRef`] for a `GetImage` request, without copying its data.
+/// See [`Cookie::reply_ref`].
+#[derive(Debug)]
+pub struct ReplyView<B, R> {
+    buffer: B,
+    phantom: PhantomData<R>,
+}
+
+impl<B, R> ReplyView<B, R>
+where
+    B: AsRef<[u8]>,
+    R: for<'b> BorrowedReply<'b>,
+{
+    /// Check that the given buffer contains a valid reply and wrap it.
+    pub fn new(buffer: B) -> Result<Self, ParseError> {
+        let _ = <R as BorrowedReply<'_>>::Ref::try_parse_ref(buffer.as_ref())?;
+        Ok(Self {
+            buffer,
+            phantom: PhantomData,
+        })
+    }
+
+    /// Get the reply.
+    pub fn get<'b>(&'b self) -> <R as BorrowedReply<'b>>::Ref {
+        <R as BorrowedReply<'b>>::Ref::try_parse_ref(self.buffer.as_ref())
+            .expect("The reply was already parsed successfully")
+            .0
+    }
+
+    /// Get the buffer containing the raw reply.
+    pub fn into_buffer(self) -> B {
+        self.buffer
+    }
+}
+
 /// A handle to a response containing `RawFd` from the X11 server.
 ///
 /// When sending a request to the X11 server, this library returns a `Cookie`. This `Cookie` can
@@ -1,5 +1,8 @@
 //! Utility functions for working with X11 cursors
 //!
+//! Cursors can be loaded from the current cursor theme with [`Handle::load_cursor`] or created
+//! from images in memory with [`Handle::create_cursor`] and [`Handle::create_animated_cursor`].
+//!
 //! The code in this module is only available when the `cursor` feature of the library is enabled.
 
 use crate::connection::Connection;
@@ -7,6 +10,7 @@ use crate::cookie::Cookie as X11Cookie;
 use crate::errors::{ConnectionError, ReplyOrIdError};
 use crate::protocol::render::{self, Pictformat};
 use crate::protocol::xproto::{self, Font, Window};
+use crate::render::{find_standard_format, StandardFormat};
 use crate::resource_manager::Database;
 use crate::NONE;
 
@@ -174,6 +178,111 @@ impl Handle {
     {
         load_cursor(conn, self, name)
     }
+
+    /// Create a cursor from an RGBA image.
+    ///
+    /// Returns `NONE` if the X11 server does not support cursors with RENDER.
+    pub fn create_cursor<C>(
+        &self,
+        conn: &C,
+        image: &CursorImage<'_>,
+    ) -> Result<xproto::Cursor, ReplyOrIdError>
+    where
+        C: Connection,
+    {
+        let frame = CursorFrame {
+            image: *image,
+            delay: 0,
+        };
+        self.create_animated_cursor(conn, &[frame])
+    }
+
+    /// Create an animated cursor from RGBA images.
+    ///
+    /// If the X11 server does not support animated cursors, only the first frame is used. Returns
+    /// `NONE` if the X11 server does not support cursors with RENDER.
+    pub fn create_animated_cursor<C>(
+        &self,
+        conn: &C,
+        frames: &[CursorFrame<'_>],
+    ) -> Result<xproto::Cursor, ReplyOrIdError>
+    where
+        C: Connection,
+    {
+        if frames.is_empty() {
+            return Err(crate::errors::ParseError::InvalidValue.into());
+        }
+        if self.render_support == RenderSupport::None {
+            return Ok(NONE);
+        }
+        let images = frames
+            .iter()
+            .map(|frame| frame.to_image())
+            .collect::<Result<Vec<_>, _>>()?;
+        create_render_cursors(conn, self, &images)
+    }
+}
+
+/// An image for a cursor.
+#[derive(Debug, Clone, Copy)]
+pub struct CursorImage<'a> {
+    /// The width of the image.
+    pub width: u16,
+
+    /// The height of the image.
+    pub height: u16,
+
+    /// The x coordinate of the hotspot inside of the image.
+    pub x_hot: u16,
+
+    /// The y coordinate of the hotspot inside of the image.
+    pub y_hot: u16,
+
+    /// The pixels of the image with four bytes per pixel in the order red, green, blue and alpha.
+    ///
+    /// The colors are not premultiplied with alpha.
+    pub pixels: &'a [u8],
+}
+
+/// A frame of an animated cursor.
+#[derive(Debug, Clone, Copy)]
+pub struct CursorFrame<'a> {
+    /// The image that is shown during this frame.
+    pub image: CursorImage<'a>,
+
+    /// How long the frame is shown in milliseconds.
+    pub delay: u32,
+}
+
+impl CursorFrame<'_> {
+    fn to_image(self) -> Result<parse_cursor::Image, crate::errors::ParseError> {
+        let image = &self.image;
+        let len = usize::from(image.width) * usize::from(image.height);
+        if image.pixels.len() < len * 4 || image.x_hot > image.width || image.y_hot > image.height {
+            return Err(crate::errors::ParseError::InvalidValue);
+        }
+        Ok(parse_cursor::Image {
+            width: image.width,
+            height: image.height,
+            x_hot: image.x_hot,
+            y_hot: image.y_hot,
+            delay: self.delay,
+            pixels: image.pixels[..len * 4]
+                .chunks_exact(4)
+                .map(rgba_to_premultiplied_argb)
+                .collect(),
+        })
+    }
+}
+
+/// Convert an RGBA pixel into the premultiplied ARGB pixel that RENDER expects.
+fn rgba_to_premultiplied_argb(rgba: &[u8]) -> u32 {
+    let alpha = u32::from(rgba[3]);
+    let premultiply = |c: u8| (u32::from(c) * alpha + 127) / 255;
+    (alpha << 24)
+        | (premultiply(rgba[0]) << 16)
+        | (premultiply(rgba[1]) << 8)
+        | premultiply(rgba[2])
 }
 
 fn open_cursor(theme: &Option<String>, name: &str) -> Option<find_cursor::Cursor<File>> {
@@ -295,7 +404,15 @@ fn load_cursor<C: Connection>(
     use std::io::BufReader;
     let images = parse_cursor::parse_cursor(&mut BufReader::new(cursor_file), handle.cursor_size)
         .or(Err(crate::errors::ParseError::InvalidValue))?;
-    let mut images = &images[..];
+    create_render_cursors(conn, handle, &images)
+}
+
+fn create_render_cursors<C: Connection>(
+    conn: &C,
+    handle: &Handle,
+    images: &[parse_cursor::Image],
+) -> Result<xproto::Cursor, ReplyOrIdError> {
+    let mut images = images;
 
     // No animated cursor support? Only use the first image
     if handle.render_support == RenderSupport::StaticCursor {
@@ -326,23 +443,7 @@ fn load_cursor<C: Connection>(
 }
 
 fn find_format(reply: &render::QueryPictFormatsReply) -> Pictformat {
-    reply
-        .formats
-        .iter()
-        .filter(|format| {
-            format.type_ == render::PictType::DIRECT
-                && format.depth == 32
-                && format.direct.red_shift == 16
-                && format.direct.red_mask == 0xff
-                && format.direct.green_shift == 8
-                && format.direct.green_mask == 0xff
-                && format.direct.blue_shift == 0
-                && format.direct.blue_mask == 0xff
-                && format.direct.alpha_shift == 24
-                && format.direct.alpha_mask == 0xff
-        })
-        .map(|format| format.id)
-        .next()
+    find_standard_format(reply, StandardFormat::Argb32)
         .expect("The X11 server is missing the RENDER ARGB_32 standard format!")
 }
 
@@ -361,3 +462,15 @@ fn get_cursor_size(rm_cursor_size: u32, rm_xft_dpi: u32, screen: &xproto::Screen
     }
     u32::from(screen.height_in_pixels.min(screen.width_in_pixels) / 48)
 }
+
+#[cfg(test)]
+mod test {
+    use super::rgba_to_premultiplied_argb;
+
+    #[test]
+    fn premultiply_rgba() {
+        assert_eq!(rgba_to_premultiplied_argb(&[1, 2, 3, 255]), 0xff01_0203);
+        assert_eq!(rgba_to_premultiplied_argb(&[255, 128, 0, 128]), 0x8080_4000);
+        assert_eq!(rgba_to_premultiplied_argb(&[255, 255, 255, 0]), 0);
+    }
+}
@@ -0,0 +1,208 @@
+//! Sharing buffers with the X11 server as dmabufs.
+//!
+//! DRI3 allows to create pixmaps from dmabufs that were allocated by a GPU driver, e.g. through
+//! Vulkan or GBM, and to export pixmaps as dmabufs. This avoids copying the content of the
+//! buffers. This module wraps the requests of DRI3 1.2 that support multiple planes and format
+//! modifiers. Formats and modifiers are described with the types of the
+//! [drm-fourcc](https://docs.rs/drm-fourcc) crate.
+//!
+//! DRI3 describes the format of a pixmap only by its depth and bits per pixel. The following
+//! formats are supported: `XRGB8888` (depth 24), `ARGB8888` (depth 32), `XRGB2101010`
+//! (depth 30) and `RGB565` (depth 16).
+//!
+//! The code in this module is only available when the `dmabuf` feature of the library is enabled.
+
+use std::os::unix::io::OwnedFd;
+
+use drm_fourcc::{DrmFourcc, DrmModifier};
+
+use crate::connection::Connection;
+use crate::errors::{ParseError, ReplyError, ReplyOrIdError};
+use crate::protocol::dri3;
+use crate::protocol::xproto::{Pixmap, Window};
+
+/// The DRI3 version that is needed for the functions in this module.
+const REQUIRED_VERSION: (u32, u32) = (1, 2);
+
+/// One plane of a dmabuf.
+#[derive(Debug)]
+pub struct DmabufPlane {
+    /// The file descriptor of the buffer that contains the plane.
+    pub fd: OwnedFd,
+
+    /// The offset of the plane inside of the buffer in bytes.
+    pub offset: u32,
+
+    /// The number of bytes between the starts of two rows of the plane.
+    pub stride: u32,
+}
+
+/// A buffer that can be shared between processes and devices.
+#[derive(Debug)]
+pub struct Dmabuf {
+    /// The width of the buffer in pixels.
+    pub width: u16,
+
+    /// The height of the buffer in pixels.
+    pub height: u16,
+
+    /// The format of the pixels.
+    pub format: DrmFourcc,
+
+    /// The layout of the pixels in memory, e.g. tiling or compression.
+    pub modifier: DrmModifier,
+
+    /// The planes of the buffer. DRI3 supports at most four planes.
+    pub planes: Vec<DmabufPlane>,
+}
+
+/// The format modifiers that the X11 server supports for a format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SupportedModifiers {
+    /// Modifiers that are supported for the window that was used for the query.
+    ///
+    /// Buffers with these modifiers may allow the X11 server to show them without copying, e.g.
+    /// by flipping.
+    pub window: Vec<DrmModifier>,
+
+    /// Modifiers that are supported on the screen of the window.
+    pub screen: Vec<DrmModifier>,
+}
+
+/// Get the format of DRI3 buffers with the given depth and bits per pixel.
+pub fn format_for_depth(depth: u8, bpp: u8) -> Option<DrmFourcc> {
+    match (depth, bpp) {
+        (16, 16) => Some(DrmFourcc::Rgb565),
+        (24, 32) => Some(DrmFourcc::Xrgb8888),
+        (30, 32) => Some(DrmFourcc::Xrgb2101010),
+        (32, 32) => Some(DrmFourcc::Argb8888),
+        _ => None,
+    }
+}
+
+/// Get the depth and bits per pixel of DRI3 buffers with the given format.
+pub fn depth_for_format(format: DrmFourcc) -> Option<(u8, u8)> {
+    match format {
+        DrmFourcc::Rgb565 => Some((16, 16)),
+        DrmFourcc::Xrgb8888 => Some((24, 32)),
+        DrmFourcc::Xrgb2101010 => Some((30, 32)),
+        DrmFourcc::Argb8888 => Some((32, 32)),
+        _ => None,
+    }
+}
+
+/// Check whether the X11 server supports the functions in this module.
+///
+/// This requires DRI3 1.2.
+pub fn is_supported<C: Connection>(conn: &C) -> Result<bool, ReplyError> {
+    if conn
+        .extension_information(dri3::X11_EXTENSION_NAME)?
+        .is_none()
+    {
+        return Ok(false);
+    }
+    let reply = dri3::query_version(conn, REQUIRED_VERSION.0, REQUIRED_VERSION.1)?.reply()?;
+    Ok((reply.major_version, reply.minor_version) >= REQUIRED_VERSION)
+}
+
+/// Query the modifiers that the X11 server supports for a format.
+///
+/// Use the root window of a screen to query the modifiers of the screen. Fails with
+/// [`ParseError::InvalidValue`] if DRI3 does not support the format.
+pub fn supported_modifiers<C: Connection>(
+    conn: &C,
+    window: Window,
+    format: DrmFourcc,
+) -> Result<SupportedModifiers, ReplyError> {
+    let (depth, bpp) = depth_for_format(format).ok_or(ParseError::InvalidValue)?;
+    let reply = dri3::get_supported_modifiers(conn, window, depth, bpp)?.reply()?;
+    let convert = |modifiers: Vec<u64>| modifiers.into_iter().map(DrmModifier::from).collect();
+    Ok(SupportedModifiers {
+        window: convert(reply.window_modifiers),
+        screen: convert(reply.screen_modifiers),
+    })
+}
+
+/// Create a pixmap from a dmabuf.
+///
+/// The pixmap is created on the screen of `window`. The file descriptors of the dmabuf are sent
+/// to the X11 server and closed afterwards. Fails with [`ParseError::InvalidValue`] if DRI3 does
+/// not support the format or if the dmabuf has no or more than four planes.
+pub fn pixmap_from_dmabuf<C: Connection>(
+    conn: &C,
+    window: Window,
+    dmabuf: Dmabuf,
+) -> Result<Pixmap, ReplyOrIdError> {
+    let (depth, bpp) = depth_for_format(dmabuf.format).ok_or(ParseError::InvalidValue)?;
+    if dmabuf.planes.is_empty() || dmabuf.planes.len() > 4 {
+        return Err(ParseError::InvalidValue.into());
+    }
+    let mut strides = [0; 4];
+    let mut offsets = [0; 4];
+    let mut fds = Vec::with_capacity(dmabuf.planes.len());
+    for (i, plane) in dmabuf.planes.into_iter().enumerate() {
+        strides[i] = plane.stride;
+        offsets[i] = plane.offset;
+        fds.push(plane.fd);
+    }
+    let pixmap = conn.generate_id()?;
+    let _ = dri3::pixmap_from_buffers(
+        conn,
+        pixmap,
+        window,
+        dmabuf.width,
+        dmabuf.height,
+        strides[0],
+        offsets[0],
+        strides[1],
+        offsets[1],
+        strides[2],
+        offsets[2],
+        strides[3],
+        offsets[3],
+        depth,
+        bpp,
+        dmabuf.modifier.into(),
+        fds,
+    )?;
+    Ok(pixmap)
+}
+
+/// Export a pixmap as a dmabuf.
+///
+/// Fails with [`ParseError::InvalidValue`] if the format of the pixmap has no DRM equivalent.
+pub fn dmabuf_from_pixmap<C: Connection>(conn: &C, pixmap: Pixmap) -> Result<Dmabuf, ReplyError> {
+    let reply = dri3::buffers_from_pixmap(conn, pixmap)?.reply()?;
+    let format = format_for_depth(reply.depth, reply.bpp).ok_or(ParseError::InvalidValue)?;
+    let planes = reply
+        .buffers
+        .into_iter()
+        .zip(reply.offsets)
+        .zip(reply.strides)
+        .map(|((fd, offset), stride)| DmabufPlane { fd, offset, stride })
+        .collect();
+    Ok(Dmabuf {
+        width: reply.width,
+        height: reply.height,
+        format,
+        modifier: reply.modifier.into(),
+        planes,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{depth_for_format, format_for_depth};
+    use drm_fourcc::DrmFourcc;
+
+    #[test]
+    fn formats_round_trip() {
+        for depth in [16, 24, 30, 32] {
+            let bpp = if depth == 16 { 16 } else { 32 };
+            let format = format_for_depth(depth, bpp).unwrap();
+            assert_eq!(depth_for_format(format), Some((depth, bpp)));
+        }
+        assert_eq!(format_for_depth(24, 24), None);
+        assert_eq!(depth_for_format(DrmFourcc::Nv12), None);
+    }
+}
@@ -0,0 +1,215 @@
+//! Flicker-free drawing into windows.
+//!
+//! A [`DoubleBuffer`] provides a back buffer for a window. Everything is drawn into the back
+//! buffer and [`DoubleBuffer::swap`] then shows the result in the window at once. The DOUBLE-BUFFER
+//! extension (DBE) is used if the X11 server supports it for the window. Otherwise, the back
+//! buffer is a pixmap that is copied into the window.
+//!
+//! The code in this module is only available when the `dbe` feature of the library is enabled.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use x11rb::connection::Connection;
+//! use x11rb::double_buffer::DoubleBuffer;
+//! use x11rb::protocol::xproto::{ConnectionExt as _, CreateGCAux, Rectangle};
+//!
+//! let (conn, _) = x11rb::connect(None)?;
+//! # let window = 0;
+//! let buffer = DoubleBuffer::new(&conn, window)?;
+//! let gc = conn.generate_id()?;
+//! conn.create_gc(gc, buffer.drawable(), &CreateGCAux::new().foreground(0xff0000))?;
+//! let rect = Rectangle { x: 10, y: 10, width: 100, height: 100 };
+//! conn.poly_fill_rectangle(buffer.drawable(), gc, &[rect])?;
+//! buffer.swap()?;
+//! conn.flush()?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::connection::Connection;
+use crate::errors::{ConnectionError, ReplyError, ReplyOrIdError};
+use crate::protocol::dbe::{self, BackBuffer, SwapAction, SwapInfo};
+use crate::protocol::xproto::{self, Drawable, Gcontext, Pixmap, Window};
+
+/// How the back buffer is implemented.
+#[derive(Debug, Clone, Copy)]
+enum Backend {
+    /// A back buffer of the DOUBLE-BUFFER extension.
+    Dbe(BackBuffer),
+
+    /// A pixmap that is copied into the window with the given graphics context.
+    Pixmap {
+        pixmap: Pixmap,
+        gc: Gcontext,
+        width: u16,
+        height: u16,
+    },
+}
+
+/// A back buffer for a window.
+///
+/// After a swap, the back buffer still contains what was drawn into it, so that only changed
+/// parts have to be redrawn. The back buffer is freed when this is dropped.
+#[derive(Debug)]
+pub struct DoubleBuffer<'c, C: Connection> {
+    conn: &'c C,
+    window: Window,
+    backend: Backend,
+}
+
+impl<'c, C: Connection> DoubleBuffer<'c, C> {
+    /// Create a back buffer for a window.
+    ///
+    /// This needs round trips to the X11 server to find out whether DBE can be used for the
+    /// window.
+    pub fn new(conn: &'c C, window: Window) -> Result<Self, ReplyOrIdError> {
+        let backend = match allocate_dbe(conn, window)? {
+            Some(buffer) => Backend::Dbe(buffer),
+            None => {
+                let geometry = xproto::get_geometry(conn, window)?.reply()?;
+                let (pixmap, gc) = (conn.generate_id()?, conn.generate_id()?);
+                let _ = xproto::create_pixmap(
+                    conn,
+                    geometry.depth,
+                    pixmap,
+                    window,
+                    geometry.width,
+                    geometry.height,
+                )?;
+                let _ = xproto::create_gc(conn, gc, window, &Default::default())?;
+                Backend::Pixmap {
+                    pixmap,
+                    gc,
+                    width: geometry.width,
+                    height: geometry.height,
+                }
+            }
+        };
+        Ok(Self {
+            conn,
+            window,
+            backend,
+        })
+    }
+
+    /// Get the window that this is the back buffer of.
+    pub fn window(&self) -> Window {
+        self.window
+    }
+
+    /// Get the drawable for drawing into the back buffer.
+    pub fn drawable(&self) -> Drawable {
+        match self.backend {
+            Backend::Dbe(buffer) => buffer,
+            Backend::Pixmap { pixmap, .. } => pixmap,
+        }
+    }
+
+    /// Check whether the back buffer is provided by the DOUBLE-BUFFER extension.
+    pub fn uses_dbe(&self) -> bool {
+        matches!(self.backend, Backend::Dbe(_))
+    }
+
+    /// Show the content of the back buffer in the window.
+    pub fn swap(&self) -> Result<(), ConnectionError> {
+        match self.backend {
+            Backend::Dbe(_) => {
+                let info = SwapInfo {
+                    window: self.window,
+                    swap_action: SwapAction::COPIED,
+                };
+                let _ = dbe::swap_buffers(self.conn, &[info])?;
+            }
+            Backend::Pixmap {
+                pixmap,
+                gc,
+                width,
+                height,
+            } => {
+                let _ = xproto::copy_area(
+                    self.conn,
+                    pixmap,
+                    self.window,
+                    gc,
+                    0,
+                    0,
+                    0,
+                    0,
+                    width,
+                    height,
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Adapt the back buffer to a new size of the window.
+    ///
+    /// This should be called when the window was resized, e.g. because of a `ConfigureNotify`
+    /// event. The back buffer of DBE follows the size of the window automatically. A back pixmap
+    /// is replaced with a new one and its content is lost.
+    pub fn resize(&mut self, new_width: u16, new_height: u16) -> Result<(), ReplyOrIdError> {
+        if let Backend::Pixmap {
+            pixmap,
+            width,
+            height,
+            ..
+        } = &mut self.backend
+        {
+            if (*width, *height) == (new_width, new_height) {
+                return Ok(());
+            }
+            let depth = xproto::get_geometry(self.conn, *pixmap)?.reply()?.depth;
+            let new_pixmap = self.conn.generate_id()?;
+            let _ = xproto::create_pixmap(
+                self.conn,
+                depth,
+                new_pixmap,
+                self.window,
+                new_width,
+                new_height,
+            )?;
+            let _ = xproto::free_pixmap(self.conn, *pixmap)?;
+            *pixmap = new_pixmap;
+            *width = new_width;
+            *height = new_height;
+        }
+        Ok(())
+    }
+}
+
+impl<C: Connection> Drop for DoubleBuffer<'_, C> {
+    fn drop(&mut self) {
+        match self.backend {
+            Backend::Dbe(buffer) => {
+                let _ = dbe::deallocate_back_buffer(self.conn, buffer);
+            }
+            Backend::Pixmap { pixmap, gc, .. } => {
+                let _ = xproto::free_pixmap(self.conn, pixmap);
+                let _ = xproto::free_gc(self.conn, gc);
+            }
+        }
+    }
+}
+
+/// Try to allocate a DBE back buffer for a window.
+///
+/// Returns `None` if the extension is missing or does not support the visual of the window.
+fn allocate_dbe<C: Connection>(
+    conn: &C,
+    window: Window,
+) -> Result<Option<BackBuffer>, ReplyOrIdError> {
+    if conn
+        .extension_information(dbe::X11_EXTENSION_NAME)?
+        .is_none()
+    {
+        return Ok(None);
+    }
+    let _ = dbe::query_version(conn, 1, 0)?.reply()?;
+    let buffer = conn.generate_id()?;
+    match dbe::allocate_back_buffer(conn, window, buffer, SwapAction::COPIED.into())?.check() {
+        Ok(()) => Ok(Some(buffer)),
+        Err(ReplyError::X11Error(_)) => Ok(None),
+        Err(ReplyError::ConnectionError(err)) => Err(err.into()),
+    }
+}
@@ -2,7 +2,9 @@
 
 use crate::x11_utils::X11Error;
 
-pub use x11rb_protocol::errors::{ConnectError, DisplayParsingError, IdsExhausted, ParseError};
+pub use x11rb_protocol::errors::{
+    ConnectError, DisplayParsingError, IdsExhausted, InvalidSendEvent, ParseError,
+};
 
 /// An error occurred  while dynamically loading libxcb.
 #[cfg(feature = "dl-libxcb")]
@@ -36,6 +38,128 @@ impl std::fmt::Display for LibxcbLoadError {
 #[cfg(feature = "dl-libxcb")]
 impl std::error::Error for LibxcbLoadError {}
 
+/// An error occurred while dynamically loading libX11 and libX11-xcb.
+#[cfg(feature = "xlib")]
+#[derive(Debug, Clone)]
+pub enum XlibLoadError {
+    /// Could not open the library. The `OsString` is the library
+    /// file name and the string is the reason.
+    OpenLibError(std::ffi::OsString, String),
+    /// Could not get a symbol from the library. The byte vector is the
+    /// symbol name and the string is the reason.
+    GetSymbolError(Vec<u8>, String),
+}
+
+#[cfg(feature = "xlib")]
+impl std::fmt::Display for XlibLoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            XlibLoadError::OpenLibError(lib_name, e) => {
+                write!(f, "failed to open library {:?}: {}", lib_name, e)
+            }
+            XlibLoadError::GetSymbolError(symbol, e) => write!(
+                f,
+                "failed to get symbol \"{}\": {}",
+                symbol.escape_ascii(),
+                e,
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "xlib")]
+impl std::error::Error for XlibLoadError {}
+
+/// An error that occurred while opening a display with Xlib.
+#[cfg(feature = "xlib")]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum XlibConnectError {
+    /// libX11 or libX11-xcb could not be loaded.
+    LoadError(XlibLoadError),
+
+    /// `XOpenDisplay` failed.
+    OpenDisplay,
+
+    /// The XCB connection of the display could not be used.
+    ConnectError(ConnectError),
+}
+
+#[cfg(feature = "xlib")]
+impl std::fmt::Display for XlibConnectError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            XlibConnectError::LoadError(e) => e.fmt(f),
+            XlibConnectError::OpenDisplay => write!(f, "failed to open the display with Xlib"),
+            XlibConnectError::ConnectError(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "xlib")]
+impl std::error::Error for XlibConnectError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            XlibConnectError::LoadError(e) => Some(e),
+            XlibConnectError::OpenDisplay => None,
+            XlibConnectError::ConnectError(e) => Some(e),
+        }
+    }
+}
+
+#[cfg(feature = "xlib")]
+impl From<ConnectError> for XlibConnectError {
+    fn from(err: ConnectError) -> Self {
+        XlibConnectError::ConnectError(err)
+    }
+}
+
+/// An error that occurred while loading an image into a pixmap.
+#[cfg(feature = "image-loader")]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ImageLoadError {
+    /// The image data could not be decoded.
+    DecodeError(image_crate::ImageError),
+
+    /// The image could not be uploaded to the X11 server.
+    ReplyOrIdError(ReplyOrIdError),
+}
+
+#[cfg(feature = "image-loader")]
+impl std::fmt::Display for ImageLoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImageLoadError::DecodeError(e) => write!(f, "failed to decode the image: {}", e),
+            ImageLoadError::ReplyOrIdError(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "image-loader")]
+impl std::error::Error for ImageLoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ImageLoadError::DecodeError(e) => Some(e),
+            ImageLoadError::ReplyOrIdError(e) => Some(e),
+        }
+    }
+}
+
+#[cfg(feature = "image-loader")]
+impl From<image_crate::ImageError> for ImageLoadError {
+    fn from(err: image_crate::ImageError) -> Self {
+        ImageLoadError::DecodeError(err)
+    }
+}
+
+#[cfg(feature = "image-loader")]
+impl From<ReplyOrIdError> for ImageLoadError {
+    fn from(err: ReplyOrIdError) -> Self {
+        ImageLoadError::ReplyOrIdError(err)
+    }
+}
+
 /// An error that occurred on an already established X11 connection
 #[derive(Debug)]
 #[non_exhaustive]
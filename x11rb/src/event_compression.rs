@@ -0,0 +1,224 @@
+//! Coalescing bursts of events that make earlier events obsolete.
+//!
+//! While a window is moved or resized interactively, the X11 server sends a large number of
+//! `MotionNotify`, `ConfigureNotify` and `Expose` events. Programs that redraw for every event
+//! cannot keep up. [`CompressedEventQueue`] sits between the connection and the program and
+//! merges consecutive events of these kinds:
+//!
+//! * Consecutive `MotionNotify` events for the same window are replaced by the newest one.
+//! * Consecutive `ConfigureNotify` events for the same window are replaced by the newest one.
+//! * Consecutive `Expose` events for the same window are merged into one event whose rectangle
+//!   covers all exposed rectangles. The `count` field is taken from the newest event, so the
+//!   last event of a burst still has a count of zero.
+//!
+//! Only events that were already received are merged, so compression never delays an event.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use x11rb::connection::Connection;
+//! use x11rb::event_compression::CompressedEventQueue;
+//!
+//! let (conn, _) = x11rb::connect(None)?;
+//! let mut queue = CompressedEventQueue::new();
+//! loop {
+//!     conn.flush()?;
+//!     let event = queue.wait_for_event(&conn)?;
+//!     println!("Got event {:?}", event);
+//! }
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::collections::VecDeque;
+
+use crate::connection::Connection;
+use crate::errors::ConnectionError;
+use crate::protocol::xproto::ExposeEvent;
+use crate::protocol::Event;
+
+/// A queue of events that merges consecutive events which supersede each other.
+///
+/// See the [module documentation](self) for the events that are merged.
+#[derive(Debug, Default)]
+pub struct CompressedEventQueue {
+    events: VecDeque<Event>,
+}
+
+impl CompressedEventQueue {
+    /// Create a new, empty queue.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Add an event to the end of the queue, merging it with the last queued event if possible.
+    pub fn push(&mut self, event: Event) {
+        match (self.events.back_mut(), event) {
+            (Some(Event::MotionNotify(last)), Event::MotionNotify(event))
+                if last.event == event.event =>
+            {
+                *last = event
+            }
+            (Some(Event::ConfigureNotify(last)), Event::ConfigureNotify(event))
+                if last.window == event.window =>
+            {
+                *last = event
+            }
+            (Some(Event::Expose(last)), Event::Expose(event)) if last.window == event.window => {
+                merge_expose(last, event)
+            }
+            (_, event) => self.events.push_back(event),
+        }
+    }
+
+    /// Take the first event out of the queue.
+    pub fn pop(&mut self) -> Option<Event> {
+        self.events.pop_front()
+    }
+
+    /// Get the number of events in the queue.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Check whether the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Move all events that are available from the connection into the queue without blocking.
+    pub fn fill<C: Connection + ?Sized>(&mut self, conn: &C) -> Result<(), ConnectionError> {
+        while let Some(event) = conn.poll_for_event()? {
+            self.push(event);
+        }
+        Ok(())
+    }
+
+    /// Get the next event without blocking.
+    ///
+    /// All events that are available from the connection are moved into the queue first, so that
+    /// they can be merged.
+    pub fn poll_for_event<C: Connection + ?Sized>(
+        &mut self,
+        conn: &C,
+    ) -> Result<Option<Event>, ConnectionError> {
+        self.fill(conn)?;
+        Ok(self.pop())
+    }
+
+    /// Wait for the next event.
+    ///
+    /// If the queue is empty, this blocks until the connection receives an event. All events that
+    /// are available afterwards are moved into the queue, so that they can be merged.
+    pub fn wait_for_event<C: Connection + ?Sized>(
+        &mut self,
+        conn: &C,
+    ) -> Result<Event, ConnectionError> {
+        if self.is_empty() {
+            let event = conn.wait_for_event()?;
+            self.push(event);
+        }
+        self.fill(conn)?;
+        Ok(self.pop().expect("The queue cannot be empty here"))
+    }
+}
+
+/// Extend the rectangle of `last` to also cover `event` and take the rest from `event`.
+fn merge_expose(last: &mut ExposeEvent, event: ExposeEvent) {
+    let right = (u32::from(last.x) + u32::from(last.width))
+        .max(u32::from(event.x) + u32::from(event.width));
+    let bottom = (u32::from(last.y) + u32::from(last.height))
+        .max(u32::from(event.y) + u32::from(event.height));
+    let x = last.x.min(event.x);
+    let y = last.y.min(event.y);
+    *last = ExposeEvent {
+        x,
+        y,
+        width: u16::try_from(right - u32::from(x)).unwrap_or(u16::MAX),
+        height: u16::try_from(bottom - u32::from(y)).unwrap_or(u16::MAX),
+        ..event
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::CompressedEventQueue;
+    use crate::protocol::xproto::{
+        ConfigureNotifyEvent, ExposeEvent, KeymapNotifyEvent, MotionNotifyEvent,
+    };
+    use crate::protocol::Event;
+
+    fn motion(event: u32, x: i16) -> Event {
+        Event::MotionNotify(MotionNotifyEvent {
+            event,
+            event_x: x,
+            ..Default::default()
+        })
+    }
+
+    fn expose(window: u32, x: u16, y: u16, width: u16, height: u16, count: u16) -> Event {
+        Event::Expose(ExposeEvent {
+            window,
+            x,
+            y,
+            width,
+            height,
+            count,
+            ..Default::default()
+        })
+    }
+
+    fn drain(queue: &mut CompressedEventQueue) -> Vec<Event> {
+        std::iter::from_fn(|| queue.pop()).collect()
+    }
+
+    #[test]
+    fn motion_and_configure() {
+        let mut queue = CompressedEventQueue::new();
+        queue.push(motion(1, 10));
+        queue.push(motion(1, 20));
+        queue.push(motion(2, 30));
+        queue.push(Event::KeymapNotify(KeymapNotifyEvent::default()));
+        queue.push(motion(2, 40));
+        for width in [100, 200] {
+            queue.push(Event::ConfigureNotify(ConfigureNotifyEvent {
+                window: 1,
+                width,
+                ..Default::default()
+            }));
+        }
+        assert_eq!(queue.len(), 5);
+
+        let x = |event: &Event| match event {
+            Event::MotionNotify(event) => event.event_x,
+            _ => -1,
+        };
+        let events = drain(&mut queue);
+        assert_eq!(
+            events.iter().map(x).collect::<Vec<_>>(),
+            [20, 30, -1, 40, -1]
+        );
+        match &events[4] {
+            Event::ConfigureNotify(event) => assert_eq!(event.width, 200),
+            event => panic!("Unexpected event {:?}", event),
+        }
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn expose_bursts() {
+        let mut queue = CompressedEventQueue::new();
+        queue.push(expose(1, 10, 20, 5, 5, 2));
+        queue.push(expose(1, 0, 22, 12, 10, 1));
+        queue.push(expose(1, 60000, 0, 10000, 1, 0));
+        queue.push(expose(2, 0, 0, 1, 1, 0));
+        let events = drain(&mut queue);
+        assert_eq!(events.len(), 2);
+        match &events[0] {
+            Event::Expose(event) => assert_eq!(
+                (event.x, event.y, event.width, event.height, event.count),
+                (0, 0, u16::MAX, 32, 0)
+            ),
+            event => panic!("Unexpected event {:?}", event),
+        }
+    }
+}
@@ -0,0 +1,279 @@
+//! Dispatching events to handlers for specific windows and kinds of events.
+//!
+//! Most X11 programs contain a loop that waits for events and then matches on the window and the
+//! kind of each event. [`EventDispatcher`] implements this loop: Handlers are registered for a
+//! window, a kind of event, or both, and [`EventDispatcher::run`] calls them for matching events.
+//! Events from extensions are routed in the same way as core events.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::ops::ControlFlow;
+//! use x11rb::event_dispatcher::EventDispatcher;
+//! use x11rb::protocol::EventKind;
+//!
+//! let (conn, _) = x11rb::connect(None)?;
+//! let window = 0x1234;
+//!
+//! let mut dispatcher = EventDispatcher::new();
+//! dispatcher.add_handler(window, EventKind::Expose, |event| {
+//!     println!("Window needs to be redrawn: {:?}", event);
+//!     ControlFlow::Continue(())
+//! });
+//! dispatcher.add_handler(window, EventKind::DestroyNotify, |_| ControlFlow::Break(()));
+//! dispatcher.run(&conn)?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::fmt;
+use std::ops::ControlFlow;
+
+use crate::connection::Connection;
+use crate::errors::{ConnectionError, ReplyError};
+use crate::protocol::xproto::{
+    ConnectionExt as _, GetKeyboardMappingReply, Keycode, Keysym, Mapping, Window,
+};
+use crate::protocol::{Event, EventKind};
+
+/// The identifier of a handler that was registered with an [`EventDispatcher`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HandlerId(u64);
+
+/// A boxed event handler.
+type Handler<'a> = Box<dyn FnMut(&Event) -> ControlFlow<()> + 'a>;
+
+/// A handler together with the events it is interested in.
+struct Registration<'a> {
+    id: HandlerId,
+    window: Option<Window>,
+    kind: Option<EventKind>,
+    handler: Handler<'a>,
+}
+
+impl Registration<'_> {
+    fn matches(&self, window: Option<Window>, kind: EventKind) -> bool {
+        self.window.map_or(true, |w| Some(w) == window) && self.kind.map_or(true, |k| k == kind)
+    }
+}
+
+/// Calls registered handlers for incoming events.
+///
+/// Handlers are called in the order in which they were registered. A handler can stop the
+/// dispatching by returning [`ControlFlow::Break`].
+///
+/// `MappingNotify` events for the keyboard are also handled internally: The keyboard mapping that
+/// is used by [`EventDispatcher::keysym`] is fetched again when it is needed next.
+#[derive(Default)]
+pub struct EventDispatcher<'a> {
+    handlers: Vec<Registration<'a>>,
+    next_id: u64,
+    keyboard_mapping: Option<GetKeyboardMappingReply>,
+}
+
+impl fmt::Debug for EventDispatcher<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventDispatcher")
+            .field("handlers", &self.handlers.len())
+            .field("next_id", &self.next_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> EventDispatcher<'a> {
+    /// Create a new dispatcher without any handlers.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Register a handler.
+    ///
+    /// The handler is called for events that are reported for `window` (see [`Event::window`])
+    /// and that are of the given `kind`. If `window` or `kind` is `None`, all windows or kinds
+    /// match, respectively.
+    pub fn add_handler<F>(
+        &mut self,
+        window: impl Into<Option<Window>>,
+        kind: impl Into<Option<EventKind>>,
+        handler: F,
+    ) -> HandlerId
+    where
+        F: FnMut(&Event) -> ControlFlow<()> + 'a,
+    {
+        let id = HandlerId(self.next_id);
+        self.next_id += 1;
+        self.handlers.push(Registration {
+            id,
+            window: window.into(),
+            kind: kind.into(),
+            handler: Box::new(handler),
+        });
+        id
+    }
+
+    /// Remove a handler.
+    ///
+    /// Returns `false` if there is no handler with the given ID.
+    pub fn remove_handler(&mut self, id: HandlerId) -> bool {
+        let len = self.handlers.len();
+        self.handlers.retain(|registration| registration.id != id);
+        self.handlers.len() != len
+    }
+
+    /// Remove all handlers for a window, e.g. after it was destroyed.
+    pub fn remove_window(&mut self, window: Window) {
+        self.handlers
+            .retain(|registration| registration.window != Some(window));
+    }
+
+    /// Call all handlers that match the given event.
+    ///
+    /// If a handler returns [`ControlFlow::Break`], the remaining handlers are not called and
+    /// `ControlFlow::Break` is returned.
+    pub fn dispatch(&mut self, event: &Event) -> ControlFlow<()> {
+        if let Event::MappingNotify(notify) = event {
+            if notify.request == Mapping::KEYBOARD {
+                self.keyboard_mapping = None;
+            }
+        }
+        let (window, kind) = (event.window(), event.kind());
+        for registration in self.handlers.iter_mut() {
+            if registration.matches(window, kind) {
+                (registration.handler)(event)?;
+            }
+        }
+        ControlFlow::Continue(())
+    }
+
+    /// Wait for events and dispatch them until a handler returns [`ControlFlow::Break`].
+    ///
+    /// The connection is flushed before waiting for the next event, so that requests sent by
+    /// handlers reach the X11 server.
+    pub fn run<C: Connection + ?Sized>(&mut self, conn: &C) -> Result<(), ConnectionError> {
+        loop {
+            conn.flush()?;
+            let event = conn.wait_for_event()?;
+            if self.dispatch(&event).is_break() {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Get the keysym in the given column of the keyboard mapping for a keycode.
+    ///
+    /// The keyboard mapping is fetched from the X11 server when it is needed for the first time
+    /// and after a `MappingNotify` event was dispatched. `None` is returned for keycodes outside
+    /// of the range of the server and for columns that are not part of the mapping.
+    pub fn keysym<C: Connection + ?Sized>(
+        &mut self,
+        conn: &C,
+        keycode: Keycode,
+        column: u8,
+    ) -> Result<Option<Keysym>, ReplyError> {
+        let setup = conn.setup();
+        let (min_keycode, max_keycode) = (setup.min_keycode, setup.max_keycode);
+        if !(min_keycode..=max_keycode).contains(&keycode) {
+            return Ok(None);
+        }
+        let mapping = match self.keyboard_mapping {
+            Some(ref mapping) => mapping,
+            None => {
+                let count = max_keycode - min_keycode + 1;
+                let mapping = conn.get_keyboard_mapping(min_keycode, count)?.reply()?;
+                self.keyboard_mapping.insert(mapping)
+            }
+        };
+        let per_keycode = usize::from(mapping.keysyms_per_keycode);
+        if usize::from(column) >= per_keycode {
+            return Ok(None);
+        }
+        let index = usize::from(keycode - min_keycode) * per_keycode + usize::from(column);
+        Ok(mapping.keysyms.get(index).copied())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::RefCell;
+    use std::ops::ControlFlow;
+
+    use super::EventDispatcher;
+    use crate::protocol::xproto::{ExposeEvent, MapRequestEvent, Mapping, MappingNotifyEvent};
+    use crate::protocol::{Event, EventKind};
+
+    fn expose(window: u32) -> Event {
+        Event::Expose(ExposeEvent {
+            window,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn routing() {
+        let calls = RefCell::new(Vec::new());
+        let mut dispatcher = EventDispatcher::new();
+        let id = dispatcher.add_handler(1, EventKind::Expose, |event| {
+            calls.borrow_mut().push(("expose 1", event.window()));
+            ControlFlow::Continue(())
+        });
+        let _ = dispatcher.add_handler(None, EventKind::Expose, |event| {
+            calls.borrow_mut().push(("any expose", event.window()));
+            ControlFlow::Continue(())
+        });
+        let _ = dispatcher.add_handler(2, None, |event| {
+            calls.borrow_mut().push(("window 2", event.window()));
+            ControlFlow::Continue(())
+        });
+
+        let map_request = Event::MapRequest(MapRequestEvent {
+            parent: 2,
+            window: 3,
+            ..Default::default()
+        });
+        assert!(dispatcher.dispatch(&expose(1)).is_continue());
+        assert!(dispatcher.dispatch(&expose(2)).is_continue());
+        assert!(dispatcher.dispatch(&map_request).is_continue());
+        assert!(dispatcher.remove_handler(id));
+        assert!(!dispatcher.remove_handler(id));
+        assert!(dispatcher.dispatch(&expose(1)).is_continue());
+        drop(dispatcher);
+
+        assert_eq!(
+            calls.into_inner(),
+            [
+                ("expose 1", Some(1)),
+                ("any expose", Some(1)),
+                ("any expose", Some(2)),
+                ("window 2", Some(2)),
+                ("window 2", Some(2)),
+                ("any expose", Some(1)),
+            ]
+        );
+    }
+
+    #[test]
+    fn break_stops_dispatching() {
+        let mut dispatcher = EventDispatcher::new();
+        let _ = dispatcher.add_handler(None, None, |_| ControlFlow::Break(()));
+        let _ = dispatcher.add_handler(None, None, |_| panic!("Should not be called"));
+        assert!(dispatcher.dispatch(&expose(1)).is_break());
+
+        dispatcher.remove_window(1);
+        assert!(dispatcher.dispatch(&expose(1)).is_break());
+    }
+
+    #[test]
+    fn mapping_notify_invalidates_keyboard_mapping() {
+        let mut dispatcher = EventDispatcher::new();
+        dispatcher.keyboard_mapping = Some(Default::default());
+        let notify = |request| {
+            Event::MappingNotify(MappingNotifyEvent {
+                request,
+                ..Default::default()
+            })
+        };
+        let _ = dispatcher.dispatch(&notify(Mapping::POINTER));
+        assert!(dispatcher.keyboard_mapping.is_some());
+        let _ = dispatcher.dispatch(&notify(Mapping::KEYBOARD));
+        assert!(dispatcher.keyboard_mapping.is_none());
+    }
+}
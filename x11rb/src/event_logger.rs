@@ -0,0 +1,458 @@
+//! Uniform, structured logging of events.
+//!
+//! Without the `extra-traits` feature, the `Debug` output of events contains no fields, and the
+//! output with the feature is too verbose and irregular for log processing. [`EventLogger`]
+//! converts events into an [`EventRecord`] with the name of the event, the window it refers to and
+//! the interesting fields of the core events. A record can be formatted as a single `key=value`
+//! line or as a JSON object.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use x11rb::connection::Connection;
+//! use x11rb::event_logger::EventLogger;
+//! use x11rb::protocol::EventKind;
+//!
+//! let (conn, _) = x11rb::connect(None)?;
+//! let logger = EventLogger::new().ignore(EventKind::MotionNotify);
+//! loop {
+//!     let event = conn.wait_for_event()?;
+//!     if let Some(record) = logger.record(&event) {
+//!         println!("{}", record.to_json());
+//!     }
+//! }
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::fmt::{self, Write as _};
+
+use crate::protocol::xproto::Window;
+use crate::protocol::{Event, EventKind};
+
+/// The value of a field of an [`EventRecord`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldValue {
+    /// An integer, e.g. a coordinate, a timestamp, or an XID.
+    Int(i64),
+    /// A boolean flag.
+    Bool(bool),
+    /// The name of a known enumeration value, e.g. `Inferior` for a `NotifyDetail`.
+    Name(&'static str),
+}
+
+impl FieldValue {
+    /// Get the value of an enumeration, using its name if it is known.
+    fn enumeration(name: Option<&'static str>, value: impl Into<i64>) -> Self {
+        name.map_or_else(|| Self::Int(value.into()), Self::Name)
+    }
+}
+
+impl fmt::Display for FieldValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Int(value) => write!(f, "{}", value),
+            Self::Bool(value) => write!(f, "{}", value),
+            Self::Name(value) => f.write_str(value),
+        }
+    }
+}
+
+/// A structured description of an event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventRecord {
+    /// The name of the event, see [`Event::name`].
+    pub name: &'static str,
+    /// The name of the extension that the event belongs to, see [`Event::extension`].
+    pub extension: Option<&'static str>,
+    /// The sequence number of the event, see [`Event::wire_sequence_number`].
+    pub sequence: Option<u16>,
+    /// The window that the event refers to, see [`Event::window`].
+    pub window: Option<Window>,
+    /// Whether the event was generated by a `SendEvent` request.
+    pub sent_event: bool,
+    /// Further fields of the event.
+    ///
+    /// These are only filled in for core events and errors.
+    pub fields: Vec<(&'static str, FieldValue)>,
+}
+
+impl EventRecord {
+    /// Describe an event.
+    pub fn new(event: &Event) -> Self {
+        let mut fields = Vec::new();
+        detail_fields(event, &mut fields);
+        Self {
+            name: event.name(),
+            extension: event.extension(),
+            sequence: event.wire_sequence_number(),
+            window: event.window(),
+            sent_event: event.sent_event(),
+            fields,
+        }
+    }
+
+    /// Format the record as a JSON object.
+    ///
+    /// The object contains the keys `event`, `extension`, `sequence`, `window` and `sent_event`,
+    /// followed by the entries of [`EventRecord::fields`].
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("{\"event\":");
+        push_json_string(&mut json, self.name);
+        json.push_str(",\"extension\":");
+        match self.extension {
+            Some(extension) => push_json_string(&mut json, extension),
+            None => json.push_str("null"),
+        }
+        let _ = match self.sequence {
+            Some(sequence) => write!(json, ",\"sequence\":{}", sequence),
+            None => write!(json, ",\"sequence\":null"),
+        };
+        let _ = match self.window {
+            Some(window) => write!(json, ",\"window\":{}", window),
+            None => write!(json, ",\"window\":null"),
+        };
+        let _ = write!(json, ",\"sent_event\":{}", self.sent_event);
+        for (key, value) in &self.fields {
+            json.push(',');
+            push_json_string(&mut json, key);
+            json.push(':');
+            match value {
+                FieldValue::Name(name) => push_json_string(&mut json, name),
+                value => {
+                    let _ = write!(json, "{}", value);
+                }
+            }
+        }
+        json.push('}');
+        json
+    }
+}
+
+/// Formats the record as a single line of `key=value` pairs, preceded by the name of the event.
+impl fmt::Display for EventRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)?;
+        if let Some(extension) = self.extension {
+            write!(f, " extension={}", extension)?;
+        }
+        if let Some(sequence) = self.sequence {
+            write!(f, " sequence={}", sequence)?;
+        }
+        if let Some(window) = self.window {
+            write!(f, " window={:#x}", window)?;
+        }
+        if self.sent_event {
+            f.write_str(" sent_event=true")?;
+        }
+        for (key, value) in &self.fields {
+            write!(f, " {}={}", key, value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Converts events into [`EventRecord`]s, skipping events that are not of interest.
+#[derive(Debug, Clone, Default)]
+pub struct EventLogger {
+    ignored: Vec<EventKind>,
+}
+
+impl EventLogger {
+    /// Create a new logger that records all events.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Do not record events of the given kind, e.g. the frequent `MotionNotify` events.
+    pub fn ignore(mut self, kind: EventKind) -> Self {
+        self.ignored.push(kind);
+        self
+    }
+
+    /// Describe an event, unless its kind is ignored.
+    pub fn record(&self, event: &Event) -> Option<EventRecord> {
+        if self.ignored.contains(&event.kind()) {
+            None
+        } else {
+            Some(EventRecord::new(event))
+        }
+    }
+
+    /// Log an event with a `DEBUG` level `tracing` event, unless its kind is ignored.
+    ///
+    /// The name, window and sequence number of the event become fields of the tracing event, the
+    /// complete record is its message. This does nothing unless the `tracing` feature is enabled.
+    pub fn log(&self, event: &Event) {
+        if let Some(record) = self.record(event) {
+            crate::debug!(
+                {
+                    event = record.name,
+                    window = record.window,
+                    sequence = record.sequence,
+                },
+                "{}",
+                record
+            );
+        }
+    }
+}
+
+/// Append a string to JSON output, including quotes and escapes.
+fn push_json_string(json: &mut String, value: &str) {
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            c if c.is_control() => {
+                let _ = write!(json, "\\u{:04x}", u32::from(c));
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
+
+/// Collect the interesting fields of core events and errors.
+fn detail_fields(event: &Event, fields: &mut Vec<(&'static str, FieldValue)>) {
+    use FieldValue::{Bool, Int};
+
+    let mut push = |key, value| fields.push((key, value));
+    match event {
+        Event::Error(error) => {
+            push("error_code", Int(error.error_code.into()));
+            push("bad_value", Int(error.bad_value.into()));
+            push("major_opcode", Int(error.major_opcode.into()));
+            push("minor_opcode", Int(error.minor_opcode.into()));
+            if let Some(request) = error.request_name {
+                push("request", FieldValue::Name(request));
+            }
+        }
+        Event::KeyPress(event) | Event::KeyRelease(event) => {
+            push("detail", Int(event.detail.into()));
+            push("time", Int(event.time.into()));
+            push("child", Int(event.child.into()));
+            push("root_x", Int(event.root_x.into()));
+            push("root_y", Int(event.root_y.into()));
+            push("event_x", Int(event.event_x.into()));
+            push("event_y", Int(event.event_y.into()));
+            push("state", Int(u16::from(event.state).into()));
+        }
+        Event::ButtonPress(event) | Event::ButtonRelease(event) => {
+            push("detail", Int(event.detail.into()));
+            push("time", Int(event.time.into()));
+            push("child", Int(event.child.into()));
+            push("root_x", Int(event.root_x.into()));
+            push("root_y", Int(event.root_y.into()));
+            push("event_x", Int(event.event_x.into()));
+            push("event_y", Int(event.event_y.into()));
+            push("state", Int(u16::from(event.state).into()));
+        }
+        Event::MotionNotify(event) => {
+            let detail = FieldValue::enumeration(event.detail.name(), u8::from(event.detail));
+            push("detail", detail);
+            push("time", Int(event.time.into()));
+            push("child", Int(event.child.into()));
+            push("root_x", Int(event.root_x.into()));
+            push("root_y", Int(event.root_y.into()));
+            push("event_x", Int(event.event_x.into()));
+            push("event_y", Int(event.event_y.into()));
+            push("state", Int(u16::from(event.state).into()));
+        }
+        Event::EnterNotify(event) | Event::LeaveNotify(event) => {
+            let detail = FieldValue::enumeration(event.detail.name(), u8::from(event.detail));
+            push("detail", detail);
+            let mode = FieldValue::enumeration(event.mode.name(), u8::from(event.mode));
+            push("mode", mode);
+            push("time", Int(event.time.into()));
+            push("child", Int(event.child.into()));
+            push("event_x", Int(event.event_x.into()));
+            push("event_y", Int(event.event_y.into()));
+            push("state", Int(u16::from(event.state).into()));
+        }
+        Event::FocusIn(event) | Event::FocusOut(event) => {
+            let detail = FieldValue::enumeration(event.detail.name(), u8::from(event.detail));
+            push("detail", detail);
+            let mode = FieldValue::enumeration(event.mode.name(), u8::from(event.mode));
+            push("mode", mode);
+        }
+        Event::Expose(event) => {
+            push("x", Int(event.x.into()));
+            push("y", Int(event.y.into()));
+            push("width", Int(event.width.into()));
+            push("height", Int(event.height.into()));
+            push("count", Int(event.count.into()));
+        }
+        Event::GraphicsExposure(event) => {
+            push("x", Int(event.x.into()));
+            push("y", Int(event.y.into()));
+            push("width", Int(event.width.into()));
+            push("height", Int(event.height.into()));
+            push("count", Int(event.count.into()));
+            push("major_opcode", Int(event.major_opcode.into()));
+            push("minor_opcode", Int(event.minor_opcode.into()));
+        }
+        Event::NoExposure(event) => {
+            push("major_opcode", Int(event.major_opcode.into()));
+            push("minor_opcode", Int(event.minor_opcode.into()));
+        }
+        Event::VisibilityNotify(event) => {
+            let state = FieldValue::enumeration(event.state.name(), u8::from(event.state));
+            push("state", state);
+        }
+        Event::CreateNotify(event) => {
+            push("parent", Int(event.parent.into()));
+            push("x", Int(event.x.into()));
+            push("y", Int(event.y.into()));
+            push("width", Int(event.width.into()));
+            push("height", Int(event.height.into()));
+            push("border_width", Int(event.border_width.into()));
+            push("override_redirect", Bool(event.override_redirect));
+        }
+        Event::DestroyNotify(event) => push("event", Int(event.event.into())),
+        Event::UnmapNotify(event) => {
+            push("event", Int(event.event.into()));
+            push("from_configure", Bool(event.from_configure));
+        }
+        Event::MapNotify(event) => {
+            push("event", Int(event.event.into()));
+            push("override_redirect", Bool(event.override_redirect));
+        }
+        Event::MapRequest(event) => push("parent", Int(event.parent.into())),
+        Event::ReparentNotify(event) => {
+            push("event", Int(event.event.into()));
+            push("parent", Int(event.parent.into()));
+            push("x", Int(event.x.into()));
+            push("y", Int(event.y.into()));
+            push("override_redirect", Bool(event.override_redirect));
+        }
+        Event::ConfigureNotify(event) => {
+            push("event", Int(event.event.into()));
+            push("above_sibling", Int(event.above_sibling.into()));
+            push("x", Int(event.x.into()));
+            push("y", Int(event.y.into()));
+            push("width", Int(event.width.into()));
+            push("height", Int(event.height.into()));
+            push("border_width", Int(event.border_width.into()));
+            push("override_redirect", Bool(event.override_redirect));
+        }
+        Event::ConfigureRequest(event) => {
+            let stack_mode =
+                FieldValue::enumeration(event.stack_mode.name(), u32::from(event.stack_mode));
+            push("parent", Int(event.parent.into()));
+            push("sibling", Int(event.sibling.into()));
+            push("x", Int(event.x.into()));
+            push("y", Int(event.y.into()));
+            push("width", Int(event.width.into()));
+            push("height", Int(event.height.into()));
+            push("border_width", Int(event.border_width.into()));
+            push("stack_mode", stack_mode);
+            push("value_mask", Int(u16::from(event.value_mask).into()));
+        }
+        Event::GravityNotify(event) => {
+            push("x", Int(event.x.into()));
+            push("y", Int(event.y.into()));
+        }
+        Event::ResizeRequest(event) => {
+            push("width", Int(event.width.into()));
+            push("height", Int(event.height.into()));
+        }
+        Event::CirculateNotify(event) | Event::CirculateRequest(event) => {
+            let place = FieldValue::enumeration(event.place.name(), u8::from(event.place));
+            push("place", place);
+        }
+        Event::PropertyNotify(event) => {
+            push("atom", Int(event.atom.into()));
+            push("time", Int(event.time.into()));
+            let state = FieldValue::enumeration(event.state.name(), u8::from(event.state));
+            push("state", state);
+        }
+        Event::SelectionClear(event) => {
+            push("time", Int(event.time.into()));
+            push("selection", Int(event.selection.into()));
+        }
+        Event::SelectionRequest(event) => {
+            push("time", Int(event.time.into()));
+            push("requestor", Int(event.requestor.into()));
+            push("selection", Int(event.selection.into()));
+            push("target", Int(event.target.into()));
+            push("property", Int(event.property.into()));
+        }
+        Event::SelectionNotify(event) => {
+            push("time", Int(event.time.into()));
+            push("selection", Int(event.selection.into()));
+            push("target", Int(event.target.into()));
+            push("property", Int(event.property.into()));
+        }
+        Event::ColormapNotify(event) => {
+            push("colormap", Int(event.colormap.into()));
+            push("new", Bool(event.new));
+            let state = FieldValue::enumeration(event.state.name(), u8::from(event.state));
+            push("state", state);
+        }
+        Event::ClientMessage(event) => {
+            push("type", Int(event.type_.into()));
+            push("format", Int(event.format.into()));
+        }
+        Event::MappingNotify(event) => {
+            let request = FieldValue::enumeration(event.request.name(), u8::from(event.request));
+            push("request", request);
+            push("first_keycode", Int(event.first_keycode.into()));
+            push("count", Int(event.count.into()));
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{EventLogger, EventRecord, FieldValue};
+    use crate::protocol::xproto::{ExposeEvent, FocusInEvent, NotifyDetail, EXPOSE_EVENT};
+    use crate::protocol::{Event, EventKind};
+
+    #[test]
+    fn record_formats() {
+        let event = Event::Expose(ExposeEvent {
+            response_type: EXPOSE_EVENT | 0x80,
+            sequence: 7,
+            window: 0x1234,
+            x: 1,
+            y: 2,
+            width: 3,
+            height: 4,
+            count: 0,
+        });
+        let record = EventRecord::new(&event);
+        assert_eq!(record.fields[2], ("width", FieldValue::Int(3)));
+        assert_eq!(
+            record.to_string(),
+            "Expose sequence=7 window=0x1234 sent_event=true x=1 y=2 width=3 height=4 count=0"
+        );
+        assert_eq!(
+            record.to_json(),
+            concat!(
+                r#"{"event":"Expose","extension":null,"sequence":7,"window":4660,"#,
+                r#""sent_event":true,"x":1,"y":2,"width":3,"height":4,"count":0}"#
+            )
+        );
+    }
+
+    #[test]
+    fn enumerations_and_ignored_kinds() {
+        let event = Event::FocusIn(FocusInEvent {
+            detail: NotifyDetail::INFERIOR,
+            mode: 42.into(),
+            ..Default::default()
+        });
+        let record = EventLogger::new().record(&event).unwrap();
+        assert_eq!(
+            record.fields,
+            [
+                ("detail", FieldValue::Name("Inferior")),
+                ("mode", FieldValue::Int(42))
+            ]
+        );
+        let logger = EventLogger::new().ignore(EventKind::FocusIn);
+        assert_eq!(logger.record(&event), None);
+    }
+}
@@ -0,0 +1,1455 @@
+//! Typed access to the Extended Window Manager Hints (EWMH).
+//!
+//! The [EWMH specification](https://specifications.freedesktop.org/wm-spec/latest/) describes
+//! `_NET_*` properties on the root window and on client windows, through which window managers,
+//! pagers, taskbars and applications exchange information. [`Ewmh`] offers getters and setters
+//! for these properties and sends the client messages with which other clients ask the window
+//! manager to change something, e.g. to activate a window or to make it fullscreen.
+//!
+//! Getters return a [`PropertyCookie`], so that several properties can be requested before
+//! waiting for the first reply. The reply is `None` if the property is not set.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use x11rb::ewmh::{Ewmh, SourceIndication, StateAction};
+//!
+//! let (conn, screen_num) = x11rb::connect(None)?;
+//! let ewmh = Ewmh::new(&conn, screen_num)?;
+//! if let Some(window) = ewmh.active_window()?.reply()? {
+//!     let name = ewmh.wm_name(window)?.reply()?;
+//!     println!("The active window is {:?}", name);
+//!     let fullscreen = ewmh.atoms()._NET_WM_STATE_FULLSCREEN;
+//!     ewmh.request_wm_state(window, StateAction::Toggle, fullscreen, 0, SourceIndication::Pager)?;
+//! }
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::connection::{Connection, RequestConnection};
+use crate::cookie::{Cookie, VoidCookie};
+use crate::errors::{ConnectionError, ParseError, ReplyError};
+use crate::properties::{NetWmIcons, NetWmIconsCookie};
+use crate::protocol::xproto::{
+    self, Atom, AtomEnum, ClientMessageEvent, ConfigureWindowAux, EventMask, GetPropertyReply,
+    Gravity, PropMode, StackMode, Timestamp, Window,
+};
+use crate::wrapper::ConnectionExt as _;
+use crate::x11_utils::{Serialize, TryParse};
+
+pub use atoms::{EwmhAtoms, EwmhAtomsCookie};
+
+mod atoms {
+    // The code that the macro generates has no documentation and a `new` function that returns a
+    // cookie
+    #![allow(missing_docs, clippy::new_ret_no_self)]
+
+    crate::atom_manager! {
+        /// The atoms of the EWMH specification.
+        pub EwmhAtoms:
+        /// A cookie for interning the atoms of the EWMH specification.
+        EwmhAtomsCookie {
+            UTF8_STRING,
+
+            // Root window properties
+            _NET_SUPPORTED,
+            _NET_CLIENT_LIST,
+            _NET_CLIENT_LIST_STACKING,
+            _NET_NUMBER_OF_DESKTOPS,
+            _NET_DESKTOP_GEOMETRY,
+            _NET_DESKTOP_VIEWPORT,
+            _NET_CURRENT_DESKTOP,
+            _NET_DESKTOP_NAMES,
+            _NET_ACTIVE_WINDOW,
+            _NET_WORKAREA,
+            _NET_SUPPORTING_WM_CHECK,
+            _NET_VIRTUAL_ROOTS,
+            _NET_DESKTOP_LAYOUT,
+            _NET_SHOWING_DESKTOP,
+
+            // Other root window messages
+            _NET_CLOSE_WINDOW,
+            _NET_MOVERESIZE_WINDOW,
+            _NET_WM_MOVERESIZE,
+            _NET_RESTACK_WINDOW,
+            _NET_REQUEST_FRAME_EXTENTS,
+
+            // Application window properties
+            _NET_WM_NAME,
+            _NET_WM_VISIBLE_NAME,
+            _NET_WM_ICON_NAME,
+            _NET_WM_VISIBLE_ICON_NAME,
+            _NET_WM_DESKTOP,
+            _NET_WM_WINDOW_TYPE,
+            _NET_WM_STATE,
+            _NET_WM_ALLOWED_ACTIONS,
+            _NET_WM_STRUT,
+            _NET_WM_STRUT_PARTIAL,
+            _NET_WM_ICON_GEOMETRY,
+            _NET_WM_ICON,
+            _NET_WM_PID,
+            _NET_WM_HANDLED_ICONS,
+            _NET_WM_USER_TIME,
+            _NET_WM_USER_TIME_WINDOW,
+            _NET_FRAME_EXTENTS,
+            _NET_WM_OPAQUE_REGION,
+            _NET_WM_BYPASS_COMPOSITOR,
+
+            // Window manager protocols
+            _NET_WM_PING,
+            _NET_WM_SYNC_REQUEST,
+            _NET_WM_SYNC_REQUEST_COUNTER,
+            _NET_WM_FULLSCREEN_MONITORS,
+            _NET_WM_FULL_PLACEMENT,
+
+            // Values of _NET_WM_WINDOW_TYPE
+            _NET_WM_WINDOW_TYPE_DESKTOP,
+            _NET_WM_WINDOW_TYPE_DOCK,
+            _NET_WM_WINDOW_TYPE_TOOLBAR,
+            _NET_WM_WINDOW_TYPE_MENU,
+            _NET_WM_WINDOW_TYPE_UTILITY,
+            _NET_WM_WINDOW_TYPE_SPLASH,
+            _NET_WM_WINDOW_TYPE_DIALOG,
+            _NET_WM_WINDOW_TYPE_DROPDOWN_MENU,
+            _NET_WM_WINDOW_TYPE_POPUP_MENU,
+            _NET_WM_WINDOW_TYPE_TOOLTIP,
+            _NET_WM_WINDOW_TYPE_NOTIFICATION,
+            _NET_WM_WINDOW_TYPE_COMBO,
+            _NET_WM_WINDOW_TYPE_DND,
+            _NET_WM_WINDOW_TYPE_NORMAL,
+
+            // Values of _NET_WM_STATE
+            _NET_WM_STATE_MODAL,
+            _NET_WM_STATE_STICKY,
+            _NET_WM_STATE_MAXIMIZED_VERT,
+            _NET_WM_STATE_MAXIMIZED_HORZ,
+            _NET_WM_STATE_SHADED,
+            _NET_WM_STATE_SKIP_TASKBAR,
+            _NET_WM_STATE_SKIP_PAGER,
+            _NET_WM_STATE_HIDDEN,
+            _NET_WM_STATE_FULLSCREEN,
+            _NET_WM_STATE_ABOVE,
+            _NET_WM_STATE_BELOW,
+            _NET_WM_STATE_DEMANDS_ATTENTION,
+            _NET_WM_STATE_FOCUSED,
+
+            // Values of _NET_WM_ALLOWED_ACTIONS
+            _NET_WM_ACTION_MOVE,
+            _NET_WM_ACTION_RESIZE,
+            _NET_WM_ACTION_MINIMIZE,
+            _NET_WM_ACTION_SHADE,
+            _NET_WM_ACTION_STICK,
+            _NET_WM_ACTION_MAXIMIZE_HORZ,
+            _NET_WM_ACTION_MAXIMIZE_VERT,
+            _NET_WM_ACTION_FULLSCREEN,
+            _NET_WM_ACTION_CHANGE_DESKTOP,
+            _NET_WM_ACTION_CLOSE,
+            _NET_WM_ACTION_ABOVE,
+            _NET_WM_ACTION_BELOW,
+        }
+    }
+}
+
+/// A cookie for getting a property and parsing it into a `T`.
+#[derive(Debug)]
+pub struct PropertyCookie<'c, C: RequestConnection + ?Sized, T> {
+    cookie: Cookie<'c, C, GetPropertyReply>,
+    type_: Atom,
+    format: u8,
+    parse: fn(&[u8]) -> Result<T, ParseError>,
+}
+
+impl<'c, C, T> PropertyCookie<'c, C, T>
+where
+    C: RequestConnection + ?Sized,
+{
+    /// Get the reply that the server sent.
+    ///
+    /// This returns `None` if the property is not set and fails with
+    /// [`ParseError::InvalidValue`] if it has an unexpected type or format.
+    pub fn reply(self) -> Result<Option<T>, ReplyError> {
+        let (type_, format, parse) = (self.type_, self.format, self.parse);
+        Ok(parse_reply(&self.cookie.reply()?, type_, format, parse)?)
+    }
+
+    /// Get the reply that the server sent, but have errors handled as events.
+    pub fn reply_unchecked(self) -> Result<Option<T>, ConnectionError> {
+        let (type_, format, parse) = (self.type_, self.format, self.parse);
+        match self.cookie.reply_unchecked()? {
+            Some(reply) => Ok(parse_reply(&reply, type_, format, parse)?),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Check the type of a property and parse its value.
+fn parse_reply<T>(
+    reply: &GetPropertyReply,
+    type_: Atom,
+    format: u8,
+    parse: fn(&[u8]) -> Result<T, ParseError>,
+) -> Result<Option<T>, ParseError> {
+    if reply.type_ == AtomEnum::NONE.into() {
+        return Ok(None);
+    }
+    if reply.type_ != type_ || reply.format != format {
+        return Err(ParseError::InvalidValue);
+    }
+    parse(&reply.value).map(Some)
+}
+
+/// Who sent a request to the window manager.
+///
+/// Window managers may treat requests from pagers and taskbars differently from requests of
+/// applications, e.g. to prevent applications from stealing the focus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceIndication {
+    /// The sender does not support this indication, e.g. because it implements an older version
+    /// of the specification.
+    None,
+    /// The request comes from a normal application.
+    Application,
+    /// The request comes from a pager, taskbar or similar tool that acts for the user.
+    Pager,
+}
+
+impl From<SourceIndication> for u32 {
+    fn from(source: SourceIndication) -> Self {
+        match source {
+            SourceIndication::None => 0,
+            SourceIndication::Application => 1,
+            SourceIndication::Pager => 2,
+        }
+    }
+}
+
+/// How a `_NET_WM_STATE` request changes the state of a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateAction {
+    /// Remove the state.
+    Remove,
+    /// Add the state.
+    Add,
+    /// Add the state if it is not set and remove it otherwise.
+    Toggle,
+}
+
+impl From<StateAction> for u32 {
+    fn from(action: StateAction) -> Self {
+        match action {
+            StateAction::Remove => 0,
+            StateAction::Add => 1,
+            StateAction::Toggle => 2,
+        }
+    }
+}
+
+/// The operation of a `_NET_WM_MOVERESIZE` request.
+///
+/// The `Size*` variants name the edge or corner of the window that follows the pointer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoveResizeDirection {
+    /// Resize at the top left corner.
+    SizeTopLeft,
+    /// Resize at the top edge.
+    SizeTop,
+    /// Resize at the top right corner.
+    SizeTopRight,
+    /// Resize at the right edge.
+    SizeRight,
+    /// Resize at the bottom right corner.
+    SizeBottomRight,
+    /// Resize at the bottom edge.
+    SizeBottom,
+    /// Resize at the bottom left corner.
+    SizeBottomLeft,
+    /// Resize at the left edge.
+    SizeLeft,
+    /// Move the window with the pointer.
+    Move,
+    /// Resize the window with the keyboard.
+    SizeKeyboard,
+    /// Move the window with the keyboard.
+    MoveKeyboard,
+    /// Cancel a move or resize operation that was started before.
+    Cancel,
+}
+
+impl From<MoveResizeDirection> for u32 {
+    fn from(direction: MoveResizeDirection) -> Self {
+        direction as u32
+    }
+}
+
+/// The space that a window manager adds around a window for its frame.
+///
+/// This is the value of the `_NET_FRAME_EXTENTS` property.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FrameExtents {
+    /// The width of the left border.
+    pub left: u32,
+    /// The width of the right border.
+    pub right: u32,
+    /// The height of the top border, e.g. with the title bar.
+    pub top: u32,
+    /// The height of the bottom border.
+    pub bottom: u32,
+}
+
+impl TryParse for FrameExtents {
+    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        let (left, remaining) = u32::try_parse(remaining)?;
+        let (right, remaining) = u32::try_parse(remaining)?;
+        let (top, remaining) = u32::try_parse(remaining)?;
+        let (bottom, remaining) = u32::try_parse(remaining)?;
+        let result = Self {
+            left,
+            right,
+            top,
+            bottom,
+        };
+        Ok((result, remaining))
+    }
+}
+
+impl Serialize for FrameExtents {
+    type Bytes = Vec<u8>;
+
+    fn serialize(&self) -> Self::Bytes {
+        let mut result = Vec::new();
+        self.serialize_into(&mut result);
+        result
+    }
+
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        for value in [self.left, self.right, self.top, self.bottom] {
+            value.serialize_into(bytes);
+        }
+    }
+}
+
+/// Space at the edges of the screen that a window reserves, e.g. for a panel.
+///
+/// This is the value of the `_NET_WM_STRUT_PARTIAL` property. Each edge reserves `left`,
+/// `right`, `top` or `bottom` pixels between the given start and end coordinates, which are
+/// inclusive. The older `_NET_WM_STRUT` property only contains the first four values and
+/// reserves the whole edge.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct StrutPartial {
+    /// The width of the reserved space at the left edge.
+    pub left: u32,
+    /// The width of the reserved space at the right edge.
+    pub right: u32,
+    /// The height of the reserved space at the top edge.
+    pub top: u32,
+    /// The height of the reserved space at the bottom edge.
+    pub bottom: u32,
+    /// The first y coordinate of the space at the left edge.
+    pub left_start_y: u32,
+    /// The last y coordinate of the space at the left edge.
+    pub left_end_y: u32,
+    /// The first y coordinate of the space at the right edge.
+    pub right_start_y: u32,
+    /// The last y coordinate of the space at the right edge.
+    pub right_end_y: u32,
+    /// The first x coordinate of the space at the top edge.
+    pub top_start_x: u32,
+    /// The last x coordinate of the space at the top edge.
+    pub top_end_x: u32,
+    /// The first x coordinate of the space at the bottom edge.
+    pub bottom_start_x: u32,
+    /// The last x coordinate of the space at the bottom edge.
+    pub bottom_end_x: u32,
+}
+
+impl TryParse for StrutPartial {
+    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        let ((left, right, top, bottom), remaining) = <(u32, u32, u32, u32)>::try_parse(remaining)?;
+        let ((left_start_y, left_end_y, right_start_y, right_end_y), remaining) =
+            <(u32, u32, u32, u32)>::try_parse(remaining)?;
+        let ((top_start_x, top_end_x, bottom_start_x, bottom_end_x), remaining) =
+            <(u32, u32, u32, u32)>::try_parse(remaining)?;
+        let result = Self {
+            left,
+            right,
+            top,
+            bottom,
+            left_start_y,
+            left_end_y,
+            right_start_y,
+            right_end_y,
+            top_start_x,
+            top_end_x,
+            bottom_start_x,
+            bottom_end_x,
+        };
+        Ok((result, remaining))
+    }
+}
+
+impl Serialize for StrutPartial {
+    type Bytes = Vec<u8>;
+
+    fn serialize(&self) -> Self::Bytes {
+        let mut result = Vec::new();
+        self.serialize_into(&mut result);
+        result
+    }
+
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        for value in [
+            self.left,
+            self.right,
+            self.top,
+            self.bottom,
+            self.left_start_y,
+            self.left_end_y,
+            self.right_start_y,
+            self.right_end_y,
+            self.top_start_x,
+            self.top_end_x,
+            self.bottom_start_x,
+            self.bottom_end_x,
+        ] {
+            value.serialize_into(bytes);
+        }
+    }
+}
+
+/// The area of a desktop that is not covered by panels and docks.
+///
+/// `_NET_WORKAREA` contains one of these for each desktop.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Workarea {
+    /// The x coordinate of the top left corner.
+    pub x: u32,
+    /// The y coordinate of the top left corner.
+    pub y: u32,
+    /// The width of the area.
+    pub width: u32,
+    /// The height of the area.
+    pub height: u32,
+}
+
+impl TryParse for Workarea {
+    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        let ((x, y, width, height), remaining) = <(u32, u32, u32, u32)>::try_parse(remaining)?;
+        let result = Self {
+            x,
+            y,
+            width,
+            height,
+        };
+        Ok((result, remaining))
+    }
+}
+
+impl Serialize for Workarea {
+    type Bytes = [u8; 16];
+
+    fn serialize(&self) -> Self::Bytes {
+        let mut result = [0; 16];
+        for (bytes, value) in
+            result
+                .chunks_exact_mut(4)
+                .zip([self.x, self.y, self.width, self.height])
+        {
+            bytes.copy_from_slice(&value.serialize());
+        }
+        result
+    }
+
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(&self.serialize());
+    }
+}
+
+/// Access to the EWMH properties and messages of a screen.
+#[derive(Debug)]
+pub struct Ewmh<'c, C: Connection> {
+    conn: &'c C,
+    root: Window,
+    atoms: EwmhAtoms,
+}
+
+impl<'c, C: Connection> Ewmh<'c, C> {
+    /// Intern the EWMH atoms and create an instance for a screen.
+    ///
+    /// Fails with [`ParseError::InvalidValue`] if the screen does not exist.
+    pub fn new(conn: &'c C, screen: usize) -> Result<Self, ReplyError> {
+        let root = conn
+            .setup()
+            .roots
+            .get(screen)
+            .ok_or(ParseError::InvalidValue)?
+            .root;
+        let atoms = EwmhAtoms::new(conn)?.reply()?;
+        Ok(Self::with_atoms(conn, root, atoms))
+    }
+
+    /// Create an instance for the screen of a root window with atoms that were already interned.
+    pub fn with_atoms(conn: &'c C, root: Window, atoms: EwmhAtoms) -> Self {
+        Self { conn, root, atoms }
+    }
+
+    /// Get the interned atoms, e.g. to compare them with the values of `_NET_WM_STATE`.
+    pub fn atoms(&self) -> &EwmhAtoms {
+        &self.atoms
+    }
+
+    /// Get the root window of the screen.
+    pub fn root(&self) -> Window {
+        self.root
+    }
+
+    // Root window properties
+
+    /// Get the atoms that the window manager supports (`_NET_SUPPORTED`).
+    pub fn supported(&self) -> Result<PropertyCookie<'c, C, Vec<Atom>>, ConnectionError> {
+        self.get(
+            self.root,
+            self.atoms._NET_SUPPORTED,
+            AtomEnum::ATOM,
+            parse_list,
+        )
+    }
+
+    /// Set the atoms that the window manager supports (`_NET_SUPPORTED`).
+    pub fn set_supported(&self, atoms: &[Atom]) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        self.set32(self.root, self.atoms._NET_SUPPORTED, AtomEnum::ATOM, atoms)
+    }
+
+    /// Get the managed windows in the order in which they were mapped (`_NET_CLIENT_LIST`).
+    pub fn client_list(&self) -> Result<PropertyCookie<'c, C, Vec<Window>>, ConnectionError> {
+        self.get(
+            self.root,
+            self.atoms._NET_CLIENT_LIST,
+            AtomEnum::WINDOW,
+            parse_list,
+        )
+    }
+
+    /// Set the managed windows in the order in which they were mapped (`_NET_CLIENT_LIST`).
+    pub fn set_client_list(
+        &self,
+        windows: &[Window],
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        self.set32(
+            self.root,
+            self.atoms._NET_CLIENT_LIST,
+            AtomEnum::WINDOW,
+            windows,
+        )
+    }
+
+    /// Get the managed windows from bottom to top (`_NET_CLIENT_LIST_STACKING`).
+    pub fn client_list_stacking(
+        &self,
+    ) -> Result<PropertyCookie<'c, C, Vec<Window>>, ConnectionError> {
+        self.get(
+            self.root,
+            self.atoms._NET_CLIENT_LIST_STACKING,
+            AtomEnum::WINDOW,
+            parse_list,
+        )
+    }
+
+    /// Set the managed windows from bottom to top (`_NET_CLIENT_LIST_STACKING`).
+    pub fn set_client_list_stacking(
+        &self,
+        windows: &[Window],
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        self.set32(
+            self.root,
+            self.atoms._NET_CLIENT_LIST_STACKING,
+            AtomEnum::WINDOW,
+            windows,
+        )
+    }
+
+    /// Get the number of virtual desktops (`_NET_NUMBER_OF_DESKTOPS`).
+    pub fn number_of_desktops(&self) -> Result<PropertyCookie<'c, C, u32>, ConnectionError> {
+        self.get(
+            self.root,
+            self.atoms._NET_NUMBER_OF_DESKTOPS,
+            AtomEnum::CARDINAL,
+            parse_one,
+        )
+    }
+
+    /// Set the number of virtual desktops (`_NET_NUMBER_OF_DESKTOPS`).
+    pub fn set_number_of_desktops(
+        &self,
+        number: u32,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        self.set32(
+            self.root,
+            self.atoms._NET_NUMBER_OF_DESKTOPS,
+            AtomEnum::CARDINAL,
+            &[number],
+        )
+    }
+
+    /// Get the common size of all desktops as `(width, height)` (`_NET_DESKTOP_GEOMETRY`).
+    pub fn desktop_geometry(&self) -> Result<PropertyCookie<'c, C, (u32, u32)>, ConnectionError> {
+        self.get(
+            self.root,
+            self.atoms._NET_DESKTOP_GEOMETRY,
+            AtomEnum::CARDINAL,
+            parse_one,
+        )
+    }
+
+    /// Set the common size of all desktops (`_NET_DESKTOP_GEOMETRY`).
+    pub fn set_desktop_geometry(
+        &self,
+        (width, height): (u32, u32),
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        self.set32(
+            self.root,
+            self.atoms._NET_DESKTOP_GEOMETRY,
+            AtomEnum::CARDINAL,
+            &[width, height],
+        )
+    }
+
+    /// Get the top left corner of the viewport of each desktop (`_NET_DESKTOP_VIEWPORT`).
+    pub fn desktop_viewport(
+        &self,
+    ) -> Result<PropertyCookie<'c, C, Vec<(u32, u32)>>, ConnectionError> {
+        self.get(
+            self.root,
+            self.atoms._NET_DESKTOP_VIEWPORT,
+            AtomEnum::CARDINAL,
+            parse_list,
+        )
+    }
+
+    /// Set the top left corner of the viewport of each desktop (`_NET_DESKTOP_VIEWPORT`).
+    pub fn set_desktop_viewport(
+        &self,
+        viewports: &[(u32, u32)],
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        let data = viewports
+            .iter()
+            .flat_map(|&(x, y)| [x, y])
+            .collect::<Vec<_>>();
+        self.set32(
+            self.root,
+            self.atoms._NET_DESKTOP_VIEWPORT,
+            AtomEnum::CARDINAL,
+            &data,
+        )
+    }
+
+    /// Get the index of the current desktop (`_NET_CURRENT_DESKTOP`).
+    pub fn current_desktop(&self) -> Result<PropertyCookie<'c, C, u32>, ConnectionError> {
+        self.get(
+            self.root,
+            self.atoms._NET_CURRENT_DESKTOP,
+            AtomEnum::CARDINAL,
+            parse_one,
+        )
+    }
+
+    /// Set the index of the current desktop (`_NET_CURRENT_DESKTOP`).
+    pub fn set_current_desktop(&self, desktop: u32) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        self.set32(
+            self.root,
+            self.atoms._NET_CURRENT_DESKTOP,
+            AtomEnum::CARDINAL,
+            &[desktop],
+        )
+    }
+
+    /// Get the names of the desktops (`_NET_DESKTOP_NAMES`).
+    pub fn desktop_names(&self) -> Result<PropertyCookie<'c, C, Vec<String>>, ConnectionError> {
+        self.get_utf8(self.root, self.atoms._NET_DESKTOP_NAMES, parse_strings)
+    }
+
+    /// Set the names of the desktops (`_NET_DESKTOP_NAMES`).
+    pub fn set_desktop_names(&self, names: &[&str]) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        let data = names
+            .iter()
+            .flat_map(|name| name.bytes().chain(Some(0)))
+            .collect::<Vec<_>>();
+        self.set_utf8(self.root, self.atoms._NET_DESKTOP_NAMES, &data)
+    }
+
+    /// Get the window that has the focus (`_NET_ACTIVE_WINDOW`).
+    ///
+    /// The reply is `Some(0)` if no window has the focus.
+    pub fn active_window(&self) -> Result<PropertyCookie<'c, C, Window>, ConnectionError> {
+        self.get(
+            self.root,
+            self.atoms._NET_ACTIVE_WINDOW,
+            AtomEnum::WINDOW,
+            parse_one,
+        )
+    }
+
+    /// Set the window that has the focus (`_NET_ACTIVE_WINDOW`).
+    pub fn set_active_window(&self, window: Window) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        self.set32(
+            self.root,
+            self.atoms._NET_ACTIVE_WINDOW,
+            AtomEnum::WINDOW,
+            &[window],
+        )
+    }
+
+    /// Get the work area of each desktop (`_NET_WORKAREA`).
+    pub fn workarea(&self) -> Result<PropertyCookie<'c, C, Vec<Workarea>>, ConnectionError> {
+        self.get(
+            self.root,
+            self.atoms._NET_WORKAREA,
+            AtomEnum::CARDINAL,
+            parse_list,
+        )
+    }
+
+    /// Set the work area of each desktop (`_NET_WORKAREA`).
+    pub fn set_workarea(&self, areas: &[Workarea]) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        let data = areas
+            .iter()
+            .flat_map(|area| [area.x, area.y, area.width, area.height])
+            .collect::<Vec<_>>();
+        self.set32(
+            self.root,
+            self.atoms._NET_WORKAREA,
+            AtomEnum::CARDINAL,
+            &data,
+        )
+    }
+
+    /// Get the child window of the window manager (`_NET_SUPPORTING_WM_CHECK`).
+    ///
+    /// A compliant window manager is running if this window has the property as well and it
+    /// points to the window itself. The name of the window manager is the `_NET_WM_NAME` of the
+    /// window.
+    pub fn supporting_wm_check(
+        &self,
+        window: Window,
+    ) -> Result<PropertyCookie<'c, C, Window>, ConnectionError> {
+        self.get(
+            window,
+            self.atoms._NET_SUPPORTING_WM_CHECK,
+            AtomEnum::WINDOW,
+            parse_one,
+        )
+    }
+
+    /// Announce a compliant window manager with its child window (`_NET_SUPPORTING_WM_CHECK`).
+    ///
+    /// The property is set on the root window and on `window`.
+    pub fn set_supporting_wm_check(&self, window: Window) -> Result<(), ConnectionError> {
+        for target in [self.root, window] {
+            let _ = self.set32(
+                target,
+                self.atoms._NET_SUPPORTING_WM_CHECK,
+                AtomEnum::WINDOW,
+                &[window],
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Check whether the desktop is shown instead of the windows (`_NET_SHOWING_DESKTOP`).
+    pub fn showing_desktop(&self) -> Result<PropertyCookie<'c, C, bool>, ConnectionError> {
+        self.get(
+            self.root,
+            self.atoms._NET_SHOWING_DESKTOP,
+            AtomEnum::CARDINAL,
+            |value| Ok(parse_one::<u32>(value)? != 0),
+        )
+    }
+
+    /// Set whether the desktop is shown instead of the windows (`_NET_SHOWING_DESKTOP`).
+    pub fn set_showing_desktop(&self, showing: bool) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        self.set32(
+            self.root,
+            self.atoms._NET_SHOWING_DESKTOP,
+            AtomEnum::CARDINAL,
+            &[showing.into()],
+        )
+    }
+
+    // Application window properties
+
+    /// Get the title of a window (`_NET_WM_NAME`).
+    pub fn wm_name(
+        &self,
+        window: Window,
+    ) -> Result<PropertyCookie<'c, C, String>, ConnectionError> {
+        self.get_utf8(window, self.atoms._NET_WM_NAME, parse_string)
+    }
+
+    /// Set the title of a window (`_NET_WM_NAME`).
+    pub fn set_wm_name(
+        &self,
+        window: Window,
+        name: &str,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        self.set_utf8(window, self.atoms._NET_WM_NAME, name.as_bytes())
+    }
+
+    /// Get the title that the window manager shows for a window (`_NET_WM_VISIBLE_NAME`).
+    pub fn wm_visible_name(
+        &self,
+        window: Window,
+    ) -> Result<PropertyCookie<'c, C, String>, ConnectionError> {
+        self.get_utf8(window, self.atoms._NET_WM_VISIBLE_NAME, parse_string)
+    }
+
+    /// Set the title that the window manager shows for a window (`_NET_WM_VISIBLE_NAME`).
+    pub fn set_wm_visible_name(
+        &self,
+        window: Window,
+        name: &str,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        self.set_utf8(window, self.atoms._NET_WM_VISIBLE_NAME, name.as_bytes())
+    }
+
+    /// Get the title of the icon of a window (`_NET_WM_ICON_NAME`).
+    pub fn wm_icon_name(
+        &self,
+        window: Window,
+    ) -> Result<PropertyCookie<'c, C, String>, ConnectionError> {
+        self.get_utf8(window, self.atoms._NET_WM_ICON_NAME, parse_string)
+    }
+
+    /// Set the title of the icon of a window (`_NET_WM_ICON_NAME`).
+    pub fn set_wm_icon_name(
+        &self,
+        window: Window,
+        name: &str,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        self.set_utf8(window, self.atoms._NET_WM_ICON_NAME, name.as_bytes())
+    }
+
+    /// Get the desktop of a window (`_NET_WM_DESKTOP`).
+    ///
+    /// The value `0xFFFFFFFF` means that the window is shown on all desktops.
+    pub fn wm_desktop(
+        &self,
+        window: Window,
+    ) -> Result<PropertyCookie<'c, C, u32>, ConnectionError> {
+        self.get(
+            window,
+            self.atoms._NET_WM_DESKTOP,
+            AtomEnum::CARDINAL,
+            parse_one,
+        )
+    }
+
+    /// Set the desktop of a window (`_NET_WM_DESKTOP`).
+    ///
+    /// Clients should only set this before mapping a window and use
+    /// [`Ewmh::request_wm_desktop`] afterwards.
+    pub fn set_wm_desktop(
+        &self,
+        window: Window,
+        desktop: u32,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        self.set32(
+            window,
+            self.atoms._NET_WM_DESKTOP,
+            AtomEnum::CARDINAL,
+            &[desktop],
+        )
+    }
+
+    /// Get the types of a window in order of preference (`_NET_WM_WINDOW_TYPE`).
+    ///
+    /// The values are atoms like [`EwmhAtoms::_NET_WM_WINDOW_TYPE_DIALOG`].
+    pub fn wm_window_type(
+        &self,
+        window: Window,
+    ) -> Result<PropertyCookie<'c, C, Vec<Atom>>, ConnectionError> {
+        self.get(
+            window,
+            self.atoms._NET_WM_WINDOW_TYPE,
+            AtomEnum::ATOM,
+            parse_list,
+        )
+    }
+
+    /// Set the types of a window in order of preference (`_NET_WM_WINDOW_TYPE`).
+    pub fn set_wm_window_type(
+        &self,
+        window: Window,
+        types: &[Atom],
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        self.set32(
+            window,
+            self.atoms._NET_WM_WINDOW_TYPE,
+            AtomEnum::ATOM,
+            types,
+        )
+    }
+
+    /// Get the states of a window (`_NET_WM_STATE`).
+    ///
+    /// The values are atoms like [`EwmhAtoms::_NET_WM_STATE_FULLSCREEN`].
+    pub fn wm_state(
+        &self,
+        window: Window,
+    ) -> Result<PropertyCookie<'c, C, Vec<Atom>>, ConnectionError> {
+        self.get(window, self.atoms._NET_WM_STATE, AtomEnum::ATOM, parse_list)
+    }
+
+    /// Set the states of a window (`_NET_WM_STATE`).
+    ///
+    /// Clients should only set this before mapping a window and use [`Ewmh::request_wm_state`]
+    /// afterwards.
+    pub fn set_wm_state(
+        &self,
+        window: Window,
+        states: &[Atom],
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        self.set32(window, self.atoms._NET_WM_STATE, AtomEnum::ATOM, states)
+    }
+
+    /// Get the actions that the window manager allows for a window (`_NET_WM_ALLOWED_ACTIONS`).
+    ///
+    /// The values are atoms like [`EwmhAtoms::_NET_WM_ACTION_CLOSE`].
+    pub fn wm_allowed_actions(
+        &self,
+        window: Window,
+    ) -> Result<PropertyCookie<'c, C, Vec<Atom>>, ConnectionError> {
+        self.get(
+            window,
+            self.atoms._NET_WM_ALLOWED_ACTIONS,
+            AtomEnum::ATOM,
+            parse_list,
+        )
+    }
+
+    /// Set the actions that the window manager allows for a window (`_NET_WM_ALLOWED_ACTIONS`).
+    pub fn set_wm_allowed_actions(
+        &self,
+        window: Window,
+        actions: &[Atom],
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        self.set32(
+            window,
+            self.atoms._NET_WM_ALLOWED_ACTIONS,
+            AtomEnum::ATOM,
+            actions,
+        )
+    }
+
+    /// Get the space that a window reserves at the edges of the screen (`_NET_WM_STRUT_PARTIAL`).
+    pub fn wm_strut_partial(
+        &self,
+        window: Window,
+    ) -> Result<PropertyCookie<'c, C, StrutPartial>, ConnectionError> {
+        self.get(
+            window,
+            self.atoms._NET_WM_STRUT_PARTIAL,
+            AtomEnum::CARDINAL,
+            parse_one,
+        )
+    }
+
+    /// Set the space that a window reserves at the edges of the screen.
+    ///
+    /// This sets `_NET_WM_STRUT_PARTIAL` and, for older window managers, `_NET_WM_STRUT`.
+    pub fn set_wm_strut_partial(
+        &self,
+        window: Window,
+        strut: &StrutPartial,
+    ) -> Result<(), ConnectionError> {
+        let data = strut.serialize();
+        for (property, len) in [
+            (self.atoms._NET_WM_STRUT_PARTIAL, 12),
+            (self.atoms._NET_WM_STRUT, 4),
+        ] {
+            let _ = xproto::change_property(
+                self.conn,
+                PropMode::REPLACE,
+                window,
+                property,
+                AtomEnum::CARDINAL,
+                32,
+                len,
+                &data[..4 * len as usize],
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Get the icons of a window (`_NET_WM_ICON`).
+    pub fn wm_icon(&self, window: Window) -> Result<NetWmIconsCookie<'c, C>, ConnectionError> {
+        NetWmIcons::get(self.conn, window, self.atoms._NET_WM_ICON)
+    }
+
+    /// Set the icons of a window (`_NET_WM_ICON`).
+    pub fn set_wm_icon(
+        &self,
+        window: Window,
+        icons: &NetWmIcons,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        icons.set(self.conn, window, self.atoms._NET_WM_ICON)
+    }
+
+    /// Get the process id of the client of a window (`_NET_WM_PID`).
+    pub fn wm_pid(&self, window: Window) -> Result<PropertyCookie<'c, C, u32>, ConnectionError> {
+        self.get(
+            window,
+            self.atoms._NET_WM_PID,
+            AtomEnum::CARDINAL,
+            parse_one,
+        )
+    }
+
+    /// Set the process id of the client of a window (`_NET_WM_PID`).
+    pub fn set_wm_pid(
+        &self,
+        window: Window,
+        pid: u32,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        self.set32(window, self.atoms._NET_WM_PID, AtomEnum::CARDINAL, &[pid])
+    }
+
+    /// Get the time of the last user activity in a window (`_NET_WM_USER_TIME`).
+    pub fn wm_user_time(
+        &self,
+        window: Window,
+    ) -> Result<PropertyCookie<'c, C, Timestamp>, ConnectionError> {
+        self.get(
+            window,
+            self.atoms._NET_WM_USER_TIME,
+            AtomEnum::CARDINAL,
+            parse_one,
+        )
+    }
+
+    /// Set the time of the last user activity in a window (`_NET_WM_USER_TIME`).
+    ///
+    /// A value of zero asks the window manager not to focus the window when it is mapped.
+    pub fn set_wm_user_time(
+        &self,
+        window: Window,
+        time: Timestamp,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        self.set32(
+            window,
+            self.atoms._NET_WM_USER_TIME,
+            AtomEnum::CARDINAL,
+            &[time],
+        )
+    }
+
+    /// Get the size of the frame around a window (`_NET_FRAME_EXTENTS`).
+    pub fn frame_extents(
+        &self,
+        window: Window,
+    ) -> Result<PropertyCookie<'c, C, FrameExtents>, ConnectionError> {
+        self.get(
+            window,
+            self.atoms._NET_FRAME_EXTENTS,
+            AtomEnum::CARDINAL,
+            parse_one,
+        )
+    }
+
+    /// Set the size of the frame around a window (`_NET_FRAME_EXTENTS`).
+    pub fn set_frame_extents(
+        &self,
+        window: Window,
+        extents: &FrameExtents,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        let data = extents.serialize();
+        xproto::change_property(
+            self.conn,
+            PropMode::REPLACE,
+            window,
+            self.atoms._NET_FRAME_EXTENTS,
+            AtomEnum::CARDINAL,
+            32,
+            4,
+            &data,
+        )
+    }
+
+    // Messages to the window manager
+
+    /// Ask the window manager to switch to another desktop.
+    pub fn request_current_desktop(
+        &self,
+        desktop: u32,
+        time: Timestamp,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        self.send_message(
+            self.root,
+            self.atoms._NET_CURRENT_DESKTOP,
+            [desktop, time, 0, 0, 0],
+        )
+    }
+
+    /// Ask the window manager to change the number of desktops.
+    pub fn request_number_of_desktops(
+        &self,
+        number: u32,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        self.send_message(
+            self.root,
+            self.atoms._NET_NUMBER_OF_DESKTOPS,
+            [number, 0, 0, 0, 0],
+        )
+    }
+
+    /// Ask the window manager to show or hide the desktop.
+    pub fn request_showing_desktop(
+        &self,
+        showing: bool,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        self.send_message(
+            self.root,
+            self.atoms._NET_SHOWING_DESKTOP,
+            [showing.into(), 0, 0, 0, 0],
+        )
+    }
+
+    /// Ask the window manager to activate a window, i.e. to raise and focus it.
+    ///
+    /// `time` is the time of the user action that caused the request and `current` the window
+    /// that is currently active in the requesting application, or zero.
+    pub fn request_active_window(
+        &self,
+        window: Window,
+        source: SourceIndication,
+        time: Timestamp,
+        current: Window,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        self.send_message(
+            window,
+            self.atoms._NET_ACTIVE_WINDOW,
+            [source.into(), time, current, 0, 0],
+        )
+    }
+
+    /// Ask the window manager to close a window.
+    pub fn request_close_window(
+        &self,
+        window: Window,
+        time: Timestamp,
+        source: SourceIndication,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        self.send_message(
+            window,
+            self.atoms._NET_CLOSE_WINDOW,
+            [time, source.into(), 0, 0, 0],
+        )
+    }
+
+    /// Ask the window manager to move or resize a window.
+    ///
+    /// Only the position and size in `aux` are used; fields that are `None` are not changed.
+    /// `gravity` describes the reference point of the position, or the `win_gravity` of the
+    /// window if it is [`Gravity::BIT_FORGET`].
+    pub fn request_moveresize_window(
+        &self,
+        window: Window,
+        gravity: Gravity,
+        aux: &ConfigureWindowAux,
+        source: SourceIndication,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        let mut flags = u32::from(gravity) | u32::from(source) << 12;
+        let values = [
+            aux.x.map(|x| x as u32),
+            aux.y.map(|y| y as u32),
+            aux.width,
+            aux.height,
+        ];
+        let mut data = [0; 5];
+        for (index, value) in values.iter().enumerate() {
+            if let Some(value) = value {
+                flags |= 1 << (8 + index);
+                data[index + 1] = *value;
+            }
+        }
+        data[0] = flags;
+        self.send_message(window, self.atoms._NET_MOVERESIZE_WINDOW, data)
+    }
+
+    /// Ask the window manager to start moving or resizing a window interactively.
+    ///
+    /// This is usually sent when the user presses `button` at the root coordinates `(x_root,
+    /// y_root)` in a client-side decoration, after ungrabbing the pointer.
+    pub fn request_wm_moveresize(
+        &self,
+        window: Window,
+        (x_root, y_root): (i16, i16),
+        direction: MoveResizeDirection,
+        button: u8,
+        source: SourceIndication,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        self.send_message(
+            window,
+            self.atoms._NET_WM_MOVERESIZE,
+            [
+                x_root as u32,
+                y_root as u32,
+                direction.into(),
+                button.into(),
+                source.into(),
+            ],
+        )
+    }
+
+    /// Ask the window manager to restack a window relative to a sibling.
+    pub fn request_restack_window(
+        &self,
+        window: Window,
+        sibling: Window,
+        detail: StackMode,
+        source: SourceIndication,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        self.send_message(
+            window,
+            self.atoms._NET_RESTACK_WINDOW,
+            [source.into(), sibling, detail.into(), 0, 0],
+        )
+    }
+
+    /// Ask the window manager to set `_NET_FRAME_EXTENTS` on a window that is not mapped yet.
+    pub fn request_frame_extents(
+        &self,
+        window: Window,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        self.send_message(window, self.atoms._NET_REQUEST_FRAME_EXTENTS, [0; 5])
+    }
+
+    /// Ask the window manager to add, remove or toggle one or two states of a window.
+    ///
+    /// `second` is ignored if it is zero. Maximizing a window in both directions is done by
+    /// changing `_NET_WM_STATE_MAXIMIZED_VERT` and `_NET_WM_STATE_MAXIMIZED_HORZ` together.
+    pub fn request_wm_state(
+        &self,
+        window: Window,
+        action: StateAction,
+        first: Atom,
+        second: Atom,
+        source: SourceIndication,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        self.send_message(
+            window,
+            self.atoms._NET_WM_STATE,
+            [action.into(), first, second, source.into(), 0],
+        )
+    }
+
+    /// Ask the window manager to move a window to another desktop.
+    ///
+    /// The desktop `0xFFFFFFFF` shows the window on all desktops.
+    pub fn request_wm_desktop(
+        &self,
+        window: Window,
+        desktop: u32,
+        source: SourceIndication,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        self.send_message(
+            window,
+            self.atoms._NET_WM_DESKTOP,
+            [desktop, source.into(), 0, 0, 0],
+        )
+    }
+
+    /// Send a `GetProperty` request for a property with format 32.
+    fn get<T>(
+        &self,
+        window: Window,
+        property: Atom,
+        type_: AtomEnum,
+        parse: fn(&[u8]) -> Result<T, ParseError>,
+    ) -> Result<PropertyCookie<'c, C, T>, ConnectionError> {
+        self.get_property(window, property, type_.into(), 32, parse)
+    }
+
+    /// Send a `GetProperty` request for a property with a `UTF8_STRING` value.
+    fn get_utf8<T>(
+        &self,
+        window: Window,
+        property: Atom,
+        parse: fn(&[u8]) -> Result<T, ParseError>,
+    ) -> Result<PropertyCookie<'c, C, T>, ConnectionError> {
+        self.get_property(window, property, self.atoms.UTF8_STRING, 8, parse)
+    }
+
+    fn get_property<T>(
+        &self,
+        window: Window,
+        property: Atom,
+        type_: Atom,
+        format: u8,
+        parse: fn(&[u8]) -> Result<T, ParseError>,
+    ) -> Result<PropertyCookie<'c, C, T>, ConnectionError> {
+        let cookie = xproto::get_property(self.conn, false, window, property, type_, 0, u32::MAX)?;
+        Ok(PropertyCookie {
+            cookie,
+            type_,
+            format,
+            parse,
+        })
+    }
+
+    /// Replace a property with format 32.
+    fn set32(
+        &self,
+        window: Window,
+        property: Atom,
+        type_: AtomEnum,
+        data: &[u32],
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        self.conn
+            .change_property32(PropMode::REPLACE, window, property, type_, data)
+    }
+
+    /// Replace a property with a `UTF8_STRING` value.
+    fn set_utf8(
+        &self,
+        window: Window,
+        property: Atom,
+        data: &[u8],
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        self.conn.change_property8(
+            PropMode::REPLACE,
+            window,
+            property,
+            self.atoms.UTF8_STRING,
+            data,
+        )
+    }
+
+    /// Send a message about `window` to the window manager.
+    fn send_message(
+        &self,
+        window: Window,
+        type_: Atom,
+        data: [u32; 5],
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        let event = ClientMessageEvent::new(32, window, type_, data);
+        xproto::send_event(
+            self.conn,
+            false,
+            self.root,
+            EventMask::SUBSTRUCTURE_NOTIFY | EventMask::SUBSTRUCTURE_REDIRECT,
+            event,
+        )
+    }
+}
+
+/// Parse a property value that consists of a single `T`.
+fn parse_one<T: TryParse>(value: &[u8]) -> Result<T, ParseError> {
+    Ok(T::try_parse(value)?.0)
+}
+
+/// Parse a property value that consists of a list of `T`.
+fn parse_list<T: TryParse>(mut value: &[u8]) -> Result<Vec<T>, ParseError> {
+    let mut result = Vec::new();
+    while !value.is_empty() {
+        let (item, remaining) = T::try_parse(value)?;
+        result.push(item);
+        value = remaining;
+    }
+    Ok(result)
+}
+
+/// Parse a `UTF8_STRING` property.
+fn parse_string(value: &[u8]) -> Result<String, ParseError> {
+    String::from_utf8(value.to_vec()).map_err(|_| ParseError::InvalidValue)
+}
+
+/// Parse a `UTF8_STRING` property with a list of null-terminated strings.
+fn parse_strings(value: &[u8]) -> Result<Vec<String>, ParseError> {
+    let value = value.strip_suffix(&[0]).unwrap_or(value);
+    if value.is_empty() {
+        return Ok(Vec::new());
+    }
+    value.split(|byte| *byte == 0).map(parse_string).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        parse_list, parse_one, parse_reply, parse_strings, FrameExtents, StrutPartial, Workarea,
+    };
+    use crate::errors::ParseError;
+    use crate::protocol::xproto::{AtomEnum, GetPropertyReply};
+    use crate::x11_utils::Serialize;
+
+    fn cardinals(values: &[u32]) -> Vec<u8> {
+        values.iter().flat_map(|v| v.serialize()).collect()
+    }
+
+    #[test]
+    fn parse_cardinal_lists() {
+        let data = cardinals(&[1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(parse_list::<u32>(&data).unwrap(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(
+            parse_list::<(u32, u32)>(&data).unwrap(),
+            [(1, 2), (3, 4), (5, 6), (7, 8)]
+        );
+        let areas = parse_list::<Workarea>(&data).unwrap();
+        assert_eq!(areas[1].width, 7);
+        assert_eq!(
+            parse_list::<(u32, u32)>(&data[..12]),
+            Err(ParseError::InsufficientData)
+        );
+        assert_eq!(
+            parse_one::<FrameExtents>(&data[..16]).unwrap(),
+            FrameExtents {
+                left: 1,
+                right: 2,
+                top: 3,
+                bottom: 4,
+            }
+        );
+    }
+
+    #[test]
+    fn strut_partial_round_trip() {
+        let data = cardinals(&[0, 0, 30, 0, 0, 0, 0, 0, 100, 899, 0, 0]);
+        let strut = parse_one::<StrutPartial>(&data).unwrap();
+        assert_eq!(
+            (strut.top, strut.top_start_x, strut.top_end_x),
+            (30, 100, 899)
+        );
+        assert_eq!(strut.serialize(), data);
+    }
+
+    #[test]
+    fn desktop_names() {
+        assert_eq!(
+            parse_strings(b"One\0Two\0\0Four\0").unwrap(),
+            ["One", "Two", "", "Four"]
+        );
+        assert_eq!(parse_strings(b"One\0Two").unwrap(), ["One", "Two"]);
+        assert!(parse_strings(b"").unwrap().is_empty());
+        assert!(parse_strings(b"\xff\0").is_err());
+    }
+
+    #[test]
+    fn property_types() {
+        let reply = |type_: AtomEnum, format| GetPropertyReply {
+            format,
+            type_: type_.into(),
+            value: cardinals(&[42]),
+            ..Default::default()
+        };
+        let parse = |reply: GetPropertyReply| {
+            parse_reply(&reply, AtomEnum::CARDINAL.into(), 32, parse_one::<u32>)
+        };
+        assert_eq!(parse(reply(AtomEnum::CARDINAL, 32)), Ok(Some(42)));
+        assert_eq!(parse(reply(AtomEnum::NONE, 0)), Ok(None));
+        assert_eq!(
+            parse(reply(AtomEnum::WINDOW, 32)),
+            Err(ParseError::InvalidValue)
+        );
+        assert_eq!(
+            parse(reply(AtomEnum::CARDINAL, 8)),
+            Err(ParseError::InvalidValue)
+        );
+    }
+}
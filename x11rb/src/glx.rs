@@ -0,0 +1,474 @@
+//! Helpers for setting up GLX windows and contexts.
+//!
+//! Rendering with OpenGL through GLX requires to pick a framebuffer configuration (fbconfig) that
+//! matches the needs of the application, to create a window with the visual of that fbconfig, to
+//! create a GLX window and a context for it, and to make the context current. This module wraps
+//! these steps:
+//!
+//! * [`fb_configs`] lists the fbconfigs of a screen and [`choose_fb_config`] picks the one that
+//!   fits [`FbConfigRequirements`] best.
+//! * [`GlxWindow`] is a GLX drawable for an X11 window.
+//! * [`Context`] is a rendering context. GLX allows only one current context per connection;
+//!   [`CurrentContext`] keeps track of it for all contexts that are used on the same connection.
+//!
+//! All resources are freed when the wrappers are dropped. The contexts that are created here are
+//! indirect contexts, i.e. OpenGL commands are sent to the X11 server via the GLX protocol. For
+//! direct rendering, a client-side GL library like libGL is needed.
+//!
+//! The code in this module is only available when the `glx` feature of the library is enabled.
+
+use std::cell::Cell;
+
+use crate::connection::Connection;
+use crate::cookie::VoidCookie;
+use crate::errors::{ConnectionError, ReplyError, ReplyOrIdError};
+use crate::protocol::glx::{self, ContextTag, Fbconfig};
+use crate::protocol::xproto::{self, Visualid};
+use crate::NONE;
+
+/// The attribute for the number of bits of red in the color buffer.
+pub const RED_SIZE: u32 = 8;
+/// The attribute for the number of bits of green in the color buffer.
+pub const GREEN_SIZE: u32 = 9;
+/// The attribute for the number of bits of blue in the color buffer.
+pub const BLUE_SIZE: u32 = 10;
+/// The attribute for the number of bits of alpha in the color buffer.
+pub const ALPHA_SIZE: u32 = 11;
+/// The attribute for the number of bits in the depth buffer.
+pub const DEPTH_SIZE: u32 = 12;
+/// The attribute for the number of bits in the stencil buffer.
+pub const STENCIL_SIZE: u32 = 13;
+/// The attribute that is non-zero for double-buffered fbconfigs.
+pub const DOUBLEBUFFER: u32 = 5;
+/// The attribute for the number of samples per pixel for multisampling.
+pub const SAMPLES: u32 = 100_001;
+/// The attribute for the visual that windows with this fbconfig need.
+pub const VISUAL_ID: u32 = 0x800b;
+/// The attribute for the kinds of drawables that the fbconfig supports.
+pub const DRAWABLE_TYPE: u32 = 0x8010;
+/// The attribute for the kinds of contexts that the fbconfig supports.
+pub const RENDER_TYPE: u32 = 0x8011;
+/// The attribute for the XID of the fbconfig.
+pub const FBCONFIG_ID: u32 = 0x8013;
+
+const WINDOW_BIT: u32 = 0x0001;
+const RGBA_BIT: u32 = 0x0001;
+const RGBA_TYPE: u32 = 0x8014;
+const SWAP_INTERVAL_EXT: u32 = 0x20f1;
+const CONTEXT_MAJOR_VERSION_ARB: u32 = 0x2091;
+const CONTEXT_MINOR_VERSION_ARB: u32 = 0x2092;
+const CONTEXT_FLAGS_ARB: u32 = 0x2094;
+const CONTEXT_PROFILE_MASK_ARB: u32 = 0x9126;
+const CONTEXT_DEBUG_BIT_ARB: u32 = 0x0001;
+const CONTEXT_CORE_PROFILE_BIT_ARB: u32 = 0x0001;
+const CONTEXT_COMPATIBILITY_PROFILE_BIT_ARB: u32 = 0x0002;
+
+/// A framebuffer configuration of a screen.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FbConfig {
+    screen: u32,
+    attribs: Vec<(u32, u32)>,
+}
+
+impl FbConfig {
+    /// Get the value of an attribute.
+    pub fn get(&self, attrib: u32) -> Option<u32> {
+        self.attribs
+            .iter()
+            .find(|(name, _)| *name == attrib)
+            .map(|(_, value)| *value)
+    }
+
+    /// Get the screen that this fbconfig belongs to.
+    pub fn screen(&self) -> u32 {
+        self.screen
+    }
+
+    /// Get the XID of this fbconfig.
+    pub fn id(&self) -> Fbconfig {
+        self.get(FBCONFIG_ID).unwrap_or(NONE)
+    }
+
+    /// Get the visual that windows need to use this fbconfig, if any.
+    pub fn visual(&self) -> Option<Visualid> {
+        self.get(VISUAL_ID).filter(|visual| *visual != NONE)
+    }
+
+    /// Check whether this fbconfig can be used for RGBA rendering into windows.
+    pub fn supports_rgba_windows(&self) -> bool {
+        let has_bit = |attrib, bit| self.get(attrib).map_or(false, |value| value & bit != 0);
+        self.visual().is_some()
+            && has_bit(DRAWABLE_TYPE, WINDOW_BIT)
+            && has_bit(RENDER_TYPE, RGBA_BIT)
+    }
+}
+
+/// Get the fbconfigs of a screen.
+pub fn fb_configs<C: Connection>(conn: &C, screen: u32) -> Result<Vec<FbConfig>, ReplyError> {
+    let reply = glx::get_fb_configs(conn, screen)?.reply()?;
+    let per_config = usize::try_from(reply.num_properties).unwrap() * 2;
+    if per_config == 0 {
+        return Ok(Vec::new());
+    }
+    Ok(reply
+        .property_list
+        .chunks_exact(per_config)
+        .map(|properties| FbConfig {
+            screen,
+            attribs: properties
+                .chunks_exact(2)
+                .map(|pair| (pair[0], pair[1]))
+                .collect(),
+        })
+        .collect())
+}
+
+/// The minimal requirements for an fbconfig, see [`choose_fb_config`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FbConfigRequirements {
+    /// The minimal number of bits of red.
+    pub red: u32,
+    /// The minimal number of bits of green.
+    pub green: u32,
+    /// The minimal number of bits of blue.
+    pub blue: u32,
+    /// The minimal number of bits of alpha.
+    pub alpha: u32,
+    /// The minimal number of bits in the depth buffer.
+    pub depth: u32,
+    /// The minimal number of bits in the stencil buffer.
+    pub stencil: u32,
+    /// The minimal number of samples per pixel.
+    pub samples: u32,
+    /// Whether the fbconfig must be double-buffered.
+    pub double_buffer: bool,
+}
+
+impl Default for FbConfigRequirements {
+    /// Double-buffered with 8 bits per color channel and a 24 bit depth buffer.
+    fn default() -> Self {
+        Self {
+            red: 8,
+            green: 8,
+            blue: 8,
+            alpha: 0,
+            depth: 24,
+            stencil: 0,
+            samples: 0,
+            double_buffer: true,
+        }
+    }
+}
+
+/// Choose the fbconfig that fits the requirements best.
+///
+/// Only fbconfigs that support RGBA rendering into windows and that meet all requirements are
+/// considered. Among those, the fbconfig that wastes the fewest bits is chosen.
+pub fn choose_fb_config<'a>(
+    configs: &'a [FbConfig],
+    requirements: &FbConfigRequirements,
+) -> Option<&'a FbConfig> {
+    let minimums = [
+        (RED_SIZE, requirements.red),
+        (GREEN_SIZE, requirements.green),
+        (BLUE_SIZE, requirements.blue),
+        (ALPHA_SIZE, requirements.alpha),
+        (DEPTH_SIZE, requirements.depth),
+        (STENCIL_SIZE, requirements.stencil),
+        (SAMPLES, requirements.samples),
+    ];
+    configs
+        .iter()
+        .filter(|config| config.supports_rgba_windows())
+        .filter(|config| (config.get(DOUBLEBUFFER).unwrap_or(0) != 0) == requirements.double_buffer)
+        .filter_map(|config| {
+            let mut waste = 0;
+            for (attrib, minimum) in minimums {
+                let value = config.get(attrib).unwrap_or(0);
+                if value < minimum {
+                    return None;
+                }
+                waste += value - minimum;
+            }
+            Some((waste, config))
+        })
+        .min_by_key(|(waste, _)| *waste)
+        .map(|(_, config)| config)
+}
+
+/// A GLX drawable for an X11 window.
+///
+/// The window must have been created with the visual of the fbconfig. The GLX window is destroyed
+/// when this is dropped, but the X11 window is not.
+#[derive(Debug)]
+pub struct GlxWindow<'c, C: Connection> {
+    conn: &'c C,
+    window: glx::Window,
+}
+
+impl<'c, C: Connection> GlxWindow<'c, C> {
+    /// Create a GLX window for an X11 window.
+    pub fn new(
+        conn: &'c C,
+        config: &FbConfig,
+        window: xproto::Window,
+    ) -> Result<Self, ReplyOrIdError> {
+        let glx_window = conn.generate_id()?;
+        let _ = glx::create_window(conn, config.screen, config.id(), window, glx_window, &[])?;
+        Ok(Self {
+            conn,
+            window: glx_window,
+        })
+    }
+
+    /// Get the XID of the GLX window.
+    pub fn glx_window(&self) -> glx::Window {
+        self.window
+    }
+
+    /// Set the minimal number of video frames between buffer swaps.
+    ///
+    /// An interval of 0 disables the synchronisation with the vertical retrace. This sets the
+    /// attribute of `GLX_EXT_swap_control` in the X11 server.
+    pub fn set_swap_interval(&self, interval: u32) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        glx::change_drawable_attributes(self.conn, self.window, &[SWAP_INTERVAL_EXT, interval])
+    }
+}
+
+impl<C: Connection> Drop for GlxWindow<'_, C> {
+    fn drop(&mut self) {
+        let _ = glx::delete_window(self.conn, self.window);
+    }
+}
+
+/// The OpenGL profile of a context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Profile {
+    /// Only the functionality of the requested OpenGL version.
+    Core,
+    /// The functionality of the requested version and of all older versions.
+    Compatibility,
+}
+
+/// The properties of a context that is created with `GLX_ARB_create_context`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContextAttribs {
+    /// The requested OpenGL version as `(major, minor)`.
+    pub version: (u32, u32),
+    /// The requested OpenGL profile.
+    pub profile: Profile,
+    /// Whether a debug context is requested.
+    pub debug: bool,
+}
+
+impl Default for ContextAttribs {
+    /// An OpenGL 3.3 core profile context.
+    fn default() -> Self {
+        Self {
+            version: (3, 3),
+            profile: Profile::Core,
+            debug: false,
+        }
+    }
+}
+
+impl ContextAttribs {
+    fn to_list(self) -> [u32; 10] {
+        let profile = match self.profile {
+            Profile::Core => CONTEXT_CORE_PROFILE_BIT_ARB,
+            Profile::Compatibility => CONTEXT_COMPATIBILITY_PROFILE_BIT_ARB,
+        };
+        let flags = if self.debug { CONTEXT_DEBUG_BIT_ARB } else { 0 };
+        [
+            CONTEXT_MAJOR_VERSION_ARB,
+            self.version.0,
+            CONTEXT_MINOR_VERSION_ARB,
+            self.version.1,
+            CONTEXT_PROFILE_MASK_ARB,
+            profile,
+            CONTEXT_FLAGS_ARB,
+            flags,
+            RENDER_TYPE,
+            RGBA_TYPE,
+        ]
+    }
+}
+
+/// The context that is current on a connection.
+///
+/// The X11 server identifies the current context with a context tag that has to be passed when
+/// another context is made current. All [`Context`]s that are used on the same connection have to
+/// share one `CurrentContext`, so that switching between them releases the previous context.
+#[derive(Debug, Default)]
+pub struct CurrentContext {
+    context: Cell<glx::Context>,
+    tag: Cell<ContextTag>,
+}
+
+impl CurrentContext {
+    /// Create a tracker for a connection without a current context.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Get the current context and its context tag, if any.
+    pub fn get(&self) -> Option<(glx::Context, ContextTag)> {
+        Some((self.context.get(), self.tag.get())).filter(|(_, tag)| *tag != 0)
+    }
+
+    fn set(&self, context: glx::Context, tag: ContextTag) {
+        self.context.set(context);
+        self.tag.set(tag);
+    }
+}
+
+/// An OpenGL rendering context.
+///
+/// If the context is still current when this is dropped, it is released before it is destroyed.
+#[derive(Debug)]
+pub struct Context<'c, C: Connection> {
+    conn: &'c C,
+    current: &'c CurrentContext,
+    context: glx::Context,
+}
+
+impl<'c, C: Connection> Context<'c, C> {
+    /// Create a context with `GLX_ARB_create_context`.
+    ///
+    /// `current` tracks the current context of `conn`. If `share` is given, the new context
+    /// shares its display lists and textures with it.
+    pub fn new(
+        conn: &'c C,
+        current: &'c CurrentContext,
+        config: &FbConfig,
+        attribs: &ContextAttribs,
+        share: Option<&Context<'_, C>>,
+    ) -> Result<Self, ReplyOrIdError> {
+        let context = conn.generate_id()?;
+        let share = share.map_or(NONE, Context::context);
+        let _ = glx::create_context_attribs_arb(
+            conn,
+            context,
+            config.id(),
+            config.screen,
+            share,
+            false,
+            &attribs.to_list(),
+        )?;
+        Ok(Self {
+            conn,
+            current,
+            context,
+        })
+    }
+
+    /// Get the XID of the context.
+    pub fn context(&self) -> glx::Context {
+        self.context
+    }
+
+    /// Get the context tag if the context is current.
+    pub fn context_tag(&self) -> Option<ContextTag> {
+        self.current
+            .get()
+            .filter(|(context, _)| *context == self.context)
+            .map(|(_, tag)| tag)
+    }
+
+    /// Make the context current for drawing into and reading from `window`.
+    ///
+    /// The context that was current before is released.
+    pub fn make_current(&self, window: &GlxWindow<'_, C>) -> Result<ContextTag, ReplyError> {
+        let old_tag = self.current.get().map_or(0, |(_, tag)| tag);
+        let reply = glx::make_context_current(
+            self.conn,
+            old_tag,
+            window.glx_window(),
+            window.glx_window(),
+            self.context,
+        )?
+        .reply()?;
+        self.current.set(self.context, reply.context_tag);
+        Ok(reply.context_tag)
+    }
+
+    /// Release the context if it is current.
+    pub fn release(&self) -> Result<(), ReplyError> {
+        if let Some(tag) = self.context_tag() {
+            let _ = glx::make_context_current(self.conn, tag, NONE, NONE, NONE)?.reply()?;
+            self.current.set(NONE, 0);
+        }
+        Ok(())
+    }
+
+    /// Show the content of the back buffer of `window`.
+    ///
+    /// Pending OpenGL commands of this context are executed before if the context is current.
+    pub fn swap_buffers(
+        &self,
+        window: &GlxWindow<'_, C>,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        let tag = self.context_tag().unwrap_or(0);
+        glx::swap_buffers(self.conn, tag, window.glx_window())
+    }
+}
+
+impl<C: Connection> Drop for Context<'_, C> {
+    fn drop(&mut self) {
+        if let Some(tag) = self.context_tag() {
+            let _ = glx::make_context_current(self.conn, tag, NONE, NONE, NONE);
+            self.current.set(NONE, 0);
+        }
+        let _ = glx::destroy_context(self.conn, self.context);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{choose_fb_config, FbConfig, FbConfigRequirements};
+
+    fn config(id: u32, sizes: [u32; 6], double_buffer: bool) -> FbConfig {
+        let [red, green, blue, alpha, depth, stencil] = sizes;
+        FbConfig {
+            screen: 0,
+            attribs: vec![
+                (super::FBCONFIG_ID, id),
+                (super::VISUAL_ID, 0x21),
+                (super::DRAWABLE_TYPE, 0x7),
+                (super::RENDER_TYPE, 0x1),
+                (super::DOUBLEBUFFER, u32::from(double_buffer)),
+                (super::RED_SIZE, red),
+                (super::GREEN_SIZE, green),
+                (super::BLUE_SIZE, blue),
+                (super::ALPHA_SIZE, alpha),
+                (super::DEPTH_SIZE, depth),
+                (super::STENCIL_SIZE, stencil),
+            ],
+        }
+    }
+
+    #[test]
+    fn choose_smallest_matching_config() {
+        let configs = [
+            config(1, [8, 8, 8, 8, 24, 8], true),
+            config(2, [8, 8, 8, 0, 24, 0], false),
+            config(3, [8, 8, 8, 0, 24, 0], true),
+            config(4, [5, 6, 5, 0, 16, 0], true),
+        ];
+        let chosen = choose_fb_config(&configs, &FbConfigRequirements::default());
+        assert_eq!(chosen.map(FbConfig::id), Some(3));
+
+        let requirements = FbConfigRequirements {
+            stencil: 8,
+            ..Default::default()
+        };
+        let chosen = choose_fb_config(&configs, &requirements);
+        assert_eq!(chosen.map(FbConfig::id), Some(1));
+
+        let requirements = FbConfigRequirements {
+            depth: 32,
+            ..Default::default()
+        };
+        assert_eq!(choose_fb_config(&configs, &requirements), None);
+    }
+}
@@ -8,7 +8,13 @@
 //! An [`Image`] can be converted to a different internal representation. [`Image::native`]
 //! converts it to the native format of the X11 server. These conversions do not change the actual
 //! content of the image, but only the way that it is laid out in memory (e.g. byte order and
-//! padding). Specifically, there is no support for converting an image to another `depth`.
+//! padding). [`Image::convert_to_format`] additionally converts between different depths and
+//! color masks, as described by a [`PixelFormat`]. [`PixelFormat::for_visual`] describes the
+//! format that the X11 server expects for a visual, including servers with a different byte order.
+//!
+//! With the `image-interop` feature, images can be converted from and to the types of the
+//! [`image`](https://docs.rs/image) crate, see [`Image::to_rgba_image`] and
+//! [`Image::from_rgba_image`].
 //!
 //! The code in this module is only available when the `image` feature of the library is
 //! enabled.
@@ -25,13 +31,14 @@
 //   begins with the second bit of each pixel etc.
 
 use std::borrow::Cow;
+use std::fmt;
 
 use crate::connection::Connection;
 use crate::cookie::VoidCookie;
 use crate::errors::{ConnectionError, ParseError, ReplyError};
 use crate::protocol::xproto::{
     get_image, put_image, Drawable, Format, Gcontext, GetImageReply, ImageFormat,
-    ImageOrder as XprotoImageOrder, Setup, VisualClass, Visualid, Visualtype,
+    ImageOrder as XprotoImageOrder, Rectangle, Setup, VisualClass, Visualid, Visualtype,
 };
 
 /// The description of a single color component.
@@ -126,6 +133,11 @@ impl ColorComponent {
     /// # Ok::<(), x11rb::errors::ParseError>(())
     /// ```
     pub fn decode(self, pixel: u32) -> u16 {
+        // Components without any bits carry no information
+        if self.width == 0 {
+            return 0;
+        }
+
         // Get the color component out
         let value = (pixel & self.mask()) >> self.shift;
 
@@ -244,6 +256,12 @@ fn compute_stride(width: u16, bits_per_pixel: BitsPerPixel, scanline_pad: Scanli
     scanline_pad.round_to_multiple(value) / 8
 }
 
+// Compute the position of a piece of an image that is uploaded with its own request
+fn piece_position(position: i16, offset: u16) -> Result<i16, ConnectionError> {
+    i16::try_from(i32::from(position) + i32::from(offset))
+        .map_err(|_| ConnectionError::MaximumRequestLengthExceeded)
+}
+
 #[cfg(test)]
 mod test_stride {
     use super::compute_stride;
@@ -480,6 +498,87 @@ impl TryFrom<XprotoImageOrder> for ImageOrder {
     }
 }
 
+/// The memory that holds the pixels of an [`Image`].
+enum ImageData<'a> {
+    /// Memory that belongs to the image.
+    Owned(Vec<u8>),
+
+    /// Memory that is borrowed read-only and copied before it is changed.
+    Borrowed(&'a [u8]),
+
+    /// Memory that is borrowed and changed in place.
+    BorrowedMut(&'a mut [u8]),
+}
+
+impl ImageData<'_> {
+    /// Get mutable access to the data, copying borrowed read-only data.
+    fn to_mut(&mut self) -> &mut [u8] {
+        if let ImageData::Borrowed(data) = self {
+            *self = ImageData::Owned(data.to_vec());
+        }
+        match self {
+            ImageData::Owned(data) => data,
+            ImageData::BorrowedMut(data) => data,
+            ImageData::Borrowed(_) => unreachable!(),
+        }
+    }
+
+    /// Get the data as a vector, copying borrowed data.
+    fn into_owned(self) -> Vec<u8> {
+        match self {
+            ImageData::Owned(data) => data,
+            ImageData::Borrowed(data) => data.to_vec(),
+            ImageData::BorrowedMut(data) => data.to_vec(),
+        }
+    }
+}
+
+impl std::ops::Deref for ImageData<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            ImageData::Owned(data) => data,
+            ImageData::Borrowed(data) => data,
+            ImageData::BorrowedMut(data) => data,
+        }
+    }
+}
+
+impl Clone for ImageData<'_> {
+    fn clone(&self) -> Self {
+        match self {
+            ImageData::Owned(data) => ImageData::Owned(data.clone()),
+            ImageData::Borrowed(data) => ImageData::Borrowed(data),
+            // Mutable borrows cannot be shared, so the clone gets a copy
+            ImageData::BorrowedMut(data) => ImageData::Owned(data.to_vec()),
+        }
+    }
+}
+
+impl PartialEq for ImageData<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl Eq for ImageData<'_> {}
+
+impl fmt::Debug for ImageData<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+impl<'a> From<Cow<'a, [u8]>> for ImageData<'a> {
+    fn from(data: Cow<'a, [u8]>) -> Self {
+        match data {
+            Cow::Owned(data) => ImageData::Owned(data),
+            Cow::Borrowed(data) => ImageData::Borrowed(data),
+        }
+    }
+}
+
 /// The description of an image.
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Image<'a> {
@@ -503,8 +602,16 @@ pub struct Image<'a> {
     /// This is the nibble order when bits_per_pixel is 4.
     byte_order: ImageOrder,
 
+    /// Order of the bits in a byte when bits_per_pixel is 1.
+    bit_order: ImageOrder,
+
+    /// The number of bytes between the starts of two scanlines.
+    ///
+    /// This is computed from the other fields unless the image wraps external memory.
+    stride: usize,
+
     /// The image data.
-    data: Cow<'a, [u8]>,
+    data: ImageData<'a>,
 }
 
 impl<'a> Image<'a> {
@@ -545,6 +652,36 @@ impl<'a> Image<'a> {
         self.byte_order
     }
 
+    /// Order in which the pixels of an image with one bit per pixel are packed into bytes.
+    ///
+    /// This is the same as [`Image::byte_order`] unless it was changed via
+    /// [`Image::with_bit_order`] or the image is in the native format of an X11 server.
+    pub fn bit_order(&self) -> ImageOrder {
+        self.bit_order
+    }
+
+    /// Change the order in which the pixels of an image with one bit per pixel are packed into
+    /// bytes.
+    ///
+    /// This only changes how the image data is interpreted; the data itself stays the same. The
+    /// X11 server describes its bit order via `Setup::bitmap_format_bit_order`, which may differ
+    /// from its byte order.
+    pub fn with_bit_order(self, bit_order: ImageOrder) -> Self {
+        Self { bit_order, ..self }
+    }
+
+    /// Get the format of this image, without color masks.
+    pub fn format(&self) -> PixelFormat {
+        PixelFormat {
+            depth: self.depth,
+            bits_per_pixel: self.bits_per_pixel,
+            scanline_pad: self.scanline_pad,
+            byte_order: self.byte_order,
+            bit_order: self.bit_order,
+            layout: None,
+        }
+    }
+
     /// Raw pixel data.
     pub fn data(&self) -> &[u8] {
         &self.data
@@ -553,11 +690,20 @@ impl<'a> Image<'a> {
     /// Mutable access to the raw pixel data.
     ///
     /// If the `Image` was constructed with `Cow::Borrowed`-access to its pixel data, then a copy
-    /// is made when this method is called.
+    /// is made when this method is called. Data from [`Image::from_mut_slice`] is changed in
+    /// place.
     pub fn data_mut(&mut self) -> &mut [u8] {
         self.data.to_mut()
     }
 
+    /// The number of bytes between the starts of two scanlines.
+    ///
+    /// This follows from the width, the bits per pixel and the scanline padding, unless the image
+    /// was created with [`Image::from_slice`] or [`Image::from_mut_slice`].
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
     /// Construct a new image from existing data.
     ///
     /// This constructs a new `Image` from given `data` without copying this data around. The other
@@ -592,7 +738,9 @@ impl<'a> Image<'a> {
                 depth,
                 bits_per_pixel,
                 byte_order,
-                data,
+                bit_order: byte_order,
+                stride,
+                data: data.into(),
             })
         }
     }
@@ -614,7 +762,7 @@ impl<'a> Image<'a> {
         byte_order: ImageOrder,
     ) -> Self {
         let stride = compute_stride(width, bits_per_pixel, scanline_pad);
-        let data = Cow::Owned(vec![0; usize::from(height) * stride]);
+        let data = ImageData::Owned(vec![0; usize::from(height) * stride]);
         Self {
             width,
             height,
@@ -622,6 +770,8 @@ impl<'a> Image<'a> {
             depth,
             bits_per_pixel,
             byte_order,
+            bit_order: byte_order,
+            stride,
             data,
         }
     }
@@ -640,20 +790,109 @@ impl<'a> Image<'a> {
         depth: u8,
         setup: &Setup,
     ) -> Result<Self, ParseError> {
-        let format = find_format(setup, depth)?;
-        Ok(Self::allocate(
+        let format = PixelFormat::native(setup, depth)?;
+        Ok(Self::allocate_format(width, height, &format))
+    }
+
+    /// Construct a new image in the given format from existing data.
+    pub(crate) fn with_format(
+        width: u16,
+        height: u16,
+        format: &PixelFormat,
+        data: Cow<'a, [u8]>,
+    ) -> Result<Self, ParseError> {
+        Ok(Self::new(
             width,
             height,
-            format.scanline_pad.try_into()?,
-            depth,
-            format.bits_per_pixel.try_into()?,
-            setup.image_byte_order.try_into()?,
-        ))
+            format.scanline_pad,
+            format.depth,
+            format.bits_per_pixel,
+            format.byte_order,
+            data,
+        )?
+        .with_bit_order(format.bit_order))
+    }
+
+    /// Wrap external memory in an image without copying it.
+    ///
+    /// The pixels are stored in `format`, but each scanline starts `stride` bytes after the start
+    /// of the previous one instead of following the scanline padding of `format`. This allows to
+    /// use buffers of other libraries directly. Uploading the image with [`Image::put`] copies at
+    /// most one request worth of scanlines at a time when the stride differs from the one that
+    /// the X11 server expects. Changing the pixels copies the memory, see
+    /// [`Image::from_mut_slice`] for changing it in place.
+    ///
+    /// # Errors
+    ///
+    /// This fails with [`ParseError::InvalidValue`] if a scanline does not fit into `stride`
+    /// bytes and with [`ParseError::InsufficientData`] if `data` is shorter than `height`
+    /// scanlines.
+    pub fn from_slice(
+        width: u16,
+        height: u16,
+        stride: usize,
+        format: &PixelFormat,
+        data: &'a [u8],
+    ) -> Result<Self, ParseError> {
+        Self::with_stride(width, height, stride, format, ImageData::Borrowed(data))
+    }
+
+    /// Wrap external memory in an image that changes it in place.
+    ///
+    /// This works like [`Image::from_slice`], but functions like [`Image::put_pixel`] and
+    /// [`Image::data_mut`] write directly into `data`.
+    pub fn from_mut_slice(
+        width: u16,
+        height: u16,
+        stride: usize,
+        format: &PixelFormat,
+        data: &'a mut [u8],
+    ) -> Result<Self, ParseError> {
+        Self::with_stride(width, height, stride, format, ImageData::BorrowedMut(data))
     }
 
-    /// The stride is the number of bytes that each row of pixel data occupies in memory.
-    fn stride(&self) -> usize {
-        compute_stride(self.width, self.bits_per_pixel, self.scanline_pad)
+    fn with_stride(
+        width: u16,
+        height: u16,
+        stride: usize,
+        format: &PixelFormat,
+        data: ImageData<'a>,
+    ) -> Result<Self, ParseError> {
+        if stride < compute_stride(width, format.bits_per_pixel, ScanlinePad::Pad8) {
+            return Err(ParseError::InvalidValue);
+        }
+        if data.len() < usize::from(height) * stride {
+            return Err(ParseError::InsufficientData);
+        }
+        Ok(Self {
+            width,
+            height,
+            scanline_pad: format.scanline_pad,
+            depth: format.depth,
+            bits_per_pixel: format.bits_per_pixel,
+            byte_order: format.byte_order,
+            bit_order: format.bit_order,
+            stride,
+            data,
+        })
+    }
+
+    /// Check whether the scanlines have the length that follows from the scanline padding.
+    fn has_padded_stride(&self) -> bool {
+        self.stride == compute_stride(self.width, self.bits_per_pixel, self.scanline_pad)
+    }
+
+    /// Construct a new, empty image in the given format.
+    fn allocate_format(width: u16, height: u16, format: &PixelFormat) -> Self {
+        Self::allocate(
+            width,
+            height,
+            format.scanline_pad,
+            format.depth,
+            format.bits_per_pixel,
+            format.byte_order,
+        )
+        .with_bit_order(format.bit_order)
     }
 
     /// Get an image from the X11 server.
@@ -688,6 +927,33 @@ impl<'a> Image<'a> {
         Ok((image, visual))
     }
 
+    /// Get an image from the X11 server and normalize it to RGBA.
+    ///
+    /// This function works like [`Image::get`] for the rectangle `rect` of `drawable`, but
+    /// converts the result with [`Image::to_rgba8888`]. The color masks are taken from the visual
+    /// of the drawable. Pixmaps do not have a visual, so the first `TrueColor` or `DirectColor`
+    /// visual with the depth of the pixmap is used for them. Like with [`Image::to_rgba8888`],
+    /// the color components are kept as they are stored, also if the visual uses premultiplied
+    /// alpha.
+    ///
+    /// This function fails with [`ParseError::InvalidValue`] if no suitable visual exists, e.g.
+    /// for drawables with a `PseudoColor` visual.
+    pub fn get_with_format(
+        conn: &impl Connection,
+        drawable: Drawable,
+        rect: Rectangle,
+    ) -> Result<Image<'static>, ReplyError> {
+        let (image, visual) = Self::get(conn, drawable, rect.x, rect.y, rect.width, rect.height)?;
+        let setup = conn.setup();
+        let layout = PixelFormat::for_visual(setup, visual)
+            .ok()
+            .filter(|format| format.depth == image.depth)
+            .or_else(|| find_true_color_format(setup, image.depth))
+            .and_then(|format| format.layout)
+            .ok_or(ParseError::InvalidValue)?;
+        Ok(image.to_rgba8888(layout))
+    }
+
     /// Construct an `Image` from a `GetImageReply`.
     ///
     /// This function takes a `GetImageReply` and wraps it in an `Image`. The given `width` and
@@ -699,16 +965,8 @@ impl<'a> Image<'a> {
         height: u16,
         reply: GetImageReply,
     ) -> Result<Self, ParseError> {
-        let format = find_format(setup, reply.depth)?;
-        Self::new(
-            width,
-            height,
-            format.scanline_pad.try_into()?,
-            reply.depth,
-            format.bits_per_pixel.try_into()?,
-            setup.image_byte_order.try_into()?,
-            Cow::Owned(reply.data),
-        )
+        let format = PixelFormat::native(setup, reply.depth)?;
+        Self::with_format(width, height, &format, Cow::Owned(reply.data))
     }
 
     /// Put an image to the X11 server.
@@ -717,8 +975,12 @@ impl<'a> Image<'a> {
     /// will upload this image to the given `drawable` to position `(dst_x, dst_y)`.
     ///
     /// The server's maximum request size is honored. This means that a too large `PutImage`
-    /// request is automatically split up into smaller pieces. Thus, if this function returns an
-    /// error, the image could already be partially sent.
+    /// request is automatically split up into smaller pieces along scanline boundaries. If even a
+    /// single scanline is too large, e.g. on servers without the BIG-REQUESTS extension, the image
+    /// is additionally split into vertical strips. Thus, if this function returns an error, the
+    /// image could already be partially sent. However, if the position of a piece does not fit
+    /// into an `i16`, [`ConnectionError::MaximumRequestLengthExceeded`] is returned before
+    /// anything is sent.
     ///
     /// Before uploading, the image is translated into the server's native format via
     /// [`Image::native`]. This may convert the image to another format, which can be slow. If you
@@ -732,8 +994,14 @@ impl<'a> Image<'a> {
         dst_x: i16,
         dst_y: i16,
     ) -> Result<Vec<VoidCookie<'c, Conn>>, ConnectionError> {
-        self.native(conn.setup())?
-            .put_impl(conn, drawable, gc, dst_x, dst_y)
+        let native = PixelFormat::native(conn.setup(), self.depth)?;
+        if self.has_layout(&native) {
+            // put_impl() takes care of a stride that differs from the scanline padding
+            self.put_impl(conn, drawable, gc, dst_x, dst_y)
+        } else {
+            self.relayout(&native)
+                .put_impl(conn, drawable, gc, dst_x, dst_y)
+        }
     }
 
     fn put_impl<'c, Conn: Connection>(
@@ -745,21 +1013,39 @@ impl<'a> Image<'a> {
         dst_y: i16,
     ) -> Result<Vec<VoidCookie<'c, Conn>>, ConnectionError> {
         // Upload the image without exceeding the server's maximum request size
-        let max_bytes = conn.maximum_request_bytes();
         let put_image_header = 24;
-        let stride = self.stride();
-        let lines_per_request = (max_bytes - put_image_header) / stride;
+        let max_bytes = conn.maximum_request_bytes() - put_image_header;
+        let stride = compute_stride(self.width, self.bits_per_pixel, self.scanline_pad);
+        if stride > max_bytes {
+            return self.put_strips(conn, drawable, gc, dst_x, dst_y, max_bytes);
+        }
+        let lines_per_request = max_bytes / stride.max(1);
         let mut result = Vec::with_capacity(
             (usize::from(self.height()) + lines_per_request - 1) / lines_per_request,
         );
         let lines_per_request = lines_per_request.try_into().unwrap_or(u16::MAX);
-        assert!(lines_per_request > 0);
+        // Check that the position of the last band fits before anything is sent
+        let last_band = self.height.saturating_sub(1) / lines_per_request * lines_per_request;
+        let _ = piece_position(dst_y, last_band)?;
 
         let (mut y_offset, mut byte_offset) = (0, 0);
         while y_offset < self.height {
             let next_lines = lines_per_request.min(self.height - y_offset);
-            let next_byte_offset = byte_offset + usize::from(next_lines) * stride;
-            let data = &self.data[byte_offset..next_byte_offset];
+            let next_byte_offset = byte_offset + usize::from(next_lines) * self.stride;
+            let data = if self.has_padded_stride() {
+                Cow::Borrowed(&self.data[byte_offset..next_byte_offset])
+            } else {
+                // Copy the scanlines of this request to remove the extra padding
+                let mut data = vec![0; usize::from(next_lines) * stride];
+                let len = compute_stride(self.width, self.bits_per_pixel, ScanlinePad::Pad8);
+                for (dst, src) in data
+                    .chunks_exact_mut(stride.max(1))
+                    .zip(self.data[byte_offset..].chunks(self.stride.max(1)))
+                {
+                    dst[..len].copy_from_slice(&src[..len]);
+                }
+                Cow::Owned(data)
+            };
             result.push(put_image(
                 conn,
                 ImageFormat::Z_PIXMAP,
@@ -768,10 +1054,10 @@ impl<'a> Image<'a> {
                 self.width,
                 next_lines,
                 dst_x,
-                dst_y + i16::try_from(y_offset).unwrap(),
+                piece_position(dst_y, y_offset)?,
                 0, // left_pad must always be 0 for ZPixmap
                 self.depth,
-                data,
+                &data,
             )?);
 
             y_offset += next_lines;
@@ -780,6 +1066,44 @@ impl<'a> Image<'a> {
         Ok(result)
     }
 
+    /// Upload an image whose scanlines are too long for a single request in vertical strips.
+    fn put_strips<'c, Conn: Connection>(
+        &self,
+        conn: &'c Conn,
+        drawable: Drawable,
+        gc: Gcontext,
+        dst_x: i16,
+        dst_y: i16,
+        max_bytes: usize,
+    ) -> Result<Vec<VoidCookie<'c, Conn>>, ConnectionError> {
+        // Leave room for the scanline padding, which is at most four bytes
+        let max_width = max_bytes.saturating_sub(4) * 8 / usize::from(self.bits_per_pixel);
+        let max_width = u16::try_from(max_width).unwrap_or(u16::MAX);
+        if max_width == 0 {
+            return Err(ConnectionError::MaximumRequestLengthExceeded);
+        }
+        // Check that the position of the last strip fits before anything is sent. The position
+        // of the bands of each strip is checked by the first call to put_impl().
+        let last_strip = self.width.saturating_sub(1) / max_width * max_width;
+        let _ = piece_position(dst_x, last_strip)?;
+        let format = self.format();
+        let mut result = Vec::new();
+        let mut x_offset = 0;
+        while x_offset < self.width {
+            let width = max_width.min(self.width - x_offset);
+            let mut strip = Image::allocate_format(width, self.height, &format);
+            for y in 0..self.height {
+                for x in 0..width {
+                    strip.put_pixel(x, y, self.get_pixel(x_offset + x, y));
+                }
+            }
+            let strip_x = piece_position(dst_x, x_offset)?;
+            result.extend(strip.put_impl(conn, drawable, gc, strip_x, dst_y)?);
+            x_offset += width;
+        }
+        Ok(result)
+    }
+
     /// Convert this image into the format specified by the other parameters.
     ///
     /// This function may need to copy the image, hence returns a `Cow`.
@@ -789,20 +1113,25 @@ impl<'a> Image<'a> {
         bits_per_pixel: BitsPerPixel,
         byte_order: ImageOrder,
     ) -> Cow<'_, Self> {
-        let already_converted = scanline_pad == self.scanline_pad
-            && bits_per_pixel == self.bits_per_pixel
-            && byte_order == self.byte_order;
-        if already_converted {
-            Cow::Borrowed(self)
-        } else {
-            let mut copy = Image::allocate(
-                self.width,
-                self.height,
-                scanline_pad,
-                self.depth,
-                bits_per_pixel,
-                byte_order,
-            );
+        let format = PixelFormat {
+            scanline_pad,
+            bits_per_pixel,
+            byte_order,
+            ..self.format()
+        };
+        self.relayout(&format)
+    }
+
+    /// Copy the pixel values into an image with the given memory layout, if necessary.
+    ///
+    /// The depth and the color masks of `format` are ignored.
+    fn relayout(&self, format: &PixelFormat) -> Cow<'_, Self> {
+        let format = PixelFormat {
+            depth: self.depth,
+            ..*format
+        };
+        if !self.has_layout(&format) {
+            let mut copy = Image::allocate_format(self.width, self.height, &format);
             // This is the slowest possible way to do this. But also the easiest one to implement.
             for y in 0..self.height {
                 for x in 0..self.width {
@@ -810,19 +1139,90 @@ impl<'a> Image<'a> {
                 }
             }
             Cow::Owned(copy)
+        } else if !self.has_padded_stride() {
+            // Only the stride differs, so the scanlines can be copied as a whole
+            let mut copy = Image::allocate_format(self.width, self.height, &format);
+            let len = compute_stride(self.width, self.bits_per_pixel, ScanlinePad::Pad8);
+            let dst = copy.data.to_mut();
+            for (dst, src) in dst
+                .chunks_exact_mut(copy.stride.max(1))
+                .zip(self.data.chunks(self.stride.max(1)))
+            {
+                dst[..len].copy_from_slice(&src[..len]);
+            }
+            Cow::Owned(copy)
+        } else {
+            Cow::Borrowed(self)
         }
     }
 
+    /// Check whether the pixels are stored in memory as described by `format`.
+    ///
+    /// The depth, the color masks and the stride are ignored.
+    fn has_layout(&self, format: &PixelFormat) -> bool {
+        format.scanline_pad == self.scanline_pad
+            && format.bits_per_pixel == self.bits_per_pixel
+            && format.byte_order == self.byte_order
+            && (format.bit_order == self.bit_order || self.bits_per_pixel != BitsPerPixel::B1)
+    }
+
     /// Convert this image into the native format of the X11 server.
     ///
     /// This function may need to copy the image, hence returns a `Cow`.
     pub fn native(&self, setup: &Setup) -> Result<Cow<'_, Self>, ParseError> {
-        let format = find_format(setup, self.depth)?;
-        Ok(self.convert(
-            format.scanline_pad.try_into()?,
-            format.bits_per_pixel.try_into()?,
-            setup.image_byte_order.try_into()?,
-        ))
+        Ok(self.relayout(&PixelFormat::native(setup, self.depth)?))
+    }
+
+    /// Convert this image into an arbitrary format.
+    ///
+    /// `layout` describes the color masks of this image. If both `layout` and the layout of
+    /// `format` are known and differ, each pixel is decoded with `layout` and encoded again for
+    /// `format`. Otherwise, the pixel values are copied unchanged, which requires that the depth
+    /// of `format` is the same as the depth of this image.
+    ///
+    /// ```
+    /// # use x11rb::image::{
+    /// #     BitsPerPixel, ColorComponent, Image, ImageOrder, PixelFormat, PixelLayout, ScanlinePad,
+    /// # };
+    /// let rgb888 = PixelLayout::new(
+    ///     ColorComponent::new(8, 16)?,
+    ///     ColorComponent::new(8, 8)?,
+    ///     ColorComponent::new(8, 0)?,
+    /// );
+    /// let rgb565 = PixelLayout::new(
+    ///     ColorComponent::new(5, 11)?,
+    ///     ColorComponent::new(6, 5)?,
+    ///     ColorComponent::new(5, 0)?,
+    /// );
+    /// let mut image = Image::allocate(1, 1, ScanlinePad::Pad32, 24, BitsPerPixel::B32, ImageOrder::LsbFirst);
+    /// image.put_pixel(0, 0, 0xff_80_00);
+    /// let format = PixelFormat::new(16, BitsPerPixel::B16, ScanlinePad::Pad32, ImageOrder::MsbFirst)?
+    ///     .with_layout(rgb565);
+    /// let converted = image.convert_to_format(Some(rgb888), &format)?;
+    /// assert_eq!(converted.get_pixel(0, 0), 0xfc_00);
+    /// assert_eq!(&converted.data()[..2], [0xfc, 0x00]);
+    /// # Ok::<(), x11rb::errors::ParseError>(())
+    /// ```
+    pub fn convert_to_format(
+        &self,
+        layout: Option<PixelLayout>,
+        format: &PixelFormat,
+    ) -> Result<Cow<'_, Self>, ParseError> {
+        match (layout, format.layout) {
+            (Some(own), Some(output)) if own != output => {
+                let (width, height) = (self.width(), self.height());
+                let mut result = Image::allocate_format(width, height, format);
+                for y in 0..height {
+                    for x in 0..width {
+                        let pixel = self.get_pixel(x, y);
+                        result.put_pixel(x, y, output.encode(own.decode(pixel)));
+                    }
+                }
+                Ok(Cow::Owned(result))
+            }
+            _ if format.depth != self.depth => Err(ParseError::InvalidValue),
+            _ => Ok(self.relayout(format)),
+        }
     }
 
     /// Reencode this image to a different pixel layout / depth.
@@ -840,18 +1240,52 @@ impl<'a> Image<'a> {
         if own == output {
             self.native(setup)
         } else {
-            // Yay, we get to convert the image :-(
-            let (width, height) = (self.width(), self.height());
-            let mut result = Image::allocate_native(width, height, output.depth(), setup)?;
-            for y in 0..height {
-                for x in 0..width {
-                    let pixel = self.get_pixel(x, y);
-                    let pixel = output.encode(own.decode(pixel));
-                    result.put_pixel(x, y, pixel);
-                }
+            let format = PixelFormat::native(setup, output.depth())?.with_layout(output);
+            self.convert_to_format(Some(own), &format)
+        }
+    }
+
+    /// Convert this image into RGBA with eight bits per component.
+    ///
+    /// Each pixel is decoded with `layout` and stored as four bytes in the order red, green, blue
+    /// and alpha, i.e. the result has a depth of 32 and the pixel values are `0xAABBGGRR` with
+    /// the least significant byte first. If the depth of this image leaves room for an alpha
+    /// channel above the color masks, e.g. for 32 bit ARGB visuals, the alpha channel is taken
+    /// from these bits. Otherwise, all pixels are opaque.
+    ///
+    /// The color components are copied as they are stored, without converting from
+    /// premultiplied alpha. In particular, the color of fully transparent pixels is kept. Use
+    /// [`Image::to_rgba_image`] to get straight alpha from a premultiplied ARGB visual.
+    pub fn to_rgba8888(&self, layout: PixelLayout) -> Image<'static> {
+        self.decode_rgba8888(layout, false)
+    }
+
+    /// Implementation of [`Self::to_rgba8888`] that can also undo premultiplied alpha.
+    fn decode_rgba8888(&self, layout: PixelLayout, unpremultiplied: bool) -> Image<'static> {
+        let alpha = alpha_component(self.depth, layout);
+        let mut result = Image::allocate(
+            self.width,
+            self.height,
+            ScanlinePad::Pad32,
+            32,
+            BitsPerPixel::B32,
+            ImageOrder::LsbFirst,
+        );
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let pixel = self.get_pixel(x, y);
+                let (red, green, blue) = layout.decode(pixel);
+                let alpha = alpha.map_or(u16::MAX, |alpha| alpha.decode(pixel));
+                let [red, green, blue] = if unpremultiplied {
+                    [red, green, blue].map(|c| unpremultiply(c, alpha))
+                } else {
+                    [red, green, blue]
+                };
+                let rgba = [red, green, blue, alpha].map(|value| (value >> 8) as u8);
+                result.put_pixel(x, y, u32::from_le_bytes(rgba));
             }
-            Ok(Cow::Owned(result))
         }
+        result
     }
 
     /// Set a single pixel in this image.
@@ -870,7 +1304,7 @@ impl<'a> Image<'a> {
         let data = self.data.to_mut();
         match self.bits_per_pixel {
             BitsPerPixel::B1 => {
-                let (byte, bit) = compute_depth_1_address(x, self.byte_order);
+                let (byte, bit) = compute_depth_1_address(x, self.bit_order);
                 let pixel = ((pixel & 0x01) << bit) as u8;
                 let old = data[row_start + byte];
                 let bit_cleared = old & !(1 << bit);
@@ -931,7 +1365,7 @@ impl<'a> Image<'a> {
         // case and copy bits_per_pixel.into() / 8 bytes in other cases?
         match self.bits_per_pixel {
             BitsPerPixel::B1 => {
-                let (byte, bit) = compute_depth_1_address(x, self.byte_order);
+                let (byte, bit) = compute_depth_1_address(x, self.bit_order);
                 ((self.data[row_start + byte] >> bit) & 1).into()
             }
             BitsPerPixel::B4 => {
@@ -974,6 +1408,117 @@ impl<'a> Image<'a> {
         }
     }
 
+    /// Copy the rectangle `src_rect` of `src` into this image at position `(dst_x, dst_y)`.
+    ///
+    /// The pixel values are copied unchanged, so both images must have the same depth; otherwise
+    /// this function fails with [`ParseError::InvalidValue`]. Parts of the rectangle that are
+    /// outside of either image are clipped.
+    pub fn blit(
+        &mut self,
+        src: &Image<'_>,
+        src_rect: Rectangle,
+        dst_x: i16,
+        dst_y: i16,
+    ) -> Result<(), ParseError> {
+        if src.depth != self.depth {
+            return Err(ParseError::InvalidValue);
+        }
+        let clip = |src_pos: i16, dst_pos: i16, len: u16, src_len: u16, dst_len: u16| {
+            // Move the start into both images, then shorten the range to fit into both
+            let skip = 0.max(-i32::from(src_pos)).max(-i32::from(dst_pos));
+            let (src_start, dst_start) = (i32::from(src_pos) + skip, i32::from(dst_pos) + skip);
+            let len = (i32::from(len) - skip)
+                .min(i32::from(src_len) - src_start)
+                .min(i32::from(dst_len) - dst_start);
+            (src_start, dst_start, len.max(0))
+        };
+        let (src_x, dst_x, width) = clip(src_rect.x, dst_x, src_rect.width, src.width, self.width);
+        let (src_y, dst_y, height) =
+            clip(src_rect.y, dst_y, src_rect.height, src.height, self.height);
+        for y in 0..height {
+            for x in 0..width {
+                let pixel = src.get_pixel((src_x + x) as u16, (src_y + y) as u16);
+                self.put_pixel((dst_x + x) as u16, (dst_y + y) as u16, pixel);
+            }
+        }
+        Ok(())
+    }
+
+    /// Scale this image to the given size with nearest-neighbor sampling.
+    ///
+    /// The result has the same format as this image. Pixel values are copied unchanged, so this
+    /// also works for images without color masks, e.g. with a palette.
+    pub fn scale_nearest(&self, width: u16, height: u16) -> Image<'static> {
+        let mut result = Image::allocate_format(width, height, &self.format());
+        if self.width == 0 || self.height == 0 {
+            return result;
+        }
+        // Sample the center of each destination pixel
+        let source = |pos: u16, len: u16, src_len: u16| {
+            let pos = (2 * u32::from(pos) + 1) * u32::from(src_len) / (2 * u32::from(len));
+            pos as u16
+        };
+        for y in 0..height {
+            let src_y = source(y, height, self.height);
+            for x in 0..width {
+                let pixel = self.get_pixel(source(x, width, self.width), src_y);
+                result.put_pixel(x, y, pixel);
+            }
+        }
+        result
+    }
+
+    /// Scale this image to the given size with bilinear interpolation.
+    ///
+    /// Interpolation works on the color components, so `layout` has to describe the color masks of
+    /// this image. If the depth leaves room for an alpha channel above the color masks, it is
+    /// interpolated as well. The result has the same format as this image.
+    pub fn scale_bilinear(&self, width: u16, height: u16, layout: PixelLayout) -> Image<'static> {
+        let mut result = Image::allocate_format(width, height, &self.format());
+        if self.width == 0 || self.height == 0 {
+            return result;
+        }
+        let alpha = alpha_component(self.depth, layout);
+        let components = |pixel: u32| {
+            let (red, green, blue) = layout.decode(pixel);
+            let alpha = alpha.map_or(0, |alpha| alpha.decode(pixel));
+            [red, green, blue, alpha].map(f32::from)
+        };
+        // Map the center of each destination pixel to a position between two source pixels
+        let source = |pos: u16, len: u16, src_len: u16| {
+            let pos = (f32::from(pos) + 0.5) * f32::from(src_len) / f32::from(len) - 0.5;
+            let pos = pos.max(0.0).min(f32::from(src_len - 1));
+            let low = pos.floor() as u16;
+            (low, (low + 1).min(src_len - 1), pos - f32::from(low))
+        };
+        for y in 0..height {
+            let (y0, y1, fy) = source(y, height, self.height);
+            for x in 0..width {
+                let (x0, x1, fx) = source(x, width, self.width);
+                let (p00, p10) = (self.get_pixel(x0, y0), self.get_pixel(x1, y0));
+                let (p01, p11) = (self.get_pixel(x0, y1), self.get_pixel(x1, y1));
+                let (c00, c10, c01, c11) = (
+                    components(p00),
+                    components(p10),
+                    components(p01),
+                    components(p11),
+                );
+                let mut value = [0u16; 4];
+                for (i, value) in value.iter_mut().enumerate() {
+                    let top = c00[i] + (c10[i] - c00[i]) * fx;
+                    let bottom = c01[i] + (c11[i] - c01[i]) * fx;
+                    *value = (top + (bottom - top) * fy).round() as u16;
+                }
+                let mut pixel = layout.encode((value[0], value[1], value[2]));
+                if let Some(alpha) = alpha {
+                    pixel |= alpha.encode(value[3]);
+                }
+                result.put_pixel(x, y, pixel);
+            }
+        }
+        result
+    }
+
     /// Get a version of this image with `'static` lifetime.
     ///
     /// If the image was constructed from a `Cow::Borrowed`, this clones the contained data.
@@ -982,12 +1527,258 @@ impl<'a> Image<'a> {
         // It would be great if we could just implement ToOwned, but that requires implementing
         // Borrow, which we cannot do. Thus, this function exists as a work-around.
         Image {
-            data: self.data.into_owned().into(),
+            data: ImageData::Owned(self.data.into_owned()),
             ..self
         }
     }
 }
 
+/// The complete description of how pixels are stored in an [`Image`].
+///
+/// This combines the memory layout of an image (bits per pixel, padding, byte and bit order) with
+/// the meaning of the pixel values (depth and, for true color visuals, the color masks).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PixelFormat {
+    depth: u8,
+    bits_per_pixel: BitsPerPixel,
+    scanline_pad: ScanlinePad,
+    byte_order: ImageOrder,
+    bit_order: ImageOrder,
+    layout: Option<PixelLayout>,
+}
+
+impl PixelFormat {
+    /// Create a new pixel format without color masks.
+    ///
+    /// The bit order is the same as the byte order. This function errors if `depth` is larger
+    /// than `bits_per_pixel`.
+    pub fn new(
+        depth: u8,
+        bits_per_pixel: BitsPerPixel,
+        scanline_pad: ScanlinePad,
+        byte_order: ImageOrder,
+    ) -> Result<Self, ParseError> {
+        if usize::from(depth) > usize::from(bits_per_pixel) {
+            Err(ParseError::InvalidValue)
+        } else {
+            Ok(Self {
+                depth,
+                bits_per_pixel,
+                scanline_pad,
+                byte_order,
+                bit_order: byte_order,
+                layout: None,
+            })
+        }
+    }
+
+    /// Get the native format of the X11 server for images with the given depth.
+    ///
+    /// The returned format does not have color masks, see [`PixelFormat::for_visual`].
+    pub fn native(setup: &Setup, depth: u8) -> Result<Self, ParseError> {
+        let format = find_format(setup, depth)?;
+        Ok(Self::new(
+            depth,
+            format.bits_per_pixel.try_into()?,
+            format.scanline_pad.try_into()?,
+            setup.image_byte_order.try_into()?,
+        )?
+        .with_bit_order(setup.bitmap_format_bit_order.try_into()?))
+    }
+
+    /// Get the native format of the X11 server for images of the given visual.
+    ///
+    /// For `TrueColor` and `DirectColor` visuals, the format includes the color masks of the
+    /// visual. This function errors if the visual does not exist.
+    pub fn for_visual(setup: &Setup, visual: Visualid) -> Result<Self, ParseError> {
+        let (depth, visual) = setup
+            .roots
+            .iter()
+            .flat_map(|screen| &screen.allowed_depths)
+            .flat_map(|depth| depth.visuals.iter().map(move |v| (depth.depth, v)))
+            .find(|(_, v)| v.visual_id == visual)
+            .ok_or(ParseError::InvalidValue)?;
+        let format = Self::native(setup, depth)?;
+        Ok(match PixelLayout::from_visual_type(*visual) {
+            Ok(layout) => format.with_layout(layout),
+            Err(_) => format,
+        })
+    }
+
+    /// Change the order in which pixels with one bit per pixel are packed into bytes.
+    pub fn with_bit_order(self, bit_order: ImageOrder) -> Self {
+        Self { bit_order, ..self }
+    }
+
+    /// Get the number of bytes that a scanline of the given width occupies in memory.
+    pub fn stride(&self, width: u16) -> usize {
+        compute_stride(width, self.bits_per_pixel, self.scanline_pad)
+    }
+
+    /// Set the color masks of this format.
+    pub fn with_layout(self, layout: PixelLayout) -> Self {
+        Self {
+            layout: Some(layout),
+            ..self
+        }
+    }
+
+    /// Color depth in bits.
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// Number of bits required to store one pixel.
+    pub fn bits_per_pixel(&self) -> BitsPerPixel {
+        self.bits_per_pixel
+    }
+
+    /// The padding on the right side of each scanline.
+    pub fn scanline_pad(&self) -> ScanlinePad {
+        self.scanline_pad
+    }
+
+    /// Order in which bytes are stored in memory.
+    pub fn byte_order(&self) -> ImageOrder {
+        self.byte_order
+    }
+
+    /// Order in which pixels with one bit per pixel are packed into bytes.
+    pub fn bit_order(&self) -> ImageOrder {
+        self.bit_order
+    }
+
+    /// The color masks, if known.
+    pub fn layout(&self) -> Option<PixelLayout> {
+        self.layout
+    }
+}
+
+#[cfg(feature = "image-interop")]
+impl Image<'_> {
+    /// Convert this image into an RGBA image of the `image` crate.
+    ///
+    /// Each pixel is decoded with `layout`, which usually comes from the visual of the drawable
+    /// that the image was taken from, see [`PixelFormat::for_visual`]. If the depth of this image
+    /// leaves room for an alpha channel above the color masks, e.g. for 32 bit ARGB visuals, the
+    /// alpha channel is taken from these bits and the colors are converted from premultiplied
+    /// alpha. Otherwise, all pixels are opaque.
+    pub fn to_rgba_image(&self, layout: PixelLayout) -> image_crate::RgbaImage {
+        let data = self.decode_rgba8888(layout, true).data.into_owned();
+        image_crate::RgbaImage::from_raw(self.width.into(), self.height.into(), data)
+            .expect("RGBA8888 images have no padding")
+    }
+
+    /// Convert an RGBA image of the `image` crate into an image in the given format.
+    ///
+    /// `format` must contain color masks, e.g. from [`PixelFormat::for_visual`]. The alpha
+    /// channel is only kept if the depth of `format` leaves room for it above the color masks.
+    /// In that case, the colors are premultiplied with alpha, since this is what RENDER and
+    /// compositing managers expect from ARGB visuals.
+    /// This function fails if `format` has no color masks or if the image is larger than
+    /// 65535x65535 pixels.
+    pub fn from_rgba_image(
+        image: &image_crate::RgbaImage,
+        format: &PixelFormat,
+    ) -> Result<Image<'static>, ParseError> {
+        let layout = format.layout.ok_or(ParseError::InvalidValue)?;
+        let alpha = alpha_component(format.depth, layout);
+        let width = u16::try_from(image.width()).map_err(|_| ParseError::InvalidValue)?;
+        let height = u16::try_from(image.height()).map_err(|_| ParseError::InvalidValue)?;
+        let mut result = Image::allocate_format(width, height, format);
+        for (x, y, pixel) in image.enumerate_pixels() {
+            let [red, green, blue, a] = pixel.0.map(|value| u16::from_ne_bytes([value, value]));
+            let value = match alpha {
+                Some(alpha) => {
+                    let [red, green, blue] = [red, green, blue].map(|c| premultiply(c, a));
+                    layout.encode((red, green, blue)) | alpha.encode(a)
+                }
+                None => layout.encode((red, green, blue)),
+            };
+            result.put_pixel(x as u16, y as u16, value);
+        }
+        Ok(result)
+    }
+}
+
+/// Find the native format of the first `TrueColor` or `DirectColor` visual with the given depth.
+fn find_true_color_format(setup: &Setup, depth: u8) -> Option<PixelFormat> {
+    let visual = setup
+        .roots
+        .iter()
+        .flat_map(|screen| &screen.allowed_depths)
+        .filter(|allowed| allowed.depth == depth)
+        .flat_map(|allowed| &allowed.visuals)
+        .find(|visual| PixelLayout::from_visual_type(**visual).is_ok())?;
+    PixelFormat::for_visual(setup, visual.visual_id).ok()
+}
+
+/// Get the alpha channel of a format, i.e. the bits of the depth above the color masks.
+fn alpha_component(depth: u8, layout: PixelLayout) -> Option<ColorComponent> {
+    let shift = [layout.red, layout.green, layout.blue]
+        .iter()
+        .map(|component| component.shift + component.width)
+        .max()?;
+    ColorComponent::new(depth.checked_sub(shift)?.min(16), shift)
+        .ok()
+        .filter(|alpha| alpha.width > 0)
+}
+
+/// Multiply a 16 bit color component with a 16 bit alpha value.
+#[cfg(feature = "image-interop")]
+fn premultiply(color: u16, alpha: u16) -> u16 {
+    ((u32::from(color) * u32::from(alpha) + 0x7fff) / 0xffff) as u16
+}
+
+/// Undo [`premultiply`]. Fully transparent pixels become black.
+fn unpremultiply(color: u16, alpha: u16) -> u16 {
+    if alpha == 0 {
+        return 0;
+    }
+    let alpha = u32::from(alpha);
+    ((u32::from(color) * 0xffff + alpha / 2) / alpha).min(0xffff) as u16
+}
+
+/// Convert an RGBA image into a 32 bit ARGB image.
+///
+/// The result is in the format of the ARGB visual that most X11 servers provide for depth 32:
+/// Each pixel is stored as `0xAARRGGBB` with premultiplied alpha in four bytes with the least
+/// significant byte first.
+/// Use [`Image::native`] before uploading it to a server with a different byte order.
+#[cfg(feature = "image-interop")]
+impl TryFrom<&image_crate::RgbaImage> for Image<'static> {
+    type Error = ParseError;
+
+    fn try_from(image: &image_crate::RgbaImage) -> Result<Self, ParseError> {
+        let layout = PixelLayout::new(
+            ColorComponent::new(8, 16)?,
+            ColorComponent::new(8, 8)?,
+            ColorComponent::new(8, 0)?,
+        );
+        let format = PixelFormat::new(
+            32,
+            BitsPerPixel::B32,
+            ScanlinePad::Pad32,
+            ImageOrder::LsbFirst,
+        )?
+        .with_layout(layout);
+        Image::from_rgba_image(image, &format)
+    }
+}
+
+/// Convert any image into a 32 bit ARGB image, see the conversion from `RgbaImage`.
+#[cfg(feature = "image-interop")]
+impl TryFrom<&image_crate::DynamicImage> for Image<'static> {
+    type Error = ParseError;
+
+    fn try_from(image: &image_crate::DynamicImage) -> Result<Self, ParseError> {
+        match image {
+            image_crate::DynamicImage::ImageRgba8(image) => image.try_into(),
+            image => (&image.to_rgba8()).try_into(),
+        }
+    }
+}
+
 fn compute_depth_1_address(x: usize, order: ImageOrder) -> (usize, usize) {
     let bit = match order {
         ImageOrder::MsbFirst => 7 - x % 8,
@@ -998,9 +1789,58 @@ fn compute_depth_1_address(x: usize, order: ImageOrder) -> (usize, usize) {
 
 #[cfg(test)]
 mod test_image {
-    use super::{BitsPerPixel, Image, ImageOrder, ParseError, ScanlinePad};
+    use super::{BitsPerPixel, Image, ImageOrder, ParseError, PixelFormat, ScanlinePad};
     use std::borrow::Cow;
 
+    fn format_b8() -> PixelFormat {
+        PixelFormat::new(
+            8,
+            BitsPerPixel::B8,
+            ScanlinePad::Pad32,
+            ImageOrder::LsbFirst,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn from_slice_with_stride() {
+        // Two scanlines of three pixels with five bytes each
+        let data = [1, 2, 3, 0xff, 0xff, 4, 5, 6, 0xff, 0xff];
+        let image = Image::from_slice(3, 2, 5, &format_b8(), &data).unwrap();
+        assert_eq!(image.stride(), 5);
+        assert_eq!(image.get_pixel(2, 0), 3);
+        assert_eq!(image.get_pixel(0, 1), 4);
+
+        let converted = image.convert(ScanlinePad::Pad32, BitsPerPixel::B8, ImageOrder::LsbFirst);
+        assert!(matches!(converted, Cow::Owned(_)));
+        assert_eq!(converted.stride(), 4);
+        assert_eq!(converted.data(), [1, 2, 3, 0, 4, 5, 6, 0]);
+
+        let converted = image.convert(ScanlinePad::Pad8, BitsPerPixel::B16, ImageOrder::MsbFirst);
+        assert_eq!(converted.data(), [0, 1, 0, 2, 0, 3, 0, 4, 0, 5, 0, 6]);
+    }
+
+    #[test]
+    fn from_mut_slice_in_place() {
+        let mut data = [0; 10];
+        let mut image = Image::from_mut_slice(3, 2, 5, &format_b8(), &mut data).unwrap();
+        image.put_pixel(1, 1, 42);
+        let copy = image.clone().into_owned();
+        drop(image);
+        assert_eq!(data, [0, 0, 0, 0, 0, 0, 42, 0, 0, 0]);
+        assert_eq!(copy.get_pixel(1, 1), 42);
+    }
+
+    #[test]
+    fn from_slice_errors() {
+        let data = [0; 10];
+        let result = Image::from_slice(3, 2, 2, &format_b8(), &data);
+        assert_eq!(result.unwrap_err(), ParseError::InvalidValue);
+        let result = Image::from_slice(3, 3, 4, &format_b8(), &data);
+        assert_eq!(result.unwrap_err(), ParseError::InsufficientData);
+        assert!(Image::from_slice(3, 2, 3, &format_b8(), &data[..6]).is_ok());
+    }
+
     #[test]
     fn test_new_too_short() {
         let depth = 16;
@@ -1299,3 +2139,314 @@ mod test_image {
         0x98,
     ];
 }
+
+#[cfg(test)]
+mod test_pixel_format {
+    use super::{
+        BitsPerPixel, ColorComponent, Image, ImageOrder, ParseError, PixelFormat, PixelLayout,
+        ScanlinePad,
+    };
+    use crate::protocol::xproto::{
+        Depth, Format, ImageOrder as XprotoImageOrder, Screen, Setup, VisualClass, Visualtype,
+    };
+
+    fn big_endian_setup() -> Setup {
+        let format = |depth, bits_per_pixel| Format {
+            depth,
+            bits_per_pixel,
+            scanline_pad: 32,
+        };
+        let visual = Visualtype {
+            visual_id: 42,
+            class: VisualClass::TRUE_COLOR,
+            red_mask: 0x3ff0_0000,
+            green_mask: 0x000f_fc00,
+            blue_mask: 0x0000_03ff,
+            ..Default::default()
+        };
+        Setup {
+            image_byte_order: XprotoImageOrder::MSB_FIRST,
+            bitmap_format_bit_order: XprotoImageOrder::LSB_FIRST,
+            pixmap_formats: vec![format(1, 1), format(16, 16), format(30, 32)],
+            roots: vec![Screen {
+                allowed_depths: vec![Depth {
+                    depth: 30,
+                    visuals: vec![visual],
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn rgb(red: u8, green: u8, blue: u8) -> PixelLayout {
+        PixelLayout::new(
+            ColorComponent::new(red, green + blue).unwrap(),
+            ColorComponent::new(green, blue).unwrap(),
+            ColorComponent::new(blue, 0).unwrap(),
+        )
+    }
+
+    #[test]
+    fn empty_component() {
+        let empty = ColorComponent::new(0, 0).unwrap();
+        assert_eq!(empty.decode(0xffff_ffff), 0);
+        assert_eq!(empty.encode(0xffff), 0);
+    }
+
+    #[test]
+    fn native_bit_order() {
+        let setup = big_endian_setup();
+        let mut image = Image::allocate(
+            8,
+            1,
+            ScanlinePad::Pad8,
+            1,
+            BitsPerPixel::B1,
+            ImageOrder::MsbFirst,
+        );
+        image.put_pixel(0, 0, 1);
+        assert_eq!(image.data(), [0x80]);
+
+        let native = image.native(&setup).unwrap();
+        assert_eq!(native.bit_order(), ImageOrder::LsbFirst);
+        assert_eq!(native.get_pixel(0, 0), 1);
+        assert_eq!(native.data(), [0x01, 0, 0, 0]);
+    }
+
+    #[test]
+    fn convert_to_30_bit_visual() {
+        let setup = big_endian_setup();
+        let format = PixelFormat::for_visual(&setup, 42).unwrap();
+        assert_eq!(format.depth(), 30);
+        assert_eq!(format.bits_per_pixel(), BitsPerPixel::B32);
+        assert_eq!(format.byte_order(), ImageOrder::MsbFirst);
+        assert_eq!(format.layout(), Some(rgb(10, 10, 10)));
+
+        let mut image = Image::allocate(
+            1,
+            1,
+            ScanlinePad::Pad8,
+            16,
+            BitsPerPixel::B16,
+            ImageOrder::LsbFirst,
+        );
+        image.put_pixel(0, 0, 0xf800);
+        let converted = image
+            .convert_to_format(Some(rgb(5, 6, 5)), &format)
+            .unwrap();
+        assert_eq!(converted.depth(), 30);
+        assert_eq!(converted.get_pixel(0, 0), 0x3ff0_0000);
+        assert_eq!(converted.data(), [0x3f, 0xf0, 0, 0]);
+
+        // Without color masks, the depth cannot change
+        assert_eq!(
+            image.convert_to_format(None, &format).unwrap_err(),
+            ParseError::InvalidValue
+        );
+        assert_eq!(
+            PixelFormat::for_visual(&setup, 43).unwrap_err(),
+            ParseError::InvalidValue
+        );
+    }
+
+    #[test]
+    fn normalize_to_rgba() {
+        let mut image = Image::allocate(
+            2,
+            1,
+            ScanlinePad::Pad32,
+            32,
+            BitsPerPixel::B32,
+            ImageOrder::MsbFirst,
+        );
+        image.put_pixel(0, 0, 0x80_12_34_56);
+        image.put_pixel(1, 0, 0x3ff0_0000);
+        let rgba = image.to_rgba8888(rgb(8, 8, 8));
+        // The color components are copied without undoing premultiplied alpha
+        assert_eq!(rgba.data(), [0x12, 0x34, 0x56, 0x80, 0xf0, 0, 0, 0x3f]);
+
+        // A 30 bit visual leaves only two bits for alpha in 32 bits. The color of the fully
+        // transparent pixel is kept.
+        let rgba = image.to_rgba8888(rgb(10, 10, 10));
+        assert_eq!(&rgba.data()[4..], [0xff, 0, 0, 0]);
+
+        // Without room for alpha, the result is opaque
+        let mut image = Image::allocate(
+            1,
+            1,
+            ScanlinePad::Pad8,
+            16,
+            BitsPerPixel::B16,
+            ImageOrder::LsbFirst,
+        );
+        image.put_pixel(0, 0, 0x07e0);
+        let rgba = image.to_rgba8888(rgb(5, 6, 5));
+        assert_eq!(rgba.data(), [0, 0xff, 0, 0xff]);
+    }
+
+    #[test]
+    fn relayout_16_bit() {
+        let mut image = Image::allocate(
+            2,
+            1,
+            ScanlinePad::Pad8,
+            16,
+            BitsPerPixel::B16,
+            ImageOrder::LsbFirst,
+        );
+        image.put_pixel(1, 0, 0x1234);
+        let format = PixelFormat::new(
+            16,
+            BitsPerPixel::B16,
+            ScanlinePad::Pad32,
+            ImageOrder::MsbFirst,
+        )
+        .unwrap();
+        let converted = image.convert_to_format(None, &format).unwrap();
+        assert_eq!(converted.data(), [0, 0, 0x12, 0x34]);
+        assert_eq!(
+            PixelFormat::new(
+                24,
+                BitsPerPixel::B16,
+                ScanlinePad::Pad8,
+                ImageOrder::MsbFirst
+            )
+            .unwrap_err(),
+            ParseError::InvalidValue
+        );
+    }
+}
+
+#[cfg(all(test, feature = "image-interop"))]
+mod test_image_interop {
+    use super::ScanlinePad;
+    use super::{BitsPerPixel, ColorComponent, Image, ImageOrder, PixelFormat, PixelLayout};
+
+    fn rgb565() -> PixelLayout {
+        PixelLayout::new(
+            ColorComponent::new(5, 11).unwrap(),
+            ColorComponent::new(6, 5).unwrap(),
+            ColorComponent::new(5, 0).unwrap(),
+        )
+    }
+
+    #[test]
+    fn argb_round_trip() {
+        let mut rgba = image_crate::RgbaImage::new(3, 1);
+        rgba.put_pixel(1, 0, image_crate::Rgba([0x12, 0x34, 0x56, 0xff]));
+        rgba.put_pixel(2, 0, image_crate::Rgba([0xff, 0x80, 0x40, 0x80]));
+        let image = Image::try_from(&rgba).unwrap();
+        assert_eq!(image.depth(), 32);
+        // The colors are premultiplied with alpha
+        assert_eq!(
+            image.data(),
+            [0, 0, 0, 0, 0x56, 0x34, 0x12, 0xff, 0x20, 0x40, 0x80, 0x80]
+        );
+
+        let layout = PixelLayout::new(
+            ColorComponent::new(8, 16).unwrap(),
+            ColorComponent::new(8, 8).unwrap(),
+            ColorComponent::new(8, 0).unwrap(),
+        );
+        assert_eq!(image.to_rgba_image(layout), rgba);
+    }
+
+    #[test]
+    fn rgb565_with_padding() {
+        let format = PixelFormat::new(
+            16,
+            BitsPerPixel::B16,
+            ScanlinePad::Pad32,
+            ImageOrder::MsbFirst,
+        )
+        .unwrap()
+        .with_layout(rgb565());
+        let mut rgba = image_crate::RgbaImage::new(1, 2);
+        rgba.put_pixel(0, 1, image_crate::Rgba([0xff, 0, 0xff, 0x80]));
+        let image = Image::from_rgba_image(&rgba, &format).unwrap();
+        assert_eq!(image.data(), [0, 0, 0, 0, 0xf8, 0x1f, 0, 0]);
+
+        // Without room for alpha, the result is opaque
+        let rgba = image.to_rgba_image(rgb565());
+        assert_eq!(rgba.get_pixel(0, 1).0, [0xff, 0, 0xff, 0xff]);
+        assert_eq!(rgba.get_pixel(0, 0).0, [0, 0, 0, 0xff]);
+    }
+}
+
+#[cfg(test)]
+mod test_blit_and_scale {
+    use super::ScanlinePad;
+    use super::{BitsPerPixel, ColorComponent, Image, ImageOrder, ParseError, PixelLayout};
+    use crate::protocol::xproto::Rectangle;
+
+    fn image(width: u16, height: u16, pixels: &[u32]) -> Image<'static> {
+        let mut image = Image::allocate(
+            width,
+            height,
+            ScanlinePad::Pad32,
+            24,
+            BitsPerPixel::B32,
+            ImageOrder::LsbFirst,
+        );
+        for (i, pixel) in pixels.iter().enumerate() {
+            let i = i as u16;
+            image.put_pixel(i % width, i / width, *pixel);
+        }
+        image
+    }
+
+    fn pixels(image: &Image<'_>) -> Vec<u32> {
+        (0..image.height())
+            .flat_map(|y| (0..image.width()).map(move |x| image.get_pixel(x, y)))
+            .collect()
+    }
+
+    #[test]
+    fn blit_with_clipping() {
+        let src = image(2, 2, &[1, 2, 3, 4]);
+        let mut dst = image(3, 3, &[0; 9]);
+        let rect = |x, y, width, height| Rectangle {
+            x,
+            y,
+            width,
+            height,
+        };
+        dst.blit(&src, rect(0, 0, 2, 2), 2, -1).unwrap();
+        assert_eq!(pixels(&dst), [0, 0, 3, 0, 0, 0, 0, 0, 0]);
+        dst.blit(&src, rect(-1, 1, 5, 5), 0, 1).unwrap();
+        assert_eq!(pixels(&dst), [0, 0, 3, 0, 3, 4, 0, 0, 0]);
+
+        let mut other_depth = Image::allocate(
+            1,
+            1,
+            ScanlinePad::Pad8,
+            8,
+            BitsPerPixel::B8,
+            ImageOrder::LsbFirst,
+        );
+        assert_eq!(
+            other_depth.blit(&src, rect(0, 0, 1, 1), 0, 0).unwrap_err(),
+            ParseError::InvalidValue
+        );
+    }
+
+    #[test]
+    fn scale() {
+        let src = image(2, 1, &[0x00_00_00, 0xff_ff_ff]);
+        let nearest = src.scale_nearest(4, 2);
+        assert_eq!(pixels(&nearest), [0, 0, 0xffffff, 0xffffff].repeat(2));
+
+        let layout = PixelLayout::new(
+            ColorComponent::new(8, 16).unwrap(),
+            ColorComponent::new(8, 8).unwrap(),
+            ColorComponent::new(8, 0).unwrap(),
+        );
+        let bilinear = src.scale_bilinear(4, 1, layout);
+        assert_eq!(
+            pixels(&bilinear),
+            [0x00_00_00, 0x40_40_40, 0xbf_bf_bf, 0xff_ff_ff]
+        );
+        assert_eq!(pixels(&src.scale_bilinear(1, 1, layout)), [0x80_80_80]);
+    }
+}
@@ -0,0 +1,194 @@
+//! Uploading PNG and BMP files into pixmaps.
+//!
+//! [`load_pixmap`] decodes an image file and uploads it into a pixmap for a screen, e.g. for
+//! window icons or decorations. The pixels are converted to the depth and color masks of the
+//! visual of the pixmap. Since pixmaps of most visuals cannot store transparency, a 1 bit mask
+//! is created for images with transparent pixels, e.g. for `IconMask` in `WM_HINTS` or for the
+//! clip mask of a graphics context.
+//!
+//! The code in this module is only available when the `image-loader` feature of the library is
+//! enabled.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use x11rb::image_loader::load_pixmap;
+//!
+//! let (conn, screen_num) = x11rb::connect(None)?;
+//! let icon = load_pixmap(&conn, screen_num, &std::fs::read("icon.png")?)?;
+//! println!("{}x{} pixmap {}", icon.width, icon.height, icon.pixmap);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use image_crate::RgbaImage;
+
+use crate::connection::Connection;
+use crate::errors::{ConnectionError, ImageLoadError, ParseError, ReplyOrIdError};
+use crate::image::{Image, PixelFormat};
+use crate::protocol::xproto::{self, Drawable, Pixmap, Visualid};
+
+/// Pixels with an alpha value below this are transparent in the mask.
+const MASK_THRESHOLD: u8 = 0x80;
+
+/// A pixmap that was created from an image.
+///
+/// The pixmaps are not freed automatically; use [`LoadedPixmap::free`] when they are no longer
+/// needed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoadedPixmap {
+    /// The pixmap with the pixels of the image.
+    pub pixmap: Pixmap,
+
+    /// A pixmap with depth 1 that is set where the image is opaque.
+    ///
+    /// This is `None` if all pixels of the image are opaque.
+    pub mask: Option<Pixmap>,
+
+    /// The width of the pixmaps.
+    pub width: u16,
+
+    /// The height of the pixmaps.
+    pub height: u16,
+
+    /// The depth of [`LoadedPixmap::pixmap`].
+    pub depth: u8,
+}
+
+impl LoadedPixmap {
+    /// Free the pixmap and the mask.
+    pub fn free<C: Connection>(self, conn: &C) -> Result<(), ConnectionError> {
+        let _ = xproto::free_pixmap(conn, self.pixmap)?;
+        if let Some(mask) = self.mask {
+            let _ = xproto::free_pixmap(conn, mask)?;
+        }
+        Ok(())
+    }
+}
+
+/// Decode a PNG or BMP file and upload it into a pixmap for the root visual of a screen.
+///
+/// Fails with [`ParseError::InvalidValue`] if the screen does not exist or its root visual has
+/// no color masks, e.g. for `PseudoColor` visuals.
+pub fn load_pixmap<C: Connection>(
+    conn: &C,
+    screen: usize,
+    data: &[u8],
+) -> Result<LoadedPixmap, ImageLoadError> {
+    let image = image_crate::load_from_memory(data)?.into_rgba8();
+    let screen = conn
+        .setup()
+        .roots
+        .get(screen)
+        .ok_or(ReplyOrIdError::from(ParseError::InvalidValue))?;
+    Ok(create_pixmap(
+        conn,
+        screen.root,
+        screen.root_visual,
+        &image,
+    )?)
+}
+
+/// Upload an RGBA image into a pixmap for a visual.
+///
+/// The pixmap is created on the screen of `drawable` with the depth of `visual`, e.g. a 32 bit
+/// ARGB visual to keep the alpha channel. In that case, the colors are premultiplied with alpha as
+/// compositing managers expect, see [`Image::from_rgba_image`]. Fails with
+/// [`ParseError::InvalidValue`] if the visual has no color masks or the image is larger than
+/// 65535x65535 pixels.
+pub fn create_pixmap<C: Connection>(
+    conn: &C,
+    drawable: Drawable,
+    visual: Visualid,
+    image: &RgbaImage,
+) -> Result<LoadedPixmap, ReplyOrIdError> {
+    let format = PixelFormat::for_visual(conn.setup(), visual)?;
+    let pixels = Image::from_rgba_image(image, &format)?;
+    let (width, height) = (pixels.width(), pixels.height());
+    let pixmap = upload(conn, drawable, &pixels)?;
+    let mask = match create_mask(image, conn.setup())? {
+        Some(mask) => Some(upload(conn, drawable, &mask)?),
+        None => None,
+    };
+    Ok(LoadedPixmap {
+        pixmap,
+        mask,
+        width,
+        height,
+        depth: format.depth(),
+    })
+}
+
+/// Create a 1 bit mask of the opaque pixels of an image.
+///
+/// Returns `None` if all pixels are opaque.
+fn create_mask(
+    image: &RgbaImage,
+    setup: &xproto::Setup,
+) -> Result<Option<Image<'static>>, ParseError> {
+    if image.pixels().all(|pixel| pixel.0[3] >= MASK_THRESHOLD) {
+        return Ok(None);
+    }
+    let mut mask = Image::allocate_native(image.width() as u16, image.height() as u16, 1, setup)?;
+    for (x, y, pixel) in image.enumerate_pixels() {
+        if pixel.0[3] >= MASK_THRESHOLD {
+            mask.put_pixel(x as u16, y as u16, 1);
+        }
+    }
+    Ok(Some(mask))
+}
+
+/// Create a pixmap with the content of an image.
+fn upload<C: Connection>(
+    conn: &C,
+    drawable: Drawable,
+    image: &Image<'_>,
+) -> Result<Pixmap, ReplyOrIdError> {
+    let (pixmap, gc) = (conn.generate_id()?, conn.generate_id()?);
+    let _ = xproto::create_pixmap(
+        conn,
+        image.depth(),
+        pixmap,
+        drawable,
+        image.width(),
+        image.height(),
+    )?;
+    let _ = xproto::create_gc(conn, gc, pixmap, &Default::default())?;
+    let _ = image.put(conn, pixmap, gc, 0, 0)?;
+    let _ = xproto::free_gc(conn, gc)?;
+    Ok(pixmap)
+}
+
+#[cfg(test)]
+mod test {
+    use super::create_mask;
+    use crate::protocol::xproto::{Format, ImageOrder, Setup};
+    use image_crate::{Rgba, RgbaImage};
+
+    fn setup() -> Setup {
+        Setup {
+            image_byte_order: ImageOrder::LSB_FIRST,
+            bitmap_format_bit_order: ImageOrder::LSB_FIRST,
+            pixmap_formats: vec![Format {
+                depth: 1,
+                bits_per_pixel: 1,
+                scanline_pad: 32,
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn mask_of_transparent_pixels() {
+        let mut image = RgbaImage::from_pixel(3, 2, Rgba([1, 2, 3, 255]));
+        assert!(create_mask(&image, &setup()).unwrap().is_none());
+
+        image.put_pixel(1, 0, Rgba([0, 0, 0, 0]));
+        image.put_pixel(2, 1, Rgba([0, 0, 0, 0x7f]));
+        let mask = create_mask(&image, &setup()).unwrap().unwrap();
+        let pixels = (0..2)
+            .flat_map(|y| (0..3).map(move |x| (x, y)))
+            .map(|(x, y)| mask.get_pixel(x, y))
+            .collect::<Vec<_>>();
+        assert_eq!(pixels, [1, 0, 1, 1, 1, 0]);
+    }
+}
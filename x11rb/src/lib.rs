@@ -99,19 +99,34 @@
 //!
 //! Additionally, the following flags exist:
 //! * `allow-unsafe-code`: Enable features that require `unsafe`. Without this flag,
-//!   [`xcb_ffi::XCBConnection`] and some support code for it are unavailable.
+//!   [`xcb_ffi::XCBConnection`] and some support code for it are unavailable. On Windows, this
+//!   also enables `rust_connection::NamedPipeStream` for connecting via named pipes.
 //! * `cursor`: Enable the code in [cursor] for loading cursor files.
+//! * `dmabuf`: Enable the code in [dmabuf] for sharing buffers with the X11 server via DRI3.
 //! * `resource_manager`: Enable the code in [resource_manager] for loading and querying the
 //!   X11 resource database.
 //! * `image`: Enable the code in [image] for working with pixel image data.
+//! * `image-interop`: Enable conversions between [image::Image] and the types of the
+//!   [image](https://docs.rs/image) crate.
+//! * `image-loader`: Enable the code in [image_loader] for uploading PNG and BMP files into
+//!   pixmaps.
+//! * `calloop`: Enable the code in [calloop_source] for using a connection as an event source of
+//!   the [calloop](https://docs.rs/calloop) event loop.
+//! * `mio`: Implement `mio::event::Source` for the connections so that they can be registered
+//!   with [mio](https://docs.rs/mio).
 //! * `dl-libxcb`: Enabling this feature will prevent from libxcb being linked to the
 //!   resulting executable. Instead libxcb will be dynamically loaded at runtime.
 //!   This feature adds the [`xcb_ffi::load_libxcb`] function, that allows to load
 //!   libxcb and check for success or failure.
+//! * `xlib`: Enable [`xcb_ffi::XlibXcbConnection`] for sharing a connection with Xlib, e.g. for
+//!   GLX or Vulkan drivers that need an Xlib `Display`.
 //! * `extra-traits`: Enable some additional traits for generated code, like `Eq`, `Ord` and
 //!   `Hash`. This is not needed by default and adds a large amount of code that bloats codegen
 //!   time
 //! * `request-parsing`: Add the ability to parse X11 requests. Not normally needed.
+//! * `wasm`: Enable [`rust_connection::MessageStream`] for transports that carry X11 data as
+//!   binary messages. On `wasm32`, this also enables `rust_connection::WebSocketChannel` for
+//!   connecting through a WebSocket of the browser.
 //! * `extra-traits`: Implement extra traits for X11 types. This improves the output of the `Debug`
 //!   impl and adds `PartialEq`, `Eq`, `PartialOrd`, `Ord`, and `Hash` where possible.
 //!
@@ -162,17 +177,86 @@ pub mod utils;
 pub mod xcb_ffi;
 #[macro_use]
 pub mod x11_utils;
+#[cfg(all(unix, feature = "calloop"))]
+pub mod calloop_source;
+#[cfg(all(
+    unix,
+    feature = "image",
+    feature = "shm",
+    feature = "composite",
+    feature = "allow-unsafe-code"
+))]
+pub mod capture;
+#[cfg(feature = "res")]
+pub mod client_info;
+pub mod clipboard;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod clipboard_manager;
+pub mod colormap;
 pub mod connection;
 pub mod cookie;
 #[cfg(feature = "cursor")]
 pub mod cursor;
+#[cfg(all(unix, feature = "dmabuf"))]
+pub mod dmabuf;
+#[cfg(feature = "dbe")]
+pub mod double_buffer;
 pub mod errors;
+pub mod event_compression;
+pub mod event_dispatcher;
+pub mod event_logger;
+pub mod ewmh;
+#[doc(inline)]
+pub use x11rb_protocol::event_filter;
 pub mod extension_manager;
+#[cfg(feature = "glx")]
+pub mod glx;
 #[cfg(feature = "image")]
 pub mod image;
+#[cfg(feature = "image-loader")]
+pub mod image_loader;
+#[cfg(all(unix, feature = "mio"))]
+mod mio_source;
+pub mod modal_loop;
 pub mod properties;
+#[cfg(feature = "record")]
+pub mod record_tap;
+#[cfg(feature = "render")]
+pub mod render;
+#[doc(inline)]
+pub use x11rb_protocol::reply_ref;
+#[cfg(feature = "sync")]
+pub mod resize_sync;
+#[cfg(unix)]
+pub mod run_loop;
 pub mod rust_connection;
+pub mod server_time;
+#[cfg(all(
+    unix,
+    feature = "image",
+    feature = "shm",
+    feature = "allow-unsafe-code"
+))]
+pub mod shm_image;
+pub mod startup_notification;
+#[cfg(all(
+    unix,
+    feature = "image",
+    feature = "shm",
+    feature = "allow-unsafe-code"
+))]
+pub mod surface;
+pub mod system_tray;
+#[doc(inline)]
+pub use x11rb_protocol::send_event;
+pub mod wm;
 pub mod wrapper;
+pub mod xembed;
+#[cfg(feature = "xinput")]
+#[doc(inline)]
+pub use x11rb_protocol::xinput_valuators;
+#[cfg(feature = "xv")]
+pub mod xv;
 #[rustfmt::skip]
 #[allow(missing_docs)]
 pub mod protocol;
@@ -183,6 +267,7 @@ mod test;
 
 use errors::ConnectError;
 use protocol::xproto::{Keysym, Timestamp};
+#[cfg(not(target_arch = "wasm32"))]
 use std::ffi::OsString;
 
 /// Establish a new connection to an X11 server.
@@ -215,7 +300,7 @@ pub const CURRENT_TIME: Timestamp = 0;
 /// This constant can be used to fill unused entries in `Keysym` tables
 pub const NO_SYMBOL: Keysym = 0;
 
-#[cfg(not(unix))]
+#[cfg(not(any(unix, target_arch = "wasm32")))]
 fn hostname() -> OsString {
     gethostname::gethostname()
 }
@@ -0,0 +1,88 @@
+//! Implementations of [`mio::event::Source`] for the connections.
+
+use std::io::{Error, ErrorKind, Result};
+use std::os::unix::io::{AsFd, AsRawFd};
+
+use mio::event::Source;
+use mio::unix::SourceFd;
+use mio::{Interest, Registry, Token};
+
+use crate::connection::Connection;
+use crate::errors::ConnectionError;
+use crate::rust_connection::{RustConnection, Stream};
+#[cfg(feature = "allow-unsafe-code")]
+use crate::xcb_ffi::XCBConnection;
+
+/// Flush a connection so that the server can react to all requests while we wait for readiness.
+fn flush(conn: &impl Connection) -> Result<()> {
+    conn.flush_all().map_err(|err| match err {
+        ConnectionError::IoError(err) => err,
+        err => Error::new(ErrorKind::Other, err),
+    })
+}
+
+/// Register the file descriptor of the connection with mio.
+///
+/// The connection is flushed when it is registered or reregistered. mio only reports new data on
+/// the file descriptor, so the following still has to be done before each call to
+/// [`mio::Poll::poll`]:
+/// * Flush the connection with [`Connection::flush_all`] so that pending requests reach the
+///   server. [`Connection::flush`] might hold them back if deferred flushing is enabled.
+/// * Drain the event queue with [`Connection::poll_for_event`] until it returns `None`. Events
+///   that were read while waiting for a reply are not reported by mio.
+///
+/// ```no_run
+/// use mio::{Events, Interest, Poll, Token};
+/// use x11rb::connection::Connection;
+///
+/// let (mut conn, _) = x11rb::connect(None)?;
+/// let mut poll = Poll::new()?;
+/// let mut events = Events::with_capacity(16);
+/// poll.registry()
+///     .register(&mut conn, Token(0), Interest::READABLE)?;
+/// loop {
+///     while let Some(event) = conn.poll_for_event()? {
+///         println!("Got event {:?}", event);
+///     }
+///     conn.flush_all()?;
+///     poll.poll(&mut events, None)?;
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+impl<S: Stream + AsFd> Source for RustConnection<S> {
+    fn register(&mut self, registry: &Registry, token: Token, interests: Interest) -> Result<()> {
+        flush(self)?;
+        SourceFd(&self.as_fd().as_raw_fd()).register(registry, token, interests)
+    }
+
+    fn reregister(&mut self, registry: &Registry, token: Token, interests: Interest) -> Result<()> {
+        flush(self)?;
+        SourceFd(&self.as_fd().as_raw_fd()).reregister(registry, token, interests)
+    }
+
+    fn deregister(&mut self, registry: &Registry) -> Result<()> {
+        SourceFd(&self.as_fd().as_raw_fd()).deregister(registry)
+    }
+}
+
+/// Register the file descriptor of the connection with mio.
+///
+/// The connection is flushed when it is registered or reregistered. Before each call to
+/// [`mio::Poll::poll`], the connection must be flushed and its event queue must be drained, see
+/// the [implementation for `RustConnection`](RustConnection#impl-Source-for-RustConnection<S>).
+#[cfg(feature = "allow-unsafe-code")]
+impl Source for XCBConnection {
+    fn register(&mut self, registry: &Registry, token: Token, interests: Interest) -> Result<()> {
+        flush(self)?;
+        SourceFd(&self.as_raw_fd()).register(registry, token, interests)
+    }
+
+    fn reregister(&mut self, registry: &Registry, token: Token, interests: Interest) -> Result<()> {
+        flush(self)?;
+        SourceFd(&self.as_raw_fd()).reregister(registry, token, interests)
+    }
+
+    fn deregister(&mut self, registry: &Registry) -> Result<()> {
+        SourceFd(&self.as_raw_fd()).deregister(registry)
+    }
+}
@@ -0,0 +1,237 @@
+//! Nested event loops for menus, popups and drag operations.
+//!
+//! While a menu is open or something is dragged, a program usually runs a second event loop that
+//! only handles the events of the menu or the drag operation. All other events, e.g. `Expose`
+//! events for the main window, must not get lost, but also must not be handled in the middle of
+//! the operation. [`ModalLoop`] implements such a loop: It returns the events that match a
+//! predicate and sets the other events aside. When the loop is finished, the events that were set
+//! aside are handed back to the outer loop in their original order.
+//!
+//! The modal loop can also grab the pointer and the keyboard. [`ModalLoop::finish`] releases the
+//! grabs and waits until the X11 server has processed this. Events that match the predicate and
+//! were generated before the grab was released still belong to the modal operation and are
+//! dropped, so that e.g. the `ButtonRelease` that closes a menu does not end up in the outer loop.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use x11rb::connection::Connection;
+//! use x11rb::modal_loop::ModalLoop;
+//! use x11rb::protocol::xproto::{EventMask, GrabStatus};
+//! use x11rb::protocol::Event;
+//! use x11rb::NONE;
+//!
+//! let (conn, _) = x11rb::connect(None)?;
+//! let menu = 0x1234;
+//!
+//! let mut modal = ModalLoop::new(&conn, |event: &Event| event.window() == Some(menu));
+//! let mask = EventMask::BUTTON_PRESS | EventMask::BUTTON_RELEASE | EventMask::POINTER_MOTION;
+//! if modal.grab_pointer(menu, true, mask, NONE, x11rb::CURRENT_TIME)? == GrabStatus::SUCCESS {
+//!     loop {
+//!         match modal.next_event()? {
+//!             Event::ButtonRelease(_) => break,
+//!             event => println!("Menu event {:?}", event),
+//!         }
+//!     }
+//! }
+//! for event in modal.finish()? {
+//!     println!("Deferred event {:?}", event);
+//! }
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::collections::VecDeque;
+use std::fmt;
+
+use crate::connection::Connection;
+use crate::errors::{ConnectionError, ReplyError};
+use crate::protocol::xproto::{
+    ConnectionExt as _, Cursor, EventMask, GrabMode, GrabStatus, Timestamp, Window,
+};
+use crate::protocol::Event;
+use crate::server_time::event_time;
+use crate::{CURRENT_TIME, NONE};
+
+/// A nested event loop that only returns the events that match a predicate.
+///
+/// See the [module documentation](self) for details. Grabs that are still active when the loop is
+/// dropped without calling [`ModalLoop::finish`] are released, but the events that were set aside
+/// are lost in this case.
+pub struct ModalLoop<'a, C: Connection, F> {
+    conn: &'a C,
+    predicate: F,
+    deferred: VecDeque<Event>,
+    pointer_grabbed: bool,
+    keyboard_grabbed: bool,
+    last_time: Timestamp,
+}
+
+impl<C: Connection, F> fmt::Debug for ModalLoop<'_, C, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ModalLoop")
+            .field("deferred", &self.deferred)
+            .field("pointer_grabbed", &self.pointer_grabbed)
+            .field("keyboard_grabbed", &self.keyboard_grabbed)
+            .field("last_time", &self.last_time)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, C: Connection, F: FnMut(&Event) -> bool> ModalLoop<'a, C, F> {
+    /// Create a new modal loop that returns the events for which `predicate` returns `true`.
+    pub fn new(conn: &'a C, predicate: F) -> Self {
+        Self {
+            conn,
+            predicate,
+            deferred: VecDeque::new(),
+            pointer_grabbed: false,
+            keyboard_grabbed: false,
+            last_time: CURRENT_TIME,
+        }
+    }
+
+    /// Actively grab the pointer for the duration of the modal loop.
+    ///
+    /// This sends a `GrabPointer` request in asynchronous mode and waits for its reply. If the
+    /// grab succeeds, it is released by [`ModalLoop::finish`]. `owner_events` is usually `true`
+    /// for menus so that events for the program's other windows are reported normally.
+    pub fn grab_pointer(
+        &mut self,
+        grab_window: Window,
+        owner_events: bool,
+        event_mask: EventMask,
+        cursor: Cursor,
+        time: Timestamp,
+    ) -> Result<GrabStatus, ReplyError> {
+        let status = self
+            .conn
+            .grab_pointer(
+                owner_events,
+                grab_window,
+                event_mask,
+                GrabMode::ASYNC,
+                GrabMode::ASYNC,
+                NONE,
+                cursor,
+                time,
+            )?
+            .reply()?
+            .status;
+        if status == GrabStatus::SUCCESS {
+            self.pointer_grabbed = true;
+        }
+        Ok(status)
+    }
+
+    /// Actively grab the keyboard for the duration of the modal loop.
+    ///
+    /// This sends a `GrabKeyboard` request in asynchronous mode and waits for its reply. If the
+    /// grab succeeds, it is released by [`ModalLoop::finish`].
+    pub fn grab_keyboard(
+        &mut self,
+        grab_window: Window,
+        owner_events: bool,
+        time: Timestamp,
+    ) -> Result<GrabStatus, ReplyError> {
+        let status = self
+            .conn
+            .grab_keyboard(
+                owner_events,
+                grab_window,
+                time,
+                GrabMode::ASYNC,
+                GrabMode::ASYNC,
+            )?
+            .reply()?
+            .status;
+        if status == GrabStatus::SUCCESS {
+            self.keyboard_grabbed = true;
+        }
+        Ok(status)
+    }
+
+    /// Wait for the next event that matches the predicate.
+    ///
+    /// Other events are set aside until the loop is finished.
+    pub fn next_event(&mut self) -> Result<Event, ConnectionError> {
+        loop {
+            let event = self.conn.wait_for_event()?;
+            if let Some(event) = self.filter(event) {
+                return Ok(event);
+            }
+        }
+    }
+
+    /// Get the next event that matches the predicate without blocking.
+    ///
+    /// Other events are set aside until the loop is finished.
+    pub fn poll_for_event(&mut self) -> Result<Option<Event>, ConnectionError> {
+        while let Some(event) = self.conn.poll_for_event()? {
+            if let Some(event) = self.filter(event) {
+                return Ok(Some(event));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Get the events that were set aside so far.
+    pub fn deferred_events(&self) -> &VecDeque<Event> {
+        &self.deferred
+    }
+
+    /// End the modal loop and return the events that were set aside.
+    ///
+    /// Active grabs are released with the timestamp of the last event that the loop received.
+    /// Afterwards, this waits until the X11 server processed the release. Events that arrive in
+    /// the meantime and match the predicate are dropped, all other events are added to the
+    /// returned queue. The outer event loop should handle the returned events before it fetches
+    /// new events from the connection.
+    pub fn finish(mut self) -> Result<VecDeque<Event>, ReplyError> {
+        self.ungrab()?;
+        let cookie = self.conn.get_input_focus()?;
+        let _ = cookie.reply()?;
+        while let Some(event) = self.conn.poll_for_event()? {
+            if !(self.predicate)(&event) {
+                self.deferred.push_back(event);
+            }
+        }
+        Ok(std::mem::take(&mut self.deferred))
+    }
+
+    /// Remember the time of the event and set it aside if it does not match the predicate.
+    fn filter(&mut self, event: Event) -> Option<Event> {
+        if let Some(time) = event_time(&event) {
+            self.last_time = time;
+        }
+        if (self.predicate)(&event) {
+            Some(event)
+        } else {
+            self.deferred.push_back(event);
+            None
+        }
+    }
+
+    /// Release all active grabs.
+    fn ungrab(&mut self) -> Result<(), ConnectionError> {
+        if std::mem::take(&mut self.pointer_grabbed) {
+            let _ = self.conn.ungrab_pointer(self.last_time)?;
+        }
+        if std::mem::take(&mut self.keyboard_grabbed) {
+            let _ = self.conn.ungrab_keyboard(self.last_time)?;
+        }
+        Ok(())
+    }
+}
+
+impl<C: Connection, F> Drop for ModalLoop<'_, C, F> {
+    fn drop(&mut self) {
+        // Errors cannot be reported here; they show up on the next use of the connection
+        if self.pointer_grabbed {
+            let _ = self.conn.ungrab_pointer(self.last_time);
+        }
+        if self.keyboard_grabbed {
+            let _ = self.conn.ungrab_keyboard(self.last_time);
+        }
+        let _ = self.conn.flush();
+    }
+}
@@ -6,6 +6,30 @@ use crate::errors::{ConnectionError, ParseError, ReplyError};
 use crate::protocol::xproto::{self, Atom, AtomEnum, GetPropertyReply, Window};
 use crate::x11_utils::{Serialize, TryParse};
 
+pub use atoms::{IcccmAtoms, IcccmAtomsCookie};
+
+mod atoms {
+    // The code that the macro generates has no documentation and a `new` function that returns a
+    // cookie
+    #![allow(missing_docs, clippy::new_ret_no_self)]
+
+    crate::atom_manager! {
+        /// The atoms of the ICCCM that are not predefined.
+        pub IcccmAtoms:
+        /// A cookie for interning the atoms of the ICCCM.
+        IcccmAtomsCookie {
+            UTF8_STRING,
+            COMPOUND_TEXT,
+            WM_STATE,
+            WM_CHANGE_STATE,
+            WM_COLORMAP_WINDOWS,
+            WM_PROTOCOLS,
+            WM_DELETE_WINDOW,
+            WM_TAKE_FOCUS,
+        }
+    }
+}
+
 macro_rules! property_cookie {
     {
         $(#[$meta:meta])*
@@ -39,6 +63,41 @@ macro_rules! property_cookie {
     }
 }
 
+macro_rules! property_cookie_with_atoms {
+    {
+        $(#[$meta:meta])*
+        pub struct $cookie_name:ident: $struct_name:ident,
+    } => {
+        $(#[$meta])*
+        #[derive(Debug)]
+        pub struct $cookie_name<'a, Conn: RequestConnection + ?Sized>(
+            Cookie<'a, Conn, GetPropertyReply>,
+            IcccmAtoms,
+        );
+
+        impl<'a, Conn> $cookie_name<'a, Conn>
+        where
+            Conn: RequestConnection + ?Sized,
+        {
+            /// Get the reply that the server sent.
+            pub fn reply(self) -> Result<Option<$struct_name>, ReplyError> {
+                Ok($struct_name::from_reply(&self.0.reply()?, &self.1)?)
+            }
+
+            /// Get the reply that the server sent, but have errors handled as events.
+            pub fn reply_unchecked(self) -> Result<Option<$struct_name>, ConnectionError> {
+                let atoms = self.1;
+                self.0
+                    .reply_unchecked()?
+                    .map(|reply| $struct_name::from_reply(&reply, &atoms))
+                    .transpose()
+                    .map(|e| e.flatten())
+                    .map_err(Into::into)
+            }
+        }
+    }
+}
+
 // WM_CLASS
 
 property_cookie! {
@@ -127,6 +186,38 @@ impl WmClass {
         Ok(Some(WmClass(reply, offset)))
     }
 
+    /// Create a `WM_CLASS` property with the given instance and class name.
+    pub fn new(instance: &[u8], class: &[u8]) -> Self {
+        let value = [instance, b"\0", class, b"\0"].concat();
+        let reply = GetPropertyReply {
+            format: 8,
+            type_: AtomEnum::STRING.into(),
+            value_len: value.len().try_into().unwrap(),
+            value,
+            ..Default::default()
+        };
+        WmClass(reply, instance.len())
+    }
+
+    /// Set this `WM_CLASS` property on some window.
+    pub fn set<'a, C: RequestConnection + ?Sized>(
+        &self,
+        conn: &'a C,
+        window: Window,
+    ) -> Result<VoidCookie<'a, C>, ConnectionError> {
+        let data = &self.0.value;
+        xproto::change_property(
+            conn,
+            xproto::PropMode::REPLACE,
+            window,
+            AtomEnum::WM_CLASS,
+            AtomEnum::STRING,
+            8,
+            data.len().try_into().unwrap(),
+            data,
+        )
+    }
+
     /// Get the instance contained in this `WM_CLASS` property
     pub fn instance(&self) -> &[u8] {
         &self.0.value[0..self.1]
@@ -662,6 +753,806 @@ impl Serialize for WmHints {
     }
 }
 
+// TEXT properties, e.g. WM_NAME, WM_ICON_NAME and WM_CLIENT_MACHINE
+
+property_cookie_with_atoms! {
+    /// A cookie for getting a property of type `TEXT`.
+    ///
+    /// See `TextProperty`.
+    pub struct TextPropertyCookie: TextProperty,
+}
+
+impl<'a, Conn> TextPropertyCookie<'a, Conn>
+where
+    Conn: RequestConnection + ?Sized,
+{
+    /// Send a `GetProperty` request for the given property of the given window.
+    pub fn new(
+        conn: &'a Conn,
+        window: Window,
+        property: impl Into<Atom>,
+        atoms: &IcccmAtoms,
+    ) -> Result<Self, ConnectionError> {
+        let cookie =
+            xproto::get_property(conn, false, window, property, AtomEnum::ANY, 0, u32::MAX)?;
+        Ok(Self(cookie, *atoms))
+    }
+}
+
+/// The encodings of a property of type `TEXT`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TextEncoding {
+    /// The type `STRING`, which contains ISO Latin-1 text.
+    String,
+    /// The type `UTF8_STRING`, which contains UTF-8 text.
+    Utf8String,
+    /// The type `COMPOUND_TEXT`, which switches between character sets with escape sequences.
+    CompoundText,
+}
+
+/// A property of type `TEXT`, e.g. `WM_NAME`, `WM_ICON_NAME` or `WM_CLIENT_MACHINE`.
+///
+/// ICCCM allows several encodings for text. [`TextProperty::new`] uses `STRING` when possible so
+/// that old clients can read the text, and `UTF8_STRING` otherwise. When reading, all three
+/// encodings are converted to a Rust string, see [`TextProperty::to_string_lossy`].
+///
+/// ```no_run
+/// use x11rb::properties::{IcccmAtoms, TextProperty};
+/// use x11rb::protocol::xproto::AtomEnum;
+///
+/// let (conn, _) = x11rb::connect(None)?;
+/// # let window = 0;
+/// let atoms = IcccmAtoms::new(&conn)?.reply()?;
+/// TextProperty::new("Grüße").set(&conn, window, AtomEnum::WM_ICON_NAME, &atoms)?;
+/// let machine = TextProperty::get(&conn, window, AtomEnum::WM_CLIENT_MACHINE, &atoms)?.reply()?;
+/// println!("{:?}", machine.map(|text| text.to_string_lossy()));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextProperty {
+    encoding: TextEncoding,
+    value: Vec<u8>,
+}
+
+impl TextProperty {
+    /// Encode text as `STRING` if it only contains ISO Latin-1 characters and as `UTF8_STRING`
+    /// otherwise.
+    pub fn new(text: &str) -> Self {
+        match encode_latin1(text) {
+            Some(value) => Self::from_raw(TextEncoding::String, value),
+            None => Self::from_raw(TextEncoding::Utf8String, text.as_bytes().to_vec()),
+        }
+    }
+
+    /// Encode text as `COMPOUND_TEXT`.
+    ///
+    /// This is meant for clients that do not understand `UTF8_STRING`. ISO Latin-1 characters
+    /// are encoded directly and all other characters in UTF-8 segments.
+    pub fn compound_text(text: &str) -> Self {
+        Self::from_raw(TextEncoding::CompoundText, encode_compound_text(text))
+    }
+
+    /// Create a property from an encoded value.
+    pub fn from_raw(encoding: TextEncoding, value: Vec<u8>) -> Self {
+        Self { encoding, value }
+    }
+
+    /// Send a `GetProperty` request for the given property of the given window.
+    pub fn get<'a, C: RequestConnection>(
+        conn: &'a C,
+        window: Window,
+        property: impl Into<Atom>,
+        atoms: &IcccmAtoms,
+    ) -> Result<TextPropertyCookie<'a, C>, ConnectionError> {
+        TextPropertyCookie::new(conn, window, property, atoms)
+    }
+
+    /// Construct a new `TextProperty` instance from a `GetPropertyReply`.
+    ///
+    /// This fails with [`ParseError::InvalidValue`] if the property does not have one of the
+    /// types of [`TextEncoding`].
+    pub fn from_reply(
+        reply: &GetPropertyReply,
+        atoms: &IcccmAtoms,
+    ) -> Result<Option<Self>, ParseError> {
+        if reply.type_ == AtomEnum::NONE.into() {
+            return Ok(None);
+        }
+        let encoding = if reply.type_ == AtomEnum::STRING.into() {
+            TextEncoding::String
+        } else if reply.type_ == atoms.UTF8_STRING {
+            TextEncoding::Utf8String
+        } else if reply.type_ == atoms.COMPOUND_TEXT {
+            TextEncoding::CompoundText
+        } else {
+            return Err(ParseError::InvalidValue);
+        };
+        if reply.format != 8 {
+            return Err(ParseError::InvalidValue);
+        }
+        Ok(Some(Self::from_raw(encoding, reply.value.clone())))
+    }
+
+    /// Get the encoding of the text.
+    pub fn encoding(&self) -> TextEncoding {
+        self.encoding
+    }
+
+    /// Get the encoded text.
+    pub fn value(&self) -> &[u8] {
+        &self.value
+    }
+
+    /// Decode the text.
+    ///
+    /// Invalid UTF-8 and characters of `COMPOUND_TEXT` in other character sets than ASCII, ISO
+    /// Latin-1 and UTF-8 are replaced with `U+FFFD`.
+    pub fn to_string_lossy(&self) -> String {
+        match self.encoding {
+            TextEncoding::String => self.value.iter().map(|&byte| char::from(byte)).collect(),
+            TextEncoding::Utf8String => String::from_utf8_lossy(&self.value).into_owned(),
+            TextEncoding::CompoundText => decode_compound_text(&self.value),
+        }
+    }
+
+    /// Set this text as the given property of some window.
+    pub fn set<'a, C: RequestConnection + ?Sized>(
+        &self,
+        conn: &'a C,
+        window: Window,
+        property: impl Into<Atom>,
+        atoms: &IcccmAtoms,
+    ) -> Result<VoidCookie<'a, C>, ConnectionError> {
+        let type_ = match self.encoding {
+            TextEncoding::String => AtomEnum::STRING.into(),
+            TextEncoding::Utf8String => atoms.UTF8_STRING,
+            TextEncoding::CompoundText => atoms.COMPOUND_TEXT,
+        };
+        xproto::change_property(
+            conn,
+            xproto::PropMode::REPLACE,
+            window,
+            property,
+            type_,
+            8,
+            self.value.len().try_into().unwrap(),
+            &self.value,
+        )
+    }
+}
+
+/// Encode text as ISO Latin-1, if possible.
+fn encode_latin1(text: &str) -> Option<Vec<u8>> {
+    text.chars().map(|c| u8::try_from(c).ok()).collect()
+}
+
+// Escape sequences of COMPOUND_TEXT for switching to and from UTF-8
+const CT_UTF8_START: &[u8] = b"\x1b%G";
+const CT_UTF8_END: &[u8] = b"\x1b%@";
+
+/// Encode text as `COMPOUND_TEXT` with UTF-8 segments for characters outside of ISO Latin-1.
+fn encode_compound_text(text: &str) -> Vec<u8> {
+    let mut result = Vec::with_capacity(text.len());
+    let mut in_utf8 = false;
+    for c in text.chars() {
+        // The C1 control characters are not allowed in COMPOUND_TEXT
+        match u8::try_from(c) {
+            Ok(byte) if !(0x80..0xa0).contains(&byte) => {
+                if in_utf8 {
+                    result.extend_from_slice(CT_UTF8_END);
+                    in_utf8 = false;
+                }
+                result.push(byte);
+            }
+            _ => {
+                if !in_utf8 {
+                    result.extend_from_slice(CT_UTF8_START);
+                    in_utf8 = true;
+                }
+                let mut buffer = [0; 4];
+                result.extend_from_slice(c.encode_utf8(&mut buffer).as_bytes());
+            }
+        }
+    }
+    if in_utf8 {
+        result.extend_from_slice(CT_UTF8_END);
+    }
+    result
+}
+
+/// Decode `COMPOUND_TEXT` with ASCII, ISO Latin-1 and UTF-8 segments.
+fn decode_compound_text(mut value: &[u8]) -> String {
+    let mut result = String::with_capacity(value.len());
+    // Whether the left and right half of the byte values are ASCII and ISO Latin-1
+    let (mut gl_ascii, mut gr_latin1) = (true, true);
+    while let Some((&byte, rest)) = value.split_first() {
+        value = rest;
+        if byte == 0x1b {
+            // An escape sequence ends with a byte in the range 0x30 to 0x7e
+            let len = rest
+                .iter()
+                .position(|byte| (0x30..0x7f).contains(byte))
+                .map_or(rest.len(), |pos| pos + 1);
+            let (sequence, rest) = rest.split_at(len);
+            value = rest;
+            match sequence {
+                b"%G" => {
+                    let end = rest
+                        .windows(CT_UTF8_END.len())
+                        .position(|window| window == CT_UTF8_END)
+                        .unwrap_or(rest.len());
+                    result.push_str(&String::from_utf8_lossy(&rest[..end]));
+                    value = rest.get(end + CT_UTF8_END.len()..).unwrap_or_default();
+                }
+                b"(B" => gl_ascii = true,
+                b"-A" => gr_latin1 = true,
+                [b'(', ..] | [b'$', b'(', ..] => gl_ascii = false,
+                [b')', ..] | [b'-', ..] | [b'$', ..] => gr_latin1 = false,
+                _ => {}
+            }
+            continue;
+        }
+        if byte == 0x9b {
+            // A control sequence for the text direction ends with a byte in the range 0x40 to 0x7e
+            let len = rest
+                .iter()
+                .position(|byte| (0x40..0x7f).contains(byte))
+                .map_or(rest.len(), |pos| pos + 1);
+            value = &rest[len..];
+            continue;
+        }
+        let known = match byte {
+            b'\t' | b'\n' => true,
+            0x20..=0x7f => gl_ascii,
+            0xa0..=0xff => gr_latin1,
+            // Other control characters
+            _ => continue,
+        };
+        result.push(if known {
+            char::from(byte)
+        } else {
+            char::REPLACEMENT_CHARACTER
+        });
+    }
+    result
+}
+
+// WM_STATE
+
+property_cookie_with_atoms! {
+    /// A cookie for getting a window's `WM_STATE` property.
+    ///
+    /// See `WmState`.
+    pub struct WmStateCookie: WmState,
+}
+
+impl<'a, Conn> WmStateCookie<'a, Conn>
+where
+    Conn: RequestConnection + ?Sized,
+{
+    /// Send a `GetProperty` request for the `WM_STATE` property of the given window.
+    pub fn new(
+        conn: &'a Conn,
+        window: Window,
+        atoms: &IcccmAtoms,
+    ) -> Result<Self, ConnectionError> {
+        let cookie =
+            xproto::get_property(conn, false, window, atoms.WM_STATE, atoms.WM_STATE, 0, 2)?;
+        Ok(Self(cookie, *atoms))
+    }
+}
+
+/// The state of a top-level window as described in ICCCM §4.1.3.1.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum WindowState {
+    /// The window is not mapped and not managed by the window manager.
+    Withdrawn,
+    /// The window is visible.
+    Normal,
+    /// The window is iconified.
+    Iconic,
+}
+
+/// A structure representing a `WM_STATE` property.
+///
+/// The window manager sets this property on the top-level windows that it manages.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct WmState {
+    /// The state of the window.
+    pub state: WindowState,
+
+    /// The window that is used as icon, or zero.
+    pub icon: Window,
+}
+
+impl WmState {
+    /// Send a `GetProperty` request for the `WM_STATE` property of the given window.
+    pub fn get<'a, C: RequestConnection>(
+        conn: &'a C,
+        window: Window,
+        atoms: &IcccmAtoms,
+    ) -> Result<WmStateCookie<'a, C>, ConnectionError> {
+        WmStateCookie::new(conn, window, atoms)
+    }
+
+    /// Construct a new `WmState` instance from a `GetPropertyReply`.
+    pub fn from_reply(
+        reply: &GetPropertyReply,
+        atoms: &IcccmAtoms,
+    ) -> Result<Option<Self>, ParseError> {
+        if reply.type_ == AtomEnum::NONE.into() {
+            return Ok(None);
+        }
+        if reply.type_ != atoms.WM_STATE || reply.format != 32 {
+            return Err(ParseError::InvalidValue);
+        }
+        Ok(Some(Self::try_parse(&reply.value)?.0))
+    }
+
+    /// Set this `WM_STATE` on some window.
+    pub fn set<'a, C: RequestConnection + ?Sized>(
+        &self,
+        conn: &'a C,
+        window: Window,
+        atoms: &IcccmAtoms,
+    ) -> Result<VoidCookie<'a, C>, ConnectionError> {
+        let data = self.serialize();
+        xproto::change_property(
+            conn,
+            xproto::PropMode::REPLACE,
+            window,
+            atoms.WM_STATE,
+            atoms.WM_STATE,
+            32,
+            2,
+            &data,
+        )
+    }
+}
+
+impl TryParse for WmState {
+    fn try_parse(remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        let (state, remaining) = u32::try_parse(remaining)?;
+        let (icon, remaining) = u32::try_parse(remaining)?;
+        let state = match state {
+            0 => WindowState::Withdrawn,
+            1 => WindowState::Normal,
+            3 => WindowState::Iconic,
+            _ => return Err(ParseError::InvalidValue),
+        };
+        Ok((Self { state, icon }, remaining))
+    }
+}
+
+impl Serialize for WmState {
+    type Bytes = [u8; 8];
+
+    fn serialize(&self) -> Self::Bytes {
+        let state: u32 = match self.state {
+            WindowState::Withdrawn => 0,
+            WindowState::Normal => 1,
+            WindowState::Iconic => 3,
+        };
+        let [a, b, c, d] = state.serialize();
+        let [e, f, g, h] = self.icon.serialize();
+        [a, b, c, d, e, f, g, h]
+    }
+
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(&self.serialize());
+    }
+}
+
+// WM_TRANSIENT_FOR
+
+property_cookie! {
+    /// A cookie for getting a window's `WM_TRANSIENT_FOR` property.
+    ///
+    /// See `WmTransientFor`.
+    pub struct WmTransientForCookie: WmTransientFor,
+    |reply| WmTransientFor::from_reply(&reply),
+}
+
+impl<'a, Conn> WmTransientForCookie<'a, Conn>
+where
+    Conn: RequestConnection + ?Sized,
+{
+    /// Send a `GetProperty` request for the `WM_TRANSIENT_FOR` property of the given window.
+    pub fn new(conn: &'a Conn, window: Window) -> Result<Self, ConnectionError> {
+        Ok(Self(xproto::get_property(
+            conn,
+            false,
+            window,
+            AtomEnum::WM_TRANSIENT_FOR,
+            AtomEnum::WINDOW,
+            0,
+            1,
+        )?))
+    }
+}
+
+/// The value of a window's `WM_TRANSIENT_FOR` property.
+///
+/// A transient window, e.g. a dialog, belongs to the contained window.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct WmTransientFor(pub Window);
+
+impl WmTransientFor {
+    /// Send a `GetProperty` request for the `WM_TRANSIENT_FOR` property of the given window.
+    pub fn get<C: RequestConnection>(
+        conn: &C,
+        window: Window,
+    ) -> Result<WmTransientForCookie<'_, C>, ConnectionError> {
+        WmTransientForCookie::new(conn, window)
+    }
+
+    /// Construct a new `WmTransientFor` instance from a `GetPropertyReply`.
+    pub fn from_reply(reply: &GetPropertyReply) -> Result<Option<Self>, ParseError> {
+        if reply.type_ == AtomEnum::NONE.into() {
+            return Ok(None);
+        }
+        if reply.type_ != AtomEnum::WINDOW.into() || reply.format != 32 {
+            return Err(ParseError::InvalidValue);
+        }
+        Ok(Some(Self(Window::try_parse(&reply.value)?.0)))
+    }
+
+    /// Set this `WM_TRANSIENT_FOR` property on some window.
+    pub fn set<'a, C: RequestConnection + ?Sized>(
+        &self,
+        conn: &'a C,
+        window: Window,
+    ) -> Result<VoidCookie<'a, C>, ConnectionError> {
+        xproto::change_property(
+            conn,
+            xproto::PropMode::REPLACE,
+            window,
+            AtomEnum::WM_TRANSIENT_FOR,
+            AtomEnum::WINDOW,
+            32,
+            1,
+            &self.0.serialize(),
+        )
+    }
+}
+
+// WM_COLORMAP_WINDOWS
+
+property_cookie! {
+    /// A cookie for getting a window's `WM_COLORMAP_WINDOWS` property.
+    ///
+    /// See `WmColormapWindows`.
+    pub struct WmColormapWindowsCookie: WmColormapWindows,
+    |reply| WmColormapWindows::from_reply(&reply),
+}
+
+impl<'a, Conn> WmColormapWindowsCookie<'a, Conn>
+where
+    Conn: RequestConnection + ?Sized,
+{
+    /// Send a `GetProperty` request for the `WM_COLORMAP_WINDOWS` property of the given window.
+    pub fn new(
+        conn: &'a Conn,
+        window: Window,
+        atoms: &IcccmAtoms,
+    ) -> Result<Self, ConnectionError> {
+        Ok(Self(xproto::get_property(
+            conn,
+            false,
+            window,
+            atoms.WM_COLORMAP_WINDOWS,
+            AtomEnum::WINDOW,
+            0,
+            u32::MAX,
+        )?))
+    }
+}
+
+/// The value of a window's `WM_COLORMAP_WINDOWS` property.
+///
+/// The list contains the subwindows of a top-level window that need other colormaps than the
+/// top-level window, in order of their importance.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WmColormapWindows(pub Vec<Window>);
+
+impl WmColormapWindows {
+    /// Send a `GetProperty` request for the `WM_COLORMAP_WINDOWS` property of the given window.
+    pub fn get<'a, C: RequestConnection>(
+        conn: &'a C,
+        window: Window,
+        atoms: &IcccmAtoms,
+    ) -> Result<WmColormapWindowsCookie<'a, C>, ConnectionError> {
+        WmColormapWindowsCookie::new(conn, window, atoms)
+    }
+
+    /// Construct a new `WmColormapWindows` instance from a `GetPropertyReply`.
+    pub fn from_reply(reply: &GetPropertyReply) -> Result<Option<Self>, ParseError> {
+        if reply.type_ == AtomEnum::NONE.into() {
+            return Ok(None);
+        }
+        if reply.type_ != AtomEnum::WINDOW.into() || reply.format != 32 {
+            return Err(ParseError::InvalidValue);
+        }
+        match reply.value32() {
+            Some(windows) => Ok(Some(Self(windows.collect()))),
+            None => Err(ParseError::InvalidValue),
+        }
+    }
+
+    /// Set this `WM_COLORMAP_WINDOWS` property on some window.
+    pub fn set<'a, C: RequestConnection + ?Sized>(
+        &self,
+        conn: &'a C,
+        window: Window,
+        atoms: &IcccmAtoms,
+    ) -> Result<VoidCookie<'a, C>, ConnectionError> {
+        let data = self
+            .0
+            .iter()
+            .flat_map(|window| window.serialize())
+            .collect::<Vec<u8>>();
+        xproto::change_property(
+            conn,
+            xproto::PropMode::REPLACE,
+            window,
+            atoms.WM_COLORMAP_WINDOWS,
+            AtomEnum::WINDOW,
+            32,
+            self.0.len().try_into().unwrap(),
+            &data,
+        )
+    }
+}
+
+// _NET_WM_ICON
+
+property_cookie! {
+    /// A cookie for getting a window's `_NET_WM_ICON` property.
+    ///
+    /// See `NetWmIcons`.
+    pub struct NetWmIconsCookie: NetWmIcons,
+    |reply| NetWmIcons::from_reply(&reply),
+}
+
+impl<'a, Conn> NetWmIconsCookie<'a, Conn>
+where
+    Conn: RequestConnection + ?Sized,
+{
+    /// Send a `GetProperty` request for the `_NET_WM_ICON` property of the given window.
+    ///
+    /// `net_wm_icon` must be the atom `_NET_WM_ICON`.
+    pub fn new(conn: &'a Conn, window: Window, net_wm_icon: Atom) -> Result<Self, ConnectionError> {
+        Ok(Self(xproto::get_property(
+            conn,
+            false,
+            window,
+            net_wm_icon,
+            AtomEnum::CARDINAL,
+            0,
+            u32::MAX,
+        )?))
+    }
+}
+
+/// A single image of a `_NET_WM_ICON` property.
+///
+/// The pixels are stored row by row as `0xAARRGGBB` values without premultiplied alpha.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetWmIcon {
+    /// The width of the icon.
+    pub width: u32,
+
+    /// The height of the icon.
+    pub height: u32,
+
+    /// The `width * height` pixels of the icon.
+    pub pixels: Vec<u32>,
+}
+
+impl NetWmIcon {
+    /// Create an icon from RGBA pixels with one byte per component, e.g. from an `RgbaImage` of
+    /// the `image` crate.
+    ///
+    /// This fails with [`ParseError::InsufficientData`] if `rgba` contains less than `width *
+    /// height` pixels.
+    pub fn from_rgba(width: u32, height: u32, rgba: &[u8]) -> Result<Self, ParseError> {
+        let len = pixel_count(width, height)?;
+        if rgba.len() / 4 < len {
+            return Err(ParseError::InsufficientData);
+        }
+        let pixels = rgba
+            .chunks_exact(4)
+            .take(len)
+            .map(|rgba| u32::from_be_bytes([rgba[3], rgba[0], rgba[1], rgba[2]]))
+            .collect();
+        Ok(Self {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    /// Get the pixels of the icon as RGBA with one byte per component.
+    pub fn to_rgba(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|pixel| {
+                let [alpha, red, green, blue] = pixel.to_be_bytes();
+                [red, green, blue, alpha]
+            })
+            .collect()
+    }
+}
+
+impl Serialize for NetWmIcon {
+    type Bytes = Vec<u8>;
+
+    fn serialize(&self) -> Self::Bytes {
+        let mut result = Vec::new();
+        self.serialize_into(&mut result);
+        result
+    }
+
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        bytes.reserve(4 * (2 + self.pixels.len()));
+        self.width.serialize_into(bytes);
+        self.height.serialize_into(bytes);
+        for pixel in &self.pixels {
+            pixel.serialize_into(bytes);
+        }
+    }
+}
+
+/// The value of a window's `_NET_WM_ICON` property.
+///
+/// The property contains the icon of a window in several sizes, so that a taskbar or window
+/// manager can pick the one that fits best, see [`NetWmIcons::best_for_size`].
+///
+/// ```no_run
+/// use x11rb::properties::{NetWmIcon, NetWmIcons};
+/// use x11rb::protocol::xproto::ConnectionExt;
+///
+/// let (conn, _) = x11rb::connect(None)?;
+/// # let (window, small_rgba, large_rgba) = (0, vec![0; 16 * 16 * 4], vec![0; 32 * 32 * 4]);
+/// let net_wm_icon = conn.intern_atom(false, b"_NET_WM_ICON")?.reply()?.atom;
+/// let icons = NetWmIcons::new(vec![
+///     NetWmIcon::from_rgba(16, 16, &small_rgba)?,
+///     NetWmIcon::from_rgba(32, 32, &large_rgba)?,
+/// ]);
+/// icons.set(&conn, window, net_wm_icon)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NetWmIcons(Vec<NetWmIcon>);
+
+impl NetWmIcons {
+    /// Create a `_NET_WM_ICON` property with the given icons.
+    pub fn new(icons: Vec<NetWmIcon>) -> Self {
+        Self(icons)
+    }
+
+    /// Send a `GetProperty` request for the `_NET_WM_ICON` property of the given window.
+    ///
+    /// `net_wm_icon` must be the atom `_NET_WM_ICON`.
+    pub fn get<C: RequestConnection>(
+        conn: &C,
+        window: Window,
+        net_wm_icon: Atom,
+    ) -> Result<NetWmIconsCookie<'_, C>, ConnectionError> {
+        NetWmIconsCookie::new(conn, window, net_wm_icon)
+    }
+
+    /// Construct a new `NetWmIcons` instance from a `GetPropertyReply`.
+    ///
+    /// The original `GetProperty` request must have been for a `_NET_WM_ICON` property for this
+    /// function to return sensible results.
+    pub fn from_reply(reply: &GetPropertyReply) -> Result<Option<Self>, ParseError> {
+        if reply.type_ == AtomEnum::NONE.into() {
+            return Ok(None);
+        }
+        if reply.type_ != AtomEnum::CARDINAL.into() || reply.format != 32 {
+            return Err(ParseError::InvalidValue);
+        }
+        Ok(Some(Self::try_parse(&reply.value)?.0))
+    }
+
+    /// Get the icons in the order in which they appear in the property.
+    pub fn icons(&self) -> &[NetWmIcon] {
+        &self.0
+    }
+
+    /// Get the icons in the order in which they appear in the property.
+    pub fn into_icons(self) -> Vec<NetWmIcon> {
+        self.0
+    }
+
+    /// Pick the icon that fits best into a square with sides of length `size`.
+    ///
+    /// This is the smallest icon that covers the square, so that it only has to be scaled down.
+    /// If all icons are smaller, the largest one is returned.
+    pub fn best_for_size(&self, size: u32) -> Option<&NetWmIcon> {
+        let area = |icon: &&NetWmIcon| u64::from(icon.width) * u64::from(icon.height);
+        self.0
+            .iter()
+            .filter(|icon| icon.width >= size && icon.height >= size)
+            .min_by_key(area)
+            .or_else(|| self.0.iter().max_by_key(area))
+    }
+
+    /// Set these icons as the `_NET_WM_ICON` property of some window.
+    ///
+    /// `net_wm_icon` must be the atom `_NET_WM_ICON`.
+    pub fn set<'a, C: RequestConnection + ?Sized>(
+        &self,
+        conn: &'a C,
+        window: Window,
+        net_wm_icon: Atom,
+    ) -> Result<VoidCookie<'a, C>, ConnectionError> {
+        let data = self.serialize();
+        xproto::change_property(
+            conn,
+            xproto::PropMode::REPLACE,
+            window,
+            net_wm_icon,
+            AtomEnum::CARDINAL,
+            32,
+            (data.len() / 4).try_into().unwrap(),
+            &data,
+        )
+    }
+}
+
+impl TryParse for NetWmIcons {
+    fn try_parse(mut remaining: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        let mut icons = Vec::new();
+        while !remaining.is_empty() {
+            let (width, rest) = u32::try_parse(remaining)?;
+            let (height, rest) = u32::try_parse(rest)?;
+            let len = pixel_count(width, height)?;
+            if rest.len() / 4 < len {
+                return Err(ParseError::InsufficientData);
+            }
+            let (data, rest) = rest.split_at(4 * len);
+            let pixels = data
+                .chunks_exact(4)
+                .map(|pixel| u32::from_ne_bytes(pixel.try_into().unwrap()))
+                .collect();
+            icons.push(NetWmIcon {
+                width,
+                height,
+                pixels,
+            });
+            remaining = rest;
+        }
+        Ok((Self(icons), remaining))
+    }
+}
+
+impl Serialize for NetWmIcons {
+    type Bytes = Vec<u8>;
+
+    fn serialize(&self) -> Self::Bytes {
+        let mut result = Vec::new();
+        self.serialize_into(&mut result);
+        result
+    }
+
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        for icon in &self.0 {
+            icon.serialize_into(bytes);
+        }
+    }
+}
+
+/// Compute the number of pixels of an icon.
+fn pixel_count(width: u32, height: u32) -> Result<usize, ParseError> {
+    usize::try_from(width)
+        .ok()
+        .zip(usize::try_from(height).ok())
+        .and_then(|(width, height)| width.checked_mul(height))
+        .ok_or(ParseError::InvalidValue)
+}
+
 /// Parse an element of type `T` and turn it into an `Option` by checking if the given `bit` is set
 /// in `flags`.
 fn parse_with_flag<T: TryParse>(
@@ -679,7 +1570,11 @@ fn parse_with_flag<T: TryParse>(
 
 #[cfg(test)]
 mod test {
-    use super::{WmClass, WmHints, WmHintsState, WmSizeHints};
+    use super::{
+        decode_compound_text, IcccmAtoms, NetWmIcon, NetWmIcons, TextEncoding, TextProperty,
+        WindowState, WmClass, WmColormapWindows, WmHints, WmHintsState, WmSizeHints, WmState,
+        WmTransientFor,
+    };
     use crate::protocol::xproto::{Atom, AtomEnum, GetPropertyReply, Gravity};
     use crate::x11_utils::Serialize;
 
@@ -820,4 +1715,167 @@ mod test {
         let wm_hints = WmHints::from_reply(&get_property_reply(&[], 0, AtomEnum::NONE)).unwrap();
         assert!(wm_hints.is_none());
     }
+
+    #[test]
+    fn test_wm_class_new() {
+        let wm_class = WmClass::new(b"xterm", b"XTerm");
+        assert_eq!(
+            (wm_class.instance(), wm_class.class()),
+            (&b"xterm"[..], &b"XTerm"[..])
+        );
+        assert_eq!(wm_class.0.value, b"xterm\0XTerm\0");
+    }
+
+    fn atoms() -> IcccmAtoms {
+        IcccmAtoms {
+            UTF8_STRING: 300,
+            COMPOUND_TEXT: 301,
+            WM_STATE: 302,
+            WM_CHANGE_STATE: 303,
+            WM_COLORMAP_WINDOWS: 304,
+            WM_PROTOCOLS: 305,
+            WM_DELETE_WINDOW: 306,
+            WM_TAKE_FOCUS: 307,
+        }
+    }
+
+    #[test]
+    fn test_text_property_encoding() {
+        let text = TextProperty::new("Grüße");
+        assert_eq!(text.encoding(), TextEncoding::String);
+        assert_eq!(text.value(), b"Gr\xfc\xdfe");
+        assert_eq!(text.to_string_lossy(), "Grüße");
+
+        let text = TextProperty::new("x ≤ y");
+        assert_eq!(text.encoding(), TextEncoding::Utf8String);
+        assert_eq!(text.to_string_lossy(), "x ≤ y");
+
+        let text = TextProperty::compound_text("ü ≤ ∞!");
+        assert_eq!(
+            text.value(),
+            b"\xfc \x1b%G\xe2\x89\xa4\x1b%@ \x1b%G\xe2\x88\x9e\x1b%@!"
+        );
+        assert_eq!(text.to_string_lossy(), "ü ≤ ∞!");
+    }
+
+    #[test]
+    fn test_text_property_from_reply() {
+        let atoms = atoms();
+        for (type_, encoding) in [
+            (AtomEnum::STRING.into(), TextEncoding::String),
+            (atoms.UTF8_STRING, TextEncoding::Utf8String),
+            (atoms.COMPOUND_TEXT, TextEncoding::CompoundText),
+        ] {
+            let reply = get_property_reply(b"abc", 8, type_);
+            let text = TextProperty::from_reply(&reply, &atoms).unwrap().unwrap();
+            assert_eq!(text.encoding(), encoding);
+            assert_eq!(text.to_string_lossy(), "abc");
+        }
+        let reply = get_property_reply(b"abc", 8, AtomEnum::ATOM);
+        assert!(TextProperty::from_reply(&reply, &atoms).is_err());
+        let reply = get_property_reply(&[], 0, AtomEnum::NONE);
+        assert!(TextProperty::from_reply(&reply, &atoms).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_decode_compound_text() {
+        // Switch the right half to ISO 8859-7 (Greek) and back to ISO Latin-1
+        assert_eq!(
+            decode_compound_text(b"a\xe1\x1b-F\xe1\x1b-A\xe1\x9b1]\n"),
+            "aá\u{fffd}á\n"
+        );
+        // Switch the left half to JIS X 0208 and back to ASCII
+        assert_eq!(
+            decode_compound_text(b"\x1b$(Bab\x1b(Bcd"),
+            "\u{fffd}\u{fffd}cd"
+        );
+        // An unterminated UTF-8 segment
+        assert_eq!(decode_compound_text(b"\x1b%G\xe2\x88\x9e"), "∞");
+    }
+
+    #[test]
+    fn test_wm_state() {
+        let atoms = atoms();
+        let input = [3, 0x0060_0009]
+            .iter()
+            .flat_map(|v| u32::serialize(v).to_vec())
+            .collect::<Vec<u8>>();
+        let wm_state = WmState::from_reply(&get_property_reply(&input, 32, atoms.WM_STATE), &atoms)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            wm_state,
+            WmState {
+                state: WindowState::Iconic,
+                icon: 0x0060_0009,
+            }
+        );
+        assert_eq!(input, wm_state.serialize());
+
+        let reply = get_property_reply(&input, 32, AtomEnum::CARDINAL);
+        assert!(WmState::from_reply(&reply, &atoms).is_err());
+    }
+
+    #[test]
+    fn test_wm_transient_for_and_colormap_windows() {
+        let input = [0x0060_0009, 0x0060_0010]
+            .iter()
+            .flat_map(|v| u32::serialize(v).to_vec())
+            .collect::<Vec<u8>>();
+        let reply = get_property_reply(&input, 32, AtomEnum::WINDOW);
+        assert_eq!(
+            WmTransientFor::from_reply(&reply).unwrap(),
+            Some(WmTransientFor(0x0060_0009))
+        );
+        assert_eq!(
+            WmColormapWindows::from_reply(&reply).unwrap(),
+            Some(WmColormapWindows(vec![0x0060_0009, 0x0060_0010]))
+        );
+        let reply = get_property_reply(&input, 32, AtomEnum::CARDINAL);
+        assert!(WmTransientFor::from_reply(&reply).is_err());
+    }
+
+    #[test]
+    fn test_net_wm_icon() {
+        let icon = NetWmIcon::from_rgba(2, 1, &[0x11, 0x22, 0x33, 0x44, 1, 2, 3, 0xff]).unwrap();
+        assert_eq!(icon.pixels, [0x4411_2233, 0xff01_0203]);
+        assert_eq!(icon.to_rgba(), [0x11, 0x22, 0x33, 0x44, 1, 2, 3, 0xff]);
+        assert!(NetWmIcon::from_rgba(2, 2, &[0; 12]).is_err());
+
+        let icons = NetWmIcons::new(vec![
+            icon,
+            NetWmIcon {
+                width: 1,
+                height: 1,
+                pixels: vec![0x8000_00ff],
+            },
+        ]);
+        let input = [2, 1, 0x4411_2233, 0xff01_0203, 1, 1, 0x8000_00ff]
+            .iter()
+            .flat_map(|v| u32::serialize(v).to_vec())
+            .collect::<Vec<u8>>();
+        assert_eq!(input, icons.serialize());
+        let parsed = NetWmIcons::from_reply(&get_property_reply(&input, 32, AtomEnum::CARDINAL))
+            .unwrap()
+            .unwrap();
+        assert_eq!(parsed, icons);
+
+        // The second icon is truncated
+        let reply = get_property_reply(&input[..24], 32, AtomEnum::CARDINAL);
+        assert!(NetWmIcons::from_reply(&reply).is_err());
+    }
+
+    #[test]
+    fn test_net_wm_icon_best_for_size() {
+        let icon = |size| NetWmIcon {
+            width: size,
+            height: size,
+            pixels: vec![0; (size * size) as usize],
+        };
+        let icons = NetWmIcons::new(vec![icon(16), icon(48), icon(32)]);
+        assert_eq!(icons.best_for_size(24).unwrap().width, 32);
+        assert_eq!(icons.best_for_size(32).unwrap().width, 32);
+        assert_eq!(icons.best_for_size(64).unwrap().width, 48);
+        assert_eq!(NetWmIcons::default().best_for_size(16), None);
+    }
 }
@@ -75,3 +75,4 @@ pub use x11rb_protocol::protocol::Request;
 pub use x11rb_protocol::protocol::Reply;
 pub use x11rb_protocol::protocol::ErrorKind;
 pub use x11rb_protocol::protocol::Event;
+pub use x11rb_protocol::protocol::EventKind;
@@ -0,0 +1,216 @@
+//! Observing input events of all clients via the RECORD extension.
+//!
+//! Tools like macro recorders need to see every key press and pointer motion, no matter which
+//! window it goes to. The RECORD extension delivers this data, but in an awkward form: Recording
+//! uses a second connection that blocks in an `EnableContext` request, and the events arrive
+//! packed into the `data` field of its replies in wire format. [`RecordTap`] sets all of this up
+//! and decodes the replies into [`Event`]s.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use x11rb::protocol::xproto;
+//! use x11rb::record_tap::RecordTap;
+//!
+//! let (ctrl_conn, _) = x11rb::connect(None)?;
+//! let (data_conn, _) = x11rb::connect(None)?;
+//! let tap = RecordTap::new(
+//!     &ctrl_conn,
+//!     data_conn,
+//!     xproto::KEY_PRESS_EVENT,
+//!     xproto::MOTION_NOTIFY_EVENT,
+//! )?;
+//! for event in tap.events()? {
+//!     println!("Recorded event {:?}", event?);
+//! }
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::collections::VecDeque;
+
+use crate::connection::{Connection, RequestConnection};
+use crate::cookie::RecordEnableContextCookie;
+use crate::errors::{ConnectionError, ParseError, ReplyError, ReplyOrIdError};
+use crate::protocol::record::{
+    self, ConnectionExt as _, Context, ExtRange, Range, Range16, Range8,
+};
+use crate::protocol::xproto::GE_GENERIC_EVENT;
+use crate::protocol::Event;
+
+/// The `category` of replies to `EnableContext` that contain data sent by the X11 server.
+const FROM_SERVER: u8 = 0;
+
+/// A recording context that records device events of all clients.
+///
+/// The context is created on a control connection. The events are received on a separate data
+/// connection that is owned by the tap, because `EnableContext` blocks this connection until the
+/// recording is disabled. Dropping the tap disables and frees the context.
+#[derive(Debug)]
+pub struct RecordTap<'c, C: Connection + ?Sized, D: RequestConnection> {
+    ctrl_conn: &'c C,
+    data_conn: D,
+    context: Context,
+}
+
+impl<'c, C: Connection + ?Sized, D: RequestConnection> RecordTap<'c, C, D> {
+    /// Create a recording context for the device events with codes from `first` to `last`.
+    ///
+    /// Device events are the core input events, i.e. `KeyPress` (2) to `MotionNotify` (6). The
+    /// context is created on `ctrl_conn`. `data_conn` must be a different connection to the same
+    /// X11 server and is only used for receiving the recorded events.
+    pub fn new(
+        ctrl_conn: &'c C,
+        data_conn: D,
+        first: u8,
+        last: u8,
+    ) -> Result<Self, ReplyOrIdError> {
+        if ctrl_conn
+            .extension_information(record::X11_EXTENSION_NAME)?
+            .is_none()
+        {
+            return Err(ConnectionError::UnsupportedExtension.into());
+        }
+        let (major, minor) = record::X11_XML_VERSION;
+        let _ = ctrl_conn
+            .record_query_version(major as _, minor as _)?
+            .reply()?;
+
+        let context = ctrl_conn.generate_id()?;
+        let empty = Range8 { first: 0, last: 0 };
+        let empty_ext = ExtRange {
+            major: empty,
+            minor: Range16 { first: 0, last: 0 },
+        };
+        let range = Range {
+            core_requests: empty,
+            core_replies: empty,
+            ext_requests: empty_ext,
+            ext_replies: empty_ext,
+            delivered_events: empty,
+            device_events: Range8 { first, last },
+            errors: empty,
+            client_started: false,
+            client_died: false,
+        };
+        ctrl_conn
+            .record_create_context(context, 0, &[record::CS::ALL_CLIENTS.into()], &[range])?
+            .check()?;
+        Ok(Self {
+            ctrl_conn,
+            data_conn,
+            context,
+        })
+    }
+
+    /// Get the recording context.
+    pub fn context(&self) -> Context {
+        self.context
+    }
+
+    /// Enable the recording and get an iterator over the recorded events.
+    ///
+    /// The iterator blocks until events are recorded. It ends after the recording was stopped with
+    /// [`RecordTap::disable`], which has to be called from another thread.
+    pub fn events(&self) -> Result<RecordedEvents<'_, D>, ConnectionError> {
+        Ok(RecordedEvents {
+            conn: &self.data_conn,
+            cookie: self.data_conn.record_enable_context(self.context)?,
+            pending: VecDeque::new(),
+        })
+    }
+
+    /// Stop the recording.
+    pub fn disable(&self) -> Result<(), ReplyError> {
+        self.ctrl_conn.record_disable_context(self.context)?.check()
+    }
+}
+
+impl<C: Connection + ?Sized, D: RequestConnection> Drop for RecordTap<'_, C, D> {
+    fn drop(&mut self) {
+        // Errors cannot be reported here; they show up on the next use of the connection
+        let _ = self.ctrl_conn.record_disable_context(self.context);
+        let _ = self.ctrl_conn.record_free_context(self.context);
+    }
+}
+
+/// An iterator over the events that are recorded by a [`RecordTap`].
+///
+/// Data that was recorded from clients with a different byte order is skipped.
+#[derive(Debug)]
+pub struct RecordedEvents<'a, D: RequestConnection> {
+    conn: &'a D,
+    cookie: RecordEnableContextCookie<'a, D>,
+    pending: VecDeque<Event>,
+}
+
+impl<D: RequestConnection> Iterator for RecordedEvents<'_, D> {
+    type Item = Result<Event, ReplyError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.pending.is_empty() {
+            let reply = match self.cookie.next()? {
+                Ok(reply) => reply,
+                Err(err) => return Some(Err(err)),
+            };
+            if reply.category != FROM_SERVER || reply.client_swapped {
+                continue;
+            }
+            for event in split_events(&reply.data) {
+                match event.and_then(|event| self.conn.parse_event(event)) {
+                    Ok(event) => self.pending.push_back(event),
+                    Err(err) => return Some(Err(err.into())),
+                }
+            }
+        }
+        self.pending.pop_front().map(Ok)
+    }
+}
+
+/// Split recorded data into the individual events in wire format.
+fn split_events(mut data: &[u8]) -> impl Iterator<Item = Result<&[u8], ParseError>> {
+    std::iter::from_fn(move || {
+        if data.is_empty() {
+            return None;
+        }
+        if data.len() < 32 {
+            data = &[];
+            return Some(Err(ParseError::InsufficientData));
+        }
+        // Generic events are longer than 32 bytes
+        let mut length = 32;
+        if data[0] & 0x7f == GE_GENERIC_EVENT {
+            let extra = u32::from_ne_bytes([data[4], data[5], data[6], data[7]]);
+            let extra = usize::try_from(extra).unwrap_or(usize::MAX);
+            length = extra.saturating_mul(4).saturating_add(length);
+        }
+        if data.len() < length {
+            data = &[];
+            return Some(Err(ParseError::InsufficientData));
+        }
+        let (event, remaining) = data.split_at(length);
+        data = remaining;
+        Some(Ok(event))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::split_events;
+    use crate::errors::ParseError;
+
+    #[test]
+    fn split() {
+        let mut data = vec![0; 32 + 40 + 20];
+        data[0] = 2;
+        data[32] = 35;
+        data[36..40].copy_from_slice(&2u32.to_ne_bytes());
+        let events = split_events(&data).collect::<Vec<_>>();
+        assert_eq!(
+            events
+                .iter()
+                .map(|e| e.map(<[u8]>::len))
+                .collect::<Vec<_>>(),
+            [Ok(32), Ok(40), Err(ParseError::InsufficientData)]
+        );
+    }
+}
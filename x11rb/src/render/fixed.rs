@@ -0,0 +1,221 @@
+//! Conversions between floating-point numbers and the fixed-point types of RENDER.
+
+use crate::protocol::render::{Fixed, Linefix, Pointfix, Transform, Trapezoid};
+
+/// The value of 1.0 in the fixed-point format of RENDER.
+const ONE: f64 = 65536.0;
+
+/// Convert a floating-point number into the 16.16 fixed-point format of RENDER.
+///
+/// The value is rounded to the nearest representable number and saturates at the bounds of the
+/// format.
+pub fn to_fixed(value: impl Into<f64>) -> Fixed {
+    (value.into() * ONE).round() as Fixed
+}
+
+/// Convert a fixed-point number of RENDER into a floating-point number.
+pub fn from_fixed(value: Fixed) -> f64 {
+    f64::from(value) / ONE
+}
+
+/// Convert a point into the fixed-point format of RENDER.
+pub fn to_pointfix((x, y): (f64, f64)) -> Pointfix {
+    Pointfix {
+        x: to_fixed(x),
+        y: to_fixed(y),
+    }
+}
+
+/// Convert a line through two points into the fixed-point format of RENDER.
+pub fn to_linefix(p1: (f64, f64), p2: (f64, f64)) -> Linefix {
+    Linefix {
+        p1: to_pointfix(p1),
+        p2: to_pointfix(p2),
+    }
+}
+
+/// Create a trapezoid between the horizontal lines at `top` and `bottom`.
+///
+/// The left and right edges of the trapezoid lie on the lines through the given points, which do
+/// not have to be at `top` or `bottom`.
+pub fn to_trapezoid(
+    top: f64,
+    bottom: f64,
+    left: ((f64, f64), (f64, f64)),
+    right: ((f64, f64), (f64, f64)),
+) -> Trapezoid {
+    Trapezoid {
+        top: to_fixed(top),
+        bottom: to_fixed(bottom),
+        left: to_linefix(left.0, left.1),
+        right: to_linefix(right.0, right.1),
+    }
+}
+
+/// A projective transformation with floating-point numbers.
+///
+/// RENDER uses the transformation of a picture to map positions in the destination of a
+/// composite operation to positions in the picture. For example, a picture with the
+/// transformation `Matrix::scale(0.5, 0.5)` appears twice as large. Use [`Matrix::invert`] to
+/// get this mapping from a transformation of the picture into the destination.
+///
+/// A `Matrix` converts into and from the [`Transform`] of RENDER.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Matrix(pub [[f64; 3]; 3]);
+
+impl Default for Matrix {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Matrix {
+    /// The transformation that does not change anything.
+    pub fn identity() -> Self {
+        Self([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]])
+    }
+
+    /// A transformation that moves positions by `(tx, ty)`.
+    pub fn translate(tx: f64, ty: f64) -> Self {
+        Self([[1.0, 0.0, tx], [0.0, 1.0, ty], [0.0, 0.0, 1.0]])
+    }
+
+    /// A transformation that scales positions by `(sx, sy)` around the origin.
+    pub fn scale(sx: f64, sy: f64) -> Self {
+        Self([[sx, 0.0, 0.0], [0.0, sy, 0.0], [0.0, 0.0, 1.0]])
+    }
+
+    /// A transformation that rotates positions by `angle` radians around the origin.
+    ///
+    /// Since the y axis of X11 points down, positive angles rotate clockwise on the screen.
+    pub fn rotate(angle: f64) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self([[cos, -sin, 0.0], [sin, cos, 0.0], [0.0, 0.0, 1.0]])
+    }
+
+    /// Combine two transformations so that `self` is applied first and `next` afterwards.
+    #[must_use]
+    pub fn then(self, next: Matrix) -> Self {
+        let (a, b) = (next.0, self.0);
+        let mut result = [[0.0; 3]; 3];
+        for (i, row) in result.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = (0..3).map(|k| a[i][k] * b[k][j]).sum();
+            }
+        }
+        Self(result)
+    }
+
+    /// Get the inverse transformation, or `None` if the matrix cannot be inverted.
+    pub fn invert(self) -> Option<Self> {
+        let m = self.0;
+        let minor = |r1: usize, r2: usize, c1: usize, c2: usize| {
+            m[r1][c1] * m[r2][c2] - m[r1][c2] * m[r2][c1]
+        };
+        let det =
+            m[0][0] * minor(1, 2, 1, 2) - m[0][1] * minor(1, 2, 0, 2) + m[0][2] * minor(1, 2, 0, 1);
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        let adjugate = [
+            [minor(1, 2, 1, 2), -minor(0, 2, 1, 2), minor(0, 1, 1, 2)],
+            [-minor(1, 2, 0, 2), minor(0, 2, 0, 2), -minor(0, 1, 0, 2)],
+            [minor(1, 2, 0, 1), -minor(0, 2, 0, 1), minor(0, 1, 0, 1)],
+        ];
+        Some(Self(adjugate.map(|row| row.map(|value| value / det))))
+    }
+
+    /// Apply the transformation to a point.
+    pub fn apply(&self, (x, y): (f64, f64)) -> (f64, f64) {
+        let m = self.0;
+        let w = m[2][0] * x + m[2][1] * y + m[2][2];
+        (
+            (m[0][0] * x + m[0][1] * y + m[0][2]) / w,
+            (m[1][0] * x + m[1][1] * y + m[1][2]) / w,
+        )
+    }
+}
+
+impl From<Matrix> for Transform {
+    fn from(matrix: Matrix) -> Self {
+        let m = matrix.0.map(|row| row.map(to_fixed));
+        Transform {
+            matrix11: m[0][0],
+            matrix12: m[0][1],
+            matrix13: m[0][2],
+            matrix21: m[1][0],
+            matrix22: m[1][1],
+            matrix23: m[1][2],
+            matrix31: m[2][0],
+            matrix32: m[2][1],
+            matrix33: m[2][2],
+        }
+    }
+}
+
+impl From<Transform> for Matrix {
+    fn from(transform: Transform) -> Self {
+        let t = transform;
+        Self(
+            [
+                [t.matrix11, t.matrix12, t.matrix13],
+                [t.matrix21, t.matrix22, t.matrix23],
+                [t.matrix31, t.matrix32, t.matrix33],
+            ]
+            .map(|row| row.map(from_fixed)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{from_fixed, to_fixed, to_trapezoid, Matrix};
+    use crate::protocol::render::Transform;
+
+    fn assert_close(actual: (f64, f64), expected: (f64, f64)) {
+        assert!(
+            (actual.0 - expected.0).abs() < 1e-9 && (actual.1 - expected.1).abs() < 1e-9,
+            "{:?} != {:?}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn fixed() {
+        assert_eq!(to_fixed(1.0), 0x1_0000);
+        assert_eq!(to_fixed(-0.5), -0x8000);
+        assert_eq!(to_fixed(2.25f32), 0x2_4000);
+        assert_eq!(to_fixed(1e10), i32::MAX);
+        assert_eq!(from_fixed(-0x1_8000), -1.5);
+    }
+
+    #[test]
+    fn trapezoid() {
+        let trap = to_trapezoid(1.0, 2.5, ((0.0, 0.0), (0.0, 3.0)), ((4.0, 0.0), (5.0, 3.0)));
+        assert_eq!((trap.top, trap.bottom), (0x1_0000, 0x2_8000));
+        assert_eq!(trap.right.p2.x, 0x5_0000);
+    }
+
+    #[test]
+    fn transformations() {
+        let matrix = Matrix::scale(2.0, 3.0)
+            .then(Matrix::rotate(std::f64::consts::FRAC_PI_2))
+            .then(Matrix::translate(10.0, 20.0));
+        assert_close(matrix.apply((1.0, 1.0)), (7.0, 22.0));
+        let inverse = matrix.invert().unwrap();
+        assert_close(inverse.apply((7.0, 22.0)), (1.0, 1.0));
+        assert_eq!(Matrix::scale(0.0, 1.0).invert(), None);
+    }
+
+    #[test]
+    fn transform_round_trip() {
+        let matrix = Matrix::translate(1.5, -2.0).then(Matrix::scale(0.5, 4.0));
+        let transform = Transform::from(matrix);
+        assert_eq!(transform.matrix11, 0x8000);
+        assert_eq!(transform.matrix13, 0xc000);
+        assert_eq!(transform.matrix23, -0x8_0000);
+        assert_eq!(transform.matrix33, 0x1_0000);
+        assert_eq!(Matrix::from(transform), matrix);
+    }
+}
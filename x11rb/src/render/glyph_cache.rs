@@ -0,0 +1,241 @@
+//! Drawing text with glyphs that are stored in RENDER glyph sets.
+
+use std::collections::HashMap;
+
+use super::Picture;
+use crate::connection::Connection;
+use crate::cookie::VoidCookie;
+use crate::errors::{ConnectionError, ReplyOrIdError};
+use crate::protocol::render::{self, Glyphinfo, GlyphsetWrapper, PictOp, Pictformat};
+
+/// The maximum number of glyphs in one element of a `CompositeGlyphs` request.
+const MAX_GLYPHS_PER_ELT: usize = 254;
+
+/// The size of the fixed part of a `CompositeGlyphs` request.
+const COMPOSITE_GLYPHS_HEADER: usize = 28;
+
+/// A rasterized glyph, e.g. produced by a font rasterizer like `fontdue` or `swash`.
+#[derive(Debug, Clone, Copy)]
+pub struct GlyphBitmap<'a> {
+    /// The width of the bitmap in pixels.
+    pub width: u16,
+
+    /// The height of the bitmap in pixels.
+    pub height: u16,
+
+    /// The horizontal distance from the glyph origin to the left edge of the bitmap.
+    pub left: i16,
+
+    /// The vertical distance from the baseline up to the top edge of the bitmap.
+    pub top: i16,
+
+    /// How far the pen moves after this glyph was drawn.
+    pub advance: (i16, i16),
+
+    /// The coverage of the pixels with one byte per pixel, row by row and without padding.
+    pub data: &'a [u8],
+}
+
+/// A RENDER glyph set with the glyphs that were uploaded to it.
+///
+/// Glyphs are identified by an arbitrary `u32` that is chosen by the caller, e.g. the glyph index
+/// of the font. The glyph set is freed when this is dropped.
+#[derive(Debug)]
+pub struct GlyphCache<'c, C: Connection> {
+    conn: &'c C,
+    format: Pictformat,
+    glyphset: GlyphsetWrapper<&'c C>,
+    advances: HashMap<u32, (i16, i16)>,
+}
+
+impl<'c, C: Connection> GlyphCache<'c, C> {
+    /// Create a new, empty glyph cache.
+    ///
+    /// `format` must be the `A8` standard format, see [`super::StandardFormat::A8`].
+    pub fn new(conn: &'c C, format: Pictformat) -> Result<Self, ReplyOrIdError> {
+        let glyphset = GlyphsetWrapper::create_glyph_set(conn, format)?;
+        Ok(Self {
+            conn,
+            format,
+            glyphset,
+            advances: HashMap::new(),
+        })
+    }
+
+    /// Check whether a glyph was already uploaded.
+    pub fn contains(&self, id: u32) -> bool {
+        self.advances.contains_key(&id)
+    }
+
+    /// Upload a glyph to the X11 server.
+    ///
+    /// If a glyph with the same `id` already exists, it is replaced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `glyph.data` is smaller than `glyph.width * glyph.height`.
+    pub fn insert(
+        &mut self,
+        id: u32,
+        glyph: &GlyphBitmap<'_>,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        let (width, height) = (usize::from(glyph.width), usize::from(glyph.height));
+        assert!(
+            glyph.data.len() >= width * height,
+            "The glyph bitmap is too small"
+        );
+        // Each row of the glyph image is padded to 32 bits
+        let stride = (width + 3) & !3;
+        let mut data = vec![0; stride * height];
+        if width > 0 {
+            for (dst, src) in data
+                .chunks_exact_mut(stride)
+                .zip(glyph.data.chunks_exact(width))
+            {
+                dst[..width].copy_from_slice(src);
+            }
+        }
+        let info = Glyphinfo {
+            width: glyph.width,
+            height: glyph.height,
+            x: glyph.left.wrapping_neg(),
+            y: glyph.top,
+            x_off: glyph.advance.0,
+            y_off: glyph.advance.1,
+        };
+        let cookie =
+            render::add_glyphs(self.conn, self.glyphset.glyphset(), &[id], &[info], &data)?;
+        let _ = self.advances.insert(id, glyph.advance);
+        Ok(cookie)
+    }
+
+    /// Remove glyphs from the X11 server.
+    pub fn remove(&mut self, ids: &[u32]) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        for id in ids {
+            let _ = self.advances.remove(id);
+        }
+        render::free_glyphs(self.conn, self.glyphset.glyphset(), ids)
+    }
+
+    /// Draw a line of text onto `dst`.
+    ///
+    /// The first glyph is drawn with its origin at `pos`. The color of the text comes from `src`,
+    /// e.g. a [`Picture::solid_fill`], whose origin is aligned with `pos`. Glyphs that were not
+    /// inserted are skipped.
+    ///
+    /// Long texts are split into as many `CompositeGlyphs` requests as necessary.
+    pub fn draw_text(
+        &self,
+        dst: &Picture<'_, C>,
+        src: &Picture<'_, C>,
+        pos: (i16, i16),
+        glyphs: &[u32],
+    ) -> Result<(), ConnectionError> {
+        let glyphs = glyphs
+            .iter()
+            .filter_map(|id| self.advances.get(id).map(|advance| (*id, *advance)))
+            .collect::<Vec<_>>();
+        let max_bytes = self.conn.maximum_request_bytes() - COMPOSITE_GLYPHS_HEADER;
+        for (src_pos, cmds) in encode_glyphs(pos, &glyphs, max_bytes) {
+            let _ = render::composite_glyphs32(
+                self.conn,
+                PictOp::OVER,
+                src.picture(),
+                dst.picture(),
+                self.format,
+                self.glyphset.glyphset(),
+                src_pos.0,
+                src_pos.1,
+                &cmds,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Encode glyphs with their advances into the glyph commands of `CompositeGlyphs32` requests.
+///
+/// Every request has at most `max_bytes` bytes of glyph commands. For each request, this returns
+/// the source position that corresponds to its first glyph and the glyph commands.
+fn encode_glyphs(
+    pos: (i16, i16),
+    glyphs: &[(u32, (i16, i16))],
+    max_bytes: usize,
+) -> Vec<((i16, i16), Vec<u8>)> {
+    let mut requests = Vec::new();
+    let mut rest = glyphs;
+    let mut pen = pos;
+    while !rest.is_empty() {
+        let src_pos = (pen.0.wrapping_sub(pos.0), pen.1.wrapping_sub(pos.1));
+        // The first element of a request is positioned relative to the origin of the destination,
+        // later elements are positioned relative to the end of the previous element.
+        let mut delta = pen;
+        let mut cmds = Vec::new();
+        while !rest.is_empty() {
+            let room = max_bytes.saturating_sub(cmds.len() + 8) / 4;
+            // Always make progress, even if the limit is unreasonably small
+            let room = if cmds.is_empty() { room.max(1) } else { room };
+            let count = rest.len().min(MAX_GLYPHS_PER_ELT).min(room);
+            if count == 0 {
+                break;
+            }
+            cmds.push(count as u8);
+            cmds.extend_from_slice(&[0; 3]);
+            cmds.extend_from_slice(&delta.0.to_ne_bytes());
+            cmds.extend_from_slice(&delta.1.to_ne_bytes());
+            for (id, advance) in &rest[..count] {
+                cmds.extend_from_slice(&id.to_ne_bytes());
+                pen = (pen.0.wrapping_add(advance.0), pen.1.wrapping_add(advance.1));
+            }
+            rest = &rest[count..];
+            delta = (0, 0);
+        }
+        requests.push((src_pos, cmds));
+    }
+    requests
+}
+
+#[cfg(test)]
+mod test {
+    use super::encode_glyphs;
+
+    fn elt(count: u8, delta: (i16, i16), ids: &[u32]) -> Vec<u8> {
+        let mut result = vec![count, 0, 0, 0];
+        result.extend_from_slice(&delta.0.to_ne_bytes());
+        result.extend_from_slice(&delta.1.to_ne_bytes());
+        for id in ids {
+            result.extend_from_slice(&id.to_ne_bytes());
+        }
+        result
+    }
+
+    #[test]
+    fn encode_single_request() {
+        let glyphs = [(1, (5, 0)), (2, (6, 0))];
+        let requests = encode_glyphs((10, 20), &glyphs, 1000);
+        assert_eq!(requests, vec![((0, 0), elt(2, (10, 20), &[1, 2]))]);
+    }
+
+    #[test]
+    fn encode_split_requests() {
+        let glyphs = [(1, (5, 0)), (2, (6, 1)), (3, (7, 0))];
+        // Room for an element header and two glyphs
+        let requests = encode_glyphs((10, 20), &glyphs, 16);
+        assert_eq!(
+            requests,
+            vec![
+                ((0, 0), elt(2, (10, 20), &[1, 2])),
+                ((11, 1), elt(1, (21, 21), &[3])),
+            ]
+        );
+    }
+
+    #[test]
+    fn encode_long_element() {
+        let glyphs = vec![(7, (1, 0)); 300];
+        let requests = encode_glyphs((0, 0), &glyphs, 4000);
+        let mut expected = elt(254, (0, 0), &[7; 254]);
+        expected.extend(elt(46, (0, 0), &[7; 46]));
+        assert_eq!(requests, vec![((0, 0), expected)]);
+    }
+}
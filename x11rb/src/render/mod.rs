@@ -0,0 +1,360 @@
+//! Helpers for drawing with the RENDER extension.
+//!
+//! The RENDER extension describes colors with 16 bit components, coordinates as fixed-point
+//! numbers and pixel formats as IDs that have to be looked up in the reply to
+//! `QueryPictFormats`. This module hides these details: [`PictFormats`] finds the format for a
+//! visual or one of the standard formats, and [`Picture`] creates pictures, fills them and
+//! composites them, and frees them when it is dropped. [`GlyphCache`] draws text from glyphs that
+//! were rasterized by the application. Functions like [`to_fixed`] and [`Matrix`] convert
+//! floating-point numbers into the fixed-point types of RENDER.
+//!
+//! The code in this module is only available when the `render` feature of the library is enabled.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use x11rb::connection::Connection;
+//! use x11rb::protocol::render::{Color, PictOp};
+//! use x11rb::protocol::xproto::Rectangle;
+//! use x11rb::render::{PictFormats, Picture};
+//!
+//! let (conn, screen_num) = x11rb::connect(None)?;
+//! let screen = &conn.setup().roots[screen_num];
+//! # let window = 0;
+//! let formats = PictFormats::query(&conn)?;
+//! let format = formats.for_visual(screen.root_visual).expect("The visual has a format");
+//! let target = Picture::for_drawable(&conn, window, format)?;
+//! let white = Color { red: 0xffff, green: 0xffff, blue: 0xffff, alpha: 0xffff };
+//! let black = Color { red: 0, green: 0, blue: 0, alpha: 0xffff };
+//! let gradient = Picture::linear_gradient(&conn, (0.0, 0.0), (100.0, 0.0), &[(0.0, white), (1.0, black)])?;
+//! let rect = Rectangle { x: 0, y: 0, width: 100, height: 100 };
+//! target.composite(PictOp::OVER, &gradient, None, (0, 0), rect)?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::connection::Connection;
+use crate::cookie::VoidCookie;
+use crate::errors::{ConnectionError, ReplyError, ReplyOrIdError};
+use crate::protocol::render::{
+    self, ChangePictureAux, Color, CreatePictureAux, Fixed, PictOp, PictType, Pictformat,
+    Pictforminfo, PictureWrapper, QueryPictFormatsReply, Repeat,
+};
+use crate::protocol::xproto::{Drawable, Rectangle, Visualid};
+use crate::NONE;
+
+mod fixed;
+pub use fixed::{from_fixed, to_fixed, to_linefix, to_pointfix, to_trapezoid, Matrix};
+mod glyph_cache;
+pub use glyph_cache::{GlyphBitmap, GlyphCache};
+
+/// The standard formats that every X11 server with the RENDER extension supports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StandardFormat {
+    /// 32 bit ARGB with 8 bits per component.
+    Argb32,
+    /// 24 bit RGB with 8 bits per component and without alpha.
+    Rgb24,
+    /// 8 bit alpha without colors.
+    A8,
+    /// 4 bit alpha without colors.
+    A4,
+    /// 1 bit alpha without colors.
+    A1,
+}
+
+impl StandardFormat {
+    /// Get the depth and the `(shift, mask)` of alpha, red, green and blue of the format.
+    fn description(self) -> (u8, [(u16, u16); 4]) {
+        let alpha = |mask| [(0, mask), (0, 0), (0, 0), (0, 0)];
+        match self {
+            Self::Argb32 => (32, [(24, 0xff), (16, 0xff), (8, 0xff), (0, 0xff)]),
+            Self::Rgb24 => (24, [(0, 0), (16, 0xff), (8, 0xff), (0, 0xff)]),
+            Self::A8 => (8, alpha(0xff)),
+            Self::A4 => (4, alpha(0x0f)),
+            Self::A1 => (1, alpha(0x01)),
+        }
+    }
+
+    /// Check whether the given format is this standard format.
+    fn matches(self, format: &Pictforminfo) -> bool {
+        let (depth, [alpha, red, green, blue]) = self.description();
+        let direct = &format.direct;
+        let component = |(shift, mask): (u16, u16)| if mask == 0 { (0, 0) } else { (shift, mask) };
+        format.type_ == PictType::DIRECT
+            && format.depth == depth
+            && component((direct.alpha_shift, direct.alpha_mask)) == alpha
+            && component((direct.red_shift, direct.red_mask)) == red
+            && component((direct.green_shift, direct.green_mask)) == green
+            && component((direct.blue_shift, direct.blue_mask)) == blue
+    }
+}
+
+/// Find one of the standard formats in the reply to `QueryPictFormats`.
+pub fn find_standard_format(
+    reply: &QueryPictFormatsReply,
+    format: StandardFormat,
+) -> Option<Pictformat> {
+    reply
+        .formats
+        .iter()
+        .find(|info| format.matches(info))
+        .map(|info| info.id)
+}
+
+/// The picture formats that the X11 server supports.
+#[derive(Debug, Clone)]
+pub struct PictFormats(QueryPictFormatsReply);
+
+impl PictFormats {
+    /// Query the supported picture formats from the X11 server.
+    pub fn query(conn: &impl Connection) -> Result<Self, ReplyError> {
+        Ok(Self(render::query_pict_formats(conn)?.reply()?))
+    }
+
+    /// Get the format for one of the standard formats.
+    pub fn standard(&self, format: StandardFormat) -> Option<Pictformat> {
+        find_standard_format(&self.0, format)
+    }
+
+    /// Get the format that corresponds to a visual.
+    pub fn for_visual(&self, visual: Visualid) -> Option<Pictformat> {
+        self.0
+            .screens
+            .iter()
+            .flat_map(|screen| &screen.depths)
+            .flat_map(|depth| &depth.visuals)
+            .find(|candidate| candidate.visual == visual)
+            .map(|candidate| candidate.format)
+    }
+
+    /// Get the description of a format.
+    pub fn info(&self, format: Pictformat) -> Option<&Pictforminfo> {
+        self.0.formats.iter().find(|info| info.id == format)
+    }
+
+    /// Get the reply that this was created from.
+    pub fn reply(&self) -> &QueryPictFormatsReply {
+        &self.0
+    }
+}
+
+/// A RENDER picture that is freed when it is dropped.
+///
+/// Pictures are either backed by a drawable, see [`Picture::for_drawable`], or are sources that
+/// compute their content, e.g. [`Picture::solid_fill`] and the gradients.
+#[derive(Debug)]
+pub struct Picture<'c, C: Connection> {
+    conn: &'c C,
+    wrapper: PictureWrapper<&'c C>,
+}
+
+impl<'c, C: Connection> Picture<'c, C> {
+    /// Create a picture for a drawable.
+    ///
+    /// `format` must describe the drawable, e.g. via [`PictFormats::for_visual`] for windows.
+    pub fn for_drawable(
+        conn: &'c C,
+        drawable: Drawable,
+        format: Pictformat,
+    ) -> Result<Self, ReplyOrIdError> {
+        let aux = CreatePictureAux::new();
+        let wrapper = PictureWrapper::create_picture(conn, drawable, format, &aux)?;
+        Ok(Self { conn, wrapper })
+    }
+
+    /// Create a picture that has the same color everywhere.
+    pub fn solid_fill(conn: &'c C, color: Color) -> Result<Self, ReplyOrIdError> {
+        let wrapper = PictureWrapper::create_solid_fill(conn, color)?;
+        Ok(Self { conn, wrapper })
+    }
+
+    /// Create a linear gradient from point `p1` to point `p2`.
+    ///
+    /// `stops` contains pairs of an offset between 0 and 1 and the color at that offset, in
+    /// increasing order of the offsets.
+    pub fn linear_gradient(
+        conn: &'c C,
+        p1: (f64, f64),
+        p2: (f64, f64),
+        stops: &[(f64, Color)],
+    ) -> Result<Self, ReplyOrIdError> {
+        let (offsets, colors) = split_stops(stops);
+        let wrapper = PictureWrapper::create_linear_gradient(
+            conn,
+            to_pointfix(p1),
+            to_pointfix(p2),
+            &offsets,
+            &colors,
+        )?;
+        Ok(Self { conn, wrapper })
+    }
+
+    /// Create a radial gradient between two circles.
+    ///
+    /// Each circle is given as its center and its radius. See [`Picture::linear_gradient`] for
+    /// `stops`.
+    pub fn radial_gradient(
+        conn: &'c C,
+        inner: ((f64, f64), f64),
+        outer: ((f64, f64), f64),
+        stops: &[(f64, Color)],
+    ) -> Result<Self, ReplyOrIdError> {
+        let (offsets, colors) = split_stops(stops);
+        let wrapper = PictureWrapper::create_radial_gradient(
+            conn,
+            to_pointfix(inner.0),
+            to_pointfix(outer.0),
+            to_fixed(inner.1),
+            to_fixed(outer.1),
+            &offsets,
+            &colors,
+        )?;
+        Ok(Self { conn, wrapper })
+    }
+
+    /// Create a conical gradient around `center` that starts at `angle` degrees.
+    ///
+    /// See [`Picture::linear_gradient`] for `stops`.
+    pub fn conical_gradient(
+        conn: &'c C,
+        center: (f64, f64),
+        angle: f64,
+        stops: &[(f64, Color)],
+    ) -> Result<Self, ReplyOrIdError> {
+        let (offsets, colors) = split_stops(stops);
+        let wrapper = PictureWrapper::create_conical_gradient(
+            conn,
+            to_pointfix(center),
+            to_fixed(angle),
+            &offsets,
+            &colors,
+        )?;
+        Ok(Self { conn, wrapper })
+    }
+
+    /// Get the XID of the picture.
+    pub fn picture(&self) -> render::Picture {
+        self.wrapper.picture()
+    }
+
+    /// Composite `src` onto this picture.
+    ///
+    /// The rectangle `dst` of this picture is combined with the same-sized rectangle of `src` that
+    /// starts at `src_pos` according to `op`. If a `mask` is given, it is used with the same
+    /// coordinates as `src`.
+    pub fn composite(
+        &self,
+        op: PictOp,
+        src: &Picture<'_, C>,
+        mask: Option<&Picture<'_, C>>,
+        src_pos: (i16, i16),
+        dst: Rectangle,
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        let mask = mask.map_or(NONE, Picture::picture);
+        render::composite(
+            self.conn,
+            op,
+            src.picture(),
+            mask,
+            self.picture(),
+            src_pos.0,
+            src_pos.1,
+            src_pos.0,
+            src_pos.1,
+            dst.x,
+            dst.y,
+            dst.width,
+            dst.height,
+        )
+    }
+
+    /// Fill rectangles of this picture with a color according to `op`.
+    pub fn fill_rectangles(
+        &self,
+        op: PictOp,
+        color: Color,
+        rects: &[Rectangle],
+    ) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        render::fill_rectangles(self.conn, op, self.picture(), color, rects)
+    }
+
+    /// Set how the picture is repeated outside of its bounds.
+    ///
+    /// For gradients, this defines the color before the first and after the last stop.
+    pub fn set_repeat(&self, repeat: Repeat) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        let aux = ChangePictureAux::new().repeat(repeat);
+        render::change_picture(self.conn, self.picture(), &aux)
+    }
+
+    /// Set the transformation that maps positions in the destination to positions in the picture.
+    ///
+    /// See [`Matrix`] for details.
+    pub fn set_transform(&self, matrix: Matrix) -> Result<VoidCookie<'c, C>, ConnectionError> {
+        render::set_picture_transform(self.conn, self.picture(), matrix.into())
+    }
+
+    /// Assume ownership of the XID of the picture.
+    ///
+    /// The picture is not freed afterwards.
+    pub fn into_picture(self) -> render::Picture {
+        self.wrapper.into_picture()
+    }
+}
+
+fn split_stops(stops: &[(f64, Color)]) -> (Vec<Fixed>, Vec<Color>) {
+    stops
+        .iter()
+        .map(|(offset, color)| (to_fixed(*offset), *color))
+        .unzip()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{find_standard_format, StandardFormat};
+    use crate::protocol::render::{Directformat, PictType, Pictforminfo, QueryPictFormatsReply};
+
+    fn format(id: u32, depth: u8, direct: Directformat) -> Pictforminfo {
+        Pictforminfo {
+            id,
+            type_: PictType::DIRECT,
+            depth,
+            direct,
+            colormap: 0,
+        }
+    }
+
+    #[test]
+    fn standard_formats() {
+        let reply = QueryPictFormatsReply {
+            formats: vec![
+                format(1, 8, Directformat::default()),
+                format(
+                    2,
+                    8,
+                    Directformat {
+                        alpha_mask: 0xff,
+                        ..Default::default()
+                    },
+                ),
+                format(
+                    3,
+                    24,
+                    Directformat {
+                        red_shift: 16,
+                        red_mask: 0xff,
+                        green_shift: 8,
+                        green_mask: 0xff,
+                        blue_mask: 0xff,
+                        // The shift of an empty mask does not matter
+                        alpha_shift: 24,
+                        ..Default::default()
+                    },
+                ),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(find_standard_format(&reply, StandardFormat::A8), Some(2));
+        assert_eq!(find_standard_format(&reply, StandardFormat::Rgb24), Some(3));
+        assert_eq!(find_standard_format(&reply, StandardFormat::Argb32), None);
+    }
+}
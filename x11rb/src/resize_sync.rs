@@ -0,0 +1,392 @@
+//! Synchronizing resizes between window managers and clients with `_NET_WM_SYNC_REQUEST`.
+//!
+//! Without synchronization, a window manager resizes the frame of a window faster than the client
+//! can redraw, which leaves garbage in the window during interactive resizes. With the
+//! `_NET_WM_SYNC_REQUEST` protocol of EWMH, the client owns a counter of the SYNC extension. The
+//! window manager sends a new counter value before it configures the window, and the client sets
+//! its counter to this value once it redrew the window with the new size.
+//!
+//! [`SyncCounter`] implements the side of the client and [`ResizeSync`] the side of the window
+//! manager, which waits for the counter with a SYNC alarm.
+//!
+//! The code in this module is only available when the `sync` feature of the library is enabled.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use x11rb::connection::Connection;
+//! use x11rb::resize_sync::SyncCounter;
+//!
+//! let (conn, _) = x11rb::connect(None)?;
+//! # let window = 0;
+//! let mut counter = SyncCounter::new(&conn, window)?.expect("No SYNC extension");
+//! loop {
+//!     let event = conn.wait_for_event()?;
+//!     if counter.handle_event(&event) {
+//!         continue;
+//!     }
+//!     // Handle ConfigureNotify and redraw the window, then:
+//!     counter.frame_done()?;
+//!     conn.flush()?;
+//! }
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::time::Instant;
+
+use crate::connection::Connection;
+use crate::errors::{ConnectionError, ReplyError, ReplyOrIdError};
+use crate::protocol::sync::{self, Alarm, ChangeAlarmAux, Counter, CreateAlarmAux, Int64};
+use crate::protocol::sync::{TESTTYPE, VALUETYPE};
+use crate::protocol::xproto::{
+    self, AtomEnum, ClientMessageEvent, EventMask, PropMode, Timestamp, Window,
+};
+use crate::protocol::Event;
+use crate::wrapper::ConnectionExt as _;
+
+pub use atoms::{SyncRequestAtoms, SyncRequestAtomsCookie};
+
+mod atoms {
+    // The code that the macro generates has no documentation and a `new` function that returns a
+    // cookie
+    #![allow(missing_docs, clippy::new_ret_no_self)]
+
+    crate::atom_manager! {
+        /// The atoms of the `_NET_WM_SYNC_REQUEST` protocol.
+        pub SyncRequestAtoms:
+        /// A cookie for interning the atoms of the `_NET_WM_SYNC_REQUEST` protocol.
+        SyncRequestAtomsCookie {
+            WM_PROTOCOLS,
+            _NET_WM_SYNC_REQUEST,
+            _NET_WM_SYNC_REQUEST_COUNTER,
+        }
+    }
+}
+
+/// The version of the SYNC extension that this module uses.
+const SYNC_VERSION: (u8, u8) = (3, 1);
+
+/// Convert a number into a 64 bit value of the SYNC extension.
+fn to_int64(value: i64) -> Int64 {
+    Int64 {
+        hi: (value >> 32) as i32,
+        lo: value as u32,
+    }
+}
+
+/// Convert a 64 bit value of the SYNC extension into a number.
+fn from_int64(value: Int64) -> i64 {
+    (i64::from(value.hi) << 32) | i64::from(value.lo)
+}
+
+/// Get the counter value of a `_NET_WM_SYNC_REQUEST` message.
+fn request_value(atoms: &SyncRequestAtoms, event: &ClientMessageEvent) -> Option<i64> {
+    let data = event.data.as_data32();
+    if event.type_ != atoms.WM_PROTOCOLS
+        || event.format != 32
+        || data[0] != atoms._NET_WM_SYNC_REQUEST
+    {
+        return None;
+    }
+    Some(from_int64(Int64 {
+        hi: data[3] as i32,
+        lo: data[2],
+    }))
+}
+
+/// Initialize the SYNC extension and intern the atoms of the protocol.
+///
+/// Returns `None` if the server does not support the SYNC extension.
+fn init<C: Connection>(conn: &C) -> Result<Option<SyncRequestAtoms>, ReplyError> {
+    if conn
+        .extension_information(sync::X11_EXTENSION_NAME)?
+        .is_none()
+    {
+        return Ok(None);
+    }
+    let atoms = SyncRequestAtoms::new(conn)?;
+    let _ = sync::initialize(conn, SYNC_VERSION.0, SYNC_VERSION.1)?.reply()?;
+    Ok(Some(atoms.reply()?))
+}
+
+/// The counter of a client window for the `_NET_WM_SYNC_REQUEST` protocol.
+///
+/// The counter is destroyed when this is dropped.
+#[derive(Debug)]
+pub struct SyncCounter<'c, C: Connection> {
+    conn: &'c C,
+    window: Window,
+    counter: Counter,
+    atoms: SyncRequestAtoms,
+    pending: Option<i64>,
+}
+
+impl<'c, C: Connection> SyncCounter<'c, C> {
+    /// Create a counter for a window and announce it to the window manager.
+    ///
+    /// The counter is stored in the `_NET_WM_SYNC_REQUEST_COUNTER` property of the window and
+    /// `_NET_WM_SYNC_REQUEST` is appended to its `WM_PROTOCOLS`. This should be done before the
+    /// window is mapped. Returns `None` if the server does not support the SYNC extension.
+    pub fn new(conn: &'c C, window: Window) -> Result<Option<Self>, ReplyOrIdError> {
+        let atoms = match init(conn)? {
+            Some(atoms) => atoms,
+            None => return Ok(None),
+        };
+        let counter = conn.generate_id()?;
+        let _ = sync::create_counter(conn, counter, to_int64(0))?;
+        let _ = conn.change_property32(
+            PropMode::REPLACE,
+            window,
+            atoms._NET_WM_SYNC_REQUEST_COUNTER,
+            AtomEnum::CARDINAL,
+            &[counter],
+        )?;
+        let _ = conn.change_property32(
+            PropMode::APPEND,
+            window,
+            atoms.WM_PROTOCOLS,
+            AtomEnum::ATOM,
+            &[atoms._NET_WM_SYNC_REQUEST],
+        )?;
+        Ok(Some(Self {
+            conn,
+            window,
+            counter,
+            atoms,
+            pending: None,
+        }))
+    }
+
+    /// Get the counter.
+    pub fn counter(&self) -> Counter {
+        self.counter
+    }
+
+    /// Check whether the window manager waits for the next redraw.
+    pub fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Handle a `_NET_WM_SYNC_REQUEST` message from the window manager.
+    ///
+    /// Returns `true` if the event was such a message. The window manager configures the window
+    /// afterwards and waits until [`SyncCounter::frame_done`] is called.
+    pub fn handle_event(&mut self, event: &Event) -> bool {
+        let value = match event {
+            Event::ClientMessage(event) if event.window == self.window => {
+                request_value(&self.atoms, event)
+            }
+            _ => None,
+        };
+        if value.is_some() {
+            self.pending = value;
+        }
+        value.is_some()
+    }
+
+    /// Tell the window manager that the window was redrawn.
+    ///
+    /// This should be called after the window was redrawn for the `ConfigureNotify` event that
+    /// followed the last `_NET_WM_SYNC_REQUEST` message. Nothing happens if there was no such
+    /// message.
+    pub fn frame_done(&mut self) -> Result<(), ConnectionError> {
+        if let Some(value) = self.pending.take() {
+            let _ = sync::set_counter(self.conn, self.counter, to_int64(value))?;
+        }
+        Ok(())
+    }
+}
+
+impl<C: Connection> Drop for SyncCounter<'_, C> {
+    fn drop(&mut self) {
+        let _ = xproto::delete_property(
+            self.conn,
+            self.window,
+            self.atoms._NET_WM_SYNC_REQUEST_COUNTER,
+        );
+        let _ = sync::destroy_counter(self.conn, self.counter);
+    }
+}
+
+/// The side of the window manager of the `_NET_WM_SYNC_REQUEST` protocol for one client.
+///
+/// The alarm that watches the counter of the client is destroyed when this is dropped.
+#[derive(Debug)]
+pub struct ResizeSync<'c, C: Connection> {
+    conn: &'c C,
+    window: Window,
+    counter: Counter,
+    alarm: Alarm,
+    atoms: SyncRequestAtoms,
+    value: i64,
+    waiting_since: Option<Instant>,
+}
+
+impl<'c, C: Connection> ResizeSync<'c, C> {
+    /// Prepare synchronized resizes of a client window.
+    ///
+    /// Returns `None` if the server does not support the SYNC extension or the client does not
+    /// support the protocol, i.e. `_NET_WM_SYNC_REQUEST` is missing in its `WM_PROTOCOLS` or it
+    /// has no `_NET_WM_SYNC_REQUEST_COUNTER`.
+    pub fn new(conn: &'c C, window: Window) -> Result<Option<Self>, ReplyOrIdError> {
+        let atoms = match init(conn)? {
+            Some(atoms) => atoms,
+            None => return Ok(None),
+        };
+        let protocols = xproto::get_property(
+            conn,
+            false,
+            window,
+            atoms.WM_PROTOCOLS,
+            AtomEnum::ATOM,
+            0,
+            u32::MAX,
+        )?;
+        let counter = xproto::get_property(
+            conn,
+            false,
+            window,
+            atoms._NET_WM_SYNC_REQUEST_COUNTER,
+            AtomEnum::CARDINAL,
+            0,
+            1,
+        )?;
+        let supported = protocols.reply()?.value32().map_or(false, |mut protocols| {
+            protocols.any(|atom| atom == atoms._NET_WM_SYNC_REQUEST)
+        });
+        let counter = counter
+            .reply()?
+            .value32()
+            .and_then(|mut value| value.next());
+        let counter = match counter {
+            Some(counter) if supported => counter,
+            _ => return Ok(None),
+        };
+        let value = from_int64(sync::query_counter(conn, counter)?.reply()?.counter_value);
+
+        let alarm = conn.generate_id()?;
+        let aux = CreateAlarmAux::new()
+            .counter(counter)
+            .value_type(VALUETYPE::ABSOLUTE)
+            .value(to_int64(value))
+            .test_type(TESTTYPE::POSITIVE_COMPARISON)
+            .delta(to_int64(0))
+            .events(1);
+        let _ = sync::create_alarm(conn, alarm, &aux)?;
+        Ok(Some(Self {
+            conn,
+            window,
+            counter,
+            alarm,
+            atoms,
+            value,
+            waiting_since: None,
+        }))
+    }
+
+    /// Get the counter of the client.
+    pub fn counter(&self) -> Counter {
+        self.counter
+    }
+
+    /// Get the alarm that watches the counter of the client.
+    pub fn alarm(&self) -> Alarm {
+        self.alarm
+    }
+
+    /// Send a `_NET_WM_SYNC_REQUEST` message to the client.
+    ///
+    /// This should be called right before the client window is configured. `time` should be the
+    /// server time of the event that caused the resize. The next resize should wait until
+    /// [`ResizeSync::is_waiting`] returns `false`.
+    pub fn request(&mut self, time: Timestamp) -> Result<(), ConnectionError> {
+        self.value = self.value.wrapping_add(1);
+        let value = to_int64(self.value);
+        let data = [
+            self.atoms._NET_WM_SYNC_REQUEST,
+            time,
+            value.lo,
+            value.hi as u32,
+            0,
+        ];
+        let event = ClientMessageEvent::new(32, self.window, self.atoms.WM_PROTOCOLS, data);
+        let _ = xproto::send_event(self.conn, false, self.window, EventMask::NO_EVENT, event)?;
+        // Changing the alarm activates it again
+        let aux = ChangeAlarmAux::new().value(value);
+        let _ = sync::change_alarm(self.conn, self.alarm, &aux)?;
+        self.waiting_since = Some(Instant::now());
+        Ok(())
+    }
+
+    /// Check whether the client did not redraw the window since the last request.
+    pub fn is_waiting(&self) -> bool {
+        self.waiting_since.is_some()
+    }
+
+    /// Get the time of the last request that the client did not answer yet.
+    ///
+    /// Clients can hang, so the window manager should stop waiting after some time, e.g. with
+    /// [`ResizeSync::cancel`].
+    pub fn waiting_since(&self) -> Option<Instant> {
+        self.waiting_since
+    }
+
+    /// Stop waiting for the client to answer the last request.
+    pub fn cancel(&mut self) {
+        self.waiting_since = None;
+    }
+
+    /// Handle an `AlarmNotify` event of the alarm of this client.
+    ///
+    /// Returns `true` if the event belongs to the alarm. Afterwards, [`ResizeSync::is_waiting`]
+    /// returns `false` if the client answered the last request.
+    pub fn handle_event(&mut self, event: &Event) -> bool {
+        match event {
+            Event::SyncAlarmNotify(event) if event.alarm == self.alarm => {
+                if from_int64(event.counter_value) >= self.value {
+                    self.waiting_since = None;
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<C: Connection> Drop for ResizeSync<'_, C> {
+    fn drop(&mut self) {
+        let _ = sync::destroy_alarm(self.conn, self.alarm);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{from_int64, request_value, to_int64, SyncRequestAtoms};
+    use crate::protocol::xproto::ClientMessageEvent;
+
+    #[test]
+    fn int64_conversion() {
+        for value in [0, 1, -1, 0x1_0000_0000, -0x1_2345_6789, i64::MAX, i64::MIN] {
+            assert_eq!(from_int64(to_int64(value)), value);
+        }
+        let value = to_int64(0x1_0000_0002);
+        assert_eq!((value.hi, value.lo), (1, 2));
+        let value = to_int64(-2);
+        assert_eq!((value.hi, value.lo), (-1, 0xffff_fffe));
+    }
+
+    #[test]
+    fn sync_request_message() {
+        let atoms = SyncRequestAtoms {
+            WM_PROTOCOLS: 1,
+            _NET_WM_SYNC_REQUEST: 2,
+            _NET_WM_SYNC_REQUEST_COUNTER: 3,
+        };
+        let event = ClientMessageEvent::new(32, 10, 1u32, [2, 1234, 5, 1, 0]);
+        assert_eq!(request_value(&atoms, &event), Some(0x1_0000_0005));
+        let event = ClientMessageEvent::new(32, 10, 1u32, [4, 1234, 5, 1, 0]);
+        assert_eq!(request_value(&atoms, &event), None);
+        let event = ClientMessageEvent::new(32, 10, 3u32, [2, 1234, 5, 1, 0]);
+        assert_eq!(request_value(&atoms, &event), None);
+    }
+}
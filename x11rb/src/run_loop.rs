@@ -0,0 +1,222 @@
+//! A simple event loop that waits for X11 events and timers.
+//!
+//! Small tools like status bars or screen lockers need to react to X11 events and to do something
+//! periodically, e.g. redraw a clock. [`RunLoop`] waits for both on the connection's file
+//! descriptor, so that no separate event loop library is needed.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::os::unix::io::AsFd;
+//! use std::time::Duration;
+//! use x11rb::run_loop::{LoopEvent, RunLoop};
+//!
+//! let (conn, _) = x11rb::connect(None)?;
+//! let mut run_loop = RunLoop::new(&conn, conn.stream().as_fd());
+//! let clock = run_loop.add_interval(Duration::from_secs(1));
+//! loop {
+//!     match run_loop.next_event()? {
+//!         LoopEvent::X11(event) => println!("Got event {:?}", event),
+//!         LoopEvent::Timer(id) if id == clock => println!("Tick"),
+//!         LoopEvent::Timer(_) => {}
+//!     }
+//! }
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! The loop must be the only place where events are fetched from the connection. See the
+//! [`event_loop_integration`](crate::event_loop_integration) module for the reasons.
+
+use std::os::unix::io::BorrowedFd;
+use std::time::{Duration, Instant};
+
+use rustix::event::PollFlags;
+
+use crate::connection::Connection;
+use crate::errors::ConnectionError;
+use crate::protocol::Event;
+use crate::utils::poll_until;
+
+/// The identifier of a timer of a [`RunLoop`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimerId(u64);
+
+/// Something that happened in a [`RunLoop`].
+#[derive(Debug)]
+pub enum LoopEvent {
+    /// An event was received from the X11 server.
+    X11(Event),
+    /// A timer expired.
+    Timer(TimerId),
+}
+
+#[derive(Debug)]
+struct Timer {
+    id: TimerId,
+    deadline: Instant,
+    interval: Option<Duration>,
+}
+
+/// The timers of a [`RunLoop`].
+#[derive(Debug, Default)]
+struct Timers {
+    timers: Vec<Timer>,
+    next_id: u64,
+}
+
+impl Timers {
+    fn add(&mut self, deadline: Instant, interval: Option<Duration>) -> TimerId {
+        let id = TimerId(self.next_id);
+        self.next_id += 1;
+        self.timers.push(Timer {
+            id,
+            deadline,
+            interval,
+        });
+        id
+    }
+
+    fn remove(&mut self, id: TimerId) -> bool {
+        let len = self.timers.len();
+        self.timers.retain(|timer| timer.id != id);
+        self.timers.len() != len
+    }
+
+    fn next_deadline(&self) -> Option<Instant> {
+        self.timers.iter().map(|timer| timer.deadline).min()
+    }
+
+    /// Get the timer with the earliest deadline if it expired at `now`.
+    ///
+    /// One-shot timers are removed and intervals are rescheduled.
+    fn expire(&mut self, now: Instant) -> Option<TimerId> {
+        let index = self
+            .timers
+            .iter()
+            .enumerate()
+            .filter(|(_, timer)| timer.deadline <= now)
+            .min_by_key(|(_, timer)| timer.deadline)
+            .map(|(index, _)| index)?;
+        let timer = &mut self.timers[index];
+        let id = timer.id;
+        match timer.interval {
+            Some(interval) => {
+                timer.deadline += interval;
+                // Skip expirations that were missed instead of reporting all of them at once
+                if timer.deadline <= now {
+                    timer.deadline = now + interval;
+                }
+            }
+            None => {
+                let _ = self.timers.swap_remove(index);
+            }
+        }
+        Some(id)
+    }
+}
+
+/// An event loop that waits for X11 events and timers.
+#[derive(Debug)]
+pub struct RunLoop<'a, C> {
+    conn: &'a C,
+    fd: BorrowedFd<'a>,
+    timers: Timers,
+}
+
+impl<'a, C: Connection> RunLoop<'a, C> {
+    /// Create a new event loop for a connection.
+    ///
+    /// `fd` must be the file descriptor of the connection, e.g. `conn.stream().as_fd()` for a
+    /// [`RustConnection`](crate::rust_connection::RustConnection).
+    pub fn new(conn: &'a C, fd: BorrowedFd<'a>) -> Self {
+        Self {
+            conn,
+            fd,
+            timers: Default::default(),
+        }
+    }
+
+    /// Add a timer that expires once after `delay`.
+    pub fn add_timer(&mut self, delay: Duration) -> TimerId {
+        self.timers.add(Instant::now() + delay, None)
+    }
+
+    /// Add a timer that expires every `interval`.
+    ///
+    /// If expirations are missed because the loop did not run, they are skipped.
+    pub fn add_interval(&mut self, interval: Duration) -> TimerId {
+        self.timers.add(Instant::now() + interval, Some(interval))
+    }
+
+    /// Remove a timer.
+    ///
+    /// Returns `false` if there is no timer with the given ID, e.g. because it was a one-shot
+    /// timer that already expired.
+    pub fn remove_timer(&mut self, id: TimerId) -> bool {
+        self.timers.remove(id)
+    }
+
+    /// Wait for the next X11 event or timer.
+    ///
+    /// The connection is flushed with [`Connection::flush_all`] before waiting, so that requests
+    /// are never held back while the loop sleeps.
+    pub fn next_event(&mut self) -> Result<LoopEvent, ConnectionError> {
+        loop {
+            if let Some(id) = self.timers.expire(Instant::now()) {
+                return Ok(LoopEvent::Timer(id));
+            }
+            if let Some(event) = self.conn.poll_for_event()? {
+                return Ok(LoopEvent::X11(event));
+            }
+            self.conn.flush_all()?;
+            self.wait_readable(self.timers.next_deadline())?;
+        }
+    }
+
+    /// Wait until the connection is readable or the deadline passed.
+    fn wait_readable(&self, deadline: Option<Instant>) -> Result<(), ConnectionError> {
+        let _ = poll_until(self.fd, PollFlags::IN, deadline)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, Instant};
+
+    use super::Timers;
+
+    #[test]
+    fn one_shot() {
+        let now = Instant::now();
+        let mut timers = Timers::default();
+        let late = timers.add(now + Duration::from_secs(2), None);
+        let early = timers.add(now + Duration::from_secs(1), None);
+        assert_eq!(timers.next_deadline(), Some(now + Duration::from_secs(1)));
+        assert_eq!(timers.expire(now), None);
+
+        let later = now + Duration::from_secs(3);
+        assert_eq!(timers.expire(later), Some(early));
+        assert_eq!(timers.expire(later), Some(late));
+        assert_eq!(timers.expire(later), None);
+        assert_eq!(timers.next_deadline(), None);
+    }
+
+    #[test]
+    fn interval() {
+        let second = Duration::from_secs(1);
+        let now = Instant::now();
+        let mut timers = Timers::default();
+        let id = timers.add(now + second, Some(second));
+        assert_eq!(timers.expire(now + second), Some(id));
+        assert_eq!(timers.next_deadline(), Some(now + 2 * second));
+
+        // Missed expirations are skipped
+        assert_eq!(timers.expire(now + 10 * second), Some(id));
+        assert_eq!(timers.expire(now + 10 * second), None);
+        assert_eq!(timers.next_deadline(), Some(now + 11 * second));
+
+        assert!(timers.remove(id));
+        assert!(!timers.remove(id));
+    }
+}
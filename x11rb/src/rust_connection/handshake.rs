@@ -0,0 +1,92 @@
+//! The setup handshake that starts every connection.
+
+use std::io::ErrorKind;
+
+use super::{PollMode, Stream};
+use crate::errors::ConnectError;
+use crate::protocol::xproto::Setup;
+use crate::utils::RawFdContainer;
+use x11rb_protocol::connect::Connect;
+
+/// The setup handshake with the X11 server.
+#[derive(Debug)]
+pub(super) struct Handshake {
+    connect: Connect,
+    request: Vec<u8>,
+    nwritten: usize,
+    fds: Vec<RawFdContainer>,
+}
+
+impl Handshake {
+    pub(super) fn new(auth_name: Vec<u8>, auth_data: Vec<u8>) -> Self {
+        let (connect, request) = Connect::with_authorization(auth_name, auth_data);
+        crate::trace!("Writing connection setup with {} bytes", request.len());
+        Self {
+            connect,
+            request,
+            nwritten: 0,
+            fds: Vec::new(),
+        }
+    }
+
+    /// Make as much progress on the handshake as possible without blocking.
+    ///
+    /// Returns `None` once the setup was completely received. Otherwise, the stream has to become
+    /// ready for the returned operation before more progress can be made.
+    pub(super) fn step<S: Stream>(&mut self, stream: &S) -> Result<Option<PollMode>, ConnectError> {
+        // write the connect() setup request
+        while self.nwritten != self.request.len() {
+            match stream.write(&self.request[self.nwritten..], &mut self.fds) {
+                Ok(0) => {
+                    return Err(std::io::Error::new(
+                        ErrorKind::WriteZero,
+                        "failed to write whole buffer",
+                    )
+                    .into())
+                }
+                Ok(n) => self.nwritten += n,
+                Err(ref e) if e.kind() == ErrorKind::WouldBlock => {
+                    return Ok(Some(PollMode::Writable))
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        // read in the setup
+        loop {
+            crate::trace!(
+                "Reading connection setup with at least {} bytes remaining",
+                self.connect.buffer().len()
+            );
+            let adv = match stream.read(self.connect.buffer(), &mut self.fds) {
+                Ok(0) => {
+                    return Err(std::io::Error::new(
+                        ErrorKind::UnexpectedEof,
+                        "failed to read whole buffer",
+                    )
+                    .into())
+                }
+                Ok(n) => n,
+                Err(ref e) if e.kind() == ErrorKind::WouldBlock => {
+                    return Ok(Some(PollMode::Readable))
+                }
+                Err(e) => return Err(e.into()),
+            };
+            crate::trace!("Read {} bytes", adv);
+
+            // advance the internal buffer
+            if self.connect.advance(adv) {
+                return Ok(None);
+            }
+        }
+    }
+
+    /// Get the setup that was received and check that the given screen exists.
+    pub(super) fn into_setup(self, screen: usize) -> Result<Setup, ConnectError> {
+        let setup = self.connect.into_setup()?;
+        if screen >= setup.roots.len() {
+            return Err(ConnectError::InvalidScreen);
+        }
+        Ok(setup)
+    }
+}
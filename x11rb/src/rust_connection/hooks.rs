@@ -0,0 +1,91 @@
+//! Observing the traffic of a [`super::RustConnection`].
+
+use std::fmt::Debug;
+
+use crate::x11_utils::{ExtInfoProvider, ExtensionInformation, X11Error};
+use x11rb_protocol::SequenceNumber;
+
+/// Callbacks that are invoked for the traffic on a [`super::RustConnection`].
+///
+/// This allows to build debugging tools like an `xtrace`-style protocol tracer or a latency
+/// profiler on top of a connection. Hooks are installed via
+/// [`super::RustConnection::set_hooks`]. All methods have empty default implementations, so only
+/// the interesting ones need to be implemented.
+///
+/// Hooks are called while internal locks of the connection are held. They should return quickly
+/// and must not use the connection, since that would deadlock.
+///
+/// The `RustConnection` of `x11rb-async` supports the same hooks via its `set_hooks` method.
+pub trait ConnectionHooks: Debug + Send + Sync {
+    /// Called when a request is sent.
+    ///
+    /// `request` contains the complete request, including its length field.
+    fn on_request(&self, sequence: SequenceNumber, major_opcode: u8, request: &[u8]) {
+        let _ = (sequence, major_opcode, request);
+    }
+
+    /// Called when a reply is received.
+    fn on_reply(&self, sequence: SequenceNumber, reply: &[u8]) {
+        let _ = (sequence, reply);
+    }
+
+    /// Called when an event is received.
+    fn on_event(&self, event: &[u8]) {
+        let _ = event;
+    }
+
+    /// Called when an X11 error is received.
+    ///
+    /// This is called for all errors, also for those that are later returned from a cookie or
+    /// that are discarded.
+    fn on_error(&self, error: &X11Error) {
+        let _ = error;
+    }
+}
+
+/// The extensions that are known to be present, for parsing errors for [`ConnectionHooks`].
+///
+/// Errors are parsed while `inner` is held. At this point, the extension manager cannot be
+/// locked, since it is held while waiting for replies. This is a copy of the information that
+/// can always be locked.
+#[derive(Debug, Default)]
+pub(super) struct KnownExtensions(Vec<(&'static str, ExtensionInformation)>);
+
+impl KnownExtensions {
+    /// Check if information about the given extension was already recorded.
+    pub(super) fn contains(&self, name: &str) -> bool {
+        self.0.iter().any(|(known, _)| *known == name)
+    }
+
+    /// Record information about an extension.
+    pub(super) fn insert(&mut self, name: &'static str, info: ExtensionInformation) {
+        if !self.contains(name) {
+            self.0.push((name, info));
+        }
+    }
+}
+
+impl ExtInfoProvider for KnownExtensions {
+    fn get_from_major_opcode(&self, major_opcode: u8) -> Option<(&str, ExtensionInformation)> {
+        self.0
+            .iter()
+            .find(|(_, info)| info.major_opcode == major_opcode)
+            .map(|(name, info)| (*name, *info))
+    }
+
+    fn get_from_event_code(&self, event_code: u8) -> Option<(&str, ExtensionInformation)> {
+        self.0
+            .iter()
+            .filter(|(_, info)| info.first_event <= event_code)
+            .max_by_key(|(_, info)| info.first_event)
+            .map(|(name, info)| (*name, *info))
+    }
+
+    fn get_from_error_code(&self, error_code: u8) -> Option<(&str, ExtensionInformation)> {
+        self.0
+            .iter()
+            .filter(|(_, info)| info.first_error <= error_code)
+            .max_by_key(|(_, info)| info.first_error)
+            .map(|(name, info)| (*name, *info))
+    }
+}
@@ -0,0 +1,221 @@
+//! A [`Stream`] on top of a transport that works with binary messages.
+
+use std::io::{Error, ErrorKind, IoSlice, Result};
+use std::sync::Mutex;
+
+use super::{PollMode, Stream};
+use crate::utils::RawFdContainer;
+
+/// A transport that exchanges binary messages, for example a WebSocket.
+///
+/// Messages do not have to correspond to X11 packets. The data of all messages is concatenated
+/// into a byte stream. Thus, a bridge on the other side of the channel can split and merge the
+/// data as it sees fit.
+///
+/// File descriptors cannot be transferred via a message channel.
+pub trait MessageChannel {
+    /// Send a binary message.
+    ///
+    /// This function shall not block. If the underlying transport cannot send data right now,
+    /// the message has to be queued.
+    fn send(&self, message: &[u8]) -> Result<()>;
+
+    /// Receive the next binary message without blocking.
+    ///
+    /// Returns `Ok(None)` if no message is currently available. When the channel was closed,
+    /// an error should be returned.
+    fn try_recv(&self) -> Result<Option<Vec<u8>>>;
+
+    /// Block until a message can be received.
+    ///
+    /// Environments like the browser do not allow blocking. There, this function should fail
+    /// with an error of kind [`ErrorKind::WouldBlock`] if no message is available. Callers then
+    /// see this error and should try again once the next message arrived.
+    fn wait_for_message(&self) -> Result<()>;
+}
+
+/// A [`Stream`] that transfers data via a [`MessageChannel`].
+///
+/// This allows to use a [`super::RustConnection`] in environments without sockets, e.g. on
+/// `wasm32-unknown-unknown` with an X11-over-WebSocket bridge. Use
+/// [`super::RustConnection::connect_to_stream`] to set up a connection with this stream. If the
+/// channel cannot block, use [`super::RustConnection::connect_to_stream_nonblocking`] instead.
+#[derive(Debug)]
+pub struct MessageStream<C> {
+    channel: C,
+
+    /// A received message that was not completely read yet and the position of the unread data.
+    pending: Mutex<(Vec<u8>, usize)>,
+}
+
+impl<C: MessageChannel> MessageStream<C> {
+    /// Create a new stream on top of the given channel.
+    pub fn new(channel: C) -> Self {
+        Self {
+            channel,
+            pending: Mutex::new((Vec::new(), 0)),
+        }
+    }
+
+    /// Get a reference to the underlying channel.
+    pub fn channel(&self) -> &C {
+        &self.channel
+    }
+
+    /// Consume this stream and return the underlying channel.
+    ///
+    /// Data that was already received, but not yet read, is lost.
+    pub fn into_channel(self) -> C {
+        self.channel
+    }
+}
+
+impl<C: MessageChannel> Stream for MessageStream<C> {
+    fn poll(&self, mode: PollMode) -> Result<()> {
+        // Sending never blocks
+        if mode.writable() {
+            return Ok(());
+        }
+        loop {
+            {
+                let mut pending = self.pending.lock().unwrap();
+                if pending.1 < pending.0.len() {
+                    return Ok(());
+                }
+                // Empty messages do not make the stream readable, so check again
+                if let Some(message) = self.channel.try_recv()? {
+                    *pending = (message, 0);
+                    continue;
+                }
+            }
+            self.channel.wait_for_message()?;
+        }
+    }
+
+    fn read(&self, buf: &mut [u8], _fd_storage: &mut Vec<RawFdContainer>) -> Result<usize> {
+        let mut pending = self.pending.lock().unwrap();
+        let (data, position) = &mut *pending;
+        while *position >= data.len() {
+            match self.channel.try_recv()? {
+                Some(message) => {
+                    *data = message;
+                    *position = 0;
+                }
+                None => return Err(ErrorKind::WouldBlock.into()),
+            }
+        }
+        let amount = buf.len().min(data.len() - *position);
+        buf[..amount].copy_from_slice(&data[*position..*position + amount]);
+        *position += amount;
+        Ok(amount)
+    }
+
+    fn write(&self, buf: &[u8], fds: &mut Vec<RawFdContainer>) -> Result<usize> {
+        self.write_vectored(&[IoSlice::new(buf)], fds)
+    }
+
+    fn write_vectored(&self, bufs: &[IoSlice<'_>], fds: &mut Vec<RawFdContainer>) -> Result<usize> {
+        if !fds.is_empty() {
+            return Err(Error::new(ErrorKind::Other, "FD passing is unsupported"));
+        }
+        // Send everything as one message instead of one message per buffer
+        let message = bufs
+            .iter()
+            .flat_map(|buf| buf.iter().copied())
+            .collect::<Vec<_>>();
+        self.channel.send(&message)?;
+        Ok(message.len())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::RefCell;
+    use std::collections::VecDeque;
+    use std::io::{ErrorKind, IoSlice, Result};
+
+    use super::{MessageChannel, MessageStream};
+    use crate::rust_connection::{PollMode, Stream};
+
+    #[derive(Debug, Default)]
+    struct TestChannel {
+        sent: RefCell<Vec<Vec<u8>>>,
+        incoming: RefCell<VecDeque<Vec<u8>>>,
+        /// Messages that arrive one by one while waiting
+        arriving: RefCell<VecDeque<Vec<u8>>>,
+    }
+
+    impl MessageChannel for TestChannel {
+        fn send(&self, message: &[u8]) -> Result<()> {
+            self.sent.borrow_mut().push(message.to_vec());
+            Ok(())
+        }
+
+        fn try_recv(&self) -> Result<Option<Vec<u8>>> {
+            Ok(self.incoming.borrow_mut().pop_front())
+        }
+
+        fn wait_for_message(&self) -> Result<()> {
+            match self.arriving.borrow_mut().pop_front() {
+                Some(message) => {
+                    self.incoming.borrow_mut().push_back(message);
+                    Ok(())
+                }
+                None => Err(ErrorKind::WouldBlock.into()),
+            }
+        }
+    }
+
+    #[test]
+    fn read_across_messages() {
+        let channel = TestChannel::default();
+        channel
+            .incoming
+            .borrow_mut()
+            .extend([vec![1, 2, 3], vec![], vec![4, 5]]);
+        let stream = MessageStream::new(channel);
+
+        let mut buf = [0; 2];
+        let mut fds = Vec::new();
+        assert_eq!(stream.read(&mut buf, &mut fds).unwrap(), 2);
+        assert_eq!(buf, [1, 2]);
+        assert_eq!(stream.read(&mut buf, &mut fds).unwrap(), 1);
+        assert_eq!(buf[..1], [3]);
+        assert_eq!(stream.read(&mut buf, &mut fds).unwrap(), 2);
+        assert_eq!(buf, [4, 5]);
+        assert_eq!(
+            stream.read(&mut buf, &mut fds).unwrap_err().kind(),
+            ErrorKind::WouldBlock
+        );
+    }
+
+    #[test]
+    fn poll_waits_for_data() {
+        let channel = TestChannel::default();
+        channel
+            .arriving
+            .borrow_mut()
+            .extend([vec![], vec![], vec![1]]);
+        let stream = MessageStream::new(channel);
+
+        stream.poll(PollMode::Readable).unwrap();
+        assert!(stream.channel().arriving.borrow().is_empty());
+        let mut buf = [0; 2];
+        assert_eq!(stream.read(&mut buf, &mut Vec::new()).unwrap(), 1);
+        assert_eq!(buf[..1], [1]);
+
+        assert_eq!(
+            stream.poll(PollMode::Readable).unwrap_err().kind(),
+            ErrorKind::WouldBlock
+        );
+        stream.poll(PollMode::ReadAndWritable).unwrap();
+    }
+
+    #[test]
+    fn write_vectored_sends_one_message() {
+        let stream = MessageStream::new(TestChannel::default());
+        let bufs = [IoSlice::new(&[1, 2]), IoSlice::new(&[]), IoSlice::new(&[3])];
+        assert_eq!(stream.write_vectored(&bufs, &mut Vec::new()).unwrap(), 3);
+        assert_eq!(*stream.channel().sent.borrow(), [vec![1, 2, 3]]);
+    }
+}
@@ -1,8 +1,9 @@
 //! A pure-rust implementation of a connection to an X11 server.
 
 use std::io::IoSlice;
-use std::sync::{Condvar, Mutex, MutexGuard, TryLockError};
-use std::time::Instant;
+use std::sync::atomic::{AtomicU32, AtomicU8, AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, RwLock, TryLockError};
+use std::time::{Duration, Instant};
 
 use crate::connection::{
     compute_length_field, Connection, ReplyOrError, RequestConnection, RequestKind,
@@ -10,23 +11,51 @@ use crate::connection::{
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
 use crate::errors::DisplayParsingError;
 pub use crate::errors::{ConnectError, ConnectionError, ParseError, ReplyError, ReplyOrIdError};
+use crate::event_filter::EventFilter;
 use crate::extension_manager::ExtensionManager;
 use crate::protocol::bigreq::{ConnectionExt as _, EnableReply};
-use crate::protocol::xproto::{Setup, GET_INPUT_FOCUS_REQUEST, QUERY_EXTENSION_REQUEST};
+use crate::protocol::xproto::{Setup, Timestamp, GET_INPUT_FOCUS_REQUEST, QUERY_EXTENSION_REQUEST};
+use crate::protocol::{Event, EventKind};
 use crate::utils::RawFdContainer;
-use crate::x11_utils::{ExtensionInformation, TryParse, TryParseFd};
-use x11rb_protocol::connect::Connect;
-use x11rb_protocol::connection::{Connection as ProtoConnection, PollReply, ReplyFdKind};
-use x11rb_protocol::id_allocator::IdAllocator;
+use crate::x11_utils::{ExtensionInformation, TryParse, TryParseFd, X11Error};
+use x11rb_protocol::connection::{
+    Connection as ProtoConnection, EventQueueId, OutstandingRequests, PollReply, ReplyFdKind,
+};
+use x11rb_protocol::id_allocator::{AtomicIdRange, IdAllocator, IdPool};
+use x11rb_protocol::packet_reader::MAX_SPARE_BUFFERS;
+use x11rb_protocol::parse_display::ConnectAddress;
 use x11rb_protocol::{xauth::get_auth, DiscardMode, RawEventAndSeqNumber, SequenceNumber};
 
+mod handshake;
+mod hooks;
+#[cfg(feature = "wasm")]
+mod message_stream;
+#[cfg(all(windows, feature = "allow-unsafe-code"))]
+mod named_pipe;
+mod nonblocking;
 mod packet_reader;
+mod request_log;
 mod stream;
+#[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+mod web_socket;
 mod write_buffer;
 
-use packet_reader::PacketReader;
-pub use stream::{DefaultStream, PollMode, Stream};
+use handshake::Handshake;
+pub use hooks::ConnectionHooks;
+use hooks::KnownExtensions;
+#[cfg(feature = "wasm")]
+pub use message_stream::{MessageChannel, MessageStream};
+#[cfg(all(windows, feature = "allow-unsafe-code"))]
+pub use named_pipe::NamedPipeStream;
+pub use nonblocking::{ConnectStep, NonBlockingConnect};
+use packet_reader::{PacketReader, DEFAULT_READ_BUFFER_SIZE, MIN_READ_BUFFER_SIZE};
+use request_log::RequestLog;
+pub use request_log::SentRequest;
+pub use stream::{AbstractSocket, DefaultStream, PollMode, Stream, TcpKeepalive};
+#[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+pub use web_socket::WebSocketChannel;
 use write_buffer::WriteBuffer;
+pub use x11rb_protocol::connection::Incoming;
 
 type Buffer = <RustConnection as RequestConnection>::Buf;
 /// A combination of a buffer and a list of file descriptors for use by [`RustConnection`].
@@ -43,6 +72,32 @@ enum MaxRequestBytes {
 struct ConnectionInner {
     inner: ProtoConnection,
     write_buffer: WriteBuffer,
+    deferred_flush: Option<DeferredFlush>,
+    /// Buffers of dropped [`EventRef`]s that are handed to the packet reader for reuse.
+    spare_buffers: Vec<Vec<u8>>,
+}
+
+/// State of the deferred flush mode, see [`RustConnection::set_deferred_flush`].
+#[derive(Debug)]
+struct DeferredFlush {
+    /// How long buffered requests may be held back.
+    interval: Duration,
+    /// When the oldest request that is still buffered was sent.
+    pending_since: Option<Instant>,
+}
+
+/// An XC-MISC request that was sent before the XIDs ran out, see [`IdPool::needs_refill`].
+#[derive(Debug, Clone, Copy)]
+enum XidRefill {
+    Range(SequenceNumber),
+    List(SequenceNumber),
+}
+
+/// The state of the XID allocation that is protected by a lock.
+#[derive(Debug)]
+struct IdState {
+    pool: IdPool,
+    refill: Option<XidRefill>,
 }
 
 type MutexGuardInner<'a> = MutexGuard<'a, ConnectionInner>;
@@ -53,6 +108,54 @@ pub(crate) enum BlockingMode {
     NonBlocking,
 }
 
+/// How requests are checked for errors in synchronous mode.
+///
+/// See [`RustConnection::set_synchronous`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SyncMode {
+    /// Requests are not checked. This is the default.
+    #[default]
+    Off,
+    /// Every request is checked. An error is passed to the error handler. If no handler is
+    /// installed, the error is left for the cookie of the request.
+    Report,
+    /// Every request is checked. An error causes a panic, so that the backtrace points to the
+    /// code that sent the request.
+    Panic,
+}
+
+impl SyncMode {
+    fn from_u8(value: u8) -> Self {
+        match value {
+            1 => SyncMode::Report,
+            2 => SyncMode::Panic,
+            _ => SyncMode::Off,
+        }
+    }
+
+    fn to_u8(self) -> u8 {
+        match self {
+            SyncMode::Off => 0,
+            SyncMode::Report => 1,
+            SyncMode::Panic => 2,
+        }
+    }
+}
+
+/// A function that is called for X11 errors that no one waits for.
+///
+/// See [`RustConnection::set_error_handler`].
+pub type ErrorHandler = Box<dyn Fn(&X11Error) + Send + Sync>;
+
+/// An [`ErrorHandler`] that can be shared with the threads that read from the connection.
+struct SharedErrorHandler(ErrorHandler);
+
+impl std::fmt::Debug for SharedErrorHandler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SharedErrorHandler").finish_non_exhaustive()
+    }
+}
+
 /// A connection to an X11 server implemented in pure rust
 ///
 /// This type is generic over `S`, which allows to use a generic stream to communicate with the
@@ -68,11 +171,19 @@ pub struct RustConnection<S: Stream = DefaultStream> {
     // This mutex is only locked with `try_lock` (never blocks), so a simpler
     // lock based only on a atomic variable would be more efficient.
     packet_reader: Mutex<PacketReader>,
+    read_buffer_size: AtomicUsize,
     reader_condition: Condvar,
     setup: Setup,
     extension_manager: Mutex<ExtensionManager>,
+    known_extensions: RwLock<KnownExtensions>,
     maximum_request_bytes: Mutex<MaxRequestBytes>,
-    id_allocator: Mutex<IdAllocator>,
+    id_allocator: Mutex<IdState>,
+    id_range: AtomicIdRange,
+    xid_list_batch_size: AtomicU32,
+    hooks: Mutex<Option<Arc<dyn ConnectionHooks>>>,
+    error_handler: Mutex<Option<Arc<SharedErrorHandler>>>,
+    request_log: Mutex<Option<RequestLog>>,
+    sync_mode: AtomicU8,
 }
 
 // Locking rules
@@ -85,6 +196,12 @@ pub struct RustConnection<S: Stream = DefaultStream> {
 // - extension_manager
 // - id_allocator
 //
+// `id_range` is lock-free. It is only refilled while `id_allocator` is held. When only a few IDs
+// are left, `generate_id` uses `try_lock` on `id_allocator` to request more IDs ahead of time.
+//
+// `hooks`, `error_handler`, `request_log` and `known_extensions` may be locked at any time, but no other mutex may be
+// locked while they are held.
+//
 // Then comes `inner`. This mutex protects the information about in-flight requests and packets
 // that were already read from the connection but not given out to callers. This mutex also
 // contains the write buffer and has to be locked in order to write something to the X11 server.
@@ -110,6 +227,76 @@ impl RustConnection<DefaultStream> {
     ///
     /// If no `dpy_name` is provided, the value from `$DISPLAY` is used.
     pub fn connect(dpy_name: Option<&str>) -> Result<(Self, usize), ConnectError> {
+        Self::connect_impl(dpy_name, None)
+    }
+
+    /// Establish a new connection, giving up after the given timeout.
+    ///
+    /// This works like [`RustConnection::connect`], but the time spent on resolving the hostname,
+    /// establishing the connection and performing the setup handshake with the X11 server is
+    /// bounded by `timeout`. If it expires, [`ConnectError::Timeout`] is returned.
+    pub fn connect_with_timeout(
+        dpy_name: Option<&str>,
+        timeout: Duration,
+    ) -> Result<(Self, usize), ConnectError> {
+        Self::connect_impl(dpy_name, Some(Instant::now() + timeout))
+    }
+
+    /// Establish a new connection to the given address.
+    ///
+    /// Unlike [`RustConnection::connect`], this does not parse a display name and does not
+    /// follow the convention of looking for sockets in `/tmp/.X11-unix`. Instead, exactly the
+    /// given address is used. This is useful in sandboxes where the socket of the X11 server is
+    /// mounted somewhere else.
+    ///
+    /// `display` is the display number that is used for looking up authentication information.
+    /// `screen` is the number of the screen that should be used. For Unix domain sockets,
+    /// `abstract_socket` controls whether the abstract namespace is used.
+    pub fn connect_to_address(
+        addr: &ConnectAddress<'_>,
+        display: u16,
+        screen: usize,
+        abstract_socket: AbstractSocket,
+    ) -> Result<Self, ConnectError> {
+        let (stream, (family, address)) =
+            DefaultStream::connect_with_abstract_socket(addr, abstract_socket)?;
+        crate::trace!("Connected to X11 server via {:?}", addr);
+        let (auth_name, auth_data) = get_auth(family, &address, display)
+            // Ignore all errors while determining auth; instead we just try without auth info.
+            .unwrap_or(None)
+            .unwrap_or_else(|| (Vec::new(), Vec::new()));
+        Self::connect_to_stream_with_auth_info(stream, screen, auth_name, auth_data)
+    }
+
+    /// Establish a new connection over an already connected socket.
+    ///
+    /// This is useful when a sandbox launcher hands out a socket that is connected to the X11
+    /// server. Only the setup handshake is performed on the given file descriptor.
+    ///
+    /// `screen` is the number of the screen that should be used. The parameters `auth_name` and
+    /// `auth_data` are used for authenticating with the X11 server, see
+    /// [`RustConnection::connect_to_stream_with_auth_info`].
+    #[cfg(unix)]
+    pub fn connect_to_fd(
+        fd: std::os::unix::io::OwnedFd,
+        screen: usize,
+        auth_name: Vec<u8>,
+        auth_data: Vec<u8>,
+    ) -> Result<Self, ConnectError> {
+        let stream = DefaultStream::from_owned_fd(fd)?;
+        Self::connect_to_stream_with_auth_info(stream, screen, auth_name, auth_data)
+    }
+
+    fn connect_impl(
+        dpy_name: Option<&str>,
+        deadline: Option<Instant>,
+    ) -> Result<(Self, usize), ConnectError> {
+        // Get the time that is left until the deadline
+        let remaining = |deadline: Instant| match deadline.checked_duration_since(Instant::now()) {
+            Some(remaining) if remaining > Duration::ZERO => Ok(remaining),
+            _ => Err(ConnectError::Timeout),
+        };
+
         // Parse display information
         let parsed_display = x11rb_protocol::parse_display::parse_display(dpy_name)?;
         let screen = parsed_display.screen.into();
@@ -119,7 +306,11 @@ impl RustConnection<DefaultStream> {
         let mut error = None;
         for addr in parsed_display.connect_instruction() {
             let start = Instant::now();
-            match DefaultStream::connect(&addr) {
+            let stream = match deadline {
+                None => DefaultStream::connect(&addr),
+                Some(deadline) => DefaultStream::connect_with_timeout(&addr, remaining(deadline)?),
+            };
+            match stream {
                 Ok((stream, (family, address))) => {
                     crate::trace!(
                         "Connected to X11 server via {:?} in {:?}",
@@ -135,12 +326,25 @@ impl RustConnection<DefaultStream> {
                     crate::trace!("Picked authentication via auth mechanism {:?}", auth_name);
 
                     // finish connecting to server
-                    return Ok((
-                        Self::connect_to_stream_with_auth_info(
+                    let conn = match deadline {
+                        None => Self::connect_to_stream_with_auth_info(
                             stream, screen, auth_name, auth_data,
                         )?,
-                        screen,
-                    ));
+                        Some(deadline) => Self::connect_to_stream_impl(
+                            stream,
+                            screen,
+                            auth_name,
+                            auth_data,
+                            |stream, mode| {
+                                if stream.poll_with_timeout(mode, Some(remaining(deadline)?))? {
+                                    Ok(())
+                                } else {
+                                    Err(ConnectError::Timeout)
+                                }
+                            },
+                        )?,
+                    };
+                    return Ok((conn, screen));
                 }
                 Err(e) => {
                     crate::debug!("Failed to connect to X11 server via {:?}: {:?}", addr, e);
@@ -152,6 +356,9 @@ impl RustConnection<DefaultStream> {
 
         // none of the addresses worked
         Err(match error {
+            Some(e) if deadline.is_some() && e.kind() == std::io::ErrorKind::TimedOut => {
+                ConnectError::Timeout
+            }
             Some(e) => ConnectError::IoError(e),
             None => DisplayParsingError::Unknown.into(),
         })
@@ -183,69 +390,26 @@ impl<S: Stream> RustConnection<S> {
         auth_name: Vec<u8>,
         auth_data: Vec<u8>,
     ) -> Result<Self, ConnectError> {
-        let (mut connect, setup_request) = Connect::with_authorization(auth_name, auth_data);
-
-        // write the connect() setup request
-        let mut nwritten = 0;
-        let mut fds = vec![];
-
-        crate::trace!(
-            "Writing connection setup with {} bytes",
-            setup_request.len()
-        );
-        while nwritten != setup_request.len() {
-            stream.poll(PollMode::Writable)?;
-            // poll returned successfully, so the stream is writable.
-            match stream.write(&setup_request[nwritten..], &mut fds) {
-                Ok(0) => {
-                    return Err(std::io::Error::new(
-                        std::io::ErrorKind::WriteZero,
-                        "failed to write whole buffer",
-                    )
-                    .into())
-                }
-                Ok(n) => nwritten += n,
-                // Spurious wakeup from poll, try again
-                Err(ref e) if e.kind() == std::io::ErrorKind::WouldBlock => {}
-                Err(e) => return Err(e.into()),
-            }
-        }
-
-        // read in the setup
-        loop {
-            stream.poll(PollMode::Readable)?;
-            crate::trace!(
-                "Reading connection setup with at least {} bytes remaining",
-                connect.buffer().len()
-            );
-            let adv = match stream.read(connect.buffer(), &mut fds) {
-                Ok(0) => {
-                    return Err(std::io::Error::new(
-                        std::io::ErrorKind::UnexpectedEof,
-                        "failed to read whole buffer",
-                    )
-                    .into())
-                }
-                Ok(n) => n,
-                // Spurious wakeup from poll, try again
-                Err(ref e) if e.kind() == std::io::ErrorKind::WouldBlock => continue,
-                Err(e) => return Err(e.into()),
-            };
-            crate::trace!("Read {} bytes", adv);
-
-            // advance the internal buffer
-            if connect.advance(adv) {
-                break;
-            }
-        }
-
-        // resolve the setup
-        let setup = connect.into_setup()?;
+        Self::connect_to_stream_impl(stream, screen, auth_name, auth_data, |stream, mode| {
+            Ok(stream.poll(mode)?)
+        })
+    }
 
-        // Check that we got a valid screen number
-        if screen >= setup.roots.len() {
-            return Err(ConnectError::InvalidScreen);
+    /// Perform the setup handshake on the given stream.
+    ///
+    /// `poll` is used to wait for the stream to become readable or writable.
+    fn connect_to_stream_impl(
+        stream: S,
+        screen: usize,
+        auth_name: Vec<u8>,
+        auth_data: Vec<u8>,
+        poll: impl Fn(&S, PollMode) -> Result<(), ConnectError>,
+    ) -> Result<Self, ConnectError> {
+        let mut handshake = Handshake::new(auth_name, auth_data);
+        while let Some(mode) = handshake.step(&stream)? {
+            poll(&stream, mode)?;
         }
+        let setup = handshake.into_setup(screen)?;
 
         // Success! Set up our state
         Self::for_connected_stream(stream, setup)
@@ -263,14 +427,27 @@ impl<S: Stream> RustConnection<S> {
             inner: Mutex::new(ConnectionInner {
                 inner: ProtoConnection::new(),
                 write_buffer: WriteBuffer::new(),
+                deferred_flush: None,
+                spare_buffers: Vec::new(),
             }),
             stream,
             packet_reader: Mutex::new(PacketReader::new()),
+            read_buffer_size: AtomicUsize::new(DEFAULT_READ_BUFFER_SIZE),
             reader_condition: Condvar::new(),
             setup,
             extension_manager: Default::default(),
+            known_extensions: Default::default(),
             maximum_request_bytes: Mutex::new(MaxRequestBytes::Unknown),
-            id_allocator: Mutex::new(id_allocator),
+            id_range: AtomicIdRange::new(&id_allocator),
+            id_allocator: Mutex::new(IdState {
+                pool: IdPool::new(id_allocator),
+                refill: None,
+            }),
+            xid_list_batch_size: AtomicU32::new(0),
+            hooks: Mutex::new(None),
+            error_handler: Mutex::new(None),
+            request_log: Mutex::new(None),
+            sync_mode: AtomicU8::new(SyncMode::Off.to_u8()),
         })
     }
 
@@ -306,8 +483,16 @@ impl<S: Stream> RustConnection<S> {
             let send_result = inner.inner.send_request(kind);
             match send_result {
                 Some(seqno) => {
+                    if let Some(log) = &mut *self.request_log.lock().unwrap() {
+                        log.record(seqno, request_info.major_opcode, request_info.minor_opcode);
+                    }
+                    self.call_request_hook(seqno, bufs);
                     // Now actually send the buffers
-                    let _inner = self.write_all_vectored(inner, bufs, fds)?;
+                    inner = self.write_all_vectored(inner, bufs, fds)?;
+                    drop(self.flush_if_due(inner)?);
+                    if kind == ReplyFdKind::NoReply {
+                        self.check_synchronously(seqno)?;
+                    }
                     return Ok(seqno);
                 }
                 None => {
@@ -342,6 +527,7 @@ impl<S: Stream> RustConnection<S> {
         inner
             .inner
             .discard_reply(seqno, DiscardMode::DiscardReplyAndError);
+        self.call_request_hook(seqno, &[IoSlice::new(&request)]);
         let inner = self.write_all_vectored(inner, &[IoSlice::new(&request)], Vec::new())?;
 
         Ok(inner)
@@ -439,6 +625,32 @@ impl<S: Stream> RustConnection<S> {
                 Err(e) => return Err(e),
             }
         }
+        if let Some(deferred) = &mut inner.deferred_flush {
+            deferred.pending_since = None;
+        }
+        Ok(inner)
+    }
+
+    /// In deferred flush mode, flush the write buffer if held back data is due.
+    ///
+    /// This also remembers since when data is waiting in the buffer. Without deferred flushing,
+    /// this does nothing.
+    fn flush_if_due<'a>(
+        &'a self,
+        mut inner: MutexGuardInner<'a>,
+    ) -> std::io::Result<MutexGuardInner<'a>> {
+        let needs_flush = inner.write_buffer.needs_flush();
+        let due = match &mut inner.deferred_flush {
+            None => false,
+            Some(deferred) if needs_flush => {
+                let pending_since = *deferred.pending_since.get_or_insert_with(Instant::now);
+                pending_since.elapsed() >= deferred.interval
+            }
+            Some(_) => false,
+        };
+        if due {
+            inner = self.flush_impl(inner)?;
+        }
         Ok(inner)
     }
 
@@ -483,7 +695,7 @@ impl<S: Stream> RustConnection<S> {
                 Ok(self.reader_condition.wait(inner).unwrap())
             }
             Err(TryLockError::Poisoned(e)) => panic!("{}", e),
-            Ok(mut packet_reader) => {
+            Ok(packet_reader) => {
                 // Make sure sleeping readers are woken up when we return
                 // (Even in case of errors)
                 let notify_on_drop = NotifyOnDrop(&self.reader_condition);
@@ -499,25 +711,8 @@ impl<S: Stream> RustConnection<S> {
                     inner = self.inner.lock().unwrap();
                 }
 
-                // 2.2. Try to read as many packets as possible without blocking.
-                let mut fds = Vec::new();
-                let mut packets = Vec::new();
-                packet_reader.try_read_packets(&self.stream, &mut packets, &mut fds)?;
-
-                // 2.3. Once `inner` has been relocked, drop the
-                // lock on `packet_reader`. While inner is locked, other
-                // threads cannot arrive at 0.1 anyways.
-                //
-                // `packet_reader` must be unlocked with `inner` is locked,
-                // otherwise it could let another thread wait on 2.1
-                // for a reply that has been read but not enqueued yet.
-                drop(packet_reader);
-
-                // 2.4. Actually enqueue the read packets.
-                inner.inner.enqueue_fds(fds);
-                packets
-                    .into_iter()
-                    .for_each(|packet| inner.inner.enqueue_packet(packet));
+                // 2.2 - 2.4. Read and enqueue packets.
+                inner = self.read_and_enqueue_locked(inner, packet_reader)?;
 
                 // 2.5. Notify the condvar by dropping the `notify_on_drop` object.
                 // The object would have been dropped when the function returns, so
@@ -531,6 +726,47 @@ impl<S: Stream> RustConnection<S> {
         }
     }
 
+    /// Read as many packets as possible without blocking and enqueue them.
+    ///
+    /// This implements steps 2.2 to 2.4 of `read_packet_and_enqueue` for a caller that already
+    /// holds the lock on `packet_reader`. The caller is responsible for notifying
+    /// `reader_condition` afterwards.
+    fn read_and_enqueue_locked<'a>(
+        &'a self,
+        mut inner: MutexGuardInner<'a>,
+        mut packet_reader: MutexGuard<'a, PacketReader>,
+    ) -> Result<MutexGuardInner<'a>, std::io::Error> {
+        // 2.2. Try to read as many packets as possible without blocking.
+        let mut fds = Vec::new();
+        let mut packets = Vec::new();
+        packet_reader.set_buffer_size(self.read_buffer_size.load(Ordering::Relaxed));
+        for buffer in inner.spare_buffers.drain(..) {
+            packet_reader.recycle(buffer);
+        }
+        packet_reader.try_read_packets(&self.stream, &mut packets, &mut fds)?;
+
+        // 2.3. Once `inner` has been relocked, drop the
+        // lock on `packet_reader`. While inner is locked, other
+        // threads cannot arrive at 0.1 anyways.
+        //
+        // `packet_reader` must be unlocked with `inner` is locked,
+        // otherwise it could let another thread wait on 2.1
+        // for a reply that has been read but not enqueued yet.
+        drop(packet_reader);
+
+        // 2.4. Actually enqueue the read packets.
+        inner.inner.enqueue_fds(fds);
+        let hooks = self.current_hooks();
+        for packet in packets {
+            if let Some(hooks) = &hooks {
+                self.call_packet_hook(&**hooks, &inner.inner, &packet);
+            }
+            inner.inner.enqueue_packet(packet);
+        }
+        self.call_error_handler(&mut inner.inner);
+        Ok(inner)
+    }
+
     fn prefetch_maximum_request_bytes_impl(&self, max_bytes: &mut MutexGuard<'_, MaxRequestBytes>) {
         if let MaxRequestBytes::Unknown = **max_bytes {
             crate::info!("Prefetching maximum request length");
@@ -546,6 +782,532 @@ impl<S: Stream> RustConnection<S> {
     pub fn stream(&self) -> &S {
         &self.stream
     }
+
+    /// Start recording the most recently sent requests.
+    ///
+    /// This is a debugging aid for finding the request that caused an X11 error. The last
+    /// `capacity` requests are kept. Use [`RustConnection::request_for_error`] or
+    /// [`RustConnection::describe_error`] to look up the request for an error.
+    ///
+    /// If `capture_origin` is given, it is called whenever a request is sent and its result is
+    /// recorded as the origin of the request. For example, with Rust 1.65 or newer,
+    /// `std::backtrace::Backtrace::force_capture().to_string()` records where the request was
+    /// sent from. Capturing a backtrace for every request is slow.
+    pub fn enable_request_log(
+        &self,
+        capacity: usize,
+        capture_origin: Option<Box<dyn Fn() -> String + Send + Sync>>,
+    ) {
+        *self.request_log.lock().unwrap() = Some(RequestLog::new(capacity, capture_origin));
+    }
+
+    /// Stop recording sent requests and forget all recorded requests.
+    pub fn disable_request_log(&self) {
+        *self.request_log.lock().unwrap() = None;
+    }
+
+    /// Find the request that caused the given X11 error.
+    ///
+    /// This returns `None` if the request log is disabled or if the request was already
+    /// forgotten. See [`RustConnection::enable_request_log`].
+    pub fn request_for_error(&self, error: &X11Error) -> Option<SentRequest> {
+        self.request_log
+            .lock()
+            .unwrap()
+            .as_ref()
+            .and_then(|log| log.find(error).cloned())
+    }
+
+    /// Describe an X11 error, including where the request that caused it was sent from.
+    ///
+    /// The result looks like `Value error in xproto::ConfigureWindow (sequence 1234) sent from
+    /// ...`. The origin is only known if it was recorded by the request log, see
+    /// [`RustConnection::enable_request_log`].
+    pub fn describe_error(&self, error: &X11Error) -> String {
+        request_log::describe_error(error, self.request_for_error(error).as_ref())
+    }
+
+    /// Install hooks that observe the traffic on this connection.
+    ///
+    /// Any previously installed hooks are replaced. Pass `None` to remove the hooks.
+    pub fn set_hooks(&self, hooks: Option<Arc<dyn ConnectionHooks>>) {
+        *self.hooks.lock().unwrap() = hooks;
+    }
+
+    /// Get the currently installed hooks.
+    fn current_hooks(&self) -> Option<Arc<dyn ConnectionHooks>> {
+        self.hooks.lock().unwrap().clone()
+    }
+
+    /// Tell the hooks about a request that is sent.
+    fn call_request_hook(&self, sequence: SequenceNumber, bufs: &[IoSlice<'_>]) {
+        if let Some(hooks) = self.current_hooks() {
+            let request = bufs
+                .iter()
+                .flat_map(|buf| buf.iter().copied())
+                .collect::<Vec<_>>();
+            hooks.on_request(sequence, request[0], &request);
+        }
+    }
+
+    /// Tell the hooks about a packet that was received.
+    fn call_packet_hook(
+        &self,
+        hooks: &dyn ConnectionHooks,
+        inner: &ProtoConnection,
+        packet: &[u8],
+    ) {
+        match packet[0] {
+            0 => match self.parse_error_nonblocking(packet) {
+                Ok(error) => hooks.on_error(&error),
+                Err(e) => {
+                    crate::warning!("Failed to parse X11 error for hooks: {:?}", e);
+                }
+            },
+            1 => hooks.on_reply(inner.sequence_number_of(packet), packet),
+            _ => hooks.on_event(packet),
+        }
+    }
+
+    /// Parse an X11 error without blocking on the extension manager.
+    fn parse_error_nonblocking(&self, packet: &[u8]) -> Result<X11Error, ParseError> {
+        // The extension manager might be locked by a thread that waits for a reply. Blocking on
+        // it would deadlock, so use the copy of the extension information instead.
+        X11Error::try_parse(packet, &*self.known_extensions.read().unwrap())
+    }
+
+    /// Install a function that is called for X11 errors that no one waits for.
+    ///
+    /// These are errors for requests whose cookie was dropped, e.g. requests sent without
+    /// checking for errors, and errors that do not belong to any known request. By default, they
+    /// are returned as [`Event::Error`] from e.g. [`Connection::poll_for_event`]. With an error
+    /// handler, they are passed to the handler instead, like with `XSetErrorHandler` in Xlib.
+    /// Errors that are returned from a cookie or that were explicitly discarded are not affected.
+    ///
+    /// The handler is called while the connection is locked internally, so it must not use the
+    /// connection. Any previously installed handler is replaced. Pass `None` to remove the handler;
+    /// errors are then put into the event queue again.
+    pub fn set_error_handler(&self, handler: Option<ErrorHandler>) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.inner.set_collect_unchecked_errors(handler.is_some());
+        *self.error_handler.lock().unwrap() =
+            handler.map(|handler| Arc::new(SharedErrorHandler(handler)));
+    }
+
+    /// Enable or disable synchronous mode, like `XSynchronize` in Xlib.
+    ///
+    /// This is a debugging aid. In synchronous mode, every request that does not have a reply is
+    /// checked for errors right after it was sent, which requires a round trip to the X11 server.
+    /// An error is thus noticed before the function that sent the request returns. With
+    /// [`SyncMode::Report`], it is passed to the error handler if one is installed, see
+    /// [`RustConnection::set_error_handler`]. Since the error was already reported, checking the
+    /// returned cookie does not report it again. Without a handler, the error is left for the
+    /// cookie: Checking it returns the error, and dropping it puts the error into the event queue
+    /// as usual. With [`SyncMode::Panic`], the request function panics with a description of the
+    /// error, so that the backtrace of the panic points to the code that sent the request.
+    ///
+    /// Synchronous mode makes the connection very slow and should only be used for debugging.
+    pub fn set_synchronous(&self, mode: SyncMode) {
+        self.sync_mode.store(mode.to_u8(), Ordering::Relaxed);
+    }
+
+    /// Wait until the server processed a request in synchronous mode and report its error.
+    fn check_synchronously(&self, sequence: SequenceNumber) -> Result<(), ConnectionError> {
+        let mode = SyncMode::from_u8(self.sync_mode.load(Ordering::Relaxed));
+        if mode == SyncMode::Off {
+            return Ok(());
+        }
+        let handler = self.error_handler.lock().unwrap().clone();
+        if mode == SyncMode::Report && handler.is_none() {
+            // Only wait until the server processed the request. Its error, if any, is then
+            // queued for the cookie.
+            let _ = crate::protocol::xproto::get_input_focus(self)?.raw_reply_unchecked()?;
+            return Ok(());
+        }
+        let error = match self.check_for_raw_error(sequence)? {
+            Some(error) => self.parse_error(&error)?,
+            None => return Ok(()),
+        };
+        match handler {
+            Some(handler) if mode == SyncMode::Report => (handler.0)(&error),
+            _ => panic!(
+                "X11 error in synchronous mode: {}",
+                self.describe_error(&error)
+            ),
+        }
+        Ok(())
+    }
+
+    /// Enable or disable the tap of this connection.
+    ///
+    /// While the tap is enabled, a copy of every reply, error and event that is received is
+    /// recorded in the order in which it arrived, including replies to requests that were sent by
+    /// other parts of the program and packets that are discarded. This is useful for tools that
+    /// proxy or record X11 traffic. Packets are still delivered normally. The recorded packets
+    /// are fetched with [`RustConnection::poll_for_tapped_packet`]; they pile up until then.
+    ///
+    /// Disabling the tap drops all packets that were not fetched yet.
+    pub fn set_tap_enabled(&self, enabled: bool) {
+        self.inner.lock().unwrap().inner.set_tap_enabled(enabled);
+    }
+
+    /// Get the next packet that was recorded by the tap, together with its sequence number.
+    ///
+    /// This does not block, but reads everything that is available from the X11 server if no
+    /// packet was recorded yet. See [`RustConnection::set_tap_enabled`].
+    pub fn poll_for_tapped_packet(
+        &self,
+    ) -> Result<Option<(Incoming, SequenceNumber)>, ConnectionError> {
+        let mut inner = self.inner.lock().unwrap();
+        if let Some(packet) = inner.inner.poll_for_tapped_packet() {
+            return Ok(Some(packet));
+        }
+        inner = self.flush_if_due(inner)?;
+        inner = self.read_packet_and_enqueue(inner, BlockingMode::NonBlocking)?;
+        Ok(inner.inner.poll_for_tapped_packet())
+    }
+
+    /// Pass the errors that no one waits for to the error handler.
+    fn call_error_handler(&self, inner: &mut ProtoConnection) {
+        let handler = match self.error_handler.lock().unwrap().clone() {
+            Some(handler) => handler,
+            None => return,
+        };
+        while let Some((error, _)) = inner.poll_for_unchecked_error() {
+            match self.parse_error_nonblocking(&error) {
+                Ok(error) => (handler.0)(&error),
+                Err(e) => {
+                    crate::warning!("Failed to parse X11 error for the error handler: {:?}", e);
+                }
+            }
+        }
+    }
+
+    /// Enable or disable deferred flushing.
+    ///
+    /// By default, requests are written to the X11 server when the write buffer is full or when
+    /// [`Connection::flush`] is called. With deferred flushing enabled, [`Connection::flush`]
+    /// does nothing until the oldest buffered request was held back for at least `interval`.
+    /// Instead, the connection flushes automatically before blocking for a reply or an event and
+    /// when a request is sent or an event is polled for after `interval` passed. This coalesces
+    /// bursts of small requests into fewer writes.
+    ///
+    /// Since there is no background thread, requests that are followed by no further activity on
+    /// the connection stay in the buffer. Code that waits for the connection's file descriptor
+    /// itself must call [`Connection::flush_all`] before waiting, which always flushes. The event
+    /// loop integrations in this crate already do this.
+    /// Alternatively, an event loop can wake up at [`RustConnection::flush_deadline`] and call
+    /// [`Connection::flush`] then.
+    ///
+    /// Passing `None` disables deferred flushing and flushes all buffered requests.
+    pub fn set_deferred_flush(&self, interval: Option<Duration>) -> Result<(), ConnectionError> {
+        let mut inner = self.inner.lock().unwrap();
+        match interval {
+            Some(interval) => {
+                let pending_since = inner
+                    .deferred_flush
+                    .as_ref()
+                    .and_then(|deferred| deferred.pending_since);
+                inner.deferred_flush = Some(DeferredFlush {
+                    interval,
+                    pending_since,
+                });
+            }
+            None => {
+                inner.deferred_flush = None;
+                let _inner = self.flush_impl(inner)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the time at which the buffered requests are due to be flushed.
+    ///
+    /// This returns `None` if deferred flushing is disabled or if no requests are waiting to be
+    /// written. See [`RustConnection::set_deferred_flush`].
+    pub fn flush_deadline(&self) -> Option<Instant> {
+        let inner = self.inner.lock().unwrap();
+        let deferred = inner.deferred_flush.as_ref()?;
+        deferred
+            .pending_since
+            .filter(|_| inner.write_buffer.needs_flush())
+            .map(|pending_since| pending_since + deferred.interval)
+    }
+
+    /// Set the size of the buffer that is used for reading from the X11 server.
+    ///
+    /// Incoming data is read into this buffer and then split into packets. A larger buffer allows
+    /// to read more packets with a single system call, which helps clients that receive lots of
+    /// events, e.g. XInput raw motion events. The default size is 4096 bytes. Sizes smaller than
+    /// 32 bytes (the size of an event) are increased to 32 bytes.
+    ///
+    /// Reads are always batched: The connection keeps reading until no more data is available and
+    /// only then hands all received packets over at once. The buffer size only limits how much
+    /// data a single system call returns.
+    ///
+    /// The new size is used for the next read.
+    pub fn set_read_buffer_size(&self, size: usize) {
+        self.read_buffer_size
+            .store(size.max(MIN_READ_BUFFER_SIZE), Ordering::Relaxed);
+    }
+
+    /// Get the size of the buffer that is used for reading from the X11 server.
+    ///
+    /// See [`RustConnection::set_read_buffer_size`].
+    pub fn read_buffer_size(&self) -> usize {
+        self.read_buffer_size.load(Ordering::Relaxed)
+    }
+
+    /// Get information about requests that were not fully handled yet.
+    ///
+    /// This allows to detect requests whose replies are never collected, for example because
+    /// their cookie was leaked, or requests that the X11 server takes long to answer.
+    pub fn outstanding_requests(&self) -> OutstandingRequests {
+        self.inner.lock().unwrap().inner.outstanding_requests()
+    }
+
+    /// Get the discard mode of the request with the given sequence number.
+    ///
+    /// See [`ProtoConnection::discard_mode`] for details.
+    pub fn discard_mode(&self, sequence: SequenceNumber) -> Option<DiscardMode> {
+        self.inner.lock().unwrap().inner.discard_mode(sequence)
+    }
+
+    /// Set how many XIDs are requested at once when the XIDs of this client are exhausted.
+    ///
+    /// With the default of zero, the XC-MISC request `GetXIDRange` is used to find free XIDs. On
+    /// busy servers, this may return very small ranges so that many round trips are needed to
+    /// create lots of resources. With a non-zero batch size, `GetXIDList` is used instead to ask
+    /// for up to this many free XIDs at once.
+    pub fn set_xid_list_batch_size(&self, size: u32) {
+        self.xid_list_batch_size.store(size, Ordering::Relaxed);
+    }
+
+    /// Get the number of XIDs that are requested at once via `GetXIDList`.
+    ///
+    /// See [`RustConnection::set_xid_list_batch_size`].
+    pub fn xid_list_batch_size(&self) -> u32 {
+        self.xid_list_batch_size.load(Ordering::Relaxed)
+    }
+
+    /// Take the first queued event that matches `filter` out of the event queue.
+    ///
+    /// The extension manager must be locked before `inner`, because `extension_information()`
+    /// sends requests while holding the lock on the extension manager.
+    fn take_matching_event<'a>(
+        &'a self,
+        filter: &EventFilter,
+    ) -> Result<(Option<Event>, MutexGuardInner<'a>), ConnectionError> {
+        let ext_mgr = self.extension_manager.lock().unwrap();
+        let mut inner = self.inner.lock().unwrap();
+        let event = inner
+            .inner
+            .poll_for_matching_event_with_sequence(|event| filter.matches_raw(event, &*ext_mgr));
+        let event = match event {
+            Some((event, _)) => Some(Event::parse(&event, &*ext_mgr)?),
+            None => None,
+        };
+        Ok((event, inner))
+    }
+
+    /// Get the first event that matches `filter` without blocking.
+    ///
+    /// Events that do not match stay in the event queue and can still be fetched with e.g.
+    /// [`Connection::poll_for_event`].
+    pub fn poll_for_matching_event(
+        &self,
+        filter: &EventFilter,
+    ) -> Result<Option<Event>, ConnectionError> {
+        let (event, inner) = self.take_matching_event(filter)?;
+        if event.is_some() {
+            return Ok(event);
+        }
+        let inner = self.flush_if_due(inner)?;
+        drop(self.read_packet_and_enqueue(inner, BlockingMode::NonBlocking)?);
+        Ok(self.take_matching_event(filter)?.0)
+    }
+
+    /// Wait for an event that matches `filter`.
+    ///
+    /// Events that do not match stay in the event queue and can still be fetched with e.g.
+    /// [`Connection::wait_for_event`].
+    pub fn wait_for_matching_event(&self, filter: &EventFilter) -> Result<Event, ConnectionError> {
+        loop {
+            let (event, mut inner) = self.take_matching_event(filter)?;
+            if let Some(event) = event {
+                return Ok(event);
+            }
+            if inner.deferred_flush.is_some() {
+                // Held back requests might be needed for the event to be generated
+                inner = self.flush_impl(inner)?;
+            }
+            drop(self.read_packet_and_enqueue(inner, BlockingMode::Blocking)?);
+        }
+    }
+
+    /// Ask the X11 server for its current time.
+    ///
+    /// This creates a temporary window on the root window of the given screen, appends nothing
+    /// to one of its properties and waits for the resulting `PropertyNotify` event, which
+    /// contains the server time. Other events stay in the event queue. See the
+    /// [`server_time`](crate::server_time) module for keeping track of the server time without a
+    /// round trip.
+    ///
+    /// If the X11 server fails to create the window or to change the property, the error is
+    /// returned. This function fails with [`ParseError::InvalidValue`] if `screen` does not
+    /// exist.
+    pub fn fetch_server_time(&self, screen: usize) -> Result<Timestamp, ReplyOrIdError> {
+        use crate::protocol::xproto::{
+            AtomEnum, ConnectionExt as _, CreateWindowAux, EventMask, PropMode, WindowClass,
+        };
+
+        let root = self
+            .setup
+            .roots
+            .get(screen)
+            .ok_or(ParseError::InvalidValue)?
+            .root;
+        let window = self.generate_id()?;
+        let aux = CreateWindowAux::new().event_mask(EventMask::PROPERTY_CHANGE);
+        let create = self.create_window(
+            0,
+            window,
+            root,
+            0,
+            0,
+            1,
+            1,
+            0,
+            WindowClass::INPUT_ONLY,
+            0,
+            &aux,
+        )?;
+        let change = self.change_property(
+            PropMode::APPEND,
+            window,
+            AtomEnum::WM_NAME,
+            AtomEnum::STRING,
+            8,
+            0,
+            &[],
+        )?;
+        // Without the window, the event never arrives. Checking both requests only needs one
+        // round trip, since the X11 server handles requests in order.
+        create.check()?;
+        let event = change.check().map_err(ReplyOrIdError::from).and_then(|()| {
+            let filter = EventFilter::window(window).types(&[EventKind::PropertyNotify]);
+            Ok(self.wait_for_matching_event(&filter)?)
+        });
+        let _ = self.destroy_window(window)?;
+        match event? {
+            Event::PropertyNotify(event) => Ok(event.time),
+            _ => unreachable!("The filter only matches PropertyNotify events"),
+        }
+    }
+
+    /// Get an iterator over the events that match `filter`.
+    ///
+    /// The iterator waits for matching events and never ends. Other events stay in the event
+    /// queue for the main event loop. This is useful e.g. for modal dialogs.
+    pub fn filtered_events(&self, filter: EventFilter) -> FilteredEvents<'_, S> {
+        FilteredEvents { conn: self, filter }
+    }
+
+    /// Add a locally constructed event to the event queue.
+    ///
+    /// The event is returned like events from the X11 server, but it is marked as sent via the
+    /// `SendEvent` request (see [`Event::sent_event`]). This allows to simulate input, e.g. in
+    /// tests or input method layers, without a round trip to the X11 server. For events from
+    /// extensions, the extension must be supported by the server. X11 errors cannot be enqueued.
+    ///
+    /// Threads that are already blocked waiting for an event only notice the event when the next
+    /// packet is received from the X11 server.
+    pub fn enqueue_synthetic_event(&self, event: &Event) -> Result<(), ConnectionError> {
+        let ext_info = match event.extension() {
+            Some(name) => Some(
+                self.extension_information(name)?
+                    .ok_or(ConnectionError::UnsupportedExtension)?,
+            ),
+            None => None,
+        };
+        let bytes = event
+            .serialize(ext_info.as_ref())
+            .ok_or(ConnectionError::UnsupportedExtension)?;
+        if bytes.len() < 32 || bytes[0] == 0 {
+            return Err(ParseError::InvalidValue.into());
+        }
+        self.inner
+            .lock()
+            .unwrap()
+            .inner
+            .enqueue_synthetic_event(bytes);
+        Ok(())
+    }
+
+    /// Get a handle to a new event queue.
+    ///
+    /// The handle gets a copy of every event that is received from now on, independent of the
+    /// main event queue of the connection and of other handles. This allows e.g. libraries that
+    /// are embedded in an application to observe events without taking them away from the
+    /// application. X11 errors are only delivered to the main event queue.
+    ///
+    /// Events pile up in the queue until they are fetched via the handle or the handle is dropped.
+    pub fn event_queue_handle(&self) -> EventQueueHandle<'_, S> {
+        let id = self.inner.lock().unwrap().inner.add_event_queue();
+        EventQueueHandle { conn: self, id }
+    }
+
+    /// Get the next event without blocking and without allocating memory for it.
+    ///
+    /// This works like [`Connection::poll_for_raw_event_with_sequence`], but the returned
+    /// [`EventRef`] hands its buffer back to the connection when it is dropped. The buffer is then
+    /// reused for one of the following packets, so that a client that parses and drops events
+    /// one by one does not allocate memory for each event.
+    pub fn poll_for_event_ref(&self) -> Result<Option<EventRef<'_, S>>, ConnectionError> {
+        Ok(self
+            .poll_for_raw_event_with_sequence()?
+            .map(|(packet, sequence_number)| EventRef {
+                conn: self,
+                packet,
+                sequence_number,
+            }))
+    }
+
+    /// Prepare for waiting on the connection's file descriptor in an external event loop.
+    ///
+    /// Returns `None` if events are already queued. These have to be handled first, since waiting
+    /// for the file descriptor would not report them. Otherwise, all pending requests are written
+    /// to the X11 server and a [`ReadGuard`] is returned. Once the file descriptor is readable,
+    /// [`ReadGuard::did_read`] reads everything that is available without blocking. If the event
+    /// loop woke up for another reason, the guard can simply be dropped.
+    ///
+    /// While the guard exists, no other thread reads from the connection. Threads that wait for
+    /// a reply or an event are blocked until the guard is gone, so it must not be kept around
+    /// longer than necessary.
+    ///
+    /// If another thread is currently reading from the connection, this function waits until it
+    /// is done.
+    pub fn prepare_read(&self) -> Result<Option<ReadGuard<'_, S>>, ConnectionError> {
+        let mut inner = self.inner.lock().unwrap();
+        loop {
+            // Flushing can read from the stream and thus queue events, so it has to happen first
+            inner = self.flush_impl(inner)?;
+            if inner.inner.has_pending_events() {
+                return Ok(None);
+            }
+            match self.packet_reader.try_lock() {
+                Ok(packet_reader) => {
+                    return Ok(Some(ReadGuard {
+                        conn: self,
+                        packet_reader: Some(packet_reader),
+                    }))
+                }
+                // Someone else is reading, wait until it is done and check the queue again
+                Err(TryLockError::WouldBlock) => inner = self.reader_condition.wait(inner).unwrap(),
+                Err(TryLockError::Poisoned(e)) => panic!("{}", e),
+            }
+        }
+    }
 }
 
 impl<S: Stream> RequestConnection for RustConnection<S> {
@@ -596,11 +1358,9 @@ impl<S: Stream> RequestConnection for RustConnection<S> {
             sequence,
             mode
         );
-        self.inner
-            .lock()
-            .unwrap()
-            .inner
-            .discard_reply(sequence, mode);
+        let mut inner = self.inner.lock().unwrap();
+        inner.inner.discard_reply(sequence, mode);
+        self.call_error_handler(&mut inner.inner);
     }
 
     fn prefetch_extension_information(
@@ -617,10 +1377,25 @@ impl<S: Stream> RequestConnection for RustConnection<S> {
         &self,
         extension_name: &'static str,
     ) -> Result<Option<ExtensionInformation>, ConnectionError> {
-        self.extension_manager
+        let info = self
+            .extension_manager
             .lock()
             .unwrap()
-            .extension_information(self, extension_name)
+            .extension_information(self, extension_name)?;
+        if let Some(info) = info {
+            if !self
+                .known_extensions
+                .read()
+                .unwrap()
+                .contains(extension_name)
+            {
+                self.known_extensions
+                    .write()
+                    .unwrap()
+                    .insert(extension_name, info);
+            }
+        }
+        Ok(info)
     }
 
     fn wait_for_reply_or_raw_error(
@@ -738,14 +1513,14 @@ impl<S: Stream> RequestConnection for RustConnection<S> {
         self.prefetch_maximum_request_bytes_impl(&mut max_bytes);
     }
 
-    fn parse_error(&self, error: &[u8]) -> Result<crate::x11_utils::X11Error, ParseError> {
+    fn parse_error(&self, error: &[u8]) -> Result<X11Error, ParseError> {
         let ext_mgr = self.extension_manager.lock().unwrap();
-        crate::x11_utils::X11Error::try_parse(error, &*ext_mgr)
+        X11Error::try_parse(error, &*ext_mgr)
     }
 
-    fn parse_event(&self, event: &[u8]) -> Result<crate::protocol::Event, ParseError> {
+    fn parse_event(&self, event: &[u8]) -> Result<Event, ParseError> {
         let ext_mgr = self.extension_manager.lock().unwrap();
-        crate::protocol::Event::parse(event, &*ext_mgr)
+        Event::parse(event, &*ext_mgr)
     }
 }
 
@@ -760,6 +1535,10 @@ impl<S: Stream> Connection for RustConnection<S> {
             if let Some(event) = inner.inner.poll_for_event_with_sequence() {
                 return Ok(event);
             }
+            if inner.deferred_flush.is_some() {
+                // Held back requests might be needed for the event to be generated
+                inner = self.flush_impl(inner)?;
+            }
             inner = self.read_packet_and_enqueue(inner, BlockingMode::Blocking)?;
         }
     }
@@ -773,12 +1552,23 @@ impl<S: Stream> Connection for RustConnection<S> {
         if let Some(event) = inner.inner.poll_for_event_with_sequence() {
             Ok(Some(event))
         } else {
+            inner = self.flush_if_due(inner)?;
             inner = self.read_packet_and_enqueue(inner, BlockingMode::NonBlocking)?;
             Ok(inner.inner.poll_for_event_with_sequence())
         }
     }
 
     fn flush(&self) -> Result<(), ConnectionError> {
+        let inner = self.inner.lock().unwrap();
+        let _inner = if inner.deferred_flush.is_some() {
+            self.flush_if_due(inner)?
+        } else {
+            self.flush_impl(inner)?
+        };
+        Ok(())
+    }
+
+    fn flush_all(&self) -> Result<(), ConnectionError> {
         let inner = self.inner.lock().unwrap();
         let _inner = self.flush_impl(inner)?;
         Ok(())
@@ -789,25 +1579,278 @@ impl<S: Stream> Connection for RustConnection<S> {
     }
 
     fn generate_id(&self) -> Result<u32, ReplyOrIdError> {
-        let mut id_allocator = self.id_allocator.lock().unwrap();
-        if let Some(id) = id_allocator.generate_id() {
-            Ok(id)
-        } else {
-            use crate::protocol::xc_misc::{self, ConnectionExt as _};
+        if let Some(id) = self.id_range.generate_id() {
+            if self.id_range.is_low() {
+                // If the lock is held, its owner takes care of refilling
+                if let Ok(mut ids) = self.id_allocator.try_lock() {
+                    self.refill_ids_ahead(&mut ids)?;
+                }
+            }
+            return Ok(id);
+        }
+        let mut ids = self.id_allocator.lock().unwrap();
+        if let Some(id) = self.id_range.generate_id_from(&mut ids.pool) {
+            self.refill_ids_ahead(&mut ids)?;
+            return Ok(id);
+        }
+        if self.finish_id_refill(&mut ids)? {
+            if let Some(id) = self.id_range.generate_id_from(&mut ids.pool) {
+                self.refill_ids_ahead(&mut ids)?;
+                return Ok(id);
+            }
+        }
 
-            if self
-                .extension_information(xc_misc::X11_EXTENSION_NAME)?
-                .is_none()
-            {
-                crate::error!("XIDs are exhausted and XC-MISC extension is not available");
-                Err(ReplyOrIdError::IdsExhausted)
-            } else {
+        use crate::protocol::xc_misc::{self, ConnectionExt as _};
+
+        if self
+            .extension_information(xc_misc::X11_EXTENSION_NAME)?
+            .is_none()
+        {
+            crate::error!("XIDs are exhausted and XC-MISC extension is not available");
+            Err(ReplyOrIdError::IdsExhausted)
+        } else {
+            let batch_size = self.xid_list_batch_size();
+            if batch_size == 0 {
                 crate::info!("XIDs are exhausted; fetching free range via XC-MISC");
-                id_allocator.update_xid_range(&self.xc_misc_get_xid_range()?.reply()?)?;
-                id_allocator
-                    .generate_id()
-                    .ok_or(ReplyOrIdError::IdsExhausted)
+                ids.pool
+                    .update_xid_range(&self.xc_misc_get_xid_range()?.reply()?)?;
+            } else {
+                crate::info!("XIDs are exhausted; fetching free list via XC-MISC");
+                ids.pool
+                    .update_xid_list(&self.xc_misc_get_xid_list(batch_size)?.reply()?)?;
+            }
+            self.id_range
+                .generate_id_from(&mut ids.pool)
+                .ok_or(ReplyOrIdError::IdsExhausted)
+        }
+    }
+}
+
+impl<S: Stream> RustConnection<S> {
+    /// Send an XC-MISC request for more XIDs if only a few XIDs are left.
+    ///
+    /// The reply is not awaited. It is only needed once the XIDs are exhausted, see
+    /// `finish_id_refill`.
+    fn refill_ids_ahead(&self, ids: &mut IdState) -> Result<(), ConnectionError> {
+        use crate::protocol::xc_misc::{self, ConnectionExt as _};
+
+        if ids.refill.is_some() || !ids.pool.needs_refill(&self.id_range) {
+            return Ok(());
+        }
+        if self
+            .extension_information(xc_misc::X11_EXTENSION_NAME)?
+            .is_none()
+        {
+            return Ok(());
+        }
+        // Reserve the remaining IDs before the server can see the request
+        ids.pool.start_refill(&self.id_range);
+        let batch_size = self.xid_list_batch_size();
+        ids.refill = Some(if batch_size == 0 {
+            crate::debug!("Few XIDs are left; fetching free range via XC-MISC");
+            XidRefill::Range(self.xc_misc_get_xid_range()?.into_sequence_number())
+        } else {
+            crate::debug!("Few XIDs are left; fetching free list via XC-MISC");
+            XidRefill::List(
+                self.xc_misc_get_xid_list(batch_size)?
+                    .into_sequence_number(),
+            )
+        });
+        Ok(())
+    }
+
+    /// Apply the reply to an XC-MISC request that was sent by `refill_ids_ahead`.
+    ///
+    /// Returns `false` if there is no such request or if its reply contains no usable IDs.
+    fn finish_id_refill(&self, ids: &mut IdState) -> Result<bool, ReplyError> {
+        use crate::protocol::xc_misc::{GetXIDListReply, GetXIDRangeReply};
+
+        let result = match ids.refill.take() {
+            None => return Ok(false),
+            Some(XidRefill::Range(seq)) => {
+                let reply = Cookie::<_, GetXIDRangeReply>::new(self, seq).reply()?;
+                ids.pool.update_xid_range(&reply)
+            }
+            Some(XidRefill::List(seq)) => {
+                let reply = Cookie::<_, GetXIDListReply>::new(self, seq).reply()?;
+                ids.pool.update_xid_list(&reply)
+            }
+        };
+        Ok(result.is_ok())
+    }
+}
+
+#[cfg(unix)]
+impl<S: Stream + std::os::unix::io::AsFd> std::os::unix::io::AsFd for RustConnection<S> {
+    fn as_fd(&self) -> std::os::unix::io::BorrowedFd<'_> {
+        self.stream.as_fd()
+    }
+}
+
+/// An iterator over the events that match an [`EventFilter`].
+///
+/// This is returned by [`RustConnection::filtered_events`].
+#[derive(Debug)]
+pub struct FilteredEvents<'a, S: Stream> {
+    conn: &'a RustConnection<S>,
+    filter: EventFilter,
+}
+
+impl<S: Stream> Iterator for FilteredEvents<'_, S> {
+    type Item = Result<Event, ConnectionError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.conn.wait_for_matching_event(&self.filter))
+    }
+}
+
+/// A handle to an event queue of a [`RustConnection`] that is independent of the main queue.
+///
+/// This is returned by [`RustConnection::event_queue_handle`]. Cloning the handle produces a new
+/// queue that starts with the events that are currently in this queue.
+#[derive(Debug)]
+pub struct EventQueueHandle<'a, S: Stream> {
+    conn: &'a RustConnection<S>,
+    id: EventQueueId,
+}
+
+impl<S: Stream> EventQueueHandle<'_, S> {
+    /// Get the next event from this queue without blocking.
+    pub fn poll_for_event(&self) -> Result<Option<Event>, ConnectionError> {
+        let mut inner = self.conn.inner.lock().unwrap();
+        let mut event = inner.inner.poll_for_event_in_queue(self.id);
+        if event.is_none() {
+            inner = self.conn.flush_if_due(inner)?;
+            inner = self
+                .conn
+                .read_packet_and_enqueue(inner, BlockingMode::NonBlocking)?;
+            event = inner.inner.poll_for_event_in_queue(self.id);
+        }
+        drop(inner);
+        match event {
+            Some((event, _)) => Ok(Some(self.conn.parse_event(&event)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Wait for the next event in this queue.
+    pub fn wait_for_event(&self) -> Result<Event, ConnectionError> {
+        let mut inner = self.conn.inner.lock().unwrap();
+        let event = loop {
+            if let Some((event, _)) = inner.inner.poll_for_event_in_queue(self.id) {
+                break event;
             }
+            if inner.deferred_flush.is_some() {
+                // Held back requests might be needed for the event to be generated
+                inner = self.conn.flush_impl(inner)?;
+            }
+            inner = self
+                .conn
+                .read_packet_and_enqueue(inner, BlockingMode::Blocking)?;
+        };
+        drop(inner);
+        Ok(self.conn.parse_event(&event)?)
+    }
+}
+
+impl<S: Stream> Clone for EventQueueHandle<'_, S> {
+    fn clone(&self) -> Self {
+        let mut inner = self.conn.inner.lock().unwrap();
+        let id = inner
+            .inner
+            .clone_event_queue(self.id)
+            .expect("The queue is only removed when the handle is dropped");
+        Self {
+            conn: self.conn,
+            id,
+        }
+    }
+}
+
+impl<S: Stream> Drop for EventQueueHandle<'_, S> {
+    fn drop(&mut self) {
+        let _ = self
+            .conn
+            .inner
+            .lock()
+            .unwrap()
+            .inner
+            .remove_event_queue(self.id);
+    }
+}
+
+/// The permission to read from a [`RustConnection`] after waiting for its file descriptor.
+///
+/// This is returned by [`RustConnection::prepare_read`]. Dropping the guard without calling
+/// [`ReadGuard::did_read`] cancels the read.
+#[derive(Debug)]
+pub struct ReadGuard<'a, S: Stream> {
+    conn: &'a RustConnection<S>,
+    packet_reader: Option<MutexGuard<'a, PacketReader>>,
+}
+
+impl<S: Stream> ReadGuard<'_, S> {
+    /// Read everything that is available from the connection without blocking.
+    ///
+    /// The events that were read can then be fetched with e.g. [`Connection::poll_for_event`].
+    pub fn did_read(mut self) -> Result<(), ConnectionError> {
+        if let Some(packet_reader) = self.packet_reader.take() {
+            let _notify_on_drop = NotifyOnDrop(&self.conn.reader_condition);
+            let inner = self.conn.inner.lock().unwrap();
+            drop(self.conn.read_and_enqueue_locked(inner, packet_reader)?);
+        }
+        Ok(())
+    }
+}
+
+impl<S: Stream> Drop for ReadGuard<'_, S> {
+    fn drop(&mut self) {
+        if let Some(packet_reader) = self.packet_reader.take() {
+            // Like in read_packet_and_enqueue, `packet_reader` must be unlocked while `inner` is
+            // locked, so that no other reader misses the notification.
+            let _inner = self.conn.inner.lock().unwrap();
+            drop(packet_reader);
+            self.conn.reader_condition.notify_all();
+        }
+    }
+}
+
+/// A raw event that was returned by [`RustConnection::poll_for_event_ref`].
+///
+/// The event dereferences to its bytes in wire format. Its buffer is reused by the connection
+/// after the event was dropped.
+#[derive(Debug)]
+pub struct EventRef<'a, S: Stream> {
+    conn: &'a RustConnection<S>,
+    packet: Vec<u8>,
+    sequence_number: SequenceNumber,
+}
+
+impl<S: Stream> EventRef<'_, S> {
+    /// Get the full sequence number of the event.
+    pub fn sequence_number(&self) -> SequenceNumber {
+        self.sequence_number
+    }
+
+    /// Parse the event.
+    pub fn parse(&self) -> Result<Event, ParseError> {
+        self.conn.parse_event(&self.packet)
+    }
+}
+
+impl<S: Stream> std::ops::Deref for EventRef<'_, S> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.packet
+    }
+}
+
+impl<S: Stream> Drop for EventRef<'_, S> {
+    fn drop(&mut self) {
+        let mut inner = self.conn.inner.lock().unwrap();
+        if inner.spare_buffers.len() < MAX_SPARE_BUFFERS {
+            inner.spare_buffers.push(std::mem::take(&mut self.packet));
         }
     }
 }
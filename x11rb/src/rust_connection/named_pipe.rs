@@ -0,0 +1,167 @@
+//! A [`Stream`] for named pipes on Windows.
+
+use std::ffi::c_void;
+use std::fs::{File, OpenOptions};
+use std::io::{Error, ErrorKind, IoSlice, Read, Result, Write};
+use std::os::windows::io::{AsHandle, AsRawHandle, BorrowedHandle, RawHandle};
+use std::time::Duration;
+
+use super::stream::{peer_addr, PeerAddr, PollMode, Stream};
+use crate::utils::RawFdContainer;
+use x11rb_protocol::parse_display::ConnectAddress;
+
+#[link(name = "kernel32")]
+extern "system" {
+    fn PeekNamedPipe(
+        named_pipe: RawHandle,
+        buffer: *mut c_void,
+        buffer_size: u32,
+        bytes_read: *mut u32,
+        total_bytes_avail: *mut u32,
+        bytes_left_this_message: *mut u32,
+    ) -> i32;
+}
+
+/// The longest time `NamedPipeStream::poll` sleeps before checking the pipe again.
+const MAX_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// A connection to an X11 server via a named pipe, e.g. `\\.\pipe\X11\0`.
+///
+/// Windows cannot wait for a synchronous pipe handle to become readable. Instead, `poll` checks
+/// with `PeekNamedPipe` whether data is available and sleeps for a few milliseconds otherwise.
+/// Reads never block, but writes block until the data was handed to the pipe.
+///
+/// [`RustConnection::connect`] uses [`DefaultStream`], which only supports TCP on Windows. To use
+/// a named pipe, connect with [`NamedPipeStream::connect`] and pass the result to
+/// [`RustConnection::connect_to_stream_with_auth_info`].
+///
+/// [`RustConnection::connect`]: super::RustConnection::connect
+/// [`RustConnection::connect_to_stream_with_auth_info`]: super::RustConnection::connect_to_stream_with_auth_info
+/// [`DefaultStream`]: super::DefaultStream
+#[derive(Debug)]
+pub struct NamedPipeStream {
+    pipe: File,
+}
+
+impl NamedPipeStream {
+    /// Try to connect to the X11 server described by the given arguments.
+    ///
+    /// Only [`ConnectAddress::NamedPipe`] is supported.
+    ///
+    /// This returns the peer address in a format suitable for [`x11rb_protocol::xauth::get_auth`].
+    pub fn connect(addr: &ConnectAddress<'_>) -> Result<(Self, PeerAddr)> {
+        match addr {
+            ConnectAddress::NamedPipe(path) => {
+                let pipe = OpenOptions::new().read(true).write(true).open(path)?;
+                Ok((Self::from_file(pipe), peer_addr::local()))
+            }
+            _ => Err(Error::new(
+                ErrorKind::Other,
+                "NamedPipeStream only supports named pipes",
+            )),
+        }
+    }
+
+    /// Creates a new `Stream` from an already opened named pipe.
+    ///
+    /// The pipe must have been opened without `FILE_FLAG_OVERLAPPED`.
+    pub fn from_file(pipe: File) -> Self {
+        Self { pipe }
+    }
+
+    /// Get the number of bytes that can be read without blocking.
+    ///
+    /// Returns `None` if the X11 server closed the pipe.
+    fn bytes_available(&self) -> Result<Option<u32>> {
+        let mut available = 0;
+        // SAFETY: The handle stays valid while `self.pipe` is alive. Without a buffer, only
+        // `available` is written to.
+        let ok = unsafe {
+            PeekNamedPipe(
+                self.pipe.as_raw_handle(),
+                std::ptr::null_mut(),
+                0,
+                std::ptr::null_mut(),
+                &mut available,
+                std::ptr::null_mut(),
+            )
+        };
+        if ok != 0 {
+            return Ok(Some(available));
+        }
+        let error = Error::last_os_error();
+        if error.kind() == ErrorKind::BrokenPipe {
+            Ok(None)
+        } else {
+            Err(error)
+        }
+    }
+}
+
+impl AsRawHandle for NamedPipeStream {
+    fn as_raw_handle(&self) -> RawHandle {
+        self.pipe.as_raw_handle()
+    }
+}
+
+impl AsHandle for NamedPipeStream {
+    fn as_handle(&self) -> BorrowedHandle<'_> {
+        self.pipe.as_handle()
+    }
+}
+
+impl Stream for NamedPipeStream {
+    fn poll(&self, mode: PollMode) -> Result<()> {
+        // Writing blocks until it succeeds, so the pipe is always writable
+        if mode.writable() {
+            return Ok(());
+        }
+        let mut interval = Duration::from_millis(1);
+        while self.bytes_available()? == Some(0) {
+            std::thread::sleep(interval);
+            interval = (interval * 2).min(MAX_POLL_INTERVAL);
+        }
+        Ok(())
+    }
+
+    fn read(&self, buf: &mut [u8], fd_storage: &mut Vec<RawFdContainer>) -> Result<usize> {
+        // No FDs are read, so nothing needs to be done with fd_storage
+        let _ = fd_storage;
+        let available = match self.bytes_available()? {
+            // The pipe was closed
+            None => return Ok(0),
+            Some(0) => return Err(ErrorKind::WouldBlock.into()),
+            Some(available) => usize::try_from(available).unwrap_or(usize::MAX),
+        };
+        let len = buf.len().min(available);
+        loop {
+            // Use `impl Read for &File` to avoid needing a mutable `File`. Since at most the
+            // available bytes are read, this does not block.
+            match (&self.pipe).read(&mut buf[..len]) {
+                Ok(n) => return Ok(n),
+                // try again
+                Err(ref e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    fn write(&self, buf: &[u8], fds: &mut Vec<RawFdContainer>) -> Result<usize> {
+        self.write_vectored(&[IoSlice::new(buf)], fds)
+    }
+
+    fn write_vectored(&self, bufs: &[IoSlice<'_>], fds: &mut Vec<RawFdContainer>) -> Result<usize> {
+        if !fds.is_empty() {
+            return Err(Error::new(ErrorKind::Other, "FD passing is unsupported"));
+        }
+        loop {
+            // Use `impl Write for &File` to avoid needing a mutable `File`.
+            match (&self.pipe).write_vectored(bufs) {
+                Ok(n) => return Ok(n),
+                // try again
+                Err(ref e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
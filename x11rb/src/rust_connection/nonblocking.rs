@@ -0,0 +1,297 @@
+//! Establishing a connection without blocking.
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::collections::VecDeque;
+#[cfg(not(target_arch = "wasm32"))]
+use std::net::{SocketAddr, ToSocketAddrs};
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Duration;
+
+use super::handshake::Handshake;
+use super::{DefaultStream, PollMode, RustConnection, Stream};
+use crate::errors::ConnectError;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::errors::DisplayParsingError;
+#[cfg(not(target_arch = "wasm32"))]
+use x11rb_protocol::parse_display::ConnectAddress;
+#[cfg(not(target_arch = "wasm32"))]
+use x11rb_protocol::xauth::{get_auth, Family};
+
+/// An address that a connection can be attempted to.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug)]
+enum Target {
+    Tcp(SocketAddr),
+    Unix(String),
+}
+
+/// The address of the X11 server that a socket is connected to, as needed for authentication.
+#[cfg(not(target_arch = "wasm32"))]
+type PeerAddr = (Family, Vec<u8>);
+
+/// The addresses of an X11 server that are tried one after another.
+///
+/// This only exists for connections that were started by [`RustConnection::connect_nonblocking`].
+/// The functions for connecting are stored here so that [`NonBlockingConnect::advance`] works
+/// for all kinds of streams.
+#[cfg(not(target_arch = "wasm32"))]
+struct Targets<S> {
+    targets: VecDeque<Target>,
+    display: u16,
+    /// Start connecting to a target. The returned flag is `true` if connecting is in progress.
+    start_connect: fn(&Target) -> std::io::Result<(S, PeerAddr, bool)>,
+    /// Check whether connecting finished. Returns `false` if connecting is still in progress.
+    finish_connect: fn(&S) -> std::io::Result<bool>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<S> std::fmt::Debug for Targets<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Targets")
+            .field("targets", &self.targets)
+            .field("display", &self.display)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug)]
+enum State<S> {
+    /// Waiting for the socket to connect.
+    #[cfg(not(target_arch = "wasm32"))]
+    Connecting(S, PeerAddr),
+    /// Performing the setup handshake.
+    Handshake(S, Handshake, PollMode),
+}
+
+/// A connection to an X11 server that is being established without blocking.
+///
+/// This is created by [`RustConnection::connect_nonblocking`] or
+/// [`RustConnection::connect_to_stream_nonblocking`]. It allows to integrate connecting to the
+/// X11 server into an existing event loop: Wait until [`Self::stream`] becomes ready for the
+/// operation described by [`Self::poll_mode`] and then call [`Self::advance`]. Repeat this until
+/// the connection is established.
+///
+/// If connecting to one address of the X11 server fails, the next address is tried. Thus, the
+/// underlying file descriptor can change whenever [`Self::advance`] is called.
+#[derive(Debug)]
+pub struct NonBlockingConnect<S = DefaultStream> {
+    state: State<S>,
+    #[cfg(not(target_arch = "wasm32"))]
+    targets: Option<Targets<S>>,
+    screen: usize,
+}
+
+/// The result of [`NonBlockingConnect::advance`].
+// The connection is only returned once, so boxing it would not help.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug)]
+pub enum ConnectStep<S: Stream = DefaultStream> {
+    /// The connection is not yet established.
+    Pending(NonBlockingConnect<S>),
+    /// The connection was established. The second value is the preferred screen.
+    Connected(RustConnection<S>, usize),
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl RustConnection<DefaultStream> {
+    /// Start establishing a new connection without blocking.
+    ///
+    /// If no `dpy_name` is provided, the value from `$DISPLAY` is used.
+    ///
+    /// Connecting to the X11 server and performing the setup handshake happen as part of
+    /// [`NonBlockingConnect::advance`]. Only resolving a hostname to its addresses is done here
+    /// and may block.
+    ///
+    /// On platforms other than Unix, connecting the socket blocks and only the setup handshake is
+    /// done without blocking.
+    pub fn connect_nonblocking(dpy_name: Option<&str>) -> Result<NonBlockingConnect, ConnectError> {
+        let parsed_display = x11rb_protocol::parse_display::parse_display(dpy_name)?;
+        let mut targets = VecDeque::new();
+        let mut error = None;
+        for addr in parsed_display.connect_instruction() {
+            match addr {
+                ConnectAddress::Hostname(host, port) => match (host, port).to_socket_addrs() {
+                    Ok(addrs) => targets.extend(addrs.map(Target::Tcp)),
+                    Err(e) => error = Some(e),
+                },
+                ConnectAddress::Socket(path) => targets.push_back(Target::Unix(path)),
+                addr => {
+                    crate::debug!("Cannot connect to X11 server via {:?}", addr);
+                    error = Some(std::io::Error::new(
+                        std::io::ErrorKind::Other,
+                        "The given address family is not implemented",
+                    ));
+                }
+            }
+        }
+        let mut targets = Targets {
+            targets,
+            display: parsed_display.display,
+            start_connect: start_connect_default,
+            finish_connect: finish_connect_default,
+        };
+        let state = targets.start_next(error)?;
+        Ok(NonBlockingConnect {
+            state,
+            targets: Some(targets),
+            screen: parsed_display.screen.into(),
+        })
+    }
+}
+
+impl<S: Stream> RustConnection<S> {
+    /// Start the setup handshake on the given stream without blocking.
+    ///
+    /// This is the non-blocking version of [`Self::connect_to_stream_with_auth_info`]. It has to
+    /// be used for streams that cannot block at all, e.g. a [`super::MessageStream`] on top of a
+    /// WebSocket in the browser. There, waiting for the stream fails with
+    /// [`std::io::ErrorKind::WouldBlock`] and the blocking functions cannot establish a
+    /// connection. Instead, call [`NonBlockingConnect::advance`] whenever the stream has new data,
+    /// e.g. from the message handler of the WebSocket.
+    ///
+    /// Nothing is sent before the first call to [`NonBlockingConnect::advance`].
+    pub fn connect_to_stream_nonblocking(
+        stream: S,
+        screen: usize,
+        auth_name: Vec<u8>,
+        auth_data: Vec<u8>,
+    ) -> NonBlockingConnect<S> {
+        NonBlockingConnect {
+            state: State::Handshake(
+                stream,
+                Handshake::new(auth_name, auth_data),
+                PollMode::Writable,
+            ),
+            #[cfg(not(target_arch = "wasm32"))]
+            targets: None,
+            screen,
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn start_connect_default(target: &Target) -> std::io::Result<(DefaultStream, PeerAddr, bool)> {
+    match target {
+        Target::Tcp(addr) => DefaultStream::start_connect_tcp(addr),
+        #[cfg(unix)]
+        Target::Unix(path) => DefaultStream::start_connect_unix(path),
+        #[cfg(not(unix))]
+        Target::Unix(_) => Err(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            "Unix domain sockets are not supported on Windows",
+        )),
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn finish_connect_default(stream: &DefaultStream) -> std::io::Result<bool> {
+    if !stream.poll_with_timeout(PollMode::Writable, Some(Duration::ZERO))? {
+        return Ok(false);
+    }
+    stream.finish_connect()?;
+    Ok(true)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<S> Targets<S> {
+    /// Start connecting to the next target that does not fail immediately.
+    fn start_next(&mut self, mut error: Option<std::io::Error>) -> Result<State<S>, ConnectError> {
+        while let Some(target) = self.targets.pop_front() {
+            match (self.start_connect)(&target) {
+                Ok((stream, peer_addr, true)) => {
+                    crate::trace!("Started connecting to X11 server via {:?}", target);
+                    return Ok(State::Connecting(stream, peer_addr));
+                }
+                Ok((stream, peer_addr, false)) => {
+                    crate::trace!("Connected to X11 server via {:?}", target);
+                    return Ok(self.start_handshake(stream, peer_addr));
+                }
+                Err(e) => {
+                    crate::debug!("Failed to connect to X11 server via {:?}: {:?}", target, e);
+                    error = Some(e);
+                }
+            }
+        }
+
+        // none of the addresses worked
+        Err(match error {
+            Some(e) => ConnectError::IoError(e),
+            None => DisplayParsingError::Unknown.into(),
+        })
+    }
+
+    fn start_handshake(&self, stream: S, peer_addr: PeerAddr) -> State<S> {
+        let (family, address) = peer_addr;
+        let (auth_name, auth_data) = get_auth(family, &address, self.display)
+            // Ignore all errors while determining auth; instead we just try without auth info.
+            .unwrap_or(None)
+            .unwrap_or_else(|| (Vec::new(), Vec::new()));
+        crate::trace!("Picked authentication via auth mechanism {:?}", auth_name);
+        State::Handshake(
+            stream,
+            Handshake::new(auth_name, auth_data),
+            PollMode::Writable,
+        )
+    }
+}
+
+impl<S: Stream> NonBlockingConnect<S> {
+    /// Get the stream that is currently used for connecting.
+    pub fn stream(&self) -> &S {
+        match &self.state {
+            #[cfg(not(target_arch = "wasm32"))]
+            State::Connecting(stream, _) => stream,
+            State::Handshake(stream, _, _) => stream,
+        }
+    }
+
+    /// Get the operation that the stream has to become ready for before calling
+    /// [`Self::advance`].
+    pub fn poll_mode(&self) -> PollMode {
+        match self.state {
+            #[cfg(not(target_arch = "wasm32"))]
+            State::Connecting(_, _) => PollMode::Writable,
+            State::Handshake(_, _, mode) => mode,
+        }
+    }
+
+    /// Make as much progress on establishing the connection as possible without blocking.
+    pub fn advance(mut self) -> Result<ConnectStep<S>, ConnectError> {
+        loop {
+            self.state = match self.state {
+                #[cfg(not(target_arch = "wasm32"))]
+                State::Connecting(stream, peer_addr) => {
+                    let targets = self
+                        .targets
+                        .as_mut()
+                        .expect("Only connections to targets start by connecting");
+                    match (targets.finish_connect)(&stream) {
+                        Ok(false) => {
+                            self.state = State::Connecting(stream, peer_addr);
+                            return Ok(ConnectStep::Pending(self));
+                        }
+                        Ok(true) => {
+                            crate::trace!("Connected to X11 server");
+                            targets.start_handshake(stream, peer_addr)
+                        }
+                        Err(e) => {
+                            crate::debug!("Failed to connect to X11 server: {:?}", e);
+                            targets.start_next(Some(e))?
+                        }
+                    }
+                }
+                State::Handshake(stream, mut handshake, _) => match handshake.step(&stream)? {
+                    Some(mode) => {
+                        self.state = State::Handshake(stream, handshake, mode);
+                        return Ok(ConnectStep::Pending(self));
+                    }
+                    None => {
+                        let setup = handshake.into_setup(self.screen)?;
+                        let conn = RustConnection::for_connected_stream(stream, setup)?;
+                        return Ok(ConnectStep::Connected(conn, self.screen));
+                    }
+                },
+            };
+        }
+    }
+}
@@ -7,6 +7,14 @@ use super::Stream;
 use crate::utils::RawFdContainer;
 use x11rb_protocol::packet_reader::PacketReader as ProtoPacketReader;
 
+/// The default size of the read buffer.
+///
+/// This size was chosen by checking what libxcb does.
+pub(crate) const DEFAULT_READ_BUFFER_SIZE: usize = 4096;
+
+/// The minimum size of the read buffer, which is the size of the smallest X11 packet.
+pub(crate) const MIN_READ_BUFFER_SIZE: usize = 32;
+
 /// A wrapper around a reader that reads X11 packet.
 pub(crate) struct PacketReader {
     /// The read buffer to store incoming bytes in.
@@ -31,12 +39,27 @@ impl PacketReader {
     /// Create a new `PacketReader` that reads from the given stream.
     pub(crate) fn new() -> Self {
         Self {
-            // Buffer size chosen by checking what libxcb does
-            read_buffer: vec![0; 4096].into_boxed_slice(),
+            read_buffer: vec![0; DEFAULT_READ_BUFFER_SIZE].into_boxed_slice(),
             inner: ProtoPacketReader::new(),
         }
     }
 
+    /// Change the size of the read buffer.
+    ///
+    /// The buffer only holds data while it is being split into packets, so this can be done at
+    /// any time.
+    pub(crate) fn set_buffer_size(&mut self, size: usize) {
+        if self.read_buffer.len() != size {
+            crate::debug!("Changing the read buffer size to {} bytes", size);
+            self.read_buffer = vec![0; size].into_boxed_slice();
+        }
+    }
+
+    /// Hand back the buffer of a packet so that it can be reused for a following packet.
+    pub(crate) fn recycle(&mut self, buffer: Vec<u8>) {
+        self.inner.recycle(buffer);
+    }
+
     /// Reads as many packets as possible from stream reader without blocking.
     pub(crate) fn try_read_packets(
         &mut self,
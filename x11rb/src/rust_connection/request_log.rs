@@ -0,0 +1,149 @@
+//! Remembering recently sent requests to find the cause of X11 errors.
+
+use std::collections::VecDeque;
+use std::fmt;
+
+use crate::x11_utils::X11Error;
+use x11rb_protocol::SequenceNumber;
+
+/// A function that describes where a request is sent from.
+pub(super) type CaptureOrigin = Box<dyn Fn() -> String + Send + Sync>;
+
+/// A request that was recorded by the request log of a [`super::RustConnection`].
+///
+/// See [`super::RustConnection::enable_request_log`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SentRequest {
+    /// The sequence number of the request.
+    pub sequence: SequenceNumber,
+    /// The major opcode of the request.
+    pub major_opcode: u8,
+    /// The minor opcode of the request.
+    pub minor_opcode: u8,
+    /// Where the request was sent from, if this was captured.
+    pub origin: Option<String>,
+}
+
+/// A ring buffer containing the most recently sent requests.
+pub(super) struct RequestLog {
+    entries: VecDeque<SentRequest>,
+    capacity: usize,
+    capture_origin: Option<CaptureOrigin>,
+}
+
+impl fmt::Debug for RequestLog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RequestLog")
+            .field("entries", &self.entries.len())
+            .field("capacity", &self.capacity)
+            .field("capture_origin", &self.capture_origin.is_some())
+            .finish()
+    }
+}
+
+impl RequestLog {
+    pub(super) fn new(capacity: usize, capture_origin: Option<CaptureOrigin>) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+            capture_origin,
+        }
+    }
+
+    /// Record a request that is sent, forgetting the oldest request if needed.
+    pub(super) fn record(&mut self, sequence: SequenceNumber, major_opcode: u8, minor_opcode: u8) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.len() == self.capacity {
+            let _ = self.entries.pop_front();
+        }
+        let origin = self.capture_origin.as_ref().map(|capture| capture());
+        self.entries.push_back(SentRequest {
+            sequence,
+            major_opcode,
+            minor_opcode,
+            origin,
+        });
+    }
+
+    /// Find the request that caused the given error.
+    ///
+    /// X11 errors only contain the lower 16 bits of the sequence number, so the most recent
+    /// request with matching bits is returned.
+    pub(super) fn find(&self, error: &X11Error) -> Option<&SentRequest> {
+        self.entries.iter().rev().find(|request| {
+            request.sequence as u16 == error.sequence && request.major_opcode == error.major_opcode
+        })
+    }
+}
+
+/// Describe an X11 error and the request that caused it.
+pub(super) fn describe_error(error: &X11Error, request: Option<&SentRequest>) -> String {
+    let extension = error.extension_name.as_deref().unwrap_or("xproto");
+    let mut result = match error.request_name {
+        Some(name) => format!("{:?} error in {}::{}", error.error_kind, extension, name),
+        None => format!(
+            "{:?} error in {} request with opcode {}.{}",
+            error.error_kind, extension, error.major_opcode, error.minor_opcode
+        ),
+    };
+    match request {
+        Some(request) => {
+            result.push_str(&format!(" (sequence {})", request.sequence));
+            if let Some(origin) = &request.origin {
+                result.push_str(" sent from ");
+                result.push_str(origin);
+            }
+        }
+        None => result.push_str(&format!(" (sequence {})", error.sequence)),
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::{describe_error, RequestLog};
+    use crate::protocol::xproto::CONFIGURE_WINDOW_REQUEST;
+    use crate::protocol::ErrorKind;
+    use crate::x11_utils::X11Error;
+
+    fn value_error(sequence: u16) -> X11Error {
+        X11Error {
+            error_kind: ErrorKind::Value,
+            error_code: 2,
+            sequence,
+            bad_value: 0,
+            minor_opcode: 0,
+            major_opcode: CONFIGURE_WINDOW_REQUEST,
+            extension_name: None,
+            request_name: Some("ConfigureWindow"),
+        }
+    }
+
+    #[test]
+    fn ring_buffer() {
+        let mut log = RequestLog::new(2, Some(Box::new(|| "here".to_string())));
+        log.record(1, CONFIGURE_WINDOW_REQUEST, 0);
+        log.record(2, CONFIGURE_WINDOW_REQUEST, 0);
+        log.record(3, CONFIGURE_WINDOW_REQUEST, 0);
+
+        // The first request was forgotten
+        assert!(log.find(&value_error(1)).is_none());
+        let request = log.find(&value_error(3)).unwrap();
+        assert_eq!(request.sequence, 3);
+        assert_eq!(
+            describe_error(&value_error(3), Some(request)),
+            "Value error in xproto::ConfigureWindow (sequence 3) sent from here"
+        );
+    }
+
+    #[test]
+    fn full_sequence_number() {
+        let mut log = RequestLog::new(4, None);
+        log.record(0x1_0005, CONFIGURE_WINDOW_REQUEST, 0);
+        let request = log.find(&value_error(5)).unwrap();
+        assert_eq!(request.sequence, 0x1_0005);
+        assert_eq!(request.origin, None);
+    }
+}
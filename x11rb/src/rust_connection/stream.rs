@@ -1,6 +1,8 @@
+#[cfg(not(target_arch = "wasm32"))]
 use rustix::fd::{AsFd, BorrowedFd};
 use std::io::{IoSlice, Result};
-use std::net::TcpStream;
+#[cfg(not(target_arch = "wasm32"))]
+use std::net::{IpAddr, SocketAddr, TcpStream, ToSocketAddrs};
 #[cfg(unix)]
 use std::os::unix::io::{AsRawFd, IntoRawFd, OwnedFd, RawFd};
 #[cfg(unix)]
@@ -9,6 +11,9 @@ use std::os::unix::net::UnixStream;
 use std::os::windows::io::{
     AsRawSocket, AsSocket, BorrowedSocket, IntoRawSocket, OwnedSocket, RawSocket,
 };
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
 
 use crate::utils::RawFdContainer;
 use x11rb_protocol::parse_display::ConnectAddress;
@@ -47,6 +52,11 @@ impl PollMode {
 
 /// A trait used to implement the raw communication with the X11 server.
 ///
+/// [`DefaultStream`] implements this trait for unix and TCP sockets. Other transports, for
+/// example a channel of an SSH library, can be used by implementing this trait and passing the
+/// stream to [`super::RustConnection::connect_to_stream`]. Such a stream does not need to be
+/// backed by a file descriptor.
+///
 /// None of the functions of this trait shall return [`std::io::ErrorKind::Interrupted`].
 /// If a system call fails with this error, the implementation should try again.
 pub trait Stream {
@@ -137,9 +147,44 @@ pub trait Stream {
     }
 }
 
+macro_rules! forward_stream {
+    ($($ty:ty),*) => {
+        $(
+            impl<S: Stream + ?Sized> Stream for $ty {
+                fn poll(&self, mode: PollMode) -> Result<()> {
+                    (**self).poll(mode)
+                }
+
+                fn read(&self, buf: &mut [u8], fd_storage: &mut Vec<RawFdContainer>) -> Result<usize> {
+                    (**self).read(buf, fd_storage)
+                }
+
+                fn write(&self, buf: &[u8], fds: &mut Vec<RawFdContainer>) -> Result<usize> {
+                    (**self).write(buf, fds)
+                }
+
+                fn write_vectored(
+                    &self,
+                    bufs: &[IoSlice<'_>],
+                    fds: &mut Vec<RawFdContainer>,
+                ) -> Result<usize> {
+                    (**self).write_vectored(bufs, fds)
+                }
+            }
+        )*
+    };
+}
+
+forward_stream!(&S, Box<S>, std::sync::Arc<S>);
+
 /// A wrapper around a `TcpStream` or `UnixStream`.
 ///
 /// Use by default in `RustConnection` as stream.
+///
+/// On Windows, only TCP connections are supported. This is what X11 servers for Windows like
+/// VcXsrv or X410 offer by default, so a display like `:0` connects to `localhost:6000`. Named
+/// pipes like `\\.\pipe\X11\0` are supported by `NamedPipeStream`, which requires the
+/// `allow-unsafe-code` feature.
 #[derive(Debug)]
 pub struct DefaultStream {
     inner: DefaultStreamInner,
@@ -148,17 +193,63 @@ pub struct DefaultStream {
 #[cfg(unix)]
 type DefaultStreamInner = RawFdContainer;
 
-#[cfg(not(unix))]
+#[cfg(not(any(unix, target_arch = "wasm32")))]
 type DefaultStreamInner = TcpStream;
 
+#[cfg(target_arch = "wasm32")]
+type DefaultStreamInner = std::convert::Infallible;
+
+/// Whether to use the abstract namespace for Unix domain sockets.
+///
+/// On Linux, X11 servers usually listen both on a socket in the file system (for example
+/// `/tmp/.X11-unix/X0`) and on a socket with the same name in the abstract namespace. Sandboxes
+/// often only make one of them available.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AbstractSocket {
+    /// Try the abstract namespace first and fall back to the file system.
+    #[default]
+    Prefer,
+    /// Only use the abstract namespace.
+    ///
+    /// This fails on systems other than Linux.
+    Only,
+    /// Only use the file system.
+    Never,
+}
+
+/// Parameters for TCP keepalive.
+///
+/// See [`DefaultStream::set_tcp_keepalive`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TcpKeepalive {
+    /// How long the connection has to be idle before keepalive probes are sent.
+    pub idle: Option<Duration>,
+    /// The time between two keepalive probes.
+    pub interval: Option<Duration>,
+    /// The number of unanswered probes after which the connection is considered dead.
+    pub retries: Option<u32>,
+}
+
 /// The address of a peer in a format suitable for xauth.
 ///
 /// These values can be directly given to [`x11rb_protocol::xauth::get_auth`].
-type PeerAddr = (Family, Vec<u8>);
+pub(super) type PeerAddr = (Family, Vec<u8>);
 
+#[cfg(not(target_arch = "wasm32"))]
 impl DefaultStream {
     /// Try to connect to the X11 server described by the given arguments.
     pub fn connect(addr: &ConnectAddress<'_>) -> Result<(Self, PeerAddr)> {
+        Self::connect_with_abstract_socket(addr, AbstractSocket::default())
+    }
+
+    /// Try to connect to the X11 server described by the given arguments.
+    ///
+    /// For Unix domain sockets, `abstract_socket` controls whether the path is looked up in the
+    /// abstract namespace. For other addresses, it has no effect.
+    pub fn connect_with_abstract_socket(
+        addr: &ConnectAddress<'_>,
+        abstract_socket: AbstractSocket,
+    ) -> Result<(Self, PeerAddr)> {
         match addr {
             ConnectAddress::Hostname(host, port) => {
                 // connect over TCP
@@ -169,12 +260,25 @@ impl DefaultStream {
             ConnectAddress::Socket(path) => {
                 // Try abstract unix socket first. If that fails, fall back to normal unix socket
                 #[cfg(any(target_os = "linux", target_os = "android"))]
-                if let Ok(stream) = connect_abstract_unix_stream(path.as_bytes()) {
-                    // TODO: Does it make sense to add a constructor similar to from_unix_stream()?
-                    // If this is done: Move the set_nonblocking() from
-                    // connect_abstract_unix_stream() to that new function.
-                    let stream = DefaultStream { inner: stream };
-                    return Ok((stream, peer_addr::local()));
+                if abstract_socket != AbstractSocket::Never {
+                    match connect_abstract_unix_stream(path.as_bytes()) {
+                        Ok(stream) => {
+                            // TODO: Does it make sense to add a constructor similar to
+                            // from_unix_stream()? If this is done: Move the set_nonblocking() from
+                            // connect_abstract_unix_stream() to that new function.
+                            let stream = DefaultStream { inner: stream };
+                            return Ok((stream, peer_addr::local()));
+                        }
+                        Err(e) if abstract_socket == AbstractSocket::Only => return Err(e.into()),
+                        Err(_) => {}
+                    }
+                }
+                #[cfg(not(any(target_os = "linux", target_os = "android")))]
+                if abstract_socket == AbstractSocket::Only {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::Unsupported,
+                        "The abstract socket namespace is only supported on Linux",
+                    ));
                 }
 
                 // connect over Unix domain socket
@@ -184,11 +288,16 @@ impl DefaultStream {
             #[cfg(not(unix))]
             ConnectAddress::Socket(_) => {
                 // Unix domain sockets are not supported on Windows
+                let _ = abstract_socket;
                 Err(std::io::Error::new(
                     std::io::ErrorKind::Other,
                     "Unix domain sockets are not supported on Windows",
                 ))
             }
+            ConnectAddress::NamedPipe(_) => Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                "Named pipes are not supported by DefaultStream, use NamedPipeStream instead",
+            )),
             _ => Err(std::io::Error::new(
                 std::io::ErrorKind::Other,
                 "The given address family is not implemented",
@@ -196,14 +305,53 @@ impl DefaultStream {
         }
     }
 
+    /// Try to connect to the X11 server described by the given arguments, giving up after the
+    /// given timeout.
+    ///
+    /// For TCP connections, both resolving the hostname and establishing the connection are
+    /// bounded by `timeout`. If it expires, an error of kind [`std::io::ErrorKind::TimedOut`] is
+    /// returned. Connecting to a Unix domain socket does not block and is not affected by the
+    /// timeout.
+    pub fn connect_with_timeout(
+        addr: &ConnectAddress<'_>,
+        timeout: Duration,
+    ) -> Result<(Self, PeerAddr)> {
+        match addr {
+            ConnectAddress::Hostname(host, port) => {
+                let deadline = Instant::now() + timeout;
+                let mut error = None;
+                for addr in resolve_with_timeout(host, *port, timeout)? {
+                    let remaining = deadline.saturating_duration_since(Instant::now());
+                    if remaining == Duration::ZERO {
+                        return Err(timed_out());
+                    }
+                    match TcpStream::connect_timeout(&addr, remaining) {
+                        Ok(stream) => return Self::from_tcp_stream(stream),
+                        Err(e) => error = Some(e),
+                    }
+                }
+                Err(error.unwrap_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "could not resolve to any addresses",
+                    )
+                }))
+            }
+            _ => Self::connect(addr),
+        }
+    }
+
     /// Creates a new `Stream` from an already connected `TcpStream`.
     ///
-    /// The stream will be set in non-blocking mode.
+    /// The stream will be set in non-blocking mode and `TCP_NODELAY` is enabled.
     ///
     /// This returns the peer address in a format suitable for [`x11rb_protocol::xauth::get_auth`].
     pub fn from_tcp_stream(stream: TcpStream) -> Result<(Self, PeerAddr)> {
         let peer_addr = peer_addr::tcp(&stream.peer_addr()?);
         stream.set_nonblocking(true)?;
+        // Just like libxcb, disable Nagle's algorithm. Requests are already buffered, so delaying
+        // them further only adds latency.
+        stream.set_nodelay(true)?;
         let result = Self {
             inner: stream.into(),
         };
@@ -224,9 +372,197 @@ impl DefaultStream {
         Ok((result, peer_addr::local()))
     }
 
+    /// Creates a new `Stream` from an already connected socket.
+    ///
+    /// The socket will be set in non-blocking mode.
+    #[cfg(unix)]
+    pub fn from_owned_fd(fd: OwnedFd) -> Result<Self> {
+        use rustix::fs::{fcntl_getfl, fcntl_setfl, OFlags};
+
+        fcntl_setfl(&fd, fcntl_getfl(&fd)? | OFlags::NONBLOCK)?;
+        Ok(Self { inner: fd })
+    }
+
     fn as_fd(&self) -> BorrowedFd<'_> {
         self.inner.as_fd()
     }
+
+    /// Enable or disable `TCP_NODELAY` on a TCP connection.
+    ///
+    /// This is enabled by default, so that small requests are not delayed by Nagle's algorithm.
+    /// This fails for connections that do not use TCP.
+    pub fn set_tcp_nodelay(&self, nodelay: bool) -> Result<()> {
+        Ok(rustix::net::sockopt::set_tcp_nodelay(
+            self.as_fd(),
+            nodelay,
+        )?)
+    }
+
+    /// Enable TCP keepalive with the given parameters, or disable it with `None`.
+    ///
+    /// With keepalive, a connection to an X11 server that vanished without closing the connection
+    /// is noticed even when no requests are sent. Parameters that are `None` keep the system's
+    /// default. This fails for connections that do not use TCP.
+    pub fn set_tcp_keepalive(&self, keepalive: Option<TcpKeepalive>) -> Result<()> {
+        use rustix::net::sockopt;
+
+        let fd = self.as_fd();
+        let keepalive = match keepalive {
+            None => return Ok(sockopt::set_socket_keepalive(fd, false)?),
+            Some(keepalive) => keepalive,
+        };
+        sockopt::set_socket_keepalive(fd, true)?;
+        #[cfg(not(any(target_os = "openbsd", target_os = "haiku", target_os = "nto")))]
+        {
+            if let Some(idle) = keepalive.idle {
+                sockopt::set_tcp_keepidle(fd, idle)?;
+            }
+            if let Some(interval) = keepalive.interval {
+                sockopt::set_tcp_keepintvl(fd, interval)?;
+            }
+            if let Some(retries) = keepalive.retries {
+                sockopt::set_tcp_keepcnt(fd, retries)?;
+            }
+        }
+        #[cfg(any(target_os = "openbsd", target_os = "haiku", target_os = "nto"))]
+        if keepalive != TcpKeepalive::default() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "TCP keepalive parameters are not supported on this platform",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Start connecting to the given TCP address without blocking.
+    ///
+    /// Returns the stream, its peer address and whether connecting is still in progress. In the
+    /// latter case, the stream becomes writable once connecting finished and
+    /// [`DefaultStream::finish_connect`] has to be called to check the result.
+    #[cfg(unix)]
+    pub(super) fn start_connect_tcp(addr: &SocketAddr) -> Result<(Self, PeerAddr, bool)> {
+        use rustix::net::{connect, AddressFamily};
+
+        let family = match addr {
+            SocketAddr::V4(_) => AddressFamily::INET,
+            SocketAddr::V6(_) => AddressFamily::INET6,
+        };
+        let socket = nonblocking_socket(family)?;
+        rustix::net::sockopt::set_tcp_nodelay(&socket, true)?;
+        let in_progress = match connect(&socket, addr) {
+            Ok(()) => false,
+            Err(rustix::io::Errno::INPROGRESS) => true,
+            Err(e) => return Err(e.into()),
+        };
+        Ok((Self { inner: socket }, peer_addr::tcp(addr), in_progress))
+    }
+
+    /// Start connecting to the given TCP address.
+    ///
+    /// On this platform, this blocks until the connection is established.
+    #[cfg(not(unix))]
+    pub(super) fn start_connect_tcp(addr: &SocketAddr) -> Result<(Self, PeerAddr, bool)> {
+        let (stream, peer_addr) = Self::from_tcp_stream(TcpStream::connect(addr)?)?;
+        Ok((stream, peer_addr, false))
+    }
+
+    /// Start connecting to the given Unix domain socket without blocking.
+    ///
+    /// See [`DefaultStream::start_connect_tcp`] for the meaning of the return value.
+    #[cfg(unix)]
+    pub(super) fn start_connect_unix(path: &str) -> Result<(Self, PeerAddr, bool)> {
+        use rustix::net::{connect_unix, AddressFamily, SocketAddrUnix};
+
+        // Try abstract unix socket first, just like DefaultStream::connect() does
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        if let Ok(stream) = connect_abstract_unix_stream(path.as_bytes()) {
+            return Ok((Self { inner: stream }, peer_addr::local(), false));
+        }
+
+        let socket = nonblocking_socket(AddressFamily::UNIX)?;
+        let in_progress = match connect_unix(&socket, &SocketAddrUnix::new(path)?) {
+            Ok(()) => false,
+            Err(rustix::io::Errno::INPROGRESS) | Err(rustix::io::Errno::AGAIN) => true,
+            Err(e) => return Err(e.into()),
+        };
+        Ok((Self { inner: socket }, peer_addr::local(), in_progress))
+    }
+
+    /// Check the result of a connection attempt that was started without blocking.
+    #[cfg(unix)]
+    pub(super) fn finish_connect(&self) -> Result<()> {
+        Ok(rustix::net::sockopt::get_socket_error(self.as_fd())??)
+    }
+
+    /// Check the result of a connection attempt.
+    ///
+    /// On this platform, connecting always blocks, so there is nothing to check.
+    #[cfg(not(unix))]
+    pub(super) fn finish_connect(&self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Wait until the stream becomes readable and/or writable or the timeout expires.
+    ///
+    /// Returns `false` if the timeout expired. A timeout of `None` means to wait forever.
+    pub(super) fn poll_with_timeout(
+        &self,
+        mode: PollMode,
+        timeout: Option<Duration>,
+    ) -> Result<bool> {
+        use rustix::event::PollFlags;
+
+        let mut poll_flags = PollFlags::empty();
+        if mode.readable() {
+            poll_flags |= PollFlags::IN;
+        }
+        if mode.writable() {
+            poll_flags |= PollFlags::OUT;
+        }
+        let deadline = timeout.and_then(|timeout| Instant::now().checked_add(timeout));
+        crate::utils::poll_until(self.as_fd(), poll_flags, deadline)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn timed_out() -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::TimedOut,
+        "Timed out while connecting to the X11 server",
+    )
+}
+
+/// Resolve the given hostname, giving up after the given timeout.
+#[cfg(not(target_arch = "wasm32"))]
+fn resolve_with_timeout(host: &str, port: u16, timeout: Duration) -> Result<Vec<SocketAddr>> {
+    use std::sync::mpsc::{channel, RecvTimeoutError};
+
+    // Avoid spawning a thread for literal IP addresses
+    if let Ok(ip) = host.parse::<IpAddr>() {
+        return Ok(vec![SocketAddr::new(ip, port)]);
+    }
+
+    // The standard library offers no way to bound the time name resolution takes. Do it in
+    // another thread instead. There is no way to cancel it, so on timeout the thread stays
+    // around until the resolution completes.
+    let (sender, receiver) = channel();
+    let host = host.to_owned();
+    let _ = std::thread::Builder::new()
+        .name("x11rb name resolution".into())
+        .spawn(move || {
+            let result = (host.as_str(), port)
+                .to_socket_addrs()
+                .map(Iterator::collect);
+            let _ = sender.send(result);
+        })?;
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => result,
+        Err(RecvTimeoutError::Timeout) => Err(timed_out()),
+        Err(RecvTimeoutError::Disconnected) => Err(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            "Name resolution failed",
+        )),
+    }
 }
 
 #[cfg(unix)]
@@ -285,6 +621,34 @@ impl From<DefaultStream> for OwnedSocket {
     }
 }
 
+/// The maximum number of FDs that are sent in a single message.
+///
+/// This is `SCM_MAX_FD` on Linux. The kernel rejects messages with more FDs.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+const MAX_FDS_PER_MESSAGE: usize = 253;
+
+/// The maximum number of FDs that are sent in a single message.
+///
+/// Other systems have no fixed limit, so all FDs are sent together.
+#[cfg(all(unix, not(any(target_os = "linux", target_os = "android"))))]
+const MAX_FDS_PER_MESSAGE: usize = usize::MAX;
+
+/// Limit the given buffers to at most `limit` bytes.
+#[cfg(unix)]
+fn truncate_bufs<'a>(bufs: &'a [IoSlice<'_>], mut limit: usize) -> Vec<IoSlice<'a>> {
+    let mut result = Vec::with_capacity(bufs.len());
+    for buf in bufs {
+        if limit == 0 {
+            break;
+        }
+        let buf: &'a [u8] = buf;
+        let len = buf.len().min(limit);
+        result.push(IoSlice::new(&buf[..len]));
+        limit -= len;
+    }
+    result
+}
+
 #[cfg(unix)]
 fn do_write(
     stream: &DefaultStream,
@@ -312,8 +676,37 @@ fn do_write(
 
     let fd = stream.as_fd();
 
-    let res = if !fds.is_empty() {
-        let fds = fds.iter().map(|fd| fd.as_fd()).collect::<Vec<_>>();
+    // All buffered data and FDs are sent with a single sendmsg() if possible. If there are too
+    // many FDs for one message, the remaining FDs are sent with the following calls. FDs can
+    // only be transferred together with some data, so one byte is held back for each of the
+    // following messages.
+    let num_fds = fds.len().min(MAX_FDS_PER_MESSAGE);
+    let remaining_fds = fds.len() - num_fds;
+    let following_messages = if remaining_fds == 0 {
+        0
+    } else {
+        (remaining_fds - 1) / MAX_FDS_PER_MESSAGE + 1
+    };
+    let total_len = bufs.iter().map(|buf| buf.len()).sum::<usize>();
+    if num_fds > 0 && total_len <= following_messages {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "Not enough data for sending all FDs",
+        ));
+    }
+    let truncated;
+    let bufs = if following_messages > 0 {
+        truncated = truncate_bufs(bufs, total_len - following_messages);
+        &truncated[..]
+    } else {
+        bufs
+    };
+
+    let res = if num_fds > 0 {
+        let fds = fds[..num_fds]
+            .iter()
+            .map(|fd| fd.as_fd())
+            .collect::<Vec<_>>();
         let rights = SendAncillaryMessage::ScmRights(&fds);
 
         let mut cmsg_space = vec![0u8; rights.size()];
@@ -325,34 +718,16 @@ fn do_write(
         sendmsg_wrapper(fd, bufs, &mut Default::default(), SendFlags::empty())?
     };
 
-    // We successfully sent all FDs
-    fds.clear();
+    // We successfully sent these FDs
+    let _ = fds.drain(..num_fds);
 
     Ok(res)
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 impl Stream for DefaultStream {
     fn poll(&self, mode: PollMode) -> Result<()> {
-        use rustix::event::{poll, PollFd, PollFlags};
-        use rustix::io::Errno;
-
-        let mut poll_flags = PollFlags::empty();
-        if mode.readable() {
-            poll_flags |= PollFlags::IN;
-        }
-        if mode.writable() {
-            poll_flags |= PollFlags::OUT;
-        }
-        let fd = self.as_fd();
-        let mut poll_fds = [PollFd::from_borrowed_fd(fd, poll_flags)];
-        loop {
-            match poll(&mut poll_fds, -1) {
-                Ok(_) => break,
-                Err(Errno::INTR) => {}
-                Err(e) => return Err(e.into()),
-            }
-        }
-        // Let the errors (POLLERR) be handled when trying to read or write.
+        let _ = self.poll_with_timeout(mode, None)?;
         Ok(())
     }
 
@@ -458,6 +833,67 @@ impl Stream for DefaultStream {
     }
 }
 
+// On wasm32 there are neither sockets nor a way to poll, so `DefaultStream` cannot be
+// constructed. Use `MessageStream` (feature `wasm`) or a custom `Stream` instead.
+#[cfg(target_arch = "wasm32")]
+impl DefaultStream {
+    /// Try to connect to the X11 server described by the given arguments.
+    ///
+    /// This always fails on `wasm32`.
+    pub fn connect(_addr: &ConnectAddress<'_>) -> Result<(Self, PeerAddr)> {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            "Sockets are not supported on wasm32",
+        ))
+    }
+
+    /// Try to connect to the X11 server described by the given arguments, giving up after the
+    /// given timeout.
+    ///
+    /// This always fails on `wasm32`.
+    pub fn connect_with_timeout(
+        addr: &ConnectAddress<'_>,
+        _timeout: Duration,
+    ) -> Result<(Self, PeerAddr)> {
+        Self::connect(addr)
+    }
+
+    pub(super) fn poll_with_timeout(
+        &self,
+        _mode: PollMode,
+        _timeout: Option<Duration>,
+    ) -> Result<bool> {
+        match self.inner {}
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Stream for DefaultStream {
+    fn poll(&self, _mode: PollMode) -> Result<()> {
+        match self.inner {}
+    }
+
+    fn read(&self, _buf: &mut [u8], _fd_storage: &mut Vec<RawFdContainer>) -> Result<usize> {
+        match self.inner {}
+    }
+
+    fn write(&self, _buf: &[u8], _fds: &mut Vec<RawFdContainer>) -> Result<usize> {
+        match self.inner {}
+    }
+}
+
+/// Create a new socket that is non-blocking and closed on exec.
+#[cfg(unix)]
+fn nonblocking_socket(family: rustix::net::AddressFamily) -> Result<RawFdContainer> {
+    use rustix::fs::{fcntl_getfl, fcntl_setfd, fcntl_setfl, FdFlags, OFlags};
+    use rustix::net::{socket, SocketType};
+
+    let socket = socket(family, SocketType::STREAM, None)?;
+    fcntl_setfd(&socket, FdFlags::CLOEXEC)?;
+    fcntl_setfl(&socket, fcntl_getfl(&socket)? | OFlags::NONBLOCK)?;
+    Ok(socket)
+}
+
 #[cfg(any(target_os = "linux", target_os = "android"))]
 fn connect_abstract_unix_stream(
     path: &[u8],
@@ -543,12 +979,13 @@ mod recvmsg {
     }
 }
 
-mod peer_addr {
+#[cfg(not(target_arch = "wasm32"))]
+pub(super) mod peer_addr {
     use super::{Family, PeerAddr};
     use std::net::{Ipv4Addr, SocketAddr};
 
     // Get xauth information representing a local connection
-    pub(super) fn local() -> PeerAddr {
+    pub(in crate::rust_connection) fn local() -> PeerAddr {
         let hostname = crate::hostname()
             .to_str()
             .map_or_else(Vec::new, |s| s.as_bytes().to_vec());
@@ -583,3 +1020,48 @@ mod peer_addr {
         }
     }
 }
+
+#[cfg(all(test, unix))]
+mod test {
+    use std::io::IoSlice;
+    use std::os::unix::net::UnixStream;
+
+    use super::{DefaultStream, Stream, MAX_FDS_PER_MESSAGE};
+    use crate::utils::RawFdContainer;
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[test]
+    fn many_fds_are_split_into_few_messages() {
+        let (stream, _other) = UnixStream::pair().unwrap();
+        let (stream, _) = DefaultStream::from_unix_stream(stream).unwrap();
+
+        let file = std::fs::File::open("/dev/null").unwrap();
+        let mut fds = (0..MAX_FDS_PER_MESSAGE + 10)
+            .map(|_| RawFdContainer::from(file.try_clone().unwrap()))
+            .collect::<Vec<_>>();
+        let data = [0; 16];
+        let bufs = [IoSlice::new(&data[..8]), IoSlice::new(&data[8..])];
+
+        // Two messages are needed, but there is only one byte of data
+        let error = stream.write(&data[..1], &mut fds).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+
+        // The first message contains everything except one byte for the remaining FDs
+        assert_eq!(stream.write_vectored(&bufs, &mut fds).unwrap(), 15);
+        assert_eq!(fds.len(), 10);
+        assert_eq!(stream.write(&data[15..], &mut fds).unwrap(), 1);
+        assert!(fds.is_empty());
+    }
+
+    #[test]
+    fn fds_need_data() {
+        let (stream, _other) = UnixStream::pair().unwrap();
+        let (stream, _) = DefaultStream::from_unix_stream(stream).unwrap();
+
+        let file = std::fs::File::open("/dev/null").unwrap();
+        let mut fds = vec![RawFdContainer::from(file)];
+        let error = stream.write(&[], &mut fds).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+        assert_eq!(fds.len(), 1);
+    }
+}
@@ -0,0 +1,169 @@
+//! A [`MessageChannel`] on top of a WebSocket in the browser.
+
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::io::{Error, ErrorKind, Result};
+use std::rc::Rc;
+
+use js_sys::{ArrayBuffer, Uint8Array};
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{BinaryType, MessageEvent, WebSocket};
+
+use super::MessageChannel;
+
+/// The state that is shared with the event handlers of the WebSocket.
+#[derive(Debug, Default)]
+struct Shared {
+    /// Received messages that were not read yet.
+    incoming: RefCell<VecDeque<Vec<u8>>>,
+    /// Messages that were sent while the WebSocket was still connecting.
+    outgoing: RefCell<Vec<Vec<u8>>>,
+    /// Whether the WebSocket was closed.
+    closed: Cell<bool>,
+}
+
+/// A [`MessageChannel`] that uses a WebSocket of the browser, e.g. to an X11-over-WebSocket
+/// bridge.
+///
+/// Wrap this in a [`super::MessageStream`] to use it with a [`super::RustConnection`].
+///
+/// The browser does not allow blocking. Thus, [`MessageChannel::wait_for_message`] fails with
+/// [`ErrorKind::WouldBlock`] when no message was received yet, and so does everything that has
+/// to wait for the X11 server, for example getting a reply. Such operations have to be repeated
+/// once the next message arrived. Use [`WebSocketChannel::socket`] to add an event listener
+/// for this.
+///
+/// This also applies to the setup handshake. Thus, the connection has to be established with
+/// [`super::RustConnection::connect_to_stream_nonblocking`]. Call
+/// [`super::NonBlockingConnect::advance`] from the event listener until the connection is
+/// established.
+#[derive(Debug)]
+pub struct WebSocketChannel {
+    socket: WebSocket,
+    shared: Rc<Shared>,
+    // The event handlers of the socket have to be kept alive while they are used
+    _on_message: Closure<dyn FnMut(MessageEvent)>,
+    _on_open: Closure<dyn FnMut()>,
+    _on_close: Closure<dyn FnMut()>,
+}
+
+impl WebSocketChannel {
+    /// Open a WebSocket to the given URL.
+    ///
+    /// Messages that are sent while the connection is being established are queued.
+    pub fn connect(url: &str) -> Result<Self> {
+        Ok(Self::from_socket(
+            WebSocket::new(url).map_err(|e| js_error(&e))?,
+        ))
+    }
+
+    /// Use an existing WebSocket.
+    ///
+    /// The `onmessage`, `onopen` and `onclose` handlers of the socket are replaced.
+    pub fn from_socket(socket: WebSocket) -> Self {
+        socket.set_binary_type(BinaryType::Arraybuffer);
+        let shared = Rc::new(Shared::default());
+
+        let on_message = {
+            let shared = Rc::clone(&shared);
+            Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+                // Text messages are not part of the X11 protocol and are ignored
+                if let Ok(data) = event.data().dyn_into::<ArrayBuffer>() {
+                    shared
+                        .incoming
+                        .borrow_mut()
+                        .push_back(Uint8Array::new(&data).to_vec());
+                }
+            })
+        };
+        let on_open = {
+            let (shared, socket) = (Rc::clone(&shared), socket.clone());
+            Closure::<dyn FnMut()>::new(move || {
+                for message in shared.outgoing.borrow_mut().drain(..) {
+                    if socket.send_with_u8_array(&message).is_err() {
+                        shared.closed.set(true);
+                        break;
+                    }
+                }
+            })
+        };
+        let on_close = {
+            let shared = Rc::clone(&shared);
+            Closure::<dyn FnMut()>::new(move || shared.closed.set(true))
+        };
+        socket.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+        socket.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+        socket.set_onclose(Some(on_close.as_ref().unchecked_ref()));
+
+        Self {
+            socket,
+            shared,
+            _on_message: on_message,
+            _on_open: on_open,
+            _on_close: on_close,
+        }
+    }
+
+    /// Get the underlying WebSocket.
+    pub fn socket(&self) -> &WebSocket {
+        &self.socket
+    }
+
+    fn is_closed(&self) -> bool {
+        self.shared.closed.get() || self.socket.ready_state() >= WebSocket::CLOSING
+    }
+}
+
+impl MessageChannel for WebSocketChannel {
+    fn send(&self, message: &[u8]) -> Result<()> {
+        if self.is_closed() {
+            return Err(closed());
+        }
+        let mut outgoing = self.shared.outgoing.borrow_mut();
+        if self.socket.ready_state() == WebSocket::CONNECTING || !outgoing.is_empty() {
+            outgoing.push(message.to_vec());
+            Ok(())
+        } else {
+            self.socket
+                .send_with_u8_array(message)
+                .map_err(|e| js_error(&e))
+        }
+    }
+
+    fn try_recv(&self) -> Result<Option<Vec<u8>>> {
+        match self.shared.incoming.borrow_mut().pop_front() {
+            Some(message) => Ok(Some(message)),
+            None if self.is_closed() => Err(closed()),
+            None => Ok(None),
+        }
+    }
+
+    fn wait_for_message(&self) -> Result<()> {
+        if !self.shared.incoming.borrow().is_empty() {
+            Ok(())
+        } else if self.is_closed() {
+            Err(closed())
+        } else {
+            Err(ErrorKind::WouldBlock.into())
+        }
+    }
+}
+
+impl Drop for WebSocketChannel {
+    fn drop(&mut self) {
+        // The handlers are freed together with self and must not be called afterwards
+        self.socket.set_onmessage(None);
+        self.socket.set_onopen(None);
+        self.socket.set_onclose(None);
+        let _ = self.socket.close();
+    }
+}
+
+fn closed() -> Error {
+    Error::new(ErrorKind::UnexpectedEof, "The WebSocket was closed")
+}
+
+fn js_error(error: &JsValue) -> Error {
+    Error::new(ErrorKind::Other, format!("{:?}", error))
+}
@@ -0,0 +1,186 @@
+//! Tracking the time of the X11 server.
+//!
+//! Many requests, e.g. `SetSelectionOwner` and `SetInputFocus`, should be given the server time
+//! of the user action that caused them instead of `CurrentTime`. The server time is a number of
+//! milliseconds that wraps around after about 49.7 days and is only known from events.
+//! [`ServerClock`] remembers the latest server time that was seen in events and converts between
+//! server time and [`Instant`]s, e.g. for timeouts. If no suitable event was received yet,
+//! [`RustConnection::fetch_server_time`](crate::rust_connection::RustConnection::fetch_server_time)
+//! asks the server for the current time.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use x11rb::connection::Connection;
+//! use x11rb::server_time::ServerClock;
+//!
+//! let (conn, screen_num) = x11rb::connect(None)?;
+//! let mut clock = ServerClock::new();
+//! clock.update(conn.fetch_server_time(screen_num)?);
+//! loop {
+//!     let event = conn.wait_for_event()?;
+//!     clock.observe(&event);
+//!     println!("The server time is about {:?}", clock.now());
+//! }
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::time::{Duration, Instant};
+
+use crate::protocol::xproto::Timestamp;
+use crate::protocol::Event;
+
+/// Get the server time at which an event was generated, if the event contains it.
+///
+/// Only core events whose time is set by the X11 server are considered. Events that were sent
+/// with `SendEvent` and events with times that were chosen by clients, e.g. `SelectionRequest`,
+/// return `None`.
+pub fn event_time(event: &Event) -> Option<Timestamp> {
+    if event.sent_event() {
+        return None;
+    }
+    match event {
+        Event::KeyPress(event) | Event::KeyRelease(event) => Some(event.time),
+        Event::ButtonPress(event) | Event::ButtonRelease(event) => Some(event.time),
+        Event::MotionNotify(event) => Some(event.time),
+        Event::EnterNotify(event) | Event::LeaveNotify(event) => Some(event.time),
+        Event::PropertyNotify(event) => Some(event.time),
+        _ => None,
+    }
+}
+
+/// The latest known server time, together with the time at which it was seen.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ServerClock {
+    latest: Option<(Timestamp, Instant)>,
+}
+
+impl ServerClock {
+    /// Create a clock that does not know the server time yet.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Remember the time of an event, if it contains one, see [`event_time`].
+    pub fn observe(&mut self, event: &Event) {
+        if let Some(time) = event_time(event) {
+            self.update(time);
+        }
+    }
+
+    /// Remember a server time that was just received.
+    pub fn update(&mut self, time: Timestamp) {
+        self.update_at(time, Instant::now());
+    }
+
+    fn update_at(&mut self, time: Timestamp, now: Instant) {
+        // Events that are older than the latest known time do not move the clock back
+        if let Some((latest, _)) = self.latest {
+            if offset(latest, time) < 0 {
+                return;
+            }
+        }
+        self.latest = Some((time, now));
+    }
+
+    /// Get the latest server time that was seen.
+    pub fn latest(&self) -> Option<Timestamp> {
+        self.latest.map(|(time, _)| time)
+    }
+
+    /// Estimate the current server time.
+    pub fn now(&self) -> Option<Timestamp> {
+        self.from_instant(Instant::now())
+    }
+
+    /// Estimate the server time at the given instant.
+    pub fn from_instant(&self, instant: Instant) -> Option<Timestamp> {
+        let (time, seen) = self.latest?;
+        let result = if instant >= seen {
+            time.wrapping_add(millis(instant - seen))
+        } else {
+            time.wrapping_sub(millis(seen - instant))
+        };
+        Some(result)
+    }
+
+    /// Estimate the instant that corresponds to the given server time.
+    ///
+    /// Server times are only compared within a window of about 24.8 days around the latest known
+    /// time because of the wraparound. This returns `None` if the server time is not known yet
+    /// or if the instant cannot be represented.
+    pub fn to_instant(&self, time: Timestamp) -> Option<Instant> {
+        let (latest, seen) = self.latest?;
+        let offset = offset(latest, time);
+        let duration = Duration::from_millis(offset.unsigned_abs().into());
+        if offset >= 0 {
+            seen.checked_add(duration)
+        } else {
+            seen.checked_sub(duration)
+        }
+    }
+}
+
+/// Get the number of milliseconds from `from` to `to`, taking the wraparound into account.
+fn offset(from: Timestamp, to: Timestamp) -> i32 {
+    to.wrapping_sub(from) as i32
+}
+
+/// Convert a duration into milliseconds of server time, which wrap around.
+fn millis(duration: Duration) -> Timestamp {
+    duration.as_millis() as Timestamp
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, Instant};
+
+    use super::{event_time, ServerClock};
+    use crate::protocol::xproto::{ExposeEvent, PropertyNotifyEvent, PROPERTY_NOTIFY_EVENT};
+    use crate::protocol::Event;
+
+    #[test]
+    fn observe_events() {
+        let property = |response_type, time| {
+            Event::PropertyNotify(PropertyNotifyEvent {
+                response_type,
+                time,
+                ..Default::default()
+            })
+        };
+        let mut clock = ServerClock::new();
+        assert_eq!(clock.latest(), None);
+        clock.observe(&Event::Expose(ExposeEvent::default()));
+        assert_eq!(clock.latest(), None);
+        clock.observe(&property(PROPERTY_NOTIFY_EVENT, 100));
+        assert_eq!(clock.latest(), Some(100));
+        clock.observe(&property(PROPERTY_NOTIFY_EVENT, 50));
+        assert_eq!(clock.latest(), Some(100));
+        // Sent events do not count
+        assert_eq!(
+            event_time(&property(PROPERTY_NOTIFY_EVENT | 0x80, 200)),
+            None
+        );
+    }
+
+    #[test]
+    fn conversions_across_wraparound() {
+        let start = Instant::now();
+        let mut clock = ServerClock::new();
+        clock.update_at(u32::MAX - 99, start + Duration::from_secs(1));
+        assert_eq!(
+            clock.from_instant(start + Duration::from_millis(1200)),
+            Some(100)
+        );
+        assert_eq!(clock.from_instant(start), Some(u32::MAX - 99 - 1000));
+        assert_eq!(
+            clock.to_instant(100),
+            Some(start + Duration::from_millis(1200))
+        );
+        assert_eq!(clock.to_instant(u32::MAX - 1099), Some(start));
+
+        // A time after the wraparound is newer
+        clock.update_at(5, start + Duration::from_secs(2));
+        assert_eq!(clock.latest(), Some(5));
+    }
+}
@@ -0,0 +1,501 @@
+//! Images in shared memory via the MIT-SHM extension.
+//!
+//! Uploading an image with `PutImage` copies all pixels through the X11 connection. With the
+//! MIT-SHM extension, the client and the X11 server instead share a memory segment, so that the
+//! pixels only have to be written once. [`ShmImage`] manages such a segment and offers the pixels
+//! as a byte slice in the native format of the server.
+//!
+//! A shared memory segment can only be used when the client runs on the same machine as the X11
+//! server. [`ShmImage`] falls back to ordinary memory and the core `PutImage` and `GetImage`
+//! requests when the segment cannot be created, e.g. for connections via TCP or when the server
+//! does not support the extension. Segments are created by the server if it supports version 1.2
+//! of the extension; otherwise a System V segment is used.
+//!
+//! The code in this module is only available on Unix when the `image`, `shm` and
+//! `allow-unsafe-code` features of the library are enabled.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use x11rb::connection::Connection;
+//! use x11rb::shm_image::ShmImage;
+//!
+//! let (conn, screen_num) = x11rb::connect(None)?;
+//! let screen = &conn.setup().roots[screen_num];
+//! let mut image = ShmImage::new(&conn, 640, 480, screen.root_depth)?;
+//! image.data_mut().fill(0xff);
+//! # let (window, gc) = (0, 0);
+//! image.put(window, gc, 0, 0)?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::borrow::Cow;
+use std::fmt;
+use std::os::unix::io::AsRawFd;
+use std::ptr::NonNull;
+
+use crate::connection::Connection;
+use crate::errors::{ConnectionError, ReplyError, ReplyOrIdError};
+use crate::image::{Image, PixelFormat};
+use crate::protocol::shm::{self, ConnectionExt as _};
+use crate::protocol::xproto::{
+    self, ConnectionExt as _, Drawable, Gcontext, ImageFormat, Rectangle,
+};
+
+/// The memory of a [`ShmBuffer`].
+enum Storage {
+    /// A segment that was created by the X11 server and mapped into our address space.
+    Mapped { seg: shm::Seg, ptr: NonNull<u8> },
+
+    /// A System V shared memory segment.
+    SysV { seg: shm::Seg, ptr: NonNull<u8> },
+
+    /// Ordinary memory, used when shared memory is unavailable.
+    Memory(Vec<u8>),
+}
+
+/// Memory that is shared with the X11 server if possible.
+///
+/// This is the storage of a [`ShmImage`]. It can also be used on its own with other requests
+/// that read from a shared memory segment, e.g. `ShmPutImage` of the XVideo extension. The
+/// segment is detached and freed when this is dropped.
+pub struct ShmBuffer<'c, C: Connection> {
+    conn: &'c C,
+    len: usize,
+    storage: Storage,
+}
+
+impl<C: Connection> fmt::Debug for ShmBuffer<'_, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShmBuffer")
+            .field("len", &self.len)
+            .field("segment", &self.segment())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'c, C: Connection> ShmBuffer<'c, C> {
+    /// Create a new buffer with `len` bytes that are initially zero.
+    ///
+    /// Shared memory is used if possible; errors while setting it up only cause the fallback to
+    /// ordinary memory.
+    pub fn new(conn: &'c C, len: usize) -> Result<Self, ConnectionError> {
+        let storage = match create_segment(conn, len)? {
+            Some(storage) => storage,
+            None => Storage::Memory(vec![0; len]),
+        };
+        Ok(Self { conn, len, storage })
+    }
+
+    /// The size of the buffer in bytes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check whether the buffer has a size of zero.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get the shared memory segment, or `None` if ordinary memory is used.
+    pub fn segment(&self) -> Option<shm::Seg> {
+        match self.storage {
+            Storage::Mapped { seg, .. } | Storage::SysV { seg, .. } => Some(seg),
+            Storage::Memory(_) => None,
+        }
+    }
+
+    /// The content of the buffer.
+    pub fn data(&self) -> &[u8] {
+        match &self.storage {
+            // SAFETY: The mapping has a size of at least self.len bytes and lives as long as self
+            Storage::Mapped { ptr, .. } | Storage::SysV { ptr, .. } => unsafe {
+                std::slice::from_raw_parts(ptr.as_ptr(), self.len)
+            },
+            Storage::Memory(data) => data,
+        }
+    }
+
+    /// Mutable access to the content of the buffer.
+    ///
+    /// The X11 server may read the shared memory until it processed the requests that use it.
+    /// Wait for the server, e.g. with a round trip, before changing data that is still in use.
+    pub fn data_mut(&mut self) -> &mut [u8] {
+        match &mut self.storage {
+            // SAFETY: The mapping has a size of at least self.len bytes and lives as long as self
+            Storage::Mapped { ptr, .. } | Storage::SysV { ptr, .. } => unsafe {
+                std::slice::from_raw_parts_mut(ptr.as_ptr(), self.len)
+            },
+            Storage::Memory(data) => data,
+        }
+    }
+}
+
+impl<C: Connection> Drop for ShmBuffer<'_, C> {
+    fn drop(&mut self) {
+        // Errors cannot be reported here; they show up on the next use of the connection
+        if let Some(seg) = self.segment() {
+            let _ = self.conn.shm_detach(seg);
+        }
+        match self.storage {
+            // SAFETY: The mapping is not used anymore
+            Storage::Mapped { ptr, .. } => unsafe {
+                let _ = libc::munmap(ptr.as_ptr().cast(), self.len.max(1));
+            },
+            // SAFETY: The segment is not used anymore
+            Storage::SysV { ptr, .. } => unsafe {
+                let _ = libc::shmdt(ptr.as_ptr().cast());
+            },
+            Storage::Memory(_) => {}
+        }
+    }
+}
+
+/// An image in the native format of the X11 server that is shared with the server if possible.
+///
+/// See the [module documentation](self) for details.
+pub struct ShmImage<'c, C: Connection> {
+    conn: &'c C,
+    width: u16,
+    height: u16,
+    format: PixelFormat,
+    buffer: ShmBuffer<'c, C>,
+}
+
+impl<C: Connection> fmt::Debug for ShmImage<'_, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShmImage")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("format", &self.format)
+            .field("segment", &self.segment())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'c, C: Connection> ShmImage<'c, C> {
+    /// Create a new image with the given size and depth.
+    ///
+    /// The image is in the native format of the X11 server for `depth` and initially black. Shared
+    /// memory is used if possible; errors while setting it up only cause the fallback to ordinary
+    /// memory. The returned error describes problems with the connection itself or a depth that
+    /// the server does not support.
+    pub fn new(conn: &'c C, width: u16, height: u16, depth: u8) -> Result<Self, ReplyOrIdError> {
+        let format = PixelFormat::native(conn.setup(), depth)?;
+        let len = format.stride(width) * usize::from(height);
+        let buffer = ShmBuffer::new(conn, len)?;
+        Ok(Self {
+            conn,
+            width,
+            height,
+            format,
+            buffer,
+        })
+    }
+
+    /// The width in pixels.
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// The height in pixels.
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// The format of the pixel data.
+    pub fn format(&self) -> PixelFormat {
+        self.format
+    }
+
+    /// Get the shared memory segment, or `None` if ordinary memory is used.
+    pub fn segment(&self) -> Option<shm::Seg> {
+        self.buffer.segment()
+    }
+
+    /// The pixel data.
+    pub fn data(&self) -> &[u8] {
+        self.buffer.data()
+    }
+
+    /// Mutable access to the pixel data.
+    pub fn data_mut(&mut self) -> &mut [u8] {
+        // The X11 server only accesses the memory while put() or get() wait for it
+        self.buffer.data_mut()
+    }
+
+    /// Get an [`Image`] that borrows the pixel data, e.g. for [`Image::get_pixel`].
+    pub fn image(&self) -> Image<'_> {
+        Image::with_format(
+            self.width,
+            self.height,
+            &self.format,
+            Cow::Borrowed(self.data()),
+        )
+        .expect("The data has the right size for the format")
+    }
+
+    /// Upload the image to position `(dst_x, dst_y)` of `drawable`.
+    ///
+    /// This waits until the X11 server processed the upload, so that the pixel data can be
+    /// modified again afterwards. Without shared memory, the image is sent with `PutImage`
+    /// requests instead.
+    pub fn put(
+        &self,
+        drawable: Drawable,
+        gc: Gcontext,
+        dst_x: i16,
+        dst_y: i16,
+    ) -> Result<(), ReplyError> {
+        let area = Rectangle {
+            x: 0,
+            y: 0,
+            width: self.width,
+            height: self.height,
+        };
+        self.send_area(drawable, gc, area, dst_x, dst_y)?;
+        self.sync()
+    }
+
+    /// Upload parts of the image to the same positions in `drawable`.
+    ///
+    /// The areas are clipped to the image. Like [`ShmImage::put`], this waits until the X11
+    /// server processed the upload.
+    pub fn put_areas(
+        &self,
+        drawable: Drawable,
+        gc: Gcontext,
+        areas: &[Rectangle],
+    ) -> Result<(), ReplyError> {
+        for area in areas {
+            if let Some(area) = clip(*area, self.width, self.height) {
+                self.send_area(drawable, gc, area, area.x, area.y)?;
+            }
+        }
+        self.sync()
+    }
+
+    /// Send the requests for uploading an area of the image, which must be inside of the image.
+    fn send_area(
+        &self,
+        drawable: Drawable,
+        gc: Gcontext,
+        area: Rectangle,
+        dst_x: i16,
+        dst_y: i16,
+    ) -> Result<(), ConnectionError> {
+        match self.segment() {
+            Some(seg) => {
+                let _ = self.conn.shm_put_image(
+                    drawable,
+                    gc,
+                    self.width,
+                    self.height,
+                    area.x as u16,
+                    area.y as u16,
+                    area.width,
+                    area.height,
+                    dst_x,
+                    dst_y,
+                    self.format.depth(),
+                    ImageFormat::Z_PIXMAP.into(),
+                    false,
+                    seg,
+                    0,
+                )?;
+            }
+            None => {
+                let image = self.image();
+                if (area.width, area.height) == (self.width, self.height) {
+                    let _ = image.put(self.conn, drawable, gc, dst_x, dst_y)?;
+                } else {
+                    let len = self.format.stride(area.width) * usize::from(area.height);
+                    let mut part = Image::with_format(
+                        area.width,
+                        area.height,
+                        &self.format,
+                        vec![0; len].into(),
+                    )
+                    .expect("The data has the right size for the format");
+                    part.blit(&image, area, 0, 0)
+                        .expect("The image and the part have the same format");
+                    let _ = part.put(self.conn, drawable, gc, dst_x, dst_y)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Wait until the X11 server processed all uploads.
+    fn sync(&self) -> Result<(), ReplyError> {
+        // A round trip guarantees that the server is done with the shared memory
+        let _ = self.conn.get_input_focus()?.reply()?;
+        Ok(())
+    }
+
+    /// Fill the image with the contents of `drawable`, starting at position `(x, y)`.
+    ///
+    /// The drawable must have the same depth as this image. Without shared memory, the image is
+    /// fetched with a `GetImage` request instead.
+    pub fn get(&mut self, drawable: Drawable, x: i16, y: i16) -> Result<(), ReplyError> {
+        match self.segment() {
+            Some(seg) => {
+                let _ = self
+                    .conn
+                    .shm_get_image(
+                        drawable,
+                        x,
+                        y,
+                        self.width,
+                        self.height,
+                        !0,
+                        ImageFormat::Z_PIXMAP.into(),
+                        seg,
+                        0,
+                    )?
+                    .reply()?;
+            }
+            None => {
+                let reply = xproto::get_image(
+                    self.conn,
+                    ImageFormat::Z_PIXMAP,
+                    drawable,
+                    x,
+                    y,
+                    self.width,
+                    self.height,
+                    !0,
+                )?
+                .reply()?;
+                let data = self.data_mut();
+                let len = data.len().min(reply.data.len());
+                data[..len].copy_from_slice(&reply.data[..len]);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Clip an area to an image with the given size.
+///
+/// Returns `None` if nothing of the area is inside of the image.
+pub(crate) fn clip(area: Rectangle, width: u16, height: u16) -> Option<Rectangle> {
+    let x1 = i32::from(area.x).max(0);
+    let y1 = i32::from(area.y).max(0);
+    let x2 = (i32::from(area.x) + i32::from(area.width)).min(width.into());
+    let y2 = (i32::from(area.y) + i32::from(area.height)).min(height.into());
+    if x1 >= x2 || y1 >= y2 {
+        return None;
+    }
+    Some(Rectangle {
+        x: x1.try_into().unwrap(),
+        y: y1.try_into().unwrap(),
+        width: (x2 - x1).try_into().unwrap(),
+        height: (y2 - y1).try_into().unwrap(),
+    })
+}
+
+/// Create a shared memory segment with the given size.
+///
+/// Returns `None` if shared memory cannot be used with this connection.
+fn create_segment<C: Connection>(conn: &C, len: usize) -> Result<Option<Storage>, ConnectionError> {
+    if conn
+        .extension_information(shm::X11_EXTENSION_NAME)?
+        .is_none()
+    {
+        return Ok(None);
+    }
+    let version = match conn.shm_query_version()?.reply() {
+        Ok(version) => version,
+        Err(ReplyError::ConnectionError(err)) => return Err(err),
+        Err(ReplyError::X11Error(_)) => return Ok(None),
+    };
+    let len = len.max(1);
+    let size = match u32::try_from(len) {
+        Ok(size) => size,
+        Err(_) => return Ok(None),
+    };
+    let seg = match conn.generate_id() {
+        Ok(seg) => seg,
+        Err(ReplyOrIdError::ConnectionError(err)) => return Err(err),
+        Err(_) => return Ok(None),
+    };
+    if (version.major_version, version.minor_version) >= (1, 2) {
+        if let Some(storage) = create_mapped_segment(conn, seg, size, len)? {
+            return Ok(Some(storage));
+        }
+    }
+    create_sysv_segment(conn, seg, len)
+}
+
+/// Let the X11 server create a segment and map it.
+fn create_mapped_segment<C: Connection>(
+    conn: &C,
+    seg: shm::Seg,
+    size: u32,
+    len: usize,
+) -> Result<Option<Storage>, ConnectionError> {
+    // Servers cannot send the file descriptor via TCP and report an error instead
+    let reply = match conn.shm_create_segment(seg, size, false)?.reply() {
+        Ok(reply) => reply,
+        Err(ReplyError::ConnectionError(err)) => return Err(err),
+        Err(ReplyError::X11Error(_)) => return Ok(None),
+    };
+    // SAFETY: We map a new segment that no one else in this process uses
+    let ptr = unsafe {
+        libc::mmap(
+            std::ptr::null_mut(),
+            len,
+            libc::PROT_READ | libc::PROT_WRITE,
+            libc::MAP_SHARED,
+            reply.shm_fd.as_raw_fd(),
+            0,
+        )
+    };
+    if ptr == libc::MAP_FAILED {
+        let _ = conn.shm_detach(seg)?;
+        return Ok(None);
+    }
+    Ok(NonNull::new(ptr.cast()).map(|ptr| Storage::Mapped { seg, ptr }))
+}
+
+/// Create a System V segment and let the X11 server attach it.
+fn create_sysv_segment<C: Connection>(
+    conn: &C,
+    seg: shm::Seg,
+    len: usize,
+) -> Result<Option<Storage>, ConnectionError> {
+    // Only the owner gets access, so that servers on other machines cannot attach the segment
+    // SAFETY: This only creates a new segment
+    let shmid = unsafe { libc::shmget(libc::IPC_PRIVATE, len, libc::IPC_CREAT | 0o600) };
+    if shmid < 0 {
+        return Ok(None);
+    }
+    // SAFETY: The segment was just created and is only used by the returned storage
+    let ptr = unsafe { libc::shmat(shmid, std::ptr::null(), 0) };
+    let storage = if ptr as isize == -1 {
+        None
+    } else {
+        let result = conn
+            .shm_attach(seg, shmid as u32, false)
+            .map(|cookie| cookie.check());
+        match result {
+            Ok(Ok(())) => NonNull::new(ptr.cast()).map(|ptr| Storage::SysV { seg, ptr }),
+            Ok(Err(_)) => {
+                // SAFETY: The segment is not used anywhere
+                let _ = unsafe { libc::shmdt(ptr) };
+                None
+            }
+            Err(err) => {
+                // SAFETY: The segment is not used anywhere
+                let _ = unsafe { libc::shmdt(ptr) };
+                let _ = unsafe { libc::shmctl(shmid, libc::IPC_RMID, std::ptr::null_mut()) };
+                return Err(err);
+            }
+        }
+    };
+    // The segment is destroyed once everyone detached it
+    // SAFETY: This only marks the segment for removal
+    let _ = unsafe { libc::shmctl(shmid, libc::IPC_RMID, std::ptr::null_mut()) };
+    Ok(storage)
+}
@@ -0,0 +1,459 @@
+//! The startup notification protocol of freedesktop.org.
+//!
+//! Launchers tell window managers and task bars that an application is starting, so that they can
+//! show a busy cursor or a placeholder until the first window of the application appears. The
+//! launcher sends a `new:` [`StartupMessage`] with a unique startup id and starts the
+//! application with this id in the `DESKTOP_STARTUP_ID` environment variable. The application
+//! takes the id with [`take_startup_id`], puts it on its windows with [`set_window_startup_id`]
+//! and sends a `remove:` message with [`complete`] when it finished starting.
+//!
+//! Messages are sent to the root window as a sequence of `_NET_STARTUP_INFO_BEGIN` and
+//! `_NET_STARTUP_INFO` client messages with 20 bytes each. A [`StartupReceiver`] puts them back
+//! together.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use x11rb::connection::Connection;
+//! use x11rb::startup_notification::{self, MessageKind, StartupAtoms, StartupMessage};
+//!
+//! let (conn, screen_num) = x11rb::connect(None)?;
+//! let atoms = StartupAtoms::new(&conn)?.reply()?;
+//! let time = conn.fetch_server_time(screen_num)?;
+//! let id = startup_notification::new_startup_id("my-launcher", time);
+//! let message = StartupMessage::new(MessageKind::New, &id)
+//!     .with("NAME", "Text Editor")
+//!     .with("BIN", "editor")
+//!     .with("SCREEN", &screen_num.to_string());
+//! startup_notification::send(&conn, screen_num, &atoms, &message)?;
+//! conn.flush()?;
+//! std::process::Command::new("editor")
+//!     .env("DESKTOP_STARTUP_ID", &id)
+//!     .spawn()?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::connection::Connection;
+use crate::cookie::VoidCookie;
+use crate::errors::{ConnectionError, ParseError, ReplyOrIdError};
+use crate::protocol::xproto::{
+    self, ClientMessageEvent, CreateWindowAux, EventMask, PropMode, Timestamp, Window, WindowClass,
+};
+use crate::protocol::Event;
+use crate::wrapper::ConnectionExt as _;
+use crate::COPY_DEPTH_FROM_PARENT;
+
+pub use atoms::{StartupAtoms, StartupAtomsCookie};
+
+mod atoms {
+    // The code that the macro generates has no documentation and a `new` function that returns a
+    // cookie
+    #![allow(missing_docs, clippy::new_ret_no_self)]
+
+    crate::atom_manager! {
+        /// The atoms of the startup notification protocol.
+        pub StartupAtoms:
+        /// A cookie for interning the atoms of the startup notification protocol.
+        StartupAtomsCookie {
+            _NET_STARTUP_INFO_BEGIN,
+            _NET_STARTUP_INFO,
+            _NET_STARTUP_ID,
+            UTF8_STRING,
+        }
+    }
+}
+
+/// The environment variable that passes the startup id to an application.
+pub const STARTUP_ID_ENV: &str = "DESKTOP_STARTUP_ID";
+
+/// The number of bytes of a message in one client message.
+const CHUNK_SIZE: usize = 20;
+
+/// The kind of a startup notification message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageKind {
+    /// An application is starting.
+    New,
+    /// Some information about a starting application changed.
+    Change,
+    /// An application finished starting or failed to start.
+    Remove,
+}
+
+impl MessageKind {
+    fn prefix(self) -> &'static str {
+        match self {
+            MessageKind::New => "new",
+            MessageKind::Change => "change",
+            MessageKind::Remove => "remove",
+        }
+    }
+}
+
+/// A message of the startup notification protocol.
+///
+/// Besides the `ID`, the protocol defines the keys `NAME`, `SCREEN`, `BIN`, `ICON`, `DESKTOP`,
+/// `TIMESTAMP`, `DESCRIPTION`, `WMCLASS`, `SILENT` and `APPLICATION_ID`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StartupMessage {
+    /// The kind of the message.
+    pub kind: MessageKind,
+
+    /// The startup id that the message is about.
+    pub id: String,
+
+    /// The other keys and values of the message in their order.
+    pub values: Vec<(String, String)>,
+}
+
+impl StartupMessage {
+    /// Create a message without values besides the startup id.
+    pub fn new(kind: MessageKind, id: &str) -> Self {
+        Self {
+            kind,
+            id: id.to_string(),
+            values: Vec::new(),
+        }
+    }
+
+    /// Add a key and its value to the message.
+    #[must_use]
+    pub fn with(mut self, key: &str, value: &str) -> Self {
+        self.values.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Get the value of a key, e.g. `"NAME"`.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        if key == "ID" {
+            return Some(&self.id);
+        }
+        self.values
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| &**value)
+    }
+
+    /// Parse the text of a message, e.g. `new: ID=foo NAME="Text Editor"`.
+    ///
+    /// Fails with [`ParseError::InvalidValue`] if the message has an unknown kind, is malformed
+    /// or has no `ID`.
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let (kind, mut rest) = text.split_once(':').ok_or(ParseError::InvalidValue)?;
+        let kind = match kind {
+            "new" => MessageKind::New,
+            "change" => MessageKind::Change,
+            "remove" => MessageKind::Remove,
+            _ => return Err(ParseError::InvalidValue),
+        };
+        let mut id = None;
+        let mut values = Vec::new();
+        loop {
+            rest = rest.trim_start_matches(' ');
+            if rest.is_empty() {
+                break;
+            }
+            let (key, remaining) = rest.split_once('=').ok_or(ParseError::InvalidValue)?;
+            let (value, remaining) = parse_value(remaining)?;
+            rest = remaining;
+            if key == "ID" {
+                id = Some(value);
+            } else {
+                values.push((key.to_string(), value));
+            }
+        }
+        Ok(Self {
+            kind,
+            id: id.ok_or(ParseError::InvalidValue)?,
+            values,
+        })
+    }
+
+    /// Get the text of the message.
+    pub fn to_text(&self) -> String {
+        let mut text = format!("{}:", self.kind.prefix());
+        let values =
+            std::iter::once(("ID", &*self.id)).chain(self.values.iter().map(|(k, v)| (&**k, &**v)));
+        for (key, value) in values {
+            let _ = write!(text, " {}={}", key, quote(value));
+        }
+        text
+    }
+}
+
+/// Parse a possibly quoted value and return the rest of the text after it.
+fn parse_value(text: &str) -> Result<(String, &str), ParseError> {
+    let mut value = String::new();
+    let mut quoted = false;
+    let mut chars = text.char_indices();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '"' => quoted = !quoted,
+            '\\' => value.push(chars.next().ok_or(ParseError::InvalidValue)?.1),
+            ' ' if !quoted => return Ok((value, &text[index..])),
+            c => value.push(c),
+        }
+    }
+    if quoted {
+        return Err(ParseError::InvalidValue);
+    }
+    Ok((value, ""))
+}
+
+/// Quote a value if it contains characters that have a special meaning.
+fn quote(value: &str) -> String {
+    if !value.is_empty() && !value.contains([' ', '"', '\\']) {
+        return value.to_string();
+    }
+    let mut quoted = String::from("\"");
+    for c in value.chars() {
+        if c == '"' || c == '\\' {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Split the text of a message into the data of client messages.
+///
+/// The text is terminated with a zero byte and the last chunk is padded with zeros.
+fn chunks(text: &str) -> Vec<[u8; CHUNK_SIZE]> {
+    text.as_bytes()
+        .chunks(CHUNK_SIZE)
+        .chain(std::iter::once(&[][..]).filter(|_| text.len() % CHUNK_SIZE == 0))
+        .map(|bytes| {
+            let mut chunk = [0; CHUNK_SIZE];
+            chunk[..bytes.len()].copy_from_slice(bytes);
+            chunk
+        })
+        .collect()
+}
+
+/// Create a unique startup id, e.g. for launching an application.
+///
+/// `time` should be the server time of the user action that launches the application. It is
+/// embedded into the id, so that the application can use it for focus stealing prevention.
+pub fn new_startup_id(launcher: &str, time: Timestamp) -> String {
+    static SEQUENCE: AtomicUsize = AtomicUsize::new(0);
+    let sequence = SEQUENCE.fetch_add(1, Ordering::Relaxed);
+    format!(
+        "{}-{}-{}_TIME{}",
+        launcher,
+        std::process::id(),
+        sequence,
+        time
+    )
+}
+
+/// Get the server time that is embedded in a startup id.
+pub fn startup_id_time(id: &str) -> Option<Timestamp> {
+    id.rsplit_once("_TIME")?.1.parse().ok()
+}
+
+/// Take the startup id from the `DESKTOP_STARTUP_ID` environment variable.
+///
+/// The variable is removed, so that it is not inherited by child processes.
+pub fn take_startup_id() -> Option<String> {
+    let id = std::env::var(STARTUP_ID_ENV).ok()?;
+    std::env::remove_var(STARTUP_ID_ENV);
+    Some(id).filter(|id| !id.is_empty())
+}
+
+/// Put a startup id into the `_NET_STARTUP_ID` property of a top-level window.
+pub fn set_window_startup_id<'c, C: Connection>(
+    conn: &'c C,
+    window: Window,
+    atoms: &StartupAtoms,
+    id: &str,
+) -> Result<VoidCookie<'c, C>, ConnectionError> {
+    conn.change_property8(
+        PropMode::REPLACE,
+        window,
+        atoms._NET_STARTUP_ID,
+        atoms.UTF8_STRING,
+        id.as_bytes(),
+    )
+}
+
+/// Send a message to all clients on a screen.
+///
+/// A temporary window is created for sending the message, since receivers put the pieces of
+/// messages together by their window.
+pub fn send<C: Connection>(
+    conn: &C,
+    screen: usize,
+    atoms: &StartupAtoms,
+    message: &StartupMessage,
+) -> Result<(), ReplyOrIdError> {
+    let root = conn.setup().roots[screen].root;
+    let window = conn.generate_id()?;
+    let aux = CreateWindowAux::new().override_redirect(1);
+    let _ = xproto::create_window(
+        conn,
+        COPY_DEPTH_FROM_PARENT,
+        window,
+        root,
+        -100,
+        -100,
+        1,
+        1,
+        0,
+        WindowClass::INPUT_ONLY,
+        0,
+        &aux,
+    )?;
+    for (index, chunk) in chunks(&message.to_text()).into_iter().enumerate() {
+        let type_ = if index == 0 {
+            atoms._NET_STARTUP_INFO_BEGIN
+        } else {
+            atoms._NET_STARTUP_INFO
+        };
+        let event = ClientMessageEvent::new(8, window, type_, chunk);
+        let _ = xproto::send_event(conn, false, root, EventMask::PROPERTY_CHANGE, event)?;
+    }
+    let _ = xproto::destroy_window(conn, window)?;
+    Ok(())
+}
+
+/// Tell the launcher of an application that the application finished starting.
+///
+/// This sends a `remove:` message for the startup id.
+pub fn complete<C: Connection>(
+    conn: &C,
+    screen: usize,
+    atoms: &StartupAtoms,
+    id: &str,
+) -> Result<(), ReplyOrIdError> {
+    send(
+        conn,
+        screen,
+        atoms,
+        &StartupMessage::new(MessageKind::Remove, id),
+    )
+}
+
+/// Puts together the messages that other clients send.
+///
+/// The events are sent to the root window with `PropertyChange`, so this event mask has to be
+/// selected on the root window.
+#[derive(Debug)]
+pub struct StartupReceiver {
+    atoms: StartupAtoms,
+    pending: HashMap<Window, Vec<u8>>,
+}
+
+impl StartupReceiver {
+    /// Create a receiver that has not received any message yet.
+    pub fn new(atoms: StartupAtoms) -> Self {
+        Self {
+            atoms,
+            pending: HashMap::new(),
+        }
+    }
+
+    /// Handle a piece of a message.
+    ///
+    /// Returns the message when its last piece was received. Malformed messages are dropped.
+    pub fn handle_event(&mut self, event: &Event) -> Option<StartupMessage> {
+        let event = match event {
+            Event::ClientMessage(event) if event.format == 8 => event,
+            _ => return None,
+        };
+        let data = event.data.as_data8();
+        if event.type_ == self.atoms._NET_STARTUP_INFO_BEGIN {
+            let _ = self.pending.insert(event.window, Vec::new());
+        } else if event.type_ != self.atoms._NET_STARTUP_INFO {
+            return None;
+        }
+        self.push(event.window, &data)
+    }
+
+    /// Add the data of a client message to the message of a window.
+    fn push(&mut self, window: Window, data: &[u8]) -> Option<StartupMessage> {
+        let buffer = self.pending.get_mut(&window)?;
+        match data.iter().position(|byte| *byte == 0) {
+            Some(end) => {
+                buffer.extend_from_slice(&data[..end]);
+                let text = self.pending.remove(&window)?;
+                StartupMessage::parse(std::str::from_utf8(&text).ok()?).ok()
+            }
+            None => {
+                buffer.extend_from_slice(data);
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{chunks, startup_id_time, MessageKind, StartupAtoms, StartupMessage};
+    use super::{StartupReceiver, CHUNK_SIZE};
+    use crate::protocol::xproto::ClientMessageEvent;
+    use crate::protocol::Event;
+
+    #[test]
+    fn format_and_parse() {
+        let message = StartupMessage::new(MessageKind::New, "launcher-1_TIME42")
+            .with("NAME", "Text \"Editor\"")
+            .with("ICON", "")
+            .with("SCREEN", "0");
+        let text = message.to_text();
+        assert_eq!(
+            text,
+            r#"new: ID=launcher-1_TIME42 NAME="Text \"Editor\"" ICON="" SCREEN=0"#
+        );
+        assert_eq!(StartupMessage::parse(&text), Ok(message.clone()));
+        assert_eq!(message.get("NAME"), Some("Text \"Editor\""));
+        assert_eq!(message.get("ID"), Some("launcher-1_TIME42"));
+        assert_eq!(message.get("BIN"), None);
+
+        let message = StartupMessage::parse(r#"change:  ID=a\ b DESCRIPTION=x"y z""#).unwrap();
+        assert_eq!(message.kind, MessageKind::Change);
+        assert_eq!(message.id, "a b");
+        assert_eq!(message.get("DESCRIPTION"), Some("xy z"));
+
+        assert!(StartupMessage::parse("remove: NAME=foo").is_err());
+        assert!(StartupMessage::parse("delete: ID=foo").is_err());
+        assert!(StartupMessage::parse("remove: ID=\"foo").is_err());
+        assert_eq!(startup_id_time("launcher-1_TIME42"), Some(42));
+    }
+
+    #[test]
+    fn receive_chunks() {
+        let atoms = StartupAtoms {
+            _NET_STARTUP_INFO_BEGIN: 1,
+            _NET_STARTUP_INFO: 2,
+            _NET_STARTUP_ID: 3,
+            UTF8_STRING: 4,
+        };
+        let message =
+            StartupMessage::new(MessageKind::Remove, "a-startup-id-that-needs-three-chunks");
+        let text = message.to_text();
+        let pieces = chunks(&text);
+        assert_eq!(pieces.len(), 3);
+        assert_eq!(pieces.len() * CHUNK_SIZE - text.len(), 13);
+
+        let mut receiver = StartupReceiver::new(atoms);
+        let mut received = None;
+        for (index, chunk) in pieces.into_iter().enumerate() {
+            let type_ = if index == 0 { 1u32 } else { 2u32 };
+            assert!(received.is_none());
+            // Pieces of another message are interleaved
+            let other = Event::ClientMessage(ClientMessageEvent::new(8, 9, 2u32, [b'x'; 20]));
+            assert!(receiver.handle_event(&other).is_none());
+            let event = Event::ClientMessage(ClientMessageEvent::new(8, 5, type_, chunk));
+            received = receiver.handle_event(&event);
+        }
+        assert_eq!(received, Some(message));
+
+        // Exactly 20 bytes need another chunk with the terminating zero byte
+        assert_eq!(chunks("remove: ID=abcdefghi").len(), 2);
+    }
+}
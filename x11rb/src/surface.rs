@@ -0,0 +1,226 @@
+//! Presenting pixels that were rendered on the CPU.
+//!
+//! A [`Surface`] offers a buffer with one `u32` per pixel for a window. After drawing into the
+//! buffer, [`Surface::present`] uploads the parts of the buffer that changed into the window. The
+//! upload uses shared memory when possible and `PutImage` otherwise, see [`ShmImage`].
+//!
+//! The code in this module is only available on Unix when the `image`, `shm` and
+//! `allow-unsafe-code` features of the library are enabled.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use x11rb::protocol::xproto::Rectangle;
+//! use x11rb::surface::Surface;
+//!
+//! let (conn, _) = x11rb::connect(None)?;
+//! # let window = 0;
+//! let mut surface = Surface::new(&conn, window)?;
+//! surface.resize(100, 100)?;
+//! surface.buffer_mut().fill(0x00ff0000);
+//! surface.present(&[Rectangle { x: 0, y: 0, width: 100, height: 100 }])?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::connection::Connection;
+use crate::errors::{ParseError, ReplyError, ReplyOrIdError};
+use crate::image::{BitsPerPixel, ColorComponent, ImageOrder, PixelFormat, PixelLayout};
+use crate::protocol::xproto::{self, Gcontext, Rectangle, Window};
+use crate::shm_image::{clip, ShmImage};
+
+/// A buffer of pixels that can be presented in a window.
+///
+/// The pixels are stored as `0x00RRGGBB`, row by row and without padding. The graphics context
+/// of the surface is freed when this is dropped.
+#[derive(Debug)]
+pub struct Surface<'c, C: Connection> {
+    conn: &'c C,
+    window: Window,
+    gc: Gcontext,
+    depth: u8,
+    format: PixelFormat,
+    buffer: Vec<u32>,
+    image: ShmImage<'c, C>,
+}
+
+impl<'c, C: Connection> Surface<'c, C> {
+    /// Create a surface for a window.
+    ///
+    /// The surface initially has the size of the window. This fails with
+    /// [`ParseError::InvalidValue`] if the visual of the window does not store pixels as 32 bit
+    /// `0x00RRGGBB` values, which is the case for the common 24 and 32 bit visuals.
+    pub fn new(conn: &'c C, window: Window) -> Result<Self, ReplyOrIdError> {
+        let geometry = xproto::get_geometry(conn, window)?.reply()?;
+        let visual = xproto::get_window_attributes(conn, window)?.reply()?.visual;
+        let format = PixelFormat::for_visual(conn.setup(), visual)?;
+        let rgb = PixelLayout::new(
+            ColorComponent::new(8, 16)?,
+            ColorComponent::new(8, 8)?,
+            ColorComponent::new(8, 0)?,
+        );
+        if format.bits_per_pixel() != BitsPerPixel::B32 || format.layout() != Some(rgb) {
+            return Err(ParseError::InvalidValue.into());
+        }
+        let image = ShmImage::new(conn, geometry.width, geometry.height, geometry.depth)?;
+        let gc = conn.generate_id()?;
+        let _ = xproto::create_gc(conn, gc, window, &Default::default())?;
+        let len = usize::from(geometry.width) * usize::from(geometry.height);
+        Ok(Self {
+            conn,
+            window,
+            gc,
+            depth: geometry.depth,
+            format,
+            buffer: vec![0; len],
+            image,
+        })
+    }
+
+    /// Get the window that this surface presents to.
+    pub fn window(&self) -> Window {
+        self.window
+    }
+
+    /// The width in pixels.
+    pub fn width(&self) -> u16 {
+        self.image.width()
+    }
+
+    /// The height in pixels.
+    pub fn height(&self) -> u16 {
+        self.image.height()
+    }
+
+    /// Change the size of the surface.
+    ///
+    /// This should be called when the window was resized, e.g. because of a `ConfigureNotify`
+    /// event. If the size changes, the content of the buffer is lost and all pixels are black.
+    pub fn resize(&mut self, width: u16, height: u16) -> Result<(), ReplyOrIdError> {
+        if (width, height) == (self.width(), self.height()) {
+            return Ok(());
+        }
+        self.image = ShmImage::new(self.conn, width, height, self.depth)?;
+        self.buffer = vec![0; usize::from(width) * usize::from(height)];
+        Ok(())
+    }
+
+    /// Get the pixels of the surface.
+    pub fn buffer(&self) -> &[u32] {
+        &self.buffer
+    }
+
+    /// Get the pixels of the surface for drawing.
+    pub fn buffer_mut(&mut self) -> &mut [u32] {
+        &mut self.buffer
+    }
+
+    /// Show the given parts of the buffer in the window.
+    ///
+    /// The damaged rectangles are clipped to the surface. Pass a single rectangle with the size
+    /// of the surface to present everything. This waits until the X11 server processed the
+    /// upload, so that the buffer can be changed again afterwards.
+    pub fn present(&mut self, damage: &[Rectangle]) -> Result<(), ReplyError> {
+        let (width, height) = (self.width(), self.height());
+        let damage = damage
+            .iter()
+            .filter_map(|area| clip(*area, width, height))
+            .collect::<Vec<_>>();
+        if damage.is_empty() {
+            return Ok(());
+        }
+        let stride = self.format.stride(width);
+        let byte_order = self.format.byte_order();
+        let data = self.image.data_mut();
+        for area in &damage {
+            copy_area(&self.buffer, width, data, stride, byte_order, *area);
+        }
+        self.image.put_areas(self.window, self.gc, &damage)
+    }
+}
+
+impl<C: Connection> Drop for Surface<'_, C> {
+    fn drop(&mut self) {
+        let _ = xproto::free_gc(self.conn, self.gc);
+    }
+}
+
+/// Copy an area of `buffer` into image data with 32 bits per pixel.
+///
+/// The area must be inside of the buffer, which has `width` pixels per row.
+fn copy_area(
+    buffer: &[u32],
+    width: u16,
+    data: &mut [u8],
+    stride: usize,
+    byte_order: ImageOrder,
+    area: Rectangle,
+) {
+    let (x, y) = (area.x as usize, area.y as usize);
+    let (area_width, width) = (usize::from(area.width), usize::from(width));
+    for row in y..y + usize::from(area.height) {
+        let src = &buffer[row * width + x..][..area_width];
+        let dst = &mut data[row * stride + 4 * x..][..4 * area_width];
+        for (dst, pixel) in dst.chunks_exact_mut(4).zip(src) {
+            let bytes = match byte_order {
+                ImageOrder::LsbFirst => pixel.to_le_bytes(),
+                ImageOrder::MsbFirst => pixel.to_be_bytes(),
+            };
+            dst.copy_from_slice(&bytes);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{clip, copy_area};
+    use crate::image::ImageOrder;
+    use crate::protocol::xproto::Rectangle;
+
+    fn rect(x: i16, y: i16, width: u16, height: u16) -> Rectangle {
+        Rectangle {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn clip_damage() {
+        let clip = |x, y, width, height| {
+            clip(rect(x, y, width, height), 4, 4).map(|r| (r.x, r.y, r.width, r.height))
+        };
+        assert_eq!(clip(-2, 1, 5, 10), Some((0, 1, 3, 3)));
+        assert_eq!(clip(1, 1, 2, 2), Some((1, 1, 2, 2)));
+        assert_eq!(clip(4, 0, 2, 2), None);
+        assert_eq!(clip(-3, 0, 3, 2), None);
+    }
+
+    #[test]
+    fn copy_damaged_area() {
+        let buffer = (0..6).map(|i| 0x0011_2200 + i).collect::<Vec<u32>>();
+        // Two rows of three pixels with four bytes of padding per row
+        let mut data = vec![0xff; 2 * 16];
+        copy_area(
+            &buffer,
+            3,
+            &mut data,
+            16,
+            ImageOrder::LsbFirst,
+            rect(1, 1, 2, 1),
+        );
+        let mut expected = vec![0xff; 2 * 16];
+        expected[20..28].copy_from_slice(&[4, 0x22, 0x11, 0, 5, 0x22, 0x11, 0]);
+        assert_eq!(data, expected);
+
+        copy_area(
+            &buffer,
+            3,
+            &mut data,
+            16,
+            ImageOrder::MsbFirst,
+            rect(0, 0, 1, 1),
+        );
+        assert_eq!(data[..4], [0, 0x11, 0x22, 0]);
+    }
+}
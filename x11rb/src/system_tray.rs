@@ -0,0 +1,582 @@
+//! The system tray protocol of freedesktop.org.
+//!
+//! A tray, e.g. in a panel, owns the `_NET_SYSTEM_TRAY_Sn` selection of a screen. Applications
+//! dock their icon windows into the tray with a `_NET_SYSTEM_TRAY_OPCODE` message, and the tray
+//! embeds them with the XEmbed protocol. Icons can also ask the tray to show balloon messages.
+//!
+//! [`TrayManager`] implements the side of the tray and [`TrayClient`] the side of an application
+//! with an icon.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use x11rb::connection::Connection;
+//! use x11rb::system_tray::{Orientation, TrayEvent, TrayManager};
+//!
+//! let (conn, screen_num) = x11rb::connect(None)?;
+//! # let panel = 0;
+//! let time = conn.fetch_server_time(screen_num)?;
+//! let mut tray = TrayManager::new(&conn, screen_num, time, Orientation::Horizontal)?
+//!     .expect("Another system tray is running");
+//! let mut x = 0;
+//! loop {
+//!     let event = conn.wait_for_event()?;
+//!     match tray.handle_event(&event)? {
+//!         Some(TrayEvent::DockRequest(icon)) => {
+//!             tray.embed(icon, panel, x, 0)?;
+//!             x += 24;
+//!         }
+//!         Some(TrayEvent::Message { text, .. }) => println!("{}", text),
+//!         Some(TrayEvent::Replaced) => break,
+//!         _ => {}
+//!     }
+//!     conn.flush()?;
+//! }
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::collections::HashMap;
+
+use crate::connection::Connection;
+use crate::errors::{ConnectionError, ReplyError, ReplyOrIdError};
+use crate::protocol::xproto::{
+    self, Atom, AtomEnum, ChangeWindowAttributesAux, ClientMessageEvent, CreateWindowAux,
+    EventMask, PropMode, Timestamp, Visualid, Window, WindowClass,
+};
+use crate::protocol::Event;
+use crate::wrapper::ConnectionExt as _;
+use crate::xembed::{SiteEvent, XEmbedAtoms, XEmbedClient, XEmbedSite};
+use crate::{COPY_DEPTH_FROM_PARENT, CURRENT_TIME, NONE};
+
+pub use atoms::{TrayAtoms, TrayAtomsCookie};
+
+mod atoms {
+    // The code that the macro generates has no documentation and a `new` function that returns a
+    // cookie
+    #![allow(missing_docs, clippy::new_ret_no_self)]
+
+    crate::atom_manager! {
+        /// The atoms of the system tray protocol.
+        pub TrayAtoms:
+        /// A cookie for interning the atoms of the system tray protocol.
+        TrayAtomsCookie {
+            MANAGER,
+            _NET_SYSTEM_TRAY_OPCODE,
+            _NET_SYSTEM_TRAY_MESSAGE_DATA,
+            _NET_SYSTEM_TRAY_ORIENTATION,
+            _NET_SYSTEM_TRAY_VISUAL,
+            _XEMBED,
+            _XEMBED_INFO,
+        }
+    }
+}
+
+/// The opcode for docking an icon.
+const SYSTEM_TRAY_REQUEST_DOCK: u32 = 0;
+
+/// The opcode for starting a balloon message.
+const SYSTEM_TRAY_BEGIN_MESSAGE: u32 = 1;
+
+/// The opcode for cancelling a balloon message.
+const SYSTEM_TRAY_CANCEL_MESSAGE: u32 = 2;
+
+/// The number of bytes of a balloon message in one client message.
+const CHUNK_SIZE: usize = 20;
+
+/// The direction in which a tray arranges its icons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    /// The icons are placed next to each other.
+    Horizontal,
+    /// The icons are placed below each other.
+    Vertical,
+}
+
+/// Get the atoms of the XEmbed protocol, which are interned with the atoms of the tray.
+fn xembed_atoms(atoms: &TrayAtoms) -> XEmbedAtoms {
+    XEmbedAtoms {
+        _XEMBED: atoms._XEMBED,
+        _XEMBED_INFO: atoms._XEMBED_INFO,
+    }
+}
+
+/// Intern the selection atom of the tray of a screen.
+fn intern_selection<C: Connection>(conn: &C, screen: usize) -> Result<Atom, ReplyError> {
+    let name = format!("_NET_SYSTEM_TRAY_S{}", screen);
+    Ok(xproto::intern_atom(conn, false, name.as_bytes())?
+        .reply()?
+        .atom)
+}
+
+/// An event of the system tray protocol that the tray has to handle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrayEvent {
+    /// An application wants to dock an icon window, see [`TrayManager::embed`].
+    DockRequest(Window),
+
+    /// An icon wants to show a balloon message.
+    Message {
+        /// The icon that sent the message.
+        icon: Window,
+        /// The id of the message, which is unique for the icon.
+        id: u32,
+        /// How long the message should be shown in milliseconds, or zero for no limit.
+        timeout: u32,
+        /// The text of the message.
+        text: String,
+    },
+
+    /// An icon wants a balloon message to disappear.
+    CancelMessage {
+        /// The icon that sent the message.
+        icon: Window,
+        /// The id of the message.
+        id: u32,
+    },
+
+    /// An embedded icon was destroyed or left the tray.
+    IconRemoved(Window),
+
+    /// Another tray acquired the tray selection.
+    ///
+    /// The tray should stop managing icons, e.g. by dropping the [`TrayManager`].
+    Replaced,
+}
+
+/// A balloon message whose text was not received completely yet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PendingMessage {
+    id: u32,
+    timeout: u32,
+    length: usize,
+    data: Vec<u8>,
+}
+
+impl PendingMessage {
+    /// Add a piece of the text and check whether the text is complete.
+    fn push(&mut self, data: &[u8]) -> bool {
+        let missing = self.length.saturating_sub(self.data.len());
+        self.data
+            .extend_from_slice(&data[..missing.min(data.len())]);
+        self.is_complete()
+    }
+
+    fn is_complete(&self) -> bool {
+        self.data.len() >= self.length
+    }
+
+    fn into_event(self, icon: Window) -> TrayEvent {
+        TrayEvent::Message {
+            icon,
+            id: self.id,
+            timeout: self.timeout,
+            text: String::from_utf8_lossy(&self.data).into_owned(),
+        }
+    }
+}
+
+/// A system tray that owns the tray selection of a screen.
+///
+/// The selection is released and embedded icons are moved back to the root window when this is
+/// dropped.
+#[derive(Debug)]
+pub struct TrayManager<'c, C: Connection> {
+    conn: &'c C,
+    window: Window,
+    selection: Atom,
+    atoms: TrayAtoms,
+    icons: HashMap<Window, XEmbedSite<'c, C>>,
+    messages: HashMap<Window, PendingMessage>,
+}
+
+impl<'c, C: Connection> TrayManager<'c, C> {
+    /// Become the system tray of a screen.
+    ///
+    /// `time` must be a recent server time, see the [`server_time`](crate::server_time) module.
+    /// Returns `None` if another tray owns the selection.
+    pub fn new(
+        conn: &'c C,
+        screen: usize,
+        time: Timestamp,
+        orientation: Orientation,
+    ) -> Result<Option<Self>, ReplyOrIdError> {
+        let root = conn.setup().roots[screen].root;
+        let atoms = TrayAtoms::new(conn)?;
+        let selection = intern_selection(conn, screen)?;
+        let atoms = atoms.reply()?;
+        if xproto::get_selection_owner(conn, selection)?.reply()?.owner != NONE {
+            return Ok(None);
+        }
+
+        let tray = Self {
+            conn,
+            window: conn.generate_id()?,
+            selection,
+            atoms,
+            icons: HashMap::new(),
+            messages: HashMap::new(),
+        };
+        let aux = CreateWindowAux::new()
+            .override_redirect(1)
+            .event_mask(EventMask::STRUCTURE_NOTIFY);
+        let _ = xproto::create_window(
+            conn,
+            COPY_DEPTH_FROM_PARENT,
+            tray.window,
+            root,
+            -1,
+            -1,
+            1,
+            1,
+            0,
+            WindowClass::INPUT_ONLY,
+            0,
+            &aux,
+        )?;
+        let orientation = match orientation {
+            Orientation::Horizontal => 0,
+            Orientation::Vertical => 1,
+        };
+        let _ = conn.change_property32(
+            PropMode::REPLACE,
+            tray.window,
+            atoms._NET_SYSTEM_TRAY_ORIENTATION,
+            AtomEnum::CARDINAL,
+            &[orientation],
+        )?;
+        let _ = xproto::set_selection_owner(conn, tray.window, selection, time)?;
+        if xproto::get_selection_owner(conn, selection)?.reply()?.owner != tray.window {
+            return Ok(None);
+        }
+        let data = [time, selection, tray.window, 0, 0];
+        let event = ClientMessageEvent::new(32, root, atoms.MANAGER, data);
+        let _ = xproto::send_event(conn, false, root, EventMask::STRUCTURE_NOTIFY, event)?;
+        Ok(Some(tray))
+    }
+
+    /// Get the window that owns the tray selection.
+    pub fn window(&self) -> Window {
+        self.window
+    }
+
+    /// Get the atoms of the system tray protocol.
+    pub fn atoms(&self) -> &TrayAtoms {
+        &self.atoms
+    }
+
+    /// Tell icons which visual they should use, e.g. a 32 bit ARGB visual for transparency.
+    pub fn set_visual(&self, visual: Visualid) -> Result<(), ConnectionError> {
+        let _ = self.conn.change_property32(
+            PropMode::REPLACE,
+            self.window,
+            self.atoms._NET_SYSTEM_TRAY_VISUAL,
+            AtomEnum::VISUALID,
+            &[visual],
+        )?;
+        Ok(())
+    }
+
+    /// Iterate over the embedded icons.
+    pub fn icons(&self) -> impl Iterator<Item = Window> + '_ {
+        self.icons.keys().copied()
+    }
+
+    /// Embed an icon into `parent` at the given position.
+    ///
+    /// The icon is embedded with the XEmbed protocol, see [`XEmbedSite::embed`].
+    pub fn embed(
+        &mut self,
+        icon: Window,
+        parent: Window,
+        x: i16,
+        y: i16,
+    ) -> Result<(), ReplyError> {
+        let atoms = xembed_atoms(&self.atoms);
+        let site = XEmbedSite::embed(self.conn, &atoms, parent, icon, (x, y), CURRENT_TIME)?;
+        let _ = self.icons.insert(icon, site);
+        Ok(())
+    }
+
+    /// Move an icon out of the tray to the root window.
+    pub fn unembed(&mut self, icon: Window) -> Result<(), ConnectionError> {
+        let _ = self.messages.remove(&icon);
+        match self.icons.remove(&icon) {
+            Some(site) => site.unembed(),
+            None => Ok(()),
+        }
+    }
+
+    /// Handle an event and get the request of an icon that it contains.
+    ///
+    /// Icons are mapped and unmapped as their `_XEMBED_INFO` says. Events that do not concern the
+    /// tray return `None`.
+    pub fn handle_event(&mut self, event: &Event) -> Result<Option<TrayEvent>, ReplyError> {
+        let event = match event {
+            Event::ClientMessage(event) => event,
+            Event::DestroyNotify(xproto::DestroyNotifyEvent { window, .. })
+            | Event::ReparentNotify(xproto::ReparentNotifyEvent { window, .. })
+            | Event::PropertyNotify(xproto::PropertyNotifyEvent { window, .. }) => {
+                let site = match self.icons.get_mut(window) {
+                    Some(site) => site,
+                    None => return Ok(None),
+                };
+                if site.handle_event(event)? == Some(SiteEvent::Unembedded) {
+                    return Ok(self.forget(*window));
+                }
+                return Ok(None);
+            }
+            Event::SelectionClear(event)
+                if event.owner == self.window && event.selection == self.selection =>
+            {
+                return Ok(Some(TrayEvent::Replaced));
+            }
+            _ => return Ok(None),
+        };
+        if event.type_ == self.atoms._NET_SYSTEM_TRAY_MESSAGE_DATA && event.format == 8 {
+            let icon = event.window;
+            let complete = match self.messages.get_mut(&icon) {
+                Some(message) => message.push(&event.data.as_data8()),
+                None => false,
+            };
+            return Ok(if complete {
+                self.messages.remove(&icon).map(|m| m.into_event(icon))
+            } else {
+                None
+            });
+        }
+        if event.type_ != self.atoms._NET_SYSTEM_TRAY_OPCODE || event.format != 32 {
+            return Ok(None);
+        }
+        let data = event.data.as_data32();
+        let icon = event.window;
+        let request = match data[1] {
+            SYSTEM_TRAY_REQUEST_DOCK => TrayEvent::DockRequest(data[2]),
+            SYSTEM_TRAY_BEGIN_MESSAGE => {
+                let message = PendingMessage {
+                    timeout: data[2],
+                    length: usize::try_from(data[3]).unwrap_or(usize::MAX),
+                    id: data[4],
+                    data: Vec::new(),
+                };
+                if !message.is_complete() {
+                    let _ = self.messages.insert(icon, message);
+                    return Ok(None);
+                }
+                message.into_event(icon)
+            }
+            SYSTEM_TRAY_CANCEL_MESSAGE => TrayEvent::CancelMessage { icon, id: data[2] },
+            _ => return Ok(None),
+        };
+        Ok(Some(request))
+    }
+
+    /// Remove an icon that left the tray.
+    fn forget(&mut self, icon: Window) -> Option<TrayEvent> {
+        let _ = self.icons.remove(&icon)?;
+        let _ = self.messages.remove(&icon);
+        Some(TrayEvent::IconRemoved(icon))
+    }
+}
+
+impl<C: Connection> Drop for TrayManager<'_, C> {
+    fn drop(&mut self) {
+        let icons = self.icons().collect::<Vec<_>>();
+        for icon in icons {
+            let _ = self.unembed(icon);
+        }
+        let _ = xproto::destroy_window(self.conn, self.window);
+    }
+}
+
+/// Split the text of a balloon message into the data of client messages.
+fn chunks(text: &str) -> impl Iterator<Item = [u8; CHUNK_SIZE]> + '_ {
+    text.as_bytes().chunks(CHUNK_SIZE).map(|bytes| {
+        let mut chunk = [0; CHUNK_SIZE];
+        chunk[..bytes.len()].copy_from_slice(bytes);
+        chunk
+    })
+}
+
+/// The side of an application with an icon in the system tray.
+///
+/// When a tray appears later or is replaced, the icon is docked into the new tray by
+/// [`TrayClient::handle_event`].
+#[derive(Debug)]
+pub struct TrayClient<'c, C: Connection> {
+    conn: &'c C,
+    root: Window,
+    icon: Window,
+    selection: Atom,
+    atoms: TrayAtoms,
+    embedded: XEmbedClient<'c, C>,
+    manager: Option<Window>,
+}
+
+impl<'c, C: Connection> TrayClient<'c, C> {
+    /// Dock an icon window into the system tray of a screen.
+    ///
+    /// `StructureNotify` is selected on the root window to notice new trays. The icon is docked
+    /// as soon as there is a tray.
+    /// `StructureNotify` should be selected on the icon to notice when it leaves a tray.
+    pub fn new(conn: &'c C, screen: usize, icon: Window) -> Result<Self, ReplyError> {
+        let root = conn.setup().roots[screen].root;
+        let atoms = TrayAtoms::new(conn)?;
+        let selection = intern_selection(conn, screen)?;
+        let atoms = atoms.reply()?;
+        let embedded = XEmbedClient::new(conn, &xembed_atoms(&atoms), icon, true)?;
+        let aux = ChangeWindowAttributesAux::new().event_mask(EventMask::STRUCTURE_NOTIFY);
+        let _ = xproto::change_window_attributes(conn, root, &aux)?;
+        let mut client = Self {
+            conn,
+            root,
+            icon,
+            selection,
+            atoms,
+            embedded,
+            manager: None,
+        };
+        let owner = xproto::get_selection_owner(conn, selection)?.reply()?.owner;
+        client.dock(owner)?;
+        Ok(client)
+    }
+
+    /// Get the state of the XEmbed protocol of the icon, e.g. whether it has the focus.
+    pub fn embedded(&self) -> &XEmbedClient<'c, C> {
+        &self.embedded
+    }
+
+    /// Get the window of the current tray, if there is one.
+    pub fn manager(&self) -> Option<Window> {
+        self.manager
+    }
+
+    /// Get the orientation of the current tray.
+    pub fn orientation(&self) -> Result<Option<Orientation>, ReplyError> {
+        let manager = match self.manager {
+            Some(manager) => manager,
+            None => return Ok(None),
+        };
+        let reply = xproto::get_property(
+            self.conn,
+            false,
+            manager,
+            self.atoms._NET_SYSTEM_TRAY_ORIENTATION,
+            AtomEnum::CARDINAL,
+            0,
+            1,
+        )?
+        .reply()?;
+        Ok(match reply.value32().and_then(|mut values| values.next()) {
+            Some(0) => Some(Orientation::Horizontal),
+            Some(1) => Some(Orientation::Vertical),
+            _ => None,
+        })
+    }
+
+    /// Ask the tray to show a balloon message.
+    ///
+    /// `id` identifies the message for [`TrayClient::cancel_message`]. `timeout` is in
+    /// milliseconds, or zero for no limit. Returns `false` if there is no tray.
+    pub fn send_message(&self, id: u32, timeout: u32, text: &str) -> Result<bool, ConnectionError> {
+        let manager = match self.manager {
+            Some(manager) => manager,
+            None => return Ok(false),
+        };
+        let length = u32::try_from(text.len()).unwrap_or(u32::MAX);
+        self.send_opcode(manager, [SYSTEM_TRAY_BEGIN_MESSAGE, timeout, length, id])?;
+        for chunk in chunks(text) {
+            let event = ClientMessageEvent::new(
+                8,
+                self.icon,
+                self.atoms._NET_SYSTEM_TRAY_MESSAGE_DATA,
+                chunk,
+            );
+            let _ = xproto::send_event(self.conn, false, manager, EventMask::NO_EVENT, event)?;
+        }
+        Ok(true)
+    }
+
+    /// Ask the tray to remove a balloon message.
+    pub fn cancel_message(&self, id: u32) -> Result<(), ConnectionError> {
+        if let Some(manager) = self.manager {
+            self.send_opcode(manager, [SYSTEM_TRAY_CANCEL_MESSAGE, id, 0, 0])?;
+        }
+        Ok(())
+    }
+
+    /// Handle the appearance and disappearance of trays and the messages of the XEmbed protocol.
+    ///
+    /// Returns `true` if the icon was docked into a new tray.
+    pub fn handle_event(&mut self, event: &Event) -> Result<bool, ConnectionError> {
+        let _ = self.embedded.handle_event(event);
+        match event {
+            Event::ClientMessage(event)
+                if event.window == self.root
+                    && event.type_ == self.atoms.MANAGER
+                    && event.data.as_data32()[1] == self.selection =>
+            {
+                self.dock(event.data.as_data32()[2])?;
+                Ok(true)
+            }
+            Event::DestroyNotify(event) if Some(event.window) == self.manager => {
+                self.manager = None;
+                Ok(false)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// Send a dock request to a tray.
+    fn dock(&mut self, manager: Window) -> Result<(), ConnectionError> {
+        if manager == NONE {
+            return Ok(());
+        }
+        // Notice when the tray goes away
+        let aux = ChangeWindowAttributesAux::new().event_mask(EventMask::STRUCTURE_NOTIFY);
+        let _ = xproto::change_window_attributes(self.conn, manager, &aux)?;
+        self.send_opcode(manager, [SYSTEM_TRAY_REQUEST_DOCK, self.icon, 0, 0])?;
+        self.manager = Some(manager);
+        Ok(())
+    }
+
+    /// Send a `_NET_SYSTEM_TRAY_OPCODE` message to a tray.
+    fn send_opcode(&self, manager: Window, data: [u32; 4]) -> Result<(), ConnectionError> {
+        let [opcode, a, b, c] = data;
+        let data = [CURRENT_TIME, opcode, a, b, c];
+        let event =
+            ClientMessageEvent::new(32, self.icon, self.atoms._NET_SYSTEM_TRAY_OPCODE, data);
+        let _ = xproto::send_event(self.conn, false, manager, EventMask::NO_EVENT, event)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{chunks, PendingMessage, TrayEvent};
+
+    #[test]
+    fn balloon_message() {
+        let text = "A message that needs two chunks";
+        let mut message = PendingMessage {
+            id: 7,
+            timeout: 1000,
+            length: text.len(),
+            data: Vec::new(),
+        };
+        let mut pieces = chunks(text).collect::<Vec<_>>();
+        assert_eq!(pieces.len(), 2);
+        assert_eq!(&pieces[1][..11], b" two chunks");
+        assert_eq!(pieces[1][11..], [0; 9]);
+        assert!(!message.push(&pieces.remove(0)));
+        assert!(message.push(&pieces.remove(0)));
+        assert_eq!(
+            message.into_event(5),
+            TrayEvent::Message {
+                icon: 5,
+                id: 7,
+                timeout: 1000,
+                text: text.to_string(),
+            }
+        );
+    }
+}
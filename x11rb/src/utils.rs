@@ -10,6 +10,14 @@
 
 pub use x11rb_protocol::RawFdContainer;
 
+#[cfg(not(target_arch = "wasm32"))]
+use {
+    rustix::event::{poll, PollFd, PollFlags},
+    rustix::fd::BorrowedFd,
+    rustix::io::Errno,
+    std::time::Instant,
+};
+
 #[cfg(feature = "allow-unsafe-code")]
 mod unsafe_code {
     use std::mem::forget;
@@ -97,3 +105,29 @@ mod unsafe_code {
 
 #[cfg(feature = "allow-unsafe-code")]
 pub use unsafe_code::CSlice;
+
+/// Wait until `fd` is ready for `flags` or the deadline passed.
+///
+/// Returns `false` if the deadline passed. A deadline of `None` means to wait forever. When the
+/// wait is interrupted by a signal, it is resumed until the deadline.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn poll_until(
+    fd: BorrowedFd<'_>,
+    flags: PollFlags,
+    deadline: Option<Instant>,
+) -> std::io::Result<bool> {
+    let mut poll_fds = [PollFd::from_borrowed_fd(fd, flags)];
+    loop {
+        // Round up to whole milliseconds so that we do not wake up too early
+        let timeout = deadline.map_or(-1, |deadline| {
+            let timeout = deadline.saturating_duration_since(Instant::now());
+            i32::try_from((timeout.as_nanos() + 999_999) / 1_000_000).unwrap_or(i32::MAX)
+        });
+        match poll(&mut poll_fds, timeout) {
+            // Let the errors (POLLERR) be handled when trying to read or write.
+            Ok(n) => return Ok(n != 0),
+            Err(Errno::INTR) => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
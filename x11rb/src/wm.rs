@@ -0,0 +1,705 @@
+//! Building blocks for window managers.
+//!
+//! A [`WindowManager`] takes over a screen as described in ICCCM §2.8: It acquires the `WM_Sn`
+//! manager selection, announces itself with a `MANAGER` message and selects
+//! `SubstructureRedirect` on the root window. Afterwards, top-level windows are not mapped or
+//! configured by the X11 server anymore. Instead, [`WindowManager::handle_event`] turns the
+//! redirected requests into [`ClientRequest`]s that the window manager decides about.
+//!
+//! Managed windows are reparented into a frame window that the window manager can use for
+//! decorations. The client is added to the save-set, so that it survives if the window manager
+//! exits without cleaning up.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use x11rb::connection::Connection;
+//! use x11rb::ewmh::FrameExtents;
+//! use x11rb::wm::{ClientRequest, WindowManager};
+//!
+//! let (conn, screen_num) = x11rb::connect(None)?;
+//! let time = conn.fetch_server_time(screen_num)?;
+//! let mut wm = WindowManager::new(&conn, screen_num, time, false)?
+//!     .expect("Another window manager is running");
+//! let borders = FrameExtents { left: 2, right: 2, top: 20, bottom: 2 };
+//! let _ = wm.manage_existing(borders)?;
+//! conn.flush()?;
+//! loop {
+//!     let event = conn.wait_for_event()?;
+//!     match wm.handle_event(&event)? {
+//!         Some(ClientRequest::Map(window)) => {
+//!             let _ = wm.manage(window, borders)?;
+//!         }
+//!         Some(ClientRequest::Configure { window, changes }) => {
+//!             wm.apply_configure(window, &changes)?;
+//!         }
+//!         Some(ClientRequest::Replaced) => break,
+//!         _ => {}
+//!     }
+//!     conn.flush()?;
+//! }
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::connection::Connection;
+use crate::errors::{ConnectionError, ReplyError, ReplyOrIdError};
+use crate::ewmh::FrameExtents;
+use crate::properties::{IcccmAtoms, WindowState, WmState};
+use crate::protocol::xproto::{
+    self, Atom, ChangeWindowAttributesAux, ClientMessageEvent, ConfigWindow, ConfigureNotifyEvent,
+    ConfigureRequestEvent, ConfigureWindowAux, CreateWindowAux, EventMask, MapState, Place,
+    Rectangle, SetMode, Timestamp, Window, WindowClass, CONFIGURE_NOTIFY_EVENT,
+};
+use crate::protocol::{ErrorKind, Event};
+use crate::{COPY_DEPTH_FROM_PARENT, NONE};
+
+/// How long to wait for a previous window manager to exit.
+const REPLACE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A request of a client window that the window manager has to decide about.
+#[derive(Debug, Clone, Copy)]
+pub enum ClientRequest {
+    /// A window wants to be mapped.
+    ///
+    /// For windows that are not managed yet, this is the time to call [`WindowManager::manage`].
+    /// Iconified clients use this to get restored, see [`WindowManager::restore`].
+    Map(Window),
+
+    /// A window wants to change its geometry or stacking order.
+    ///
+    /// `changes` contains only the values that the client asked for. The position and size refer
+    /// to the client window and not to its frame. Use [`WindowManager::apply_configure`] to grant
+    /// the request.
+    Configure {
+        /// The window that wants to be changed.
+        window: Window,
+        /// The requested changes.
+        changes: ConfigureWindowAux,
+    },
+
+    /// A window wants to be raised or lowered.
+    Circulate {
+        /// The window that wants to be restacked.
+        window: Window,
+        /// Whether the window wants to be on top or at the bottom.
+        place: Place,
+    },
+
+    /// A managed client asked to be iconified with a `WM_CHANGE_STATE` message.
+    Iconify(Window),
+
+    /// A managed client unmapped itself and is no longer managed.
+    Withdrawn(Window),
+
+    /// A managed client was destroyed and is no longer managed.
+    Destroyed(Window),
+
+    /// Another window manager acquired the manager selection.
+    ///
+    /// The window manager should stop managing the screen, e.g. by dropping the
+    /// [`WindowManager`].
+    Replaced,
+}
+
+/// A top-level window that is managed by a [`WindowManager`].
+#[derive(Debug, Clone, Copy)]
+pub struct Client {
+    window: Window,
+    frame: Window,
+    extents: FrameExtents,
+    geometry: Rectangle,
+    border_width: u16,
+    iconic: bool,
+    ignore_unmaps: u32,
+}
+
+impl Client {
+    /// Get the client window.
+    pub fn window(&self) -> Window {
+        self.window
+    }
+
+    /// Get the frame window that contains the client window.
+    pub fn frame(&self) -> Window {
+        self.frame
+    }
+
+    /// Get the size of the frame around the client window.
+    pub fn extents(&self) -> FrameExtents {
+        self.extents
+    }
+
+    /// Get the position of the frame and the size of the client window.
+    ///
+    /// This is the geometry that the client sees in synthetic `ConfigureNotify` events, except
+    /// that those contain the position of the client window itself.
+    pub fn geometry(&self) -> Rectangle {
+        self.geometry
+    }
+
+    /// Get the position and size of the frame window.
+    pub fn frame_geometry(&self) -> Rectangle {
+        frame_geometry(self.geometry, self.extents)
+    }
+
+    /// Check whether the client is iconified.
+    pub fn is_iconic(&self) -> bool {
+        self.iconic
+    }
+}
+
+/// A window manager that manages the top-level windows of one screen.
+///
+/// The frames of all managed clients are removed and the manager selection is released when
+/// this is dropped.
+#[derive(Debug)]
+pub struct WindowManager<'c, C: Connection> {
+    conn: &'c C,
+    root: Window,
+    window: Window,
+    selection: Atom,
+    atoms: IcccmAtoms,
+    clients: HashMap<Window, Client>,
+    frames: HashMap<Window, Window>,
+}
+
+impl<'c, C: Connection> WindowManager<'c, C> {
+    /// Become the window manager of a screen.
+    ///
+    /// `time` must be a recent server time, see the [`server_time`](crate::server_time) module.
+    /// Returns `None` if another window manager owns the `WM_Sn` selection of the screen and
+    /// `replace` is `false`. Otherwise, the selection is taken from the other window manager and
+    /// this waits until it released the root window. If another window manager that does not
+    /// own the selection is running, this fails with an `Access` error.
+    pub fn new(
+        conn: &'c C,
+        screen: usize,
+        time: Timestamp,
+        replace: bool,
+    ) -> Result<Option<Self>, ReplyOrIdError> {
+        let root = conn.setup().roots[screen].root;
+        let selection = format!("WM_S{}", screen);
+        let selection = xproto::intern_atom(conn, false, selection.as_bytes())?;
+        let manager = xproto::intern_atom(conn, false, b"MANAGER")?;
+        let atoms = IcccmAtoms::new(conn)?;
+        let selection = selection.reply()?.atom;
+        let manager = manager.reply()?.atom;
+        let atoms = atoms.reply()?;
+
+        let previous = xproto::get_selection_owner(conn, selection)?.reply()?.owner;
+        if previous != NONE && !replace {
+            return Ok(None);
+        }
+
+        let wm = Self {
+            conn,
+            root,
+            window: conn.generate_id()?,
+            selection,
+            atoms,
+            clients: HashMap::new(),
+            frames: HashMap::new(),
+        };
+        let aux = CreateWindowAux::new().override_redirect(1);
+        let _ = xproto::create_window(
+            conn,
+            COPY_DEPTH_FROM_PARENT,
+            wm.window,
+            root,
+            -1,
+            -1,
+            1,
+            1,
+            0,
+            WindowClass::INPUT_ONLY,
+            0,
+            &aux,
+        )?;
+        let _ = xproto::set_selection_owner(conn, wm.window, selection, time)?;
+        let owner = xproto::get_selection_owner(conn, selection)?.reply()?.owner;
+        if owner != wm.window {
+            return Ok(None);
+        }
+        let event = ClientMessageEvent::new(32, root, manager, [time, selection, wm.window, 0, 0]);
+        let _ = xproto::send_event(conn, false, root, EventMask::STRUCTURE_NOTIFY, event)?;
+
+        // The previous window manager releases SubstructureRedirect when it exits
+        let start = Instant::now();
+        let aux = ChangeWindowAttributesAux::new().event_mask(root_event_mask());
+        loop {
+            match xproto::change_window_attributes(conn, root, &aux)?.check() {
+                Ok(()) => break,
+                Err(ReplyError::X11Error(err))
+                    if err.error_kind == ErrorKind::Access
+                        && previous != NONE
+                        && start.elapsed() < REPLACE_TIMEOUT =>
+                {
+                    std::thread::sleep(Duration::from_millis(10));
+                }
+                Err(err) => return Err(err.into()),
+            }
+        }
+        Ok(Some(wm))
+    }
+
+    /// Get the root window of the managed screen.
+    pub fn root(&self) -> Window {
+        self.root
+    }
+
+    /// Get the window that owns the manager selection.
+    pub fn window(&self) -> Window {
+        self.window
+    }
+
+    /// Get the atoms of the ICCCM that were interned for managing windows.
+    pub fn atoms(&self) -> &IcccmAtoms {
+        &self.atoms
+    }
+
+    /// Select additional events on the root window, e.g. `PropertyChange`.
+    ///
+    /// The events that the window manager needs stay selected.
+    pub fn select_root_input(&self, events: EventMask) -> Result<(), ConnectionError> {
+        let aux = ChangeWindowAttributesAux::new().event_mask(root_event_mask() | events);
+        let _ = xproto::change_window_attributes(self.conn, self.root, &aux)?;
+        Ok(())
+    }
+
+    /// Get a managed client by its client window.
+    pub fn client(&self, window: Window) -> Option<&Client> {
+        self.clients.get(&window)
+    }
+
+    /// Get a managed client by its client window or its frame window.
+    pub fn find_client(&self, window: Window) -> Option<&Client> {
+        let window = self.frames.get(&window).unwrap_or(&window);
+        self.clients.get(window)
+    }
+
+    /// Iterate over all managed clients.
+    pub fn clients(&self) -> impl Iterator<Item = &Client> {
+        self.clients.values()
+    }
+
+    /// Manage all mapped top-level windows that already exist.
+    ///
+    /// This should be called once after creating the window manager. Windows with
+    /// `override_redirect` are ignored. Returns the windows that are now managed.
+    pub fn manage_existing(
+        &mut self,
+        extents: FrameExtents,
+    ) -> Result<Vec<Window>, ReplyOrIdError> {
+        let children = xproto::query_tree(self.conn, self.root)?.reply()?.children;
+        let cookies = children
+            .iter()
+            .filter(|window| **window != self.window)
+            .map(|window| Ok((*window, xproto::get_window_attributes(self.conn, *window)?)))
+            .collect::<Result<Vec<_>, ConnectionError>>()?;
+        let mut managed = Vec::new();
+        for (window, cookie) in cookies {
+            let attributes = match cookie.reply() {
+                Ok(attributes) => attributes,
+                // The window was destroyed in the meantime
+                Err(ReplyError::X11Error(_)) => continue,
+                Err(err) => return Err(err.into()),
+            };
+            if !attributes.override_redirect && attributes.map_state == MapState::VIEWABLE {
+                let _ = self.manage(window, extents)?;
+                managed.push(window);
+            }
+        }
+        Ok(managed)
+    }
+
+    /// Start managing a window.
+    ///
+    /// A frame window is created at the position of the window with room for `extents` around
+    /// it, and the window is reparented into the frame. Both are mapped and the `WM_STATE` of the
+    /// window is set to `NormalState`. Nothing happens if the window is managed already.
+    pub fn manage(
+        &mut self,
+        window: Window,
+        extents: FrameExtents,
+    ) -> Result<&Client, ReplyOrIdError> {
+        if self.clients.contains_key(&window) {
+            return Ok(&self.clients[&window]);
+        }
+        let geometry = xproto::get_geometry(self.conn, window)?;
+        let attributes = xproto::get_window_attributes(self.conn, window)?;
+        let geometry = geometry.reply()?;
+        let viewable = attributes.reply()?.map_state == MapState::VIEWABLE;
+
+        let client = Client {
+            window,
+            frame: self.conn.generate_id()?,
+            extents,
+            geometry: Rectangle {
+                x: geometry.x,
+                y: geometry.y,
+                width: geometry.width,
+                height: geometry.height,
+            },
+            border_width: geometry.border_width,
+            iconic: false,
+            // Reparenting a mapped window unmaps it
+            ignore_unmaps: u32::from(viewable),
+        };
+        let frame = client.frame_geometry();
+        let aux = CreateWindowAux::new().event_mask(frame_event_mask());
+        let _ = xproto::create_window(
+            self.conn,
+            COPY_DEPTH_FROM_PARENT,
+            client.frame,
+            self.root,
+            frame.x,
+            frame.y,
+            frame.width,
+            frame.height,
+            0,
+            WindowClass::INPUT_OUTPUT,
+            0,
+            &aux,
+        )?;
+        let _ = xproto::change_save_set(self.conn, SetMode::INSERT, window)?;
+        let aux = ConfigureWindowAux::new().border_width(0);
+        let _ = xproto::configure_window(self.conn, window, &aux)?;
+        let (x, y) = client_offset(extents);
+        let _ = xproto::reparent_window(self.conn, window, client.frame, x, y)?;
+        let _ = xproto::map_window(self.conn, window)?;
+        let _ = xproto::map_window(self.conn, client.frame)?;
+        self.set_state(window, WindowState::Normal)?;
+
+        let _ = self.frames.insert(client.frame, window);
+        Ok(self.clients.entry(window).or_insert(client))
+    }
+
+    /// Stop managing a window.
+    ///
+    /// The window is reparented back to the root window at the position of its frame and the
+    /// frame is destroyed. Returns `None` if the window was not managed.
+    pub fn unmanage(&mut self, window: Window) -> Result<Option<Client>, ConnectionError> {
+        let client = match self.forget(window) {
+            Some(client) => client,
+            None => return Ok(None),
+        };
+        let conn = self.conn;
+        let frame = client.frame_geometry();
+        let _ = xproto::unmap_window(conn, client.frame)?;
+        let _ = xproto::reparent_window(conn, window, self.root, frame.x, frame.y)?;
+        let aux = ConfigureWindowAux::new().border_width(u32::from(client.border_width));
+        let _ = xproto::configure_window(conn, window, &aux)?;
+        let _ = xproto::change_save_set(conn, SetMode::DELETE, window)?;
+        let _ = xproto::destroy_window(conn, client.frame)?;
+        Ok(Some(client))
+    }
+
+    /// Move and resize a managed client.
+    ///
+    /// `geometry` contains the position of the frame and the size of the client window. The
+    /// client is informed about its new geometry with a synthetic `ConfigureNotify` event as
+    /// required by ICCCM §4.1.5.
+    pub fn move_resize(
+        &mut self,
+        window: Window,
+        geometry: Rectangle,
+    ) -> Result<(), ConnectionError> {
+        let client = match self.clients.get_mut(&window) {
+            Some(client) => client,
+            None => return Ok(()),
+        };
+        client.geometry = geometry;
+        let frame = client.frame_geometry();
+        let aux = ConfigureWindowAux::new()
+            .x(i32::from(frame.x))
+            .y(i32::from(frame.y))
+            .width(u32::from(frame.width))
+            .height(u32::from(frame.height));
+        let _ = xproto::configure_window(self.conn, client.frame, &aux)?;
+        let aux = ConfigureWindowAux::new()
+            .width(u32::from(geometry.width))
+            .height(u32::from(geometry.height));
+        let _ = xproto::configure_window(self.conn, window, &aux)?;
+        let (x, y) = client_offset(client.extents);
+        let event = ConfigureNotifyEvent {
+            response_type: CONFIGURE_NOTIFY_EVENT,
+            sequence: 0,
+            event: window,
+            window,
+            above_sibling: NONE,
+            x: frame.x.saturating_add(x),
+            y: frame.y.saturating_add(y),
+            width: geometry.width,
+            height: geometry.height,
+            border_width: 0,
+            override_redirect: false,
+        };
+        let _ = xproto::send_event(self.conn, false, window, EventMask::STRUCTURE_NOTIFY, event)?;
+        Ok(())
+    }
+
+    /// Grant a [`ClientRequest::Configure`].
+    ///
+    /// Managed clients are moved and resized with their frame, which is also restacked. Changes
+    /// of the border width of managed clients are ignored. Other windows are configured as
+    /// requested.
+    pub fn apply_configure(
+        &mut self,
+        window: Window,
+        changes: &ConfigureWindowAux,
+    ) -> Result<(), ConnectionError> {
+        let (frame, geometry) = match self.clients.get(&window) {
+            Some(client) => (client.frame, apply_changes(client.geometry, changes)),
+            None => {
+                let _ = xproto::configure_window(self.conn, window, changes)?;
+                return Ok(());
+            }
+        };
+        self.move_resize(window, geometry)?;
+        if let Some(stack_mode) = changes.stack_mode {
+            let mut aux = ConfigureWindowAux::new().stack_mode(stack_mode);
+            if let Some(sibling) = changes.sibling {
+                // Siblings of clients are stacked by their frames
+                let sibling = self.clients.get(&sibling).map_or(sibling, |c| c.frame);
+                aux = aux.sibling(sibling);
+            }
+            let _ = xproto::configure_window(self.conn, frame, &aux)?;
+        }
+        Ok(())
+    }
+
+    /// Iconify a managed client.
+    ///
+    /// The client and its frame are unmapped and the `WM_STATE` is set to `IconicState`.
+    pub fn iconify(&mut self, window: Window) -> Result<(), ConnectionError> {
+        let client = match self.clients.get_mut(&window) {
+            Some(client) if !client.iconic => client,
+            _ => return Ok(()),
+        };
+        client.iconic = true;
+        client.ignore_unmaps += 1;
+        let frame = client.frame;
+        let _ = xproto::unmap_window(self.conn, frame)?;
+        let _ = xproto::unmap_window(self.conn, window)?;
+        self.set_state(window, WindowState::Iconic)
+    }
+
+    /// Map an iconified client again.
+    pub fn restore(&mut self, window: Window) -> Result<(), ConnectionError> {
+        let client = match self.clients.get_mut(&window) {
+            Some(client) if client.iconic => client,
+            _ => return Ok(()),
+        };
+        client.iconic = false;
+        let frame = client.frame;
+        let _ = xproto::map_window(self.conn, window)?;
+        let _ = xproto::map_window(self.conn, frame)?;
+        self.set_state(window, WindowState::Normal)
+    }
+
+    /// Handle an event and get the request of a client that it contains.
+    ///
+    /// Clients that were withdrawn or destroyed are no longer managed when this returns.
+    /// Events that do not concern the window manager return `None`.
+    pub fn handle_event(
+        &mut self,
+        event: &Event,
+    ) -> Result<Option<ClientRequest>, ConnectionError> {
+        let request = match event {
+            Event::MapRequest(event) => ClientRequest::Map(event.window),
+            Event::ConfigureRequest(event) => ClientRequest::Configure {
+                window: event.window,
+                changes: requested_changes(event),
+            },
+            Event::CirculateRequest(event) => ClientRequest::Circulate {
+                window: event.window,
+                place: event.place,
+            },
+            Event::ClientMessage(event)
+                if event.type_ == self.atoms.WM_CHANGE_STATE
+                    && event.format == 32
+                    && event.data.as_data32()[0] == 3
+                    && self.clients.contains_key(&event.window) =>
+            {
+                ClientRequest::Iconify(event.window)
+            }
+            Event::UnmapNotify(event) if event.event != self.root || !event.from_configure => {
+                let client = match self.clients.get_mut(&event.window) {
+                    Some(client) => client,
+                    None => return Ok(None),
+                };
+                if client.ignore_unmaps > 0 {
+                    client.ignore_unmaps -= 1;
+                    return Ok(None);
+                }
+                let _ = self.unmanage(event.window)?;
+                self.set_state(event.window, WindowState::Withdrawn)?;
+                ClientRequest::Withdrawn(event.window)
+            }
+            Event::DestroyNotify(event) => match self.forget(event.window) {
+                Some(client) => {
+                    let _ = xproto::destroy_window(self.conn, client.frame)?;
+                    ClientRequest::Destroyed(event.window)
+                }
+                None => return Ok(None),
+            },
+            Event::SelectionClear(event)
+                if event.owner == self.window && event.selection == self.selection =>
+            {
+                ClientRequest::Replaced
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(request))
+    }
+
+    /// Remove a client from the managed clients.
+    fn forget(&mut self, window: Window) -> Option<Client> {
+        let client = self.clients.remove(&window)?;
+        let _ = self.frames.remove(&client.frame);
+        Some(client)
+    }
+
+    /// Set the `WM_STATE` property of a window.
+    fn set_state(&self, window: Window, state: WindowState) -> Result<(), ConnectionError> {
+        let state = WmState { state, icon: 0 };
+        let _ = state.set(self.conn, window, &self.atoms)?;
+        Ok(())
+    }
+}
+
+impl<C: Connection> Drop for WindowManager<'_, C> {
+    fn drop(&mut self) {
+        let windows = self.clients.keys().copied().collect::<Vec<_>>();
+        for window in windows {
+            let _ = self.unmanage(window);
+        }
+        let _ = xproto::destroy_window(self.conn, self.window);
+    }
+}
+
+/// Get the events that a [`WindowManager`] selects on the root window.
+fn root_event_mask() -> EventMask {
+    EventMask::SUBSTRUCTURE_REDIRECT | EventMask::SUBSTRUCTURE_NOTIFY | EventMask::STRUCTURE_NOTIFY
+}
+
+/// Get the events that a [`WindowManager`] selects on frame windows.
+fn frame_event_mask() -> EventMask {
+    EventMask::SUBSTRUCTURE_REDIRECT | EventMask::SUBSTRUCTURE_NOTIFY
+}
+
+/// Get the position of a client window inside its frame.
+fn client_offset(extents: FrameExtents) -> (i16, i16) {
+    let clamp = |value: u32| i16::try_from(value).unwrap_or(i16::MAX);
+    (clamp(extents.left), clamp(extents.top))
+}
+
+/// Get the geometry of a frame from the geometry of its client.
+fn frame_geometry(geometry: Rectangle, extents: FrameExtents) -> Rectangle {
+    let grow = |size: u16, a: u32, b: u32| {
+        let size = u32::from(size).saturating_add(a).saturating_add(b);
+        u16::try_from(size).unwrap_or(u16::MAX)
+    };
+    Rectangle {
+        x: geometry.x,
+        y: geometry.y,
+        width: grow(geometry.width, extents.left, extents.right),
+        height: grow(geometry.height, extents.top, extents.bottom),
+    }
+}
+
+/// Get the changes that a `ConfigureRequest` asks for.
+fn requested_changes(event: &ConfigureRequestEvent) -> ConfigureWindowAux {
+    let mask = event.value_mask;
+    let value =
+        |flag: ConfigWindow, value: u16| Some(u32::from(value)).filter(|_| mask.contains(flag));
+    ConfigureWindowAux {
+        x: Some(i32::from(event.x)).filter(|_| mask.contains(ConfigWindow::X)),
+        y: Some(i32::from(event.y)).filter(|_| mask.contains(ConfigWindow::Y)),
+        width: value(ConfigWindow::WIDTH, event.width),
+        height: value(ConfigWindow::HEIGHT, event.height),
+        border_width: value(ConfigWindow::BORDER_WIDTH, event.border_width),
+        sibling: Some(event.sibling).filter(|_| mask.contains(ConfigWindow::SIBLING)),
+        stack_mode: Some(event.stack_mode).filter(|_| mask.contains(ConfigWindow::STACK_MODE)),
+    }
+}
+
+/// Apply the position and size of a `ConfigureRequest` to the geometry of a client.
+fn apply_changes(geometry: Rectangle, changes: &ConfigureWindowAux) -> Rectangle {
+    let position = |value: Option<i32>, old: i16| {
+        value.map_or(old, |value| i16::try_from(value).unwrap_or(old))
+    };
+    let size = |value: Option<u32>, old: u16| {
+        value.map_or(old, |value| u16::try_from(value).unwrap_or(u16::MAX).max(1))
+    };
+    Rectangle {
+        x: position(changes.x, geometry.x),
+        y: position(changes.y, geometry.y),
+        width: size(changes.width, geometry.width),
+        height: size(changes.height, geometry.height),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{apply_changes, frame_geometry, requested_changes};
+    use crate::ewmh::FrameExtents;
+    use crate::protocol::xproto::{
+        ConfigWindow, ConfigureRequestEvent, ConfigureWindowAux, Rectangle, StackMode,
+    };
+
+    #[test]
+    fn configure_request_changes() {
+        let event = ConfigureRequestEvent {
+            response_type: 23,
+            stack_mode: StackMode::BELOW,
+            sequence: 0,
+            parent: 1,
+            window: 2,
+            sibling: 3,
+            x: -10,
+            y: 20,
+            width: 300,
+            height: 400,
+            border_width: 5,
+            value_mask: ConfigWindow::X | ConfigWindow::HEIGHT | ConfigWindow::STACK_MODE,
+        };
+        let changes = requested_changes(&event);
+        assert_eq!((changes.x, changes.y), (Some(-10), None));
+        assert_eq!((changes.width, changes.height), (None, Some(400)));
+        assert_eq!(changes.border_width, None);
+        assert_eq!(changes.sibling, None);
+        assert_eq!(changes.stack_mode, Some(StackMode::BELOW));
+    }
+
+    #[test]
+    fn client_geometry() {
+        let geometry = Rectangle {
+            x: 10,
+            y: 20,
+            width: 100,
+            height: 50,
+        };
+        let changes = ConfigureWindowAux::new().y(-5).width(0).height(70);
+        let changed = apply_changes(geometry, &changes);
+        assert_eq!(
+            (changed.x, changed.y, changed.width, changed.height),
+            (10, -5, 1, 70)
+        );
+
+        let extents = FrameExtents {
+            left: 1,
+            right: 2,
+            top: 20,
+            bottom: 3,
+        };
+        let frame = frame_geometry(geometry, extents);
+        assert_eq!(
+            (frame.x, frame.y, frame.width, frame.height),
+            (10, 20, 103, 73)
+        );
+    }
+}
@@ -2,7 +2,16 @@
 
 use super::cookie::VoidCookie;
 use super::errors::{ConnectionError, ReplyError};
-use super::protocol::xproto::{Atom, ConnectionExt as XProtoConnectionExt, PropMode, Window};
+use super::protocol::xproto::{
+    Atom, ConnectionExt as XProtoConnectionExt, Drawable, Gcontext, ImageFormat, PropMode, Window,
+};
+use std::borrow::Cow;
+
+/// The name of the extension that is provided by Xwayland.
+const XWAYLAND_EXTENSION_NAME: &str = "XWAYLAND";
+
+/// The size of the fixed part of `ChangeProperty` and `PutImage` requests.
+const CHUNKED_REQUEST_HEADER: usize = 24;
 
 /// Extension trait that simplifies API use
 pub trait ConnectionExt: XProtoConnectionExt {
@@ -86,6 +95,174 @@ pub trait ConnectionExt: XProtoConnectionExt {
         )
     }
 
+    /// Change a property on a window, splitting the data across several requests if needed.
+    ///
+    /// A single `ChangeProperty` request must not be larger than the server's maximum request
+    /// size. If `data` does not fit into one request, it is split into several parts. The first
+    /// part is sent with the given `mode` and the following parts are appended to the property.
+    /// For [`PropMode::PREPEND`], the parts are sent in reverse order instead, so that the
+    /// property still ends up with `data` in the right order. Thus, if this function returns an
+    /// error, the property could already be partially changed.
+    ///
+    /// `data` contains the raw bytes of the property value. `format` must be 8, 16 or 32.
+    fn change_property_chunked<A, B>(
+        &self,
+        mode: PropMode,
+        window: Window,
+        property: A,
+        type_: B,
+        format: u8,
+        data: &[u8],
+    ) -> Result<Vec<VoidCookie<'_, Self>>, ConnectionError>
+    where
+        A: Into<Atom>,
+        B: Into<Atom>,
+    {
+        assert!(
+            matches!(format, 8 | 16 | 32),
+            "Invalid property format {}",
+            format
+        );
+        let element_size = usize::from(format / 8);
+        assert_eq!(
+            data.len() % element_size,
+            0,
+            "`data` does not contain a whole number of elements"
+        );
+        let (property, type_) = (property.into(), type_.into());
+
+        // Use parts with a multiple of four bytes, so that each part contains whole elements
+        let max_bytes = (self.maximum_request_bytes() - CHUNKED_REQUEST_HEADER) & !3;
+        let mut chunks = data.chunks(max_bytes.max(4)).collect::<Vec<_>>();
+        if chunks.is_empty() {
+            chunks.push(&[]);
+        }
+        if mode == PropMode::PREPEND {
+            chunks.reverse();
+        }
+        let mut result = Vec::with_capacity(chunks.len());
+        for (index, chunk) in chunks.into_iter().enumerate() {
+            let mode = match (index, mode) {
+                (0, mode) => mode,
+                (_, PropMode::PREPEND) => PropMode::PREPEND,
+                _ => PropMode::APPEND,
+            };
+            let num_elements = chunk.len() / element_size;
+            result.push(
+                self.change_property(
+                    mode,
+                    window,
+                    property,
+                    type_,
+                    format,
+                    num_elements
+                        .try_into()
+                        .expect("`data` has too many elements"),
+                    chunk,
+                )?,
+            );
+        }
+        Ok(result)
+    }
+
+    /// Upload image data, splitting it across several `PutImage` requests if needed.
+    ///
+    /// The arguments are the same as for [`put_image`](XProtoConnectionExt::put_image). If the
+    /// request would be larger than the server's maximum request size, the image is split into
+    /// bands of whole scanlines that are uploaded one after another. The padding at the end of
+    /// each scanline is preserved. Thus, if this function returns an error, the image could
+    /// already be partially sent.
+    ///
+    /// `data` must contain `height` scanlines of the same size (for each plane in the case of
+    /// [`ImageFormat::XY_PIXMAP`]). An error is returned if a single scanline does not fit into
+    /// a request or if the y coordinate of a band would not fit into an `i16`.
+    ///
+    /// See also [`Image::put`](crate::image::Image::put), which also converts the image into the
+    /// server's native format.
+    #[allow(clippy::too_many_arguments)]
+    fn put_image_auto(
+        &self,
+        format: ImageFormat,
+        drawable: Drawable,
+        gc: Gcontext,
+        width: u16,
+        height: u16,
+        dst_x: i16,
+        dst_y: i16,
+        left_pad: u8,
+        depth: u8,
+        data: &[u8],
+    ) -> Result<Vec<VoidCookie<'_, Self>>, ConnectionError> {
+        let put = |height, dst_y, data: &[u8]| {
+            self.put_image(
+                format, drawable, gc, width, height, dst_x, dst_y, left_pad, depth, data,
+            )
+        };
+        let max_bytes = self.maximum_request_bytes() - CHUNKED_REQUEST_HEADER;
+        if data.len() <= max_bytes || height == 0 {
+            return Ok(vec![put(height, dst_y, data)?]);
+        }
+
+        // XYPixmap images contain one bitmap per plane. The other formats contain a single one.
+        let planes = if format == ImageFormat::XY_PIXMAP {
+            usize::from(depth)
+        } else {
+            1
+        };
+        let plane_size = data.len() / planes;
+        let stride = plane_size / usize::from(height);
+        assert_eq!(
+            stride * usize::from(height) * planes,
+            data.len(),
+            "`data` does not consist of scanlines of the same size"
+        );
+        let lines_per_request = max_bytes / (stride * planes);
+        if lines_per_request == 0 {
+            return Err(ConnectionError::MaximumRequestLengthExceeded);
+        }
+        let lines_per_request = u16::try_from(lines_per_request).unwrap_or(u16::MAX);
+        let band_y = |y_offset: u16| {
+            i16::try_from(i32::from(dst_y) + i32::from(y_offset))
+                .map_err(|_| ConnectionError::MaximumRequestLengthExceeded)
+        };
+        // Check that the position of the last band fits before anything is sent
+        let _ = band_y((height - 1) / lines_per_request * lines_per_request)?;
+
+        let mut result = Vec::new();
+        let mut y_offset = 0;
+        while y_offset < height {
+            let next_lines = lines_per_request.min(height - y_offset);
+            let start = usize::from(y_offset) * stride;
+            let end = start + usize::from(next_lines) * stride;
+            let band = if planes == 1 {
+                Cow::Borrowed(&data[start..end])
+            } else {
+                // Collect the scanlines of this band from each plane
+                let band = data
+                    .chunks(plane_size)
+                    .flat_map(|plane| &plane[start..end])
+                    .copied()
+                    .collect();
+                Cow::Owned(band)
+            };
+            result.push(put(next_lines, band_y(y_offset)?, &band)?);
+            y_offset += next_lines;
+        }
+        Ok(result)
+    }
+
+    /// Check whether the X11 server is Xwayland.
+    ///
+    /// Xwayland announces itself via the `XWAYLAND` extension. Applications can use this to
+    /// adjust their behaviour, e.g. because keyboard grabs and server-side decorations do not
+    /// work as on other X11 servers. The protocol of this extension is not part of xcb-proto, so
+    /// only its presence is checked.
+    fn is_xwayland(&self) -> Result<bool, ConnectionError> {
+        Ok(self
+            .extension_information(XWAYLAND_EXTENSION_NAME)?
+            .is_some())
+    }
+
     /// Synchronise with the X11 server.
     ///
     /// This function synchronises with the X11 server. This means that all requests that are still
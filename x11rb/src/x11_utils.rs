@@ -1,10 +1,69 @@
 //! Some utilities for working with X11.
 
 pub use x11rb_protocol::x11_utils::{
-    parse_request_header, BigRequests, ExtInfoProvider, ExtensionInformation, ReplyParsingFunction,
-    Request, RequestHeader, Serialize, TryParse, TryParseFd, X11Error,
+    parse_request_header, BigRequests, BorrowedReply, ExtInfoProvider, ExtensionInformation,
+    ReplyFDsRequest, ReplyParsingFunction, ReplyRequest, Request, RequestHeader, Serialize,
+    TryParse, TryParseFd, TryParseRef, VoidRequest, X11Error,
 };
 
+use crate::connection::RequestConnection;
+use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
+use crate::errors::ConnectionError;
+
+/// Extension trait for sending requests without a reply.
+///
+/// This allows to send a request that was constructed with its builder methods:
+///
+/// ```no_run
+/// # use x11rb::protocol::xproto::{ConfigureWindowRequest, StackMode};
+/// use x11rb::x11_utils::VoidRequestExt;
+///
+/// # fn send(conn: &impl x11rb::connection::Connection) -> Result<(), x11rb::errors::ConnectionError> {
+/// ConfigureWindowRequest::new(0x1234)
+///     .x(5)
+///     .stack_mode(StackMode::ABOVE)
+///     .send(conn)?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait VoidRequestExt: VoidRequest + Sized {
+    /// Send this request to the X11 server.
+    fn send<C: RequestConnection + ?Sized>(
+        self,
+        conn: &C,
+    ) -> Result<VoidCookie<'_, C>, ConnectionError> {
+        conn.send_trait_request_without_reply(self)
+    }
+}
+
+impl<R: VoidRequest> VoidRequestExt for R {}
+
+/// Extension trait for sending requests with a reply.
+pub trait ReplyRequestExt: ReplyRequest + Sized {
+    /// Send this request to the X11 server.
+    fn send<C: RequestConnection + ?Sized>(
+        self,
+        conn: &C,
+    ) -> Result<Cookie<'_, C, Self::Reply>, ConnectionError> {
+        conn.send_trait_request_with_reply(self)
+    }
+}
+
+impl<R: ReplyRequest> ReplyRequestExt for R {}
+
+/// Extension trait for sending requests with a reply containing file descriptors.
+pub trait ReplyFDsRequestExt: ReplyFDsRequest + Sized {
+    /// Send this request to the X11 server.
+    fn send<C: RequestConnection + ?Sized>(
+        self,
+        conn: &C,
+    ) -> Result<CookieWithFds<'_, C, Self::Reply>, ConnectionError> {
+        conn.send_trait_request_with_reply_with_fds(self)
+    }
+}
+
+impl<R: ReplyFDsRequest> ReplyFDsRequestExt for R {}
+
 /// A helper macro for managing atoms
 ///
 /// In X11, one often has to work with many different atoms that are already known at compile time.
@@ -28,10 +28,14 @@ use x11rb_protocol::{DiscardMode, SequenceNumber};
 mod atomic_u64;
 mod pending_errors;
 mod raw_ffi;
+#[cfg(feature = "xlib")]
+mod xlib;
 
 use atomic_u64::AtomicU64;
 #[cfg(all(not(test), feature = "dl-libxcb"))]
 pub use raw_ffi::libxcb_library::load_libxcb;
+#[cfg(feature = "xlib")]
+pub use xlib::XlibXcbConnection;
 
 type Buffer = <XCBConnection as RequestConnection>::Buf;
 /// The raw bytes of an event received by [`XCBConnection`] and its sequence number.
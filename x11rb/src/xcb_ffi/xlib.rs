@@ -0,0 +1,168 @@
+//! A connection that is shared between Xlib and x11rb.
+//!
+//! Some libraries, e.g. GLX and some Vulkan drivers, need an Xlib `Display`. Since libX11 uses
+//! libxcb internally, an [`XlibXcbConnection`] opens the display with Xlib and then uses the
+//! libxcb connection of the display for x11rb.
+//!
+//! libX11 and libX11-xcb are loaded at runtime when the first connection is opened.
+
+use std::ffi::CStr;
+use std::ops::Deref;
+use std::os::raw::{c_char, c_int, c_void};
+use std::ptr::{null, NonNull};
+
+use once_cell::sync::Lazy;
+
+use super::XCBConnection;
+use crate::errors::{XlibConnectError, XlibLoadError};
+
+#[cfg(target_os = "netbsd")]
+const X11_LIB_NAME: &str = "/usr/X11R7/lib/libX11.so.7";
+#[cfg(target_os = "netbsd")]
+const X11_XCB_LIB_NAME: &str = "/usr/X11R7/lib/libX11-xcb.so.2";
+#[cfg(target_os = "linux")]
+const X11_LIB_NAME: &str = "libX11.so.6";
+#[cfg(target_os = "linux")]
+const X11_XCB_LIB_NAME: &str = "libX11-xcb.so.1";
+#[cfg(not(any(target_os = "linux", target_os = "netbsd")))]
+const X11_LIB_NAME: &str = "libX11.so";
+#[cfg(not(any(target_os = "linux", target_os = "netbsd")))]
+const X11_XCB_LIB_NAME: &str = "libX11-xcb.so";
+
+/// The value of `XCBOwnsEventQueue` from `Xlib-xcb.h`.
+const XCB_OWNS_EVENT_QUEUE: c_int = 1;
+
+/// The functions of libX11 and libX11-xcb that are needed here.
+struct XlibFuncs {
+    // Needed to keep the libraries loaded
+    _x11: libloading::Library,
+    _x11_xcb: libloading::Library,
+    x_init_threads: unsafe extern "C" fn() -> c_int,
+    x_open_display: unsafe extern "C" fn(*const c_char) -> *mut c_void,
+    x_close_display: unsafe extern "C" fn(*mut c_void) -> c_int,
+    x_default_screen: unsafe extern "C" fn(*mut c_void) -> c_int,
+    x_get_xcb_connection: unsafe extern "C" fn(*mut c_void) -> *mut c_void,
+    x_set_event_queue_owner: unsafe extern "C" fn(*mut c_void, c_int),
+}
+
+impl XlibFuncs {
+    /// # Safety
+    ///
+    /// The function pointers must not outlive the loaded libraries. This is ensured by keeping
+    /// them in the same struct.
+    #[cold]
+    unsafe fn load() -> Result<Self, XlibLoadError> {
+        let open = |name: &str| {
+            libloading::Library::new(name)
+                .map_err(|e| XlibLoadError::OpenLibError(name.into(), e.to_string()))
+        };
+        let x11 = open(X11_LIB_NAME)?;
+        let x11_xcb = open(X11_XCB_LIB_NAME)?;
+        macro_rules! symbol {
+            ($library:expr, $name:literal) => {
+                *$library
+                    .get(concat!($name, "\0").as_bytes())
+                    .map_err(|e| XlibLoadError::GetSymbolError($name.into(), e.to_string()))?
+            };
+        }
+        Ok(Self {
+            x_init_threads: symbol!(x11, "XInitThreads"),
+            x_open_display: symbol!(x11, "XOpenDisplay"),
+            x_close_display: symbol!(x11, "XCloseDisplay"),
+            x_default_screen: symbol!(x11, "XDefaultScreen"),
+            x_get_xcb_connection: symbol!(x11_xcb, "XGetXCBConnection"),
+            x_set_event_queue_owner: symbol!(x11_xcb, "XSetEventQueueOwner"),
+            _x11: x11,
+            _x11_xcb: x11_xcb,
+        })
+    }
+}
+
+static XLIB: Lazy<Result<XlibFuncs, XlibLoadError>> = Lazy::new(|| unsafe { XlibFuncs::load() });
+
+/// An Xlib `Display` that closes the display when dropped.
+#[derive(Debug)]
+struct DisplayWrapper(NonNull<c_void>);
+
+impl Drop for DisplayWrapper {
+    fn drop(&mut self) {
+        if let Ok(xlib) = &*XLIB {
+            // SAFETY: The display was opened with XOpenDisplay and is not used afterwards.
+            let _ = unsafe { (xlib.x_close_display)(self.0.as_ptr()) };
+        }
+    }
+}
+
+/// A connection to an X11 server that is shared between Xlib and x11rb.
+///
+/// The event queue belongs to x11rb, i.e. events have to be read through the [`Connection`]
+/// trait and Xlib functions that wait for events must not be used.
+///
+/// This derefs to [`XCBConnection`] and implements [`Connection`] by forwarding to it.
+///
+/// [`Connection`]: crate::connection::Connection
+#[derive(Debug)]
+pub struct XlibXcbConnection {
+    // The XCB connection belongs to the display, so it has to be dropped first
+    conn: XCBConnection,
+    display: DisplayWrapper,
+}
+
+// SAFETY: libxcb is thread-safe and Xlib was initialised with XInitThreads before the display
+// was opened.
+unsafe impl Send for XlibXcbConnection {}
+unsafe impl Sync for XlibXcbConnection {}
+
+impl XlibXcbConnection {
+    /// Open a display with Xlib.
+    ///
+    /// If a `dpy_name` is provided, it describes the display that should be connected to, for
+    /// example `127.0.0.1:1`. If no value is provided, the `$DISPLAY` environment variable is
+    /// used. Returns the connection and the default screen of the display.
+    pub fn connect(dpy_name: Option<&CStr>) -> Result<(Self, usize), XlibConnectError> {
+        let xlib = XLIB
+            .as_ref()
+            .map_err(|e| XlibConnectError::LoadError(e.clone()))?;
+        // SAFETY: XInitThreads is called before any other Xlib function. The display is only
+        // used while it is open and the XCB connection is not disconnected by XCBConnection.
+        unsafe {
+            let _ = (xlib.x_init_threads)();
+            let display = (xlib.x_open_display)(dpy_name.map_or(null(), CStr::as_ptr));
+            let display =
+                DisplayWrapper(NonNull::new(display).ok_or(XlibConnectError::OpenDisplay)?);
+            (xlib.x_set_event_queue_owner)(display.0.as_ptr(), XCB_OWNS_EVENT_QUEUE);
+            let screen = (xlib.x_default_screen)(display.0.as_ptr());
+            let raw_conn = (xlib.x_get_xcb_connection)(display.0.as_ptr());
+            let conn = XCBConnection::from_raw_xcb_connection(raw_conn, false)?;
+            let screen = usize::try_from(screen).unwrap_or(0);
+            Ok((Self { conn, display }, screen))
+        }
+    }
+
+    /// Get the Xlib `Display` of this connection.
+    ///
+    /// The pointer is valid as long as this connection exists.
+    pub fn display(&self) -> *mut c_void {
+        self.display.0.as_ptr()
+    }
+
+    /// Get the XCB connection of this connection.
+    pub fn xcb_connection(&self) -> &XCBConnection {
+        &self.conn
+    }
+}
+
+impl Deref for XlibXcbConnection {
+    type Target = XCBConnection;
+
+    fn deref(&self) -> &XCBConnection {
+        &self.conn
+    }
+}
+
+// SAFETY: The xcb_connection_t of the display is valid for as long as this connection exists.
+unsafe impl as_raw_xcb_connection::AsRawXcbConnection for XlibXcbConnection {
+    fn as_raw_xcb_connection(&self) -> *mut as_raw_xcb_connection::xcb_connection_t {
+        self.conn.get_raw_xcb_connection().cast()
+    }
+}
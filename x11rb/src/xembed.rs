@@ -0,0 +1,632 @@
+//! Embedding windows of other clients with the XEmbed protocol.
+//!
+//! With XEmbed, a window of one client is reparented into a window of another client, e.g. a
+//! plugin into a host application or an icon into a system tray. The embedder, called the site,
+//! is represented by [`XEmbedSite`] and forwards the activation of its top-level window, the
+//! keyboard focus and modality to the embedded client. The client, represented by
+//! [`XEmbedClient`], tells the site whether it wants to be mapped and asks it for the focus.
+//!
+//! Both sides communicate with [`XEmbedMessage`]s, which are `_XEMBED` client messages.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use x11rb::connection::Connection;
+//! use x11rb::xembed::{ClientEvent, XEmbedAtoms, XEmbedClient};
+//!
+//! let (conn, _) = x11rb::connect(None)?;
+//! # let plug = 0;
+//! let atoms = XEmbedAtoms::new(&conn)?.reply()?;
+//! let mut client = XEmbedClient::new(&conn, &atoms, plug, true)?;
+//! conn.flush()?;
+//! loop {
+//!     let event = conn.wait_for_event()?;
+//!     match client.handle_event(&event) {
+//!         Some(ClientEvent::Embedded) => println!("Embedded into {:?}", client.embedder()),
+//!         Some(ClientEvent::FocusIn(_)) => println!("Got the focus"),
+//!         _ => {}
+//!     }
+//! }
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::connection::Connection;
+use crate::errors::{ConnectionError, ReplyError};
+use crate::protocol::xproto::{
+    self, ChangeWindowAttributesAux, ClientMessageEvent, EventMask, PropMode, SetMode, Timestamp,
+    Window,
+};
+use crate::protocol::Event;
+use crate::wrapper::ConnectionExt as _;
+use crate::NONE;
+
+pub use atoms::{XEmbedAtoms, XEmbedAtomsCookie};
+
+mod atoms {
+    // The code that the macro generates has no documentation and a `new` function that returns a
+    // cookie
+    #![allow(missing_docs, clippy::new_ret_no_self)]
+
+    crate::atom_manager! {
+        /// The atoms of the XEmbed protocol.
+        pub XEmbedAtoms:
+        /// A cookie for interning the atoms of the XEmbed protocol.
+        XEmbedAtomsCookie {
+            _XEMBED,
+            _XEMBED_INFO,
+        }
+    }
+}
+
+/// The version of the XEmbed protocol that this module implements.
+pub const XEMBED_VERSION: u32 = 0;
+
+/// The flag in `_XEMBED_INFO` that says whether the client wants to be mapped.
+const XEMBED_MAPPED: u32 = 1;
+
+/// Where the focus goes when an embedded client gets it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusDetail {
+    /// The focus stays on the widget that had it before.
+    Current,
+    /// The focus goes to the first widget, e.g. when tabbing forward into the client.
+    First,
+    /// The focus goes to the last widget, e.g. when tabbing backward into the client.
+    Last,
+}
+
+/// A message of the XEmbed protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum XEmbedMessage {
+    /// The site tells the client that it was embedded.
+    EmbeddedNotify {
+        /// The site window.
+        embedder: Window,
+        /// The protocol version that the site uses.
+        version: u32,
+    },
+    /// The site tells the client that the top-level window got the focus.
+    WindowActivate,
+    /// The site tells the client that the top-level window lost the focus.
+    WindowDeactivate,
+    /// The client asks the site for the focus.
+    RequestFocus,
+    /// The site gives the focus to the client.
+    FocusIn(FocusDetail),
+    /// The site takes the focus from the client.
+    FocusOut,
+    /// The client moves the focus past its last widget.
+    FocusNext,
+    /// The client moves the focus before its first widget.
+    FocusPrev,
+    /// The site tells the client that a modal dialog is shown.
+    ModalityOn,
+    /// The site tells the client that a modal dialog was closed.
+    ModalityOff,
+}
+
+impl XEmbedMessage {
+    /// Get the message in an `_XEMBED` client message.
+    ///
+    /// Returns `None` for other client messages and for messages that this module does not
+    /// implement, e.g. the accelerator messages.
+    pub fn parse(atoms: &XEmbedAtoms, event: &ClientMessageEvent) -> Option<(Timestamp, Self)> {
+        if event.type_ != atoms._XEMBED || event.format != 32 {
+            return None;
+        }
+        let [time, message, detail, data1, data2] = event.data.as_data32();
+        let message = match message {
+            0 => XEmbedMessage::EmbeddedNotify {
+                embedder: data1,
+                version: data2,
+            },
+            1 => XEmbedMessage::WindowActivate,
+            2 => XEmbedMessage::WindowDeactivate,
+            3 => XEmbedMessage::RequestFocus,
+            4 => XEmbedMessage::FocusIn(match detail {
+                1 => FocusDetail::First,
+                2 => FocusDetail::Last,
+                _ => FocusDetail::Current,
+            }),
+            5 => XEmbedMessage::FocusOut,
+            6 => XEmbedMessage::FocusNext,
+            7 => XEmbedMessage::FocusPrev,
+            10 => XEmbedMessage::ModalityOn,
+            11 => XEmbedMessage::ModalityOff,
+            _ => return None,
+        };
+        Some((time, message))
+    }
+
+    /// Get the data of an `_XEMBED` client message with this message.
+    pub fn to_data(self, time: Timestamp) -> [u32; 5] {
+        let (message, detail, data1, data2) = match self {
+            XEmbedMessage::EmbeddedNotify { embedder, version } => (0, 0, embedder, version),
+            XEmbedMessage::WindowActivate => (1, 0, 0, 0),
+            XEmbedMessage::WindowDeactivate => (2, 0, 0, 0),
+            XEmbedMessage::RequestFocus => (3, 0, 0, 0),
+            XEmbedMessage::FocusIn(detail) => {
+                let detail = match detail {
+                    FocusDetail::Current => 0,
+                    FocusDetail::First => 1,
+                    FocusDetail::Last => 2,
+                };
+                (4, detail, 0, 0)
+            }
+            XEmbedMessage::FocusOut => (5, 0, 0, 0),
+            XEmbedMessage::FocusNext => (6, 0, 0, 0),
+            XEmbedMessage::FocusPrev => (7, 0, 0, 0),
+            XEmbedMessage::ModalityOn => (10, 0, 0, 0),
+            XEmbedMessage::ModalityOff => (11, 0, 0, 0),
+        };
+        [time, message, detail, data1, data2]
+    }
+
+    /// Send this message to a window.
+    pub fn send<C: Connection>(
+        self,
+        conn: &C,
+        atoms: &XEmbedAtoms,
+        window: Window,
+        time: Timestamp,
+    ) -> Result<(), ConnectionError> {
+        let event = ClientMessageEvent::new(32, window, atoms._XEMBED, self.to_data(time));
+        let _ = xproto::send_event(conn, false, window, EventMask::NO_EVENT, event)?;
+        Ok(())
+    }
+}
+
+/// An event of an [`XEmbedSite`] that the embedder has to handle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SiteEvent {
+    /// The client wants the focus, e.g. because it was clicked.
+    RequestFocus,
+    /// The focus should move to the widget after the site.
+    FocusNext,
+    /// The focus should move to the widget before the site.
+    FocusPrev,
+    /// The client was destroyed or moved out of the site.
+    Unembedded,
+}
+
+/// The embedder of a client window.
+#[derive(Debug)]
+pub struct XEmbedSite<'c, C: Connection> {
+    conn: &'c C,
+    atoms: XEmbedAtoms,
+    root: Window,
+    site: Window,
+    client: Window,
+    version: u32,
+    mapped: bool,
+}
+
+impl<'c, C: Connection> XEmbedSite<'c, C> {
+    /// Embed `client` into the window `site` at the given position.
+    ///
+    /// The client is reparented, added to the save-set and told that it was embedded. It is
+    /// mapped unless its `_XEMBED_INFO` says that it does not want to be visible. Clients
+    /// without `_XEMBED_INFO` are mapped, too.
+    pub fn embed(
+        conn: &'c C,
+        atoms: &XEmbedAtoms,
+        site: Window,
+        client: Window,
+        (x, y): (i16, i16),
+        time: Timestamp,
+    ) -> Result<Self, ReplyError> {
+        let aux = ChangeWindowAttributesAux::new()
+            .event_mask(EventMask::STRUCTURE_NOTIFY | EventMask::PROPERTY_CHANGE);
+        let _ = xproto::change_window_attributes(conn, client, &aux)?;
+        let geometry = xproto::get_geometry(conn, client)?;
+        let info = get_info(conn, atoms, client)?;
+        let _ = xproto::change_save_set(conn, SetMode::INSERT, client)?;
+        let _ = xproto::reparent_window(conn, client, site, x, y)?;
+        let (version, flags) =
+            parse_info(&info.reply()?).unwrap_or((XEMBED_VERSION, XEMBED_MAPPED));
+        let mut embedded = Self {
+            conn,
+            atoms: *atoms,
+            root: geometry.reply()?.root,
+            site,
+            client,
+            version,
+            mapped: false,
+        };
+        let notify = XEmbedMessage::EmbeddedNotify {
+            embedder: site,
+            version: XEMBED_VERSION,
+        };
+        embedded.send(notify, time)?;
+        embedded.set_mapped(flags & XEMBED_MAPPED != 0)?;
+        Ok(embedded)
+    }
+
+    /// Get the site window.
+    pub fn site(&self) -> Window {
+        self.site
+    }
+
+    /// Get the embedded client window.
+    pub fn client(&self) -> Window {
+        self.client
+    }
+
+    /// Get the protocol version that the client supports.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Check whether the client is mapped.
+    pub fn is_mapped(&self) -> bool {
+        self.mapped
+    }
+
+    /// Send a message to the client.
+    pub fn send(&self, message: XEmbedMessage, time: Timestamp) -> Result<(), ConnectionError> {
+        message.send(self.conn, &self.atoms, self.client, time)
+    }
+
+    /// Tell the client whether the top-level window of the site has the focus.
+    pub fn set_active(&self, active: bool, time: Timestamp) -> Result<(), ConnectionError> {
+        let message = if active {
+            XEmbedMessage::WindowActivate
+        } else {
+            XEmbedMessage::WindowDeactivate
+        };
+        self.send(message, time)
+    }
+
+    /// Give the focus to the client.
+    ///
+    /// The site keeps the X11 input focus and the client gets the key events through it, so the
+    /// site has to forward them, e.g. with `SendEvent`.
+    pub fn focus_in(&self, detail: FocusDetail, time: Timestamp) -> Result<(), ConnectionError> {
+        self.send(XEmbedMessage::FocusIn(detail), time)
+    }
+
+    /// Take the focus from the client.
+    pub fn focus_out(&self, time: Timestamp) -> Result<(), ConnectionError> {
+        self.send(XEmbedMessage::FocusOut, time)
+    }
+
+    /// Tell the client whether a modal dialog of the site is shown.
+    pub fn set_modality(&self, modal: bool, time: Timestamp) -> Result<(), ConnectionError> {
+        let message = if modal {
+            XEmbedMessage::ModalityOn
+        } else {
+            XEmbedMessage::ModalityOff
+        };
+        self.send(message, time)
+    }
+
+    /// Handle an event that concerns the embedded client.
+    ///
+    /// The client is mapped and unmapped as its `_XEMBED_INFO` says. Events that do not concern
+    /// the embedding return `None`.
+    pub fn handle_event(&mut self, event: &Event) -> Result<Option<SiteEvent>, ReplyError> {
+        let event = match event {
+            Event::ClientMessage(event) if event.window == self.site => event,
+            Event::DestroyNotify(event) if event.window == self.client => {
+                return Ok(Some(SiteEvent::Unembedded));
+            }
+            Event::ReparentNotify(event)
+                if event.window == self.client && event.parent != self.site =>
+            {
+                return Ok(Some(SiteEvent::Unembedded));
+            }
+            Event::PropertyNotify(event)
+                if event.window == self.client && event.atom == self.atoms._XEMBED_INFO =>
+            {
+                let info = get_info(self.conn, &self.atoms, self.client)?.reply()?;
+                if let Some((_, flags)) = parse_info(&info) {
+                    self.set_mapped(flags & XEMBED_MAPPED != 0)?;
+                }
+                return Ok(None);
+            }
+            _ => return Ok(None),
+        };
+        let event = match XEmbedMessage::parse(&self.atoms, event) {
+            Some((_, XEmbedMessage::RequestFocus)) => SiteEvent::RequestFocus,
+            Some((_, XEmbedMessage::FocusNext)) => SiteEvent::FocusNext,
+            Some((_, XEmbedMessage::FocusPrev)) => SiteEvent::FocusPrev,
+            _ => return Ok(None),
+        };
+        Ok(Some(event))
+    }
+
+    /// Move the client out of the site to the root window.
+    pub fn unembed(self) -> Result<(), ConnectionError> {
+        let conn = self.conn;
+        let _ = xproto::unmap_window(conn, self.client)?;
+        let _ = xproto::reparent_window(conn, self.client, self.root, 0, 0)?;
+        let _ = xproto::change_save_set(conn, SetMode::DELETE, self.client)?;
+        Ok(())
+    }
+
+    fn set_mapped(&mut self, mapped: bool) -> Result<(), ConnectionError> {
+        if mapped {
+            let _ = xproto::map_window(self.conn, self.client)?;
+        } else {
+            let _ = xproto::unmap_window(self.conn, self.client)?;
+        }
+        self.mapped = mapped;
+        Ok(())
+    }
+}
+
+/// Send a `GetProperty` request for the `_XEMBED_INFO` property of a window.
+fn get_info<'c, C: Connection>(
+    conn: &'c C,
+    atoms: &XEmbedAtoms,
+    window: Window,
+) -> Result<crate::cookie::Cookie<'c, C, xproto::GetPropertyReply>, ConnectionError> {
+    xproto::get_property(
+        conn,
+        false,
+        window,
+        atoms._XEMBED_INFO,
+        atoms._XEMBED_INFO,
+        0,
+        2,
+    )
+}
+
+/// Get the version and the flags from an `_XEMBED_INFO` property.
+fn parse_info(reply: &xproto::GetPropertyReply) -> Option<(u32, u32)> {
+    let mut values = reply.value32()?;
+    Some((values.next()?, values.next()?))
+}
+
+/// An event of an [`XEmbedClient`] that the embedded client has to handle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClientEvent {
+    /// The client was embedded into a site, see [`XEmbedClient::embedder`].
+    Embedded,
+    /// The client was moved out of its site.
+    Unembedded,
+    /// The top-level window of the site got or lost the focus.
+    Activated(bool),
+    /// The client got the focus.
+    FocusIn(FocusDetail),
+    /// The client lost the focus.
+    FocusOut,
+    /// The site showed or closed a modal dialog.
+    Modality(bool),
+}
+
+/// The state of an embedded client.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct ClientState {
+    embedder: Option<Window>,
+    version: u32,
+    active: bool,
+    focused: bool,
+    modal: bool,
+}
+
+impl ClientState {
+    /// Update the state for a message from the site.
+    fn apply(&mut self, message: XEmbedMessage) -> Option<ClientEvent> {
+        let event = match message {
+            XEmbedMessage::EmbeddedNotify { embedder, version } => {
+                self.embedder = Some(embedder).filter(|embedder| *embedder != NONE);
+                self.version = version;
+                ClientEvent::Embedded
+            }
+            XEmbedMessage::WindowActivate | XEmbedMessage::WindowDeactivate => {
+                self.active = message == XEmbedMessage::WindowActivate;
+                ClientEvent::Activated(self.active)
+            }
+            XEmbedMessage::FocusIn(detail) => {
+                self.focused = true;
+                ClientEvent::FocusIn(detail)
+            }
+            XEmbedMessage::FocusOut => {
+                self.focused = false;
+                ClientEvent::FocusOut
+            }
+            XEmbedMessage::ModalityOn | XEmbedMessage::ModalityOff => {
+                self.modal = message == XEmbedMessage::ModalityOn;
+                ClientEvent::Modality(self.modal)
+            }
+            _ => return None,
+        };
+        Some(event)
+    }
+
+    /// Forget the site.
+    fn unembed(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// A window that can be embedded into a site of another client.
+#[derive(Debug)]
+pub struct XEmbedClient<'c, C: Connection> {
+    conn: &'c C,
+    atoms: XEmbedAtoms,
+    window: Window,
+    mapped: bool,
+    state: ClientState,
+}
+
+impl<'c, C: Connection> XEmbedClient<'c, C> {
+    /// Prepare a window for being embedded.
+    ///
+    /// This sets the `_XEMBED_INFO` property, which says whether the site should map the window.
+    /// `StructureNotify` has to be selected on the window to notice when it leaves its site.
+    pub fn new(
+        conn: &'c C,
+        atoms: &XEmbedAtoms,
+        window: Window,
+        mapped: bool,
+    ) -> Result<Self, ConnectionError> {
+        let client = Self {
+            conn,
+            atoms: *atoms,
+            window,
+            mapped,
+            state: ClientState::default(),
+        };
+        client.set_info()?;
+        Ok(client)
+    }
+
+    /// Get the site window, if the client is embedded.
+    pub fn embedder(&self) -> Option<Window> {
+        self.state.embedder
+    }
+
+    /// Get the protocol version that the site supports.
+    pub fn version(&self) -> u32 {
+        self.state.version
+    }
+
+    /// Check whether the top-level window of the site has the focus.
+    pub fn is_active(&self) -> bool {
+        self.state.active
+    }
+
+    /// Check whether the client has the focus.
+    pub fn has_focus(&self) -> bool {
+        self.state.focused
+    }
+
+    /// Check whether a modal dialog of the site is shown.
+    pub fn is_modal(&self) -> bool {
+        self.state.modal
+    }
+
+    /// Ask the site to map or unmap the window.
+    pub fn set_mapped(&mut self, mapped: bool) -> Result<(), ConnectionError> {
+        self.mapped = mapped;
+        self.set_info()
+    }
+
+    /// Ask the site for the focus.
+    pub fn request_focus(&self, time: Timestamp) -> Result<(), ConnectionError> {
+        self.send(XEmbedMessage::RequestFocus, time)
+    }
+
+    /// Move the focus to the widget after the site, e.g. when tabbing past the last widget.
+    pub fn focus_next(&self, time: Timestamp) -> Result<(), ConnectionError> {
+        self.send(XEmbedMessage::FocusNext, time)
+    }
+
+    /// Move the focus to the widget before the site.
+    pub fn focus_prev(&self, time: Timestamp) -> Result<(), ConnectionError> {
+        self.send(XEmbedMessage::FocusPrev, time)
+    }
+
+    /// Handle a message from the site or the removal from the site.
+    pub fn handle_event(&mut self, event: &Event) -> Option<ClientEvent> {
+        match event {
+            Event::ClientMessage(event) if event.window == self.window => {
+                let (_, message) = XEmbedMessage::parse(&self.atoms, event)?;
+                self.state.apply(message)
+            }
+            Event::ReparentNotify(event)
+                if event.window == self.window
+                    && self.state.embedder.map_or(false, |e| e != event.parent) =>
+            {
+                self.state.unembed();
+                Some(ClientEvent::Unembedded)
+            }
+            _ => None,
+        }
+    }
+
+    /// Send a message to the site.
+    fn send(&self, message: XEmbedMessage, time: Timestamp) -> Result<(), ConnectionError> {
+        match self.state.embedder {
+            Some(embedder) => message.send(self.conn, &self.atoms, embedder, time),
+            None => Ok(()),
+        }
+    }
+
+    /// Set the `_XEMBED_INFO` property.
+    fn set_info(&self) -> Result<(), ConnectionError> {
+        let flags = if self.mapped { XEMBED_MAPPED } else { 0 };
+        let _ = self.conn.change_property32(
+            PropMode::REPLACE,
+            self.window,
+            self.atoms._XEMBED_INFO,
+            self.atoms._XEMBED_INFO,
+            &[XEMBED_VERSION, flags],
+        )?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ClientEvent, ClientState, FocusDetail, XEmbedAtoms, XEmbedMessage};
+    use crate::protocol::xproto::ClientMessageEvent;
+
+    const ATOMS: XEmbedAtoms = XEmbedAtoms {
+        _XEMBED: 10,
+        _XEMBED_INFO: 11,
+    };
+
+    #[test]
+    fn message_round_trip() {
+        let messages = [
+            XEmbedMessage::EmbeddedNotify {
+                embedder: 42,
+                version: 0,
+            },
+            XEmbedMessage::WindowActivate,
+            XEmbedMessage::RequestFocus,
+            XEmbedMessage::FocusIn(FocusDetail::Last),
+            XEmbedMessage::FocusPrev,
+            XEmbedMessage::ModalityOff,
+        ];
+        for message in messages {
+            let event = ClientMessageEvent::new(32, 1, ATOMS._XEMBED, message.to_data(1234));
+            assert_eq!(XEmbedMessage::parse(&ATOMS, &event), Some((1234, message)));
+        }
+        assert_eq!(
+            XEmbedMessage::FocusIn(FocusDetail::First).to_data(5),
+            [5, 4, 1, 0, 0]
+        );
+        // Accelerators are not implemented
+        let event = ClientMessageEvent::new(32, 1, ATOMS._XEMBED, [0, 12, 0, 0, 0]);
+        assert_eq!(XEmbedMessage::parse(&ATOMS, &event), None);
+        let event = ClientMessageEvent::new(32, 1, ATOMS._XEMBED_INFO, [0, 1, 0, 0, 0]);
+        assert_eq!(XEmbedMessage::parse(&ATOMS, &event), None);
+    }
+
+    #[test]
+    fn client_state() {
+        let mut state = ClientState::default();
+        let notify = XEmbedMessage::EmbeddedNotify {
+            embedder: 7,
+            version: 1,
+        };
+        assert_eq!(state.apply(notify), Some(ClientEvent::Embedded));
+        assert_eq!((state.embedder, state.version), (Some(7), 1));
+        assert_eq!(
+            state.apply(XEmbedMessage::WindowActivate),
+            Some(ClientEvent::Activated(true))
+        );
+        let focus_in = XEmbedMessage::FocusIn(FocusDetail::First);
+        assert_eq!(
+            state.apply(focus_in),
+            Some(ClientEvent::FocusIn(FocusDetail::First))
+        );
+        assert_eq!(
+            state.apply(XEmbedMessage::ModalityOn),
+            Some(ClientEvent::Modality(true))
+        );
+        assert!(state.active && state.focused && state.modal);
+        assert_eq!(state.apply(XEmbedMessage::RequestFocus), None);
+        assert_eq!(
+            state.apply(XEmbedMessage::FocusOut),
+            Some(ClientEvent::FocusOut)
+        );
+        assert!(!state.focused);
+        state.unembed();
+        assert_eq!(state, ClientState::default());
+    }
+}
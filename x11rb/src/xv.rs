@@ -0,0 +1,366 @@
+//! Showing video frames with the XVideo extension.
+//!
+//! XVideo lets the X11 server convert YUV images to RGB and scale them, often with the help of
+//! the graphics hardware. A [`VideoPort`] is a port of an adaptor that was grabbed for showing
+//! images in a given format, e.g. [`FOURCC_I420`] or [`FOURCC_NV12`]. Frames are uploaded with
+//! [`VideoPort::put_image`] or, with shared memory, with [`VideoPort::put_frame`].
+//!
+//! The code in this module is only available when the `xv` feature of the library is enabled.
+//! [`VideoFrame`] additionally needs the `image` and `allow-unsafe-code` features on Unix.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # #[cfg(all(unix, feature = "image", feature = "allow-unsafe-code"))]
+//! # {
+//! use x11rb::protocol::xproto::Rectangle;
+//! use x11rb::xv::{VideoPort, FOURCC_I420};
+//!
+//! let (conn, _) = x11rb::connect(None)?;
+//! # let (window, gc) = (0, 0);
+//! let port = VideoPort::open(&conn, window, FOURCC_I420)?.expect("No XVideo port for I420");
+//! let mut frame = port.create_frame(FOURCC_I420, 640, 480)?;
+//! frame.data_mut().fill(0x80);
+//! let dst = Rectangle { x: 0, y: 0, width: 1280, height: 960 };
+//! port.put_frame(window, gc, &frame, dst)?;
+//! # }
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::ops::Range;
+
+use crate::connection::Connection;
+use crate::errors::{ConnectionError, ReplyError};
+use crate::protocol::xproto::{self, Drawable, Gcontext, Rectangle, Window};
+use crate::protocol::xv::{self, AttributeInfo, GrabPortStatus, ImageFormatInfo, Port, Type};
+use crate::CURRENT_TIME;
+
+#[cfg(all(unix, feature = "image", feature = "allow-unsafe-code"))]
+use crate::shm_image::ShmBuffer;
+
+/// Get the image format id of a FOURCC code, e.g. `fourcc(*b"I420")`.
+pub const fn fourcc(code: [u8; 4]) -> u32 {
+    u32::from_le_bytes(code)
+}
+
+/// Planar YUV 4:2:0 with a Y plane followed by a U and a V plane.
+pub const FOURCC_I420: u32 = fourcc(*b"I420");
+
+/// Planar YUV 4:2:0 with a Y plane followed by a V and a U plane.
+pub const FOURCC_YV12: u32 = fourcc(*b"YV12");
+
+/// YUV 4:2:0 with a Y plane followed by a plane with interleaved U and V samples.
+pub const FOURCC_NV12: u32 = fourcc(*b"NV12");
+
+/// Packed YUV 4:2:2 in the order Y0, U, Y1, V.
+pub const FOURCC_YUY2: u32 = fourcc(*b"YUY2");
+
+/// How the X11 server expects the planes of an image in memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameLayout {
+    /// The width of the image, which the server may have rounded up.
+    pub width: u16,
+
+    /// The height of the image, which the server may have rounded up.
+    pub height: u16,
+
+    /// The size of the image data in bytes.
+    pub data_size: u32,
+
+    /// The number of bytes per row of each plane.
+    pub pitches: Vec<u32>,
+
+    /// The offset of each plane in the image data.
+    pub offsets: Vec<u32>,
+}
+
+impl FrameLayout {
+    /// Get the byte range of a plane in the image data, e.g. of the Y plane with index 0.
+    ///
+    /// A plane ends where the next plane starts or at the end of the data. Returns `None` if the
+    /// plane does not exist.
+    pub fn plane_range(&self, plane: usize) -> Option<Range<usize>> {
+        let start = *self.offsets.get(plane)?;
+        let end = self
+            .offsets
+            .iter()
+            .copied()
+            .filter(|offset| *offset > start)
+            .min()
+            .unwrap_or(self.data_size);
+        Some(usize::try_from(start).ok()?..usize::try_from(end).ok()?)
+    }
+}
+
+/// A grabbed XVideo port that can show images.
+///
+/// The port is ungrabbed when this is dropped.
+#[derive(Debug)]
+pub struct VideoPort<'c, C: Connection> {
+    conn: &'c C,
+    port: Port,
+    formats: Vec<ImageFormatInfo>,
+}
+
+impl<'c, C: Connection> VideoPort<'c, C> {
+    /// Find and grab a port that can show images with the given format in `window`.
+    ///
+    /// All image adaptors for the screen of `window` are searched for a port that supports
+    /// `format` and is not grabbed by another client. Returns `None` if there is no such port or
+    /// the server does not support XVideo.
+    pub fn open(conn: &'c C, window: Window, format: u32) -> Result<Option<Self>, ReplyError> {
+        if conn
+            .extension_information(xv::X11_EXTENSION_NAME)?
+            .is_none()
+        {
+            return Ok(None);
+        }
+        let adaptors = xv::query_adaptors(conn, window)?.reply()?.info;
+        for adaptor in adaptors {
+            if !adaptor.type_.contains(Type::INPUT_MASK | Type::IMAGE_MASK) {
+                continue;
+            }
+            for port in (0..adaptor.num_ports).map(|i| adaptor.base_id + u32::from(i)) {
+                let formats = xv::list_image_formats(conn, port)?.reply()?.format;
+                if !formats.iter().any(|info| info.id == format) {
+                    // All ports of an adaptor support the same formats
+                    break;
+                }
+                let status = xv::grab_port(conn, port, CURRENT_TIME)?.reply()?.result;
+                if status == GrabPortStatus::SUCCESS {
+                    return Ok(Some(Self {
+                        conn,
+                        port,
+                        formats,
+                    }));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Get the grabbed port.
+    pub fn port(&self) -> Port {
+        self.port
+    }
+
+    /// Get the image formats that the port supports.
+    pub fn formats(&self) -> &[ImageFormatInfo] {
+        &self.formats
+    }
+
+    /// Check whether the port supports an image format.
+    pub fn supports_format(&self, format: u32) -> bool {
+        self.formats.iter().any(|info| info.id == format)
+    }
+
+    /// Query how an image with the given format and size is laid out in memory.
+    pub fn frame_layout(
+        &self,
+        format: u32,
+        width: u16,
+        height: u16,
+    ) -> Result<FrameLayout, ReplyError> {
+        let reply =
+            xv::query_image_attributes(self.conn, self.port, format, width, height)?.reply()?;
+        Ok(FrameLayout {
+            width: reply.width,
+            height: reply.height,
+            data_size: reply.data_size,
+            pitches: reply.pitches,
+            offsets: reply.offsets,
+        })
+    }
+
+    /// Show an image in `drawable`, scaled to `dst`.
+    ///
+    /// `data` contains the image in the given format and `(width, height)` as described by
+    /// [`VideoPort::frame_layout`]. The image is sent through the X11 connection.
+    pub fn put_image(
+        &self,
+        drawable: Drawable,
+        gc: Gcontext,
+        format: u32,
+        (width, height): (u16, u16),
+        data: &[u8],
+        dst: Rectangle,
+    ) -> Result<(), ConnectionError> {
+        let _ = xv::put_image(
+            self.conn, self.port, drawable, gc, format, 0, 0, width, height, dst.x, dst.y,
+            dst.width, dst.height, width, height, data,
+        )?;
+        Ok(())
+    }
+
+    /// Get the attributes of the port, e.g. `XV_BRIGHTNESS`.
+    pub fn attributes(&self) -> Result<Vec<AttributeInfo>, ReplyError> {
+        Ok(xv::query_port_attributes(self.conn, self.port)?
+            .reply()?
+            .attributes)
+    }
+
+    /// Get the value of an attribute.
+    pub fn attribute(&self, name: &str) -> Result<i32, ReplyError> {
+        let atom = self.intern(name)?;
+        Ok(xv::get_port_attribute(self.conn, self.port, atom)?
+            .reply()?
+            .value)
+    }
+
+    /// Set the value of an attribute.
+    ///
+    /// The allowed range of values is described by [`VideoPort::attributes`].
+    pub fn set_attribute(&self, name: &str, value: i32) -> Result<(), ReplyError> {
+        let atom = self.intern(name)?;
+        xv::set_port_attribute(self.conn, self.port, atom, value)?.check()
+    }
+
+    fn intern(&self, name: &str) -> Result<xproto::Atom, ReplyError> {
+        Ok(xproto::intern_atom(self.conn, false, name.as_bytes())?
+            .reply()?
+            .atom)
+    }
+}
+
+#[cfg(all(unix, feature = "image", feature = "allow-unsafe-code"))]
+impl<'c, C: Connection> VideoPort<'c, C> {
+    /// Create a frame for images with the given format and size.
+    pub fn create_frame(
+        &self,
+        format: u32,
+        width: u16,
+        height: u16,
+    ) -> Result<VideoFrame<'c, C>, ReplyError> {
+        let layout = self.frame_layout(format, width, height)?;
+        let len = usize::try_from(layout.data_size).unwrap();
+        Ok(VideoFrame {
+            format,
+            buffer: ShmBuffer::new(self.conn, len)?,
+            layout,
+        })
+    }
+
+    /// Show a frame in `drawable`, scaled to `dst`.
+    ///
+    /// Shared memory is used if possible. This waits until the X11 server processed the frame, so
+    /// that it can be changed again afterwards.
+    pub fn put_frame(
+        &self,
+        drawable: Drawable,
+        gc: Gcontext,
+        frame: &VideoFrame<'_, C>,
+        dst: Rectangle,
+    ) -> Result<(), ReplyError> {
+        let (width, height) = (frame.layout.width, frame.layout.height);
+        match frame.buffer.segment() {
+            Some(seg) => {
+                let _ = xv::shm_put_image(
+                    self.conn,
+                    self.port,
+                    drawable,
+                    gc,
+                    seg,
+                    frame.format,
+                    0,
+                    0,
+                    0,
+                    width,
+                    height,
+                    dst.x,
+                    dst.y,
+                    dst.width,
+                    dst.height,
+                    width,
+                    height,
+                    0,
+                )?;
+            }
+            None => self.put_image(
+                drawable,
+                gc,
+                frame.format,
+                (width, height),
+                frame.buffer.data(),
+                dst,
+            )?,
+        }
+        // A round trip guarantees that the server is done with the shared memory
+        let _ = xproto::get_input_focus(self.conn)?.reply()?;
+        Ok(())
+    }
+}
+
+impl<C: Connection> Drop for VideoPort<'_, C> {
+    fn drop(&mut self) {
+        let _ = xv::ungrab_port(self.conn, self.port, CURRENT_TIME);
+    }
+}
+
+/// An image for a [`VideoPort`] that is shared with the X11 server if possible.
+///
+/// The planes of the image are stored as described by [`VideoFrame::layout`].
+#[cfg(all(unix, feature = "image", feature = "allow-unsafe-code"))]
+#[derive(Debug)]
+pub struct VideoFrame<'c, C: Connection> {
+    format: u32,
+    layout: FrameLayout,
+    buffer: ShmBuffer<'c, C>,
+}
+
+#[cfg(all(unix, feature = "image", feature = "allow-unsafe-code"))]
+impl<C: Connection> VideoFrame<'_, C> {
+    /// The image format of the frame.
+    pub fn format(&self) -> u32 {
+        self.format
+    }
+
+    /// How the planes of the frame are laid out in memory.
+    pub fn layout(&self) -> &FrameLayout {
+        &self.layout
+    }
+
+    /// The image data.
+    pub fn data(&self) -> &[u8] {
+        self.buffer.data()
+    }
+
+    /// Mutable access to the image data.
+    pub fn data_mut(&mut self) -> &mut [u8] {
+        self.buffer.data_mut()
+    }
+
+    /// Mutable access to one plane of the image data, e.g. the Y plane with index 0.
+    ///
+    /// Returns `None` if the plane does not exist.
+    pub fn plane_mut(&mut self, plane: usize) -> Option<&mut [u8]> {
+        let range = self.layout.plane_range(plane)?;
+        self.buffer.data_mut().get_mut(range)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{fourcc, FrameLayout, FOURCC_I420};
+
+    #[test]
+    fn fourcc_codes() {
+        assert_eq!(FOURCC_I420, 0x3032_3449);
+        assert_eq!(fourcc(*b"YUY2"), 0x3259_5559);
+    }
+
+    #[test]
+    fn plane_ranges() {
+        // I420 with 4x2 pixels
+        let layout = FrameLayout {
+            width: 4,
+            height: 2,
+            data_size: 12,
+            pitches: vec![4, 2, 2],
+            offsets: vec![0, 8, 10],
+        };
+        assert_eq!(layout.plane_range(0), Some(0..8));
+        assert_eq!(layout.plane_range(1), Some(8..10));
+        assert_eq!(layout.plane_range(2), Some(10..12));
+        assert_eq!(layout.plane_range(3), None);
+    }
+}
@@ -247,6 +247,108 @@ fn test_too_large_request() {
     };
 }
 
+#[test]
+fn test_change_property_chunked() -> Result<(), ConnectionError> {
+    use x11rb::protocol::xproto::{AtomEnum, PropMode};
+    use x11rb::wrapper::ConnectionExt as _;
+
+    let conn = FakeConnection::default();
+    let data = vec![0; 600_000];
+    let cookies = conn.change_property_chunked(
+        PropMode::REPLACE,
+        1,
+        AtomEnum::WM_NAME,
+        AtomEnum::STRING,
+        32,
+        &data,
+    )?;
+    assert_eq!(cookies.len(), 2);
+    cookies.into_iter().for_each(VoidCookie::ignore_error);
+
+    // The first part replaces the property, the rest is appended
+    let requests = conn.0.borrow();
+    let modes = requests.iter().map(|r| r.data[1]).collect::<Vec<_>>();
+    assert_eq!(
+        modes,
+        [PropMode::REPLACE.into(), u8::from(PropMode::APPEND)]
+    );
+    // Four bytes for the big request length field in the first request
+    let sizes = requests.iter().map(|r| r.data.len()).collect::<Vec<_>>();
+    assert_eq!(sizes, [(1 << 19) + 4, 24 + 600_000 - ((1 << 19) - 24)]);
+    Ok(())
+}
+
+#[test]
+fn test_put_image_auto() -> Result<(), ConnectionError> {
+    use x11rb::protocol::xproto::ImageFormat;
+    use x11rb::wrapper::ConnectionExt as _;
+
+    let conn = FakeConnection::default();
+    let (stride, height) = (4000, 200);
+    let data = (0..stride * height)
+        .map(|i| (i / stride) as u8)
+        .collect::<Vec<_>>();
+    let cookies = conn.put_image_auto(
+        ImageFormat::Z_PIXMAP,
+        1,
+        2,
+        1000,
+        height as u16,
+        0,
+        10,
+        0,
+        24,
+        &data,
+    )?;
+    assert_eq!(cookies.len(), 2);
+    cookies.into_iter().for_each(VoidCookie::ignore_error);
+
+    // The image is split into bands of whole scanlines
+    // Both requests are big requests, so all fields are shifted by four bytes
+    let requests = conn.0.borrow();
+    let lines_per_request = ((1 << 19) - 24) / stride;
+    let first = &requests[0].data;
+    assert_eq!(first[18..20], (lines_per_request as u16).to_ne_bytes());
+    assert_eq!(first[22..24], 10i16.to_ne_bytes());
+    let second = &requests[1].data;
+    let second_lines = (height - lines_per_request) as u16;
+    assert_eq!(second[18..20], second_lines.to_ne_bytes());
+    assert_eq!(
+        second[22..24],
+        (10 + lines_per_request as i16).to_ne_bytes()
+    );
+    assert_eq!(second[28], lines_per_request as u8);
+    Ok(())
+}
+
+#[test]
+fn test_put_image_auto_y_overflow() {
+    use x11rb::protocol::xproto::ImageFormat;
+    use x11rb::wrapper::ConnectionExt as _;
+
+    // The second band would start below i16::MAX
+    let conn = FakeConnection::default();
+    let (stride, height) = (4000, 200);
+    let data = vec![0; stride * height];
+    let result = conn.put_image_auto(
+        ImageFormat::Z_PIXMAP,
+        1,
+        2,
+        1000,
+        height as u16,
+        0,
+        i16::MAX - 10,
+        0,
+        24,
+        &data,
+    );
+    assert!(matches!(
+        result,
+        Err(ConnectionError::MaximumRequestLengthExceeded)
+    ));
+    assert!(conn.0.borrow().is_empty());
+}
+
 #[test]
 fn test_send_event() -> Result<(), ConnectionError> {
     // Prepare the event
@@ -272,6 +374,25 @@ fn test_send_event() -> Result<(), ConnectionError> {
     Ok(())
 }
 
+#[test]
+fn test_request_builder() -> Result<(), ConnectionError> {
+    use x11rb::protocol::xproto::{ConfigureWindowAux, ConfigureWindowRequest, StackMode};
+    use x11rb::x11_utils::VoidRequestExt;
+
+    let conn = FakeConnection::default();
+    let aux = ConfigureWindowAux::new().x(5).stack_mode(StackMode::ABOVE);
+    conn.configure_window(0x1234, &aux)?;
+    ConfigureWindowRequest::new(0x1234)
+        .x(5)
+        .stack_mode(StackMode::ABOVE)
+        .send(&conn)?;
+
+    let requests = conn.0.borrow();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].data, requests[1].data);
+    Ok(())
+}
+
 #[test]
 fn test_get_keyboard_mapping() -> Result<(), ConnectionError> {
     let conn = FakeConnection::default();
@@ -340,3 +461,1419 @@ fn compile_test(conn: &impl RequestConnection) {
     use x11rb::protocol::xinput::{xi_query_device, Device};
     let _ = xi_query_device(conn, Device::ALL);
 }
+
+/// Listen on a local TCP port that can be reached as an X11 display.
+///
+/// Returns the listener and the display number, i.e. the port minus 6000.
+fn listen_as_display() -> (std::net::TcpListener, u16) {
+    // Ephemeral ports are usually above 6000, but the OS may hand out lower ones
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    if let Some(display) = port.checked_sub(6000) {
+        return (listener, display);
+    }
+    (6100..7000)
+        .find_map(|port| {
+            let listener = std::net::TcpListener::bind(("127.0.0.1", port)).ok()?;
+            Some((listener, port - 6000))
+        })
+        .expect("No free port for a display")
+}
+
+#[test]
+fn test_connect_with_timeout() {
+    use std::time::{Duration, Instant};
+    use x11rb::errors::ConnectError;
+    use x11rb::rust_connection::RustConnection;
+
+    // A server that accepts the TCP connection, but never answers the setup request
+    let (_listener, display) = listen_as_display();
+    let display = format!("127.0.0.1:{}", display);
+
+    let start = Instant::now();
+    let timeout = Duration::from_millis(100);
+    match RustConnection::connect_with_timeout(Some(&display), timeout) {
+        Err(ConnectError::Timeout) => {}
+        res => panic!("Unexpected result: {:?}", res.map(|_| ())),
+    }
+    assert!(start.elapsed() >= timeout);
+}
+
+/// A transport that is not backed by a socket
+#[derive(Debug)]
+struct ChannelStream {
+    incoming: std::sync::Mutex<Vec<u8>>,
+    outgoing: std::sync::Mutex<Vec<u8>>,
+}
+
+impl ChannelStream {
+    fn new(incoming: Vec<u8>) -> Self {
+        Self {
+            incoming: std::sync::Mutex::new(incoming),
+            outgoing: Default::default(),
+        }
+    }
+}
+
+impl x11rb::rust_connection::Stream for ChannelStream {
+    fn poll(&self, _: x11rb::rust_connection::PollMode) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn read(&self, buf: &mut [u8], _: &mut Vec<RawFdContainer>) -> std::io::Result<usize> {
+        let mut incoming = self.incoming.lock().unwrap();
+        if incoming.is_empty() {
+            return Err(std::io::ErrorKind::WouldBlock.into());
+        }
+        let amount = buf.len().min(incoming.len());
+        buf[..amount].copy_from_slice(&incoming[..amount]);
+        let _ = incoming.drain(..amount);
+        Ok(amount)
+    }
+
+    fn write(&self, buf: &[u8], _: &mut Vec<RawFdContainer>) -> std::io::Result<usize> {
+        self.outgoing.lock().unwrap().extend(buf);
+        Ok(buf.len())
+    }
+}
+
+fn test_setup() -> x11rb::protocol::xproto::Setup {
+    x11rb::protocol::xproto::Setup {
+        status: 1,
+        resource_id_mask: 0xff,
+        roots: vec![Default::default()],
+        ..Default::default()
+    }
+}
+
+/// Serialize the setup from `test_setup()` as the X11 server would send it
+fn test_setup_data() -> Vec<u8> {
+    let mut data = test_setup().serialize();
+    let length = u16::try_from((data.len() - 8) / 4).unwrap();
+    data[6..8].copy_from_slice(&length.to_ne_bytes());
+    data
+}
+
+/// Act as the X11 server: Receive the setup request and answer it
+fn answer_setup_request(server: &mut (impl std::io::Read + std::io::Write)) {
+    read_setup_request(server);
+    server.write_all(&test_setup_data()).unwrap();
+}
+
+/// Act as the X11 server: Receive the setup request
+fn read_setup_request(server: &mut impl std::io::Read) {
+    let mut request = [0; 12];
+    server.read_exact(&mut request).unwrap();
+    let auth_name_len = usize::from(u16::from_ne_bytes([request[6], request[7]]));
+    let auth_data_len = usize::from(u16::from_ne_bytes([request[8], request[9]]));
+    let padded = |len: usize| (len + 3) & !3;
+    let mut auth = vec![0; padded(auth_name_len) + padded(auth_data_len)];
+    server.read_exact(&mut auth).unwrap();
+}
+
+#[test]
+fn test_connect_to_custom_stream() {
+    use x11rb::connection::Connection;
+    use x11rb::rust_connection::{RustConnection, Stream};
+
+    let data = test_setup_data();
+    let stream: Box<dyn Stream> = Box::new(ChannelStream::new(data));
+    let conn = RustConnection::connect_to_stream(stream, 0).unwrap();
+    assert_eq!(conn.setup().roots.len(), 1);
+}
+
+#[test]
+fn test_connect_to_stream_nonblocking() {
+    use x11rb::connection::Connection;
+    use x11rb::rust_connection::{ConnectStep, PollMode, RustConnection};
+
+    // The setup was not received yet, so the handshake has to wait
+    let connect = RustConnection::connect_to_stream_nonblocking(
+        ChannelStream::new(Vec::new()),
+        0,
+        vec![],
+        vec![],
+    );
+    let connect = match connect.advance().unwrap() {
+        ConnectStep::Pending(connect) => connect,
+        ConnectStep::Connected(..) => panic!("Connected without a setup"),
+    };
+    assert!(matches!(connect.poll_mode(), PollMode::Readable));
+    assert_eq!(connect.stream().outgoing.lock().unwrap().len(), 12);
+
+    connect
+        .stream()
+        .incoming
+        .lock()
+        .unwrap()
+        .extend(test_setup_data());
+    match connect.advance().unwrap() {
+        ConnectStep::Connected(conn, screen) => {
+            assert_eq!(screen, 0);
+            assert_eq!(conn.setup().roots.len(), 1);
+        }
+        ConnectStep::Pending(_) => panic!("Setup was not received"),
+    }
+}
+
+#[test]
+fn test_connection_hooks() -> Result<(), ReplyError> {
+    use std::sync::{Arc, Mutex};
+    use x11rb::connection::Connection;
+    use x11rb::rust_connection::{ConnectionHooks, RustConnection};
+    use x11rb::x11_utils::X11Error;
+
+    #[derive(Debug, Default)]
+    struct RecordingHooks(Mutex<Vec<String>>);
+
+    impl ConnectionHooks for RecordingHooks {
+        fn on_request(&self, sequence: SequenceNumber, major_opcode: u8, request: &[u8]) {
+            let entry = format!("request {} {} {}", sequence, major_opcode, request.len());
+            self.0.lock().unwrap().push(entry);
+        }
+
+        fn on_reply(&self, sequence: SequenceNumber, reply: &[u8]) {
+            let entry = format!("reply {} {}", sequence, reply.len());
+            self.0.lock().unwrap().push(entry);
+        }
+
+        fn on_event(&self, event: &[u8]) {
+            self.0.lock().unwrap().push(format!("event {}", event[0]));
+        }
+
+        fn on_error(&self, error: &X11Error) {
+            let entry = format!("error {:?} {}", error.error_kind, error.sequence);
+            self.0.lock().unwrap().push(entry);
+        }
+    }
+
+    // A reply to GetInputFocus, an error for MapWindow, and a MapNotify event
+    let packet = |response_type: u8, detail: u8, sequence: u16| {
+        let mut packet = vec![0; 32];
+        packet[0] = response_type;
+        packet[1] = detail;
+        packet[2..4].copy_from_slice(&sequence.to_ne_bytes());
+        packet
+    };
+    let mut data = packet(1, 0, 1);
+    data.extend(packet(0, 3, 2));
+    data.extend(packet(19, 0, 2));
+
+    let conn =
+        RustConnection::for_connected_stream(ChannelStream::new(data), test_setup()).unwrap();
+    let hooks = Arc::new(RecordingHooks::default());
+    conn.set_hooks(Some(hooks.clone()));
+
+    let cookie = conn.get_input_focus()?;
+    conn.map_window(1)?;
+    let _ = cookie.reply()?;
+    assert!(conn.poll_for_event()?.is_some());
+    assert!(conn.poll_for_event()?.is_some());
+
+    assert_eq!(
+        *hooks.0.lock().unwrap(),
+        [
+            "request 1 43 4",
+            "request 2 8 8",
+            "reply 1 32",
+            "error Window 2",
+            "event 19",
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_connection_hooks_extension_error() -> Result<(), ReplyError> {
+    use std::sync::{Arc, Mutex};
+    use x11rb::rust_connection::{ConnectionHooks, RustConnection};
+    use x11rb::x11_utils::X11Error;
+
+    #[derive(Debug, Default)]
+    struct RecordingHooks(Mutex<Vec<Option<String>>>);
+
+    impl ConnectionHooks for RecordingHooks {
+        fn on_error(&self, error: &X11Error) {
+            self.0.lock().unwrap().push(error.extension_name.clone());
+        }
+    }
+
+    let packet = |response_type: u8, sequence: u16| {
+        let mut packet = vec![0; 32];
+        packet[0] = response_type;
+        packet[2..4].copy_from_slice(&sequence.to_ne_bytes());
+        packet
+    };
+    // QueryExtension reply for FOO with major opcode 130
+    let mut data = packet(1, 1);
+    data[8] = 1;
+    data[9] = 130;
+
+    let conn =
+        RustConnection::for_connected_stream(ChannelStream::new(data), test_setup()).unwrap();
+    let hooks = Arc::new(RecordingHooks::default());
+    conn.set_hooks(Some(hooks.clone()));
+
+    assert!(conn.extension_information("FOO")?.is_some());
+    conn.map_window(1)?;
+
+    // An error for a FOO request. It arrives while the extension manager is busy with BAR.
+    let mut error = packet(0, 2);
+    error[1] = 3;
+    error[10] = 130;
+    let mut incoming = conn.stream().incoming.lock().unwrap();
+    incoming.extend(error);
+    // QueryExtension reply for BAR, which is not present
+    incoming.extend(packet(1, 3));
+    drop(incoming);
+
+    assert_eq!(conn.extension_information("BAR")?, None);
+
+    assert_eq!(*hooks.0.lock().unwrap(), [Some("FOO".to_string())]);
+    Ok(())
+}
+
+#[test]
+fn test_prefetch_all_extensions() -> Result<(), ReplyError> {
+    use std::sync::{Arc, Mutex};
+    use x11rb::connection::Connection;
+    use x11rb::rust_connection::{ConnectionHooks, RustConnection};
+
+    #[derive(Debug, Default)]
+    struct RecordingHooks(Mutex<Vec<String>>);
+
+    impl ConnectionHooks for RecordingHooks {
+        fn on_request(&self, sequence: SequenceNumber, _: u8, _: &[u8]) {
+            self.0.lock().unwrap().push(format!("request {}", sequence));
+        }
+
+        fn on_reply(&self, sequence: SequenceNumber, _: &[u8]) {
+            self.0.lock().unwrap().push(format!("reply {}", sequence));
+        }
+    }
+
+    // QueryExtension replies: the first extension is present, the second one is not
+    let reply = |sequence: u16, present: u8, major_opcode: u8| {
+        let mut packet = vec![0; 32];
+        packet[0] = 1;
+        packet[2..4].copy_from_slice(&sequence.to_ne_bytes());
+        packet[8] = present;
+        packet[9] = major_opcode;
+        packet
+    };
+    let mut data = reply(1, 1, 130);
+    data.extend(reply(2, 0, 0));
+
+    let conn =
+        RustConnection::for_connected_stream(ChannelStream::new(data), test_setup()).unwrap();
+    let hooks = Arc::new(RecordingHooks::default());
+    conn.set_hooks(Some(hooks.clone()));
+
+    conn.prefetch_all_extensions(&["FOO", "BAR"])?;
+    assert!(conn.stream().outgoing.lock().unwrap().is_empty());
+
+    // A single flush sends both QueryExtension requests
+    conn.flush()?;
+    let outgoing = std::mem::take(&mut *conn.stream().outgoing.lock().unwrap());
+    assert_eq!(outgoing.len(), 2 * 12);
+    assert_eq!(&outgoing[8..11], b"FOO");
+    assert_eq!(&outgoing[20..23], b"BAR");
+
+    let foo = conn.extension_information("FOO")?;
+    let bar = conn.extension_information("BAR")?;
+    assert_eq!(foo.map(|info| info.major_opcode), Some(130));
+    assert_eq!(bar, None);
+
+    // Both requests were sent before the first reply was awaited
+    assert_eq!(
+        *hooks.0.lock().unwrap(),
+        ["request 1", "request 2", "reply 1", "reply 2"]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_connect_nonblocking() {
+    use x11rb::connection::Connection;
+    use x11rb::rust_connection::{ConnectStep, NonBlockingConnect, PollMode, RustConnection};
+
+    // Advance until the connection is established or, optionally, waits for reading
+    fn advance_until(mut connect: NonBlockingConnect, wait_for_reading: bool) -> ConnectStep {
+        loop {
+            connect = match connect.advance().unwrap() {
+                ConnectStep::Pending(connect) => connect,
+                step @ ConnectStep::Connected(..) => return step,
+            };
+            if wait_for_reading && matches!(connect.poll_mode(), PollMode::Readable) {
+                return ConnectStep::Pending(connect);
+            }
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+    }
+
+    let (listener, display) = listen_as_display();
+    let display = format!("127.0.0.1:{}.0", display);
+
+    // Nothing is sent by the server, so the setup request is written and then we wait
+    let connect = RustConnection::connect_nonblocking(Some(&display)).unwrap();
+    let connect = match advance_until(connect, true) {
+        ConnectStep::Pending(connect) => connect,
+        ConnectStep::Connected(..) => panic!("Connected without a setup reply"),
+    };
+
+    let (mut server, _) = listener.accept().unwrap();
+    answer_setup_request(&mut server);
+
+    let (conn, screen) = match advance_until(connect, false) {
+        ConnectStep::Connected(conn, screen) => (conn, screen),
+        ConnectStep::Pending(_) => unreachable!(),
+    };
+    assert_eq!(screen, 0);
+    assert_eq!(conn.setup().roots.len(), 1);
+}
+
+#[test]
+fn test_connect_nonblocking_unsupported_address() {
+    use x11rb::errors::ConnectError;
+    use x11rb::rust_connection::RustConnection;
+
+    // Named pipes are not supported by DefaultStream
+    match RustConnection::connect_nonblocking(Some(r"\\.\pipe\X11\0")) {
+        Err(ConnectError::IoError(e)) => assert_eq!(e.kind(), std::io::ErrorKind::Other),
+        result => panic!("Unexpected result {:?}", result.map(|_| ())),
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn test_connect_to_address() {
+    use x11rb::connection::Connection;
+    use x11rb::reexports::x11rb_protocol::parse_display::ConnectAddress;
+    use x11rb::rust_connection::{AbstractSocket, RustConnection};
+
+    let dir = std::env::temp_dir().join(format!("x11rb-test-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("X0").to_str().unwrap().to_string();
+    let listener = std::os::unix::net::UnixListener::bind(&path).unwrap();
+    let addr = ConnectAddress::Socket(path.clone());
+
+    // Nothing listens in the abstract namespace
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    assert!(RustConnection::connect_to_address(&addr, 0, 0, AbstractSocket::Only).is_err());
+
+    let server =
+        std::thread::spawn(move || answer_setup_request(&mut listener.accept().unwrap().0));
+    let conn = RustConnection::connect_to_address(&addr, 0, 0, AbstractSocket::Never).unwrap();
+    server.join().unwrap();
+    assert_eq!(conn.setup().roots.len(), 1);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[cfg(unix)]
+#[test]
+fn test_connect_to_fd() {
+    use x11rb::connection::Connection;
+    use x11rb::rust_connection::RustConnection;
+
+    let (client, mut server) = std::os::unix::net::UnixStream::pair().unwrap();
+    let server = std::thread::spawn(move || answer_setup_request(&mut server));
+    let conn = RustConnection::connect_to_fd(client.into(), 0, Vec::new(), Vec::new()).unwrap();
+    server.join().unwrap();
+    assert_eq!(conn.setup().roots.len(), 1);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn test_tcp_options() {
+    use std::net::{TcpListener, TcpStream};
+    use std::time::Duration;
+    use x11rb::rust_connection::{DefaultStream, TcpKeepalive};
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+    let _server = listener.accept().unwrap();
+    // Socket options are shared between the clones
+    let observer = client.try_clone().unwrap();
+
+    let (stream, _) = DefaultStream::from_tcp_stream(client).unwrap();
+    assert!(observer.nodelay().unwrap());
+    stream.set_tcp_nodelay(false).unwrap();
+    assert!(!observer.nodelay().unwrap());
+
+    let keepalive = TcpKeepalive {
+        idle: Some(Duration::from_secs(30)),
+        interval: Some(Duration::from_secs(5)),
+        retries: Some(3),
+    };
+    stream.set_tcp_keepalive(Some(keepalive)).unwrap();
+    stream.set_tcp_keepalive(None).unwrap();
+}
+
+#[cfg(unix)]
+#[test]
+fn test_deferred_flush() {
+    use std::io::Read;
+    use std::time::Duration;
+    use x11rb::connection::Connection;
+    use x11rb::rust_connection::RustConnection;
+
+    let (client, mut server) = std::os::unix::net::UnixStream::pair().unwrap();
+    let setup = std::thread::spawn(move || {
+        answer_setup_request(&mut server);
+        server
+    });
+    let conn = RustConnection::connect_to_fd(client.into(), 0, Vec::new(), Vec::new()).unwrap();
+    let mut server = setup.join().unwrap();
+    server.set_nonblocking(true).unwrap();
+    let mut buffer = [0; 64];
+
+    conn.set_deferred_flush(Some(Duration::from_secs(3600)))
+        .unwrap();
+    assert_eq!(conn.flush_deadline(), None);
+    let _ = conn.no_operation().unwrap();
+    let _ = conn.no_operation().unwrap();
+
+    // The requests are held back, even when flushing
+    conn.flush().unwrap();
+    assert!(conn.flush_deadline().is_some());
+    assert_eq!(
+        server.read(&mut buffer).unwrap_err().kind(),
+        std::io::ErrorKind::WouldBlock
+    );
+
+    // flush_all() is not held back
+    conn.flush_all().unwrap();
+    assert_eq!(conn.flush_deadline(), None);
+    assert_eq!(server.read(&mut buffer).unwrap(), 8);
+    let _ = conn.no_operation().unwrap();
+    let _ = conn.no_operation().unwrap();
+
+    // Disabling deferred flushing sends everything at once
+    conn.set_deferred_flush(None).unwrap();
+    assert_eq!(conn.flush_deadline(), None);
+    assert_eq!(server.read(&mut buffer).unwrap(), 8);
+
+    // Once the interval has passed, the next request triggers a flush
+    conn.set_deferred_flush(Some(Duration::ZERO)).unwrap();
+    let _ = conn.no_operation().unwrap();
+    assert_eq!(server.read(&mut buffer).unwrap(), 4);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_run_loop_deferred_flush() {
+    use std::io::{Read, Write};
+    use std::os::unix::io::AsFd;
+    use std::time::Duration;
+    use x11rb::protocol::xproto::{ExposeEvent, EXPOSE_EVENT};
+    use x11rb::protocol::Event;
+    use x11rb::run_loop::{LoopEvent, RunLoop};
+    use x11rb::rust_connection::RustConnection;
+
+    let (client, mut server) = std::os::unix::net::UnixStream::pair().unwrap();
+    let setup = std::thread::spawn(move || {
+        answer_setup_request(&mut server);
+        server
+    });
+    let conn = RustConnection::connect_to_fd(client.into(), 0, Vec::new(), Vec::new()).unwrap();
+    let mut server = setup.join().unwrap();
+
+    // The server only sends the event after it received the request
+    let server = std::thread::spawn(move || {
+        let mut request = [0; 4];
+        server.read_exact(&mut request).unwrap();
+        let event = ExposeEvent {
+            response_type: EXPOSE_EVENT,
+            window: 1,
+            ..Default::default()
+        };
+        server.write_all(&<[u8; 32]>::from(event)).unwrap();
+        server
+    });
+
+    conn.set_deferred_flush(Some(Duration::from_secs(3600)))
+        .unwrap();
+    let _ = conn.no_operation().unwrap();
+    let mut run_loop = RunLoop::new(&conn, conn.stream().as_fd());
+    // Fail instead of hanging if the request is held back
+    let _ = run_loop.add_timer(Duration::from_secs(10));
+    match run_loop.next_event().unwrap() {
+        LoopEvent::X11(Event::Expose(event)) => assert_eq!(event.window, 1),
+        event => panic!("Unexpected event {:?}", event),
+    }
+    let _server = server.join().unwrap();
+}
+
+#[cfg(all(unix, feature = "calloop"))]
+#[test]
+fn test_calloop_source() {
+    use std::io::Write;
+    use std::sync::Arc;
+    use std::time::Duration;
+    use x11rb::calloop_source::X11Source;
+    use x11rb::protocol::xproto::{ExposeEvent, EXPOSE_EVENT};
+    use x11rb::protocol::Event;
+    use x11rb::rust_connection::RustConnection;
+
+    let (client, mut server) = std::os::unix::net::UnixStream::pair().unwrap();
+    let setup = std::thread::spawn(move || {
+        answer_setup_request(&mut server);
+        server
+    });
+    let conn = RustConnection::connect_to_fd(client.into(), 0, Vec::new(), Vec::new()).unwrap();
+    let mut server = setup.join().unwrap();
+    let conn = Arc::new(conn);
+
+    let expose = |window| {
+        <[u8; 32]>::from(ExposeEvent {
+            response_type: EXPOSE_EVENT,
+            window,
+            ..Default::default()
+        })
+    };
+    // An event followed by the reply to GetInputFocus
+    let mut reply = [0; 32];
+    reply[0] = 1;
+    reply[2] = 1;
+    server.write_all(&expose(1)).unwrap();
+    server.write_all(&reply).unwrap();
+
+    let mut event_loop = calloop::EventLoop::try_new().unwrap();
+    let _ = event_loop
+        .handle()
+        .insert_source(
+            X11Source::new(Arc::clone(&conn)),
+            |event, _, windows: &mut Vec<u32>| match event {
+                Event::Expose(event) => windows.push(event.window),
+                event => panic!("Unexpected event {:?}", event),
+            },
+        )
+        .unwrap();
+
+    // Waiting for the reply puts the event into the queue without anything left to read
+    let _ = conn.get_input_focus().unwrap().reply().unwrap();
+    let mut windows = Vec::new();
+    event_loop
+        .dispatch(Some(Duration::ZERO), &mut windows)
+        .unwrap();
+    assert_eq!(windows, [1]);
+
+    // Events that arrive later are delivered once the file descriptor becomes readable
+    server.write_all(&expose(2)).unwrap();
+    server.write_all(&expose(3)).unwrap();
+    event_loop
+        .dispatch(Some(Duration::from_secs(10)), &mut windows)
+        .unwrap();
+    assert_eq!(windows, [1, 2, 3]);
+}
+
+#[cfg(all(unix, feature = "mio"))]
+#[test]
+fn test_mio_source() {
+    use mio::{Events, Interest, Poll, Token};
+    use std::io::{Read, Write};
+    use std::time::Duration;
+    use x11rb::connection::Connection;
+    use x11rb::protocol::xproto::{ExposeEvent, EXPOSE_EVENT};
+    use x11rb::protocol::Event;
+    use x11rb::rust_connection::RustConnection;
+
+    let (client, mut server) = std::os::unix::net::UnixStream::pair().unwrap();
+    let setup = std::thread::spawn(move || {
+        answer_setup_request(&mut server);
+        server
+    });
+    let mut conn = RustConnection::connect_to_fd(client.into(), 0, Vec::new(), Vec::new()).unwrap();
+    let mut server = setup.join().unwrap();
+    server.set_nonblocking(true).unwrap();
+
+    // Registering flushes pending requests
+    let _ = conn.no_operation().unwrap();
+    let mut buffer = [0; 8];
+    assert_eq!(
+        server.read(&mut buffer).unwrap_err().kind(),
+        std::io::ErrorKind::WouldBlock
+    );
+    let mut poll = Poll::new().unwrap();
+    poll.registry()
+        .register(&mut conn, Token(1), Interest::READABLE)
+        .unwrap();
+    assert_eq!(server.read(&mut buffer).unwrap(), 4);
+
+    let expose = <[u8; 32]>::from(ExposeEvent {
+        response_type: EXPOSE_EVENT,
+        window: 1,
+        ..Default::default()
+    });
+    server.write_all(&expose).unwrap();
+    let mut events = Events::with_capacity(4);
+    poll.poll(&mut events, Some(Duration::from_secs(10)))
+        .unwrap();
+    let tokens = events.iter().map(|event| event.token()).collect::<Vec<_>>();
+    assert_eq!(tokens, [Token(1)]);
+    match conn.poll_for_event().unwrap() {
+        Some(Event::Expose(event)) => assert_eq!(event.window, 1),
+        event => panic!("Unexpected event {:?}", event),
+    }
+
+    poll.registry().deregister(&mut conn).unwrap();
+}
+
+#[cfg(unix)]
+#[test]
+fn test_prepare_read() {
+    use std::io::{Read, Write};
+    use x11rb::connection::Connection;
+    use x11rb::protocol::xproto::{ExposeEvent, EXPOSE_EVENT};
+    use x11rb::protocol::Event;
+    use x11rb::rust_connection::RustConnection;
+
+    let (client, mut server) = std::os::unix::net::UnixStream::pair().unwrap();
+    let setup = std::thread::spawn(move || {
+        answer_setup_request(&mut server);
+        server
+    });
+    let conn = RustConnection::connect_to_fd(client.into(), 0, Vec::new(), Vec::new()).unwrap();
+    let mut server = setup.join().unwrap();
+    server.set_nonblocking(true).unwrap();
+
+    // Preparing to read flushes pending requests
+    let _ = conn.no_operation().unwrap();
+    let guard = conn.prepare_read().unwrap().unwrap();
+    let mut buffer = [0; 8];
+    assert_eq!(server.read(&mut buffer).unwrap(), 4);
+
+    // A cancelled read can be prepared again
+    let expose = |window| {
+        <[u8; 32]>::from(ExposeEvent {
+            response_type: EXPOSE_EVENT,
+            window,
+            ..Default::default()
+        })
+    };
+    server.write_all(&expose(1)).unwrap();
+    drop(guard);
+    let guard = conn.prepare_read().unwrap().unwrap();
+    guard.did_read().unwrap();
+
+    // Queued events have to be handled before waiting again
+    assert!(conn.prepare_read().unwrap().is_none());
+    match conn.poll_for_event().unwrap() {
+        Some(Event::Expose(event)) => assert_eq!(event.window, 1),
+        event => panic!("Unexpected event {:?}", event),
+    }
+    assert!(conn.prepare_read().unwrap().is_some());
+}
+
+#[test]
+fn test_prepare_read_after_flush_queued_events() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use x11rb::connection::Connection;
+    use x11rb::protocol::xproto::{ExposeEvent, EXPOSE_EVENT};
+    use x11rb::rust_connection::{PollMode, RustConnection, Stream};
+
+    /// A stream that cannot be written to until something was read
+    #[derive(Debug)]
+    struct ReadBeforeWrite {
+        inner: ChannelStream,
+        did_read: AtomicBool,
+    }
+
+    impl Stream for ReadBeforeWrite {
+        fn poll(&self, mode: PollMode) -> std::io::Result<()> {
+            self.inner.poll(mode)
+        }
+
+        fn read(&self, buf: &mut [u8], fds: &mut Vec<RawFdContainer>) -> std::io::Result<usize> {
+            self.did_read.store(true, Ordering::Relaxed);
+            self.inner.read(buf, fds)
+        }
+
+        fn write(&self, buf: &[u8], fds: &mut Vec<RawFdContainer>) -> std::io::Result<usize> {
+            if !self.did_read.load(Ordering::Relaxed) {
+                return Err(std::io::ErrorKind::WouldBlock.into());
+            }
+            self.inner.write(buf, fds)
+        }
+    }
+
+    let event = <[u8; 32]>::from(ExposeEvent {
+        response_type: EXPOSE_EVENT,
+        ..Default::default()
+    });
+    let stream = ReadBeforeWrite {
+        inner: ChannelStream::new(event.to_vec()),
+        did_read: AtomicBool::new(false),
+    };
+    let conn = RustConnection::for_connected_stream(stream, test_setup()).unwrap();
+
+    // Flushing the request reads the event, which then has to be handled first
+    let _ = conn.no_operation().unwrap();
+    assert!(conn.prepare_read().unwrap().is_none());
+    assert_eq!(conn.stream().inner.outgoing.lock().unwrap().len(), 4);
+    assert!(conn.poll_for_event().unwrap().is_some());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_error_handler() {
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+    use x11rb::connection::Connection;
+    use x11rb::protocol::{ErrorKind, Event};
+    use x11rb::rust_connection::RustConnection;
+
+    let (client, mut server) = std::os::unix::net::UnixStream::pair().unwrap();
+    let setup = std::thread::spawn(move || {
+        answer_setup_request(&mut server);
+        server
+    });
+    let conn = RustConnection::connect_to_fd(client.into(), 0, Vec::new(), Vec::new()).unwrap();
+    let mut server = setup.join().unwrap();
+
+    let errors = Arc::new(Mutex::new(Vec::new()));
+    let handler_errors = Arc::clone(&errors);
+    conn.set_error_handler(Some(Box::new(move |error| {
+        handler_errors
+            .lock()
+            .unwrap()
+            .push((error.error_kind, error.sequence))
+    })));
+
+    // A Value error for the unchecked request with sequence number 1, followed by the reply to
+    // the second request
+    let _ = conn.no_operation().unwrap();
+    let cookie = conn.get_input_focus().unwrap();
+    let mut error = [0; 32];
+    error[1] = 2;
+    error[2] = 1;
+    server.write_all(&error).unwrap();
+    let mut reply = [0; 32];
+    reply[0] = 1;
+    reply[2] = 2;
+    server.write_all(&reply).unwrap();
+    let _ = cookie.reply().unwrap();
+
+    assert!(conn.poll_for_event().unwrap().is_none());
+    assert_eq!(*errors.lock().unwrap(), [(ErrorKind::Value, 1)]);
+
+    // Without a handler, errors are events again
+    conn.set_error_handler(None);
+    let _ = conn.no_operation().unwrap();
+    conn.flush().unwrap();
+    error[2] = 3;
+    server.write_all(&error).unwrap();
+    match conn.wait_for_event().unwrap() {
+        Event::Error(error) => assert_eq!(error.sequence, 3),
+        event => panic!("Unexpected event {:?}", event),
+    }
+    assert_eq!(errors.lock().unwrap().len(), 1);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_tap() {
+    use std::io::Write;
+    use x11rb::connection::Connection;
+    use x11rb::protocol::xproto::{ExposeEvent, EXPOSE_EVENT};
+    use x11rb::rust_connection::{Incoming, RustConnection};
+
+    let (client, mut server) = std::os::unix::net::UnixStream::pair().unwrap();
+    let setup = std::thread::spawn(move || {
+        answer_setup_request(&mut server);
+        server
+    });
+    let conn = RustConnection::connect_to_fd(client.into(), 0, Vec::new(), Vec::new()).unwrap();
+    let mut server = setup.join().unwrap();
+    conn.set_tap_enabled(true);
+
+    // The reply is discarded since the cookie is dropped
+    let _ = conn.get_input_focus().unwrap();
+    conn.flush().unwrap();
+    let mut reply = [0; 32];
+    reply[0] = 1;
+    reply[2] = 1;
+    let event = <[u8; 32]>::from(ExposeEvent {
+        response_type: EXPOSE_EVENT,
+        sequence: 1,
+        ..Default::default()
+    });
+    server.write_all(&reply).unwrap();
+    server.write_all(&event).unwrap();
+
+    let mut tapped = Vec::new();
+    while tapped.len() < 2 {
+        tapped.extend(conn.poll_for_tapped_packet().unwrap());
+    }
+    assert_eq!(
+        tapped,
+        [
+            (Incoming::Reply(reply.to_vec()), 1),
+            (Incoming::Event(event.to_vec()), 1)
+        ]
+    );
+    assert!(conn.poll_for_event().unwrap().is_some());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_modal_loop() -> Result<(), ReplyError> {
+    use std::io::{Read, Write};
+    use x11rb::modal_loop::ModalLoop;
+    use x11rb::protocol::xproto::{
+        ButtonPressEvent, EventMask, ExposeEvent, GrabStatus, BUTTON_PRESS_EVENT,
+        BUTTON_RELEASE_EVENT, EXPOSE_EVENT,
+    };
+    use x11rb::protocol::Event;
+    use x11rb::rust_connection::RustConnection;
+
+    const MENU: u32 = 1;
+    const MAIN: u32 = 2;
+    let button = |response_type, sequence, time| {
+        <[u8; 32]>::from(ButtonPressEvent {
+            response_type,
+            sequence,
+            time,
+            event: MENU,
+            ..Default::default()
+        })
+    };
+    let expose = |sequence| {
+        <[u8; 32]>::from(ExposeEvent {
+            response_type: EXPOSE_EVENT,
+            sequence,
+            window: MAIN,
+            ..Default::default()
+        })
+    };
+
+    let (client, mut server) = std::os::unix::net::UnixStream::pair().unwrap();
+    let server = std::thread::spawn(move || {
+        answer_setup_request(&mut server);
+
+        // GrabPointer
+        let mut request = [0; 24];
+        server.read_exact(&mut request).unwrap();
+        assert_eq!(request[0], 26);
+        let mut reply = [0; 32];
+        reply[0] = 1;
+        reply[2] = 1;
+        server.write_all(&reply).unwrap();
+        server.write_all(&expose(1)).unwrap();
+        server
+            .write_all(&button(BUTTON_PRESS_EVENT, 1, 42))
+            .unwrap();
+
+        // UngrabPointer and GetInputFocus
+        let mut request = [0; 12];
+        server.read_exact(&mut request).unwrap();
+        assert_eq!(request[0], 27);
+        assert_eq!(request[4..8], 42u32.to_ne_bytes());
+        assert_eq!(request[8], 43);
+        server
+            .write_all(&button(BUTTON_RELEASE_EVENT, 1, 43))
+            .unwrap();
+        server.write_all(&expose(2)).unwrap();
+        reply[2] = 3;
+        server.write_all(&reply).unwrap();
+        server
+    });
+    let conn = RustConnection::connect_to_fd(client.into(), 0, Vec::new(), Vec::new()).unwrap();
+
+    let mut modal = ModalLoop::new(&conn, |event: &Event| event.window() == Some(MENU));
+    let status = modal.grab_pointer(MENU, true, EventMask::BUTTON_PRESS, 0, 0)?;
+    assert_eq!(status, GrabStatus::SUCCESS);
+    match modal.next_event()? {
+        Event::ButtonPress(event) => assert_eq!(event.time, 42),
+        event => panic!("Unexpected event {:?}", event),
+    }
+    assert_eq!(modal.deferred_events().len(), 1);
+
+    // The ButtonRelease still belongs to the modal loop and is dropped
+    let deferred = modal.finish()?;
+    let _server = server.join().unwrap();
+    let sequences = deferred
+        .iter()
+        .map(|event| match event {
+            Event::Expose(event) => event.sequence,
+            event => panic!("Unexpected event {:?}", event),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(sequences, [1, 2]);
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn test_poll_for_event_ref() {
+    use std::io::Write;
+    use x11rb::protocol::xproto::{ExposeEvent, EXPOSE_EVENT};
+    use x11rb::protocol::Event;
+    use x11rb::rust_connection::{EventRef, RustConnection, Stream};
+
+    fn wait<S: Stream>(conn: &RustConnection<S>) -> EventRef<'_, S> {
+        loop {
+            if let Some(event) = conn.poll_for_event_ref().unwrap() {
+                return event;
+            }
+        }
+    }
+
+    let (client, mut server) = std::os::unix::net::UnixStream::pair().unwrap();
+    let setup = std::thread::spawn(move || {
+        answer_setup_request(&mut server);
+        server
+    });
+    let conn = RustConnection::connect_to_fd(client.into(), 0, Vec::new(), Vec::new()).unwrap();
+    let mut server = setup.join().unwrap();
+
+    let mut pointers = Vec::new();
+    for window in 1..=3 {
+        let event = <[u8; 32]>::from(ExposeEvent {
+            response_type: EXPOSE_EVENT,
+            window,
+            ..Default::default()
+        });
+        server.write_all(&event).unwrap();
+        let event_ref = wait(&conn);
+        assert_eq!(*event_ref, event);
+        match event_ref.parse().unwrap() {
+            Event::Expose(event) => assert_eq!(event.window, window),
+            event => panic!("Unexpected event {:?}", event),
+        }
+        pointers.push(event_ref.as_ptr());
+    }
+    // The buffer of the first event is handed to the packet reader while the second event is read
+    // and then used for the third event.
+    assert_eq!(pointers[0], pointers[2]);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_synchronous_mode() {
+    use std::io::{Read, Write};
+    use std::sync::{Arc, Mutex};
+    use x11rb::protocol::ErrorKind;
+    use x11rb::rust_connection::{RustConnection, SyncMode};
+
+    let (client, mut server) = std::os::unix::net::UnixStream::pair().unwrap();
+    let server = std::thread::spawn(move || {
+        answer_setup_request(&mut server);
+        // Each NoOperation is followed by a GetInputFocus and fails with a Value error
+        for sequence in [1, 3, 5] {
+            let mut requests = [0; 8];
+            server.read_exact(&mut requests).unwrap();
+            assert_eq!((requests[0], requests[4]), (127, 43));
+            let mut error = [0; 32];
+            error[1] = 2;
+            error[2] = sequence;
+            server.write_all(&error).unwrap();
+            let mut reply = [0; 32];
+            reply[0] = 1;
+            reply[2] = sequence + 1;
+            server.write_all(&reply).unwrap();
+        }
+        server
+    });
+    let conn = RustConnection::connect_to_fd(client.into(), 0, Vec::new(), Vec::new()).unwrap();
+    conn.set_synchronous(SyncMode::Report);
+
+    // The error is passed to the handler before the request function returns
+    let errors = Arc::new(Mutex::new(Vec::new()));
+    let handler_errors = Arc::clone(&errors);
+    conn.set_error_handler(Some(Box::new(move |error| {
+        handler_errors
+            .lock()
+            .unwrap()
+            .push((error.error_kind, error.sequence))
+    })));
+    let cookie = conn.no_operation().unwrap();
+    assert_eq!(*errors.lock().unwrap(), [(ErrorKind::Value, 1)]);
+    assert!(cookie.check().is_ok());
+
+    // Without a handler, the error is left for the cookie
+    conn.set_error_handler(None);
+    match conn.no_operation().unwrap().check() {
+        Err(ReplyError::X11Error(error)) => {
+            assert_eq!((error.error_kind, error.sequence), (ErrorKind::Value, 3))
+        }
+        result => panic!("Unexpected result {:?}", result),
+    }
+
+    // Panicking has to be requested explicitly
+    conn.set_synchronous(SyncMode::Panic);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| conn.no_operation()));
+    let panic = result.unwrap_err();
+    let message = panic.downcast_ref::<String>().unwrap();
+    assert!(message.contains("Value error"), "{}", message);
+    let _server = server.join().unwrap();
+}
+
+#[cfg(unix)]
+#[test]
+fn test_fetch_server_time() {
+    use std::io::{Read, Write};
+    use x11rb::connection::Connection;
+    use x11rb::protocol::xproto::{
+        ExposeEvent, PropertyNotifyEvent, EXPOSE_EVENT, PROPERTY_NOTIFY_EVENT,
+    };
+    use x11rb::protocol::Event;
+    use x11rb::rust_connection::RustConnection;
+
+    let (client, mut server) = std::os::unix::net::UnixStream::pair().unwrap();
+    let server = std::thread::spawn(move || {
+        answer_setup_request(&mut server);
+        // CreateWindow with an event mask, ChangeProperty without data and GetInputFocus for
+        // checking both for errors
+        let mut requests = [0; 36 + 24 + 4];
+        server.read_exact(&mut requests).unwrap();
+        assert_eq!((requests[0], requests[36], requests[60]), (1, 18, 43));
+        let window = u32::from_ne_bytes(requests[4..8].try_into().unwrap());
+        let expose = <[u8; 32]>::from(ExposeEvent {
+            response_type: EXPOSE_EVENT,
+            sequence: 2,
+            window,
+            ..Default::default()
+        });
+        server.write_all(&expose).unwrap();
+        let notify = <[u8; 32]>::from(PropertyNotifyEvent {
+            response_type: PROPERTY_NOTIFY_EVENT,
+            sequence: 2,
+            window,
+            time: 1234,
+            ..Default::default()
+        });
+        server.write_all(&notify).unwrap();
+        server.write_all(&get_input_focus_reply(3)).unwrap();
+        server
+    });
+    let conn = RustConnection::connect_to_fd(client.into(), 0, Vec::new(), Vec::new()).unwrap();
+    assert_eq!(conn.fetch_server_time(0).unwrap(), 1234);
+    // Other events stay in the queue
+    assert!(matches!(
+        conn.poll_for_event().unwrap(),
+        Some(Event::Expose(_))
+    ));
+    let _server = server.join().unwrap();
+}
+
+#[test]
+fn test_fetch_server_time_invalid_screen() {
+    use x11rb::errors::{ParseError, ReplyOrIdError};
+    use x11rb::rust_connection::RustConnection;
+
+    let conn =
+        RustConnection::for_connected_stream(ChannelStream::new(Vec::new()), test_setup()).unwrap();
+    assert!(matches!(
+        conn.fetch_server_time(1),
+        Err(ReplyOrIdError::ConnectionError(
+            ConnectionError::ParseError(ParseError::InvalidValue)
+        ))
+    ));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_fetch_server_time_error() {
+    use std::io::{Read, Write};
+    use x11rb::errors::ReplyOrIdError;
+    use x11rb::protocol::ErrorKind;
+    use x11rb::rust_connection::RustConnection;
+
+    let (client, mut server) = std::os::unix::net::UnixStream::pair().unwrap();
+    let server = std::thread::spawn(move || {
+        answer_setup_request(&mut server);
+        let mut requests = [0; 36 + 24 + 4];
+        server.read_exact(&mut requests).unwrap();
+        // The window cannot be created, so no PropertyNotify event is generated
+        let mut error = [0; 32];
+        error[1] = 11; // Alloc
+        error[2..4].copy_from_slice(&1u16.to_ne_bytes());
+        error[10] = 1; // CreateWindow
+        server.write_all(&error).unwrap();
+        error[1] = 3; // Window
+        error[2..4].copy_from_slice(&2u16.to_ne_bytes());
+        error[10] = 18; // ChangeProperty
+        server.write_all(&error).unwrap();
+        server.write_all(&get_input_focus_reply(3)).unwrap();
+        server
+    });
+    let conn = RustConnection::connect_to_fd(client.into(), 0, Vec::new(), Vec::new()).unwrap();
+    match conn.fetch_server_time(0) {
+        Err(ReplyOrIdError::X11Error(error)) => assert_eq!(error.error_kind, ErrorKind::Alloc),
+        result => panic!("Unexpected result {:?}", result),
+    }
+    let _server = server.join().unwrap();
+}
+
+/// A reply to GetInputFocus with the given sequence number
+fn get_input_focus_reply(sequence: u16) -> [u8; 32] {
+    let mut reply = [0; 32];
+    reply[0] = 1;
+    reply[2..4].copy_from_slice(&sequence.to_ne_bytes());
+    reply
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn test_put_image_in_strips() {
+    use std::borrow::Cow;
+    use std::io::{Read, Write};
+    use x11rb::connection::Connection;
+    use x11rb::image::{BitsPerPixel, Image, ImageOrder, ScanlinePad};
+    use x11rb::protocol::xproto::{Format, Setup};
+    use x11rb::rust_connection::RustConnection;
+
+    let (client, mut server) = std::os::unix::net::UnixStream::pair().unwrap();
+    let server = std::thread::spawn(move || {
+        read_setup_request(&mut server);
+        // Requests have at most 64 bytes, so that a scanline does not fit into one request
+        let setup = Setup {
+            maximum_request_length: 16,
+            pixmap_formats: vec![Format {
+                depth: 8,
+                bits_per_pixel: 8,
+                scanline_pad: 8,
+            }],
+            ..test_setup()
+        };
+        let mut data = setup.serialize();
+        let length = u16::try_from((data.len() - 8) / 4).unwrap();
+        data[6..8].copy_from_slice(&length.to_ne_bytes());
+        server.write_all(&data).unwrap();
+
+        // QueryExtension for BIG-REQUESTS, which is not present
+        let mut request = [0; 20];
+        server.read_exact(&mut request).unwrap();
+        let mut reply = [0; 32];
+        reply[0] = 1;
+        reply[2] = 1;
+        server.write_all(&reply).unwrap();
+
+        // Three PutImage requests with strips of 36, 36 and 28 pixels
+        let mut requests = [0; 60 + 60 + 52];
+        server.read_exact(&mut requests).unwrap();
+        (server, requests)
+    });
+    let conn = RustConnection::connect_to_fd(client.into(), 0, Vec::new(), Vec::new()).unwrap();
+    let data = (0..100).collect::<Vec<u8>>();
+    let image = Image::new(
+        100,
+        1,
+        ScanlinePad::Pad8,
+        8,
+        BitsPerPixel::B8,
+        ImageOrder::LsbFirst,
+        Cow::Owned(data),
+    )
+    .unwrap();
+    let cookies = image.put(&conn, 1, 2, 5, 0).unwrap();
+    assert_eq!(cookies.len(), 3);
+    conn.flush().unwrap();
+    let (_server, requests) = server.join().unwrap();
+
+    let field = |offset: usize| u16::from_ne_bytes([requests[offset], requests[offset + 1]]);
+    for (start, width, dst_x, first_pixel) in [(0, 36, 5, 0), (60, 36, 41, 36), (120, 28, 77, 72)] {
+        assert_eq!(requests[start], 72);
+        assert_eq!(field(start + 12), width);
+        assert_eq!(field(start + 16), dst_x);
+        assert_eq!(requests[start + 24], first_pixel);
+    }
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn test_put_image_position_overflow() {
+    use std::borrow::Cow;
+    use std::io::{Read, Write};
+    use x11rb::connection::Connection;
+    use x11rb::image::{BitsPerPixel, Image, ImageOrder, ScanlinePad};
+    use x11rb::protocol::xproto::{Format, Setup};
+    use x11rb::rust_connection::RustConnection;
+
+    let (client, mut server) = std::os::unix::net::UnixStream::pair().unwrap();
+    let server = std::thread::spawn(move || {
+        read_setup_request(&mut server);
+        // Requests have at most 64 bytes, so that the image is split into strips and bands
+        let setup = Setup {
+            maximum_request_length: 16,
+            pixmap_formats: vec![Format {
+                depth: 8,
+                bits_per_pixel: 8,
+                scanline_pad: 8,
+            }],
+            ..test_setup()
+        };
+        let mut data = setup.serialize();
+        let length = u16::try_from((data.len() - 8) / 4).unwrap();
+        data[6..8].copy_from_slice(&length.to_ne_bytes());
+        server.write_all(&data).unwrap();
+
+        // QueryExtension for BIG-REQUESTS, which is not present
+        let mut request = [0; 20];
+        server.read_exact(&mut request).unwrap();
+        let mut reply = [0; 32];
+        reply[0] = 1;
+        reply[2] = 1;
+        server.write_all(&reply).unwrap();
+
+        // Only the NoOperation request that follows the failed uploads
+        let mut request = [0; 4];
+        server.read_exact(&mut request).unwrap();
+        (server, request)
+    });
+    let conn = RustConnection::connect_to_fd(client.into(), 0, Vec::new(), Vec::new()).unwrap();
+    let image = |width, height| {
+        let data = vec![0; usize::from(width) * usize::from(height)];
+        let order = ImageOrder::LsbFirst;
+        Image::new(
+            width,
+            height,
+            ScanlinePad::Pad8,
+            8,
+            BitsPerPixel::B8,
+            order,
+            Cow::Owned(data),
+        )
+        .unwrap()
+    };
+
+    // The last strip and the last band would start beyond i16::MAX
+    let error = image(100, 1)
+        .put(&conn, 1, 2, i16::MAX - 50, 0)
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        ConnectionError::MaximumRequestLengthExceeded
+    ));
+    let error = image(8, 10).put(&conn, 1, 2, 0, i16::MAX - 4).unwrap_err();
+    assert!(matches!(
+        error,
+        ConnectionError::MaximumRequestLengthExceeded
+    ));
+    conn.no_operation().unwrap().ignore_error();
+    conn.flush().unwrap();
+    let (_server, request) = server.join().unwrap();
+    assert_eq!(request[0], 127);
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn test_put_image_with_stride() {
+    use std::io::{Read, Write};
+    use x11rb::connection::Connection;
+    use x11rb::image::{BitsPerPixel, Image, ImageOrder, PixelFormat, ScanlinePad};
+    use x11rb::protocol::xproto::{Format, Setup};
+    use x11rb::rust_connection::RustConnection;
+
+    let (client, mut server) = std::os::unix::net::UnixStream::pair().unwrap();
+    let server = std::thread::spawn(move || {
+        read_setup_request(&mut server);
+        let setup = Setup {
+            maximum_request_length: 1024,
+            pixmap_formats: vec![Format {
+                depth: 8,
+                bits_per_pixel: 8,
+                scanline_pad: 8,
+            }],
+            ..test_setup()
+        };
+        let mut data = setup.serialize();
+        let length = u16::try_from((data.len() - 8) / 4).unwrap();
+        data[6..8].copy_from_slice(&length.to_ne_bytes());
+        server.write_all(&data).unwrap();
+
+        // QueryExtension for BIG-REQUESTS, which is not present
+        let mut request = [0; 20];
+        server.read_exact(&mut request).unwrap();
+        let mut reply = [0; 32];
+        reply[0] = 1;
+        reply[2] = 1;
+        server.write_all(&reply).unwrap();
+
+        // One PutImage request with six bytes of pixels and two bytes of padding
+        let mut request = [0; 24 + 8];
+        server.read_exact(&mut request).unwrap();
+        (server, request)
+    });
+    let conn = RustConnection::connect_to_fd(client.into(), 0, Vec::new(), Vec::new()).unwrap();
+    // Two scanlines of three pixels, each followed by two bytes that do not belong to the image
+    let data = [1, 2, 3, 0xff, 0xff, 4, 5, 6, 0xff, 0xff];
+    let format =
+        PixelFormat::new(8, BitsPerPixel::B8, ScanlinePad::Pad8, ImageOrder::LsbFirst).unwrap();
+    let image = Image::from_slice(3, 2, 5, &format, &data).unwrap();
+    let cookies = image.put(&conn, 1, 2, 0, 0).unwrap();
+    assert_eq!(cookies.len(), 1);
+    conn.flush().unwrap();
+    let (_server, request) = server.join().unwrap();
+
+    assert_eq!(request[0], 72);
+    assert_eq!(&request[24..30], [1, 2, 3, 4, 5, 6]);
+}
+
+#[cfg(all(unix, feature = "dbe"))]
+#[test]
+fn test_double_buffer_fallback() {
+    use std::io::{Read, Write};
+    use x11rb::connection::Connection;
+    use x11rb::double_buffer::DoubleBuffer;
+    use x11rb::rust_connection::RustConnection;
+
+    let (client, mut server) = std::os::unix::net::UnixStream::pair().unwrap();
+    let server = std::thread::spawn(move || {
+        answer_setup_request(&mut server);
+
+        // QueryExtension for DOUBLE-BUFFER, which is not present
+        let mut request = [0; 24];
+        server.read_exact(&mut request).unwrap();
+        assert_eq!(&request[8..21], b"DOUBLE-BUFFER");
+        let mut reply = [0; 32];
+        reply[0] = 1;
+        reply[2..4].copy_from_slice(&1u16.to_ne_bytes());
+        server.write_all(&reply).unwrap();
+
+        // GetGeometry of the window
+        let mut request = [0; 8];
+        server.read_exact(&mut request).unwrap();
+        assert_eq!(request[0], 14);
+        let mut reply = [0; 32];
+        reply[0] = 1;
+        reply[1] = 24;
+        reply[2..4].copy_from_slice(&2u16.to_ne_bytes());
+        reply[16..18].copy_from_slice(&100u16.to_ne_bytes());
+        reply[18..20].copy_from_slice(&50u16.to_ne_bytes());
+        server.write_all(&reply).unwrap();
+
+        // CreatePixmap, CreateGC, CopyArea, FreePixmap and FreeGC
+        let mut requests = [0; 16 + 16 + 28 + 8 + 8];
+        server.read_exact(&mut requests).unwrap();
+        (server, requests)
+    });
+    let conn = RustConnection::connect_to_fd(client.into(), 0, Vec::new(), Vec::new()).unwrap();
+    let buffer = DoubleBuffer::new(&conn, 42).unwrap();
+    assert!(!buffer.uses_dbe());
+    buffer.swap().unwrap();
+    let pixmap = buffer.drawable();
+    drop(buffer);
+    conn.flush().unwrap();
+    let (_server, requests) = server.join().unwrap();
+
+    let opcodes = [
+        requests[0],
+        requests[16],
+        requests[32],
+        requests[60],
+        requests[68],
+    ];
+    assert_eq!(opcodes, [53, 55, 62, 54, 60]);
+    // The pixmap has the depth and size of the window
+    assert_eq!(requests[1], 24);
+    assert_eq!(&requests[4..8], &pixmap.to_ne_bytes());
+    let field = |offset: usize| u16::from_ne_bytes([requests[offset], requests[offset + 1]]);
+    assert_eq!((field(12), field(14)), (100, 50));
+    // CopyArea copies the whole pixmap into the window
+    assert_eq!(&requests[36..40], &pixmap.to_ne_bytes());
+    assert_eq!(&requests[40..44], &42u32.to_ne_bytes());
+    assert_eq!((field(56), field(58)), (100, 50));
+}